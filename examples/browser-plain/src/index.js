@@ -1,4 +1,4 @@
-import {LucidSuggest} from 'lucid-suggest/en'
+import {LucidSuggest} from 'lucid-suggest'
 import DATA from './e_commerce.json'
 
 document.addEventListener('DOMContentLoaded', () => {
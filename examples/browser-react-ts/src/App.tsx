@@ -1,4 +1,4 @@
-import {LucidSuggest, Hit} from 'lucid-suggest/en'
+import {LucidSuggest, Hit} from 'lucid-suggest'
 import DATA from './e_commerce.json'
 import React, {useState, useEffect} from 'react'
 import IconLoupe from './IconLoupe'
@@ -1,4 +1,4 @@
-import {Hit} from 'lucid-suggest/en'
+import {Hit} from 'lucid-suggest'
 import React from 'react'
 
 type ListItemProps = {
@@ -1,5 +1,5 @@
 import * as readline from 'readline'
-import {LucidSuggest, Hit} from 'lucid-suggest/en'
+import {LucidSuggest, Hit} from 'lucid-suggest'
 import DATA from './e_commerce.json'
 
 const CODE_RESET = '\x1b[0m'
@@ -1,5 +1,5 @@
 const {Suite} = require('benchmark')
-const {LucidSuggest} = require('../en')
+const {LucidSuggest} = require('../index')
 const {first2digits} = require('./utils')
 const {generateRecords, generateQueries} = require('./dataset')
 
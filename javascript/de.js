@@ -1,346 +0,0 @@
-'use strict';
-
-Object.defineProperty(exports, '__esModule', { value: true });
-
-var src = 'AGFzbQEAAAABehNgAn9/AGACf38Bf2ADf39/AGADf39/AX9gAX8AYAR/f39/AGAFf39/f38AYAF/AX9gAAF/YAR/f39/AX9gAABgB39/f39/f38AYAV/f39/fwF/YAd/f39/f39/AX9gAn9+AX9gAn5/AX9gAX8BfmABfAF8YAJ8fAF8A94B3AEFAgIHBgYGBgYABwAAAAAAAAAAAAACCwAEBAUDBAEAAAIECQIBDAIIAAUAAQUJAQAABwIBBwcGAwUBAQACAgEBAAAGBQUBAgUFAA0CAwAAAA8CAAAAAQEBAQAEAQEAAQEBAwABBgQABQIFBQQFAAAABAQAAAIABQAAAAQCCAICBAARAAQGBgICAgACAAAAAAgIAAQJBAIEAgAJAgUOAAMAAAAAAQAAAAAAAAQAAgABBAAAAAoCBAABAgADCQACAgMEBwQHAAMICAMFBQUEEgEBAQEACgkBCgEKEAAEBAUBcAEaGgUDAQARBgkBfwFBgIDAAAsHxAEMBm1lbW9yeQIADGNyZWF0ZV9zdG9yZQAcDWRlc3Ryb3lfc3RvcmUAGA5oaWdobGlnaHRfd2l0aABCCXNldF9saW1pdABZC3NldF9yZWNvcmRzABYKcnVuX3NlYXJjaAACDmdldF9yZXN1bHRfaWRzAE0RZ2V0X3Jlc3VsdF90aXRsZXMAQRFfX3diaW5kZ2VuX21hbGxvYwDBARJfX3diaW5kZ2VuX3JlYWxsb2MAyAEPX193YmluZGdlbl9mcmVlANIBCTQBAEEBCxkK2gHRAR3VAaMBLtUBrgFV2wHZAdsBzwHbAdAB2wHOAdsB2wHbAdsBxwHbAdsBCvTYBtwB1VgDQX8FfgJ8IwBBgARrIgQkAAJAAkACQAJAAkAgASgCACIFIAEoAgRHBEAgBEHsAGohJyAEQdQDaiEoIARBlQNqIS8gBEHlA2ohMCAEQZACaiEdIARB+AJqIRggBEH4AGohMSAEQfUAaiE8IARByANqISIgBEHMA2ohGSAEQYQBaiE5IARB5ABqITogBEHgAGohPSAEQYsCaiEyIARB3QNqIR4gBEHsA2ohIyAEQdwCaiEpIARB7QJqIT4DQCABIAVBBGo2AgACQAJAAkACQAJAIAEoAggoAgAiBkEMaigCACIHIAUoAgAiBUsEQCAGKAIEIAVBPGxqIgUoAjghPyAFKAIIISogBSgCBCFAIAVBNGooAgAhMyAFQSxqKAIAITQgBUEoaigCACE1IAVBIGooAgAhNiAFQRxqKAIAITcgBUEUaigCACE4IAVBEGooAgAhJCADKAIAKAIAIQoQjwEiF0UNDBCQASIWRQ0MIBcoAgBFBEAgF0F/NgIAIBYoAgBFBEAgFkF/NgIAIBZBBGohCwJAIBdBBGoiESgCACIFRQRAQQAhBSAXQQA2AhQMAQsgFygCCEH/ASAFQQVqEMUBGiAXQQA2AhQgFygCBCIFQQhJDQAgBUEBakEDdkEHbCEFCyAXIAU2AhACQCALKAIAIgVFBEBBACEFIBZBADYCFAwBCyAWKAIIQf8BIAVBBWoQxQEaIBZBADYCFCAWKAIEIgVBCEkNACAFQQFqQQN2QQdsIQULIBYgBTYCECAKKAIEIgUEQCAKKAIAIhQgBUEUbGohJSAqICRBFGxqIhpBbGohKwNAAkAgCyAUKAIAIgkQYA0AIAQgCTYCKCAEIBQvARA7AVAgBCAUKAIMNgI0IAQgFCgCCCIONgIwIAQgFCgCBCITNgIsIAQgCigCGCIfNgJIIAQgCigCECIgNgJAIAQgCigCCCIhNgI4IAQgCkEcaigCACIsNgJMIAQgCkEUaigCACJBNgJEIAQgCkEMaigCACJCNgI8IARBAjoAdCAkRQ0AQQIhByAqIQUCQANAAkACQCARIAUoAgAiDRBgDQAgBCAzNgK8ASAEIDQ2ArgBIAQgNTYCtAEgBCA2NgKwASAEIDc2AqwBIAQgODYCqAEgBCAFQRBqLwEAOwHAASAEIAVBDGooAgA2AqQBIAQgBUEIaigCACIMNgKgASAEIAVBBGooAgAiBjYCnAEgBCANNgKYAQJAAkAgJCANQQFqIhJNDQAgBCAzNgKsAiAEIDQ2AqgCIAQgNTYCpAIgBCA2NgKgAiAEIDc2ApwCIAQgODYCmAIgBCAqIBJBFGxqIggtABEiFToAsQIgBCAILQAQOgCwAiAEIAgoAgwiGzYClAIgBCAIKAIIIg82ApACIAQgCCgCBCIQNgKMAiAEIAgoAgA2AogCAkACQCAGIA9JBEAgECAMSQ0BIBAgDGshCAwCCyAGIA9rIQgMAQsQ1gEACyAOIBNrIAwgBmsgCGpJDQAgESASEGANACAEIBU6AJkDIARBCjoAmAMgBCAPNgL4AiAEIA02AvACIAQgMzYClAMgBCA0NgKQAyAEIDU2AowDIAQgNjYCiAMgBCA3NgKEAyAEIDg2AoADIAQgBjYC9AIgBCAQIAZrIBtqNgL8AiAEQcADaiAEQfACaiAEQShqEAEgBEGAAmoiDSAZQQhqIggpAgA3AwAgBEH2AWoiDCAeQQJqIhstAAA6AAAgBEHoAWoiBiAjQQhqIiYpAAA3AwAgBEHwAWoiDyAjQRBqIjsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiLUECRg0AIAQoAugDIRwgBCkD4AMhRSAEKALIAyEuIAQpA8ADIUYgBEHIAmoiECANKQMANwMAIARBqANqIhIgBikDADcDACAEQbADaiIVIA8oAgA2AgAgBCAEKQP4ATcDwAIgBCAELwH0ATsBuAMgBCAEKQPgATcDoAMgBCAMLQAAOgC6AyAEIC42AtgCIAQgRjcD0AIgKSAEKQPAAjcCACApQQhqIBApAwA3AgAgBCAtOgDsAiA+IAQvAbgDOwAAID5BAmogBC0AugM6AAAgBEHYAWoiLSAVKAIANgIAIARB0AFqIi4gEikDADcDACAEIAQpA6ADNwPIASAEQcADaiAEQdACaiAEQZgBaiAEQYgCahBDIA0gCCkCADcDACAMIBstAAA6AAAgBiAmKQAANwMAIA8gOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyImQQJGDQAgBCgC6AMhBSAEKQPgAyFHIAQoAsgDIQcgBCkDwAMhRiAQIA0pAwA3AwAgGCAGKQMANwMAIARBgANqIhMgDygCADYCACAEIAQpA/gBNwPAAiAEIAQvAfQBOwG4AyAEIAQpA+ABNwPwAiAEIAwtAAA6ALoDIAYgECkDADcDACAEIAQpA8ACNwPgASAEIAQvAbgDOwH4ASAEIAQtALoDOgD6ASAVIBMoAgA2AgAgEiAYKQMANwMAIAQgBCkD8AI3A6ADIAQgBzYCyAMgBCBGNwPAAyAZIAQpA+ABNwIAIAggBikDADcCACAEICY6ANwDIB4gBC8B+AE7AAAgGyAELQD6AToAACAEQfACaiARIAcgBEHAA2oQKSAZIAQpA6ADNwIAIAggEikDADcCACAZQRBqIgYgFSgCADYCACAEIAU2AsgDIAQgRzcDwAMgBEHwAmogESAFIARBwANqECkgGSAEKQPIATcCACAIIC4pAwA3AgAgBiAtKAIANgIAIAQgHDYCyAMgBCBFNwPAAyAEQfACaiALIBwgBEHAA2oQKQwBCwJAIAooAgQgCUEBaiIMTQ0AIAooAgAhBiAKKQIIIUUgCikCECFGIAQgCikCGDcDqAIgBCBGNwOgAiAEIEU3A5gCIAQgBiAMQRRsaiIGLQARIg86ALECIAQgBi0AEDoAsAIgBCAGKAIMIhA2ApQCIAQgBigCCCIINgKQAiAEIAYoAgQiDTYCjAIgBCAGKAIANgKIAiAEKAKcASESIAQoAqABAkACQCATIAhJBEAgDSAOSQ0BIA0gDmshBgwCCyATIAhrIQYMAQsQ1gEACyASayAOIBNrIAZqSQ0AIAsgDBBgDQAgBCAPOgCZAyAEQQo6AJgDIAQgCDYC+AIgBCAJNgLwAiAEICw2ApQDIAQgHzYCkAMgBCBBNgKMAyAEICA2AogDIAQgQjYChAMgBCAhNgKAAyAEIBM2AvQCIAQgDSATayAQajYC/AIgBEHAA2ogBEGYAWogBEHwAmoQASAEQYACaiINIBlBCGoiCCkCADcDACAEQfYBaiIMIB5BAmoiDy0AADoAACAEQegBaiIGICNBCGoiJikAADcDACAEQfABaiIQICNBEGoiOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyItQQJGDQAgBCgC6AMhLiAEKQPgAyFFIAQoAsgDIRwgBCkDwAMhRiAEQdABaiISIA0pAwA3AwAgBEG6A2oiQyAMLQAAOgAAIARBqANqIhUgBikDADcDACAEQbADaiIbIBAoAgA2AgAgBCAEKQP4ATcDyAEgBCAELwH0ATsBuAMgBCAEKQPgATcDoAMgBEHIAmoiRCASKQMANwMAIAQgBCkDyAE3A8ACIAQgBC8BuAM7AbgCIAQgQy0AADoAugIgBCAuNgLYAiAEIEU3A9ACICkgBCkDoAM3AgAgKUEIaiAVKQMANwIAIClBEGogGygCADYCACAEQcADaiAEQdACaiAEQShqIARBiAJqEEMgDSAIKQIANwMAIAwgDy0AADoAACAGICYpAAA3AwAgECA7KAAANgIAIAQgGSkCADcD+AEgBCAeLwAAOwH0ASAEICMpAAA3A+ABIAQtANwDIiZBAkYNACAEKALoAyEFIAQpA+ADIUcgBCgCyAMhByAEKQPAAyFFIBIgDSkDADcDACAYIAYpAwA3AwAgBEGAA2oiEyAQKAIANgIAIAQgBCkD+AE3A8gBIAQgBC8B9AE7AbgDIAQgBCkD4AE3A/ACIAQgDC0AADoAugMgBiASKQMANwMAIAQgBCkDyAE3A+ABIAQgBC8BuAM7AfgBIAQgBC0AugM6APoBIBsgEygCADYCACAVIBgpAwA3AwAgBCAEKQPwAjcDoAMgBCAcNgLIAyAEIEY3A8ADIBkgBCkDwAI3AgAgCCBEKQMANwIAIAQgLToA3AMgHiAELwG4AjsAACAPIAQtALoCOgAAIARB8AJqIBEgHCAEQcADahApIBkgBCkD4AE3AgAgCCAGKQMANwIAIB4gBC8B+AE7AAAgDyAELQD6AToAACAEIAc2AsgDIAQgRTcDwAMgBCAmOgDcAyAEQfACaiALIAcgBEHAA2oQKSAZIAQpA6ADNwIAIAggFSkDADcCACAZQRBqIBsoAgA2AgAgBCAFNgLIAyAEIEc3A8ADIARB8AJqIAsgBSAEQcADahApDAELIARBwANqIARBmAFqIARBKGoQASAEQdgCaiIIICJBCGooAgA2AgAgBCAiKQMANwPQAiAEKwPAAyFKIAQoAtQDIQ0gBCgC2AMhDCAELQDcAyEGIARB8AJqIB5BIxC/ARogBkECRg0BIARBqANqIg8gCCgCADYCACAEIAQpA9ACNwOgAyAEQYgCaiAEQfACakEjEL8BGiAYIA8oAgA2AgAgBCAEKQOgAzcD8AIgBCAELwCIAjsB0AIgBCAEQYoCai0AADoA0gIgBEHYA2oiECAyQRhqKQAANwMAIARB0ANqIhIgMkEQaikAADcDACAiIDJBCGopAAA3AwAgBCAyKQAANwPAAwJ/IEqbIktEAAAAAAAA8EFjIEtEAAAAAAAAAABmcQRAIEurDAELQQALIQ8CQAJAIAdB/wFxQQJGIhUNAEEAIQhBfyAMIA1rIA9BAXRrIhtBACAEQdgAaiAVGyIPKAIYIA8oAhRrAn8gDysDAJsiS0QAAAAAAADwQWMgS0QAAAAAAAAAAGZxBEAgS6sMAQtBAAtBAXRrIg9HIA8gG0kbQQFqIg9BAUsNASAPQQFrDQAgBg0BCyA9IAQpA/ACNwMAIDwgBC8B0AI7AAAgMSAEKQPAAzcDACA8QQJqIAQtANICOgAAID1BCGogGCgCADYCACAxQQhqICIpAwA3AwAgMUEQaiASKQMANwMAIDFBGGogECkDADcDACAEIAw2AnAgBCANNgJsIAQgBjoAdCAGQQFzIQggBCBKOQNYIEq9IUcgBiEHCyAFICtGDQQgBUEUaiEFIAhFDQMMBAsgBEECOgDcA0EAIQUDQCAEQdgAaiAFaiIGKQMAIUcgBiAEQcADaiAFaiIHKQMANwMAIAcgRzcDACAGQQhqIggpAwAhRSAIIAdBCGoiCCkDADcDACAGQRBqIhMpAwAhRiATIAdBEGoiEykDADcDACAGQRhqIgYpAwAhRyAGIAdBGGoiBikDADcDACAGIEc3AwAgEyBGNwMAIAggRTcDACAFQUBrIAVBIGohBUHBAEkNAAsgBC0AdCEHDAELIBogBUEUaiIFRw0BCwsgB0H/AXFBAkYNASAEKQNYIUcLIAQpA3ghRSAEKAKAASEFIAQoAmAhBiAZIDopAgA3AgAgGUEIaiIHIDpBCGopAgA3AgAgGUEQaiIIIDpBEGooAgA2AgAgBCAGNgLIAyAEIEc3A8ADIARB8AJqIBEgBiAEQcADahApIBkgOSkCADcCACAHIDlBCGopAgA3AgAgCCA5QRBqKAIANgIAIAQgBTYCyAMgBCBFNwPAAyAEQfACaiALIAUgBEHAA2oQKQsgFEEUaiIUICVHDQALCyAXKAIIIgUoAgAhCCAXKAIUIQYgF0EANgIUIBcoAhAhFCAXQQA2AhAgFygCDCEHIBdBCDYCDCAXQbCmwgA2AgggFygCBCEKIBdBADYCBCAEIBE2AoABIAQgBjYCfCAEIBQ2AnggBCAHNgJ0IAQgBTYCcCAEIAo2AmwgBCAGNgJoIAQgBSAKakEBajYCZCAEIAVBBGo2AmAgBCAHNgJcIAQgCEF/c0GAgYKEeHE2AlggBEHAA2ogBEHYAGoQggECfyAELQDkAyIGQQJGBEACQCAEKAJsIgVFBEBBACEFIARBADYCfAwBCyAEKAJwQf8BIAVBBWoQxQEaIARBADYCfCAFQQhJDQAgBUEBakEDdkEHbCEFCyAEIAU2AnggBCgCgAEiBSAnKQIANwIAIAVBEGogJ0EQaigCADYCACAFQQhqICdBCGopAgA3AgBCACFFQQgMAQsgBEHwAmogBEHAA2pBJBC/ARogHSAYQQhqKQMANwMAIARBmAJqIgogGEEQaikDADcDACAEQaACaiIIIBhBGGooAgA2AgAgBCAwQQJqLQAAOgCaASAEIDAvAAA7AZgBIAQgGCkDADcDiAIgBEEgakF/IAQoAmgiBUEBaiIHIAcgBUkbEKkBIAQoAiQhBSAEKAIgIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsEL8BGiAEQfACaiAEQcADahCCAQJAIAQtAJQDIghBAkYNAEEBIQZBICEJA0AgBEGIAmogBEHwAmpBJBC/ARogBEGwAWogHUEYaigCACIKNgIAIARBqAFqIB1BEGopAwAiRTcDACAEQaABaiAdQQhqKQMAIkY3AwAgBCAvQQJqLQAAOgDSAiAEIC8vAAA7AdACIAQgHSkDACJHNwOYASAYIEY3AwAgBEGAA2oiESBFNwMAIARBiANqIhQgCjYCACAEIEc3A/ACIAQgBC8B0AI7AYgCIAQgBC0A0gI6AIoCIAUgBkYEQCAEQShqIAVBfyAEKALQAyIFQQFqIgcgByAFSRsQhwEgBCgCKCEHCyAHIAlqIgUgBCkD8AI3AwAgGCkDACFFIBEpAwAhRiAUKAIAIQogBUEcaiAIOgAAIAVBHWogBC8BiAI7AAAgBUEfaiAELQCKAjoAACAFQRhqIAo2AgAgBUEQaiBGNwMAIAVBCGogRTcDACAEIAZBAWoiBjYCMCAEQfACaiAEQcADahCCASAELQCUAyIIQQJGDQEgCUEgaiEJIAQoAiwhBQwACwALAkAgBCgC1AMiBUUEQEEAIQUgBEEANgLkAwwBCyAEKALYA0H/ASAFQQVqEMUBGiAEQQA2AuQDIAVBCEkNACAFQQFqQQN2QQdsIQULIAQoAugDIgYgKCkCADcCACAEIAU2AuADIAZBCGogKEEIaikCADcCACAGQRBqIChBEGooAgA2AgAgBCkCLCFFIAQoAigLIQ0gFigCCCIFKAIAIQggFigCFCEGIBZBADYCFCAWKAIQIREgFkEANgIQIBYoAgwhByAWQQg2AgwgFkGwpsIANgIIIBYoAgQhCiAWQQA2AgQgBCALNgKAASAEIAY2AnwgBCARNgJ4IAQgBzYCdCAEIAU2AnAgBCAKNgJsIAQgBjYCaCAEIAUgCmpBAWo2AmQgBCAFQQRqNgJgIAQgBzYCXCAEIAhBf3NBgIGChHhxNgJYIARBwANqIARB2ABqEIIBAn8gBC0A5AMiBkECRgRAAkAgBCgCbCIFRQRAQQAhBSAEQQA2AnwMAQsgBCgCcEH/ASAFQQVqEMUBGiAEQQA2AnwgBUEISQ0AIAVBAWpBA3ZBB2whBQsgBCAFNgJ4IAQoAoABIgUgJykCADcCACAFQRBqICdBEGooAgA2AgAgBUEIaiAnQQhqKQIANwIAQQAhG0EAIRFBCAwBCyAEQfACaiAEQcADakEkEL8BGiAdIBhBCGopAwA3AwAgBEGYAmoiCiAYQRBqKQMANwMAIARBoAJqIgggGEEYaigCADYCACAEIDBBAmotAAA6AJoBIAQgMC8AADsBmAEgBCAYKQMANwOIAkEBIREgBEEYakF/IAQoAmgiBUEBaiIHIAcgBUkbEKkBIAQoAhwhBSAEKAIYIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsEL8BGiAEQfACaiAEQcADahCCASAELQCUAyIIQQJHBEBBASEGQSAhCQNAIARBiAJqIARB8AJqQSQQvwEaIARBsAFqIB1BGGooAgAiCjYCACAEQagBaiAdQRBqKQMAIkY3AwAgBEGgAWogHUEIaikDACJINwMAIAQgL0ECai0AADoA0gIgBCAvLwAAOwHQAiAEIB0pAwAiRzcDmAEgGCBINwMAIARBgANqIgsgRjcDACAEQYgDaiIRIAo2AgAgBCBHNwPwAiAEIAQvAdACOwGIAiAEIAQtANICOgCKAiAFIAZGBEAgBEEoaiAFQX8gBCgC0AMiBUEBaiIHIAcgBUkbEIcBIAQoAighBwsgByAJaiIFIAQpA/ACNwMAIBgpAwAhRiALKQMAIUggESgCACEKIAVBHGogCDoAACAFQR1qIAQvAYgCOwAAIAVBH2ogBC0AigI6AAAgBUEYaiAKNgIAIAVBEGogSDcDACAFQQhqIEY3AwAgBCAGQQFqIgo2AjAgBEHwAmogBEHAA2oQggEgBC0AlAMiCEECRwRAIAlBIGohCSAEKAIsIQUgCiEGDAELCyAGQQFqIRELAkAgBCgC1AMiBUUEQEEAIQUgBEEANgLkAwwBCyAEKALYA0H/ASAFQQVqEMUBGiAEQQA2AuQDIAVBCEkNACAFQQFqQQN2QQdsIQULIAQoAugDIgYgKCkCADcCACAEIAU2AuADIAZBCGogKEEIaikCADcCACAGQRBqIChBEGooAgA2AgAgBCgCLCEbIAQoAigLIQ8gRUIgiCJGpyIUQRVJDQMgBEEQaiBFQiGIpxCpASAEKAIUIAQoAhAhE0EAIQUgBEEANgJgIARCBDcDWCANQWBqIRwgDUGof2ohJUEAIQcgFCEKA0ACQCAKIgtBf2oiCkUEQEEBIQhBACEKDAELAkACQAJAAkACQCANIApBBXRqKAIIIAtBBXQiDCANakFIaigCACIGTwRAIAtBfmohDiAMICVqIQhBACEKQQAhCQNAIAkgDkYEQCALIQgMCAsgCUEBaiEJIAYgCCgCACIGTyAIQWBqIQgNAAsgCUEBaiEIIAlBf3MgC2ohCgwBCyAMICVqIQkDQAJAIApBAUYEQEEAIQoMAQsgCkF/aiEKIAYgCSgCACIGSSAJQWBqIQkNAQsLIAsgCkkNASALIBRLDQIgCyAKayIIQQF2Ig5FDQAgDSAKQQV0aiEGIAwgHGohCQNAIARB2ANqIgwgBkEYaiIQKQMANwMAIARB0ANqIhIgBkEQaiIVKQMANwMAICIgBkEIaiIaKQMANwMAIAQgBikDADcDwAMgCUEIaiIfKQMAIUggCUEQaiIgKQMAIUkgCUEYaiIhKQMAIUcgBiAJKQMANwMAIBAgRzcDACAVIEk3AwAgGiBINwMAICEgDCkDADcDACAgIBIpAwA3AwAgHyAiKQMANwMAIAkgBCkDwAM3AwAgBkEgaiEGIAlBYGohCSAOQX9qIg4NAAsLIApFIAhBCUtyDQQgCyAKayEIIApBf2ohBSAcIApBBXRqIQYDQCALIAVJDQMgCyAUSw0CIAYgCEEBaiIIEEAgBUF/aiIHIAVPDQQgBkFgaiEGIAchBSAIQQpJDQALDAMLIAogCxCtAQALIAsgFBCrAQALIAUgCxCtAQALIAdBAWohCiAEKAJgIgUhBwsgBCgCXCAHRgRAIARB2ABqIAcQjgEgBCgCYCIFIQcLIAQoAlgiBiAHQQN0aiIHIAg2AgQgByAKNgIAIAQgBUEBaiIFNgJgAn8CQCAFQQJJDQACQANAAkACQAJAAkAgBiAFQX9qQQN0aiIHKAIARQ0AIAVBA3QgBmoiDkF0aigCACILIAcoAgQiCE0NAEECIAVBAk0NBxogBiAFQX1qIgxBA3RqKAIEIgkgCCALak0NAUEDIAVBA00NBxogDkFkaigCACAJIAtqSw0GDAELIAVBA0kNASAHKAIEIQggBiAFQX1qIgxBA3RqKAIEIQkLIAkgCEkNAQsgBUF+aiEMCwJAAkACQAJAAkACQAJAIAUgDEEBaiISSwRAIAUgDE0NASAGIAxBA3QiH2oiBSgCBCIgIAUoAgBqIgUgBiASQQN0IiFqIgYoAgAiFUkNAiAFIBRLDQMgDSAVQQV0aiILIAYoAgQiEEEFdCIIaiEHIAVBBXQhGiAFIBVrIg4gEGsiBSAQTw0EIBMgByAFQQV0IgYQvwEiDiAGaiEJIBBBAU5BACAFQQFOG0UEQCAHIQUMBgsgGiAcaiEIIAchBQNAIAggBUFgaiIaIAlBYGoiLCAJQWhqKAIAIAVBaGooAgBJIgcbIgYpAwA3AwAgCEEYaiAGQRhqKQMANwMAIAhBEGogBkEQaikDADcDACAIQQhqIAZBCGopAwA3AwAgCSAsIAcbIQkgCyAaIAUgBxsiBU8NBiAIQWBqIQggCSAOIgZLDQALDAcLQeirwgAgEiAFEKwBAAtB6KvCACAMIAUQrAEACyAVIAUQrQEACyAFIBQQqwEACyAIIBMgCyAIEL8BIgZqIQkgDiAQSkEAIBBBAU4bDQEgCyEFCyATIQYMAQsgDSAaaiEOIAshBQNAIAUgByAGIAcoAgggBigCCEkiCxsiCCkDADcDACAFQRhqIAhBGGopAwA3AwAgBUEQaiAIQRBqKQMANwMAIAVBCGogCEEIaikDADcDACAGIAZBIGogCxshBiAFQSBqIQUgB0EgaiAHIAsbIgcgDk8NASAJIAZLDQALCyAFIAYgCSAGa0FgcRC/ARogBCgCYCIFIAxLBEAgBCgCWCIGIB9qIgcgECAgajYCBCAHIBU2AgAgBSASTQ0CIAYgIWoiByAHQQhqIAUgEkF/c2pBA3QQngEaIAQgBUF/aiIFNgJgIAUgBUEBTQ0EGgwBCwtB+KvCACAMIAUQrAEAC0GnrsIAQR1BxK7CABC+AQALIAULIQcgCg0ACyAEKAJcBEAgBhAhC0UNBCATECEMBAsMDAsMCwtB6KvCACAFIAcQrAEACyAUQQJJDQAgFEF+aiEFIA0gFEEFdGpBQGohBkECIQkDQCAFIBRLDQIgBiAJEEAgBkFgaiEGIAlBAWohCSAFQX9qIgVBf0cNAAsLIBFBFUkNASAEQQhqIBFBAXYQqQEgBCgCDCAEKAIIIRNBACEFIARBADYCYCAEQgQ3A1ggD0FgaiEcIA9BqH9qISVBACEHIBEhCgNAAkAgCiILQX9qIgpFBEBBASEIQQAhCgwBCwJAAkACQAJAAkAgDyAKQQV0aigCCCALQQV0IgwgD2pBSGooAgAiBk8EQCALQX5qIQ4gDCAlaiEIQQAhCkEAIQkDQCAJIA5GBEAgCyEIDAgLIAlBAWohCSAGIAgoAgAiBk8gCEFgaiEIDQALIAlBAWohCCAJQX9zIAtqIQoMAQsgDCAlaiEJA0ACQCAKQQFGBEBBACEKDAELIApBf2ohCiAGIAkoAgAiBkkgCUFgaiEJDQELCyALIApJDQEgCyARSw0CIAsgCmsiCEEBdiIORQ0AIA8gCkEFdGohBiAMIBxqIQkDQCAEQdgDaiIMIAZBGGoiECkDADcDACAEQdADaiISIAZBEGoiFSkDADcDACAiIAZBCGoiGikDADcDACAEIAYpAwA3A8ADIAlBCGoiHykDACFIIAlBEGoiICkDACFJIAlBGGoiISkDACFHIAYgCSkDADcDACAQIEc3AwAgFSBJNwMAIBogSDcDACAhIAwpAwA3AwAgICASKQMANwMAIB8gIikDADcDACAJIAQpA8ADNwMAIAZBIGohBiAJQWBqIQkgDkF/aiIODQALCyAKRSAIQQlLcg0EIAsgCmshCCAKQX9qIQUgHCAKQQV0aiEGA0AgCyAFSQ0DIAsgEUsNAiAGIAhBAWoiCBBAIAVBf2oiByAFTw0EIAZBYGohBiAHIQUgCEEKSQ0ACwwDCyAKIAsQrQEACyALIBEQqwEACyAFIAsQrQEACyAHQQFqIQogBCgCYCIFIQcLIAQoAlwgB0YEQCAEQdgAaiAHEI4BIAQoAmAiBSEHCyAEKAJYIgYgB0EDdGoiByAINgIEIAcgCjYCACAEIAVBAWoiBTYCYAJ/AkAgBUECSQ0AAkADQAJAAkACQAJAIAYgBUF/akEDdGoiBygCAEUNACAFQQN0IAZqIg5BdGooAgAiCyAHKAIEIghNDQBBAiAFQQJNDQcaIAYgBUF9aiIMQQN0aigCBCIJIAggC2pNDQFBAyAFQQNNDQcaIA5BZGooAgAgCSALaksNBgwBCyAFQQNJDQEgBygCBCEIIAYgBUF9aiIMQQN0aigCBCEJCyAJIAhJDQELIAVBfmohDAsCQAJAAkACQAJAAkACQCAFIAxBAWoiEksEQCAFIAxNDQEgBiAMQQN0Ih9qIgUoAgQiICAFKAIAaiIFIAYgEkEDdCIhaiIGKAIAIhVJDQIgBSARSw0DIA8gFUEFdGoiCyAGKAIEIhBBBXQiCGohByAFQQV0IRogBSAVayIOIBBrIgUgEE8NBCATIAcgBUEFdCIGEL8BIg4gBmohCSAQQQFOQQAgBUEBThtFBEAgByEFDAYLIBogHGohCCAHIQUDQCAIIAVBYGoiGiAJQWBqIiwgCUFoaigCACAFQWhqKAIASSIHGyIGKQMANwMAIAhBGGogBkEYaikDADcDACAIQRBqIAZBEGopAwA3AwAgCEEIaiAGQQhqKQMANwMAIAkgLCAHGyEJIAsgGiAFIAcbIgVPDQYgCEFgaiEIIAkgDiIGSw0ACwwHC0Hoq8IAIBIgBRCsAQALQeirwgAgDCAFEKwBAAsgFSAFEK0BAAsgBSAREKsBAAsgCCATIAsgCBC/ASIGaiEJIA4gEEpBACAQQQFOGw0BIAshBQsgEyEGDAELIA8gGmohDiALIQUDQCAFIAcgBiAHKAIIIAYoAghJIgsbIggpAwA3AwAgBUEYaiAIQRhqKQMANwMAIAVBEGogCEEQaikDADcDACAFQQhqIAhBCGopAwA3AwAgBiAGQSBqIAsbIQYgBUEgaiEFIAdBIGogByALGyIHIA5PDQEgCSAGSw0ACwsgBSAGIAkgBmtBYHEQvwEaIAQoAmAiBSAMSwRAIAQoAlgiBiAfaiIHIBAgIGo2AgQgByAVNgIAIAUgEk0NAiAGICFqIgcgB0EIaiAFIBJBf3NqQQN0EJ4BGiAEIAVBf2oiBTYCYCAFIAVBAU0NBBoMAQsLQfirwgAgDCAFEKwBAAtBp67CAEEdQcSuwgAQvgEACyAFCyEHIAoNAAsgBCgCXARAIAYQIQtFDQIgExAhDAILIAUgFBCtAQALIBFBAkkNACARQX5qIQUgDyARQQV0akFAaiEGQQIhCQNAIBEgBUkNBiAGIAkQQCAGQWBqIQYgCUEBaiEJIAVBf2oiBUF/Rw0ACwsgFiAWKAIAQQFqNgIAIBcgFygCAEEBajYCACANRQ0GIEanIgtBBXQhF0EAIQoCfyALRQRAQQAhB0EAIRNBACEWQQAMAQsgFEEFdCEGQQAhBUEAIRMDQCATIAUgDWoiB0EUaigCAGsgB0EYaigCAGoCfyAHKwMAmyJKRAAAAAAAAPBBYyBKRAAAAAAAAAAAZnEEQCBKqwwBC0EAC0EBdGshEyAGIAVBIGoiBUcNAAtBACEFQQAhBwNAIAcgBSANakEcai0AAEEBc2ohByAGIAVBIGoiBUcNAAtBACEJQQAhCANAIAggCSANaiIFQQxqKAIAayAFQRBqKAIAaiAFQRRqKAIAIAVBGGooAgBraiEIIAYgCUEgaiIJRw0AC0EAIQYgF0FgakEFdiIFIAtBf2oiDiAOIAVLGyIMBEAgDUEoaiEFQQAhCQNAIAZBACAFQWBqKAIAIhBBAWoiDiAFKAIAIhZrIhIgEiAOSxtqIgYgFiAQQX9zamogBiAOIBZJGyEGIAVBIGohBSAJQQFqIgkgDEkNAAsLQQAgBmshFkEAIAhrCyEIQQEhDiALQX9qIgUgC00EQCAFIAtJBEAgDSAFQQV0ai0AHSEOCyANKAIIIQUgDUEgaiANIBdqRwRAIA1BKGohBiAUQQV0QWBqIQkDQCAGKAIAIgogBSAFIApLGyEFIAZBIGohBiAJQWBqIgkNAAsLQQAgBWshCgtBACEGICQEQCAkQRRsIQkgKkEIaiEFA0AgBSgCACAFQXxqKAIAayAGaiEGIAVBFGohBSAJQWxqIgkNAAtBACAGayEGCyBFpyEFIAIoAgAoAgAoAgQiFEUgC0EBS3INAiALQQFrRQRAIBFBAUcgFEECSXINAyAPQRBqKAIAIA8oAgxrQQF0IA1BEGooAgAgDSgCDGtPDQMgDS0AHQ0DCyAFBEAgDRAhCyAbBEAgDxAhCyABKAIAIgUgASgCBEcNAAsLIABBADYCBAwBCyAAICQ2AgggACAqNgIEIAAgQDYCACAAQeAAaiAGNgIAIABB3ABqQQAgJGs2AgAgAEHYAGogPzYCACAAQdQAaiAKNgIAIABB0ABqIA42AgAgAEHMAGogFjYCACAAQcgAaiAINgIAIABBxABqIAc2AgAgAEFAayATNgIAIABBPGogETYCACAAQThqIBs2AgAgAEE0aiAPNgIAIABBMGogCzYCACAAQSxqIAU2AgAgAEEoaiANNgIAIABBJGogPzYCACAAQSBqIDM2AgAgAEEcaiA0NgIAIABBGGogNTYCACAAQRRqIDY2AgAgAEEQaiA3NgIAIABBDGogODYCAAsgBEGABGokAA8LIAUgERCtAQALQZa/wgBBECAEQfACakHMqMIAEJsBAAtB0L7CAEHGACAEQfACakHcqMIAEJsBAAvjHwMkfwN+BnwjAEHAAWsiBCQAAkACQAJAAkACQAJAAkACQCACQQhqKAIAIgMgAigCBCIGRg0AIAFBCGooAgAiBSABKAIEIgtGDQAgBSALayIFIAUgAyAGayIDIAMgBUsbIAItACkiBhshBSADQQJPQQAgBUEBSxsNASADIAVGDQIMAwsgAEECOgAcDAMLRAAAAAAAAPA/IAUgAyADIAVLIgsbuCADIAUgCxu4o6FEpHA9Ctej0D9jRQ0BCyAEQShqIAEQsQEgBCgCLCEFIAQoAighCQJAIAZFBEAgBSABKAIIIAEoAgRrIgYgA0EBaiIDIAMgBksbIgNPDQEgAyAFEKsBAAsgBSEDCwJAAkACQAJARAAAAAAAAPA/AnwCQBB8IgUEQCAEQSBqIAIQsQEgBCgCJCEGIAMEQCAGRQ0CIAUoAgANCyAEKAIgIQogBUF/NgIAIAUoAhANCyAFQQRqIQsgBUF/NgIQAkAgAyAFKAIMIghNBEAgBSADNgIMDAELIAsgAyAIaxCdAQsgBUEUaiEIAkAgBiAFKAIcIgxNBEAgBSAGNgIcDAELIAggBiAMaxCdAQsgBSgCBCAFKAIMIAkgAxB2IAUoAhQgBSgCHCAKIAYQdkEAIQMgBSgCBCAFKAIMIgYgBEGAAWpBAEEgIAZnaxAIIAUoAhQgBSgCHCIGIARBgAFqQQBBICAGZ2sQCCALEIMBIAgQgwEgBSgCHCEJAkAgBSgCDCIKRQRAQQAhBkEAIQtBACEIDAELQQAhBkEAIQtBACEIIAlFDQAgBSgCFCEMIAUoAgQhDgNAAkACQAJAAkBBfyAOIANBAnRqKAIAIg8gDCAGQQJ0aigCACIQRyAPIBBJGw4CAAIBCyAGQQFqIQYgA0EBaiEDIAhBAWohCAwCCyADQQFqIQMMAQsgBkEBaiEGCyALQQFqIQsgAyAKTw0BIAYgCUkNAAsLIAUgBSgCEEEBajYCECAFIAUoAgBBAWo2AgAgCLcgCSAKaiADayAGayALarijDAMLRAAAAAAAAAAARAAAAAAAAPA/IAYbDAILDAgLRAAAAAAAAAAAC6FEUrgehetR4D9jBEAgBEECOgBMECciB0UNByAEQRhqIAIQsQEgBCgCHCELIAQoAhghEyAEQRBqIAEQsQEgBygCLA0JIAQoAhQhGiAEKAIQIRQgB0F/NgIsIAcoAjwNCSAHQQA2AkggB0KAgICAcDcCOCAEQQhqIAIQsgEgB0EwaiAEKAIIIgMgAyAEKAIMahCXASAEIAEQsgEgB0FAayIVIAQoAgAiAyADIAQoAgRqEJcBIAcoAgANCSAHQX82AgAgBygCQCEGIAcoAjAhDSAHKAI4IglBAmoiAyAHKAJIIgpBAmoiBSADIAVLGyIDIAcoAgRNDQQgB0EEaiEPIANBAXYgA2oiDiAObCIMIAcoAhAiA00NAyAHQQhqIAMgDCADayIFEIgBIAcoAgggBygCECIMQQN0aiEDIAVBAkkNAUEBIQgDQCADQgA3AwAgA0EIaiEDIAhBAWoiCCAFSQ0ACyAIIAxqQX9qIQwMAgsgAEECOgAcDAULIAUNAAwBCyADQgA3AwAgDEEBaiEMCyAHIA42AgQgByAMNgIQIA8QfwsgCQRAIAlBA3QhCEECIQMDQCAHKAIIIgUgBygCBCIJIANsQQN0akEIaiAJIANBf2psQQN0IAVqQQhqKwMAIA0rAwCgOQMAIANBAWohAyANQQhqIQ0gCEF4aiIIDQALCyAKBEAgCkEDdCENQQIhAwNAIAcoAgggAyAHKAIEakEDdGoiBSAFQXhqKwMAIAYrAwCgOQMAIANBAWohAyAGQQhqIQYgDUF4aiINDQALCyAHKAIURQRAIAdBfzYCFAJAIAcoAhgiA0UEQEEAIQMgB0EANgIoDAELIAcoAhxB/wEgA0EFahDFARogB0EANgIoIAcoAhgiA0EISQ0AIANBAWpBA3ZBB2whAwsgByADNgIkIAsEQCAHQRhqIRYgEyALQQJ0aiENIBQgGkECdGohF0EAIQsgEyEOA0AgC0EBaiEPIA4oAgAhDCAaBEAgC0ECaiEYIAtBAnQgE2pBfGohGUEAIQlBACEDIBQhBQNAIAMhCCAEIAUoAgAiEDYCgAEgBUEEaiEFQqXGiKHInKf5SyEnQQEhAyAQIQYDQCAnIAatQv8Bg4VCs4OAgIAgfiEnIANBBEcEQCAEQYABaiADaiADQQFqIQMtAAAhBgwBCwsgJ6ciBkEZdiIDQQh0IANyIgNBEHQgA3IhGyAIQQFqIQMgBygCGCIRIAZxIQYgBygCHCEcQQAhEgJAA0ACQCAGIBxqKAAAIh0gG3MiCkF/cyAKQf/9+3dqcUGAgYKEeHEiCgRAIAcoAiAhHgNAIBAgHiAKaEEDdiAGaiARcUEDdGoiHygCAEYNAiAKQX9qIApxIgoNAAsLIBJBBGoiEiAGaiARcSEGQQAhCiAdIB1BAXRxQYCBgoR4cUUNAQwCCwsgH0EEaiEKCwJAAkACQCAHKAI4IgYgC0sEQCAHKAJIIgYgCE0NASAKQdiqwgAgChsoAgAhCiAHKAIwIAtBA3RqKwMAISogFSgCACAIQQN0aisDACErIAtFDQJEAAAAAAAA4D8hLCAMIBkoAgBHDQIMAwtB6KvCACALIAYQrAEAC0Hoq8IAIAggBhCsAQALRAAAAAAAAPA/ISwLIAgEfEQAAAAAAADgP0QAAAAAAADwPyAQIAhBAnQgFGpBfGooAgBGGwVEAAAAAAAA8D8LIS4gBygCCCIGIAcoAgQiESAKbCAJakEDdGorAwAhLSArICsgKiAqICtjGyAqICpiG0QAAAAAAADwP0QAAAAAAAAAACAMIBBHG6IgBiAPIBFsIhIgA2pBA3RqKwMAoCAtIA8gCWsgCGogCmu4RAAAAAAAAOA/oqAQzQEhLSAGIBIgCEECaiIIakEDdGorAwAhLyAqICwQzQEhKiAGIAggESAYbCIKakEDdGogKyAuEM0BIAYgAyAKakEDdGorAwCgICogL6AQzQEgLRDNATkDACADIAkgDCAQRhshCSAFIBdHDQALCyAOQQRqIQ4gBCAMNgKAAUKlxoihyJyn+UshJ0EBIQMgDCEGA0AgJyAGrUL/AYOFQrODgICAIH4hJyADQQRHBEAgBEGAAWogA2ogA0EBaiEDLQAAIQYMAQsLICdCGYinQf8AcSIFQQh0IAVyIgNBEHQgA3IhECAHKAIYIgggJ6dxIQMgBygCHCEGQQAhCgJAAkADQCADIAZqKAAAIgsgEHMiCUF/cyAJQf/9+3dqcUGAgYKEeHEiCQRAIAcoAiAhEQNAIBEgCWhBA3YgA2ogCHFBA3RqIhIoAgAgDEYNAyAJQX9qIAlxIgkNAAsLIApBBGoiCiADaiAIcSEDIAsgC0EBdHFBgIGChHhxRQ0ACyAGIBYgJxCcASIDai0AAEEBcSELIAcgBygCJCIJIAtFcgR/IAkFIARBgAFqIBYQFCAWICcQnAEhAyAHKAIYIQggBygCHCEGIAcoAiQLIAtrNgIkIAcoAiAgAyAGaiAFOgAAIAggA0F8anEgBmpBBGogBToAACADQQN0aiIDIA82AgQgAyAMNgIAIAcgBygCKEEBajYCKAwBCyASIA82AgQLIA8hCyANIA5HDQALCyAHIAcoAhRBAWo2AhQgByAHKAIAIgNBAWoiIjYCACAHIAcoAjxBAWo2AjwgByAHKAIsQQFqNgIsIANBAmoiA0EASgRAIAcgAzYCACACKAIMIhchAyACLQApIhsEQCAXIAEoAgwiAyAXIANLGyEDCyACKAIIIhYgAigCBCIaayIcIAEoAggiESABKAIEIhJrIhggHCAYSxsiBUEBaiILIANBf2oiFUsEQEEAIANrISNBACAFayEZQoGChIAQIAItACgiJEF7aiIDrUIDhoinISUgAigCACEdIANBBUkhJiALIQUDQCAFIg9Bf2oiBUEBIAVBAUsbIR4gCyEDA0ACQCAVIANPDQAgDyADayEGIANBf2ohCUEAIAcoAgQiAkEDdGshDCAHKAIIIA8gAiADbGoiDUEDdGohCCAHKAIQIQoDQAJAIAkiAyAcSyAFIBhLciAFIBVGIAMgI2pBf0ZxIAMgF0lycg0AIAUgASgCDElBACAbGw0CIAYgAyAZaiIJIAlBAEgbQQFKDQAgCiANSwRAIAgrAwAiKiADIB4gAyAeSxu4o0ThehSuR+HKP2QNASAEQQI6AJwBQQAhBgNAIARBMGogBmoiAikDACEnIAIgBEGAAWogBmoiCCkDADcDACAIICc3AwAgAkEIaiIJKQMAIScgCSAIQQhqIgkpAwA3AwAgAkEQaiIKKQMAISggCiAIQRBqIgopAwA3AwAgAkEYaiICKQMAISkgAiAIQRhqIgIpAwA3AwAgAiApNwMAIAogKDcDACAJICc3AwAgBkFAayAGQSBqIQZBwQBJDQALIAQgBC8BngE7AXwgBC0AnQEhDSAEKAKYASEIIAQoApQBIQkgBCgCkAEhCiAEKAKMASEMIAQoAogBIR8gBCsDgAEhKyAEKwOgASEsIAQoAqgBIRAgBCgCrAEhEyAEKAKwASEUIAQoArQBIQ4gBCgCuAEhAiAELQC8ASEhIAQtAL0BISAgBC0AnAEhBiAEIAQvAb4BOwF6AkACQCAGQQJHBEAgBCAELwF8OwGAASAEIAQvAXo7AX4gKyAqZQ0BC0EAIQkgG0EARyAFIBhGIAEoAgAhHwJ/QQAgAS0AKCICQQpGDQAaQQAgAkF7aiICQQRLDQAaQoGChIAQIAKtQgOGiKcLIQZBACAlQQAgJhsgJEEKRhshISAFIQggESEKIBIhDCAqISsgKiEsIB0hECAaIRMgFiEUQQAhDiADIQJyIg0hIAwBCyAEIAQvAYABOwF4IAQgBC8BfjsBdgsgBCANOgBNIAQgBjoATCAEIAg2AkggBCAJNgJEIAQgCjYCQCAEIAw2AjwgBCAfNgI4IAQgKzkDMCAEICA6AG0gBCAhOgBsIAQgAjYCaCAEIA42AmQgBCAUNgJgIAQgEzYCXCAEIBA2AlggBCAsOQNQIAQgBC8BeDsBTiAEIAQvAXY7AW4gKkQAAAAAAACwPGVBAXMNBAwDC0Hoq8IAIA0gChCsAQALIAZBAWohBiADQX9qIQkgCCAMaiEIIA0gAmshDSAVIANJDQALCwsgGUEBaiEZIBUgBUkNAAsLIAcgIjYCACAAIARBMGpBwAAQvwEaDAMLQYyqwgBBGCAEQYABakGkqsIAEJsBAAsMBAsgAEECOgAcCyAEQcABaiQADwtB0L7CAEHGACAEQYABakHcqMIAEJsBAAtBlr/CAEEQIARBgAFqQdyqwgAQmwEAC0GWv8IAQRAgBEGAAWpByKrCABCbAQALmBwCG38BfiMAQfADayIDJAAgAyACNgKQAyADIAI2AowDIAMgATYCiAMgA0HQAGogA0GIA2oQkQEgAygCVCEPIAMoAlAhDgJAAkACQAJAAkACQAJAEMcBIgoEQCAKKAIADQYgCkF/NgIAIApBBGogABBWIgRFDQEQxgEiCwRAIAsoAgANByALQX82AgAgC0EEaiAAEFciCEUNAyADQaACaiAOIA4gD2oQIyADQYgDaiADQaACahBiIANBoAJqIANBiANqIARBFGoiAhA9IAMoAqgCIgBBf2oiASAATyABIABLckUEQCADKAKgAiABQRRsakEAOgARCyADQYgDaiADQaACakEwEL8BGiADQaACaiADQYgDaiACECYgA0GIA2ogA0GgAmogAhAgIANBoAJqIANBiANqEDsgAygCqAIiAQRAIAMoAqACIQAgAUEUbCEBIANBwAJqKAIAIQUgAygCuAIhDANAIAAgDCAFIAIQSCAAQRRqIQAgAUFsaiIBDQALCyADQYgDaiADQaACakEwEL8BGiADQaACaiADQYgDaiACEFEgAygCoAIhDAJAIAMoAqgCIgVFBEAgA0HAAmooAgAhBiADKAK4AiENDAELIAVBFGwhASADQcACaigCACEGIAMoArgCIQ0gDCEAA0AgACANIAYgAhBHIABBFGohACABQWxqIgENAAsLIANByAJqKAIAIRYgA0G8AmooAgAhFyADQbACaigCACEYIANBtAJqKAIAIQAgAygCpAIhGSADKAKsAiEQIAMoAsQCIREgA0H0AGogA0HMAmooAgA2AgAgA0HsAGogBjYCACADQeQAaiAANgIAIAMgETYCcCADIA02AmggAyAQNgJgIAMgBTYCXCADIAw2AlgCQCAIKAIIIgJFBEBBACECDAELIAgoAgAgAkEEdGpBdGohAANAIAJBf2ohAiAAQQRqKAIABEAgACgCABAhCyAAQXBqIQAgAg0ACyADKAJcIQULIAggAjYCCCADIANB2ABqNgJ8IAMgBDYCeCADQYwBaiAEKAKsATYCACADIAQoAqQBNgKIASADIAQoAqABNgKEASADIAQoApgBNgKAAQJAIAVFBEAgBCgC2AENCiAEQdgBaiEFIARBfzYC2AEgBEHcAWohByAEKALcAQ0BIAQoAgQhACAEKAIMIQEgA0EgaiAEKAIQIgJBAXQQpQEgAykDICEeIANBsAJqIgZBADYCACADQQA7AbgCIAMgAjYCtAIgAyAeNwOoAiADIAAgAUE8bGo2AqQCIAMgADYCoAICQCADQaACahAxIgBFBEAgA0EANgKoASADQgQ3A6ABIAMoAqwCRQ0BIAMoAqgCECEMAQsgACgCACEAIANBGGpBARClASADKAIcIQEgAygCGCIJIAA2AgAgA0EBNgLoASADIAE2AuQBIAMgCTYC4AEgA0GgA2ogA0G4AmooAgA2AgAgA0GYA2ogBikDADcDACADQZADaiADQagCaikDADcDACADIAMpA6ACNwOIAwJAIANBiANqEDEiBkUNAEEEIQJBASEAA0AgBigCACEGIAAgAUYEQCADQeABaiABQQEQhgEgAygC4AEhCQsgAiAJaiAGNgIAIAMgAEEBaiIANgLoASADQYgDahAxIgZFDQEgAkEEaiECIAMoAuQBIQEMAAsACyADQZQDaigCAARAIAMoApADECELIANBqAFqIANB6AFqKAIANgIAIAMgAykD4AE3A6ABCyADQYgDaiADQaABahCYAQJAIAcoAgAiAEUNACAEKALgAUUNACAAECELIAcgAykDiAM3AgAgB0EIaiADQZADaigCADYCACADQZgBaiADQagBaigCADYCACADIAMpA6ABNwOQAQwHCyAEKAKwAQ0JIARBfzYCsAEgBEEANgLUASAEQcwBaiEJIAQoAhAhEiAEIAQoArQBIgEEfyAJQQAgARCGASAEKALMASAEKALUASICQQJ0aiEAIAFBAUcEQCABQX9qIQEDQCAAQQA2AgAgAkEBaiECIABBBGohACABQX9qIgENAAsLIABBADYCACACQQFqBUEACzYC1AEgA0GgAWogA0HYAGoQHyADKAKgASEHIAMoAqgBIgBFDQUgByAAQQxsaiEaIAchBQNAIANCpcaIocicp/lLNwOIAyAFIgEgA0GIA2oQeSADKAKIAyIAQRl2IgJBCHQgAnIiAkEQdCACciEbIAFBDGohBSAEKAK4ASITIABxIQAgBCgCvAEhHEEAIRQCQANAAkAgACAcaigAACIVIBtzIgJBf3MgAkH//ft3anFBgIGChHhxIgIEQCAEKALAASEdA0AgASAdIAJoQQN2IABqIBNxQRhsaiIGRg0CIAEgBkEMELoBRQ0CIAJBf2ogAnEiAg0ACwsgFEEEaiIUIABqIBNxIQAgFSAVQQF0cUGAgYKEeHFFDQEMAgsLIAYoAhQiAUUNACAGKAIMIQAgAUECdCECA0AgCSgCACAAKAIAQQJ0aiIBIAEoAgBBAWo2AgAgAEEEaiEAIAJBfGoiAg0ACwsgBSAaRw0ACwwFCyADQZABaiAHEJgBDAULDAULDAQLQYXJwgBBK0GwycIAEL4BAAtBhcnCAEErQbDJwgAQvgEACyAEKALMASEAIAQoAtQBIQEgA0HIAGogEkEUbBCmASADKQNIIR4gA0G0AmpBADYCACADQQA7AbwCIAMgEkEKbDYCuAIgAyAeNwKsAiADQQA2AqgCIAMgACABQQJ0ajYCpAIgAyAANgKgAiADQUBrIANBoAJqEDACQCADKAJERQRAIANBADYCmAEgA0IENwOQASADKAKwAkUNASADKAKsAhAhDAELIAMoAkAhACADQThqQQEQpQEgAygCPCEBIAMoAjgiBSAANgIAIANBATYC6AEgAyABNgLkASADIAU2AuABIANBoANqIANBuAJqKQMANwMAIANBmANqIgkgA0GwAmopAwA3AwAgA0GQA2ogA0GoAmopAwA3AwAgAyADKQOgAjcDiAMgA0EwaiADQYgDahAwAkAgAygCNEUNACADKAIwIQZBBCECQQEhAANAIAAgAUYEQCADQeABaiABQQEQhgEgAygC4AEhBQsgAiAFaiAGNgIAIAMgAEEBaiIANgLoASADQShqIANBiANqEDAgAygCLEUNASADKAIoIQYgAkEEaiECIAMoAuQBIQEMAAsACyAJKAIABEAgAygClAMQIQsgA0GYAWogA0HoAWooAgA2AgAgAyADKQPgATcDkAELIAMoAqQBBEAgBxAhCyAEQbABaiEFCyAFIAUoAgBBAWo2AgAgAygCkAEhByADKAKYASEAIANBEGogAygCeCgCECIBQQF0EKIBIAMpAxAhHiADQbwBakEANgIAIANBADsBxAEgAyABNgLAASADIB43ArQBIAMgByAAQQJ0ajYCpAEgAyAHNgKgASADIANBgAFqNgLIASADIANB/ABqNgKwASADIANB/ABqNgKsASADIANB+ABqNgKoASADQaACaiADQaABahAeAkACQAJAAkAgAygCpAJFBEAgA0EANgLkAQwBCyADQYgDaiADQaACakHkABC/ARogA0HgAWogA0HIAWogA0GIA2oQFSADKALkAQ0BCyADKAK8ASIBBEAgAygCtAEhACABQeQAbCECA0AgABDCASAAQeQAaiEAIAJBnH9qIgINAAsLQQAhBEEEIQUgAygCuAFFDQEgAygCtAEQIQwBC0EBIQIgA0EIakEBEKQBIANB6AFqKQMAIR4gAygCDCEAIAMoAggiBSADKQPgATcCACAFQQhqIB43AgAgA0EBNgLYASADIAA2AtQBIAMgBTYC0AEgA0HgAWogA0GgAWpBLBC/ARogA0GgAmogA0HgAWoQHgJAIAMoAqQCBEAgA0GIAmohAUEQIQADQCADQYgDaiADQaACakHkABC/ARogA0GQAmogASADQYgDahAVIAMoApQCRQ0CIANBkANqIgQgA0GYAmopAwA3AwAgAyADKQOQAjcDiAMgAygC1AEgAkYEQCADQdABaiACELkBIAMoAtABIQULIAAgBWoiBiADKQOIAzcCACAGQQhqIAQpAwA3AgAgAyACQQFqIgI2AtgBIABBEGohACADQaACaiADQeABahAeIAMoAqQCDQALCyADQQA2ApQCCyADQfwBaigCACIBBEAgAygC9AEhACABQeQAbCEBA0AgABDCASAAQeQAaiEAIAFBnH9qIgENAAsLIANB+AFqKAIABEAgAygC9AEQIQsgAygC1AEhBAwBC0EAIQILIAMoApQBBEAgBxAhCyAFIAJBBHRqIQECQCABIAIEfyAFIQADQCAAQQRqKAIAIgcEQCAAQQhqKQIAIR4gACgCACEGIAgoAggiAiAIKAIERgRAIAggAhC5ASAIKAIIIQILIAgoAgAgAkEEdGoiAiAHNgIEIAIgBjYCACACQQhqIB43AgAgCCAIKAIIQQFqNgIIIABBEGoiACABRw0BDAMLCyAAQRBqBSAFCyIARg0AA0AgAEEEaigCACICRQ0BIABBCGooAgAEQCACECELIABBEGoiAiEAIAEgAkcNAAsLIAQEQCAFECELIBkEQCAMECELIBgEQCAQECELIBcEQCANECELIBYEQCARECELIAsgCygCAEEBajYCACAKIAooAgBBAWo2AgAgDwRAIA4QIQsgA0HwA2okAA8LQdC+wgBBxgAgA0GIA2pB3KjCABCbAQALQZa/wgBBECADQYgDakHMqMIAEJsBAAtBlr/CAEEQIANBiANqQbiqwgAQmwEAC8MYAgh/AX4CQAJAAkAgAEH1AU8EQCAAQc3/e08NAiAAQQtqIgBBeHEhBEHEzMIAKAIAIghFDQFBACAEayECAkACQAJ/QQAgAEEIdiIARQ0AGkEfIARB////B0sNABogBEEGIABnIgBrQR9xdkEBcSAAQQF0a0E+agsiB0ECdEHQzsIAaigCACIABEAgBEEAQRkgB0EBdmtBH3EgB0EfRht0IQUDQAJAIAAoAgRBeHEiBiAESQ0AIAYgBGsiBiACTw0AIAAhAyAGIgINAEEAIQIMAwsgAEEUaigCACIGIAEgBiAAIAVBHXZBBHFqQRBqKAIAIgBHGyABIAYbIQEgBUEBdCEFIAANAAsgAQRAIAEhAAwCCyADDQILQQAhA0ECIAdBH3F0IgBBACAAa3IgCHEiAEUNAyAAQQAgAGtxaEECdEHQzsIAaigCACIARQ0DCwNAIAAgAyAAKAIEQXhxIgEgBE8gASAEayIGIAJJcSIFGyEDIAYgAiAFGyECIAAoAhAiAQR/IAEFIABBFGooAgALIgANAAsgA0UNAgtB0M/CACgCACIAIARPQQAgAiAAIARrTxsNASADEFoCQCACQRBPBEAgAyAEQQNyNgIEIAMgBGoiASACQQFyNgIEIAEgAmogAjYCACACQYACTwRAIAEgAhBSDAILIAJBA3YiAkEDdEHIzMIAaiEAAn9BwMzCACgCACIFQQEgAkEfcXQiAnEEQCAAKAIIDAELQcDMwgAgAiAFcjYCACAACyECIAAgATYCCCACIAE2AgwgASAANgIMIAEgAjYCCAwBCyADIAIgBGoiAEEDcjYCBCAAIANqIgAgACgCBEEBcjYCBAsgA0EIag8LAkACQEHAzMIAKAIAIgNBECAAQQtqQXhxIABBC0kbIgRBA3YiAkEfcSIBdiIAQQNxRQRAIARB0M/CACgCAE0NAyAADQFBxMzCACgCACIARQ0DIABBACAAa3FoQQJ0QdDOwgBqKAIAIgEoAgRBeHEgBGshAiABIQUDQCABKAIQIgBFBEAgAUEUaigCACIARQ0ECyAAKAIEQXhxIARrIgEgAiABIAJJIgEbIQIgACAFIAEbIQUgACEBDAALAAsgAEF/c0EBcSACaiIBQQN0IgZB0MzCAGooAgAiAEEIaiECAkAgACgCCCIFIAZByMzCAGoiBkcEQCAFIAY2AgwgBiAFNgIIDAELQcDMwgAgA0F+IAF3cTYCAAsgACABQQN0IgFBA3I2AgQgACABaiIAIAAoAgRBAXI2AgQMAwsCQEECIAF0IgJBACACa3IgACABdHEiAEEAIABrcWgiAEEDdCIFQdDMwgBqKAIAIgEoAggiAiAFQcjMwgBqIgVHBEAgAiAFNgIMIAUgAjYCCAwBC0HAzMIAIANBfiAAd3E2AgALIAEgBEEDcjYCBCABIARqIgYgAEEDdCIAIARrIgVBAXI2AgQgACABaiAFNgIAQdDPwgAoAgAiAARAIABBA3YiA0EDdEHIzMIAaiEAQdjPwgAoAgAhAgJ/QcDMwgAoAgAiBEEBIANBH3F0IgNxBEAgACgCCAwBC0HAzMIAIAMgBHI2AgAgAAshAyAAIAI2AgggAyACNgIMIAIgADYCDCACIAM2AggLQdjPwgAgBjYCAEHQz8IAIAU2AgAgAUEIag8LIAUQWgJAIAJBEE8EQCAFIARBA3I2AgQgBCAFaiIGIAJBAXI2AgQgAiAGaiACNgIAQdDPwgAoAgAiAARAIABBA3YiA0EDdEHIzMIAaiEAQdjPwgAoAgAhAQJ/QcDMwgAoAgAiBEEBIANBH3F0IgNxBEAgACgCCAwBC0HAzMIAIAMgBHI2AgAgAAshAyAAIAE2AgggAyABNgIMIAEgADYCDCABIAM2AggLQdjPwgAgBjYCAEHQz8IAIAI2AgAMAQsgBSACIARqIgBBA3I2AgQgACAFaiIAIAAoAgRBAXI2AgQLIAVBCGoPCwJAAkACQAJAQdDPwgAoAgAiASAESQRAQdTPwgAoAgAiACAESw0GQQAhAiAEQa+ABGoiAEEQdkAAIgFBf0YNBSABQRB0IgNFDQVB4M/CACAAQYCAfHEiBUHgz8IAKAIAaiIANgIAQeTPwgBB5M/CACgCACIBIAAgASAASxs2AgBB3M/CACgCACICRQ0BQejPwgAhAANAIAAoAgAiASAAKAIEIgZqIANGDQMgACgCCCIADQALDAMLQdjPwgAoAgAhAAJAIAEgBGsiA0EPTQRAQdjPwgBBADYCAEHQz8IAQQA2AgAgACABQQNyNgIEIAAgAWoiAUEEaiEEIAEoAgRBAXIhAgwBC0HQz8IAIAM2AgBB2M/CACAAIARqIgI2AgAgAiADQQFyNgIEIAAgAWogAzYCACAEQQNyIQIgAEEEaiEECyAEIAI2AgAgAEEIag8LQfzPwgAoAgAiAEEAIAAgA00bRQRAQfzPwgAgAzYCAAtBACEAQYDQwgBB/x82AgBB7M/CACAFNgIAQejPwgAgAzYCAEH0z8IAQQA2AgADQCAAQdDMwgBqIABByMzCAGoiATYCACAAQdTMwgBqIAE2AgAgAEEIaiIAQYACRw0AC0Hcz8IAIAM2AgBB1M/CACAFQVhqIgA2AgAgAyAAQQFyNgIEIAAgA2pBKDYCBEH4z8IAQYCAgAE2AgAMAgsgACgCDCADIAJNciABIAJLcg0AIAAgBSAGajYCBEHcz8IAQdzPwgAoAgAiAEEPakF4cSIBQXhqNgIAQdTPwgBB1M/CACgCACAFaiIDIAAgAWtqQQhqIgI2AgAgAUF8aiACQQFyNgIAIAAgA2pBKDYCBEH4z8IAQYCAgAE2AgAMAQtB/M/CAEH8z8IAKAIAIgAgAyAAIANJGzYCACADIAVqIQFB6M/CACEAAkADQCABIAAoAgBHBEAgACgCCCIADQEMAgsLIAAoAgwNACAAIAM2AgAgACAAKAIEIAVqNgIEIAMgBEEDcjYCBCADIARqIQUgASADayAEayEEAkACQCABQdzPwgAoAgBHBEBB2M/CACgCACABRg0BIAEoAgQiAEEDcUEBRgRAAkAgAEF4cSICQYACTwRAIAEQWgwBCyABKAIMIgYgASgCCCIHRwRAIAcgBjYCDCAGIAc2AggMAQtBwMzCAEHAzMIAKAIAQX4gAEEDdndxNgIACyACIARqIQQgASACaiEBCyABIAEoAgRBfnE2AgQgBSAEQQFyNgIEIAQgBWogBDYCACAEQYACTwRAIAUgBBBSDAMLIARBA3YiAUEDdEHIzMIAaiEAAn9BwMzCACgCACICQQEgAUEfcXQiAXEEQCAAKAIIDAELQcDMwgAgASACcjYCACAACyECIAAgBTYCCCACIAU2AgwgBSAANgIMIAUgAjYCCAwCC0Hcz8IAIAU2AgBB1M/CAEHUz8IAKAIAIARqIgA2AgAgBSAAQQFyNgIEDAELQdjPwgAgBTYCAEHQz8IAQdDPwgAoAgAgBGoiADYCACAFIABBAXI2AgQgACAFaiAANgIACyADQQhqDwtB6M/CACEAA0ACQCAAKAIAIgEgAk0EQCABIAAoAgRqIgYgAksNAQsgACgCCCEADAELC0Hcz8IAIAM2AgBB1M/CACAFQVhqIgA2AgAgAyAAQQFyNgIEIAAgA2pBKDYCBEH4z8IAQYCAgAE2AgAgAiAGQWBqQXhxQXhqIgAgACACQRBqSRsiAUEbNgIEQejPwgApAgAhCSABQRBqQfDPwgApAgA3AgAgASAJNwIIQezPwgAgBTYCAEHoz8IAIAM2AgBB8M/CACABQQhqNgIAQfTPwgBBADYCACABQRxqIQADQCAAQQc2AgAgBiAAQQRqIgBLDQALIAEgAkYNACABIAEoAgRBfnE2AgQgAiABIAJrIgBBAXI2AgQgASAANgIAIABBgAJPBEAgAiAAEFIMAQsgAEEDdiIBQQN0QcjMwgBqIQACf0HAzMIAKAIAIgNBASABQR9xdCIBcQRAIAAoAggMAQtBwMzCACABIANyNgIAIAALIQEgACACNgIIIAEgAjYCDCACIAA2AgwgAiABNgIIC0EAIQJB1M/CACgCACIAIARNDQAMAQsgAg8LQdTPwgAgACAEayIBNgIAQdzPwgBB3M/CACgCACIAIARqIgM2AgAgAyABQQFyNgIEIAAgBEEDcjYCBCAAQQhqC6QWARZ/IwBB8ANrIgYkAAJAAkAgAUEVSQ0AIAZB4ABqIRcgBkHIAmohGEEBIRFBASESAkACQAJAA0AgASEKIAAhDiADQUBrIRAgESAScUEBcyEPAkADQAJ/AkAgBARAIBFBAXFFBEAgACABEGYgBEF/aiEECyAGIAI2AgggBiAANgKIASAGIAE2AowBIAYgAUECdiIFNgIMIAYgBUEBdCIHNgIQIAYgBUEDbCIJNgIUIAZBADYCGCAGIAZBGGo2ApACIAYgBkGIAWo2AowCIAYgBkEIajYCiAIgBiAGQYgCajYCHCABQTJPBEAgBiAFQX9qNgIgIAYgBUEBajYCiAMgBkEcaiAGQSBqIAZBDGogBkGIA2oQygEgBiAHQX9qNgIgIAYgB0EBcjYCiAMgBkEcaiAGQSBqIAZBEGogBkGIA2oQygEgBiAJQX9qNgIgIAYgCUEBajYCiAMgBkEcaiAGQSBqIAZBFGogBkGIA2oQygELIAZBHGogBkEMaiAGQRBqIAZBFGoQygEgBigCGCIFQQtLDQEgBigCECEIIAVFDAILIAAgARB4DAkLIAYoAowBIglBAXYiBwRAIAYoAogBIgUgCUHkAGxqQZx/aiEMA0AgBkGIA2ogBUHkABC/ARogBSAMQeQAEJ4BQeQAaiEFIAwgBkGIA2pB5AAQvwFBnH9qIQwgB0F/aiIHDQALCyABIAYoAhBBf3NqIQhBAQtFIA9yQQFxRQRAIAAgARAkDQgLIANFBEAgDiEAIAohAQwCCwJAIAggAUkEQCAAIAhB5ABsaiIJQUBrIQ1BACEFAkADQCAFQQRqIgdBKEYNASAFIBBqIQsgBSANaiEMIAchBUF/IAwoAgAiByALKAIAIgtHIAcgC0gbIgdFDQALIAdB/wFxQf8BRg0ECyABBEAgBkGIA2ogAEHkABC/ARogACAJQeQAEJ4BIQ0gCSAGQYgDakHkABC/ARogBkGIAmogDUHkABC/ARogDUFcaiEMIA1BpAFqIRMgDUHkAGohFEEAIQggASEJA0ACQCAIIAlBf2oiB08NACATIAhB5ABsaiELA0BBACEFAkADQCAFQQRqIgBBKEYNASAFIBhqIRIgBSALaiEVIAAhBUF/IBUoAgAiACASKAIAIhJHIAAgEkgbIgBFDQALIABB/wFxQf8BRg0CCyALQeQAaiELIAhBAWoiCCAHRw0ACyAHIQgMBAsgCCAHTw0DIAwgCUHkAGxqIQsDQCAHIglBf2ohB0EAIQUCQANAIAVBBGoiAEEoRg0BIAUgGGohEiAFIAtqIRUgACEFQX8gFSgCACIAIBIoAgAiEkcgACASSBsiAEUNAAsgAEH/AXFB/wFHDQAgC0Gcf2ohCyAIIAdPDQUMAQsLIAZBiANqIBQgCEHkAGxqIgBB5AAQvwEaIAAgDSAJQeQAbGoiAEHkABCeARogACAGQYgDakHkABC/ARogCEEBaiEIDAALAAtBsKfCAEEAQQAQrAEAC0HQp8IAIAggARCsAQALIA0gBkGIAmpB5AAQvwEhACABIAhBAWoiBU8EQCAAIAVB5ABsaiEAIAEgBWsiAUEVTw0BDAcLCyAFIAEQrQEACwJAAkAgAQRAIAggAU8NASAGQYgCaiAAQeQAEL8BGiAAIAAgCEHkAGxqIgVB5AAQngEhFCAFIAZBiAJqQeQAEL8BGiAGQSBqIBRB5AAQvwEaIAFBf2oiCUUEQEEAIRAMAwsgFEGkAWohC0EAIRADQEEAIQUDQCAFQQRqIgdBKEYNBCAFIAtqIQogBSAXaiEOIAchBUF/IA4oAgAiByAKKAIAIgpHIAcgCkgbIgdFDQALIAdB/wFxQf8BRw0DIAtB5ABqIQsgEEEBaiIQIAlHDQALIAkhEAwCC0Gwp8IAQQBBABCsAQALQcCnwgAgCCABEKwBAAsgFEHkAGohDSAUIAFB5ABsakFcaiELIAkhBwNAIAciCiAQTSISRQRAIApBf2ohB0EAIQUDQAJAIAVBBGoiDkEoRgRAQQAhDAwBCyAFIAtqIQggBSAXaiEMIA4hBUF/IAwoAgAiDiAIKAIAIghHIA4gCEgbIgxFDQELCyALQZx/aiELIAxBf0cNAQsLIAogEE8EQCAJIApJDQIgDSAKQeQAbGohDkGAASETQQAhCUEAIQpBACEHQQAhD0GAASERIA0gEEHkAGxqIhUhDQNAIA4gDWsiCEHkAG4hBQJAIAhB48gBSyIZDQAgBUGAf2ogBSAPIAdJIgggCiAJSXIiCxshBSALBEAgESAFIAgbIREgBSATIAgbIRMMAQsgBSAFQQF2IhFrIRMLAkAgByAPRw0AIBFFBEAgBkGIAWoiByEPDAELQQAhCCAGQYgBaiEHIA0hCwNAIAcgCDoAACAIQQFqIQhBwAAhBQNAAkAgBUEEaiIMQegARgRAQQAhDAwBCyAFIAtqIQ8gBkEgaiAFaiEWIAwhBUF/IBYoAgAiDCAPKAIAIg9HIAwgD0gbIgxFDQELCyALQeQAaiELIAcgDEF/R2ohByAIIBFHDQALIAZBiAFqIQ8LAkAgCSAKRw0AIBNFBEAgBkGIAmoiCSEKDAELQQAhCCAGQYgCaiEJIA4hCgNAIAkgCDoAACAKIgtBnH9qIQogCEEBaiEIQQAhBQNAAkAgBUEEaiIMQShGBEBBACEMDAELIAUgF2ohFiAFIAtqIRogDCEFQX8gFigCACIMIBpBXGooAgAiFkcgDCAWSBsiDEUNAQsLIAkgDEF/RmohCSAIIBNHDQALIAZBiAJqIQoLIAkgCmsiBSAHIA9rIgggCCAFSxsiCARAIAZBiANqIA0gDy0AAEHkAGxqQeQAEL8BGiANIA8tAABB5ABsaiAOIAotAABBf3NB5ABsakHkABC/ARogCEEBRwRAQQAhBQNAIA4gBSAKaiILLQAAQX9zQeQAbGogDSAFIA9qQQFqIgwtAABB5ABsakHkABC/ARogDSAMLQAAQeQAbGogDiALQQFqLQAAQX9zQeQAbGpB5AAQvwEaIAVBAmogBUEBaiILIQUgCEkNAAsgCyAPaiEPIAogC2ohCgsgDiAKLQAAQX9zQeQAbGogBkGIA2pB5AAQvwEaIA9BAWohDyAKQQFqIQoLIA0gEUHkAGxqIA0gByAPRhshDSAOQQAgE2tB5ABsaiAOIAkgCkYbIQ4gGQ0ACwJAIA8gB0kEQCAOIQUDQCAGQYgDaiANIAdBf2oiBy0AAEHkAGxqIglB5AAQvwEaIAkgBUGcf2oiBUHkABCeARogBSAGQYgDakHkABC/ARogDyAHSQ0ACwwBCyANIQUgCiAJTw0AA0AgCUF/aiIJLQAAIQcgBkGIA2ogBUHkABC/ARogBSAOIAdBf3NB5ABsaiIFQeQAEJ4BIAUgBkGIA2pB5AAQvwEaQeQAaiEFIAogCUkNAAsLIBQgBkEgakHkABC/ASEHIAEgBSAVa0HkAG4gEGoiBU0NAyAGQYgCaiAHQeQAEL8BGiAHIAcgBUHkAGxqIgdB5AAQngEhDiAHIAZBiAJqQeQAEL8BIQcgASAFayIKRQ0EIAogBSAFIApLGyABQQN2IAdB5ABqIQkCQCAFIApBf2oiAU8EQCAJIAEgAiAHIAQQBCAFIQEMAQsgDiAFIAIgAyAEEAQgByEDIAkhAAtPIREgAUEVTw0BDAULCyAQIAoQrQEACyAKIAkQqwEAC0HAp8IAIAUgARCsAQALQbWtwgBBHEHUrcIAEL4BAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEEkgASAFRw0ACwsgBkHwA2okAAuBFQEXfyMAQcACayIGJAACQAJAIAFBFUkNAEEBIQlBASETAkACQAJAA0AgASEPIAAhDiAJIBNxQQFzIRECQAJAAkACQAJAAkADQAJ/AkAgBARAIAlFBEAgACABEF0gBEF/aiEECyAGIAI2AgggBiAANgIwIAYgATYCNCAGIAFBAnYiBTYCDCAGIAVBAXQiCDYCECAGIAVBA2wiBzYCFCAGQQA2AhggBiAGQRhqNgK4ASAGIAZBMGo2ArQBIAYgBkEIajYCsAEgBiAGQbABajYCHCABQTJPBEAgBiAFQX9qNgIgIAYgBUEBajYCsAIgBkEcaiAGQSBqIAZBDGogBkGwAmoQywEgBiAIQX9qNgIgIAYgCEEBcjYCsAIgBkEcaiAGQSBqIAZBEGogBkGwAmoQywEgBiAHQX9qNgIgIAYgB0EBajYCsAIgBkEcaiAGQSBqIAZBFGogBkGwAmoQywELIAZBHGogBkEMaiAGQRBqIAZBFGoQywEgBigCGCIFQQtLDQEgBigCECEHIAVFDAILIAAgARBuDA4LIAYoAjQiB0EBdiIIBEAgBigCMCIFIAdBDGxqQXRqIQcDQCAGQbgCaiIMIAVBCGoiCigCADYCACAGIAUpAgA3A7ACIAdBCGoiCygCACENIAUgBykCADcCACAKIA02AgAgCyAMKAIANgIAIAcgBikDsAI3AgAgBUEMaiEFIAdBdGohByAIQX9qIggNAAsLIAEgBigCEEF/c2ohB0EBC0UgEXJBAXFFBEAgACABECsNDQsgA0UNAiAHIAFPDQEgA0EDIAAgB0EMbGoiBUEDEJkBQf8BcUH/AUYEQCABIQ8gACEODAQLIAZBuAJqIgogAEEIaiILKAIANgIAIAYgACkCADcDsAIgBUEIaiIIKAIAIQcgACAFKQIANwIAIAsgBzYCACAIIAooAgA2AgAgBSAGKQOwAjcCACAGQbgBaiISIAsoAgA2AgAgBiAAKQIANwOwASAAQXRqIRQgAEEMaiENQQAhByABIQgDQAJAIAcgCEF/aiIMTw0AIA0gB0EMbGohBQNAIAZBsAFqQQMgBUEDEJkBQf8BcUH/AUYNASAFQQxqIQUgDCAHQQFqIgdHDQALIAwhBwsgFCAIQQxsaiEFAkADQCAHIAhBf2oiCE8NASAGQbABakEDIAVBAxCZASAFQXRqIgwhBUH/AXFB/wFGDQALIAogDSAHQQxsaiIFQQhqIhMoAgA2AgAgBiAFKQIANwOwAiAMQRRqIhAoAgAhFiAFIAxBDGoiBSkCADcCACATIBY2AgAgECAKKAIANgIAIAUgBikDsAI3AgAgB0EBaiEHDAELCyAAIAYpA7ABNwIAIAsgEigCADYCACABIAdBAWoiBU8EQCAAIAVBDGxqIQAgASAFayIBQRVPDQEMDAsLIAUgARCtAQALQdCnwgAgByABEKwBAAsgD0UNAQsgByAPTw0BIAZBuAFqIhQgDkEIaiISKAIANgIAIAYgDikCADcDsAEgDiAHQQxsaiIAQQhqIgEoAgAhBSAOIAApAgA3AgAgEiAFNgIAIAEgFCgCADYCACAAIAYpA7ABNwIAIAZBKGoiFiASKAIANgIAIAYgDikCADcDICAOQQxqIQlBACEMIA9Bf2oiAUUNAiAJIQUDQCAFQQMgBkEgakEDEJkBQf8BcUH/AUcNAyAFQQxqIQUgASAMQQFqIgxHDQALIAEhDAwCC0Gwp8IAQQBBABCsAQALQcCnwgAgByAPEKwBAAsgDiAPQQxsaiEHIAEhCANAAkAgByEAIAgiBSAMTSITDQAgBUF/aiEIIABBdGoiB0EDIAZBIGpBAxCZAUH/AXFB/wFHDQELCyAFIAxPBEAgASAFSQ0CQYABIQ1BACEIQQAhCkEAIQdBACELQYABIREgCSAMQQxsaiIXIQEDQCAAIAFrIglBDG4hBQJAIAlBixhLIhgNACAFQYB/aiAFIAsgB0kiCSAKIAhJciIQGyEFIBAEQCARIAUgCRshESAFIA0gCRshDQwBCyAFIAVBAXYiEWshDQsCQCAHIAtHDQAgEUUEQCAGQTBqIgchCwwBC0EAIQUgASEJIAZBMGoiCyEHA0AgByAFOgAAIAcgCUEDIAZBIGpBAxCZAUH/AXFB/wFHaiEHIAlBDGohCSARIAVBAWoiBUcNAAsLAkAgCCAKRw0AIA1FBEAgBkGwAWoiCCEKDAELIABBdGohBUEAIQkgBkGwAWoiCiEIA0AgCCAJOgAAIAggBUEDIAZBIGpBAxCZAUH/AXFB/wFGaiEIIAVBdGohBSANIAlBAWoiCUcNAAsLIAggCmsiBSAHIAtrIgkgCSAFSxsiCQRAIAZBuAJqIhkgASALLQAAQQxsaiIFQQhqKAIANgIAIAYgBSkCADcDsAIgASALLQAAQQxsaiIFIAAgCi0AAEF/c0EMbGoiECkCADcCACAFQQhqIBBBCGooAgA2AgAgCUEBRwRAQQAhBQNAIAAgBSAKaiIQLQAAQX9zQQxsaiIVIAEgBSALakEBaiIaLQAAQQxsaiIbKQIANwIAIBVBCGogG0EIaigCADYCACABIBotAABBDGxqIhUgACAQQQFqLQAAQX9zQQxsaiIQKQIANwIAIBVBCGogEEEIaigCADYCACAFQQJqIAVBAWoiECEFIAlJDQALIAsgEGohCyAKIBBqIQoLIAAgCi0AAEF/c0EMbGoiBSAGKQOwAjcCACAFQQhqIBkoAgA2AgAgC0EBaiELIApBAWohCgsgASARQQxsaiABIAcgC0YbIQEgAEEAIA1rQQxsaiAAIAggCkYbIQAgGA0ACwJAIAsgB0kEQCAAIQUDQCAGQbgCaiIIIAEgB0F/aiIHLQAAQQxsaiIAQQhqIgkoAgA2AgAgBiAAKQIANwOwAiAFQXRqIgVBCGoiCigCACENIAAgBSkCADcCACAJIA02AgAgCiAIKAIANgIAIAUgBikDsAI3AgAgCyAHSQ0ACwwBCyABIQUgCiAITw0AA0AgCEF/aiIILQAAIQEgBkG4AmoiByAFQQhqIgkoAgA2AgAgBiAFKQIANwOwAiAAIAFBf3NBDGxqIgFBCGoiCygCACENIAUgASkCADcCACAJIA02AgAgCyAHKAIANgIAIAEgBikDsAI3AgAgBUEMaiEFIAogCEkNAAsLIA4gBikDIDcCACASIBYoAgA2AgAgDyAFIBdrQQxuIAxqIgFNDQMgFCASKAIANgIAIAYgDikCADcDsAEgDiABQQxsaiIFQQhqIgAoAgAhCCAOIAUpAgA3AgAgEiAINgIAIAAgFCgCADYCACAFIAYpA7ABNwIAIA8gAWsiCEUNBCAIIAEgASAISxsgD0EDdiAFQQxqIQACQCABIAhBf2oiCE8EQCAAIAggAiAFIAQQBSAOIQAMAQsgDiABIAIgAyAEEAUgBSEDIAghAQtPIQkgAUEVTw0BDAULCyAMIAUQrQEACyAFIAEQqwEAC0HAp8IAIAEgDxCsAQALQbWtwgBBHEHUrcIAEL4BAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEE8gASAFRw0ACwsgBkHAAmokAAvkEAIUfwF+IwBBsAJrIgYkAAJAAkAgAUEVSQ0AQQEhD0EBIRMCQAJAAkADQCABIQ4gACEMIA8gE3FBAXMhCQJAAkACQAJAAkACQANAAn8CQCAEBEAgD0EBcUUEQCAAIAEQciAEQX9qIQQLIAYgAjYCDCAGIAA2AjAgBiABNgI0IAYgAUECdiIINgIQIAYgCEEBdCIFNgIUIAYgCEEDbCIHNgIYIAZBADYCHCAGIAZBHGo2ArgBIAYgBkEwajYCtAEgBiAGQQxqNgKwASAGIAZBsAFqNgIgIAFBMk8EQCAGIAhBf2o2AiQgBiAIQQFqNgIoIAZBIGogBkEkaiAGQRBqIAZBKGoQZyAGIAVBf2o2AiQgBiAFQQFyNgIoIAZBIGogBkEkaiAGQRRqIAZBKGoQZyAGIAdBf2o2AiQgBiAHQQFqNgIoIAZBIGogBkEkaiAGQRhqIAZBKGoQZwsgBkEgaiAGQRBqIAZBFGogBkEYahBnIAYoAhwiCEELSw0BIAhFIQcgBigCFAwCCyAAIAEQjQEMDgsgBigCNCIHQQF2IggEQCAGKAIwIgUgB0EDdGpBeGohBwNAIAUpAgAhGSAFIAcpAgA3AgAgByAZNwIAIAVBCGohBSAHQXhqIQcgCEF/aiIIDQALC0EBIQcgASAGKAIUQX9zagshBSAHRSAJckEBcUUEQCAAIAEQOQ0NCyADRQ0CIAUgAU8NASAAIAVBA3RqIggoAgQoAgAgAygCBCgCAEkEQCABIQ4gACEMDAQLIAApAgAhGSAAIAgpAgA3AgAgCCAZNwIAIABBfGohCyAAQQxqIREgAEEIaiESIAAoAgQhDSAAKAIAIRNBACEHIAEhCANAAkAgByAIQX9qIgpPDQAgESAHQQN0aiEFIA0oAgAhEANAIAUoAgAoAgAgEEkNASAFQQhqIQUgCiAHQQFqIgdHDQALIAohBwsgCyAIQQN0aiEFAkADQCAHIAhBf2oiCE8NASAFKAIAIAVBeGoiECEFKAIAIA0oAgBJDQALIBIgB0EDdGoiBSkCACEZIAUgEEEEaiIFKQIANwIAIAUgGTcCACAHQQFqIQcMAQsLIAAgDTYCBCAAIBM2AgAgASAHQQFqIghPBEAgACAIQQN0aiEAIAEgCGsiAUEVTw0BDAwLCyAIIAEQrQEAC0HQp8IAIAUgARCsAQALIA5FDQELIAUgDk8NASAMKQIAIRkgDCAMIAVBA3RqIgApAgA3AgAgACAZNwIAIAwoAgAhACAGIAwoAgQiEjYCLCAGIAA2AiggDkF/aiIBRQRAQQAhDQwDCyAMQQxqIQUgEigCACEAQQAhDQNAIAAgBSgCACgCAE8NAyAFQQhqIQUgASANQQFqIg1HDQALIAEhDQwCC0Gwp8IAQQBBABCsAQALQcCnwgAgBSAOEKwBAAsgDEEIaiEKIAwgDkEDdGohByABIQADQAJAIAchCCAAIgUgDU0iEw0AIAhBeGohByAFQX9qIQAgEigCACAIQXxqKAIAKAIATw0BCwsgBSANTwRAIAEgBUkNAkGAASERQQAhAEEAIQFBACEHQQAhC0GAASEPIAogDUEDdGoiFSEKA0ACQCAIIAprIgVBhxBLIhYNACAFQQN2IgVBgH9qIAUgCyAHSSIJIAEgAElyIhAbIQUgEARAIA8gBSAJGyEPIAUgESAJGyERDAELIAUgBUEBdiIPayERCwJAIAcgC0cNACAPRQRAIAZBMGoiByELDAELQQAhBSAKIQkgBkEwaiILIQcDQCAHIAU6AAAgByASKAIAIAlBBGooAgAoAgBPaiEHIAlBCGohCSAPIAVBAWoiBUcNAAsLAkAgACABRw0AIBFFBEAgBkGwAWoiACEBDAELIAhBfGohBUEAIQkgBkGwAWoiASEAA0AgACAJOgAAIAAgBigCLCISKAIAIAUoAgAoAgBJaiEAIAVBeGohBSARIAlBAWoiCUcNAAsLIAggEUEDdGsgCCAAIAFrIgUgByALayIJIAkgBUsbIgkEQCAKIAstAABBA3RqIgUoAgQhFyAFKAIAIRggBSAIIAEtAABBf3NBA3RqKQIANwIAIAlBAUcEQEEAIQUDQCAIIAEgBWoiEC0AAEF/c0EDdGogCiAFIAtqQQFqIhQtAABBA3RqKQIANwIAIAogFC0AAEEDdGogCCAQQQFqLQAAQX9zQQN0aikCADcCACAFQQJqIAVBAWoiECEFIAlJDQALIAsgEGohCyABIBBqIQELIAggAS0AAEF/c0EDdGoiBSAXNgIEIAUgGDYCACALQQFqIQsgAUEBaiEBCyAAIAFGGyEIIAogD0EDdGogCiAHIAtGGyEKIBYNAAsCQCALIAdJBEAgCCEFA0AgCiAHQX9qIgctAABBA3RqIgApAgAhGSAAIAVBeGoiBSkCADcCACAFIBk3AgAgCyAHSQ0ACwwBCyAKIQUgASAATw0AA0AgBSkCACEZIAUgCCAAQX9qIgAtAABBf3NBA3RqIgcpAgA3AgAgByAZNwIAIAVBCGohBSABIABJDQALCyAMIAYpAygiGTcCACAOIAUgFWtBA3YgDWoiAU0NAyAMIAwgAUEDdGoiCCkCADcCACAIIBk3AgAgDiABayIFRQ0EIAUgASABIAVLGyAOQQN2IAhBCGohAAJAIAEgBUF/aiIFTwRAIAAgBSACIAggBBAGIAwhAAwBCyAMIAEgAiADIAQQBiAIIQMgBSEBC08hDyABQRVPDQEMBQsLIA0gBRCtAQALIAUgARCrAQALQcCnwgAgASAOEKwBAAtBta3CAEEcQdStwgAQvgEACyABQQJJDQBBASEFA0AgACAFQQFqIgUQbSABIAVHDQALCyAGQbACaiQAC+8PARN/IwBBoAJrIgYkAAJAAkAgAUEVSQ0AQQEhD0EBIRMCQAJAAkADQCABIQwgACENIA8gE3FBAXMhCwJAAkACQAJAAkACQANAAn8CQCAEBEAgD0EBcUUEQCAAIAEQcyAEQX9qIQQLIAYgAjYCACAGIAA2AiAgBiABNgIkIAYgAUECdiIFNgIEIAYgBUEBdCIINgIIIAYgBUEDbCIHNgIMIAZBADYCECAGIAZBEGo2AqgBIAYgBkEgajYCpAEgBiAGNgKgASAGIAZBoAFqNgIUIAFBMk8EQCAGIAVBf2o2AhggBiAFQQFqNgIcIAZBFGogBkEYaiAGQQRqIAZBHGoQyQEgBiAIQX9qNgIYIAYgCEEBcjYCHCAGQRRqIAZBGGogBkEIaiAGQRxqEMkBIAYgB0F/ajYCGCAGIAdBAWo2AhwgBkEUaiAGQRhqIAZBDGogBkEcahDJAQsgBkEUaiAGQQRqIAZBCGogBkEMahDJASAGKAIQIgVBC0sNASAFRSEHIAYoAggMAgsgACABEIwBDA4LIAYoAiQiB0EBdiIIBEAgBigCICIFIAdBAnRqQXxqIQcDQCAFKAIAIQkgBSAHKAIANgIAIAcgCTYCACAFQQRqIQUgB0F8aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCCEF/c2oLIQUgB0UgC3JBAXFFBEAgACABED8NDQsgA0UNAiAFIAFPDQEgAygCACAAIAVBAnRqIggoAgAiBxC3AQRAIAEhDCAAIQ0MBAsgACgCACEFIAAgBzYCACAIIAU2AgAgAEF8aiEQIABBBGohCiAAKAIAIQ5BACEHIAEhCANAAkAgByAIQX9qIglPDQAgCiAHQQJ0aiEFA0AgDiAFKAIAELcBDQEgBUEEaiEFIAkgB0EBaiIHRw0ACyAJIQcLIBAgCEECdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACEJIAVBfGoiESEFIA4gCRC3AQ0ACyAKIAdBAnRqIgUoAgAhEyAFIAk2AgAgEUEEaiATNgIAIAdBAWohBwwBCwsgACAONgIAIAEgB0EBaiIFTwRAIAAgBUECdGohACABIAVrIgFBFU8NAQwMCwsgBSABEK0BAAtB0KfCACAFIAEQrAEACyAMRQ0BCyAFIAxPDQEgDSgCACEAIA0gDSAFQQJ0aiIBKAIANgIAIAEgADYCACANQQRqIQkgDSgCACEQQQAhDiAMQX9qIgFFDQIgCSEFA0AgBSgCACAQELcBRQ0DIAVBBGohBSABIA5BAWoiDkcNAAsgASEODAILQbCnwgBBAEEAEKwBAAtBwKfCACAFIAwQrAEACyANIAxBAnRqIQcgASEIA0ACQCAHIQAgCCIFIA5NIhMNACAFQX9qIQggAEF8aiIHKAIAIBAQtwFFDQELCyAFIA5PBEAgASAFSQ0CQYABIQ9BACEIQQAhAUEAIQdBACELQYABIREgCSAOQQJ0aiIVIQkDQAJAIAAgCWsiBUGDCEsiFg0AIAVBAnYiBUGAf2ogBSALIAdJIgogASAISXIiEhshBSASBEAgESAFIAobIREgBSAPIAobIQ8MAQsgBSAFQQF2IhFrIQ8LAkAgByALRw0AIBFFBEAgBkEgaiIHIQsMAQtBACEFIAkhCiAGQSBqIgshBwNAIAcgBToAACAHIAooAgAgEBC3AUEBc2ohByAKQQRqIQogESAFQQFqIgVHDQALCwJAIAEgCEcNACAPRQRAIAZBoAFqIgghAQwBCyAAQXxqIQVBACEKIAZBoAFqIgEhCANAIAggCjoAACAFKAIAIBAQtwEgCGohCCAFQXxqIQUgDyAKQQFqIgpHDQALCyAAIA9BAnRrIAAgCCABayIFIAcgC2siCiAKIAVLGyIKBEAgCSALLQAAQQJ0aiIFKAIAIRcgBSAAIAEtAABBf3NBAnRqKAIANgIAIApBAUcEQEEAIQUDQCAAIAEgBWoiEi0AAEF/c0ECdGogCSAFIAtqQQFqIhQtAABBAnRqKAIANgIAIAkgFC0AAEECdGogACASQQFqLQAAQX9zQQJ0aigCADYCACAFQQJqIAVBAWoiEiEFIApJDQALIAsgEmohCyABIBJqIQELIAAgAS0AAEF/c0ECdGogFzYCACALQQFqIQsgAUEBaiEBCyABIAhGGyEAIAkgEUECdGogCSAHIAtGGyEJIBYNAAsCQCALIAdJBEAgACEFA0AgCSAHQX9qIgctAABBAnRqIgAoAgAhASAAIAVBfGoiBSgCADYCACAFIAE2AgAgCyAHSQ0ACwwBCyAJIQUgASAITw0AA0AgBSgCACEHIAUgACAIQX9qIggtAABBf3NBAnRqIgkoAgA2AgAgCSAHNgIAIAVBBGohBSABIAhJDQALCyANIBA2AgAgDCAFIBVrQQJ2IA5qIgFNDQMgDSANIAFBAnRqIgUoAgA2AgAgBSAQNgIAIAwgAWsiCEUNBCAIIAEgASAISxsgDEEDdiAFQQRqIQACQCABIAhBf2oiCE8EQCAAIAggAiAFIAQQByANIQAMAQsgDSABIAIgAyAEEAcgBSEDIAghAQtPIQ8gAUEVTw0BDAULCyAOIAUQrQEACyAFIAEQqwEAC0HAp8IAIAEgDBCsAQALQbWtwgBBHEHUrcIAEL4BAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEIABIAEgBUcNAAsLIAZBoAJqJAAL1w8BE38jAEGgAmsiBiQAAkACQCABQRVJDQBBASEPQQEhEwJAAkACQANAIAEhDCAAIQ0gDyATcUEBcyELAkACQAJAAkACQAJAA0ACfwJAIAQEQCAPQQFxRQRAIAAgARBzIARBf2ohBAsgBiACNgIAIAYgADYCICAGIAE2AiQgBiABQQJ2IgU2AgQgBiAFQQF0Igg2AgggBiAFQQNsIgc2AgwgBkEANgIQIAYgBkEQajYCqAEgBiAGQSBqNgKkASAGIAY2AqABIAYgBkGgAWo2AhQgAUEyTwRAIAYgBUF/ajYCGCAGIAVBAWo2AhwgBkEUaiAGQRhqIAZBBGogBkEcahBqIAYgCEF/ajYCGCAGIAhBAXI2AhwgBkEUaiAGQRhqIAZBCGogBkEcahBqIAYgB0F/ajYCGCAGIAdBAWo2AhwgBkEUaiAGQRhqIAZBDGogBkEcahBqCyAGQRRqIAZBBGogBkEIaiAGQQxqEGogBigCECIFQQtLDQEgBUUhByAGKAIIDAILIAAgARCLAQwOCyAGKAIkIgdBAXYiCARAIAYoAiAiBSAHQQJ0akF8aiEHA0AgBSgCACEJIAUgBygCADYCACAHIAk2AgAgBUEEaiEFIAdBfGohByAIQX9qIggNAAsLQQEhByABIAYoAghBf3NqCyEFIAdFIAtyQQFxRQRAIAAgARA+DQ0LIANFDQIgBSABTw0BIAMoAgAgACAFQQJ0aiIIKAIAIgdJBEAgASEMIAAhDQwECyAAKAIAIQUgACAHNgIAIAggBTYCACAAQXxqIRAgAEEEaiEKIAAoAgAhDkEAIQcgASEIA0ACQCAHIAhBf2oiCU8NACAKIAdBAnRqIQUDQCAOIAUoAgBJDQEgBUEEaiEFIAkgB0EBaiIHRw0ACyAJIQcLIBAgCEECdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACEJIAVBfGoiESEFIA4gCUkNAAsgCiAHQQJ0aiIFKAIAIRMgBSAJNgIAIBFBBGogEzYCACAHQQFqIQcMAQsLIAAgDjYCACABIAdBAWoiBU8EQCAAIAVBAnRqIQAgASAFayIBQRVPDQEMDAsLIAUgARCtAQALQdCnwgAgBSABEKwBAAsgDEUNAQsgBSAMTw0BIA0oAgAhACANIA0gBUECdGoiASgCADYCACABIAA2AgAgDUEEaiEJIA0oAgAhEEEAIQ4gDEF/aiIBRQ0CIAkhBQNAIAUoAgAgEE8NAyAFQQRqIQUgASAOQQFqIg5HDQALIAEhDgwCC0Gwp8IAQQBBABCsAQALQcCnwgAgBSAMEKwBAAsgDSAMQQJ0aiEHIAEhCANAAkAgByEAIAgiBSAOTSITDQAgBUF/aiEIIABBfGoiBygCACAQTw0BCwsgBSAOTwRAIAEgBUkNAkGAASEPQQAhCEEAIQFBACEHQQAhC0GAASERIAkgDkECdGoiFSEJA0ACQCAAIAlrIgVBgwhLIhYNACAFQQJ2IgVBgH9qIAUgCyAHSSIKIAEgCElyIhIbIQUgEgRAIBEgBSAKGyERIAUgDyAKGyEPDAELIAUgBUEBdiIRayEPCwJAIAcgC0cNACARRQRAIAZBIGoiByELDAELQQAhBSAJIQogBkEgaiILIQcDQCAHIAU6AAAgByAKKAIAIBBPaiEHIApBBGohCiARIAVBAWoiBUcNAAsLAkAgASAIRw0AIA9FBEAgBkGgAWoiCCEBDAELIABBfGohBUEAIQogBkGgAWoiASEIA0AgCCAKOgAAIAggBSgCACAQSWohCCAFQXxqIQUgDyAKQQFqIgpHDQALCyAAIA9BAnRrIAAgCCABayIFIAcgC2siCiAKIAVLGyIKBEAgCSALLQAAQQJ0aiIFKAIAIRcgBSAAIAEtAABBf3NBAnRqKAIANgIAIApBAUcEQEEAIQUDQCAAIAEgBWoiEi0AAEF/c0ECdGogCSAFIAtqQQFqIhQtAABBAnRqKAIANgIAIAkgFC0AAEECdGogACASQQFqLQAAQX9zQQJ0aigCADYCACAFQQJqIAVBAWoiEiEFIApJDQALIAsgEmohCyABIBJqIQELIAAgAS0AAEF/c0ECdGogFzYCACALQQFqIQsgAUEBaiEBCyABIAhGGyEAIAkgEUECdGogCSAHIAtGGyEJIBYNAAsCQCALIAdJBEAgACEFA0AgCSAHQX9qIgctAABBAnRqIgAoAgAhASAAIAVBfGoiBSgCADYCACAFIAE2AgAgCyAHSQ0ACwwBCyAJIQUgASAITw0AA0AgBSgCACEHIAUgACAIQX9qIggtAABBf3NBAnRqIgkoAgA2AgAgCSAHNgIAIAVBBGohBSABIAhJDQALCyANIBA2AgAgDCAFIBVrQQJ2IA5qIgFNDQMgDSANIAFBAnRqIgUoAgA2AgAgBSAQNgIAIAwgAWsiCEUNBCAIIAEgASAISxsgDEEDdiAFQQRqIQACQCABIAhBf2oiCE8EQCAAIAggAiAFIAQQCCANIQAMAQsgDSABIAIgAyAEEAggBSEDIAghAQtPIQ8gAUEVTw0BDAULCyAOIAUQrQEACyAFIAEQqwEAC0HAp8IAIAEgDBCsAQALQbWtwgBBHEHUrcIAEL4BAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEHUgASAFRw0ACwsgBkGgAmokAAu3DgEafyMAQYABayIHJAAgACgCBCEEIAEoAgghCSABKAIAIQ0CQAJAAkAgACgCAC0AAARAIAcgBDYCDCAJQRVJDQEgByAJQQF2EKIBIAcoAgQgBygCACEQIAdBADYCGCAHQgQ3AxAgDUGcf2ohFUEEIQpBACEEIAkhBgNAAkAgBiIDQQFGBEBBACEGQQEhAAwBCyADQX5qIQIgDSADQeQAbCIRaiEFQVghAQJAAkACQAJAAkACQAJAA0AgAUEEaiIARQ0BIAEgBWohCCAAIQEgCEGgf2ooAgAiBiAIQQRqKAIAIgBGDQALIAYgAEgNAQsgDSARaiEFA0AgAiIGRQRAQQAhBiADIQAMCAsgBkF/aiECQVghAQNAAkAgAUEEaiIARQRAQQAhAAwBCyABIAVqIQwgACEBQX8gDEG8fmooAgAiCCAMQaB/aigCACIARyAIIABIGyIARQ0BCwsgBUGcf2ohBSAAQX9HDQALIAMgBmshAAwBCwJAIAIEQANAIAIiBkF/aiECQVghAQNAIAFBBGoiAEUNAyABIAVqIQwgACEBQX8gDEG8fmooAgAiCCAMQaB/aigCACIARyAIIABIGyIARQ0ACyAAQf8BcUH/AUcNAiAFQZx/aiEFIAINAAsLQQAhBgsgAyAGSQ0BIAMgCUsNAiADIAZrIgBBAXYiBUUNACANIAZB5ABsaiEBIBEgFWohAgNAIAdBHGogAUHkABC/ARogASACQeQAEJ4BQeQAaiEBIAIgB0EcakHkABC/AUGcf2ohAiAFQX9qIgUNAAsLIAZFIABBCUtyDQQgAyAGayEAIAZBf2ohASAVIAZB5ABsaiECA0AgAyABSQ0DIAMgCUsNAiACIABBAWoiABBOIAFBf2oiBCABTw0EIAJBnH9qIQIgBCEBIABBCkkNAAsMAwsgBiADEK0BAAsgAyAJEKsBAAsgASADEK0BAAsgBEEBaiEGIAshBAsgBygCFCAERgR/IAdBEGogBBCOASAHKAIQIQogBygCGCILBSAEC0EDdCAKaiIBIAA2AgQgASAGNgIAIAcgC0EBaiILNgIYAkAgCyIEQQJJDQACQANAAkACQAJAAkAgCiALIgNBf2oiC0EDdGoiASgCAARAIANBA3QgCmoiBUF0aigCACIIIAEoAgQiAEsNAQsgA0EDSQ0CIAEoAgQhACAKIANBfWoiAUEDdGooAgQhAgwBC0ECIQQgA0ECTQ0EIAogA0F9aiIBQQN0aigCBCICIAAgCGpNDQBBAyEEIANBA00NBCAFQWRqKAIAIAIgCGpNDQAgAyELIAMhBAwFCyACIABJDQELIANBfmohAQsCQAJAAkACQAJAAkACQCADIAFBAWoiE0sEQCADIAFNDQEgCiABQQN0IhlqIgAoAgQiGiAAKAIAaiIBIAogE0EDdCIbaiIAKAIAIhJJDQIgASAJSw0DIA0gEkHkAGxqIgggACgCBCIPQeQAbCIFaiEEIA0gAUHkAGxqIRQgASASayIAIA9rIgEgD08NBCAQIAQgAUHkAGwiABC/ASIMIABqIQ4gD0EBSCABQQFIcg0FIAQhBQNAIA5BXGohESAFQZx/aiEWIA5BnH9qIRdBACEBA0ACQCABQQRqIgBBKEYEQEEAIQAMAQsgASARaiECIAEgBWohBCAAIQFBfyAEQVxqKAIAIgQgAigCACIARyAEIABIGyIARQ0BCwsgFEGcf2oiFCAWIBcgAEF/RiIAG0HkABC/ARogDiAXIAAbIQ4gCCAWIAUgABsiBU8NByAOIAwiAksNAAsMBwtB6KvCACATIAMQrAEAC0Hoq8IAIAEgAxCsAQALIBIgARCtAQALIAEgCRCrAQALIBAgCCAFEL8BIgIgBWohDiAPQQFIIAAgD0xyRQRAIAghBQNAQcAAIQEDQAJAIAFBBGoiAEHoAEYEQEEAIQAMAQsgASAEaiEMIAEgAmohCCAAIQFBfyAIKAIAIgggDCgCACIARyAIIABIGyIARQ0BCwsgBCACIABBf0YiARshACACIAJB5ABqIAEbIQIgBSAAQeQAEL8BQeQAaiEFIARB5ABqIAQgARsiBCAUTw0EIA4gAksNAAsMAwsgCCEFDAELIAQhBQsgECECCyAFIAIgDiACayIAIABB5ABwaxC/ARogCiAZaiIAIA8gGmo2AgQgACASNgIAIAogG2oiACAAQQhqIAMgE0F/c2pBA3QQngEaIAcgCzYCGCALQQFLDQALIAshBAwBCyADIQsLIAYNAAsgBygCFARAIAoQIQtFDQIgEBAhDAILIAcgBDYCECAHIAdBEGo2AhwgDSAJIAdBHGpBAEEgIAlnaxAEDAELQQIhASAJQQJJDQAgDSAJQX5qIgBB5ABsaiECA0AgCSAASQ0CIAIgARBOIAJBnH9qIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgB0GAAWokAA8LIAAgCRCtAQAL9Q4BBn8jAEEQayIEJAAgBEEANgIIIARCADcDACAAKAIQIgEhAgNAIAAgAjYCHAJAIABBkMPCAEECEEwEQCAAIAAoAhAiAjYCICAAIAAoAhwgAkGSw8IAQQIQNgwBCyAAIAI2AhAgAiAAKAIUTwRAIAEhAgNAIAAgAjYCEANAAkAgABA0RQ0AIAAgACgCECIDNgIcAn8CQCAAQfHIwgBBARBMRQ0AIAAgACgCEDYCICAAEDRFDQBBqsPCAAwBCyAAIAM2AhAgAEGow8IAQQEQTEUNASAAIAAoAhA2AiAgABA0RQ0BQanDwgALIQMgACAAKAIcIAAoAiAgA0EBEDYMAgsgACACNgIQIAIgACgCFCIDSQRAIAAQlAEgACgCECECDAELCwsgACABNgIQIAQgAzYCCCAEIAM2AgQCQCAAQQMQYyIFQQBIIAUgA0pyDQAgACABNgIQIAQgBTYCACAAEDRFBEADQCAAKAIQIAAoAhQiA08NAiAAEJQBIAAQNEUNAAsLIAAQNSAAKAIQIQJFBEADQCACIAAoAhQiA08NAiAAEJQBIAAQNSAAKAIQIQJFDQALCyAEIAUgAiACIAVJGzYCCCAAEDRFBEADQCAAKAIQIAAoAhQiA08NAiAAEJQBIAAQNEUNAAsLIAAQNSAAKAIQIQJFBEADQCACIAAoAhQiA08NAiAAEJQBIAAQNSAAKAIQIQJFDQALCyAEIAI2AgQgACgCFCEDCyAAIAM2AiAgACADNgIQIAAgATYCGAJAIABBrMPCAEEHIAQQLSIBRQ0AIAAgACgCECICNgIcIAQoAgggAksNACABQX9qIgFBAksNAAJAAkACQAJAIAFBAWsOAgECAAsgACACIAAoAiBByMjCAEEAEDYMAwsgACACIAAoAiBByMjCAEEAEDYgACAAKAIQIgE2AiAgACgCFCABayEBIABB+MjCAEEBEGFFDQEgACAAKAIQNgIcIABBtMXCAEEDEGEEQCAAIAAoAhwgACgCIEHIyMIAQQAQNgwDCyAAIAAoAhQgAWs2AhAMAgsgAEG3xcIAEDNFDQEgACAAKAIcIAAoAiBByMjCAEEAEDYMAQsgACAAKAIUIAFrNgIQCyAAIAAoAhQiATYCICAAIAE2AhACQCAAQdTEwgBBBCAEEC0iAUUNACAAIAAoAhAiAjYCHCAEKAIIIAJLDQAgAUF/aiIBQQFLDQAgAUEBawRAIAAgAiAAKAIgQcjIwgBBABA2DAELIABB/MbCABAzRQ0AIAAoAhggAEF9EGMiAUoNACABIAAoAhRKDQAgACABNgIQIAAgACgCHCAAKAIgQcjIwgBBABA2CyAAIAAoAhQiATYCICAAIAE2AhACQCAAQbzFwgBBCCAEEC0iAUUNACAAIAAoAhAiAjYCHCAEKAIEIAJLDQAgAUF/aiIBQQNLDQACQAJAAkACQAJAAkACQCABQQFrDgMBAgMACyAAIAIgACgCIEHIyMIAQQAQNiAAIAAoAhAiATYCICAAKAIUIAFrIQYgAEH/xsIAQQIQYUUNAyAAIAAoAhAiAzYCHCAAKAIUIQIgAEHvyMIAQQEQYSAAKAIUIQUEQCAAIAUgBms2AhAMBwsgACAFIAMgAmtqIgE2AhAgBCgCBCABTQRAIAAgACgCHCAAKAIgQcjIwgBBABA2DAcLIAAgBSAGazYCEAwGCyAAKAIUIQEgAEHvyMIAQQEQYQ0FIAAgACgCFCACIAFrajYCECAAIAAoAhwgACgCIEHIyMIAQQAQNgwFCyAAIAIgACgCIEHIyMIAQQAQNiAAIAAoAhAiAzYCICAAKAIUIgEgA2shAiAAQYHHwgBBAhBhRQRAIAAgACgCFCADIAFrajYCECAAQYPHwgBBAhBhRQ0DCyAAIAAoAhAiATYCHCAEKAIIIAFNBEAgACABIAAoAiBByMjCAEEAEDYMBQsgACAAKAIUIAJrNgIQDAQLIAAgAiAAKAIgQcjIwgBBABA2IAAgACgCECIBNgIgIAAoAhQgAWshAyAAQYjHwgBBAiAEEC0iAUUNAiAAIAAoAhAiAjYCHCAEKAIEIAJLBEAgACAAKAIUIANrNgIQDAQLIAFBAUcNAyAAIAIgACgCIEHIyMIAQQAQNgwDCyAAIAAoAhQgBms2AhAMAgsgACAAKAIUIAJrNgIQDAELIAAgACgCFCADazYCEAsgACAAKAIYIgM2AhwgACADNgIQAkAgACAEEDoiAkUNAANAIAAgACgCECIFNgIgAkAgAkF/aiIBQQVLDQACQAJAAkACQAJAAkAgAUEBaw4FAQIDBAUACyAAIAAoAhwgBUGow8IAQQEQNgwFCyAAIAAoAhwgBUHxyMIAQQEQNgwECyAAIAAoAhwgBUHuyMIAQQEQNgwDCyAAIAAoAhwgBUHwyMIAQQEQNgwCCyAAIAAoAhwgBUHxyMIAQQEQNgwBCyAFIAAoAhRPDQIgABCUAQsgACAAKAIQNgIcIAAgBBA6IgINAAsLIAAgAzYCECAEQRBqJABBAQ8LIAAQlAELIAAoAhAhAgwACwALvwwCFn8BfiMAQSBrIgckACAAKAIEIQMgASgCCCEIIAEoAgAhCwJAAkACQCAAKAIALQAABEAgByADNgIMIAhBFUkNASAHIAhBAXYQpgEgBygCBCAHKAIAIRBBACEBIAdBADYCGCAHQgQ3AxAgC0F4aiETIAtBbGohFUEEIQlBACEDIAghBgNAAkAgBiIFQX9qIgZFBEBBACEGQQEhBAwBCwJAAkACQAJAAkAgBUEDdCINIAtqQXRqKAIAKAIAIgAgCyAGQQN0aigCBCgCAE8EQCAFQX5qIRQgDSAVaiEEQQAhBkEAIQIDQCACIBRGBEAgBSEEDAgLIAJBAWohAiAEKAIAKAIAIgogAE8gBEF4aiEEIAohAA0ACyACQQFqIQQgAkF/cyAFaiEGDAELIA0gFWohAgNAAkAgBkEBRgRAQQAhBgwBCyAGQX9qIQYgAigCACgCACIEIABJIAJBeGohAiAEIQANAQsLIAUgBkkNASAFIAhLDQIgBSAGayIEQQF2IgpFDQAgCyAGQQN0aiEAIA0gE2ohAgNAIAApAgAhGCAAIAIpAgA3AgAgAiAYNwIAIABBCGohACACQXhqIQIgCkF/aiIKDQALCyAGRSAEQQlLcg0EIAUgBmshBCAGQX9qIQAgEyAGQQN0aiECA0AgBSAASQ0DIAUgCEsNAiACIARBAWoiBBBvIABBf2oiAyAATw0EIAJBeGohAiADIQAgBEEKSQ0ACwwDCyAGIAUQrQEACyAFIAgQqwEACyAAIAUQrQEACyADQQFqIQYgASEDCyAHKAIUIANGBH8gB0EQaiADEI4BIAcoAhAhCSAHKAIYIgEFIAMLQQN0IAlqIgAgBDYCBCAAIAY2AgAgByABQQFqIgE2AhggASEDAkAgAUECSQ0AAkADQAJAAkACQAJAIAkgAUF/akEDdGoiACgCAEUNACABQQN0IAlqIgRBdGooAgAiBSAAKAIEIgJNDQBBAiEDIAFBAk0NBiAJIAFBfWoiDEEDdGooAgQiACACIAVqTQ0BQQMhAyABQQNNDQYgBEFkaigCACAAIAVqTQ0BIAEhAwwGCyABQQNJDQEgACgCBCECIAkgAUF9aiIMQQN0aigCBCEACyAAIAJJDQELIAFBfmohDAsCQAJAIAEgDEEBaiIRSwRAIAEgDEsEQCAJIAxBA3QiF2oiACgCBCIUIAAoAgBqIgMgCSARQQN0Ig1qIgAoAgAiEk8EQCADIAhNBEAgCyASQQN0aiIEIAAoAgQiDkEDdCIKaiEBIANBA3QhDwJAAkAgAyASayIDIA5rIgUgDkkEQCAQIAEgBUEDdCIAEL8BIgogAGohAiAOQQFIIAVBAUhyDQEgDyATaiEAA0ACQCAAIAFBeGoiBSACQXhqIgMgAUF8aigCACgCACACQXxqKAIAKAIASSIPGykCADcCACACIAMgDxshAiAEIAUgASAPGyIBTw0AIABBeGohACACIApLDQELCyAHKAIQIQkMAQsgCiAQIAQgChC/ASIAaiECIAMgDkpBACAOQQFOGw0BIAQhAQsgECEADAYLIAsgD2ohAwNAIAQgASAAIAAoAgQoAgAgASgCBCgCAEkiBRspAgA3AgAgACAAQQhqIAUbIQAgBEEIaiEEIAFBCGogASAFGyIBIANPDQUgAiAASw0ACwwECyADIAgQqwEACyASIAMQrQEAC0Hoq8IAIAwgARCsAQALQeirwgAgESABEKwBAAsgBCEBCyABIAAgAiAAa0F4cRC/ARogBygCGCIBIAxLBEAgCSAXaiIAIA4gFGo2AgQgACASNgIAIAEgEU0NAiAJIA1qIgAgAEEIaiABIBFBf3NqQQN0EJ4BGiAHIAFBf2oiATYCGCABIQMgAUEBTQ0DDAELC0H4q8IAIAwgARCsAQALQaeuwgBBHUHErsIAEL4BAAsgBg0ACyAHKAIUBEAgCRAhC0UNAiAQECEMAgsgByADNgIMIAcgB0EMajYCECALIAggB0EQakEAQSAgCGdrEAYMAQtBAiEBIAhBAkkNACALIAhBfmoiAEEDdGohAgNAIAggAEkNAiACIAEQbyACQXhqIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgB0EgaiQADwsgACAIEK0BAAvmDAIMfwR+IwBB4ABrIgYkAAJAAkACQAJAIAEoAhAiA0EBaiIFIANPBEAgASgCACICIQMCQCAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAAn8gBSADQQFqIgMgBSADSxsiA0UEQEGwpsIAIQpBBCEEQQAMAQsgBkEgakF/An8gA0EITwRAIAMgA0H/////AXFGBEAgA0EDdEEHbgwCCwwJCyADQQFqC0F/amd2IgdBAWoiBRCgASAGKAIkIgRFDQcgBigCKCECIAYoAiAiAyAEENcBIgpFDQIgCkH/ASAHQQVqEMUBIAJqIQQgByAFQQN2QQdsIAdBCEkbCyECIAYgASgCECIDNgIwIAYgAiADazYCLCAGIAQ2AiggBiAKNgIkIAYgBzYCICABKAIEIgNBBGohAiABKAIAIANqQQFqIQ0gAygCAEF/c0GAgYKEeHEhCCABKAIIIQkDQAJAAkACQCAIBEAgAiEDDAELA0AgAiANTw0CIAlBsAdqIQkgAigCACACQQRqIgMhAkGAgYKEeHEiBUGAgYKEeEYNAAsgBUGAgYKEeHMhCAsgBiAJIAhoQQN2QewBbGoiDCgCACIENgJQIAhBf2ohC0KlxoihyJyn+UshDkEBIQIDQCAOIAStQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQdAAaiACaiACQQFqIQItAAAhBAwACwALIAEpAgAhDyABIAYpAyA3AgAgBkEoaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIAZBMGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA83AyAgAEEANgIAIA+nIgBFDQcgBkHQAGogAEEBahCgASAGKAIkECEMBwsgCiAGQSBqIA4QnAEiBWogDqdBGXYiAjoAACAHIAVBfGpxIApqQQRqIAI6AAAgBigCKCAFQewBbGogDEHsARC/ARogCCALcSEIIAMhAgwACwALIAJBAWohC0EAIQIDQAJAAn8gBEEBcUUEQCACIAtPDQIgAkEBaiIDIAJJDQIgAiEEIAMMAQsgAkEDaiIEIAJJIgUNASAEIAtJIgNFDQEgCyACQQRqIAUbIAsgAxsLIQIgASgCBCAEaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEEDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQngEaDAMLIAMgBEGU0MIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDSADTwR/QQAhAwNAIAMiBUEBaiEDAkAgBSABKAIEai0AAEGAAUcNAANAIAYgASgCCCAFQewBbGoiCCgCACIENgIgQqXGiKHInKf5SyEOQQEhAgNAIA4gBK1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAZBIGogAmogAkEBaiECLQAAIQQMAQsLIAUgASgCACIJIA6nIgxxIgJrIAEgDhCcASIHIAJrcyAJcUEETwRAIAEoAgQiCyAHaiICLQAAIAIgDEEZdiICOgAAIAsgCSAHQXxqcWpBBGogAjoAAEH/AUYEQCABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIAdB7AFsaiAIQewBEL8BGgwDCyABKAIIIAdB7AFsaiEKQQAhAgNAIAIgCmoiBykCACEQIAcgAiAIaiIJKQIANwIAIAdBGGoiBCkCACERIAQgCUEYaiIMKQIANwIAIAdBEGoiBCkCACEPIAQgCUEQaiILKQIANwIAIAdBCGoiBCkCACEOIAQgCUEIaiIEKQIANwIAIAQgDjcCACALIA83AgAgDCARNwIAIAkgEDcCACACQUBrIAJBIGohAkHtAUkNAAsgCikC4AEhDiAKIAgpAuABNwLgASAKQegBaiICKAIAIQQgAiAIQegBaiICKAIANgIAIAggDjcC4AEgAiAENgIADAELCyABKAIEIgQgBWogDEEZdiICOgAAIAQgCSAFQXxqcWpBBGogAjoAAAsgAyANRw0ACyABKAIAIgJBCEkNASACQQFqBSANC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQeAAaiQADwtBtKbCAEEcQdCmwgAQvgEAC44MARV/IwBBIGsiBiQAIAAoAgQhByABKAIIIQkgASgCACEKAkACQAJAIAAoAgAtAAAEQCAGIAc2AgwgCUEVSQ0BIAYgCUEBdhClASAGKAIEIAYoAgAhDkEAIQEgBkEANgIYIAZCBDcDECAKQXxqIRAgCkF0aiERQQQhCCAJIQcDQCAHIQVBACEHQQEhAgJAIAVBf2oiBEUNAAJAAkACQAJAAkACQCAKIARBAnRqKAIAIAVBAnQiByAKakF4aigCACIAELcBRQRAIAVBfmohBCAHIBFqIQJBACEHQQAhAwNAIAMgBEYEQCAFIQIMCQsgA0EBaiEDIAAgAigCACIAELcBIAJBfGohAkUNAAsgA0EBaiECIANBf3MgBWohBAwBCyAHIBFqIQIDQAJAIARBAUYEQEEAIQQMAQsgBEF/aiEEIAAgAigCACIAELcBIAJBfGohAg0BCwsgBSAESQ0BIAUgCUsNAiAFIARrIgJBAXYiC0UNACAKIARBAnRqIQAgByAQaiEDA0AgACgCACEHIAAgAygCADYCACADIAc2AgAgAEEEaiEAIANBfGohAyALQX9qIgsNAAsLIARFIAJBCUtyDQQgBSAEayECIARBf2ohACAQIARBAnRqIQMDQCAFIABJDQMgBSAJSw0CIAMgAkEBaiICEHcgAEF/aiIHIABPDQQgA0F8aiEDIAchACACQQpJDQALDAMLIAQgBRCtAQALIAUgCRCrAQALIAAgBRCtAQALIAdBAWohBwwBCyAEIQcLIAYoAhQgAUYEQCAGQRBqIAEQjgEgBigCECEIIAYoAhghAQsgCCABQQN0aiIAIAI2AgQgACAHNgIAIAYgAUEBaiIBNgIYAkAgAUECSQ0AAkADQAJAAkACQAJAIAggAUF/akEDdGoiACgCAEUNACABQQN0IAhqIgRBdGooAgAiAyAAKAIEIgJNDQAgAUECTQ0GIAggAUF9aiIFQQN0aigCBCIAIAIgA2pNDQEgAUEDTQ0GIARBZGooAgAgACADaksNBgwBCyABQQNJDQEgACgCBCECIAggAUF9aiIFQQN0aigCBCEACyAAIAJJDQELIAFBfmohBQsCQAJAIAEgBUEBaiILSwRAIAEgBUsEQCAIIAVBA3QiE2oiACgCBCIUIAAoAgBqIgAgCCALQQN0IhVqIgEoAgAiD08EQCAAIAlNBEAgCiAPQQJ0aiIDIAEoAgQiBEECdCICaiEBIABBAnQhDAJAAkAgACAPayINIARrIgAgBEkEQCAOIAEgAEECdCICEL8BIhYgAmohAiAEQQFIIABBAUhyDQEgDCAQaiEAA0ACQCAAIAFBfGoiCCACQXxqIgwgDCgCACAIKAIAELcBIg0bKAIANgIAIAIgDCANGyECIAMgCCABIA0bIgFPDQAgAEF8aiEAIAIgFksNAQsLIAYoAhAhCAwBCyAOIAMgAhC/ASIAIAJqIQIgDSAESkEAIARBAU4bDQEgAyEBCyAOIQAMBgsgCiAMaiENA0AgAyABIAAgASgCACAAKAIAELcBIgwbKAIANgIAIAAgAEEEaiAMGyEAIANBBGohAyABQQRqIAEgDBsiASANTw0FIAIgAEsNAAsMBAsgACAJEKsBAAsgDyAAEK0BAAtB6KvCACAFIAEQrAEAC0Hoq8IAIAsgARCsAQALIAMhAQsgASAAIAIgAGtBfHEQvwEaIAYoAhgiACAFSwRAIAggE2oiASAEIBRqNgIEIAEgDzYCACAAIAtNDQIgCCAVaiIBIAFBCGogACALQX9zakEDdBCeARogBiAAQX9qIgE2AhggAUEBTQ0DDAELC0H4q8IAIAUgABCsAQALQaeuwgBBHUHErsIAEL4BAAsgBw0ACyAGKAIUBEAgCBAhC0UNAiAOECEMAgsgBiAHNgIMIAYgBkEMajYCECAKIAkgBkEQakEAQSAgCWdrEAcMAQtBAiEBIAlBAkkNACAKIAlBfmoiAEECdGohAgNAIAkgAEkNAiACIAEQdyACQXxqIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgBkEgaiQADwsgACAJEK0BAAvPCwIMfwJ+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgQgA08EQCABKAIAIgIhAwJAIAQgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAEIANBAWoiAyAEIANLGyIDRQRAQbCmwgAhB0EEIQVBAAwBCyAGQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiCEEBaiIEEKgBIAYoAgQiBUUNByAGKAIIIQIgBigCACIDIAUQ1wEiB0UNAiAHQf8BIAhBBWoQxQEgAmohBSAIIARBA3ZBB2wgCEEISRsLIQIgBiABKAIQIgM2AhAgBiACIANrNgIMIAYgBTYCCCAGIAc2AgQgBiAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDSADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIA1PDQIgCkFAayEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQsLQQEhAiAGIAogC2hBAXRB8ABxaiIMKAIAIgU2AjAgC0F/aiEJQqXGiKHInKf5SyEOA0AgDiAFrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBkEwaiACaiACQQFqIQItAAAhBQwACwALIAEpAgAhDyABIAYpAwA3AgAgBkEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA83AwAgAEEANgIAIA+nIgBFDQcgBkEwaiAAQQFqEKgBIAYoAgQQIQwHCyAHIAYgDhCcASIEaiAOp0EZdiICOgAAIAggBEF8anEgB2pBBGogAjoAACAGKAIIIARBBHRqIgIgDCkCADcCACACQQhqIAxBCGopAgA3AgAgCSALcSELIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBUEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEFIAMMAQsgAkEDaiIFIAJJIgQNASAFIAlJIgNFDQEgCSACQQRqIAQbIAkgAxsLIQIgASgCBCAFaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEFDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQngEaDAMLIAMgBUGU0MIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDCADTwR/QQAhAwNAIAMiBEEBaiEDAkAgBCABKAIEai0AAEGAAUcNAAJAA0AgBiABKAIIIARBBHRqIgcoAgAiBTYCAEKlxoihyJyn+UshDkEBIQIDQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCACIAZqIAJBAWohAi0AACEFDAELCyAEIAEoAgAiCiAOpyINcSICayABIA4QnAEiCCACa3MgCnFBBEkNASABKAIEIgkgCGoiAi0AACACIA1BGXYiAjoAACAJIAogCEF8anFqQQRqIAI6AABB/wFHBEAgASgCCCAIQQR0aiICKQIAIQ8gAiAHKQIANwIAIAJBCGoiAikCACEOIAIgB0EIaiICKQIANwIAIAcgDzcCACACIA43AgAMAQsLIAEoAgQiAiAEakH/AToAACACIAEoAgAgBEF8anFqQQRqQf8BOgAAIAEoAgggCEEEdGoiAiAHKQIANwIAIAJBCGogB0EIaikCADcCAAwBCyABKAIEIgUgBGogDUEZdiICOgAAIAUgCiAEQXxqcWpBBGogAjoAAAsgAyAMRw0ACyABKAIAIgJBCEkNASACQQFqBSAMC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC+AQALqgsCC38DfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiICIANPBEAgASgCACIDIQQCQCACIANBAWpBA3ZBB2wgBCADQQhPGyIEQQF2SwRAAn8gAiAEQQFqIgMgAiADSxsiAkUEQEGwpsIAIQdBACECQQQMAQsgBkF/An8gAkEITwRAIAIgAkH/////AXFGBEAgAkEDdEEHbgwCCwwJCyACQQFqC0F/amd2IglBAWoiAxCfASAGKAIEIgJFDQcgBigCCCEEIAYoAgAiBSACENcBIgdFDQIgCSADQQN2QQdsIAlBCEkbIQIgB0H/ASAJQQVqEMUBIARqCyEDIAYgASgCECIENgIQIAYgAiAEazYCDCAGIAM2AgggBiAHNgIEIAYgCTYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQogAygCAEF/c0GAgYKEeHEhBSABKAIIIQMDQAJAIAVFBEADQCACIApPDQIgA0HgAGohAyACKAIAIAJBBGoiBCECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyEFIAQhAgsgBkKlxoihyJyn+Us3AzAgAyAFaEEDdkEYbGoiBCgCACAEKAIIIAZBMGoQdCAHIAYgBikDMCINEJwBIghqIA2nQRl2Igs6AAAgCSAIQXxqcSAHakEEaiALOgAAIAYoAgggCEEYbGoiCCAEKQIANwIAIAhBCGogBCkCCDcCACAIQRBqIARBEGopAgA3AgAgBUF/aiAFcSEFDAELCyABKQIAIQ0gASAGKQMANwIAIAZBCGoiAikDACEOIAIgAUEIaiICKQIANwMAIAIgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiANNwMAIABBADYCACANpyIARQ0FIAZBMGogAEEBahCfASAGKAIEECEMBQsgA0EBaiEEQQAhA0EAIQIDQAJAAn8gA0EBcUUEQCACIARPDQIgAkEBaiIFIAJJDQIgAiEDIAUMAQsgAkEDaiIDIAJJIgUNASADIARJIgdFDQEgBCACQQRqIAUbIAQgBxsLIQIgASgCBCADaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEDDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQngEaDAMLIAUgAkGU0MIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgJBAWoiCSACTwR/QQAhAwNAIAMiAkEBaiEDAkAgAiABKAIEai0AAEGAAUcNAAJAA0AgASgCCCAGQqXGiKHInKf5SzcDACACQRhsaiIEKAIAIAQoAgggBhB0IAIgASgCACIHIAYpAwAiDaciCHEiCmsgASANEJwBIgUgCmtzIAdxQQRJDQEgASgCBCIKIAVqIgstAAAgCyAIQRl2Igg6AAAgCiAHIAVBfGpxakEEaiAIOgAAQf8BRwRAIAEoAgggBUEYbGoiBSkCACENIAUgBCkCADcCACAFQRBqIgcpAgAhDiAHIARBEGoiBykCADcCACAFQQhqIgUpAgAhDyAFIARBCGoiBSkCADcCACAEIA03AgAgByAONwIAIAUgDzcCAAwBCwsgASgCBCIHIAJqQf8BOgAAIAcgASgCACACQXxqcWpBBGpB/wE6AAAgASgCCCAFQRhsaiICIAQpAgA3AgAgAkEIaiAEQQhqKQIANwIAIAJBEGogBEEQaikCADcCAAwBCyABKAIEIgQgAmogCEEZdiIFOgAAIAQgByACQXxqcWpBBGogBToAAAsgAyAJRw0ACyABKAIAIgJBCEkNASACQQFqBSAJC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC+AQALnQsCC38DfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiICIANPBEAgASgCACIDIQUCQCACIANBAWpBA3ZBB2wgBSADQQhPGyIFQQF2SwRAAn8gAiAFQQFqIgMgAiADSxsiAkUEQEGwpsIAIQdBACECQQQMAQsgBkF/An8gAkEITwRAIAIgAkH/////AXFGBEAgAkEDdEEHbgwCCwwJCyACQQFqC0F/amd2IglBAWoiAxCfASAGKAIEIgJFDQcgBigCCCEFIAYoAgAiBCACENcBIgdFDQIgCSADQQN2QQdsIAlBCEkbIQIgB0H/ASAJQQVqEMUBIAVqCyEDIAYgASgCECIFNgIQIAYgAiAFazYCDCAGIAM2AgggBiAHNgIEIAYgCTYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQogAygCAEF/c0GAgYKEeHEhBCABKAIIIQMDQAJAIARFBEADQCACIApPDQIgA0HgAGohAyACKAIAIAJBBGoiBSECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyEEIAUhAgsgBkKlxoihyJyn+Us3AzAgAyAEaEEDdkEYbGoiBSAGQTBqEHkgByAGIAYpAzAiDRCcASIIaiANp0EZdiILOgAAIAkgCEF8anEgB2pBBGogCzoAACAGKAIIIAhBGGxqIgggBSkCADcCACAIQQhqIAVBCGopAgA3AgAgCEEQaiAFQRBqKQIANwIAIARBf2ogBHEhBAwBCwsgASkCACENIAEgBikDADcCACAGQQhqIgIpAwAhDiACIAFBCGoiAikCADcDACACIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDTcDACAAQQA2AgAgDaciAEUNBSAGQTBqIABBAWoQnwEgBigCBBAhDAULIANBAWohBUEAIQNBACECA0ACQAJ/IANBAXFFBEAgAiAFTw0CIAJBAWoiBCACSQ0CIAIhAyAEDAELIAJBA2oiAyACSSIEDQEgAyAFSSIHRQ0BIAUgAkEEaiAEGyAFIAcbCyECIAEoAgQgA2oiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhAwwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJ4BGgwDCyAEIAJBlNDCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACICQQFqIgkgAk8Ef0EAIQMDQCADIgJBAWohAwJAIAIgASgCBGotAABBgAFHDQACQANAIAEoAgggBkKlxoihyJyn+Us3AwAgAkEYbGoiBSAGEHkgAiABKAIAIgcgBikDACINpyIIcSIKayABIA0QnAEiBCAKa3MgB3FBBEkNASABKAIEIgogBGoiCy0AACALIAhBGXYiCDoAACAKIAcgBEF8anFqQQRqIAg6AABB/wFHBEAgASgCCCAEQRhsaiIEKQIAIQ0gBCAFKQIANwIAIARBEGoiBykCACEOIAcgBUEQaiIHKQIANwIAIARBCGoiBCkCACEPIAQgBUEIaiIEKQIANwIAIAUgDTcCACAHIA43AgAgBCAPNwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIARBGGxqIgIgBSkCADcCACACQQhqIAVBCGopAgA3AgAgAkEQaiAFQRBqKQIANwIADAELIAEoAgQiBSACaiAIQRl2IgQ6AAAgBSAHIAJBfGpxakEEaiAEOgAACyADIAlHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAkLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAEL4BAAuPCwIMfwJ+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgQgA08EQCABKAIAIgIhAwJAIAQgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAEIANBAWoiAyAEIANLGyIDRQRAQbCmwgAhB0EEIQVBAAwBCyAGQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiCEEBaiIEEKcBIAYoAgQiBUUNByAGKAIIIQIgBigCACIDIAUQ1wEiB0UNAiAHQf8BIAhBBWoQxQEgAmohBSAIIARBA3ZBB2wgCEEISRsLIQIgBiABKAIQIgM2AhAgBiACIANrNgIMIAYgBTYCCCAGIAc2AgQgBiAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDCADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIAxPDQIgCkEgaiEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQsLIAYgCiALaEE4cWoiDSgCACIFNgIwIAtBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQTBqIAJqIAJBAWohAi0AACEFDAALAAsgASkCACEPIAEgBikDADcCACAGQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDzcDACAAQQA2AgAgD6ciAEUNByAGQTBqIABBAWoQpwEgBigCBBAhDAcLIAcgBiAOEJwBIgRqIA6nQRl2IgI6AAAgCCAEQXxqcSAHakEEaiACOgAAIAYoAgggBEEDdGogDSkCADcCACAJIAtxIQsgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAFQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQUgAwwBCyACQQNqIgUgAkkiBA0BIAUgCUkiA0UNASAJIAJBBGogBBsgCSADGwshAiABKAIEIAVqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQUMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCeARoMAwsgAyAFQZTQwgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIEQQFqIQMCQCAEIAEoAgRqLQAAQYABRw0AAkADQCAGIAEoAgggBEEDdGoiBygCACIFNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBmogAkEBaiECLQAAIQUMAQsLIAQgASgCACIKIA6nIg1xIgJrIAEgDhCcASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBA3RqIgIpAgAhDiACIAcpAgA3AgAgByAOQiCIPAAEIAcgDj4CAAwBCwsgASgCBCICIARqQf8BOgAAIAIgASgCACAEQXxqcWpBBGpB/wE6AAAgASgCCCAIQQN0aiAHKQIANwIADAELIAEoAgQiBSAEaiANQRl2IgI6AAAgBSAKIARBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAEL4BAAujCwIMfwR+IwBBQGoiBCQAAkACQAJAIAEoAhAiA0EBaiIFIANPBEAgASgCACICIQMgBSACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQCAEIAUgA0EBaiIDIAUgA0sbEFQgBEEIaigCACEHIAQoAgQhCiAEKAIAQQFGBEAgACAKNgIEIABBATYCACAAQQhqIAc2AgAMBQsgBEEQaigCACEFIARBDGooAgAhAiAEIAEoAhAiAzYCECAEIAUgA2s2AgwgBCACNgIIIAQgBzYCBCAEIAo2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiENIAMoAgBBf3NBgIGChHhxIQwgASgCCCELA0ACQAJAAkAgDARAIAIhAwwBCwNAIAIgDU8NAiALQaABaiELIAIoAgAgAkEEaiIDIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQwLIAQgCyAMaEEDdkEobGoiCCgCACIGNgIwIAxBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAEQTBqIAJqIAJBAWohAi0AACEGDAALAAsgASkCACEPIAEgBCkDADcCACAEQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBEEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAQgDzcDACAAQQA2AgAgD6ciAEUNBiAEQTBqIABBAWoQoQEgBCgCBBAhDAYLIAcgBCAOEJwBIgVqIA6nQRl2IgI6AAAgCiAFQXxqcSAHakEEaiACOgAAIAQoAgggBUEobGogCEEoEL8BGiAJIAxxIQwgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAGQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQYgAwwBCyACQQNqIgYgAkkiBQ0BIAYgCUkiA0UNASAJIAJBBGogBRsgCSADGwshAiABKAIEIAZqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQYMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NASACQQRqIAIgAxCeARoMAgtBtKbCAEEcQdCmwgAQvgEACyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIg0gA08Ef0EAIQMDQCADIgVBAWohAwJAIAUgASgCBGotAABBgAFHDQACQANAIAQgASgCCCAFQShsaiIHKAIAIgY2AgBCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAEaiACQQFqIQItAAAhBgwBCwsgBSABKAIAIgsgDqciCHEiAmsgASAOEJwBIgogAmtzIAtxQQRJDQEgASgCBCIJIApqIgItAAAgAiAIQRl2IgI6AAAgCSALIApBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCkEobGoiCCkDACEQIAggBykDADcDACAIQRhqIgIpAwAhESACIAdBGGoiCSkDADcDACAIQRBqIgIpAwAhDyACIAdBEGoiBikDADcDACAIQQhqIgIpAwAhDiACIAdBCGoiAikDADcDACAHIBA3AwAgAiAONwMAIAYgDzcDACAJIBE3AwAgCEEgaiICKQIAIQ4gAiAHQSBqIgIpAgA3AgAgAiAONwIADAELCyABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIApBKGxqIAdBKBC/ARoMAQsgASgCBCIGIAVqIAhBGXYiAjoAACAGIAsgBUF8anFqQQRqIAI6AAALIAMgDUcNAAsgASgCACICQQhJDQEgAkEBagUgDQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBEFAayQAC+gKAgt/An4jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiAiADTwRAIAEoAgAiAyEFAkAgAiADQQFqQQN2QQdsIAUgA0EITxsiBUEBdksEQAJ/IAIgBUEBaiIDIAIgA0sbIgJFBEBBsKbCACEHQQAhAkEEDAELIAZBfwJ/IAJBCE8EQCACIAJB/////wFxRgRAIAJBA3RBB24MAgsMCQsgAkEBagtBf2pndiIJQQFqIgMQqAEgBigCBCICRQ0HIAYoAgghBSAGKAIAIgQgAhDXASIHRQ0CIAkgA0EDdkEHbCAJQQhJGyECIAdB/wEgCUEFahDFASAFagshAyAGIAEoAhAiBTYCECAGIAIgBWs2AgwgBiADNgIIIAYgBzYCBCAGIAk2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEKIAMoAgBBf3NBgIGChHhxIQQgASgCCCEDA0ACQCAERQRAA0AgAiAKTw0CIANBQGshAyACKAIAIAJBBGoiBSECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyEEIAUhAgsgBkKlxoihyJyn+Us3AzAgAyAEaEEBdEHwAHFqIgUoAgAgBSgCCCAGQTBqEHQgByAGIAYpAzAiDRCcASIIaiANp0EZdiILOgAAIAkgCEF8anEgB2pBBGogCzoAACAGKAIIIAhBBHRqIgggBSkCADcCACAIQQhqIAUpAgg3AgAgBEF/aiAEcSEEDAELCyABKQIAIQ0gASAGKQMANwIAIAZBCGoiAikDACEOIAIgAUEIaiICKQIANwMAIAIgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiANNwMAIABBADYCACANpyIARQ0FIAZBMGogAEEBahCoASAGKAIEECEMBQsgA0EBaiEFQQAhA0EAIQIDQAJAAn8gA0EBcUUEQCACIAVPDQIgAkEBaiIEIAJJDQIgAiEDIAQMAQsgAkEDaiIDIAJJIgQNASADIAVJIgdFDQEgBSACQQRqIAQbIAUgBxsLIQIgASgCBCADaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEDDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQngEaDAMLIAQgAkGU0MIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgJBAWoiCSACTwR/QQAhAwNAIAMiAkEBaiEDAkAgAiABKAIEai0AAEGAAUcNAAJAA0AgASgCCCAGQqXGiKHInKf5SzcDACACQQR0aiIFKAIAIAUoAgggBhB0IAIgASgCACIHIAYpAwAiDaciCHEiCmsgASANEJwBIgQgCmtzIAdxQQRJDQEgASgCBCIKIARqIgstAAAgCyAIQRl2Igg6AAAgCiAHIARBfGpxakEEaiAIOgAAQf8BRwRAIAEoAgggBEEEdGoiBCkCACENIAQgBSkCADcCACAEQQhqIgQpAgAhDiAEIAVBCGoiBCkCADcCACAFIA03AgAgBCAONwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIARBBHRqIgIgBSkCADcCACACQQhqIAVBCGopAgA3AgAMAQsgASgCBCIFIAJqIAhBGXYiBDoAACAFIAcgAkF8anFqQQRqIAQ6AAALIAMgCUcNAAsgASgCACICQQhJDQEgAkEBagUgCQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQvgEAC6EKAgx/An4jAEFAaiIEJAACQAJAAkAgASgCECIDQQFqIgUgA08EQCABKAIAIgIhAyAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAIAQgBSADQQFqIgMgBSADSxsQUyAEQQhqKAIAIQcgBCgCBCEIIAQoAgBBAUYEQCAAIAg2AgQgAEEBNgIAIABBCGogBzYCAAwFCyAEQRBqKAIAIQUgBEEMaigCACECIAQgASgCECIDNgIQIAQgBSADazYCDCAEIAI2AgggBCAHNgIEIAQgCDYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQwgAygCAEF/c0GAgYKEeHEhCyABKAIIIQoDQAJAAkACQCALBEAgAiEDDAELA0AgAiAMTw0CIApBIGohCiACKAIAIAJBBGoiAyECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyELCyAEIAogC2hBOHFqIg0oAgAiBjYCMCALQX9qIQlCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBEEwaiACaiACQQFqIQItAAAhBgwACwALIAEpAgAhDyABIAQpAwA3AgAgBEEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIARBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAEIA83AwAgAEEANgIAIA+nIgBFDQYgBEEwaiAAQQFqEKcBIAQoAgQQIQwGCyAHIAQgDhCcASIFaiAOp0EZdiICOgAAIAggBUF8anEgB2pBBGogAjoAACAEKAIIIAVBA3RqIA0pAgA3AgAgCSALcSELIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBkEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEGIAMMAQsgAkEDaiIGIAJJIgUNASAGIAlJIgNFDQEgCSACQQRqIAUbIAkgAxsLIQIgASgCBCAGaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEGDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQEgAkEEaiACIAMQngEaDAILQbSmwgBBHEHQpsIAEL4BAAsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIFQQFqIQMCQCAFIAEoAgRqLQAAQYABRw0AAkADQCAEIAEoAgggBUEDdGoiBygCACIGNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBq1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBGogAkEBaiECLQAAIQYMAQsLIAUgASgCACIKIA6nIg1xIgJrIAEgDhCcASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBA3RqIgIpAgAhDiACIAcpAgA3AgAgByAOQiCIPgIEIAcgDj4CAAwBCwsgASgCBCICIAVqQf8BOgAAIAIgASgCACAFQXxqcWpBBGpB/wE6AAAgASgCCCAIQQN0aiAHKQIANwIADAELIAEoAgQiBiAFaiANQRl2IgI6AAAgBiAKIAVBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIARBQGskAAu8CQIYfwF+IwBBMGsiAyQAIAJBOGooAgAgAkEwaigCACEIIAJBLGooAgAgAkEMaigCACELIAIoAjQhFSACKAIoIQ4gAigCACEWIAIoAgQhCiABKAIAIgEoAgghDyABKAIAIRAgA0EIaiACQRBqKAIAIgcgAkEIaigCACICIAEoAgQiESABKAIMIhJqQQFqbGpBAnQQtgEgA0EANgIoIAMgAykDCDcDIAJAAkAgAgRAIAogAkEUbGohFyAIQQV0IQwgDkF0aiENIA8gEkECdGohGCAQIBFBAnRqIRlBACEIAkACQAJAAkACQAJAA0AgCCIGQQFqIQggCiIJQRRqIQogDCECIA0hBCAOIQECQAJ/AkADQCACRQ0BIAJBYGohAiAEQSBqIQQgASgCCCEaIAFBIGohASAGIBpHDQALIAkoAgQiAiAEKAIAaiIBIAVJDQUgByABSQ0GIARBBGooAgAhBCADQSBqIAMoAiggASAFaxCVASALIAVBAnRqIAsgAUECdGoiBSADQSBqEEsgA0EgaiADKAIoIBEQlQEgECAZIANBIGoQSyACIARqIgYgAUkNByAHIAZJDQggA0EgaiADKAIoIAYgAWsQlQEgBSALIAZBAnRqIgQgA0EgahBLIANBIGogAygCKCASEJUBIA8gGCADQSBqEEsgCSgCCCICIAZJDQkgByACSQ0EIAIgBmsMAQsgCSgCCCICIAVJDQEgByACSQ0DIAsgBUECdGohBCACIAVrCyEBIANBIGogAygCKCABEJUBIAQgCyACQQJ0aiADQSBqEEsgCSgCCCEFIAogF0YNCQwBCwsgBSACEK0BAAsgAiAHEKsBAAsgBSABEK0BAAsgASAHEKsBAAsgASAGEK0BAAsgBiAHEKsBAAsgBiACEK0BAAtBACECDAELIAcgBU8EQCADKAIoIQIMAQsgBSAHEK0BAAsgA0EgaiACIAcgBWsQlQEgCyAFQQJ0aiALIAdBAnRqIANBIGoQSwJAIAMoAigiDUUNAEEAIQIgAygCICEGQQAhCQNAAkACQAJAIAIgBmoiDCwAACIIQX9KBEAgCEH/AXEhBAwBCwJ/IAYgDWoiASAMQQFqRgRAQQAhCiABDAELIAwtAAFBP3EhCiAMQQJqCyEEIAhBH3EhBQJAAkAgCEH/AXEiB0HfAU0EQCAKIAVBBnRyIQQMAQsCfyABIARGBEAgASEIQQAMAQsgBEEBaiEIIAQtAABBP3ELIApBBnRyIQQgB0HwAUkEQCAEIAVBDHRyIQQMAQsgASAIRgR/QQAFIAgtAABBP3ELIAVBEnRBgIDwAHEgBEEGdHJyIgRBgIDEAEYNAQsgBEGAAUkNAUECIQEgBEGAEEkNAkEDQQQgBEGAgARJGyEBDAILQYXJwgBBK0GwycIAEL4BAAtBASEBIAQNACAJQQFqIQkMAQsgCUUEQEEAIQkMAQsgBiACIAlraiAMIAEQngEaCyANIAEgAmoiAksNAAsgCUUNACADIA0gCWs2AigLIANBGGogA0EoaigCACIBNgIAIAMgAykDICIbNwMQIAAgFjYCACAAIBs3AgQgAEEMaiABNgIABEAgDhAhCwRAIBUQIQsgA0EwaiQAC8kKAQ1/IwBBoAJrIgckACAHIAI2AkAgByACNgI8IAcgATYCOCAHQShqIAdBOGoQiQEgBygCKCEBIAcoAiwhCiAHIAQ2AkAgByAENgI8IAcgAzYCOCAHQSBqIAdBOGoQkQEgBygCJCEJIAcoAiAhDSAHIAY2AkAgByAGNgI8IAcgBTYCOCAHQRhqIAdBOGoQiQEgBygCHCEOIAcoAhghCwJAAkACQAJAEMcBIgQEQCAHIAcvATQ7ATYgBCgCAA0CIARBfzYCACAEQQRqIAAQViIMRQ0DIAwQtQEgB0HkAGpBATsBACAHQeAAaiIPQQA2AgAgB0HYAGpCgICAgBA3AwAgB0HUAGogCTYCACAHQdAAakEANgIAIAdBzABqIAk2AgAgB0HIAGogDTYCACAHQcQAaiAJNgIAIAdB9ABqIAsgDkECdGo2AgAgB0HmAGogBy8BNjsBACAHQQA2AkAgByABIApBAnRqNgI8IAcgATYCOCAHQgA3A3ggByALNgJwIAdCADcDaCAKRQ0BIAxBFGohAyAHQUBrIRAgByABQQRqNgI4IAdBiAFqIRFBACECIAEhBgNAIAJB/wFxDQIgBigCACESAn8CQAJAIAcoAlQiACAHKAJQIgJJDQAgBygCTCAASQ0AIAdBEGogBygCXCAQakEfai0AACAHKAJIIgggAmogACACaxBEIAcoAhBBAUYEQCAHKAIUIQYDQCAHIAYgBygCUGpBAWoiADYCUAJAIAAgBygCXCICSQRAIAcoAkwhBQwBCyAHKAJMIgUgAEkNACACQQVPDQsgACACayIGIAcoAkhqIhMgD0YNBCATIA8gAhC6AUUNBAsgBygCVCIGIABJIAUgBklyDQIgB0EIaiACIBBqQR9qLQAAIAcoAkggAGogBiAAaxBEIAcoAgwhBiAHKAIIQQFGDQALCyAHIAcoAlQ2AlALIActAGUNBAJAIActAGQEQCAHKAJEIQIgBygCQCEGDAELIAcoAkQiAiAHKAJAIgZGDQULIAdBAToAZSACIAZrIQAgBygCSCAGagwBCyAHKAJAIQIgByAANgJAIAYgAmshACACIAhqCyECIAcoAnAiBSAHKAJ0Rg0CIAcgBUEEajYCcCAFKAIAIQUgB0HAAWogAiAAIAJqECMgB0HwAWogB0HAAWoQYiAHQcABaiAHQfABaiADED0gB0HwAWogB0HAAWogAxAmIAdBwAFqIAdB8AFqIAMQICAHQfABaiAHQcABahA7IAcoAvgBIgAEQCAHKALwASEGIABBFGwhAiAHKAKQAiEAIAcoAogCIQgDQCAGIAggACADEEggBkEUaiEGIAJBbGoiAg0ACwsgB0HAAWogB0HwAWpBMBC/ARogB0HwAWogB0HAAWogAxBRIAcoAvgBIgAEQCAHKALwASEGIABBFGwhAiAHKAKQAiEAIAcoAogCIQgDQCAGIAggACADEEcgBkEUaiEGIAJBbGoiAg0ACwsgB0HAAWogB0HwAWpBMBC/ARogByASNgKEASAHQQA2AoABIBEgB0HAAWpBMBC/ARogByAFNgK4ASAMIAdBgAFqEBcgBygCOCIGIAcoAjxGDQIgByAGQQRqNgI4IActAGUhAgwACwALQdC+wgBBxgAgB0E4akG4v8IAEJsBAAsgBCAEKAIAQQFqNgIAIAkEQCANECELIA5B/////wNxBEAgCxAhCyAKQf////8DcQRAIAEQIQsgB0GgAmokAA8LQZa/wgBBECAHQThqQai/wgAQmwEAC0GFycIAQStBsMnCABC+AQALIAJBBBCrAQALlAoCFH8BfiMAQeAAayIDJAAgASAAKAIAIg02AgACQCAAKAKwAUUEQCAAQX82ArABIANBHGogAUE0aigCADYCACADQRRqIAFBKGooAgA2AgAgA0EMaiABQRxqKAIANgIAIAMgAUEsaigCADYCGCADIAFBIGooAgA2AhAgAyABQRRqKAIANgIIIAMgAUEQaigCADYCBCADIAEoAgg2AgAgA0FAayADEB8gAEG0AWoiAiACKAIAQQFqNgIAIAMoAkAhCiADKAJIIgJFDQEgCiACQQxsaiESIABBuAFqIQ8gAEG8AWohEyAAQcQBaiEQIABBwAFqIRQgAEHIAWohESAKIQkDQCADQdgAaiAJQQhqKAIANgIAIAMgCSkCADcDUCADQqXGiKHInKf5SzcDACADQdAAaiADEHkgAykDACIWQhmIp0H/AHEiBEEIdCAEciICQRB0IAJyIQsgCUEMaiEJIAAoArgBIgcgFqdxIQIgACgCwAEhDiAAKAK8ASEVQQAhCAJAAkADQAJAIAIgFWooAAAiDCALcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAOIAZoQQN2IAJqIAdxQRhsaiIFIANB0ABqRgRAIANB0ABqIQUMAQsgBSADQdAAakEMELoBRQ0AIAZBf2ogBnEiBg0BDAILCyAFQQxqIQggBSgCFCEEQQAhB0EAIQIDQAJAIAQgAksEQCACQQFqIgYgAk8NAQsgBSgCECAERgRAIAggBEEBEIYBIAUoAhQhBAsgBSgCDCAEQQJ0aiANNgIAIAUgBSgCFEEBajYCFAwECyAIKAIAIgwgB2ohCyAHQQRqIQcgBiECAkBBfyANIAsoAgAiC0cgDSALSRsOAgQBAAsLIAQgBkF/ak8EQCAFKAIQIARGBH8gCCAEQQEQhgEgCCgCAAUgDAsgB2oiAiACQXxqIgIgBEECdCAHa0EEahCeARogAiANNgIAIAUgBEEBajYCFAwDC0HkrcIAQR5BhK7CABC+AQALIAhBBGoiCCACaiAHcSECIAwgDEEBdHFBgIGChHhxRQ0ACyAAKALEAUUEQCADIA8QEAsgAygCWCEFIAMoAlQhByADKAJQIQhBBEEEENcBIgZFDQEgBiANNgIAIBAgECgCACATKAIAIgwgDyAWEJwBIgJqIgstAABBAXFrNgIAIBQoAgAgCyAEOgAAIAwgACgCuAEgAkF8anFqQQRqIAQ6AAAgAkEYbGoiAkKBgICAEDcCECACIAY2AgwgAiAFNgIIIAIgBzYCBCACIAg2AgAgESARKAIAQQFqNgIACyAJIBJHDQEMAwsLQQRBBEGU0MIAKAIAIgBBAiAAGxEAAAALQZa/wgBBECADQbiqwgAQmwEACyADKAJEBEAgChAhCyAAIAAoArABQQFqNgKwASADIAFBPBC/ASEJAkAgAEEMaigCACIBIABBCGooAgBGBEACQAJAIAFBAWoiAiABSQ0AIAFBAXQiCiACIAogAksbIgqtQjx+IhZCIIinDQAgFqciAkEATg0BCxDTAQALAn8gAUUEQCACQQQQ1wEMAQsgACgCBCABQTxsQQQgAhDUAQsiAUUNASAAIAo2AgggACABNgIEIAAoAgwhAQsgACgCBCABQTxsaiAJQTwQvwEaIAAgACgCDEEBajYCDCAAIAAoAgBBAWo2AgAgCUHgAGokAA8LIAJBBEGU0MIAKAIAIgBBAiAAGxEAAAAL7QgCDH8BfiMAQdADayIEJAACQBDHASIDBEACQCADKAIARQRAIANBfzYCACADQQRqIAAQXwRAIAQgADYCCEKlxoihyJyn+UshDUEBIQIgACEBA0AgDSABrUL/AYOFQrODgICAIH4hDSACQQRGRQRAIARBCGogAmogAkEBaiECLQAAIQEMAQsLIA2nIgFBGXYiAkEIdCACciICQRB0IAJyIQkgAygCBCIGIAFxIQIgAygCDCEKIAMoAgghCANAAkACQCACIAhqKAAAIgcgCXMiAUF/cyABQf/9+3dqcUGAgYKEeHEiAUUNAANAIAAgCiABaEEDdiACaiAGcSILQewBbGoiDCgCAEcEQCABQX9qIAFxIgENAQwCCwtBgAEhAiAIIAtB7AFsIgFB7AFtIgVqIgcoAAAiCSAJQQF0cUGAgYKEeHFoQQN2IAggBUF8aiAGcWoiBSgAACIGIAZBAXRxQYCBgoR4cWdBA3ZqQQNNBEAgAyADKAIQQQFqNgIQQf8BIQILIAcgAjoAACAFQQRqIAI6AAAgAyADKAIUQX9qNgIUIAEgCmoiAigCCCEBIAwoAgQhBSAEQfABaiACQQxqQeABEL8BGiABRQRAQQAhAQwCCyAEIAU2AgggBEEQaiAEQfABakHgARC/ARoMAQsgBUEEaiIFIAJqIAZxIQJBACEBIAcgB0EBdHFBgIGChHhxRQ0BCwsgBCABNgIMIARBCGoQeiADIAMoAgBBAWo2AgACQAJAEMYBIgMEQCADKAIADQIgA0F/NgIAIANBBGogABBeRQ0BIAQgADYCCEKlxoihyJyn+UshDUEBIQIgACEBA0AgDSABrUL/AYOFQrODgICAIH4hDSACQQRGRQRAIARBCGogAmogAkEBaiECLQAAIQEMAQsLIA2nIgFBGXYiAkEIdCACciICQRB0IAJyIQkgAygCBCIGIAFxIQIgAygCDCEKIAMoAgghCEEAIQUCQANAAkACQCACIAhqKAAAIgcgCXMiAUF/cyABQf/9+3dqcUGAgYKEeHEiAUUNAANAIAAgCiABaEEDdiACaiAGcSILQQR0aigCAEcEQCABQX9qIAFxIgENAQwCCwtBgAEhAiAIIAtBBHQiAEEEdSIBaiIFKAAAIgcgB0EBdHFBgIGChHhxaEEDdiAIIAFBfGogBnFqIgEoAAAiBiAGQQF0cUGAgYKEeHFnQQN2akEDTQRAIAMgAygCEEEBajYCEEH/ASECCyAFIAI6AAAgAUEEaiACOgAAIAMgAygCFEF/ajYCFCAAIApqIgAoAgQiAUUNASAEIAApAgg3AgwgBCABNgIIDAMLIAVBBGoiBSACaiAGcSECIAcgB0EBdHFBgIGChHhxRQ0BCwsgBEEANgIICyAEQQhqEKoBIAMgAygCAEEBajYCACAEQdADaiQADwsMBAsQ1gEACwwECxDWAQALDAILC0HQvsIAQcYAIARBCGpB3KjCABCbAQALQZa/wgBBECAEQQhqQcyowgAQmwEAC8cJAg1/A34jAEGwCWsiBSQAIAUgAEGIARC/ASEBAkACQAJAQcy+wgAoAgARCAAiAwRAIAEoAgAhACABQYgBaiABQQRyQYQBEL8BGiADKAIADQIgA0F/NgIAIANBBGoiCSAAKAIAEF8NASABQcgHahBlQQRBBBDXASIFRQ0DIAVB2wA2AgBBBEEEENcBIgJFDQMgAkHdADYCACABQZgCakIANwMAIAFBCjYCoAIgAUKAgICAwAA3A5ACIAFBpAJqIAFByAdqQYQBEL8BIQYgAUHgA2pCADcDACABQdgDakKAgICAwAA3AwAgAUHQA2pCBDcDACABQcwDakGwpsIANgIAIAFByANqQQA2AgAgAUG4A2pCgYCAgBA3AwAgAUG0A2ogAjYCACABQawDakKBgICAEDcCACABQegDakIANwMAIAFCADcDwAMgASAFNgKoAyAGEJIBIAYgAUGIAWpBhAEQvwEaIAAoAgAhBSABQeAFaiABQZACakHoARC/ARogASAFNgLIB0KlxoihyJyn+UshDkEBIQIgBSEAA0AgDiAArUL/AYOFQrODgICAIH4hDiACQQRHBEAgAUHIB2ogAmogAkEBaiECLQAAIQAMAQsLIA5CGYinIgxB/wBxIgBBCHQgAHIiAEEQdCAAciENIAMoAgQiCCAOp3EhAiADKAIMIQYgAygCCCEAAkADQAJAIAAgAmooAAAiCiANcyIEQX9zIARB//37d2pxQYCBgoR4cSIERQ0AA0AgBSAGIARoQQN2IAJqIAhxIgtB7AFsaigCAEcEQCAEQX9qIARxIgQNAQwCCwsgAUHIB2ogAUHgBWpB6AEQvwEaIAYgC0HsAWxqQQRqIQhBACECA0AgAiAIaiIAKQIAIQ4gACABQcgHaiACaiIFKQIANwIAIAUgDjcCACAAQQhqIgQpAgAhDiAEIAVBCGoiBCkCADcCACAAQRBqIgcpAgAhDyAHIAVBEGoiBykCADcCACAAQRhqIgApAgAhECAAIAVBGGoiACkCADcCACAAIBA3AgAgByAPNwIAIAQgDjcCACACQUBrIAJBIGohAkHpAUkNAAsgBiALQewBbGoiACkC5AEhDiAAIAFBqAlqIgApAwA3AuQBIAAgDjcDACABQfgDaiABQcgHakHoARC/ARoMAgsgB0EEaiIHIAJqIAhxIQIgCiAKQQF0cUGAgYKEeHFFDQALIAFByAdqIAFB4AVqQegBEL8BGiAAIAkgDhCcASICai0AAEEBcSEEIAMoAhAiByAERXJFBEAgAUH4A2ogCRAMIAkgDhCcASECIAMoAgQhCCADKAIMIQYgAygCECEHIAMoAgghAAsgAyAHIARrNgIQIAAgAmogDEH/AHEiBDoAACAIIAJBfGpxIABqQQRqIAQ6AAAgBiACQewBbGoiACAFNgIAIABBBGogAUHIB2pB6AEQvwEaIAMgAygCFEEBajYCFCABQQA2AvwDCyABQfgDahB6IAMgAygCAEEBajYCACABQbAJaiQADwsgAUEEchCSAUHQvsIAQcYAIAFByAdqQdyowgAQmwEACxDWAQALQZa/wgBBECABQcgHakHMqMIAEJsBAAtBBEEEQZTQwgAoAgAiAEECIAAbEQAAAAuuCAEGfyMAQfAAayIEJAAgBCADNgIMIAQgAjYCCEEBIQggASEGAkAgAUGBAkkNAEEAIAFrIQdBgAIhBQNAAkAgBSABTw0AIAAgBWosAABBv39MDQBBACEIIAUhBgwCCyAFQX9qIQZBACEIIAVBAUYNASAFIAdqIAYhBUEBRw0ACwsgBCAGNgIUIAQgADYCECAEQQBBBSAIGzYCHCAEQcjIwgBBzoPAACAIGzYCGAJAAkACQCACIAFLIgUgAyABS3JFBEAgAiADSw0BAkAgAkUgASACRnJFBEAgASACTQ0BIAAgAmosAABBQEgNAQsgAyECCyAEIAI2AiAgAkUgASACRnINAiABQQFqIQMDQCACIAFJBEAgACACaiwAAEFATg0ECyACQX9qIQUgAkEBRg0EIAIgA0YgBSECRQ0ACwwDCyAEIAIgAyAFGzYCKCAEQcQAakEDNgIAIARB3ABqQQQ2AgAgBEHUAGpBBDYCACAEQgM3AjQgBEHUg8AANgIwIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCWCAEIARBEGo2AlAgBCAEQShqNgJIIARBMGpB7IPAABDEAQALIARB5ABqQQQ2AgAgBEHcAGpBBDYCACAEQdQAakEFNgIAIARBxABqQQQ2AgAgBEIENwI0IARB/IPAADYCMCAEQQU2AkwgBCAEQcgAajYCQCAEIARBGGo2AmAgBCAEQRBqNgJYIAQgBEEMajYCUCAEIARBCGo2AkggBEEwakGchMAAEMQBAAsgAiEFCwJAIAEgBUYNAEEBIQYCQAJAAkAgACAFaiIHLAAAIgJBf0wEQEEAIQggACABaiIDIQEgAyAHQQFqRwRAIActAAFBP3EhCCAHQQJqIQELIAJBH3EhByACQf8BcUHfAUsNASAIIAdBBnRyIQEMAgsgBCACQf8BcTYCJCAEQShqIQIMAgtBACEAIAMhBiABIANHBH8gAUEBaiEGIAEtAABBP3EFIAALIAhBBnRyIQAgAkH/AXFB8AFJBEAgACAHQQx0ciEBDAELQQAhAiADIAZHBH8gBi0AAEE/cQUgAgsgB0ESdEGAgPAAcSAAQQZ0cnIiAUGAgMQARg0CCyAEIAE2AiRBASEGIARBKGohAiABQYABSQ0AQQIhBiABQYAQSQ0AQQNBBCABQYCABEkbIQYLIAQgBTYCKCAEIAUgBmo2AiwgBEHEAGpBBTYCACAEQewAakEENgIAIARB5ABqQQQ2AgAgBEHcAGpBBjYCACAEQdQAakEHNgIAIARCBTcCNCAEQayEwAA2AjAgBCACNgJYIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCaCAEIARBEGo2AmAgBCAEQSRqNgJQIAQgBEEgajYCSCAEQTBqQdSEwAAQxAEAC0GFycIAQStBsMnCABC+AQALpQgBCH8jAEFAaiIDJAAgA0EkaiABNgIAIANBNGogAkEUaigCACIENgIAIANBAzoAOCADQSxqIAIoAhAiBSAEQQN0ajYCACADQoCAgICABDcDCCADIAA2AiAgA0EANgIYIANBADYCECADIAU2AjAgAyAFNgIoAkACQAJAAkAgAigCCCIGRQRAIAIoAgAhCCACKAIEIgkgBCAEIAlLGyIGRQ0BQQEhBCAAIAgoAgAgCCgCBCABKAIMEQMADQQgCEEIaiECQQEhBwNAIAUoAgAgA0EIaiAFQQRqKAIAEQEABEAMBgsgByAGTw0CIAJBBGohACACKAIAIQEgBUEIaiEFIAJBCGohAiAHQQFqIQcgAygCICABIAAoAgAgAygCJCgCDBEDAEUNAAsMBAsgAigCACEIIAIoAgQiCSACQQxqKAIAIgIgAiAJSxsiCkUNAEEBIQQgACAIKAIAIAgoAgQgASgCDBEDAA0DIAZBEGohBSAIQQhqIQJBASEHA0AgAyAFQXhqKAIANgIMIAMgBUEQai0AADoAOCADIAVBfGooAgA2AghBACEBQQAhBAJAAkACQAJAIAVBCGooAgBBAWsOAwECAwALIAVBDGooAgAhAEEBIQQMAgsgBUEMaigCACIGIAMoAjQiBEkEQEEAIQQgAygCMCAGQQN0aiIGKAIEQQhHDQIgBigCACgCACEAQQEhBAwCC0HEhcAAIAYgBBCsAQALIAMoAigiBiADKAIsRg0AIAMgBkEIajYCKCAGKAIEQQhHDQAgBigCACgCACEAQQEhBAsgAyAANgIUIAMgBDYCEAJAAn8CQAJAAkACQAJAIAUoAgBBAWsOAwEABgQLIAMoAigiACADKAIsRw0BDAULIAVBBGooAgAiACADKAI0IgRPDQEgAygCMCAAQQN0aiIAKAIEQQhHDQQgACgCACgCAAwDCyADIABBCGo2AiggACgCBEEIRw0DIAAoAgAoAgAMAgtBxIXAACAAIAQQrAEACyAFQQRqKAIACyEEQQEhAQsgAyAENgIcIAMgATYCGAJAIAVBcGooAgBBAUcEQCADKAIoIgQgAygCLEYNBCADIARBCGo2AigMAQsgBUF0aigCACIAIAMoAjQiAU8NBCADKAIwIABBA3RqIQQLIAQoAgAgA0EIaiAEQQRqKAIAEQEABEBBASEEDAULIAcgCk8NASACQQRqIQAgAigCACEBIAVBJGohBSACQQhqIQJBASEEIAdBAWohByADKAIgIAEgACgCACADKAIkKAIMEQMARQ0ACwwDCyAJIAdLBEBBASEEIAMoAiAgCCAHQQN0aiIAKAIAIAAoAgQgAygCJCgCDBEDAA0DC0EAIQQMAgtBhcnCAEErQbDJwgAQvgEAC0HUhcAAIAAgARCsAQALIANBQGskACAEC+kHAg9/AX4jAEGgAmsiAiQAIAJBmAFqEGUgAkEBNgKYAUGsscIAIQEDQCACQZgBaiABKAIAIAFBBGooAgAgAUEIaigCACABQQxqKAIAEIQBIAFBEGoiAUGMssIARw0AC0GMssIAIQEDQCACQZgBaiABKAIAIAFBBGooAgAgAUEIaigCACABQQxqKAIAEIUBIAFBEGoiAUGMs8IARw0AC0GMs8IAIQEDQCACQZgBaiABQQRqKAIAIAFBCGooAgAgAS0AABBsIAFBDGoiAUH8usIARw0ACyACQZgBakEEciEFQcivwgAhAQNAIAUgAUEEaigCACABLQAAEDwgAUEIaiIBQZixwgBHDQALIAVB3wFBBhA8IAJBEGogAkGYAWpBhAEQvwEaIAIgADYClAEgAiACQZQBajYCmAEgAkGYAWpBBHIgAkEQakGEARC/ARogAkGYAWoQGRDGASIDBEAgAygCAEUEQCADQX82AgAgA0EEaiIJIAIoApQBIgUQXkUEQCACQQhqQQoQpAEgAigCDCEKIAIoAgghCyACIAU2ApgBQqXGiKHInKf5SyEQQQEhASAFIQADQCAQIACtQv8Bg4VCs4OAgIAgfiEQIAFBBEZFBEAgAkGYAWogAWogAUEBaiEBLQAAIQAMAQsLIBBCGYinIg1B/wBxIgBBCHQgAHIiAEEQdCAAciEOIAMoAgQiByAQp3EhASADKAIMIQggAygCCCEAAkADQAJAIAAgAWooAAAiDCAOcyIEQX9zIARB//37d2pxQYCBgoR4cSIERQ0AA0AgBSAIIARoQQN2IAFqIAdxIg9BBHRqKAIARwRAIARBf2ogBHEiBA0BDAILCyACQaABaiAIIA9BBHRqIgAoAgw2AgAgAEEANgIMIAApAgQhECAAIAo2AgggACALNgIEIAIgEDcDmAEMAgsgBkEEaiIGIAFqIAdxIQEgDCAMQQF0cUGAgYKEeHFFDQALIAAgCSAQEJwBIgFqLQAAQQFxIQQgAygCECIGIARFckUEQCACQZgBaiAJEA4gCSAQEJwBIQEgAygCBCEHIAMoAgwhCCADKAIQIQYgAygCCCEACyADIAYgBGs2AhAgACABaiANQf8AcSIEOgAAIAcgAUF8anEgAGpBBGogBDoAACAIIAFBBHRqIgBBADYCDCAAIAo2AgggACALNgIEIAAgBTYCACADIAMoAhRBAWo2AhQgAkEANgKYAQsgAkGYAWoQqgEgAyADKAIAQQFqNgIAIAJBoAJqJAAPCxDWAQALQZa/wgBBECACQZgBakHMqMIAEJsBAAtB0L7CAEHGACACQZgBakHcqMIAEJsBAAuoBgENfyABKAIQIQIgACgCBCEDIAAoAgAhBQJAAkACQCABKAIIIg1BAUcEQCACDQEMAwsgAkUNAQsCQCADRQRAQQAhAwwBCyADIAVqIQcgAUEUaigCAEEBaiEKIAUiACELA0AgAEEBaiEEAkACfyAALAAAIgJBf0wEQAJ/IAQgB0YEQEEAIQggBwwBCyAALQABQT9xIQggAEECaiIECyEAIAJBH3EhCSAIIAlBBnRyIAJB/wFxIg5B3wFNDQEaAn8gACAHRgRAQQAhDCAHDAELIAAtAABBP3EhDCAAQQFqIgQLIQIgDCAIQQZ0ciEIIAggCUEMdHIgDkHwAUkNARoCfyACIAdGBEAgBCEAQQAMAQsgAkEBaiEAIAItAABBP3ELIAlBEnRBgIDwAHEgCEEGdHJyIgJBgIDEAEcNAgwECyACQf8BcQshAiAEIQALIApBf2oiCgRAIAYgC2sgAGohBiAAIQsgACAHRw0BDAILCyACQYCAxABGDQACQCAGRSADIAZGckUEQEEAIQAgBiADTw0BIAUgBmosAABBQEgNAQsgBSEACyAGIAMgABshAyAAIAUgABshBQsgDQ0ADAELQQAhBCADBEAgAyECIAUhAANAIAQgAC0AAEHAAXFBgAFGaiEEIABBAWohACACQX9qIgINAAsLIAMgBGsgASgCDCIHTw0AQQAhBkEAIQQgAwRAIAMhAiAFIQADQCAEIAAtAABBwAFxQYABRmohBCAAQQFqIQAgAkF/aiICDQALCyAEIANrIAdqIQICQAJAAkBBACABLQAwIgAgAEEDRhtBAWsOAwABAAILIAIhBkEAIQIMAQsgAkEBdiEGIAJBAWpBAXYhAgsgBkEBaiEAAn8CQANAIABBf2oiAARAIAEoAhggASgCBCABKAIcKAIQEQEARQ0BDAILCyABKAIEIQRBASABKAIYIAUgAyABKAIcKAIMEQMADQEaIAJBAWohACABKAIcIQUgASgCGCEBA0AgAEF/aiIARQRAQQAPCyABIAQgBSgCEBEBAEUNAAsLQQELDwsgASgCGCAFIAMgAUEcaigCACgCDBEDAAv9BgIVfwF+IwBB4AFrIgIkACACIAE2AgAgASgCICEJIAIgAS0AJDoAByACIAI2AgwgAiACQQdqNgIIIAEtACVFBEAgAUEUaiEEIAJB+ABqIAEgAUEQaiIKIAFBDGoiCxAAAkAgAigCfCIGRQ0AIAlBAXQhDCACQbQBaiENIAJBgAFqIQgDQAJAIAJBQGsiAyAIQQhqKQIANwMAIAJByABqIgUgCEEQaikCADcDACACQdAAaiIHIAhBGGopAgA3AwAgAiAIKQIANwM4IAIoAnghDiACKAKgASEPIAIoAqQBIRAgAigCqAEhESACKAKsASESIAIoArABIRMgAkEQaiANQSgQvwEaIAJB4ABqIhQgAykDADcDACACQegAaiIVIAUpAwA3AwAgAkHwAGoiFiAHKQMANwMAIAIgAikDODcDWCACQfgAaiACQRBqQSgQvwEaIAEoAhwiAyABKAIYRgRAAkACQCADQQFqIgUgA0kNACADQQF0IgcgBSAHIAVLGyIHrULkAH4iF0IgiKcNACAXpyIFQQBODQELENMBAAsCfyADRQRAIAVBBBDXAQwBCyAEKAIAIANB5ABsQQQgBRDUAQsiA0UNASABIAc2AhggASADNgIUIAEoAhwhAwsgASgCFCADQeQAbGoiAyAGNgIEIAMgDjYCACADIBI2AjQgAyAPNgIoIANBCGogAikDWDcCACADQRBqIBQpAwA3AgAgA0EYaiAVKQMANwIAIANBIGogFikDADcCACADQThqIBM2AgAgA0EwaiARNgIAIANBLGogEDYCACADQTxqIAJB+ABqQSgQvwEaIAEgASgCHEEBaiIDNgIcIAMgDE8EQCACQQhqIAQQCSAEIAkQvAELIAJB+ABqIAEgCiALEAAgAigCfCIGDQEMAgsLIAVBBEGU0MIAKAIAIgBBAiAAGxEAAAALIAJBCGogBBAJIAQgCRC8ASABKAIcIgRBAXYiBgRAIAEoAhQiAyAEQeQAbGpBnH9qIQQDQCACQfgAaiADQeQAEL8BGiADIARB5AAQngFB5ABqIQMgBCACQfgAakHkABC/AUGcf2ohBCAGQX9qIgYNAAsLIAFBAToAJQsCQCABQRxqKAIAIgMEQCABIANBf2oiAzYCHCAAIAEoAhQgA0HkAGxqQeQAEL8BGgwBCyAAQQA2AgQLIAJB4AFqJAAL1QYCDn8CfiMAQRBrIgYkACABKAIEIghBFGwhAyABKAIAIQUCQCAIRQRADAELIAVBCGohBCADIQIDQCAEKAIAIARBfGooAgBrIAdqIQcgBEEUaiEEIAJBbGoiAg0ACwsCQCAHrUIMfiIQQiCIp0UEQCAQpyICQX9KBEACQAJAIAJFBEBBBCEJDAELIAJBBBDXASIJRQ0BCyAIRQRAQQAhAgwECyADIAVqIQ0gAUEUaigCACEMIAEoAhAhDkEAIQIDQAJAAkACQAJAAkAgBSgCCCIBIAUoAgQiCk8EQCAMIAFJDQEgASAKayIIRQ0FQQEhBCACQQF0IQMgAkEMbCEBIA4gCkECdGohCgNAIAZBADYCCCAGQgA3AgAgBiAEIAogBBB2An8gBEEDRgRAIAhFDQUgCkEEaiEKIAhBf2ohCEEDDAELIARBAWoLIQQgBigCACIPQYCAxABGDQYgBikCBCEQIAIgB0YEQCACQQFqIgcgAkkNBiADIAcgAyAHSxsiB61CDH4iEUIgiKcNBiARpyILQQBIDQYCfyACRQRAIAtBBBDXAQwBCyAJIAFBBCALENQBCyIJRQ0FCyABIAlqIgsgDzYCACALQQRqIBA3AgAgA0ECaiEDIAFBDGohASACQQFqIQIgCCAETw0ACwwFCyAKIAEQrQEACyABIAwQqwEAC0EBQQAQrQEACyALQQRBlNDCACgCACIAQQIgABsRAAAACxDTAQALIA0gBUEUaiIFRw0ACwwDCyACQQRBlNDCACgCACIAQQIgABsRAAAACxDYAQALENgBAAsgCSACIAZBAEEgIAJnaxAFAkAgAkECSQRAIAIhAwwBCyAJQQxqIQFBASEDQQEhBANAAkAgBCADQX9qIgVGDQAgASAJIAVBDGxqQQwQugFFDQAgAyAERwRAIAZBCGoiCCABQQhqIgUoAgA2AgAgBiABKQIANwMAIAUgCSADQQxsaiIFQQhqIgooAgA2AgAgASAFKQIANwIAIAogCCgCADYCACAFIAYpAwA3AgALIANBAWohAwsgAUEMaiEBIAIgBEEBaiIERw0ACyADIAJNDQBBta3CAEEcQdStwgAQvgEACyAAIAM2AgggACAHNgIEIAAgCTYCACAGQRBqJAALngYBDX8gASgCACEHAn9BACABKAIIIgRFDQAaIAcgBEEUbGohDgJAAkADQCAHIggoAggiCSAIKAIEIgNPBEAgCSABKAIgIgRLDQIgASgCGCENIAkgA2shDyADQQJ0IQtBACEKQQAhBAJAIAMgCUYNAEEFIAsgDWoiBygCACACEDciA0H/AXFBAkYNACADQQFxRQ0AIAlBAnQgC2tBfGohAyAHQQRqIQVBASEGA0AgBiEEIANFDQFBBSAFKAIAIAIQNyIHQf8BcUECRg0BIARBAWohBiADQXxqIQMgBUEEaiEFIAdBAXENAAsLIAhBFGohBwJAIA8gBGsiBkUNACALIAlBAnQiBWshAyAFIA1qQXxqIQUDQCADRQ0BQQUgBSgCACACEDciCUH/AXFBAkYgCUEBcUVyDQEgA0EEaiEDIAVBfGohBSAGIApBAWoiCkcNAAsLIAggCCgCBCAEajYCBCAIIAgoAgggCms2AgggCCAILQARIApBAEdyOgARIAcgDkcNAQwDCwsgAyAJEK0BAAsgCSAEEKsBAAsgASgCACEHIAEoAggLIQYgAUEANgIIQQAhBEEAIQICQANAIAQgBk8NASAEQRRsIQkgBCAMakEUbCEKIAchBSAEIQMCQAJAAkADQCADIAZPDQEgBSAJaiIIQQhqIgsoAgAgCEEEaigCAEYNAyACBEAgAyAMaiIEIAZPDQMgBSAKaiIEIAgpAgA3AgAgBEEQaiAIQRBqKAIANgIAIARBCGogCykCADcCAAsgBUEUaiEFIANBAWoiAyAGSQ0ACyADIQQMBAtB1K7CACADIAYQrAEAC0HkrsIAIAQgBhCsAQALIANBAWohBCAMQX9qIQwgAkEBaiECIAhBEWotAABBAkcNAAsgA0EBaiEECyACRSAGIARNckUEQCAHIARBFGxqIgNBACACa0EUbGogAyAGIARrQRRsEJ4BGgsgASAGIAJrIgQ2AgggBARAIAZBFGwgAkEUbGshBUEAIQMDQCAHIAM2AgAgB0EUaiEHIANBAWohAyAFQWxqIgUNAAsLIAAgAUEwEL8BGguiBwEFfyAAQXhqIgEgAEF8aigCACIDQXhxIgBqIQJBgNDCAAJ/AkACQAJAAkAgA0EBcQ0AIANBA3FFDQEgASgCACIDIABqIQAgASADayIBQdjPwgAoAgBGBEAgAigCBEEDcUEDRw0BQdDPwgAgADYCACACIAIoAgRBfnE2AgQgASAAQQFyNgIEIAAgAWogADYCAA8LIANBgAJPBEAgARBaDAELIAEoAgwiBCABKAIIIgVHBEAgBSAENgIMIAQgBTYCCAwBC0HAzMIAQcDMwgAoAgBBfiADQQN2d3E2AgALAkAgAigCBCIDQQJxBEAgAiADQX5xNgIEIAEgAEEBcjYCBCAAIAFqIAA2AgAMAQsCQCACQdzPwgAoAgBHBEBB2M/CACgCACACRw0BQdjPwgAgATYCAEHQz8IAQdDPwgAoAgAgAGoiADYCACABIABBAXI2AgQgACABaiAANgIADwtB3M/CACABNgIAQdTPwgBB1M/CACgCACAAaiIANgIAIAEgAEEBcjYCBEHYz8IAKAIAIAFGBEBB0M/CAEEANgIAQdjPwgBBADYCAAtB+M/CACgCACICIABPDQJB3M/CACgCACIARQ0CAkBB1M/CACgCACIDQSlJDQBB6M/CACEBA0AgASgCACIEIABNBEAgBCABKAIEaiAASw0CCyABKAIIIgENAAsLQYDQwgACf0H/H0Hwz8IAKAIAIgBFDQAaQQAhAQNAIAFBAWohASAAKAIIIgANAAsgAUH/HyABQf8fSxsLNgIAIAMgAk0NAkH4z8IAQX82AgAPCyADQXhxIgQgAGohAAJAIARBgAJPBEAgAhBaDAELIAIoAgwiBCACKAIIIgJHBEAgAiAENgIMIAQgAjYCCAwBC0HAzMIAQcDMwgAoAgBBfiADQQN2d3E2AgALIAEgAEEBcjYCBCAAIAFqIAA2AgAgAUHYz8IAKAIARw0AQdDPwgAgADYCAAwBCyAAQYACSQ0BIAEgABBSQYDQwgBBgNDCACgCAEF/aiIANgIAIAANAEHwz8IAKAIAIgANAkH/HwwDCw8LIABBA3YiAkEDdEHIzMIAaiEAAn9BwMzCACgCACIDQQEgAkEfcXQiAnEEQCAAKAIIDAELQcDMwgAgAiADcjYCACAACyECIAAgATYCCCACIAE2AgwgASAANgIMIAEgAjYCCA8LQQAhAQNAIAFBAWohASAAKAIIIgANAAsgAUH/HyABQf8fSxsLNgIAC4cGAQZ/AkACQAJAAkAgAkEJTwRAIAIgAxBFIgINAUEADwtBACECIANBzP97Sw0CQRAgA0ELakF4cSADQQtJGyEBIABBfGoiBigCACIHQXhxIQQCQAJAAkACQCAHQQNxBEAgAEF4aiIIIARqIQUgBCABTw0BQdzPwgAoAgAgBUYNAkHYz8IAKAIAIAVGDQMgBSgCBCIHQQJxDQYgB0F4cSIJIARqIgQgAU8NBAwGCyABQYACSSAEIAFBBHJJciAEIAFrQYGACE9yDQUMBwsgBCABayICQRBJDQYgBiABIAdBAXFyQQJyNgIAIAEgCGoiASACQQNyNgIEIAUgBSgCBEEBcjYCBCABIAIQLwwGC0HUz8IAKAIAIARqIgQgAU0NAyAGIAEgB0EBcXJBAnI2AgAgASAIaiICIAQgAWsiAUEBcjYCBEHUz8IAIAE2AgBB3M/CACACNgIADAULQdDPwgAoAgAgBGoiBCABSQ0CAkAgBCABayIDQQ9NBEAgBiAHQQFxIARyQQJyNgIAIAQgCGoiASABKAIEQQFyNgIEQQAhAwwBCyAGIAEgB0EBcXJBAnI2AgAgASAIaiICIANBAXI2AgQgBCAIaiIBIAM2AgAgASABKAIEQX5xNgIEC0HYz8IAIAI2AgBB0M/CACADNgIADAQLIAQgAWshAgJAIAlBgAJPBEAgBRBaDAELIAUoAgwiAyAFKAIIIgVHBEAgBSADNgIMIAMgBTYCCAwBC0HAzMIAQcDMwgAoAgBBfiAHQQN2d3E2AgALIAJBEE8EQCAGIAEgBigCAEEBcXJBAnI2AgAgASAIaiIBIAJBA3I2AgQgBCAIaiIDIAMoAgRBAXI2AgQgASACEC8MBAsgBiAEIAYoAgBBAXFyQQJyNgIAIAQgCGoiASABKAIEQQFyNgIEDAMLIAIgACADIAEgASADSxsQvwEaIAAQIQwBCyADEAMiAUUNACABIAAgAyAGKAIAIgFBeHFBBEEIIAFBA3EbayIBIAEgA0sbEL8BIAAQIQ8LIAIPCyAAC8EFAQt/IwBBIGsiBiQAAkACQAJAIAEgAkcEQCABQQFqIQMgASwAACIFQX9KDQECfyACIANGBEAgAgwBCyABLQABQT9xIQQgAUECaiIDCyEBIAVBH3EhByAFQf8BcUHfAU0EQCAEIAdBBnRyIQEMAwsCfyABIAJGBEAgAgwBCyABLQAAQT9xIQggAUEBaiIDCyEBIAggBEEGdHIhBCAFQf8BcUHwAUkEQCAEIAdBDHRyIQEMAwsgASACRgR/QQAFIAFBAWohAyABLQAAQT9xCyAHQRJ0QYCA8ABxIARBBnRyciIBQYCAxABHDQILIABBADYCCCAAQgQ3AgAMAgsgBUH/AXEhAQsgBkEIaiACQQNqIgwgA2tBAnZBAWoQpQEgBigCDCEFIAYoAggiCCABNgIAIAZBATYCGCAGIAU2AhQgBiAINgIQAkAgAiADRg0AQQQhB0EBIQEDQCADQQFqIQUCQAJAIAMsAAAiBEF/TARAAn8gAiAFRgRAQQAhCSACDAELIAMtAAFBP3EhCSADQQJqIgULIQMgBEEfcSEKIARB/wFxIg1B3wFLDQEgCSAKQQZ0ciEEIAUhAwwCCyAEQf8BcSEEIAUhAwwBCwJ/IAIgA0YEQEEAIQsgAgwBCyADLQAAQT9xIQsgA0EBaiIFCyEEIAsgCUEGdHIhCSANQfABSQRAIAkgCkEMdHIhBCAFIQMMAQsCfyACIARGBEAgBSEDQQAMAQsgBEEBaiEDIAQtAABBP3ELIApBEnRBgIDwAHEgCUEGdHJyIgRBgIDEAEYNAgsgBigCFCABRgRAIAZBEGogASAMIANrQQJ2QQFqEIYBIAYoAhAhCAsgByAIaiAENgIAIAYgAUEBaiIBNgIYIAdBBGohByACIANHDQALCyAAIAYpAxA3AgAgAEEIaiAGQRhqKAIANgIACyAGQSBqJAALhAUBEH8jAEHwAGsiCSQAIABBiAJqIQ0gCUHIAGohDiABQTJJIQ9BASEEAkADQAJAIAQgAU8EQEEAIQcMAQsgACAEQeQAbGohA0EBIQcDQEEAIQICQANAIAJBBGoiCEEoRg0BIAIgA2ohBSAIIQJBfyAFQVxqKAIAIgggBUFAaygCACIFRyAIIAVIGyIIRQ0ACyAIQf8BcUH/AUYNAgsgA0HkAGohA0EBIQIgBEEBaiIEIAFJIQcgASAERw0ACwwCCyABIARGIQIgDyABIARGcg0BAkACQCAEQX9qIgIgAUkEQCAHQQFxBEAgCkEBaiEKIAlBCGogACACQeQAbGoiAkHkABC/ARogAiAAIARB5ABsIgdqIgJB5AAQvwEaIAIgCUEIakHkABC/ASEFIAAgBBBJIAEgBGsiDEECSQ0DIAVB5ABqIQhBwAAhAgNAIAJBBGoiA0HoAEYNBCACIAVqIQYgAyECQX8gBigCACIDIAZB5ABqKAIAIgZHIAMgBkgbIgNFDQALIANB/wFxQf8BRw0DIAlBCGogBUHkABC/ARogBSAIQeQAEL8BIRAgDEEDSQ0CIAcgDWohB0ECIQIDQCACQQFqIBAgAkHkAGxqIQNBACECA0AgAkEEaiIGQShGDQQgAiAHaiELIAIgDmohESAGIQJBfyARKAIAIgYgCygCACILRyAGIAtIGyIGRQ0ACyAGQf8BcUH/AUcNAyADQZx/aiADQeQAEL8BGiAHQeQAaiEHIAMhCCICIAxHDQALDAILQcCnwgAgBCABEKwBAAtBsKfCACACIAEQrAEACyAIIAlBCGpB5AAQvwEaCyAKQQVHDQALQQAhAgsgCUHwAGokACACC6UFAQZ/IAAoAgAiCUEBcSIKIARqIQgCQCAJQQRxRQRAQQAhAQwBCyACBEAgAiEHIAEhBQNAIAYgBS0AAEHAAXFBgAFGaiEGIAVBAWohBSAHQX9qIgcNAAsLIAIgCGogBmshCAtBK0GAgMQAIAobIQYCQAJAIAAoAghBAUcEQEEBIQUgACAGIAEgAhC7AQ0BDAILIABBDGooAgAiByAITQRAQQEhBSAAIAYgASACELsBDQEMAgsCQCAJQQhxRQRAIAcgCGshB0EAIQUCQAJAAkBBASAALQAwIgggCEEDRhtBAWsOAwABAAILIAchBUEAIQcMAQsgB0EBdiEFIAdBAWpBAXYhBwsgBUEBaiEFA0AgBUF/aiIFRQ0CIAAoAhggACgCBCAAKAIcKAIQEQEARQ0AC0EBDwtBASEFIABBAToAMCAAQTA2AgQgACAGIAEgAhC7AQ0BIAcgCGshBkEAIQUCQAJAAkBBASAALQAwIgEgAUEDRhtBAWsOAwABAAILIAYhBUEAIQYMAQsgBkEBdiEFIAZBAWpBAXYhBgsgBUEBaiEFAkADQCAFQX9qIgVFDQEgACgCGCAAKAIEIAAoAhwoAhARAQBFDQALQQEPCyAAKAIEIQFBASEFIAAoAhggAyAEIAAoAhwoAgwRAwANASAGQQFqIQYgACgCHCECIAAoAhghAANAIAZBf2oiBkUEQEEADwsgACABIAIoAhARAQBFDQALDAELIAAoAgQhCEEBIQUgACAGIAEgAhC7AQ0AIAAoAhggAyAEIAAoAhwoAgwRAwANACAHQQFqIQYgACgCHCEBIAAoAhghAANAIAZBf2oiBkUEQEEADwsgACAIIAEoAhARAQBFDQALCyAFDwsgACgCGCADIAQgAEEcaigCACgCDBEDAAuVBQINfwJ+IwBB0ABrIgMkAAJAIAEoAggiBK1CFH4iEEIgiKdFBEAgEKciBUF/SgRAAkACQCAFRQRAQQQhCAwBCyAFQQQQ1wEiCEUNAQsgASgCACEKIARFBEBBACEFQQAhBAwECyAKIARBFGxqIQ0gAUEgaigCACEOIAEoAhghD0EAIQUgCiEJA0AgCSgCACEHIANBADYCGCADIAc2AhQgA0G0qsIANgIQIAMgDjYCDCADIA82AgggAyACNgIEIAMgCTYCACADQThqIAMQKAJAIAMtAElBAkYNAAJAA0ACQCADQTBqIANByABqIgsoAgAiBzYCACADQShqIANBQGsiDCkDACIQNwMAIAMgAykDOCIRNwMgIAsgBzYCACAMIBA3AwAgAyARNwM4AkAgBCAGRwRAIAYhBQwBCyAEQQFqIgYgBEkNAyAEQQF0IgcgBiAHIAZLGyIGrUIUfiIQQiCIpw0DIBCnIgdBAEgNAwJ/IARFBEAgB0EEENcBDAELIAggBEEUbEEEIAcQ1AELIghFDQEgBiEECyAIIAVBFGxqIgYgAykDODcCACAGQRBqIAsoAgA2AgAgBkEIaiAMKQMANwIAIANBOGogAxAoIAVBAWoiBSEGIAMtAElBAkcNAQwDCwsgB0EEQZTQwgAoAgAiAEECIAAbEQAAAAsQ0wEACyANIAlBFGoiCUcNAAsMAwsgBUEEQZTQwgAoAgAiAEECIAAbEQAAAAsQ2AEACxDYAQALIAEoAgQEQCAKECELIAEgBTYCCCABIAQ2AgQgASAINgIAIAUEQCAFQRRsIQVBACEEA0AgCCAENgIAIAhBFGohCCAEQQFqIQQgBUFsaiIFDQALCyAAIAFBMBC/ARogA0HQAGokAAvABQIIfwR+IwBBsAFrIgAkAAJAQcjLwgAoAgANACAAQRBqQeQDQQEQmgEgACkDECEIIABBzABqIgFB5AM2AgAgACAINwJEIABBFjYCQCAAQUBrEH8gAEE4aiIEIABByABqKQMANwMAIAAgACkDQDcDMCAAQUBrQRQQUyAAQSBqIgMgASkCADcDACAAQShqIgUgAEHUAGoiBigCADYCACAAIAApAkQ3AxggAEEIakEUQQAQmgEgACkDCCEIIABBFEEAEJoBIAApAwAhCSABIAQpAwA3AgAgBkEANgIAIABB2ABqIAApAxg3AwAgAEHgAGogAykDADcDACAAQegAaiAFKAIANgIAIABBiAFqQQA2AgAgAEGAAWoiBCAJNwMAIABB+ABqQgA3AwAgAEHwAGogCDcDACAAQewAakEANgIAIABBADYCQCAAIAApAzA3AkQDQCAAQUBrIAJqIgEpAgAhCCABIAJBwMvCAGoiAykCADcCACACQcjLwgBqIgUpAgAhCSACQdDLwgBqIgYpAgAhCiACQdjLwgBqIgcpAgAhCyADIAg3AgAgAUEYaiIDKQIAIQggAyALNwIAIAFBEGoiAykCACELIAMgCjcCACABQQhqIgEpAgAhCiABIAk3AgAgByAINwIAIAYgCzcCACAFIAo3AgAgAkFAayACQSBqIQJBzQBJDQALIAQpAgAhCCAEQYDMwgApAgA3AgBBgMzCACAINwIAIARBCGoiAigCACEBIAJBiMzCACgCADYCAEGIzMIAIAE2AgAgACgCSCIBRQ0AIABB3ABqKAIAIQQgACgCWCECIAAoAnAhAyAAKAJ0IAAoAoQBIQYgACgCgAEhByAAKAJMBEAgARAhCyACBEAgAEFAayACQQFqEKcBIAQQIQsEQCADECELIAZFDQAgBxAhCyAAQbABaiQAQcDLwgAL/QQBDH8CQAJAAkACQCABKAIAIgRBCGooAgAiAiAEKAIEIgRPBEAgAUEMaigCACIDIAJJDQFBAiEDIAEoAhgiBiACIARrIghPDQQCQCABKAIIIgUgBEECdGoiCiAGQQJ0aiIDIAUgAkECdGoiCUYEQEEAIQUMAQtBACEFA0AgA0EEaiEGIAEoAgQhByABKAIQIQsgAygCACEMQQAhA0EAIQQCfwNAQQEhAiADIAtqLQAAIAwgBxA3Ig1B/wFxQQJHBEAgBCECQQEgDUEBcQ0CGgsgAiEEIANBAWoiA0EDRw0AC0ECQQAgAkEBcRsLIgdBAXFFIAdBAkZyRQRAIAVBAWohBSAGIgMgCUcNAQsLIAEoAhghBgsgASAFIAZqIgI2AhggCCACSQ0CQQIhAyAKIAJBAnRqIgYgCUYNBEEAIQUDQCAGIgJBBGohBiABKAIEIQggASgCECEKIAIoAgAhB0EAIQNBACEEAn8DQEEBIQIgAyAKai0AACAHIAgQNyILQf8BcUECRwRAIAQhAkEBIAtBAXENAhoLIAIhBCADQQFqIgNBA0cNAAtBAkEAIAJBAXEbCyIHQQJHQQAgB0EBcRtFBEAgBUEBaiEFIAYgCUcNAQwFCwsgBQ0DQQIhAwwECyAEIAIQrQEACyACIAMQqwEACyACIAgQrQEACyABKAIYIgMgBWohAiADIAEoAgAiBCgCBCIHaiIJIAVqIQggASgCFCEGQQEhAyAELQARRQRAIAIgBEEIaigCACAHa0khAwsgASACNgIYIABBCjoAECAAIAU2AgwgACAINgIIIAAgCTYCBCAAIAY2AgAgASAGQQFqNgIUCyAAIAM6ABELiQUCC38DfiMAQUBqIgYkACAGIAI2AgxCpcaIocicp/lLIQ9BASEFIAIhBANAIA8gBK1C/wGDhUKzg4CAgCB+IQ8gBUEERkUEQCAGQQxqIAVqIAVBAWohBS0AACEEDAELCyAPQhmIpyIMQf8AcSIEQQh0IARyIgRBEHQgBHIhDSABKAIAIgggD6dxIQUgASgCCCEJIAEoAgQhBAJAA0ACQCAEIAVqKAAAIgsgDXMiB0F/cyAHQf/9+3dqcUGAgYKEeHEiB0UNAANAIAIgCSAHaEEDdiAFaiAIcSIOQShsaigCAEcEQCAHQX9qIAdxIgcNAQwCCwsgCSAOQShsaiIBKQMIIQ8gASADKQMANwMIIAAgDzcDACABQSBqIgIpAwAhDyACIANBGGopAwA3AwAgAUEYaiICKQMAIRAgAiADKQMQNwMAIAFBEGoiASkDACERIAEgAykDCDcDACAAQRhqIA83AwAgACAQNwMQIAAgETcDCAwCCyAKQQRqIgogBWogCHEhBSALIAtBAXRxQYCBgoR4cUUNAAsgBkEoaiADQRhqKQIANwIAIAZBIGogA0EQaikCADcCACAGQRhqIANBCGopAgA3AgAgBiADKQIANwIQIAQgASAPEJwBIgVqLQAAQQFxIQMgASgCDCIHIANFckUEQCAGQTBqIAEQEiABIA8QnAEhBSABKAIAIQggASgCCCEJIAEoAgwhByABKAIEIQQLIAEgByADazYCDCAEIAVqIAxB/wBxIgM6AAAgCCAFQXxqcSAEakEEaiADOgAAIAkgBUEobGoiAyACNgIAIANBBGogBkEMakEkEL8BGiABIAEoAhBBAWo2AhAgAEECOgAcCyAGQUBrJAAL2QQBEX8jAEEQayIIJAACQCABKAIEIgJFBEAgAEEANgIADAELIAEgAkF/ajYCBCABIAEoAgAiBEEEajYCACABKAIIIQcCQAJAAkACQAJ/IAIgByAHIAJLGyABKAIQIgVFDQAaA0AgAiAGaiIDQQFGDQIgASAEQQhqNgIAIAEgBSAGakF/ajYCECABIANBfmo2AgQgBEEEaiEEIAUgBkF/aiIGag0ACyACIAZqIgIgByAHIAJLGwsiB0EBaiIGQQJPBEADQCAHIAZBf2oiAkkNBSABKAIMIQkgCEKlxoihyJyn+Us3AwggBCACIAhBCGoQdCAIKAIIIgVBGXYiA0EIdCADciIDQRB0IANyIQ8gAkECdCEQIAkoAgAiCyAFcSEFIAkoAgQhEUEAIQwDQCAFIBFqKAAAIg0gD3MiA0F/cyADQf/9+3dqcUGAgYKEeHEiAwRAIAkoAgghDgNAIA4gA2hBA3YgBWogC3EiEkEYbGoiCigCCCACRgRAIAQgCigCACIKRg0HIAQgCiAQELoBRQ0HCyADQX9qIANxIgMNAAsLIAxBBGoiDCAFaiALcSEFIA0gDUEBdHFBgIGChHhxRQ0ACyACIQYgAkEBSw0ACwsgB0UNAiAAQQE2AgQgACAENgIAIABBDGpBATYCACAAQQhqIAQ2AgAMBAsgAEEANgIADAMLIAEgBkF+ajYCECAAIAI2AgQgACAENgIAIABBDGogDiASQRhsaiIBKAIUNgIAIABBCGogASgCDDYCAAwCC0EBQQAQqwEACyACIAcQqwEACyAIQRBqJAALwQQCDX8BfiMAQSBrIgckACABQX9qIQwgAUEySSENQQEhAwJAAkACfwNAAn9BACADIAFPDQAaQQEgACADQQxsaiIEQQMgBEF0akEDEJkBQf8BcUH/AUYNABoDQEEBIAMgDEYNAxogA0EBaiEDIARBDGoiAkEDIARBAxCZASACIQRB/wFxQf8BRw0ACyADIAFJCyABIANGIA0gASADRnINARogA0F/aiICIAFPDQJFDQMgCkEBaiEKIAAgA0EMbGoiBSkCACEPIAUgACACQQxsaiICKQIANwIAIAIgDzcCACAFQQhqIgYoAgAhBCAGIAJBCGoiAigCADYCACACIAQ2AgAgACADEE8CQCABIANrIgtBAkkNACAFQQxqIghBAyAFQQMQmQFB/wFxQf8BRw0AIAUpAgAhDyAFIAgpAgA3AgAgB0EYaiIOIAYoAgA2AgAgBiAIQQhqKAIANgIAIAcgDzcDEAJAIAtBA0kNACAFQRhqIglBAyAHQRBqQQMQmQFB/wFxQf8BRw0AQQMhAkECIQYDQCAGQQxsIAVqIghBdGoiBCAJKQIANwIAIARBCGogCUEIaigCADYCACACIAtPDQEgAkEBaiIEIAJJDQEgAkEMbCEJIAIhBiAEIQIgBSAJaiIJQQMgB0EQakEDEJkBQf8BcUH/AUYNAAsLIAggBykDEDcCACAIQQhqIA4oAgA2AgALIApBBUcNAAtBAAsgB0EgaiQADwtBsKfCACACIAEQrAEAC0HAp8IAIAMgARCsAQAL2QQCD38BfiMAQTBrIgQkACAEQqXGiKHInKf5SzcDCCACKAIAIgogAigCCCIMIARBCGoQdCAEKQMIIhNCGYinIg5B/wBxIgVBCHQgBXIiBUEQdCAFciEPIAxBAnQhECABKAIAIgggE6dxIQcgASgCCCEJIAEoAgQhBQJAA0ACQCAFIAdqKAAAIg0gD3MiBkF/cyAGQf/9+3dqcUGAgYKEeHEiBkUNAANAAkAgCSAGaEEDdiAHaiAIcSIRQRhsaiILKAIIIAxGBEAgCiALKAIAIgtGDQEgCiALIBAQugFFDQELIAZBf2ogBnEiBg0BDAILCyAAIAkgEUEYbGoiASkCDDcCACABIAMpAgA3AgwgAEEIaiABQRRqIgAoAgA2AgAgACADQQhqKAIANgIAIAIoAgRFDQIgChAhDAILIBJBBGoiEiAHaiAIcSEHIA0gDUEBdHFBgIGChHhxRQ0ACyAEQRBqIgcgAkEIaigCADYCACAEQRxqIANBCGooAgA2AgAgBCACKQIANwMIIAQgAykCADcCFCAFIAEgExCcASICai0AAEEBcSEDIAEgASgCDCIGIANFcgR/IAYFIARBIGogARAPIAEgExCcASECIAEoAgAhCCABKAIIIQkgASgCBCEFIAEoAgwLIANrNgIMIAIgBWogDkH/AHEiAzoAACAIIAJBfGpxIAVqQQRqIAM6AAAgCSACQRhsaiICQRBqIARBGGopAwA3AgAgAkEIaiAHKQMANwIAIAIgBCkDCDcCACABIAEoAhBBAWo2AhAgAEEANgIACyAEQTBqJAALpQQBFH8CQCACQQF1IgggAkkEQEEAIAAoAhhrIRQgACgCECISIAAoAgRqQX9qIQkgAEEMaiAAQQhqIAAoAgBBAUYbIRUgAiERA0ACQAJAIAEgCEEYbGoiFigCBCIHIARrRQ0AIBQgBGshF0EAIARrIQsgByEMIAkhDSASIQ4gBCEFAkACQAJAA0BBfyEPIA4gF2pFDQMgCyAOakF/aiAVKAIAIg9PDQEgCyAMakF/aiAHTw0CIAsgDWotAAAgFigCACALaiAMakF/ai0AAGsiDw0DIA1Bf2ohDSAOQX9qIQ4gBUEBaiEFIAsgDEF/aiIMag0ACyAFIQQMAwtBqMHCACAEQX9zIA5qIA8QrAEAC0G4wcIAIARBf3MgDGogBxCsAQALIAUhBAwBC0EAIQ8LIAogBCAPQQBIIgcbIQoCQCAIIBEgBxsiESAGIAggBxsiBmsiDUECTg0AIBMgBiARRiAGQQBKcnJBASETQQFxRQ0AAn8DQCAGIAJPDQUCQCAKIAEgBkEYbGoiCSgCBCIFTwRAIAAgEiAFayIENgIQIAkoAhAiBUUNASAFIAAgAyAJQRRqKAIAKAIMEQMAIAAgBDYCEA0BC0EAIAkoAggiBkEASA0CGgwBCwsgASAGQRhsaigCDAsPCyAKIAQgECAHGyIQIAogEEkbIQQgDUEBdSAGaiIIIAJJDQALC0GYwcIAIAggAhCsAQALQcjBwgAgBiACEKwBAAvPBQIEfwF+QQEgASgCGEEnIAFBHGooAgAoAhARAQBFBEBBAiECAkACQAJAIAAoAgAiAEF3aiIEQR5LBEAgAEHcAEcNAQwCC0H0ACEDAkACQCAEQQFrDh4BAgIAAgICAgICAgICAgICAgICAgICAgIDAgICAgMEC0HyACEDDAMLQe4AIQMMAgsCQAJ+QYCGwAAgABBbRQRAAkACQCAAQYCABE8EQCAAQYCACEkNASAAQZD8R2pBkPwLSSAAQeKLdGpB4o0sSXIgAEGfqHRqQZ8YSSAAQd7idGpBDklyciAAQf7//wBxQZ7wCkYgAEGpsnVqQSlJcnINAiAAQcuRdWpBCksNBQwCCyAAQfiRwABBKUHKksAAQaUCQe+UwABBugIQSkUNAQwECyAAQamXwABBI0Hvl8AAQaYBQZWZwABBmAMQSg0DCyAAQQFyZ0ECdkEHc61CgICAgNAAhAwBCyAAQQFyZ0ECdkEHc61CgICAgNAAhAshBkEDIQIMAQtBASECCyAAIQMLA0AgAiEEQdwAIQBBASECAkACQAJAAkAgBEEBaw4DAgMAAQsCQAJAAkACQAJAIAZCIIinQf8BcUEBaw4FBAMCAQAFCyAGQv////+PYINCgICAgMAAhCEGQQMhAgwGCyAGQv////+PYINCgICAgDCEIQZB9QAhAEEDIQIMBQsgBkL/////j2CDQoCAgIAghCEGQfsAIQBBAyECDAQLIAMgBqciAkECdEEccXZBD3EiAEEwciAAQdcAaiAAQQpJGyEAIAIEQCAGQn98Qv////8PgyAGQoCAgIBwg4QhBkEDIQIMBAsgBkL/////j2CDQoCAgIAQhCEGQQMhAgwDCyAGQv////+PYIMhBkH9ACEAQQMhAgwCCyABKAIYQScgASgCHCgCEBEBAA8LQQAhAiADIQALIAEoAhggACABKAIcKAIQEQEARQ0ACwsLhwUBBH8gACABaiECAkACQAJAIAAoAgQiA0EBcQ0AIANBA3FFDQEgACgCACIDIAFqIQEgACADayIAQdjPwgAoAgBGBEAgAigCBEEDcUEDRw0BQdDPwgAgATYCACACIAIoAgRBfnE2AgQgACABQQFyNgIEIAIgATYCAA8LIANBgAJPBEAgABBaDAELIAAoAgwiBCAAKAIIIgVHBEAgBSAENgIMIAQgBTYCCAwBC0HAzMIAQcDMwgAoAgBBfiADQQN2d3E2AgALIAIoAgQiA0ECcQRAIAIgA0F+cTYCBCAAIAFBAXI2AgQgACABaiABNgIADAILAkAgAkHcz8IAKAIARwRAQdjPwgAoAgAgAkcNAUHYz8IAIAA2AgBB0M/CAEHQz8IAKAIAIAFqIgE2AgAgACABQQFyNgIEIAAgAWogATYCAA8LQdzPwgAgADYCAEHUz8IAQdTPwgAoAgAgAWoiATYCACAAIAFBAXI2AgQgAEHYz8IAKAIARw0BQdDPwgBBADYCAEHYz8IAQQA2AgAPCyADQXhxIgQgAWohAQJAIARBgAJPBEAgAhBaDAELIAIoAgwiBCACKAIIIgJHBEAgAiAENgIMIAQgAjYCCAwBC0HAzMIAQcDMwgAoAgBBfiADQQN2d3E2AgALIAAgAUEBcjYCBCAAIAFqIAE2AgAgAEHYz8IAKAIARw0BQdDPwgAgATYCAAsPCyABQYACTwRAIAAgARBSDwsgAUEDdiICQQN0QcjMwgBqIQECf0HAzMIAKAIAIgNBASACQR9xdCICcQRAIAEoAggMAQtBwMzCACACIANyNgIAIAELIQIgASAANgIIIAIgADYCDCAAIAE2AgwgACACNgIIC80EAgl/AX4jAEEQayIEJAAgBCABNgIAIAEoAhghAyAEIAEtABw6AAcgBCAENgIMIAQgBEEHajYCCAJAAkAgAS0AHUUEQCABQQxqIQggA0EBdCEJA0AgASgCBCEFIAEoAgAhAgJAA0AgAiAFRg0BIAEgAkEEaiIHNgIAIAIoAgAgASABKAIIIgpBAWo2AgggByECRQ0ACyABKAIUIgIgASgCEEYEQCACQQFqIgUgAkkNBSACQQF0IgYgBSAGIAVLGyIFQf////8BcSAFRw0FIAVBA3QiBkEASA0FAn8gAkUEQCAGQQQQ1wEMAQsgCCgCACACQQN0QQQgBhDUAQsiAkUNBCABIAU2AhAgASACNgIMIAEoAhQhAgsgASgCDCACQQN0aiICIAdBfGo2AgQgAiAKNgIAIAEgASgCFEEBaiICNgIUIAIgCUkNASAEQQhqIAgQCyABKAIUIANJDQEgASADNgIUDAELCyAEQQhqIAgQCyABKAIUIgIgA08EQCABIAM2AhQgAyECCyACQQF2IgcEQCACQQN0IAgoAgAiAmpBeGohAwNAIAIpAgAhCyACIAMpAgA3AgAgAyALNwIAIAJBCGohAiADQXhqIQMgB0F/aiIHDQALCyABQQE6AB0LAkAgAUEUaigCACICRQRAQQAhAgwBCyABIAJBf2oiAjYCFCABKAIMIAJBA3RqIgEoAgQhAiABKAIAIQELIAAgAjYCBCAAIAE2AgAgBEEQaiQADwsgBkEEQZTQwgAoAgAiAEECIAAbEQAAAAsQ0wEAC5gEAQh/IwBBEGsiAyQAIAMgADYCACAAKAIUIQIgAyAALQAYOgAHIAMgAzYCDCADIANBB2o2AggCQAJAIAAtABlFBEAgAEEIaiEHIAAoAgAiBCAAKAIERwRAIAJBAXQhCCAAQRBqKAIAIQEDQCAAIARBPGo2AgAgACgCDCABRgRAIAFBAWoiBiABSQ0FIAFBAXQiBSAGIAUgBksbIgZB/////wNxIAZHDQUgBkECdCIFQQBIDQUCfyABRQRAIAVBBBDXAQwBCyAHKAIAIAFBAnRBBCAFENQBCyIBRQ0EIAAgBjYCDCAAIAE2AgggACgCECEBCyAAKAIIIAFBAnRqIAQ2AgAgACAAKAIQQQFqIgE2AhACQCABIAhJDQAgA0EIaiAHEA0gACgCECIBIAJJDQAgACACNgIQIAIhAQsgACgCACIEIAAoAgRHDQALCyADQQhqIAcQDSAAQRBqKAIAIgEgAk8EQCAAIAI2AhAgAiEBCyABQQF2IgUEQCABQQJ0IAcoAgAiAWpBfGohBANAIAEoAgAhAiABIAQoAgA2AgAgBCACNgIAIAFBBGohASAEQXxqIQQgBUF/aiIFDQALCyAAQQE6ABkLAn9BACAAQRBqKAIAIgJFDQAaIAAgAkF/aiICNgIQIAAoAgggAkECdGooAgALIANBEGokAA8LIAVBBEGU0MIAKAIAIgBBAiAAGxEAAAALENMBAAubBAIPfwF+IwBBIGsiAyQAIANCpcaIocicp/lLNwMQIAEoAgAiByABKAIIIgsgA0EQahB0IAMpAxAiEkIZiKciDUH/AHEiBEEIdCAEciIEQRB0IARyIQ4gC0ECdCEPIAAoAgAiCCASp3EhBSAAKAIIIQkgACgCBCEEAkADQAJAIAQgBWooAAAiDCAOcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAJIAZoQQN2IAVqIAhxIhBBBHRqIgooAgggC0YEQCAHIAooAgAiCkYNASAHIAogDxC6AUUNAQsgBkF/aiAGcSIGDQEMAgsLIAkgEEEEdGogAjoADCABKAIERQ0CIAcQIQwCCyARQQRqIhEgBWogCHEhBSAMIAxBAXRxQYCBgoR4cUUNAAsgA0EIaiIGIAFBCGooAgA2AgAgAyABKQIANwMAIAQgACASEJwBIgFqLQAAQQFxIQUgACAAKAIMIgcgBUVyBH8gBwUgA0EQaiAAEBMgACASEJwBIQEgACgCACEIIAAoAgghCSAAKAIEIQQgACgCDAsgBWs2AgwgASAEaiANQf8AcSIFOgAAIAggAUF8anEgBGpBBGogBToAACAJIAFBBHRqIgFBCGogBigCADYCACABIAMpAwA3AgAgASACOgAMIAEgAy8AEDsADSABQQ9qIANBEmotAAA6AAAgACAAKAIQQQFqNgIQCyADQSBqJAAL9gMBB38jAEEgayIEJAACQAJAIAAoAhAgACgCGE0NACAAEJYBIAAoAhAhAiAAKAIEIQMgBCAAQQxqIABBCGogACgCAEEBRhsoAgAiBTYCBCAEIAM2AgAgBCACNgIIIAQgBTYCDAJAIAJFIAIgBUZyRQRAIAUgAk0NASACIANqLAAAQb9/TA0BCyACIAVGDQECQCACIANqIgYsAAAiCEF/SgRAIAhB/wFxIQIMAQsCfyADIAVqIgIgBkEBakYEQCACDAELIAYtAAFBP3EhByAGQQJqCyEDIAhBH3EhBiAIQf8BcUHfAU0EQCAHIAZBBnRyIQIMAQsCfyACIANGBEAgAiEFQQAMAQsgA0EBaiEFIAMtAABBP3ELIAdBBnRyIQcgCEH/AXFB8AFJBEAgByAGQQx0ciECDAELQQAhAyACIAVHBH8gBS0AAEE/cQVBAAsgBkESdEGAgPAAcSAHQQZ0cnIiAkGAgMQARg0DCyAAEJQBIAJB4gBJIAJB9ABLcg0BIAJB4gBrIgVBA3YiAkEDSQRAQQAhAyABIAJqLQAAQQEgBUEHcXRxRQ0DIAAQlgFBASEDDAMLQcjAwgAgAkEDEKwBAAsgBCAEQQxqNgIYIAQgBEEIajYCFCAEIAQ2AhAgBEEQahDMAQALQQAhAwsgBEEgaiQAIAML6gMBB38jAEEgayIDJAACQAJAIAAoAhAiASAAKAIUTw0AIAAoAgQhAiADIABBDGogAEEIaiAAKAIAQQFGGygCACIENgIEIAMgAjYCACADIAE2AgggAyAENgIMAkAgAUUgASAERnJFBEAgBCABTQ0BIAEgAmosAABBv39MDQELIAEgBEYNAQJAIAEgAmoiBSwAACIHQX9KBEAgB0H/AXEhAQwBCwJ/IAIgBGoiASAFQQFqRgRAIAEMAQsgBS0AAUE/cSEGIAVBAmoLIQIgB0EfcSEFIAdB/wFxQd8BTQRAIAYgBUEGdHIhAQwBCwJ/IAEgAkYEQCABIQRBAAwBCyACQQFqIQQgAi0AAEE/cQsgBkEGdHIhBiAHQf8BcUHwAUkEQCAGIAVBDHRyIQEMAQtBACECIAEgBEcEfyAELQAAQT9xBUEACyAFQRJ0QYCA8ABxIAZBBnRyciIBQYCAxABGDQMLIAFB4QBJIAFB/AFLcg0BIAFB4QBrIgRBA3YiAUEUSQRAQQAhAiABQZTDwgBqLQAAQQEgBEEHcXRxRQ0DIAAQlAFBASECDAMLQci/wgAgAUEUEKwBAAsgAyADQQxqNgIYIAMgA0EIajYCFCADIAM2AhAgA0EQahDMAQALQQAhAgsgA0EgaiQAIAIL5QMBB38jAEEgayICJAACQAJAAkAgACgCECIBIAAoAhRPDQAgACgCBCEDIAIgAEEMaiAAQQhqIAAoAgBBAUYbKAIAIgQ2AgQgAiADNgIAIAIgATYCCCACIAQ2AgwgAUUgASAERnJFBEAgBCABTQ0DIAEgA2osAABBv39MDQMLIAEgBEYNAAJAIAEgA2oiBSwAACIHQX9KBEAgB0H/AXEhAQwBCwJ/IAMgBGoiASAFQQFqRgRAIAEMAQsgBS0AAUE/cSEGIAVBAmoLIQMgB0EfcSEFIAdB/wFxQd8BTQRAIAYgBUEGdHIhAQwBCwJ/IAEgA0YEQCABIQRBAAwBCyADQQFqIQQgAy0AAEE/cQsgBkEGdHIhAyAHQf8BcUHwAUkEQCADIAVBDHRyIQEMAQtBACEGIAEgBEcEfyAELQAAQT9xBUEACyAFQRJ0QYCA8ABxIANBBnRyciIBQYCAxABGDQELIAFB4QBJIAFB/AFLckUEQCABQeEAayIEQQN2IgFBFE8NAkEAIQYgAUGUw8IAai0AAEEBIARBB3F0cQ0BCyAAEJQBQQEhBgsgAkEgaiQAIAYPC0HYwMIAIAFBFBCsAQALIAIgAkEMajYCGCACIAJBCGo2AhQgAiACNgIQIAJBEGoQzAEAC/4DAQZ/IwBBMGsiBSQAIAVBCGogAEEMaiIHIABBCGoiCCAAKAIAQQFGGygCABC2ASAFQQA2AhggBSAFKQMINwMQIAAoAgAhCSAAKAIEIQYCQAJAAkAgAUUNACAHIAggCUEBRhsoAgAiCiABRg0AIAogAU0NASABIAZqLAAAQb9/TA0BCyACRSAHIAggCRsoAgAiByACRnJFBEAgByACTQ0CIAIgBmosAABBv39MDQILIAVBEGpBACABEJUBIAUgBSgCGCIIIAFqIgk2AhggCCAFKAIQaiAGIAEQvwEaIAVBEGogCSAEEJUBIAUgBSgCGCIIIARqIgk2AhggCCAFKAIQaiADIAQQvwEaIAVBEGogCSAGIAdqIAIgBmoiBmsiAxCVASAFIAUoAhgiByADajYCGCAHIAUoAhBqIAYgAxC/ARogACABIAJrIARqIgQgACgCFGo2AhQCQAJAIAAoAhAiAyACSQRAIAMgAUsNAQwCCyADIARqIQELIAAgATYCEAsgAEEEaiEBIAVBKGoiAiAFQRhqKAIANgIAIAUgBSkDEDcDIAJAIAAoAgBFDQAgAEEIaigCAEUNACABKAIAECELIABBATYCACABIAUpAyA3AgAgAUEIaiACKAIANgIAIAVBMGokAA8LIAYgCkEAIAEQGgALIAYgB0EAIAIQGgALkwQBAX9BASEDAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAEH/AXFBAWsOBwABAgMEBQYMC0EAIQMgAUG/AUsNCyABQQZ2QdCswABqLQAAIgBBAU0EQCAAQQN0QdiswABqKQMAQgEgAUE/ca2Gg0IAUg8LQZCswAAgAEECEKwBAAsgAUF3akEFSQ0JIAFBIEYNCkEAIQMgAUGAAUkgAUG/4ABLcg0KIAFBBnZBzarAAGotAAAiAEEFTQRAIABBA3RBoKzAAGopAwBCASABQT9xrYaDQgBSDwtBkKzAACAAQQYQrAEAC0EAIQMgAUGQwABMBEAgAUFfaiIAQQ1NDQUgAUFGaiIAQQVLDQogAEECaw4DCgoKCQsgAUHvv39qQQRJDQggAUHEv39qIgBBDUtBASAAdEGB8ABxRXINBwwIC0EAIQMgAUFfcUG/f2pBGkkNCEEBIQMgAUGAAUkNCEHYwcAAIAEQW0EBcw8LQQAhAyABQV9xQb9/akEaSQ0HIAFBgAFJDQNB2MHAACABEFsNB0EBIQNB6KzAACABEFtFDQcMBAtBAiEDIAIgARBYQf8BcSIAQQhGDQYgAEEGRg8LQQIhAyACIAEQWEH/AXEiAEEIRg0FIABBB0YPCyAAQQFrDgoEBAQEAwQDAwQEAwtBASEDIAFBUGpBCk8NAwtBAA8LIAFBpsAARw0BC0EBDwsgAwvuAwEHfyMAQTBrIgQkACABKAJkRQRAAkAgAUF/NgJkIAEoAnQNACABQegAaiEHIAFBgAFqQQA2AgAgAUHwAGpCgICAgHA3AgAgBEEANgIoIAQgAUFAazYCJCAEQQI2AiAgBCADNgIcIAQgAjYCGCABQfgAaiEIIARBCGogBEEYahAqIAQoAggiBQRAA0AgBCgCECEGIAQoAhQhCSAHIAUgBSAEKAIMIgpBAnRqEL0BIAggBiAGIAlBAnRqEL0BIAkgCmsiBgRAA0AgASgCcCIFIAEoAmxGBEAgByAFQQEQhgEgASgCcCEFCyABKAJoIAVBAnRqQQA2AgAgASABKAJwQQFqNgJwIAZBf2oiBg0ACwsgBEEIaiAEQRhqECogBCgCCCIFDQALCwJAAkAgAyABKAKAAUYEQCABKAJ4IgUgAkYNASAFIAIgA0ECdBC6AUUNAQsgBEEYaiAHELABIARBCGogCBCwASAEQSxqIARBEGooAgA2AgAgBCAEKQMINwIkIABBEGogBEEoaikDADcCACAAQQhqIARBIGopAwA3AgAgACAEKQMYNwIADAELIABBADYCAAsgASABKAJ0QQFqNgJ0IAEgASgCZEEBajYCZCAEQTBqJAAPCwtBlr/CAEEQIARBGGpBuK/CABCbAQALygMCDn8BfiABQX9qIQsgAEEMaiEMIAFBMkkhDUEBIQMCQAJAAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0EDdCIGaiICQXxqKAIAKAIAIAIoAgQoAgAiAkkNABogBiAMaiEFA0BBASADIAtGDQMaIANBAWohAyACIAUoAgAoAgAiAk8gBUEIaiEFDQALIAMgAUkLIAEgA0YgDSABIANGcg0BGiADQX9qIgIgAU8NAkUNAyAIQQFqIQggACACQQN0aiICKQIAIRAgAiAAIANBA3RqIgQpAgA3AgAgBCAQNwIAIAAgAxBtAkAgASADayIKQQJJDQAgBCgCBCIJKAIAIARBDGooAgAoAgBPDQAgBCgCACEOIAQgBCkCCDcCACAEQQhqIQcCQCAKQQNJDQAgCSgCACAEQRRqKAIAKAIATw0AQQMhAkECIQUDQCAEIAVBA3RqIgdBeGogBykCADcCACACIApPDQEgAkEBaiIGIAJJDQEgAkEDdCEPIAIhBSAGIQIgCSgCACAEIA9qKAIEKAIASQ0ACwsgByAJNgIEIAcgDjYCAAsgCEEFRw0AC0EACw8LQbCnwgAgAiABEKwBAAtBwKfCACADIAEQrAEAC8wDAQ9/IAAoAgQhDCAAKAIUIQ0gACgCECEKQQMhBiAAQQxqIABBCGogACgCAEEBRhshDkEGIQkCQANAAkACQCADIAZBGGwiAkG8x8IAaigCACIFTw0AIAJBuMfCAGohDwJAA0AgDSADIApqIgJGBEBBfyECDAQLIAIgDigCACIQTw0BIAIgDGotAAAgDygCACADai0AAGsiAg0DIANBAWoiAyAFRw0ACyAFIQMMAQtB+MDCACACIBAQrAEAC0EAIQILIAcgAyACQQBIIgUbIQcCQCAGIAkgBRsiCSAEIAYgBRsiBGsiAkECTg0AIAsgBCAJRiAEQQBKcnJBASELQQFxRQ0AAn8DQCAEQQVLDQQCQCAHIARBGGwiA0G8x8IAaigCACIFTwRAIAAgBSAKaiIFNgIQIANByMfCAGooAgAiAkUNASACIAAgASADQczHwgBqKAIAKAIMEQMAIAAgBTYCEA0BC0EAIANBwMfCAGooAgAiBEEASA0CGgwBCwsgBEEYbEHEx8IAaigCAAsPCyADIAggBRsiCCAHIAcgCEsbIQMgAkEBdSAEaiIGQQZJDQALQejAwgAgBkEGEKwBAAtBiMHCACAEQQYQrAEAC90DAQV/AkAgAUEgaigCACIERQ0AIARBAnQhBSABKAIYIgYhAwNAAkAgAygCACICQb9/akEaSQ0AIAJBgAFPBEBB+OXAACACEFsNAQsgA0EEaiEDIAVBfGoiBQ0BDAILCyAEQQJ0IQUDQCAGKAIAIgMhBCAGIAMgA0EAQbcFIANB7DxJGyICIAJB2wJqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQa4BaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkHXAGoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBK2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBFmoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBC2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBBWoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBA2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBAWoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBAWoiAiACQQR0QdD4wABqKAIAIANLG0EEdCICQdD4wABqKAIARgRAIAJB1PjAAGooAgAhBAsgBCAEQYCAxABGGzYCACAGQQRqIQYgBUF8aiIFDQALCyAAIAFBMBC/ARoLyAMCC38BfiMAQRBrIgYkACAGIAE2AgBCpcaIocicp/lLIQ5BASEEIAEhAwNAIA4gA61C/wGDhUKzg4CAgCB+IQ4gBEEERwRAIAQgBmogBEEBaiEELQAAIQMMAQsLIA5CGYinIgxB/wBxIgNBCHQgA3IiA0EQdCADciENIAAoAgAiByAOp3EhBCAAKAIIIQkgACgCBCEDAkADQAJAIAMgBGooAAAiCiANcyIFQX9zIAVB//37d2pxQYCBgoR4cSIFRQ0AA0AgASAJIAVoQQN2IARqIAdxQQN0aiILKAIARwRAIAVBf2ogBXEiBQ0BDAILCyALLQAEGiALIAI6AAQMAgsgCEEEaiIIIARqIAdxIQQgCiAKQQF0cUGAgYKEeHFFDQALIAMgACAOEJwBIgRqLQAAQQFxIQUgACgCDCIIIAVFckUEQCAGIAAQESAAIA4QnAEhBCAAKAIAIQcgACgCCCEJIAAoAgwhCCAAKAIEIQMLIAAgCCAFazYCDCADIARqIAxB/wBxIgU6AAAgByAEQXxqcSADakEEaiAFOgAAIAkgBEEDdGoiAyACOgAEIAMgATYCACAAIAAoAhBBAWo2AhALIAZBEGokAAuqAwEEfyMAQUBqIgMkAAJAAkACQCABKAIIIgVBAU0EQCAFQQFrDQMgA0EIaiACIAEoAgwiBCABQRRqKAIAEGkgAUEMaiEFIAMoAggNASABQSBqKAIAIQQMAgsQ1gEACyADQSBqIgYgA0EQaigCADYCACADIAMpAwg3AxggA0EoaiADQRhqELABIAFBEGooAgAEQCAEECELIAUgAykDKDcCACAFQQhqIANBMGoiBCgCADYCACAEIAYoAgA2AgAgAyADKQMYNwMoIAFBGGohBCABQRxqKAIABEAgBCgCABAhCyAEIAMpAyg3AgAgBEEIaiADQTBqKAIANgIAIAEoAgBBCGogAUEgaigCACIENgIACyADQShqIAIgASgCGCICIAQQOCADKAIoBEAgAUEQaigCAARAIAUoAgAQIQsgBSADKQMoNwIAIAVBCGogA0EwaigCADYCACABQRxqKAIABEAgAhAhCyABQRhqIgJBCGogA0E0aiIFQQhqKAIANgIAIAIgBSkCADcCACABKAIAQQhqIAEoAiA2AgALCyAAIAFBMBC/ARogA0FAayQAC7IDAQ5/IAFBf2ohDCAAQQRqIQ0gAUEySSEOQQEhAwJ/A0ACf0EAIAMgAU8NABpBASAAIANBAnQiBGoiBygCACICIAdBfGooAgBJDQAaIAQgDWohBQNAQQEgAyAMRg0DGiADQQFqIQMgBSgCACIEIAJPIAVBBGohBSAEIQINAAsgAyABSQshBSABIANGIA4gASADRnINARoCQAJAAkACQCADQX9qIgIgAUkEQCAFRQ0BIAlBAWohCSAAIAJBAnRqIgIoAgAhBCACIAAgA0ECdGoiBigCADYCACAGIAQ2AgAgACADEHUgASADayILQQJJDQQgBigCBCICIAYoAgAiCk8NBCAGQQRqIQQgBiACNgIAIAtBA0kNAyAGKAIIIgggCk8NAyAGQQhqIQVBAyECQQIhBwNAIAdBAnQgBmpBfGogCDYCACACIAtPDQMgAkEBaiIIIAJJDQMgBSEEIAYgAkECdGoiDyEFIAIhByAIIQIgDygCACIIIApJDQALDAMLQbCnwgAgAiABEKwBAAtBwKfCACADIAEQrAEACyAFIQQLIAQgCjYCAAsgCUEFRw0AC0EACwuzAwENfyABQX9qIQsgAEEEaiEMIAFBMkkhDUEBIQMCQAJAAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0ECdCIHaiIEKAIAIgIgBEF8aigCABC3AQ0AGiAHIAxqIQYDQEEBIAMgC0YNAxogA0EBaiEDIAYoAgAiBCACELcBIAZBBGohBiAEIQJFDQALIAMgAUkLIAEgA0YgDSABIANGcg0BGiADQX9qIgIgAU8NAkUNAyAIQQFqIQggACACQQJ0aiIEKAIAIQIgBCAAIANBAnRqIgUoAgA2AgAgBSACNgIAIAAgAxCAAQJAIAEgA2siCkECSQ0AIAUoAgQiAiAFKAIAIgkQtwFFDQAgBUEEaiEGIAUgAjYCAAJAIApBA0kNACAFKAIIIAkQtwFFDQAgBUEIaiEEQQMhAkECIQcDQCAHQQJ0IAVqQXxqIAQiBigCADYCACACIApPDQEgAkEBaiIEIAJJDQEgAkECdCEOIAIhByAEIQIgBSAOaiIEKAIAIAkQtwENAAsLIAYgCTYCAAsgCEEFRw0AC0EACw8LQbCnwgAgAiABEKwBAAtBwKfCACADIAEQrAEAC7wDAgt/A34jAEEgayIFJAACQAJAAkAgAUECSQ0AIABBKGoiAygCACAAKAIIIghPDQAgACkDACENIAAgACkDIDcDACAAQRRqKQIAIQ4gACkCDCEPIABBEGogAEEwaikDADcDACAAQQhqIAMpAwA3AwAgAEEcaigCACEDIABBGGogAEE4aikDADcDACAFQRBqIgogDjcDACAFQRhqIgsgAzYCACAFIA83AwggAEEgaiECQQMhBkEBIQcCQCABQQNJDQBBAiEJA0AgCSIDIAFPDQMgACADQQV0aiIEKAIIIAhPDQEgA0F/aiICIAFPDQQgACACQQV0aiICIAQpAwA3AwAgAkEYaiAEQRhqKQMANwMAIAJBEGogBEEQaikDADcDACACQQhqIARBCGopAwA3AwAgBiABSQRAIAYhCSAGQQFqIgIgBkkgAiEGIAMhByAEIQINAgwBCwsgAyEHIAQhAgsgAiANNwMAIAAgB0EFdGogCDYCCCACQRxqIAsoAgA2AgAgAkEUaiAKKQMANwIAIAIgBSkDCDcCDAsgBUEgaiQADwtB0KzCACADIAEQrAEAC0HgrMIAIAIgARCsAQALxgMCCH8BfiMAQSBrIgIkAAJAEMYBIgZFDQACQCAGKAIARQRAIAZBfzYCACAGQQRqIAEQVyIFRQ0BIAUoAggiBARAIARBBHQhByAFKAIAQQxqIQEDQCABKAIAIANqIQMgAUEQaiEBIAdBcGoiBw0ACwsgAkEIaiADIARqELYBIAJBADYCGCACIAIoAgw2AhQgAiACKAIIIgQ2AhAgBSgCCCIBBEAgAUEEdCEHIAUoAgBBDGohA0EAIQEDQCADQXhqKAIAIQggAkEQaiABIAMoAgAiBRCVASACIAUgAigCGCIJaiIBNgIYIAkgAigCECIEaiAIIAUQvwEaIAIoAhQgAUYEQCACQRBqIAFBARCVASACKAIQIQQgAigCGCEBCyABIARqQQA6AAAgAiABQQFqIgE2AhggA0EQaiEDIAdBcGoiBw0ACwsgAikCFCEKIAYgBigCAEEBajYCACAERQ0CIAIgCjcCFCACIAQ2AhAgAiACQRBqEJEBIAAgAikDADcCACACQSBqJAAPC0GWv8IAQRAgAkEQakGov8IAEJsBAAtBhcnCAEErQbDJwgAQvgEAC0HQvsIAQcYAIAJBEGpBuL/CABCbAQALnwMBA38jAEHQAGsiBSQAIAUgAjYCQCAFIAI2AjwgBSABNgI4IAVBEGogBUE4ahCRASAFKAIUIQcgBSgCECEGIAUgBDYCQCAFIAQ2AjwgBSADNgI4IAVBCGogBUE4ahCRASAFKAIMIQQgBSgCCCEDEMcBIgEEQAJAIAEoAgBFBEAgAUF/NgIAIAFBBGogABBWIgBFDQEgBUEYaiAGIAYgB2oQIyAFQShqIAMgAyAEahAjIAVBQGsgBUEgaigCADYCACAFQcwAaiAFQTBqKAIANgIAIAUgBSkDGDcDOCAFIAUpAyg3AkQgAEGYAWohAiAAKAKcAQRAIAIoAgAQIQsgACgCqAEEQCAAKAKkARAhCyACIAUpAzg3AgAgAkEQaiAFQcgAaikDADcCACACQQhqIAVBQGspAwA3AgAgASABKAIAQQFqNgIAIAQEQCADECELIAcEQCAGECELIAVB0ABqJAAPC0GWv8IAQRAgBUE4akHMqMIAEJsBAAtBhcnCAEErQbDJwgAQvgEAC0HQvsIAQcYAIAVBOGpB3KjCABCbAQALrgMCCn8CfCACKAIEIgcgAUEYaigCAGoiBCADKAIEIgVLBEAgA0EIaigCACEIIAErAwAhDgJAIAJBCGooAgAiCyAHayIJRQRADAELIAggBWsiBkUEQCAOIQ9EAAAAAAAAAAAhDgwBCyAOIA4gCbgiD6JEAAAAAAAAJECiIA8gBrigo5tEAAAAAAAAJECjIg+hRAAAAAAAACRAohCBAUQAAAAAAAAkQKMhDgsgAigCACEMAn9BACACLQAoIgJBCkYNABpBACACQXtqIgJBBEsNABpCgYKEgBAgAq1CA4aIpwshDSAEIAVrIQQgAygCACEGAkAgAy0AKCICQQpGDQAgAkF7aiICQQRLDQBCgYKEgBAgAq1CA4aIpyEKCyAAQQE6AB0gACANOgAcIAAgCTYCGCAAQQA2AhQgACALNgIQIAAgBzYCDCAAIAw2AgggACAPOQMAIABBPWogAS0AHToAACAAQTxqIAo6AAAgAEE4aiAENgIAIABBNGpBADYCACAAQTBqIAg2AgAgAEEsaiAFNgIAIABBKGogBjYCACAAQSBqIA45AwAPCyAAQQI6ABwL5wIBBn8CQAJAIAJBA3EiBEUNAEEEIARrIgRFDQAgAyAEIAQgA0sbIQVBACEEIAFB/wFxIQgDQCAEIAVGDQEgAiAEaiAEQQFqIQQtAAAiBiAIRw0AC0EBIQMgBiABQf8BcUZBAWpBAXEgBGpBf2ohBAwBCyABQf8BcSEIAkACQCADQQhJDQAgBSADQXhqIgZLDQAgCEGBgoQIbCEEA0AgAiAFaiIHQQRqKAIAIARzIglBf3MgCUH//ft3anEgBygCACAEcyIHQX9zIAdB//37d2pxckGAgYKEeHFFBEAgBUEIaiIFIAZNDQELCyAFIANLDQELIAIgBWohAiADIAVrIQZBACEDQQAhBAJAA0AgBCAGRg0BIAIgBGogBEEBaiEELQAAIgcgCEcNAAtBASEDIAcgAUH/AXFGQQFqQQFxIARqQX9qIQQLIAQgBWohBAwBCyAFIAMQrQEACyAAIAQ2AgQgACADNgIAC+ICAQV/AkBBzf97IABBECAAQRBLGyIAayABTQ0AIABBECABQQtqQXhxIAFBC0kbIgRqQQxqEAMiAkUNACACQXhqIQECQCAAQX9qIgMgAnFFBEAgASEADAELIAJBfGoiBSgCACIGQXhxIAIgA2pBACAAa3FBeGoiAiAAIAJqIAIgAWtBEEsbIgAgAWsiAmshAyAGQQNxBEAgACADIAAoAgRBAXFyQQJyNgIEIAAgA2oiAyADKAIEQQFyNgIEIAUgAiAFKAIAQQFxckECcjYCACAAIAAoAgRBAXI2AgQgASACEC8MAQsgASgCACEBIAAgAzYCBCAAIAEgAmo2AgALAkAgACgCBCIBQQNxRQ0AIAFBeHEiAiAEQRBqTQ0AIAAgBCABQQFxckECcjYCBCAAIARqIgEgAiAEayIEQQNyNgIEIAAgAmoiAiACKAIEQQFyNgIEIAEgBBAvCyAAQQhqIQMLIAML9wIBCn8jAEHwAGsiCCQAIABBQGshCwNAIAJBAXQiA0EBciEFAkACQAJAAkAgA0ECaiIHIAFPDQAgBSABTw0BIAAgA0HkAGxqIQlBACEDA0AgA0EEaiIEQShGDQEgAyAJaiEGIAQhA0F/IAZBiAJqKAIAIgQgBkGkAWooAgAiBkcgBCAGSBsiBEUNAAsgBEH/AXFB/wFHDQAgByEFCwJAIAUgAU8NACACIAFPDQIgCyAFQeQAbCIDaiEGIAsgAkHkAGwiAmohCSAAIANqIQcgACACaiEEQQAhAwNAIANBBGoiAkEoRg0BIAMgCWohCiADIAZqIQwgAiEDQX8gDCgCACICIAooAgAiCkcgAiAKSBsiAkUNAAsgAkH/AXFB/wFGDQMLIAhB8ABqJAAPC0GsqMIAIAUgARCsAQALQbyowgAgAiABEKwBAAsgCEEMaiAEQeQAEL8BGiAEIAdB5AAQngEaIAcgCEEMakHkABC/ARogBSECDAALAAv5AgEFfyMAQTBrIgQkAAJAAkAgAEEIaigCACIFIAAoAgQiBk8EQCAFIAJLDQEgBSAGayEHIAAgAygCAAR/IAMoAlQNAyADQX82AlRBACECIANB4ABqIghBADYCACADQdgAaiIAQQAgBxCVASABIAZBAnRqIAEgBUECdGogABBLIAAoAgAhASAEQRBqIgUgCCgCACIANgIAIAQgADYCKCAEQgA3AyAgBCAANgIcIARBADYCGCAEIAE2AgwgBEEANgIIIARBCGogAygCABEHABogBCgCDCEAIARBFGooAgAgBSgCACIHIAQoAggiCEEBRhsiBQRAIAUhBiAAIQEDQCACIAEtAABBwAFxQYABRmohAiABQQFqIQEgBkF/aiIGDQALCyAIRSAHRXJFBEAgABAhCyADIAMoAlRBAWo2AlQgBSACawUgBws2AgwgBEEwaiQADwsgBiAFEK0BAAsgBSACEKsBAAtBlr/CAEEQIARBCGpBuK/CABCbAQAL4gIBC38jAEEQayIGJAAgAEEIaigCACIFIAAoAgQiBE8EQCAFIAJNBEAgBkKlxoihyJyn+Us3AwggASAEQQJ0aiICIAUgBGsiBSAGQQhqEHQgBigCCCIBQRl2IgRBCHQgBHIiBEEQdCAEciEKIAVBAnQhCyADKAIYIgQgAXEhASADQSBqKAIAIQggA0EcaigCACEMA0ACQAJAIAEgDGooAAAiCSAKcyIDQX9zIANB//37d2pxQYCBgoR4cSIDRQ0AA0ACQCAFIAggA2hBA3YgAWogBHEiDUEEdGoiBygCCEYEQCAHKAIAIgcgAkYNASACIAcgCxC6AUUNAQsgA0F/aiADcSIDDQEMAgsLIAggDUEEdGotAAwhAwwBCyAOQQRqIg4gAWogBHEhAUEKIQMgCSAJQQF0cUGAgYKEeHFFDQELCyAAIAM6ABAgBkEQaiQADwsgBSACEKsBAAsgBCAFEK0BAAvdAgEKfyMAQfAAayIHJAACQCABQQJJDQAgAUF+aiEEIAAgAUHkAGxqIgVBuH5qIQYgBUGcf2ohCEFYIQEDQCABQQRqIgNFDQEgASAFaiECIAMhAUF/IAJBoH9qKAIAIgMgAkEEaigCACICRyADIAJIGyIDRQ0ACyADQf8BcUH/AUcNACAHQQhqIAhB5AAQvwEaIAggBkHkABC/ARogACAEQeQAbCIBaiEDAkAgBEUNACAAIAFqQVxqIQYgB0HIAGohCgNAIAAgBEF/aiIIQeQAbGohBUEAIQEDQCABQQRqIgJBKEYNAiABIApqIQkgASAGaiELIAIhAUF/IAsoAgAiAiAJKAIAIglHIAIgCUgbIgJFDQALIAJB/wFxQf8BRw0BIAAgBEHkAGxqIAVB5AAQvwEaIAZBnH9qIQYgBSEDIAgiBA0ACwsgAyAHQQhqQeQAEL8BGgsgB0HwAGokAAvNAgEGfyABIAJBAXRqIQkgAEGA/gNxQQh2IQogAEH/AXEhDAJAAkACQANAIAFBAmohCyAHIAEtAAEiAmohCCAKIAEtAAAiAUcEQCABIApLDQMgCCEHIAsiASAJRw0BDAMLIAggB08EQCAIIARLDQIgAyAHaiEBAkADQCACRQ0BIAJBf2ohAiABLQAAIAFBAWohASAMRw0AC0EAIQIMBQsgCCEHIAsiASAJRw0BDAMLCyAHIAgQrQEACyAIIAQQqwEACyAAQf//A3EhByAFIAZqIQNBASECA0ACQCAFQQFqIQACfyAAIAUtAAAiAUEYdEEYdSIEQQBODQAaIAAgA0YNASAFLQABIARB/wBxQQh0ciEBIAVBAmoLIQUgByABayIHQQBIDQIgAkEBcyECIAMgBUcNAQwCCwtBhcnCAEErQbDJwgAQvgEACyACQQFxC9sCAQN/IwBBEGsiAyQAIAAgAUcEQANAIABBBGoCQCAAKAIAIgBBgAFPBEAgA0EANgIMAn8gAEH/D00EQCADIABBP3FBgAFyOgANIAMgAEEGdkEfcUHAAXI6AAxBAgwBCyAAQf//A00EQCADIABBP3FBgAFyOgAOIAMgAEEGdkE/cUGAAXI6AA0gAyAAQQx2QQ9xQeABcjoADEEDDAELIAMgAEE/cUGAAXI6AA8gAyAAQRJ2QfABcjoADCADIABBBnZBP3FBgAFyOgAOIAMgAEEMdkE/cUGAAXI6AA1BBAshACACIAIoAgggABCVASACIAIoAggiBCAAajYCCCAEIAIoAgBqIANBDGogABC/ARoMAQsgAigCCCIEIAIoAgRGBH8gAiAEQQEQlQEgAigCCAUgBAsgAigCAGogADoAACACIAIoAghBAWo2AggLIgAgAUcNAAsLIANBEGokAAvKAgEFfyMAQSBrIgMkAAJAIAAoAhAiBCAAKAIUTw0AIAAoAgQhByADIABBDGogAEEIaiAAKAIAQQFGGygCACIFNgIEIAMgBzYCACADIAQ2AgggAyAFNgIMAkACQCAERSAEIAVGckUEQCAFIARNDQEgBCAHaiwAAEG/f0wNAQsgBCAHaiAFIARrIAEgAhCTAUUNAiAAIAAoAhAgAmoiATYCECABRQ0BIABBDGogAEEIaiAAKAIAIgRBAUYbKAIAIgIgAUYNASAAQQxqIABBCGogBBshBQNAIAIgAUsEQCAAKAIEIAFqLAAAQb9/Sg0DC0EBIQYgACABQQFqIgQ2AhAgBCABSQ0DIAUoAgAiAiAEIgFHDQALDAILIAMgA0EMajYCGCADIANBCGo2AhQgAyADNgIQIANBEGoQzAEAC0EBIQYLIANBIGokACAGC+ICAgZ/An4jAEEgayIDJAACQBDGASIERQ0AAkAgBCgCAEUEQCAEQX82AgBBBCEGIARBBGogARBXIgFFDQECfiABKAIIIgJFBEBCAAwBCwJAAkAgAkH/////A3EgAkcNACACQQJ0IgVBAEgNACABKAIAIQEgBUEEENcBIgYNASAFQQRBlNDCACgCACIAQQIgABsRAAAACxDTAQALIAKtIQggAkEEdCIHIQUgBiECA0AgAiABKAIANgIAIAJBBGohAiABQRBqIQEgBUFwaiIFDQALIAdBcGpBBHZBAWqtQiCGCyEJIAQgBCgCAEEBajYCACAGRQ0CIAMgCCAJhDcCFCADIAY2AhAgA0EIaiADQRBqEIkBIAAgAykDCDcCACADQSBqJAAPC0GWv8IAQRAgA0EQakGov8IAEJsBAAtBhcnCAEErQbDJwgAQvgEAC0HQvsIAQcYAIANBEGpBuL/CABCbAQALwgIBCn8jAEHwAGsiBiQAAkAgAUECSQ0AIABB5ABqIQRBwAAhAgNAIAJBBGoiA0HoAEYNASAAIAJqIQUgAyECQX8gBSgCACIDIAVB5ABqKAIAIgVHIAMgBUgbIgNFDQALIANB/wFxQf8BRw0AIAZBCGogAEHkABC/ARogACAEQeQAEL8BIglB5ABqIQMCQCABQQNJDQAgCUGIAmohByAGQcgAaiEKQQIhAgNAIAJBAWogCSACQeQAbGohAEEAIQIDQCACQQRqIgRBKEYNAiACIAdqIQggAiAKaiELIAQhAkF/IAsoAgAiBCAIKAIAIghHIAQgCEgbIgRFDQALIARB/wFxQf8BRw0BIABBnH9qIABB5AAQvwEaIAdB5ABqIQcgACEDIgIgAUcNAAsLIAMgBkEIakHkABC/ARoLIAZB8ABqJAALvQICBH8BfiMAQRBrIgQkAAJAIAFBAkkNACABQQxsIABqIgNBdGoiAkEDIANBaGoiA0EDEJkBQf8BcUH/AUcNACACKQIAIQYgAiADKQIANwIAIARBCGoiBSACQQhqIgIoAgA2AgAgAiADQQhqKAIANgIAIAQgBjcDACAAIAFBfmoiAkEMbGohAwJAIAJFDQAgBEEDIAAgAUF9aiICQQxsakEDEJkBQf8BcUH/AUcNACABQQxsIABqQVBqIQEgAAJ/AkADQCABQSBqIAFBFGooAgA2AgAgAUEYaiABQQxqKQIANwIAIAJFDQEgAkF/aiECIARBAyABQQMQmQEgAUF0aiEBQf8BcUH/AUYNAAsgAkEBagwBC0EAC0EMbGohAwsgAyAEKQMANwIAIANBCGogBSgCADYCAAsgBEEQaiQAC74CAgV/AX4jAEEwayIEJABBJyECAkAgAEKQzgBUBEAgACEHDAELA0AgBEEJaiACaiIDQXxqIAAgAEKQzgCAIgdC8LF/fnynIgVB//8DcUHkAG4iBkEBdEH2gMAAai8AADsAACADQX5qIAZBnH9sIAVqQf//A3FBAXRB9oDAAGovAAA7AAAgAkF8aiECIABC/8HXL1YgByEADQALCyAHpyIDQeMASgRAIAJBfmoiAiAEQQlqaiAHpyIFQf//A3FB5ABuIgNBnH9sIAVqQf//A3FBAXRB9oDAAGovAAA7AAALAkAgA0EKTgRAIAJBfmoiAiAEQQlqaiADQQF0QfaAwABqLwAAOwAADAELIAJBf2oiAiAEQQlqaiADQTBqOgAACyABQcjIwgBBACAEQQlqIAJqQScgAmsQJSAEQTBqJAALtgIBB38CfyABQSBqKAIAIgQgAUEsaigCACIFTQRAIAEgBDYCLCAEDAELIAFBJGogBSAEIAVrIgYQlQEgASgCLCEDIAEoAiQhByABAn8CQCAGQQJPBEAgBUF/cyAEaiEEA0AgAyAHakEAOgAAIANBAWohAyAEQX9qIgQNAAsMAQsgBg0AIAMMAQsgAyAHakEAOgAAIANBAWoLIgQ2AiwgASgCIAshAwJAIAQgAyADIARLGyIGRQ0AIAEoAiQhByABKAIYIQRBACEDA0AgA0EBaiEFIAMgB2oCQCACIAQoAgAiCBBYQf8BcSIDQQhHDQBBACEDQQQgCCACEDciCEH/AXFBAkYNACAIQQJ0QQRxIQMLIAM6AAAgBSAGTw0BIARBBGohBCAFIQMMAAsACyAAIAFBMBC/ARoLvwIBBH8gAEIANwIQIAACf0EAIAFBCHYiA0UNABpBHyABQf///wdLDQAaIAFBBiADZyIDa0EfcXZBAXEgA0EBdGtBPmoLIgI2AhwgAkECdEHQzsIAaiEEAkACQAJAAkBBxMzCACgCACIFQQEgAkEfcXQiA3EEQCAEKAIAIgMoAgRBeHEgAUcNASADIQIMAgtBxMzCACADIAVyNgIAIAQgADYCACAAIAQ2AhgMAwsgAUEAQRkgAkEBdmtBH3EgAkEfRht0IQQDQCADIARBHXZBBHFqQRBqIgUoAgAiAkUNAiAEQQF0IQQgAiEDIAIoAgRBeHEgAUcNAAsLIAIoAggiASAANgIMIAIgADYCCCAAQQA2AhggACACNgIMIAAgATYCCA8LIAUgADYCACAAIAM2AhgLIAAgADYCDCAAIAA2AggLuAIBBn8jAEEQayICJAACQCACQX8CfwJAIAEEQCABQQhJDQEgASABQf////8BcUYEQCABQQN0QQduDAMLQbSmwgBBHEHQpsIAEL4BAAsgAEIANwIAIABBFGpBADYCACAAQQxqQgQ3AgAgAEEIakGwpsIANgIADAILIAFBAWoLQX9qZ3YiAUEBaiIEEKcBAkAgAigCBCIDBEAgAigCCCEFIAIoAgAiBiADENcBIgdFDQEgB0H/ASABQQVqEMUBIQMgAEEUakEANgIAIABBDGogAyAFajYCACAAQQhqIAM2AgAgACABNgIEIABBADYCACAAQRBqIAEgBEEDdkEHbCABQQhJGzYCAAwCC0G0psIAQRxB0KbCABC+AQALIAYgA0GU0MIAKAIAIgBBAiAAGxEAAAALIAJBEGokAAu4AgEGfyMAQRBrIgIkAAJAIAJBfwJ/AkAgAQRAIAFBCEkNASABIAFB/////wFxRgRAIAFBA3RBB24MAwtBtKbCAEEcQdCmwgAQvgEACyAAQgA3AgAgAEEUakEANgIAIABBDGpCCDcCACAAQQhqQbCmwgA2AgAMAgsgAUEBagtBf2pndiIBQQFqIgQQoQECQCACKAIEIgMEQCACKAIIIQUgAigCACIGIAMQ1wEiB0UNASAHQf8BIAFBBWoQxQEhAyAAQRRqQQA2AgAgAEEMaiADIAVqNgIAIABBCGogAzYCACAAIAE2AgQgAEEANgIAIABBEGogASAEQQN2QQdsIAFBCEkbNgIADAILQbSmwgBBHEHQpsIAEL4BAAsgBiADQZTQwgAoAgAiAEECIAAbEQAAAAsgAkEQaiQAC6kCAQN/IwBBgAFrIgQkACAAKAIAIQACQAJAAn8CQCABKAIAIgNBEHFFBEAgACgCACECIANBIHENASACrSABEFAMAgsgACgCACECQQAhAANAIAAgBGpB/wBqIAJBD3EiA0EwciADQdcAaiADQQpJGzoAACAAQX9qIQAgAkEEdiICDQALIABBgAFqIgJBgQFPDQIgAUHMg8AAQQIgACAEakGAAWpBACAAaxAlDAELQQAhAANAIAAgBGpB/wBqIAJBD3EiA0EwciADQTdqIANBCkkbOgAAIABBf2ohACACQQR2IgINAAsgAEGAAWoiAkGBAU8NAiABQcyDwABBAiAAIARqQYABakEAIABrECULIARBgAFqJAAPCyACQYABEK0BAAsgAkGAARCtAQALpgICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEEIAAoAgQhCANAAkACQCACIAhqKAAAIgYgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgBCAAaEEDdiACaiADcSIJQewBbGooAgBHBEAgAEF/aiAAcSIADQEMAgsLIAQgCUHsAWxqQQRqIQAMAQsgBUEEaiIFIAJqIANxIQJBACEAIAYgBkEBdHFBgIGChHhxRQ0BCwsgAAukAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQQgACgCBCEIA0ACQAJAIAIgCGooAAAiBiAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIARQ0AA0AgASAEIABoQQN2IAJqIANxIglBBHRqKAIARwRAIABBf2ogAHEiAA0BDAILCyAEIAlBBHRqQQRqIQAMAQsgBUEEaiIFIAJqIANxIQJBACEAIAYgBkEBdHFBgIGChHhxRQ0BCwsgAAuoAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhBiADIAAoAgQiA3EhAiAAQQxqKAIAIQcgAEEIaigCACEIQQAhBANAAkACQCACIAhqKAAAIgUgBnMiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgByAAaEEDdiACaiADcUEDdGoiCSgCAEcEQCAAQX9qIABxIgANAQwCCwsgCS0ABCEADAELIARBBGoiBCACaiADcSECQQghACAFIAVBAXRxQYCBgoR4cUUNAQsLIAALqAIBBH8jAEEgayICJAACQAJAAkACQAJAAkAQxwEiAwRAIAMoAgANBiADQX82AgAgA0EEaiAAEFYiBUUNAhDGASIEBEAgBCgCAA0HIARBfzYCACAEQQRqIAAQVyIARQ0EIAUgATYCECAAKAIEIAFPDQIgAkEIaiAAIAAoAggiACABIABrQQAQZCACKAIIQQFHDQIgAkEQaigCAA0FENMBAAsMBQsMBAsgBCAEKAIAQQFqNgIAIAMgAygCAEEBajYCACACQSBqJAAPC0GFycIAQStBsMnCABC+AQALQYXJwgBBK0GwycIAEL4BAAtBo8rCAEEoQdirwgAQvgEAC0HQvsIAQcYAIAJBGGpB3KjCABCbAQALQZa/wgBBECACQRhqQcyowgAQmwEAC7ICAQV/IAAoAhghBAJAAkAgACAAKAIMIgFGBEAgAEEUQRAgAEEUaiIBKAIAIgMbaigCACICDQFBACEBDAILIAAoAggiAiABNgIMIAEgAjYCCAwBCyABIABBEGogAxshAwNAIAMhBSACIgFBFGoiAygCACICRQRAIAFBEGohAyABKAIQIQILIAINAAsgBUEANgIACwJAIARFDQACQCAAIAAoAhxBAnRB0M7CAGoiAigCAEYEQCACIAE2AgAgAQ0BQcTMwgBBxMzCACgCAEF+IAAoAhx3cTYCAA8LIARBEEEUIAQoAhAgAEYbaiABNgIAIAFFDQELIAEgBDYCGCAAKAIQIgIEQCABIAI2AhAgAiABNgIYCyAAQRRqKAIAIgBFDQAgAUEUaiAANgIAIAAgATYCGAsLpwIBAn8CfyABQYAQTwRAAkACQAJAAkACQCABQYCABE8EQCABQQx2QXBqIgJBgAJJDQFB0JzAACACQYACEKwBAAsgAUEGdkFgaiICQd8HSw0BIABBhAJqKAIAIgMgACACakGYAmotAAAiAk0NAiAAKAKAAiACQQN0agwGCyABQQZ2QT9xIAAgAmpB+AlqLQAAQQZ0ciICIABBjAJqKAIAIgNPDQIgAEGUAmooAgAiAyAAKAKIAiACai0AACICTQ0DIAAoApACIAJBA3RqDAULQbCcwAAgAkHgBxCsAQALQcCcwAAgAiADEKwBAAtB4JzAACACIAMQrAEAC0HwnMAAIAIgAxCsAQALIAAgAUEDdkH4////AXFqCykDAEIBIAFBP3GthoNCAFILlAIBA38jAEGAAWsiAyQAAkACQAJ/AkAgASgCACICQRBxRQRAIAJBIHENASAArSABEFAMAgtBACECA0AgAiADakH/AGogAEEPcSIEQTByIARB1wBqIARBCkkbOgAAIAJBf2ohAiAAQQR2IgANAAsgAkGAAWoiAEGBAU8NAiABQcyDwABBAiACIANqQYABakEAIAJrECUMAQtBACECA0AgAiADakH/AGogAEEPcSIEQTByIARBN2ogBEEKSRs6AAAgAkF/aiECIABBBHYiAA0ACyACQYABaiIAQYEBTw0CIAFBzIPAAEECIAIgA2pBgAFqQQAgAmsQJQsgA0GAAWokAA8LIABBgAEQrQEACyAAQYABEK0BAAuTAgELfyMAQRBrIgUkAAJAAkAgAUEITwRAIAFBAnYiAkEBdCEHQX8hBkF/IAFBf2pndiEIIAJBGGwgAGpBdGohBCABIQIDQCAGIAdqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAhxIgNBACABIAMgAUkbayIDIAFPDQMgBUEIaiIJIARBCGoiCigCADYCACAFIAQpAgA3AwAgACADQQxsaiIDQQhqIgsoAgAhDCAEIAMpAgA3AgAgCiAMNgIAIAsgCSgCADYCACADIAUpAwA3AgAgBEEMaiEEIAZBAWoiBkECRw0ACwsgBUEQaiQADwtBsKfCACADIAEQrAEAC0HAp8IAIAMgARCsAQALlQICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEIIAAoAgQhCUEAIQQDQAJAIAIgCWooAAAiBSAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIABEADQEEBIQYgCCAAaEEDdiACaiADcUEEdGooAgAgAUYNAiAAQX9qIABxIgANAAsLIARBBGoiBCACaiADcSECQQAhBiAFIAVBAXRxQYCBgoR4cUUNAQsLIAYLlgICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEIIAAoAgQhCUEAIQQDQAJAIAIgCWooAAAiBSAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIABEADQEEBIQYgCCAAaEEDdiACaiADcUHsAWxqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC5UCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghCCAAKAIEIQlBACEEA0ACQCACIAlqKAAAIgUgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAARAA0BBASEGIAggAGhBA3YgAmogA3FBKGxqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC5ECAQh/IwBBIGsiAyQAAkACQCAAKAIQIgQgACgCGGsgAkgNACAAQQhqIQUgAEEMaiEIIAAoAgAhCSAAKAIEIQYCQCAEIAJrIgRFDQAgCCAFIAlBAUYbKAIAIgogBEYNACAKIARNDQEgBCAGaiwAAEFASA0BCyADIAggBSAJGygCACIFNgIEIAMgBjYCACADIAQ2AgggAyAFNgIMIARFIAQgBUZyRQRAIAUgBE0NAiAEIAZqLAAAQb9/TA0CCyAEIAZqIAUgBGsgASACEJMBRQ0AIAAgACgCECACazYCEEEBIQcLIANBIGokACAHDwsgAyADQQxqNgIYIAMgA0EIajYCFCADIAM2AhAgA0EQahDMAQALqAIBBH8jAEEwayICJAAgASgCCCEFIAIgARCwASACKAIIIQQgAkEANgIoIAJCATcDICACQSBqQQAgBBCVASACKAIoIQMgBARAIAIoAiAgA2pBACAEQQJ0QXxqQQJ2QQFqIgQQxQEaIAMgBGohAwsgAkEYaiIEIAM2AgAgAiACKQMgNwMQQRRBBBDXASIDRQRAQRRBBEGU0MIAKAIAIgBBAiAAGxEAAAALIANBigI7ARAgAyAFNgIMIAMgBTYCCCADQgA3AgAgAEKBgICAEDcCBCAAIAM2AgAgACACKQMANwIYIAAgAikDEDcCJCAAIAEpAgA3AgwgAEEUaiABQQhqKAIANgIAIABBIGogAkEIaigCADYCACAAQSxqIAQoAgA2AgAgAkEwaiQAC4oCAQN/AkAgAUEATARAIAAoAhAhAiABQX9KDQEgACgCBCEDIABBDGogAEEIaiAAKAIAQQFGGyEAA0AgAUEBaiEBAkAgAkF/aiICQQBIDQADQCACRQ0BIAIgACgCACIERg0BIAQgAksEQCACIANqLAAAQb9/Sg0CCyACQX9qIQIMAAsACyABDQALDAELIAAoAgQhAyAAKAIQIQIgAEEMaiAAQQhqIAAoAgAbIQADQCABQX9qIQECQCACQQFqIgIgACgCAEsNAANAIAJFDQEgAiAAKAIAIgRGDQEgBCACSwRAIAIgA2osAABBv39KDQILIAJBAWoiAiAAKAIATQ0ACwsgAUEASg0ACwsgAgviAQECfwJAIAEoAgQiBSACayADTw0AIAIgA2oiAyACSSECAkACQAJAIAQEQCACRQ0BIAAgAzYCBAwDCyACRQ0BIAAgAzYCBAwCCyAFQQF0IgIgAyACIANLGyEDCyADIANB/////wBxRwRADAELIANBBHQiBEF/TARADAELAn8gBUUEQCAEQQQQ1wEMAQsgASgCACAFQQR0QQQgBBDUAQsiAkUEQCAEQQRBlNDCACgCACIAQQIgABsRAAAACyABIAM2AgQgASACNgIADAELIABBCGpBADYCAEEBIQYLIAAgBjYCAAuZAgIBfwN+IwBBIGsiASQAIAFBGGpBFBC2ASABKQMYIQIgAUEQakEUEKUBIAEpAxAhAyABQQhqQRQQpQEgASkDCCEEIABBgAFqQQA2AgAgAEHwAGpCADcCACAAQegAaiADNwIAIABB4ABqQgA3AgAgAEHYAGogAjcCACAAQdAAakIANwIAIABByABqQgQ3AgAgAEHEAGpBsKbCADYCACAAQTxqQgA3AgAgAEE0akIENwIAIABBMGpBsKbCADYCACAAQShqQgA3AgAgAEEgakIENwIAIABBHGpBsKbCADYCACAAQRRqQgA3AgAgAEEMakIENwIAIABBCGpBsKbCADYCACAAQgA3AgAgAEH4AGogBDcCACABQSBqJAAL7gEBB38jAEHwAGsiBSQAAkACQCABQQhPBEAgAUECdiICQQF0IQdBfyEGQX8gAUF/amd2IQggAkHIAWwgAGpBnH9qIQQgASECA0AgBiAHaiIDIAFPDQIgAkENdCACcyICQRF2IAJzIgJBBXQgAnMiAiAIcSIDQQAgASADIAFJG2siAyABTw0DIAVBDGogBEHkABC/ARogBCAAIANB5ABsaiIEQeQAEJ4BIAQgBUEMakHkABC/ARpB5ABqIQQgBkEBaiIGQQJHDQALCyAFQfAAaiQADwtBsKfCACADIAEQrAEAC0HAp8IAIAMgARCsAQALkwIBBX8gACgCACIEKAIEKAIAIgUgASgCACIIQQN0aigCBCgCACAFIAIoAgAiBkEDdGooAgQoAgAiB0kEQCABIAY2AgAgAiAINgIAIAQoAggiBCAEKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkEDdGooAgQoAgAhBwsgByAFIAMoAgAiCEEDdGooAgQoAgBJBEAgAiAINgIAIAMgBjYCACAEKAIIIgMgAygCAEEBajYCACAAKAIAIgQoAgQoAgAiBSACKAIAIgZBA3RqKAIEKAIAIQcLIAUgASgCACIAQQN0aigCBCgCACAHSQRAIAEgBjYCACACIAA2AgAgBCgCCCIAIAAoAgBBAWo2AgALC/wBAgZ/AX4jAEEQayIFJAADQCACQQF0IgNBAXIhBAJAAkACQCADQQJqIgMgAUkEQCAEIAFPDQEgAyAEIAAgBEEMbGpBAyAAIANBDGxqQQMQmQFB/wFxQf8BRhshBAsgBCABSQRAIAIgAU8NAiAAIAJBDGxqIgJBAyAAIARBDGxqIgNBAxCZAUH/AXFB/wFGDQMLIAVBEGokAA8LQayowgAgBCABEKwBAAtBvKjCACACIAEQrAEACyADQQhqIgYoAgAhByACKQIAIQkgAiADKQIANwIAIAJBCGoiAigCACEIIAIgBzYCACAGIAg2AgAgAyAJNwIAIAQhAgwACwALgwIBA38jAEEwayIEJAACQAJAIAEoAmRFBEAgAUF/NgJkIAFB8ABqQQA2AgAgBEEANgIYIAQgAUEsajYCFCAEQQI2AhAgBCADNgIMIAQgAjYCCCABQegAaiEGIARBIGogBEEIahAqIAQoAiANAQwCC0GWv8IAQRAgBEEIakG4r8IAEJsBAAsDQCAGIAQoAigiBSAFIAQoAixBAnRqEL0BIARBIGogBEEIahAqIAQoAiANAAsLAkACQCADIAEoAnBGBEAgASgCaCIFIAJGDQEgBSACIANBAnQQugFFDQELIAAgBhCwAQwBCyAAQQA2AgALIAEgASgCZEEBajYCZCAEQTBqJAALgAIBBX8gACgCACIEKAIEKAIAIgUgAigCACIGQQJ0aigCACIHIAUgASgCACIIQQJ0aigCAEkEQCABIAY2AgAgAiAINgIAIAQoAggiBCAEKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkECdGooAgAhBwsgBSADKAIAIghBAnRqKAIAIAdJBH8gAiAINgIAIAMgBjYCACAEKAIIIgMgAygCAEEBajYCACAAKAIAIgQoAgQoAgAiBSACKAIAIgZBAnRqKAIABSAHCyAFIAEoAgAiAEECdGooAgBJBEAgASAGNgIAIAIgADYCACAEKAIIIgAgACgCAEEBajYCAAsL4wEBBn8jAEEQayIFJAAgACgCACIBBEAgACgCBCICQQRqIQMgASACakEBaiEGIAIoAgBBf3NBgIGChHhxIQEgACgCCCEEA0ACQCABRQRAA0AgAyAGTw0CIARB4ABqIQQgAygCACADQQRqIgIhA0GAgYKEeHEiAUGAgYKEeEYNAAsgAUGAgYKEeHMhASACIQMLIAQgAWhBA3ZBGGxqIgIoAgQEQCACKAIAECELIAFBf2ogAXEhASACKAIQRQ0BIAIoAgwQIQwBCwsgBSAAKAIAQQFqEJ8BIAAoAgQQIQsgBUEQaiQAC+UBAQR/IwBBQGoiBCQAIARBCGogASABIAJqECMgBEEYaiAAIAQoAggiBSAEKAIQIgEQaSAEKAIMIQYCQCAEKAIYIgJFBEAgBiEHIAUhAgwBCyAEQSBqKAIAIQEgBCgCHCEHIAZFDQAgBRAhCyAEQRhqIAAgAiABEDggBCABNgI4IAQgBzYCNCAEIAI2AjAgAEEYaiIAIARBMGogAxAyAkAgBCgCGCIBRQ0AIARBOGogBEEsaigCADYCACAEIARBJGopAgA3AzAgACAEQTBqIAMQMiAEKAIcRQ0AIAEQIQsgBEFAayQAC9gBAQV/AkAgAUECSQ0AIAAgAUF+aiICQQN0aiIDKAIEKAIAIAFBA3QgAGoiBEF8aigCACIFKAIATw0AIARBeGoiBCgCACEGIAQgAykCADcCAAJAIAJFDQAgACABQX1qIgJBA3RqIgEoAgQoAgAgBSgCAE8NACABQXxqIQEgAAJ/AkADQCABQQxqIAFBBGopAgA3AgAgAkUNASACQX9qIQIgASgCACABQXhqIQEoAgAgBSgCAEkNAAsgAkEBagwBC0EAC0EDdGohAwsgAyAFNgIEIAMgBjYCAAsL3QECB38BfiMAQRBrIgQkACABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhBoIAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUEMbCAAakF0aiECIAEhAwNAIANBf2oiBSABTw0CIARBCGogAEEIaiIDKAIAIgY2AgAgBCAAKQIAIgk3AwAgAkEIaiIHKAIAIQggACACKQIANwIAIAMgCDYCACAHIAY2AgAgAiAJNwIAIAAgBUEAEGggAkF0aiECIAUiA0EBSw0ACwsgBEEQaiQADwtBwKfCACADQX9qIAEQrAEAC90BAQZ/AkACQAJAIAFBAkkNACAAKAIEIgYoAgAgAEEMaigCACgCAE8NACAAKAIAIQcgACAAKQIINwIAIABBCGohAgJAIAFBA0kNAEECIQNBAyEEA0AgAyABTw0DIAYoAgAgACADQQN0aiIFKAIEKAIATw0BIANBf2oiAiABTw0EIAAgAkEDdGogBSkCADcCACAEIAFJBEAgBSECIAQiA0EBaiIEIANJDQIMAQsLIAUhAgsgAiAGNgIEIAIgBzYCAAsPC0HQrMIAIAMgARCsAQALQeCswgAgAiABEKwBAAvUAQEGfyMAQRBrIgUkACAAKAIAIgIEQCAAKAIEIgFBBGohAyABIAJqQQFqIQYgASgCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAEEfyABBQNAIAMgBk8NAiAEQeAAaiEEIAMoAgAgA0EEaiICIQNBgIGChHhxIgFBgIGChHhGDQALIAIhAyABQYCBgoR4cwsiAiACQX9qcSEBIAQgAmhBA3ZBGGxqIgIoAhBFDQEgAigCDBAhDAELCyAFIAAoAgBBAWoQnwEgACgCBBAhCyAFQRBqJAAL0wEBBn8jAEEQayIFJAAgACgCACICBEAgACgCBCIBQQRqIQMgASACakEBaiEGIAEoAgBBf3NBgIGChHhxIQEgACgCCCEEA0ACQCABBH8gAQUDQCADIAZPDQIgBEFAayEEIAMoAgAgA0EEaiICIQNBgIGChHhxIgFBgIGChHhGDQALIAIhAyABQYCBgoR4cwsiAiACQX9qcSEBIAQgAmhBA3ZBBHRqIgIoAgRFDQEgAigCABAhDAELCyAFIAAoAgBBAWoQqAEgACgCBBAhCyAFQRBqJAALzgECBn8BfgJAAkAgAUEITwRAIAFBAnYiAkEBdCEGQX8hBUF/IAFBf2pndiEHIAJBBHQgAGpBeGohBCABIQIDQCAFIAZqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAdxIgNBACABIAMgAUkbayIDIAFPDQMgBCkCACEIIAQgACADQQN0aiIDKQIANwIAIAMgCDcCACAEQQhqIQQgBUEBaiIFQQJHDQALCw8LQbCnwgAgAyABEKwBAAtBwKfCACADIAEQrAEAC8wBAQd/AkACQCABQQhPBEAgAUECdiICQQF0IQZBfyEFQX8gAUF/amd2IQcgAkEDdCAAakF8aiEEIAEhAgNAIAUgBmoiAyABTw0CIAJBDXQgAnMiAkERdiACcyICQQV0IAJzIgIgB3EiA0EAIAEgAyABSRtrIgMgAU8NAyAEKAIAIQggBCAAIANBAnRqIgMoAgA2AgAgAyAINgIAIARBBGohBCAFQQFqIgVBAkcNAAsLDwtBsKfCACADIAEQrAEAC0HAp8IAIAMgARCsAQALyQECA38BfiMAQRBrIgUgATYCCCACKQMAIQZBASEDIAEhBANAIAYgBK1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAFQQhqIANqIANBAWohAy0AACEEDAELCyACIAY3AwAgAQRAIAAgAUECdGohAQNAIAUgACgCACIENgIMQQEhAwNAIAYgBK1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAFQQxqIANqIANBAWohAy0AACEEDAELCyACIAY3AwAgAEEEaiIAIAFHDQALCwvCAQEFfwJAIAFBAkkNACABQQJ0IABqQXxqIgMoAgAiBCAAIAFBfmoiBUECdGoiBigCACICTw0AIAMgAjYCAAJAAkAgBUUNACAEIAAgAUF9aiIDQQJ0aiICKAIAIgVPDQAgBiAFNgIAIANFDQEgAUECdCAAakFwaiEBA0AgBCABKAIAIgBPBEAgAUEEaiECDAMLIAFBBGogADYCACABQXxqIQEgA0F/aiIDDQALIAFBBGohAgwBCyAGIQILIAIgBDYCAAsL1AEBAX8jAEHgAGsiBCQAIAQgATYCCCAEIAM2AgwgASADRgRAIAAgAiABQQJ0EL8BGiAEQeAAaiQADwsgBEE8akEJNgIAIARBNGpBCjYCACAEQSRqQQM2AgAgBEIDNwIUIARB4MHCADYCECAEQQo2AiwgBCAEQQhqNgJAIAQgBEEMajYCRCAEQgQ3A1ggBEIBNwJMIARB2MHCADYCSCAEIARBKGo2AiAgBCAEQcgAajYCOCAEIARBxABqNgIwIAQgBEFAazYCKCAEQRBqQfjBwgAQxAEAC8QBAQZ/AkACQAJAIAFBAkkNACAAKAIEIgQgACgCACIFELcBRQ0AIABBBGohAiAAIAQ2AgBBAyEEAkAgAUEDSQ0AQQIhAwNAIAMgAU8NAyAAIANBAnRqIgYoAgAiByAFELcBRQ0BIANBf2oiAiABTw0EIAAgAkECdGogBzYCACAEIAFJBEAgBiECIAQiA0EBaiIEIANJDQIMAQsLIAYhAgsgAiAFNgIACw8LQdCswgAgAyABEKwBAAtB4KzCACACIAEQrAEAC7oBAQR/IwBB8ABrIgQkACABQQF2IgIEQCACQX9qIQIDQCAAIAEgAhBGIAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUHkAGwgAGpBnH9qIQMgASECA0AgAkF/aiIFIAFPDQIgBEEMaiAAQeQAEL8BGiAAIANB5AAQngEgAyAEQQxqQeQAEL8BIQMgBUEAEEYgA0Gcf2ohAyAFIgJBAUsNAAsLIARB8ABqJAAPC0HAp8IAIAJBf2ogARCsAQALwQECBH8BfkEDIQIjAEEQayIEQQM2AgggASkDACEGQQEhAwNAIAYgAq1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAEQQhqIANqIANBAWohAy0AACECDAELCyABIAY3AwAgAEEMaiEFA0AgBCAAKAIAIgI2AgxBASEDA0AgBiACrUL/AYOFQrODgICAIH4hBiADQQRGRQRAIARBDGogA2ogA0EBaiEDLQAAIQIMAQsLIAEgBjcDACAAQQRqIgAgBUcNAAsLvAEBAn8CQCAAKAIEIgFFDQAgACgCDCICBEAgAkE8bCECA0AgARCvASABQTxqIQEgAkFEaiICDQALCyAAKAIIBEAgACgCBBAhCyAAQRRqEJIBIABBnAFqKAIABEAgAEGYAWooAgAQIQsgAEGoAWooAgAEQCAAQaQBaigCABAhCyAAQbgBahBwIABB0AFqKAIABEAgAEHMAWooAgAQIQsgAEHcAWooAgAiAUUNACAAQeABaigCAEUNACABECELC8IBAgJ/AX4DQCACQQF0IgRBAXIhAwJAAkACQCAEQQJqIgQgAUkEQCADIAFPDQEgBCADIAAgBEEDdGooAgQoAgAgACADQQN0aigCBCgCAEkbIQMLIAMgAUkEQCACIAFPDQIgACADQQN0aiIEKAIEKAIAIAAgAkEDdGoiAigCBCgCAEkNAwsPC0GsqMIAIAMgARCsAQALQbyowgAgAiABEKwBAAsgAikCACEFIAIgBCkCADcCACAEIAU3AgAgAyECDAALAAvaAQIGfwF+IwAiACAAQUBqQWBxIgAkAAJAQaTMwgAoAgANACAAQRhqQRQQpQEgACkDGCEGIABBEGpBFBClASAAQgA3AiwgACAGNwIkQajMwgAoAgBBqMzCACAAKQMoNwMAIABBADYCIEGkzMIAKAIAIQFBoMzCACAAKQMgNwMAIABBADYCPCAAIAApAxA3AjRBuMzCACgCACEEQbjMwgAgACkDODcDAEG0zMIAKAIAIQVBsMzCACAAKQMwNwMAIAFFDQAEQCABECELIARFDQAgBRAhCyQAQaDMwgALrgEBBH8DQCACQQF0IgRBAXIhAwJAAkACQCAEQQJqIgQgAUkEQCADIAFPDQEgBCADIAAgA0ECdGooAgAgACAEQQJ0aigCAEkbIQMLIAMgAUkEQCACIAFPDQIgACACQQJ0aiICKAIAIgQgACADQQJ0aiIFKAIAIgZJDQMLDwtBrKjCACADIAEQrAEAC0G8qMIAIAIgARCsAQALIAIgBjYCACAFIAQ2AgAgAyECDAALAAuyAQEEfwNAIAJBAXQiBEEBciEDAkACQAJAIARBAmoiBCABSQRAIAMgAU8NASAEIAMgACADQQJ0aigCACAAIARBAnRqKAIAELcBGyEDCyADIAFJBEAgAiABTw0CIAAgAkECdGoiAigCACIEIAAgA0ECdGoiBSgCACIGELcBDQMLDwtBrKjCACADIAEQrAEAC0G8qMIAIAIgARCsAQALIAIgBjYCACAFIAQ2AgAgAyECDAALAAunAQIEfwF8AkAgACgCACIDRQ0AA0AgACgCBCAAKAIAIgQgAmxBA3RqIAS4OQMAIAAoAgQgAWogACgCALg5AwAgAUEIaiEBIAMgAkEBaiICRw0ACyAAKAIAIgJBAkkNAEEBIQEDQCAAKAIEIAAoAgAgAWxBA3RqQQhqIAFBf2q4IgU5AwAgACgCBCABIAAoAgBqQQN0aiAFOQMAIAIgAUEBaiIBRw0ACwsLqwEBBX8CQCABQQJJDQAgAUECdCAAakF8aiIFKAIAIgIgACABQX5qIgNBAnRqIgQoAgAiBhC3AUUNACAFIAY2AgACQCADRQ0AIAIgAUECdCAAaiIAQXRqKAIAELcBRQ0AIABBcGohAANAAkAgACIBQQhqIAFBBGooAgA2AgAgA0F/aiIDRQ0AIAFBfGohACACIAEoAgAQtwENAQsLIAFBBGohBAsgBCACNgIACwvPAQMBfwJ+AnwjAEEQayEBIAC9IgJCNIhC/w+DIgNCsghYBHwgAJogACACQgBTGyEEAnwCQCADQv4HWgRAIAREAAAAAAAAMEOgRAAAAAAAADDDoCAEoSIFRAAAAAAAAOA/ZA0BIAQgBaAiACAFRAAAAAAAAOC/ZUEBcw0CGiAARAAAAAAAAPA/oAwCCyABIAREAAAAAAAAMEOgOQMIIAErAwgaIABEAAAAAAAAAACiDwsgBCAFoEQAAAAAAADwv6ALIgCaIAAgAkIAUxsFIAALC7IBAQV/AkACQCABKAIAIgIEQCABKAIEIQQMAQsgASgCDCEFIAEoAgghAwNAIAMgBU8NAiADKAIAIQIgASADQQRqIgM2AgggASABKAIEQaABaiIENgIEIAEgAkGAgYKEeHEiBkGAgYKEeHMiAjYCACAGQYCBgoR4Rg0ACwsgASACQX9qIAJxNgIAIAEgASgCEEF/ajYCECAAIAQgAmhBA3ZBKGxqQSgQvwEaDwsgAEECOgAkC6sBAQd/AkACQCAAKAIIIgNBAkkEQCADIQEMAQsgACgCACIGQQRqIQJBASEBQQEhBANAIAIoAgAiByABQQJ0IAZqIgVBfGooAgBHBEAgASAERwRAIAIgBSgCADYCACAFIAc2AgALIAFBAWohAQsgAkEEaiECIAMgBEEBaiIERw0ACyABIANLBEBBta3CAEEcQdStwgAQvgEACyAAKAIIIAFJDQELIAAgATYCCAsLkwEBAX8jAEHQAGsiBSQAIAVBEGogASABIAJqECMgBUEgaiADIAMgBGoQIyAFQThqIAVBGGooAgA2AgAgBSAFKQMQNwMwIAVByABqIAVBKGooAgA2AgAgBSAFKQMgNwNAIAUgAEEsaiAFQTBqIAVBQGsQLAJAIAUoAgAiAEUNACAFKAIERQ0AIAAQIQsgBUHQAGokAAuTAQEBfyMAQdAAayIFJAAgBUEQaiABIAEgAmoQIyAFQSBqIAMgAyAEahAjIAVBOGogBUEYaigCADYCACAFIAUpAxA3AzAgBUHIAGogBUEoaigCADYCACAFIAUpAyA3A0AgBSAAQUBrIAVBMGogBUFAaxAsAkAgBSgCACIARQ0AIAUoAgRFDQAgABAhCyAFQdAAaiQAC6EBAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf////8DcSABRw0BIAFBAnQiBEEASA0BAn8gA0UEQCAEQQQQ1wEMAQsgACgCACADQQJ0QQQgBBDUAQsiAkUNAiAAIAE2AgQgACACNgIACw8LENMBAAsgBEEEQZTQwgAoAgAiAEECIAAbEQAAAAugAQECfwJAAkAgACgCBCIDIAFrIAJJBEAgASACaiICIAFJDQEgA0EBdCIBIAIgASACSxsiAUH///8/cSABRw0BIAFBBXQiBEEASA0BAn8gA0UEQCAEQQgQ1wEMAQsgACgCACADQQV0QQggBBDUAQsiAkUNAiAAIAE2AgQgACACNgIACw8LENMBAAsgBEEIQZTQwgAoAgAiAEECIAAbEQAAAAuhAQECfwJAAkAgACgCBCIDIAFrIAJJBEAgASACaiICIAFJDQEgA0EBdCIBIAIgASACSxsiAUH/////AXEgAUcNASABQQN0IgRBAEgNAQJ/IANFBEAgBEEIENcBDAELIAAoAgAgA0EDdEEIIAQQ1AELIgJFDQIgACABNgIEIAAgAjYCAAsPCxDTAQALIARBCEGU0MIAKAIAIgBBAiAAGxEAAAALsAEBA38CQAJAAkAgASgCBCIDIAEoAggiAkYEQCADIQIMAQsgAyACSQ0BAkAgAkUEQCADBEAgASgCABAhCyABQQQ2AgBBACECDAELIAEoAgAgA0ECdEEEIAJBAnQiBBDUASIDRQ0DIAEgAzYCAAsgASACNgIECyAAIAI2AgQgACABKAIANgIADwtB1cnCAEEkQfzJwgAQvgEACyAEQQRBlNDCACgCACIAQQIgABsRAAAAC54BAQh/IAAoAgQoAgAiAyABKAIAIgZB5ABsakFAayEHIAMgAigCACIIQeQAbGpBQGshCQJAA0AgBEEEaiIDQShGDQEgBCAJaiEKIAQgB2ohBSADIQRBfyAFKAIAIgUgCigCACIDRyAFIANIGyIDRQ0ACyADQf8BcUH/AUcNACABIAg2AgAgAiAGNgIAIAAoAggiACAAKAIAQQFqNgIACwuYAQEDfyABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB9IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUECdCAAakF8aiECIAEhAwNAIANBf2oiBCABTw0CIAAoAgAhAyAAIAIoAgA2AgAgAiADNgIAIAAgBEEAEH0gAkF8aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCsAQALmAEBA38gAUEBdiIDBEAgA0F/aiECA0AgACABIAIQfiACQX9qIgJBf0cNAAsLAkAgAUECTwRAIAFBAnQgAGpBfGohAiABIQMDQCADQX9qIgQgAU8NAiAAKAIAIQMgACACKAIANgIAIAIgAzYCACAAIARBABB+IAJBfGohAiAEIgNBAUsNAAsLDwtBwKfCACADQX9qIAEQrAEAC5oBAgN/AX4gAUEBdiIDBEAgA0F/aiECA0AgACABIAIQeyACQX9qIgJBf0cNAAsLAkAgAUECTwRAIAFBA3QgAGpBeGohAiABIQMDQCADQX9qIgQgAU8NAiAAKQIAIQUgACACKQIANwIAIAIgBTcCACAAIARBABB7IAJBeGohAiAEIgNBAUsNAAsLDwtBwKfCACADQX9qIAEQrAEAC5wBAQJ/AkACQCABIAAoAgRGBEAgAUEBaiICIAFJDQEgAUEBdCIDIAIgAyACSxsiAkH/////AXEgAkcNASACQQN0IgNBAEgNAQJ/IAFFBEAgA0EEENcBDAELIAAoAgAgAUEDdEEEIAMQ1AELIgFFDQIgACACNgIEIAAgATYCAAsPCxDTAQALIANBBEGU0MIAKAIAIgBBAiAAGxEAAAALtwECBH8CfiMAQTBrIgAkAAJAQejKwgAoAgANACAAQRhqQQoQVCAAQRBqIABBLGooAgAiAzYCACAAQQhqIABBJGopAgAiBDcDAEHkysIAKAIAIQFB6MrCACgCACECQeTKwgAgACkCHCIFNwIAQezKwgAgBDcCAEH0ysIAIAM2AgAgACAFNwMAQeDKwgBBADYCACABRSACRXINACAAQRhqIAFBAWoQoQEgAhAhCyAAQTBqJABB4MrCAAu3AQIEfwJ+IwBBMGsiACQAAkBBsMvCACgCAA0AIABBGGpBChBUIABBEGogAEEsaigCACIDNgIAIABBCGogAEEkaikCACIENwMAQazLwgAoAgAhAUGwy8IAKAIAIQJBrMvCACAAKQIcIgU3AgBBtMvCACAENwIAQbzLwgAgAzYCACAAIAU3AwBBqMvCAEEANgIAIAFFIAJFcg0AIABBGGogAUEBahChASACECELIABBMGokAEGoy8IAC6gBAQJ/AkACQAJAIAEoAgQiAyABKAIIIgJGBEAgAyECDAELIAMgAkkNAQJAIAJFBEAgAwRAIAEoAgAQIQsgAUEBNgIAQQAhAgwBCyABKAIAIANBASACENQBIgNFDQMgASADNgIACyABIAI2AgQLIAAgAjYCBCAAIAEoAgA2AgAPC0HVycIAQSRB/MnCABC+AQALIAJBAUGU0MIAKAIAIgBBAiAAGxEAAAALjAEBAn8jAEEQayIBJAAgACgCBCICBEAgASACQQFqEKcBIABBCGooAgAQIQsgAEEYahBxIABBLGoQayAAQUBrEGsgAEHcAGooAgAEQCAAQdgAaigCABAhCyAAQewAaigCAARAIABB6ABqKAIAECELIABB/ABqKAIABEAgAEH4AGooAgAQIQsgAUEQaiQAC6IBAQJ/IwBBEGsiBCQAAkACQAJAAkAgA0EAIAEgA0cbRQRAIAQgATYCDCAEIAA2AggMAQsgASADTQ0CIAAgA2oiBSwAAEG/f0wNAiAEIAE2AgwgBCAANgIIIAUsAABBv39MDQELQQEhASAAIAJGDQIgAiAAIAMQugENAQwCCyAEQQhqIgAoAgAgACgCBEEAIAMQGgALQQAhAQsgBEEQaiQAIAELigEBBH8gACAAKAIQIgJBAWoiATYCEAJAIAEgAkkNACAAQQxqIABBCGogACgCACICQQFGGygCACIDIAFGDQAgAEEMaiAAQQhqIAIbIQQDQCADIAFLBEAgACgCBCABaiwAAEG/f0oNAgsgACABQQFqIgI2AhAgAiABSQ0BIAQoAgAiAyACIgFHDQALCwuLAQEBfwJAAkAgACgCBCIDIAFrIAJJBEAgASACaiICIAFJDQEgA0EBdCIBIAIgASACSxsiAUEASA0BAn8gA0UEQCABQQEQ1wEMAQsgACgCACADQQEgARDUAQsiAkUNAiAAIAE2AgQgACACNgIACw8LENMBAAsgAUEBQZTQwgAoAgAiAEECIAAbEQAAAAuGAQEEfyAAIAAoAhBBf2oiATYCEAJAIAFFDQAgAEEMaiAAQQhqIAAoAgAiAkEBRhsoAgAiAyABRg0AIABBDGogAEEIaiACGyEEA0AgAyABSwRAIAAoAgQgAWosAABBv39KDQILIAAgAUF/aiICNgIQIAFBAUYNASAEKAIAIgMgAiIBRw0ACwsLnQECA38BfCAAIAAoAgggAiABaxCIASAAKAIIIQMgACABIAJHBH8gACgCACADQQN0aiEEIAEhAANAIAAtAAAhBSAAQQFqIQBEAAAAAAAA8D8hBgJAIAVBfGoiBUEDSw0AAkAgBUEBaw4CAQEAC0QAAAAAAADgPyEGCyAEIAY5AwAgBEEIaiEEIAAgAkcNAAsgAiADIAFragUgAws2AggLgwEBA38jAEEgayICJAAgASgCACEDIAJBCGogASgCCCIBEKUBIAJBADYCGCACIAIpAwg3AxAgAkEQakEAIAEQhgEgAiABIAIoAhgiBGo2AhggAigCECAEQQJ0aiADIAFBAnQQvwEaIABBCGogAigCGDYCACAAIAIpAxA3AgAgAkEgaiQAC2wBBX8gAyABIAEgA0sbIQgDQAJAIAQgCEkEQCAEQQFqIgUgBE8NAQtBfyABIANHIAEgA0kbDwsgAigCACEGIAAoAgAhByACQQRqIQIgAEEEaiEAIAUhBEF/IAYgB0cgByAGSRsiBUUNAAsgBQt+AQF/AkACQCABIAFB/////wFxRgRAIAFBA3QiA0F/TA0BAkAgA0UEQEEIIQIMAQsCfyACRQRAIANBCBDXAQwBCyADEMMBCyICRQ0DCyAAIAE2AgQgACACNgIADwsQ2AEACxDYAQALIANBCEGU0MIAKAIAIgBBAiAAGxEAAAALgQEBAX8jAEFAaiIEJAAgBCABNgIMIAQgADYCCCAEIAM2AhQgBCACNgIQIARBLGpBAjYCACAEQTxqQQM2AgAgBEICNwIcIARBlKrAADYCGCAEQQQ2AjQgBCAEQTBqNgIoIAQgBEEQajYCOCAEIARBCGo2AjAgBEEYakGoqsAAEMQBAAtwAQV/IAAoAgQhAyABpyECIAAoAgAhBEEAIQADQCAAQQRqIgAgAiAEcSIFaiECIAMgBWooAABBgIGChHhxIgZFDQALIAMgBmhBA3YgBWogBHEiAmosAABBAE4EfyADKAIAQYCBgoR4cWhBA3YFIAILC3QBAn8gACAAKAIIIAEQhgEgACgCACAAKAIIIgJBAnRqIQMCQAJAIAFBAk8EQCABQX9qIQEDQCADQQA2AgAgAkEBaiECIANBBGohAyABQX9qIgENAAsMAQsgAUUNAQsgA0EANgIAIAJBAWohAgsgACACNgIIC28BAX8CQCABIABPBEAgAkUNASAAIQMDQCADIAEtAAA6AAAgAUEBaiEBIANBAWohAyACQX9qIgINAAsMAQsgAkUNACABQX9qIQEgAEF/aiEDA0AgAiADaiABIAJqLQAAOgAAIAJBf2oiAg0ACwsgAAtmAgF/AX4gAa1CGH4iA0IgiKcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiADp2oiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALZwIBfwF+IAGtQuwBfiIDQiCIpwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAOnaiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtmAgF/AX4gAa1CKH4iA0IgiKcEQCAAQQA2AgQPCwJAAkAgAUELakF4cSICIAFBBGpJDQAgAiADp2oiASACSQ0AIAFBeUkNAQsgAEEANgIEDwsgACACNgIIIABBCDYCBCAAIAE2AgALcAICfwF+AkACQCABrULkAH4iBEIgiKdFBEAgBKciAkF/TA0BAkAgAkUEQEEEIQMMAQsgAkEEENcBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDYAQALENgBAAsgAkEEQZTQwgAoAgAiAEECIAAbEQAAAAtnAQN/IwBBIGsiAiQAAkAgACgCACABEFwNACABQRxqKAIAIQMgASgCGCACQgQ3AxggAkIBNwIMIAJBvIXAADYCCCADIAJBCGoQGw0AIAAoAgQgARBcIAJBIGokAA8LIAJBIGokAEEBC24BAn8CQAJAIAEgAUH/////AHFGBEAgAUEEdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ1wEiA0UNAwsgACABNgIEIAAgAzYCAA8LENgBAAsQ2AEACyACQQRBlNDCACgCACIAQQIgABsRAAAAC24BAn8CQAJAIAEgAUH/////A3FGBEAgAUECdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ1wEiA0UNAwsgACABNgIEIAAgAzYCAA8LENgBAAsQ2AEACyACQQRBlNDCACgCACIAQQIgABsRAAAAC24BAn8CQAJAIAEgAUH/////AXFGBEAgAUEDdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ1wEiA0UNAwsgACABNgIEIAAgAzYCAA8LENgBAAsQ2AEACyACQQRBlNDCACgCACIAQQIgABsRAAAAC2YBAX8gASABQf////8BcUcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiABQQN0aiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtmAQF/IAEgAUH/////AHFHBEAgAEEANgIEDwsCQAJAIAFBB2pBfHEiAiABQQRqSQ0AIAIgAUEEdGoiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALbQECfwJAAkAgASABQf///z9xRgRAIAFBBXQiAkF/TA0BAkAgAkUEQEEIIQMMAQsgAkEIENcBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDYAQALENgBAAsgAkEIQZTQwgAoAgAiAEECIAAbEQAAAAtgAQJ/AkAgACgCACIBRQ0AIABBCGooAgAiAgRAIAJBBHQhAiABQQhqIQEDQCABKAIABEAgAUF8aigCABAhCyABQRBqIQEgAkFwaiICDQALCyAAKAIERQ0AIAAoAgAQIQsLcAEBfyMAQTBrIgIkACACIAE2AgQgAiAANgIAIAJBHGpBAjYCACACQSxqQQU2AgAgAkICNwIMIAJBwILAADYCCCACQQU2AiQgAiACQSBqNgIYIAIgAkEEajYCKCACIAI2AiAgAkEIakHQgsAAEMQBAAttAQF/IwBBMGsiAyQAIAMgAjYCBCADIAE2AgAgA0EcakECNgIAIANBLGpBBTYCACADQgI3AgwgA0GkgMAANgIIIANBBTYCJCADIANBIGo2AhggAyADNgIoIAMgA0EEajYCICADQQhqIAAQxAEAC3ABAX8jAEEwayICJAAgAiABNgIEIAIgADYCACACQRxqQQI2AgAgAkEsakEFNgIAIAJCAjcCDCACQYiDwAA2AgggAkEFNgIkIAIgAkEgajYCGCACIAJBBGo2AiggAiACNgIgIAJBCGpBmIPAABDEAQALVgECfyMAQSBrIgIkACABQRxqKAIAIQMgASgCGCACQRhqIABBEGopAgA3AwAgAkEQaiAAQQhqKQIANwMAIAIgACkCADcDCCADIAJBCGoQGyACQSBqJAALUwAgAEEMaigCAARAIAAoAggQIQsgAEEYaigCAARAIABBFGooAgAQIQsgAEEkaigCAARAIABBIGooAgAQIQsgAEEwaigCAARAIABBLGooAgAQIQsLZAECfyMAQSBrIgIkACABKAIAIQMgAkEIaiABKAIIIgEQpQEgAkEANgIYIAIgAikDCDcDECACQRBqIAMgAyABQQJ0ahC9ASAAQQhqIAIoAhg2AgAgACACKQMQNwIAIAJBIGokAAtaAQN/AkAgAUEIaigCACICIAEoAgQiA08EQCABQRxqKAIAIgQgAkkNASABKAIYIQEgACACIANrNgIEIAAgASADQQJ0ajYCAA8LIAMgAhCtAQALIAIgBBCrAQALVwEDfwJAIAFBCGooAgAiAiABKAIEIgNPBEAgAUEkaigCACIEIAJJDQEgASgCICEBIAAgAiADazYCBCAAIAEgA2o2AgAPCyADIAIQrQEACyACIAQQqwEAC2oBAn9BASEAAkACQEGI0MIAKAIAQQFHBEBBiNDCAEKBgICAEDcDAAwBC0GM0MIAQYzQwgAoAgBBAWoiADYCACAAQQJLDQELQZDQwgAoAgAiAUF/TA0AQZDQwgAgATYCACAAQQFLDQAACwALWAEDfyAAKAIEKAIAIgMgAigCACIEQQxsakEDIAMgASgCACIFQQxsakEDEJkBQf8BcUH/AUYEQCABIAQ2AgAgAiAFNgIAIAAoAggiACAAKAIAQQFqNgIACwtLAQN/IABBDGooAgAiAwRAIAAoAgQgA0E8bGpBRGohAiADIQEDQCACEK8BIAJBRGohAiABQX9qIgENAAsLIABBADYCACAAIAE2AgwLVAEBfwJAIAFBf0oEQAJAIAFFBEBBASECDAELIAFBARDXASICRQ0CCyAAIAE2AgQgACACNgIADwsQ2AEACyABQQFBlNDCACgCACIAQQIgABsRAAAAC04BAn9BfyABKAI4IgIgACgCOCIDRyACIANJGyICBH8gAgUgAEEgaigCACAAQShqKAIAIAFBIGooAgAgAUEoaigCABCZAQtB/wFxQf8BRgtSAQN/IAAoAgQoAgAiAyACKAIAIgRBAnRqKAIAIAMgASgCACIFQQJ0aigCABC3AQRAIAEgBDYCACACIAU2AgAgACgCCCIAIAAoAgBBAWo2AgALC04BAX8jAEEQayICJAAgAiAAIAFBAUEBEGQCQCACKAIAQQFGBEAgAkEIaigCAEUNAUGjysIAQShBzMrCABC+AQALIAJBEGokAA8LENMBAAtDAQN/AkAgAkUNAANAIAAtAAAiBCABLQAAIgVGBEAgAUEBaiEBIABBAWohACACQX9qIgINAQwCCwsgBCAFayEDCyADC0oAAn8gAUGAgMQARwRAQQEgACgCGCABIABBHGooAgAoAhARAQANARoLIAJFBEBBAA8LIAAoAhggAiADIABBHGooAgAoAgwRAwALC0YBAn8gACgCCCICIAFLBEAgACgCACACQeQAbGpBnH9qIQMDQCADEMIBIANBnH9qIQMgASACQX9qIgJHDQALCyAAIAI2AggLOgEBfyAAIAAoAgggAiABa0ECdiICEIYBIAAgACgCCCIDIAJqNgIIIAAoAgAgA0ECdGogAiABIAIQdgs7AQF/IwBBIGsiAyQAIANCBDcDECADQgE3AgQgAyABNgIcIAMgADYCGCADIANBGGo2AgAgAyACEMQBAAszAQF/IAIEQCAAIQMDQCADIAEtAAA6AAAgA0EBaiEDIAFBAWohASACQX9qIgINAAsLIAALRQECfyMAQRBrIgEkACAAKAIMIQIgACgCCEUEQEGFycIAQStBsMnCABC+AQALIAEgAikCCDcDCCABIAIpAgA3AwAQswEACysAAkAgAEF8Sw0AIABFBEBBBA8LIAAgAEF9SUECdBDXASIARQ0AIAAPCwALJgAgAEEsaigCAARAIAAoAigQIQsgAEE4aigCAARAIAAoAjQQIQsLKgEBfwJAIAAQAyIBRQ0AIAFBfGotAABBA3FFDQAgAUEAIAAQxQEaCyABCzIBAX8jAEEQayICJAAgAiABNgIMIAIgADYCCCACQbSAwAA2AgQgAkEBNgIAIAIQwAEACykBAX8gAgRAIAAhAwNAIAMgAToAACADQQFqIQMgAkF/aiICDQALCyAACz4AQYDLwgAoAgBFBEBBhMvCAEIENwIAQYDLwgBBsKbCADYCAEH4ysIAQgA3AgBBjMvCAEEANgIAC0H4ysIACz4AQZjLwgAoAgBFBEBBnMvCAEIENwIAQZjLwgBBsKbCADYCAEGQy8IAQgA3AgBBpMvCAEEANgIAC0GQy8IACyAAAkAgAUF8Sw0AIAAgAUEEIAIQ1AEiAEUNACAADwsACyYAIAAoAgAgASACELgBIAAoAgAgAiADELgBIAAoAgAgASACELgBCyYAIAAoAgAgASACEIoBIAAoAgAgAiADEIoBIAAoAgAgASACEIoBCyYAIAAoAgAgASACELQBIAAoAgAgAiADELQBIAAoAgAgASACELQBCyYBAX8gACgCACIBKAIAIAEoAgQgACgCBCgCACAAKAIIKAIAEBoACxQAIAAgACABIAAgAWMbIAEgAWIbCxwAIAEoAhhB+KnAAEELIAFBHGooAgAoAgwRAwALHAAgASgCGEGDqsAAQQ4gAUEcaigCACgCDBEDAAscACABKAIYQfrIwgBBCyABQRxqKAIAKAIMEQMACxQAIAAoAgAgASAAKAIEKAIMEQEACwsAIAEEQCAAECELCxIAQYCAwABBEUGUgMAAEL4BAAsMACAAIAEgAiADECILCwAgADUCACABEFALBgAQswEACxkAAn8gAUEJTwRAIAEgABBFDAELIAAQAwsLBgAQ0wEACw0AQrOa5o7J8obc7QALAwABCwMAAQsLppwCiQwAQYCAwAAL/AVjYXBhY2l0eSBvdmVyZmxvdwAAAAylEAAXAAAACQMAAAUAAABEABAAIAAAAGQAEAASAAAACwAAAAAAAAABAAAADAAAAGluZGV4IG91dCBvZiBib3VuZHM6IHRoZSBsZW4gaXMgIGJ1dCB0aGUgaW5kZXggaXMgMDAwMTAyMDMwNDA1MDYwNzA4MDkxMDExMTIxMzE0MTUxNjE3MTgxOTIwMjEyMjIzMjQyNTI2MjcyODI5MzAzMTMyMzMzNDM1MzYzNzM4Mzk0MDQxNDI0MzQ0NDU0NjQ3NDg0OTUwNTE1MjUzNTQ1NTU2NTc1ODU5NjA2MTYyNjM2NDY1NjY2NzY4Njk3MDcxNzI3Mzc0NzU3Njc3Nzg3OTgwODE4MjgzODQ4NTg2ODc4ODg5OTA5MTkyOTM5NDk1OTY5Nzk4OTkAAGABEAAGAAAAZgEQACIAAAAIoRAAGAAAAGgKAAAFAAAAaW5kZXggIG91dCBvZiByYW5nZSBmb3Igc2xpY2Ugb2YgbGVuZ3RoIKgBEAAWAAAAvgEQAA0AAAAIoRAAGAAAAG4KAAAFAAAAc2xpY2UgaW5kZXggc3RhcnRzIGF0ICBidXQgZW5kcyBhdCBgMHhbLi4uXQB6AhAACwAAAOIUEAAWAAAAywEQAAEAAABkAhAAFgAAAAMIAAAJAAAAwBQQAA4AAADOFBAABAAAANIUEAAQAAAAywEQAAEAAABkAhAAFgAAAAcIAAAFAAAAegIQAAsAAACFAhAAJgAAAKsCEAAIAAAAswIQAAYAAADLARAAAQAAAGQCEAAWAAAAFAgAAAUAAABzcmMvbGliY29yZS9zdHIvbW9kLnJzYnl0ZSBpbmRleCAgaXMgbm90IGEgY2hhciBib3VuZGFyeTsgaXQgaXMgaW5zaWRlICAoYnl0ZXMgKSBvZiBgAAAA+gIQAAIAAADkAhAAFgAAAGAEAAARAAAA5AIQABYAAABUBAAAKAAAAHNyYy9saWJjb3JlL2ZtdC9tb2QucnMuLgBB4IbAAAsO//////////////////8AQZCHwAALAvgDAEGyh8AACwf+/////7+2AEHCh8AACw3/BwAAAAAA+P//AAABAEHah8AACxDAn589AAAAAAIAAAD///8HAEH0h8AAC7gVwP8BAAAAAAAA+A8goA4QAEoAAADwEBAAAAIAAPASEAA6AAAAAAECAwQFBgcICQgKCwwNDg8QERITFAIVFhcYGRobHB0eHyACAgICAgICAgICIQICAgICAgICAgICAgICIiMkJSYCJwIoAgICKSorAiwtLi8wAgIxAgICMgICAgICAgICMwICNAICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICNQI2AjcCAgICAgICAjgCOQICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICOjs8AgICAj0CAj4/QEFCQ0RFRgICAkcCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICSAICAgICAgICAgICSQICAgICOwIAAQICAgIDAgICAgQCBQYCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICBwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAEDBQUGBgMHBggICREKHAsZDBQNEg4NDwQQAxISEwkWARcFGAIZAxoHHAIdAR8WIAMrBCwCLQsuATADMQIyAacCqQKqBKsI+gL7Bf0E/gP/Ca14eYuNojBXWIuMkBwd3Q4PS0z7/C4vP1xdX7XihI2OkZKpsbq7xcbJyt7k5f8ABBESKTE0Nzo7PUlKXYSOkqmxtLq7xsrOz+TlAAQNDhESKTE0OjtFRklKXmRlhJGbncnOzw0RKUVJV2RljZGptLq7xcnf5OXwBA0RRUlkZYCBhLK8vr/V1/Dxg4WLpKa+v8XHzs/a20iYvc3Gzs9JTk9XWV5fiY6Psba3v8HGx9cRFhdbXPb3/v+ADW1x3t8ODx9ubxwdX31+rq+7vPoWFx4fRkdOT1haXF5+f7XF1NXc8PH1cnOPdHWWly9fJi4vp6+3v8fP19+aQJeYMI8fwMHO/05PWlsHCA8QJy/u725vNz0/QkWQkf7/U2d1yMnQ0djZ5/7/ACBfIoLfBIJECBsEBhGBrA6AqzUeFYDgAxkIAQQvBDQEBwMBBwYHEQpQDxIHVQgCBBwKCQMIAwcDAgMDAwwEBQMLBgEOFQU6AxEHBgUQB1cHAgcVDVAEQwMtAwEEEQYPDDoEHSVfIG0EaiWAyAWCsAMaBoL9A1kHFQsXCRQMFAxqBgoGGgZZBysFRgosBAwEAQMxCywEGgYLA4CsBgoGH0FMBC0DdAg8Aw8DPAc4CCsFgv8RGAgvES0DIBAhD4CMBIKXGQsViJQFLwU7BwIOGAmAsDB0DIDWGgwFgP8FgLYFJAybxgrSMBCEjQM3CYFcFIC4CIDHMDUECgY4CEYIDAZ0Cx4DWgRZCYCDGBwKFglICICKBqukDBcEMaEEgdomBwwFBYClEYFtEHgoKgZMBICNBIC+AxsDDw0ABgEBAwEEAggICQIKBQsCEAERBBIFExEUAhUCFwIZBBwFHQgkAWoDawK8AtEC1AzVCdYC1wLaAeAF4QLoAu4g8AT5BvoCDCc7Pk5Pj56enwYHCTY9Plbz0NEEFBg2N1ZXvTXOz+ASh4mOngQNDhESKTE0OkVGSUpOT2RlWly2txscqKnY2Qk3kJGoBwo7PmZpj5JvX+7vWmKamycoVZ2goaOkp6iturzEBgsMFR06P0VRpqfMzaAHGRoiJT4/xcYEICMlJigzODpISkxQU1VWWFpcXmBjZWZrc3h9f4qkqq+wwNAMcqOky8xub14iewUDBC0DZQQBLy6Agh0DMQ8cBCQJHgUrBUQEDiqAqgYkBCQEKAg0CwGAkIE3CRYKCICYOQNjCAkwFgUhAxsFAUA4BEsFLwQKBwkHQCAnBAwJNgM6BRoHBAwHUEk3Mw0zBy4ICoEmH4CBKAgqgIYXCU4EHg9DDhkHCgZHCScJdQs/QSoGOwUKBlEGAQUQAwWAi2AgSAgKgKZeIkULCgYNEzkHCjYsBBCAwDxkUwwBgKBFG0gIUx05gQdGCh0DR0k3Aw4ICgY5BwqBNhmAxzINg5tmdQuAxIq8hC+P0YJHobmCOQcqBAJgJgpGCigFE4KwW2VLBDkHEUAEHJf4CILzpQ2BHzEDEQQIgYyJBGsFDQMJBxCTYID2CnMIbhdGgJoUDFcJGYCHgUcDhUIPFYVQK4DVLQMaBAKBcDoFAYUAgNcpTAQKBAKDEURMPYDCPAYBBFUFGzQCgQ4sBGQMVgoNA10DPTkdDSwECQcCDgaAmoPWCg0DCwV0DFkHDBQMBDgICgYoCB5SdwMxA4CmDBQEAwUDDQaFagAAAIAOEAAgAAAAJwAAABkAAACADhAAIAAAACgAAAAgAAAAgA4QACAAAAAqAAAAGQAAAIAOEAAgAAAAKwAAABgAAACADhAAIAAAACwAAAAgAAAAc3JjL2xpYmNvcmUvdW5pY29kZS9ib29sX3RyaWUucnMAAMD77z4AAAAAAA4AQbqdwAALkgH4//v///8HAAAAAAAAFP4h/gAMAAAAAgAAAAAAAFAeIIAADAAAQAYAAAAAAAAQhjkCAAAAIwC+IQAADAAA/AIAAAAAAADQHiDAAAwAAAAEAAAAAAAAQAEggAAAAAAAEQAAAAAAAMDBPWAADAAAAAIAAAAAAACQRDBgAAwAAAADAAAAAAAAWB4ggAAMAAAAAIRcgABB1p7AAAsE8geAfwBB5p7AAAsE8h8APwBB857AAAsWAwAAoAIAAAAAAAD+f9/g//7///8fQABBlZ/AAAulAeD9ZgAAAMMBAB4AZCAAIAAAAAAAAADgAAAAAAAAHAAAABwAAAAMAAAADAAAAAAAAACwP0D+DyAAAAAAADgAAAAAAABgAAAAAAIAAAAAAACHAQQOAACACQAAAAAAAEB/5R/4nwAAAAAAAP9/DwAAAAAA8BcEAAAAAPgPAAMAAAA8OwAAAAAAAECjAwAAAAAAAPDPAAAA9//9IRAD//////////sAEABBwqDAAAsN/////wEAAAAAAACAAwBB16DAAAsVgAAAAAD/////AAAAAAD8AAAAAAAGAEH1oMAACweA9z8AAADAAEGGocAACy8DAEQIAABgAAAAMAAAAP//A4AAAAAAwD8AAID/AwAAAAAABwAAAAAAyDMAAAAAIABBvaHAAAsxfmYACBAAAAAAABAAAAAAAACdwQIAAAAAMEAAAAAAACAhAAAAAABAAAAAAP//AAD//wBB96HAAAsHAQAAAAIAAwBBmKLAAAsEBAAABQBBpKLAAAsBBgBBraLAAAs/BwAACAkKAAsMDQ4PAAAQERIAABMUFRYAABcYGRobABwAAAAdAAAAAAAAHh8gIQAAAAAAIgAjACQlJgAAAAAnAEHbo8AACwIoKQBB7aPAAAsCKisAQaKkwAALASwAQbWkwAALBS0uAAAvAEHYpMAACwMwMTIAQfCkwAALDDMAAAApAAAAAAAANABBk6XAAAsDNQA2AEGwpcAACwg3OAAAODg4OQBB/6XAAAsGIAAAAAABAEGOpsAAC1TAB27wAAAAAACHAAAAAGAAAAAAAAAA8AAAAMD/AQAAAAAAAgAAAAAAAP9/AAAAAAAAgAMAAAAAAHgGBwAAAIDvHwAAAAAAAAAIAAMAAAAAAMB/AB4AQe2mwAALGoDTQAAAAID4BwAAAwAAAAAAAFgBAIAAwB8fAEGPp8AACwX/XAAAQABBnqfAAAsD+aUNAEGtp8AACweAPLABAAAwAEG+p8AACwP4pwEAQc2nwAALLyi/AAAAAOC8DwAAAAAAAACA/wYAAPAMAQAAAP4HAAAAAPh5gAB+DgAAAAAA/H8DAEGGqMAACxN/vwAA/P///G0AAAAAAAAAfrS/AEGiqMAACwGjAEGuqMAACx0YAAAAAAAAAB8AAAAAAAAAfwAAgAAAAAAAAACABwBB06jAAAsBYABB3KjAAAtGoMMH+OcPAAAAPAAAHAAAAAAAAAD///////9/+P//////HyAAEAAA+P7/AAB////52wcAAAAAAAAA8AAAAAB/AAAAAADwBwBBrKnAAAv5Av///////////////////////wAAYmVnaW4gPD0gZW5kICggPD0gKSB3aGVuIHNsaWNpbmcgYCBpcyBvdXQgb2YgYm91bmRzIG9mIGBCb3Jyb3dFcnJvckJvcnJvd011dEVycm9yAAAASKQQAAAAAAAkFRAAAgAAADogAAA4FRAAFQAAAI0EAAAFAAAAc3JjL2xpYmNvcmUvcmVzdWx0LnJzAAECAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAwEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEEBQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAwAAgA4QACAAAAA6AAAAMAAAAAA+AAABAEGwrMAACywgAAAAAQAAAAEAAAAAAAAA/wcAAACDAAAAAACAAAAAAAABAAAAAAAA/////wBB56zAAAsJgAAAAAAAAP8DAEH+rMAACwIMcgBBtK7AAAsC/wMAQcauwAALAv8DAEHgrsAACx3/AwAAAAAAAGAcEAAhAAAAaB0QAAACAABoHxAALgBBha/AAAseAQACAAEAAQADAAQABQABAAYAAQAHAAcIAAAAAAkHAEGtr8AACwEKAEG7r8AACxcLAAAADAcAAAAADQAOAAAPAAAHEAAADwBB4a/AAAsGEQkAABITAEHxr8AACwMUFRYAQf2vwAALAhcYAEGTsMAACwEZAEGgsMAACwsaAAAAAAAbABwdHgBB+LPAAAsYHAAAAQAAAAAfAAAHCQAAIAAHAAAAAAAQAEHctsAAC4QCBwAAAAABAgMDAwQDAwMDAwMFBgcDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMAQey4wAALMMD/AAAAAAAAwP/wAwAAAADA//wAAAAAAMD/BwAAAAAAwP8AfwAAAH/A//8BAAD/AwBBpLnAAAsG//8PAP8DAEG1ucAACxX+/x8AAAAAAMABAAAAAAD/A/8DwP8AQdK5wAALCv8HAAAAAP8D/wMAQea5wAALFP8DAAAAAAAA8QMAAP///////+cDAEGEusAACwj/////////DwBBlbrAAAsO/P//AAAAAAAAwP///w8AQa+6wAALDCCAAAAA/gMABwAAPABBxLrAAAs3/wMAAAD//v8AAAAA/wMAAAAA/v8AAAAAAAA/AAAA/wMAAP8DAAAAAAECAwAAAAAEBQYABwAACABBibvAAAtDCQoLDAANDgAPEBEAEhMAAAAAFBUAAAAAFgAAFxgAAAAZABUaAAAbAAAAFQAAAAAAHAAcAAAAAAAcAB0eAAAAAAAAHwBB2bvAAAsGIAAAABwIAEHnu8AACwEhAEH4u8AACwIiIwBBkb3AAAsFCAAAACQAQaK9wAALASUAQbO9wAALAyYAJwBBx73AAAsBKABB7b3AAAsHHQAAAAAAFQBBi77AAAsDKQAcAEGZvsAACwQqKwAsAEGsvsAACwEtAEHwvsAACxKA//////8PAP////////8BAAwAQYy/wAALDv7//w8AAAAADwAAAAIEAEGiv8AACwE+AEGsv8AACxL/AwAAAAAA/wAAAP4AAAAAgP8AQce/wAALBfgAAMAPAEHXv8AACxUw///8////////AQAAAAAAYAAAAOAAQfW/wAALEfgAAAAAAP8AAAD/AAAAAAD+AEGPwMAACyf8AAAAAAAA/wMAAAAA////fwAAAOB/AAAAAAAeAAAAAAAAAPz///8AQb7AwAALFMD/AAD/A/7/HwAAAP8DAAAAAP8DAEHewMAACzX/DwAAAAD//wcAAAD///8fAAD//x8AAAAAAP////////////////9/AAAAAP/7AwAAAP//fwBBnMHAAAsW//8PAAAAAAD///8BAMD///////+A/wBBvsHAAAsU/v///////+8eAP7/////v/8//x8AQeDBwAALVv7//wf+//8HAAAAAAAEIAT//3////9/////////////////////////////////////////////////////////////////////////////w/8DAB9QAEHAwsAAC4gNIAAAAAAA37xA1///+////////////7///////////////////////wP8///////////////////////////+////fwL//////wEAAAAA/7+2AP///4cHAAAA/wf//////////gDA////////////////7x/+4QCcAAD///////8A4P///////////////wMAAPz///8HMATQJhAAhgAAAAArEACABAAAgC8QAG8AAAAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHyAhIiMkJCQkJCUmJygpKissJCQkJCQkJCQtLi8wMTIzNDU2Nzg5Ojs8PT4fP0BBQkNERUYkJCRHJCQkJEhJSksfTE0fTk9QHx8fHx8fHx8fHx9RUh8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx9TVCRVVldYWVofHx8fHx8fWyxcXV4kX2AfHx8fHx8fHyQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJDcfJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkYSQkJCQkJCQkJCQkJCQkJCQkJGJjJCQkJGRlJGFmJGdoaWoka2xtbm9DcHFyc3QkdSQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJHZ3Hx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fJCQkJCR4JHl6e3x9JCQkJH4hf4AfgSSCg4RxhQABAgMEBQYHCAUFCQUKCwwHBwcHBwcHBwcHDQ4PBxARBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQX////8/x8AAP///wH/BwAAAAAAAP//3z8AAPD/+AP////////////v/9/h/w8A/v/vn/n///3F459ZgLAPAAMQ7of5///9bcOHGQJeAAA/AO6/+////e3jvxsBAA8AAB7un/n///3t458ZwLAPAAIA7Mc91hjH/8PHHYEAAAAAAO/f/f///f/j3x1gBw8AAADv3/3///3v498dYEAPAAYA79/9/////+ffXfCADwAA/Oz/f/z///svf4Bf/wAADAD+////////B38gAAAAAAAA1vf//6///ztfIADwAAAAAAEAAAAAAAAA//7///8f/v8D///+////HwBB0M/AAAucAf///////3/5AAD//////////wA8/////78g///////3////////////PX89//////89/////z1/Pf9//////////z3//////////wcAAAAA//8AAP////////////8/P/7///////////////+f///+//8H////////////x/8B/98PAP//DwD//w8A/98NAP///////8///wGAEABB9NDAAAveAf//////////////Af//////B///////////PwD///9//w//AQAA////Px8A//////8P////AwAAAAAAAP///w//////////f/7/HwAAAAAAgAAAAP///////+//7w8AAAAAAAD///////MA/P////+//wMA////////fwAA4AD8////P/8B///////nAAAAAADebwQAAAAAgP8fAP//Pz//////Pz//qv///z/////////fX9wfzw//H9wfAAAAAAAAAoAAAP8fAAAAAIT8Lz5Qvf/z4EMAAP//////AQBB3tLAAAuEAcD///////8DAAD//////3///////3///////////x94DAD/////vyD/////////gAAA//9/AH9/f39/f39//////wAAAAAAgAAA4AAAAP4DPh///3/g/v/////////////34P///////v//fwAA////BwAAAAAAAP//////////AAD/HwBB6tPAAAu2C///////P/8f//8ADAAA//////9/8I8AAID//P/////5////////fAAAAAAAgP+//////wAAAP///////w8ALwAAAAAA/OgA/P///wf/////BwD///8f////////9/8AgAAA//8AfP8/AAD//3/8/////////38FAAA4//88AH5+fgB/f///////9/8A/////////wcAAP////8PAP//f/j//////w///////z///////wMAAAAAfwD44P/9f1/b/////////////////wMAAAD4////////////////P////P///////wAAAAAA/w8AAAAAAADf//////////8fAAAAAP7//wf+//8HwP////z8/BwAAAAAAAECAwQFBAQEBAYHCAkKCwICDA0ODwQEAgICAhARBAQSExQVFgQXBBgZGhscHR4EAh8gICEEBAQEBAQEIiMEJAIjJSYgJwIoKQQqKywtBAQCLgIvBAQwMTIzNAQ1BAQENgQ3OAQEOTo7PD02BAQEBD4/QARBQkMEBAQEJAQEBAQCAgICAgICAgICAgICAkQEAkUCAgJGBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgICAgICAgICAkUEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAgJHBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAjYUBEhJSksEBAQEBAQEBAQEAgQEAkxNTgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICTwICAgICAgICAgICIAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAhRQAgICAgJRBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAlJTBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCVFVWV1gCAgICWVpbXF1eBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBARfBAQEYGEEBAQEBGIEBAQEBAQEBAQEBAQEBAQEBAQEBAICAmMCZAQEBAQEBAQEBAQEBAQEBAQEBGVmZwQEBAQEBAQEBGhpagQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICAgICAgICAgICAgICAgICAgICAmsCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICBQICAgoCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAmwCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICbQQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICbgQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE/+///3///7f/P/8/AAAAAP///////////////////wcAQajfwAALfP///////x8A////H////////wEAAAAAAP////8A4P///wf//////wf///8//////w//PgAAAAAA////PwAA/////w//////D///////AP///////w8AAAD///////9/AP//PwD/AAAAP/3/////v5H//z8A//9/AP///38AQazgwAALbf//NwD//z8A////A//////////Ab/Dv/v//PwAAAAAA////H////x8AAAAA//7//x8AAAD///////8/AP//PwD//wcA//8DAAAAAAD/AQAAAAAAAP///////wcA//////8AAAD///8fgAD//z8AQaThwAALlQL//38A/P///////wEAAP///wEAAHAA/////0cAHgAAFAAAAAD///v///+fQH+9/7//Af////////8BAADvn/n///3t458ZgeAPAAAAuwcAgAAAAACzAAAAAAAAAP///////z9/AAAAPwAAAAD/////////fxEAAAAAAAAA////////PwH////n/wcAAP////////8BAAAAAP//////////AAAAgAAAAAD//P///////BoAAAD////////nfwAA////////////IAAAAAD//f////9/fwEAAAAAAPz////8///+fwB/+/////9/tMsAAAC//f///397AQAAAAD///8DAAAAAP//////fwAADwAAAAAAAAB/AEHC48AACyr///8/AAD///////8AAA8AAAD4///g//8AAAAAAAD/h/////////+A//8AQfTjwAALgAILAAAA/////////wAAAAcA8AD///////////8P//////8H/x//Af9DAAAAAP//3///////////32Te/+vv/////////7/n39////97X/z9////////////P/////3///f////3///f////3///f////3/////9/////f//9w8AAAAAAAB////52wcAAP//////H4A/AEAAAAAAAAD//////w8AAB8AAAAAAAAAjwgAAAAAAADv////lv73CoTqlqqW9/de//v/D+77/w8AAAAAAAD///8D////A////wMAAAAAAAD//38AAAAAAP////8DAP///////wEAAAD///8/AEGA5sAACwT+//8HAEGQ5sAACzL//39/AAAAAFVVVVVVVVWqqlRVVVVVVSvWztux1dKuEZCkqkpVVdJVVVVVVVVVBWx6VQBB5ubAAAs9RYBA1/7/+w8AAACAHFVVVZDm////////AAAAAAAAVVVVVQFUVVVVVVVVqypVVVVVVVVVVVVVVVX+////fwBB+OfAAAsV8DgQABoAAADAORAAwAEAAIA7EAAaAEGy6MAACwIBAgBBvujAAAsCAQMAQeLowAALFQQAAAAAAAUFBgUHCAkKAAAAAAsMDQBBgunAAAsCDg8AQaDpwAALBBARBRIAQYntwAALBxMUABUWFxgAQezvwAALhAIZAAAAAAECAgICAwICAgICAgQFBgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgBB/PHAAAs6/////78gAAAAAAAA////////PwAAAP//////51VVVVVVVVVVVVUVQFVVVVUA/wA/AP8A/wA/AKoA/wBBv/LAAAsaDwAPAA8AHwAPhDgnPlA9D8AgAAAA//8AAAgAQebywAALLsD///8AAAAAAAD//////38AAAAAAACd6iXAVVVVVQUoBABVVVVVVRUAAFVVVQUAQZzzwAALFVRVVFVVVVVVVVUAalUoRVVVfV9VdABBvPPAAAsE/v//BwBB0PPAAAsEAQACAwBB8vPAAAsBBABBovTAAAsBBQBBufXAAAsBBgBB0PXAAAsQBwgJCgsMDQ4PEBESExQVFgBBpPbAAAsBFwBBxPbAAAsDAhgZAEGI98AACwX//////wBBlvfAAAu/Af////8PAAAAAAD///////8HAAAAAAD//////////wAAAAD///8DAADw//8/AAAA////AwAA0GTePwAAAP///wMAALDn3x8AAAB7X/wBAADw//8/AAAA////AwAA8P//PwAAAP///wMAAPD//z8AAAD///8DAAAA////AQAAAPz//wcAAADw//8fAAAAwP//fwAAAAD///8BAAAABAAAAAAAAP////8DAAAA/wP///8D////AwAAAAAAAEEAAABhAEHg+MAACwVCAAAAYgBB8PjAAAsFQwAAAGMAQYD5wAALBUQAAABkAEGQ+cAACwVFAAAAZQBBoPnAAAsFRgAAAGYAQbD5wAALBUcAAABnAEHA+cAACwVIAAAAaABB0PnAAAsFSQAAAGkAQeD5wAALBUoAAABqAEHw+cAACwVLAAAAawBBgPrAAAsFTAAAAGwAQZD6wAALBU0AAABtAEGg+sAACwVOAAAAbgBBsPrAAAsFTwAAAG8AQcD6wAALBVAAAABwAEHQ+sAACwVRAAAAcQBB4PrAAAsFUgAAAHIAQfD6wAALBVMAAABzAEGA+8AACwVUAAAAdABBkPvAAAsFVQAAAHUAQaD7wAALBVYAAAB2AEGw+8AACwVXAAAAdwBBwPvAAAsFWAAAAHgAQdD7wAALBVkAAAB5AEHg+8AACwVaAAAAegBB8PvAAAsFwAAAAOAAQYD8wAALBcEAAADhAEGQ/MAACwXCAAAA4gBBoPzAAAsFwwAAAOMAQbD8wAALBcQAAADkAEHA/MAACwXFAAAA5QBB0PzAAAsFxgAAAOYAQeD8wAALBccAAADnAEHw/MAACwXIAAAA6ABBgP3AAAsFyQAAAOkAQZD9wAALBcoAAADqAEGg/cAACwXLAAAA6wBBsP3AAAsFzAAAAOwAQcD9wAALBc0AAADtAEHQ/cAACwXOAAAA7gBB4P3AAAsFzwAAAO8AQfD9wAALBdAAAADwAEGA/sAACwXRAAAA8QBBkP7AAAsF0gAAAPIAQaD+wAALBdMAAADzAEGw/sAACwXUAAAA9ABBwP7AAAsF1QAAAPUAQdD+wAALBdYAAAD2AEHg/sAACwXYAAAA+ABB8P7AAAsF2QAAAPkAQYD/wAALBdoAAAD6AEGQ/8AACwXbAAAA+wBBoP/AAAsF3AAAAPwAQbD/wAALBd0AAAD9AEHA/8AACwXeAAAA/gBB0f/AAAsFAQAAAQEAQeD/wAALBgIBAAADAQBB8P/AAAsGBAEAAAUBAEGAgMEACwYGAQAABwEAQZCAwQALBggBAAAJAQBBoIDBAAsGCgEAAAsBAEGwgMEACwYMAQAADQEAQcCAwQALBg4BAAAPAQBB0IDBAAsGEAEAABEBAEHggMEACwYSAQAAEwEAQfCAwQALBhQBAAAVAQBBgIHBAAsGFgEAABcBAEGQgcEACwYYAQAAGQEAQaCBwQALBhoBAAAbAQBBsIHBAAsGHAEAAB0BAEHAgcEACwYeAQAAHwEAQdCBwQALBiABAAAhAQBB4IHBAAsGIgEAACMBAEHwgcEACwYkAQAAJQEAQYCCwQALBiYBAAAnAQBBkILBAAsGKAEAACkBAEGggsEACwYqAQAAKwEAQbCCwQALBiwBAAAtAQBBwILBAAsGLgEAAC8BAEHQgsEACxYwAQAAaQAAAAcDAAAAAAAAMgEAADMBAEHwgsEACwY0AQAANQEAQYCDwQALBjYBAAA3AQBBkIPBAAsGOQEAADoBAEGgg8EACwY7AQAAPAEAQbCDwQALBj0BAAA+AQBBwIPBAAsGPwEAAEABAEHQg8EACwZBAQAAQgEAQeCDwQALBkMBAABEAQBB8IPBAAsGRQEAAEYBAEGAhMEACwZHAQAASAEAQZCEwQALBkoBAABLAQBBoITBAAsGTAEAAE0BAEGwhMEACwZOAQAATwEAQcCEwQALBlABAABRAQBB0ITBAAsGUgEAAFMBAEHghMEACwZUAQAAVQEAQfCEwQALBlYBAABXAQBBgIXBAAsGWAEAAFkBAEGQhcEACwZaAQAAWwEAQaCFwQALBlwBAABdAQBBsIXBAAsGXgEAAF8BAEHAhcEACwZgAQAAYQEAQdCFwQALBmIBAABjAQBB4IXBAAsGZAEAAGUBAEHwhcEACwZmAQAAZwEAQYCGwQALBmgBAABpAQBBkIbBAAsGagEAAGsBAEGghsEACwZsAQAAbQEAQbCGwQALBm4BAABvAQBBwIbBAAsGcAEAAHEBAEHQhsEACwZyAQAAcwEAQeCGwQALBnQBAAB1AQBB8IbBAAsGdgEAAHcBAEGAh8EACwV4AQAA/wBBkIfBAAsGeQEAAHoBAEGgh8EACwZ7AQAAfAEAQbCHwQALBn0BAAB+AQBBwIfBAAsGgQEAAFMCAEHQh8EACwaCAQAAgwEAQeCHwQALBoQBAACFAQBB8IfBAAsGhgEAAFQCAEGAiMEACwaHAQAAiAEAQZCIwQALBokBAABWAgBBoIjBAAsGigEAAFcCAEGwiMEACwaLAQAAjAEAQcCIwQALBo4BAADdAQBB0IjBAAsGjwEAAFkCAEHgiMEACwaQAQAAWwIAQfCIwQALBpEBAACSAQBBgInBAAsGkwEAAGACAEGQicEACwaUAQAAYwIAQaCJwQALBpYBAABpAgBBsInBAAsGlwEAAGgCAEHAicEACwaYAQAAmQEAQdCJwQALBpwBAABvAgBB4InBAAsGnQEAAHICAEHwicEACwafAQAAdQIAQYCKwQALBqABAAChAQBBkIrBAAsGogEAAKMBAEGgisEACwakAQAApQEAQbCKwQALBqYBAACAAgBBwIrBAAsGpwEAAKgBAEHQisEACwapAQAAgwIAQeCKwQALBqwBAACtAQBB8IrBAAsGrgEAAIgCAEGAi8EACwavAQAAsAEAQZCLwQALBrEBAACKAgBBoIvBAAsGsgEAAIsCAEGwi8EACwazAQAAtAEAQcCLwQALBrUBAAC2AQBB0IvBAAsGtwEAAJICAEHgi8EACwa4AQAAuQEAQfCLwQALBrwBAAC9AQBBgIzBAAsGxAEAAMYBAEGQjMEACwbFAQAAxgEAQaCMwQALBscBAADJAQBBsIzBAAsGyAEAAMkBAEHAjMEACwbKAQAAzAEAQdCMwQALBssBAADMAQBB4IzBAAsGzQEAAM4BAEHwjMEACwbPAQAA0AEAQYCNwQALBtEBAADSAQBBkI3BAAsG0wEAANQBAEGgjcEACwbVAQAA1gEAQbCNwQALBtcBAADYAQBBwI3BAAsG2QEAANoBAEHQjcEACwbbAQAA3AEAQeCNwQALBt4BAADfAQBB8I3BAAsG4AEAAOEBAEGAjsEACwbiAQAA4wEAQZCOwQALBuQBAADlAQBBoI7BAAsG5gEAAOcBAEGwjsEACwboAQAA6QEAQcCOwQALBuoBAADrAQBB0I7BAAsG7AEAAO0BAEHgjsEACwbuAQAA7wEAQfCOwQALBvEBAADzAQBBgI/BAAsG8gEAAPMBAEGQj8EACwb0AQAA9QEAQaCPwQALBvYBAACVAQBBsI/BAAsG9wEAAL8BAEHAj8EACwb4AQAA+QEAQdCPwQALBvoBAAD7AQBB4I/BAAsG/AEAAP0BAEHwj8EACwb+AQAA/wEAQYGQwQALBQIAAAECAEGQkMEACwYCAgAAAwIAQaCQwQALBgQCAAAFAgBBsJDBAAsGBgIAAAcCAEHAkMEACwYIAgAACQIAQdCQwQALBgoCAAALAgBB4JDBAAsGDAIAAA0CAEHwkMEACwYOAgAADwIAQYCRwQALBhACAAARAgBBkJHBAAsGEgIAABMCAEGgkcEACwYUAgAAFQIAQbCRwQALBhYCAAAXAgBBwJHBAAsGGAIAABkCAEHQkcEACwYaAgAAGwIAQeCRwQALBhwCAAAdAgBB8JHBAAsGHgIAAB8CAEGAksEACwYgAgAAngEAQZCSwQALBiICAAAjAgBBoJLBAAsGJAIAACUCAEGwksEACwYmAgAAJwIAQcCSwQALBigCAAApAgBB0JLBAAsGKgIAACsCAEHgksEACwYsAgAALQIAQfCSwQALBi4CAAAvAgBBgJPBAAsGMAIAADECAEGQk8EACwYyAgAAMwIAQaCTwQALBjoCAABlLABBsJPBAAsGOwIAADwCAEHAk8EACwY9AgAAmgEAQdCTwQALBj4CAABmLABB4JPBAAsGQQIAAEICAEHwk8EACwZDAgAAgAEAQYCUwQALBkQCAACJAgBBkJTBAAsGRQIAAIwCAEGglMEACwZGAgAARwIAQbCUwQALBkgCAABJAgBBwJTBAAsGSgIAAEsCAEHQlMEACwZMAgAATQIAQeCUwQALBk4CAABPAgBB8JTBAAsGcAMAAHEDAEGAlcEACwZyAwAAcwMAQZCVwQALBnYDAAB3AwBBoJXBAAsGfwMAAPMDAEGwlcEACwaGAwAArAMAQcCVwQALBogDAACtAwBB0JXBAAsGiQMAAK4DAEHglcEACwaKAwAArwMAQfCVwQALBowDAADMAwBBgJbBAAsGjgMAAM0DAEGQlsEACwaPAwAAzgMAQaCWwQALBpEDAACxAwBBsJbBAAsGkgMAALIDAEHAlsEACwaTAwAAswMAQdCWwQALBpQDAAC0AwBB4JbBAAsGlQMAALUDAEHwlsEACwaWAwAAtgMAQYCXwQALBpcDAAC3AwBBkJfBAAsGmAMAALgDAEGgl8EACwaZAwAAuQMAQbCXwQALBpoDAAC6AwBBwJfBAAsGmwMAALsDAEHQl8EACwacAwAAvAMAQeCXwQALBp0DAAC9AwBB8JfBAAsGngMAAL4DAEGAmMEACwafAwAAvwMAQZCYwQALBqADAADAAwBBoJjBAAsGoQMAAMEDAEGwmMEACwajAwAAwwMAQcCYwQALBqQDAADEAwBB0JjBAAsGpQMAAMUDAEHgmMEACwamAwAAxgMAQfCYwQALBqcDAADHAwBBgJnBAAsGqAMAAMgDAEGQmcEACwapAwAAyQMAQaCZwQALBqoDAADKAwBBsJnBAAsGqwMAAMsDAEHAmcEACwbPAwAA1wMAQdCZwQALBtgDAADZAwBB4JnBAAsG2gMAANsDAEHwmcEACwbcAwAA3QMAQYCawQALBt4DAADfAwBBkJrBAAsG4AMAAOEDAEGgmsEACwbiAwAA4wMAQbCawQALBuQDAADlAwBBwJrBAAsG5gMAAOcDAEHQmsEACwboAwAA6QMAQeCawQALBuoDAADrAwBB8JrBAAsG7AMAAO0DAEGAm8EACwbuAwAA7wMAQZCbwQALBvQDAAC4AwBBoJvBAAsG9wMAAPgDAEGwm8EACwb5AwAA8gMAQcCbwQALBvoDAAD7AwBB0JvBAAsG/QMAAHsDAEHgm8EACwb+AwAAfAMAQfCbwQALBv8DAAB9AwBBgZzBAAsFBAAAUAQAQZCcwQALBgEEAABRBABBoJzBAAsGAgQAAFIEAEGwnMEACwYDBAAAUwQAQcCcwQALBgQEAABUBABB0JzBAAsGBQQAAFUEAEHgnMEACwYGBAAAVgQAQfCcwQALBgcEAABXBABBgJ3BAAsGCAQAAFgEAEGQncEACwYJBAAAWQQAQaCdwQALBgoEAABaBABBsJ3BAAsGCwQAAFsEAEHAncEACwYMBAAAXAQAQdCdwQALBg0EAABdBABB4J3BAAsGDgQAAF4EAEHwncEACwYPBAAAXwQAQYCewQALBhAEAAAwBABBkJ7BAAsGEQQAADEEAEGgnsEACwYSBAAAMgQAQbCewQALBhMEAAAzBABBwJ7BAAsGFAQAADQEAEHQnsEACwYVBAAANQQAQeCewQALBhYEAAA2BABB8J7BAAsGFwQAADcEAEGAn8EACwYYBAAAOAQAQZCfwQALBhkEAAA5BABBoJ/BAAsGGgQAADoEAEGwn8EACwYbBAAAOwQAQcCfwQALBhwEAAA8BABB0J/BAAsGHQQAAD0EAEHgn8EACwYeBAAAPgQAQfCfwQALBh8EAAA/BABBgKDBAAsGIAQAAEAEAEGQoMEACwYhBAAAQQQAQaCgwQALBiIEAABCBABBsKDBAAsGIwQAAEMEAEHAoMEACwYkBAAARAQAQdCgwQALBiUEAABFBABB4KDBAAsGJgQAAEYEAEHwoMEACwYnBAAARwQAQYChwQALBigEAABIBABBkKHBAAsGKQQAAEkEAEGgocEACwYqBAAASgQAQbChwQALBisEAABLBABBwKHBAAsGLAQAAEwEAEHQocEACwYtBAAATQQAQeChwQALBi4EAABOBABB8KHBAAsGLwQAAE8EAEGAosEACwZgBAAAYQQAQZCiwQALBmIEAABjBABBoKLBAAsGZAQAAGUEAEGwosEACwZmBAAAZwQAQcCiwQALBmgEAABpBABB0KLBAAsGagQAAGsEAEHgosEACwZsBAAAbQQAQfCiwQALBm4EAABvBABBgKPBAAsGcAQAAHEEAEGQo8EACwZyBAAAcwQAQaCjwQALBnQEAAB1BABBsKPBAAsGdgQAAHcEAEHAo8EACwZ4BAAAeQQAQdCjwQALBnoEAAB7BABB4KPBAAsGfAQAAH0EAEHwo8EACwZ+BAAAfwQAQYCkwQALBoAEAACBBABBkKTBAAsGigQAAIsEAEGgpMEACwaMBAAAjQQAQbCkwQALBo4EAACPBABBwKTBAAsGkAQAAJEEAEHQpMEACwaSBAAAkwQAQeCkwQALBpQEAACVBABB8KTBAAsGlgQAAJcEAEGApcEACwaYBAAAmQQAQZClwQALBpoEAACbBABBoKXBAAsGnAQAAJ0EAEGwpcEACwaeBAAAnwQAQcClwQALBqAEAAChBABB0KXBAAsGogQAAKMEAEHgpcEACwakBAAApQQAQfClwQALBqYEAACnBABBgKbBAAsGqAQAAKkEAEGQpsEACwaqBAAAqwQAQaCmwQALBqwEAACtBABBsKbBAAsGrgQAAK8EAEHApsEACwawBAAAsQQAQdCmwQALBrIEAACzBABB4KbBAAsGtAQAALUEAEHwpsEACwa2BAAAtwQAQYCnwQALBrgEAAC5BABBkKfBAAsGugQAALsEAEGgp8EACwa8BAAAvQQAQbCnwQALBr4EAAC/BABBwKfBAAsGwAQAAM8EAEHQp8EACwbBBAAAwgQAQeCnwQALBsMEAADEBABB8KfBAAsGxQQAAMYEAEGAqMEACwbHBAAAyAQAQZCowQALBskEAADKBABBoKjBAAsGywQAAMwEAEGwqMEACwbNBAAAzgQAQcCowQALBtAEAADRBABB0KjBAAsG0gQAANMEAEHgqMEACwbUBAAA1QQAQfCowQALBtYEAADXBABBgKnBAAsG2AQAANkEAEGQqcEACwbaBAAA2wQAQaCpwQALBtwEAADdBABBsKnBAAsG3gQAAN8EAEHAqcEACwbgBAAA4QQAQdCpwQALBuIEAADjBABB4KnBAAsG5AQAAOUEAEHwqcEACwbmBAAA5wQAQYCqwQALBugEAADpBABBkKrBAAsG6gQAAOsEAEGgqsEACwbsBAAA7QQAQbCqwQALBu4EAADvBABBwKrBAAsG8AQAAPEEAEHQqsEACwbyBAAA8wQAQeCqwQALBvQEAAD1BABB8KrBAAsG9gQAAPcEAEGAq8EACwb4BAAA+QQAQZCrwQALBvoEAAD7BABBoKvBAAsG/AQAAP0EAEGwq8EACwb+BAAA/wQAQcGrwQALBQUAAAEFAEHQq8EACwYCBQAAAwUAQeCrwQALBgQFAAAFBQBB8KvBAAsGBgUAAAcFAEGArMEACwYIBQAACQUAQZCswQALBgoFAAALBQBBoKzBAAsGDAUAAA0FAEGwrMEACwYOBQAADwUAQcCswQALBhAFAAARBQBB0KzBAAsGEgUAABMFAEHgrMEACwYUBQAAFQUAQfCswQALBhYFAAAXBQBBgK3BAAsGGAUAABkFAEGQrcEACwYaBQAAGwUAQaCtwQALBhwFAAAdBQBBsK3BAAsGHgUAAB8FAEHArcEACwYgBQAAIQUAQdCtwQALBiIFAAAjBQBB4K3BAAsGJAUAACUFAEHwrcEACwYmBQAAJwUAQYCuwQALBigFAAApBQBBkK7BAAsGKgUAACsFAEGgrsEACwYsBQAALQUAQbCuwQALBi4FAAAvBQBBwK7BAAsGMQUAAGEFAEHQrsEACwYyBQAAYgUAQeCuwQALBjMFAABjBQBB8K7BAAsGNAUAAGQFAEGAr8EACwY1BQAAZQUAQZCvwQALBjYFAABmBQBBoK/BAAsGNwUAAGcFAEGwr8EACwY4BQAAaAUAQcCvwQALBjkFAABpBQBB0K/BAAsGOgUAAGoFAEHgr8EACwY7BQAAawUAQfCvwQALBjwFAABsBQBBgLDBAAsGPQUAAG0FAEGQsMEACwY+BQAAbgUAQaCwwQALBj8FAABvBQBBsLDBAAsGQAUAAHAFAEHAsMEACwZBBQAAcQUAQdCwwQALBkIFAAByBQBB4LDBAAsGQwUAAHMFAEHwsMEACwZEBQAAdAUAQYCxwQALBkUFAAB1BQBBkLHBAAsGRgUAAHYFAEGgscEACwZHBQAAdwUAQbCxwQALBkgFAAB4BQBBwLHBAAsGSQUAAHkFAEHQscEACwZKBQAAegUAQeCxwQALBksFAAB7BQBB8LHBAAsGTAUAAHwFAEGAssEACwZNBQAAfQUAQZCywQALBk4FAAB+BQBBoLLBAAsGTwUAAH8FAEGwssEACwZQBQAAgAUAQcCywQALBlEFAACBBQBB0LLBAAsGUgUAAIIFAEHgssEACwZTBQAAgwUAQfCywQALBlQFAACEBQBBgLPBAAsGVQUAAIUFAEGQs8EACwZWBQAAhgUAQaCzwQALBqAQAAAALQBBsLPBAAsGoRAAAAEtAEHAs8EACwaiEAAAAi0AQdCzwQALBqMQAAADLQBB4LPBAAsGpBAAAAQtAEHws8EACwalEAAABS0AQYC0wQALBqYQAAAGLQBBkLTBAAsGpxAAAActAEGgtMEACwaoEAAACC0AQbC0wQALBqkQAAAJLQBBwLTBAAsGqhAAAAotAEHQtMEACwarEAAACy0AQeC0wQALBqwQAAAMLQBB8LTBAAsGrRAAAA0tAEGAtcEACwauEAAADi0AQZC1wQALBq8QAAAPLQBBoLXBAAsGsBAAABAtAEGwtcEACwaxEAAAES0AQcC1wQALBrIQAAASLQBB0LXBAAsGsxAAABMtAEHgtcEACwa0EAAAFC0AQfC1wQALBrUQAAAVLQBBgLbBAAsGthAAABYtAEGQtsEACwa3EAAAFy0AQaC2wQALBrgQAAAYLQBBsLbBAAsGuRAAABktAEHAtsEACwa6EAAAGi0AQdC2wQALBrsQAAAbLQBB4LbBAAsGvBAAABwtAEHwtsEACwa9EAAAHS0AQYC3wQALBr4QAAAeLQBBkLfBAAsGvxAAAB8tAEGgt8EACwbAEAAAIC0AQbC3wQALBsEQAAAhLQBBwLfBAAsGwhAAACItAEHQt8EACwbDEAAAIy0AQeC3wQALBsQQAAAkLQBB8LfBAAsGxRAAACUtAEGAuMEACwbHEAAAJy0AQZC4wQALBs0QAAAtLQBBoLjBAAsGoBMAAHCrAEGwuMEACwahEwAAcasAQcC4wQALBqITAAByqwBB0LjBAAsGoxMAAHOrAEHguMEACwakEwAAdKsAQfC4wQALBqUTAAB1qwBBgLnBAAsGphMAAHarAEGQucEACwanEwAAd6sAQaC5wQALBqgTAAB4qwBBsLnBAAsGqRMAAHmrAEHAucEACwaqEwAAeqsAQdC5wQALBqsTAAB7qwBB4LnBAAsGrBMAAHyrAEHwucEACwatEwAAfasAQYC6wQALBq4TAAB+qwBBkLrBAAsGrxMAAH+rAEGgusEACwawEwAAgKsAQbC6wQALBrETAACBqwBBwLrBAAsGshMAAIKrAEHQusEACwazEwAAg6sAQeC6wQALBrQTAACEqwBB8LrBAAsGtRMAAIWrAEGAu8EACwa2EwAAhqsAQZC7wQALBrcTAACHqwBBoLvBAAsGuBMAAIirAEGwu8EACwa5EwAAiasAQcC7wQALBroTAACKqwBB0LvBAAsGuxMAAIurAEHgu8EACwa8EwAAjKsAQfC7wQALBr0TAACNqwBBgLzBAAsGvhMAAI6rAEGQvMEACwa/EwAAj6sAQaC8wQALBsATAACQqwBBsLzBAAsGwRMAAJGrAEHAvMEACwbCEwAAkqsAQdC8wQALBsMTAACTqwBB4LzBAAsGxBMAAJSrAEHwvMEACwbFEwAAlasAQYC9wQALBsYTAACWqwBBkL3BAAsGxxMAAJerAEGgvcEACwbIEwAAmKsAQbC9wQALBskTAACZqwBBwL3BAAsGyhMAAJqrAEHQvcEACwbLEwAAm6sAQeC9wQALBswTAACcqwBB8L3BAAsGzRMAAJ2rAEGAvsEACwbOEwAAnqsAQZC+wQALBs8TAACfqwBBoL7BAAsG0BMAAKCrAEGwvsEACwbREwAAoasAQcC+wQALBtITAACiqwBB0L7BAAsG0xMAAKOrAEHgvsEACwbUEwAApKsAQfC+wQALBtUTAAClqwBBgL/BAAsG1hMAAKarAEGQv8EACwbXEwAAp6sAQaC/wQALBtgTAACoqwBBsL/BAAsG2RMAAKmrAEHAv8EACwbaEwAAqqsAQdC/wQALBtsTAACrqwBB4L/BAAsG3BMAAKyrAEHwv8EACwbdEwAArasAQYDAwQALBt4TAACuqwBBkMDBAAsG3xMAAK+rAEGgwMEACwbgEwAAsKsAQbDAwQALBuETAACxqwBBwMDBAAsG4hMAALKrAEHQwMEACwbjEwAAs6sAQeDAwQALBuQTAAC0qwBB8MDBAAsG5RMAALWrAEGAwcEACwbmEwAAtqsAQZDBwQALBucTAAC3qwBBoMHBAAsG6BMAALirAEGwwcEACwbpEwAAuasAQcDBwQALBuoTAAC6qwBB0MHBAAsG6xMAALurAEHgwcEACwbsEwAAvKsAQfDBwQALBu0TAAC9qwBBgMLBAAsG7hMAAL6rAEGQwsEACwbvEwAAv6sAQaDCwQALBvATAAD4EwBBsMLBAAsG8RMAAPkTAEHAwsEACwbyEwAA+hMAQdDCwQALBvMTAAD7EwBB4MLBAAsG9BMAAPwTAEHwwsEACwb1EwAA/RMAQYDDwQALBpAcAADQEABBkMPBAAsGkRwAANEQAEGgw8EACwaSHAAA0hAAQbDDwQALBpMcAADTEABBwMPBAAsGlBwAANQQAEHQw8EACwaVHAAA1RAAQeDDwQALBpYcAADWEABB8MPBAAsGlxwAANcQAEGAxMEACwaYHAAA2BAAQZDEwQALBpkcAADZEABBoMTBAAsGmhwAANoQAEGwxMEACwabHAAA2xAAQcDEwQALBpwcAADcEABB0MTBAAsGnRwAAN0QAEHgxMEACwaeHAAA3hAAQfDEwQALBp8cAADfEABBgMXBAAsGoBwAAOAQAEGQxcEACwahHAAA4RAAQaDFwQALBqIcAADiEABBsMXBAAsGoxwAAOMQAEHAxcEACwakHAAA5BAAQdDFwQALBqUcAADlEABB4MXBAAsGphwAAOYQAEHwxcEACwanHAAA5xAAQYDGwQALBqgcAADoEABBkMbBAAsGqRwAAOkQAEGgxsEACwaqHAAA6hAAQbDGwQALBqscAADrEABBwMbBAAsGrBwAAOwQAEHQxsEACwatHAAA7RAAQeDGwQALBq4cAADuEABB8MbBAAsGrxwAAO8QAEGAx8EACwawHAAA8BAAQZDHwQALBrEcAADxEABBoMfBAAsGshwAAPIQAEGwx8EACwazHAAA8xAAQcDHwQALBrQcAAD0EABB0MfBAAsGtRwAAPUQAEHgx8EACwa2HAAA9hAAQfDHwQALBrccAAD3EABBgMjBAAsGuBwAAPgQAEGQyMEACwa5HAAA+RAAQaDIwQALBrocAAD6EABBsMjBAAsGvRwAAP0QAEHAyMEACwa+HAAA/hAAQdDIwQALBr8cAAD/EABB4cjBAAsFHgAAAR4AQfDIwQALBgIeAAADHgBBgMnBAAsGBB4AAAUeAEGQycEACwYGHgAABx4AQaDJwQALBggeAAAJHgBBsMnBAAsGCh4AAAseAEHAycEACwYMHgAADR4AQdDJwQALBg4eAAAPHgBB4MnBAAsGEB4AABEeAEHwycEACwYSHgAAEx4AQYDKwQALBhQeAAAVHgBBkMrBAAsGFh4AABceAEGgysEACwYYHgAAGR4AQbDKwQALBhoeAAAbHgBBwMrBAAsGHB4AAB0eAEHQysEACwYeHgAAHx4AQeDKwQALBiAeAAAhHgBB8MrBAAsGIh4AACMeAEGAy8EACwYkHgAAJR4AQZDLwQALBiYeAAAnHgBBoMvBAAsGKB4AACkeAEGwy8EACwYqHgAAKx4AQcDLwQALBiweAAAtHgBB0MvBAAsGLh4AAC8eAEHgy8EACwYwHgAAMR4AQfDLwQALBjIeAAAzHgBBgMzBAAsGNB4AADUeAEGQzMEACwY2HgAANx4AQaDMwQALBjgeAAA5HgBBsMzBAAsGOh4AADseAEHAzMEACwY8HgAAPR4AQdDMwQALBj4eAAA/HgBB4MzBAAsGQB4AAEEeAEHwzMEACwZCHgAAQx4AQYDNwQALBkQeAABFHgBBkM3BAAsGRh4AAEceAEGgzcEACwZIHgAASR4AQbDNwQALBkoeAABLHgBBwM3BAAsGTB4AAE0eAEHQzcEACwZOHgAATx4AQeDNwQALBlAeAABRHgBB8M3BAAsGUh4AAFMeAEGAzsEACwZUHgAAVR4AQZDOwQALBlYeAABXHgBBoM7BAAsGWB4AAFkeAEGwzsEACwZaHgAAWx4AQcDOwQALBlweAABdHgBB0M7BAAsGXh4AAF8eAEHgzsEACwZgHgAAYR4AQfDOwQALBmIeAABjHgBBgM/BAAsGZB4AAGUeAEGQz8EACwZmHgAAZx4AQaDPwQALBmgeAABpHgBBsM/BAAsGah4AAGseAEHAz8EACwZsHgAAbR4AQdDPwQALBm4eAABvHgBB4M/BAAsGcB4AAHEeAEHwz8EACwZyHgAAcx4AQYDQwQALBnQeAAB1HgBBkNDBAAsGdh4AAHceAEGg0MEACwZ4HgAAeR4AQbDQwQALBnoeAAB7HgBBwNDBAAsGfB4AAH0eAEHQ0MEACwZ+HgAAfx4AQeDQwQALBoAeAACBHgBB8NDBAAsGgh4AAIMeAEGA0cEACwaEHgAAhR4AQZDRwQALBoYeAACHHgBBoNHBAAsGiB4AAIkeAEGw0cEACwaKHgAAix4AQcDRwQALBoweAACNHgBB0NHBAAsGjh4AAI8eAEHg0cEACwaQHgAAkR4AQfDRwQALBpIeAACTHgBBgNLBAAsGlB4AAJUeAEGQ0sEACwWeHgAA3wBBoNLBAAsGoB4AAKEeAEGw0sEACwaiHgAAox4AQcDSwQALBqQeAAClHgBB0NLBAAsGph4AAKceAEHg0sEACwaoHgAAqR4AQfDSwQALBqoeAACrHgBBgNPBAAsGrB4AAK0eAEGQ08EACwauHgAArx4AQaDTwQALBrAeAACxHgBBsNPBAAsGsh4AALMeAEHA08EACwa0HgAAtR4AQdDTwQALBrYeAAC3HgBB4NPBAAsGuB4AALkeAEHw08EACwa6HgAAux4AQYDUwQALBrweAAC9HgBBkNTBAAsGvh4AAL8eAEGg1MEACwbAHgAAwR4AQbDUwQALBsIeAADDHgBBwNTBAAsGxB4AAMUeAEHQ1MEACwbGHgAAxx4AQeDUwQALBsgeAADJHgBB8NTBAAsGyh4AAMseAEGA1cEACwbMHgAAzR4AQZDVwQALBs4eAADPHgBBoNXBAAsG0B4AANEeAEGw1cEACwbSHgAA0x4AQcDVwQALBtQeAADVHgBB0NXBAAsG1h4AANceAEHg1cEACwbYHgAA2R4AQfDVwQALBtoeAADbHgBBgNbBAAsG3B4AAN0eAEGQ1sEACwbeHgAA3x4AQaDWwQALBuAeAADhHgBBsNbBAAsG4h4AAOMeAEHA1sEACwbkHgAA5R4AQdDWwQALBuYeAADnHgBB4NbBAAsG6B4AAOkeAEHw1sEACwbqHgAA6x4AQYDXwQALBuweAADtHgBBkNfBAAsG7h4AAO8eAEGg18EACwbwHgAA8R4AQbDXwQALBvIeAADzHgBBwNfBAAsG9B4AAPUeAEHQ18EACwb2HgAA9x4AQeDXwQALBvgeAAD5HgBB8NfBAAsG+h4AAPseAEGA2MEACwb8HgAA/R4AQZDYwQALBv4eAAD/HgBBoNjBAAsGCB8AAAAfAEGw2MEACwYJHwAAAR8AQcDYwQALBgofAAACHwBB0NjBAAsGCx8AAAMfAEHg2MEACwYMHwAABB8AQfDYwQALBg0fAAAFHwBBgNnBAAsGDh8AAAYfAEGQ2cEACwYPHwAABx8AQaDZwQALBhgfAAAQHwBBsNnBAAsGGR8AABEfAEHA2cEACwYaHwAAEh8AQdDZwQALBhsfAAATHwBB4NnBAAsGHB8AABQfAEHw2cEACwYdHwAAFR8AQYDawQALBigfAAAgHwBBkNrBAAsGKR8AACEfAEGg2sEACwYqHwAAIh8AQbDawQALBisfAAAjHwBBwNrBAAsGLB8AACQfAEHQ2sEACwYtHwAAJR8AQeDawQALBi4fAAAmHwBB8NrBAAsGLx8AACcfAEGA28EACwY4HwAAMB8AQZDbwQALBjkfAAAxHwBBoNvBAAsGOh8AADIfAEGw28EACwY7HwAAMx8AQcDbwQALBjwfAAA0HwBB0NvBAAsGPR8AADUfAEHg28EACwY+HwAANh8AQfDbwQALBj8fAAA3HwBBgNzBAAsGSB8AAEAfAEGQ3MEACwZJHwAAQR8AQaDcwQALBkofAABCHwBBsNzBAAsGSx8AAEMfAEHA3MEACwZMHwAARB8AQdDcwQALBk0fAABFHwBB4NzBAAsGWR8AAFEfAEHw3MEACwZbHwAAUx8AQYDdwQALBl0fAABVHwBBkN3BAAsGXx8AAFcfAEGg3cEACwZoHwAAYB8AQbDdwQALBmkfAABhHwBBwN3BAAsGah8AAGIfAEHQ3cEACwZrHwAAYx8AQeDdwQALBmwfAABkHwBB8N3BAAsGbR8AAGUfAEGA3sEACwZuHwAAZh8AQZDewQALBm8fAABnHwBBoN7BAAsGiB8AAIAfAEGw3sEACwaJHwAAgR8AQcDewQALBoofAACCHwBB0N7BAAsGix8AAIMfAEHg3sEACwaMHwAAhB8AQfDewQALBo0fAACFHwBBgN/BAAsGjh8AAIYfAEGQ38EACwaPHwAAhx8AQaDfwQALBpgfAACQHwBBsN/BAAsGmR8AAJEfAEHA38EACwaaHwAAkh8AQdDfwQALBpsfAACTHwBB4N/BAAsGnB8AAJQfAEHw38EACwadHwAAlR8AQYDgwQALBp4fAACWHwBBkODBAAsGnx8AAJcfAEGg4MEACwaoHwAAoB8AQbDgwQALBqkfAAChHwBBwODBAAsGqh8AAKIfAEHQ4MEACwarHwAAox8AQeDgwQALBqwfAACkHwBB8ODBAAsGrR8AAKUfAEGA4cEACwauHwAAph8AQZDhwQALBq8fAACnHwBBoOHBAAsGuB8AALAfAEGw4cEACwa5HwAAsR8AQcDhwQALBrofAABwHwBB0OHBAAsGux8AAHEfAEHg4cEACwa8HwAAsx8AQfDhwQALBsgfAAByHwBBgOLBAAsGyR8AAHMfAEGQ4sEACwbKHwAAdB8AQaDiwQALBssfAAB1HwBBsOLBAAsGzB8AAMMfAEHA4sEACwbYHwAA0B8AQdDiwQALBtkfAADRHwBB4OLBAAsG2h8AAHYfAEHw4sEACwbbHwAAdx8AQYDjwQALBugfAADgHwBBkOPBAAsG6R8AAOEfAEGg48EACwbqHwAAeh8AQbDjwQALBusfAAB7HwBBwOPBAAsG7B8AAOUfAEHQ48EACwb4HwAAeB8AQeDjwQALBvkfAAB5HwBB8OPBAAsG+h8AAHwfAEGA5MEACwb7HwAAfR8AQZDkwQALBvwfAADzHwBBoOTBAAsGJiEAAMkDAEGw5MEACwUqIQAAawBBwOTBAAsFKyEAAOUAQdDkwQALBjIhAABOIQBB4OTBAAsGYCEAAHAhAEHw5MEACwZhIQAAcSEAQYDlwQALBmIhAAByIQBBkOXBAAsGYyEAAHMhAEGg5cEACwZkIQAAdCEAQbDlwQALBmUhAAB1IQBBwOXBAAsGZiEAAHYhAEHQ5cEACwZnIQAAdyEAQeDlwQALBmghAAB4IQBB8OXBAAsGaSEAAHkhAEGA5sEACwZqIQAAeiEAQZDmwQALBmshAAB7IQBBoObBAAsGbCEAAHwhAEGw5sEACwZtIQAAfSEAQcDmwQALBm4hAAB+IQBB0ObBAAsGbyEAAH8hAEHg5sEACwaDIQAAhCEAQfDmwQALBrYkAADQJABBgOfBAAsGtyQAANEkAEGQ58EACwa4JAAA0iQAQaDnwQALBrkkAADTJABBsOfBAAsGuiQAANQkAEHA58EACwa7JAAA1SQAQdDnwQALBrwkAADWJABB4OfBAAsGvSQAANckAEHw58EACwa+JAAA2CQAQYDowQALBr8kAADZJABBkOjBAAsGwCQAANokAEGg6MEACwbBJAAA2yQAQbDowQALBsIkAADcJABBwOjBAAsGwyQAAN0kAEHQ6MEACwbEJAAA3iQAQeDowQALBsUkAADfJABB8OjBAAsGxiQAAOAkAEGA6cEACwbHJAAA4SQAQZDpwQALBsgkAADiJABBoOnBAAsGySQAAOMkAEGw6cEACwbKJAAA5CQAQcDpwQALBsskAADlJABB0OnBAAsGzCQAAOYkAEHg6cEACwbNJAAA5yQAQfDpwQALBs4kAADoJABBgOrBAAsGzyQAAOkkAEGR6sEACwUsAAAwLABBoOrBAAsGASwAADEsAEGw6sEACwYCLAAAMiwAQcDqwQALBgMsAAAzLABB0OrBAAsGBCwAADQsAEHg6sEACwYFLAAANSwAQfDqwQALBgYsAAA2LABBgOvBAAsGBywAADcsAEGQ68EACwYILAAAOCwAQaDrwQALBgksAAA5LABBsOvBAAsGCiwAADosAEHA68EACwYLLAAAOywAQdDrwQALBgwsAAA8LABB4OvBAAsGDSwAAD0sAEHw68EACwYOLAAAPiwAQYDswQALBg8sAAA/LABBkOzBAAsGECwAAEAsAEGg7MEACwYRLAAAQSwAQbDswQALBhIsAABCLABBwOzBAAsGEywAAEMsAEHQ7MEACwYULAAARCwAQeDswQALBhUsAABFLABB8OzBAAsGFiwAAEYsAEGA7cEACwYXLAAARywAQZDtwQALBhgsAABILABBoO3BAAsGGSwAAEksAEGw7cEACwYaLAAASiwAQcDtwQALBhssAABLLABB0O3BAAsGHCwAAEwsAEHg7cEACwYdLAAATSwAQfDtwQALBh4sAABOLABBgO7BAAsGHywAAE8sAEGQ7sEACwYgLAAAUCwAQaDuwQALBiEsAABRLABBsO7BAAsGIiwAAFIsAEHA7sEACwYjLAAAUywAQdDuwQALBiQsAABULABB4O7BAAsGJSwAAFUsAEHw7sEACwYmLAAAViwAQYDvwQALBicsAABXLABBkO/BAAsGKCwAAFgsAEGg78EACwYpLAAAWSwAQbDvwQALBiosAABaLABBwO/BAAsGKywAAFssAEHQ78EACwYsLAAAXCwAQeDvwQALBi0sAABdLABB8O/BAAsGLiwAAF4sAEGA8MEACwZgLAAAYSwAQZDwwQALBmIsAABrAgBBoPDBAAsGYywAAH0dAEGw8MEACwZkLAAAfQIAQcDwwQALBmcsAABoLABB0PDBAAsGaSwAAGosAEHg8MEACwZrLAAAbCwAQfDwwQALBm0sAABRAgBBgPHBAAsGbiwAAHECAEGQ8cEACwZvLAAAUAIAQaDxwQALBnAsAABSAgBBsPHBAAsGciwAAHMsAEHA8cEACwZ1LAAAdiwAQdDxwQALBn4sAAA/AgBB4PHBAAsGfywAAEACAEHw8cEACwaALAAAgSwAQYDywQALBoIsAACDLABBkPLBAAsGhCwAAIUsAEGg8sEACwaGLAAAhywAQbDywQALBogsAACJLABBwPLBAAsGiiwAAIssAEHQ8sEACwaMLAAAjSwAQeDywQALBo4sAACPLABB8PLBAAsGkCwAAJEsAEGA88EACwaSLAAAkywAQZDzwQALBpQsAACVLABBoPPBAAsGliwAAJcsAEGw88EACwaYLAAAmSwAQcDzwQALBposAACbLABB0PPBAAsGnCwAAJ0sAEHg88EACwaeLAAAnywAQfDzwQALBqAsAAChLABBgPTBAAsGoiwAAKMsAEGQ9MEACwakLAAApSwAQaD0wQALBqYsAACnLABBsPTBAAsGqCwAAKksAEHA9MEACwaqLAAAqywAQdD0wQALBqwsAACtLABB4PTBAAsGriwAAK8sAEHw9MEACwawLAAAsSwAQYD1wQALBrIsAACzLABBkPXBAAsGtCwAALUsAEGg9cEACwa2LAAAtywAQbD1wQALBrgsAAC5LABBwPXBAAsGuiwAALssAEHQ9cEACwa8LAAAvSwAQeD1wQALBr4sAAC/LABB8PXBAAsGwCwAAMEsAEGA9sEACwbCLAAAwywAQZD2wQALBsQsAADFLABBoPbBAAsGxiwAAMcsAEGw9sEACwbILAAAySwAQcD2wQALBsosAADLLABB0PbBAAsGzCwAAM0sAEHg9sEACwbOLAAAzywAQfD2wQALBtAsAADRLABBgPfBAAsG0iwAANMsAEGQ98EACwbULAAA1SwAQaD3wQALBtYsAADXLABBsPfBAAsG2CwAANksAEHA98EACwbaLAAA2ywAQdD3wQALBtwsAADdLABB4PfBAAsG3iwAAN8sAEHw98EACwbgLAAA4SwAQYD4wQALBuIsAADjLABBkPjBAAsG6ywAAOwsAEGg+MEACwbtLAAA7iwAQbD4wQALBvIsAADzLABBwPjBAAsGQKYAAEGmAEHQ+MEACwZCpgAAQ6YAQeD4wQALBkSmAABFpgBB8PjBAAsGRqYAAEemAEGA+cEACwZIpgAASaYAQZD5wQALBkqmAABLpgBBoPnBAAsGTKYAAE2mAEGw+cEACwZOpgAAT6YAQcD5wQALBlCmAABRpgBB0PnBAAsGUqYAAFOmAEHg+cEACwZUpgAAVaYAQfD5wQALBlamAABXpgBBgPrBAAsGWKYAAFmmAEGQ+sEACwZapgAAW6YAQaD6wQALBlymAABdpgBBsPrBAAsGXqYAAF+mAEHA+sEACwZgpgAAYaYAQdD6wQALBmKmAABjpgBB4PrBAAsGZKYAAGWmAEHw+sEACwZmpgAAZ6YAQYD7wQALBmimAABppgBBkPvBAAsGaqYAAGumAEGg+8EACwZspgAAbaYAQbD7wQALBoCmAACBpgBBwPvBAAsGgqYAAIOmAEHQ+8EACwaEpgAAhaYAQeD7wQALBoamAACHpgBB8PvBAAsGiKYAAImmAEGA/MEACwaKpgAAi6YAQZD8wQALBoymAACNpgBBoPzBAAsGjqYAAI+mAEGw/MEACwaQpgAAkaYAQcD8wQALBpKmAACTpgBB0PzBAAsGlKYAAJWmAEHg/MEACwaWpgAAl6YAQfD8wQALBpimAACZpgBBgP3BAAsGmqYAAJumAEGQ/cEACwYipwAAI6cAQaD9wQALBiSnAAAlpwBBsP3BAAsGJqcAACenAEHA/cEACwYopwAAKacAQdD9wQALBiqnAAArpwBB4P3BAAsGLKcAAC2nAEHw/cEACwYupwAAL6cAQYD+wQALBjKnAAAzpwBBkP7BAAsGNKcAADWnAEGg/sEACwY2pwAAN6cAQbD+wQALBjinAAA5pwBBwP7BAAsGOqcAADunAEHQ/sEACwY8pwAAPacAQeD+wQALBj6nAAA/pwBB8P7BAAsGQKcAAEGnAEGA/8EACwZCpwAAQ6cAQZD/wQALBkSnAABFpwBBoP/BAAsGRqcAAEenAEGw/8EACwZIpwAASacAQcD/wQALBkqnAABLpwBB0P/BAAsGTKcAAE2nAEHg/8EACwZOpwAAT6cAQfD/wQALBlCnAABRpwBBgIDCAAsGUqcAAFOnAEGQgMIACwZUpwAAVacAQaCAwgALBlanAABXpwBBsIDCAAsGWKcAAFmnAEHAgMIACwZapwAAW6cAQdCAwgALBlynAABdpwBB4IDCAAsGXqcAAF+nAEHwgMIACwZgpwAAYacAQYCBwgALBmKnAABjpwBBkIHCAAsGZKcAAGWnAEGggcIACwZmpwAAZ6cAQbCBwgALBminAABppwBBwIHCAAsGaqcAAGunAEHQgcIACwZspwAAbacAQeCBwgALBm6nAABvpwBB8IHCAAsGeacAAHqnAEGAgsIACwZ7pwAAfKcAQZCCwgALBn2nAAB5HQBBoILCAAsGfqcAAH+nAEGwgsIACwaApwAAgacAQcCCwgALBoKnAACDpwBB0ILCAAsGhKcAAIWnAEHggsIACwaGpwAAh6cAQfCCwgALBounAACMpwBBgIPCAAsGjacAAGUCAEGQg8IACwaQpwAAkacAQaCDwgALBpKnAACTpwBBsIPCAAsGlqcAAJenAEHAg8IACwaYpwAAmacAQdCDwgALBpqnAACbpwBB4IPCAAsGnKcAAJ2nAEHwg8IACwaepwAAn6cAQYCEwgALBqCnAAChpwBBkITCAAsGoqcAAKOnAEGghMIACwakpwAApacAQbCEwgALBqanAACnpwBBwITCAAsGqKcAAKmnAEHQhMIACwaqpwAAZgIAQeCEwgALBqunAABcAgBB8ITCAAsGrKcAAGECAEGAhcIACwatpwAAbAIAQZCFwgALBq6nAABqAgBBoIXCAAsGsKcAAJ4CAEGwhcIACwaxpwAAhwIAQcCFwgALBrKnAACdAgBB0IXCAAsGs6cAAFOrAEHghcIACwa0pwAAtacAQfCFwgALBranAAC3pwBBgIbCAAsGuKcAALmnAEGQhsIACwa6pwAAu6cAQaCGwgALBrynAAC9pwBBsIbCAAsGvqcAAL+nAEHAhsIACwbCpwAAw6cAQdCGwgALBsSnAACUpwBB4IbCAAsGxacAAIICAEHwhsIACwbGpwAAjh0AQYCHwgALBiH/AABB/wBBkIfCAAsGIv8AAEL/AEGgh8IACwYj/wAAQ/8AQbCHwgALBiT/AABE/wBBwIfCAAsGJf8AAEX/AEHQh8IACwYm/wAARv8AQeCHwgALBif/AABH/wBB8IfCAAsGKP8AAEj/AEGAiMIACwYp/wAASf8AQZCIwgALBir/AABK/wBBoIjCAAsGK/8AAEv/AEGwiMIACwYs/wAATP8AQcCIwgALBi3/AABN/wBB0IjCAAsGLv8AAE7/AEHgiMIACwYv/wAAT/8AQfCIwgALBjD/AABQ/wBBgInCAAsGMf8AAFH/AEGQicIACwYy/wAAUv8AQaCJwgALBjP/AABT/wBBsInCAAsGNP8AAFT/AEHAicIACwY1/wAAVf8AQdCJwgALBjb/AABW/wBB4InCAAsGN/8AAFf/AEHwicIACwY4/wAAWP8AQYCKwgALBjn/AABZ/wBBkIrCAAsGOv8AAFr/AEGhisIACwYEAQAoBAEAQbCKwgALBwEEAQApBAEAQcCKwgALBwIEAQAqBAEAQdCKwgALBwMEAQArBAEAQeCKwgALBwQEAQAsBAEAQfCKwgALBwUEAQAtBAEAQYCLwgALBwYEAQAuBAEAQZCLwgALBwcEAQAvBAEAQaCLwgALBwgEAQAwBAEAQbCLwgALBwkEAQAxBAEAQcCLwgALBwoEAQAyBAEAQdCLwgALBwsEAQAzBAEAQeCLwgALBwwEAQA0BAEAQfCLwgALBw0EAQA1BAEAQYCMwgALBw4EAQA2BAEAQZCMwgALBw8EAQA3BAEAQaCMwgALBxAEAQA4BAEAQbCMwgALBxEEAQA5BAEAQcCMwgALBxIEAQA6BAEAQdCMwgALBxMEAQA7BAEAQeCMwgALBxQEAQA8BAEAQfCMwgALBxUEAQA9BAEAQYCNwgALBxYEAQA+BAEAQZCNwgALBxcEAQA/BAEAQaCNwgALBxgEAQBABAEAQbCNwgALBxkEAQBBBAEAQcCNwgALBxoEAQBCBAEAQdCNwgALBxsEAQBDBAEAQeCNwgALBxwEAQBEBAEAQfCNwgALBx0EAQBFBAEAQYCOwgALBx4EAQBGBAEAQZCOwgALBx8EAQBHBAEAQaCOwgALByAEAQBIBAEAQbCOwgALByEEAQBJBAEAQcCOwgALByIEAQBKBAEAQdCOwgALByMEAQBLBAEAQeCOwgALByQEAQBMBAEAQfCOwgALByUEAQBNBAEAQYCPwgALByYEAQBOBAEAQZCPwgALBycEAQBPBAEAQaCPwgALB7AEAQDYBAEAQbCPwgALB7EEAQDZBAEAQcCPwgALB7IEAQDaBAEAQdCPwgALB7MEAQDbBAEAQeCPwgALB7QEAQDcBAEAQfCPwgALB7UEAQDdBAEAQYCQwgALB7YEAQDeBAEAQZCQwgALB7cEAQDfBAEAQaCQwgALB7gEAQDgBAEAQbCQwgALB7kEAQDhBAEAQcCQwgALB7oEAQDiBAEAQdCQwgALB7sEAQDjBAEAQeCQwgALB7wEAQDkBAEAQfCQwgALB70EAQDlBAEAQYCRwgALB74EAQDmBAEAQZCRwgALB78EAQDnBAEAQaCRwgALB8AEAQDoBAEAQbCRwgALB8EEAQDpBAEAQcCRwgALB8IEAQDqBAEAQdCRwgALB8MEAQDrBAEAQeCRwgALB8QEAQDsBAEAQfCRwgALB8UEAQDtBAEAQYCSwgALB8YEAQDuBAEAQZCSwgALB8cEAQDvBAEAQaCSwgALB8gEAQDwBAEAQbCSwgALB8kEAQDxBAEAQcCSwgALB8oEAQDyBAEAQdCSwgALB8sEAQDzBAEAQeCSwgALB8wEAQD0BAEAQfCSwgALB80EAQD1BAEAQYCTwgALB84EAQD2BAEAQZCTwgALB88EAQD3BAEAQaCTwgALB9AEAQD4BAEAQbCTwgALB9EEAQD5BAEAQcCTwgALB9IEAQD6BAEAQdCTwgALB9MEAQD7BAEAQeCTwgALB4AMAQDADAEAQfCTwgALB4EMAQDBDAEAQYCUwgALB4IMAQDCDAEAQZCUwgALB4MMAQDDDAEAQaCUwgALB4QMAQDEDAEAQbCUwgALB4UMAQDFDAEAQcCUwgALB4YMAQDGDAEAQdCUwgALB4cMAQDHDAEAQeCUwgALB4gMAQDIDAEAQfCUwgALB4kMAQDJDAEAQYCVwgALB4oMAQDKDAEAQZCVwgALB4sMAQDLDAEAQaCVwgALB4wMAQDMDAEAQbCVwgALB40MAQDNDAEAQcCVwgALB44MAQDODAEAQdCVwgALB48MAQDPDAEAQeCVwgALB5AMAQDQDAEAQfCVwgALB5EMAQDRDAEAQYCWwgALB5IMAQDSDAEAQZCWwgALB5MMAQDTDAEAQaCWwgALB5QMAQDUDAEAQbCWwgALB5UMAQDVDAEAQcCWwgALB5YMAQDWDAEAQdCWwgALB5cMAQDXDAEAQeCWwgALB5gMAQDYDAEAQfCWwgALB5kMAQDZDAEAQYCXwgALB5oMAQDaDAEAQZCXwgALB5sMAQDbDAEAQaCXwgALB5wMAQDcDAEAQbCXwgALB50MAQDdDAEAQcCXwgALB54MAQDeDAEAQdCXwgALB58MAQDfDAEAQeCXwgALB6AMAQDgDAEAQfCXwgALB6EMAQDhDAEAQYCYwgALB6IMAQDiDAEAQZCYwgALB6MMAQDjDAEAQaCYwgALB6QMAQDkDAEAQbCYwgALB6UMAQDlDAEAQcCYwgALB6YMAQDmDAEAQdCYwgALB6cMAQDnDAEAQeCYwgALB6gMAQDoDAEAQfCYwgALB6kMAQDpDAEAQYCZwgALB6oMAQDqDAEAQZCZwgALB6sMAQDrDAEAQaCZwgALB6wMAQDsDAEAQbCZwgALB60MAQDtDAEAQcCZwgALB64MAQDuDAEAQdCZwgALB68MAQDvDAEAQeCZwgALB7AMAQDwDAEAQfCZwgALB7EMAQDxDAEAQYCawgALB7IMAQDyDAEAQZCawgALB6AYAQDAGAEAQaCawgALB6EYAQDBGAEAQbCawgALB6IYAQDCGAEAQcCawgALB6MYAQDDGAEAQdCawgALB6QYAQDEGAEAQeCawgALB6UYAQDFGAEAQfCawgALB6YYAQDGGAEAQYCbwgALB6cYAQDHGAEAQZCbwgALB6gYAQDIGAEAQaCbwgALB6kYAQDJGAEAQbCbwgALB6oYAQDKGAEAQcCbwgALB6sYAQDLGAEAQdCbwgALB6wYAQDMGAEAQeCbwgALB60YAQDNGAEAQfCbwgALB64YAQDOGAEAQYCcwgALB68YAQDPGAEAQZCcwgALB7AYAQDQGAEAQaCcwgALB7EYAQDRGAEAQbCcwgALB7IYAQDSGAEAQcCcwgALB7MYAQDTGAEAQdCcwgALB7QYAQDUGAEAQeCcwgALB7UYAQDVGAEAQfCcwgALB7YYAQDWGAEAQYCdwgALB7cYAQDXGAEAQZCdwgALB7gYAQDYGAEAQaCdwgALB7kYAQDZGAEAQbCdwgALB7oYAQDaGAEAQcCdwgALB7sYAQDbGAEAQdCdwgALB7wYAQDcGAEAQeCdwgALB70YAQDdGAEAQfCdwgALB74YAQDeGAEAQYCewgALB78YAQDfGAEAQZCewgALB0BuAQBgbgEAQaCewgALB0FuAQBhbgEAQbCewgALB0JuAQBibgEAQcCewgALB0NuAQBjbgEAQdCewgALB0RuAQBkbgEAQeCewgALB0VuAQBlbgEAQfCewgALB0ZuAQBmbgEAQYCfwgALB0duAQBnbgEAQZCfwgALB0huAQBobgEAQaCfwgALB0luAQBpbgEAQbCfwgALB0puAQBqbgEAQcCfwgALB0tuAQBrbgEAQdCfwgALB0xuAQBsbgEAQeCfwgALB01uAQBtbgEAQfCfwgALB05uAQBubgEAQYCgwgALB09uAQBvbgEAQZCgwgALB1BuAQBwbgEAQaCgwgALB1FuAQBxbgEAQbCgwgALB1JuAQBybgEAQcCgwgALB1NuAQBzbgEAQdCgwgALB1RuAQB0bgEAQeCgwgALB1VuAQB1bgEAQfCgwgALB1ZuAQB2bgEAQYChwgALB1duAQB3bgEAQZChwgALB1huAQB4bgEAQaChwgALB1luAQB5bgEAQbChwgALB1puAQB6bgEAQcChwgALB1tuAQB7bgEAQdChwgALB1xuAQB8bgEAQeChwgALB11uAQB9bgEAQfChwgALB15uAQB+bgEAQYCiwgALB19uAQB/bgEAQZGiwgALBukBACLpAQBBoKLCAAsHAekBACPpAQBBsKLCAAsHAukBACTpAQBBwKLCAAsHA+kBACXpAQBB0KLCAAsHBOkBACbpAQBB4KLCAAsHBekBACfpAQBB8KLCAAsHBukBACjpAQBBgKPCAAsHB+kBACnpAQBBkKPCAAsHCOkBACrpAQBBoKPCAAsHCekBACvpAQBBsKPCAAsHCukBACzpAQBBwKPCAAsHC+kBAC3pAQBB0KPCAAsHDOkBAC7pAQBB4KPCAAsHDekBAC/pAQBB8KPCAAsHDukBADDpAQBBgKTCAAsHD+kBADHpAQBBkKTCAAsHEOkBADLpAQBBoKTCAAsHEekBADPpAQBBsKTCAAsHEukBADTpAQBBwKTCAAsHE+kBADXpAQBB0KTCAAsHFOkBADbpAQBB4KTCAAsHFekBADfpAQBB8KTCAAsHFukBADjpAQBBgKXCAAsHF+kBADnpAQBBkKXCAAsHGOkBADrpAQBBoKXCAAsHGekBADvpAQBBsKXCAAsHGukBADzpAQBBwKXCAAsHG+kBAD3pAQBB0KXCAAsHHOkBAD7pAQBB4KXCAAsHHekBAD/pAQBB8KXCAAsHHukBAEDpAQBBgKbCAAsHH+kBAEHpAQBBkKbCAAsHIOkBAELpAQBBoKbCAAsHIekBAEPpAQBBsKbCAAvoHP////9IYXNoIHRhYmxlIGNhcGFjaXR5IG92ZXJmbG93YJMQAE4AAABQAAAAGwAAAC9jYXJnby9yZWdpc3RyeS9zcmMvZ2l0aHViLmNvbS0xZWNjNjI5OWRiOWVjODIzL2hhc2hicm93bi0wLjYuMi9zcmMvcmF3L21vZC5ycwAACJYQAEgAAAACAgAAIwAAAAiWEABIAAAAAwIAACMAAADgkxAASQAAAIcCAAAdAAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmNvcmUvc2xpY2Uvc29ydC5ycwAAAOCTEABJAAAAnQAAADoAAADgkxAASQAAAKQAAAAwAAAADQAAAAAAAAABAAAADgAAAA8AAAAAAAAAAQAAABAAAAB8lBAAPQAAACUAAAANAAAAL1VzZXJzL3RoYXVtYW50L1BsYXlncm91bmQvbHVjaWQtc3VnZ2VzdC9ydXN0L2NvcmUvc3JjL2xpYi5yc0R1cGxpY2F0ZSBzdG9yZSBpZAB8lBAAPQAAAC8AAAANAAAAfJQQAD0AAAA7AAAADQAAAE1pc3Npbmcgc3RvcmUgaWR8lBAAPQAAAEMAAAANAAAAYWxyZWFkeSBtdXRhYmx5IGJvcnJvd2VkEQAAAAAAAAABAAAAEgAAAAIBAwATAAAAAAAAAAEAAAAOAAAAFAAAAAAAAAABAAAADgAAAAAAAAAVAAAAAAAAAAEAAAAOAAAAfJUQAEsAAAAjAAAACQAAAC9Vc2Vycy90aGF1bWFudC9QbGF5Z3JvdW5kL2x1Y2lkLXN1Z2dlc3QvcnVzdC9jb3JlL3NyYy90b2tlbml6YXRpb24vd29yZC5yc01hbGZvcm1lZCB3b3JkcwAADKUQABcAAAC5AQAAJwAAAAiWEABIAAAA7AoAAAoAAAAIlhAASAAAAPIKAAAOAAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzcJYQAEUAAAAJAwAAHgAAAHCWEABFAAAADAMAADYAAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliYWxsb2Mvc2xpY2UucnNhc3NlcnRpb24gZmFpbGVkOiBtaWQgPD0gbGVuAAAACKEQABgAAAA2BAAADQAAAGFzc2VydGlvbiBmYWlsZWQ6IGluZGV4IDw9IGxlbgAAFJcQABMAAADjAwAACQAAAHNyYy9saWJhbGxvYy92ZWMucnNhc3NlcnRpb24gZmFpbGVkOiBpbmRleCA8IGxlbhSXEAATAAAACwQAAAkAAAB0lxAAQwAAADcLAAAwAAAAdJcQAEMAAABDCwAALAAAAC9ydXN0Yy83MzUyOGUzMzlhYWUwZjE3YTE1ZmZhNDlhOGFjNjA4ZjUwYzZjZjE0L3NyYy9saWJhbGxvYy92ZWMucnMAFgAAAAAAAAABAAAADgAAAAYAAABiAAAABgAAAGMAAAAGAAAAZAAAAAYAAABmAAAABgAAAGcAAAAGAAAAaAAAAAYAAABqAAAABgAAAGsAAAAGAAAAbAAAAAYAAABtAAAABgAAAG4AAAAGAAAAcAAAAAYAAABxAAAABgAAAHIAAAAGAAAAcwAAAAYAAAB0AAAABgAAAHYAAAAGAAAAdwAAAAYAAAB4AAAABgAAAHoAAAAHAAAAYQAAAAcAAABlAAAABwAAAGkAAAAHAAAAbwAAAAcAAAB1AAAABwAAAHkAAABhbnRoZWJ1dGRlc0/DnFXMiHXMiLeeEAADAAAAs54QAAIAAAC6nhAAAwAAALWeEAACAAAAppgQAAMAAACkmBAAAgAAAL2eEAADAAAASKQQAAIAAADAnhAAAwAAAHKkEAACAAAAqZgQAAMAAAB0pBAAAgAAAK6eEAADAAAAsZ4QAAIAAACQoRAAAgAAAJKhEAACAAAAs54QAAIAAAB5pBAAAQAAALWeEAACAAAAo5gQAAEAAACkmBAAAgAAAKqhEAABAAAASKQQAAIAAABupBAAAQAAAHKkEAACAAAAcKQQAAEAAAB0pBAAAgAAAHGkEAABAAAACQAAAHydEAADAAAACQAAAH+dEAADAAAACQAAAIKdEAADAAAACQAAAIWdEAADAAAACQAAAKCYEAADAAAACQAAAIidEAADAAAACQAAAIudEAADAAAACQAAAI6dEAAEAAAACQAAAJKdEAAFAAAACQAAAJedEAAFAAAACQAAAJydEAAFAAAACQAAAKGdEAAFAAAABQAAAJiYEAACAAAABQAAAKadEAADAAAABQAAAKmdEAADAAAABQAAAKydEAADAAAABQAAAK+dEAADAAAABQAAALKdEAAFAAAABQAAALedEAAHAAAABQAAAL6dEAAEAAAABQAAAMKdEAAFAAAABQAAAMedEAAGAAAABQAAAHakEAACAAAABQAAAM2dEAADAAAABQAAANCdEAAEAAAABQAAANSdEAAFAAAABQAAANmdEAAEAAAABQAAAN2dEAAEAAAABQAAAOGdEAACAAAABQAAAOOdEAADAAAABQAAAOadEAACAAAABgAAAOidEAAEAAAABgAAAOydEAADAAAABgAAAOydEAADAAAABgAAAO+dEAAHAAAABgAAAPadEAAEAAAABgAAAPqdEAAFAAAABgAAAK+dEAADAAAABgAAAJ2YEAADAAAABgAAAP+dEAAFAAAABgAAAASeEAAEAAAABgAAAAieEAAEAAAABgAAAAyeEAAIAAAABgAAABSeEAAHAAAABgAAABueEAAEAAAABgAAAB+eEAACAAAABgAAACGeEAAGAAAABgAAACeeEAAEAAAABgAAACeeEAAEAAAABgAAACueEAAHAAAABgAAADKeEAAGAAAABgAAADieEAAGAAAABgAAAD6eEAAHAAAABgAAAEWeEAAGAAAABgAAAEueEAAFAAAABgAAAFCeEAAGAAAABgAAAFCeEAAGAAAABgAAAJqYEAADAAAABgAAAFaeEAADAAAABgAAAFmeEAAIAAAABgAAAGGeEAAFAAAABgAAAGaeEAAEAAAABgAAAGqeEAAEAAAABgAAAG6eEAADAAAABgAAAG6eEAADAAAABgAAAHGeEAACAAAABgAAAOadEAACAAAABwAAAHOeEAAFAAAABwAAAHieEAACAAAABwAAAHqeEAAEAAAABwAAAH6eEAAEAAAABwAAAIKeEAAEAAAABwAAAIaeEAADAAAABwAAAOidEAAEAAAABwAAAPadEAAEAAAABwAAAImeEAAFAAAABwAAAAieEAAEAAAABwAAAI6eEAAEAAAABwAAAJKeEAAFAAAABwAAAJeeEAADAAAABwAAAJqeEAAFAAAABwAAAJ+eEAAEAAAABwAAAKOeEAAEAAAABwAAAKeeEAAHAAAAZGFzZGVtZGVuZGVyZGllZWluZWluZWVpbmVtZWluZW5laW5lcmVpbmVzYXVmYXVzYmVpYmlzZHVyY2hlbnRsYW5nZsO8cmdlZ2VuaGludGVybWl0bmFjaG5lYmVub2huZXNlaXR1bXZvbnp1YWJlcmFsc2Fuc3RhdHRhdWNoYmV2b3JkYW1pdGRhc3NkZW5uZW50d2VkZXJuYWNoZGVtbm9jaG9ib2J3b2hsb2RlcnNlaXRkZW1zb2JhbGRzb2Zlcm5zb25kZXJuc293ZWl0c293aWVzb3dvaGx1bmR3w6RocmVuZHdlZGVyd2VpbHdlbm53aWV3b3NjaG9uamFoYWx0d29obGRvY2htYWxibG/Dn2ViZW5ldHdhc251cnJ1aGlnc2hvbnp3YXJzb3dlaXNv4bqeU1PDhMOWQcyIT8yIYcyIb8yITm9ybWFsaXphdGlvbiBzaG91bGQgYWx3YXlzIGJlIHRoZSBmaXJzdCBzdGVwAJ8QAEsAAABgAAAADQAAAC9Vc2Vycy90aGF1bWFudC9QbGF5Z3JvdW5kL2x1Y2lkLXN1Z2dlc3QvcnVzdC9jb3JlL3NyYy90b2tlbml6YXRpb24vdGV4dC5ycwAXAAAAY2Fubm90IGFjY2VzcyBhIFRocmVhZCBMb2NhbCBTdG9yYWdlIHZhbHVlIGR1cmluZyBvciBhZnRlciBkZXN0cnVjdGlvbmFscmVhZHkgYm9ycm93ZWQAABgAAAAAAAAAAQAAAA4AAAAZAAAAAAAAAAEAAAAQAAAA2J8QAHAAAAChAAAAEQAAAC9Vc2Vycy90aGF1bWFudC8uY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9ydXN0LXN0ZW1tZXJzLTEuMi4wL3NyYy9zbm93YmFsbC9zbm93YmFsbF9lbnYucnPYnxAAcAAAALYAAAARAAAA2J8QAHAAAADKAAAAEQAAANifEABwAAAADAEAABYAAADYnxAAcAAAABIBAAAYAAAA2J8QAHAAAAAuAQAAFgAAANifEABwAAAAVgEAABYAAADYnxAAcAAAAFwBAAAYAAAA2J8QAHAAAABcAQAASQAAANifEABwAAAAdwEAABYAAABcoRAANAAAACChEAAtAAAATaEQAAwAAABZoRAAAwAAAAihEAAYAAAAqQgAAAkAAABzcmMvbGliY29yZS9zbGljZS9tb2QucnNhc3NlcnRpb24gZmFpbGVkOiBgKGxlZnQgPT0gcmlnaHQpYAogIGxlZnQ6IGBgLAogcmlnaHQ6IGBgOiBkZXN0aW5hdGlvbiBhbmQgc291cmNlIHNsaWNlcyBoYXZlIGRpZmZlcmVudCBsZW5ndGhzw59zcxFBEAEAQaTDwgALFQgAIAh5WVUAb6QQAAEAAAD/////AgBBxMPCAAsNZ6QQAAIAAAD/////AQBB3MPCAAsNg6MQAAIAAAD/////AgBB9MPCAAsNaaQQAAMAAAD/////AQBBjMTCAAsNgaMQAAIAAAD/////AQBBpMTCAAsNeKQQAAEAAAD/////AwBBvMTCAAsNbKQQAAIAAAAFAAAAAgBB1MTCAAsNg6MQAAIAAAD/////AQBB7MTCAAsNgaMQAAIAAAD/////AQBBhMXCAAsNYqQQAAIAAAD/////AgBBnMXCAAsNZKQQAAMAAAACAAAAAQBBtMXCAAsVbmlzdR4FAABOpBAAAwAAAP////8BAEHUxcIACw1/oxAAAgAAAP////8CAEHsxcIACw1RpBAAAwAAAP////8BAEGExsIACw1KpBAABAAAAP////8DAEGcxsIACw1UpBAABAAAAP////8CAEG0xsIACw1YpBAAAgAAAP////8CAEHMxsIACw1apBAABAAAAP////8DAEHkxsIACw1epBAABAAAAP////8EAEH8xsIACxl1HgRpZ2VyZW4AAAB/oxAAAgAAAP////8BAEGgx8IACw1KpBAABAAAAP////8BAEG4x8IACw1IpBAAAAAAAP////8GAEHQx8IACw2qoRAAAQAAAAAAAAACAEHox8IACw2poRAAAQAAAAAAAAABAEGAyMIACw1IpBAAAgAAAAAAAAADAEGYyMIACw1ypBAAAgAAAAAAAAAEAEGwyMIACw10pBAAAgAAAAAAAAAFAEHIyMIAC5ECw6RsaWNoZW5kdW5naXNjaGlraGVpdGtlaXRzdGVzdGVtZXJuZXNhZW91w7bDvGluc0FBY2Nlc3NFcnJvcmNhbGxlZCBgT3B0aW9uOjp1bndyYXAoKWAgb24gYSBgTm9uZWAgdmFsdWXApBAAFQAAAHoBAAAVAAAAc3JjL2xpYmNvcmUvb3B0aW9uLnJzVHJpZWQgdG8gc2hyaW5rIHRvIGEgbGFyZ2VyIGNhcGFjaXR5AAAADKUQABcAAABdAgAACQAAAHNyYy9saWJhbGxvYy9yYXdfdmVjLnJzaW50ZXJuYWwgZXJyb3I6IGVudGVyZWQgdW5yZWFjaGFibGUgY29kZQAMpRAAFwAAAAoCAAAnAHsJcHJvZHVjZXJzAghsYW5ndWFnZQEEUnVzdAAMcHJvY2Vzc2VkLWJ5AwVydXN0Yx0xLjQwLjAgKDczNTI4ZTMzOSAyMDE5LTEyLTE2KQZ3YWxydXMGMC4xNC4wDHdhc20tYmluZGdlbhIwLjIuNTggKDI5MDJjZWIyNik=';
-
-                var binary = null;
-
-                var isNode = typeof process !== 'undefined'
-                    && process.versions != null
-                    && process.versions.node != null;
-
-                if (isNode) {
-                    binary = Buffer.from(src, 'base64');
-                } else {
-                    var raw = window.atob(src);
-                    binary = new Uint8Array(new ArrayBuffer(raw.length));
-                    for(var i = 0; i < raw.length; i++) {
-                        binary[i] = raw.charCodeAt(i);
-                    }
-                }
-
-                var instantiatePromise = WebAssembly.instantiate(
-                    binary,
-                    {env: {memory: new WebAssembly.Memory({initial: 10})}}
-                );
-
-                var exportsPromise = instantiatePromise.then(result => {
-                    return result.instance.exports
-                });
-
-var compileWasm = exportsPromise.then(function(wasm) {
-                const __exports__ = {};
-
-                
-/**
-* @param {number} id
-*/
-__exports__.create_store = function(id) {
-    wasm.create_store(id);
-};
-
-/**
-* @param {number} id
-*/
-__exports__.destroy_store = function(id) {
-    wasm.destroy_store(id);
-};
-
-let WASM_VECTOR_LEN = 0;
-
-let cachegetUint8Memory0 = null;
-function getUint8Memory0() {
-    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
-    }
-    return cachegetUint8Memory0;
-}
-
-const lTextEncoder = typeof TextEncoder === 'undefined' ? require('util').TextEncoder : TextEncoder;
-
-let cachedTextEncoder = new lTextEncoder('utf-8');
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-}
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
-
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length);
-        getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
-        return ptr;
-    }
-
-    let len = arg.length;
-    let ptr = malloc(len);
-
-    const mem = getUint8Memory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
-    }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3);
-        const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
-
-        offset += ret.written;
-    }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {string} left
-* @param {string} right
-*/
-__exports__.highlight_with = function(store_id, left, right) {
-    var ptr0 = passStringToWasm0(left, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(right, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    wasm.highlight_with(store_id, ptr0, len0, ptr1, len1);
-};
-
-/**
-* @param {number} id
-* @param {number} limit
-*/
-__exports__.set_limit = function(id, limit) {
-    wasm.set_limit(id, limit);
-};
-
-let cachegetUint32Memory0 = null;
-function getUint32Memory0() {
-    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
-    }
-    return cachegetUint32Memory0;
-}
-
-function passArray32ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 4);
-    getUint32Memory0().set(arg, ptr / 4);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {Uint32Array} ids
-* @param {string} texts
-* @param {Uint32Array} ratings
-*/
-__exports__.set_records = function(store_id, ids, texts, ratings) {
-    var ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(texts, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    var ptr2 = passArray32ToWasm0(ratings, wasm.__wbindgen_malloc);
-    var len2 = WASM_VECTOR_LEN;
-    wasm.set_records(store_id, ptr0, len0, ptr1, len1, ptr2, len2);
-};
-
-/**
-* @param {number} store_id
-* @param {string} query
-*/
-__exports__.run_search = function(store_id, query) {
-    var ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    wasm.run_search(store_id, ptr0, len0);
-};
-
-let cachegetInt32Memory0 = null;
-function getInt32Memory0() {
-    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
-    }
-    return cachegetInt32Memory0;
-}
-
-function getArrayU32FromWasm0(ptr, len) {
-    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
-}
-/**
-* @param {number} store_id
-* @returns {Uint32Array}
-*/
-__exports__.get_result_ids = function(store_id) {
-    wasm.get_result_ids(8, store_id);
-    var r0 = getInt32Memory0()[8 / 4 + 0];
-    var r1 = getInt32Memory0()[8 / 4 + 1];
-    var v0 = getArrayU32FromWasm0(r0, r1).slice();
-    wasm.__wbindgen_free(r0, r1 * 4);
-    return v0;
-};
-
-const lTextDecoder = typeof TextDecoder === 'undefined' ? require('util').TextDecoder : TextDecoder;
-
-let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-
-cachedTextDecoder.decode();
-
-function getStringFromWasm0(ptr, len) {
-    return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
-}
-/**
-* @param {number} store_id
-* @returns {string}
-*/
-__exports__.get_result_titles = function(store_id) {
-    try {
-        wasm.get_result_titles(8, store_id);
-        var r0 = getInt32Memory0()[8 / 4 + 0];
-        var r1 = getInt32Memory0()[8 / 4 + 1];
-        return getStringFromWasm0(r0, r1);
-    } finally {
-        wasm.__wbindgen_free(r0, r1);
-    }
-};
-
-
-
-                return __exports__;
-            });
-
-const DEFAULT_LIMIT = 10;
-var NEXT_ID = 1;
-
-
-class LucidSuggest {
-    constructor() {
-        this.id         = NEXT_ID++;
-        this.limit      = DEFAULT_LIMIT;
-        this.records    = [];
-        this.setupQueue = compileWasm;
-
-        this.setup(wasm => {
-            wasm.create_store(this.id);
-            wasm.highlight_with(this.id, '{{', '}}');
-        });
-    }
-
-    setup(fn) {
-        this.setupQueue = this.setupQueue.then(async wasm => {
-            await fn(wasm);
-            this.setupQueue = Promise.resolve(wasm);
-            return wasm
-        });
-        return this.setupQueue
-    }
-
-    destroy() {
-        const oldQueue = this.setupQueue;
-        this.setupQueue = Promise.reject(new Error('Suggest destroyed'));
-        oldQueue.then(wasm => {
-            wasm.destroy_store(this.id);
-        });
-    }
-
-    setRecords(records) {
-        return this.setup(wasm => {
-            this.records = setRatings(records);
-            wasm.set_records(
-                this.id,
-                this.records.map(r => r.id),
-                this.records.map(r => r.title).join('\0'),
-                this.records.map(r => r.rating),
-            );
-        })
-    }
-
-    setLimit(limit) {
-        return this.setup(wasm => {
-            this.limit = limit;
-            wasm.set_limit(this.id, this.limit);
-        })
-    }
-
-    async search(query) {
-        const wasm = await this.setupQueue;
-        wasm.run_search(this.id, query);
-        const ids    = wasm.get_result_ids(this.id);
-        const titles = wasm.get_result_titles(this.id).split('\0');
-        const hits   = [];
-        for (let i = 0; i < ids.length; i++) {
-            const id     = ids[i];
-            const title  = titles[i];
-            const record = this.records.find(r => r.id === id);
-            if (!record) throw new Error(`Missing record ${id}`)
-            if (!title)  throw new Error(`Missing title for ${id}`)
-            hits.push(new Hit(title, record));
-        }
-        return hits
-    }
-}
-
-
-function highlight(hit, left, right) {
-    let result = '';
-    for (const {text, highlight} of hit.chunks) {
-        result += highlight
-            ? left + text + right
-            : text;
-    }
-    return result
-}
-
-
-class Hit {
-    constructor(title, record) {
-        this.record = record;
-        this.chunks = toChunks(title);
-    }
-
-    get title() {
-        return highlight(this, '[', ']')
-    }
-}
-
-
-function toChunks(title) {
-    const split  = title.split(/{{|}}/g);
-    const chunks = [];
-    for (let i = 0; i < split.length; i++) {
-        if (split[i] != '') {
-            chunks.push({
-                text: split[i],
-                highlight: i % 2 === 1,
-            });
-        }
-    }
-    return chunks
-}
-
-
-function setRatings(records) {
-    return records.some(r => r.rating != null && r.rating > 0)
-        ? records.map(r => ({...r, rating: r.rating > 0 ? r.rating : 0}))
-        : records.map((r, i) => ({...r, rating: records.length - i}))
-}
-
-exports.Hit = Hit;
-exports.LucidSuggest = LucidSuggest;
-exports.highlight = highlight;
@@ -1,346 +0,0 @@
-'use strict';
-
-Object.defineProperty(exports, '__esModule', { value: true });
-
-var src = 'AGFzbQEAAAABehNgAn9/AGACf38Bf2ADf39/AGADf39/AX9gAX8AYAR/f39/AGABfwF/YAV/f39/fwBgAAF/YAR/f39/AX9gAABgBX9/f39/AX9gB39/f39/f38AYAd/f39/f39/AX9gAn9+AX9gAn5/AX9gAX8BfmABfAF8YAJ8fAF8A90B2wEFAgIGBwYHBwcHAAAAAAAAAAAAAAIMAAQEBQMBAAACBAQJAgELAggABQABCQEAAAYCCwkGCwYDBQEJAAICAQEAAAcFBQECBQUADQIBAAAADwIAAAABAQEBAAQBAQABAQEBAAEHBAAFAgQFBQUAAAAEBAAAAgAFAAAABAIIAgIEABEABAICAgACAAAAAAgIAAQDBAIEAgYACQIFDgADAAAAAAEAAAAAAAAEAAIAAQQAAAAKAgQAAQIAAwkAAgICAwQGBAYAAwgIAwUFBQQSAQEBAQAKCQEKAQoQAAQEBQFwARoaBQMBABEGCQF/AUGAgMAACwfEAQwGbWVtb3J5AgAMY3JlYXRlX3N0b3JlACANZGVzdHJveV9zdG9yZQAXDmhpZ2hsaWdodF93aXRoAEEJc2V0X2xpbWl0AFgLc2V0X3JlY29yZHMAFQpydW5fc2VhcmNoAAIOZ2V0X3Jlc3VsdF9pZHMATBFnZXRfcmVzdWx0X3RpdGxlcwBAEV9fd2JpbmRnZW5fbWFsbG9jAMABEl9fd2JpbmRnZW5fcmVhbGxvYwDHAQ9fX3diaW5kZ2VuX2ZyZWUA0QEJNAEAQQELGQXZAdABG9QBoQEs1AGsAVTaAdgB2gHOAdoBzwHaAc0B2gHaAdoB2gHGAdoB2gEK6tQG2wHVWANBfwV+AnwjAEGABGsiBCQAAkACQAJAAkACQCABKAIAIgUgASgCBEcEQCAEQewAaiEnIARB1ANqISggBEGVA2ohLyAEQeUDaiEwIARBkAJqIR0gBEH4AmohGCAEQfgAaiExIARB9QBqITwgBEHIA2ohIiAEQcwDaiEZIARBhAFqITkgBEHkAGohOiAEQeAAaiE9IARBiwJqITIgBEHdA2ohHiAEQewDaiEjIARB3AJqISkgBEHtAmohPgNAIAEgBUEEajYCAAJAAkACQAJAAkAgASgCCCgCACIGQQxqKAIAIgcgBSgCACIFSwRAIAYoAgQgBUE8bGoiBSgCOCE/IAUoAgghKiAFKAIEIUAgBUE0aigCACEzIAVBLGooAgAhNCAFQShqKAIAITUgBUEgaigCACE2IAVBHGooAgAhNyAFQRRqKAIAITggBUEQaigCACEkIAMoAgAoAgAhChCMASIXRQ0MEI0BIhZFDQwgFygCAEUEQCAXQX82AgAgFigCAEUEQCAWQX82AgAgFkEEaiELAkAgF0EEaiIRKAIAIgVFBEBBACEFIBdBADYCFAwBCyAXKAIIQf8BIAVBBWoQxAEaIBdBADYCFCAXKAIEIgVBCEkNACAFQQFqQQN2QQdsIQULIBcgBTYCEAJAIAsoAgAiBUUEQEEAIQUgFkEANgIUDAELIBYoAghB/wEgBUEFahDEARogFkEANgIUIBYoAgQiBUEISQ0AIAVBAWpBA3ZBB2whBQsgFiAFNgIQIAooAgQiBQRAIAooAgAiFCAFQRRsaiElICogJEEUbGoiGkFsaiErA0ACQCALIBQoAgAiCRBfDQAgBCAJNgIoIAQgFC8BEDsBUCAEIBQoAgw2AjQgBCAUKAIIIg42AjAgBCAUKAIEIhM2AiwgBCAKKAIYIh82AkggBCAKKAIQIiA2AkAgBCAKKAIIIiE2AjggBCAKQRxqKAIAIiw2AkwgBCAKQRRqKAIAIkE2AkQgBCAKQQxqKAIAIkI2AjwgBEECOgB0ICRFDQBBAiEHICohBQJAA0ACQAJAIBEgBSgCACINEF8NACAEIDM2ArwBIAQgNDYCuAEgBCA1NgK0ASAEIDY2ArABIAQgNzYCrAEgBCA4NgKoASAEIAVBEGovAQA7AcABIAQgBUEMaigCADYCpAEgBCAFQQhqKAIAIgw2AqABIAQgBUEEaigCACIGNgKcASAEIA02ApgBAkACQCAkIA1BAWoiEk0NACAEIDM2AqwCIAQgNDYCqAIgBCA1NgKkAiAEIDY2AqACIAQgNzYCnAIgBCA4NgKYAiAEICogEkEUbGoiCC0AESIVOgCxAiAEIAgtABA6ALACIAQgCCgCDCIbNgKUAiAEIAgoAggiDzYCkAIgBCAIKAIEIhA2AowCIAQgCCgCADYCiAICQAJAIAYgD0kEQCAQIAxJDQEgECAMayEIDAILIAYgD2shCAwBCxDVAQALIA4gE2sgDCAGayAIakkNACARIBIQXw0AIAQgFToAmQMgBEEKOgCYAyAEIA82AvgCIAQgDTYC8AIgBCAzNgKUAyAEIDQ2ApADIAQgNTYCjAMgBCA2NgKIAyAEIDc2AoQDIAQgODYCgAMgBCAGNgL0AiAEIBAgBmsgG2o2AvwCIARBwANqIARB8AJqIARBKGoQASAEQYACaiINIBlBCGoiCCkCADcDACAEQfYBaiIMIB5BAmoiGy0AADoAACAEQegBaiIGICNBCGoiJikAADcDACAEQfABaiIPICNBEGoiOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyItQQJGDQAgBCgC6AMhHCAEKQPgAyFFIAQoAsgDIS4gBCkDwAMhRiAEQcgCaiIQIA0pAwA3AwAgBEGoA2oiEiAGKQMANwMAIARBsANqIhUgDygCADYCACAEIAQpA/gBNwPAAiAEIAQvAfQBOwG4AyAEIAQpA+ABNwOgAyAEIAwtAAA6ALoDIAQgLjYC2AIgBCBGNwPQAiApIAQpA8ACNwIAIClBCGogECkDADcCACAEIC06AOwCID4gBC8BuAM7AAAgPkECaiAELQC6AzoAACAEQdgBaiItIBUoAgA2AgAgBEHQAWoiLiASKQMANwMAIAQgBCkDoAM3A8gBIARBwANqIARB0AJqIARBmAFqIARBiAJqEEIgDSAIKQIANwMAIAwgGy0AADoAACAGICYpAAA3AwAgDyA7KAAANgIAIAQgGSkCADcD+AEgBCAeLwAAOwH0ASAEICMpAAA3A+ABIAQtANwDIiZBAkYNACAEKALoAyEFIAQpA+ADIUcgBCgCyAMhByAEKQPAAyFGIBAgDSkDADcDACAYIAYpAwA3AwAgBEGAA2oiEyAPKAIANgIAIAQgBCkD+AE3A8ACIAQgBC8B9AE7AbgDIAQgBCkD4AE3A/ACIAQgDC0AADoAugMgBiAQKQMANwMAIAQgBCkDwAI3A+ABIAQgBC8BuAM7AfgBIAQgBC0AugM6APoBIBUgEygCADYCACASIBgpAwA3AwAgBCAEKQPwAjcDoAMgBCAHNgLIAyAEIEY3A8ADIBkgBCkD4AE3AgAgCCAGKQMANwIAIAQgJjoA3AMgHiAELwH4ATsAACAbIAQtAPoBOgAAIARB8AJqIBEgByAEQcADahAoIBkgBCkDoAM3AgAgCCASKQMANwIAIBlBEGoiBiAVKAIANgIAIAQgBTYCyAMgBCBHNwPAAyAEQfACaiARIAUgBEHAA2oQKCAZIAQpA8gBNwIAIAggLikDADcCACAGIC0oAgA2AgAgBCAcNgLIAyAEIEU3A8ADIARB8AJqIAsgHCAEQcADahAoDAELAkAgCigCBCAJQQFqIgxNDQAgCigCACEGIAopAgghRSAKKQIQIUYgBCAKKQIYNwOoAiAEIEY3A6ACIAQgRTcDmAIgBCAGIAxBFGxqIgYtABEiDzoAsQIgBCAGLQAQOgCwAiAEIAYoAgwiEDYClAIgBCAGKAIIIgg2ApACIAQgBigCBCINNgKMAiAEIAYoAgA2AogCIAQoApwBIRIgBCgCoAECQAJAIBMgCEkEQCANIA5JDQEgDSAOayEGDAILIBMgCGshBgwBCxDVAQALIBJrIA4gE2sgBmpJDQAgCyAMEF8NACAEIA86AJkDIARBCjoAmAMgBCAINgL4AiAEIAk2AvACIAQgLDYClAMgBCAfNgKQAyAEIEE2AowDIAQgIDYCiAMgBCBCNgKEAyAEICE2AoADIAQgEzYC9AIgBCANIBNrIBBqNgL8AiAEQcADaiAEQZgBaiAEQfACahABIARBgAJqIg0gGUEIaiIIKQIANwMAIARB9gFqIgwgHkECaiIPLQAAOgAAIARB6AFqIgYgI0EIaiImKQAANwMAIARB8AFqIhAgI0EQaiI7KAAANgIAIAQgGSkCADcD+AEgBCAeLwAAOwH0ASAEICMpAAA3A+ABIAQtANwDIi1BAkYNACAEKALoAyEuIAQpA+ADIUUgBCgCyAMhHCAEKQPAAyFGIARB0AFqIhIgDSkDADcDACAEQboDaiJDIAwtAAA6AAAgBEGoA2oiFSAGKQMANwMAIARBsANqIhsgECgCADYCACAEIAQpA/gBNwPIASAEIAQvAfQBOwG4AyAEIAQpA+ABNwOgAyAEQcgCaiJEIBIpAwA3AwAgBCAEKQPIATcDwAIgBCAELwG4AzsBuAIgBCBDLQAAOgC6AiAEIC42AtgCIAQgRTcD0AIgKSAEKQOgAzcCACApQQhqIBUpAwA3AgAgKUEQaiAbKAIANgIAIARBwANqIARB0AJqIARBKGogBEGIAmoQQiANIAgpAgA3AwAgDCAPLQAAOgAAIAYgJikAADcDACAQIDsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiJkECRg0AIAQoAugDIQUgBCkD4AMhRyAEKALIAyEHIAQpA8ADIUUgEiANKQMANwMAIBggBikDADcDACAEQYADaiITIBAoAgA2AgAgBCAEKQP4ATcDyAEgBCAELwH0ATsBuAMgBCAEKQPgATcD8AIgBCAMLQAAOgC6AyAGIBIpAwA3AwAgBCAEKQPIATcD4AEgBCAELwG4AzsB+AEgBCAELQC6AzoA+gEgGyATKAIANgIAIBUgGCkDADcDACAEIAQpA/ACNwOgAyAEIBw2AsgDIAQgRjcDwAMgGSAEKQPAAjcCACAIIEQpAwA3AgAgBCAtOgDcAyAeIAQvAbgCOwAAIA8gBC0AugI6AAAgBEHwAmogESAcIARBwANqECggGSAEKQPgATcCACAIIAYpAwA3AgAgHiAELwH4ATsAACAPIAQtAPoBOgAAIAQgBzYCyAMgBCBFNwPAAyAEICY6ANwDIARB8AJqIAsgByAEQcADahAoIBkgBCkDoAM3AgAgCCAVKQMANwIAIBlBEGogGygCADYCACAEIAU2AsgDIAQgRzcDwAMgBEHwAmogCyAFIARBwANqECgMAQsgBEHAA2ogBEGYAWogBEEoahABIARB2AJqIgggIkEIaigCADYCACAEICIpAwA3A9ACIAQrA8ADIUogBCgC1AMhDSAEKALYAyEMIAQtANwDIQYgBEHwAmogHkEjEL4BGiAGQQJGDQEgBEGoA2oiDyAIKAIANgIAIAQgBCkD0AI3A6ADIARBiAJqIARB8AJqQSMQvgEaIBggDygCADYCACAEIAQpA6ADNwPwAiAEIAQvAIgCOwHQAiAEIARBigJqLQAAOgDSAiAEQdgDaiIQIDJBGGopAAA3AwAgBEHQA2oiEiAyQRBqKQAANwMAICIgMkEIaikAADcDACAEIDIpAAA3A8ADAn8gSpsiS0QAAAAAAADwQWMgS0QAAAAAAAAAAGZxBEAgS6sMAQtBAAshDwJAAkAgB0H/AXFBAkYiFQ0AQQAhCEF/IAwgDWsgD0EBdGsiG0EAIARB2ABqIBUbIg8oAhggDygCFGsCfyAPKwMAmyJLRAAAAAAAAPBBYyBLRAAAAAAAAAAAZnEEQCBLqwwBC0EAC0EBdGsiD0cgDyAbSRtBAWoiD0EBSw0BIA9BAWsNACAGDQELID0gBCkD8AI3AwAgPCAELwHQAjsAACAxIAQpA8ADNwMAIDxBAmogBC0A0gI6AAAgPUEIaiAYKAIANgIAIDFBCGogIikDADcDACAxQRBqIBIpAwA3AwAgMUEYaiAQKQMANwMAIAQgDDYCcCAEIA02AmwgBCAGOgB0IAZBAXMhCCAEIEo5A1ggSr0hRyAGIQcLIAUgK0YNBCAFQRRqIQUgCEUNAwwECyAEQQI6ANwDQQAhBQNAIARB2ABqIAVqIgYpAwAhRyAGIARBwANqIAVqIgcpAwA3AwAgByBHNwMAIAZBCGoiCCkDACFFIAggB0EIaiIIKQMANwMAIAZBEGoiEykDACFGIBMgB0EQaiITKQMANwMAIAZBGGoiBikDACFHIAYgB0EYaiIGKQMANwMAIAYgRzcDACATIEY3AwAgCCBFNwMAIAVBQGsgBUEgaiEFQcEASQ0ACyAELQB0IQcMAQsgGiAFQRRqIgVHDQELCyAHQf8BcUECRg0BIAQpA1ghRwsgBCkDeCFFIAQoAoABIQUgBCgCYCEGIBkgOikCADcCACAZQQhqIgcgOkEIaikCADcCACAZQRBqIgggOkEQaigCADYCACAEIAY2AsgDIAQgRzcDwAMgBEHwAmogESAGIARBwANqECggGSA5KQIANwIAIAcgOUEIaikCADcCACAIIDlBEGooAgA2AgAgBCAFNgLIAyAEIEU3A8ADIARB8AJqIAsgBSAEQcADahAoCyAUQRRqIhQgJUcNAAsLIBcoAggiBSgCACEIIBcoAhQhBiAXQQA2AhQgFygCECEUIBdBADYCECAXKAIMIQcgF0EINgIMIBdBsKbCADYCCCAXKAIEIQogF0EANgIEIAQgETYCgAEgBCAGNgJ8IAQgFDYCeCAEIAc2AnQgBCAFNgJwIAQgCjYCbCAEIAY2AmggBCAFIApqQQFqNgJkIAQgBUEEajYCYCAEIAc2AlwgBCAIQX9zQYCBgoR4cTYCWCAEQcADaiAEQdgAahCBAQJ/IAQtAOQDIgZBAkYEQAJAIAQoAmwiBUUEQEEAIQUgBEEANgJ8DAELIAQoAnBB/wEgBUEFahDEARogBEEANgJ8IAVBCEkNACAFQQFqQQN2QQdsIQULIAQgBTYCeCAEKAKAASIFICcpAgA3AgAgBUEQaiAnQRBqKAIANgIAIAVBCGogJ0EIaikCADcCAEIAIUVBCAwBCyAEQfACaiAEQcADakEkEL4BGiAdIBhBCGopAwA3AwAgBEGYAmoiCiAYQRBqKQMANwMAIARBoAJqIgggGEEYaigCADYCACAEIDBBAmotAAA6AJoBIAQgMC8AADsBmAEgBCAYKQMANwOIAiAEQSBqQX8gBCgCaCIFQQFqIgcgByAFSRsQpwEgBCgCJCEFIAQoAiAiByAEKQOIAjcDACAHIAY6ABwgB0EYaiAIKAIANgIAIAdBEGogCikDADcDACAHQQhqIB0pAwA3AwAgByAELwGYATsAHSAHQR9qIAQtAJoBOgAAIARBATYCMCAEIAU2AiwgBCAHNgIoIARBwANqIARB2ABqQSwQvgEaIARB8AJqIARBwANqEIEBAkAgBC0AlAMiCEECRg0AQQEhBkEgIQkDQCAEQYgCaiAEQfACakEkEL4BGiAEQbABaiAdQRhqKAIAIgo2AgAgBEGoAWogHUEQaikDACJFNwMAIARBoAFqIB1BCGopAwAiRjcDACAEIC9BAmotAAA6ANICIAQgLy8AADsB0AIgBCAdKQMAIkc3A5gBIBggRjcDACAEQYADaiIRIEU3AwAgBEGIA2oiFCAKNgIAIAQgRzcD8AIgBCAELwHQAjsBiAIgBCAELQDSAjoAigIgBSAGRgRAIARBKGogBUF/IAQoAtADIgVBAWoiByAHIAVJGxCEASAEKAIoIQcLIAcgCWoiBSAEKQPwAjcDACAYKQMAIUUgESkDACFGIBQoAgAhCiAFQRxqIAg6AAAgBUEdaiAELwGIAjsAACAFQR9qIAQtAIoCOgAAIAVBGGogCjYCACAFQRBqIEY3AwAgBUEIaiBFNwMAIAQgBkEBaiIGNgIwIARB8AJqIARBwANqEIEBIAQtAJQDIghBAkYNASAJQSBqIQkgBCgCLCEFDAALAAsCQCAEKALUAyIFRQRAQQAhBSAEQQA2AuQDDAELIAQoAtgDQf8BIAVBBWoQxAEaIARBADYC5AMgBUEISQ0AIAVBAWpBA3ZBB2whBQsgBCgC6AMiBiAoKQIANwIAIAQgBTYC4AMgBkEIaiAoQQhqKQIANwIAIAZBEGogKEEQaigCADYCACAEKQIsIUUgBCgCKAshDSAWKAIIIgUoAgAhCCAWKAIUIQYgFkEANgIUIBYoAhAhESAWQQA2AhAgFigCDCEHIBZBCDYCDCAWQbCmwgA2AgggFigCBCEKIBZBADYCBCAEIAs2AoABIAQgBjYCfCAEIBE2AnggBCAHNgJ0IAQgBTYCcCAEIAo2AmwgBCAGNgJoIAQgBSAKakEBajYCZCAEIAVBBGo2AmAgBCAHNgJcIAQgCEF/c0GAgYKEeHE2AlggBEHAA2ogBEHYAGoQgQECfyAELQDkAyIGQQJGBEACQCAEKAJsIgVFBEBBACEFIARBADYCfAwBCyAEKAJwQf8BIAVBBWoQxAEaIARBADYCfCAFQQhJDQAgBUEBakEDdkEHbCEFCyAEIAU2AnggBCgCgAEiBSAnKQIANwIAIAVBEGogJ0EQaigCADYCACAFQQhqICdBCGopAgA3AgBBACEbQQAhEUEIDAELIARB8AJqIARBwANqQSQQvgEaIB0gGEEIaikDADcDACAEQZgCaiIKIBhBEGopAwA3AwAgBEGgAmoiCCAYQRhqKAIANgIAIAQgMEECai0AADoAmgEgBCAwLwAAOwGYASAEIBgpAwA3A4gCQQEhESAEQRhqQX8gBCgCaCIFQQFqIgcgByAFSRsQpwEgBCgCHCEFIAQoAhgiByAEKQOIAjcDACAHIAY6ABwgB0EYaiAIKAIANgIAIAdBEGogCikDADcDACAHQQhqIB0pAwA3AwAgByAELwGYATsAHSAHQR9qIAQtAJoBOgAAIARBATYCMCAEIAU2AiwgBCAHNgIoIARBwANqIARB2ABqQSwQvgEaIARB8AJqIARBwANqEIEBIAQtAJQDIghBAkcEQEEBIQZBICEJA0AgBEGIAmogBEHwAmpBJBC+ARogBEGwAWogHUEYaigCACIKNgIAIARBqAFqIB1BEGopAwAiRjcDACAEQaABaiAdQQhqKQMAIkg3AwAgBCAvQQJqLQAAOgDSAiAEIC8vAAA7AdACIAQgHSkDACJHNwOYASAYIEg3AwAgBEGAA2oiCyBGNwMAIARBiANqIhEgCjYCACAEIEc3A/ACIAQgBC8B0AI7AYgCIAQgBC0A0gI6AIoCIAUgBkYEQCAEQShqIAVBfyAEKALQAyIFQQFqIgcgByAFSRsQhAEgBCgCKCEHCyAHIAlqIgUgBCkD8AI3AwAgGCkDACFGIAspAwAhSCARKAIAIQogBUEcaiAIOgAAIAVBHWogBC8BiAI7AAAgBUEfaiAELQCKAjoAACAFQRhqIAo2AgAgBUEQaiBINwMAIAVBCGogRjcDACAEIAZBAWoiCjYCMCAEQfACaiAEQcADahCBASAELQCUAyIIQQJHBEAgCUEgaiEJIAQoAiwhBSAKIQYMAQsLIAZBAWohEQsCQCAEKALUAyIFRQRAQQAhBSAEQQA2AuQDDAELIAQoAtgDQf8BIAVBBWoQxAEaIARBADYC5AMgBUEISQ0AIAVBAWpBA3ZBB2whBQsgBCgC6AMiBiAoKQIANwIAIAQgBTYC4AMgBkEIaiAoQQhqKQIANwIAIAZBEGogKEEQaigCADYCACAEKAIsIRsgBCgCKAshDyBFQiCIIkanIhRBFUkNAyAEQRBqIEVCIYinEKcBIAQoAhQgBCgCECETQQAhBSAEQQA2AmAgBEIENwNYIA1BYGohHCANQah/aiElQQAhByAUIQoDQAJAIAoiC0F/aiIKRQRAQQEhCEEAIQoMAQsCQAJAAkACQAJAIA0gCkEFdGooAgggC0EFdCIMIA1qQUhqKAIAIgZPBEAgC0F+aiEOIAwgJWohCEEAIQpBACEJA0AgCSAORgRAIAshCAwICyAJQQFqIQkgBiAIKAIAIgZPIAhBYGohCA0ACyAJQQFqIQggCUF/cyALaiEKDAELIAwgJWohCQNAAkAgCkEBRgRAQQAhCgwBCyAKQX9qIQogBiAJKAIAIgZJIAlBYGohCQ0BCwsgCyAKSQ0BIAsgFEsNAiALIAprIghBAXYiDkUNACANIApBBXRqIQYgDCAcaiEJA0AgBEHYA2oiDCAGQRhqIhApAwA3AwAgBEHQA2oiEiAGQRBqIhUpAwA3AwAgIiAGQQhqIhopAwA3AwAgBCAGKQMANwPAAyAJQQhqIh8pAwAhSCAJQRBqIiApAwAhSSAJQRhqIiEpAwAhRyAGIAkpAwA3AwAgECBHNwMAIBUgSTcDACAaIEg3AwAgISAMKQMANwMAICAgEikDADcDACAfICIpAwA3AwAgCSAEKQPAAzcDACAGQSBqIQYgCUFgaiEJIA5Bf2oiDg0ACwsgCkUgCEEJS3INBCALIAprIQggCkF/aiEFIBwgCkEFdGohBgNAIAsgBUkNAyALIBRLDQIgBiAIQQFqIggQPyAFQX9qIgcgBU8NBCAGQWBqIQYgByEFIAhBCkkNAAsMAwsgCiALEKsBAAsgCyAUEKkBAAsgBSALEKsBAAsgB0EBaiEKIAQoAmAiBSEHCyAEKAJcIAdGBEAgBEHYAGogBxCLASAEKAJgIgUhBwsgBCgCWCIGIAdBA3RqIgcgCDYCBCAHIAo2AgAgBCAFQQFqIgU2AmACfwJAIAVBAkkNAAJAA0ACQAJAAkACQCAGIAVBf2pBA3RqIgcoAgBFDQAgBUEDdCAGaiIOQXRqKAIAIgsgBygCBCIITQ0AQQIgBUECTQ0HGiAGIAVBfWoiDEEDdGooAgQiCSAIIAtqTQ0BQQMgBUEDTQ0HGiAOQWRqKAIAIAkgC2pLDQYMAQsgBUEDSQ0BIAcoAgQhCCAGIAVBfWoiDEEDdGooAgQhCQsgCSAISQ0BCyAFQX5qIQwLAkACQAJAAkACQAJAAkAgBSAMQQFqIhJLBEAgBSAMTQ0BIAYgDEEDdCIfaiIFKAIEIiAgBSgCAGoiBSAGIBJBA3QiIWoiBigCACIVSQ0CIAUgFEsNAyANIBVBBXRqIgsgBigCBCIQQQV0IghqIQcgBUEFdCEaIAUgFWsiDiAQayIFIBBPDQQgEyAHIAVBBXQiBhC+ASIOIAZqIQkgEEEBTkEAIAVBAU4bRQRAIAchBQwGCyAaIBxqIQggByEFA0AgCCAFQWBqIhogCUFgaiIsIAlBaGooAgAgBUFoaigCAEkiBxsiBikDADcDACAIQRhqIAZBGGopAwA3AwAgCEEQaiAGQRBqKQMANwMAIAhBCGogBkEIaikDADcDACAJICwgBxshCSALIBogBSAHGyIFTw0GIAhBYGohCCAJIA4iBksNAAsMBwtB6KvCACASIAUQqgEAC0Hoq8IAIAwgBRCqAQALIBUgBRCrAQALIAUgFBCpAQALIAggEyALIAgQvgEiBmohCSAOIBBKQQAgEEEBThsNASALIQULIBMhBgwBCyANIBpqIQ4gCyEFA0AgBSAHIAYgBygCCCAGKAIISSILGyIIKQMANwMAIAVBGGogCEEYaikDADcDACAFQRBqIAhBEGopAwA3AwAgBUEIaiAIQQhqKQMANwMAIAYgBkEgaiALGyEGIAVBIGohBSAHQSBqIAcgCxsiByAOTw0BIAkgBksNAAsLIAUgBiAJIAZrQWBxEL4BGiAEKAJgIgUgDEsEQCAEKAJYIgYgH2oiByAQICBqNgIEIAcgFTYCACAFIBJNDQIgBiAhaiIHIAdBCGogBSASQX9zakEDdBCcARogBCAFQX9qIgU2AmAgBSAFQQFNDQQaDAELC0H4q8IAIAwgBRCqAQALQaeuwgBBHUHErsIAEL0BAAsgBQshByAKDQALIAQoAlwEQCAGEB8LRQ0EIBMQHwwECwwMCwwLC0Hoq8IAIAUgBxCqAQALIBRBAkkNACAUQX5qIQUgDSAUQQV0akFAaiEGQQIhCQNAIAUgFEsNAiAGIAkQPyAGQWBqIQYgCUEBaiEJIAVBf2oiBUF/Rw0ACwsgEUEVSQ0BIARBCGogEUEBdhCnASAEKAIMIAQoAgghE0EAIQUgBEEANgJgIARCBDcDWCAPQWBqIRwgD0Gof2ohJUEAIQcgESEKA0ACQCAKIgtBf2oiCkUEQEEBIQhBACEKDAELAkACQAJAAkACQCAPIApBBXRqKAIIIAtBBXQiDCAPakFIaigCACIGTwRAIAtBfmohDiAMICVqIQhBACEKQQAhCQNAIAkgDkYEQCALIQgMCAsgCUEBaiEJIAYgCCgCACIGTyAIQWBqIQgNAAsgCUEBaiEIIAlBf3MgC2ohCgwBCyAMICVqIQkDQAJAIApBAUYEQEEAIQoMAQsgCkF/aiEKIAYgCSgCACIGSSAJQWBqIQkNAQsLIAsgCkkNASALIBFLDQIgCyAKayIIQQF2Ig5FDQAgDyAKQQV0aiEGIAwgHGohCQNAIARB2ANqIgwgBkEYaiIQKQMANwMAIARB0ANqIhIgBkEQaiIVKQMANwMAICIgBkEIaiIaKQMANwMAIAQgBikDADcDwAMgCUEIaiIfKQMAIUggCUEQaiIgKQMAIUkgCUEYaiIhKQMAIUcgBiAJKQMANwMAIBAgRzcDACAVIEk3AwAgGiBINwMAICEgDCkDADcDACAgIBIpAwA3AwAgHyAiKQMANwMAIAkgBCkDwAM3AwAgBkEgaiEGIAlBYGohCSAOQX9qIg4NAAsLIApFIAhBCUtyDQQgCyAKayEIIApBf2ohBSAcIApBBXRqIQYDQCALIAVJDQMgCyARSw0CIAYgCEEBaiIIED8gBUF/aiIHIAVPDQQgBkFgaiEGIAchBSAIQQpJDQALDAMLIAogCxCrAQALIAsgERCpAQALIAUgCxCrAQALIAdBAWohCiAEKAJgIgUhBwsgBCgCXCAHRgRAIARB2ABqIAcQiwEgBCgCYCIFIQcLIAQoAlgiBiAHQQN0aiIHIAg2AgQgByAKNgIAIAQgBUEBaiIFNgJgAn8CQCAFQQJJDQACQANAAkACQAJAAkAgBiAFQX9qQQN0aiIHKAIARQ0AIAVBA3QgBmoiDkF0aigCACILIAcoAgQiCE0NAEECIAVBAk0NBxogBiAFQX1qIgxBA3RqKAIEIgkgCCALak0NAUEDIAVBA00NBxogDkFkaigCACAJIAtqSw0GDAELIAVBA0kNASAHKAIEIQggBiAFQX1qIgxBA3RqKAIEIQkLIAkgCEkNAQsgBUF+aiEMCwJAAkACQAJAAkACQAJAIAUgDEEBaiISSwRAIAUgDE0NASAGIAxBA3QiH2oiBSgCBCIgIAUoAgBqIgUgBiASQQN0IiFqIgYoAgAiFUkNAiAFIBFLDQMgDyAVQQV0aiILIAYoAgQiEEEFdCIIaiEHIAVBBXQhGiAFIBVrIg4gEGsiBSAQTw0EIBMgByAFQQV0IgYQvgEiDiAGaiEJIBBBAU5BACAFQQFOG0UEQCAHIQUMBgsgGiAcaiEIIAchBQNAIAggBUFgaiIaIAlBYGoiLCAJQWhqKAIAIAVBaGooAgBJIgcbIgYpAwA3AwAgCEEYaiAGQRhqKQMANwMAIAhBEGogBkEQaikDADcDACAIQQhqIAZBCGopAwA3AwAgCSAsIAcbIQkgCyAaIAUgBxsiBU8NBiAIQWBqIQggCSAOIgZLDQALDAcLQeirwgAgEiAFEKoBAAtB6KvCACAMIAUQqgEACyAVIAUQqwEACyAFIBEQqQEACyAIIBMgCyAIEL4BIgZqIQkgDiAQSkEAIBBBAU4bDQEgCyEFCyATIQYMAQsgDyAaaiEOIAshBQNAIAUgByAGIAcoAgggBigCCEkiCxsiCCkDADcDACAFQRhqIAhBGGopAwA3AwAgBUEQaiAIQRBqKQMANwMAIAVBCGogCEEIaikDADcDACAGIAZBIGogCxshBiAFQSBqIQUgB0EgaiAHIAsbIgcgDk8NASAJIAZLDQALCyAFIAYgCSAGa0FgcRC+ARogBCgCYCIFIAxLBEAgBCgCWCIGIB9qIgcgECAgajYCBCAHIBU2AgAgBSASTQ0CIAYgIWoiByAHQQhqIAUgEkF/c2pBA3QQnAEaIAQgBUF/aiIFNgJgIAUgBUEBTQ0EGgwBCwtB+KvCACAMIAUQqgEAC0GnrsIAQR1BxK7CABC9AQALIAULIQcgCg0ACyAEKAJcBEAgBhAfC0UNAiATEB8MAgsgBSAUEKsBAAsgEUECSQ0AIBFBfmohBSAPIBFBBXRqQUBqIQZBAiEJA0AgESAFSQ0GIAYgCRA/IAZBYGohBiAJQQFqIQkgBUF/aiIFQX9HDQALCyAWIBYoAgBBAWo2AgAgFyAXKAIAQQFqNgIAIA1FDQYgRqciC0EFdCEXQQAhCgJ/IAtFBEBBACEHQQAhE0EAIRZBAAwBCyAUQQV0IQZBACEFQQAhEwNAIBMgBSANaiIHQRRqKAIAayAHQRhqKAIAagJ/IAcrAwCbIkpEAAAAAAAA8EFjIEpEAAAAAAAAAABmcQRAIEqrDAELQQALQQF0ayETIAYgBUEgaiIFRw0AC0EAIQVBACEHA0AgByAFIA1qQRxqLQAAQQFzaiEHIAYgBUEgaiIFRw0AC0EAIQlBACEIA0AgCCAJIA1qIgVBDGooAgBrIAVBEGooAgBqIAVBFGooAgAgBUEYaigCAGtqIQggBiAJQSBqIglHDQALQQAhBiAXQWBqQQV2IgUgC0F/aiIOIA4gBUsbIgwEQCANQShqIQVBACEJA0AgBkEAIAVBYGooAgAiEEEBaiIOIAUoAgAiFmsiEiASIA5LG2oiBiAWIBBBf3NqaiAGIA4gFkkbIQYgBUEgaiEFIAlBAWoiCSAMSQ0ACwtBACAGayEWQQAgCGsLIQhBASEOIAtBf2oiBSALTQRAIAUgC0kEQCANIAVBBXRqLQAdIQ4LIA0oAgghBSANQSBqIA0gF2pHBEAgDUEoaiEGIBRBBXRBYGohCQNAIAYoAgAiCiAFIAUgCksbIQUgBkEgaiEGIAlBYGoiCQ0ACwtBACAFayEKC0EAIQYgJARAICRBFGwhCSAqQQhqIQUDQCAFKAIAIAVBfGooAgBrIAZqIQYgBUEUaiEFIAlBbGoiCQ0AC0EAIAZrIQYLIEWnIQUgAigCACgCACgCBCIURSALQQFLcg0CIAtBAWtFBEAgEUEBRyAUQQJJcg0DIA9BEGooAgAgDygCDGtBAXQgDUEQaigCACANKAIMa08NAyANLQAdDQMLIAUEQCANEB8LIBsEQCAPEB8LIAEoAgAiBSABKAIERw0ACwsgAEEANgIEDAELIAAgJDYCCCAAICo2AgQgACBANgIAIABB4ABqIAY2AgAgAEHcAGpBACAkazYCACAAQdgAaiA/NgIAIABB1ABqIAo2AgAgAEHQAGogDjYCACAAQcwAaiAWNgIAIABByABqIAg2AgAgAEHEAGogBzYCACAAQUBrIBM2AgAgAEE8aiARNgIAIABBOGogGzYCACAAQTRqIA82AgAgAEEwaiALNgIAIABBLGogBTYCACAAQShqIA02AgAgAEEkaiA/NgIAIABBIGogMzYCACAAQRxqIDQ2AgAgAEEYaiA1NgIAIABBFGogNjYCACAAQRBqIDc2AgAgAEEMaiA4NgIACyAEQYAEaiQADwsgBSAREKsBAAtBnr3CAEEQIARB8AJqQcyowgAQmQEAC0HYvMIAQcYAIARB8AJqQdyowgAQmQEAC+MfAyR/A34GfCMAQcABayIEJAACQAJAAkACQAJAAkACQAJAIAJBCGooAgAiAyACKAIEIgZGDQAgAUEIaigCACIFIAEoAgQiC0YNACAFIAtrIgUgBSADIAZrIgMgAyAFSxsgAi0AKSIGGyEFIANBAk9BACAFQQFLGw0BIAMgBUYNAgwDCyAAQQI6ABwMAwtEAAAAAAAA8D8gBSADIAMgBUsiCxu4IAMgBSALG7ijoUSkcD0K16PQP2NFDQELIARBKGogARCvASAEKAIsIQUgBCgCKCEJAkAgBkUEQCAFIAEoAgggASgCBGsiBiADQQFqIgMgAyAGSxsiA08NASADIAUQqQEACyAFIQMLAkACQAJAAkBEAAAAAAAA8D8CfAJAEHsiBQRAIARBIGogAhCvASAEKAIkIQYgAwRAIAZFDQIgBSgCAA0LIAQoAiAhCiAFQX82AgAgBSgCEA0LIAVBBGohCyAFQX82AhACQCADIAUoAgwiCE0EQCAFIAM2AgwMAQsgCyADIAhrEJsBCyAFQRRqIQgCQCAGIAUoAhwiDE0EQCAFIAY2AhwMAQsgCCAGIAxrEJsBCyAFKAIEIAUoAgwgCSADEHUgBSgCFCAFKAIcIAogBhB1QQAhAyAFKAIEIAUoAgwiBiAEQYABakEAQSAgBmdrEAkgBSgCFCAFKAIcIgYgBEGAAWpBAEEgIAZnaxAJIAsQggEgCBCCASAFKAIcIQkCQCAFKAIMIgpFBEBBACEGQQAhC0EAIQgMAQtBACEGQQAhC0EAIQggCUUNACAFKAIUIQwgBSgCBCEOA0ACQAJAAkACQEF/IA4gA0ECdGooAgAiDyAMIAZBAnRqKAIAIhBHIA8gEEkbDgIAAgELIAZBAWohBiADQQFqIQMgCEEBaiEIDAILIANBAWohAwwBCyAGQQFqIQYLIAtBAWohCyADIApPDQEgBiAJSQ0ACwsgBSAFKAIQQQFqNgIQIAUgBSgCAEEBajYCACAItyAJIApqIANrIAZrIAtquKMMAwtEAAAAAAAAAABEAAAAAAAA8D8gBhsMAgsMCAtEAAAAAAAAAAALoURSuB6F61HgP2MEQCAEQQI6AEwQJiIHRQ0HIARBGGogAhCvASAEKAIcIQsgBCgCGCETIARBEGogARCvASAHKAIsDQkgBCgCFCEaIAQoAhAhFCAHQX82AiwgBygCPA0JIAdBADYCSCAHQoCAgIBwNwI4IARBCGogAhCwASAHQTBqIAQoAggiAyADIAQoAgxqEJQBIAQgARCwASAHQUBrIhUgBCgCACIDIAMgBCgCBGoQlAEgBygCAA0JIAdBfzYCACAHKAJAIQYgBygCMCENIAcoAjgiCUECaiIDIAcoAkgiCkECaiIFIAMgBUsbIgMgBygCBE0NBCAHQQRqIQ8gA0EBdiADaiIOIA5sIgwgBygCECIDTQ0DIAdBCGogAyAMIANrIgUQhQEgBygCCCAHKAIQIgxBA3RqIQMgBUECSQ0BQQEhCANAIANCADcDACADQQhqIQMgCEEBaiIIIAVJDQALIAggDGpBf2ohDAwCCyAAQQI6ABwMBQsgBQ0ADAELIANCADcDACAMQQFqIQwLIAcgDjYCBCAHIAw2AhAgDxB+CyAJBEAgCUEDdCEIQQIhAwNAIAcoAggiBSAHKAIEIgkgA2xBA3RqQQhqIAkgA0F/amxBA3QgBWpBCGorAwAgDSsDAKA5AwAgA0EBaiEDIA1BCGohDSAIQXhqIggNAAsLIAoEQCAKQQN0IQ1BAiEDA0AgBygCCCADIAcoAgRqQQN0aiIFIAVBeGorAwAgBisDAKA5AwAgA0EBaiEDIAZBCGohBiANQXhqIg0NAAsLIAcoAhRFBEAgB0F/NgIUAkAgBygCGCIDRQRAQQAhAyAHQQA2AigMAQsgBygCHEH/ASADQQVqEMQBGiAHQQA2AiggBygCGCIDQQhJDQAgA0EBakEDdkEHbCEDCyAHIAM2AiQgCwRAIAdBGGohFiATIAtBAnRqIQ0gFCAaQQJ0aiEXQQAhCyATIQ4DQCALQQFqIQ8gDigCACEMIBoEQCALQQJqIRggC0ECdCATakF8aiEZQQAhCUEAIQMgFCEFA0AgAyEIIAQgBSgCACIQNgKAASAFQQRqIQVCpcaIocicp/lLISdBASEDIBAhBgNAICcgBq1C/wGDhUKzg4CAgCB+IScgA0EERwRAIARBgAFqIANqIANBAWohAy0AACEGDAELCyAnpyIGQRl2IgNBCHQgA3IiA0EQdCADciEbIAhBAWohAyAHKAIYIhEgBnEhBiAHKAIcIRxBACESAkADQAJAIAYgHGooAAAiHSAbcyIKQX9zIApB//37d2pxQYCBgoR4cSIKBEAgBygCICEeA0AgECAeIApoQQN2IAZqIBFxQQN0aiIfKAIARg0CIApBf2ogCnEiCg0ACwsgEkEEaiISIAZqIBFxIQZBACEKIB0gHUEBdHFBgIGChHhxRQ0BDAILCyAfQQRqIQoLAkACQAJAIAcoAjgiBiALSwRAIAcoAkgiBiAITQ0BIApB2KrCACAKGygCACEKIAcoAjAgC0EDdGorAwAhKiAVKAIAIAhBA3RqKwMAISsgC0UNAkQAAAAAAADgPyEsIAwgGSgCAEcNAgwDC0Hoq8IAIAsgBhCqAQALQeirwgAgCCAGEKoBAAtEAAAAAAAA8D8hLAsgCAR8RAAAAAAAAOA/RAAAAAAAAPA/IBAgCEECdCAUakF8aigCAEYbBUQAAAAAAADwPwshLiAHKAIIIgYgBygCBCIRIApsIAlqQQN0aisDACEtICsgKyAqICogK2MbICogKmIbRAAAAAAAAPA/RAAAAAAAAAAAIAwgEEcboiAGIA8gEWwiEiADakEDdGorAwCgIC0gDyAJayAIaiAKa7hEAAAAAAAA4D+ioBDMASEtIAYgEiAIQQJqIghqQQN0aisDACEvICogLBDMASEqIAYgCCARIBhsIgpqQQN0aiArIC4QzAEgBiADIApqQQN0aisDAKAgKiAvoBDMASAtEMwBOQMAIAMgCSAMIBBGGyEJIAUgF0cNAAsLIA5BBGohDiAEIAw2AoABQqXGiKHInKf5SyEnQQEhAyAMIQYDQCAnIAatQv8Bg4VCs4OAgIAgfiEnIANBBEcEQCAEQYABaiADaiADQQFqIQMtAAAhBgwBCwsgJ0IZiKdB/wBxIgVBCHQgBXIiA0EQdCADciEQIAcoAhgiCCAnp3EhAyAHKAIcIQZBACEKAkACQANAIAMgBmooAAAiCyAQcyIJQX9zIAlB//37d2pxQYCBgoR4cSIJBEAgBygCICERA0AgESAJaEEDdiADaiAIcUEDdGoiEigCACAMRg0DIAlBf2ogCXEiCQ0ACwsgCkEEaiIKIANqIAhxIQMgCyALQQF0cUGAgYKEeHFFDQALIAYgFiAnEJoBIgNqLQAAQQFxIQsgByAHKAIkIgkgC0VyBH8gCQUgBEGAAWogFhATIBYgJxCaASEDIAcoAhghCCAHKAIcIQYgBygCJAsgC2s2AiQgBygCICADIAZqIAU6AAAgCCADQXxqcSAGakEEaiAFOgAAIANBA3RqIgMgDzYCBCADIAw2AgAgByAHKAIoQQFqNgIoDAELIBIgDzYCBAsgDyELIA0gDkcNAAsLIAcgBygCFEEBajYCFCAHIAcoAgAiA0EBaiIiNgIAIAcgBygCPEEBajYCPCAHIAcoAixBAWo2AiwgA0ECaiIDQQBKBEAgByADNgIAIAIoAgwiFyEDIAItACkiGwRAIBcgASgCDCIDIBcgA0sbIQMLIAIoAggiFiACKAIEIhprIhwgASgCCCIRIAEoAgQiEmsiGCAcIBhLGyIFQQFqIgsgA0F/aiIVSwRAQQAgA2shI0EAIAVrIRlCgYKEgBAgAi0AKCIkQXtqIgOtQgOGiKchJSACKAIAIR0gA0EFSSEmIAshBQNAIAUiD0F/aiIFQQEgBUEBSxshHiALIQMDQAJAIBUgA08NACAPIANrIQYgA0F/aiEJQQAgBygCBCICQQN0ayEMIAcoAgggDyACIANsaiINQQN0aiEIIAcoAhAhCgNAAkAgCSIDIBxLIAUgGEtyIAUgFUYgAyAjakF/RnEgAyAXSXJyDQAgBSABKAIMSUEAIBsbDQIgBiADIBlqIgkgCUEASBtBAUoNACAKIA1LBEAgCCsDACIqIAMgHiADIB5LG7ijROF6FK5H4co/ZA0BIARBAjoAnAFBACEGA0AgBEEwaiAGaiICKQMAIScgAiAEQYABaiAGaiIIKQMANwMAIAggJzcDACACQQhqIgkpAwAhJyAJIAhBCGoiCSkDADcDACACQRBqIgopAwAhKCAKIAhBEGoiCikDADcDACACQRhqIgIpAwAhKSACIAhBGGoiAikDADcDACACICk3AwAgCiAoNwMAIAkgJzcDACAGQUBrIAZBIGohBkHBAEkNAAsgBCAELwGeATsBfCAELQCdASENIAQoApgBIQggBCgClAEhCSAEKAKQASEKIAQoAowBIQwgBCgCiAEhHyAEKwOAASErIAQrA6ABISwgBCgCqAEhECAEKAKsASETIAQoArABIRQgBCgCtAEhDiAEKAK4ASECIAQtALwBISEgBC0AvQEhICAELQCcASEGIAQgBC8BvgE7AXoCQAJAIAZBAkcEQCAEIAQvAXw7AYABIAQgBC8BejsBfiArICplDQELQQAhCSAbQQBHIAUgGEYgASgCACEfAn9BACABLQAoIgJBCkYNABpBACACQXtqIgJBBEsNABpCgYKEgBAgAq1CA4aIpwshBkEAICVBACAmGyAkQQpGGyEhIAUhCCARIQogEiEMICohKyAqISwgHSEQIBohEyAWIRRBACEOIAMhAnIiDSEgDAELIAQgBC8BgAE7AXggBCAELwF+OwF2CyAEIA06AE0gBCAGOgBMIAQgCDYCSCAEIAk2AkQgBCAKNgJAIAQgDDYCPCAEIB82AjggBCArOQMwIAQgIDoAbSAEICE6AGwgBCACNgJoIAQgDjYCZCAEIBQ2AmAgBCATNgJcIAQgEDYCWCAEICw5A1AgBCAELwF4OwFOIAQgBC8BdjsBbiAqRAAAAAAAALA8ZUEBcw0EDAMLQeirwgAgDSAKEKoBAAsgBkEBaiEGIANBf2ohCSAIIAxqIQggDSACayENIBUgA0kNAAsLCyAZQQFqIRkgFSAFSQ0ACwsgByAiNgIAIAAgBEEwakHAABC+ARoMAwtBjKrCAEEYIARBgAFqQaSqwgAQmQEACwwECyAAQQI6ABwLIARBwAFqJAAPC0HYvMIAQcYAIARBgAFqQdyowgAQmQEAC0GevcIAQRAgBEGAAWpB3KrCABCZAQALQZ69wgBBECAEQYABakHIqsIAEJkBAAuYHAIbfwF+IwBB8ANrIgMkACADIAI2ApADIAMgAjYCjAMgAyABNgKIAyADQdAAaiADQYgDahCOASADKAJUIQ8gAygCUCEOAkACQAJAAkACQAJAAkAQxgEiCgRAIAooAgANBiAKQX82AgAgCkEEaiAAEFUiBEUNARDFASILBEAgCygCAA0HIAtBfzYCACALQQRqIAAQViIIRQ0DIANBoAJqIA4gDiAPahAiIANBiANqIANBoAJqEGEgA0GgAmogA0GIA2ogBEEUaiICEDwgAygCqAIiAEF/aiIBIABPIAEgAEtyRQRAIAMoAqACIAFBFGxqQQA6ABELIANBiANqIANBoAJqQTAQvgEaIANBoAJqIANBiANqIAIQJSADQYgDaiADQaACaiACEB4gA0GgAmogA0GIA2oQOiADKAKoAiIBBEAgAygCoAIhACABQRRsIQEgA0HAAmooAgAhBSADKAK4AiEMA0AgACAMIAUgAhBHIABBFGohACABQWxqIgENAAsLIANBiANqIANBoAJqQTAQvgEaIANBoAJqIANBiANqIAIQUCADKAKgAiEMAkAgAygCqAIiBUUEQCADQcACaigCACEGIAMoArgCIQ0MAQsgBUEUbCEBIANBwAJqKAIAIQYgAygCuAIhDSAMIQADQCAAIA0gBiACEEYgAEEUaiEAIAFBbGoiAQ0ACwsgA0HIAmooAgAhFiADQbwCaigCACEXIANBsAJqKAIAIRggA0G0AmooAgAhACADKAKkAiEZIAMoAqwCIRAgAygCxAIhESADQfQAaiADQcwCaigCADYCACADQewAaiAGNgIAIANB5ABqIAA2AgAgAyARNgJwIAMgDTYCaCADIBA2AmAgAyAFNgJcIAMgDDYCWAJAIAgoAggiAkUEQEEAIQIMAQsgCCgCACACQQR0akF0aiEAA0AgAkF/aiECIABBBGooAgAEQCAAKAIAEB8LIABBcGohACACDQALIAMoAlwhBQsgCCACNgIIIAMgA0HYAGo2AnwgAyAENgJ4IANBjAFqIAQoAqwBNgIAIAMgBCgCpAE2AogBIAMgBCgCoAE2AoQBIAMgBCgCmAE2AoABAkAgBUUEQCAEKALYAQ0KIARB2AFqIQUgBEF/NgLYASAEQdwBaiEHIAQoAtwBDQEgBCgCBCEAIAQoAgwhASADQSBqIAQoAhAiAkEBdBCjASADKQMgIR4gA0GwAmoiBkEANgIAIANBADsBuAIgAyACNgK0AiADIB43A6gCIAMgACABQTxsajYCpAIgAyAANgKgAgJAIANBoAJqEC8iAEUEQCADQQA2AqgBIANCBDcDoAEgAygCrAJFDQEgAygCqAIQHwwBCyAAKAIAIQAgA0EYakEBEKMBIAMoAhwhASADKAIYIgkgADYCACADQQE2AugBIAMgATYC5AEgAyAJNgLgASADQaADaiADQbgCaigCADYCACADQZgDaiAGKQMANwMAIANBkANqIANBqAJqKQMANwMAIAMgAykDoAI3A4gDAkAgA0GIA2oQLyIGRQ0AQQQhAkEBIQADQCAGKAIAIQYgACABRgRAIANB4AFqIAFBARCDASADKALgASEJCyACIAlqIAY2AgAgAyAAQQFqIgA2AugBIANBiANqEC8iBkUNASACQQRqIQIgAygC5AEhAQwACwALIANBlANqKAIABEAgAygCkAMQHwsgA0GoAWogA0HoAWooAgA2AgAgAyADKQPgATcDoAELIANBiANqIANBoAFqEJYBAkAgBygCACIARQ0AIAQoAuABRQ0AIAAQHwsgByADKQOIAzcCACAHQQhqIANBkANqKAIANgIAIANBmAFqIANBqAFqKAIANgIAIAMgAykDoAE3A5ABDAcLIAQoArABDQkgBEF/NgKwASAEQQA2AtQBIARBzAFqIQkgBCgCECESIAQgBCgCtAEiAQR/IAlBACABEIMBIAQoAswBIAQoAtQBIgJBAnRqIQAgAUEBRwRAIAFBf2ohAQNAIABBADYCACACQQFqIQIgAEEEaiEAIAFBf2oiAQ0ACwsgAEEANgIAIAJBAWoFQQALNgLUASADQaABaiADQdgAahAdIAMoAqABIQcgAygCqAEiAEUNBSAHIABBDGxqIRogByEFA0AgA0KlxoihyJyn+Us3A4gDIAUiASADQYgDahB4IAMoAogDIgBBGXYiAkEIdCACciICQRB0IAJyIRsgAUEMaiEFIAQoArgBIhMgAHEhACAEKAK8ASEcQQAhFAJAA0ACQCAAIBxqKAAAIhUgG3MiAkF/cyACQf/9+3dqcUGAgYKEeHEiAgRAIAQoAsABIR0DQCABIB0gAmhBA3YgAGogE3FBGGxqIgZGDQIgASAGQQwQuAFFDQIgAkF/aiACcSICDQALCyAUQQRqIhQgAGogE3EhACAVIBVBAXRxQYCBgoR4cUUNAQwCCwsgBigCFCIBRQ0AIAYoAgwhACABQQJ0IQIDQCAJKAIAIAAoAgBBAnRqIgEgASgCAEEBajYCACAAQQRqIQAgAkF8aiICDQALCyAFIBpHDQALDAULIANBkAFqIAcQlgEMBQsMBQsMBAtBjNrCAEErQbjawgAQvQEAC0GM2sIAQStBuNrCABC9AQALIAQoAswBIQAgBCgC1AEhASADQcgAaiASQRRsEKQBIAMpA0ghHiADQbQCakEANgIAIANBADsBvAIgAyASQQpsNgK4AiADIB43AqwCIANBADYCqAIgAyAAIAFBAnRqNgKkAiADIAA2AqACIANBQGsgA0GgAmoQLgJAIAMoAkRFBEAgA0EANgKYASADQgQ3A5ABIAMoArACRQ0BIAMoAqwCEB8MAQsgAygCQCEAIANBOGpBARCjASADKAI8IQEgAygCOCIFIAA2AgAgA0EBNgLoASADIAE2AuQBIAMgBTYC4AEgA0GgA2ogA0G4AmopAwA3AwAgA0GYA2oiCSADQbACaikDADcDACADQZADaiADQagCaikDADcDACADIAMpA6ACNwOIAyADQTBqIANBiANqEC4CQCADKAI0RQ0AIAMoAjAhBkEEIQJBASEAA0AgACABRgRAIANB4AFqIAFBARCDASADKALgASEFCyACIAVqIAY2AgAgAyAAQQFqIgA2AugBIANBKGogA0GIA2oQLiADKAIsRQ0BIAMoAighBiACQQRqIQIgAygC5AEhAQwACwALIAkoAgAEQCADKAKUAxAfCyADQZgBaiADQegBaigCADYCACADIAMpA+ABNwOQAQsgAygCpAEEQCAHEB8LIARBsAFqIQULIAUgBSgCAEEBajYCACADKAKQASEHIAMoApgBIQAgA0EQaiADKAJ4KAIQIgFBAXQQoAEgAykDECEeIANBvAFqQQA2AgAgA0EAOwHEASADIAE2AsABIAMgHjcCtAEgAyAHIABBAnRqNgKkASADIAc2AqABIAMgA0GAAWo2AsgBIAMgA0H8AGo2ArABIAMgA0H8AGo2AqwBIAMgA0H4AGo2AqgBIANBoAJqIANBoAFqEBwCQAJAAkACQCADKAKkAkUEQCADQQA2AuQBDAELIANBiANqIANBoAJqQeQAEL4BGiADQeABaiADQcgBaiADQYgDahAUIAMoAuQBDQELIAMoArwBIgEEQCADKAK0ASEAIAFB5ABsIQIDQCAAEMEBIABB5ABqIQAgAkGcf2oiAg0ACwtBACEEQQQhBSADKAK4AUUNASADKAK0ARAfDAELQQEhAiADQQhqQQEQogEgA0HoAWopAwAhHiADKAIMIQAgAygCCCIFIAMpA+ABNwIAIAVBCGogHjcCACADQQE2AtgBIAMgADYC1AEgAyAFNgLQASADQeABaiADQaABakEsEL4BGiADQaACaiADQeABahAcAkAgAygCpAIEQCADQYgCaiEBQRAhAANAIANBiANqIANBoAJqQeQAEL4BGiADQZACaiABIANBiANqEBQgAygClAJFDQIgA0GQA2oiBCADQZgCaikDADcDACADIAMpA5ACNwOIAyADKALUASACRgRAIANB0AFqIAIQtwEgAygC0AEhBQsgACAFaiIGIAMpA4gDNwIAIAZBCGogBCkDADcCACADIAJBAWoiAjYC2AEgAEEQaiEAIANBoAJqIANB4AFqEBwgAygCpAINAAsLIANBADYClAILIANB/AFqKAIAIgEEQCADKAL0ASEAIAFB5ABsIQEDQCAAEMEBIABB5ABqIQAgAUGcf2oiAQ0ACwsgA0H4AWooAgAEQCADKAL0ARAfCyADKALUASEEDAELQQAhAgsgAygClAEEQCAHEB8LIAUgAkEEdGohAQJAIAEgAgR/IAUhAANAIABBBGooAgAiBwRAIABBCGopAgAhHiAAKAIAIQYgCCgCCCICIAgoAgRGBEAgCCACELcBIAgoAgghAgsgCCgCACACQQR0aiICIAc2AgQgAiAGNgIAIAJBCGogHjcCACAIIAgoAghBAWo2AgggAEEQaiIAIAFHDQEMAwsLIABBEGoFIAULIgBGDQADQCAAQQRqKAIAIgJFDQEgAEEIaigCAARAIAIQHwsgAEEQaiICIQAgASACRw0ACwsgBARAIAUQHwsgGQRAIAwQHwsgGARAIBAQHwsgFwRAIA0QHwsgFgRAIBEQHwsgCyALKAIAQQFqNgIAIAogCigCAEEBajYCACAPBEAgDhAfCyADQfADaiQADwtB2LzCAEHGACADQYgDakHcqMIAEJkBAAtBnr3CAEEQIANBiANqQcyowgAQmQEAC0GevcIAQRAgA0GIA2pBuKrCABCZAQALwxgCCH8BfgJAAkACQCAAQfUBTwRAIABBzf97Tw0CIABBC2oiAEF4cSEEQeTdwgAoAgAiCEUNAUEAIARrIQICQAJAAn9BACAAQQh2IgBFDQAaQR8gBEH///8HSw0AGiAEQQYgAGciAGtBH3F2QQFxIABBAXRrQT5qCyIHQQJ0QfDfwgBqKAIAIgAEQCAEQQBBGSAHQQF2a0EfcSAHQR9GG3QhBQNAAkAgACgCBEF4cSIGIARJDQAgBiAEayIGIAJPDQAgACEDIAYiAg0AQQAhAgwDCyAAQRRqKAIAIgYgASAGIAAgBUEddkEEcWpBEGooAgAiAEcbIAEgBhshASAFQQF0IQUgAA0ACyABBEAgASEADAILIAMNAgtBACEDQQIgB0EfcXQiAEEAIABrciAIcSIARQ0DIABBACAAa3FoQQJ0QfDfwgBqKAIAIgBFDQMLA0AgACADIAAoAgRBeHEiASAETyABIARrIgYgAklxIgUbIQMgBiACIAUbIQIgACgCECIBBH8gAQUgAEEUaigCAAsiAA0ACyADRQ0CC0Hw4MIAKAIAIgAgBE9BACACIAAgBGtPGw0BIAMQWQJAIAJBEE8EQCADIARBA3I2AgQgAyAEaiIBIAJBAXI2AgQgASACaiACNgIAIAJBgAJPBEAgASACEFEMAgsgAkEDdiICQQN0QejdwgBqIQACf0Hg3cIAKAIAIgVBASACQR9xdCICcQRAIAAoAggMAQtB4N3CACACIAVyNgIAIAALIQIgACABNgIIIAIgATYCDCABIAA2AgwgASACNgIIDAELIAMgAiAEaiIAQQNyNgIEIAAgA2oiACAAKAIEQQFyNgIECyADQQhqDwsCQAJAQeDdwgAoAgAiA0EQIABBC2pBeHEgAEELSRsiBEEDdiICQR9xIgF2IgBBA3FFBEAgBEHw4MIAKAIATQ0DIAANAUHk3cIAKAIAIgBFDQMgAEEAIABrcWhBAnRB8N/CAGooAgAiASgCBEF4cSAEayECIAEhBQNAIAEoAhAiAEUEQCABQRRqKAIAIgBFDQQLIAAoAgRBeHEgBGsiASACIAEgAkkiARshAiAAIAUgARshBSAAIQEMAAsACyAAQX9zQQFxIAJqIgFBA3QiBkHw3cIAaigCACIAQQhqIQICQCAAKAIIIgUgBkHo3cIAaiIGRwRAIAUgBjYCDCAGIAU2AggMAQtB4N3CACADQX4gAXdxNgIACyAAIAFBA3QiAUEDcjYCBCAAIAFqIgAgACgCBEEBcjYCBAwDCwJAQQIgAXQiAkEAIAJrciAAIAF0cSIAQQAgAGtxaCIAQQN0IgVB8N3CAGooAgAiASgCCCICIAVB6N3CAGoiBUcEQCACIAU2AgwgBSACNgIIDAELQeDdwgAgA0F+IAB3cTYCAAsgASAEQQNyNgIEIAEgBGoiBiAAQQN0IgAgBGsiBUEBcjYCBCAAIAFqIAU2AgBB8ODCACgCACIABEAgAEEDdiIDQQN0QejdwgBqIQBB+ODCACgCACECAn9B4N3CACgCACIEQQEgA0EfcXQiA3EEQCAAKAIIDAELQeDdwgAgAyAEcjYCACAACyEDIAAgAjYCCCADIAI2AgwgAiAANgIMIAIgAzYCCAtB+ODCACAGNgIAQfDgwgAgBTYCACABQQhqDwsgBRBZAkAgAkEQTwRAIAUgBEEDcjYCBCAEIAVqIgYgAkEBcjYCBCACIAZqIAI2AgBB8ODCACgCACIABEAgAEEDdiIDQQN0QejdwgBqIQBB+ODCACgCACEBAn9B4N3CACgCACIEQQEgA0EfcXQiA3EEQCAAKAIIDAELQeDdwgAgAyAEcjYCACAACyEDIAAgATYCCCADIAE2AgwgASAANgIMIAEgAzYCCAtB+ODCACAGNgIAQfDgwgAgAjYCAAwBCyAFIAIgBGoiAEEDcjYCBCAAIAVqIgAgACgCBEEBcjYCBAsgBUEIag8LAkACQAJAAkBB8ODCACgCACIBIARJBEBB9ODCACgCACIAIARLDQZBACECIARBr4AEaiIAQRB2QAAiAUF/Rg0FIAFBEHQiA0UNBUGA4cIAIABBgIB8cSIFQYDhwgAoAgBqIgA2AgBBhOHCAEGE4cIAKAIAIgEgACABIABLGzYCAEH84MIAKAIAIgJFDQFBiOHCACEAA0AgACgCACIBIAAoAgQiBmogA0YNAyAAKAIIIgANAAsMAwtB+ODCACgCACEAAkAgASAEayIDQQ9NBEBB+ODCAEEANgIAQfDgwgBBADYCACAAIAFBA3I2AgQgACABaiIBQQRqIQQgASgCBEEBciECDAELQfDgwgAgAzYCAEH44MIAIAAgBGoiAjYCACACIANBAXI2AgQgACABaiADNgIAIARBA3IhAiAAQQRqIQQLIAQgAjYCACAAQQhqDwtBnOHCACgCACIAQQAgACADTRtFBEBBnOHCACADNgIAC0EAIQBBoOHCAEH/HzYCAEGM4cIAIAU2AgBBiOHCACADNgIAQZThwgBBADYCAANAIABB8N3CAGogAEHo3cIAaiIBNgIAIABB9N3CAGogATYCACAAQQhqIgBBgAJHDQALQfzgwgAgAzYCAEH04MIAIAVBWGoiADYCACADIABBAXI2AgQgACADakEoNgIEQZjhwgBBgICAATYCAAwCCyAAKAIMIAMgAk1yIAEgAktyDQAgACAFIAZqNgIEQfzgwgBB/ODCACgCACIAQQ9qQXhxIgFBeGo2AgBB9ODCAEH04MIAKAIAIAVqIgMgACABa2pBCGoiAjYCACABQXxqIAJBAXI2AgAgACADakEoNgIEQZjhwgBBgICAATYCAAwBC0Gc4cIAQZzhwgAoAgAiACADIAAgA0kbNgIAIAMgBWohAUGI4cIAIQACQANAIAEgACgCAEcEQCAAKAIIIgANAQwCCwsgACgCDA0AIAAgAzYCACAAIAAoAgQgBWo2AgQgAyAEQQNyNgIEIAMgBGohBSABIANrIARrIQQCQAJAIAFB/ODCACgCAEcEQEH44MIAKAIAIAFGDQEgASgCBCIAQQNxQQFGBEACQCAAQXhxIgJBgAJPBEAgARBZDAELIAEoAgwiBiABKAIIIgdHBEAgByAGNgIMIAYgBzYCCAwBC0Hg3cIAQeDdwgAoAgBBfiAAQQN2d3E2AgALIAIgBGohBCABIAJqIQELIAEgASgCBEF+cTYCBCAFIARBAXI2AgQgBCAFaiAENgIAIARBgAJPBEAgBSAEEFEMAwsgBEEDdiIBQQN0QejdwgBqIQACf0Hg3cIAKAIAIgJBASABQR9xdCIBcQRAIAAoAggMAQtB4N3CACABIAJyNgIAIAALIQIgACAFNgIIIAIgBTYCDCAFIAA2AgwgBSACNgIIDAILQfzgwgAgBTYCAEH04MIAQfTgwgAoAgAgBGoiADYCACAFIABBAXI2AgQMAQtB+ODCACAFNgIAQfDgwgBB8ODCACgCACAEaiIANgIAIAUgAEEBcjYCBCAAIAVqIAA2AgALIANBCGoPC0GI4cIAIQADQAJAIAAoAgAiASACTQRAIAEgACgCBGoiBiACSw0BCyAAKAIIIQAMAQsLQfzgwgAgAzYCAEH04MIAIAVBWGoiADYCACADIABBAXI2AgQgACADakEoNgIEQZjhwgBBgICAATYCACACIAZBYGpBeHFBeGoiACAAIAJBEGpJGyIBQRs2AgRBiOHCACkCACEJIAFBEGpBkOHCACkCADcCACABIAk3AghBjOHCACAFNgIAQYjhwgAgAzYCAEGQ4cIAIAFBCGo2AgBBlOHCAEEANgIAIAFBHGohAANAIABBBzYCACAGIABBBGoiAEsNAAsgASACRg0AIAEgASgCBEF+cTYCBCACIAEgAmsiAEEBcjYCBCABIAA2AgAgAEGAAk8EQCACIAAQUQwBCyAAQQN2IgFBA3RB6N3CAGohAAJ/QeDdwgAoAgAiA0EBIAFBH3F0IgFxBEAgACgCCAwBC0Hg3cIAIAEgA3I2AgAgAAshASAAIAI2AgggASACNgIMIAIgADYCDCACIAE2AggLQQAhAkH04MIAKAIAIgAgBE0NAAwBCyACDwtB9ODCACAAIARrIgE2AgBB/ODCAEH84MIAKAIAIgAgBGoiAzYCACADIAFBAXI2AgQgACAEQQNyNgIEIABBCGoLpBYBFn8jAEHwA2siBiQAAkACQCABQRVJDQAgBkHgAGohFyAGQcgCaiEYQQEhEUEBIRICQAJAAkADQCABIQogACEOIANBQGshECARIBJxQQFzIQ8CQANAAn8CQCAEBEAgEUEBcUUEQCAAIAEQZSAEQX9qIQQLIAYgAjYCCCAGIAA2AogBIAYgATYCjAEgBiABQQJ2IgU2AgwgBiAFQQF0Igc2AhAgBiAFQQNsIgk2AhQgBkEANgIYIAYgBkEYajYCkAIgBiAGQYgBajYCjAIgBiAGQQhqNgKIAiAGIAZBiAJqNgIcIAFBMk8EQCAGIAVBf2o2AiAgBiAFQQFqNgKIAyAGQRxqIAZBIGogBkEMaiAGQYgDahDJASAGIAdBf2o2AiAgBiAHQQFyNgKIAyAGQRxqIAZBIGogBkEQaiAGQYgDahDJASAGIAlBf2o2AiAgBiAJQQFqNgKIAyAGQRxqIAZBIGogBkEUaiAGQYgDahDJAQsgBkEcaiAGQQxqIAZBEGogBkEUahDJASAGKAIYIgVBC0sNASAGKAIQIQggBUUMAgsgACABEHcMCQsgBigCjAEiCUEBdiIHBEAgBigCiAEiBSAJQeQAbGpBnH9qIQwDQCAGQYgDaiAFQeQAEL4BGiAFIAxB5AAQnAFB5ABqIQUgDCAGQYgDakHkABC+AUGcf2ohDCAHQX9qIgcNAAsLIAEgBigCEEF/c2ohCEEBC0UgD3JBAXFFBEAgACABECMNCAsgA0UEQCAOIQAgCiEBDAILAkAgCCABSQRAIAAgCEHkAGxqIglBQGshDUEAIQUCQANAIAVBBGoiB0EoRg0BIAUgEGohCyAFIA1qIQwgByEFQX8gDCgCACIHIAsoAgAiC0cgByALSBsiB0UNAAsgB0H/AXFB/wFGDQQLIAEEQCAGQYgDaiAAQeQAEL4BGiAAIAlB5AAQnAEhDSAJIAZBiANqQeQAEL4BGiAGQYgCaiANQeQAEL4BGiANQVxqIQwgDUGkAWohEyANQeQAaiEUQQAhCCABIQkDQAJAIAggCUF/aiIHTw0AIBMgCEHkAGxqIQsDQEEAIQUCQANAIAVBBGoiAEEoRg0BIAUgGGohEiAFIAtqIRUgACEFQX8gFSgCACIAIBIoAgAiEkcgACASSBsiAEUNAAsgAEH/AXFB/wFGDQILIAtB5ABqIQsgCEEBaiIIIAdHDQALIAchCAwECyAIIAdPDQMgDCAJQeQAbGohCwNAIAciCUF/aiEHQQAhBQJAA0AgBUEEaiIAQShGDQEgBSAYaiESIAUgC2ohFSAAIQVBfyAVKAIAIgAgEigCACISRyAAIBJIGyIARQ0ACyAAQf8BcUH/AUcNACALQZx/aiELIAggB08NBQwBCwsgBkGIA2ogFCAIQeQAbGoiAEHkABC+ARogACANIAlB5ABsaiIAQeQAEJwBGiAAIAZBiANqQeQAEL4BGiAIQQFqIQgMAAsAC0Gwp8IAQQBBABCqAQALQdCnwgAgCCABEKoBAAsgDSAGQYgCakHkABC+ASEAIAEgCEEBaiIFTwRAIAAgBUHkAGxqIQAgASAFayIBQRVPDQEMBwsLIAUgARCrAQALAkACQCABBEAgCCABTw0BIAZBiAJqIABB5AAQvgEaIAAgACAIQeQAbGoiBUHkABCcASEUIAUgBkGIAmpB5AAQvgEaIAZBIGogFEHkABC+ARogAUF/aiIJRQRAQQAhEAwDCyAUQaQBaiELQQAhEANAQQAhBQNAIAVBBGoiB0EoRg0EIAUgC2ohCiAFIBdqIQ4gByEFQX8gDigCACIHIAooAgAiCkcgByAKSBsiB0UNAAsgB0H/AXFB/wFHDQMgC0HkAGohCyAQQQFqIhAgCUcNAAsgCSEQDAILQbCnwgBBAEEAEKoBAAtBwKfCACAIIAEQqgEACyAUQeQAaiENIBQgAUHkAGxqQVxqIQsgCSEHA0AgByIKIBBNIhJFBEAgCkF/aiEHQQAhBQNAAkAgBUEEaiIOQShGBEBBACEMDAELIAUgC2ohCCAFIBdqIQwgDiEFQX8gDCgCACIOIAgoAgAiCEcgDiAISBsiDEUNAQsLIAtBnH9qIQsgDEF/Rw0BCwsgCiAQTwRAIAkgCkkNAiANIApB5ABsaiEOQYABIRNBACEJQQAhCkEAIQdBACEPQYABIREgDSAQQeQAbGoiFSENA0AgDiANayIIQeQAbiEFAkAgCEHjyAFLIhkNACAFQYB/aiAFIA8gB0kiCCAKIAlJciILGyEFIAsEQCARIAUgCBshESAFIBMgCBshEwwBCyAFIAVBAXYiEWshEwsCQCAHIA9HDQAgEUUEQCAGQYgBaiIHIQ8MAQtBACEIIAZBiAFqIQcgDSELA0AgByAIOgAAIAhBAWohCEHAACEFA0ACQCAFQQRqIgxB6ABGBEBBACEMDAELIAUgC2ohDyAGQSBqIAVqIRYgDCEFQX8gFigCACIMIA8oAgAiD0cgDCAPSBsiDEUNAQsLIAtB5ABqIQsgByAMQX9HaiEHIAggEUcNAAsgBkGIAWohDwsCQCAJIApHDQAgE0UEQCAGQYgCaiIJIQoMAQtBACEIIAZBiAJqIQkgDiEKA0AgCSAIOgAAIAoiC0Gcf2ohCiAIQQFqIQhBACEFA0ACQCAFQQRqIgxBKEYEQEEAIQwMAQsgBSAXaiEWIAUgC2ohGiAMIQVBfyAWKAIAIgwgGkFcaigCACIWRyAMIBZIGyIMRQ0BCwsgCSAMQX9GaiEJIAggE0cNAAsgBkGIAmohCgsgCSAKayIFIAcgD2siCCAIIAVLGyIIBEAgBkGIA2ogDSAPLQAAQeQAbGpB5AAQvgEaIA0gDy0AAEHkAGxqIA4gCi0AAEF/c0HkAGxqQeQAEL4BGiAIQQFHBEBBACEFA0AgDiAFIApqIgstAABBf3NB5ABsaiANIAUgD2pBAWoiDC0AAEHkAGxqQeQAEL4BGiANIAwtAABB5ABsaiAOIAtBAWotAABBf3NB5ABsakHkABC+ARogBUECaiAFQQFqIgshBSAISQ0ACyALIA9qIQ8gCiALaiEKCyAOIAotAABBf3NB5ABsaiAGQYgDakHkABC+ARogD0EBaiEPIApBAWohCgsgDSARQeQAbGogDSAHIA9GGyENIA5BACATa0HkAGxqIA4gCSAKRhshDiAZDQALAkAgDyAHSQRAIA4hBQNAIAZBiANqIA0gB0F/aiIHLQAAQeQAbGoiCUHkABC+ARogCSAFQZx/aiIFQeQAEJwBGiAFIAZBiANqQeQAEL4BGiAPIAdJDQALDAELIA0hBSAKIAlPDQADQCAJQX9qIgktAAAhByAGQYgDaiAFQeQAEL4BGiAFIA4gB0F/c0HkAGxqIgVB5AAQnAEgBSAGQYgDakHkABC+ARpB5ABqIQUgCiAJSQ0ACwsgFCAGQSBqQeQAEL4BIQcgASAFIBVrQeQAbiAQaiIFTQ0DIAZBiAJqIAdB5AAQvgEaIAcgByAFQeQAbGoiB0HkABCcASEOIAcgBkGIAmpB5AAQvgEhByABIAVrIgpFDQQgCiAFIAUgCksbIAFBA3YgB0HkAGohCQJAIAUgCkF/aiIBTwRAIAkgASACIAcgBBAEIAUhAQwBCyAOIAUgAiADIAQQBCAHIQMgCSEAC08hESABQRVPDQEMBQsLIBAgChCrAQALIAogCRCpAQALQcCnwgAgBSABEKoBAAtBta3CAEEcQdStwgAQvQEACyABQQJJDQBBASEFA0AgACAFQQFqIgUQSCABIAVHDQALCyAGQfADaiQAC4cZAQR/IwBBEGsiAyQAIANBCGpBADoAACAAIAAoAhAiBDYCHCADQgA3AwACQAJAIABBrMHCAEESIAMQOSICRQ0AIAAgACgCECIBNgIgIAEgACgCFEkNACACQX9qIgJBCksNAQJAAkACQAJAAkACQAJAAkACQAJAAkAgAkEBaw4KAAECAwQFBgcICQoLIAAgACgCHCABQd/EwgBBAxA0GgwLCyAAIAAoAhwgAUHixMIAQQMQNBoMCgsgACAAKAIcIAFB5cTCAEEDEDQaDAkLIAAgACgCHCABQejEwgBBAxA0GgwICyAAIAAoAhwgAUHrxMIAQQMQNBoMBwsgACAAKAIcIAFB7sTCAEEFEDQaDAYLIAAgACgCHCABQfPEwgBBBBA0GgwFCyAAIAAoAhwgAUH3xMIAQQUQNBoMBAsgACAAKAIcIAFB/MTCAEEEEDQaDAMLIAAgACgCHCABQYDFwgBBBRA0GgwCCyAAIAAoAhwgAUHcxMIAQQMQNBoMAQsgACAENgIQIABBAxBiIgFBAEgEQCAAIAQ2AhAMAQsgACAENgIQIAEgACgCFEoNACAAIAQ2AhwgA0EAOgAIIABBhcXCABBLBEAgACAAKAIQIgE2AiAgACAAKAIcIAFBrNjCAEEAEDQaCyAAIAQ2AhwgACAENgIQIABBhsXCABBLBEAgACAAKAIQIgE2AiAgACAAKAIcIAFBh8XCAEEBEDQaIANBAToACAsgACAENgIQIAQhAQNAAkACQAJAIAAQM0UNACAAIAAoAhA2AhwgAEGGxcIAEEtFDQAgACAAKAIQIgI2AiAgACABNgIQIAAgACgCHCACQYfFwgBBARA0GiADQQE6AAgMAQsgACABNgIQIAEgACgCFCIBTw0BIAAQkQELIAAoAhAhAQwBCwsgACAENgIQIAMgATYCBCADIAE2AgACQAJAIABBjMXCAEEDIAMQOQ0AIAAgBDYCECAAEDNFBEADQCAAKAIQIAAoAhQiAU8NAyAAEJEBIAAQM0UNAAsLIAAQNQ0AA0AgACgCECAAKAIUIgFPDQIgABCRASAAEDVFDQALCyADIAAoAhA2AgQgABAzRQRAA0AgACgCECAAKAIUIgFPDQIgABCRASAAEDNFDQALCyAAEDUgACgCECECRQRAA0AgAiAAKAIUIgFPDQIgABCRASAAEDUgACgCECECRQ0ACwsgAyACNgIAIAAoAhQhAQsgACABNgIgIAAgATYCECAAIAQ2AhgCQCAAQdTFwgBBAyADECsiAgRAIAAgACgCECIBNgIcIAJBAUcNASAAIAEgACgCIEGs2MIAQQAQNBogACgCECEBDAELIAAgACgCFCIBNgIQCyAAIAE2AiACQCAAQZzGwgBBBiADECsiAkUNACAAIAAoAhAiATYCHCACQX9qIgJBAksNAAJAAkACQAJAIAJBAWsOAgABAgsgACgCFCEEIAAoAhggAEF+EGIiAkogAiAESnJFBEAgACACNgIQIAAgASAAKAIgQa7HwgBBARA0GgwECyAAIAEgACgCIEGvx8IAQQIQNBoMAwsgASAAKAIYTQ0CIAAQkwEgAEGIxcIAQQRB4QBB+QAQMQ0BA0AgACgCECAAKAIYTQ0DIAAQkwEgAEGIxcIAQQRB4QBB+QAQMUUNAAsMAQsgACABIAAoAiBBrMfCAEECEDQaDAELIAAgACgCHCAAKAIgQazYwgBBABA0GgsgACAAKAIUIgE2AiAgACABNgIQAkAgAEG0x8IAQQggAxArBEAgACAAKAIQIgE2AhwgASAAKAIYIgRNDQELIAAgACgCFCIBNgIgIAAgATYCEAJAIABB9MjCAEEGIAMQKyICRQ0AIAAgACgCECIBNgIcIAJBf2oiAkEBSw0AIAJBAWsEQCADKAIEIAFLDQEgACABIAAoAiBBhMrCAEECEDQaDAELIAAoAhQhAiAAQYjFwgBBBEHhAEH5ABAxRQRAA0AgACgCECAAKAIYTQ0CIAAQkwEgAEGIxcIAQQRB4QBB+QAQMUUNAAsLIAAgACgCFCABIAJrajYCECAAIAAoAhwgACgCIEGs2MIAQQAQNBogACgCECEBIAAoAhQhAiAAQYjKwgBBDSADECsiBEUNACAAIAEgAmsiAiAAKAIUaiIBNgIQIARBf2oiBEECSw0AAkACQAJAIARBAWsOAgECAAsgACABIAEQuwEgACABNgIQDAILIAAgATYCICABIAAoAhhNDQEgABCTASAAIAAoAhAiATYCHCAAIAEgACgCIEGs2MIAQQAQNBoMAQsgASADKAIERw0AIAAQlQFFDQAgACAAKAIUIAJqIgE2AhAgACABIAEQuwEgACABNgIQCyAAIAAoAhQiATYCICAAIAE2AhACQCAAQYbFwgAQYEUEQCAAIAAoAhQ2AhAgAEGHxcIAEGBFDQELIAAgACgCEDYCHCAAQYjFwgBBBEHhABAyRQ0AIAAoAhAgACgCGE0NACAAIAAoAhwgACgCIEGux8IAQQEQNBoLIAAgACgCFCIBNgIgIAAgATYCEAJAIABBxMzCAEEYIAMQKyICRQ0AIAAgACgCECIBNgIcIAMoAgQgAUsNACACQX9qIgJBD0sNAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAJBAWsODwECAwQFBgcICQoLDA0ODwALIAAgASAAKAIgQYTRwgBBBBA0GgwPCyAAIAEgACgCIEGI0cIAQQQQNBoMDgsgACABIAAoAiBBjNHCAEEEEDQaDA0LIAAgASAAKAIgQZDRwgBBBBA0GgwMCyAAIAEgACgCIEGU0cIAQQMQNBoMCwsgACABIAAoAiBBl9HCAEEDEDQaDAoLIAAgASAAKAIgQZrRwgBBAxA0GgwJCyAAIAEgACgCIEGd0cIAQQIQNBoMCAsgACABIAAoAiBBn9HCAEEDEDQaDAcLIAAgASAAKAIgQaLRwgBBAxA0GgwGCyAAIAEgACgCIEGl0cIAQQMQNBoMBQsgACABIAAoAiBBqNHCAEEDEDQaDAQLIABBq9HCABBgRQ0DIAAgACgCHCAAKAIgQazRwgBBAhA0GgwDCyAAIAEgACgCIEGf0cIAQQMQNBoMAgsgACABIAAoAiBBrtHCAEEEEDQaDAELIABBstHCAEEDQeMAQfQAEDFFDQAgACAAKAIcIAAoAiBBrNjCAEEAEDQaCyAAIAAoAhQiATYCICAAIAE2AhACQCAAQbjRwgBBCSADECsiAkUNACAAIAAoAhAiATYCHCADKAIEIAFLDQAgAkF/aiICQQVLDQACQAJAAkACQAJAAkAgAkEBaw4FAQIDBAUACyAAIAEgACgCIEGE0cIAQQQQNBoMBQsgACABIAAoAiBBmtHCAEEDEDQaDAQLIAAgASAAKAIgQZ3RwgBBAhA0GgwDCyAAIAEgACgCIEGQ08IAQQIQNBoMAgsgACABIAAoAiBBrNjCAEEAEDQaDAELIAMoAgAgAUsNACAAIAEgACgCIEGs2MIAQQAQNBoLIAAgACgCFCIBNgIgIAAgATYCEAJAIABBlNPCAEESIAMQKyICRQ0AIAAgACgCECIBNgIcIAMoAgAgAUsNACACQX9qIgJBAUsNACACQQFrBEAgACABIAAoAiBBrNjCAEEAEDQaDAELIAAoAhQhAiAAQcTWwgAQYEUEQCAAIAAoAhQgASACa2o2AhAgAEHF1sIAEGBFDQELIAAgACgCHCAAKAIgQazYwgBBABA0GgsgACAAKAIUIgE2AiAgACABNgIQAkAgAEHI1sIAQQIgAxArIgFFDQAgACAAKAIQIgI2AhwgAUF/aiIBQQFLDQAgAUEBawRAIAAgAygCACACSwR/IAMoAgQgAksNAiAAKAIUIQEgABCVAQ0CIAAgACgCFCACIAFrajYCECAAKAIcBSACCyAAKAIgQazYwgBBABA0GgwBCyADKAIAIAJLDQAgAEGr0cIAEGBFDQAgACAAKAIcIAAoAiBBrNjCAEEAEDQaCyAAIAAoAhQ2AhAgACgCGCEECyAAIAQ2AhACQCADLQAIRQ0AIAQhAgNAIAAgAjYCHCACIQECQCAAQYfFwgAQSwRADAELA0ACQCAAIAE2AhAgASAAKAIUTw0AIAAQkQEgACAAKAIQIgE2AhwgAEGHxcIAEEtFDQEMAgsLIAAgAjYCEAwCCyAAIAAoAhAiAjYCICAAIAE2AhAgACAAKAIcIAJBhsXCAEEBEDQaIAAoAhAhAgwACwALIAAgBDYCEAsgA0EQaiQAQQELgRUBF38jAEHAAmsiBiQAAkACQCABQRVJDQBBASEJQQEhEwJAAkACQANAIAEhDyAAIQ4gCSATcUEBcyERAkACQAJAAkACQAJAA0ACfwJAIAQEQCAJRQRAIAAgARBcIARBf2ohBAsgBiACNgIIIAYgADYCMCAGIAE2AjQgBiABQQJ2IgU2AgwgBiAFQQF0Igg2AhAgBiAFQQNsIgc2AhQgBkEANgIYIAYgBkEYajYCuAEgBiAGQTBqNgK0ASAGIAZBCGo2ArABIAYgBkGwAWo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2ArACIAZBHGogBkEgaiAGQQxqIAZBsAJqEMoBIAYgCEF/ajYCICAGIAhBAXI2ArACIAZBHGogBkEgaiAGQRBqIAZBsAJqEMoBIAYgB0F/ajYCICAGIAdBAWo2ArACIAZBHGogBkEgaiAGQRRqIAZBsAJqEMoBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMoBIAYoAhgiBUELSw0BIAYoAhAhByAFRQwCCyAAIAEQbQwOCyAGKAI0IgdBAXYiCARAIAYoAjAiBSAHQQxsakF0aiEHA0AgBkG4AmoiDCAFQQhqIgooAgA2AgAgBiAFKQIANwOwAiAHQQhqIgsoAgAhDSAFIAcpAgA3AgAgCiANNgIAIAsgDCgCADYCACAHIAYpA7ACNwIAIAVBDGohBSAHQXRqIQcgCEF/aiIIDQALCyABIAYoAhBBf3NqIQdBAQtFIBFyQQFxRQRAIAAgARAqDQ0LIANFDQIgByABTw0BIANBAyAAIAdBDGxqIgVBAxCXAUH/AXFB/wFGBEAgASEPIAAhDgwECyAGQbgCaiIKIABBCGoiCygCADYCACAGIAApAgA3A7ACIAVBCGoiCCgCACEHIAAgBSkCADcCACALIAc2AgAgCCAKKAIANgIAIAUgBikDsAI3AgAgBkG4AWoiEiALKAIANgIAIAYgACkCADcDsAEgAEF0aiEUIABBDGohDUEAIQcgASEIA0ACQCAHIAhBf2oiDE8NACANIAdBDGxqIQUDQCAGQbABakEDIAVBAxCXAUH/AXFB/wFGDQEgBUEMaiEFIAwgB0EBaiIHRw0ACyAMIQcLIBQgCEEMbGohBQJAA0AgByAIQX9qIghPDQEgBkGwAWpBAyAFQQMQlwEgBUF0aiIMIQVB/wFxQf8BRg0ACyAKIA0gB0EMbGoiBUEIaiITKAIANgIAIAYgBSkCADcDsAIgDEEUaiIQKAIAIRYgBSAMQQxqIgUpAgA3AgAgEyAWNgIAIBAgCigCADYCACAFIAYpA7ACNwIAIAdBAWohBwwBCwsgACAGKQOwATcCACALIBIoAgA2AgAgASAHQQFqIgVPBEAgACAFQQxsaiEAIAEgBWsiAUEVTw0BDAwLCyAFIAEQqwEAC0HQp8IAIAcgARCqAQALIA9FDQELIAcgD08NASAGQbgBaiIUIA5BCGoiEigCADYCACAGIA4pAgA3A7ABIA4gB0EMbGoiAEEIaiIBKAIAIQUgDiAAKQIANwIAIBIgBTYCACABIBQoAgA2AgAgACAGKQOwATcCACAGQShqIhYgEigCADYCACAGIA4pAgA3AyAgDkEMaiEJQQAhDCAPQX9qIgFFDQIgCSEFA0AgBUEDIAZBIGpBAxCXAUH/AXFB/wFHDQMgBUEMaiEFIAEgDEEBaiIMRw0ACyABIQwMAgtBsKfCAEEAQQAQqgEAC0HAp8IAIAcgDxCqAQALIA4gD0EMbGohByABIQgDQAJAIAchACAIIgUgDE0iEw0AIAVBf2ohCCAAQXRqIgdBAyAGQSBqQQMQlwFB/wFxQf8BRw0BCwsgBSAMTwRAIAEgBUkNAkGAASENQQAhCEEAIQpBACEHQQAhC0GAASERIAkgDEEMbGoiFyEBA0AgACABayIJQQxuIQUCQCAJQYsYSyIYDQAgBUGAf2ogBSALIAdJIgkgCiAISXIiEBshBSAQBEAgESAFIAkbIREgBSANIAkbIQ0MAQsgBSAFQQF2IhFrIQ0LAkAgByALRw0AIBFFBEAgBkEwaiIHIQsMAQtBACEFIAEhCSAGQTBqIgshBwNAIAcgBToAACAHIAlBAyAGQSBqQQMQlwFB/wFxQf8BR2ohByAJQQxqIQkgESAFQQFqIgVHDQALCwJAIAggCkcNACANRQRAIAZBsAFqIgghCgwBCyAAQXRqIQVBACEJIAZBsAFqIgohCANAIAggCToAACAIIAVBAyAGQSBqQQMQlwFB/wFxQf8BRmohCCAFQXRqIQUgDSAJQQFqIglHDQALCyAIIAprIgUgByALayIJIAkgBUsbIgkEQCAGQbgCaiIZIAEgCy0AAEEMbGoiBUEIaigCADYCACAGIAUpAgA3A7ACIAEgCy0AAEEMbGoiBSAAIAotAABBf3NBDGxqIhApAgA3AgAgBUEIaiAQQQhqKAIANgIAIAlBAUcEQEEAIQUDQCAAIAUgCmoiEC0AAEF/c0EMbGoiFSABIAUgC2pBAWoiGi0AAEEMbGoiGykCADcCACAVQQhqIBtBCGooAgA2AgAgASAaLQAAQQxsaiIVIAAgEEEBai0AAEF/c0EMbGoiECkCADcCACAVQQhqIBBBCGooAgA2AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgCiAQaiEKCyAAIAotAABBf3NBDGxqIgUgBikDsAI3AgAgBUEIaiAZKAIANgIAIAtBAWohCyAKQQFqIQoLIAEgEUEMbGogASAHIAtGGyEBIABBACANa0EMbGogACAIIApGGyEAIBgNAAsCQCALIAdJBEAgACEFA0AgBkG4AmoiCCABIAdBf2oiBy0AAEEMbGoiAEEIaiIJKAIANgIAIAYgACkCADcDsAIgBUF0aiIFQQhqIgooAgAhDSAAIAUpAgA3AgAgCSANNgIAIAogCCgCADYCACAFIAYpA7ACNwIAIAsgB0kNAAsMAQsgASEFIAogCE8NAANAIAhBf2oiCC0AACEBIAZBuAJqIgcgBUEIaiIJKAIANgIAIAYgBSkCADcDsAIgACABQX9zQQxsaiIBQQhqIgsoAgAhDSAFIAEpAgA3AgAgCSANNgIAIAsgBygCADYCACABIAYpA7ACNwIAIAVBDGohBSAKIAhJDQALCyAOIAYpAyA3AgAgEiAWKAIANgIAIA8gBSAXa0EMbiAMaiIBTQ0DIBQgEigCADYCACAGIA4pAgA3A7ABIA4gAUEMbGoiBUEIaiIAKAIAIQggDiAFKQIANwIAIBIgCDYCACAAIBQoAgA2AgAgBSAGKQOwATcCACAPIAFrIghFDQQgCCABIAEgCEsbIA9BA3YgBUEMaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAYgDiEADAELIA4gASACIAMgBBAGIAUhAyAIIQELTyEJIAFBFU8NAQwFCwsgDCAFEKsBAAsgBSABEKkBAAtBwKfCACABIA8QqgEAC0G1rcIAQRxB1K3CABC9AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBOIAEgBUcNAAsLIAZBwAJqJAAL5BACFH8BfiMAQbACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEOIAAhDCAPIBNxQQFzIQkCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEHEgBEF/aiEECyAGIAI2AgwgBiAANgIwIAYgATYCNCAGIAFBAnYiCDYCECAGIAhBAXQiBTYCFCAGIAhBA2wiBzYCGCAGQQA2AhwgBiAGQRxqNgK4ASAGIAZBMGo2ArQBIAYgBkEMajYCsAEgBiAGQbABajYCICABQTJPBEAgBiAIQX9qNgIkIAYgCEEBajYCKCAGQSBqIAZBJGogBkEQaiAGQShqEGYgBiAFQX9qNgIkIAYgBUEBcjYCKCAGQSBqIAZBJGogBkEUaiAGQShqEGYgBiAHQX9qNgIkIAYgB0EBajYCKCAGQSBqIAZBJGogBkEYaiAGQShqEGYLIAZBIGogBkEQaiAGQRRqIAZBGGoQZiAGKAIcIghBC0sNASAIRSEHIAYoAhQMAgsgACABEIoBDA4LIAYoAjQiB0EBdiIIBEAgBigCMCIFIAdBA3RqQXhqIQcDQCAFKQIAIRkgBSAHKQIANwIAIAcgGTcCACAFQQhqIQUgB0F4aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCFEF/c2oLIQUgB0UgCXJBAXFFBEAgACABEDgNDQsgA0UNAiAFIAFPDQEgACAFQQN0aiIIKAIEKAIAIAMoAgQoAgBJBEAgASEOIAAhDAwECyAAKQIAIRkgACAIKQIANwIAIAggGTcCACAAQXxqIQsgAEEMaiERIABBCGohEiAAKAIEIQ0gACgCACETQQAhByABIQgDQAJAIAcgCEF/aiIKTw0AIBEgB0EDdGohBSANKAIAIRADQCAFKAIAKAIAIBBJDQEgBUEIaiEFIAogB0EBaiIHRw0ACyAKIQcLIAsgCEEDdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACAFQXhqIhAhBSgCACANKAIASQ0ACyASIAdBA3RqIgUpAgAhGSAFIBBBBGoiBSkCADcCACAFIBk3AgAgB0EBaiEHDAELCyAAIA02AgQgACATNgIAIAEgB0EBaiIITwRAIAAgCEEDdGohACABIAhrIgFBFU8NAQwMCwsgCCABEKsBAAtB0KfCACAFIAEQqgEACyAORQ0BCyAFIA5PDQEgDCkCACEZIAwgDCAFQQN0aiIAKQIANwIAIAAgGTcCACAMKAIAIQAgBiAMKAIEIhI2AiwgBiAANgIoIA5Bf2oiAUUEQEEAIQ0MAwsgDEEMaiEFIBIoAgAhAEEAIQ0DQCAAIAUoAgAoAgBPDQMgBUEIaiEFIAEgDUEBaiINRw0ACyABIQ0MAgtBsKfCAEEAQQAQqgEAC0HAp8IAIAUgDhCqAQALIAxBCGohCiAMIA5BA3RqIQcgASEAA0ACQCAHIQggACIFIA1NIhMNACAIQXhqIQcgBUF/aiEAIBIoAgAgCEF8aigCACgCAE8NAQsLIAUgDU8EQCABIAVJDQJBgAEhEUEAIQBBACEBQQAhB0EAIQtBgAEhDyAKIA1BA3RqIhUhCgNAAkAgCCAKayIFQYcQSyIWDQAgBUEDdiIFQYB/aiAFIAsgB0kiCSABIABJciIQGyEFIBAEQCAPIAUgCRshDyAFIBEgCRshEQwBCyAFIAVBAXYiD2shEQsCQCAHIAtHDQAgD0UEQCAGQTBqIgchCwwBC0EAIQUgCiEJIAZBMGoiCyEHA0AgByAFOgAAIAcgEigCACAJQQRqKAIAKAIAT2ohByAJQQhqIQkgDyAFQQFqIgVHDQALCwJAIAAgAUcNACARRQRAIAZBsAFqIgAhAQwBCyAIQXxqIQVBACEJIAZBsAFqIgEhAANAIAAgCToAACAAIAYoAiwiEigCACAFKAIAKAIASWohACAFQXhqIQUgESAJQQFqIglHDQALCyAIIBFBA3RrIAggACABayIFIAcgC2siCSAJIAVLGyIJBEAgCiALLQAAQQN0aiIFKAIEIRcgBSgCACEYIAUgCCABLQAAQX9zQQN0aikCADcCACAJQQFHBEBBACEFA0AgCCABIAVqIhAtAABBf3NBA3RqIAogBSALakEBaiIULQAAQQN0aikCADcCACAKIBQtAABBA3RqIAggEEEBai0AAEF/c0EDdGopAgA3AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgASAQaiEBCyAIIAEtAABBf3NBA3RqIgUgFzYCBCAFIBg2AgAgC0EBaiELIAFBAWohAQsgACABRhshCCAKIA9BA3RqIAogByALRhshCiAWDQALAkAgCyAHSQRAIAghBQNAIAogB0F/aiIHLQAAQQN0aiIAKQIAIRkgACAFQXhqIgUpAgA3AgAgBSAZNwIAIAsgB0kNAAsMAQsgCiEFIAEgAE8NAANAIAUpAgAhGSAFIAggAEF/aiIALQAAQX9zQQN0aiIHKQIANwIAIAcgGTcCACAFQQhqIQUgASAASQ0ACwsgDCAGKQMoIhk3AgAgDiAFIBVrQQN2IA1qIgFNDQMgDCAMIAFBA3RqIggpAgA3AgAgCCAZNwIAIA4gAWsiBUUNBCAFIAEgASAFSxsgDkEDdiAIQQhqIQACQCABIAVBf2oiBU8EQCAAIAUgAiAIIAQQByAMIQAMAQsgDCABIAIgAyAEEAcgCCEDIAUhAQtPIQ8gAUEVTw0BDAULCyANIAUQqwEACyAFIAEQqQEAC0HAp8IAIAEgDhCqAQALQbWtwgBBHEHUrcIAEL0BAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEGwgASAFRw0ACwsgBkGwAmokAAvuDwETfyMAQaACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEMIAAhDSAPIBNxQQFzIQsCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEHIgBEF/aiEECyAGIAI2AgAgBiAANgIgIAYgATYCJCAGIAFBAnYiBTYCBCAGIAVBAXQiCDYCCCAGIAVBA2wiBzYCDCAGQQA2AhAgBiAGQRBqNgKoASAGIAZBIGo2AqQBIAYgBjYCoAEgBiAGQaABajYCFCABQTJPBEAgBiAFQX9qNgIYIAYgBUEBajYCHCAGQRRqIAZBGGogBkEEaiAGQRxqEMgBIAYgCEF/ajYCGCAGIAhBAXI2AhwgBkEUaiAGQRhqIAZBCGogBkEcahDIASAGIAdBf2o2AhggBiAHQQFqNgIcIAZBFGogBkEYaiAGQQxqIAZBHGoQyAELIAZBFGogBkEEaiAGQQhqIAZBDGoQyAEgBigCECIFQQtLDQEgBUUhByAGKAIIDAILIAAgARCJAQwOCyAGKAIkIgdBAXYiCARAIAYoAiAiBSAHQQJ0akF8aiEHA0AgBSgCACEJIAUgBygCADYCACAHIAk2AgAgBUEEaiEFIAdBfGohByAIQX9qIggNAAsLQQEhByABIAYoAghBf3NqCyEFIAdFIAtyQQFxRQRAIAAgARA+DQ0LIANFDQIgBSABTw0BIAMoAgAgACAFQQJ0aiIIKAIAIgcQtQEEQCABIQwgACENDAQLIAAoAgAhBSAAIAc2AgAgCCAFNgIAIABBfGohECAAQQRqIQogACgCACEOQQAhByABIQgDQAJAIAcgCEF/aiIJTw0AIAogB0ECdGohBQNAIA4gBSgCABC1AQ0BIAVBBGohBSAJIAdBAWoiB0cNAAsgCSEHCyAQIAhBAnRqIQUCQANAIAcgCEF/aiIITw0BIAUoAgAhCSAFQXxqIhEhBSAOIAkQtQENAAsgCiAHQQJ0aiIFKAIAIRMgBSAJNgIAIBFBBGogEzYCACAHQQFqIQcMAQsLIAAgDjYCACABIAdBAWoiBU8EQCAAIAVBAnRqIQAgASAFayIBQRVPDQEMDAsLIAUgARCrAQALQdCnwgAgBSABEKoBAAsgDEUNAQsgBSAMTw0BIA0oAgAhACANIA0gBUECdGoiASgCADYCACABIAA2AgAgDUEEaiEJIA0oAgAhEEEAIQ4gDEF/aiIBRQ0CIAkhBQNAIAUoAgAgEBC1AUUNAyAFQQRqIQUgASAOQQFqIg5HDQALIAEhDgwCC0Gwp8IAQQBBABCqAQALQcCnwgAgBSAMEKoBAAsgDSAMQQJ0aiEHIAEhCANAAkAgByEAIAgiBSAOTSITDQAgBUF/aiEIIABBfGoiBygCACAQELUBRQ0BCwsgBSAOTwRAIAEgBUkNAkGAASEPQQAhCEEAIQFBACEHQQAhC0GAASERIAkgDkECdGoiFSEJA0ACQCAAIAlrIgVBgwhLIhYNACAFQQJ2IgVBgH9qIAUgCyAHSSIKIAEgCElyIhIbIQUgEgRAIBEgBSAKGyERIAUgDyAKGyEPDAELIAUgBUEBdiIRayEPCwJAIAcgC0cNACARRQRAIAZBIGoiByELDAELQQAhBSAJIQogBkEgaiILIQcDQCAHIAU6AAAgByAKKAIAIBAQtQFBAXNqIQcgCkEEaiEKIBEgBUEBaiIFRw0ACwsCQCABIAhHDQAgD0UEQCAGQaABaiIIIQEMAQsgAEF8aiEFQQAhCiAGQaABaiIBIQgDQCAIIAo6AAAgBSgCACAQELUBIAhqIQggBUF8aiEFIA8gCkEBaiIKRw0ACwsgACAPQQJ0ayAAIAggAWsiBSAHIAtrIgogCiAFSxsiCgRAIAkgCy0AAEECdGoiBSgCACEXIAUgACABLQAAQX9zQQJ0aigCADYCACAKQQFHBEBBACEFA0AgACABIAVqIhItAABBf3NBAnRqIAkgBSALakEBaiIULQAAQQJ0aigCADYCACAJIBQtAABBAnRqIAAgEkEBai0AAEF/c0ECdGooAgA2AgAgBUECaiAFQQFqIhIhBSAKSQ0ACyALIBJqIQsgASASaiEBCyAAIAEtAABBf3NBAnRqIBc2AgAgC0EBaiELIAFBAWohAQsgASAIRhshACAJIBFBAnRqIAkgByALRhshCSAWDQALAkAgCyAHSQRAIAAhBQNAIAkgB0F/aiIHLQAAQQJ0aiIAKAIAIQEgACAFQXxqIgUoAgA2AgAgBSABNgIAIAsgB0kNAAsMAQsgCSEFIAEgCE8NAANAIAUoAgAhByAFIAAgCEF/aiIILQAAQX9zQQJ0aiIJKAIANgIAIAkgBzYCACAFQQRqIQUgASAISQ0ACwsgDSAQNgIAIAwgBSAVa0ECdiAOaiIBTQ0DIA0gDSABQQJ0aiIFKAIANgIAIAUgEDYCACAMIAFrIghFDQQgCCABIAEgCEsbIAxBA3YgBUEEaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAggDSEADAELIA0gASACIAMgBBAIIAUhAyAIIQELTyEPIAFBFU8NAQwFCwsgDiAFEKsBAAsgBSABEKkBAAtBwKfCACABIAwQqgEAC0G1rcIAQRxB1K3CABC9AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRB/IAEgBUcNAAsLIAZBoAJqJAAL1w8BE38jAEGgAmsiBiQAAkACQCABQRVJDQBBASEPQQEhEwJAAkACQANAIAEhDCAAIQ0gDyATcUEBcyELAkACQAJAAkACQAJAA0ACfwJAIAQEQCAPQQFxRQRAIAAgARByIARBf2ohBAsgBiACNgIAIAYgADYCICAGIAE2AiQgBiABQQJ2IgU2AgQgBiAFQQF0Igg2AgggBiAFQQNsIgc2AgwgBkEANgIQIAYgBkEQajYCqAEgBiAGQSBqNgKkASAGIAY2AqABIAYgBkGgAWo2AhQgAUEyTwRAIAYgBUF/ajYCGCAGIAVBAWo2AhwgBkEUaiAGQRhqIAZBBGogBkEcahBqIAYgCEF/ajYCGCAGIAhBAXI2AhwgBkEUaiAGQRhqIAZBCGogBkEcahBqIAYgB0F/ajYCGCAGIAdBAWo2AhwgBkEUaiAGQRhqIAZBDGogBkEcahBqCyAGQRRqIAZBBGogBkEIaiAGQQxqEGogBigCECIFQQtLDQEgBUUhByAGKAIIDAILIAAgARCIAQwOCyAGKAIkIgdBAXYiCARAIAYoAiAiBSAHQQJ0akF8aiEHA0AgBSgCACEJIAUgBygCADYCACAHIAk2AgAgBUEEaiEFIAdBfGohByAIQX9qIggNAAsLQQEhByABIAYoAghBf3NqCyEFIAdFIAtyQQFxRQRAIAAgARA9DQ0LIANFDQIgBSABTw0BIAMoAgAgACAFQQJ0aiIIKAIAIgdJBEAgASEMIAAhDQwECyAAKAIAIQUgACAHNgIAIAggBTYCACAAQXxqIRAgAEEEaiEKIAAoAgAhDkEAIQcgASEIA0ACQCAHIAhBf2oiCU8NACAKIAdBAnRqIQUDQCAOIAUoAgBJDQEgBUEEaiEFIAkgB0EBaiIHRw0ACyAJIQcLIBAgCEECdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACEJIAVBfGoiESEFIA4gCUkNAAsgCiAHQQJ0aiIFKAIAIRMgBSAJNgIAIBFBBGogEzYCACAHQQFqIQcMAQsLIAAgDjYCACABIAdBAWoiBU8EQCAAIAVBAnRqIQAgASAFayIBQRVPDQEMDAsLIAUgARCrAQALQdCnwgAgBSABEKoBAAsgDEUNAQsgBSAMTw0BIA0oAgAhACANIA0gBUECdGoiASgCADYCACABIAA2AgAgDUEEaiEJIA0oAgAhEEEAIQ4gDEF/aiIBRQ0CIAkhBQNAIAUoAgAgEE8NAyAFQQRqIQUgASAOQQFqIg5HDQALIAEhDgwCC0Gwp8IAQQBBABCqAQALQcCnwgAgBSAMEKoBAAsgDSAMQQJ0aiEHIAEhCANAAkAgByEAIAgiBSAOTSITDQAgBUF/aiEIIABBfGoiBygCACAQTw0BCwsgBSAOTwRAIAEgBUkNAkGAASEPQQAhCEEAIQFBACEHQQAhC0GAASERIAkgDkECdGoiFSEJA0ACQCAAIAlrIgVBgwhLIhYNACAFQQJ2IgVBgH9qIAUgCyAHSSIKIAEgCElyIhIbIQUgEgRAIBEgBSAKGyERIAUgDyAKGyEPDAELIAUgBUEBdiIRayEPCwJAIAcgC0cNACARRQRAIAZBIGoiByELDAELQQAhBSAJIQogBkEgaiILIQcDQCAHIAU6AAAgByAKKAIAIBBPaiEHIApBBGohCiARIAVBAWoiBUcNAAsLAkAgASAIRw0AIA9FBEAgBkGgAWoiCCEBDAELIABBfGohBUEAIQogBkGgAWoiASEIA0AgCCAKOgAAIAggBSgCACAQSWohCCAFQXxqIQUgDyAKQQFqIgpHDQALCyAAIA9BAnRrIAAgCCABayIFIAcgC2siCiAKIAVLGyIKBEAgCSALLQAAQQJ0aiIFKAIAIRcgBSAAIAEtAABBf3NBAnRqKAIANgIAIApBAUcEQEEAIQUDQCAAIAEgBWoiEi0AAEF/c0ECdGogCSAFIAtqQQFqIhQtAABBAnRqKAIANgIAIAkgFC0AAEECdGogACASQQFqLQAAQX9zQQJ0aigCADYCACAFQQJqIAVBAWoiEiEFIApJDQALIAsgEmohCyABIBJqIQELIAAgAS0AAEF/c0ECdGogFzYCACALQQFqIQsgAUEBaiEBCyABIAhGGyEAIAkgEUECdGogCSAHIAtGGyEJIBYNAAsCQCALIAdJBEAgACEFA0AgCSAHQX9qIgctAABBAnRqIgAoAgAhASAAIAVBfGoiBSgCADYCACAFIAE2AgAgCyAHSQ0ACwwBCyAJIQUgASAITw0AA0AgBSgCACEHIAUgACAIQX9qIggtAABBf3NBAnRqIgkoAgA2AgAgCSAHNgIAIAVBBGohBSABIAhJDQALCyANIBA2AgAgDCAFIBVrQQJ2IA5qIgFNDQMgDSANIAFBAnRqIgUoAgA2AgAgBSAQNgIAIAwgAWsiCEUNBCAIIAEgASAISxsgDEEDdiAFQQRqIQACQCABIAhBf2oiCE8EQCAAIAggAiAFIAQQCSANIQAMAQsgDSABIAIgAyAEEAkgBSEDIAghAQtPIQ8gAUEVTw0BDAULCyAOIAUQqwEACyAFIAEQqQEAC0HAp8IAIAEgDBCqAQALQbWtwgBBHEHUrcIAEL0BAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEHQgASAFRw0ACwsgBkGgAmokAAu3DgEafyMAQYABayIHJAAgACgCBCEEIAEoAgghCSABKAIAIQ0CQAJAAkAgACgCAC0AAARAIAcgBDYCDCAJQRVJDQEgByAJQQF2EKABIAcoAgQgBygCACEQIAdBADYCGCAHQgQ3AxAgDUGcf2ohFUEEIQpBACEEIAkhBgNAAkAgBiIDQQFGBEBBACEGQQEhAAwBCyADQX5qIQIgDSADQeQAbCIRaiEFQVghAQJAAkACQAJAAkACQAJAA0AgAUEEaiIARQ0BIAEgBWohCCAAIQEgCEGgf2ooAgAiBiAIQQRqKAIAIgBGDQALIAYgAEgNAQsgDSARaiEFA0AgAiIGRQRAQQAhBiADIQAMCAsgBkF/aiECQVghAQNAAkAgAUEEaiIARQRAQQAhAAwBCyABIAVqIQwgACEBQX8gDEG8fmooAgAiCCAMQaB/aigCACIARyAIIABIGyIARQ0BCwsgBUGcf2ohBSAAQX9HDQALIAMgBmshAAwBCwJAIAIEQANAIAIiBkF/aiECQVghAQNAIAFBBGoiAEUNAyABIAVqIQwgACEBQX8gDEG8fmooAgAiCCAMQaB/aigCACIARyAIIABIGyIARQ0ACyAAQf8BcUH/AUcNAiAFQZx/aiEFIAINAAsLQQAhBgsgAyAGSQ0BIAMgCUsNAiADIAZrIgBBAXYiBUUNACANIAZB5ABsaiEBIBEgFWohAgNAIAdBHGogAUHkABC+ARogASACQeQAEJwBQeQAaiEBIAIgB0EcakHkABC+AUGcf2ohAiAFQX9qIgUNAAsLIAZFIABBCUtyDQQgAyAGayEAIAZBf2ohASAVIAZB5ABsaiECA0AgAyABSQ0DIAMgCUsNAiACIABBAWoiABBNIAFBf2oiBCABTw0EIAJBnH9qIQIgBCEBIABBCkkNAAsMAwsgBiADEKsBAAsgAyAJEKkBAAsgASADEKsBAAsgBEEBaiEGIAshBAsgBygCFCAERgR/IAdBEGogBBCLASAHKAIQIQogBygCGCILBSAEC0EDdCAKaiIBIAA2AgQgASAGNgIAIAcgC0EBaiILNgIYAkAgCyIEQQJJDQACQANAAkACQAJAAkAgCiALIgNBf2oiC0EDdGoiASgCAARAIANBA3QgCmoiBUF0aigCACIIIAEoAgQiAEsNAQsgA0EDSQ0CIAEoAgQhACAKIANBfWoiAUEDdGooAgQhAgwBC0ECIQQgA0ECTQ0EIAogA0F9aiIBQQN0aigCBCICIAAgCGpNDQBBAyEEIANBA00NBCAFQWRqKAIAIAIgCGpNDQAgAyELIAMhBAwFCyACIABJDQELIANBfmohAQsCQAJAAkACQAJAAkACQCADIAFBAWoiE0sEQCADIAFNDQEgCiABQQN0IhlqIgAoAgQiGiAAKAIAaiIBIAogE0EDdCIbaiIAKAIAIhJJDQIgASAJSw0DIA0gEkHkAGxqIgggACgCBCIPQeQAbCIFaiEEIA0gAUHkAGxqIRQgASASayIAIA9rIgEgD08NBCAQIAQgAUHkAGwiABC+ASIMIABqIQ4gD0EBSCABQQFIcg0FIAQhBQNAIA5BXGohESAFQZx/aiEWIA5BnH9qIRdBACEBA0ACQCABQQRqIgBBKEYEQEEAIQAMAQsgASARaiECIAEgBWohBCAAIQFBfyAEQVxqKAIAIgQgAigCACIARyAEIABIGyIARQ0BCwsgFEGcf2oiFCAWIBcgAEF/RiIAG0HkABC+ARogDiAXIAAbIQ4gCCAWIAUgABsiBU8NByAOIAwiAksNAAsMBwtB6KvCACATIAMQqgEAC0Hoq8IAIAEgAxCqAQALIBIgARCrAQALIAEgCRCpAQALIBAgCCAFEL4BIgIgBWohDiAPQQFIIAAgD0xyRQRAIAghBQNAQcAAIQEDQAJAIAFBBGoiAEHoAEYEQEEAIQAMAQsgASAEaiEMIAEgAmohCCAAIQFBfyAIKAIAIgggDCgCACIARyAIIABIGyIARQ0BCwsgBCACIABBf0YiARshACACIAJB5ABqIAEbIQIgBSAAQeQAEL4BQeQAaiEFIARB5ABqIAQgARsiBCAUTw0EIA4gAksNAAsMAwsgCCEFDAELIAQhBQsgECECCyAFIAIgDiACayIAIABB5ABwaxC+ARogCiAZaiIAIA8gGmo2AgQgACASNgIAIAogG2oiACAAQQhqIAMgE0F/c2pBA3QQnAEaIAcgCzYCGCALQQFLDQALIAshBAwBCyADIQsLIAYNAAsgBygCFARAIAoQHwtFDQIgEBAfDAILIAcgBDYCECAHIAdBEGo2AhwgDSAJIAdBHGpBAEEgIAlnaxAEDAELQQIhASAJQQJJDQAgDSAJQX5qIgBB5ABsaiECA0AgCSAASQ0CIAIgARBNIAJBnH9qIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgB0GAAWokAA8LIAAgCRCrAQALvwwCFn8BfiMAQSBrIgckACAAKAIEIQMgASgCCCEIIAEoAgAhCwJAAkACQCAAKAIALQAABEAgByADNgIMIAhBFUkNASAHIAhBAXYQpAEgBygCBCAHKAIAIRBBACEBIAdBADYCGCAHQgQ3AxAgC0F4aiETIAtBbGohFUEEIQlBACEDIAghBgNAAkAgBiIFQX9qIgZFBEBBACEGQQEhBAwBCwJAAkACQAJAAkAgBUEDdCINIAtqQXRqKAIAKAIAIgAgCyAGQQN0aigCBCgCAE8EQCAFQX5qIRQgDSAVaiEEQQAhBkEAIQIDQCACIBRGBEAgBSEEDAgLIAJBAWohAiAEKAIAKAIAIgogAE8gBEF4aiEEIAohAA0ACyACQQFqIQQgAkF/cyAFaiEGDAELIA0gFWohAgNAAkAgBkEBRgRAQQAhBgwBCyAGQX9qIQYgAigCACgCACIEIABJIAJBeGohAiAEIQANAQsLIAUgBkkNASAFIAhLDQIgBSAGayIEQQF2IgpFDQAgCyAGQQN0aiEAIA0gE2ohAgNAIAApAgAhGCAAIAIpAgA3AgAgAiAYNwIAIABBCGohACACQXhqIQIgCkF/aiIKDQALCyAGRSAEQQlLcg0EIAUgBmshBCAGQX9qIQAgEyAGQQN0aiECA0AgBSAASQ0DIAUgCEsNAiACIARBAWoiBBBuIABBf2oiAyAATw0EIAJBeGohAiADIQAgBEEKSQ0ACwwDCyAGIAUQqwEACyAFIAgQqQEACyAAIAUQqwEACyADQQFqIQYgASEDCyAHKAIUIANGBH8gB0EQaiADEIsBIAcoAhAhCSAHKAIYIgEFIAMLQQN0IAlqIgAgBDYCBCAAIAY2AgAgByABQQFqIgE2AhggASEDAkAgAUECSQ0AAkADQAJAAkACQAJAIAkgAUF/akEDdGoiACgCAEUNACABQQN0IAlqIgRBdGooAgAiBSAAKAIEIgJNDQBBAiEDIAFBAk0NBiAJIAFBfWoiDEEDdGooAgQiACACIAVqTQ0BQQMhAyABQQNNDQYgBEFkaigCACAAIAVqTQ0BIAEhAwwGCyABQQNJDQEgACgCBCECIAkgAUF9aiIMQQN0aigCBCEACyAAIAJJDQELIAFBfmohDAsCQAJAIAEgDEEBaiIRSwRAIAEgDEsEQCAJIAxBA3QiF2oiACgCBCIUIAAoAgBqIgMgCSARQQN0Ig1qIgAoAgAiEk8EQCADIAhNBEAgCyASQQN0aiIEIAAoAgQiDkEDdCIKaiEBIANBA3QhDwJAAkAgAyASayIDIA5rIgUgDkkEQCAQIAEgBUEDdCIAEL4BIgogAGohAiAOQQFIIAVBAUhyDQEgDyATaiEAA0ACQCAAIAFBeGoiBSACQXhqIgMgAUF8aigCACgCACACQXxqKAIAKAIASSIPGykCADcCACACIAMgDxshAiAEIAUgASAPGyIBTw0AIABBeGohACACIApLDQELCyAHKAIQIQkMAQsgCiAQIAQgChC+ASIAaiECIAMgDkpBACAOQQFOGw0BIAQhAQsgECEADAYLIAsgD2ohAwNAIAQgASAAIAAoAgQoAgAgASgCBCgCAEkiBRspAgA3AgAgACAAQQhqIAUbIQAgBEEIaiEEIAFBCGogASAFGyIBIANPDQUgAiAASw0ACwwECyADIAgQqQEACyASIAMQqwEAC0Hoq8IAIAwgARCqAQALQeirwgAgESABEKoBAAsgBCEBCyABIAAgAiAAa0F4cRC+ARogBygCGCIBIAxLBEAgCSAXaiIAIA4gFGo2AgQgACASNgIAIAEgEU0NAiAJIA1qIgAgAEEIaiABIBFBf3NqQQN0EJwBGiAHIAFBf2oiATYCGCABIQMgAUEBTQ0DDAELC0H4q8IAIAwgARCqAQALQaeuwgBBHUHErsIAEL0BAAsgBg0ACyAHKAIUBEAgCRAfC0UNAiAQEB8MAgsgByADNgIMIAcgB0EMajYCECALIAggB0EQakEAQSAgCGdrEAcMAQtBAiEBIAhBAkkNACALIAhBfmoiAEEDdGohAgNAIAggAEkNAiACIAEQbiACQXhqIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgB0EgaiQADwsgACAIEKsBAAvmDAIMfwR+IwBB4ABrIgYkAAJAAkACQAJAIAEoAhAiA0EBaiIFIANPBEAgASgCACICIQMCQCAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAAn8gBSADQQFqIgMgBSADSxsiA0UEQEGwpsIAIQpBBCEEQQAMAQsgBkEgakF/An8gA0EITwRAIAMgA0H/////AXFGBEAgA0EDdEEHbgwCCwwJCyADQQFqC0F/amd2IgdBAWoiBRCeASAGKAIkIgRFDQcgBigCKCECIAYoAiAiAyAEENYBIgpFDQIgCkH/ASAHQQVqEMQBIAJqIQQgByAFQQN2QQdsIAdBCEkbCyECIAYgASgCECIDNgIwIAYgAiADazYCLCAGIAQ2AiggBiAKNgIkIAYgBzYCICABKAIEIgNBBGohAiABKAIAIANqQQFqIQ0gAygCAEF/c0GAgYKEeHEhCCABKAIIIQkDQAJAAkACQCAIBEAgAiEDDAELA0AgAiANTw0CIAlBsAdqIQkgAigCACACQQRqIgMhAkGAgYKEeHEiBUGAgYKEeEYNAAsgBUGAgYKEeHMhCAsgBiAJIAhoQQN2QewBbGoiDCgCACIENgJQIAhBf2ohC0KlxoihyJyn+UshDkEBIQIDQCAOIAStQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQdAAaiACaiACQQFqIQItAAAhBAwACwALIAEpAgAhDyABIAYpAyA3AgAgBkEoaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIAZBMGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA83AyAgAEEANgIAIA+nIgBFDQcgBkHQAGogAEEBahCeASAGKAIkEB8MBwsgCiAGQSBqIA4QmgEiBWogDqdBGXYiAjoAACAHIAVBfGpxIApqQQRqIAI6AAAgBigCKCAFQewBbGogDEHsARC+ARogCCALcSEIIAMhAgwACwALIAJBAWohC0EAIQIDQAJAAn8gBEEBcUUEQCACIAtPDQIgAkEBaiIDIAJJDQIgAiEEIAMMAQsgAkEDaiIEIAJJIgUNASAEIAtJIgNFDQEgCyACQQRqIAUbIAsgAxsLIQIgASgCBCAEaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEEDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQnAEaDAMLIAMgBEG04cIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDSADTwR/QQAhAwNAIAMiBUEBaiEDAkAgBSABKAIEai0AAEGAAUcNAANAIAYgASgCCCAFQewBbGoiCCgCACIENgIgQqXGiKHInKf5SyEOQQEhAgNAIA4gBK1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAZBIGogAmogAkEBaiECLQAAIQQMAQsLIAUgASgCACIJIA6nIgxxIgJrIAEgDhCaASIHIAJrcyAJcUEETwRAIAEoAgQiCyAHaiICLQAAIAIgDEEZdiICOgAAIAsgCSAHQXxqcWpBBGogAjoAAEH/AUYEQCABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIAdB7AFsaiAIQewBEL4BGgwDCyABKAIIIAdB7AFsaiEKQQAhAgNAIAIgCmoiBykCACEQIAcgAiAIaiIJKQIANwIAIAdBGGoiBCkCACERIAQgCUEYaiIMKQIANwIAIAdBEGoiBCkCACEPIAQgCUEQaiILKQIANwIAIAdBCGoiBCkCACEOIAQgCUEIaiIEKQIANwIAIAQgDjcCACALIA83AgAgDCARNwIAIAkgEDcCACACQUBrIAJBIGohAkHtAUkNAAsgCikC4AEhDiAKIAgpAuABNwLgASAKQegBaiICKAIAIQQgAiAIQegBaiICKAIANgIAIAggDjcC4AEgAiAENgIADAELCyABKAIEIgQgBWogDEEZdiICOgAAIAQgCSAFQXxqcWpBBGogAjoAAAsgAyANRw0ACyABKAIAIgJBCEkNASACQQFqBSANC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQeAAaiQADwtBtKbCAEEcQdCmwgAQvQEAC44MARV/IwBBIGsiBiQAIAAoAgQhByABKAIIIQkgASgCACEKAkACQAJAIAAoAgAtAAAEQCAGIAc2AgwgCUEVSQ0BIAYgCUEBdhCjASAGKAIEIAYoAgAhDkEAIQEgBkEANgIYIAZCBDcDECAKQXxqIRAgCkF0aiERQQQhCCAJIQcDQCAHIQVBACEHQQEhAgJAIAVBf2oiBEUNAAJAAkACQAJAAkACQCAKIARBAnRqKAIAIAVBAnQiByAKakF4aigCACIAELUBRQRAIAVBfmohBCAHIBFqIQJBACEHQQAhAwNAIAMgBEYEQCAFIQIMCQsgA0EBaiEDIAAgAigCACIAELUBIAJBfGohAkUNAAsgA0EBaiECIANBf3MgBWohBAwBCyAHIBFqIQIDQAJAIARBAUYEQEEAIQQMAQsgBEF/aiEEIAAgAigCACIAELUBIAJBfGohAg0BCwsgBSAESQ0BIAUgCUsNAiAFIARrIgJBAXYiC0UNACAKIARBAnRqIQAgByAQaiEDA0AgACgCACEHIAAgAygCADYCACADIAc2AgAgAEEEaiEAIANBfGohAyALQX9qIgsNAAsLIARFIAJBCUtyDQQgBSAEayECIARBf2ohACAQIARBAnRqIQMDQCAFIABJDQMgBSAJSw0CIAMgAkEBaiICEHYgAEF/aiIHIABPDQQgA0F8aiEDIAchACACQQpJDQALDAMLIAQgBRCrAQALIAUgCRCpAQALIAAgBRCrAQALIAdBAWohBwwBCyAEIQcLIAYoAhQgAUYEQCAGQRBqIAEQiwEgBigCECEIIAYoAhghAQsgCCABQQN0aiIAIAI2AgQgACAHNgIAIAYgAUEBaiIBNgIYAkAgAUECSQ0AAkADQAJAAkACQAJAIAggAUF/akEDdGoiACgCAEUNACABQQN0IAhqIgRBdGooAgAiAyAAKAIEIgJNDQAgAUECTQ0GIAggAUF9aiIFQQN0aigCBCIAIAIgA2pNDQEgAUEDTQ0GIARBZGooAgAgACADaksNBgwBCyABQQNJDQEgACgCBCECIAggAUF9aiIFQQN0aigCBCEACyAAIAJJDQELIAFBfmohBQsCQAJAIAEgBUEBaiILSwRAIAEgBUsEQCAIIAVBA3QiE2oiACgCBCIUIAAoAgBqIgAgCCALQQN0IhVqIgEoAgAiD08EQCAAIAlNBEAgCiAPQQJ0aiIDIAEoAgQiBEECdCICaiEBIABBAnQhDAJAAkAgACAPayINIARrIgAgBEkEQCAOIAEgAEECdCICEL4BIhYgAmohAiAEQQFIIABBAUhyDQEgDCAQaiEAA0ACQCAAIAFBfGoiCCACQXxqIgwgDCgCACAIKAIAELUBIg0bKAIANgIAIAIgDCANGyECIAMgCCABIA0bIgFPDQAgAEF8aiEAIAIgFksNAQsLIAYoAhAhCAwBCyAOIAMgAhC+ASIAIAJqIQIgDSAESkEAIARBAU4bDQEgAyEBCyAOIQAMBgsgCiAMaiENA0AgAyABIAAgASgCACAAKAIAELUBIgwbKAIANgIAIAAgAEEEaiAMGyEAIANBBGohAyABQQRqIAEgDBsiASANTw0FIAIgAEsNAAsMBAsgACAJEKkBAAsgDyAAEKsBAAtB6KvCACAFIAEQqgEAC0Hoq8IAIAsgARCqAQALIAMhAQsgASAAIAIgAGtBfHEQvgEaIAYoAhgiACAFSwRAIAggE2oiASAEIBRqNgIEIAEgDzYCACAAIAtNDQIgCCAVaiIBIAFBCGogACALQX9zakEDdBCcARogBiAAQX9qIgE2AhggAUEBTQ0DDAELC0H4q8IAIAUgABCqAQALQaeuwgBBHUHErsIAEL0BAAsgBw0ACyAGKAIUBEAgCBAfC0UNAiAOEB8MAgsgBiAHNgIMIAYgBkEMajYCECAKIAkgBkEQakEAQSAgCWdrEAgMAQtBAiEBIAlBAkkNACAKIAlBfmoiAEECdGohAgNAIAkgAEkNAiACIAEQdiACQXxqIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgBkEgaiQADwsgACAJEKsBAAvPCwIMfwJ+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgQgA08EQCABKAIAIgIhAwJAIAQgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAEIANBAWoiAyAEIANLGyIDRQRAQbCmwgAhB0EEIQVBAAwBCyAGQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiCEEBaiIEEKYBIAYoAgQiBUUNByAGKAIIIQIgBigCACIDIAUQ1gEiB0UNAiAHQf8BIAhBBWoQxAEgAmohBSAIIARBA3ZBB2wgCEEISRsLIQIgBiABKAIQIgM2AhAgBiACIANrNgIMIAYgBTYCCCAGIAc2AgQgBiAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDSADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIA1PDQIgCkFAayEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQsLQQEhAiAGIAogC2hBAXRB8ABxaiIMKAIAIgU2AjAgC0F/aiEJQqXGiKHInKf5SyEOA0AgDiAFrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBkEwaiACaiACQQFqIQItAAAhBQwACwALIAEpAgAhDyABIAYpAwA3AgAgBkEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA83AwAgAEEANgIAIA+nIgBFDQcgBkEwaiAAQQFqEKYBIAYoAgQQHwwHCyAHIAYgDhCaASIEaiAOp0EZdiICOgAAIAggBEF8anEgB2pBBGogAjoAACAGKAIIIARBBHRqIgIgDCkCADcCACACQQhqIAxBCGopAgA3AgAgCSALcSELIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBUEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEFIAMMAQsgAkEDaiIFIAJJIgQNASAFIAlJIgNFDQEgCSACQQRqIAQbIAkgAxsLIQIgASgCBCAFaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEFDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQnAEaDAMLIAMgBUG04cIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDCADTwR/QQAhAwNAIAMiBEEBaiEDAkAgBCABKAIEai0AAEGAAUcNAAJAA0AgBiABKAIIIARBBHRqIgcoAgAiBTYCAEKlxoihyJyn+UshDkEBIQIDQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCACIAZqIAJBAWohAi0AACEFDAELCyAEIAEoAgAiCiAOpyINcSICayABIA4QmgEiCCACa3MgCnFBBEkNASABKAIEIgkgCGoiAi0AACACIA1BGXYiAjoAACAJIAogCEF8anFqQQRqIAI6AABB/wFHBEAgASgCCCAIQQR0aiICKQIAIQ8gAiAHKQIANwIAIAJBCGoiAikCACEOIAIgB0EIaiICKQIANwIAIAcgDzcCACACIA43AgAMAQsLIAEoAgQiAiAEakH/AToAACACIAEoAgAgBEF8anFqQQRqQf8BOgAAIAEoAgggCEEEdGoiAiAHKQIANwIAIAJBCGogB0EIaikCADcCAAwBCyABKAIEIgUgBGogDUEZdiICOgAAIAUgCiAEQXxqcWpBBGogAjoAAAsgAyAMRw0ACyABKAIAIgJBCEkNASACQQFqBSAMC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC9AQALnQsCC38DfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiICIANPBEAgASgCACIDIQUCQCACIANBAWpBA3ZBB2wgBSADQQhPGyIFQQF2SwRAAn8gAiAFQQFqIgMgAiADSxsiAkUEQEGwpsIAIQdBACECQQQMAQsgBkF/An8gAkEITwRAIAIgAkH/////AXFGBEAgAkEDdEEHbgwCCwwJCyACQQFqC0F/amd2IglBAWoiAxCdASAGKAIEIgJFDQcgBigCCCEFIAYoAgAiBCACENYBIgdFDQIgCSADQQN2QQdsIAlBCEkbIQIgB0H/ASAJQQVqEMQBIAVqCyEDIAYgASgCECIFNgIQIAYgAiAFazYCDCAGIAM2AgggBiAHNgIEIAYgCTYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQogAygCAEF/c0GAgYKEeHEhBCABKAIIIQMDQAJAIARFBEADQCACIApPDQIgA0HgAGohAyACKAIAIAJBBGoiBSECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyEEIAUhAgsgBkKlxoihyJyn+Us3AzAgAyAEaEEDdkEYbGoiBSAGQTBqEHggByAGIAYpAzAiDRCaASIIaiANp0EZdiILOgAAIAkgCEF8anEgB2pBBGogCzoAACAGKAIIIAhBGGxqIgggBSkCADcCACAIQQhqIAVBCGopAgA3AgAgCEEQaiAFQRBqKQIANwIAIARBf2ogBHEhBAwBCwsgASkCACENIAEgBikDADcCACAGQQhqIgIpAwAhDiACIAFBCGoiAikCADcDACACIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDTcDACAAQQA2AgAgDaciAEUNBSAGQTBqIABBAWoQnQEgBigCBBAfDAULIANBAWohBUEAIQNBACECA0ACQAJ/IANBAXFFBEAgAiAFTw0CIAJBAWoiBCACSQ0CIAIhAyAEDAELIAJBA2oiAyACSSIEDQEgAyAFSSIHRQ0BIAUgAkEEaiAEGyAFIAcbCyECIAEoAgQgA2oiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhAwwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJwBGgwDCyAEIAJBtOHCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACICQQFqIgkgAk8Ef0EAIQMDQCADIgJBAWohAwJAIAIgASgCBGotAABBgAFHDQACQANAIAEoAgggBkKlxoihyJyn+Us3AwAgAkEYbGoiBSAGEHggAiABKAIAIgcgBikDACINpyIIcSIKayABIA0QmgEiBCAKa3MgB3FBBEkNASABKAIEIgogBGoiCy0AACALIAhBGXYiCDoAACAKIAcgBEF8anFqQQRqIAg6AABB/wFHBEAgASgCCCAEQRhsaiIEKQIAIQ0gBCAFKQIANwIAIARBEGoiBykCACEOIAcgBUEQaiIHKQIANwIAIARBCGoiBCkCACEPIAQgBUEIaiIEKQIANwIAIAUgDTcCACAHIA43AgAgBCAPNwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIARBGGxqIgIgBSkCADcCACACQQhqIAVBCGopAgA3AgAgAkEQaiAFQRBqKQIANwIADAELIAEoAgQiBSACaiAIQRl2IgQ6AAAgBSAHIAJBfGpxakEEaiAEOgAACyADIAlHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAkLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAEL0BAAuPCwIMfwJ+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgQgA08EQCABKAIAIgIhAwJAIAQgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAEIANBAWoiAyAEIANLGyIDRQRAQbCmwgAhB0EEIQVBAAwBCyAGQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiCEEBaiIEEKUBIAYoAgQiBUUNByAGKAIIIQIgBigCACIDIAUQ1gEiB0UNAiAHQf8BIAhBBWoQxAEgAmohBSAIIARBA3ZBB2wgCEEISRsLIQIgBiABKAIQIgM2AhAgBiACIANrNgIMIAYgBTYCCCAGIAc2AgQgBiAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDCADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIAxPDQIgCkEgaiEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQsLIAYgCiALaEE4cWoiDSgCACIFNgIwIAtBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQTBqIAJqIAJBAWohAi0AACEFDAALAAsgASkCACEPIAEgBikDADcCACAGQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDzcDACAAQQA2AgAgD6ciAEUNByAGQTBqIABBAWoQpQEgBigCBBAfDAcLIAcgBiAOEJoBIgRqIA6nQRl2IgI6AAAgCCAEQXxqcSAHakEEaiACOgAAIAYoAgggBEEDdGogDSkCADcCACAJIAtxIQsgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAFQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQUgAwwBCyACQQNqIgUgAkkiBA0BIAUgCUkiA0UNASAJIAJBBGogBBsgCSADGwshAiABKAIEIAVqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQUMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCcARoMAwsgAyAFQbThwgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIEQQFqIQMCQCAEIAEoAgRqLQAAQYABRw0AAkADQCAGIAEoAgggBEEDdGoiBygCACIFNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBmogAkEBaiECLQAAIQUMAQsLIAQgASgCACIKIA6nIg1xIgJrIAEgDhCaASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBA3RqIgIpAgAhDiACIAcpAgA3AgAgByAOQiCIPAAEIAcgDj4CAAwBCwsgASgCBCICIARqQf8BOgAAIAIgASgCACAEQXxqcWpBBGpB/wE6AAAgASgCCCAIQQN0aiAHKQIANwIADAELIAEoAgQiBSAEaiANQRl2IgI6AAAgBSAKIARBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAEL0BAAujCwIMfwR+IwBBQGoiBCQAAkACQAJAIAEoAhAiA0EBaiIFIANPBEAgASgCACICIQMgBSACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQCAEIAUgA0EBaiIDIAUgA0sbEFMgBEEIaigCACEHIAQoAgQhCiAEKAIAQQFGBEAgACAKNgIEIABBATYCACAAQQhqIAc2AgAMBQsgBEEQaigCACEFIARBDGooAgAhAiAEIAEoAhAiAzYCECAEIAUgA2s2AgwgBCACNgIIIAQgBzYCBCAEIAo2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiENIAMoAgBBf3NBgIGChHhxIQwgASgCCCELA0ACQAJAAkAgDARAIAIhAwwBCwNAIAIgDU8NAiALQaABaiELIAIoAgAgAkEEaiIDIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQwLIAQgCyAMaEEDdkEobGoiCCgCACIGNgIwIAxBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAEQTBqIAJqIAJBAWohAi0AACEGDAALAAsgASkCACEPIAEgBCkDADcCACAEQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBEEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAQgDzcDACAAQQA2AgAgD6ciAEUNBiAEQTBqIABBAWoQnwEgBCgCBBAfDAYLIAcgBCAOEJoBIgVqIA6nQRl2IgI6AAAgCiAFQXxqcSAHakEEaiACOgAAIAQoAgggBUEobGogCEEoEL4BGiAJIAxxIQwgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAGQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQYgAwwBCyACQQNqIgYgAkkiBQ0BIAYgCUkiA0UNASAJIAJBBGogBRsgCSADGwshAiABKAIEIAZqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQYMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NASACQQRqIAIgAxCcARoMAgtBtKbCAEEcQdCmwgAQvQEACyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIg0gA08Ef0EAIQMDQCADIgVBAWohAwJAIAUgASgCBGotAABBgAFHDQACQANAIAQgASgCCCAFQShsaiIHKAIAIgY2AgBCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAEaiACQQFqIQItAAAhBgwBCwsgBSABKAIAIgsgDqciCHEiAmsgASAOEJoBIgogAmtzIAtxQQRJDQEgASgCBCIJIApqIgItAAAgAiAIQRl2IgI6AAAgCSALIApBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCkEobGoiCCkDACEQIAggBykDADcDACAIQRhqIgIpAwAhESACIAdBGGoiCSkDADcDACAIQRBqIgIpAwAhDyACIAdBEGoiBikDADcDACAIQQhqIgIpAwAhDiACIAdBCGoiAikDADcDACAHIBA3AwAgAiAONwMAIAYgDzcDACAJIBE3AwAgCEEgaiICKQIAIQ4gAiAHQSBqIgIpAgA3AgAgAiAONwIADAELCyABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIApBKGxqIAdBKBC+ARoMAQsgASgCBCIGIAVqIAhBGXYiAjoAACAGIAsgBUF8anFqQQRqIAI6AAALIAMgDUcNAAsgASgCACICQQhJDQEgAkEBagUgDQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBEFAayQAC+gKAgt/An4jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiAiADTwRAIAEoAgAiAyEFAkAgAiADQQFqQQN2QQdsIAUgA0EITxsiBUEBdksEQAJ/IAIgBUEBaiIDIAIgA0sbIgJFBEBBsKbCACEHQQAhAkEEDAELIAZBfwJ/IAJBCE8EQCACIAJB/////wFxRgRAIAJBA3RBB24MAgsMCQsgAkEBagtBf2pndiIJQQFqIgMQpgEgBigCBCICRQ0HIAYoAgghBSAGKAIAIgQgAhDWASIHRQ0CIAkgA0EDdkEHbCAJQQhJGyECIAdB/wEgCUEFahDEASAFagshAyAGIAEoAhAiBTYCECAGIAIgBWs2AgwgBiADNgIIIAYgBzYCBCAGIAk2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEKIAMoAgBBf3NBgIGChHhxIQQgASgCCCEDA0ACQCAERQRAA0AgAiAKTw0CIANBQGshAyACKAIAIAJBBGoiBSECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyEEIAUhAgsgBkKlxoihyJyn+Us3AzAgAyAEaEEBdEHwAHFqIgUoAgAgBSgCCCAGQTBqEHMgByAGIAYpAzAiDRCaASIIaiANp0EZdiILOgAAIAkgCEF8anEgB2pBBGogCzoAACAGKAIIIAhBBHRqIgggBSkCADcCACAIQQhqIAUpAgg3AgAgBEF/aiAEcSEEDAELCyABKQIAIQ0gASAGKQMANwIAIAZBCGoiAikDACEOIAIgAUEIaiICKQIANwMAIAIgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiANNwMAIABBADYCACANpyIARQ0FIAZBMGogAEEBahCmASAGKAIEEB8MBQsgA0EBaiEFQQAhA0EAIQIDQAJAAn8gA0EBcUUEQCACIAVPDQIgAkEBaiIEIAJJDQIgAiEDIAQMAQsgAkEDaiIDIAJJIgQNASADIAVJIgdFDQEgBSACQQRqIAQbIAUgBxsLIQIgASgCBCADaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEDDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQnAEaDAMLIAQgAkG04cIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgJBAWoiCSACTwR/QQAhAwNAIAMiAkEBaiEDAkAgAiABKAIEai0AAEGAAUcNAAJAA0AgASgCCCAGQqXGiKHInKf5SzcDACACQQR0aiIFKAIAIAUoAgggBhBzIAIgASgCACIHIAYpAwAiDaciCHEiCmsgASANEJoBIgQgCmtzIAdxQQRJDQEgASgCBCIKIARqIgstAAAgCyAIQRl2Igg6AAAgCiAHIARBfGpxakEEaiAIOgAAQf8BRwRAIAEoAgggBEEEdGoiBCkCACENIAQgBSkCADcCACAEQQhqIgQpAgAhDiAEIAVBCGoiBCkCADcCACAFIA03AgAgBCAONwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIARBBHRqIgIgBSkCADcCACACQQhqIAVBCGopAgA3AgAMAQsgASgCBCIFIAJqIAhBGXYiBDoAACAFIAcgAkF8anFqQQRqIAQ6AAALIAMgCUcNAAsgASgCACICQQhJDQEgAkEBagUgCQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQvQEAC6EKAgx/An4jAEFAaiIEJAACQAJAAkAgASgCECIDQQFqIgUgA08EQCABKAIAIgIhAyAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAIAQgBSADQQFqIgMgBSADSxsQUiAEQQhqKAIAIQcgBCgCBCEIIAQoAgBBAUYEQCAAIAg2AgQgAEEBNgIAIABBCGogBzYCAAwFCyAEQRBqKAIAIQUgBEEMaigCACECIAQgASgCECIDNgIQIAQgBSADazYCDCAEIAI2AgggBCAHNgIEIAQgCDYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQwgAygCAEF/c0GAgYKEeHEhCyABKAIIIQoDQAJAAkACQCALBEAgAiEDDAELA0AgAiAMTw0CIApBIGohCiACKAIAIAJBBGoiAyECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyELCyAEIAogC2hBOHFqIg0oAgAiBjYCMCALQX9qIQlCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBEEwaiACaiACQQFqIQItAAAhBgwACwALIAEpAgAhDyABIAQpAwA3AgAgBEEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIARBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAEIA83AwAgAEEANgIAIA+nIgBFDQYgBEEwaiAAQQFqEKUBIAQoAgQQHwwGCyAHIAQgDhCaASIFaiAOp0EZdiICOgAAIAggBUF8anEgB2pBBGogAjoAACAEKAIIIAVBA3RqIA0pAgA3AgAgCSALcSELIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBkEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEGIAMMAQsgAkEDaiIGIAJJIgUNASAGIAlJIgNFDQEgCSACQQRqIAUbIAkgAxsLIQIgASgCBCAGaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEGDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQEgAkEEaiACIAMQnAEaDAILQbSmwgBBHEHQpsIAEL0BAAsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIFQQFqIQMCQCAFIAEoAgRqLQAAQYABRw0AAkADQCAEIAEoAgggBUEDdGoiBygCACIGNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBq1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBGogAkEBaiECLQAAIQYMAQsLIAUgASgCACIKIA6nIg1xIgJrIAEgDhCaASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBA3RqIgIpAgAhDiACIAcpAgA3AgAgByAOQiCIPgIEIAcgDj4CAAwBCwsgASgCBCICIAVqQf8BOgAAIAIgASgCACAFQXxqcWpBBGpB/wE6AAAgASgCCCAIQQN0aiAHKQIANwIADAELIAEoAgQiBiAFaiANQRl2IgI6AAAgBiAKIAVBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIARBQGskAAu8CQIYfwF+IwBBMGsiAyQAIAJBOGooAgAgAkEwaigCACEIIAJBLGooAgAgAkEMaigCACELIAIoAjQhFSACKAIoIQ4gAigCACEWIAIoAgQhCiABKAIAIgEoAgghDyABKAIAIRAgA0EIaiACQRBqKAIAIgcgAkEIaigCACICIAEoAgQiESABKAIMIhJqQQFqbGpBAnQQtAEgA0EANgIoIAMgAykDCDcDIAJAAkAgAgRAIAogAkEUbGohFyAIQQV0IQwgDkF0aiENIA8gEkECdGohGCAQIBFBAnRqIRlBACEIAkACQAJAAkACQAJAA0AgCCIGQQFqIQggCiIJQRRqIQogDCECIA0hBCAOIQECQAJ/AkADQCACRQ0BIAJBYGohAiAEQSBqIQQgASgCCCEaIAFBIGohASAGIBpHDQALIAkoAgQiAiAEKAIAaiIBIAVJDQUgByABSQ0GIARBBGooAgAhBCADQSBqIAMoAiggASAFaxCSASALIAVBAnRqIAsgAUECdGoiBSADQSBqEEogA0EgaiADKAIoIBEQkgEgECAZIANBIGoQSiACIARqIgYgAUkNByAHIAZJDQggA0EgaiADKAIoIAYgAWsQkgEgBSALIAZBAnRqIgQgA0EgahBKIANBIGogAygCKCASEJIBIA8gGCADQSBqEEogCSgCCCICIAZJDQkgByACSQ0EIAIgBmsMAQsgCSgCCCICIAVJDQEgByACSQ0DIAsgBUECdGohBCACIAVrCyEBIANBIGogAygCKCABEJIBIAQgCyACQQJ0aiADQSBqEEogCSgCCCEFIAogF0YNCQwBCwsgBSACEKsBAAsgAiAHEKkBAAsgBSABEKsBAAsgASAHEKkBAAsgASAGEKsBAAsgBiAHEKkBAAsgBiACEKsBAAtBACECDAELIAcgBU8EQCADKAIoIQIMAQsgBSAHEKsBAAsgA0EgaiACIAcgBWsQkgEgCyAFQQJ0aiALIAdBAnRqIANBIGoQSgJAIAMoAigiDUUNAEEAIQIgAygCICEGQQAhCQNAAkACQAJAIAIgBmoiDCwAACIIQX9KBEAgCEH/AXEhBAwBCwJ/IAYgDWoiASAMQQFqRgRAQQAhCiABDAELIAwtAAFBP3EhCiAMQQJqCyEEIAhBH3EhBQJAAkAgCEH/AXEiB0HfAU0EQCAKIAVBBnRyIQQMAQsCfyABIARGBEAgASEIQQAMAQsgBEEBaiEIIAQtAABBP3ELIApBBnRyIQQgB0HwAUkEQCAEIAVBDHRyIQQMAQsgASAIRgR/QQAFIAgtAABBP3ELIAVBEnRBgIDwAHEgBEEGdHJyIgRBgIDEAEYNAQsgBEGAAUkNAUECIQEgBEGAEEkNAkEDQQQgBEGAgARJGyEBDAILQYzawgBBK0G42sIAEL0BAAtBASEBIAQNACAJQQFqIQkMAQsgCUUEQEEAIQkMAQsgBiACIAlraiAMIAEQnAEaCyANIAEgAmoiAksNAAsgCUUNACADIA0gCWs2AigLIANBGGogA0EoaigCACIBNgIAIAMgAykDICIbNwMQIAAgFjYCACAAIBs3AgQgAEEMaiABNgIABEAgDhAfCwRAIBUQHwsgA0EwaiQAC8kKAQ1/IwBBoAJrIgckACAHIAI2AkAgByACNgI8IAcgATYCOCAHQShqIAdBOGoQhgEgBygCKCEBIAcoAiwhCiAHIAQ2AkAgByAENgI8IAcgAzYCOCAHQSBqIAdBOGoQjgEgBygCJCEJIAcoAiAhDSAHIAY2AkAgByAGNgI8IAcgBTYCOCAHQRhqIAdBOGoQhgEgBygCHCEOIAcoAhghCwJAAkACQAJAEMYBIgQEQCAHIAcvATQ7ATYgBCgCAA0CIARBfzYCACAEQQRqIAAQVSIMRQ0DIAwQswEgB0HkAGpBATsBACAHQeAAaiIPQQA2AgAgB0HYAGpCgICAgBA3AwAgB0HUAGogCTYCACAHQdAAakEANgIAIAdBzABqIAk2AgAgB0HIAGogDTYCACAHQcQAaiAJNgIAIAdB9ABqIAsgDkECdGo2AgAgB0HmAGogBy8BNjsBACAHQQA2AkAgByABIApBAnRqNgI8IAcgATYCOCAHQgA3A3ggByALNgJwIAdCADcDaCAKRQ0BIAxBFGohAyAHQUBrIRAgByABQQRqNgI4IAdBiAFqIRFBACECIAEhBgNAIAJB/wFxDQIgBigCACESAn8CQAJAIAcoAlQiACAHKAJQIgJJDQAgBygCTCAASQ0AIAdBEGogBygCXCAQakEfai0AACAHKAJIIgggAmogACACaxBDIAcoAhBBAUYEQCAHKAIUIQYDQCAHIAYgBygCUGpBAWoiADYCUAJAIAAgBygCXCICSQRAIAcoAkwhBQwBCyAHKAJMIgUgAEkNACACQQVPDQsgACACayIGIAcoAkhqIhMgD0YNBCATIA8gAhC4AUUNBAsgBygCVCIGIABJIAUgBklyDQIgB0EIaiACIBBqQR9qLQAAIAcoAkggAGogBiAAaxBDIAcoAgwhBiAHKAIIQQFGDQALCyAHIAcoAlQ2AlALIActAGUNBAJAIActAGQEQCAHKAJEIQIgBygCQCEGDAELIAcoAkQiAiAHKAJAIgZGDQULIAdBAToAZSACIAZrIQAgBygCSCAGagwBCyAHKAJAIQIgByAANgJAIAYgAmshACACIAhqCyECIAcoAnAiBSAHKAJ0Rg0CIAcgBUEEajYCcCAFKAIAIQUgB0HAAWogAiAAIAJqECIgB0HwAWogB0HAAWoQYSAHQcABaiAHQfABaiADEDwgB0HwAWogB0HAAWogAxAlIAdBwAFqIAdB8AFqIAMQHiAHQfABaiAHQcABahA6IAcoAvgBIgAEQCAHKALwASEGIABBFGwhAiAHKAKQAiEAIAcoAogCIQgDQCAGIAggACADEEcgBkEUaiEGIAJBbGoiAg0ACwsgB0HAAWogB0HwAWpBMBC+ARogB0HwAWogB0HAAWogAxBQIAcoAvgBIgAEQCAHKALwASEGIABBFGwhAiAHKAKQAiEAIAcoAogCIQgDQCAGIAggACADEEYgBkEUaiEGIAJBbGoiAg0ACwsgB0HAAWogB0HwAWpBMBC+ARogByASNgKEASAHQQA2AoABIBEgB0HAAWpBMBC+ARogByAFNgK4ASAMIAdBgAFqEBYgBygCOCIGIAcoAjxGDQIgByAGQQRqNgI4IActAGUhAgwACwALQdi8wgBBxgAgB0E4akHAvcIAEJkBAAsgBCAEKAIAQQFqNgIAIAkEQCANEB8LIA5B/////wNxBEAgCxAfCyAKQf////8DcQRAIAEQHwsgB0GgAmokAA8LQZ69wgBBECAHQThqQbC9wgAQmQEAC0GM2sIAQStBuNrCABC9AQALIAJBBBCpAQALlAoCFH8BfiMAQeAAayIDJAAgASAAKAIAIg02AgACQCAAKAKwAUUEQCAAQX82ArABIANBHGogAUE0aigCADYCACADQRRqIAFBKGooAgA2AgAgA0EMaiABQRxqKAIANgIAIAMgAUEsaigCADYCGCADIAFBIGooAgA2AhAgAyABQRRqKAIANgIIIAMgAUEQaigCADYCBCADIAEoAgg2AgAgA0FAayADEB0gAEG0AWoiAiACKAIAQQFqNgIAIAMoAkAhCiADKAJIIgJFDQEgCiACQQxsaiESIABBuAFqIQ8gAEG8AWohEyAAQcQBaiEQIABBwAFqIRQgAEHIAWohESAKIQkDQCADQdgAaiAJQQhqKAIANgIAIAMgCSkCADcDUCADQqXGiKHInKf5SzcDACADQdAAaiADEHggAykDACIWQhmIp0H/AHEiBEEIdCAEciICQRB0IAJyIQsgCUEMaiEJIAAoArgBIgcgFqdxIQIgACgCwAEhDiAAKAK8ASEVQQAhCAJAAkADQAJAIAIgFWooAAAiDCALcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAOIAZoQQN2IAJqIAdxQRhsaiIFIANB0ABqRgRAIANB0ABqIQUMAQsgBSADQdAAakEMELgBRQ0AIAZBf2ogBnEiBg0BDAILCyAFQQxqIQggBSgCFCEEQQAhB0EAIQIDQAJAIAQgAksEQCACQQFqIgYgAk8NAQsgBSgCECAERgRAIAggBEEBEIMBIAUoAhQhBAsgBSgCDCAEQQJ0aiANNgIAIAUgBSgCFEEBajYCFAwECyAIKAIAIgwgB2ohCyAHQQRqIQcgBiECAkBBfyANIAsoAgAiC0cgDSALSRsOAgQBAAsLIAQgBkF/ak8EQCAFKAIQIARGBH8gCCAEQQEQgwEgCCgCAAUgDAsgB2oiAiACQXxqIgIgBEECdCAHa0EEahCcARogAiANNgIAIAUgBEEBajYCFAwDC0HkrcIAQR5BhK7CABC9AQALIAhBBGoiCCACaiAHcSECIAwgDEEBdHFBgIGChHhxRQ0ACyAAKALEAUUEQCADIA8QDwsgAygCWCEFIAMoAlQhByADKAJQIQhBBEEEENYBIgZFDQEgBiANNgIAIBAgECgCACATKAIAIgwgDyAWEJoBIgJqIgstAABBAXFrNgIAIBQoAgAgCyAEOgAAIAwgACgCuAEgAkF8anFqQQRqIAQ6AAAgAkEYbGoiAkKBgICAEDcCECACIAY2AgwgAiAFNgIIIAIgBzYCBCACIAg2AgAgESARKAIAQQFqNgIACyAJIBJHDQEMAwsLQQRBBEG04cIAKAIAIgBBAiAAGxEAAAALQZ69wgBBECADQbiqwgAQmQEACyADKAJEBEAgChAfCyAAIAAoArABQQFqNgKwASADIAFBPBC+ASEJAkAgAEEMaigCACIBIABBCGooAgBGBEACQAJAIAFBAWoiAiABSQ0AIAFBAXQiCiACIAogAksbIgqtQjx+IhZCIIinDQAgFqciAkEATg0BCxDSAQALAn8gAUUEQCACQQQQ1gEMAQsgACgCBCABQTxsQQQgAhDTAQsiAUUNASAAIAo2AgggACABNgIEIAAoAgwhAQsgACgCBCABQTxsaiAJQTwQvgEaIAAgACgCDEEBajYCDCAAIAAoAgBBAWo2AgAgCUHgAGokAA8LIAJBBEG04cIAKAIAIgBBAiAAGxEAAAAL7QgCDH8BfiMAQdADayIEJAACQBDGASIDBEACQCADKAIARQRAIANBfzYCACADQQRqIAAQXgRAIAQgADYCCEKlxoihyJyn+UshDUEBIQIgACEBA0AgDSABrUL/AYOFQrODgICAIH4hDSACQQRGRQRAIARBCGogAmogAkEBaiECLQAAIQEMAQsLIA2nIgFBGXYiAkEIdCACciICQRB0IAJyIQkgAygCBCIGIAFxIQIgAygCDCEKIAMoAgghCANAAkACQCACIAhqKAAAIgcgCXMiAUF/cyABQf/9+3dqcUGAgYKEeHEiAUUNAANAIAAgCiABaEEDdiACaiAGcSILQewBbGoiDCgCAEcEQCABQX9qIAFxIgENAQwCCwtBgAEhAiAIIAtB7AFsIgFB7AFtIgVqIgcoAAAiCSAJQQF0cUGAgYKEeHFoQQN2IAggBUF8aiAGcWoiBSgAACIGIAZBAXRxQYCBgoR4cWdBA3ZqQQNNBEAgAyADKAIQQQFqNgIQQf8BIQILIAcgAjoAACAFQQRqIAI6AAAgAyADKAIUQX9qNgIUIAEgCmoiAigCCCEBIAwoAgQhBSAEQfABaiACQQxqQeABEL4BGiABRQRAQQAhAQwCCyAEIAU2AgggBEEQaiAEQfABakHgARC+ARoMAQsgBUEEaiIFIAJqIAZxIQJBACEBIAcgB0EBdHFBgIGChHhxRQ0BCwsgBCABNgIMIARBCGoQeSADIAMoAgBBAWo2AgACQAJAEMUBIgMEQCADKAIADQIgA0F/NgIAIANBBGogABBdRQ0BIAQgADYCCEKlxoihyJyn+UshDUEBIQIgACEBA0AgDSABrUL/AYOFQrODgICAIH4hDSACQQRGRQRAIARBCGogAmogAkEBaiECLQAAIQEMAQsLIA2nIgFBGXYiAkEIdCACciICQRB0IAJyIQkgAygCBCIGIAFxIQIgAygCDCEKIAMoAgghCEEAIQUCQANAAkACQCACIAhqKAAAIgcgCXMiAUF/cyABQf/9+3dqcUGAgYKEeHEiAUUNAANAIAAgCiABaEEDdiACaiAGcSILQQR0aigCAEcEQCABQX9qIAFxIgENAQwCCwtBgAEhAiAIIAtBBHQiAEEEdSIBaiIFKAAAIgcgB0EBdHFBgIGChHhxaEEDdiAIIAFBfGogBnFqIgEoAAAiBiAGQQF0cUGAgYKEeHFnQQN2akEDTQRAIAMgAygCEEEBajYCEEH/ASECCyAFIAI6AAAgAUEEaiACOgAAIAMgAygCFEF/ajYCFCAAIApqIgAoAgQiAUUNASAEIAApAgg3AgwgBCABNgIIDAMLIAVBBGoiBSACaiAGcSECIAcgB0EBdHFBgIGChHhxRQ0BCwsgBEEANgIICyAEQQhqEKgBIAMgAygCAEEBajYCACAEQdADaiQADwsMBAsQ1QEACwwECxDVAQALDAILC0HYvMIAQcYAIARBCGpB3KjCABCZAQALQZ69wgBBECAEQQhqQcyowgAQmQEAC8cJAg1/A34jAEGwCWsiBSQAIAUgAEGIARC+ASEBAkACQAJAQdS8wgAoAgARCAAiAwRAIAEoAgAhACABQYgBaiABQQRyQYQBEL4BGiADKAIADQIgA0F/NgIAIANBBGoiCSAAKAIAEF4NASABQcgHahBkQQRBBBDWASIFRQ0DIAVB2wA2AgBBBEEEENYBIgJFDQMgAkHdADYCACABQZgCakIANwMAIAFBCjYCoAIgAUKAgICAwAA3A5ACIAFBpAJqIAFByAdqQYQBEL4BIQYgAUHgA2pCADcDACABQdgDakKAgICAwAA3AwAgAUHQA2pCBDcDACABQcwDakGwpsIANgIAIAFByANqQQA2AgAgAUG4A2pCgYCAgBA3AwAgAUG0A2ogAjYCACABQawDakKBgICAEDcCACABQegDakIANwMAIAFCADcDwAMgASAFNgKoAyAGEI8BIAYgAUGIAWpBhAEQvgEaIAAoAgAhBSABQeAFaiABQZACakHoARC+ARogASAFNgLIB0KlxoihyJyn+UshDkEBIQIgBSEAA0AgDiAArUL/AYOFQrODgICAIH4hDiACQQRHBEAgAUHIB2ogAmogAkEBaiECLQAAIQAMAQsLIA5CGYinIgxB/wBxIgBBCHQgAHIiAEEQdCAAciENIAMoAgQiCCAOp3EhAiADKAIMIQYgAygCCCEAAkADQAJAIAAgAmooAAAiCiANcyIEQX9zIARB//37d2pxQYCBgoR4cSIERQ0AA0AgBSAGIARoQQN2IAJqIAhxIgtB7AFsaigCAEcEQCAEQX9qIARxIgQNAQwCCwsgAUHIB2ogAUHgBWpB6AEQvgEaIAYgC0HsAWxqQQRqIQhBACECA0AgAiAIaiIAKQIAIQ4gACABQcgHaiACaiIFKQIANwIAIAUgDjcCACAAQQhqIgQpAgAhDiAEIAVBCGoiBCkCADcCACAAQRBqIgcpAgAhDyAHIAVBEGoiBykCADcCACAAQRhqIgApAgAhECAAIAVBGGoiACkCADcCACAAIBA3AgAgByAPNwIAIAQgDjcCACACQUBrIAJBIGohAkHpAUkNAAsgBiALQewBbGoiACkC5AEhDiAAIAFBqAlqIgApAwA3AuQBIAAgDjcDACABQfgDaiABQcgHakHoARC+ARoMAgsgB0EEaiIHIAJqIAhxIQIgCiAKQQF0cUGAgYKEeHFFDQALIAFByAdqIAFB4AVqQegBEL4BGiAAIAkgDhCaASICai0AAEEBcSEEIAMoAhAiByAERXJFBEAgAUH4A2ogCRAMIAkgDhCaASECIAMoAgQhCCADKAIMIQYgAygCECEHIAMoAgghAAsgAyAHIARrNgIQIAAgAmogDEH/AHEiBDoAACAIIAJBfGpxIABqQQRqIAQ6AAAgBiACQewBbGoiACAFNgIAIABBBGogAUHIB2pB6AEQvgEaIAMgAygCFEEBajYCFCABQQA2AvwDCyABQfgDahB5IAMgAygCAEEBajYCACABQbAJaiQADwsgAUEEchCPAUHYvMIAQcYAIAFByAdqQdyowgAQmQEACxDVAQALQZ69wgBBECABQcgHakHMqMIAEJkBAAtBBEEEQbThwgAoAgAiAEECIAAbEQAAAAuuCAEGfyMAQfAAayIEJAAgBCADNgIMIAQgAjYCCEEBIQggASEGAkAgAUGBAkkNAEEAIAFrIQdBgAIhBQNAAkAgBSABTw0AIAAgBWosAABBv39MDQBBACEIIAUhBgwCCyAFQX9qIQZBACEIIAVBAUYNASAFIAdqIAYhBUEBRw0ACwsgBCAGNgIUIAQgADYCECAEQQBBBSAIGzYCHCAEQazYwgBBzoPAACAIGzYCGAJAAkACQCACIAFLIgUgAyABS3JFBEAgAiADSw0BAkAgAkUgASACRnJFBEAgASACTQ0BIAAgAmosAABBQEgNAQsgAyECCyAEIAI2AiAgAkUgASACRnINAiABQQFqIQMDQCACIAFJBEAgACACaiwAAEFATg0ECyACQX9qIQUgAkEBRg0EIAIgA0YgBSECRQ0ACwwDCyAEIAIgAyAFGzYCKCAEQcQAakEDNgIAIARB3ABqQQQ2AgAgBEHUAGpBBDYCACAEQgM3AjQgBEHUg8AANgIwIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCWCAEIARBEGo2AlAgBCAEQShqNgJIIARBMGpB7IPAABDDAQALIARB5ABqQQQ2AgAgBEHcAGpBBDYCACAEQdQAakEFNgIAIARBxABqQQQ2AgAgBEIENwI0IARB/IPAADYCMCAEQQU2AkwgBCAEQcgAajYCQCAEIARBGGo2AmAgBCAEQRBqNgJYIAQgBEEMajYCUCAEIARBCGo2AkggBEEwakGchMAAEMMBAAsgAiEFCwJAIAEgBUYNAEEBIQYCQAJAAkAgACAFaiIHLAAAIgJBf0wEQEEAIQggACABaiIDIQEgAyAHQQFqRwRAIActAAFBP3EhCCAHQQJqIQELIAJBH3EhByACQf8BcUHfAUsNASAIIAdBBnRyIQEMAgsgBCACQf8BcTYCJCAEQShqIQIMAgtBACEAIAMhBiABIANHBH8gAUEBaiEGIAEtAABBP3EFIAALIAhBBnRyIQAgAkH/AXFB8AFJBEAgACAHQQx0ciEBDAELQQAhAiADIAZHBH8gBi0AAEE/cQUgAgsgB0ESdEGAgPAAcSAAQQZ0cnIiAUGAgMQARg0CCyAEIAE2AiRBASEGIARBKGohAiABQYABSQ0AQQIhBiABQYAQSQ0AQQNBBCABQYCABEkbIQYLIAQgBTYCKCAEIAUgBmo2AiwgBEHEAGpBBTYCACAEQewAakEENgIAIARB5ABqQQQ2AgAgBEHcAGpBBjYCACAEQdQAakEHNgIAIARCBTcCNCAEQayEwAA2AjAgBCACNgJYIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCaCAEIARBEGo2AmAgBCAEQSRqNgJQIAQgBEEgajYCSCAEQTBqQdSEwAAQwwEAC0GM2sIAQStBuNrCABC9AQALpQgBCH8jAEFAaiIDJAAgA0EkaiABNgIAIANBNGogAkEUaigCACIENgIAIANBAzoAOCADQSxqIAIoAhAiBSAEQQN0ajYCACADQoCAgICABDcDCCADIAA2AiAgA0EANgIYIANBADYCECADIAU2AjAgAyAFNgIoAkACQAJAAkAgAigCCCIGRQRAIAIoAgAhCCACKAIEIgkgBCAEIAlLGyIGRQ0BQQEhBCAAIAgoAgAgCCgCBCABKAIMEQMADQQgCEEIaiECQQEhBwNAIAUoAgAgA0EIaiAFQQRqKAIAEQEABEAMBgsgByAGTw0CIAJBBGohACACKAIAIQEgBUEIaiEFIAJBCGohAiAHQQFqIQcgAygCICABIAAoAgAgAygCJCgCDBEDAEUNAAsMBAsgAigCACEIIAIoAgQiCSACQQxqKAIAIgIgAiAJSxsiCkUNAEEBIQQgACAIKAIAIAgoAgQgASgCDBEDAA0DIAZBEGohBSAIQQhqIQJBASEHA0AgAyAFQXhqKAIANgIMIAMgBUEQai0AADoAOCADIAVBfGooAgA2AghBACEBQQAhBAJAAkACQAJAIAVBCGooAgBBAWsOAwECAwALIAVBDGooAgAhAEEBIQQMAgsgBUEMaigCACIGIAMoAjQiBEkEQEEAIQQgAygCMCAGQQN0aiIGKAIEQQhHDQIgBigCACgCACEAQQEhBAwCC0HEhcAAIAYgBBCqAQALIAMoAigiBiADKAIsRg0AIAMgBkEIajYCKCAGKAIEQQhHDQAgBigCACgCACEAQQEhBAsgAyAANgIUIAMgBDYCEAJAAn8CQAJAAkACQAJAIAUoAgBBAWsOAwEABgQLIAMoAigiACADKAIsRw0BDAULIAVBBGooAgAiACADKAI0IgRPDQEgAygCMCAAQQN0aiIAKAIEQQhHDQQgACgCACgCAAwDCyADIABBCGo2AiggACgCBEEIRw0DIAAoAgAoAgAMAgtBxIXAACAAIAQQqgEACyAFQQRqKAIACyEEQQEhAQsgAyAENgIcIAMgATYCGAJAIAVBcGooAgBBAUcEQCADKAIoIgQgAygCLEYNBCADIARBCGo2AigMAQsgBUF0aigCACIAIAMoAjQiAU8NBCADKAIwIABBA3RqIQQLIAQoAgAgA0EIaiAEQQRqKAIAEQEABEBBASEEDAULIAcgCk8NASACQQRqIQAgAigCACEBIAVBJGohBSACQQhqIQJBASEEIAdBAWohByADKAIgIAEgACgCACADKAIkKAIMEQMARQ0ACwwDCyAJIAdLBEBBASEEIAMoAiAgCCAHQQN0aiIAKAIAIAAoAgQgAygCJCgCDBEDAA0DC0EAIQQMAgtBjNrCAEErQbjawgAQvQEAC0HUhcAAIAAgARCqAQALIANBQGskACAEC6gGAQ1/IAEoAhAhAiAAKAIEIQMgACgCACEFAkACQAJAIAEoAggiDUEBRwRAIAINAQwDCyACRQ0BCwJAIANFBEBBACEDDAELIAMgBWohByABQRRqKAIAQQFqIQogBSIAIQsDQCAAQQFqIQQCQAJ/IAAsAAAiAkF/TARAAn8gBCAHRgRAQQAhCCAHDAELIAAtAAFBP3EhCCAAQQJqIgQLIQAgAkEfcSEJIAggCUEGdHIgAkH/AXEiDkHfAU0NARoCfyAAIAdGBEBBACEMIAcMAQsgAC0AAEE/cSEMIABBAWoiBAshAiAMIAhBBnRyIQggCCAJQQx0ciAOQfABSQ0BGgJ/IAIgB0YEQCAEIQBBAAwBCyACQQFqIQAgAi0AAEE/cQsgCUESdEGAgPAAcSAIQQZ0cnIiAkGAgMQARw0CDAQLIAJB/wFxCyECIAQhAAsgCkF/aiIKBEAgBiALayAAaiEGIAAhCyAAIAdHDQEMAgsLIAJBgIDEAEYNAAJAIAZFIAMgBkZyRQRAQQAhACAGIANPDQEgBSAGaiwAAEFASA0BCyAFIQALIAYgAyAAGyEDIAAgBSAAGyEFCyANDQAMAQtBACEEIAMEQCADIQIgBSEAA0AgBCAALQAAQcABcUGAAUZqIQQgAEEBaiEAIAJBf2oiAg0ACwsgAyAEayABKAIMIgdPDQBBACEGQQAhBCADBEAgAyECIAUhAANAIAQgAC0AAEHAAXFBgAFGaiEEIABBAWohACACQX9qIgINAAsLIAQgA2sgB2ohAgJAAkACQEEAIAEtADAiACAAQQNGG0EBaw4DAAEAAgsgAiEGQQAhAgwBCyACQQF2IQYgAkEBakEBdiECCyAGQQFqIQACfwJAA0AgAEF/aiIABEAgASgCGCABKAIEIAEoAhwoAhARAQBFDQEMAgsLIAEoAgQhBEEBIAEoAhggBSADIAEoAhwoAgwRAwANARogAkEBaiEAIAEoAhwhBSABKAIYIQEDQCAAQX9qIgBFBEBBAA8LIAEgBCAFKAIQEQEARQ0ACwtBAQsPCyABKAIYIAUgAyABQRxqKAIAKAIMEQMAC/0GAhV/AX4jAEHgAWsiAiQAIAIgATYCACABKAIgIQkgAiABLQAkOgAHIAIgAjYCDCACIAJBB2o2AgggAS0AJUUEQCABQRRqIQQgAkH4AGogASABQRBqIgogAUEMaiILEAACQCACKAJ8IgZFDQAgCUEBdCEMIAJBtAFqIQ0gAkGAAWohCANAAkAgAkFAayIDIAhBCGopAgA3AwAgAkHIAGoiBSAIQRBqKQIANwMAIAJB0ABqIgcgCEEYaikCADcDACACIAgpAgA3AzggAigCeCEOIAIoAqABIQ8gAigCpAEhECACKAKoASERIAIoAqwBIRIgAigCsAEhEyACQRBqIA1BKBC+ARogAkHgAGoiFCADKQMANwMAIAJB6ABqIhUgBSkDADcDACACQfAAaiIWIAcpAwA3AwAgAiACKQM4NwNYIAJB+ABqIAJBEGpBKBC+ARogASgCHCIDIAEoAhhGBEACQAJAIANBAWoiBSADSQ0AIANBAXQiByAFIAcgBUsbIgetQuQAfiIXQiCIpw0AIBenIgVBAE4NAQsQ0gEACwJ/IANFBEAgBUEEENYBDAELIAQoAgAgA0HkAGxBBCAFENMBCyIDRQ0BIAEgBzYCGCABIAM2AhQgASgCHCEDCyABKAIUIANB5ABsaiIDIAY2AgQgAyAONgIAIAMgEjYCNCADIA82AiggA0EIaiACKQNYNwIAIANBEGogFCkDADcCACADQRhqIBUpAwA3AgAgA0EgaiAWKQMANwIAIANBOGogEzYCACADQTBqIBE2AgAgA0EsaiAQNgIAIANBPGogAkH4AGpBKBC+ARogASABKAIcQQFqIgM2AhwgAyAMTwRAIAJBCGogBBAKIAQgCRC6AQsgAkH4AGogASAKIAsQACACKAJ8IgYNAQwCCwsgBUEEQbThwgAoAgAiAEECIAAbEQAAAAsgAkEIaiAEEAogBCAJELoBIAEoAhwiBEEBdiIGBEAgASgCFCIDIARB5ABsakGcf2ohBANAIAJB+ABqIANB5AAQvgEaIAMgBEHkABCcAUHkAGohAyAEIAJB+ABqQeQAEL4BQZx/aiEEIAZBf2oiBg0ACwsgAUEBOgAlCwJAIAFBHGooAgAiAwRAIAEgA0F/aiIDNgIcIAAgASgCFCADQeQAbGpB5AAQvgEaDAELIABBADYCBAsgAkHgAWokAAvVBgIOfwJ+IwBBEGsiBiQAIAEoAgQiCEEUbCEDIAEoAgAhBQJAIAhFBEAMAQsgBUEIaiEEIAMhAgNAIAQoAgAgBEF8aigCAGsgB2ohByAEQRRqIQQgAkFsaiICDQALCwJAIAetQgx+IhBCIIinRQRAIBCnIgJBf0oEQAJAAkAgAkUEQEEEIQkMAQsgAkEEENYBIglFDQELIAhFBEBBACECDAQLIAMgBWohDSABQRRqKAIAIQwgASgCECEOQQAhAgNAAkACQAJAAkACQCAFKAIIIgEgBSgCBCIKTwRAIAwgAUkNASABIAprIghFDQVBASEEIAJBAXQhAyACQQxsIQEgDiAKQQJ0aiEKA0AgBkEANgIIIAZCADcCACAGIAQgCiAEEHUCfyAEQQNGBEAgCEUNBSAKQQRqIQogCEF/aiEIQQMMAQsgBEEBagshBCAGKAIAIg9BgIDEAEYNBiAGKQIEIRAgAiAHRgRAIAJBAWoiByACSQ0GIAMgByADIAdLGyIHrUIMfiIRQiCIpw0GIBGnIgtBAEgNBgJ/IAJFBEAgC0EEENYBDAELIAkgAUEEIAsQ0wELIglFDQULIAEgCWoiCyAPNgIAIAtBBGogEDcCACADQQJqIQMgAUEMaiEBIAJBAWohAiAIIARPDQALDAULIAogARCrAQALIAEgDBCpAQALQQFBABCrAQALIAtBBEG04cIAKAIAIgBBAiAAGxEAAAALENIBAAsgDSAFQRRqIgVHDQALDAMLIAJBBEG04cIAKAIAIgBBAiAAGxEAAAALENcBAAsQ1wEACyAJIAIgBkEAQSAgAmdrEAYCQCACQQJJBEAgAiEDDAELIAlBDGohAUEBIQNBASEEA0ACQCAEIANBf2oiBUYNACABIAkgBUEMbGpBDBC4AUUNACADIARHBEAgBkEIaiIIIAFBCGoiBSgCADYCACAGIAEpAgA3AwAgBSAJIANBDGxqIgVBCGoiCigCADYCACABIAUpAgA3AgAgCiAIKAIANgIAIAUgBikDADcCAAsgA0EBaiEDCyABQQxqIQEgAiAEQQFqIgRHDQALIAMgAk0NAEG1rcIAQRxB1K3CABC9AQALIAAgAzYCCCAAIAc2AgQgACAJNgIAIAZBEGokAAueBgENfyABKAIAIQcCf0EAIAEoAggiBEUNABogByAEQRRsaiEOAkACQANAIAciCCgCCCIJIAgoAgQiA08EQCAJIAEoAiAiBEsNAiABKAIYIQ0gCSADayEPIANBAnQhC0EAIQpBACEEAkAgAyAJRg0AQQUgCyANaiIHKAIAIAIQNiIDQf8BcUECRg0AIANBAXFFDQAgCUECdCALa0F8aiEDIAdBBGohBUEBIQYDQCAGIQQgA0UNAUEFIAUoAgAgAhA2IgdB/wFxQQJGDQEgBEEBaiEGIANBfGohAyAFQQRqIQUgB0EBcQ0ACwsgCEEUaiEHAkAgDyAEayIGRQ0AIAsgCUECdCIFayEDIAUgDWpBfGohBQNAIANFDQFBBSAFKAIAIAIQNiIJQf8BcUECRiAJQQFxRXINASADQQRqIQMgBUF8aiEFIAYgCkEBaiIKRw0ACwsgCCAIKAIEIARqNgIEIAggCCgCCCAKazYCCCAIIAgtABEgCkEAR3I6ABEgByAORw0BDAMLCyADIAkQqwEACyAJIAQQqQEACyABKAIAIQcgASgCCAshBiABQQA2AghBACEEQQAhAgJAA0AgBCAGTw0BIARBFGwhCSAEIAxqQRRsIQogByEFIAQhAwJAAkACQANAIAMgBk8NASAFIAlqIghBCGoiCygCACAIQQRqKAIARg0DIAIEQCADIAxqIgQgBk8NAyAFIApqIgQgCCkCADcCACAEQRBqIAhBEGooAgA2AgAgBEEIaiALKQIANwIACyAFQRRqIQUgA0EBaiIDIAZJDQALIAMhBAwEC0HUrsIAIAMgBhCqAQALQeSuwgAgBCAGEKoBAAsgA0EBaiEEIAxBf2ohDCACQQFqIQIgCEERai0AAEECRw0ACyADQQFqIQQLIAJFIAYgBE1yRQRAIAcgBEEUbGoiA0EAIAJrQRRsaiADIAYgBGtBFGwQnAEaCyABIAYgAmsiBDYCCCAEBEAgBkEUbCACQRRsayEFQQAhAwNAIAcgAzYCACAHQRRqIQcgA0EBaiEDIAVBbGoiBQ0ACwsgACABQTAQvgEaC6IHAQV/IABBeGoiASAAQXxqKAIAIgNBeHEiAGohAkGg4cIAAn8CQAJAAkACQCADQQFxDQAgA0EDcUUNASABKAIAIgMgAGohACABIANrIgFB+ODCACgCAEYEQCACKAIEQQNxQQNHDQFB8ODCACAANgIAIAIgAigCBEF+cTYCBCABIABBAXI2AgQgACABaiAANgIADwsgA0GAAk8EQCABEFkMAQsgASgCDCIEIAEoAggiBUcEQCAFIAQ2AgwgBCAFNgIIDAELQeDdwgBB4N3CACgCAEF+IANBA3Z3cTYCAAsCQCACKAIEIgNBAnEEQCACIANBfnE2AgQgASAAQQFyNgIEIAAgAWogADYCAAwBCwJAIAJB/ODCACgCAEcEQEH44MIAKAIAIAJHDQFB+ODCACABNgIAQfDgwgBB8ODCACgCACAAaiIANgIAIAEgAEEBcjYCBCAAIAFqIAA2AgAPC0H84MIAIAE2AgBB9ODCAEH04MIAKAIAIABqIgA2AgAgASAAQQFyNgIEQfjgwgAoAgAgAUYEQEHw4MIAQQA2AgBB+ODCAEEANgIAC0GY4cIAKAIAIgIgAE8NAkH84MIAKAIAIgBFDQICQEH04MIAKAIAIgNBKUkNAEGI4cIAIQEDQCABKAIAIgQgAE0EQCAEIAEoAgRqIABLDQILIAEoAggiAQ0ACwtBoOHCAAJ/Qf8fQZDhwgAoAgAiAEUNABpBACEBA0AgAUEBaiEBIAAoAggiAA0ACyABQf8fIAFB/x9LGws2AgAgAyACTQ0CQZjhwgBBfzYCAA8LIANBeHEiBCAAaiEAAkAgBEGAAk8EQCACEFkMAQsgAigCDCIEIAIoAggiAkcEQCACIAQ2AgwgBCACNgIIDAELQeDdwgBB4N3CACgCAEF+IANBA3Z3cTYCAAsgASAAQQFyNgIEIAAgAWogADYCACABQfjgwgAoAgBHDQBB8ODCACAANgIADAELIABBgAJJDQEgASAAEFFBoOHCAEGg4cIAKAIAQX9qIgA2AgAgAA0AQZDhwgAoAgAiAA0CQf8fDAMLDwsgAEEDdiICQQN0QejdwgBqIQACf0Hg3cIAKAIAIgNBASACQR9xdCICcQRAIAAoAggMAQtB4N3CACACIANyNgIAIAALIQIgACABNgIIIAIgATYCDCABIAA2AgwgASACNgIIDwtBACEBA0AgAUEBaiEBIAAoAggiAA0ACyABQf8fIAFB/x9LGws2AgAL4gYCD38BfiMAQaACayIBJAAgAUGYAWoQZCABQQE2ApgBQcivwgAhAgNAIAFBmAFqIAJBBGooAgAgAkEIaigCACACLQAAEGsgAkEMaiICQdC3wgBHDQALIAFBmAFqQQRyIQVB0LfCACECA0AgBSACQQRqKAIAIAItAAAQOyACQQhqIgJBoLnCAEcNAAsgAUEQaiABQZgBakGEARC+ARogASAANgKUASABIAFBlAFqNgKYASABQZgBakEEciABQRBqQYQBEL4BGiABQZgBahAYEMUBIgMEQCADKAIARQRAIANBfzYCACADQQRqIgkgASgClAEiBRBdRQRAIAFBCGpBChCiASABKAIMIQogASgCCCELIAEgBTYCmAFCpcaIocicp/lLIRBBASECIAUhAANAIBAgAK1C/wGDhUKzg4CAgCB+IRAgAkEERkUEQCABQZgBaiACaiACQQFqIQItAAAhAAwBCwsgEEIZiKciDUH/AHEiAEEIdCAAciIAQRB0IAByIQ4gAygCBCIHIBCncSECIAMoAgwhCCADKAIIIQACQANAAkAgACACaigAACIMIA5zIgRBf3MgBEH//ft3anFBgIGChHhxIgRFDQADQCAFIAggBGhBA3YgAmogB3EiD0EEdGooAgBHBEAgBEF/aiAEcSIEDQEMAgsLIAFBoAFqIAggD0EEdGoiACgCDDYCACAAQQA2AgwgACkCBCEQIAAgCjYCCCAAIAs2AgQgASAQNwOYAQwCCyAGQQRqIgYgAmogB3EhAiAMIAxBAXRxQYCBgoR4cUUNAAsgACAJIBAQmgEiAmotAABBAXEhBCADKAIQIgYgBEVyRQRAIAFBmAFqIAkQDiAJIBAQmgEhAiADKAIEIQcgAygCDCEIIAMoAhAhBiADKAIIIQALIAMgBiAEazYCECAAIAJqIA1B/wBxIgQ6AAAgByACQXxqcSAAakEEaiAEOgAAIAggAkEEdGoiAEEANgIMIAAgCjYCCCAAIAs2AgQgACAFNgIAIAMgAygCFEEBajYCFCABQQA2ApgBCyABQZgBahCoASADIAMoAgBBAWo2AgAgAUGgAmokAA8LENUBAAtBnr3CAEEQIAFBmAFqQcyowgAQmQEAC0HYvMIAQcYAIAFBmAFqQdyowgAQmQEAC4cGAQZ/AkACQAJAAkAgAkEJTwRAIAIgAxBEIgINAUEADwtBACECIANBzP97Sw0CQRAgA0ELakF4cSADQQtJGyEBIABBfGoiBigCACIHQXhxIQQCQAJAAkACQCAHQQNxBEAgAEF4aiIIIARqIQUgBCABTw0BQfzgwgAoAgAgBUYNAkH44MIAKAIAIAVGDQMgBSgCBCIHQQJxDQYgB0F4cSIJIARqIgQgAU8NBAwGCyABQYACSSAEIAFBBHJJciAEIAFrQYGACE9yDQUMBwsgBCABayICQRBJDQYgBiABIAdBAXFyQQJyNgIAIAEgCGoiASACQQNyNgIEIAUgBSgCBEEBcjYCBCABIAIQLQwGC0H04MIAKAIAIARqIgQgAU0NAyAGIAEgB0EBcXJBAnI2AgAgASAIaiICIAQgAWsiAUEBcjYCBEH04MIAIAE2AgBB/ODCACACNgIADAULQfDgwgAoAgAgBGoiBCABSQ0CAkAgBCABayIDQQ9NBEAgBiAHQQFxIARyQQJyNgIAIAQgCGoiASABKAIEQQFyNgIEQQAhAwwBCyAGIAEgB0EBcXJBAnI2AgAgASAIaiICIANBAXI2AgQgBCAIaiIBIAM2AgAgASABKAIEQX5xNgIEC0H44MIAIAI2AgBB8ODCACADNgIADAQLIAQgAWshAgJAIAlBgAJPBEAgBRBZDAELIAUoAgwiAyAFKAIIIgVHBEAgBSADNgIMIAMgBTYCCAwBC0Hg3cIAQeDdwgAoAgBBfiAHQQN2d3E2AgALIAJBEE8EQCAGIAEgBigCAEEBcXJBAnI2AgAgASAIaiIBIAJBA3I2AgQgBCAIaiIDIAMoAgRBAXI2AgQgASACEC0MBAsgBiAEIAYoAgBBAXFyQQJyNgIAIAQgCGoiASABKAIEQQFyNgIEDAMLIAIgACADIAEgASADSxsQvgEaIAAQHwwBCyADEAMiAUUNACABIAAgAyAGKAIAIgFBeHFBBEEIIAFBA3EbayIBIAEgA0sbEL4BIAAQHw8LIAIPCyAAC8EFAQt/IwBBIGsiBiQAAkACQAJAIAEgAkcEQCABQQFqIQMgASwAACIFQX9KDQECfyACIANGBEAgAgwBCyABLQABQT9xIQQgAUECaiIDCyEBIAVBH3EhByAFQf8BcUHfAU0EQCAEIAdBBnRyIQEMAwsCfyABIAJGBEAgAgwBCyABLQAAQT9xIQggAUEBaiIDCyEBIAggBEEGdHIhBCAFQf8BcUHwAUkEQCAEIAdBDHRyIQEMAwsgASACRgR/QQAFIAFBAWohAyABLQAAQT9xCyAHQRJ0QYCA8ABxIARBBnRyciIBQYCAxABHDQILIABBADYCCCAAQgQ3AgAMAgsgBUH/AXEhAQsgBkEIaiACQQNqIgwgA2tBAnZBAWoQowEgBigCDCEFIAYoAggiCCABNgIAIAZBATYCGCAGIAU2AhQgBiAINgIQAkAgAiADRg0AQQQhB0EBIQEDQCADQQFqIQUCQAJAIAMsAAAiBEF/TARAAn8gAiAFRgRAQQAhCSACDAELIAMtAAFBP3EhCSADQQJqIgULIQMgBEEfcSEKIARB/wFxIg1B3wFLDQEgCSAKQQZ0ciEEIAUhAwwCCyAEQf8BcSEEIAUhAwwBCwJ/IAIgA0YEQEEAIQsgAgwBCyADLQAAQT9xIQsgA0EBaiIFCyEEIAsgCUEGdHIhCSANQfABSQRAIAkgCkEMdHIhBCAFIQMMAQsCfyACIARGBEAgBSEDQQAMAQsgBEEBaiEDIAQtAABBP3ELIApBEnRBgIDwAHEgCUEGdHJyIgRBgIDEAEYNAgsgBigCFCABRgRAIAZBEGogASAMIANrQQJ2QQFqEIMBIAYoAhAhCAsgByAIaiAENgIAIAYgAUEBaiIBNgIYIAdBBGohByACIANHDQALCyAAIAYpAxA3AgAgAEEIaiAGQRhqKAIANgIACyAGQSBqJAALhAUBEH8jAEHwAGsiCSQAIABBiAJqIQ0gCUHIAGohDiABQTJJIQ9BASEEAkADQAJAIAQgAU8EQEEAIQcMAQsgACAEQeQAbGohA0EBIQcDQEEAIQICQANAIAJBBGoiCEEoRg0BIAIgA2ohBSAIIQJBfyAFQVxqKAIAIgggBUFAaygCACIFRyAIIAVIGyIIRQ0ACyAIQf8BcUH/AUYNAgsgA0HkAGohA0EBIQIgBEEBaiIEIAFJIQcgASAERw0ACwwCCyABIARGIQIgDyABIARGcg0BAkACQCAEQX9qIgIgAUkEQCAHQQFxBEAgCkEBaiEKIAlBCGogACACQeQAbGoiAkHkABC+ARogAiAAIARB5ABsIgdqIgJB5AAQvgEaIAIgCUEIakHkABC+ASEFIAAgBBBIIAEgBGsiDEECSQ0DIAVB5ABqIQhBwAAhAgNAIAJBBGoiA0HoAEYNBCACIAVqIQYgAyECQX8gBigCACIDIAZB5ABqKAIAIgZHIAMgBkgbIgNFDQALIANB/wFxQf8BRw0DIAlBCGogBUHkABC+ARogBSAIQeQAEL4BIRAgDEEDSQ0CIAcgDWohB0ECIQIDQCACQQFqIBAgAkHkAGxqIQNBACECA0AgAkEEaiIGQShGDQQgAiAHaiELIAIgDmohESAGIQJBfyARKAIAIgYgCygCACILRyAGIAtIGyIGRQ0ACyAGQf8BcUH/AUcNAyADQZx/aiADQeQAEL4BGiAHQeQAaiEHIAMhCCICIAxHDQALDAILQcCnwgAgBCABEKoBAAtBsKfCACACIAEQqgEACyAIIAlBCGpB5AAQvgEaCyAKQQVHDQALQQAhAgsgCUHwAGokACACC6UFAQZ/IAAoAgAiCUEBcSIKIARqIQgCQCAJQQRxRQRAQQAhAQwBCyACBEAgAiEHIAEhBQNAIAYgBS0AAEHAAXFBgAFGaiEGIAVBAWohBSAHQX9qIgcNAAsLIAIgCGogBmshCAtBK0GAgMQAIAobIQYCQAJAIAAoAghBAUcEQEEBIQUgACAGIAEgAhC5AQ0BDAILIABBDGooAgAiByAITQRAQQEhBSAAIAYgASACELkBDQEMAgsCQCAJQQhxRQRAIAcgCGshB0EAIQUCQAJAAkBBASAALQAwIgggCEEDRhtBAWsOAwABAAILIAchBUEAIQcMAQsgB0EBdiEFIAdBAWpBAXYhBwsgBUEBaiEFA0AgBUF/aiIFRQ0CIAAoAhggACgCBCAAKAIcKAIQEQEARQ0AC0EBDwtBASEFIABBAToAMCAAQTA2AgQgACAGIAEgAhC5AQ0BIAcgCGshBkEAIQUCQAJAAkBBASAALQAwIgEgAUEDRhtBAWsOAwABAAILIAYhBUEAIQYMAQsgBkEBdiEFIAZBAWpBAXYhBgsgBUEBaiEFAkADQCAFQX9qIgVFDQEgACgCGCAAKAIEIAAoAhwoAhARAQBFDQALQQEPCyAAKAIEIQFBASEFIAAoAhggAyAEIAAoAhwoAgwRAwANASAGQQFqIQYgACgCHCECIAAoAhghAANAIAZBf2oiBkUEQEEADwsgACABIAIoAhARAQBFDQALDAELIAAoAgQhCEEBIQUgACAGIAEgAhC5AQ0AIAAoAhggAyAEIAAoAhwoAgwRAwANACAHQQFqIQYgACgCHCEBIAAoAhghAANAIAZBf2oiBkUEQEEADwsgACAIIAEoAhARAQBFDQALCyAFDwsgACgCGCADIAQgAEEcaigCACgCDBEDAAuVBQINfwJ+IwBB0ABrIgMkAAJAIAEoAggiBK1CFH4iEEIgiKdFBEAgEKciBUF/SgRAAkACQCAFRQRAQQQhCAwBCyAFQQQQ1gEiCEUNAQsgASgCACEKIARFBEBBACEFQQAhBAwECyAKIARBFGxqIQ0gAUEgaigCACEOIAEoAhghD0EAIQUgCiEJA0AgCSgCACEHIANBADYCGCADIAc2AhQgA0G0qsIANgIQIAMgDjYCDCADIA82AgggAyACNgIEIAMgCTYCACADQThqIAMQJwJAIAMtAElBAkYNAAJAA0ACQCADQTBqIANByABqIgsoAgAiBzYCACADQShqIANBQGsiDCkDACIQNwMAIAMgAykDOCIRNwMgIAsgBzYCACAMIBA3AwAgAyARNwM4AkAgBCAGRwRAIAYhBQwBCyAEQQFqIgYgBEkNAyAEQQF0IgcgBiAHIAZLGyIGrUIUfiIQQiCIpw0DIBCnIgdBAEgNAwJ/IARFBEAgB0EEENYBDAELIAggBEEUbEEEIAcQ0wELIghFDQEgBiEECyAIIAVBFGxqIgYgAykDODcCACAGQRBqIAsoAgA2AgAgBkEIaiAMKQMANwIAIANBOGogAxAnIAVBAWoiBSEGIAMtAElBAkcNAQwDCwsgB0EEQbThwgAoAgAiAEECIAAbEQAAAAsQ0gEACyANIAlBFGoiCUcNAAsMAwsgBUEEQbThwgAoAgAiAEECIAAbEQAAAAsQ1wEACxDXAQALIAEoAgQEQCAKEB8LIAEgBTYCCCABIAQ2AgQgASAINgIAIAUEQCAFQRRsIQVBACEEA0AgCCAENgIAIAhBFGohCCAEQQFqIQQgBUFsaiIFDQALCyAAIAFBMBC+ARogA0HQAGokAAvABQIIfwR+IwBBsAFrIgAkAAJAQejcwgAoAgANACAAQRBqQeQDQQEQmAEgACkDECEIIABBzABqIgFB5AM2AgAgACAINwJEIABBFjYCQCAAQUBrEH4gAEE4aiIEIABByABqKQMANwMAIAAgACkDQDcDMCAAQUBrQRQQUiAAQSBqIgMgASkCADcDACAAQShqIgUgAEHUAGoiBigCADYCACAAIAApAkQ3AxggAEEIakEUQQAQmAEgACkDCCEIIABBFEEAEJgBIAApAwAhCSABIAQpAwA3AgAgBkEANgIAIABB2ABqIAApAxg3AwAgAEHgAGogAykDADcDACAAQegAaiAFKAIANgIAIABBiAFqQQA2AgAgAEGAAWoiBCAJNwMAIABB+ABqQgA3AwAgAEHwAGogCDcDACAAQewAakEANgIAIABBADYCQCAAIAApAzA3AkQDQCAAQUBrIAJqIgEpAgAhCCABIAJB4NzCAGoiAykCADcCACACQejcwgBqIgUpAgAhCSACQfDcwgBqIgYpAgAhCiACQfjcwgBqIgcpAgAhCyADIAg3AgAgAUEYaiIDKQIAIQggAyALNwIAIAFBEGoiAykCACELIAMgCjcCACABQQhqIgEpAgAhCiABIAk3AgAgByAINwIAIAYgCzcCACAFIAo3AgAgAkFAayACQSBqIQJBzQBJDQALIAQpAgAhCCAEQaDdwgApAgA3AgBBoN3CACAINwIAIARBCGoiAigCACEBIAJBqN3CACgCADYCAEGo3cIAIAE2AgAgACgCSCIBRQ0AIABB3ABqKAIAIQQgACgCWCECIAAoAnAhAyAAKAJ0IAAoAoQBIQYgACgCgAEhByAAKAJMBEAgARAfCyACBEAgAEFAayACQQFqEKUBIAQQHwsEQCADEB8LIAZFDQAgBxAfCyAAQbABaiQAQeDcwgAL/QQBDH8CQAJAAkACQCABKAIAIgRBCGooAgAiAiAEKAIEIgRPBEAgAUEMaigCACIDIAJJDQFBAiEDIAEoAhgiBiACIARrIghPDQQCQCABKAIIIgUgBEECdGoiCiAGQQJ0aiIDIAUgAkECdGoiCUYEQEEAIQUMAQtBACEFA0AgA0EEaiEGIAEoAgQhByABKAIQIQsgAygCACEMQQAhA0EAIQQCfwNAQQEhAiADIAtqLQAAIAwgBxA2Ig1B/wFxQQJHBEAgBCECQQEgDUEBcQ0CGgsgAiEEIANBAWoiA0EDRw0AC0ECQQAgAkEBcRsLIgdBAXFFIAdBAkZyRQRAIAVBAWohBSAGIgMgCUcNAQsLIAEoAhghBgsgASAFIAZqIgI2AhggCCACSQ0CQQIhAyAKIAJBAnRqIgYgCUYNBEEAIQUDQCAGIgJBBGohBiABKAIEIQggASgCECEKIAIoAgAhB0EAIQNBACEEAn8DQEEBIQIgAyAKai0AACAHIAgQNiILQf8BcUECRwRAIAQhAkEBIAtBAXENAhoLIAIhBCADQQFqIgNBA0cNAAtBAkEAIAJBAXEbCyIHQQJHQQAgB0EBcRtFBEAgBUEBaiEFIAYgCUcNAQwFCwsgBQ0DQQIhAwwECyAEIAIQqwEACyACIAMQqQEACyACIAgQqwEACyABKAIYIgMgBWohAiADIAEoAgAiBCgCBCIHaiIJIAVqIQggASgCFCEGQQEhAyAELQARRQRAIAIgBEEIaigCACAHa0khAwsgASACNgIYIABBCjoAECAAIAU2AgwgACAINgIIIAAgCTYCBCAAIAY2AgAgASAGQQFqNgIUCyAAIAM6ABELiQUCC38DfiMAQUBqIgYkACAGIAI2AgxCpcaIocicp/lLIQ9BASEFIAIhBANAIA8gBK1C/wGDhUKzg4CAgCB+IQ8gBUEERkUEQCAGQQxqIAVqIAVBAWohBS0AACEEDAELCyAPQhmIpyIMQf8AcSIEQQh0IARyIgRBEHQgBHIhDSABKAIAIgggD6dxIQUgASgCCCEJIAEoAgQhBAJAA0ACQCAEIAVqKAAAIgsgDXMiB0F/cyAHQf/9+3dqcUGAgYKEeHEiB0UNAANAIAIgCSAHaEEDdiAFaiAIcSIOQShsaigCAEcEQCAHQX9qIAdxIgcNAQwCCwsgCSAOQShsaiIBKQMIIQ8gASADKQMANwMIIAAgDzcDACABQSBqIgIpAwAhDyACIANBGGopAwA3AwAgAUEYaiICKQMAIRAgAiADKQMQNwMAIAFBEGoiASkDACERIAEgAykDCDcDACAAQRhqIA83AwAgACAQNwMQIAAgETcDCAwCCyAKQQRqIgogBWogCHEhBSALIAtBAXRxQYCBgoR4cUUNAAsgBkEoaiADQRhqKQIANwIAIAZBIGogA0EQaikCADcCACAGQRhqIANBCGopAgA3AgAgBiADKQIANwIQIAQgASAPEJoBIgVqLQAAQQFxIQMgASgCDCIHIANFckUEQCAGQTBqIAEQESABIA8QmgEhBSABKAIAIQggASgCCCEJIAEoAgwhByABKAIEIQQLIAEgByADazYCDCAEIAVqIAxB/wBxIgM6AAAgCCAFQXxqcSAEakEEaiADOgAAIAkgBUEobGoiAyACNgIAIANBBGogBkEMakEkEL4BGiABIAEoAhBBAWo2AhAgAEECOgAcCyAGQUBrJAAL2QQBEX8jAEEQayIIJAACQCABKAIEIgJFBEAgAEEANgIADAELIAEgAkF/ajYCBCABIAEoAgAiBEEEajYCACABKAIIIQcCQAJAAkACQAJ/IAIgByAHIAJLGyABKAIQIgVFDQAaA0AgAiAGaiIDQQFGDQIgASAEQQhqNgIAIAEgBSAGakF/ajYCECABIANBfmo2AgQgBEEEaiEEIAUgBkF/aiIGag0ACyACIAZqIgIgByAHIAJLGwsiB0EBaiIGQQJPBEADQCAHIAZBf2oiAkkNBSABKAIMIQkgCEKlxoihyJyn+Us3AwggBCACIAhBCGoQcyAIKAIIIgVBGXYiA0EIdCADciIDQRB0IANyIQ8gAkECdCEQIAkoAgAiCyAFcSEFIAkoAgQhEUEAIQwDQCAFIBFqKAAAIg0gD3MiA0F/cyADQf/9+3dqcUGAgYKEeHEiAwRAIAkoAgghDgNAIA4gA2hBA3YgBWogC3EiEkEYbGoiCigCCCACRgRAIAQgCigCACIKRg0HIAQgCiAQELgBRQ0HCyADQX9qIANxIgMNAAsLIAxBBGoiDCAFaiALcSEFIA0gDUEBdHFBgIGChHhxRQ0ACyACIQYgAkEBSw0ACwsgB0UNAiAAQQE2AgQgACAENgIAIABBDGpBATYCACAAQQhqIAQ2AgAMBAsgAEEANgIADAMLIAEgBkF+ajYCECAAIAI2AgQgACAENgIAIABBDGogDiASQRhsaiIBKAIUNgIAIABBCGogASgCDDYCAAwCC0EBQQAQqQEACyACIAcQqQEACyAIQRBqJAALwQQCDX8BfiMAQSBrIgckACABQX9qIQwgAUEySSENQQEhAwJAAkACfwNAAn9BACADIAFPDQAaQQEgACADQQxsaiIEQQMgBEF0akEDEJcBQf8BcUH/AUYNABoDQEEBIAMgDEYNAxogA0EBaiEDIARBDGoiAkEDIARBAxCXASACIQRB/wFxQf8BRw0ACyADIAFJCyABIANGIA0gASADRnINARogA0F/aiICIAFPDQJFDQMgCkEBaiEKIAAgA0EMbGoiBSkCACEPIAUgACACQQxsaiICKQIANwIAIAIgDzcCACAFQQhqIgYoAgAhBCAGIAJBCGoiAigCADYCACACIAQ2AgAgACADEE4CQCABIANrIgtBAkkNACAFQQxqIghBAyAFQQMQlwFB/wFxQf8BRw0AIAUpAgAhDyAFIAgpAgA3AgAgB0EYaiIOIAYoAgA2AgAgBiAIQQhqKAIANgIAIAcgDzcDEAJAIAtBA0kNACAFQRhqIglBAyAHQRBqQQMQlwFB/wFxQf8BRw0AQQMhAkECIQYDQCAGQQxsIAVqIghBdGoiBCAJKQIANwIAIARBCGogCUEIaigCADYCACACIAtPDQEgAkEBaiIEIAJJDQEgAkEMbCEJIAIhBiAEIQIgBSAJaiIJQQMgB0EQakEDEJcBQf8BcUH/AUYNAAsLIAggBykDEDcCACAIQQhqIA4oAgA2AgALIApBBUcNAAtBAAsgB0EgaiQADwtBsKfCACACIAEQqgEAC0HAp8IAIAMgARCqAQALpQQBFH8CQCACQQF1IgggAkkEQEEAIAAoAhhrIRQgACgCECISIAAoAgRqQX9qIQkgAEEMaiAAQQhqIAAoAgBBAUYbIRUgAiERA0ACQAJAIAEgCEEYbGoiFigCBCIHIARrRQ0AIBQgBGshF0EAIARrIQsgByEMIAkhDSASIQ4gBCEFAkACQAJAA0BBfyEPIA4gF2pFDQMgCyAOakF/aiAVKAIAIg9PDQEgCyAMakF/aiAHTw0CIAsgDWotAAAgFigCACALaiAMakF/ai0AAGsiDw0DIA1Bf2ohDSAOQX9qIQ4gBUEBaiEFIAsgDEF/aiIMag0ACyAFIQQMAwtBwL/CACAEQX9zIA5qIA8QqgEAC0HQv8IAIARBf3MgDGogBxCqAQALIAUhBAwBC0EAIQ8LIAogBCAPQQBIIgcbIQoCQCAIIBEgBxsiESAGIAggBxsiBmsiDUECTg0AIBMgBiARRiAGQQBKcnJBASETQQFxRQ0AAn8DQCAGIAJPDQUCQCAKIAEgBkEYbGoiCSgCBCIFTwRAIAAgEiAFayIENgIQIAkoAhAiBUUNASAFIAAgAyAJQRRqKAIAKAIMEQMAIAAgBDYCEA0BC0EAIAkoAggiBkEASA0CGgwBCwsgASAGQRhsaigCDAsPCyAKIAQgECAHGyIQIAogEEkbIQQgDUEBdSAGaiIIIAJJDQALC0Gwv8IAIAggAhCqAQALQeC/wgAgBiACEKoBAAvPBQIEfwF+QQEgASgCGEEnIAFBHGooAgAoAhARAQBFBEBBAiECAkACQAJAIAAoAgAiAEF3aiIEQR5LBEAgAEHcAEcNAQwCC0H0ACEDAkACQCAEQQFrDh4BAgIAAgICAgICAgICAgICAgICAgICAgIDAgICAgMEC0HyACEDDAMLQe4AIQMMAgsCQAJ+QYCGwAAgABBaRQRAAkACQCAAQYCABE8EQCAAQYCACEkNASAAQZD8R2pBkPwLSSAAQeKLdGpB4o0sSXIgAEGfqHRqQZ8YSSAAQd7idGpBDklyciAAQf7//wBxQZ7wCkYgAEGpsnVqQSlJcnINAiAAQcuRdWpBCksNBQwCCyAAQfiRwABBKUHKksAAQaUCQe+UwABBugIQSUUNAQwECyAAQamXwABBI0Hvl8AAQaYBQZWZwABBmAMQSQ0DCyAAQQFyZ0ECdkEHc61CgICAgNAAhAwBCyAAQQFyZ0ECdkEHc61CgICAgNAAhAshBkEDIQIMAQtBASECCyAAIQMLA0AgAiEEQdwAIQBBASECAkACQAJAAkAgBEEBaw4DAgMAAQsCQAJAAkACQAJAIAZCIIinQf8BcUEBaw4FBAMCAQAFCyAGQv////+PYINCgICAgMAAhCEGQQMhAgwGCyAGQv////+PYINCgICAgDCEIQZB9QAhAEEDIQIMBQsgBkL/////j2CDQoCAgIAghCEGQfsAIQBBAyECDAQLIAMgBqciAkECdEEccXZBD3EiAEEwciAAQdcAaiAAQQpJGyEAIAIEQCAGQn98Qv////8PgyAGQoCAgIBwg4QhBkEDIQIMBAsgBkL/////j2CDQoCAgIAQhCEGQQMhAgwDCyAGQv////+PYIMhBkH9ACEAQQMhAgwCCyABKAIYQScgASgCHCgCEBEBAA8LQQAhAiADIQALIAEoAhggACABKAIcKAIQEQEARQ0ACwsLhwUBBH8gACABaiECAkACQAJAIAAoAgQiA0EBcQ0AIANBA3FFDQEgACgCACIDIAFqIQEgACADayIAQfjgwgAoAgBGBEAgAigCBEEDcUEDRw0BQfDgwgAgATYCACACIAIoAgRBfnE2AgQgACABQQFyNgIEIAIgATYCAA8LIANBgAJPBEAgABBZDAELIAAoAgwiBCAAKAIIIgVHBEAgBSAENgIMIAQgBTYCCAwBC0Hg3cIAQeDdwgAoAgBBfiADQQN2d3E2AgALIAIoAgQiA0ECcQRAIAIgA0F+cTYCBCAAIAFBAXI2AgQgACABaiABNgIADAILAkAgAkH84MIAKAIARwRAQfjgwgAoAgAgAkcNAUH44MIAIAA2AgBB8ODCAEHw4MIAKAIAIAFqIgE2AgAgACABQQFyNgIEIAAgAWogATYCAA8LQfzgwgAgADYCAEH04MIAQfTgwgAoAgAgAWoiATYCACAAIAFBAXI2AgQgAEH44MIAKAIARw0BQfDgwgBBADYCAEH44MIAQQA2AgAPCyADQXhxIgQgAWohAQJAIARBgAJPBEAgAhBZDAELIAIoAgwiBCACKAIIIgJHBEAgAiAENgIMIAQgAjYCCAwBC0Hg3cIAQeDdwgAoAgBBfiADQQN2d3E2AgALIAAgAUEBcjYCBCAAIAFqIAE2AgAgAEH44MIAKAIARw0BQfDgwgAgATYCAAsPCyABQYACTwRAIAAgARBRDwsgAUEDdiICQQN0QejdwgBqIQECf0Hg3cIAKAIAIgNBASACQR9xdCICcQRAIAEoAggMAQtB4N3CACACIANyNgIAIAELIQIgASAANgIIIAIgADYCDCAAIAE2AgwgACACNgIIC80EAgl/AX4jAEEQayIEJAAgBCABNgIAIAEoAhghAyAEIAEtABw6AAcgBCAENgIMIAQgBEEHajYCCAJAAkAgAS0AHUUEQCABQQxqIQggA0EBdCEJA0AgASgCBCEFIAEoAgAhAgJAA0AgAiAFRg0BIAEgAkEEaiIHNgIAIAIoAgAgASABKAIIIgpBAWo2AgggByECRQ0ACyABKAIUIgIgASgCEEYEQCACQQFqIgUgAkkNBSACQQF0IgYgBSAGIAVLGyIFQf////8BcSAFRw0FIAVBA3QiBkEASA0FAn8gAkUEQCAGQQQQ1gEMAQsgCCgCACACQQN0QQQgBhDTAQsiAkUNBCABIAU2AhAgASACNgIMIAEoAhQhAgsgASgCDCACQQN0aiICIAdBfGo2AgQgAiAKNgIAIAEgASgCFEEBaiICNgIUIAIgCUkNASAEQQhqIAgQCyABKAIUIANJDQEgASADNgIUDAELCyAEQQhqIAgQCyABKAIUIgIgA08EQCABIAM2AhQgAyECCyACQQF2IgcEQCACQQN0IAgoAgAiAmpBeGohAwNAIAIpAgAhCyACIAMpAgA3AgAgAyALNwIAIAJBCGohAiADQXhqIQMgB0F/aiIHDQALCyABQQE6AB0LAkAgAUEUaigCACICRQRAQQAhAgwBCyABIAJBf2oiAjYCFCABKAIMIAJBA3RqIgEoAgQhAiABKAIAIQELIAAgAjYCBCAAIAE2AgAgBEEQaiQADwsgBkEEQbThwgAoAgAiAEECIAAbEQAAAAsQ0gEAC5gEAQh/IwBBEGsiAyQAIAMgADYCACAAKAIUIQIgAyAALQAYOgAHIAMgAzYCDCADIANBB2o2AggCQAJAIAAtABlFBEAgAEEIaiEHIAAoAgAiBCAAKAIERwRAIAJBAXQhCCAAQRBqKAIAIQEDQCAAIARBPGo2AgAgACgCDCABRgRAIAFBAWoiBiABSQ0FIAFBAXQiBSAGIAUgBksbIgZB/////wNxIAZHDQUgBkECdCIFQQBIDQUCfyABRQRAIAVBBBDWAQwBCyAHKAIAIAFBAnRBBCAFENMBCyIBRQ0EIAAgBjYCDCAAIAE2AgggACgCECEBCyAAKAIIIAFBAnRqIAQ2AgAgACAAKAIQQQFqIgE2AhACQCABIAhJDQAgA0EIaiAHEA0gACgCECIBIAJJDQAgACACNgIQIAIhAQsgACgCACIEIAAoAgRHDQALCyADQQhqIAcQDSAAQRBqKAIAIgEgAk8EQCAAIAI2AhAgAiEBCyABQQF2IgUEQCABQQJ0IAcoAgAiAWpBfGohBANAIAEoAgAhAiABIAQoAgA2AgAgBCACNgIAIAFBBGohASAEQXxqIQQgBUF/aiIFDQALCyAAQQE6ABkLAn9BACAAQRBqKAIAIgJFDQAaIAAgAkF/aiICNgIQIAAoAgggAkECdGooAgALIANBEGokAA8LIAVBBEG04cIAKAIAIgBBAiAAGxEAAAALENIBAAubBAIPfwF+IwBBIGsiAyQAIANCpcaIocicp/lLNwMQIAEoAgAiByABKAIIIgsgA0EQahBzIAMpAxAiEkIZiKciDUH/AHEiBEEIdCAEciIEQRB0IARyIQ4gC0ECdCEPIAAoAgAiCCASp3EhBSAAKAIIIQkgACgCBCEEAkADQAJAIAQgBWooAAAiDCAOcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAJIAZoQQN2IAVqIAhxIhBBBHRqIgooAgggC0YEQCAHIAooAgAiCkYNASAHIAogDxC4AUUNAQsgBkF/aiAGcSIGDQEMAgsLIAkgEEEEdGogAjoADCABKAIERQ0CIAcQHwwCCyARQQRqIhEgBWogCHEhBSAMIAxBAXRxQYCBgoR4cUUNAAsgA0EIaiIGIAFBCGooAgA2AgAgAyABKQIANwMAIAQgACASEJoBIgFqLQAAQQFxIQUgACAAKAIMIgcgBUVyBH8gBwUgA0EQaiAAEBIgACASEJoBIQEgACgCACEIIAAoAgghCSAAKAIEIQQgACgCDAsgBWs2AgwgASAEaiANQf8AcSIFOgAAIAggAUF8anEgBGpBBGogBToAACAJIAFBBHRqIgFBCGogBigCADYCACABIAMpAwA3AgAgASACOgAMIAEgAy8AEDsADSABQQ9qIANBEmotAAA6AAAgACAAKAIQQQFqNgIQCyADQSBqJAAL9wMBB38jAEEgayIHJAACQAJAIAAoAhAgACgCGE0NACAAEJMBIAAoAhAhBiAAKAIEIQUgByAAQQxqIABBCGogACgCAEEBRhsoAgAiCDYCBCAHIAU2AgAgByAGNgIIIAcgCDYCDAJAIAZFIAYgCEZyRQRAIAggBk0NASAFIAZqLAAAQb9/TA0BCyAGIAhGDQECQCAFIAZqIgYsAAAiCUF/SgRAIAlB/wFxIQUMAQsCfyAFIAhqIgUgBkEBakYEQCAFDAELIAYtAAFBP3EhCiAGQQJqCyEIIAlBH3EhCyAJQf8BcUHfAU0EQCAKIAtBBnRyIQUMAQsCfyAFIAhGBEAgBSEGQQAMAQsgCEEBaiEGIAgtAABBP3ELIApBBnRyIQggCUH/AXFB8AFJBEAgCCALQQx0ciEFDAELQQAhCUEAIQogBSAGRwR/IAYtAABBP3EFIAoLIAtBEnRBgIDwAHEgCEEGdHJyIgVBgIDEAEYNAwsgABCRASAFIANJIAUgBEtyDQEgBSADayIDQQN2IgQgAkkEQEEAIQkgASAEai0AAEEBIANBB3F0cUUNAyAAEJMBQQEhCQwDC0HQvsIAIAQgAhCqAQALIAcgB0EMajYCGCAHIAdBCGo2AhQgByAHNgIQIAdBEGoQywEAC0EAIQkLIAdBIGokACAJC+8DAQd/IwBBIGsiBSQAAkACQAJAIAAoAhAgACgCGE0NACAAEJMBIAAoAhAhBCAAKAIEIQYgBSAAQQxqIABBCGogACgCAEEBRhsoAgAiBzYCBCAFIAY2AgAgBSAENgIIIAUgBzYCDCAERSAEIAdGckUEQCAHIARNDQMgBCAGaiwAAEG/f0wNAwsgBCAHRg0AAkAgBCAGaiIILAAAIgpBf0oEQCAKQf8BcSEEDAELAn8gBiAHaiIEIAhBAWpGBEAgBAwBCyAILQABQT9xIQkgCEECagshBiAKQR9xIQggCkH/AXFB3wFNBEAgCSAIQQZ0ciEEDAELAn8gBCAGRgRAIAQhB0EADAELIAZBAWohByAGLQAAQT9xCyAJQQZ0ciEGIApB/wFxQfABSQRAIAYgCEEMdHIhBAwBC0EAIQkgBCAHRwR/IActAABBP3EFQQALIAhBEnRBgIDwAHEgBkEGdHJyIgRBgIDEAEYNAQsgABCRASAEIANJIARB+QBLckUEQCAEIANrIgRBA3YiAyACTw0CQQAhCSABIANqLQAAQQEgBEEHcXRxDQELIAAQkwFBASEJCyAFQSBqJAAgCQ8LQfC+wgAgAyACEKoBAAsgBSAFQQxqNgIYIAUgBUEIajYCFCAFIAU2AhAgBUEQahDLAQAL6gMBB38jAEEgayIDJAACQAJAIAAoAhAiASAAKAIUTw0AIAAoAgQhAiADIABBDGogAEEIaiAAKAIAQQFGGygCACIENgIEIAMgAjYCACADIAE2AgggAyAENgIMAkAgAUUgASAERnJFBEAgBCABTQ0BIAEgAmosAABBv39MDQELIAEgBEYNAQJAIAEgAmoiBSwAACIHQX9KBEAgB0H/AXEhAQwBCwJ/IAIgBGoiASAFQQFqRgRAIAEMAQsgBS0AAUE/cSEGIAVBAmoLIQIgB0EfcSEFIAdB/wFxQd8BTQRAIAYgBUEGdHIhAQwBCwJ/IAEgAkYEQCABIQRBAAwBCyACQQFqIQQgAi0AAEE/cQsgBkEGdHIhBiAHQf8BcUHwAUkEQCAGIAVBDHRyIQEMAQtBACECIAEgBEcEfyAELQAAQT9xBUEACyAFQRJ0QYCA8ABxIAZBBnRyciIBQYCAxABGDQMLIAFB4QBJIAFB+QBLcg0BIAFB4QBrIgRBA3YiAUEESQRAQQAhAiABQYjFwgBqLQAAQQEgBEEHcXRxRQ0DIAAQkQFBASECDAMLQdC9wgAgAUEEEKoBAAsgAyADQQxqNgIYIAMgA0EIajYCFCADIAM2AhAgA0EQahDLAQALQQAhAgsgA0EgaiQAIAILgAQBBn8jAEEwayIFJAAgBUEIaiAAQQxqIgcgAEEIaiIIIAAoAgBBAUYbKAIAELQBIAVBADYCGCAFIAUpAwg3AxAgACgCACEJIAAoAgQhBgJAAkACQCABRQ0AIAcgCCAJQQFGGygCACIKIAFGDQAgCiABTQ0BIAEgBmosAABBv39MDQELIAJFIAcgCCAJGygCACIHIAJGckUEQCAHIAJNDQIgAiAGaiwAAEG/f0wNAgsgBUEQakEAIAEQkgEgBSAFKAIYIgggAWoiCTYCGCAIIAUoAhBqIAYgARC+ARogBUEQaiAJIAQQkgEgBSAFKAIYIgggBGoiCTYCGCAIIAUoAhBqIAMgBBC+ARogBUEQaiAJIAYgB2ogAiAGaiIGayIDEJIBIAUgBSgCGCIHIANqNgIYIAcgBSgCEGogBiADEL4BGiAAIAEgAmsgBGoiAyAAKAIUajYCFAJAAkAgACgCECIEIAJJBEAgBCABSw0BDAILIAMgBGohAQsgACABNgIQCyAAQQRqIQEgBUEoaiICIAVBGGooAgA2AgAgBSAFKQMQNwMgAkAgACgCAEUNACAAQQhqKAIARQ0AIAEoAgAQHwsgAEEBNgIAIAEgBSkDIDcCACABQQhqIAIoAgA2AgAgBUEwaiQAIAMPCyAGIApBACABEBkACyAGIAdBACACEBkAC+UDAQd/IwBBIGsiAiQAAkACQAJAIAAoAhAiASAAKAIUTw0AIAAoAgQhAyACIABBDGogAEEIaiAAKAIAQQFGGygCACIENgIEIAIgAzYCACACIAE2AgggAiAENgIMIAFFIAEgBEZyRQRAIAQgAU0NAyABIANqLAAAQb9/TA0DCyABIARGDQACQCABIANqIgUsAAAiB0F/SgRAIAdB/wFxIQEMAQsCfyADIARqIgEgBUEBakYEQCABDAELIAUtAAFBP3EhBiAFQQJqCyEDIAdBH3EhBSAHQf8BcUHfAU0EQCAGIAVBBnRyIQEMAQsCfyABIANGBEAgASEEQQAMAQsgA0EBaiEEIAMtAABBP3ELIAZBBnRyIQMgB0H/AXFB8AFJBEAgAyAFQQx0ciEBDAELQQAhBiABIARHBH8gBC0AAEE/cQVBAAsgBUESdEGAgPAAcSADQQZ0cnIiAUGAgMQARg0BCyABQeEASSABQfkAS3JFBEAgAUHhAGsiBEEDdiIBQQRPDQJBACEGIAFBiMXCAGotAABBASAEQQdxdHENAQsgABCRAUEBIQYLIAJBIGokACAGDwtB4L7CACABQQQQqgEACyACIAJBDGo2AhggAiACQQhqNgIUIAIgAjYCECACQRBqEMsBAAuTBAEBf0EBIQMCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCAAQf8BcUEBaw4HAAECAwQFBgwLQQAhAyABQb8BSw0LIAFBBnZB0KzAAGotAAAiAEEBTQRAIABBA3RB2KzAAGopAwBCASABQT9xrYaDQgBSDwtBkKzAACAAQQIQqgEACyABQXdqQQVJDQkgAUEgRg0KQQAhAyABQYABSSABQb/gAEtyDQogAUEGdkHNqsAAai0AACIAQQVNBEAgAEEDdEGgrMAAaikDAEIBIAFBP3GthoNCAFIPC0GQrMAAIABBBhCqAQALQQAhAyABQZDAAEwEQCABQV9qIgBBDU0NBSABQUZqIgBBBUsNCiAAQQJrDgMKCgoJCyABQe+/f2pBBEkNCCABQcS/f2oiAEENS0EBIAB0QYHwAHFFcg0HDAgLQQAhAyABQV9xQb9/akEaSQ0IQQEhAyABQYABSQ0IQdjBwAAgARBaQQFzDwtBACEDIAFBX3FBv39qQRpJDQcgAUGAAUkNA0HYwcAAIAEQWg0HQQEhA0HorMAAIAEQWkUNBwwEC0ECIQMgAiABEFdB/wFxIgBBCEYNBiAAQQZGDwtBAiEDIAIgARBXQf8BcSIAQQhGDQUgAEEHRg8LIABBAWsOCgQEBAQDBAMDBAQDC0EBIQMgAUFQakEKTw0DC0EADwsgAUGmwABHDQELQQEPCyADC+4DAQd/IwBBMGsiBCQAIAEoAmRFBEACQCABQX82AmQgASgCdA0AIAFB6ABqIQcgAUGAAWpBADYCACABQfAAakKAgICAcDcCACAEQQA2AiggBCABQUBrNgIkIARBAjYCICAEIAM2AhwgBCACNgIYIAFB+ABqIQggBEEIaiAEQRhqECkgBCgCCCIFBEADQCAEKAIQIQYgBCgCFCEJIAcgBSAFIAQoAgwiCkECdGoQvAEgCCAGIAYgCUECdGoQvAEgCSAKayIGBEADQCABKAJwIgUgASgCbEYEQCAHIAVBARCDASABKAJwIQULIAEoAmggBUECdGpBADYCACABIAEoAnBBAWo2AnAgBkF/aiIGDQALCyAEQQhqIARBGGoQKSAEKAIIIgUNAAsLAkACQCADIAEoAoABRgRAIAEoAngiBSACRg0BIAUgAiADQQJ0ELgBRQ0BCyAEQRhqIAcQrgEgBEEIaiAIEK4BIARBLGogBEEQaigCADYCACAEIAQpAwg3AiQgAEEQaiAEQShqKQMANwIAIABBCGogBEEgaikDADcCACAAIAQpAxg3AgAMAQsgAEEANgIACyABIAEoAnRBAWo2AnQgASABKAJkQQFqNgJkIARBMGokAA8LC0GevcIAQRAgBEEYakG4r8IAEJkBAAvKAwIOfwF+IAFBf2ohCyAAQQxqIQwgAUEySSENQQEhAwJAAkACfwNAAn9BACADIAFPDQAaQQEgACADQQN0IgZqIgJBfGooAgAoAgAgAigCBCgCACICSQ0AGiAGIAxqIQUDQEEBIAMgC0YNAxogA0EBaiEDIAIgBSgCACgCACICTyAFQQhqIQUNAAsgAyABSQsgASADRiANIAEgA0ZyDQEaIANBf2oiAiABTw0CRQ0DIAhBAWohCCAAIAJBA3RqIgIpAgAhECACIAAgA0EDdGoiBCkCADcCACAEIBA3AgAgACADEGwCQCABIANrIgpBAkkNACAEKAIEIgkoAgAgBEEMaigCACgCAE8NACAEKAIAIQ4gBCAEKQIINwIAIARBCGohBwJAIApBA0kNACAJKAIAIARBFGooAgAoAgBPDQBBAyECQQIhBQNAIAQgBUEDdGoiB0F4aiAHKQIANwIAIAIgCk8NASACQQFqIgYgAkkNASACQQN0IQ8gAiEFIAYhAiAJKAIAIAQgD2ooAgQoAgBJDQALCyAHIAk2AgQgByAONgIACyAIQQVHDQALQQALDwtBsKfCACACIAEQqgEAC0HAp8IAIAMgARCqAQALswMBD38CQCACQQF1IgggAkkEQCAAKAIUIQ4gACgCECEMIAAoAgQhDyAAQQxqIABBCGogACgCAEEBRhshECACIQkDQAJAAkAgBCABIAhBGGxqIhEoAgQiBk8NAAJAA0AgDiAEIAxqIgdGBEBBfyEHDAQLIAcgECgCACISTw0BIAcgD2otAAAgESgCACAEai0AAGsiBw0DIARBAWoiBCAGRw0ACyAGIQQMAQtBkL/CACAHIBIQqgEAC0EAIQcLIAogBCAHQQBIIgYbIQoCQCAIIAkgBhsiCSAFIAggBhsiBWsiB0ECTg0AIA0gBSAJRiAFQQBKcnJBASENQQFxRQ0AAn8DQCAFIAJPDQUCQCAKIAEgBUEYbGoiBCgCBCIJTwRAIAAgCSAMaiIJNgIQIAQoAhAiBkUNASAGIAAgAyAEQRRqKAIAKAIMEQMAIAAgCTYCEA0BC0EAIAQoAggiBUEASA0CGgwBCwsgASAFQRhsaigCDAsPCyAEIAsgBhsiCyAKIAogC0sbIQQgB0EBdSAFaiIIIAJJDQALC0GAv8IAIAggAhCqAQALQaC/wgAgBSACEKoBAAvdAwEFfwJAIAFBIGooAgAiBEUNACAEQQJ0IQUgASgCGCIGIQMDQAJAIAMoAgAiAkG/f2pBGkkNACACQYABTwRAQfjlwAAgAhBaDQELIANBBGohAyAFQXxqIgUNAQwCCwsgBEECdCEFA0AgBigCACIDIQQgBiADIANBAEG3BSADQew8SRsiAiACQdsCaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkGuAWoiAiACQQR0QdD4wABqKAIAIANLGyICIAJB1wBqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQStqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQRZqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQQtqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQQVqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQQNqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQQFqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQQFqIgIgAkEEdEHQ+MAAaigCACADSxtBBHQiAkHQ+MAAaigCAEYEQCACQdT4wABqKAIAIQQLIAQgBEGAgMQARhs2AgAgBkEEaiEGIAVBfGoiBQ0ACwsgACABQTAQvgEaC8gDAgt/AX4jAEEQayIGJAAgBiABNgIAQqXGiKHInKf5SyEOQQEhBCABIQMDQCAOIAOtQv8Bg4VCs4OAgIAgfiEOIARBBEcEQCAEIAZqIARBAWohBC0AACEDDAELCyAOQhmIpyIMQf8AcSIDQQh0IANyIgNBEHQgA3IhDSAAKAIAIgcgDqdxIQQgACgCCCEJIAAoAgQhAwJAA0ACQCADIARqKAAAIgogDXMiBUF/cyAFQf/9+3dqcUGAgYKEeHEiBUUNAANAIAEgCSAFaEEDdiAEaiAHcUEDdGoiCygCAEcEQCAFQX9qIAVxIgUNAQwCCwsgCy0ABBogCyACOgAEDAILIAhBBGoiCCAEaiAHcSEEIAogCkEBdHFBgIGChHhxRQ0ACyADIAAgDhCaASIEai0AAEEBcSEFIAAoAgwiCCAFRXJFBEAgBiAAEBAgACAOEJoBIQQgACgCACEHIAAoAgghCSAAKAIMIQggACgCBCEDCyAAIAggBWs2AgwgAyAEaiAMQf8AcSIFOgAAIAcgBEF8anEgA2pBBGogBToAACAJIARBA3RqIgMgAjoABCADIAE2AgAgACAAKAIQQQFqNgIQCyAGQRBqJAALqgMBBH8jAEFAaiIDJAACQAJAAkAgASgCCCIFQQFNBEAgBUEBaw0DIANBCGogAiABKAIMIgQgAUEUaigCABBpIAFBDGohBSADKAIIDQEgAUEgaigCACEEDAILENUBAAsgA0EgaiIGIANBEGooAgA2AgAgAyADKQMINwMYIANBKGogA0EYahCuASABQRBqKAIABEAgBBAfCyAFIAMpAyg3AgAgBUEIaiADQTBqIgQoAgA2AgAgBCAGKAIANgIAIAMgAykDGDcDKCABQRhqIQQgAUEcaigCAARAIAQoAgAQHwsgBCADKQMoNwIAIARBCGogA0EwaigCADYCACABKAIAQQhqIAFBIGooAgAiBDYCAAsgA0EoaiACIAEoAhgiAiAEEDcgAygCKARAIAFBEGooAgAEQCAFKAIAEB8LIAUgAykDKDcCACAFQQhqIANBMGooAgA2AgAgAUEcaigCAARAIAIQHwsgAUEYaiICQQhqIANBNGoiBUEIaigCADYCACACIAUpAgA3AgAgASgCAEEIaiABKAIgNgIACwsgACABQTAQvgEaIANBQGskAAuyAwEOfyABQX9qIQwgAEEEaiENIAFBMkkhDkEBIQMCfwNAAn9BACADIAFPDQAaQQEgACADQQJ0IgRqIgcoAgAiAiAHQXxqKAIASQ0AGiAEIA1qIQUDQEEBIAMgDEYNAxogA0EBaiEDIAUoAgAiBCACTyAFQQRqIQUgBCECDQALIAMgAUkLIQUgASADRiAOIAEgA0ZyDQEaAkACQAJAAkAgA0F/aiICIAFJBEAgBUUNASAJQQFqIQkgACACQQJ0aiICKAIAIQQgAiAAIANBAnRqIgYoAgA2AgAgBiAENgIAIAAgAxB0IAEgA2siC0ECSQ0EIAYoAgQiAiAGKAIAIgpPDQQgBkEEaiEEIAYgAjYCACALQQNJDQMgBigCCCIIIApPDQMgBkEIaiEFQQMhAkECIQcDQCAHQQJ0IAZqQXxqIAg2AgAgAiALTw0DIAJBAWoiCCACSQ0DIAUhBCAGIAJBAnRqIg8hBSACIQcgCCECIA8oAgAiCCAKSQ0ACwwDC0Gwp8IAIAIgARCqAQALQcCnwgAgAyABEKoBAAsgBSEECyAEIAo2AgALIAlBBUcNAAtBAAsLsgMBDX8gAUF/aiELIABBBGohDCABQTJJIQ1BASEDAkACQAJ/A0ACf0EAIAMgAU8NABpBASAAIANBAnQiB2oiBCgCACICIARBfGooAgAQtQENABogByAMaiEGA0BBASADIAtGDQMaIANBAWohAyAGKAIAIgQgAhC1ASAGQQRqIQYgBCECRQ0ACyADIAFJCyABIANGIA0gASADRnINARogA0F/aiICIAFPDQJFDQMgCEEBaiEIIAAgAkECdGoiBCgCACECIAQgACADQQJ0aiIFKAIANgIAIAUgAjYCACAAIAMQfwJAIAEgA2siCkECSQ0AIAUoAgQiAiAFKAIAIgkQtQFFDQAgBUEEaiEGIAUgAjYCAAJAIApBA0kNACAFKAIIIAkQtQFFDQAgBUEIaiEEQQMhAkECIQcDQCAHQQJ0IAVqQXxqIAQiBigCADYCACACIApPDQEgAkEBaiIEIAJJDQEgAkECdCEOIAIhByAEIQIgBSAOaiIEKAIAIAkQtQENAAsLIAYgCTYCAAsgCEEFRw0AC0EACw8LQbCnwgAgAiABEKoBAAtBwKfCACADIAEQqgEAC7wDAgt/A34jAEEgayIFJAACQAJAAkAgAUECSQ0AIABBKGoiAygCACAAKAIIIghPDQAgACkDACENIAAgACkDIDcDACAAQRRqKQIAIQ4gACkCDCEPIABBEGogAEEwaikDADcDACAAQQhqIAMpAwA3AwAgAEEcaigCACEDIABBGGogAEE4aikDADcDACAFQRBqIgogDjcDACAFQRhqIgsgAzYCACAFIA83AwggAEEgaiECQQMhBkEBIQcCQCABQQNJDQBBAiEJA0AgCSIDIAFPDQMgACADQQV0aiIEKAIIIAhPDQEgA0F/aiICIAFPDQQgACACQQV0aiICIAQpAwA3AwAgAkEYaiAEQRhqKQMANwMAIAJBEGogBEEQaikDADcDACACQQhqIARBCGopAwA3AwAgBiABSQRAIAYhCSAGQQFqIgIgBkkgAiEGIAMhByAEIQINAgwBCwsgAyEHIAQhAgsgAiANNwMAIAAgB0EFdGogCDYCCCACQRxqIAsoAgA2AgAgAkEUaiAKKQMANwIAIAIgBSkDCDcCDAsgBUEgaiQADwtB0KzCACADIAEQqgEAC0HgrMIAIAIgARCqAQALxgMCCH8BfiMAQSBrIgIkAAJAEMUBIgZFDQACQCAGKAIARQRAIAZBfzYCACAGQQRqIAEQViIFRQ0BIAUoAggiBARAIARBBHQhByAFKAIAQQxqIQEDQCABKAIAIANqIQMgAUEQaiEBIAdBcGoiBw0ACwsgAkEIaiADIARqELQBIAJBADYCGCACIAIoAgw2AhQgAiACKAIIIgQ2AhAgBSgCCCIBBEAgAUEEdCEHIAUoAgBBDGohA0EAIQEDQCADQXhqKAIAIQggAkEQaiABIAMoAgAiBRCSASACIAUgAigCGCIJaiIBNgIYIAkgAigCECIEaiAIIAUQvgEaIAIoAhQgAUYEQCACQRBqIAFBARCSASACKAIQIQQgAigCGCEBCyABIARqQQA6AAAgAiABQQFqIgE2AhggA0EQaiEDIAdBcGoiBw0ACwsgAikCFCEKIAYgBigCAEEBajYCACAERQ0CIAIgCjcCFCACIAQ2AhAgAiACQRBqEI4BIAAgAikDADcCACACQSBqJAAPC0GevcIAQRAgAkEQakGwvcIAEJkBAAtBjNrCAEErQbjawgAQvQEAC0HYvMIAQcYAIAJBEGpBwL3CABCZAQALnwMBA38jAEHQAGsiBSQAIAUgAjYCQCAFIAI2AjwgBSABNgI4IAVBEGogBUE4ahCOASAFKAIUIQcgBSgCECEGIAUgBDYCQCAFIAQ2AjwgBSADNgI4IAVBCGogBUE4ahCOASAFKAIMIQQgBSgCCCEDEMYBIgEEQAJAIAEoAgBFBEAgAUF/NgIAIAFBBGogABBVIgBFDQEgBUEYaiAGIAYgB2oQIiAFQShqIAMgAyAEahAiIAVBQGsgBUEgaigCADYCACAFQcwAaiAFQTBqKAIANgIAIAUgBSkDGDcDOCAFIAUpAyg3AkQgAEGYAWohAiAAKAKcAQRAIAIoAgAQHwsgACgCqAEEQCAAKAKkARAfCyACIAUpAzg3AgAgAkEQaiAFQcgAaikDADcCACACQQhqIAVBQGspAwA3AgAgASABKAIAQQFqNgIAIAQEQCADEB8LIAcEQCAGEB8LIAVB0ABqJAAPC0GevcIAQRAgBUE4akHMqMIAEJkBAAtBjNrCAEErQbjawgAQvQEAC0HYvMIAQcYAIAVBOGpB3KjCABCZAQALrgMCCn8CfCACKAIEIgcgAUEYaigCAGoiBCADKAIEIgVLBEAgA0EIaigCACEIIAErAwAhDgJAIAJBCGooAgAiCyAHayIJRQRADAELIAggBWsiBkUEQCAOIQ9EAAAAAAAAAAAhDgwBCyAOIA4gCbgiD6JEAAAAAAAAJECiIA8gBrigo5tEAAAAAAAAJECjIg+hRAAAAAAAACRAohCAAUQAAAAAAAAkQKMhDgsgAigCACEMAn9BACACLQAoIgJBCkYNABpBACACQXtqIgJBBEsNABpCgYKEgBAgAq1CA4aIpwshDSAEIAVrIQQgAygCACEGAkAgAy0AKCICQQpGDQAgAkF7aiICQQRLDQBCgYKEgBAgAq1CA4aIpyEKCyAAQQE6AB0gACANOgAcIAAgCTYCGCAAQQA2AhQgACALNgIQIAAgBzYCDCAAIAw2AgggACAPOQMAIABBPWogAS0AHToAACAAQTxqIAo6AAAgAEE4aiAENgIAIABBNGpBADYCACAAQTBqIAg2AgAgAEEsaiAFNgIAIABBKGogBjYCACAAQSBqIA45AwAPCyAAQQI6ABwL5wIBBn8CQAJAIAJBA3EiBEUNAEEEIARrIgRFDQAgAyAEIAQgA0sbIQVBACEEIAFB/wFxIQgDQCAEIAVGDQEgAiAEaiAEQQFqIQQtAAAiBiAIRw0AC0EBIQMgBiABQf8BcUZBAWpBAXEgBGpBf2ohBAwBCyABQf8BcSEIAkACQCADQQhJDQAgBSADQXhqIgZLDQAgCEGBgoQIbCEEA0AgAiAFaiIHQQRqKAIAIARzIglBf3MgCUH//ft3anEgBygCACAEcyIHQX9zIAdB//37d2pxckGAgYKEeHFFBEAgBUEIaiIFIAZNDQELCyAFIANLDQELIAIgBWohAiADIAVrIQZBACEDQQAhBAJAA0AgBCAGRg0BIAIgBGogBEEBaiEELQAAIgcgCEcNAAtBASEDIAcgAUH/AXFGQQFqQQFxIARqQX9qIQQLIAQgBWohBAwBCyAFIAMQqwEACyAAIAQ2AgQgACADNgIAC+ICAQV/AkBBzf97IABBECAAQRBLGyIAayABTQ0AIABBECABQQtqQXhxIAFBC0kbIgRqQQxqEAMiAkUNACACQXhqIQECQCAAQX9qIgMgAnFFBEAgASEADAELIAJBfGoiBSgCACIGQXhxIAIgA2pBACAAa3FBeGoiAiAAIAJqIAIgAWtBEEsbIgAgAWsiAmshAyAGQQNxBEAgACADIAAoAgRBAXFyQQJyNgIEIAAgA2oiAyADKAIEQQFyNgIEIAUgAiAFKAIAQQFxckECcjYCACAAIAAoAgRBAXI2AgQgASACEC0MAQsgASgCACEBIAAgAzYCBCAAIAEgAmo2AgALAkAgACgCBCIBQQNxRQ0AIAFBeHEiAiAEQRBqTQ0AIAAgBCABQQFxckECcjYCBCAAIARqIgEgAiAEayIEQQNyNgIEIAAgAmoiAiACKAIEQQFyNgIEIAEgBBAtCyAAQQhqIQMLIAML9wIBCn8jAEHwAGsiCCQAIABBQGshCwNAIAJBAXQiA0EBciEFAkACQAJAAkAgA0ECaiIHIAFPDQAgBSABTw0BIAAgA0HkAGxqIQlBACEDA0AgA0EEaiIEQShGDQEgAyAJaiEGIAQhA0F/IAZBiAJqKAIAIgQgBkGkAWooAgAiBkcgBCAGSBsiBEUNAAsgBEH/AXFB/wFHDQAgByEFCwJAIAUgAU8NACACIAFPDQIgCyAFQeQAbCIDaiEGIAsgAkHkAGwiAmohCSAAIANqIQcgACACaiEEQQAhAwNAIANBBGoiAkEoRg0BIAMgCWohCiADIAZqIQwgAiEDQX8gDCgCACICIAooAgAiCkcgAiAKSBsiAkUNAAsgAkH/AXFB/wFGDQMLIAhB8ABqJAAPC0GsqMIAIAUgARCqAQALQbyowgAgAiABEKoBAAsgCEEMaiAEQeQAEL4BGiAEIAdB5AAQnAEaIAcgCEEMakHkABC+ARogBSECDAALAAv5AgEFfyMAQTBrIgQkAAJAAkAgAEEIaigCACIFIAAoAgQiBk8EQCAFIAJLDQEgBSAGayEHIAAgAygCAAR/IAMoAlQNAyADQX82AlRBACECIANB4ABqIghBADYCACADQdgAaiIAQQAgBxCSASABIAZBAnRqIAEgBUECdGogABBKIAAoAgAhASAEQRBqIgUgCCgCACIANgIAIAQgADYCKCAEQgA3AyAgBCAANgIcIARBADYCGCAEIAE2AgwgBEEANgIIIARBCGogAygCABEGABogBCgCDCEAIARBFGooAgAgBSgCACIHIAQoAggiCEEBRhsiBQRAIAUhBiAAIQEDQCACIAEtAABBwAFxQYABRmohAiABQQFqIQEgBkF/aiIGDQALCyAIRSAHRXJFBEAgABAfCyADIAMoAlRBAWo2AlQgBSACawUgBws2AgwgBEEwaiQADwsgBiAFEKsBAAsgBSACEKkBAAtBnr3CAEEQIARBCGpBuK/CABCZAQAL4gIBC38jAEEQayIGJAAgAEEIaigCACIFIAAoAgQiBE8EQCAFIAJNBEAgBkKlxoihyJyn+Us3AwggASAEQQJ0aiICIAUgBGsiBSAGQQhqEHMgBigCCCIBQRl2IgRBCHQgBHIiBEEQdCAEciEKIAVBAnQhCyADKAIYIgQgAXEhASADQSBqKAIAIQggA0EcaigCACEMA0ACQAJAIAEgDGooAAAiCSAKcyIDQX9zIANB//37d2pxQYCBgoR4cSIDRQ0AA0ACQCAFIAggA2hBA3YgAWogBHEiDUEEdGoiBygCCEYEQCAHKAIAIgcgAkYNASACIAcgCxC4AUUNAQsgA0F/aiADcSIDDQEMAgsLIAggDUEEdGotAAwhAwwBCyAOQQRqIg4gAWogBHEhAUEKIQMgCSAJQQF0cUGAgYKEeHFFDQELCyAAIAM6ABAgBkEQaiQADwsgBSACEKkBAAsgBCAFEKsBAAvdAgEKfyMAQfAAayIHJAACQCABQQJJDQAgAUF+aiEEIAAgAUHkAGxqIgVBuH5qIQYgBUGcf2ohCEFYIQEDQCABQQRqIgNFDQEgASAFaiECIAMhAUF/IAJBoH9qKAIAIgMgAkEEaigCACICRyADIAJIGyIDRQ0ACyADQf8BcUH/AUcNACAHQQhqIAhB5AAQvgEaIAggBkHkABC+ARogACAEQeQAbCIBaiEDAkAgBEUNACAAIAFqQVxqIQYgB0HIAGohCgNAIAAgBEF/aiIIQeQAbGohBUEAIQEDQCABQQRqIgJBKEYNAiABIApqIQkgASAGaiELIAIhAUF/IAsoAgAiAiAJKAIAIglHIAIgCUgbIgJFDQALIAJB/wFxQf8BRw0BIAAgBEHkAGxqIAVB5AAQvgEaIAZBnH9qIQYgBSEDIAgiBA0ACwsgAyAHQQhqQeQAEL4BGgsgB0HwAGokAAvNAgEGfyABIAJBAXRqIQkgAEGA/gNxQQh2IQogAEH/AXEhDAJAAkACQANAIAFBAmohCyAHIAEtAAEiAmohCCAKIAEtAAAiAUcEQCABIApLDQMgCCEHIAsiASAJRw0BDAMLIAggB08EQCAIIARLDQIgAyAHaiEBAkADQCACRQ0BIAJBf2ohAiABLQAAIAFBAWohASAMRw0AC0EAIQIMBQsgCCEHIAsiASAJRw0BDAMLCyAHIAgQqwEACyAIIAQQqQEACyAAQf//A3EhByAFIAZqIQNBASECA0ACQCAFQQFqIQACfyAAIAUtAAAiAUEYdEEYdSIEQQBODQAaIAAgA0YNASAFLQABIARB/wBxQQh0ciEBIAVBAmoLIQUgByABayIHQQBIDQIgAkEBcyECIAMgBUcNAQwCCwtBjNrCAEErQbjawgAQvQEACyACQQFxC9sCAQN/IwBBEGsiAyQAIAAgAUcEQANAIABBBGoCQCAAKAIAIgBBgAFPBEAgA0EANgIMAn8gAEH/D00EQCADIABBP3FBgAFyOgANIAMgAEEGdkEfcUHAAXI6AAxBAgwBCyAAQf//A00EQCADIABBP3FBgAFyOgAOIAMgAEEGdkE/cUGAAXI6AA0gAyAAQQx2QQ9xQeABcjoADEEDDAELIAMgAEE/cUGAAXI6AA8gAyAAQRJ2QfABcjoADCADIABBBnZBP3FBgAFyOgAOIAMgAEEMdkE/cUGAAXI6AA1BBAshACACIAIoAgggABCSASACIAIoAggiBCAAajYCCCAEIAIoAgBqIANBDGogABC+ARoMAQsgAigCCCIEIAIoAgRGBH8gAiAEQQEQkgEgAigCCAUgBAsgAigCAGogADoAACACIAIoAghBAWo2AggLIgAgAUcNAAsLIANBEGokAAvIAgEFfyMAQSBrIgIkAAJAIAAoAhAiAyAAKAIUTw0AIAAoAgQhBSACIABBDGogAEEIaiAAKAIAQQFGGygCACIENgIEIAIgBTYCACACIAM2AgggAiAENgIMAkACQCADRSADIARGckUEQCAEIANNDQEgAyAFaiwAAEG/f0wNAQsgAyAFaiAEIANrIAEQkAFFDQIgACAAKAIQQQFqIgE2AhAgAUUNASAAQQxqIABBCGogACgCACIDQQFGGygCACIEIAFGDQEgAEEMaiAAQQhqIAMbIQUDQCAEIAFLBEAgACgCBCABaiwAAEG/f0oNAwtBASEGIAAgAUEBaiIDNgIQIAMgAUkNAyAFKAIAIgQgAyIBRw0ACwwCCyACIAJBDGo2AhggAiACQQhqNgIUIAIgAjYCECACQRBqEMsBAAtBASEGCyACQSBqJAAgBgviAgIGfwJ+IwBBIGsiAyQAAkAQxQEiBEUNAAJAIAQoAgBFBEAgBEF/NgIAQQQhBiAEQQRqIAEQViIBRQ0BAn4gASgCCCICRQRAQgAMAQsCQAJAIAJB/////wNxIAJHDQAgAkECdCIFQQBIDQAgASgCACEBIAVBBBDWASIGDQEgBUEEQbThwgAoAgAiAEECIAAbEQAAAAsQ0gEACyACrSEIIAJBBHQiByEFIAYhAgNAIAIgASgCADYCACACQQRqIQIgAUEQaiEBIAVBcGoiBQ0ACyAHQXBqQQR2QQFqrUIghgshCSAEIAQoAgBBAWo2AgAgBkUNAiADIAggCYQ3AhQgAyAGNgIQIANBCGogA0EQahCGASAAIAMpAwg3AgAgA0EgaiQADwtBnr3CAEEQIANBEGpBsL3CABCZAQALQYzawgBBK0G42sIAEL0BAAtB2LzCAEHGACADQRBqQcC9wgAQmQEAC8ICAQp/IwBB8ABrIgYkAAJAIAFBAkkNACAAQeQAaiEEQcAAIQIDQCACQQRqIgNB6ABGDQEgACACaiEFIAMhAkF/IAUoAgAiAyAFQeQAaigCACIFRyADIAVIGyIDRQ0ACyADQf8BcUH/AUcNACAGQQhqIABB5AAQvgEaIAAgBEHkABC+ASIJQeQAaiEDAkAgAUEDSQ0AIAlBiAJqIQcgBkHIAGohCkECIQIDQCACQQFqIAkgAkHkAGxqIQBBACECA0AgAkEEaiIEQShGDQIgAiAHaiEIIAIgCmohCyAEIQJBfyALKAIAIgQgCCgCACIIRyAEIAhIGyIERQ0ACyAEQf8BcUH/AUcNASAAQZx/aiAAQeQAEL4BGiAHQeQAaiEHIAAhAyICIAFHDQALCyADIAZBCGpB5AAQvgEaCyAGQfAAaiQAC70CAgR/AX4jAEEQayIEJAACQCABQQJJDQAgAUEMbCAAaiIDQXRqIgJBAyADQWhqIgNBAxCXAUH/AXFB/wFHDQAgAikCACEGIAIgAykCADcCACAEQQhqIgUgAkEIaiICKAIANgIAIAIgA0EIaigCADYCACAEIAY3AwAgACABQX5qIgJBDGxqIQMCQCACRQ0AIARBAyAAIAFBfWoiAkEMbGpBAxCXAUH/AXFB/wFHDQAgAUEMbCAAakFQaiEBIAACfwJAA0AgAUEgaiABQRRqKAIANgIAIAFBGGogAUEMaikCADcCACACRQ0BIAJBf2ohAiAEQQMgAUEDEJcBIAFBdGohAUH/AXFB/wFGDQALIAJBAWoMAQtBAAtBDGxqIQMLIAMgBCkDADcCACADQQhqIAUoAgA2AgALIARBEGokAAu+AgIFfwF+IwBBMGsiBCQAQSchAgJAIABCkM4AVARAIAAhBwwBCwNAIARBCWogAmoiA0F8aiAAIABCkM4AgCIHQvCxf358pyIFQf//A3FB5ABuIgZBAXRB9oDAAGovAAA7AAAgA0F+aiAGQZx/bCAFakH//wNxQQF0QfaAwABqLwAAOwAAIAJBfGohAiAAQv/B1y9WIAchAA0ACwsgB6ciA0HjAEoEQCACQX5qIgIgBEEJamogB6ciBUH//wNxQeQAbiIDQZx/bCAFakH//wNxQQF0QfaAwABqLwAAOwAACwJAIANBCk4EQCACQX5qIgIgBEEJamogA0EBdEH2gMAAai8AADsAAAwBCyACQX9qIgIgBEEJamogA0EwajoAAAsgAUGs2MIAQQAgBEEJaiACakEnIAJrECQgBEEwaiQAC7YCAQd/An8gAUEgaigCACIEIAFBLGooAgAiBU0EQCABIAQ2AiwgBAwBCyABQSRqIAUgBCAFayIGEJIBIAEoAiwhAyABKAIkIQcgAQJ/AkAgBkECTwRAIAVBf3MgBGohBANAIAMgB2pBADoAACADQQFqIQMgBEF/aiIEDQALDAELIAYNACADDAELIAMgB2pBADoAACADQQFqCyIENgIsIAEoAiALIQMCQCAEIAMgAyAESxsiBkUNACABKAIkIQcgASgCGCEEQQAhAwNAIANBAWohBSADIAdqAkAgAiAEKAIAIggQV0H/AXEiA0EIRw0AQQAhA0EEIAggAhA2IghB/wFxQQJGDQAgCEECdEEEcSEDCyADOgAAIAUgBk8NASAEQQRqIQQgBSEDDAALAAsgACABQTAQvgEaC78CAQR/IABCADcCECAAAn9BACABQQh2IgNFDQAaQR8gAUH///8HSw0AGiABQQYgA2ciA2tBH3F2QQFxIANBAXRrQT5qCyICNgIcIAJBAnRB8N/CAGohBAJAAkACQAJAQeTdwgAoAgAiBUEBIAJBH3F0IgNxBEAgBCgCACIDKAIEQXhxIAFHDQEgAyECDAILQeTdwgAgAyAFcjYCACAEIAA2AgAgACAENgIYDAMLIAFBAEEZIAJBAXZrQR9xIAJBH0YbdCEEA0AgAyAEQR12QQRxakEQaiIFKAIAIgJFDQIgBEEBdCEEIAIhAyACKAIEQXhxIAFHDQALCyACKAIIIgEgADYCDCACIAA2AgggAEEANgIYIAAgAjYCDCAAIAE2AggPCyAFIAA2AgAgACADNgIYCyAAIAA2AgwgACAANgIIC7gCAQZ/IwBBEGsiAiQAAkAgAkF/An8CQCABBEAgAUEISQ0BIAEgAUH/////AXFGBEAgAUEDdEEHbgwDC0G0psIAQRxB0KbCABC9AQALIABCADcCACAAQRRqQQA2AgAgAEEMakIENwIAIABBCGpBsKbCADYCAAwCCyABQQFqC0F/amd2IgFBAWoiBBClAQJAIAIoAgQiAwRAIAIoAgghBSACKAIAIgYgAxDWASIHRQ0BIAdB/wEgAUEFahDEASEDIABBFGpBADYCACAAQQxqIAMgBWo2AgAgAEEIaiADNgIAIAAgATYCBCAAQQA2AgAgAEEQaiABIARBA3ZBB2wgAUEISRs2AgAMAgtBtKbCAEEcQdCmwgAQvQEACyAGIANBtOHCACgCACIAQQIgABsRAAAACyACQRBqJAALuAIBBn8jAEEQayICJAACQCACQX8CfwJAIAEEQCABQQhJDQEgASABQf////8BcUYEQCABQQN0QQduDAMLQbSmwgBBHEHQpsIAEL0BAAsgAEIANwIAIABBFGpBADYCACAAQQxqQgg3AgAgAEEIakGwpsIANgIADAILIAFBAWoLQX9qZ3YiAUEBaiIEEJ8BAkAgAigCBCIDBEAgAigCCCEFIAIoAgAiBiADENYBIgdFDQEgB0H/ASABQQVqEMQBIQMgAEEUakEANgIAIABBDGogAyAFajYCACAAQQhqIAM2AgAgACABNgIEIABBADYCACAAQRBqIAEgBEEDdkEHbCABQQhJGzYCAAwCC0G0psIAQRxB0KbCABC9AQALIAYgA0G04cIAKAIAIgBBAiAAGxEAAAALIAJBEGokAAupAgEDfyMAQYABayIEJAAgACgCACEAAkACQAJ/AkAgASgCACIDQRBxRQRAIAAoAgAhAiADQSBxDQEgAq0gARBPDAILIAAoAgAhAkEAIQADQCAAIARqQf8AaiACQQ9xIgNBMHIgA0HXAGogA0EKSRs6AAAgAEF/aiEAIAJBBHYiAg0ACyAAQYABaiICQYEBTw0CIAFBzIPAAEECIAAgBGpBgAFqQQAgAGsQJAwBC0EAIQADQCAAIARqQf8AaiACQQ9xIgNBMHIgA0E3aiADQQpJGzoAACAAQX9qIQAgAkEEdiICDQALIABBgAFqIgJBgQFPDQIgAUHMg8AAQQIgACAEakGAAWpBACAAaxAkCyAEQYABaiQADwsgAkGAARCrAQALIAJBgAEQqwEAC6YCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghBCAAKAIEIQgDQAJAAkAgAiAIaigAACIGIAdzIgBBf3MgAEH//ft3anFBgIGChHhxIgBFDQADQCABIAQgAGhBA3YgAmogA3EiCUHsAWxqKAIARwRAIABBf2ogAHEiAA0BDAILCyAEIAlB7AFsakEEaiEADAELIAVBBGoiBSACaiADcSECQQAhACAGIAZBAXRxQYCBgoR4cUUNAQsLIAALpAICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEEIAAoAgQhCANAAkACQCACIAhqKAAAIgYgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgBCAAaEEDdiACaiADcSIJQQR0aigCAEcEQCAAQX9qIABxIgANAQwCCwsgBCAJQQR0akEEaiEADAELIAVBBGoiBSACaiADcSECQQAhACAGIAZBAXRxQYCBgoR4cUUNAQsLIAALqAICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQYgAyAAKAIEIgNxIQIgAEEMaigCACEHIABBCGooAgAhCEEAIQQDQAJAAkAgAiAIaigAACIFIAZzIgBBf3MgAEH//ft3anFBgIGChHhxIgBFDQADQCABIAcgAGhBA3YgAmogA3FBA3RqIgkoAgBHBEAgAEF/aiAAcSIADQEMAgsLIAktAAQhAAwBCyAEQQRqIgQgAmogA3EhAkEIIQAgBSAFQQF0cUGAgYKEeHFFDQELCyAAC6gCAQR/IwBBIGsiAiQAAkACQAJAAkACQAJAEMYBIgMEQCADKAIADQYgA0F/NgIAIANBBGogABBVIgVFDQIQxQEiBARAIAQoAgANByAEQX82AgAgBEEEaiAAEFYiAEUNBCAFIAE2AhAgACgCBCABTw0CIAJBCGogACAAKAIIIgAgASAAa0EAEGMgAigCCEEBRw0CIAJBEGooAgANBRDSAQALDAULDAQLIAQgBCgCAEEBajYCACADIAMoAgBBAWo2AgAgAkEgaiQADwtBjNrCAEErQbjawgAQvQEAC0GM2sIAQStBuNrCABC9AQALQavbwgBBKEHYq8IAEL0BAAtB2LzCAEHGACACQRhqQdyowgAQmQEAC0GevcIAQRAgAkEYakHMqMIAEJkBAAuyAgEFfyAAKAIYIQQCQAJAIAAgACgCDCIBRgRAIABBFEEQIABBFGoiASgCACIDG2ooAgAiAg0BQQAhAQwCCyAAKAIIIgIgATYCDCABIAI2AggMAQsgASAAQRBqIAMbIQMDQCADIQUgAiIBQRRqIgMoAgAiAkUEQCABQRBqIQMgASgCECECCyACDQALIAVBADYCAAsCQCAERQ0AAkAgACAAKAIcQQJ0QfDfwgBqIgIoAgBGBEAgAiABNgIAIAENAUHk3cIAQeTdwgAoAgBBfiAAKAIcd3E2AgAPCyAEQRBBFCAEKAIQIABGG2ogATYCACABRQ0BCyABIAQ2AhggACgCECICBEAgASACNgIQIAIgATYCGAsgAEEUaigCACIARQ0AIAFBFGogADYCACAAIAE2AhgLC6cCAQJ/An8gAUGAEE8EQAJAAkACQAJAAkAgAUGAgARPBEAgAUEMdkFwaiICQYACSQ0BQdCcwAAgAkGAAhCqAQALIAFBBnZBYGoiAkHfB0sNASAAQYQCaigCACIDIAAgAmpBmAJqLQAAIgJNDQIgACgCgAIgAkEDdGoMBgsgAUEGdkE/cSAAIAJqQfgJai0AAEEGdHIiAiAAQYwCaigCACIDTw0CIABBlAJqKAIAIgMgACgCiAIgAmotAAAiAk0NAyAAKAKQAiACQQN0agwFC0GwnMAAIAJB4AcQqgEAC0HAnMAAIAIgAxCqAQALQeCcwAAgAiADEKoBAAtB8JzAACACIAMQqgEACyAAIAFBA3ZB+P///wFxagspAwBCASABQT9xrYaDQgBSC5QCAQN/IwBBgAFrIgMkAAJAAkACfwJAIAEoAgAiAkEQcUUEQCACQSBxDQEgAK0gARBPDAILQQAhAgNAIAIgA2pB/wBqIABBD3EiBEEwciAEQdcAaiAEQQpJGzoAACACQX9qIQIgAEEEdiIADQALIAJBgAFqIgBBgQFPDQIgAUHMg8AAQQIgAiADakGAAWpBACACaxAkDAELQQAhAgNAIAIgA2pB/wBqIABBD3EiBEEwciAEQTdqIARBCkkbOgAAIAJBf2ohAiAAQQR2IgANAAsgAkGAAWoiAEGBAU8NAiABQcyDwABBAiACIANqQYABakEAIAJrECQLIANBgAFqJAAPCyAAQYABEKsBAAsgAEGAARCrAQALkwIBC38jAEEQayIFJAACQAJAIAFBCE8EQCABQQJ2IgJBAXQhB0F/IQZBfyABQX9qZ3YhCCACQRhsIABqQXRqIQQgASECA0AgBiAHaiIDIAFPDQIgAkENdCACcyICQRF2IAJzIgJBBXQgAnMiAiAIcSIDQQAgASADIAFJG2siAyABTw0DIAVBCGoiCSAEQQhqIgooAgA2AgAgBSAEKQIANwMAIAAgA0EMbGoiA0EIaiILKAIAIQwgBCADKQIANwIAIAogDDYCACALIAkoAgA2AgAgAyAFKQMANwIAIARBDGohBCAGQQFqIgZBAkcNAAsLIAVBEGokAA8LQbCnwgAgAyABEKoBAAtBwKfCACADIAEQqgEAC5UCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghCCAAKAIEIQlBACEEA0ACQCACIAlqKAAAIgUgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAARAA0BBASEGIAggAGhBA3YgAmogA3FBBHRqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC5YCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghCCAAKAIEIQlBACEEA0ACQCACIAlqKAAAIgUgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAARAA0BBASEGIAggAGhBA3YgAmogA3FB7AFsaigCACABRg0CIABBf2ogAHEiAA0ACwsgBEEEaiIEIAJqIANxIQJBACEGIAUgBUEBdHFBgIGChHhxRQ0BCwsgBguVAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQggACgCBCEJQQAhBANAAkAgAiAJaigAACIFIAdzIgBBf3MgAEH//ft3anFBgIGChHhxIgAEQANAQQEhBiAIIABoQQN2IAJqIANxQShsaigCACABRg0CIABBf2ogAHEiAA0ACwsgBEEEaiIEIAJqIANxIQJBACEGIAUgBUEBdHFBgIGChHhxRQ0BCwsgBguPAgEIfyMAQSBrIgIkAAJAAkAgACgCECIDIAAoAhhrQQFIDQAgAEEIaiEEIABBDGohBiAAKAIAIQcgACgCBCEFAkAgA0EBayIDRQ0AIAYgBCAHQQFGGygCACIIIANGDQAgCCADTQ0BIAMgBWosAABBQEgNAQsgAiAGIAQgBxsoAgAiBDYCBCACIAU2AgAgAiADNgIIIAIgBDYCDCADRSADIARGckUEQCAEIANNDQIgAyAFaiwAAEG/f0wNAgsgAyAFaiAEIANrIAEQkAFFDQAgACAAKAIQQQFrNgIQQQEhCQsgAkEgaiQAIAkPCyACIAJBDGo2AhggAiACQQhqNgIUIAIgAjYCECACQRBqEMsBAAuoAgEEfyMAQTBrIgIkACABKAIIIQUgAiABEK4BIAIoAgghBCACQQA2AiggAkIBNwMgIAJBIGpBACAEEJIBIAIoAighAyAEBEAgAigCICADakEAIARBAnRBfGpBAnZBAWoiBBDEARogAyAEaiEDCyACQRhqIgQgAzYCACACIAIpAyA3AxBBFEEEENYBIgNFBEBBFEEEQbThwgAoAgAiAEECIAAbEQAAAAsgA0GKAjsBECADIAU2AgwgAyAFNgIIIANCADcCACAAQoGAgIAQNwIEIAAgAzYCACAAIAIpAwA3AhggACACKQMQNwIkIAAgASkCADcCDCAAQRRqIAFBCGooAgA2AgAgAEEgaiACQQhqKAIANgIAIABBLGogBCgCADYCACACQTBqJAALigIBA38CQCABQQBMBEAgACgCECECIAFBf0oNASAAKAIEIQMgAEEMaiAAQQhqIAAoAgBBAUYbIQADQCABQQFqIQECQCACQX9qIgJBAEgNAANAIAJFDQEgAiAAKAIAIgRGDQEgBCACSwRAIAIgA2osAABBv39KDQILIAJBf2ohAgwACwALIAENAAsMAQsgACgCBCEDIAAoAhAhAiAAQQxqIABBCGogACgCABshAANAIAFBf2ohAQJAIAJBAWoiAiAAKAIASw0AA0AgAkUNASACIAAoAgAiBEYNASAEIAJLBEAgAiADaiwAAEG/f0oNAgsgAkEBaiICIAAoAgBNDQALCyABQQBKDQALCyACC+IBAQJ/AkAgASgCBCIFIAJrIANPDQAgAiADaiIDIAJJIQICQAJAAkAgBARAIAJFDQEgACADNgIEDAMLIAJFDQEgACADNgIEDAILIAVBAXQiAiADIAIgA0sbIQMLIAMgA0H/////AHFHBEAMAQsgA0EEdCIEQX9MBEAMAQsCfyAFRQRAIARBBBDWAQwBCyABKAIAIAVBBHRBBCAEENMBCyICRQRAIARBBEG04cIAKAIAIgBBAiAAGxEAAAALIAEgAzYCBCABIAI2AgAMAQsgAEEIakEANgIAQQEhBgsgACAGNgIAC5kCAgF/A34jAEEgayIBJAAgAUEYakEUELQBIAEpAxghAiABQRBqQRQQowEgASkDECEDIAFBCGpBFBCjASABKQMIIQQgAEGAAWpBADYCACAAQfAAakIANwIAIABB6ABqIAM3AgAgAEHgAGpCADcCACAAQdgAaiACNwIAIABB0ABqQgA3AgAgAEHIAGpCBDcCACAAQcQAakGwpsIANgIAIABBPGpCADcCACAAQTRqQgQ3AgAgAEEwakGwpsIANgIAIABBKGpCADcCACAAQSBqQgQ3AgAgAEEcakGwpsIANgIAIABBFGpCADcCACAAQQxqQgQ3AgAgAEEIakGwpsIANgIAIABCADcCACAAQfgAaiAENwIAIAFBIGokAAvuAQEHfyMAQfAAayIFJAACQAJAIAFBCE8EQCABQQJ2IgJBAXQhB0F/IQZBfyABQX9qZ3YhCCACQcgBbCAAakGcf2ohBCABIQIDQCAGIAdqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAhxIgNBACABIAMgAUkbayIDIAFPDQMgBUEMaiAEQeQAEL4BGiAEIAAgA0HkAGxqIgRB5AAQnAEgBCAFQQxqQeQAEL4BGkHkAGohBCAGQQFqIgZBAkcNAAsLIAVB8ABqJAAPC0Gwp8IAIAMgARCqAQALQcCnwgAgAyABEKoBAAuTAgEFfyAAKAIAIgQoAgQoAgAiBSABKAIAIghBA3RqKAIEKAIAIAUgAigCACIGQQN0aigCBCgCACIHSQRAIAEgBjYCACACIAg2AgAgBCgCCCIEIAQoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQN0aigCBCgCACEHCyAHIAUgAygCACIIQQN0aigCBCgCAEkEQCACIAg2AgAgAyAGNgIAIAQoAggiAyADKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkEDdGooAgQoAgAhBwsgBSABKAIAIgBBA3RqKAIEKAIAIAdJBEAgASAGNgIAIAIgADYCACAEKAIIIgAgACgCAEEBajYCAAsL/AECBn8BfiMAQRBrIgUkAANAIAJBAXQiA0EBciEEAkACQAJAIANBAmoiAyABSQRAIAQgAU8NASADIAQgACAEQQxsakEDIAAgA0EMbGpBAxCXAUH/AXFB/wFGGyEECyAEIAFJBEAgAiABTw0CIAAgAkEMbGoiAkEDIAAgBEEMbGoiA0EDEJcBQf8BcUH/AUYNAwsgBUEQaiQADwtBrKjCACAEIAEQqgEAC0G8qMIAIAIgARCqAQALIANBCGoiBigCACEHIAIpAgAhCSACIAMpAgA3AgAgAkEIaiICKAIAIQggAiAHNgIAIAYgCDYCACADIAk3AgAgBCECDAALAAvMAQEFfyAAKAIAIgEEQCAAKAIEIgJBBGohAyABIAJqQQFqIQUgAigCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAFFBEADQCADIAVPDQIgBEHgAGohBCADKAIAIANBBGoiAiEDQYCBgoR4cSIBQYCBgoR4Rg0ACyABQYCBgoR4cyEBIAIhAwsgBCABaEEDdkEYbGoiAigCBARAIAIoAgAQHwsgAUF/aiABcSEBIAIoAhBFDQEgAigCDBAfDAELCyAAKAIAGiAAKAIEEB8LC4MCAQN/IwBBMGsiBCQAAkACQCABKAJkRQRAIAFBfzYCZCABQfAAakEANgIAIARBADYCGCAEIAFBLGo2AhQgBEECNgIQIAQgAzYCDCAEIAI2AgggAUHoAGohBiAEQSBqIARBCGoQKSAEKAIgDQEMAgtBnr3CAEEQIARBCGpBuK/CABCZAQALA0AgBiAEKAIoIgUgBSAEKAIsQQJ0ahC8ASAEQSBqIARBCGoQKSAEKAIgDQALCwJAAkAgAyABKAJwRgRAIAEoAmgiBSACRg0BIAUgAiADQQJ0ELgBRQ0BCyAAIAYQrgEMAQsgAEEANgIACyABIAEoAmRBAWo2AmQgBEEwaiQAC4ACAQV/IAAoAgAiBCgCBCgCACIFIAIoAgAiBkECdGooAgAiByAFIAEoAgAiCEECdGooAgBJBEAgASAGNgIAIAIgCDYCACAEKAIIIgQgBCgCAEEBajYCACAAKAIAIgQoAgQoAgAiBSACKAIAIgZBAnRqKAIAIQcLIAUgAygCACIIQQJ0aigCACAHSQR/IAIgCDYCACADIAY2AgAgBCgCCCIDIAMoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQJ0aigCAAUgBwsgBSABKAIAIgBBAnRqKAIASQRAIAEgBjYCACACIAA2AgAgBCgCCCIAIAAoAgBBAWo2AgALC+UBAQR/IwBBQGoiBCQAIARBCGogASABIAJqECIgBEEYaiAAIAQoAggiBSAEKAIQIgEQaSAEKAIMIQYCQCAEKAIYIgJFBEAgBiEHIAUhAgwBCyAEQSBqKAIAIQEgBCgCHCEHIAZFDQAgBRAfCyAEQRhqIAAgAiABEDcgBCABNgI4IAQgBzYCNCAEIAI2AjAgAEEYaiIAIARBMGogAxAwAkAgBCgCGCIBRQ0AIARBOGogBEEsaigCADYCACAEIARBJGopAgA3AzAgACAEQTBqIAMQMCAEKAIcRQ0AIAEQHwsgBEFAayQAC9gBAQV/AkAgAUECSQ0AIAAgAUF+aiICQQN0aiIDKAIEKAIAIAFBA3QgAGoiBEF8aigCACIFKAIATw0AIARBeGoiBCgCACEGIAQgAykCADcCAAJAIAJFDQAgACABQX1qIgJBA3RqIgEoAgQoAgAgBSgCAE8NACABQXxqIQEgAAJ/AkADQCABQQxqIAFBBGopAgA3AgAgAkUNASACQX9qIQIgASgCACABQXhqIQEoAgAgBSgCAEkNAAsgAkEBagwBC0EAC0EDdGohAwsgAyAFNgIEIAMgBjYCAAsL3QECB38BfiMAQRBrIgQkACABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhBnIAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUEMbCAAakF0aiECIAEhAwNAIANBf2oiBSABTw0CIARBCGogAEEIaiIDKAIAIgY2AgAgBCAAKQIAIgk3AwAgAkEIaiIHKAIAIQggACACKQIANwIAIAMgCDYCACAHIAY2AgAgAiAJNwIAIAAgBUEAEGcgAkF0aiECIAUiA0EBSw0ACwsgBEEQaiQADwtBwKfCACADQX9qIAEQqgEAC90BAQZ/AkACQAJAIAFBAkkNACAAKAIEIgYoAgAgAEEMaigCACgCAE8NACAAKAIAIQcgACAAKQIINwIAIABBCGohAgJAIAFBA0kNAEECIQNBAyEEA0AgAyABTw0DIAYoAgAgACADQQN0aiIFKAIEKAIATw0BIANBf2oiAiABTw0EIAAgAkEDdGogBSkCADcCACAEIAFJBEAgBSECIAQiA0EBaiIEIANJDQIMAQsLIAUhAgsgAiAGNgIEIAIgBzYCAAsPC0HQrMIAIAMgARCqAQALQeCswgAgAiABEKoBAAvUAQEGfyMAQRBrIgUkACAAKAIAIgIEQCAAKAIEIgFBBGohAyABIAJqQQFqIQYgASgCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAEEfyABBQNAIAMgBk8NAiAEQeAAaiEEIAMoAgAgA0EEaiICIQNBgIGChHhxIgFBgIGChHhGDQALIAIhAyABQYCBgoR4cwsiAiACQX9qcSEBIAQgAmhBA3ZBGGxqIgIoAhBFDQEgAigCDBAfDAELCyAFIAAoAgBBAWoQnQEgACgCBBAfCyAFQRBqJAAL0wEBBn8jAEEQayIFJAAgACgCACICBEAgACgCBCIBQQRqIQMgASACakEBaiEGIAEoAgBBf3NBgIGChHhxIQEgACgCCCEEA0ACQCABBH8gAQUDQCADIAZPDQIgBEFAayEEIAMoAgAgA0EEaiICIQNBgIGChHhxIgFBgIGChHhGDQALIAIhAyABQYCBgoR4cwsiAiACQX9qcSEBIAQgAmhBA3ZBBHRqIgIoAgRFDQEgAigCABAfDAELCyAFIAAoAgBBAWoQpgEgACgCBBAfCyAFQRBqJAALzgECBn8BfgJAAkAgAUEITwRAIAFBAnYiAkEBdCEGQX8hBUF/IAFBf2pndiEHIAJBBHQgAGpBeGohBCABIQIDQCAFIAZqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAdxIgNBACABIAMgAUkbayIDIAFPDQMgBCkCACEIIAQgACADQQN0aiIDKQIANwIAIAMgCDcCACAEQQhqIQQgBUEBaiIFQQJHDQALCw8LQbCnwgAgAyABEKoBAAtBwKfCACADIAEQqgEAC8wBAQd/AkACQCABQQhPBEAgAUECdiICQQF0IQZBfyEFQX8gAUF/amd2IQcgAkEDdCAAakF8aiEEIAEhAgNAIAUgBmoiAyABTw0CIAJBDXQgAnMiAkERdiACcyICQQV0IAJzIgIgB3EiA0EAIAEgAyABSRtrIgMgAU8NAyAEKAIAIQggBCAAIANBAnRqIgMoAgA2AgAgAyAINgIAIARBBGohBCAFQQFqIgVBAkcNAAsLDwtBsKfCACADIAEQqgEAC0HAp8IAIAMgARCqAQALyQECA38BfiMAQRBrIgUgATYCCCACKQMAIQZBASEDIAEhBANAIAYgBK1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAFQQhqIANqIANBAWohAy0AACEEDAELCyACIAY3AwAgAQRAIAAgAUECdGohAQNAIAUgACgCACIENgIMQQEhAwNAIAYgBK1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAFQQxqIANqIANBAWohAy0AACEEDAELCyACIAY3AwAgAEEEaiIAIAFHDQALCwvCAQEFfwJAIAFBAkkNACABQQJ0IABqQXxqIgMoAgAiBCAAIAFBfmoiBUECdGoiBigCACICTw0AIAMgAjYCAAJAAkAgBUUNACAEIAAgAUF9aiIDQQJ0aiICKAIAIgVPDQAgBiAFNgIAIANFDQEgAUECdCAAakFwaiEBA0AgBCABKAIAIgBPBEAgAUEEaiECDAMLIAFBBGogADYCACABQXxqIQEgA0F/aiIDDQALIAFBBGohAgwBCyAGIQILIAIgBDYCAAsL1AEBAX8jAEHgAGsiBCQAIAQgATYCCCAEIAM2AgwgASADRgRAIAAgAiABQQJ0EL4BGiAEQeAAaiQADwsgBEE8akEJNgIAIARBNGpBCjYCACAEQSRqQQM2AgAgBEIDNwIUIARB+L/CADYCECAEQQo2AiwgBCAEQQhqNgJAIAQgBEEMajYCRCAEQgQ3A1ggBEIBNwJMIARB8L/CADYCSCAEIARBKGo2AiAgBCAEQcgAajYCOCAEIARBxABqNgIwIAQgBEFAazYCKCAEQRBqQZDAwgAQwwEAC8QBAQZ/AkACQAJAIAFBAkkNACAAKAIEIgQgACgCACIFELUBRQ0AIABBBGohAiAAIAQ2AgBBAyEEAkAgAUEDSQ0AQQIhAwNAIAMgAU8NAyAAIANBAnRqIgYoAgAiByAFELUBRQ0BIANBf2oiAiABTw0EIAAgAkECdGogBzYCACAEIAFJBEAgBiECIAQiA0EBaiIEIANJDQIMAQsLIAYhAgsgAiAFNgIACw8LQdCswgAgAyABEKoBAAtB4KzCACACIAEQqgEAC7oBAQR/IwBB8ABrIgQkACABQQF2IgIEQCACQX9qIQIDQCAAIAEgAhBFIAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUHkAGwgAGpBnH9qIQMgASECA0AgAkF/aiIFIAFPDQIgBEEMaiAAQeQAEL4BGiAAIANB5AAQnAEgAyAEQQxqQeQAEL4BIQMgBUEAEEUgA0Gcf2ohAyAFIgJBAUsNAAsLIARB8ABqJAAPC0HAp8IAIAJBf2ogARCqAQALwQECBH8BfkEDIQIjAEEQayIEQQM2AgggASkDACEGQQEhAwNAIAYgAq1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAEQQhqIANqIANBAWohAy0AACECDAELCyABIAY3AwAgAEEMaiEFA0AgBCAAKAIAIgI2AgxBASEDA0AgBiACrUL/AYOFQrODgICAIH4hBiADQQRGRQRAIARBDGogA2ogA0EBaiEDLQAAIQIMAQsLIAEgBjcDACAAQQRqIgAgBUcNAAsLvAEBAn8CQCAAKAIEIgFFDQAgACgCDCICBEAgAkE8bCECA0AgARCtASABQTxqIQEgAkFEaiICDQALCyAAKAIIBEAgACgCBBAfCyAAQRRqEI8BIABBnAFqKAIABEAgAEGYAWooAgAQHwsgAEGoAWooAgAEQCAAQaQBaigCABAfCyAAQbgBahBvIABB0AFqKAIABEAgAEHMAWooAgAQHwsgAEHcAWooAgAiAUUNACAAQeABaigCAEUNACABEB8LC8IBAgJ/AX4DQCACQQF0IgRBAXIhAwJAAkACQCAEQQJqIgQgAUkEQCADIAFPDQEgBCADIAAgBEEDdGooAgQoAgAgACADQQN0aigCBCgCAEkbIQMLIAMgAUkEQCACIAFPDQIgACADQQN0aiIEKAIEKAIAIAAgAkEDdGoiAigCBCgCAEkNAwsPC0GsqMIAIAMgARCqAQALQbyowgAgAiABEKoBAAsgAikCACEFIAIgBCkCADcCACAEIAU3AgAgAyECDAALAAvaAQIGfwF+IwAiACAAQUBqQWBxIgAkAAJAQcTdwgAoAgANACAAQRhqQRQQowEgACkDGCEGIABBEGpBFBCjASAAQgA3AiwgACAGNwIkQcjdwgAoAgBByN3CACAAKQMoNwMAIABBADYCIEHE3cIAKAIAIQFBwN3CACAAKQMgNwMAIABBADYCPCAAIAApAxA3AjRB2N3CACgCACEEQdjdwgAgACkDODcDAEHU3cIAKAIAIQVB0N3CACAAKQMwNwMAIAFFDQAEQCABEB8LIARFDQAgBRAfCyQAQcDdwgALrgEBBH8DQCACQQF0IgRBAXIhAwJAAkACQCAEQQJqIgQgAUkEQCADIAFPDQEgBCADIAAgA0ECdGooAgAgACAEQQJ0aigCAEkbIQMLIAMgAUkEQCACIAFPDQIgACACQQJ0aiICKAIAIgQgACADQQJ0aiIFKAIAIgZJDQMLDwtBrKjCACADIAEQqgEAC0G8qMIAIAIgARCqAQALIAIgBjYCACAFIAQ2AgAgAyECDAALAAuyAQEEfwNAIAJBAXQiBEEBciEDAkACQAJAIARBAmoiBCABSQRAIAMgAU8NASAEIAMgACADQQJ0aigCACAAIARBAnRqKAIAELUBGyEDCyADIAFJBEAgAiABTw0CIAAgAkECdGoiAigCACIEIAAgA0ECdGoiBSgCACIGELUBDQMLDwtBrKjCACADIAEQqgEAC0G8qMIAIAIgARCqAQALIAIgBjYCACAFIAQ2AgAgAyECDAALAAunAQIEfwF8AkAgACgCACIDRQ0AA0AgACgCBCAAKAIAIgQgAmxBA3RqIAS4OQMAIAAoAgQgAWogACgCALg5AwAgAUEIaiEBIAMgAkEBaiICRw0ACyAAKAIAIgJBAkkNAEEBIQEDQCAAKAIEIAAoAgAgAWxBA3RqQQhqIAFBf2q4IgU5AwAgACgCBCABIAAoAgBqQQN0aiAFOQMAIAIgAUEBaiIBRw0ACwsLqwEBBX8CQCABQQJJDQAgAUECdCAAakF8aiIFKAIAIgIgACABQX5qIgNBAnRqIgQoAgAiBhC1AUUNACAFIAY2AgACQCADRQ0AIAIgAUECdCAAaiIAQXRqKAIAELUBRQ0AIABBcGohAANAAkAgACIBQQhqIAFBBGooAgA2AgAgA0F/aiIDRQ0AIAFBfGohACACIAEoAgAQtQENAQsLIAFBBGohBAsgBCACNgIACwvPAQMBfwJ+AnwjAEEQayEBIAC9IgJCNIhC/w+DIgNCsghYBHwgAJogACACQgBTGyEEAnwCQCADQv4HWgRAIAREAAAAAAAAMEOgRAAAAAAAADDDoCAEoSIFRAAAAAAAAOA/ZA0BIAQgBaAiACAFRAAAAAAAAOC/ZUEBcw0CGiAARAAAAAAAAPA/oAwCCyABIAREAAAAAAAAMEOgOQMIIAErAwgaIABEAAAAAAAAAACiDwsgBCAFoEQAAAAAAADwv6ALIgCaIAAgAkIAUxsFIAALC7IBAQV/AkACQCABKAIAIgIEQCABKAIEIQQMAQsgASgCDCEFIAEoAgghAwNAIAMgBU8NAiADKAIAIQIgASADQQRqIgM2AgggASABKAIEQaABaiIENgIEIAEgAkGAgYKEeHEiBkGAgYKEeHMiAjYCACAGQYCBgoR4Rg0ACwsgASACQX9qIAJxNgIAIAEgASgCEEF/ajYCECAAIAQgAmhBA3ZBKGxqQSgQvgEaDwsgAEECOgAkC6sBAQd/AkACQCAAKAIIIgNBAkkEQCADIQEMAQsgACgCACIGQQRqIQJBASEBQQEhBANAIAIoAgAiByABQQJ0IAZqIgVBfGooAgBHBEAgASAERwRAIAIgBSgCADYCACAFIAc2AgALIAFBAWohAQsgAkEEaiECIAMgBEEBaiIERw0ACyABIANLBEBBta3CAEEcQdStwgAQvQEACyAAKAIIIAFJDQELIAAgATYCCAsLoQEBAn8CQAJAIAAoAgQiAyABayACSQRAIAEgAmoiAiABSQ0BIANBAXQiASACIAEgAksbIgFB/////wNxIAFHDQEgAUECdCIEQQBIDQECfyADRQRAIARBBBDWAQwBCyAAKAIAIANBAnRBBCAEENMBCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQ0gEACyAEQQRBtOHCACgCACIAQQIgABsRAAAAC6ABAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf///z9xIAFHDQEgAUEFdCIEQQBIDQECfyADRQRAIARBCBDWAQwBCyAAKAIAIANBBXRBCCAEENMBCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQ0gEACyAEQQhBtOHCACgCACIAQQIgABsRAAAAC6EBAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf////8BcSABRw0BIAFBA3QiBEEASA0BAn8gA0UEQCAEQQgQ1gEMAQsgACgCACADQQN0QQggBBDTAQsiAkUNAiAAIAE2AgQgACACNgIACw8LENIBAAsgBEEIQbThwgAoAgAiAEECIAAbEQAAAAuwAQEDfwJAAkACQCABKAIEIgMgASgCCCICRgRAIAMhAgwBCyADIAJJDQECQCACRQRAIAMEQCABKAIAEB8LIAFBBDYCAEEAIQIMAQsgASgCACADQQJ0QQQgAkECdCIEENMBIgNFDQMgASADNgIACyABIAI2AgQLIAAgAjYCBCAAIAEoAgA2AgAPC0Hd2sIAQSRBhNvCABC9AQALIARBBEG04cIAKAIAIgBBAiAAGxEAAAALngEBCH8gACgCBCgCACIDIAEoAgAiBkHkAGxqQUBrIQcgAyACKAIAIghB5ABsakFAayEJAkADQCAEQQRqIgNBKEYNASAEIAlqIQogBCAHaiEFIAMhBEF/IAUoAgAiBSAKKAIAIgNHIAUgA0gbIgNFDQALIANB/wFxQf8BRw0AIAEgCDYCACACIAY2AgAgACgCCCIAIAAoAgBBAWo2AgALC5gBAQN/IAFBAXYiAwRAIANBf2ohAgNAIAAgASACEHwgAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQQJ0IABqQXxqIQIgASEDA0AgA0F/aiIEIAFPDQIgACgCACEDIAAgAigCADYCACACIAM2AgAgACAEQQAQfCACQXxqIQIgBCIDQQFLDQALCw8LQcCnwgAgA0F/aiABEKoBAAuYAQEDfyABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB9IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUECdCAAakF8aiECIAEhAwNAIANBf2oiBCABTw0CIAAoAgAhAyAAIAIoAgA2AgAgAiADNgIAIAAgBEEAEH0gAkF8aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCqAQALmgECA38BfiABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB6IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUEDdCAAakF4aiECIAEhAwNAIANBf2oiBCABTw0CIAApAgAhBSAAIAIpAgA3AgAgAiAFNwIAIAAgBEEAEHogAkF4aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCqAQALnAEBAn8CQAJAIAEgACgCBEYEQCABQQFqIgIgAUkNASABQQF0IgMgAiADIAJLGyICQf////8BcSACRw0BIAJBA3QiA0EASA0BAn8gAUUEQCADQQQQ1gEMAQsgACgCACABQQN0QQQgAxDTAQsiAUUNAiAAIAI2AgQgACABNgIACw8LENIBAAsgA0EEQbThwgAoAgAiAEECIAAbEQAAAAu3AQIEfwJ+IwBBMGsiACQAAkBBiNzCACgCAA0AIABBGGpBChBTIABBEGogAEEsaigCACIDNgIAIABBCGogAEEkaikCACIENwMAQYTcwgAoAgAhAUGI3MIAKAIAIQJBhNzCACAAKQIcIgU3AgBBjNzCACAENwIAQZTcwgAgAzYCACAAIAU3AwBBgNzCAEEANgIAIAFFIAJFcg0AIABBGGogAUEBahCfASACEB8LIABBMGokAEGA3MIAC7cBAgR/An4jAEEwayIAJAACQEHQ3MIAKAIADQAgAEEYakEKEFMgAEEQaiAAQSxqKAIAIgM2AgAgAEEIaiAAQSRqKQIAIgQ3AwBBzNzCACgCACEBQdDcwgAoAgAhAkHM3MIAIAApAhwiBTcCAEHU3MIAIAQ3AgBB3NzCACADNgIAIAAgBTcDAEHI3MIAQQA2AgAgAUUgAkVyDQAgAEEYaiABQQFqEJ8BIAIQHwsgAEEwaiQAQcjcwgALqAEBAn8CQAJAAkAgASgCBCIDIAEoAggiAkYEQCADIQIMAQsgAyACSQ0BAkAgAkUEQCADBEAgASgCABAfCyABQQE2AgBBACECDAELIAEoAgAgA0EBIAIQ0wEiA0UNAyABIAM2AgALIAEgAjYCBAsgACACNgIEIAAgASgCADYCAA8LQd3awgBBJEGE28IAEL0BAAsgAkEBQbThwgAoAgAiAEECIAAbEQAAAAuMAQECfyMAQRBrIgEkACAAKAIEIgIEQCABIAJBAWoQpQEgAEEIaigCABAfCyAAQRhqEHAgAEEsahBoIABBQGsQaCAAQdwAaigCAARAIABB2ABqKAIAEB8LIABB7ABqKAIABEAgAEHoAGooAgAQHwsgAEH8AGooAgAEQCAAQfgAaigCABAfCyABQRBqJAALogEBAn8jAEEQayIDJAACQAJAAkACQEEBQQAgAUEBRxtFBEAgAyABNgIMIAMgADYCCAwBCyABQQFNDQIgAEEBaiIELAAAQb9/TA0CIAMgATYCDCADIAA2AgggBCwAAEG/f0wNAQtBASEBIAAgAkYNAiACIABBARC4AQ0BDAILIANBCGoiACgCACAAKAIEQQBBARAZAAtBACEBCyADQRBqJAAgAQuKAQEEfyAAIAAoAhAiAkEBaiIBNgIQAkAgASACSQ0AIABBDGogAEEIaiAAKAIAIgJBAUYbKAIAIgMgAUYNACAAQQxqIABBCGogAhshBANAIAMgAUsEQCAAKAIEIAFqLAAAQb9/Sg0CCyAAIAFBAWoiAjYCECACIAFJDQEgBCgCACIDIAIiAUcNAAsLC4sBAQF/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQQBIDQECfyADRQRAIAFBARDWAQwBCyAAKAIAIANBASABENMBCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQ0gEACyABQQFBtOHCACgCACIAQQIgABsRAAAAC4YBAQR/IAAgACgCEEF/aiIBNgIQAkAgAUUNACAAQQxqIABBCGogACgCACICQQFGGygCACIDIAFGDQAgAEEMaiAAQQhqIAIbIQQDQCADIAFLBEAgACgCBCABaiwAAEG/f0oNAgsgACABQX9qIgI2AhAgAUEBRg0BIAQoAgAiAyACIgFHDQALCwudAQIDfwF8IAAgACgCCCACIAFrEIUBIAAoAgghAyAAIAEgAkcEfyAAKAIAIANBA3RqIQQgASEAA0AgAC0AACEFIABBAWohAEQAAAAAAADwPyEGAkAgBUF8aiIFQQNLDQACQCAFQQFrDgIBAQALRAAAAAAAAOA/IQYLIAQgBjkDACAEQQhqIQQgACACRw0ACyACIAMgAWtqBSADCzYCCAuYAQEDfyAAKAIQIQIgACgCFCEDAkACQCAAQafYwgBBBUHZABAyRQ0AIABBiMXCAEEEQeEAQfkAEDFFDQBBASEBIABBiMXCAEEEQeEAEDINAQsgACAAKAIUIAIgA2tqNgIQQQAhASAAQYjFwgBBBEHhABAyRQ0AIABBiMXCAEEEQeEAQfkAEDFFDQAgACgCECAAKAIYTQ8LIAELgwEBA38jAEEgayICJAAgASgCACEDIAJBCGogASgCCCIBEKMBIAJBADYCGCACIAIpAwg3AxAgAkEQakEAIAEQgwEgAiABIAIoAhgiBGo2AhggAigCECAEQQJ0aiADIAFBAnQQvgEaIABBCGogAigCGDYCACAAIAIpAxA3AgAgAkEgaiQAC2wBBX8gAyABIAEgA0sbIQgDQAJAIAQgCEkEQCAEQQFqIgUgBE8NAQtBfyABIANHIAEgA0kbDwsgAigCACEGIAAoAgAhByACQQRqIQIgAEEEaiEAIAUhBEF/IAYgB0cgByAGSRsiBUUNAAsgBQt+AQF/AkACQCABIAFB/////wFxRgRAIAFBA3QiA0F/TA0BAkAgA0UEQEEIIQIMAQsCfyACRQRAIANBCBDWAQwBCyADEMIBCyICRQ0DCyAAIAE2AgQgACACNgIADwsQ1wEACxDXAQALIANBCEG04cIAKAIAIgBBAiAAGxEAAAALgQEBAX8jAEFAaiIEJAAgBCABNgIMIAQgADYCCCAEIAM2AhQgBCACNgIQIARBLGpBAjYCACAEQTxqQQM2AgAgBEICNwIcIARBlKrAADYCGCAEQQQ2AjQgBCAEQTBqNgIoIAQgBEEQajYCOCAEIARBCGo2AjAgBEEYakGoqsAAEMMBAAtwAQV/IAAoAgQhAyABpyECIAAoAgAhBEEAIQADQCAAQQRqIgAgAiAEcSIFaiECIAMgBWooAABBgIGChHhxIgZFDQALIAMgBmhBA3YgBWogBHEiAmosAABBAE4EfyADKAIAQYCBgoR4cWhBA3YFIAILC3QBAn8gACAAKAIIIAEQgwEgACgCACAAKAIIIgJBAnRqIQMCQAJAIAFBAk8EQCABQX9qIQEDQCADQQA2AgAgAkEBaiECIANBBGohAyABQX9qIgENAAsMAQsgAUUNAQsgA0EANgIAIAJBAWohAgsgACACNgIIC28BAX8CQCABIABPBEAgAkUNASAAIQMDQCADIAEtAAA6AAAgAUEBaiEBIANBAWohAyACQX9qIgINAAsMAQsgAkUNACABQX9qIQEgAEF/aiEDA0AgAiADaiABIAJqLQAAOgAAIAJBf2oiAg0ACwsgAAtmAgF/AX4gAa1CGH4iA0IgiKcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiADp2oiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALZwIBfwF+IAGtQuwBfiIDQiCIpwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAOnaiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtmAgF/AX4gAa1CKH4iA0IgiKcEQCAAQQA2AgQPCwJAAkAgAUELakF4cSICIAFBBGpJDQAgAiADp2oiASACSQ0AIAFBeUkNAQsgAEEANgIEDwsgACACNgIIIABBCDYCBCAAIAE2AgALcAICfwF+AkACQCABrULkAH4iBEIgiKdFBEAgBKciAkF/TA0BAkAgAkUEQEEEIQMMAQsgAkEEENYBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDXAQALENcBAAsgAkEEQbThwgAoAgAiAEECIAAbEQAAAAtnAQN/IwBBIGsiAiQAAkAgACgCACABEFsNACABQRxqKAIAIQMgASgCGCACQgQ3AxggAkIBNwIMIAJBvIXAADYCCCADIAJBCGoQGg0AIAAoAgQgARBbIAJBIGokAA8LIAJBIGokAEEBC24BAn8CQAJAIAEgAUH/////AHFGBEAgAUEEdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ1gEiA0UNAwsgACABNgIEIAAgAzYCAA8LENcBAAsQ1wEACyACQQRBtOHCACgCACIAQQIgABsRAAAAC24BAn8CQAJAIAEgAUH/////A3FGBEAgAUECdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ1gEiA0UNAwsgACABNgIEIAAgAzYCAA8LENcBAAsQ1wEACyACQQRBtOHCACgCACIAQQIgABsRAAAAC24BAn8CQAJAIAEgAUH/////AXFGBEAgAUEDdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ1gEiA0UNAwsgACABNgIEIAAgAzYCAA8LENcBAAsQ1wEACyACQQRBtOHCACgCACIAQQIgABsRAAAAC2YBAX8gASABQf////8BcUcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiABQQN0aiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtmAQF/IAEgAUH/////AHFHBEAgAEEANgIEDwsCQAJAIAFBB2pBfHEiAiABQQRqSQ0AIAIgAUEEdGoiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALbQECfwJAAkAgASABQf///z9xRgRAIAFBBXQiAkF/TA0BAkAgAkUEQEEIIQMMAQsgAkEIENYBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDXAQALENcBAAsgAkEIQbThwgAoAgAiAEECIAAbEQAAAAtgAQJ/AkAgACgCACIBRQ0AIABBCGooAgAiAgRAIAJBBHQhAiABQQhqIQEDQCABKAIABEAgAUF8aigCABAfCyABQRBqIQEgAkFwaiICDQALCyAAKAIERQ0AIAAoAgAQHwsLcAEBfyMAQTBrIgIkACACIAE2AgQgAiAANgIAIAJBHGpBAjYCACACQSxqQQU2AgAgAkICNwIMIAJBwILAADYCCCACQQU2AiQgAiACQSBqNgIYIAIgAkEEajYCKCACIAI2AiAgAkEIakHQgsAAEMMBAAttAQF/IwBBMGsiAyQAIAMgAjYCBCADIAE2AgAgA0EcakECNgIAIANBLGpBBTYCACADQgI3AgwgA0GkgMAANgIIIANBBTYCJCADIANBIGo2AhggAyADNgIoIAMgA0EEajYCICADQQhqIAAQwwEAC3ABAX8jAEEwayICJAAgAiABNgIEIAIgADYCACACQRxqQQI2AgAgAkEsakEFNgIAIAJCAjcCDCACQYiDwAA2AgggAkEFNgIkIAIgAkEgajYCGCACIAJBBGo2AiggAiACNgIgIAJBCGpBmIPAABDDAQALVgECfyMAQSBrIgIkACABQRxqKAIAIQMgASgCGCACQRhqIABBEGopAgA3AwAgAkEQaiAAQQhqKQIANwMAIAIgACkCADcDCCADIAJBCGoQGiACQSBqJAALUwAgAEEMaigCAARAIAAoAggQHwsgAEEYaigCAARAIABBFGooAgAQHwsgAEEkaigCAARAIABBIGooAgAQHwsgAEEwaigCAARAIABBLGooAgAQHwsLZAECfyMAQSBrIgIkACABKAIAIQMgAkEIaiABKAIIIgEQowEgAkEANgIYIAIgAikDCDcDECACQRBqIAMgAyABQQJ0ahC8ASAAQQhqIAIoAhg2AgAgACACKQMQNwIAIAJBIGokAAtaAQN/AkAgAUEIaigCACICIAEoAgQiA08EQCABQRxqKAIAIgQgAkkNASABKAIYIQEgACACIANrNgIEIAAgASADQQJ0ajYCAA8LIAMgAhCrAQALIAIgBBCpAQALVwEDfwJAIAFBCGooAgAiAiABKAIEIgNPBEAgAUEkaigCACIEIAJJDQEgASgCICEBIAAgAiADazYCBCAAIAEgA2o2AgAPCyADIAIQqwEACyACIAQQqQEAC2oBAn9BASEAAkACQEGo4cIAKAIAQQFHBEBBqOHCAEKBgICAEDcDAAwBC0Gs4cIAQazhwgAoAgBBAWoiADYCACAAQQJLDQELQbDhwgAoAgAiAUF/TA0AQbDhwgAgATYCACAAQQFLDQAACwALWAEDfyAAKAIEKAIAIgMgAigCACIEQQxsakEDIAMgASgCACIFQQxsakEDEJcBQf8BcUH/AUYEQCABIAQ2AgAgAiAFNgIAIAAoAggiACAAKAIAQQFqNgIACwtLAQN/IABBDGooAgAiAwRAIAAoAgQgA0E8bGpBRGohAiADIQEDQCACEK0BIAJBRGohAiABQX9qIgENAAsLIABBADYCACAAIAE2AgwLVAEBfwJAIAFBf0oEQAJAIAFFBEBBASECDAELIAFBARDWASICRQ0CCyAAIAE2AgQgACACNgIADwsQ1wEACyABQQFBtOHCACgCACIAQQIgABsRAAAAC04BAn9BfyABKAI4IgIgACgCOCIDRyACIANJGyICBH8gAgUgAEEgaigCACAAQShqKAIAIAFBIGooAgAgAUEoaigCABCXAQtB/wFxQf8BRgtSAQN/IAAoAgQoAgAiAyACKAIAIgRBAnRqKAIAIAMgASgCACIFQQJ0aigCABC1AQRAIAEgBDYCACACIAU2AgAgACgCCCIAIAAoAgBBAWo2AgALC04BAX8jAEEQayICJAAgAiAAIAFBAUEBEGMCQCACKAIAQQFGBEAgAkEIaigCAEUNAUGr28IAQShB1NvCABC9AQALIAJBEGokAA8LENIBAAtDAQN/AkAgAkUNAANAIAAtAAAiBCABLQAAIgVGBEAgAUEBaiEBIABBAWohACACQX9qIgINAQwCCwsgBCAFayEDCyADC0oAAn8gAUGAgMQARwRAQQEgACgCGCABIABBHGooAgAoAhARAQANARoLIAJFBEBBAA8LIAAoAhggAiADIABBHGooAgAoAgwRAwALC0YBAn8gACgCCCICIAFLBEAgACgCACACQeQAbGpBnH9qIQMDQCADEMEBIANBnH9qIQMgASACQX9qIgJHDQALCyAAIAI2AggLQwEBfyAAIAEgAkHAzMIAQQEQNCECIAAoAhwiAyABTwRAIAAgAiADajYCHAsgACgCICIDIAFPBEAgACACIANqNgIgCws6AQF/IAAgACgCCCACIAFrQQJ2IgIQgwEgACAAKAIIIgMgAmo2AgggACgCACADQQJ0aiACIAEgAhB1CzsBAX8jAEEgayIDJAAgA0IENwMQIANCATcCBCADIAE2AhwgAyAANgIYIAMgA0EYajYCACADIAIQwwEACzMBAX8gAgRAIAAhAwNAIAMgAS0AADoAACADQQFqIQMgAUEBaiEBIAJBf2oiAg0ACwsgAAtFAQJ/IwBBEGsiASQAIAAoAgwhAiAAKAIIRQRAQYzawgBBK0G42sIAEL0BAAsgASACKQIINwMIIAEgAikCADcDABCxAQALKwACQCAAQXxLDQAgAEUEQEEEDwsgACAAQX1JQQJ0ENYBIgBFDQAgAA8LAAsmACAAQSxqKAIABEAgACgCKBAfCyAAQThqKAIABEAgACgCNBAfCwsqAQF/AkAgABADIgFFDQAgAUF8ai0AAEEDcUUNACABQQAgABDEARoLIAELMgEBfyMAQRBrIgIkACACIAE2AgwgAiAANgIIIAJBtIDAADYCBCACQQE2AgAgAhC/AQALKQEBfyACBEAgACEDA0AgAyABOgAAIANBAWohAyACQX9qIgINAAsLIAALPgBBoNzCACgCAEUEQEGk3MIAQgQ3AgBBoNzCAEGwpsIANgIAQZjcwgBCADcCAEGs3MIAQQA2AgALQZjcwgALPgBBuNzCACgCAEUEQEG83MIAQgQ3AgBBuNzCAEGwpsIANgIAQbDcwgBCADcCAEHE3MIAQQA2AgALQbDcwgALIAACQCABQXxLDQAgACABQQQgAhDTASIARQ0AIAAPCwALJgAgACgCACABIAIQtgEgACgCACACIAMQtgEgACgCACABIAIQtgELJgAgACgCACABIAIQhwEgACgCACACIAMQhwEgACgCACABIAIQhwELJgAgACgCACABIAIQsgEgACgCACACIAMQsgEgACgCACABIAIQsgELJgEBfyAAKAIAIgEoAgAgASgCBCAAKAIEKAIAIAAoAggoAgAQGQALFAAgACAAIAEgACABYxsgASABYhsLHAAgASgCGEH4qcAAQQsgAUEcaigCACgCDBEDAAscACABKAIYQYOqwABBDiABQRxqKAIAKAIMEQMACxwAIAEoAhhBgdrCAEELIAFBHGooAgAoAgwRAwALFAAgACgCACABIAAoAgQoAgwRAQALCwAgAQRAIAAQHwsLEgBBgIDAAEERQZSAwAAQvQEACwwAIAAgASACIAMQIQsLACAANQIAIAEQTwsGABCxAQALGQACfyABQQlPBEAgASAAEEQMAQsgABADCwsGABDSAQALDQBCs5rmjsnyhtztAAsDAAELAwABCwv1qwLbDABBgIDAAAv8BWNhcGFjaXR5IG92ZXJmbG93AAAAlK0QABcAAAAJAwAABQAAAEQAEAAgAAAAZAAQABIAAAALAAAAAAAAAAEAAAAMAAAAaW5kZXggb3V0IG9mIGJvdW5kczogdGhlIGxlbiBpcyAgYnV0IHRoZSBpbmRleCBpcyAwMDAxMDIwMzA0MDUwNjA3MDgwOTEwMTExMjEzMTQxNTE2MTcxODE5MjAyMTIyMjMyNDI1MjYyNzI4MjkzMDMxMzIzMzM0MzUzNjM3MzgzOTQwNDE0MjQzNDQ0NTQ2NDc0ODQ5NTA1MTUyNTM1NDU1NTY1NzU4NTk2MDYxNjI2MzY0NjU2NjY3Njg2OTcwNzE3MjczNzQ3NTc2Nzc3ODc5ODA4MTgyODM4NDg1ODY4Nzg4ODk5MDkxOTI5Mzk0OTU5Njk3OTg5OQAAYAEQAAYAAABmARAAIgAAACCgEAAYAAAAaAoAAAUAAABpbmRleCAgb3V0IG9mIHJhbmdlIGZvciBzbGljZSBvZiBsZW5ndGggqAEQABYAAAC+ARAADQAAACCgEAAYAAAAbgoAAAUAAABzbGljZSBpbmRleCBzdGFydHMgYXQgIGJ1dCBlbmRzIGF0IGAweFsuLi5dAHoCEAALAAAA4hQQABYAAADLARAAAQAAAGQCEAAWAAAAAwgAAAkAAADAFBAADgAAAM4UEAAEAAAA0hQQABAAAADLARAAAQAAAGQCEAAWAAAABwgAAAUAAAB6AhAACwAAAIUCEAAmAAAAqwIQAAgAAACzAhAABgAAAMsBEAABAAAAZAIQABYAAAAUCAAABQAAAHNyYy9saWJjb3JlL3N0ci9tb2QucnNieXRlIGluZGV4ICBpcyBub3QgYSBjaGFyIGJvdW5kYXJ5OyBpdCBpcyBpbnNpZGUgIChieXRlcyApIG9mIGAAAAD6AhAAAgAAAOQCEAAWAAAAYAQAABEAAADkAhAAFgAAAFQEAAAoAAAAc3JjL2xpYmNvcmUvZm10L21vZC5ycy4uAEHghsAACw7//////////////////wBBkIfAAAsC+AMAQbKHwAALB/7/////v7YAQcKHwAALDf8HAAAAAAD4//8AAAEAQdqHwAALEMCfnz0AAAAAAgAAAP///wcAQfSHwAALuBXA/wEAAAAAAAD4DyCgDhAASgAAAPAQEAAAAgAA8BIQADoAAAAAAQIDBAUGBwgJCAoLDA0ODxAREhMUAhUWFxgZGhscHR4fIAICAgICAgICAgIhAgICAgICAgICAgICAgIiIyQlJgInAigCAgIpKisCLC0uLzACAjECAgIyAgICAgICAgIzAgI0AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI1AjYCNwICAgICAgICOAI5AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI6OzwCAgICPQICPj9AQUJDREVGAgICRwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJIAgICAgICAgICAgJJAgICAgI7AgABAgICAgMCAgICBAIFBgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIHAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAAQMFBQYGAwcGCAgJEQocCxkMFA0SDg0PBBADEhITCRYBFwUYAhkDGgccAh0BHxYgAysELAItCy4BMAMxAjIBpwKpAqoEqwj6AvsF/QT+A/8JrXh5i42iMFdYi4yQHB3dDg9LTPv8Li8/XF1fteKEjY6RkqmxurvFxsnK3uTl/wAEERIpMTQ3Ojs9SUpdhI6SqbG0urvGys7P5OUABA0OERIpMTQ6O0VGSUpeZGWEkZudyc7PDREpRUlXZGWNkam0urvFyd/k5fAEDRFFSWRlgIGEsry+v9XX8PGDhYukpr6/xcfOz9rbSJi9zcbOz0lOT1dZXl+Jjo+xtre/wcbH1xEWF1tc9vf+/4ANbXHe3w4PH25vHB1ffX6ur7u8+hYXHh9GR05PWFpcXn5/tcXU1dzw8fVyc490dZaXL18mLi+nr7e/x8/X35pAl5gwjx/Awc7/Tk9aWwcIDxAnL+7vbm83PT9CRZCR/v9TZ3XIydDR2Nnn/v8AIF8igt8EgkQIGwQGEYGsDoCrNR4VgOADGQgBBC8ENAQHAwEHBgcRClAPEgdVCAIEHAoJAwgDBwMCAwMDDAQFAwsGAQ4VBToDEQcGBRAHVwcCBxUNUARDAy0DAQQRBg8MOgQdJV8gbQRqJYDIBYKwAxoGgv0DWQcVCxcJFAwUDGoGCgYaBlkHKwVGCiwEDAQBAzELLAQaBgsDgKwGCgYfQUwELQN0CDwDDwM8BzgIKwWC/xEYCC8RLQMgECEPgIwEgpcZCxWIlAUvBTsHAg4YCYCwMHQMgNYaDAWA/wWAtgUkDJvGCtIwEISNAzcJgVwUgLgIgMcwNQQKBjgIRggMBnQLHgNaBFkJgIMYHAoWCUgIgIoGq6QMFwQxoQSB2iYHDAUFgKURgW0QeCgqBkwEgI0EgL4DGwMPDQAGAQEDAQQCCAgJAgoFCwIQAREEEgUTERQCFQIXAhkEHAUdCCQBagNrArwC0QLUDNUJ1gLXAtoB4AXhAugC7iDwBPkG+gIMJzs+Tk+Pnp6fBgcJNj0+VvPQ0QQUGDY3Vle9Nc7P4BKHiY6eBA0OERIpMTQ6RUZJSk5PZGVaXLa3GxyoqdjZCTeQkagHCjs+ZmmPkm9f7u9aYpqbJyhVnaCho6SnqK26vMQGCwwVHTo/RVGmp8zNoAcZGiIlPj/FxgQgIyUmKDM4OkhKTFBTVVZYWlxeYGNlZmtzeH1/iqSqr7DA0Axyo6TLzG5vXiJ7BQMELQNlBAEvLoCCHQMxDxwEJAkeBSsFRAQOKoCqBiQEJAQoCDQLAYCQgTcJFgoIgJg5A2MICTAWBSEDGwUBQDgESwUvBAoHCQdAICcEDAk2AzoFGgcEDAdQSTczDTMHLggKgSYfgIEoCCqAhhcJTgQeD0MOGQcKBkcJJwl1Cz9BKgY7BQoGUQYBBRADBYCLYCBICAqApl4iRQsKBg0TOQcKNiwEEIDAPGRTDAGAoEUbSAhTHTmBB0YKHQNHSTcDDggKBjkHCoE2GYDHMg2Dm2Z1C4DEiryEL4/RgkehuYI5ByoEAmAmCkYKKAUTgrBbZUsEOQcRQAQcl/gIgvOlDYEfMQMRBAiBjIkEawUNAwkHEJNggPYKcwhuF0aAmhQMVwkZgIeBRwOFQg8VhVArgNUtAxoEAoFwOgUBhQCA1ylMBAoEAoMRREw9gMI8BgEEVQUbNAKBDiwEZAxWCg0DXQM9OR0NLAQJBwIOBoCag9YKDQMLBXQMWQcMFAwEOAgKBigIHlJ3AzEDgKYMFAQDBQMNBoVqAAAAgA4QACAAAAAnAAAAGQAAAIAOEAAgAAAAKAAAACAAAACADhAAIAAAACoAAAAZAAAAgA4QACAAAAArAAAAGAAAAIAOEAAgAAAALAAAACAAAABzcmMvbGliY29yZS91bmljb2RlL2Jvb2xfdHJpZS5ycwAAwPvvPgAAAAAADgBBup3AAAuSAfj/+////wcAAAAAAAAU/iH+AAwAAAACAAAAAAAAUB4ggAAMAABABgAAAAAAABCGOQIAAAAjAL4hAAAMAAD8AgAAAAAAANAeIMAADAAAAAQAAAAAAABAASCAAAAAAAARAAAAAAAAwME9YAAMAAAAAgAAAAAAAJBEMGAADAAAAAMAAAAAAABYHiCAAAwAAAAAhFyAAEHWnsAACwTyB4B/AEHmnsAACwTyHwA/AEHznsAACxYDAACgAgAAAAAAAP5/3+D//v///x9AAEGVn8AAC6UB4P1mAAAAwwEAHgBkIAAgAAAAAAAAAOAAAAAAAAAcAAAAHAAAAAwAAAAMAAAAAAAAALA/QP4PIAAAAAAAOAAAAAAAAGAAAAAAAgAAAAAAAIcBBA4AAIAJAAAAAAAAQH/lH/ifAAAAAAAA/38PAAAAAADwFwQAAAAA+A8AAwAAADw7AAAAAAAAQKMDAAAAAAAA8M8AAAD3//0hEAP/////////+wAQAEHCoMAACw3/////AQAAAAAAAIADAEHXoMAACxWAAAAAAP////8AAAAAAPwAAAAAAAYAQfWgwAALB4D3PwAAAMAAQYahwAALLwMARAgAAGAAAAAwAAAA//8DgAAAAADAPwAAgP8DAAAAAAAHAAAAAADIMwAAAAAgAEG9ocAACzF+ZgAIEAAAAAAAEAAAAAAAAJ3BAgAAAAAwQAAAAAAAICEAAAAAAEAAAAAA//8AAP//AEH3ocAACwcBAAAAAgADAEGYosAACwQEAAAFAEGkosAACwEGAEGtosAACz8HAAAICQoACwwNDg8AABAREgAAExQVFgAAFxgZGhsAHAAAAB0AAAAAAAAeHyAhAAAAAAAiACMAJCUmAAAAACcAQdujwAALAigpAEHto8AACwIqKwBBoqTAAAsBLABBtaTAAAsFLS4AAC8AQdikwAALAzAxMgBB8KTAAAsMMwAAACkAAAAAAAA0AEGTpcAACwM1ADYAQbClwAALCDc4AAA4ODg5AEH/pcAACwYgAAAAAAEAQY6mwAALVMAHbvAAAAAAAIcAAAAAYAAAAAAAAADwAAAAwP8BAAAAAAACAAAAAAAA/38AAAAAAACAAwAAAAAAeAYHAAAAgO8fAAAAAAAAAAgAAwAAAAAAwH8AHgBB7abAAAsagNNAAAAAgPgHAAADAAAAAAAAWAEAgADAHx8AQY+nwAALBf9cAABAAEGep8AACwP5pQ0AQa2nwAALB4A8sAEAADAAQb6nwAALA/inAQBBzafAAAsvKL8AAAAA4LwPAAAAAAAAAID/BgAA8AwBAAAA/gcAAAAA+HmAAH4OAAAAAAD8fwMAQYaowAALE3+/AAD8///8bQAAAAAAAAB+tL8AQaKowAALAaMAQa6owAALHRgAAAAAAAAAHwAAAAAAAAB/AACAAAAAAAAAAIAHAEHTqMAACwFgAEHcqMAAC0agwwf45w8AAAA8AAAcAAAAAAAAAP///////3/4//////8fIAAQAAD4/v8AAH////nbBwAAAAAAAADwAAAAAH8AAAAAAPAHAEGsqcAAC/kC////////////////////////AABiZWdpbiA8PSBlbmQgKCA8PSApIHdoZW4gc2xpY2luZyBgIGlzIG91dCBvZiBib3VuZHMgb2YgYEJvcnJvd0Vycm9yQm9ycm93TXV0RXJyb3IAAAAsrBAAAAAAACQVEAACAAAAOiAAADgVEAAVAAAAjQQAAAUAAABzcmMvbGliY29yZS9yZXN1bHQucnMAAQIBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEDAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQQFAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEDAACADhAAIAAAADoAAAAwAAAAAD4AAAEAQbCswAALLCAAAAABAAAAAQAAAAAAAAD/BwAAAIMAAAAAAIAAAAAAAAEAAAAAAAD/////AEHnrMAACwmAAAAAAAAA/wMAQf6swAALAgxyAEG0rsAACwL/AwBBxq7AAAsC/wMAQeCuwAALHf8DAAAAAAAAYBwQACEAAABoHRAAAAIAAGgfEAAuAEGFr8AACx4BAAIAAQABAAMABAAFAAEABgABAAcABwgAAAAACQcAQa2vwAALAQoAQbuvwAALFwsAAAAMBwAAAAANAA4AAA8AAAcQAAAPAEHhr8AACwYRCQAAEhMAQfGvwAALAxQVFgBB/a/AAAsCFxgAQZOwwAALARkAQaCwwAALCxoAAAAAABsAHB0eAEH4s8AACxgcAAABAAAAAB8AAAcJAAAgAAcAAAAAABAAQdy2wAALhAIHAAAAAAECAwMDBAMDAwMDAwUGBwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwBB7LjAAAswwP8AAAAAAADA//ADAAAAAMD//AAAAAAAwP8HAAAAAADA/wB/AAAAf8D//wEAAP8DAEGkucAACwb//w8A/wMAQbW5wAALFf7/HwAAAAAAwAEAAAAAAP8D/wPA/wBB0rnAAAsK/wcAAAAA/wP/AwBB5rnAAAsU/wMAAAAAAADxAwAA////////5wMAQYS6wAALCP////////8PAEGVusAACw78//8AAAAAAADA////DwBBr7rAAAsMIIAAAAD+AwAHAAA8AEHEusAACzf/AwAAAP/+/wAAAAD/AwAAAAD+/wAAAAAAAD8AAAD/AwAA/wMAAAAAAQIDAAAAAAQFBgAHAAAIAEGJu8AAC0MJCgsMAA0OAA8QEQASEwAAAAAUFQAAAAAWAAAXGAAAABkAFRoAABsAAAAVAAAAAAAcABwAAAAAABwAHR4AAAAAAAAfAEHZu8AACwYgAAAAHAgAQee7wAALASEAQfi7wAALAiIjAEGRvcAACwUIAAAAJABBor3AAAsBJQBBs73AAAsDJgAnAEHHvcAACwEoAEHtvcAACwcdAAAAAAAVAEGLvsAACwMpABwAQZm+wAALBCorACwAQay+wAALAS0AQfC+wAALEoD//////w8A/////////wEADABBjL/AAAsO/v//DwAAAAAPAAAAAgQAQaK/wAALAT4AQay/wAALEv8DAAAAAAD/AAAA/gAAAACA/wBBx7/AAAsF+AAAwA8AQde/wAALFTD///z///////8BAAAAAABgAAAA4ABB9b/AAAsR+AAAAAAA/wAAAP8AAAAAAP4AQY/AwAALJ/wAAAAAAAD/AwAAAAD///9/AAAA4H8AAAAAAB4AAAAAAAAA/P///wBBvsDAAAsUwP8AAP8D/v8fAAAA/wMAAAAA/wMAQd7AwAALNf8PAAAAAP//BwAAAP///x8AAP//HwAAAAAA/////////////////38AAAAA//sDAAAA//9/AEGcwcAACxb//w8AAAAAAP///wEAwP///////4D/AEG+wcAACxT+////////7x4A/v////+//z//HwBB4MHAAAtW/v//B/7//wcAAAAAAAQgBP//f////3/////////////////////////////////////////////////////////////////////////////D/wMAH1AAQcDCwAALiA0gAAAAAADfvEDX///7////////////v///////////////////////A/z///////////////////////////7///9/Av//////AQAAAAD/v7YA////hwcAAAD/B//////////+AMD////////////////vH/7hAJwAAP///////wDg////////////////AwAA/P///wcwBNAmEACGAAAAACsQAIAEAACALxAAbwAAAAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiIyQkJCQkJSYnKCkqKywkJCQkJCQkJC0uLzAxMjM0NTY3ODk6Ozw9Ph8/QEFCQ0RFRiQkJEckJCQkSElKSx9MTR9OT1AfHx8fHx8fHx8fH1FSHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fH1NUJFVWV1hZWh8fHx8fHx9bLFxdXiRfYB8fHx8fHx8fJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkNx8kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCRhJCQkJCQkJCQkJCQkJCQkJCQkYmMkJCQkZGUkYWYkZ2hpaiRrbG1ub0NwcXJzdCR1JCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkdncfHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8kJCQkJHgkeXp7fH0kJCQkfiF/gB+BJIKDhHGFAAECAwQFBgcIBQUJBQoLDAcHBwcHBwcHBwcNDg8HEBEFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBf////z/HwAA////Af8HAAAAAAAA///fPwAA8P/4A////////////+//3+H/DwD+/++f+f///cXjn1mAsA8AAxDuh/n///1tw4cZAl4AAD8A7r/7///97eO/GwEADwAAHu6f+f///e3jnxnAsA8AAgDsxz3WGMf/w8cdgQAAAAAA79/9///9/+PfHWAHDwAAAO/f/f///e/j3x1gQA8ABgDv3/3/////599d8IAPAAD87P9//P//+y9/gF//AAAMAP7///////8HfyAAAAAAAADW9///r///O18gAPAAAAAAAQAAAAAAAAD//v///x/+/wP///7///8fAEHQz8AAC5wB////////f/kAAP//////////ADz/////vyD///////f///////////89fz3//////z3/////PX89/3//////////Pf//////////BwAAAAD//wAA/////////////z8//v///////////////5////7//wf////////////H/wH/3w8A//8PAP//DwD/3w0A////////z///AYAQAEH00MAAC94B//////////////8B//////8H//////////8/AP///3//D/8BAAD///8/HwD//////w////8DAAAAAAAA////D/////////9//v8fAAAAAACAAAAA////////7//vDwAAAAAAAP//////8wD8/////7//AwD///////9/AADgAPz///8//wH//////+cAAAAAAN5vBAAAAACA/x8A//8/P/////8/P/+q////P////////99f3B/PD/8f3B8AAAAAAAACgAAA/x8AAAAAhPwvPlC9//PgQwAA//////8BAEHe0sAAC4QBwP///////wMAAP//////f///////f///////////H3gMAP////+/IP////////+AAAD//38Af39/f39/f3//////AAAAAACAAADgAAAA/gM+H///f+D+//////////////fg///////+//9/AAD///8HAAAAAAAA//////////8AAP8fAEHq08AAC7YL//////8//x///wAMAAD//////3/wjwAAgP/8//////n///////98AAAAAACA/7//////AAAA////////DwAvAAAAAAD86AD8////B/////8HAP///x/////////3/wCAAAD//wB8/z8AAP//f/z/////////fwUAADj//zwAfn5+AH9////////3/wD/////////BwAA/////w8A//9/+P//////D///////P///////AwAAAAB/APjg//1/X9v/////////////////AwAAAPj///////////////8////8////////AAAAAAD/DwAAAAAAAN///////////x8AAAAA/v//B/7//wfA/////Pz8HAAAAAAAAQIDBAUEBAQEBgcICQoLAgIMDQ4PBAQCAgICEBEEBBITFBUWBBcEGBkaGxwdHgQCHyAgIQQEBAQEBAQiIwQkAiMlJiAnAigpBCorLC0EBAIuAi8EBDAxMjM0BDUEBAQ2BDc4BAQ5Ojs8PTYEBAQEPj9ABEFCQwQEBAQkBAQEBAICAgICAgICAgICAgICRAQCRQICAkYEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAgICAgICAgICRQQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICAkcEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICNhQESElKSwQEBAQEBAQEBAQCBAQCTE1OAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJPAgICAgICAgICAgIgBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICFFACAgICAlEEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCUlMEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAJUVVZXWAICAgJZWltcXV4EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBF8EBARgYQQEBAQEYgQEBAQEBAQEBAQEBAQEBAQEBAQEAgICYwJkBAQEBAQEBAQEBAQEBAQEBAQEZWZnBAQEBAQEBAQEaGlqBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgICAgICAgICAgICAgICAgICAgICawICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIFAgICCgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICbAICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJtBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgJuBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAT/7///f///t/8//z8AAAAA////////////////////BwBBqN/AAAt8////////HwD///8f////////AQAAAAAA/////wDg////B///////B////z//////D/8+AAAAAAD///8/AAD/////D/////8P//////8A////////DwAAAP///////38A//8/AP8AAAA//f////+/kf//PwD//38A////fwBBrODAAAtt//83AP//PwD///8D/////////8Bv8O/+//8/AAAAAAD///8f////HwAAAAD//v//HwAAAP///////z8A//8/AP//BwD//wMAAAAAAP8BAAAAAAAA////////BwD//////wAAAP///x+AAP//PwBBpOHAAAuVAv//fwD8////////AQAA////AQAAcAD/////RwAeAAAUAAAAAP//+////59Af73/v/8B/////////wEAAO+f+f///e3jnxmB4A8AAAC7BwCAAAAAALMAAAAAAAAA////////P38AAAA/AAAAAP////////9/EQAAAAAAAAD///////8/Af///+f/BwAA/////////wEAAAAA//////////8AAACAAAAAAP/8///////8GgAAAP///////+d/AAD///////////8gAAAAAP/9/////39/AQAAAAAA/P////z///5/AH/7/////3+0ywAAAL/9////f3sBAAAAAP///wMAAAAA//////9/AAAPAAAAAAAAAH8AQcLjwAALKv///z8AAP///////wAADwAAAPj//+D//wAAAAAAAP+H/////////4D//wBB9OPAAAuAAgsAAAD/////////AAAABwDwAP///////////w///////wf/H/8B/0MAAAAA///f///////////fZN7/6+//////////v+ff3////3tf/P3///////////8//////f//9/////f//9/////f//9/////f/////3////9///3DwAAAAAAAH////nbBwAA//////8fgD8AQAAAAAAAAP//////DwAAHwAAAAAAAACPCAAAAAAAAO////+W/vcKhOqWqpb3917/+/8P7vv/DwAAAAAAAP///wP///8D////AwAAAAAAAP//fwAAAAAA/////wMA////////AQAAAP///z8AQYDmwAALBP7//wcAQZDmwAALMv//f38AAAAAVVVVVVVVVaqqVFVVVVVVK9bO27HV0q4RkKSqSlVV0lVVVVVVVVUFbHpVAEHm5sAACz1FgEDX/v/7DwAAAIAcVVVVkOb///////8AAAAAAABVVVVVAVRVVVVVVVWrKlVVVVVVVVVVVVVVVf7///9/AEH458AACxXwOBAAGgAAAMA5EADAAQAAgDsQABoAQbLowAALAgECAEG+6MAACwIBAwBB4ujAAAsVBAAAAAAABQUGBQcICQoAAAAACwwNAEGC6cAACwIODwBBoOnAAAsEEBEFEgBBie3AAAsHExQAFRYXGABB7O/AAAuEAhkAAAAAAQICAgIDAgICAgICBAUGAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAEH88cAACzr/////vyAAAAAAAAD///////8/AAAA///////nVVVVVVVVVVVVVRVAVVVVVQD/AD8A/wD/AD8AqgD/AEG/8sAACxoPAA8ADwAfAA+EOCc+UD0PwCAAAAD//wAACABB5vLAAAsuwP///wAAAAAAAP//////fwAAAAAAAJ3qJcBVVVVVBSgEAFVVVVVVFQAAVVVVBQBBnPPAAAsVVFVUVVVVVVVVVQBqVShFVVV9X1V0AEG888AACwT+//8HAEHQ88AACwQBAAIDAEHy88AACwEEAEGi9MAACwEFAEG59cAACwEGAEHQ9cAACxAHCAkKCwwNDg8QERITFBUWAEGk9sAACwEXAEHE9sAACwMCGBkAQYj3wAALBf//////AEGW98AAC78B/////w8AAAAAAP///////wcAAAAAAP//////////AAAAAP///wMAAPD//z8AAAD///8DAADQZN4/AAAA////AwAAsOffHwAAAHtf/AEAAPD//z8AAAD///8DAADw//8/AAAA////AwAA8P//PwAAAP///wMAAAD///8BAAAA/P//BwAAAPD//x8AAADA//9/AAAAAP///wEAAAAEAAAAAAAA/////wMAAAD/A////wP///8DAAAAAAAAQQAAAGEAQeD4wAALBUIAAABiAEHw+MAACwVDAAAAYwBBgPnAAAsFRAAAAGQAQZD5wAALBUUAAABlAEGg+cAACwVGAAAAZgBBsPnAAAsFRwAAAGcAQcD5wAALBUgAAABoAEHQ+cAACwVJAAAAaQBB4PnAAAsFSgAAAGoAQfD5wAALBUsAAABrAEGA+sAACwVMAAAAbABBkPrAAAsFTQAAAG0AQaD6wAALBU4AAABuAEGw+sAACwVPAAAAbwBBwPrAAAsFUAAAAHAAQdD6wAALBVEAAABxAEHg+sAACwVSAAAAcgBB8PrAAAsFUwAAAHMAQYD7wAALBVQAAAB0AEGQ+8AACwVVAAAAdQBBoPvAAAsFVgAAAHYAQbD7wAALBVcAAAB3AEHA+8AACwVYAAAAeABB0PvAAAsFWQAAAHkAQeD7wAALBVoAAAB6AEHw+8AACwXAAAAA4ABBgPzAAAsFwQAAAOEAQZD8wAALBcIAAADiAEGg/MAACwXDAAAA4wBBsPzAAAsFxAAAAOQAQcD8wAALBcUAAADlAEHQ/MAACwXGAAAA5gBB4PzAAAsFxwAAAOcAQfD8wAALBcgAAADoAEGA/cAACwXJAAAA6QBBkP3AAAsFygAAAOoAQaD9wAALBcsAAADrAEGw/cAACwXMAAAA7ABBwP3AAAsFzQAAAO0AQdD9wAALBc4AAADuAEHg/cAACwXPAAAA7wBB8P3AAAsF0AAAAPAAQYD+wAALBdEAAADxAEGQ/sAACwXSAAAA8gBBoP7AAAsF0wAAAPMAQbD+wAALBdQAAAD0AEHA/sAACwXVAAAA9QBB0P7AAAsF1gAAAPYAQeD+wAALBdgAAAD4AEHw/sAACwXZAAAA+QBBgP/AAAsF2gAAAPoAQZD/wAALBdsAAAD7AEGg/8AACwXcAAAA/ABBsP/AAAsF3QAAAP0AQcD/wAALBd4AAAD+AEHR/8AACwUBAAABAQBB4P/AAAsGAgEAAAMBAEHw/8AACwYEAQAABQEAQYCAwQALBgYBAAAHAQBBkIDBAAsGCAEAAAkBAEGggMEACwYKAQAACwEAQbCAwQALBgwBAAANAQBBwIDBAAsGDgEAAA8BAEHQgMEACwYQAQAAEQEAQeCAwQALBhIBAAATAQBB8IDBAAsGFAEAABUBAEGAgcEACwYWAQAAFwEAQZCBwQALBhgBAAAZAQBBoIHBAAsGGgEAABsBAEGwgcEACwYcAQAAHQEAQcCBwQALBh4BAAAfAQBB0IHBAAsGIAEAACEBAEHggcEACwYiAQAAIwEAQfCBwQALBiQBAAAlAQBBgILBAAsGJgEAACcBAEGQgsEACwYoAQAAKQEAQaCCwQALBioBAAArAQBBsILBAAsGLAEAAC0BAEHAgsEACwYuAQAALwEAQdCCwQALFjABAABpAAAABwMAAAAAAAAyAQAAMwEAQfCCwQALBjQBAAA1AQBBgIPBAAsGNgEAADcBAEGQg8EACwY5AQAAOgEAQaCDwQALBjsBAAA8AQBBsIPBAAsGPQEAAD4BAEHAg8EACwY/AQAAQAEAQdCDwQALBkEBAABCAQBB4IPBAAsGQwEAAEQBAEHwg8EACwZFAQAARgEAQYCEwQALBkcBAABIAQBBkITBAAsGSgEAAEsBAEGghMEACwZMAQAATQEAQbCEwQALBk4BAABPAQBBwITBAAsGUAEAAFEBAEHQhMEACwZSAQAAUwEAQeCEwQALBlQBAABVAQBB8ITBAAsGVgEAAFcBAEGAhcEACwZYAQAAWQEAQZCFwQALBloBAABbAQBBoIXBAAsGXAEAAF0BAEGwhcEACwZeAQAAXwEAQcCFwQALBmABAABhAQBB0IXBAAsGYgEAAGMBAEHghcEACwZkAQAAZQEAQfCFwQALBmYBAABnAQBBgIbBAAsGaAEAAGkBAEGQhsEACwZqAQAAawEAQaCGwQALBmwBAABtAQBBsIbBAAsGbgEAAG8BAEHAhsEACwZwAQAAcQEAQdCGwQALBnIBAABzAQBB4IbBAAsGdAEAAHUBAEHwhsEACwZ2AQAAdwEAQYCHwQALBXgBAAD/AEGQh8EACwZ5AQAAegEAQaCHwQALBnsBAAB8AQBBsIfBAAsGfQEAAH4BAEHAh8EACwaBAQAAUwIAQdCHwQALBoIBAACDAQBB4IfBAAsGhAEAAIUBAEHwh8EACwaGAQAAVAIAQYCIwQALBocBAACIAQBBkIjBAAsGiQEAAFYCAEGgiMEACwaKAQAAVwIAQbCIwQALBosBAACMAQBBwIjBAAsGjgEAAN0BAEHQiMEACwaPAQAAWQIAQeCIwQALBpABAABbAgBB8IjBAAsGkQEAAJIBAEGAicEACwaTAQAAYAIAQZCJwQALBpQBAABjAgBBoInBAAsGlgEAAGkCAEGwicEACwaXAQAAaAIAQcCJwQALBpgBAACZAQBB0InBAAsGnAEAAG8CAEHgicEACwadAQAAcgIAQfCJwQALBp8BAAB1AgBBgIrBAAsGoAEAAKEBAEGQisEACwaiAQAAowEAQaCKwQALBqQBAAClAQBBsIrBAAsGpgEAAIACAEHAisEACwanAQAAqAEAQdCKwQALBqkBAACDAgBB4IrBAAsGrAEAAK0BAEHwisEACwauAQAAiAIAQYCLwQALBq8BAACwAQBBkIvBAAsGsQEAAIoCAEGgi8EACwayAQAAiwIAQbCLwQALBrMBAAC0AQBBwIvBAAsGtQEAALYBAEHQi8EACwa3AQAAkgIAQeCLwQALBrgBAAC5AQBB8IvBAAsGvAEAAL0BAEGAjMEACwbEAQAAxgEAQZCMwQALBsUBAADGAQBBoIzBAAsGxwEAAMkBAEGwjMEACwbIAQAAyQEAQcCMwQALBsoBAADMAQBB0IzBAAsGywEAAMwBAEHgjMEACwbNAQAAzgEAQfCMwQALBs8BAADQAQBBgI3BAAsG0QEAANIBAEGQjcEACwbTAQAA1AEAQaCNwQALBtUBAADWAQBBsI3BAAsG1wEAANgBAEHAjcEACwbZAQAA2gEAQdCNwQALBtsBAADcAQBB4I3BAAsG3gEAAN8BAEHwjcEACwbgAQAA4QEAQYCOwQALBuIBAADjAQBBkI7BAAsG5AEAAOUBAEGgjsEACwbmAQAA5wEAQbCOwQALBugBAADpAQBBwI7BAAsG6gEAAOsBAEHQjsEACwbsAQAA7QEAQeCOwQALBu4BAADvAQBB8I7BAAsG8QEAAPMBAEGAj8EACwbyAQAA8wEAQZCPwQALBvQBAAD1AQBBoI/BAAsG9gEAAJUBAEGwj8EACwb3AQAAvwEAQcCPwQALBvgBAAD5AQBB0I/BAAsG+gEAAPsBAEHgj8EACwb8AQAA/QEAQfCPwQALBv4BAAD/AQBBgZDBAAsFAgAAAQIAQZCQwQALBgICAAADAgBBoJDBAAsGBAIAAAUCAEGwkMEACwYGAgAABwIAQcCQwQALBggCAAAJAgBB0JDBAAsGCgIAAAsCAEHgkMEACwYMAgAADQIAQfCQwQALBg4CAAAPAgBBgJHBAAsGEAIAABECAEGQkcEACwYSAgAAEwIAQaCRwQALBhQCAAAVAgBBsJHBAAsGFgIAABcCAEHAkcEACwYYAgAAGQIAQdCRwQALBhoCAAAbAgBB4JHBAAsGHAIAAB0CAEHwkcEACwYeAgAAHwIAQYCSwQALBiACAACeAQBBkJLBAAsGIgIAACMCAEGgksEACwYkAgAAJQIAQbCSwQALBiYCAAAnAgBBwJLBAAsGKAIAACkCAEHQksEACwYqAgAAKwIAQeCSwQALBiwCAAAtAgBB8JLBAAsGLgIAAC8CAEGAk8EACwYwAgAAMQIAQZCTwQALBjICAAAzAgBBoJPBAAsGOgIAAGUsAEGwk8EACwY7AgAAPAIAQcCTwQALBj0CAACaAQBB0JPBAAsGPgIAAGYsAEHgk8EACwZBAgAAQgIAQfCTwQALBkMCAACAAQBBgJTBAAsGRAIAAIkCAEGQlMEACwZFAgAAjAIAQaCUwQALBkYCAABHAgBBsJTBAAsGSAIAAEkCAEHAlMEACwZKAgAASwIAQdCUwQALBkwCAABNAgBB4JTBAAsGTgIAAE8CAEHwlMEACwZwAwAAcQMAQYCVwQALBnIDAABzAwBBkJXBAAsGdgMAAHcDAEGglcEACwZ/AwAA8wMAQbCVwQALBoYDAACsAwBBwJXBAAsGiAMAAK0DAEHQlcEACwaJAwAArgMAQeCVwQALBooDAACvAwBB8JXBAAsGjAMAAMwDAEGAlsEACwaOAwAAzQMAQZCWwQALBo8DAADOAwBBoJbBAAsGkQMAALEDAEGwlsEACwaSAwAAsgMAQcCWwQALBpMDAACzAwBB0JbBAAsGlAMAALQDAEHglsEACwaVAwAAtQMAQfCWwQALBpYDAAC2AwBBgJfBAAsGlwMAALcDAEGQl8EACwaYAwAAuAMAQaCXwQALBpkDAAC5AwBBsJfBAAsGmgMAALoDAEHAl8EACwabAwAAuwMAQdCXwQALBpwDAAC8AwBB4JfBAAsGnQMAAL0DAEHwl8EACwaeAwAAvgMAQYCYwQALBp8DAAC/AwBBkJjBAAsGoAMAAMADAEGgmMEACwahAwAAwQMAQbCYwQALBqMDAADDAwBBwJjBAAsGpAMAAMQDAEHQmMEACwalAwAAxQMAQeCYwQALBqYDAADGAwBB8JjBAAsGpwMAAMcDAEGAmcEACwaoAwAAyAMAQZCZwQALBqkDAADJAwBBoJnBAAsGqgMAAMoDAEGwmcEACwarAwAAywMAQcCZwQALBs8DAADXAwBB0JnBAAsG2AMAANkDAEHgmcEACwbaAwAA2wMAQfCZwQALBtwDAADdAwBBgJrBAAsG3gMAAN8DAEGQmsEACwbgAwAA4QMAQaCawQALBuIDAADjAwBBsJrBAAsG5AMAAOUDAEHAmsEACwbmAwAA5wMAQdCawQALBugDAADpAwBB4JrBAAsG6gMAAOsDAEHwmsEACwbsAwAA7QMAQYCbwQALBu4DAADvAwBBkJvBAAsG9AMAALgDAEGgm8EACwb3AwAA+AMAQbCbwQALBvkDAADyAwBBwJvBAAsG+gMAAPsDAEHQm8EACwb9AwAAewMAQeCbwQALBv4DAAB8AwBB8JvBAAsG/wMAAH0DAEGBnMEACwUEAABQBABBkJzBAAsGAQQAAFEEAEGgnMEACwYCBAAAUgQAQbCcwQALBgMEAABTBABBwJzBAAsGBAQAAFQEAEHQnMEACwYFBAAAVQQAQeCcwQALBgYEAABWBABB8JzBAAsGBwQAAFcEAEGAncEACwYIBAAAWAQAQZCdwQALBgkEAABZBABBoJ3BAAsGCgQAAFoEAEGwncEACwYLBAAAWwQAQcCdwQALBgwEAABcBABB0J3BAAsGDQQAAF0EAEHgncEACwYOBAAAXgQAQfCdwQALBg8EAABfBABBgJ7BAAsGEAQAADAEAEGQnsEACwYRBAAAMQQAQaCewQALBhIEAAAyBABBsJ7BAAsGEwQAADMEAEHAnsEACwYUBAAANAQAQdCewQALBhUEAAA1BABB4J7BAAsGFgQAADYEAEHwnsEACwYXBAAANwQAQYCfwQALBhgEAAA4BABBkJ/BAAsGGQQAADkEAEGgn8EACwYaBAAAOgQAQbCfwQALBhsEAAA7BABBwJ/BAAsGHAQAADwEAEHQn8EACwYdBAAAPQQAQeCfwQALBh4EAAA+BABB8J/BAAsGHwQAAD8EAEGAoMEACwYgBAAAQAQAQZCgwQALBiEEAABBBABBoKDBAAsGIgQAAEIEAEGwoMEACwYjBAAAQwQAQcCgwQALBiQEAABEBABB0KDBAAsGJQQAAEUEAEHgoMEACwYmBAAARgQAQfCgwQALBicEAABHBABBgKHBAAsGKAQAAEgEAEGQocEACwYpBAAASQQAQaChwQALBioEAABKBABBsKHBAAsGKwQAAEsEAEHAocEACwYsBAAATAQAQdChwQALBi0EAABNBABB4KHBAAsGLgQAAE4EAEHwocEACwYvBAAATwQAQYCiwQALBmAEAABhBABBkKLBAAsGYgQAAGMEAEGgosEACwZkBAAAZQQAQbCiwQALBmYEAABnBABBwKLBAAsGaAQAAGkEAEHQosEACwZqBAAAawQAQeCiwQALBmwEAABtBABB8KLBAAsGbgQAAG8EAEGAo8EACwZwBAAAcQQAQZCjwQALBnIEAABzBABBoKPBAAsGdAQAAHUEAEGwo8EACwZ2BAAAdwQAQcCjwQALBngEAAB5BABB0KPBAAsGegQAAHsEAEHgo8EACwZ8BAAAfQQAQfCjwQALBn4EAAB/BABBgKTBAAsGgAQAAIEEAEGQpMEACwaKBAAAiwQAQaCkwQALBowEAACNBABBsKTBAAsGjgQAAI8EAEHApMEACwaQBAAAkQQAQdCkwQALBpIEAACTBABB4KTBAAsGlAQAAJUEAEHwpMEACwaWBAAAlwQAQYClwQALBpgEAACZBABBkKXBAAsGmgQAAJsEAEGgpcEACwacBAAAnQQAQbClwQALBp4EAACfBABBwKXBAAsGoAQAAKEEAEHQpcEACwaiBAAAowQAQeClwQALBqQEAAClBABB8KXBAAsGpgQAAKcEAEGApsEACwaoBAAAqQQAQZCmwQALBqoEAACrBABBoKbBAAsGrAQAAK0EAEGwpsEACwauBAAArwQAQcCmwQALBrAEAACxBABB0KbBAAsGsgQAALMEAEHgpsEACwa0BAAAtQQAQfCmwQALBrYEAAC3BABBgKfBAAsGuAQAALkEAEGQp8EACwa6BAAAuwQAQaCnwQALBrwEAAC9BABBsKfBAAsGvgQAAL8EAEHAp8EACwbABAAAzwQAQdCnwQALBsEEAADCBABB4KfBAAsGwwQAAMQEAEHwp8EACwbFBAAAxgQAQYCowQALBscEAADIBABBkKjBAAsGyQQAAMoEAEGgqMEACwbLBAAAzAQAQbCowQALBs0EAADOBABBwKjBAAsG0AQAANEEAEHQqMEACwbSBAAA0wQAQeCowQALBtQEAADVBABB8KjBAAsG1gQAANcEAEGAqcEACwbYBAAA2QQAQZCpwQALBtoEAADbBABBoKnBAAsG3AQAAN0EAEGwqcEACwbeBAAA3wQAQcCpwQALBuAEAADhBABB0KnBAAsG4gQAAOMEAEHgqcEACwbkBAAA5QQAQfCpwQALBuYEAADnBABBgKrBAAsG6AQAAOkEAEGQqsEACwbqBAAA6wQAQaCqwQALBuwEAADtBABBsKrBAAsG7gQAAO8EAEHAqsEACwbwBAAA8QQAQdCqwQALBvIEAADzBABB4KrBAAsG9AQAAPUEAEHwqsEACwb2BAAA9wQAQYCrwQALBvgEAAD5BABBkKvBAAsG+gQAAPsEAEGgq8EACwb8BAAA/QQAQbCrwQALBv4EAAD/BABBwavBAAsFBQAAAQUAQdCrwQALBgIFAAADBQBB4KvBAAsGBAUAAAUFAEHwq8EACwYGBQAABwUAQYCswQALBggFAAAJBQBBkKzBAAsGCgUAAAsFAEGgrMEACwYMBQAADQUAQbCswQALBg4FAAAPBQBBwKzBAAsGEAUAABEFAEHQrMEACwYSBQAAEwUAQeCswQALBhQFAAAVBQBB8KzBAAsGFgUAABcFAEGArcEACwYYBQAAGQUAQZCtwQALBhoFAAAbBQBBoK3BAAsGHAUAAB0FAEGwrcEACwYeBQAAHwUAQcCtwQALBiAFAAAhBQBB0K3BAAsGIgUAACMFAEHgrcEACwYkBQAAJQUAQfCtwQALBiYFAAAnBQBBgK7BAAsGKAUAACkFAEGQrsEACwYqBQAAKwUAQaCuwQALBiwFAAAtBQBBsK7BAAsGLgUAAC8FAEHArsEACwYxBQAAYQUAQdCuwQALBjIFAABiBQBB4K7BAAsGMwUAAGMFAEHwrsEACwY0BQAAZAUAQYCvwQALBjUFAABlBQBBkK/BAAsGNgUAAGYFAEGgr8EACwY3BQAAZwUAQbCvwQALBjgFAABoBQBBwK/BAAsGOQUAAGkFAEHQr8EACwY6BQAAagUAQeCvwQALBjsFAABrBQBB8K/BAAsGPAUAAGwFAEGAsMEACwY9BQAAbQUAQZCwwQALBj4FAABuBQBBoLDBAAsGPwUAAG8FAEGwsMEACwZABQAAcAUAQcCwwQALBkEFAABxBQBB0LDBAAsGQgUAAHIFAEHgsMEACwZDBQAAcwUAQfCwwQALBkQFAAB0BQBBgLHBAAsGRQUAAHUFAEGQscEACwZGBQAAdgUAQaCxwQALBkcFAAB3BQBBsLHBAAsGSAUAAHgFAEHAscEACwZJBQAAeQUAQdCxwQALBkoFAAB6BQBB4LHBAAsGSwUAAHsFAEHwscEACwZMBQAAfAUAQYCywQALBk0FAAB9BQBBkLLBAAsGTgUAAH4FAEGgssEACwZPBQAAfwUAQbCywQALBlAFAACABQBBwLLBAAsGUQUAAIEFAEHQssEACwZSBQAAggUAQeCywQALBlMFAACDBQBB8LLBAAsGVAUAAIQFAEGAs8EACwZVBQAAhQUAQZCzwQALBlYFAACGBQBBoLPBAAsGoBAAAAAtAEGws8EACwahEAAAAS0AQcCzwQALBqIQAAACLQBB0LPBAAsGoxAAAAMtAEHgs8EACwakEAAABC0AQfCzwQALBqUQAAAFLQBBgLTBAAsGphAAAAYtAEGQtMEACwanEAAABy0AQaC0wQALBqgQAAAILQBBsLTBAAsGqRAAAAktAEHAtMEACwaqEAAACi0AQdC0wQALBqsQAAALLQBB4LTBAAsGrBAAAAwtAEHwtMEACwatEAAADS0AQYC1wQALBq4QAAAOLQBBkLXBAAsGrxAAAA8tAEGgtcEACwawEAAAEC0AQbC1wQALBrEQAAARLQBBwLXBAAsGshAAABItAEHQtcEACwazEAAAEy0AQeC1wQALBrQQAAAULQBB8LXBAAsGtRAAABUtAEGAtsEACwa2EAAAFi0AQZC2wQALBrcQAAAXLQBBoLbBAAsGuBAAABgtAEGwtsEACwa5EAAAGS0AQcC2wQALBroQAAAaLQBB0LbBAAsGuxAAABstAEHgtsEACwa8EAAAHC0AQfC2wQALBr0QAAAdLQBBgLfBAAsGvhAAAB4tAEGQt8EACwa/EAAAHy0AQaC3wQALBsAQAAAgLQBBsLfBAAsGwRAAACEtAEHAt8EACwbCEAAAIi0AQdC3wQALBsMQAAAjLQBB4LfBAAsGxBAAACQtAEHwt8EACwbFEAAAJS0AQYC4wQALBscQAAAnLQBBkLjBAAsGzRAAAC0tAEGguMEACwagEwAAcKsAQbC4wQALBqETAABxqwBBwLjBAAsGohMAAHKrAEHQuMEACwajEwAAc6sAQeC4wQALBqQTAAB0qwBB8LjBAAsGpRMAAHWrAEGAucEACwamEwAAdqsAQZC5wQALBqcTAAB3qwBBoLnBAAsGqBMAAHirAEGwucEACwapEwAAeasAQcC5wQALBqoTAAB6qwBB0LnBAAsGqxMAAHurAEHgucEACwasEwAAfKsAQfC5wQALBq0TAAB9qwBBgLrBAAsGrhMAAH6rAEGQusEACwavEwAAf6sAQaC6wQALBrATAACAqwBBsLrBAAsGsRMAAIGrAEHAusEACwayEwAAgqsAQdC6wQALBrMTAACDqwBB4LrBAAsGtBMAAISrAEHwusEACwa1EwAAhasAQYC7wQALBrYTAACGqwBBkLvBAAsGtxMAAIerAEGgu8EACwa4EwAAiKsAQbC7wQALBrkTAACJqwBBwLvBAAsGuhMAAIqrAEHQu8EACwa7EwAAi6sAQeC7wQALBrwTAACMqwBB8LvBAAsGvRMAAI2rAEGAvMEACwa+EwAAjqsAQZC8wQALBr8TAACPqwBBoLzBAAsGwBMAAJCrAEGwvMEACwbBEwAAkasAQcC8wQALBsITAACSqwBB0LzBAAsGwxMAAJOrAEHgvMEACwbEEwAAlKsAQfC8wQALBsUTAACVqwBBgL3BAAsGxhMAAJarAEGQvcEACwbHEwAAl6sAQaC9wQALBsgTAACYqwBBsL3BAAsGyRMAAJmrAEHAvcEACwbKEwAAmqsAQdC9wQALBssTAACbqwBB4L3BAAsGzBMAAJyrAEHwvcEACwbNEwAAnasAQYC+wQALBs4TAACeqwBBkL7BAAsGzxMAAJ+rAEGgvsEACwbQEwAAoKsAQbC+wQALBtETAAChqwBBwL7BAAsG0hMAAKKrAEHQvsEACwbTEwAAo6sAQeC+wQALBtQTAACkqwBB8L7BAAsG1RMAAKWrAEGAv8EACwbWEwAApqsAQZC/wQALBtcTAACnqwBBoL/BAAsG2BMAAKirAEGwv8EACwbZEwAAqasAQcC/wQALBtoTAACqqwBB0L/BAAsG2xMAAKurAEHgv8EACwbcEwAArKsAQfC/wQALBt0TAACtqwBBgMDBAAsG3hMAAK6rAEGQwMEACwbfEwAAr6sAQaDAwQALBuATAACwqwBBsMDBAAsG4RMAALGrAEHAwMEACwbiEwAAsqsAQdDAwQALBuMTAACzqwBB4MDBAAsG5BMAALSrAEHwwMEACwblEwAAtasAQYDBwQALBuYTAAC2qwBBkMHBAAsG5xMAALerAEGgwcEACwboEwAAuKsAQbDBwQALBukTAAC5qwBBwMHBAAsG6hMAALqrAEHQwcEACwbrEwAAu6sAQeDBwQALBuwTAAC8qwBB8MHBAAsG7RMAAL2rAEGAwsEACwbuEwAAvqsAQZDCwQALBu8TAAC/qwBBoMLBAAsG8BMAAPgTAEGwwsEACwbxEwAA+RMAQcDCwQALBvITAAD6EwBB0MLBAAsG8xMAAPsTAEHgwsEACwb0EwAA/BMAQfDCwQALBvUTAAD9EwBBgMPBAAsGkBwAANAQAEGQw8EACwaRHAAA0RAAQaDDwQALBpIcAADSEABBsMPBAAsGkxwAANMQAEHAw8EACwaUHAAA1BAAQdDDwQALBpUcAADVEABB4MPBAAsGlhwAANYQAEHww8EACwaXHAAA1xAAQYDEwQALBpgcAADYEABBkMTBAAsGmRwAANkQAEGgxMEACwaaHAAA2hAAQbDEwQALBpscAADbEABBwMTBAAsGnBwAANwQAEHQxMEACwadHAAA3RAAQeDEwQALBp4cAADeEABB8MTBAAsGnxwAAN8QAEGAxcEACwagHAAA4BAAQZDFwQALBqEcAADhEABBoMXBAAsGohwAAOIQAEGwxcEACwajHAAA4xAAQcDFwQALBqQcAADkEABB0MXBAAsGpRwAAOUQAEHgxcEACwamHAAA5hAAQfDFwQALBqccAADnEABBgMbBAAsGqBwAAOgQAEGQxsEACwapHAAA6RAAQaDGwQALBqocAADqEABBsMbBAAsGqxwAAOsQAEHAxsEACwasHAAA7BAAQdDGwQALBq0cAADtEABB4MbBAAsGrhwAAO4QAEHwxsEACwavHAAA7xAAQYDHwQALBrAcAADwEABBkMfBAAsGsRwAAPEQAEGgx8EACwayHAAA8hAAQbDHwQALBrMcAADzEABBwMfBAAsGtBwAAPQQAEHQx8EACwa1HAAA9RAAQeDHwQALBrYcAAD2EABB8MfBAAsGtxwAAPcQAEGAyMEACwa4HAAA+BAAQZDIwQALBrkcAAD5EABBoMjBAAsGuhwAAPoQAEGwyMEACwa9HAAA/RAAQcDIwQALBr4cAAD+EABB0MjBAAsGvxwAAP8QAEHhyMEACwUeAAABHgBB8MjBAAsGAh4AAAMeAEGAycEACwYEHgAABR4AQZDJwQALBgYeAAAHHgBBoMnBAAsGCB4AAAkeAEGwycEACwYKHgAACx4AQcDJwQALBgweAAANHgBB0MnBAAsGDh4AAA8eAEHgycEACwYQHgAAER4AQfDJwQALBhIeAAATHgBBgMrBAAsGFB4AABUeAEGQysEACwYWHgAAFx4AQaDKwQALBhgeAAAZHgBBsMrBAAsGGh4AABseAEHAysEACwYcHgAAHR4AQdDKwQALBh4eAAAfHgBB4MrBAAsGIB4AACEeAEHwysEACwYiHgAAIx4AQYDLwQALBiQeAAAlHgBBkMvBAAsGJh4AACceAEGgy8EACwYoHgAAKR4AQbDLwQALBioeAAArHgBBwMvBAAsGLB4AAC0eAEHQy8EACwYuHgAALx4AQeDLwQALBjAeAAAxHgBB8MvBAAsGMh4AADMeAEGAzMEACwY0HgAANR4AQZDMwQALBjYeAAA3HgBBoMzBAAsGOB4AADkeAEGwzMEACwY6HgAAOx4AQcDMwQALBjweAAA9HgBB0MzBAAsGPh4AAD8eAEHgzMEACwZAHgAAQR4AQfDMwQALBkIeAABDHgBBgM3BAAsGRB4AAEUeAEGQzcEACwZGHgAARx4AQaDNwQALBkgeAABJHgBBsM3BAAsGSh4AAEseAEHAzcEACwZMHgAATR4AQdDNwQALBk4eAABPHgBB4M3BAAsGUB4AAFEeAEHwzcEACwZSHgAAUx4AQYDOwQALBlQeAABVHgBBkM7BAAsGVh4AAFceAEGgzsEACwZYHgAAWR4AQbDOwQALBloeAABbHgBBwM7BAAsGXB4AAF0eAEHQzsEACwZeHgAAXx4AQeDOwQALBmAeAABhHgBB8M7BAAsGYh4AAGMeAEGAz8EACwZkHgAAZR4AQZDPwQALBmYeAABnHgBBoM/BAAsGaB4AAGkeAEGwz8EACwZqHgAAax4AQcDPwQALBmweAABtHgBB0M/BAAsGbh4AAG8eAEHgz8EACwZwHgAAcR4AQfDPwQALBnIeAABzHgBBgNDBAAsGdB4AAHUeAEGQ0MEACwZ2HgAAdx4AQaDQwQALBngeAAB5HgBBsNDBAAsGeh4AAHseAEHA0MEACwZ8HgAAfR4AQdDQwQALBn4eAAB/HgBB4NDBAAsGgB4AAIEeAEHw0MEACwaCHgAAgx4AQYDRwQALBoQeAACFHgBBkNHBAAsGhh4AAIceAEGg0cEACwaIHgAAiR4AQbDRwQALBooeAACLHgBBwNHBAAsGjB4AAI0eAEHQ0cEACwaOHgAAjx4AQeDRwQALBpAeAACRHgBB8NHBAAsGkh4AAJMeAEGA0sEACwaUHgAAlR4AQZDSwQALBZ4eAADfAEGg0sEACwagHgAAoR4AQbDSwQALBqIeAACjHgBBwNLBAAsGpB4AAKUeAEHQ0sEACwamHgAApx4AQeDSwQALBqgeAACpHgBB8NLBAAsGqh4AAKseAEGA08EACwasHgAArR4AQZDTwQALBq4eAACvHgBBoNPBAAsGsB4AALEeAEGw08EACwayHgAAsx4AQcDTwQALBrQeAAC1HgBB0NPBAAsGth4AALceAEHg08EACwa4HgAAuR4AQfDTwQALBroeAAC7HgBBgNTBAAsGvB4AAL0eAEGQ1MEACwa+HgAAvx4AQaDUwQALBsAeAADBHgBBsNTBAAsGwh4AAMMeAEHA1MEACwbEHgAAxR4AQdDUwQALBsYeAADHHgBB4NTBAAsGyB4AAMkeAEHw1MEACwbKHgAAyx4AQYDVwQALBsweAADNHgBBkNXBAAsGzh4AAM8eAEGg1cEACwbQHgAA0R4AQbDVwQALBtIeAADTHgBBwNXBAAsG1B4AANUeAEHQ1cEACwbWHgAA1x4AQeDVwQALBtgeAADZHgBB8NXBAAsG2h4AANseAEGA1sEACwbcHgAA3R4AQZDWwQALBt4eAADfHgBBoNbBAAsG4B4AAOEeAEGw1sEACwbiHgAA4x4AQcDWwQALBuQeAADlHgBB0NbBAAsG5h4AAOceAEHg1sEACwboHgAA6R4AQfDWwQALBuoeAADrHgBBgNfBAAsG7B4AAO0eAEGQ18EACwbuHgAA7x4AQaDXwQALBvAeAADxHgBBsNfBAAsG8h4AAPMeAEHA18EACwb0HgAA9R4AQdDXwQALBvYeAAD3HgBB4NfBAAsG+B4AAPkeAEHw18EACwb6HgAA+x4AQYDYwQALBvweAAD9HgBBkNjBAAsG/h4AAP8eAEGg2MEACwYIHwAAAB8AQbDYwQALBgkfAAABHwBBwNjBAAsGCh8AAAIfAEHQ2MEACwYLHwAAAx8AQeDYwQALBgwfAAAEHwBB8NjBAAsGDR8AAAUfAEGA2cEACwYOHwAABh8AQZDZwQALBg8fAAAHHwBBoNnBAAsGGB8AABAfAEGw2cEACwYZHwAAER8AQcDZwQALBhofAAASHwBB0NnBAAsGGx8AABMfAEHg2cEACwYcHwAAFB8AQfDZwQALBh0fAAAVHwBBgNrBAAsGKB8AACAfAEGQ2sEACwYpHwAAIR8AQaDawQALBiofAAAiHwBBsNrBAAsGKx8AACMfAEHA2sEACwYsHwAAJB8AQdDawQALBi0fAAAlHwBB4NrBAAsGLh8AACYfAEHw2sEACwYvHwAAJx8AQYDbwQALBjgfAAAwHwBBkNvBAAsGOR8AADEfAEGg28EACwY6HwAAMh8AQbDbwQALBjsfAAAzHwBBwNvBAAsGPB8AADQfAEHQ28EACwY9HwAANR8AQeDbwQALBj4fAAA2HwBB8NvBAAsGPx8AADcfAEGA3MEACwZIHwAAQB8AQZDcwQALBkkfAABBHwBBoNzBAAsGSh8AAEIfAEGw3MEACwZLHwAAQx8AQcDcwQALBkwfAABEHwBB0NzBAAsGTR8AAEUfAEHg3MEACwZZHwAAUR8AQfDcwQALBlsfAABTHwBBgN3BAAsGXR8AAFUfAEGQ3cEACwZfHwAAVx8AQaDdwQALBmgfAABgHwBBsN3BAAsGaR8AAGEfAEHA3cEACwZqHwAAYh8AQdDdwQALBmsfAABjHwBB4N3BAAsGbB8AAGQfAEHw3cEACwZtHwAAZR8AQYDewQALBm4fAABmHwBBkN7BAAsGbx8AAGcfAEGg3sEACwaIHwAAgB8AQbDewQALBokfAACBHwBBwN7BAAsGih8AAIIfAEHQ3sEACwaLHwAAgx8AQeDewQALBowfAACEHwBB8N7BAAsGjR8AAIUfAEGA38EACwaOHwAAhh8AQZDfwQALBo8fAACHHwBBoN/BAAsGmB8AAJAfAEGw38EACwaZHwAAkR8AQcDfwQALBpofAACSHwBB0N/BAAsGmx8AAJMfAEHg38EACwacHwAAlB8AQfDfwQALBp0fAACVHwBBgODBAAsGnh8AAJYfAEGQ4MEACwafHwAAlx8AQaDgwQALBqgfAACgHwBBsODBAAsGqR8AAKEfAEHA4MEACwaqHwAAoh8AQdDgwQALBqsfAACjHwBB4ODBAAsGrB8AAKQfAEHw4MEACwatHwAApR8AQYDhwQALBq4fAACmHwBBkOHBAAsGrx8AAKcfAEGg4cEACwa4HwAAsB8AQbDhwQALBrkfAACxHwBBwOHBAAsGuh8AAHAfAEHQ4cEACwa7HwAAcR8AQeDhwQALBrwfAACzHwBB8OHBAAsGyB8AAHIfAEGA4sEACwbJHwAAcx8AQZDiwQALBsofAAB0HwBBoOLBAAsGyx8AAHUfAEGw4sEACwbMHwAAwx8AQcDiwQALBtgfAADQHwBB0OLBAAsG2R8AANEfAEHg4sEACwbaHwAAdh8AQfDiwQALBtsfAAB3HwBBgOPBAAsG6B8AAOAfAEGQ48EACwbpHwAA4R8AQaDjwQALBuofAAB6HwBBsOPBAAsG6x8AAHsfAEHA48EACwbsHwAA5R8AQdDjwQALBvgfAAB4HwBB4OPBAAsG+R8AAHkfAEHw48EACwb6HwAAfB8AQYDkwQALBvsfAAB9HwBBkOTBAAsG/B8AAPMfAEGg5MEACwYmIQAAyQMAQbDkwQALBSohAABrAEHA5MEACwUrIQAA5QBB0OTBAAsGMiEAAE4hAEHg5MEACwZgIQAAcCEAQfDkwQALBmEhAABxIQBBgOXBAAsGYiEAAHIhAEGQ5cEACwZjIQAAcyEAQaDlwQALBmQhAAB0IQBBsOXBAAsGZSEAAHUhAEHA5cEACwZmIQAAdiEAQdDlwQALBmchAAB3IQBB4OXBAAsGaCEAAHghAEHw5cEACwZpIQAAeSEAQYDmwQALBmohAAB6IQBBkObBAAsGayEAAHshAEGg5sEACwZsIQAAfCEAQbDmwQALBm0hAAB9IQBBwObBAAsGbiEAAH4hAEHQ5sEACwZvIQAAfyEAQeDmwQALBoMhAACEIQBB8ObBAAsGtiQAANAkAEGA58EACwa3JAAA0SQAQZDnwQALBrgkAADSJABBoOfBAAsGuSQAANMkAEGw58EACwa6JAAA1CQAQcDnwQALBrskAADVJABB0OfBAAsGvCQAANYkAEHg58EACwa9JAAA1yQAQfDnwQALBr4kAADYJABBgOjBAAsGvyQAANkkAEGQ6MEACwbAJAAA2iQAQaDowQALBsEkAADbJABBsOjBAAsGwiQAANwkAEHA6MEACwbDJAAA3SQAQdDowQALBsQkAADeJABB4OjBAAsGxSQAAN8kAEHw6MEACwbGJAAA4CQAQYDpwQALBsckAADhJABBkOnBAAsGyCQAAOIkAEGg6cEACwbJJAAA4yQAQbDpwQALBsokAADkJABBwOnBAAsGyyQAAOUkAEHQ6cEACwbMJAAA5iQAQeDpwQALBs0kAADnJABB8OnBAAsGziQAAOgkAEGA6sEACwbPJAAA6SQAQZHqwQALBSwAADAsAEGg6sEACwYBLAAAMSwAQbDqwQALBgIsAAAyLABBwOrBAAsGAywAADMsAEHQ6sEACwYELAAANCwAQeDqwQALBgUsAAA1LABB8OrBAAsGBiwAADYsAEGA68EACwYHLAAANywAQZDrwQALBggsAAA4LABBoOvBAAsGCSwAADksAEGw68EACwYKLAAAOiwAQcDrwQALBgssAAA7LABB0OvBAAsGDCwAADwsAEHg68EACwYNLAAAPSwAQfDrwQALBg4sAAA+LABBgOzBAAsGDywAAD8sAEGQ7MEACwYQLAAAQCwAQaDswQALBhEsAABBLABBsOzBAAsGEiwAAEIsAEHA7MEACwYTLAAAQywAQdDswQALBhQsAABELABB4OzBAAsGFSwAAEUsAEHw7MEACwYWLAAARiwAQYDtwQALBhcsAABHLABBkO3BAAsGGCwAAEgsAEGg7cEACwYZLAAASSwAQbDtwQALBhosAABKLABBwO3BAAsGGywAAEssAEHQ7cEACwYcLAAATCwAQeDtwQALBh0sAABNLABB8O3BAAsGHiwAAE4sAEGA7sEACwYfLAAATywAQZDuwQALBiAsAABQLABBoO7BAAsGISwAAFEsAEGw7sEACwYiLAAAUiwAQcDuwQALBiMsAABTLABB0O7BAAsGJCwAAFQsAEHg7sEACwYlLAAAVSwAQfDuwQALBiYsAABWLABBgO/BAAsGJywAAFcsAEGQ78EACwYoLAAAWCwAQaDvwQALBiksAABZLABBsO/BAAsGKiwAAFosAEHA78EACwYrLAAAWywAQdDvwQALBiwsAABcLABB4O/BAAsGLSwAAF0sAEHw78EACwYuLAAAXiwAQYDwwQALBmAsAABhLABBkPDBAAsGYiwAAGsCAEGg8MEACwZjLAAAfR0AQbDwwQALBmQsAAB9AgBBwPDBAAsGZywAAGgsAEHQ8MEACwZpLAAAaiwAQeDwwQALBmssAABsLABB8PDBAAsGbSwAAFECAEGA8cEACwZuLAAAcQIAQZDxwQALBm8sAABQAgBBoPHBAAsGcCwAAFICAEGw8cEACwZyLAAAcywAQcDxwQALBnUsAAB2LABB0PHBAAsGfiwAAD8CAEHg8cEACwZ/LAAAQAIAQfDxwQALBoAsAACBLABBgPLBAAsGgiwAAIMsAEGQ8sEACwaELAAAhSwAQaDywQALBoYsAACHLABBsPLBAAsGiCwAAIksAEHA8sEACwaKLAAAiywAQdDywQALBowsAACNLABB4PLBAAsGjiwAAI8sAEHw8sEACwaQLAAAkSwAQYDzwQALBpIsAACTLABBkPPBAAsGlCwAAJUsAEGg88EACwaWLAAAlywAQbDzwQALBpgsAACZLABBwPPBAAsGmiwAAJssAEHQ88EACwacLAAAnSwAQeDzwQALBp4sAACfLABB8PPBAAsGoCwAAKEsAEGA9MEACwaiLAAAoywAQZD0wQALBqQsAAClLABBoPTBAAsGpiwAAKcsAEGw9MEACwaoLAAAqSwAQcD0wQALBqosAACrLABB0PTBAAsGrCwAAK0sAEHg9MEACwauLAAArywAQfD0wQALBrAsAACxLABBgPXBAAsGsiwAALMsAEGQ9cEACwa0LAAAtSwAQaD1wQALBrYsAAC3LABBsPXBAAsGuCwAALksAEHA9cEACwa6LAAAuywAQdD1wQALBrwsAAC9LABB4PXBAAsGviwAAL8sAEHw9cEACwbALAAAwSwAQYD2wQALBsIsAADDLABBkPbBAAsGxCwAAMUsAEGg9sEACwbGLAAAxywAQbD2wQALBsgsAADJLABBwPbBAAsGyiwAAMssAEHQ9sEACwbMLAAAzSwAQeD2wQALBs4sAADPLABB8PbBAAsG0CwAANEsAEGA98EACwbSLAAA0ywAQZD3wQALBtQsAADVLABBoPfBAAsG1iwAANcsAEGw98EACwbYLAAA2SwAQcD3wQALBtosAADbLABB0PfBAAsG3CwAAN0sAEHg98EACwbeLAAA3ywAQfD3wQALBuAsAADhLABBgPjBAAsG4iwAAOMsAEGQ+MEACwbrLAAA7CwAQaD4wQALBu0sAADuLABBsPjBAAsG8iwAAPMsAEHA+MEACwZApgAAQaYAQdD4wQALBkKmAABDpgBB4PjBAAsGRKYAAEWmAEHw+MEACwZGpgAAR6YAQYD5wQALBkimAABJpgBBkPnBAAsGSqYAAEumAEGg+cEACwZMpgAATaYAQbD5wQALBk6mAABPpgBBwPnBAAsGUKYAAFGmAEHQ+cEACwZSpgAAU6YAQeD5wQALBlSmAABVpgBB8PnBAAsGVqYAAFemAEGA+sEACwZYpgAAWaYAQZD6wQALBlqmAABbpgBBoPrBAAsGXKYAAF2mAEGw+sEACwZepgAAX6YAQcD6wQALBmCmAABhpgBB0PrBAAsGYqYAAGOmAEHg+sEACwZkpgAAZaYAQfD6wQALBmamAABnpgBBgPvBAAsGaKYAAGmmAEGQ+8EACwZqpgAAa6YAQaD7wQALBmymAABtpgBBsPvBAAsGgKYAAIGmAEHA+8EACwaCpgAAg6YAQdD7wQALBoSmAACFpgBB4PvBAAsGhqYAAIemAEHw+8EACwaIpgAAiaYAQYD8wQALBoqmAACLpgBBkPzBAAsGjKYAAI2mAEGg/MEACwaOpgAAj6YAQbD8wQALBpCmAACRpgBBwPzBAAsGkqYAAJOmAEHQ/MEACwaUpgAAlaYAQeD8wQALBpamAACXpgBB8PzBAAsGmKYAAJmmAEGA/cEACwaapgAAm6YAQZD9wQALBiKnAAAjpwBBoP3BAAsGJKcAACWnAEGw/cEACwYmpwAAJ6cAQcD9wQALBiinAAAppwBB0P3BAAsGKqcAACunAEHg/cEACwYspwAALacAQfD9wQALBi6nAAAvpwBBgP7BAAsGMqcAADOnAEGQ/sEACwY0pwAANacAQaD+wQALBjanAAA3pwBBsP7BAAsGOKcAADmnAEHA/sEACwY6pwAAO6cAQdD+wQALBjynAAA9pwBB4P7BAAsGPqcAAD+nAEHw/sEACwZApwAAQacAQYD/wQALBkKnAABDpwBBkP/BAAsGRKcAAEWnAEGg/8EACwZGpwAAR6cAQbD/wQALBkinAABJpwBBwP/BAAsGSqcAAEunAEHQ/8EACwZMpwAATacAQeD/wQALBk6nAABPpwBB8P/BAAsGUKcAAFGnAEGAgMIACwZSpwAAU6cAQZCAwgALBlSnAABVpwBBoIDCAAsGVqcAAFenAEGwgMIACwZYpwAAWacAQcCAwgALBlqnAABbpwBB0IDCAAsGXKcAAF2nAEHggMIACwZepwAAX6cAQfCAwgALBmCnAABhpwBBgIHCAAsGYqcAAGOnAEGQgcIACwZkpwAAZacAQaCBwgALBmanAABnpwBBsIHCAAsGaKcAAGmnAEHAgcIACwZqpwAAa6cAQdCBwgALBmynAABtpwBB4IHCAAsGbqcAAG+nAEHwgcIACwZ5pwAAeqcAQYCCwgALBnunAAB8pwBBkILCAAsGfacAAHkdAEGggsIACwZ+pwAAf6cAQbCCwgALBoCnAACBpwBBwILCAAsGgqcAAIOnAEHQgsIACwaEpwAAhacAQeCCwgALBoanAACHpwBB8ILCAAsGi6cAAIynAEGAg8IACwaNpwAAZQIAQZCDwgALBpCnAACRpwBBoIPCAAsGkqcAAJOnAEGwg8IACwaWpwAAl6cAQcCDwgALBpinAACZpwBB0IPCAAsGmqcAAJunAEHgg8IACwacpwAAnacAQfCDwgALBp6nAACfpwBBgITCAAsGoKcAAKGnAEGQhMIACwaipwAAo6cAQaCEwgALBqSnAAClpwBBsITCAAsGpqcAAKenAEHAhMIACwaopwAAqacAQdCEwgALBqqnAABmAgBB4ITCAAsGq6cAAFwCAEHwhMIACwaspwAAYQIAQYCFwgALBq2nAABsAgBBkIXCAAsGrqcAAGoCAEGghcIACwawpwAAngIAQbCFwgALBrGnAACHAgBBwIXCAAsGsqcAAJ0CAEHQhcIACwazpwAAU6sAQeCFwgALBrSnAAC1pwBB8IXCAAsGtqcAALenAEGAhsIACwa4pwAAuacAQZCGwgALBrqnAAC7pwBBoIbCAAsGvKcAAL2nAEGwhsIACwa+pwAAv6cAQcCGwgALBsKnAADDpwBB0IbCAAsGxKcAAJSnAEHghsIACwbFpwAAggIAQfCGwgALBsanAACOHQBBgIfCAAsGIf8AAEH/AEGQh8IACwYi/wAAQv8AQaCHwgALBiP/AABD/wBBsIfCAAsGJP8AAET/AEHAh8IACwYl/wAARf8AQdCHwgALBib/AABG/wBB4IfCAAsGJ/8AAEf/AEHwh8IACwYo/wAASP8AQYCIwgALBin/AABJ/wBBkIjCAAsGKv8AAEr/AEGgiMIACwYr/wAAS/8AQbCIwgALBiz/AABM/wBBwIjCAAsGLf8AAE3/AEHQiMIACwYu/wAATv8AQeCIwgALBi//AABP/wBB8IjCAAsGMP8AAFD/AEGAicIACwYx/wAAUf8AQZCJwgALBjL/AABS/wBBoInCAAsGM/8AAFP/AEGwicIACwY0/wAAVP8AQcCJwgALBjX/AABV/wBB0InCAAsGNv8AAFb/AEHgicIACwY3/wAAV/8AQfCJwgALBjj/AABY/wBBgIrCAAsGOf8AAFn/AEGQisIACwY6/wAAWv8AQaGKwgALBgQBACgEAQBBsIrCAAsHAQQBACkEAQBBwIrCAAsHAgQBACoEAQBB0IrCAAsHAwQBACsEAQBB4IrCAAsHBAQBACwEAQBB8IrCAAsHBQQBAC0EAQBBgIvCAAsHBgQBAC4EAQBBkIvCAAsHBwQBAC8EAQBBoIvCAAsHCAQBADAEAQBBsIvCAAsHCQQBADEEAQBBwIvCAAsHCgQBADIEAQBB0IvCAAsHCwQBADMEAQBB4IvCAAsHDAQBADQEAQBB8IvCAAsHDQQBADUEAQBBgIzCAAsHDgQBADYEAQBBkIzCAAsHDwQBADcEAQBBoIzCAAsHEAQBADgEAQBBsIzCAAsHEQQBADkEAQBBwIzCAAsHEgQBADoEAQBB0IzCAAsHEwQBADsEAQBB4IzCAAsHFAQBADwEAQBB8IzCAAsHFQQBAD0EAQBBgI3CAAsHFgQBAD4EAQBBkI3CAAsHFwQBAD8EAQBBoI3CAAsHGAQBAEAEAQBBsI3CAAsHGQQBAEEEAQBBwI3CAAsHGgQBAEIEAQBB0I3CAAsHGwQBAEMEAQBB4I3CAAsHHAQBAEQEAQBB8I3CAAsHHQQBAEUEAQBBgI7CAAsHHgQBAEYEAQBBkI7CAAsHHwQBAEcEAQBBoI7CAAsHIAQBAEgEAQBBsI7CAAsHIQQBAEkEAQBBwI7CAAsHIgQBAEoEAQBB0I7CAAsHIwQBAEsEAQBB4I7CAAsHJAQBAEwEAQBB8I7CAAsHJQQBAE0EAQBBgI/CAAsHJgQBAE4EAQBBkI/CAAsHJwQBAE8EAQBBoI/CAAsHsAQBANgEAQBBsI/CAAsHsQQBANkEAQBBwI/CAAsHsgQBANoEAQBB0I/CAAsHswQBANsEAQBB4I/CAAsHtAQBANwEAQBB8I/CAAsHtQQBAN0EAQBBgJDCAAsHtgQBAN4EAQBBkJDCAAsHtwQBAN8EAQBBoJDCAAsHuAQBAOAEAQBBsJDCAAsHuQQBAOEEAQBBwJDCAAsHugQBAOIEAQBB0JDCAAsHuwQBAOMEAQBB4JDCAAsHvAQBAOQEAQBB8JDCAAsHvQQBAOUEAQBBgJHCAAsHvgQBAOYEAQBBkJHCAAsHvwQBAOcEAQBBoJHCAAsHwAQBAOgEAQBBsJHCAAsHwQQBAOkEAQBBwJHCAAsHwgQBAOoEAQBB0JHCAAsHwwQBAOsEAQBB4JHCAAsHxAQBAOwEAQBB8JHCAAsHxQQBAO0EAQBBgJLCAAsHxgQBAO4EAQBBkJLCAAsHxwQBAO8EAQBBoJLCAAsHyAQBAPAEAQBBsJLCAAsHyQQBAPEEAQBBwJLCAAsHygQBAPIEAQBB0JLCAAsHywQBAPMEAQBB4JLCAAsHzAQBAPQEAQBB8JLCAAsHzQQBAPUEAQBBgJPCAAsHzgQBAPYEAQBBkJPCAAsHzwQBAPcEAQBBoJPCAAsH0AQBAPgEAQBBsJPCAAsH0QQBAPkEAQBBwJPCAAsH0gQBAPoEAQBB0JPCAAsH0wQBAPsEAQBB4JPCAAsHgAwBAMAMAQBB8JPCAAsHgQwBAMEMAQBBgJTCAAsHggwBAMIMAQBBkJTCAAsHgwwBAMMMAQBBoJTCAAsHhAwBAMQMAQBBsJTCAAsHhQwBAMUMAQBBwJTCAAsHhgwBAMYMAQBB0JTCAAsHhwwBAMcMAQBB4JTCAAsHiAwBAMgMAQBB8JTCAAsHiQwBAMkMAQBBgJXCAAsHigwBAMoMAQBBkJXCAAsHiwwBAMsMAQBBoJXCAAsHjAwBAMwMAQBBsJXCAAsHjQwBAM0MAQBBwJXCAAsHjgwBAM4MAQBB0JXCAAsHjwwBAM8MAQBB4JXCAAsHkAwBANAMAQBB8JXCAAsHkQwBANEMAQBBgJbCAAsHkgwBANIMAQBBkJbCAAsHkwwBANMMAQBBoJbCAAsHlAwBANQMAQBBsJbCAAsHlQwBANUMAQBBwJbCAAsHlgwBANYMAQBB0JbCAAsHlwwBANcMAQBB4JbCAAsHmAwBANgMAQBB8JbCAAsHmQwBANkMAQBBgJfCAAsHmgwBANoMAQBBkJfCAAsHmwwBANsMAQBBoJfCAAsHnAwBANwMAQBBsJfCAAsHnQwBAN0MAQBBwJfCAAsHngwBAN4MAQBB0JfCAAsHnwwBAN8MAQBB4JfCAAsHoAwBAOAMAQBB8JfCAAsHoQwBAOEMAQBBgJjCAAsHogwBAOIMAQBBkJjCAAsHowwBAOMMAQBBoJjCAAsHpAwBAOQMAQBBsJjCAAsHpQwBAOUMAQBBwJjCAAsHpgwBAOYMAQBB0JjCAAsHpwwBAOcMAQBB4JjCAAsHqAwBAOgMAQBB8JjCAAsHqQwBAOkMAQBBgJnCAAsHqgwBAOoMAQBBkJnCAAsHqwwBAOsMAQBBoJnCAAsHrAwBAOwMAQBBsJnCAAsHrQwBAO0MAQBBwJnCAAsHrgwBAO4MAQBB0JnCAAsHrwwBAO8MAQBB4JnCAAsHsAwBAPAMAQBB8JnCAAsHsQwBAPEMAQBBgJrCAAsHsgwBAPIMAQBBkJrCAAsHoBgBAMAYAQBBoJrCAAsHoRgBAMEYAQBBsJrCAAsHohgBAMIYAQBBwJrCAAsHoxgBAMMYAQBB0JrCAAsHpBgBAMQYAQBB4JrCAAsHpRgBAMUYAQBB8JrCAAsHphgBAMYYAQBBgJvCAAsHpxgBAMcYAQBBkJvCAAsHqBgBAMgYAQBBoJvCAAsHqRgBAMkYAQBBsJvCAAsHqhgBAMoYAQBBwJvCAAsHqxgBAMsYAQBB0JvCAAsHrBgBAMwYAQBB4JvCAAsHrRgBAM0YAQBB8JvCAAsHrhgBAM4YAQBBgJzCAAsHrxgBAM8YAQBBkJzCAAsHsBgBANAYAQBBoJzCAAsHsRgBANEYAQBBsJzCAAsHshgBANIYAQBBwJzCAAsHsxgBANMYAQBB0JzCAAsHtBgBANQYAQBB4JzCAAsHtRgBANUYAQBB8JzCAAsHthgBANYYAQBBgJ3CAAsHtxgBANcYAQBBkJ3CAAsHuBgBANgYAQBBoJ3CAAsHuRgBANkYAQBBsJ3CAAsHuhgBANoYAQBBwJ3CAAsHuxgBANsYAQBB0J3CAAsHvBgBANwYAQBB4J3CAAsHvRgBAN0YAQBB8J3CAAsHvhgBAN4YAQBBgJ7CAAsHvxgBAN8YAQBBkJ7CAAsHQG4BAGBuAQBBoJ7CAAsHQW4BAGFuAQBBsJ7CAAsHQm4BAGJuAQBBwJ7CAAsHQ24BAGNuAQBB0J7CAAsHRG4BAGRuAQBB4J7CAAsHRW4BAGVuAQBB8J7CAAsHRm4BAGZuAQBBgJ/CAAsHR24BAGduAQBBkJ/CAAsHSG4BAGhuAQBBoJ/CAAsHSW4BAGluAQBBsJ/CAAsHSm4BAGpuAQBBwJ/CAAsHS24BAGtuAQBB0J/CAAsHTG4BAGxuAQBB4J/CAAsHTW4BAG1uAQBB8J/CAAsHTm4BAG5uAQBBgKDCAAsHT24BAG9uAQBBkKDCAAsHUG4BAHBuAQBBoKDCAAsHUW4BAHFuAQBBsKDCAAsHUm4BAHJuAQBBwKDCAAsHU24BAHNuAQBB0KDCAAsHVG4BAHRuAQBB4KDCAAsHVW4BAHVuAQBB8KDCAAsHVm4BAHZuAQBBgKHCAAsHV24BAHduAQBBkKHCAAsHWG4BAHhuAQBBoKHCAAsHWW4BAHluAQBBsKHCAAsHWm4BAHpuAQBBwKHCAAsHW24BAHtuAQBB0KHCAAsHXG4BAHxuAQBB4KHCAAsHXW4BAH1uAQBB8KHCAAsHXm4BAH5uAQBBgKLCAAsHX24BAH9uAQBBkaLCAAsG6QEAIukBAEGgosIACwcB6QEAI+kBAEGwosIACwcC6QEAJOkBAEHAosIACwcD6QEAJekBAEHQosIACwcE6QEAJukBAEHgosIACwcF6QEAJ+kBAEHwosIACwcG6QEAKOkBAEGAo8IACwcH6QEAKekBAEGQo8IACwcI6QEAKukBAEGgo8IACwcJ6QEAK+kBAEGwo8IACwcK6QEALOkBAEHAo8IACwcL6QEALekBAEHQo8IACwcM6QEALukBAEHgo8IACwcN6QEAL+kBAEHwo8IACwcO6QEAMOkBAEGApMIACwcP6QEAMekBAEGQpMIACwcQ6QEAMukBAEGgpMIACwcR6QEAM+kBAEGwpMIACwcS6QEANOkBAEHApMIACwcT6QEANekBAEHQpMIACwcU6QEANukBAEHgpMIACwcV6QEAN+kBAEHwpMIACwcW6QEAOOkBAEGApcIACwcX6QEAOekBAEGQpcIACwcY6QEAOukBAEGgpcIACwcZ6QEAO+kBAEGwpcIACwca6QEAPOkBAEHApcIACwcb6QEAPekBAEHQpcIACwcc6QEAPukBAEHgpcIACwcd6QEAP+kBAEHwpcIACwce6QEAQOkBAEGApsIACwcf6QEAQekBAEGQpsIACwcg6QEAQukBAEGgpsIACwch6QEAQ+kBAEGwpsIAC4wb/////0hhc2ggdGFibGUgY2FwYWNpdHkgb3ZlcmZsb3dgkxAATgAAAFAAAAAbAAAAL2NhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvaGFzaGJyb3duLTAuNi4yL3NyYy9yYXcvbW9kLnJzAAAIlhAASAAAAAICAAAjAAAACJYQAEgAAAADAgAAIwAAAOCTEABJAAAAhwIAAB0AAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliY29yZS9zbGljZS9zb3J0LnJzAAAA4JMQAEkAAACdAAAAOgAAAOCTEABJAAAApAAAADAAAAANAAAAAAAAAAEAAAAOAAAADwAAAAAAAAABAAAAEAAAAHyUEAA9AAAAJQAAAA0AAAAvVXNlcnMvdGhhdW1hbnQvUGxheWdyb3VuZC9sdWNpZC1zdWdnZXN0L3J1c3QvY29yZS9zcmMvbGliLnJzRHVwbGljYXRlIHN0b3JlIGlkAHyUEAA9AAAALwAAAA0AAAB8lBAAPQAAADsAAAANAAAATWlzc2luZyBzdG9yZSBpZHyUEAA9AAAAQwAAAA0AAABhbHJlYWR5IG11dGFibHkgYm9ycm93ZWQRAAAAAAAAAAEAAAASAAAAAgEDABMAAAAAAAAAAQAAAA4AAAAUAAAAAAAAAAEAAAAOAAAAAAAAABUAAAAAAAAAAQAAAA4AAAB8lRAASwAAACMAAAAJAAAAL1VzZXJzL3RoYXVtYW50L1BsYXlncm91bmQvbHVjaWQtc3VnZ2VzdC9ydXN0L2NvcmUvc3JjL3Rva2VuaXphdGlvbi93b3JkLnJzTWFsZm9ybWVkIHdvcmRzAACUrRAAFwAAALkBAAAnAAAACJYQAEgAAADsCgAACgAAAAiWEABIAAAA8goAAA4AAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliY29yZS9zbGljZS9tb2QucnNwlhAARQAAAAkDAAAeAAAAcJYQAEUAAAAMAwAANgAAAC9ydXN0Yy83MzUyOGUzMzlhYWUwZjE3YTE1ZmZhNDlhOGFjNjA4ZjUwYzZjZjE0L3NyYy9saWJhbGxvYy9zbGljZS5yc2Fzc2VydGlvbiBmYWlsZWQ6IG1pZCA8PSBsZW4AAAAgoBAAGAAAADYEAAANAAAAYXNzZXJ0aW9uIGZhaWxlZDogaW5kZXggPD0gbGVuAAAUlxAAEwAAAOMDAAAJAAAAc3JjL2xpYmFsbG9jL3ZlYy5yc2Fzc2VydGlvbiBmYWlsZWQ6IGluZGV4IDwgbGVuFJcQABMAAAALBAAACQAAAHSXEABDAAAANwsAADAAAAB0lxAAQwAAAEMLAAAsAAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmFsbG9jL3ZlYy5ycwAWAAAAAAAAAAEAAAAOAAAACQAAAKigEAABAAAACQAAAKCcEAACAAAACQAAAKKcEAADAAAABQAAAD6sEAACAAAABQAAAKWcEAACAAAABQAAAKecEAADAAAABQAAAKqcEAAEAAAABQAAAKmgEAACAAAABQAAAK6cEAACAAAABQAAALCcEAACAAAABQAAALKcEAACAAAABQAAALScEAAFAAAABQAAALmcEAAGAAAABQAAAL+cEAAEAAAABQAAAMOcEAAGAAAABQAAAMmcEAAGAAAABQAAAM+cEAAFAAAABQAAANScEAAFAAAABQAAANmcEAAEAAAABQAAAN2cEAAFAAAABQAAAOKcEAAGAAAABQAAAOicEAAHAAAABQAAAO+cEAAEAAAABQAAAPOcEAAHAAAABQAAAPqcEAAEAAAABQAAAP6cEAADAAAABQAAAAGdEAADAAAABQAAAASdEAAFAAAABgAAAAmdEAAFAAAABgAAAA6dEAAIAAAABgAAABadEAACAAAABgAAABidEAAHAAAABgAAALmcEAAGAAAABgAAAB+dEAADAAAABgAAACKdEAAGAAAABgAAAKecEAADAAAABgAAACidEAADAAAABgAAACudEAACAAAABgAAAC2dEAAEAAAABgAAADGdEAADAAAABgAAADSdEAAEAAAABgAAADSdEAAEAAAABgAAADidEAACAAAABgAAALScEAAFAAAABgAAALScEAAFAAAABgAAALScEAAFAAAABgAAADqdEAACAAAABgAAADydEAAEAAAABgAAAECdEAAEAAAABgAAAECdEAAEAAAABgAAAESdEAAGAAAABgAAAL+cEAAEAAAABgAAAEqdEAAGAAAABgAAAFCdEAAFAAAABgAAAFCdEAAFAAAABgAAAFCdEAAFAAAABgAAAFWdEAAEAAAABgAAAFmdEAAIAAAABgAAAGGdEAAEAAAABgAAAGGdEAAEAAAABgAAAGWdEAAIAAAABgAAAG2dEAAFAAAABgAAAHKdEAAHAAAABgAAAHKdEAAHAAAABgAAAHmdEAAIAAAABgAAAIGdEAAHAAAABgAAAIidEAAFAAAABgAAAI2dEAAJAAAABgAAAJadEAAFAAAABgAAAJadEAAFAAAABgAAAJadEAAFAAAABgAAAJudEAAGAAAABgAAAKGdEAADAAAABgAAAKSdEAAHAAAABgAAAKudEAAEAAAABgAAAK+dEAAIAAAABgAAALedEAAFAAAABgAAALydEAADAAAABgAAAL+dEAADAAAABgAAAMKdEAADAAAABwAAAKWcEAACAAAABwAAAKmgEAACAAAABwAAAMWdEAADAAAABwAAALCcEAACAAAABwAAALKcEAACAAAABwAAAMidEAACAAAABgAAAGIAAAAGAAAAYwAAAAYAAABkAAAABgAAAGYAAAAGAAAAZwAAAAYAAABoAAAABgAAAGoAAAAGAAAAawAAAAYAAABsAAAABgAAAG0AAAAGAAAAbgAAAAYAAABwAAAABgAAAHEAAAAGAAAAcgAAAAYAAABzAAAABgAAAHQAAAAGAAAAdgAAAAYAAAB3AAAABgAAAHgAAAAGAAAAegAAAAcAAABhAAAABwAAAGUAAAAHAAAAaQAAAAcAAABvAAAABwAAAHUAAAAHAAAAeQAAAGFudGhlYnlmb3Jmcm9tb2ZvbnRvc2luY2ViZWZvcmV0aWxsdW50aWxsYmVzaWRldW5kZXJiZWxvd292ZXJhYm92ZWFjcm9zc3Rocm91Z2hpbnRvdG93YXJkc29udG9vZmZvdXRhYm91dGFmdGVyYWx0aG91Z2hhc2JlY2F1c2VidXRlaXRoZXJob3dpZmxlc3Rub3JvbmNlb3Jzb3RoYW50aGF0dGhvdWdodW5sZXNzdW50aWx3aGF0d2hhdGV2ZXJ3aGVud2hlbmV2ZXJ3aGVyZXdoZXJlYXN3aGVyZXZlcndoZXRoZXJ3aGljaHdoaWNoZXZlcndoaWxld2hpbHN0d2hvd2hvZXZlcndob213aG9tZXZlcndob3Nld2h5eWV0YW5kbm90b2hOb3JtYWxpemF0aW9uIHNob3VsZCBhbHdheXMgYmUgdGhlIGZpcnN0IHN0ZXAACJ4QAEsAAABgAAAADQAAAC9Vc2Vycy90aGF1bWFudC9QbGF5Z3JvdW5kL2x1Y2lkLXN1Z2dlc3QvcnVzdC9jb3JlL3NyYy90b2tlbml6YXRpb24vdGV4dC5ycwAXAAAAY2Fubm90IGFjY2VzcyBhIFRocmVhZCBMb2NhbCBTdG9yYWdlIHZhbHVlIGR1cmluZyBvciBhZnRlciBkZXN0cnVjdGlvbmFscmVhZHkgYm9ycm93ZWQAABgAAAAAAAAAAQAAAA4AAAAZAAAAAAAAAAEAAAAQAAAA4J4QAHAAAAChAAAAEQAAAC9Vc2Vycy90aGF1bWFudC8uY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9ydXN0LXN0ZW1tZXJzLTEuMi4wL3NyYy9zbm93YmFsbC9zbm93YmFsbF9lbnYucnPgnhAAcAAAALYAAAARAAAA4J4QAHAAAADKAAAAEQAAAOCeEABwAAAA3wAAABEAAADgnhAAcAAAAAwBAAAWAAAA4J4QAHAAAAASAQAAGAAAAOCeEABwAAAALgEAABYAAADgnhAAcAAAAFYBAAAWAAAA4J4QAHAAAABcAQAAGAAAAOCeEABwAAAAXAEAAEkAAADgnhAAcAAAAHcBAAAWAAAAdKAQADQAAAA4oBAALQAAAGWgEAAMAAAAcaAQAAMAAAAgoBAAGAAAAKkIAAAJAAAAc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzYXNzZXJ0aW9uIGZhaWxlZDogYChsZWZ0ID09IHJpZ2h0KWAKICBsZWZ0OiBgYCwKIHJpZ2h0OiBgYDogZGVzdGluYXRpb24gYW5kIHNvdXJjZSBzbGljZXMgaGF2ZSBkaWZmZXJlbnQgbGVuZ3Roc2FpbgCwrBAABQAAAP//////////AEHEwcIACxC1rBAABQAAAP//////////AEHcwcIACxC6rBAABAAAAP//////////AEH0wcIACxC+rBAABgAAAP//////////AEGMwsIACw3ErBAABQAAAP////8DAEGkwsIACw3JrBAABQAAAP////8JAEG8wsIACw3OrBAABgAAAP////8HAEHUwsIACxDUrBAABAAAAP//////////AEHswsIACw3YrBAABAAAAP////8GAEGEw8IACw3crBAABQAAAP////8EAEGcw8IACxDhrBAABAAAAP//////////AEG0w8IACw3lrBAABAAAAP////8KAEHMw8IACw3prBAABgAAAP////8LAEHkw8IACw3vrBAABQAAAP////8CAEH8w8IACw30rBAABAAAAP////8BAEGUxMIACxBfohAAAwAAAP//////////AEGsxMIACw34rBAABQAAAP////8FAEHExMIACw39rBAABAAAAP////8IAEHcxMIAC0Bza2lza3lkaWVsaWV0aWVpZGxnZW50bHVnbGllYXJsaW9ubGlzaW5nbCd5WRFBEAGgrBAABQAAAP//////////AEGkxcIACxClrBAABgAAAP//////////AEG8xcIACxCrrBAABQAAAP//////////AEHUxcIACw2FohAAAQAAAP////8BAEHsxcIACw2brBAAAwAAAAAAAAABAEGExsIACw2erBAAAgAAAP////8BAEGcxsIACw2PrBAAAwAAAP////8CAEG0xsIACw1EqxAAAQAAAP////8DAEHMxsIACw2SrBAAAwAAAAEAAAACAEHkxsIACw2VrBAABAAAAAEAAAABAEH8xsIACxCsoxAAAgAAAAEAAAD/////AEGUx8IACxCZrBAAAgAAAAEAAAD/////AEGsx8IACxhzc2lpZQAAAFqsEAAHAAAA//////////8AQczHwgALEGGsEAAHAAAA//////////8AQeTHwgALEGisEAAGAAAA//////////8AQfzHwgALEG6sEAAHAAAA//////////8AQZTIwgALEHWsEAAGAAAA//////////8AQazIwgALEHusEAAHAAAA//////////8AQcTIwgALEIKsEAAHAAAA//////////8AQdzIwgALEImsEAAGAAAA//////////8AQfTIwgALDUSsEAACAAAA/////wIAQYzJwgALDUasEAADAAAAAAAAAAEAQaTJwgALDUmsEAADAAAA/////wIAQbzJwgALDUysEAAEAAAA/////wIAQdTJwgALDVCsEAAFAAAAAwAAAAEAQezJwgALDVWsEAAFAAAA/////wIAQYTKwgALEWVlAAAsrBAAAAAAAP////8DAEGgysIACw0srBAAAgAAAAAAAAACAEG4ysIACw0urBAAAgAAAAAAAAACAEHQysIACw0wrBAAAgAAAAAAAAACAEHoysIACw0yrBAAAgAAAAAAAAACAEGAy8IACw00rBAAAgAAAAAAAAABAEGYy8IACw02rBAAAgAAAAAAAAACAEGwy8IACw04rBAAAgAAAAAAAAACAEHIy8IACw06rBAAAgAAAAAAAAACAEHgy8IACw08rBAAAgAAAAAAAAACAEH4y8IACw0+rBAAAgAAAAAAAAABAEGQzMIACw1ArBAAAgAAAAAAAAACAEGozMIACw1CrBAAAgAAAAAAAAABAEHAzMIACxFlAAAAvKsQAAQAAAD/////AwBB3MzCAAsNwKsQAAQAAAD/////AgBB9MzCAAsNxKsQAAMAAAD/////DQBBjM3CAAsNx6sQAAIAAAD/////EABBpM3CAAsNyasQAAMAAAADAAAADABBvM3CAAsNzKsQAAQAAAAEAAAABABB1M3CAAsN0KsQAAQAAAADAAAACABB7M3CAAsN1KsQAAUAAAADAAAADgBBhM7CAAsN2asQAAYAAAADAAAADwBBnM7CAAsN36sQAAUAAAADAAAACgBBtM7CAAsN5KsQAAUAAAADAAAABQBBzM7CAAsN6asQAAUAAAD/////CABB5M7CAAsN7qsQAAYAAAD/////DABB/M7CAAsN9KsQAAUAAAD/////CwBBlM/CAAsNq6sQAAYAAAD/////AQBBrM/CAAsNsasQAAcAAAAOAAAABwBBxM/CAAsN+asQAAUAAAD/////CABB3M/CAAsN/qsQAAUAAAD/////BwBB9M/CAAsNA6wQAAcAAAARAAAABgBBjNDCAAsNCqwQAAQAAAD/////BgBBpNDCAAsNDqwQAAQAAAD/////BwBBvNDCAAsNEqwQAAcAAAD/////CwBB1NDCAAsNGawQAAcAAAD/////CQBB7NDCAAsNIKwQAAcAAAD/////CgBBhNHCAAtBdGlvbmVuY2VhbmNlYWJsZWVudGl6ZWF0ZWFsZnVsb3VzaXZlYmxlbG9nbGVzczeNAgAAAJOrEAAFAAAA/////wQAQdDRwgALDZirEAAFAAAA/////wYAQejRwgALDZ2rEAAFAAAA/////wMAQYDSwgALDaKrEAAFAAAA/////wQAQZjSwgALDaerEAAEAAAA/////wQAQbDSwgALDaurEAAGAAAA/////wEAQcjSwgALDbGrEAAHAAAABQAAAAIAQeDSwgALDZ+oEAADAAAA/////wUAQfjSwgALDbirEAAEAAAA/////wUAQZDTwgALEWljAACQqRAAAgAAAP////8BAEGs08IACw2MqBAABAAAAP////8BAEHE08IACw2IqBAABAAAAP////8BAEHc08IACw2QqBAABAAAAP////8BAEH008IACw14qxAABAAAAP////8BAEGM1MIACw2aqBAAAwAAAP////8BAEGk1MIACw2lqBAAAwAAAP////8BAEG81MIACw2XqBAAAwAAAP////8BAEHU1MIACw18qxAAAwAAAP////8BAEHs1MIACw2dqBAAAgAAAP////8BAEGE1cIACw1/qxAAAwAAAP////8BAEGc1cIACw2CqxAAAwAAAP////8CAEG01cIACw2FqxAAAgAAAP////8BAEHM1cIACw2iqBAAAwAAAP////8BAEHk1cIACw2HqxAAAwAAAP////8BAEH81cIACw2UqBAAAwAAAP////8BAEGU1sIACw2KqxAABAAAAA8AAAABAEGs1sIACw2OqxAABQAAABAAAAABAEHE1sIACxFzdAAAQKYQAAEAAAD/////AQBB4NbCAAsNq6gQAAEAAAD/////AgBB+NbCAAvpBGlibGVpdGlpc21pb25lcmFudG1lbnRlbWVudGljYXRlYXRpdmVhbGl6ZWljaXRpaWNhbHRpb25hbGF0aW9uYWxuZXNzYW5jaWVuY2lvZ2lsaWJsaWFibGlhbGxpZnVsbGlsZXNzbGlvdXNsaWVudGxpYWxpdGliaWxpdGlpdml0aWFsaXNtYXRpb25pemF0aW9uaXplcmF0b3JpdmVuZXNzZnVsbmVzc291c25lc3MBEUHQAWJiZGRmZmdnYmxtbW5ucHBycmF0dHRpemVkZWVkaW5nZWRseWVlZGx5aW5nbHlzdWNjZWVkcHJvY2VlZGV4Y2VlZGNhbm5pbmdpbm5pbmdlYXJyaW5naGVycmluZ291dGluZ2llZGllc3NzZXN1cydzJydzYXJzZW5jb21tdW5nZW5lcmFuZGVzYXRsYXNiaWFzY29zbW9zZHlpbmdlYXJseWdlbnRseWhvd2VpZGx5bHlpbmduZXdzb25seXNpbmdseXNraWVzc2tpc3R5aW5ndWdseUFjY2Vzc0Vycm9yY2FsbGVkIGBPcHRpb246OnVud3JhcCgpYCBvbiBhIGBOb25lYCB2YWx1ZQBIrRAAFQAAAHoBAAAVAAAAc3JjL2xpYmNvcmUvb3B0aW9uLnJzVHJpZWQgdG8gc2hyaW5rIHRvIGEgbGFyZ2VyIGNhcGFjaXR5AAAAlK0QABcAAABdAgAACQAAAHNyYy9saWJhbGxvYy9yYXdfdmVjLnJzaW50ZXJuYWwgZXJyb3I6IGVudGVyZWQgdW5yZWFjaGFibGUgY29kZQCUrRAAFwAAAAoCAAAnAHsJcHJvZHVjZXJzAghsYW5ndWFnZQEEUnVzdAAMcHJvY2Vzc2VkLWJ5AwVydXN0Yx0xLjQwLjAgKDczNTI4ZTMzOSAyMDE5LTEyLTE2KQZ3YWxydXMGMC4xNC4wDHdhc20tYmluZGdlbhIwLjIuNTggKDI5MDJjZWIyNik=';
-
-                var binary = null;
-
-                var isNode = typeof process !== 'undefined'
-                    && process.versions != null
-                    && process.versions.node != null;
-
-                if (isNode) {
-                    binary = Buffer.from(src, 'base64');
-                } else {
-                    var raw = window.atob(src);
-                    binary = new Uint8Array(new ArrayBuffer(raw.length));
-                    for(var i = 0; i < raw.length; i++) {
-                        binary[i] = raw.charCodeAt(i);
-                    }
-                }
-
-                var instantiatePromise = WebAssembly.instantiate(
-                    binary,
-                    {env: {memory: new WebAssembly.Memory({initial: 10})}}
-                );
-
-                var exportsPromise = instantiatePromise.then(result => {
-                    return result.instance.exports
-                });
-
-var compileWasm = exportsPromise.then(function(wasm) {
-                const __exports__ = {};
-
-                
-/**
-* @param {number} id
-*/
-__exports__.create_store = function(id) {
-    wasm.create_store(id);
-};
-
-/**
-* @param {number} id
-*/
-__exports__.destroy_store = function(id) {
-    wasm.destroy_store(id);
-};
-
-let WASM_VECTOR_LEN = 0;
-
-let cachegetUint8Memory0 = null;
-function getUint8Memory0() {
-    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
-    }
-    return cachegetUint8Memory0;
-}
-
-const lTextEncoder = typeof TextEncoder === 'undefined' ? require('util').TextEncoder : TextEncoder;
-
-let cachedTextEncoder = new lTextEncoder('utf-8');
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-}
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
-
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length);
-        getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
-        return ptr;
-    }
-
-    let len = arg.length;
-    let ptr = malloc(len);
-
-    const mem = getUint8Memory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
-    }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3);
-        const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
-
-        offset += ret.written;
-    }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {string} left
-* @param {string} right
-*/
-__exports__.highlight_with = function(store_id, left, right) {
-    var ptr0 = passStringToWasm0(left, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(right, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    wasm.highlight_with(store_id, ptr0, len0, ptr1, len1);
-};
-
-/**
-* @param {number} id
-* @param {number} limit
-*/
-__exports__.set_limit = function(id, limit) {
-    wasm.set_limit(id, limit);
-};
-
-let cachegetUint32Memory0 = null;
-function getUint32Memory0() {
-    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
-    }
-    return cachegetUint32Memory0;
-}
-
-function passArray32ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 4);
-    getUint32Memory0().set(arg, ptr / 4);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {Uint32Array} ids
-* @param {string} texts
-* @param {Uint32Array} ratings
-*/
-__exports__.set_records = function(store_id, ids, texts, ratings) {
-    var ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(texts, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    var ptr2 = passArray32ToWasm0(ratings, wasm.__wbindgen_malloc);
-    var len2 = WASM_VECTOR_LEN;
-    wasm.set_records(store_id, ptr0, len0, ptr1, len1, ptr2, len2);
-};
-
-/**
-* @param {number} store_id
-* @param {string} query
-*/
-__exports__.run_search = function(store_id, query) {
-    var ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    wasm.run_search(store_id, ptr0, len0);
-};
-
-let cachegetInt32Memory0 = null;
-function getInt32Memory0() {
-    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
-    }
-    return cachegetInt32Memory0;
-}
-
-function getArrayU32FromWasm0(ptr, len) {
-    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
-}
-/**
-* @param {number} store_id
-* @returns {Uint32Array}
-*/
-__exports__.get_result_ids = function(store_id) {
-    wasm.get_result_ids(8, store_id);
-    var r0 = getInt32Memory0()[8 / 4 + 0];
-    var r1 = getInt32Memory0()[8 / 4 + 1];
-    var v0 = getArrayU32FromWasm0(r0, r1).slice();
-    wasm.__wbindgen_free(r0, r1 * 4);
-    return v0;
-};
-
-const lTextDecoder = typeof TextDecoder === 'undefined' ? require('util').TextDecoder : TextDecoder;
-
-let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-
-cachedTextDecoder.decode();
-
-function getStringFromWasm0(ptr, len) {
-    return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
-}
-/**
-* @param {number} store_id
-* @returns {string}
-*/
-__exports__.get_result_titles = function(store_id) {
-    try {
-        wasm.get_result_titles(8, store_id);
-        var r0 = getInt32Memory0()[8 / 4 + 0];
-        var r1 = getInt32Memory0()[8 / 4 + 1];
-        return getStringFromWasm0(r0, r1);
-    } finally {
-        wasm.__wbindgen_free(r0, r1);
-    }
-};
-
-
-
-                return __exports__;
-            });
-
-const DEFAULT_LIMIT = 10;
-var NEXT_ID = 1;
-
-
-class LucidSuggest {
-    constructor() {
-        this.id         = NEXT_ID++;
-        this.limit      = DEFAULT_LIMIT;
-        this.records    = [];
-        this.setupQueue = compileWasm;
-
-        this.setup(wasm => {
-            wasm.create_store(this.id);
-            wasm.highlight_with(this.id, '{{', '}}');
-        });
-    }
-
-    setup(fn) {
-        this.setupQueue = this.setupQueue.then(async wasm => {
-            await fn(wasm);
-            this.setupQueue = Promise.resolve(wasm);
-            return wasm
-        });
-        return this.setupQueue
-    }
-
-    destroy() {
-        const oldQueue = this.setupQueue;
-        this.setupQueue = Promise.reject(new Error('Suggest destroyed'));
-        oldQueue.then(wasm => {
-            wasm.destroy_store(this.id);
-        });
-    }
-
-    setRecords(records) {
-        return this.setup(wasm => {
-            this.records = setRatings(records);
-            wasm.set_records(
-                this.id,
-                this.records.map(r => r.id),
-                this.records.map(r => r.title).join('\0'),
-                this.records.map(r => r.rating),
-            );
-        })
-    }
-
-    setLimit(limit) {
-        return this.setup(wasm => {
-            this.limit = limit;
-            wasm.set_limit(this.id, this.limit);
-        })
-    }
-
-    async search(query) {
-        const wasm = await this.setupQueue;
-        wasm.run_search(this.id, query);
-        const ids    = wasm.get_result_ids(this.id);
-        const titles = wasm.get_result_titles(this.id).split('\0');
-        const hits   = [];
-        for (let i = 0; i < ids.length; i++) {
-            const id     = ids[i];
-            const title  = titles[i];
-            const record = this.records.find(r => r.id === id);
-            if (!record) throw new Error(`Missing record ${id}`)
-            if (!title)  throw new Error(`Missing title for ${id}`)
-            hits.push(new Hit(title, record));
-        }
-        return hits
-    }
-}
-
-
-function highlight(hit, left, right) {
-    let result = '';
-    for (const {text, highlight} of hit.chunks) {
-        result += highlight
-            ? left + text + right
-            : text;
-    }
-    return result
-}
-
-
-class Hit {
-    constructor(title, record) {
-        this.record = record;
-        this.chunks = toChunks(title);
-    }
-
-    get title() {
-        return highlight(this, '[', ']')
-    }
-}
-
-
-function toChunks(title) {
-    const split  = title.split(/{{|}}/g);
-    const chunks = [];
-    for (let i = 0; i < split.length; i++) {
-        if (split[i] != '') {
-            chunks.push({
-                text: split[i],
-                highlight: i % 2 === 1,
-            });
-        }
-    }
-    return chunks
-}
-
-
-function setRatings(records) {
-    return records.some(r => r.rating != null && r.rating > 0)
-        ? records.map(r => ({...r, rating: r.rating > 0 ? r.rating : 0}))
-        : records.map((r, i) => ({...r, rating: records.length - i}))
-}
-
-exports.Hit = Hit;
-exports.LucidSuggest = LucidSuggest;
-exports.highlight = highlight;
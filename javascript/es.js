@@ -1,346 +0,0 @@
-'use strict';
-
-Object.defineProperty(exports, '__esModule', { value: true });
-
-var src = 'AGFzbQEAAAABehNgAn9/AGACf38Bf2ADf39/AGADf39/AX9gAX8AYAR/f39/AGAFf39/f38AYAF/AX9gAAF/YAR/f39/AX9gAABgB39/f39/f38AYAV/f39/fwF/YAd/f39/f39/AX9gAn9+AX9gAn5/AX9gAX8BfmABfAF8YAJ8fAF8A9kB1wEFAgIHBgYHBgYGAAAAAAAAAAAAAAACCwAEBAUDBAEAAAIECQIBDAIIAAUAAQUJAQAABwIHBwYDBQEBAAICAQEAAAYFBQEDAgUFAA0CAAAADwIAAAABAQEBAAQBAQABAQEABgQABQIFBQQFAAAABAQAAAIABQAAAAQCCAICBAARAAQGBgICAgACAAAAAAgIAAQEAgIACQIFDgADAAAAAAEAAAAAAAAEAAIAAQQAAAAKAgQAAQIAAwkAAgIDBAcEBwADCAgDBQUFBBIBAQEBAAoJAQoBChAABAQFAXABGhoFAwEAEQYJAX8BQYCAwAALB8QBDAZtZW1vcnkCAAxjcmVhdGVfc3RvcmUAHA1kZXN0cm95X3N0b3JlABgOaGlnaGxpZ2h0X3dpdGgAQQlzZXRfbGltaXQAWAtzZXRfcmVjb3JkcwAWCnJ1bl9zZWFyY2gAAg5nZXRfcmVzdWx0X2lkcwBMEWdldF9yZXN1bHRfdGl0bGVzAEARX193YmluZGdlbl9tYWxsb2MAvAESX193YmluZGdlbl9yZWFsbG9jAMMBD19fd2JpbmRnZW5fZnJlZQDNAQk0AQBBAQsZBtUBzAEd0AGeAS7QAakBVNYB1AHWAcoB1gHLAdYByQHWAdYB1gHWAcIB1gHWAQrj1QbXAc9YA0F/BX4CfCMAQYAEayIEJAACQAJAAkACQAJAIAEoAgAiBSABKAIERwRAIARB7ABqIScgBEHUA2ohKCAEQZUDaiEvIARB5QNqITAgBEGQAmohHSAEQfgCaiEYIARB+ABqITEgBEH1AGohPCAEQcgDaiEiIARBzANqIRkgBEGEAWohOSAEQeQAaiE6IARB4ABqIT0gBEGLAmohMiAEQd0DaiEeIARB7ANqISMgBEHcAmohKSAEQe0CaiE+A0AgASAFQQRqNgIAAkACQAJAAkACQCABKAIIKAIAIgZBDGooAgAiByAFKAIAIgVLBEAgBigCBCAFQTxsaiIFKAI4IT8gBSgCCCEqIAUoAgQhQCAFQTRqKAIAITMgBUEsaigCACE0IAVBKGooAgAhNSAFQSBqKAIAITYgBUEcaigCACE3IAVBFGooAgAhOCAFQRBqKAIAISQgAygCACgCACEKEIwBIhdFDQwQjQEiFkUNDCAXKAIARQRAIBdBfzYCACAWKAIARQRAIBZBfzYCACAWQQRqIQsCQCAXQQRqIhEoAgAiBUUEQEEAIQUgF0EANgIUDAELIBcoAghB/wEgBUEFahDAARogF0EANgIUIBcoAgQiBUEISQ0AIAVBAWpBA3ZBB2whBQsgFyAFNgIQAkAgCygCACIFRQRAQQAhBSAWQQA2AhQMAQsgFigCCEH/ASAFQQVqEMABGiAWQQA2AhQgFigCBCIFQQhJDQAgBUEBakEDdkEHbCEFCyAWIAU2AhAgCigCBCIFBEAgCigCACIUIAVBFGxqISUgKiAkQRRsaiIaQWxqISsDQAJAIAsgFCgCACIJEF8NACAEIAk2AiggBCAULwEQOwFQIAQgFCgCDDYCNCAEIBQoAggiDjYCMCAEIBQoAgQiEzYCLCAEIAooAhgiHzYCSCAEIAooAhAiIDYCQCAEIAooAggiITYCOCAEIApBHGooAgAiLDYCTCAEIApBFGooAgAiQTYCRCAEIApBDGooAgAiQjYCPCAEQQI6AHQgJEUNAEECIQcgKiEFAkADQAJAAkAgESAFKAIAIg0QXw0AIAQgMzYCvAEgBCA0NgK4ASAEIDU2ArQBIAQgNjYCsAEgBCA3NgKsASAEIDg2AqgBIAQgBUEQai8BADsBwAEgBCAFQQxqKAIANgKkASAEIAVBCGooAgAiDDYCoAEgBCAFQQRqKAIAIgY2ApwBIAQgDTYCmAECQAJAICQgDUEBaiISTQ0AIAQgMzYCrAIgBCA0NgKoAiAEIDU2AqQCIAQgNjYCoAIgBCA3NgKcAiAEIDg2ApgCIAQgKiASQRRsaiIILQARIhU6ALECIAQgCC0AEDoAsAIgBCAIKAIMIhs2ApQCIAQgCCgCCCIPNgKQAiAEIAgoAgQiEDYCjAIgBCAIKAIANgKIAgJAAkAgBiAPSQRAIBAgDEkNASAQIAxrIQgMAgsgBiAPayEIDAELENEBAAsgDiATayAMIAZrIAhqSQ0AIBEgEhBfDQAgBCAVOgCZAyAEQQo6AJgDIAQgDzYC+AIgBCANNgLwAiAEIDM2ApQDIAQgNDYCkAMgBCA1NgKMAyAEIDY2AogDIAQgNzYChAMgBCA4NgKAAyAEIAY2AvQCIAQgECAGayAbajYC/AIgBEHAA2ogBEHwAmogBEEoahABIARBgAJqIg0gGUEIaiIIKQIANwMAIARB9gFqIgwgHkECaiIbLQAAOgAAIARB6AFqIgYgI0EIaiImKQAANwMAIARB8AFqIg8gI0EQaiI7KAAANgIAIAQgGSkCADcD+AEgBCAeLwAAOwH0ASAEICMpAAA3A+ABIAQtANwDIi1BAkYNACAEKALoAyEcIAQpA+ADIUUgBCgCyAMhLiAEKQPAAyFGIARByAJqIhAgDSkDADcDACAEQagDaiISIAYpAwA3AwAgBEGwA2oiFSAPKAIANgIAIAQgBCkD+AE3A8ACIAQgBC8B9AE7AbgDIAQgBCkD4AE3A6ADIAQgDC0AADoAugMgBCAuNgLYAiAEIEY3A9ACICkgBCkDwAI3AgAgKUEIaiAQKQMANwIAIAQgLToA7AIgPiAELwG4AzsAACA+QQJqIAQtALoDOgAAIARB2AFqIi0gFSgCADYCACAEQdABaiIuIBIpAwA3AwAgBCAEKQOgAzcDyAEgBEHAA2ogBEHQAmogBEGYAWogBEGIAmoQQiANIAgpAgA3AwAgDCAbLQAAOgAAIAYgJikAADcDACAPIDsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiJkECRg0AIAQoAugDIQUgBCkD4AMhRyAEKALIAyEHIAQpA8ADIUYgECANKQMANwMAIBggBikDADcDACAEQYADaiITIA8oAgA2AgAgBCAEKQP4ATcDwAIgBCAELwH0ATsBuAMgBCAEKQPgATcD8AIgBCAMLQAAOgC6AyAGIBApAwA3AwAgBCAEKQPAAjcD4AEgBCAELwG4AzsB+AEgBCAELQC6AzoA+gEgFSATKAIANgIAIBIgGCkDADcDACAEIAQpA/ACNwOgAyAEIAc2AsgDIAQgRjcDwAMgGSAEKQPgATcCACAIIAYpAwA3AgAgBCAmOgDcAyAeIAQvAfgBOwAAIBsgBC0A+gE6AAAgBEHwAmogESAHIARBwANqECkgGSAEKQOgAzcCACAIIBIpAwA3AgAgGUEQaiIGIBUoAgA2AgAgBCAFNgLIAyAEIEc3A8ADIARB8AJqIBEgBSAEQcADahApIBkgBCkDyAE3AgAgCCAuKQMANwIAIAYgLSgCADYCACAEIBw2AsgDIAQgRTcDwAMgBEHwAmogCyAcIARBwANqECkMAQsCQCAKKAIEIAlBAWoiDE0NACAKKAIAIQYgCikCCCFFIAopAhAhRiAEIAopAhg3A6gCIAQgRjcDoAIgBCBFNwOYAiAEIAYgDEEUbGoiBi0AESIPOgCxAiAEIAYtABA6ALACIAQgBigCDCIQNgKUAiAEIAYoAggiCDYCkAIgBCAGKAIEIg02AowCIAQgBigCADYCiAIgBCgCnAEhEiAEKAKgAQJAAkAgEyAISQRAIA0gDkkNASANIA5rIQYMAgsgEyAIayEGDAELENEBAAsgEmsgDiATayAGakkNACALIAwQXw0AIAQgDzoAmQMgBEEKOgCYAyAEIAg2AvgCIAQgCTYC8AIgBCAsNgKUAyAEIB82ApADIAQgQTYCjAMgBCAgNgKIAyAEIEI2AoQDIAQgITYCgAMgBCATNgL0AiAEIA0gE2sgEGo2AvwCIARBwANqIARBmAFqIARB8AJqEAEgBEGAAmoiDSAZQQhqIggpAgA3AwAgBEH2AWoiDCAeQQJqIg8tAAA6AAAgBEHoAWoiBiAjQQhqIiYpAAA3AwAgBEHwAWoiECAjQRBqIjsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiLUECRg0AIAQoAugDIS4gBCkD4AMhRSAEKALIAyEcIAQpA8ADIUYgBEHQAWoiEiANKQMANwMAIARBugNqIkMgDC0AADoAACAEQagDaiIVIAYpAwA3AwAgBEGwA2oiGyAQKAIANgIAIAQgBCkD+AE3A8gBIAQgBC8B9AE7AbgDIAQgBCkD4AE3A6ADIARByAJqIkQgEikDADcDACAEIAQpA8gBNwPAAiAEIAQvAbgDOwG4AiAEIEMtAAA6ALoCIAQgLjYC2AIgBCBFNwPQAiApIAQpA6ADNwIAIClBCGogFSkDADcCACApQRBqIBsoAgA2AgAgBEHAA2ogBEHQAmogBEEoaiAEQYgCahBCIA0gCCkCADcDACAMIA8tAAA6AAAgBiAmKQAANwMAIBAgOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyImQQJGDQAgBCgC6AMhBSAEKQPgAyFHIAQoAsgDIQcgBCkDwAMhRSASIA0pAwA3AwAgGCAGKQMANwMAIARBgANqIhMgECgCADYCACAEIAQpA/gBNwPIASAEIAQvAfQBOwG4AyAEIAQpA+ABNwPwAiAEIAwtAAA6ALoDIAYgEikDADcDACAEIAQpA8gBNwPgASAEIAQvAbgDOwH4ASAEIAQtALoDOgD6ASAbIBMoAgA2AgAgFSAYKQMANwMAIAQgBCkD8AI3A6ADIAQgHDYCyAMgBCBGNwPAAyAZIAQpA8ACNwIAIAggRCkDADcCACAEIC06ANwDIB4gBC8BuAI7AAAgDyAELQC6AjoAACAEQfACaiARIBwgBEHAA2oQKSAZIAQpA+ABNwIAIAggBikDADcCACAeIAQvAfgBOwAAIA8gBC0A+gE6AAAgBCAHNgLIAyAEIEU3A8ADIAQgJjoA3AMgBEHwAmogCyAHIARBwANqECkgGSAEKQOgAzcCACAIIBUpAwA3AgAgGUEQaiAbKAIANgIAIAQgBTYCyAMgBCBHNwPAAyAEQfACaiALIAUgBEHAA2oQKQwBCyAEQcADaiAEQZgBaiAEQShqEAEgBEHYAmoiCCAiQQhqKAIANgIAIAQgIikDADcD0AIgBCsDwAMhSiAEKALUAyENIAQoAtgDIQwgBC0A3AMhBiAEQfACaiAeQSMQugEaIAZBAkYNASAEQagDaiIPIAgoAgA2AgAgBCAEKQPQAjcDoAMgBEGIAmogBEHwAmpBIxC6ARogGCAPKAIANgIAIAQgBCkDoAM3A/ACIAQgBC8AiAI7AdACIAQgBEGKAmotAAA6ANICIARB2ANqIhAgMkEYaikAADcDACAEQdADaiISIDJBEGopAAA3AwAgIiAyQQhqKQAANwMAIAQgMikAADcDwAMCfyBKmyJLRAAAAAAAAPBBYyBLRAAAAAAAAAAAZnEEQCBLqwwBC0EACyEPAkACQCAHQf8BcUECRiIVDQBBACEIQX8gDCANayAPQQF0ayIbQQAgBEHYAGogFRsiDygCGCAPKAIUawJ/IA8rAwCbIktEAAAAAAAA8EFjIEtEAAAAAAAAAABmcQRAIEurDAELQQALQQF0ayIPRyAPIBtJG0EBaiIPQQFLDQEgD0EBaw0AIAYNAQsgPSAEKQPwAjcDACA8IAQvAdACOwAAIDEgBCkDwAM3AwAgPEECaiAELQDSAjoAACA9QQhqIBgoAgA2AgAgMUEIaiAiKQMANwMAIDFBEGogEikDADcDACAxQRhqIBApAwA3AwAgBCAMNgJwIAQgDTYCbCAEIAY6AHQgBkEBcyEIIAQgSjkDWCBKvSFHIAYhBwsgBSArRg0EIAVBFGohBSAIRQ0DDAQLIARBAjoA3ANBACEFA0AgBEHYAGogBWoiBikDACFHIAYgBEHAA2ogBWoiBykDADcDACAHIEc3AwAgBkEIaiIIKQMAIUUgCCAHQQhqIggpAwA3AwAgBkEQaiITKQMAIUYgEyAHQRBqIhMpAwA3AwAgBkEYaiIGKQMAIUcgBiAHQRhqIgYpAwA3AwAgBiBHNwMAIBMgRjcDACAIIEU3AwAgBUFAayAFQSBqIQVBwQBJDQALIAQtAHQhBwwBCyAaIAVBFGoiBUcNAQsLIAdB/wFxQQJGDQEgBCkDWCFHCyAEKQN4IUUgBCgCgAEhBSAEKAJgIQYgGSA6KQIANwIAIBlBCGoiByA6QQhqKQIANwIAIBlBEGoiCCA6QRBqKAIANgIAIAQgBjYCyAMgBCBHNwPAAyAEQfACaiARIAYgBEHAA2oQKSAZIDkpAgA3AgAgByA5QQhqKQIANwIAIAggOUEQaigCADYCACAEIAU2AsgDIAQgRTcDwAMgBEHwAmogCyAFIARBwANqECkLIBRBFGoiFCAlRw0ACwsgFygCCCIFKAIAIQggFygCFCEGIBdBADYCFCAXKAIQIRQgF0EANgIQIBcoAgwhByAXQQg2AgwgF0GwpsIANgIIIBcoAgQhCiAXQQA2AgQgBCARNgKAASAEIAY2AnwgBCAUNgJ4IAQgBzYCdCAEIAU2AnAgBCAKNgJsIAQgBjYCaCAEIAUgCmpBAWo2AmQgBCAFQQRqNgJgIAQgBzYCXCAEIAhBf3NBgIGChHhxNgJYIARBwANqIARB2ABqEH8CfyAELQDkAyIGQQJGBEACQCAEKAJsIgVFBEBBACEFIARBADYCfAwBCyAEKAJwQf8BIAVBBWoQwAEaIARBADYCfCAFQQhJDQAgBUEBakEDdkEHbCEFCyAEIAU2AnggBCgCgAEiBSAnKQIANwIAIAVBEGogJ0EQaigCADYCACAFQQhqICdBCGopAgA3AgBCACFFQQgMAQsgBEHwAmogBEHAA2pBJBC6ARogHSAYQQhqKQMANwMAIARBmAJqIgogGEEQaikDADcDACAEQaACaiIIIBhBGGooAgA2AgAgBCAwQQJqLQAAOgCaASAEIDAvAAA7AZgBIAQgGCkDADcDiAIgBEEgakF/IAQoAmgiBUEBaiIHIAcgBUkbEKQBIAQoAiQhBSAEKAIgIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsELoBGiAEQfACaiAEQcADahB/AkAgBC0AlAMiCEECRg0AQQEhBkEgIQkDQCAEQYgCaiAEQfACakEkELoBGiAEQbABaiAdQRhqKAIAIgo2AgAgBEGoAWogHUEQaikDACJFNwMAIARBoAFqIB1BCGopAwAiRjcDACAEIC9BAmotAAA6ANICIAQgLy8AADsB0AIgBCAdKQMAIkc3A5gBIBggRjcDACAEQYADaiIRIEU3AwAgBEGIA2oiFCAKNgIAIAQgRzcD8AIgBCAELwHQAjsBiAIgBCAELQDSAjoAigIgBSAGRgRAIARBKGogBUF/IAQoAtADIgVBAWoiByAHIAVJGxCEASAEKAIoIQcLIAcgCWoiBSAEKQPwAjcDACAYKQMAIUUgESkDACFGIBQoAgAhCiAFQRxqIAg6AAAgBUEdaiAELwGIAjsAACAFQR9qIAQtAIoCOgAAIAVBGGogCjYCACAFQRBqIEY3AwAgBUEIaiBFNwMAIAQgBkEBaiIGNgIwIARB8AJqIARBwANqEH8gBC0AlAMiCEECRg0BIAlBIGohCSAEKAIsIQUMAAsACwJAIAQoAtQDIgVFBEBBACEFIARBADYC5AMMAQsgBCgC2ANB/wEgBUEFahDAARogBEEANgLkAyAFQQhJDQAgBUEBakEDdkEHbCEFCyAEKALoAyIGICgpAgA3AgAgBCAFNgLgAyAGQQhqIChBCGopAgA3AgAgBkEQaiAoQRBqKAIANgIAIAQpAiwhRSAEKAIoCyENIBYoAggiBSgCACEIIBYoAhQhBiAWQQA2AhQgFigCECERIBZBADYCECAWKAIMIQcgFkEINgIMIBZBsKbCADYCCCAWKAIEIQogFkEANgIEIAQgCzYCgAEgBCAGNgJ8IAQgETYCeCAEIAc2AnQgBCAFNgJwIAQgCjYCbCAEIAY2AmggBCAFIApqQQFqNgJkIAQgBUEEajYCYCAEIAc2AlwgBCAIQX9zQYCBgoR4cTYCWCAEQcADaiAEQdgAahB/An8gBC0A5AMiBkECRgRAAkAgBCgCbCIFRQRAQQAhBSAEQQA2AnwMAQsgBCgCcEH/ASAFQQVqEMABGiAEQQA2AnwgBUEISQ0AIAVBAWpBA3ZBB2whBQsgBCAFNgJ4IAQoAoABIgUgJykCADcCACAFQRBqICdBEGooAgA2AgAgBUEIaiAnQQhqKQIANwIAQQAhG0EAIRFBCAwBCyAEQfACaiAEQcADakEkELoBGiAdIBhBCGopAwA3AwAgBEGYAmoiCiAYQRBqKQMANwMAIARBoAJqIgggGEEYaigCADYCACAEIDBBAmotAAA6AJoBIAQgMC8AADsBmAEgBCAYKQMANwOIAkEBIREgBEEYakF/IAQoAmgiBUEBaiIHIAcgBUkbEKQBIAQoAhwhBSAEKAIYIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsELoBGiAEQfACaiAEQcADahB/IAQtAJQDIghBAkcEQEEBIQZBICEJA0AgBEGIAmogBEHwAmpBJBC6ARogBEGwAWogHUEYaigCACIKNgIAIARBqAFqIB1BEGopAwAiRjcDACAEQaABaiAdQQhqKQMAIkg3AwAgBCAvQQJqLQAAOgDSAiAEIC8vAAA7AdACIAQgHSkDACJHNwOYASAYIEg3AwAgBEGAA2oiCyBGNwMAIARBiANqIhEgCjYCACAEIEc3A/ACIAQgBC8B0AI7AYgCIAQgBC0A0gI6AIoCIAUgBkYEQCAEQShqIAVBfyAEKALQAyIFQQFqIgcgByAFSRsQhAEgBCgCKCEHCyAHIAlqIgUgBCkD8AI3AwAgGCkDACFGIAspAwAhSCARKAIAIQogBUEcaiAIOgAAIAVBHWogBC8BiAI7AAAgBUEfaiAELQCKAjoAACAFQRhqIAo2AgAgBUEQaiBINwMAIAVBCGogRjcDACAEIAZBAWoiCjYCMCAEQfACaiAEQcADahB/IAQtAJQDIghBAkcEQCAJQSBqIQkgBCgCLCEFIAohBgwBCwsgBkEBaiERCwJAIAQoAtQDIgVFBEBBACEFIARBADYC5AMMAQsgBCgC2ANB/wEgBUEFahDAARogBEEANgLkAyAFQQhJDQAgBUEBakEDdkEHbCEFCyAEKALoAyIGICgpAgA3AgAgBCAFNgLgAyAGQQhqIChBCGopAgA3AgAgBkEQaiAoQRBqKAIANgIAIAQoAiwhGyAEKAIoCyEPIEVCIIgiRqciFEEVSQ0DIARBEGogRUIhiKcQpAEgBCgCFCAEKAIQIRNBACEFIARBADYCYCAEQgQ3A1ggDUFgaiEcIA1BqH9qISVBACEHIBQhCgNAAkAgCiILQX9qIgpFBEBBASEIQQAhCgwBCwJAAkACQAJAAkAgDSAKQQV0aigCCCALQQV0IgwgDWpBSGooAgAiBk8EQCALQX5qIQ4gDCAlaiEIQQAhCkEAIQkDQCAJIA5GBEAgCyEIDAgLIAlBAWohCSAGIAgoAgAiBk8gCEFgaiEIDQALIAlBAWohCCAJQX9zIAtqIQoMAQsgDCAlaiEJA0ACQCAKQQFGBEBBACEKDAELIApBf2ohCiAGIAkoAgAiBkkgCUFgaiEJDQELCyALIApJDQEgCyAUSw0CIAsgCmsiCEEBdiIORQ0AIA0gCkEFdGohBiAMIBxqIQkDQCAEQdgDaiIMIAZBGGoiECkDADcDACAEQdADaiISIAZBEGoiFSkDADcDACAiIAZBCGoiGikDADcDACAEIAYpAwA3A8ADIAlBCGoiHykDACFIIAlBEGoiICkDACFJIAlBGGoiISkDACFHIAYgCSkDADcDACAQIEc3AwAgFSBJNwMAIBogSDcDACAhIAwpAwA3AwAgICASKQMANwMAIB8gIikDADcDACAJIAQpA8ADNwMAIAZBIGohBiAJQWBqIQkgDkF/aiIODQALCyAKRSAIQQlLcg0EIAsgCmshCCAKQX9qIQUgHCAKQQV0aiEGA0AgCyAFSQ0DIAsgFEsNAiAGIAhBAWoiCBA/IAVBf2oiByAFTw0EIAZBYGohBiAHIQUgCEEKSQ0ACwwDCyAKIAsQqAEACyALIBQQpgEACyAFIAsQqAEACyAHQQFqIQogBCgCYCIFIQcLIAQoAlwgB0YEQCAEQdgAaiAHEIsBIAQoAmAiBSEHCyAEKAJYIgYgB0EDdGoiByAINgIEIAcgCjYCACAEIAVBAWoiBTYCYAJ/AkAgBUECSQ0AAkADQAJAAkACQAJAIAYgBUF/akEDdGoiBygCAEUNACAFQQN0IAZqIg5BdGooAgAiCyAHKAIEIghNDQBBAiAFQQJNDQcaIAYgBUF9aiIMQQN0aigCBCIJIAggC2pNDQFBAyAFQQNNDQcaIA5BZGooAgAgCSALaksNBgwBCyAFQQNJDQEgBygCBCEIIAYgBUF9aiIMQQN0aigCBCEJCyAJIAhJDQELIAVBfmohDAsCQAJAAkACQAJAAkACQCAFIAxBAWoiEksEQCAFIAxNDQEgBiAMQQN0Ih9qIgUoAgQiICAFKAIAaiIFIAYgEkEDdCIhaiIGKAIAIhVJDQIgBSAUSw0DIA0gFUEFdGoiCyAGKAIEIhBBBXQiCGohByAFQQV0IRogBSAVayIOIBBrIgUgEE8NBCATIAcgBUEFdCIGELoBIg4gBmohCSAQQQFOQQAgBUEBThtFBEAgByEFDAYLIBogHGohCCAHIQUDQCAIIAVBYGoiGiAJQWBqIiwgCUFoaigCACAFQWhqKAIASSIHGyIGKQMANwMAIAhBGGogBkEYaikDADcDACAIQRBqIAZBEGopAwA3AwAgCEEIaiAGQQhqKQMANwMAIAkgLCAHGyEJIAsgGiAFIAcbIgVPDQYgCEFgaiEIIAkgDiIGSw0ACwwHC0Hoq8IAIBIgBRCnAQALQeirwgAgDCAFEKcBAAsgFSAFEKgBAAsgBSAUEKYBAAsgCCATIAsgCBC6ASIGaiEJIA4gEEpBACAQQQFOGw0BIAshBQsgEyEGDAELIA0gGmohDiALIQUDQCAFIAcgBiAHKAIIIAYoAghJIgsbIggpAwA3AwAgBUEYaiAIQRhqKQMANwMAIAVBEGogCEEQaikDADcDACAFQQhqIAhBCGopAwA3AwAgBiAGQSBqIAsbIQYgBUEgaiEFIAdBIGogByALGyIHIA5PDQEgCSAGSw0ACwsgBSAGIAkgBmtBYHEQugEaIAQoAmAiBSAMSwRAIAQoAlgiBiAfaiIHIBAgIGo2AgQgByAVNgIAIAUgEk0NAiAGICFqIgcgB0EIaiAFIBJBf3NqQQN0EJkBGiAEIAVBf2oiBTYCYCAFIAVBAU0NBBoMAQsLQfirwgAgDCAFEKcBAAtBp67CAEEdQcSuwgAQuQEACyAFCyEHIAoNAAsgBCgCXARAIAYQIQtFDQQgExAhDAQLDAwLDAsLQeirwgAgBSAHEKcBAAsgFEECSQ0AIBRBfmohBSANIBRBBXRqQUBqIQZBAiEJA0AgBSAUSw0CIAYgCRA/IAZBYGohBiAJQQFqIQkgBUF/aiIFQX9HDQALCyARQRVJDQEgBEEIaiARQQF2EKQBIAQoAgwgBCgCCCETQQAhBSAEQQA2AmAgBEIENwNYIA9BYGohHCAPQah/aiElQQAhByARIQoDQAJAIAoiC0F/aiIKRQRAQQEhCEEAIQoMAQsCQAJAAkACQAJAIA8gCkEFdGooAgggC0EFdCIMIA9qQUhqKAIAIgZPBEAgC0F+aiEOIAwgJWohCEEAIQpBACEJA0AgCSAORgRAIAshCAwICyAJQQFqIQkgBiAIKAIAIgZPIAhBYGohCA0ACyAJQQFqIQggCUF/cyALaiEKDAELIAwgJWohCQNAAkAgCkEBRgRAQQAhCgwBCyAKQX9qIQogBiAJKAIAIgZJIAlBYGohCQ0BCwsgCyAKSQ0BIAsgEUsNAiALIAprIghBAXYiDkUNACAPIApBBXRqIQYgDCAcaiEJA0AgBEHYA2oiDCAGQRhqIhApAwA3AwAgBEHQA2oiEiAGQRBqIhUpAwA3AwAgIiAGQQhqIhopAwA3AwAgBCAGKQMANwPAAyAJQQhqIh8pAwAhSCAJQRBqIiApAwAhSSAJQRhqIiEpAwAhRyAGIAkpAwA3AwAgECBHNwMAIBUgSTcDACAaIEg3AwAgISAMKQMANwMAICAgEikDADcDACAfICIpAwA3AwAgCSAEKQPAAzcDACAGQSBqIQYgCUFgaiEJIA5Bf2oiDg0ACwsgCkUgCEEJS3INBCALIAprIQggCkF/aiEFIBwgCkEFdGohBgNAIAsgBUkNAyALIBFLDQIgBiAIQQFqIggQPyAFQX9qIgcgBU8NBCAGQWBqIQYgByEFIAhBCkkNAAsMAwsgCiALEKgBAAsgCyAREKYBAAsgBSALEKgBAAsgB0EBaiEKIAQoAmAiBSEHCyAEKAJcIAdGBEAgBEHYAGogBxCLASAEKAJgIgUhBwsgBCgCWCIGIAdBA3RqIgcgCDYCBCAHIAo2AgAgBCAFQQFqIgU2AmACfwJAIAVBAkkNAAJAA0ACQAJAAkACQCAGIAVBf2pBA3RqIgcoAgBFDQAgBUEDdCAGaiIOQXRqKAIAIgsgBygCBCIITQ0AQQIgBUECTQ0HGiAGIAVBfWoiDEEDdGooAgQiCSAIIAtqTQ0BQQMgBUEDTQ0HGiAOQWRqKAIAIAkgC2pLDQYMAQsgBUEDSQ0BIAcoAgQhCCAGIAVBfWoiDEEDdGooAgQhCQsgCSAISQ0BCyAFQX5qIQwLAkACQAJAAkACQAJAAkAgBSAMQQFqIhJLBEAgBSAMTQ0BIAYgDEEDdCIfaiIFKAIEIiAgBSgCAGoiBSAGIBJBA3QiIWoiBigCACIVSQ0CIAUgEUsNAyAPIBVBBXRqIgsgBigCBCIQQQV0IghqIQcgBUEFdCEaIAUgFWsiDiAQayIFIBBPDQQgEyAHIAVBBXQiBhC6ASIOIAZqIQkgEEEBTkEAIAVBAU4bRQRAIAchBQwGCyAaIBxqIQggByEFA0AgCCAFQWBqIhogCUFgaiIsIAlBaGooAgAgBUFoaigCAEkiBxsiBikDADcDACAIQRhqIAZBGGopAwA3AwAgCEEQaiAGQRBqKQMANwMAIAhBCGogBkEIaikDADcDACAJICwgBxshCSALIBogBSAHGyIFTw0GIAhBYGohCCAJIA4iBksNAAsMBwtB6KvCACASIAUQpwEAC0Hoq8IAIAwgBRCnAQALIBUgBRCoAQALIAUgERCmAQALIAggEyALIAgQugEiBmohCSAOIBBKQQAgEEEBThsNASALIQULIBMhBgwBCyAPIBpqIQ4gCyEFA0AgBSAHIAYgBygCCCAGKAIISSILGyIIKQMANwMAIAVBGGogCEEYaikDADcDACAFQRBqIAhBEGopAwA3AwAgBUEIaiAIQQhqKQMANwMAIAYgBkEgaiALGyEGIAVBIGohBSAHQSBqIAcgCxsiByAOTw0BIAkgBksNAAsLIAUgBiAJIAZrQWBxELoBGiAEKAJgIgUgDEsEQCAEKAJYIgYgH2oiByAQICBqNgIEIAcgFTYCACAFIBJNDQIgBiAhaiIHIAdBCGogBSASQX9zakEDdBCZARogBCAFQX9qIgU2AmAgBSAFQQFNDQQaDAELC0H4q8IAIAwgBRCnAQALQaeuwgBBHUHErsIAELkBAAsgBQshByAKDQALIAQoAlwEQCAGECELRQ0CIBMQIQwCCyAFIBQQqAEACyARQQJJDQAgEUF+aiEFIA8gEUEFdGpBQGohBkECIQkDQCARIAVJDQYgBiAJED8gBkFgaiEGIAlBAWohCSAFQX9qIgVBf0cNAAsLIBYgFigCAEEBajYCACAXIBcoAgBBAWo2AgAgDUUNBiBGpyILQQV0IRdBACEKAn8gC0UEQEEAIQdBACETQQAhFkEADAELIBRBBXQhBkEAIQVBACETA0AgEyAFIA1qIgdBFGooAgBrIAdBGGooAgBqAn8gBysDAJsiSkQAAAAAAADwQWMgSkQAAAAAAAAAAGZxBEAgSqsMAQtBAAtBAXRrIRMgBiAFQSBqIgVHDQALQQAhBUEAIQcDQCAHIAUgDWpBHGotAABBAXNqIQcgBiAFQSBqIgVHDQALQQAhCUEAIQgDQCAIIAkgDWoiBUEMaigCAGsgBUEQaigCAGogBUEUaigCACAFQRhqKAIAa2ohCCAGIAlBIGoiCUcNAAtBACEGIBdBYGpBBXYiBSALQX9qIg4gDiAFSxsiDARAIA1BKGohBUEAIQkDQCAGQQAgBUFgaigCACIQQQFqIg4gBSgCACIWayISIBIgDksbaiIGIBYgEEF/c2pqIAYgDiAWSRshBiAFQSBqIQUgCUEBaiIJIAxJDQALC0EAIAZrIRZBACAIawshCEEBIQ4gC0F/aiIFIAtNBEAgBSALSQRAIA0gBUEFdGotAB0hDgsgDSgCCCEFIA1BIGogDSAXakcEQCANQShqIQYgFEEFdEFgaiEJA0AgBigCACIKIAUgBSAKSxshBSAGQSBqIQYgCUFgaiIJDQALC0EAIAVrIQoLQQAhBiAkBEAgJEEUbCEJICpBCGohBQNAIAUoAgAgBUF8aigCAGsgBmohBiAFQRRqIQUgCUFsaiIJDQALQQAgBmshBgsgRachBSACKAIAKAIAKAIEIhRFIAtBAUtyDQIgC0EBa0UEQCARQQFHIBRBAklyDQMgD0EQaigCACAPKAIMa0EBdCANQRBqKAIAIA0oAgxrTw0DIA0tAB0NAwsgBQRAIA0QIQsgGwRAIA8QIQsgASgCACIFIAEoAgRHDQALCyAAQQA2AgQMAQsgACAkNgIIIAAgKjYCBCAAIEA2AgAgAEHgAGogBjYCACAAQdwAakEAICRrNgIAIABB2ABqID82AgAgAEHUAGogCjYCACAAQdAAaiAONgIAIABBzABqIBY2AgAgAEHIAGogCDYCACAAQcQAaiAHNgIAIABBQGsgEzYCACAAQTxqIBE2AgAgAEE4aiAbNgIAIABBNGogDzYCACAAQTBqIAs2AgAgAEEsaiAFNgIAIABBKGogDTYCACAAQSRqID82AgAgAEEgaiAzNgIAIABBHGogNDYCACAAQRhqIDU2AgAgAEEUaiA2NgIAIABBEGogNzYCACAAQQxqIDg2AgALIARBgARqJAAPCyAFIBEQqAEAC0HevcIAQRAgBEHwAmpBzKjCABCWAQALQZi9wgBBxgAgBEHwAmpB3KjCABCWAQAL4x8DJH8DfgZ8IwBBwAFrIgQkAAJAAkACQAJAAkACQAJAAkAgAkEIaigCACIDIAIoAgQiBkYNACABQQhqKAIAIgUgASgCBCILRg0AIAUgC2siBSAFIAMgBmsiAyADIAVLGyACLQApIgYbIQUgA0ECT0EAIAVBAUsbDQEgAyAFRg0CDAMLIABBAjoAHAwDC0QAAAAAAADwPyAFIAMgAyAFSyILG7ggAyAFIAsbuKOhRKRwPQrXo9A/Y0UNAQsgBEEoaiABEKwBIAQoAiwhBSAEKAIoIQkCQCAGRQRAIAUgASgCCCABKAIEayIGIANBAWoiAyADIAZLGyIDTw0BIAMgBRCmAQALIAUhAwsCQAJAAkACQEQAAAAAAADwPwJ8AkAQeSIFBEAgBEEgaiACEKwBIAQoAiQhBiADBEAgBkUNAiAFKAIADQsgBCgCICEKIAVBfzYCACAFKAIQDQsgBUEEaiELIAVBfzYCEAJAIAMgBSgCDCIITQRAIAUgAzYCDAwBCyALIAMgCGsQmAELIAVBFGohCAJAIAYgBSgCHCIMTQRAIAUgBjYCHAwBCyAIIAYgDGsQmAELIAUoAgQgBSgCDCAJIAMQcyAFKAIUIAUoAhwgCiAGEHNBACEDIAUoAgQgBSgCDCIGIARBgAFqQQBBICAGZ2sQCSAFKAIUIAUoAhwiBiAEQYABakEAQSAgBmdrEAkgCxCAASAIEIABIAUoAhwhCQJAIAUoAgwiCkUEQEEAIQZBACELQQAhCAwBC0EAIQZBACELQQAhCCAJRQ0AIAUoAhQhDCAFKAIEIQ4DQAJAAkACQAJAQX8gDiADQQJ0aigCACIPIAwgBkECdGooAgAiEEcgDyAQSRsOAgACAQsgBkEBaiEGIANBAWohAyAIQQFqIQgMAgsgA0EBaiEDDAELIAZBAWohBgsgC0EBaiELIAMgCk8NASAGIAlJDQALCyAFIAUoAhBBAWo2AhAgBSAFKAIAQQFqNgIAIAi3IAkgCmogA2sgBmsgC2q4owwDC0QAAAAAAAAAAEQAAAAAAADwPyAGGwwCCwwIC0QAAAAAAAAAAAuhRFK4HoXrUeA/YwRAIARBAjoATBAnIgdFDQcgBEEYaiACEKwBIAQoAhwhCyAEKAIYIRMgBEEQaiABEKwBIAcoAiwNCSAEKAIUIRogBCgCECEUIAdBfzYCLCAHKAI8DQkgB0EANgJIIAdCgICAgHA3AjggBEEIaiACEK0BIAdBMGogBCgCCCIDIAMgBCgCDGoQkgEgBCABEK0BIAdBQGsiFSAEKAIAIgMgAyAEKAIEahCSASAHKAIADQkgB0F/NgIAIAcoAkAhBiAHKAIwIQ0gBygCOCIJQQJqIgMgBygCSCIKQQJqIgUgAyAFSxsiAyAHKAIETQ0EIAdBBGohDyADQQF2IANqIg4gDmwiDCAHKAIQIgNNDQMgB0EIaiADIAwgA2siBRCFASAHKAIIIAcoAhAiDEEDdGohAyAFQQJJDQFBASEIA0AgA0IANwMAIANBCGohAyAIQQFqIgggBUkNAAsgCCAMakF/aiEMDAILIABBAjoAHAwFCyAFDQAMAQsgA0IANwMAIAxBAWohDAsgByAONgIEIAcgDDYCECAPEHwLIAkEQCAJQQN0IQhBAiEDA0AgBygCCCIFIAcoAgQiCSADbEEDdGpBCGogCSADQX9qbEEDdCAFakEIaisDACANKwMAoDkDACADQQFqIQMgDUEIaiENIAhBeGoiCA0ACwsgCgRAIApBA3QhDUECIQMDQCAHKAIIIAMgBygCBGpBA3RqIgUgBUF4aisDACAGKwMAoDkDACADQQFqIQMgBkEIaiEGIA1BeGoiDQ0ACwsgBygCFEUEQCAHQX82AhQCQCAHKAIYIgNFBEBBACEDIAdBADYCKAwBCyAHKAIcQf8BIANBBWoQwAEaIAdBADYCKCAHKAIYIgNBCEkNACADQQFqQQN2QQdsIQMLIAcgAzYCJCALBEAgB0EYaiEWIBMgC0ECdGohDSAUIBpBAnRqIRdBACELIBMhDgNAIAtBAWohDyAOKAIAIQwgGgRAIAtBAmohGCALQQJ0IBNqQXxqIRlBACEJQQAhAyAUIQUDQCADIQggBCAFKAIAIhA2AoABIAVBBGohBUKlxoihyJyn+UshJ0EBIQMgECEGA0AgJyAGrUL/AYOFQrODgICAIH4hJyADQQRHBEAgBEGAAWogA2ogA0EBaiEDLQAAIQYMAQsLICenIgZBGXYiA0EIdCADciIDQRB0IANyIRsgCEEBaiEDIAcoAhgiESAGcSEGIAcoAhwhHEEAIRICQANAAkAgBiAcaigAACIdIBtzIgpBf3MgCkH//ft3anFBgIGChHhxIgoEQCAHKAIgIR4DQCAQIB4gCmhBA3YgBmogEXFBA3RqIh8oAgBGDQIgCkF/aiAKcSIKDQALCyASQQRqIhIgBmogEXEhBkEAIQogHSAdQQF0cUGAgYKEeHFFDQEMAgsLIB9BBGohCgsCQAJAAkAgBygCOCIGIAtLBEAgBygCSCIGIAhNDQEgCkHYqsIAIAobKAIAIQogBygCMCALQQN0aisDACEqIBUoAgAgCEEDdGorAwAhKyALRQ0CRAAAAAAAAOA/ISwgDCAZKAIARw0CDAMLQeirwgAgCyAGEKcBAAtB6KvCACAIIAYQpwEAC0QAAAAAAADwPyEsCyAIBHxEAAAAAAAA4D9EAAAAAAAA8D8gECAIQQJ0IBRqQXxqKAIARhsFRAAAAAAAAPA/CyEuIAcoAggiBiAHKAIEIhEgCmwgCWpBA3RqKwMAIS0gKyArICogKiArYxsgKiAqYhtEAAAAAAAA8D9EAAAAAAAAAAAgDCAQRxuiIAYgDyARbCISIANqQQN0aisDAKAgLSAPIAlrIAhqIApruEQAAAAAAADgP6KgEMgBIS0gBiASIAhBAmoiCGpBA3RqKwMAIS8gKiAsEMgBISogBiAIIBEgGGwiCmpBA3RqICsgLhDIASAGIAMgCmpBA3RqKwMAoCAqIC+gEMgBIC0QyAE5AwAgAyAJIAwgEEYbIQkgBSAXRw0ACwsgDkEEaiEOIAQgDDYCgAFCpcaIocicp/lLISdBASEDIAwhBgNAICcgBq1C/wGDhUKzg4CAgCB+IScgA0EERwRAIARBgAFqIANqIANBAWohAy0AACEGDAELCyAnQhmIp0H/AHEiBUEIdCAFciIDQRB0IANyIRAgBygCGCIIICencSEDIAcoAhwhBkEAIQoCQAJAA0AgAyAGaigAACILIBBzIglBf3MgCUH//ft3anFBgIGChHhxIgkEQCAHKAIgIREDQCARIAloQQN2IANqIAhxQQN0aiISKAIAIAxGDQMgCUF/aiAJcSIJDQALCyAKQQRqIgogA2ogCHEhAyALIAtBAXRxQYCBgoR4cUUNAAsgBiAWICcQlwEiA2otAABBAXEhCyAHIAcoAiQiCSALRXIEfyAJBSAEQYABaiAWEBQgFiAnEJcBIQMgBygCGCEIIAcoAhwhBiAHKAIkCyALazYCJCAHKAIgIAMgBmogBToAACAIIANBfGpxIAZqQQRqIAU6AAAgA0EDdGoiAyAPNgIEIAMgDDYCACAHIAcoAihBAWo2AigMAQsgEiAPNgIECyAPIQsgDSAORw0ACwsgByAHKAIUQQFqNgIUIAcgBygCACIDQQFqIiI2AgAgByAHKAI8QQFqNgI8IAcgBygCLEEBajYCLCADQQJqIgNBAEoEQCAHIAM2AgAgAigCDCIXIQMgAi0AKSIbBEAgFyABKAIMIgMgFyADSxshAwsgAigCCCIWIAIoAgQiGmsiHCABKAIIIhEgASgCBCISayIYIBwgGEsbIgVBAWoiCyADQX9qIhVLBEBBACADayEjQQAgBWshGUKBgoSAECACLQAoIiRBe2oiA61CA4aIpyElIAIoAgAhHSADQQVJISYgCyEFA0AgBSIPQX9qIgVBASAFQQFLGyEeIAshAwNAAkAgFSADTw0AIA8gA2shBiADQX9qIQlBACAHKAIEIgJBA3RrIQwgBygCCCAPIAIgA2xqIg1BA3RqIQggBygCECEKA0ACQCAJIgMgHEsgBSAYS3IgBSAVRiADICNqQX9GcSADIBdJcnINACAFIAEoAgxJQQAgGxsNAiAGIAMgGWoiCSAJQQBIG0EBSg0AIAogDUsEQCAIKwMAIiogAyAeIAMgHksbuKNE4XoUrkfhyj9kDQEgBEECOgCcAUEAIQYDQCAEQTBqIAZqIgIpAwAhJyACIARBgAFqIAZqIggpAwA3AwAgCCAnNwMAIAJBCGoiCSkDACEnIAkgCEEIaiIJKQMANwMAIAJBEGoiCikDACEoIAogCEEQaiIKKQMANwMAIAJBGGoiAikDACEpIAIgCEEYaiICKQMANwMAIAIgKTcDACAKICg3AwAgCSAnNwMAIAZBQGsgBkEgaiEGQcEASQ0ACyAEIAQvAZ4BOwF8IAQtAJ0BIQ0gBCgCmAEhCCAEKAKUASEJIAQoApABIQogBCgCjAEhDCAEKAKIASEfIAQrA4ABISsgBCsDoAEhLCAEKAKoASEQIAQoAqwBIRMgBCgCsAEhFCAEKAK0ASEOIAQoArgBIQIgBC0AvAEhISAELQC9ASEgIAQtAJwBIQYgBCAELwG+ATsBegJAAkAgBkECRwRAIAQgBC8BfDsBgAEgBCAELwF6OwF+ICsgKmUNAQtBACEJIBtBAEcgBSAYRiABKAIAIR8Cf0EAIAEtACgiAkEKRg0AGkEAIAJBe2oiAkEESw0AGkKBgoSAECACrUIDhoinCyEGQQAgJUEAICYbICRBCkYbISEgBSEIIBEhCiASIQwgKiErICohLCAdIRAgGiETIBYhFEEAIQ4gAyECciINISAMAQsgBCAELwGAATsBeCAEIAQvAX47AXYLIAQgDToATSAEIAY6AEwgBCAINgJIIAQgCTYCRCAEIAo2AkAgBCAMNgI8IAQgHzYCOCAEICs5AzAgBCAgOgBtIAQgIToAbCAEIAI2AmggBCAONgJkIAQgFDYCYCAEIBM2AlwgBCAQNgJYIAQgLDkDUCAEIAQvAXg7AU4gBCAELwF2OwFuICpEAAAAAAAAsDxlQQFzDQQMAwtB6KvCACANIAoQpwEACyAGQQFqIQYgA0F/aiEJIAggDGohCCANIAJrIQ0gFSADSQ0ACwsLIBlBAWohGSAVIAVJDQALCyAHICI2AgAgACAEQTBqQcAAELoBGgwDC0GMqsIAQRggBEGAAWpBpKrCABCWAQALDAQLIABBAjoAHAsgBEHAAWokAA8LQZi9wgBBxgAgBEGAAWpB3KjCABCWAQALQd69wgBBECAEQYABakHcqsIAEJYBAAtB3r3CAEEQIARBgAFqQciqwgAQlgEAC5gcAht/AX4jAEHwA2siAyQAIAMgAjYCkAMgAyACNgKMAyADIAE2AogDIANB0ABqIANBiANqEI4BIAMoAlQhDyADKAJQIQ4CQAJAAkACQAJAAkACQBDCASIKBEAgCigCAA0GIApBfzYCACAKQQRqIAAQVSIERQ0BEMEBIgsEQCALKAIADQcgC0F/NgIAIAtBBGogABBWIghFDQMgA0GgAmogDiAOIA9qECMgA0GIA2ogA0GgAmoQYCADQaACaiADQYgDaiAEQRRqIgIQPCADKAKoAiIAQX9qIgEgAE8gASAAS3JFBEAgAygCoAIgAUEUbGpBADoAEQsgA0GIA2ogA0GgAmpBMBC6ARogA0GgAmogA0GIA2ogAhAmIANBiANqIANBoAJqIAIQICADQaACaiADQYgDahA6IAMoAqgCIgEEQCADKAKgAiEAIAFBFGwhASADQcACaigCACEFIAMoArgCIQwDQCAAIAwgBSACEEggAEEUaiEAIAFBbGoiAQ0ACwsgA0GIA2ogA0GgAmpBMBC6ARogA0GgAmogA0GIA2ogAhBQIAMoAqACIQwCQCADKAKoAiIFRQRAIANBwAJqKAIAIQYgAygCuAIhDQwBCyAFQRRsIQEgA0HAAmooAgAhBiADKAK4AiENIAwhAANAIAAgDSAGIAIQRyAAQRRqIQAgAUFsaiIBDQALCyADQcgCaigCACEWIANBvAJqKAIAIRcgA0GwAmooAgAhGCADQbQCaigCACEAIAMoAqQCIRkgAygCrAIhECADKALEAiERIANB9ABqIANBzAJqKAIANgIAIANB7ABqIAY2AgAgA0HkAGogADYCACADIBE2AnAgAyANNgJoIAMgEDYCYCADIAU2AlwgAyAMNgJYAkAgCCgCCCICRQRAQQAhAgwBCyAIKAIAIAJBBHRqQXRqIQADQCACQX9qIQIgAEEEaigCAARAIAAoAgAQIQsgAEFwaiEAIAINAAsgAygCXCEFCyAIIAI2AgggAyADQdgAajYCfCADIAQ2AnggA0GMAWogBCgCrAE2AgAgAyAEKAKkATYCiAEgAyAEKAKgATYChAEgAyAEKAKYATYCgAECQCAFRQRAIAQoAtgBDQogBEHYAWohBSAEQX82AtgBIARB3AFqIQcgBCgC3AENASAEKAIEIQAgBCgCDCEBIANBIGogBCgCECICQQF0EKABIAMpAyAhHiADQbACaiIGQQA2AgAgA0EAOwG4AiADIAI2ArQCIAMgHjcDqAIgAyAAIAFBPGxqNgKkAiADIAA2AqACAkAgA0GgAmoQMSIARQRAIANBADYCqAEgA0IENwOgASADKAKsAkUNASADKAKoAhAhDAELIAAoAgAhACADQRhqQQEQoAEgAygCHCEBIAMoAhgiCSAANgIAIANBATYC6AEgAyABNgLkASADIAk2AuABIANBoANqIANBuAJqKAIANgIAIANBmANqIAYpAwA3AwAgA0GQA2ogA0GoAmopAwA3AwAgAyADKQOgAjcDiAMCQCADQYgDahAxIgZFDQBBBCECQQEhAANAIAYoAgAhBiAAIAFGBEAgA0HgAWogAUEBEIMBIAMoAuABIQkLIAIgCWogBjYCACADIABBAWoiADYC6AEgA0GIA2oQMSIGRQ0BIAJBBGohAiADKALkASEBDAALAAsgA0GUA2ooAgAEQCADKAKQAxAhCyADQagBaiADQegBaigCADYCACADIAMpA+ABNwOgAQsgA0GIA2ogA0GgAWoQkwECQCAHKAIAIgBFDQAgBCgC4AFFDQAgABAhCyAHIAMpA4gDNwIAIAdBCGogA0GQA2ooAgA2AgAgA0GYAWogA0GoAWooAgA2AgAgAyADKQOgATcDkAEMBwsgBCgCsAENCSAEQX82ArABIARBADYC1AEgBEHMAWohCSAEKAIQIRIgBCAEKAK0ASIBBH8gCUEAIAEQgwEgBCgCzAEgBCgC1AEiAkECdGohACABQQFHBEAgAUF/aiEBA0AgAEEANgIAIAJBAWohAiAAQQRqIQAgAUF/aiIBDQALCyAAQQA2AgAgAkEBagVBAAs2AtQBIANBoAFqIANB2ABqEB8gAygCoAEhByADKAKoASIARQ0FIAcgAEEMbGohGiAHIQUDQCADQqXGiKHInKf5SzcDiAMgBSIBIANBiANqEHYgAygCiAMiAEEZdiICQQh0IAJyIgJBEHQgAnIhGyABQQxqIQUgBCgCuAEiEyAAcSEAIAQoArwBIRxBACEUAkADQAJAIAAgHGooAAAiFSAbcyICQX9zIAJB//37d2pxQYCBgoR4cSICBEAgBCgCwAEhHQNAIAEgHSACaEEDdiAAaiATcUEYbGoiBkYNAiABIAZBDBC1AUUNAiACQX9qIAJxIgINAAsLIBRBBGoiFCAAaiATcSEAIBUgFUEBdHFBgIGChHhxRQ0BDAILCyAGKAIUIgFFDQAgBigCDCEAIAFBAnQhAgNAIAkoAgAgACgCAEECdGoiASABKAIAQQFqNgIAIABBBGohACACQXxqIgINAAsLIAUgGkcNAAsMBQsgA0GQAWogBxCTAQwFCwwFCwwEC0GU7sIAQStBwO7CABC5AQALQZTuwgBBK0HA7sIAELkBAAsgBCgCzAEhACAEKALUASEBIANByABqIBJBFGwQoQEgAykDSCEeIANBtAJqQQA2AgAgA0EAOwG8AiADIBJBCmw2ArgCIAMgHjcCrAIgA0EANgKoAiADIAAgAUECdGo2AqQCIAMgADYCoAIgA0FAayADQaACahAwAkAgAygCREUEQCADQQA2ApgBIANCBDcDkAEgAygCsAJFDQEgAygCrAIQIQwBCyADKAJAIQAgA0E4akEBEKABIAMoAjwhASADKAI4IgUgADYCACADQQE2AugBIAMgATYC5AEgAyAFNgLgASADQaADaiADQbgCaikDADcDACADQZgDaiIJIANBsAJqKQMANwMAIANBkANqIANBqAJqKQMANwMAIAMgAykDoAI3A4gDIANBMGogA0GIA2oQMAJAIAMoAjRFDQAgAygCMCEGQQQhAkEBIQADQCAAIAFGBEAgA0HgAWogAUEBEIMBIAMoAuABIQULIAIgBWogBjYCACADIABBAWoiADYC6AEgA0EoaiADQYgDahAwIAMoAixFDQEgAygCKCEGIAJBBGohAiADKALkASEBDAALAAsgCSgCAARAIAMoApQDECELIANBmAFqIANB6AFqKAIANgIAIAMgAykD4AE3A5ABCyADKAKkAQRAIAcQIQsgBEGwAWohBQsgBSAFKAIAQQFqNgIAIAMoApABIQcgAygCmAEhACADQRBqIAMoAngoAhAiAUEBdBCdASADKQMQIR4gA0G8AWpBADYCACADQQA7AcQBIAMgATYCwAEgAyAeNwK0ASADIAcgAEECdGo2AqQBIAMgBzYCoAEgAyADQYABajYCyAEgAyADQfwAajYCsAEgAyADQfwAajYCrAEgAyADQfgAajYCqAEgA0GgAmogA0GgAWoQHgJAAkACQAJAIAMoAqQCRQRAIANBADYC5AEMAQsgA0GIA2ogA0GgAmpB5AAQugEaIANB4AFqIANByAFqIANBiANqEBUgAygC5AENAQsgAygCvAEiAQRAIAMoArQBIQAgAUHkAGwhAgNAIAAQvQEgAEHkAGohACACQZx/aiICDQALC0EAIQRBBCEFIAMoArgBRQ0BIAMoArQBECEMAQtBASECIANBCGpBARCfASADQegBaikDACEeIAMoAgwhACADKAIIIgUgAykD4AE3AgAgBUEIaiAeNwIAIANBATYC2AEgAyAANgLUASADIAU2AtABIANB4AFqIANBoAFqQSwQugEaIANBoAJqIANB4AFqEB4CQCADKAKkAgRAIANBiAJqIQFBECEAA0AgA0GIA2ogA0GgAmpB5AAQugEaIANBkAJqIAEgA0GIA2oQFSADKAKUAkUNAiADQZADaiIEIANBmAJqKQMANwMAIAMgAykDkAI3A4gDIAMoAtQBIAJGBEAgA0HQAWogAhC0ASADKALQASEFCyAAIAVqIgYgAykDiAM3AgAgBkEIaiAEKQMANwIAIAMgAkEBaiICNgLYASAAQRBqIQAgA0GgAmogA0HgAWoQHiADKAKkAg0ACwsgA0EANgKUAgsgA0H8AWooAgAiAQRAIAMoAvQBIQAgAUHkAGwhAQNAIAAQvQEgAEHkAGohACABQZx/aiIBDQALCyADQfgBaigCAARAIAMoAvQBECELIAMoAtQBIQQMAQtBACECCyADKAKUAQRAIAcQIQsgBSACQQR0aiEBAkAgASACBH8gBSEAA0AgAEEEaigCACIHBEAgAEEIaikCACEeIAAoAgAhBiAIKAIIIgIgCCgCBEYEQCAIIAIQtAEgCCgCCCECCyAIKAIAIAJBBHRqIgIgBzYCBCACIAY2AgAgAkEIaiAeNwIAIAggCCgCCEEBajYCCCAAQRBqIgAgAUcNAQwDCwsgAEEQagUgBQsiAEYNAANAIABBBGooAgAiAkUNASAAQQhqKAIABEAgAhAhCyAAQRBqIgIhACABIAJHDQALCyAEBEAgBRAhCyAZBEAgDBAhCyAYBEAgEBAhCyAXBEAgDRAhCyAWBEAgERAhCyALIAsoAgBBAWo2AgAgCiAKKAIAQQFqNgIAIA8EQCAOECELIANB8ANqJAAPC0GYvcIAQcYAIANBiANqQdyowgAQlgEAC0HevcIAQRAgA0GIA2pBzKjCABCWAQALQd69wgBBECADQYgDakG4qsIAEJYBAAvDGAIIfwF+AkACQAJAIABB9QFPBEAgAEHN/3tPDQIgAEELaiIAQXhxIQRB5PHCACgCACIIRQ0BQQAgBGshAgJAAkACf0EAIABBCHYiAEUNABpBHyAEQf///wdLDQAaIARBBiAAZyIAa0EfcXZBAXEgAEEBdGtBPmoLIgdBAnRB8PPCAGooAgAiAARAIARBAEEZIAdBAXZrQR9xIAdBH0YbdCEFA0ACQCAAKAIEQXhxIgYgBEkNACAGIARrIgYgAk8NACAAIQMgBiICDQBBACECDAMLIABBFGooAgAiBiABIAYgACAFQR12QQRxakEQaigCACIARxsgASAGGyEBIAVBAXQhBSAADQALIAEEQCABIQAMAgsgAw0CC0EAIQNBAiAHQR9xdCIAQQAgAGtyIAhxIgBFDQMgAEEAIABrcWhBAnRB8PPCAGooAgAiAEUNAwsDQCAAIAMgACgCBEF4cSIBIARPIAEgBGsiBiACSXEiBRshAyAGIAIgBRshAiAAKAIQIgEEfyABBSAAQRRqKAIACyIADQALIANFDQILQfD0wgAoAgAiACAET0EAIAIgACAEa08bDQEgAxBZAkAgAkEQTwRAIAMgBEEDcjYCBCADIARqIgEgAkEBcjYCBCABIAJqIAI2AgAgAkGAAk8EQCABIAIQUQwCCyACQQN2IgJBA3RB6PHCAGohAAJ/QeDxwgAoAgAiBUEBIAJBH3F0IgJxBEAgACgCCAwBC0Hg8cIAIAIgBXI2AgAgAAshAiAAIAE2AgggAiABNgIMIAEgADYCDCABIAI2AggMAQsgAyACIARqIgBBA3I2AgQgACADaiIAIAAoAgRBAXI2AgQLIANBCGoPCwJAAkBB4PHCACgCACIDQRAgAEELakF4cSAAQQtJGyIEQQN2IgJBH3EiAXYiAEEDcUUEQCAEQfD0wgAoAgBNDQMgAA0BQeTxwgAoAgAiAEUNAyAAQQAgAGtxaEECdEHw88IAaigCACIBKAIEQXhxIARrIQIgASEFA0AgASgCECIARQRAIAFBFGooAgAiAEUNBAsgACgCBEF4cSAEayIBIAIgASACSSIBGyECIAAgBSABGyEFIAAhAQwACwALIABBf3NBAXEgAmoiAUEDdCIGQfDxwgBqKAIAIgBBCGohAgJAIAAoAggiBSAGQejxwgBqIgZHBEAgBSAGNgIMIAYgBTYCCAwBC0Hg8cIAIANBfiABd3E2AgALIAAgAUEDdCIBQQNyNgIEIAAgAWoiACAAKAIEQQFyNgIEDAMLAkBBAiABdCICQQAgAmtyIAAgAXRxIgBBACAAa3FoIgBBA3QiBUHw8cIAaigCACIBKAIIIgIgBUHo8cIAaiIFRwRAIAIgBTYCDCAFIAI2AggMAQtB4PHCACADQX4gAHdxNgIACyABIARBA3I2AgQgASAEaiIGIABBA3QiACAEayIFQQFyNgIEIAAgAWogBTYCAEHw9MIAKAIAIgAEQCAAQQN2IgNBA3RB6PHCAGohAEH49MIAKAIAIQICf0Hg8cIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtB4PHCACADIARyNgIAIAALIQMgACACNgIIIAMgAjYCDCACIAA2AgwgAiADNgIIC0H49MIAIAY2AgBB8PTCACAFNgIAIAFBCGoPCyAFEFkCQCACQRBPBEAgBSAEQQNyNgIEIAQgBWoiBiACQQFyNgIEIAIgBmogAjYCAEHw9MIAKAIAIgAEQCAAQQN2IgNBA3RB6PHCAGohAEH49MIAKAIAIQECf0Hg8cIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtB4PHCACADIARyNgIAIAALIQMgACABNgIIIAMgATYCDCABIAA2AgwgASADNgIIC0H49MIAIAY2AgBB8PTCACACNgIADAELIAUgAiAEaiIAQQNyNgIEIAAgBWoiACAAKAIEQQFyNgIECyAFQQhqDwsCQAJAAkACQEHw9MIAKAIAIgEgBEkEQEH09MIAKAIAIgAgBEsNBkEAIQIgBEGvgARqIgBBEHZAACIBQX9GDQUgAUEQdCIDRQ0FQYD1wgAgAEGAgHxxIgVBgPXCACgCAGoiADYCAEGE9cIAQYT1wgAoAgAiASAAIAEgAEsbNgIAQfz0wgAoAgAiAkUNAUGI9cIAIQADQCAAKAIAIgEgACgCBCIGaiADRg0DIAAoAggiAA0ACwwDC0H49MIAKAIAIQACQCABIARrIgNBD00EQEH49MIAQQA2AgBB8PTCAEEANgIAIAAgAUEDcjYCBCAAIAFqIgFBBGohBCABKAIEQQFyIQIMAQtB8PTCACADNgIAQfj0wgAgACAEaiICNgIAIAIgA0EBcjYCBCAAIAFqIAM2AgAgBEEDciECIABBBGohBAsgBCACNgIAIABBCGoPC0Gc9cIAKAIAIgBBACAAIANNG0UEQEGc9cIAIAM2AgALQQAhAEGg9cIAQf8fNgIAQYz1wgAgBTYCAEGI9cIAIAM2AgBBlPXCAEEANgIAA0AgAEHw8cIAaiAAQejxwgBqIgE2AgAgAEH08cIAaiABNgIAIABBCGoiAEGAAkcNAAtB/PTCACADNgIAQfT0wgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRBmPXCAEGAgIABNgIADAILIAAoAgwgAyACTXIgASACS3INACAAIAUgBmo2AgRB/PTCAEH89MIAKAIAIgBBD2pBeHEiAUF4ajYCAEH09MIAQfT0wgAoAgAgBWoiAyAAIAFrakEIaiICNgIAIAFBfGogAkEBcjYCACAAIANqQSg2AgRBmPXCAEGAgIABNgIADAELQZz1wgBBnPXCACgCACIAIAMgACADSRs2AgAgAyAFaiEBQYj1wgAhAAJAA0AgASAAKAIARwRAIAAoAggiAA0BDAILCyAAKAIMDQAgACADNgIAIAAgACgCBCAFajYCBCADIARBA3I2AgQgAyAEaiEFIAEgA2sgBGshBAJAAkAgAUH89MIAKAIARwRAQfj0wgAoAgAgAUYNASABKAIEIgBBA3FBAUYEQAJAIABBeHEiAkGAAk8EQCABEFkMAQsgASgCDCIGIAEoAggiB0cEQCAHIAY2AgwgBiAHNgIIDAELQeDxwgBB4PHCACgCAEF+IABBA3Z3cTYCAAsgAiAEaiEEIAEgAmohAQsgASABKAIEQX5xNgIEIAUgBEEBcjYCBCAEIAVqIAQ2AgAgBEGAAk8EQCAFIAQQUQwDCyAEQQN2IgFBA3RB6PHCAGohAAJ/QeDxwgAoAgAiAkEBIAFBH3F0IgFxBEAgACgCCAwBC0Hg8cIAIAEgAnI2AgAgAAshAiAAIAU2AgggAiAFNgIMIAUgADYCDCAFIAI2AggMAgtB/PTCACAFNgIAQfT0wgBB9PTCACgCACAEaiIANgIAIAUgAEEBcjYCBAwBC0H49MIAIAU2AgBB8PTCAEHw9MIAKAIAIARqIgA2AgAgBSAAQQFyNgIEIAAgBWogADYCAAsgA0EIag8LQYj1wgAhAANAAkAgACgCACIBIAJNBEAgASAAKAIEaiIGIAJLDQELIAAoAgghAAwBCwtB/PTCACADNgIAQfT0wgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRBmPXCAEGAgIABNgIAIAIgBkFgakF4cUF4aiIAIAAgAkEQakkbIgFBGzYCBEGI9cIAKQIAIQkgAUEQakGQ9cIAKQIANwIAIAEgCTcCCEGM9cIAIAU2AgBBiPXCACADNgIAQZD1wgAgAUEIajYCAEGU9cIAQQA2AgAgAUEcaiEAA0AgAEEHNgIAIAYgAEEEaiIASw0ACyABIAJGDQAgASABKAIEQX5xNgIEIAIgASACayIAQQFyNgIEIAEgADYCACAAQYACTwRAIAIgABBRDAELIABBA3YiAUEDdEHo8cIAaiEAAn9B4PHCACgCACIDQQEgAUEfcXQiAXEEQCAAKAIIDAELQeDxwgAgASADcjYCACAACyEBIAAgAjYCCCABIAI2AgwgAiAANgIMIAIgATYCCAtBACECQfT0wgAoAgAiACAETQ0ADAELIAIPC0H09MIAIAAgBGsiATYCAEH89MIAQfz0wgAoAgAiACAEaiIDNgIAIAMgAUEBcjYCBCAAIARBA3I2AgQgAEEIagukFgEWfyMAQfADayIGJAACQAJAIAFBFUkNACAGQeAAaiEXIAZByAJqIRhBASERQQEhEgJAAkACQANAIAEhCiAAIQ4gA0FAayEQIBEgEnFBAXMhDwJAA0ACfwJAIAQEQCARQQFxRQRAIAAgARBjIARBf2ohBAsgBiACNgIIIAYgADYCiAEgBiABNgKMASAGIAFBAnYiBTYCDCAGIAVBAXQiBzYCECAGIAVBA2wiCTYCFCAGQQA2AhggBiAGQRhqNgKQAiAGIAZBiAFqNgKMAiAGIAZBCGo2AogCIAYgBkGIAmo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2AogDIAZBHGogBkEgaiAGQQxqIAZBiANqEMUBIAYgB0F/ajYCICAGIAdBAXI2AogDIAZBHGogBkEgaiAGQRBqIAZBiANqEMUBIAYgCUF/ajYCICAGIAlBAWo2AogDIAZBHGogBkEgaiAGQRRqIAZBiANqEMUBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMUBIAYoAhgiBUELSw0BIAYoAhAhCCAFRQwCCyAAIAEQdQwJCyAGKAKMASIJQQF2IgcEQCAGKAKIASIFIAlB5ABsakGcf2ohDANAIAZBiANqIAVB5AAQugEaIAUgDEHkABCZAUHkAGohBSAMIAZBiANqQeQAELoBQZx/aiEMIAdBf2oiBw0ACwsgASAGKAIQQX9zaiEIQQELRSAPckEBcUUEQCAAIAEQJA0ICyADRQRAIA4hACAKIQEMAgsCQCAIIAFJBEAgACAIQeQAbGoiCUFAayENQQAhBQJAA0AgBUEEaiIHQShGDQEgBSAQaiELIAUgDWohDCAHIQVBfyAMKAIAIgcgCygCACILRyAHIAtIGyIHRQ0ACyAHQf8BcUH/AUYNBAsgAQRAIAZBiANqIABB5AAQugEaIAAgCUHkABCZASENIAkgBkGIA2pB5AAQugEaIAZBiAJqIA1B5AAQugEaIA1BXGohDCANQaQBaiETIA1B5ABqIRRBACEIIAEhCQNAAkAgCCAJQX9qIgdPDQAgEyAIQeQAbGohCwNAQQAhBQJAA0AgBUEEaiIAQShGDQEgBSAYaiESIAUgC2ohFSAAIQVBfyAVKAIAIgAgEigCACISRyAAIBJIGyIARQ0ACyAAQf8BcUH/AUYNAgsgC0HkAGohCyAIQQFqIgggB0cNAAsgByEIDAQLIAggB08NAyAMIAlB5ABsaiELA0AgByIJQX9qIQdBACEFAkADQCAFQQRqIgBBKEYNASAFIBhqIRIgBSALaiEVIAAhBUF/IBUoAgAiACASKAIAIhJHIAAgEkgbIgBFDQALIABB/wFxQf8BRw0AIAtBnH9qIQsgCCAHTw0FDAELCyAGQYgDaiAUIAhB5ABsaiIAQeQAELoBGiAAIA0gCUHkAGxqIgBB5AAQmQEaIAAgBkGIA2pB5AAQugEaIAhBAWohCAwACwALQbCnwgBBAEEAEKcBAAtB0KfCACAIIAEQpwEACyANIAZBiAJqQeQAELoBIQAgASAIQQFqIgVPBEAgACAFQeQAbGohACABIAVrIgFBFU8NAQwHCwsgBSABEKgBAAsCQAJAIAEEQCAIIAFPDQEgBkGIAmogAEHkABC6ARogACAAIAhB5ABsaiIFQeQAEJkBIRQgBSAGQYgCakHkABC6ARogBkEgaiAUQeQAELoBGiABQX9qIglFBEBBACEQDAMLIBRBpAFqIQtBACEQA0BBACEFA0AgBUEEaiIHQShGDQQgBSALaiEKIAUgF2ohDiAHIQVBfyAOKAIAIgcgCigCACIKRyAHIApIGyIHRQ0ACyAHQf8BcUH/AUcNAyALQeQAaiELIBBBAWoiECAJRw0ACyAJIRAMAgtBsKfCAEEAQQAQpwEAC0HAp8IAIAggARCnAQALIBRB5ABqIQ0gFCABQeQAbGpBXGohCyAJIQcDQCAHIgogEE0iEkUEQCAKQX9qIQdBACEFA0ACQCAFQQRqIg5BKEYEQEEAIQwMAQsgBSALaiEIIAUgF2ohDCAOIQVBfyAMKAIAIg4gCCgCACIIRyAOIAhIGyIMRQ0BCwsgC0Gcf2ohCyAMQX9HDQELCyAKIBBPBEAgCSAKSQ0CIA0gCkHkAGxqIQ5BgAEhE0EAIQlBACEKQQAhB0EAIQ9BgAEhESANIBBB5ABsaiIVIQ0DQCAOIA1rIghB5ABuIQUCQCAIQePIAUsiGQ0AIAVBgH9qIAUgDyAHSSIIIAogCUlyIgsbIQUgCwRAIBEgBSAIGyERIAUgEyAIGyETDAELIAUgBUEBdiIRayETCwJAIAcgD0cNACARRQRAIAZBiAFqIgchDwwBC0EAIQggBkGIAWohByANIQsDQCAHIAg6AAAgCEEBaiEIQcAAIQUDQAJAIAVBBGoiDEHoAEYEQEEAIQwMAQsgBSALaiEPIAZBIGogBWohFiAMIQVBfyAWKAIAIgwgDygCACIPRyAMIA9IGyIMRQ0BCwsgC0HkAGohCyAHIAxBf0dqIQcgCCARRw0ACyAGQYgBaiEPCwJAIAkgCkcNACATRQRAIAZBiAJqIgkhCgwBC0EAIQggBkGIAmohCSAOIQoDQCAJIAg6AAAgCiILQZx/aiEKIAhBAWohCEEAIQUDQAJAIAVBBGoiDEEoRgRAQQAhDAwBCyAFIBdqIRYgBSALaiEaIAwhBUF/IBYoAgAiDCAaQVxqKAIAIhZHIAwgFkgbIgxFDQELCyAJIAxBf0ZqIQkgCCATRw0ACyAGQYgCaiEKCyAJIAprIgUgByAPayIIIAggBUsbIggEQCAGQYgDaiANIA8tAABB5ABsakHkABC6ARogDSAPLQAAQeQAbGogDiAKLQAAQX9zQeQAbGpB5AAQugEaIAhBAUcEQEEAIQUDQCAOIAUgCmoiCy0AAEF/c0HkAGxqIA0gBSAPakEBaiIMLQAAQeQAbGpB5AAQugEaIA0gDC0AAEHkAGxqIA4gC0EBai0AAEF/c0HkAGxqQeQAELoBGiAFQQJqIAVBAWoiCyEFIAhJDQALIAsgD2ohDyAKIAtqIQoLIA4gCi0AAEF/c0HkAGxqIAZBiANqQeQAELoBGiAPQQFqIQ8gCkEBaiEKCyANIBFB5ABsaiANIAcgD0YbIQ0gDkEAIBNrQeQAbGogDiAJIApGGyEOIBkNAAsCQCAPIAdJBEAgDiEFA0AgBkGIA2ogDSAHQX9qIgctAABB5ABsaiIJQeQAELoBGiAJIAVBnH9qIgVB5AAQmQEaIAUgBkGIA2pB5AAQugEaIA8gB0kNAAsMAQsgDSEFIAogCU8NAANAIAlBf2oiCS0AACEHIAZBiANqIAVB5AAQugEaIAUgDiAHQX9zQeQAbGoiBUHkABCZASAFIAZBiANqQeQAELoBGkHkAGohBSAKIAlJDQALCyAUIAZBIGpB5AAQugEhByABIAUgFWtB5ABuIBBqIgVNDQMgBkGIAmogB0HkABC6ARogByAHIAVB5ABsaiIHQeQAEJkBIQ4gByAGQYgCakHkABC6ASEHIAEgBWsiCkUNBCAKIAUgBSAKSxsgAUEDdiAHQeQAaiEJAkAgBSAKQX9qIgFPBEAgCSABIAIgByAEEAQgBSEBDAELIA4gBSACIAMgBBAEIAchAyAJIQALTyERIAFBFU8NAQwFCwsgECAKEKgBAAsgCiAJEKYBAAtBwKfCACAFIAEQpwEAC0G1rcIAQRxB1K3CABC5AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBJIAEgBUcNAAsLIAZB8ANqJAALgRUBF38jAEHAAmsiBiQAAkACQCABQRVJDQBBASEJQQEhEwJAAkACQANAIAEhDyAAIQ4gCSATcUEBcyERAkACQAJAAkACQAJAA0ACfwJAIAQEQCAJRQRAIAAgARBcIARBf2ohBAsgBiACNgIIIAYgADYCMCAGIAE2AjQgBiABQQJ2IgU2AgwgBiAFQQF0Igg2AhAgBiAFQQNsIgc2AhQgBkEANgIYIAYgBkEYajYCuAEgBiAGQTBqNgK0ASAGIAZBCGo2ArABIAYgBkGwAWo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2ArACIAZBHGogBkEgaiAGQQxqIAZBsAJqEMYBIAYgCEF/ajYCICAGIAhBAXI2ArACIAZBHGogBkEgaiAGQRBqIAZBsAJqEMYBIAYgB0F/ajYCICAGIAdBAWo2ArACIAZBHGogBkEgaiAGQRRqIAZBsAJqEMYBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMYBIAYoAhgiBUELSw0BIAYoAhAhByAFRQwCCyAAIAEQawwOCyAGKAI0IgdBAXYiCARAIAYoAjAiBSAHQQxsakF0aiEHA0AgBkG4AmoiDCAFQQhqIgooAgA2AgAgBiAFKQIANwOwAiAHQQhqIgsoAgAhDSAFIAcpAgA3AgAgCiANNgIAIAsgDCgCADYCACAHIAYpA7ACNwIAIAVBDGohBSAHQXRqIQcgCEF/aiIIDQALCyABIAYoAhBBf3NqIQdBAQtFIBFyQQFxRQRAIAAgARArDQ0LIANFDQIgByABTw0BIANBAyAAIAdBDGxqIgVBAxCUAUH/AXFB/wFGBEAgASEPIAAhDgwECyAGQbgCaiIKIABBCGoiCygCADYCACAGIAApAgA3A7ACIAVBCGoiCCgCACEHIAAgBSkCADcCACALIAc2AgAgCCAKKAIANgIAIAUgBikDsAI3AgAgBkG4AWoiEiALKAIANgIAIAYgACkCADcDsAEgAEF0aiEUIABBDGohDUEAIQcgASEIA0ACQCAHIAhBf2oiDE8NACANIAdBDGxqIQUDQCAGQbABakEDIAVBAxCUAUH/AXFB/wFGDQEgBUEMaiEFIAwgB0EBaiIHRw0ACyAMIQcLIBQgCEEMbGohBQJAA0AgByAIQX9qIghPDQEgBkGwAWpBAyAFQQMQlAEgBUF0aiIMIQVB/wFxQf8BRg0ACyAKIA0gB0EMbGoiBUEIaiITKAIANgIAIAYgBSkCADcDsAIgDEEUaiIQKAIAIRYgBSAMQQxqIgUpAgA3AgAgEyAWNgIAIBAgCigCADYCACAFIAYpA7ACNwIAIAdBAWohBwwBCwsgACAGKQOwATcCACALIBIoAgA2AgAgASAHQQFqIgVPBEAgACAFQQxsaiEAIAEgBWsiAUEVTw0BDAwLCyAFIAEQqAEAC0HQp8IAIAcgARCnAQALIA9FDQELIAcgD08NASAGQbgBaiIUIA5BCGoiEigCADYCACAGIA4pAgA3A7ABIA4gB0EMbGoiAEEIaiIBKAIAIQUgDiAAKQIANwIAIBIgBTYCACABIBQoAgA2AgAgACAGKQOwATcCACAGQShqIhYgEigCADYCACAGIA4pAgA3AyAgDkEMaiEJQQAhDCAPQX9qIgFFDQIgCSEFA0AgBUEDIAZBIGpBAxCUAUH/AXFB/wFHDQMgBUEMaiEFIAEgDEEBaiIMRw0ACyABIQwMAgtBsKfCAEEAQQAQpwEAC0HAp8IAIAcgDxCnAQALIA4gD0EMbGohByABIQgDQAJAIAchACAIIgUgDE0iEw0AIAVBf2ohCCAAQXRqIgdBAyAGQSBqQQMQlAFB/wFxQf8BRw0BCwsgBSAMTwRAIAEgBUkNAkGAASENQQAhCEEAIQpBACEHQQAhC0GAASERIAkgDEEMbGoiFyEBA0AgACABayIJQQxuIQUCQCAJQYsYSyIYDQAgBUGAf2ogBSALIAdJIgkgCiAISXIiEBshBSAQBEAgESAFIAkbIREgBSANIAkbIQ0MAQsgBSAFQQF2IhFrIQ0LAkAgByALRw0AIBFFBEAgBkEwaiIHIQsMAQtBACEFIAEhCSAGQTBqIgshBwNAIAcgBToAACAHIAlBAyAGQSBqQQMQlAFB/wFxQf8BR2ohByAJQQxqIQkgESAFQQFqIgVHDQALCwJAIAggCkcNACANRQRAIAZBsAFqIgghCgwBCyAAQXRqIQVBACEJIAZBsAFqIgohCANAIAggCToAACAIIAVBAyAGQSBqQQMQlAFB/wFxQf8BRmohCCAFQXRqIQUgDSAJQQFqIglHDQALCyAIIAprIgUgByALayIJIAkgBUsbIgkEQCAGQbgCaiIZIAEgCy0AAEEMbGoiBUEIaigCADYCACAGIAUpAgA3A7ACIAEgCy0AAEEMbGoiBSAAIAotAABBf3NBDGxqIhApAgA3AgAgBUEIaiAQQQhqKAIANgIAIAlBAUcEQEEAIQUDQCAAIAUgCmoiEC0AAEF/c0EMbGoiFSABIAUgC2pBAWoiGi0AAEEMbGoiGykCADcCACAVQQhqIBtBCGooAgA2AgAgASAaLQAAQQxsaiIVIAAgEEEBai0AAEF/c0EMbGoiECkCADcCACAVQQhqIBBBCGooAgA2AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgCiAQaiEKCyAAIAotAABBf3NBDGxqIgUgBikDsAI3AgAgBUEIaiAZKAIANgIAIAtBAWohCyAKQQFqIQoLIAEgEUEMbGogASAHIAtGGyEBIABBACANa0EMbGogACAIIApGGyEAIBgNAAsCQCALIAdJBEAgACEFA0AgBkG4AmoiCCABIAdBf2oiBy0AAEEMbGoiAEEIaiIJKAIANgIAIAYgACkCADcDsAIgBUF0aiIFQQhqIgooAgAhDSAAIAUpAgA3AgAgCSANNgIAIAogCCgCADYCACAFIAYpA7ACNwIAIAsgB0kNAAsMAQsgASEFIAogCE8NAANAIAhBf2oiCC0AACEBIAZBuAJqIgcgBUEIaiIJKAIANgIAIAYgBSkCADcDsAIgACABQX9zQQxsaiIBQQhqIgsoAgAhDSAFIAEpAgA3AgAgCSANNgIAIAsgBygCADYCACABIAYpA7ACNwIAIAVBDGohBSAKIAhJDQALCyAOIAYpAyA3AgAgEiAWKAIANgIAIA8gBSAXa0EMbiAMaiIBTQ0DIBQgEigCADYCACAGIA4pAgA3A7ABIA4gAUEMbGoiBUEIaiIAKAIAIQggDiAFKQIANwIAIBIgCDYCACAAIBQoAgA2AgAgBSAGKQOwATcCACAPIAFrIghFDQQgCCABIAEgCEsbIA9BA3YgBUEMaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAUgDiEADAELIA4gASACIAMgBBAFIAUhAyAIIQELTyEJIAFBFU8NAQwFCwsgDCAFEKgBAAsgBSABEKYBAAtBwKfCACABIA8QpwEAC0G1rcIAQRxB1K3CABC5AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBOIAEgBUcNAAsLIAZBwAJqJAALhBYBCH8jAEEQayIDJAAgAyAAKAIUIgQ2AgggAyAENgIEIAMgBDYCACAAKAIQIQIgAEEUaiEFIABBEGohBAJAAkACQCAAEDNFDQAgACgCECEBAkAgABA0RQ0AIAAQMw0CA0AgBCgCACAFKAIATw0BIAAQkAEgABAzRQ0ACwwCCyAAIAE2AhAgABAzRQ0AIAAQNA0BA0AgBCgCACAFKAIATw0BIAAQkAEgABA0RQ0ACwwBCyAAIAI2AhAgABA0RQ0BIAAoAhAhAQJAIAAQNEUNACAAEDMNAQNAIAQoAgAgBSgCAE8NASAAEJABIAAQM0UNAAsMAQsgACABNgIQIAAQM0UNASAEKAIAIAUoAgBPDQEgABCQAQsgAyAEKAIANgIICyAAIAI2AhACQCAAEDNFBEADQCAEKAIAIAUoAgAiAU8NAiAAEJABIAAQM0UNAAsLIAAQNCAAKAIQIQFFBEADQCABIAUoAgAiAU8NAiAAEJABIAAQNCAAKAIQIQFFDQALCyADIAE2AgQgABAzRQRAA0AgBCgCACAFKAIAIgFPDQIgABCQASAAEDNFDQALCyAAEDQgACgCECEBRQRAA0AgASAFKAIAIgFPDQIgABCQASAAEDQgACgCECEBRQ0ACwsgAyABNgIAIAUoAgAhAQsgACABNgIgIAAgATYCECAAIAI2AhgCQCAAQaTAwgBBDSADEC1FDQAgACAAKAIQNgIcIABB3MLCAEELIAMQLSICRQ0AIAMoAgggBCgCACIBSw0AIAJBf2oiAkEGSw0AAkACQAJAAkACQAJAAkAgAkEBaw4GAQIDBAUGAAsgACABNgIcIAAgASAAKAIgQeTEwgBBBRA1DAYLIAAgATYCHCAAIAEgACgCIEHpxMIAQQQQNQwFCyAAIAE2AhwgACABIAAoAiBB7cTCAEECEDUMBAsgACABNgIcIAAgASAAKAIgQe/EwgBBAhA1DAMLIAAgATYCHCAAIAEgACgCIEHxxMIAQQIQNQwCCyAAIAAoAhwgACgCIEGO6sIAQQAQNQwBCyAAQYDuwgBBARBFRQ0AIAAgACgCHCAAKAIgQY7qwgBBABA1CyAAIAAoAhQiATYCICAAIAE2AhACQAJAAkACQAJAAkACQAJAIABB9MTCAEEuIAMQLSICRQ0AIAAgACgCECIBNgIcIAJBf2oiAkEISw0HAkACQAJAAkACQAJAAkACQAJAIAJBAWsOCAECAwQFBgcIAAsgAygCACABSw0IIAAgASAAKAIgQY7qwgBBABA1DA8LIAMoAgAgAUsNByAAIAEgACgCIEGO6sIAQQAQNSAAIAAoAhAiATYCICAAKAIUIAFrIQEgAEHEzcIAQQIQRUUNCCAAIAAoAhAiAjYCHCADKAIAIAJNBEAgACACIAAoAiBBjurCAEEAEDUMDwsgBCAFKAIAIAFrNgIADA4LIAMoAgAgAUsNBiAAIAEgACgCIEHGzcIAQQMQNQwNCyADKAIAIAFLDQUgACABIAAoAiBBgO7CAEEBEDUMDAsgAygCACABSw0EIAAgASAAKAIgQcnNwgBBBBA1DAsLIAMoAgQgAUsNAyAAIAEgACgCIEGO6sIAQQAQNSAAIAAoAhAiATYCICAAKAIUIAFrIQEgAEHQzcIAQQQgAxAtIgZFDQUgACAAKAIQIgI2AhwgAygCACACSwRAIAQgBSgCACABazYCAAwLCyAAIAIgACgCIEGO6sIAQQAQNSAGQQFHDQogACAAKAIQNgIgIABBsM7CAEECEEVFDQYgACAAKAIQIgI2AhwgAygCACACTQRAIAAgAiAAKAIgQY7qwgBBABA1DAsLIAQgBSgCACABazYCAAwKCyADKAIAIAFLDQIgACABIAAoAiBBjurCAEEAEDUgACAAKAIQIgE2AiAgACgCFCABayEBIABBtM7CAEEDIAMQLSIGRQ0GIAAgACgCECICNgIcIAZBAUcNCSADKAIAIAJNBEAgACACIAAoAiBBjurCAEEAEDUMCgsgBCAFKAIAIAFrNgIADAkLIAMoAgAgAUsNASAAIAEgACgCIEGO6sIAQQAQNSAAIAAoAhAiATYCICAAKAIUIAFrIQEgAEH8zsIAQQMgAxAtIgZFDQYgACAAKAIQIgI2AhwgBkEBRw0IIAMoAgAgAk0EQCAAIAIgACgCIEGO6sIAQQAQNQwJCyAEIAUoAgAgAWs2AgAMCAsgAygCACABSw0AIAAgASAAKAIgQY7qwgBBABA1IAAgACgCECIBNgIgIAAoAhQgAWshASAAQbDOwgBBAhBFRQ0GIAAgACgCECICNgIcIAMoAgAgAk0EQCAAIAIgACgCIEGO6sIAQQAQNQwICyAEIAUoAgAgAWs2AgAMBwsgBCAFKAIAIgE2AgACQCABIAMoAggiAkkNACAAIAE2AiAgACABNgIQIAAoAhghASAAIAI2AhggAEGY0sIAQQwgAxAtIgIEQCAAIAE2AhggACAAKAIQNgIcIAJBAUcNCCAAQYDuwgBBARBFRQ0BIAAgACgCHCAAKAIgQY7qwgBBABA1DAgLIAAgATYCGAsgBCAFKAIAIgE2AgAgASADKAIIIgJJDQYgACABNgIgIAAgATYCECAAKAIYIQEgACACNgIYAkACQCAAQbjUwgBB4AAgAxAtIgIEQCAAIAE2AhggACAAKAIQIgE2AhwgAkF/aiICQQFLDQkgAkEBaw0BDAILIAAgATYCGAwICyAAKAIUIABBgO7CAEEBEEUhBiABayEBIAAoAhQhAiAAAn8gBgRAIAAoAhAhBiAAQYTRwgBBARBFIQcgACgCFCAGIAJrQQAgAWsgBxtqDAELIAIgAWsLIgE2AhwgACABNgIQIAAgASAAKAIgQY7qwgBBABA1DAcLIAAgASAAKAIgQY7qwgBBABA1DAYLIAQgBSgCACABazYCAAwFCyAEIAUoAgAgAWs2AgAMBAsgBCAFKAIAIAFrNgIADAMLIAQgBSgCACABazYCAAwCCyAEIAUoAgAgAWs2AgAMAQsgBCAFKAIAIAFrNgIACyAAIAAoAhQiATYCICAAIAE2AhACQCAAQcTPwgBBCCADEC0iAkUNACAAIAAoAhAiATYCHCACQX9qIgJBAUsNACACQQFrBEAgAygCCCABSw0BIAAgASAAKAIgQY7qwgBBABA1DAELIAMoAgggAUsNACAAIAEgACgCIEGO6sIAQQAQNSAAIAAoAhAiATYCICAAKAIUIAFrIQECQCAAQYDuwgBBARBFBEAgACAAKAIQIgY2AhwgACgCFCEHIABBhNHCAEEBEEUgACgCFCECRQ0BIAQgAiAGIAdraiIGNgIAIAMoAgggBk0EQCAAIAAoAhwgACgCIEGO6sIAQQAQNQwDCyAEIAIgAWs2AgAMAgsgBCAFKAIAIAFrNgIADAELIAQgAiABazYCAAsgACAAKAIYIgY2AhwgACAGNgIQAkAgACADEDkiAUUNAANAIAAgACgCECICNgIgAkAgAUF/aiIBQQVLDQACQAJAAkACQAJAAkAgAUEBaw4FAQIDBAUACyAAIAAoAhwgAkH87cIAQQEQNQwFCyAAIAAoAhwgAkH97cIAQQEQNQwECyAAIAAoAhwgAkH+7cIAQQEQNQwDCyAAIAAoAhwgAkH/7cIAQQEQNQwCCyAAIAAoAhwgAkGA7sIAQQEQNQwBCyACIAUoAgBPDQIgABCQAQsgACAAKAIQNgIcIAAgAxA5IgENAAsLIAQgBjYCACADQRBqJABBAQvkEAIUfwF+IwBBsAJrIgYkAAJAAkAgAUEVSQ0AQQEhD0EBIRMCQAJAAkADQCABIQ4gACEMIA8gE3FBAXMhCQJAAkACQAJAAkACQANAAn8CQCAEBEAgD0EBcUUEQCAAIAEQbyAEQX9qIQQLIAYgAjYCDCAGIAA2AjAgBiABNgI0IAYgAUECdiIINgIQIAYgCEEBdCIFNgIUIAYgCEEDbCIHNgIYIAZBADYCHCAGIAZBHGo2ArgBIAYgBkEwajYCtAEgBiAGQQxqNgKwASAGIAZBsAFqNgIgIAFBMk8EQCAGIAhBf2o2AiQgBiAIQQFqNgIoIAZBIGogBkEkaiAGQRBqIAZBKGoQZCAGIAVBf2o2AiQgBiAFQQFyNgIoIAZBIGogBkEkaiAGQRRqIAZBKGoQZCAGIAdBf2o2AiQgBiAHQQFqNgIoIAZBIGogBkEkaiAGQRhqIAZBKGoQZAsgBkEgaiAGQRBqIAZBFGogBkEYahBkIAYoAhwiCEELSw0BIAhFIQcgBigCFAwCCyAAIAEQigEMDgsgBigCNCIHQQF2IggEQCAGKAIwIgUgB0EDdGpBeGohBwNAIAUpAgAhGSAFIAcpAgA3AgAgByAZNwIAIAVBCGohBSAHQXhqIQcgCEF/aiIIDQALC0EBIQcgASAGKAIUQX9zagshBSAHRSAJckEBcUUEQCAAIAEQOA0NCyADRQ0CIAUgAU8NASAAIAVBA3RqIggoAgQoAgAgAygCBCgCAEkEQCABIQ4gACEMDAQLIAApAgAhGSAAIAgpAgA3AgAgCCAZNwIAIABBfGohCyAAQQxqIREgAEEIaiESIAAoAgQhDSAAKAIAIRNBACEHIAEhCANAAkAgByAIQX9qIgpPDQAgESAHQQN0aiEFIA0oAgAhEANAIAUoAgAoAgAgEEkNASAFQQhqIQUgCiAHQQFqIgdHDQALIAohBwsgCyAIQQN0aiEFAkADQCAHIAhBf2oiCE8NASAFKAIAIAVBeGoiECEFKAIAIA0oAgBJDQALIBIgB0EDdGoiBSkCACEZIAUgEEEEaiIFKQIANwIAIAUgGTcCACAHQQFqIQcMAQsLIAAgDTYCBCAAIBM2AgAgASAHQQFqIghPBEAgACAIQQN0aiEAIAEgCGsiAUEVTw0BDAwLCyAIIAEQqAEAC0HQp8IAIAUgARCnAQALIA5FDQELIAUgDk8NASAMKQIAIRkgDCAMIAVBA3RqIgApAgA3AgAgACAZNwIAIAwoAgAhACAGIAwoAgQiEjYCLCAGIAA2AiggDkF/aiIBRQRAQQAhDQwDCyAMQQxqIQUgEigCACEAQQAhDQNAIAAgBSgCACgCAE8NAyAFQQhqIQUgASANQQFqIg1HDQALIAEhDQwCC0Gwp8IAQQBBABCnAQALQcCnwgAgBSAOEKcBAAsgDEEIaiEKIAwgDkEDdGohByABIQADQAJAIAchCCAAIgUgDU0iEw0AIAhBeGohByAFQX9qIQAgEigCACAIQXxqKAIAKAIATw0BCwsgBSANTwRAIAEgBUkNAkGAASERQQAhAEEAIQFBACEHQQAhC0GAASEPIAogDUEDdGoiFSEKA0ACQCAIIAprIgVBhxBLIhYNACAFQQN2IgVBgH9qIAUgCyAHSSIJIAEgAElyIhAbIQUgEARAIA8gBSAJGyEPIAUgESAJGyERDAELIAUgBUEBdiIPayERCwJAIAcgC0cNACAPRQRAIAZBMGoiByELDAELQQAhBSAKIQkgBkEwaiILIQcDQCAHIAU6AAAgByASKAIAIAlBBGooAgAoAgBPaiEHIAlBCGohCSAPIAVBAWoiBUcNAAsLAkAgACABRw0AIBFFBEAgBkGwAWoiACEBDAELIAhBfGohBUEAIQkgBkGwAWoiASEAA0AgACAJOgAAIAAgBigCLCISKAIAIAUoAgAoAgBJaiEAIAVBeGohBSARIAlBAWoiCUcNAAsLIAggEUEDdGsgCCAAIAFrIgUgByALayIJIAkgBUsbIgkEQCAKIAstAABBA3RqIgUoAgQhFyAFKAIAIRggBSAIIAEtAABBf3NBA3RqKQIANwIAIAlBAUcEQEEAIQUDQCAIIAEgBWoiEC0AAEF/c0EDdGogCiAFIAtqQQFqIhQtAABBA3RqKQIANwIAIAogFC0AAEEDdGogCCAQQQFqLQAAQX9zQQN0aikCADcCACAFQQJqIAVBAWoiECEFIAlJDQALIAsgEGohCyABIBBqIQELIAggAS0AAEF/c0EDdGoiBSAXNgIEIAUgGDYCACALQQFqIQsgAUEBaiEBCyAAIAFGGyEIIAogD0EDdGogCiAHIAtGGyEKIBYNAAsCQCALIAdJBEAgCCEFA0AgCiAHQX9qIgctAABBA3RqIgApAgAhGSAAIAVBeGoiBSkCADcCACAFIBk3AgAgCyAHSQ0ACwwBCyAKIQUgASAATw0AA0AgBSkCACEZIAUgCCAAQX9qIgAtAABBf3NBA3RqIgcpAgA3AgAgByAZNwIAIAVBCGohBSABIABJDQALCyAMIAYpAygiGTcCACAOIAUgFWtBA3YgDWoiAU0NAyAMIAwgAUEDdGoiCCkCADcCACAIIBk3AgAgDiABayIFRQ0EIAUgASABIAVLGyAOQQN2IAhBCGohAAJAIAEgBUF/aiIFTwRAIAAgBSACIAggBBAHIAwhAAwBCyAMIAEgAiADIAQQByAIIQMgBSEBC08hDyABQRVPDQEMBQsLIA0gBRCoAQALIAUgARCmAQALQcCnwgAgASAOEKcBAAtBta3CAEEcQdStwgAQuQEACyABQQJJDQBBASEFA0AgACAFQQFqIgUQaiABIAVHDQALCyAGQbACaiQAC+4PARN/IwBBoAJrIgYkAAJAAkAgAUEVSQ0AQQEhD0EBIRMCQAJAAkADQCABIQwgACENIA8gE3FBAXMhCwJAAkACQAJAAkACQANAAn8CQCAEBEAgD0EBcUUEQCAAIAEQcCAEQX9qIQQLIAYgAjYCACAGIAA2AiAgBiABNgIkIAYgAUECdiIFNgIEIAYgBUEBdCIINgIIIAYgBUEDbCIHNgIMIAZBADYCECAGIAZBEGo2AqgBIAYgBkEgajYCpAEgBiAGNgKgASAGIAZBoAFqNgIUIAFBMk8EQCAGIAVBf2o2AhggBiAFQQFqNgIcIAZBFGogBkEYaiAGQQRqIAZBHGoQxAEgBiAIQX9qNgIYIAYgCEEBcjYCHCAGQRRqIAZBGGogBkEIaiAGQRxqEMQBIAYgB0F/ajYCGCAGIAdBAWo2AhwgBkEUaiAGQRhqIAZBDGogBkEcahDEAQsgBkEUaiAGQQRqIAZBCGogBkEMahDEASAGKAIQIgVBC0sNASAFRSEHIAYoAggMAgsgACABEIkBDA4LIAYoAiQiB0EBdiIIBEAgBigCICIFIAdBAnRqQXxqIQcDQCAFKAIAIQkgBSAHKAIANgIAIAcgCTYCACAFQQRqIQUgB0F8aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCCEF/c2oLIQUgB0UgC3JBAXFFBEAgACABED4NDQsgA0UNAiAFIAFPDQEgAygCACAAIAVBAnRqIggoAgAiBxCyAQRAIAEhDCAAIQ0MBAsgACgCACEFIAAgBzYCACAIIAU2AgAgAEF8aiEQIABBBGohCiAAKAIAIQ5BACEHIAEhCANAAkAgByAIQX9qIglPDQAgCiAHQQJ0aiEFA0AgDiAFKAIAELIBDQEgBUEEaiEFIAkgB0EBaiIHRw0ACyAJIQcLIBAgCEECdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACEJIAVBfGoiESEFIA4gCRCyAQ0ACyAKIAdBAnRqIgUoAgAhEyAFIAk2AgAgEUEEaiATNgIAIAdBAWohBwwBCwsgACAONgIAIAEgB0EBaiIFTwRAIAAgBUECdGohACABIAVrIgFBFU8NAQwMCwsgBSABEKgBAAtB0KfCACAFIAEQpwEACyAMRQ0BCyAFIAxPDQEgDSgCACEAIA0gDSAFQQJ0aiIBKAIANgIAIAEgADYCACANQQRqIQkgDSgCACEQQQAhDiAMQX9qIgFFDQIgCSEFA0AgBSgCACAQELIBRQ0DIAVBBGohBSABIA5BAWoiDkcNAAsgASEODAILQbCnwgBBAEEAEKcBAAtBwKfCACAFIAwQpwEACyANIAxBAnRqIQcgASEIA0ACQCAHIQAgCCIFIA5NIhMNACAFQX9qIQggAEF8aiIHKAIAIBAQsgFFDQELCyAFIA5PBEAgASAFSQ0CQYABIQ9BACEIQQAhAUEAIQdBACELQYABIREgCSAOQQJ0aiIVIQkDQAJAIAAgCWsiBUGDCEsiFg0AIAVBAnYiBUGAf2ogBSALIAdJIgogASAISXIiEhshBSASBEAgESAFIAobIREgBSAPIAobIQ8MAQsgBSAFQQF2IhFrIQ8LAkAgByALRw0AIBFFBEAgBkEgaiIHIQsMAQtBACEFIAkhCiAGQSBqIgshBwNAIAcgBToAACAHIAooAgAgEBCyAUEBc2ohByAKQQRqIQogESAFQQFqIgVHDQALCwJAIAEgCEcNACAPRQRAIAZBoAFqIgghAQwBCyAAQXxqIQVBACEKIAZBoAFqIgEhCANAIAggCjoAACAFKAIAIBAQsgEgCGohCCAFQXxqIQUgDyAKQQFqIgpHDQALCyAAIA9BAnRrIAAgCCABayIFIAcgC2siCiAKIAVLGyIKBEAgCSALLQAAQQJ0aiIFKAIAIRcgBSAAIAEtAABBf3NBAnRqKAIANgIAIApBAUcEQEEAIQUDQCAAIAEgBWoiEi0AAEF/c0ECdGogCSAFIAtqQQFqIhQtAABBAnRqKAIANgIAIAkgFC0AAEECdGogACASQQFqLQAAQX9zQQJ0aigCADYCACAFQQJqIAVBAWoiEiEFIApJDQALIAsgEmohCyABIBJqIQELIAAgAS0AAEF/c0ECdGogFzYCACALQQFqIQsgAUEBaiEBCyABIAhGGyEAIAkgEUECdGogCSAHIAtGGyEJIBYNAAsCQCALIAdJBEAgACEFA0AgCSAHQX9qIgctAABBAnRqIgAoAgAhASAAIAVBfGoiBSgCADYCACAFIAE2AgAgCyAHSQ0ACwwBCyAJIQUgASAITw0AA0AgBSgCACEHIAUgACAIQX9qIggtAABBf3NBAnRqIgkoAgA2AgAgCSAHNgIAIAVBBGohBSABIAhJDQALCyANIBA2AgAgDCAFIBVrQQJ2IA5qIgFNDQMgDSANIAFBAnRqIgUoAgA2AgAgBSAQNgIAIAwgAWsiCEUNBCAIIAEgASAISxsgDEEDdiAFQQRqIQACQCABIAhBf2oiCE8EQCAAIAggAiAFIAQQCCANIQAMAQsgDSABIAIgAyAEEAggBSEDIAghAQtPIQ8gAUEVTw0BDAULCyAOIAUQqAEACyAFIAEQpgEAC0HAp8IAIAEgDBCnAQALQbWtwgBBHEHUrcIAELkBAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEH0gASAFRw0ACwsgBkGgAmokAAvXDwETfyMAQaACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEMIAAhDSAPIBNxQQFzIQsCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEHAgBEF/aiEECyAGIAI2AgAgBiAANgIgIAYgATYCJCAGIAFBAnYiBTYCBCAGIAVBAXQiCDYCCCAGIAVBA2wiBzYCDCAGQQA2AhAgBiAGQRBqNgKoASAGIAZBIGo2AqQBIAYgBjYCoAEgBiAGQaABajYCFCABQTJPBEAgBiAFQX9qNgIYIAYgBUEBajYCHCAGQRRqIAZBGGogBkEEaiAGQRxqEGcgBiAIQX9qNgIYIAYgCEEBcjYCHCAGQRRqIAZBGGogBkEIaiAGQRxqEGcgBiAHQX9qNgIYIAYgB0EBajYCHCAGQRRqIAZBGGogBkEMaiAGQRxqEGcLIAZBFGogBkEEaiAGQQhqIAZBDGoQZyAGKAIQIgVBC0sNASAFRSEHIAYoAggMAgsgACABEIgBDA4LIAYoAiQiB0EBdiIIBEAgBigCICIFIAdBAnRqQXxqIQcDQCAFKAIAIQkgBSAHKAIANgIAIAcgCTYCACAFQQRqIQUgB0F8aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCCEF/c2oLIQUgB0UgC3JBAXFFBEAgACABED0NDQsgA0UNAiAFIAFPDQEgAygCACAAIAVBAnRqIggoAgAiB0kEQCABIQwgACENDAQLIAAoAgAhBSAAIAc2AgAgCCAFNgIAIABBfGohECAAQQRqIQogACgCACEOQQAhByABIQgDQAJAIAcgCEF/aiIJTw0AIAogB0ECdGohBQNAIA4gBSgCAEkNASAFQQRqIQUgCSAHQQFqIgdHDQALIAkhBwsgECAIQQJ0aiEFAkADQCAHIAhBf2oiCE8NASAFKAIAIQkgBUF8aiIRIQUgDiAJSQ0ACyAKIAdBAnRqIgUoAgAhEyAFIAk2AgAgEUEEaiATNgIAIAdBAWohBwwBCwsgACAONgIAIAEgB0EBaiIFTwRAIAAgBUECdGohACABIAVrIgFBFU8NAQwMCwsgBSABEKgBAAtB0KfCACAFIAEQpwEACyAMRQ0BCyAFIAxPDQEgDSgCACEAIA0gDSAFQQJ0aiIBKAIANgIAIAEgADYCACANQQRqIQkgDSgCACEQQQAhDiAMQX9qIgFFDQIgCSEFA0AgBSgCACAQTw0DIAVBBGohBSABIA5BAWoiDkcNAAsgASEODAILQbCnwgBBAEEAEKcBAAtBwKfCACAFIAwQpwEACyANIAxBAnRqIQcgASEIA0ACQCAHIQAgCCIFIA5NIhMNACAFQX9qIQggAEF8aiIHKAIAIBBPDQELCyAFIA5PBEAgASAFSQ0CQYABIQ9BACEIQQAhAUEAIQdBACELQYABIREgCSAOQQJ0aiIVIQkDQAJAIAAgCWsiBUGDCEsiFg0AIAVBAnYiBUGAf2ogBSALIAdJIgogASAISXIiEhshBSASBEAgESAFIAobIREgBSAPIAobIQ8MAQsgBSAFQQF2IhFrIQ8LAkAgByALRw0AIBFFBEAgBkEgaiIHIQsMAQtBACEFIAkhCiAGQSBqIgshBwNAIAcgBToAACAHIAooAgAgEE9qIQcgCkEEaiEKIBEgBUEBaiIFRw0ACwsCQCABIAhHDQAgD0UEQCAGQaABaiIIIQEMAQsgAEF8aiEFQQAhCiAGQaABaiIBIQgDQCAIIAo6AAAgCCAFKAIAIBBJaiEIIAVBfGohBSAPIApBAWoiCkcNAAsLIAAgD0ECdGsgACAIIAFrIgUgByALayIKIAogBUsbIgoEQCAJIAstAABBAnRqIgUoAgAhFyAFIAAgAS0AAEF/c0ECdGooAgA2AgAgCkEBRwRAQQAhBQNAIAAgASAFaiISLQAAQX9zQQJ0aiAJIAUgC2pBAWoiFC0AAEECdGooAgA2AgAgCSAULQAAQQJ0aiAAIBJBAWotAABBf3NBAnRqKAIANgIAIAVBAmogBUEBaiISIQUgCkkNAAsgCyASaiELIAEgEmohAQsgACABLQAAQX9zQQJ0aiAXNgIAIAtBAWohCyABQQFqIQELIAEgCEYbIQAgCSARQQJ0aiAJIAcgC0YbIQkgFg0ACwJAIAsgB0kEQCAAIQUDQCAJIAdBf2oiBy0AAEECdGoiACgCACEBIAAgBUF8aiIFKAIANgIAIAUgATYCACALIAdJDQALDAELIAkhBSABIAhPDQADQCAFKAIAIQcgBSAAIAhBf2oiCC0AAEF/c0ECdGoiCSgCADYCACAJIAc2AgAgBUEEaiEFIAEgCEkNAAsLIA0gEDYCACAMIAUgFWtBAnYgDmoiAU0NAyANIA0gAUECdGoiBSgCADYCACAFIBA2AgAgDCABayIIRQ0EIAggASABIAhLGyAMQQN2IAVBBGohAAJAIAEgCEF/aiIITwRAIAAgCCACIAUgBBAJIA0hAAwBCyANIAEgAiADIAQQCSAFIQMgCCEBC08hDyABQRVPDQEMBQsLIA4gBRCoAQALIAUgARCmAQALQcCnwgAgASAMEKcBAAtBta3CAEEcQdStwgAQuQEACyABQQJJDQBBASEFA0AgACAFQQFqIgUQciABIAVHDQALCyAGQaACaiQAC7cOARp/IwBBgAFrIgckACAAKAIEIQQgASgCCCEJIAEoAgAhDQJAAkACQCAAKAIALQAABEAgByAENgIMIAlBFUkNASAHIAlBAXYQnQEgBygCBCAHKAIAIRAgB0EANgIYIAdCBDcDECANQZx/aiEVQQQhCkEAIQQgCSEGA0ACQCAGIgNBAUYEQEEAIQZBASEADAELIANBfmohAiANIANB5ABsIhFqIQVBWCEBAkACQAJAAkACQAJAAkADQCABQQRqIgBFDQEgASAFaiEIIAAhASAIQaB/aigCACIGIAhBBGooAgAiAEYNAAsgBiAASA0BCyANIBFqIQUDQCACIgZFBEBBACEGIAMhAAwICyAGQX9qIQJBWCEBA0ACQCABQQRqIgBFBEBBACEADAELIAEgBWohDCAAIQFBfyAMQbx+aigCACIIIAxBoH9qKAIAIgBHIAggAEgbIgBFDQELCyAFQZx/aiEFIABBf0cNAAsgAyAGayEADAELAkAgAgRAA0AgAiIGQX9qIQJBWCEBA0AgAUEEaiIARQ0DIAEgBWohDCAAIQFBfyAMQbx+aigCACIIIAxBoH9qKAIAIgBHIAggAEgbIgBFDQALIABB/wFxQf8BRw0CIAVBnH9qIQUgAg0ACwtBACEGCyADIAZJDQEgAyAJSw0CIAMgBmsiAEEBdiIFRQ0AIA0gBkHkAGxqIQEgESAVaiECA0AgB0EcaiABQeQAELoBGiABIAJB5AAQmQFB5ABqIQEgAiAHQRxqQeQAELoBQZx/aiECIAVBf2oiBQ0ACwsgBkUgAEEJS3INBCADIAZrIQAgBkF/aiEBIBUgBkHkAGxqIQIDQCADIAFJDQMgAyAJSw0CIAIgAEEBaiIAEE0gAUF/aiIEIAFPDQQgAkGcf2ohAiAEIQEgAEEKSQ0ACwwDCyAGIAMQqAEACyADIAkQpgEACyABIAMQqAEACyAEQQFqIQYgCyEECyAHKAIUIARGBH8gB0EQaiAEEIsBIAcoAhAhCiAHKAIYIgsFIAQLQQN0IApqIgEgADYCBCABIAY2AgAgByALQQFqIgs2AhgCQCALIgRBAkkNAAJAA0ACQAJAAkACQCAKIAsiA0F/aiILQQN0aiIBKAIABEAgA0EDdCAKaiIFQXRqKAIAIgggASgCBCIASw0BCyADQQNJDQIgASgCBCEAIAogA0F9aiIBQQN0aigCBCECDAELQQIhBCADQQJNDQQgCiADQX1qIgFBA3RqKAIEIgIgACAIak0NAEEDIQQgA0EDTQ0EIAVBZGooAgAgAiAIak0NACADIQsgAyEEDAULIAIgAEkNAQsgA0F+aiEBCwJAAkACQAJAAkACQAJAIAMgAUEBaiITSwRAIAMgAU0NASAKIAFBA3QiGWoiACgCBCIaIAAoAgBqIgEgCiATQQN0IhtqIgAoAgAiEkkNAiABIAlLDQMgDSASQeQAbGoiCCAAKAIEIg9B5ABsIgVqIQQgDSABQeQAbGohFCABIBJrIgAgD2siASAPTw0EIBAgBCABQeQAbCIAELoBIgwgAGohDiAPQQFIIAFBAUhyDQUgBCEFA0AgDkFcaiERIAVBnH9qIRYgDkGcf2ohF0EAIQEDQAJAIAFBBGoiAEEoRgRAQQAhAAwBCyABIBFqIQIgASAFaiEEIAAhAUF/IARBXGooAgAiBCACKAIAIgBHIAQgAEgbIgBFDQELCyAUQZx/aiIUIBYgFyAAQX9GIgAbQeQAELoBGiAOIBcgABshDiAIIBYgBSAAGyIFTw0HIA4gDCICSw0ACwwHC0Hoq8IAIBMgAxCnAQALQeirwgAgASADEKcBAAsgEiABEKgBAAsgASAJEKYBAAsgECAIIAUQugEiAiAFaiEOIA9BAUggACAPTHJFBEAgCCEFA0BBwAAhAQNAAkAgAUEEaiIAQegARgRAQQAhAAwBCyABIARqIQwgASACaiEIIAAhAUF/IAgoAgAiCCAMKAIAIgBHIAggAEgbIgBFDQELCyAEIAIgAEF/RiIBGyEAIAIgAkHkAGogARshAiAFIABB5AAQugFB5ABqIQUgBEHkAGogBCABGyIEIBRPDQQgDiACSw0ACwwDCyAIIQUMAQsgBCEFCyAQIQILIAUgAiAOIAJrIgAgAEHkAHBrELoBGiAKIBlqIgAgDyAaajYCBCAAIBI2AgAgCiAbaiIAIABBCGogAyATQX9zakEDdBCZARogByALNgIYIAtBAUsNAAsgCyEEDAELIAMhCwsgBg0ACyAHKAIUBEAgChAhC0UNAiAQECEMAgsgByAENgIQIAcgB0EQajYCHCANIAkgB0EcakEAQSAgCWdrEAQMAQtBAiEBIAlBAkkNACANIAlBfmoiAEHkAGxqIQIDQCAJIABJDQIgAiABEE0gAkGcf2ohAiABQQFqIQEgAEF/aiIAQX9HDQALCyAHQYABaiQADwsgACAJEKgBAAu/DAIWfwF+IwBBIGsiByQAIAAoAgQhAyABKAIIIQggASgCACELAkACQAJAIAAoAgAtAAAEQCAHIAM2AgwgCEEVSQ0BIAcgCEEBdhChASAHKAIEIAcoAgAhEEEAIQEgB0EANgIYIAdCBDcDECALQXhqIRMgC0FsaiEVQQQhCUEAIQMgCCEGA0ACQCAGIgVBf2oiBkUEQEEAIQZBASEEDAELAkACQAJAAkACQCAFQQN0Ig0gC2pBdGooAgAoAgAiACALIAZBA3RqKAIEKAIATwRAIAVBfmohFCANIBVqIQRBACEGQQAhAgNAIAIgFEYEQCAFIQQMCAsgAkEBaiECIAQoAgAoAgAiCiAATyAEQXhqIQQgCiEADQALIAJBAWohBCACQX9zIAVqIQYMAQsgDSAVaiECA0ACQCAGQQFGBEBBACEGDAELIAZBf2ohBiACKAIAKAIAIgQgAEkgAkF4aiECIAQhAA0BCwsgBSAGSQ0BIAUgCEsNAiAFIAZrIgRBAXYiCkUNACALIAZBA3RqIQAgDSATaiECA0AgACkCACEYIAAgAikCADcCACACIBg3AgAgAEEIaiEAIAJBeGohAiAKQX9qIgoNAAsLIAZFIARBCUtyDQQgBSAGayEEIAZBf2ohACATIAZBA3RqIQIDQCAFIABJDQMgBSAISw0CIAIgBEEBaiIEEGwgAEF/aiIDIABPDQQgAkF4aiECIAMhACAEQQpJDQALDAMLIAYgBRCoAQALIAUgCBCmAQALIAAgBRCoAQALIANBAWohBiABIQMLIAcoAhQgA0YEfyAHQRBqIAMQiwEgBygCECEJIAcoAhgiAQUgAwtBA3QgCWoiACAENgIEIAAgBjYCACAHIAFBAWoiATYCGCABIQMCQCABQQJJDQACQANAAkACQAJAAkAgCSABQX9qQQN0aiIAKAIARQ0AIAFBA3QgCWoiBEF0aigCACIFIAAoAgQiAk0NAEECIQMgAUECTQ0GIAkgAUF9aiIMQQN0aigCBCIAIAIgBWpNDQFBAyEDIAFBA00NBiAEQWRqKAIAIAAgBWpNDQEgASEDDAYLIAFBA0kNASAAKAIEIQIgCSABQX1qIgxBA3RqKAIEIQALIAAgAkkNAQsgAUF+aiEMCwJAAkAgASAMQQFqIhFLBEAgASAMSwRAIAkgDEEDdCIXaiIAKAIEIhQgACgCAGoiAyAJIBFBA3QiDWoiACgCACISTwRAIAMgCE0EQCALIBJBA3RqIgQgACgCBCIOQQN0IgpqIQEgA0EDdCEPAkACQCADIBJrIgMgDmsiBSAOSQRAIBAgASAFQQN0IgAQugEiCiAAaiECIA5BAUggBUEBSHINASAPIBNqIQADQAJAIAAgAUF4aiIFIAJBeGoiAyABQXxqKAIAKAIAIAJBfGooAgAoAgBJIg8bKQIANwIAIAIgAyAPGyECIAQgBSABIA8bIgFPDQAgAEF4aiEAIAIgCksNAQsLIAcoAhAhCQwBCyAKIBAgBCAKELoBIgBqIQIgAyAOSkEAIA5BAU4bDQEgBCEBCyAQIQAMBgsgCyAPaiEDA0AgBCABIAAgACgCBCgCACABKAIEKAIASSIFGykCADcCACAAIABBCGogBRshACAEQQhqIQQgAUEIaiABIAUbIgEgA08NBSACIABLDQALDAQLIAMgCBCmAQALIBIgAxCoAQALQeirwgAgDCABEKcBAAtB6KvCACARIAEQpwEACyAEIQELIAEgACACIABrQXhxELoBGiAHKAIYIgEgDEsEQCAJIBdqIgAgDiAUajYCBCAAIBI2AgAgASARTQ0CIAkgDWoiACAAQQhqIAEgEUF/c2pBA3QQmQEaIAcgAUF/aiIBNgIYIAEhAyABQQFNDQMMAQsLQfirwgAgDCABEKcBAAtBp67CAEEdQcSuwgAQuQEACyAGDQALIAcoAhQEQCAJECELRQ0CIBAQIQwCCyAHIAM2AgwgByAHQQxqNgIQIAsgCCAHQRBqQQBBICAIZ2sQBwwBC0ECIQEgCEECSQ0AIAsgCEF+aiIAQQN0aiECA0AgCCAASQ0CIAIgARBsIAJBeGohAiABQQFqIQEgAEF/aiIAQX9HDQALCyAHQSBqJAAPCyAAIAgQqAEAC+YMAgx/BH4jAEHgAGsiBiQAAkACQAJAAkAgASgCECIDQQFqIgUgA08EQCABKAIAIgIhAwJAIAUgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAFIANBAWoiAyAFIANLGyIDRQRAQbCmwgAhCkEEIQRBAAwBCyAGQSBqQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiB0EBaiIFEJsBIAYoAiQiBEUNByAGKAIoIQIgBigCICIDIAQQ0gEiCkUNAiAKQf8BIAdBBWoQwAEgAmohBCAHIAVBA3ZBB2wgB0EISRsLIQIgBiABKAIQIgM2AjAgBiACIANrNgIsIAYgBDYCKCAGIAo2AiQgBiAHNgIgIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDSADKAIAQX9zQYCBgoR4cSEIIAEoAgghCQNAAkACQAJAIAgEQCACIQMMAQsDQCACIA1PDQIgCUGwB2ohCSACKAIAIAJBBGoiAyECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyEICyAGIAkgCGhBA3ZB7AFsaiIMKAIAIgQ2AlAgCEF/aiELQqXGiKHInKf5SyEOQQEhAgNAIA4gBK1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIAZB0ABqIAJqIAJBAWohAi0AACEEDAALAAsgASkCACEPIAEgBikDIDcCACAGQShqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBkEwaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDzcDICAAQQA2AgAgD6ciAEUNByAGQdAAaiAAQQFqEJsBIAYoAiQQIQwHCyAKIAZBIGogDhCXASIFaiAOp0EZdiICOgAAIAcgBUF8anEgCmpBBGogAjoAACAGKAIoIAVB7AFsaiAMQewBELoBGiAIIAtxIQggAyECDAALAAsgAkEBaiELQQAhAgNAAkACfyAEQQFxRQRAIAIgC08NAiACQQFqIgMgAkkNAiACIQQgAwwBCyACQQNqIgQgAkkiBQ0BIAQgC0kiA0UNASALIAJBBGogBRsgCyADGwshAiABKAIEIARqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQQMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgAyAEQbT1wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiINIANPBH9BACEDA0AgAyIFQQFqIQMCQCAFIAEoAgRqLQAAQYABRw0AA0AgBiABKAIIIAVB7AFsaiIIKAIAIgQ2AiBCpcaIocicp/lLIQ5BASECA0AgDiAErUL/AYOFQrODgICAIH4hDiACQQRHBEAgBkEgaiACaiACQQFqIQItAAAhBAwBCwsgBSABKAIAIgkgDqciDHEiAmsgASAOEJcBIgcgAmtzIAlxQQRPBEAgASgCBCILIAdqIgItAAAgAiAMQRl2IgI6AAAgCyAJIAdBfGpxakEEaiACOgAAQf8BRgRAIAEoAgQiAiAFakH/AToAACACIAEoAgAgBUF8anFqQQRqQf8BOgAAIAEoAgggB0HsAWxqIAhB7AEQugEaDAMLIAEoAgggB0HsAWxqIQpBACECA0AgAiAKaiIHKQIAIRAgByACIAhqIgkpAgA3AgAgB0EYaiIEKQIAIREgBCAJQRhqIgwpAgA3AgAgB0EQaiIEKQIAIQ8gBCAJQRBqIgspAgA3AgAgB0EIaiIEKQIAIQ4gBCAJQQhqIgQpAgA3AgAgBCAONwIAIAsgDzcCACAMIBE3AgAgCSAQNwIAIAJBQGsgAkEgaiECQe0BSQ0ACyAKKQLgASEOIAogCCkC4AE3AuABIApB6AFqIgIoAgAhBCACIAhB6AFqIgIoAgA2AgAgCCAONwLgASACIAQ2AgAMAQsLIAEoAgQiBCAFaiAMQRl2IgI6AAAgBCAJIAVBfGpxakEEaiACOgAACyADIA1HDQALIAEoAgAiAkEISQ0BIAJBAWoFIA0LQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZB4ABqJAAPC0G0psIAQRxB0KbCABC5AQALjgwBFX8jAEEgayIGJAAgACgCBCEHIAEoAgghCSABKAIAIQoCQAJAAkAgACgCAC0AAARAIAYgBzYCDCAJQRVJDQEgBiAJQQF2EKABIAYoAgQgBigCACEOQQAhASAGQQA2AhggBkIENwMQIApBfGohECAKQXRqIRFBBCEIIAkhBwNAIAchBUEAIQdBASECAkAgBUF/aiIERQ0AAkACQAJAAkACQAJAIAogBEECdGooAgAgBUECdCIHIApqQXhqKAIAIgAQsgFFBEAgBUF+aiEEIAcgEWohAkEAIQdBACEDA0AgAyAERgRAIAUhAgwJCyADQQFqIQMgACACKAIAIgAQsgEgAkF8aiECRQ0ACyADQQFqIQIgA0F/cyAFaiEEDAELIAcgEWohAgNAAkAgBEEBRgRAQQAhBAwBCyAEQX9qIQQgACACKAIAIgAQsgEgAkF8aiECDQELCyAFIARJDQEgBSAJSw0CIAUgBGsiAkEBdiILRQ0AIAogBEECdGohACAHIBBqIQMDQCAAKAIAIQcgACADKAIANgIAIAMgBzYCACAAQQRqIQAgA0F8aiEDIAtBf2oiCw0ACwsgBEUgAkEJS3INBCAFIARrIQIgBEF/aiEAIBAgBEECdGohAwNAIAUgAEkNAyAFIAlLDQIgAyACQQFqIgIQdCAAQX9qIgcgAE8NBCADQXxqIQMgByEAIAJBCkkNAAsMAwsgBCAFEKgBAAsgBSAJEKYBAAsgACAFEKgBAAsgB0EBaiEHDAELIAQhBwsgBigCFCABRgRAIAZBEGogARCLASAGKAIQIQggBigCGCEBCyAIIAFBA3RqIgAgAjYCBCAAIAc2AgAgBiABQQFqIgE2AhgCQCABQQJJDQACQANAAkACQAJAAkAgCCABQX9qQQN0aiIAKAIARQ0AIAFBA3QgCGoiBEF0aigCACIDIAAoAgQiAk0NACABQQJNDQYgCCABQX1qIgVBA3RqKAIEIgAgAiADak0NASABQQNNDQYgBEFkaigCACAAIANqSw0GDAELIAFBA0kNASAAKAIEIQIgCCABQX1qIgVBA3RqKAIEIQALIAAgAkkNAQsgAUF+aiEFCwJAAkAgASAFQQFqIgtLBEAgASAFSwRAIAggBUEDdCITaiIAKAIEIhQgACgCAGoiACAIIAtBA3QiFWoiASgCACIPTwRAIAAgCU0EQCAKIA9BAnRqIgMgASgCBCIEQQJ0IgJqIQEgAEECdCEMAkACQCAAIA9rIg0gBGsiACAESQRAIA4gASAAQQJ0IgIQugEiFiACaiECIARBAUggAEEBSHINASAMIBBqIQADQAJAIAAgAUF8aiIIIAJBfGoiDCAMKAIAIAgoAgAQsgEiDRsoAgA2AgAgAiAMIA0bIQIgAyAIIAEgDRsiAU8NACAAQXxqIQAgAiAWSw0BCwsgBigCECEIDAELIA4gAyACELoBIgAgAmohAiANIARKQQAgBEEBThsNASADIQELIA4hAAwGCyAKIAxqIQ0DQCADIAEgACABKAIAIAAoAgAQsgEiDBsoAgA2AgAgACAAQQRqIAwbIQAgA0EEaiEDIAFBBGogASAMGyIBIA1PDQUgAiAASw0ACwwECyAAIAkQpgEACyAPIAAQqAEAC0Hoq8IAIAUgARCnAQALQeirwgAgCyABEKcBAAsgAyEBCyABIAAgAiAAa0F8cRC6ARogBigCGCIAIAVLBEAgCCATaiIBIAQgFGo2AgQgASAPNgIAIAAgC00NAiAIIBVqIgEgAUEIaiAAIAtBf3NqQQN0EJkBGiAGIABBf2oiATYCGCABQQFNDQMMAQsLQfirwgAgBSAAEKcBAAtBp67CAEEdQcSuwgAQuQEACyAHDQALIAYoAhQEQCAIECELRQ0CIA4QIQwCCyAGIAc2AgwgBiAGQQxqNgIQIAogCSAGQRBqQQBBICAJZ2sQCAwBC0ECIQEgCUECSQ0AIAogCUF+aiIAQQJ0aiECA0AgCSAASQ0CIAIgARB0IAJBfGohAiABQQFqIQEgAEF/aiIAQX9HDQALCyAGQSBqJAAPCyAAIAkQqAEAC88LAgx/An4jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiBCADTwRAIAEoAgAiAiEDAkAgBCACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQAJ/IAQgA0EBaiIDIAQgA0sbIgNFBEBBsKbCACEHQQQhBUEADAELIAZBfwJ/IANBCE8EQCADIANB/////wFxRgRAIANBA3RBB24MAgsMCQsgA0EBagtBf2pndiIIQQFqIgQQowEgBigCBCIFRQ0HIAYoAgghAiAGKAIAIgMgBRDSASIHRQ0CIAdB/wEgCEEFahDAASACaiEFIAggBEEDdkEHbCAIQQhJGwshAiAGIAEoAhAiAzYCECAGIAIgA2s2AgwgBiAFNgIIIAYgBzYCBCAGIAg2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiENIAMoAgBBf3NBgIGChHhxIQsgASgCCCEKA0ACQAJAAkAgCwRAIAIhAwwBCwNAIAIgDU8NAiAKQUBrIQogAigCACACQQRqIgMhAkGAgYKEeHEiBEGAgYKEeEYNAAsgBEGAgYKEeHMhCwtBASECIAYgCiALaEEBdEHwAHFqIgwoAgAiBTYCMCALQX9qIQlCpcaIocicp/lLIQ4DQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQTBqIAJqIAJBAWohAi0AACEFDAALAAsgASkCACEPIAEgBikDADcCACAGQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDzcDACAAQQA2AgAgD6ciAEUNByAGQTBqIABBAWoQowEgBigCBBAhDAcLIAcgBiAOEJcBIgRqIA6nQRl2IgI6AAAgCCAEQXxqcSAHakEEaiACOgAAIAYoAgggBEEEdGoiAiAMKQIANwIAIAJBCGogDEEIaikCADcCACAJIAtxIQsgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAFQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQUgAwwBCyACQQNqIgUgAkkiBA0BIAUgCUkiA0UNASAJIAJBBGogBBsgCSADGwshAiABKAIEIAVqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQUMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgAyAFQbT1wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIEQQFqIQMCQCAEIAEoAgRqLQAAQYABRw0AAkADQCAGIAEoAgggBEEEdGoiBygCACIFNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBmogAkEBaiECLQAAIQUMAQsLIAQgASgCACIKIA6nIg1xIgJrIAEgDhCXASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBBHRqIgIpAgAhDyACIAcpAgA3AgAgAkEIaiICKQIAIQ4gAiAHQQhqIgIpAgA3AgAgByAPNwIAIAIgDjcCAAwBCwsgASgCBCICIARqQf8BOgAAIAIgASgCACAEQXxqcWpBBGpB/wE6AAAgASgCCCAIQQR0aiICIAcpAgA3AgAgAkEIaiAHQQhqKQIANwIADAELIAEoAgQiBSAEaiANQRl2IgI6AAAgBSAKIARBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAELkBAAuqCwILfwN+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgIgA08EQCABKAIAIgMhBAJAIAIgA0EBakEDdkEHbCAEIANBCE8bIgRBAXZLBEACfyACIARBAWoiAyACIANLGyICRQRAQbCmwgAhB0EAIQJBBAwBCyAGQX8CfyACQQhPBEAgAiACQf////8BcUYEQCACQQN0QQduDAILDAkLIAJBAWoLQX9qZ3YiCUEBaiIDEJoBIAYoAgQiAkUNByAGKAIIIQQgBigCACIFIAIQ0gEiB0UNAiAJIANBA3ZBB2wgCUEISRshAiAHQf8BIAlBBWoQwAEgBGoLIQMgBiABKAIQIgQ2AhAgBiACIARrNgIMIAYgAzYCCCAGIAc2AgQgBiAJNgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohCiADKAIAQX9zQYCBgoR4cSEFIAEoAgghAwNAAkAgBUUEQANAIAIgCk8NAiADQeAAaiEDIAIoAgAgAkEEaiIEIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQUgBCECCyAGQqXGiKHInKf5SzcDMCADIAVoQQN2QRhsaiIEKAIAIAQoAgggBkEwahBxIAcgBiAGKQMwIg0QlwEiCGogDadBGXYiCzoAACAJIAhBfGpxIAdqQQRqIAs6AAAgBigCCCAIQRhsaiIIIAQpAgA3AgAgCEEIaiAEKQIINwIAIAhBEGogBEEQaikCADcCACAFQX9qIAVxIQUMAQsLIAEpAgAhDSABIAYpAwA3AgAgBkEIaiICKQMAIQ4gAiABQQhqIgIpAgA3AwAgAiAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA03AwAgAEEANgIAIA2nIgBFDQUgBkEwaiAAQQFqEJoBIAYoAgQQIQwFCyADQQFqIQRBACEDQQAhAgNAAkACfyADQQFxRQRAIAIgBE8NAiACQQFqIgUgAkkNAiACIQMgBQwBCyACQQNqIgMgAkkiBQ0BIAMgBEkiB0UNASAEIAJBBGogBRsgBCAHGwshAiABKAIEIANqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQMMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgBSACQbT1wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiAkEBaiIJIAJPBH9BACEDA0AgAyICQQFqIQMCQCACIAEoAgRqLQAAQYABRw0AAkADQCABKAIIIAZCpcaIocicp/lLNwMAIAJBGGxqIgQoAgAgBCgCCCAGEHEgAiABKAIAIgcgBikDACINpyIIcSIKayABIA0QlwEiBSAKa3MgB3FBBEkNASABKAIEIgogBWoiCy0AACALIAhBGXYiCDoAACAKIAcgBUF8anFqQQRqIAg6AABB/wFHBEAgASgCCCAFQRhsaiIFKQIAIQ0gBSAEKQIANwIAIAVBEGoiBykCACEOIAcgBEEQaiIHKQIANwIAIAVBCGoiBSkCACEPIAUgBEEIaiIFKQIANwIAIAQgDTcCACAHIA43AgAgBSAPNwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIAVBGGxqIgIgBCkCADcCACACQQhqIARBCGopAgA3AgAgAkEQaiAEQRBqKQIANwIADAELIAEoAgQiBCACaiAIQRl2IgU6AAAgBCAHIAJBfGpxakEEaiAFOgAACyADIAlHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAkLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAELkBAAudCwILfwN+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgIgA08EQCABKAIAIgMhBQJAIAIgA0EBakEDdkEHbCAFIANBCE8bIgVBAXZLBEACfyACIAVBAWoiAyACIANLGyICRQRAQbCmwgAhB0EAIQJBBAwBCyAGQX8CfyACQQhPBEAgAiACQf////8BcUYEQCACQQN0QQduDAILDAkLIAJBAWoLQX9qZ3YiCUEBaiIDEJoBIAYoAgQiAkUNByAGKAIIIQUgBigCACIEIAIQ0gEiB0UNAiAJIANBA3ZBB2wgCUEISRshAiAHQf8BIAlBBWoQwAEgBWoLIQMgBiABKAIQIgU2AhAgBiACIAVrNgIMIAYgAzYCCCAGIAc2AgQgBiAJNgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohCiADKAIAQX9zQYCBgoR4cSEEIAEoAgghAwNAAkAgBEUEQANAIAIgCk8NAiADQeAAaiEDIAIoAgAgAkEEaiIFIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQQgBSECCyAGQqXGiKHInKf5SzcDMCADIARoQQN2QRhsaiIFIAZBMGoQdiAHIAYgBikDMCINEJcBIghqIA2nQRl2Igs6AAAgCSAIQXxqcSAHakEEaiALOgAAIAYoAgggCEEYbGoiCCAFKQIANwIAIAhBCGogBUEIaikCADcCACAIQRBqIAVBEGopAgA3AgAgBEF/aiAEcSEEDAELCyABKQIAIQ0gASAGKQMANwIAIAZBCGoiAikDACEOIAIgAUEIaiICKQIANwMAIAIgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiANNwMAIABBADYCACANpyIARQ0FIAZBMGogAEEBahCaASAGKAIEECEMBQsgA0EBaiEFQQAhA0EAIQIDQAJAAn8gA0EBcUUEQCACIAVPDQIgAkEBaiIEIAJJDQIgAiEDIAQMAQsgAkEDaiIDIAJJIgQNASADIAVJIgdFDQEgBSACQQRqIAQbIAUgBxsLIQIgASgCBCADaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEDDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQmQEaDAMLIAQgAkG09cIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgJBAWoiCSACTwR/QQAhAwNAIAMiAkEBaiEDAkAgAiABKAIEai0AAEGAAUcNAAJAA0AgASgCCCAGQqXGiKHInKf5SzcDACACQRhsaiIFIAYQdiACIAEoAgAiByAGKQMAIg2nIghxIgprIAEgDRCXASIEIAprcyAHcUEESQ0BIAEoAgQiCiAEaiILLQAAIAsgCEEZdiIIOgAAIAogByAEQXxqcWpBBGogCDoAAEH/AUcEQCABKAIIIARBGGxqIgQpAgAhDSAEIAUpAgA3AgAgBEEQaiIHKQIAIQ4gByAFQRBqIgcpAgA3AgAgBEEIaiIEKQIAIQ8gBCAFQQhqIgQpAgA3AgAgBSANNwIAIAcgDjcCACAEIA83AgAMAQsLIAEoAgQiByACakH/AToAACAHIAEoAgAgAkF8anFqQQRqQf8BOgAAIAEoAgggBEEYbGoiAiAFKQIANwIAIAJBCGogBUEIaikCADcCACACQRBqIAVBEGopAgA3AgAMAQsgASgCBCIFIAJqIAhBGXYiBDoAACAFIAcgAkF8anFqQQRqIAQ6AAALIAMgCUcNAAsgASgCACICQQhJDQEgAkEBagUgCQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQuQEAC48LAgx/An4jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiBCADTwRAIAEoAgAiAiEDAkAgBCACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQAJ/IAQgA0EBaiIDIAQgA0sbIgNFBEBBsKbCACEHQQQhBUEADAELIAZBfwJ/IANBCE8EQCADIANB/////wFxRgRAIANBA3RBB24MAgsMCQsgA0EBagtBf2pndiIIQQFqIgQQogEgBigCBCIFRQ0HIAYoAgghAiAGKAIAIgMgBRDSASIHRQ0CIAdB/wEgCEEFahDAASACaiEFIAggBEEDdkEHbCAIQQhJGwshAiAGIAEoAhAiAzYCECAGIAIgA2s2AgwgBiAFNgIIIAYgBzYCBCAGIAg2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEMIAMoAgBBf3NBgIGChHhxIQsgASgCCCEKA0ACQAJAAkAgCwRAIAIhAwwBCwNAIAIgDE8NAiAKQSBqIQogAigCACACQQRqIgMhAkGAgYKEeHEiBEGAgYKEeEYNAAsgBEGAgYKEeHMhCwsgBiAKIAtoQThxaiINKAIAIgU2AjAgC0F/aiEJQqXGiKHInKf5SyEOQQEhAgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIAZBMGogAmogAkEBaiECLQAAIQUMAAsACyABKQIAIQ8gASAGKQMANwIAIAZBCGoiAykDACEOIAMgAUEIaiIDKQIANwMAIAMgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiAPNwMAIABBADYCACAPpyIARQ0HIAZBMGogAEEBahCiASAGKAIEECEMBwsgByAGIA4QlwEiBGogDqdBGXYiAjoAACAIIARBfGpxIAdqQQRqIAI6AAAgBigCCCAEQQN0aiANKQIANwIAIAkgC3EhCyADIQIMAAsACyACQQFqIQlBACECA0ACQAJ/IAVBAXFFBEAgAiAJTw0CIAJBAWoiAyACSQ0CIAIhBSADDAELIAJBA2oiBSACSSIEDQEgBSAJSSIDRQ0BIAkgAkEEaiAEGyAJIAMbCyECIAEoAgQgBWoiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhBQwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJkBGgwDCyADIAVBtPXCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIgwgA08Ef0EAIQMDQCADIgRBAWohAwJAIAQgASgCBGotAABBgAFHDQACQANAIAYgASgCCCAEQQN0aiIHKAIAIgU2AgBCpcaIocicp/lLIQ5BASECA0AgDiAFrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAGaiACQQFqIQItAAAhBQwBCwsgBCABKAIAIgogDqciDXEiAmsgASAOEJcBIgggAmtzIApxQQRJDQEgASgCBCIJIAhqIgItAAAgAiANQRl2IgI6AAAgCSAKIAhBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCEEDdGoiAikCACEOIAIgBykCADcCACAHIA5CIIg8AAQgByAOPgIADAELCyABKAIEIgIgBGpB/wE6AAAgAiABKAIAIARBfGpxakEEakH/AToAACABKAIIIAhBA3RqIAcpAgA3AgAMAQsgASgCBCIFIARqIA1BGXYiAjoAACAFIAogBEF8anFqQQRqIAI6AAALIAMgDEcNAAsgASgCACICQQhJDQEgAkEBagUgDAtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQuQEAC6MLAgx/BH4jAEFAaiIEJAACQAJAAkAgASgCECIDQQFqIgUgA08EQCABKAIAIgIhAyAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAIAQgBSADQQFqIgMgBSADSxsQUyAEQQhqKAIAIQcgBCgCBCEKIAQoAgBBAUYEQCAAIAo2AgQgAEEBNgIAIABBCGogBzYCAAwFCyAEQRBqKAIAIQUgBEEMaigCACECIAQgASgCECIDNgIQIAQgBSADazYCDCAEIAI2AgggBCAHNgIEIAQgCjYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQ0gAygCAEF/c0GAgYKEeHEhDCABKAIIIQsDQAJAAkACQCAMBEAgAiEDDAELA0AgAiANTw0CIAtBoAFqIQsgAigCACACQQRqIgMhAkGAgYKEeHEiBUGAgYKEeEYNAAsgBUGAgYKEeHMhDAsgBCALIAxoQQN2QShsaiIIKAIAIgY2AjAgDEF/aiEJQqXGiKHInKf5SyEOQQEhAgNAIA4gBq1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIARBMGogAmogAkEBaiECLQAAIQYMAAsACyABKQIAIQ8gASAEKQMANwIAIARBCGoiAykDACEOIAMgAUEIaiIDKQIANwMAIAMgDjcCACAEQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBCAPNwMAIABBADYCACAPpyIARQ0GIARBMGogAEEBahCcASAEKAIEECEMBgsgByAEIA4QlwEiBWogDqdBGXYiAjoAACAKIAVBfGpxIAdqQQRqIAI6AAAgBCgCCCAFQShsaiAIQSgQugEaIAkgDHEhDCADIQIMAAsACyACQQFqIQlBACECA0ACQAJ/IAZBAXFFBEAgAiAJTw0CIAJBAWoiAyACSQ0CIAIhBiADDAELIAJBA2oiBiACSSIFDQEgBiAJSSIDRQ0BIAkgAkEEaiAFGyAJIAMbCyECIAEoAgQgBmoiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhBgwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0BIAJBBGogAiADEJkBGgwCC0G0psIAQRxB0KbCABC5AQALIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDSADTwR/QQAhAwNAIAMiBUEBaiEDAkAgBSABKAIEai0AAEGAAUcNAAJAA0AgBCABKAIIIAVBKGxqIgcoAgAiBjYCAEKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCACIARqIAJBAWohAi0AACEGDAELCyAFIAEoAgAiCyAOpyIIcSICayABIA4QlwEiCiACa3MgC3FBBEkNASABKAIEIgkgCmoiAi0AACACIAhBGXYiAjoAACAJIAsgCkF8anFqQQRqIAI6AABB/wFHBEAgASgCCCAKQShsaiIIKQMAIRAgCCAHKQMANwMAIAhBGGoiAikDACERIAIgB0EYaiIJKQMANwMAIAhBEGoiAikDACEPIAIgB0EQaiIGKQMANwMAIAhBCGoiAikDACEOIAIgB0EIaiICKQMANwMAIAcgEDcDACACIA43AwAgBiAPNwMAIAkgETcDACAIQSBqIgIpAgAhDiACIAdBIGoiAikCADcCACACIA43AgAMAQsLIAEoAgQiAiAFakH/AToAACACIAEoAgAgBUF8anFqQQRqQf8BOgAAIAEoAgggCkEobGogB0EoELoBGgwBCyABKAIEIgYgBWogCEEZdiICOgAAIAYgCyAFQXxqcWpBBGogAjoAAAsgAyANRw0ACyABKAIAIgJBCEkNASACQQFqBSANC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAEQUBrJAAL6AoCC38CfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiICIANPBEAgASgCACIDIQUCQCACIANBAWpBA3ZBB2wgBSADQQhPGyIFQQF2SwRAAn8gAiAFQQFqIgMgAiADSxsiAkUEQEGwpsIAIQdBACECQQQMAQsgBkF/An8gAkEITwRAIAIgAkH/////AXFGBEAgAkEDdEEHbgwCCwwJCyACQQFqC0F/amd2IglBAWoiAxCjASAGKAIEIgJFDQcgBigCCCEFIAYoAgAiBCACENIBIgdFDQIgCSADQQN2QQdsIAlBCEkbIQIgB0H/ASAJQQVqEMABIAVqCyEDIAYgASgCECIFNgIQIAYgAiAFazYCDCAGIAM2AgggBiAHNgIEIAYgCTYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQogAygCAEF/c0GAgYKEeHEhBCABKAIIIQMDQAJAIARFBEADQCACIApPDQIgA0FAayEDIAIoAgAgAkEEaiIFIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQQgBSECCyAGQqXGiKHInKf5SzcDMCADIARoQQF0QfAAcWoiBSgCACAFKAIIIAZBMGoQcSAHIAYgBikDMCINEJcBIghqIA2nQRl2Igs6AAAgCSAIQXxqcSAHakEEaiALOgAAIAYoAgggCEEEdGoiCCAFKQIANwIAIAhBCGogBSkCCDcCACAEQX9qIARxIQQMAQsLIAEpAgAhDSABIAYpAwA3AgAgBkEIaiICKQMAIQ4gAiABQQhqIgIpAgA3AwAgAiAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA03AwAgAEEANgIAIA2nIgBFDQUgBkEwaiAAQQFqEKMBIAYoAgQQIQwFCyADQQFqIQVBACEDQQAhAgNAAkACfyADQQFxRQRAIAIgBU8NAiACQQFqIgQgAkkNAiACIQMgBAwBCyACQQNqIgMgAkkiBA0BIAMgBUkiB0UNASAFIAJBBGogBBsgBSAHGwshAiABKAIEIANqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQMMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgBCACQbT1wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiAkEBaiIJIAJPBH9BACEDA0AgAyICQQFqIQMCQCACIAEoAgRqLQAAQYABRw0AAkADQCABKAIIIAZCpcaIocicp/lLNwMAIAJBBHRqIgUoAgAgBSgCCCAGEHEgAiABKAIAIgcgBikDACINpyIIcSIKayABIA0QlwEiBCAKa3MgB3FBBEkNASABKAIEIgogBGoiCy0AACALIAhBGXYiCDoAACAKIAcgBEF8anFqQQRqIAg6AABB/wFHBEAgASgCCCAEQQR0aiIEKQIAIQ0gBCAFKQIANwIAIARBCGoiBCkCACEOIAQgBUEIaiIEKQIANwIAIAUgDTcCACAEIA43AgAMAQsLIAEoAgQiByACakH/AToAACAHIAEoAgAgAkF8anFqQQRqQf8BOgAAIAEoAgggBEEEdGoiAiAFKQIANwIAIAJBCGogBUEIaikCADcCAAwBCyABKAIEIgUgAmogCEEZdiIEOgAAIAUgByACQXxqcWpBBGogBDoAAAsgAyAJRw0ACyABKAIAIgJBCEkNASACQQFqBSAJC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC5AQALoQoCDH8CfiMAQUBqIgQkAAJAAkACQCABKAIQIgNBAWoiBSADTwRAIAEoAgAiAiEDIAUgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEAgBCAFIANBAWoiAyAFIANLGxBSIARBCGooAgAhByAEKAIEIQggBCgCAEEBRgRAIAAgCDYCBCAAQQE2AgAgAEEIaiAHNgIADAULIARBEGooAgAhBSAEQQxqKAIAIQIgBCABKAIQIgM2AhAgBCAFIANrNgIMIAQgAjYCCCAEIAc2AgQgBCAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDCADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIAxPDQIgCkEgaiEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQsLIAQgCiALaEE4cWoiDSgCACIGNgIwIAtBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAEQTBqIAJqIAJBAWohAi0AACEGDAALAAsgASkCACEPIAEgBCkDADcCACAEQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBEEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAQgDzcDACAAQQA2AgAgD6ciAEUNBiAEQTBqIABBAWoQogEgBCgCBBAhDAYLIAcgBCAOEJcBIgVqIA6nQRl2IgI6AAAgCCAFQXxqcSAHakEEaiACOgAAIAQoAgggBUEDdGogDSkCADcCACAJIAtxIQsgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAGQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQYgAwwBCyACQQNqIgYgAkkiBQ0BIAYgCUkiA0UNASAJIAJBBGogBRsgCSADGwshAiABKAIEIAZqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQYMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NASACQQRqIAIgAxCZARoMAgtBtKbCAEEcQdCmwgAQuQEACyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIgwgA08Ef0EAIQMDQCADIgVBAWohAwJAIAUgASgCBGotAABBgAFHDQACQANAIAQgASgCCCAFQQN0aiIHKAIAIgY2AgBCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAEaiACQQFqIQItAAAhBgwBCwsgBSABKAIAIgogDqciDXEiAmsgASAOEJcBIgggAmtzIApxQQRJDQEgASgCBCIJIAhqIgItAAAgAiANQRl2IgI6AAAgCSAKIAhBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCEEDdGoiAikCACEOIAIgBykCADcCACAHIA5CIIg+AgQgByAOPgIADAELCyABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIAhBA3RqIAcpAgA3AgAMAQsgASgCBCIGIAVqIA1BGXYiAjoAACAGIAogBUF8anFqQQRqIAI6AAALIAMgDEcNAAsgASgCACICQQhJDQEgAkEBagUgDAtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBEFAayQAC7wJAhh/AX4jAEEwayIDJAAgAkE4aigCACACQTBqKAIAIQggAkEsaigCACACQQxqKAIAIQsgAigCNCEVIAIoAighDiACKAIAIRYgAigCBCEKIAEoAgAiASgCCCEPIAEoAgAhECADQQhqIAJBEGooAgAiByACQQhqKAIAIgIgASgCBCIRIAEoAgwiEmpBAWpsakECdBCxASADQQA2AiggAyADKQMINwMgAkACQCACBEAgCiACQRRsaiEXIAhBBXQhDCAOQXRqIQ0gDyASQQJ0aiEYIBAgEUECdGohGUEAIQgCQAJAAkACQAJAAkADQCAIIgZBAWohCCAKIglBFGohCiAMIQIgDSEEIA4hAQJAAn8CQANAIAJFDQEgAkFgaiECIARBIGohBCABKAIIIRogAUEgaiEBIAYgGkcNAAsgCSgCBCICIAQoAgBqIgEgBUkNBSAHIAFJDQYgBEEEaigCACEEIANBIGogAygCKCABIAVrEJEBIAsgBUECdGogCyABQQJ0aiIFIANBIGoQSyADQSBqIAMoAiggERCRASAQIBkgA0EgahBLIAIgBGoiBiABSQ0HIAcgBkkNCCADQSBqIAMoAiggBiABaxCRASAFIAsgBkECdGoiBCADQSBqEEsgA0EgaiADKAIoIBIQkQEgDyAYIANBIGoQSyAJKAIIIgIgBkkNCSAHIAJJDQQgAiAGawwBCyAJKAIIIgIgBUkNASAHIAJJDQMgCyAFQQJ0aiEEIAIgBWsLIQEgA0EgaiADKAIoIAEQkQEgBCALIAJBAnRqIANBIGoQSyAJKAIIIQUgCiAXRg0JDAELCyAFIAIQqAEACyACIAcQpgEACyAFIAEQqAEACyABIAcQpgEACyABIAYQqAEACyAGIAcQpgEACyAGIAIQqAEAC0EAIQIMAQsgByAFTwRAIAMoAighAgwBCyAFIAcQqAEACyADQSBqIAIgByAFaxCRASALIAVBAnRqIAsgB0ECdGogA0EgahBLAkAgAygCKCINRQ0AQQAhAiADKAIgIQZBACEJA0ACQAJAAkAgAiAGaiIMLAAAIghBf0oEQCAIQf8BcSEEDAELAn8gBiANaiIBIAxBAWpGBEBBACEKIAEMAQsgDC0AAUE/cSEKIAxBAmoLIQQgCEEfcSEFAkACQCAIQf8BcSIHQd8BTQRAIAogBUEGdHIhBAwBCwJ/IAEgBEYEQCABIQhBAAwBCyAEQQFqIQggBC0AAEE/cQsgCkEGdHIhBCAHQfABSQRAIAQgBUEMdHIhBAwBCyABIAhGBH9BAAUgCC0AAEE/cQsgBUESdEGAgPAAcSAEQQZ0cnIiBEGAgMQARg0BCyAEQYABSQ0BQQIhASAEQYAQSQ0CQQNBBCAEQYCABEkbIQEMAgtBlO7CAEErQcDuwgAQuQEAC0EBIQEgBA0AIAlBAWohCQwBCyAJRQRAQQAhCQwBCyAGIAIgCWtqIAwgARCZARoLIA0gASACaiICSw0ACyAJRQ0AIAMgDSAJazYCKAsgA0EYaiADQShqKAIAIgE2AgAgAyADKQMgIhs3AxAgACAWNgIAIAAgGzcCBCAAQQxqIAE2AgAEQCAOECELBEAgFRAhCyADQTBqJAALyQoBDX8jAEGgAmsiByQAIAcgAjYCQCAHIAI2AjwgByABNgI4IAdBKGogB0E4ahCGASAHKAIoIQEgBygCLCEKIAcgBDYCQCAHIAQ2AjwgByADNgI4IAdBIGogB0E4ahCOASAHKAIkIQkgBygCICENIAcgBjYCQCAHIAY2AjwgByAFNgI4IAdBGGogB0E4ahCGASAHKAIcIQ4gBygCGCELAkACQAJAAkAQwgEiBARAIAcgBy8BNDsBNiAEKAIADQIgBEF/NgIAIARBBGogABBVIgxFDQMgDBCwASAHQeQAakEBOwEAIAdB4ABqIg9BADYCACAHQdgAakKAgICAEDcDACAHQdQAaiAJNgIAIAdB0ABqQQA2AgAgB0HMAGogCTYCACAHQcgAaiANNgIAIAdBxABqIAk2AgAgB0H0AGogCyAOQQJ0ajYCACAHQeYAaiAHLwE2OwEAIAdBADYCQCAHIAEgCkECdGo2AjwgByABNgI4IAdCADcDeCAHIAs2AnAgB0IANwNoIApFDQEgDEEUaiEDIAdBQGshECAHIAFBBGo2AjggB0GIAWohEUEAIQIgASEGA0AgAkH/AXENAiAGKAIAIRICfwJAAkAgBygCVCIAIAcoAlAiAkkNACAHKAJMIABJDQAgB0EQaiAHKAJcIBBqQR9qLQAAIAcoAkgiCCACaiAAIAJrEEMgBygCEEEBRgRAIAcoAhQhBgNAIAcgBiAHKAJQakEBaiIANgJQAkAgACAHKAJcIgJJBEAgBygCTCEFDAELIAcoAkwiBSAASQ0AIAJBBU8NCyAAIAJrIgYgBygCSGoiEyAPRg0EIBMgDyACELUBRQ0ECyAHKAJUIgYgAEkgBSAGSXINAiAHQQhqIAIgEGpBH2otAAAgBygCSCAAaiAGIABrEEMgBygCDCEGIAcoAghBAUYNAAsLIAcgBygCVDYCUAsgBy0AZQ0EAkAgBy0AZARAIAcoAkQhAiAHKAJAIQYMAQsgBygCRCICIAcoAkAiBkYNBQsgB0EBOgBlIAIgBmshACAHKAJIIAZqDAELIAcoAkAhAiAHIAA2AkAgBiACayEAIAIgCGoLIQIgBygCcCIFIAcoAnRGDQIgByAFQQRqNgJwIAUoAgAhBSAHQcABaiACIAAgAmoQIyAHQfABaiAHQcABahBgIAdBwAFqIAdB8AFqIAMQPCAHQfABaiAHQcABaiADECYgB0HAAWogB0HwAWogAxAgIAdB8AFqIAdBwAFqEDogBygC+AEiAARAIAcoAvABIQYgAEEUbCECIAcoApACIQAgBygCiAIhCANAIAYgCCAAIAMQSCAGQRRqIQYgAkFsaiICDQALCyAHQcABaiAHQfABakEwELoBGiAHQfABaiAHQcABaiADEFAgBygC+AEiAARAIAcoAvABIQYgAEEUbCECIAcoApACIQAgBygCiAIhCANAIAYgCCAAIAMQRyAGQRRqIQYgAkFsaiICDQALCyAHQcABaiAHQfABakEwELoBGiAHIBI2AoQBIAdBADYCgAEgESAHQcABakEwELoBGiAHIAU2ArgBIAwgB0GAAWoQFyAHKAI4IgYgBygCPEYNAiAHIAZBBGo2AjggBy0AZSECDAALAAtBmL3CAEHGACAHQThqQYC+wgAQlgEACyAEIAQoAgBBAWo2AgAgCQRAIA0QIQsgDkH/////A3EEQCALECELIApB/////wNxBEAgARAhCyAHQaACaiQADwtB3r3CAEEQIAdBOGpB8L3CABCWAQALQZTuwgBBK0HA7sIAELkBAAsgAkEEEKYBAAuUCgIUfwF+IwBB4ABrIgMkACABIAAoAgAiDTYCAAJAIAAoArABRQRAIABBfzYCsAEgA0EcaiABQTRqKAIANgIAIANBFGogAUEoaigCADYCACADQQxqIAFBHGooAgA2AgAgAyABQSxqKAIANgIYIAMgAUEgaigCADYCECADIAFBFGooAgA2AgggAyABQRBqKAIANgIEIAMgASgCCDYCACADQUBrIAMQHyAAQbQBaiICIAIoAgBBAWo2AgAgAygCQCEKIAMoAkgiAkUNASAKIAJBDGxqIRIgAEG4AWohDyAAQbwBaiETIABBxAFqIRAgAEHAAWohFCAAQcgBaiERIAohCQNAIANB2ABqIAlBCGooAgA2AgAgAyAJKQIANwNQIANCpcaIocicp/lLNwMAIANB0ABqIAMQdiADKQMAIhZCGYinQf8AcSIEQQh0IARyIgJBEHQgAnIhCyAJQQxqIQkgACgCuAEiByAWp3EhAiAAKALAASEOIAAoArwBIRVBACEIAkACQANAAkAgAiAVaigAACIMIAtzIgZBf3MgBkH//ft3anFBgIGChHhxIgZFDQADQAJAIA4gBmhBA3YgAmogB3FBGGxqIgUgA0HQAGpGBEAgA0HQAGohBQwBCyAFIANB0ABqQQwQtQFFDQAgBkF/aiAGcSIGDQEMAgsLIAVBDGohCCAFKAIUIQRBACEHQQAhAgNAAkAgBCACSwRAIAJBAWoiBiACTw0BCyAFKAIQIARGBEAgCCAEQQEQgwEgBSgCFCEECyAFKAIMIARBAnRqIA02AgAgBSAFKAIUQQFqNgIUDAQLIAgoAgAiDCAHaiELIAdBBGohByAGIQICQEF/IA0gCygCACILRyANIAtJGw4CBAEACwsgBCAGQX9qTwRAIAUoAhAgBEYEfyAIIARBARCDASAIKAIABSAMCyAHaiICIAJBfGoiAiAEQQJ0IAdrQQRqEJkBGiACIA02AgAgBSAEQQFqNgIUDAMLQeStwgBBHkGErsIAELkBAAsgCEEEaiIIIAJqIAdxIQIgDCAMQQF0cUGAgYKEeHFFDQALIAAoAsQBRQRAIAMgDxAQCyADKAJYIQUgAygCVCEHIAMoAlAhCEEEQQQQ0gEiBkUNASAGIA02AgAgECAQKAIAIBMoAgAiDCAPIBYQlwEiAmoiCy0AAEEBcWs2AgAgFCgCACALIAQ6AAAgDCAAKAK4ASACQXxqcWpBBGogBDoAACACQRhsaiICQoGAgIAQNwIQIAIgBjYCDCACIAU2AgggAiAHNgIEIAIgCDYCACARIBEoAgBBAWo2AgALIAkgEkcNAQwDCwtBBEEEQbT1wgAoAgAiAEECIAAbEQAAAAtB3r3CAEEQIANBuKrCABCWAQALIAMoAkQEQCAKECELIAAgACgCsAFBAWo2ArABIAMgAUE8ELoBIQkCQCAAQQxqKAIAIgEgAEEIaigCAEYEQAJAAkAgAUEBaiICIAFJDQAgAUEBdCIKIAIgCiACSxsiCq1CPH4iFkIgiKcNACAWpyICQQBODQELEM4BAAsCfyABRQRAIAJBBBDSAQwBCyAAKAIEIAFBPGxBBCACEM8BCyIBRQ0BIAAgCjYCCCAAIAE2AgQgACgCDCEBCyAAKAIEIAFBPGxqIAlBPBC6ARogACAAKAIMQQFqNgIMIAAgACgCAEEBajYCACAJQeAAaiQADwsgAkEEQbT1wgAoAgAiAEECIAAbEQAAAAvtCAIMfwF+IwBB0ANrIgQkAAJAEMIBIgMEQAJAIAMoAgBFBEAgA0F/NgIAIANBBGogABBeBEAgBCAANgIIQqXGiKHInKf5SyENQQEhAiAAIQEDQCANIAGtQv8Bg4VCs4OAgIAgfiENIAJBBEZFBEAgBEEIaiACaiACQQFqIQItAAAhAQwBCwsgDaciAUEZdiICQQh0IAJyIgJBEHQgAnIhCSADKAIEIgYgAXEhAiADKAIMIQogAygCCCEIA0ACQAJAIAIgCGooAAAiByAJcyIBQX9zIAFB//37d2pxQYCBgoR4cSIBRQ0AA0AgACAKIAFoQQN2IAJqIAZxIgtB7AFsaiIMKAIARwRAIAFBf2ogAXEiAQ0BDAILC0GAASECIAggC0HsAWwiAUHsAW0iBWoiBygAACIJIAlBAXRxQYCBgoR4cWhBA3YgCCAFQXxqIAZxaiIFKAAAIgYgBkEBdHFBgIGChHhxZ0EDdmpBA00EQCADIAMoAhBBAWo2AhBB/wEhAgsgByACOgAAIAVBBGogAjoAACADIAMoAhRBf2o2AhQgASAKaiICKAIIIQEgDCgCBCEFIARB8AFqIAJBDGpB4AEQugEaIAFFBEBBACEBDAILIAQgBTYCCCAEQRBqIARB8AFqQeABELoBGgwBCyAFQQRqIgUgAmogBnEhAkEAIQEgByAHQQF0cUGAgYKEeHFFDQELCyAEIAE2AgwgBEEIahB3IAMgAygCAEEBajYCAAJAAkAQwQEiAwRAIAMoAgANAiADQX82AgAgA0EEaiAAEF1FDQEgBCAANgIIQqXGiKHInKf5SyENQQEhAiAAIQEDQCANIAGtQv8Bg4VCs4OAgIAgfiENIAJBBEZFBEAgBEEIaiACaiACQQFqIQItAAAhAQwBCwsgDaciAUEZdiICQQh0IAJyIgJBEHQgAnIhCSADKAIEIgYgAXEhAiADKAIMIQogAygCCCEIQQAhBQJAA0ACQAJAIAIgCGooAAAiByAJcyIBQX9zIAFB//37d2pxQYCBgoR4cSIBRQ0AA0AgACAKIAFoQQN2IAJqIAZxIgtBBHRqKAIARwRAIAFBf2ogAXEiAQ0BDAILC0GAASECIAggC0EEdCIAQQR1IgFqIgUoAAAiByAHQQF0cUGAgYKEeHFoQQN2IAggAUF8aiAGcWoiASgAACIGIAZBAXRxQYCBgoR4cWdBA3ZqQQNNBEAgAyADKAIQQQFqNgIQQf8BIQILIAUgAjoAACABQQRqIAI6AAAgAyADKAIUQX9qNgIUIAAgCmoiACgCBCIBRQ0BIAQgACkCCDcCDCAEIAE2AggMAwsgBUEEaiIFIAJqIAZxIQIgByAHQQF0cUGAgYKEeHFFDQELCyAEQQA2AggLIARBCGoQpQEgAyADKAIAQQFqNgIAIARB0ANqJAAPCwwECxDRAQALDAQLENEBAAsMAgsLQZi9wgBBxgAgBEEIakHcqMIAEJYBAAtB3r3CAEEQIARBCGpBzKjCABCWAQALxwkCDX8DfiMAQbAJayIFJAAgBSAAQYgBELoBIQECQAJAAkBBlL3CACgCABEIACIDBEAgASgCACEAIAFBiAFqIAFBBHJBhAEQugEaIAMoAgANAiADQX82AgAgA0EEaiIJIAAoAgAQXg0BIAFByAdqEGJBBEEEENIBIgVFDQMgBUHbADYCAEEEQQQQ0gEiAkUNAyACQd0ANgIAIAFBmAJqQgA3AwAgAUEKNgKgAiABQoCAgIDAADcDkAIgAUGkAmogAUHIB2pBhAEQugEhBiABQeADakIANwMAIAFB2ANqQoCAgIDAADcDACABQdADakIENwMAIAFBzANqQbCmwgA2AgAgAUHIA2pBADYCACABQbgDakKBgICAEDcDACABQbQDaiACNgIAIAFBrANqQoGAgIAQNwIAIAFB6ANqQgA3AwAgAUIANwPAAyABIAU2AqgDIAYQjwEgBiABQYgBakGEARC6ARogACgCACEFIAFB4AVqIAFBkAJqQegBELoBGiABIAU2AsgHQqXGiKHInKf5SyEOQQEhAiAFIQADQCAOIACtQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCABQcgHaiACaiACQQFqIQItAAAhAAwBCwsgDkIZiKciDEH/AHEiAEEIdCAAciIAQRB0IAByIQ0gAygCBCIIIA6ncSECIAMoAgwhBiADKAIIIQACQANAAkAgACACaigAACIKIA1zIgRBf3MgBEH//ft3anFBgIGChHhxIgRFDQADQCAFIAYgBGhBA3YgAmogCHEiC0HsAWxqKAIARwRAIARBf2ogBHEiBA0BDAILCyABQcgHaiABQeAFakHoARC6ARogBiALQewBbGpBBGohCEEAIQIDQCACIAhqIgApAgAhDiAAIAFByAdqIAJqIgUpAgA3AgAgBSAONwIAIABBCGoiBCkCACEOIAQgBUEIaiIEKQIANwIAIABBEGoiBykCACEPIAcgBUEQaiIHKQIANwIAIABBGGoiACkCACEQIAAgBUEYaiIAKQIANwIAIAAgEDcCACAHIA83AgAgBCAONwIAIAJBQGsgAkEgaiECQekBSQ0ACyAGIAtB7AFsaiIAKQLkASEOIAAgAUGoCWoiACkDADcC5AEgACAONwMAIAFB+ANqIAFByAdqQegBELoBGgwCCyAHQQRqIgcgAmogCHEhAiAKIApBAXRxQYCBgoR4cUUNAAsgAUHIB2ogAUHgBWpB6AEQugEaIAAgCSAOEJcBIgJqLQAAQQFxIQQgAygCECIHIARFckUEQCABQfgDaiAJEAwgCSAOEJcBIQIgAygCBCEIIAMoAgwhBiADKAIQIQcgAygCCCEACyADIAcgBGs2AhAgACACaiAMQf8AcSIEOgAAIAggAkF8anEgAGpBBGogBDoAACAGIAJB7AFsaiIAIAU2AgAgAEEEaiABQcgHakHoARC6ARogAyADKAIUQQFqNgIUIAFBADYC/AMLIAFB+ANqEHcgAyADKAIAQQFqNgIAIAFBsAlqJAAPCyABQQRyEI8BQZi9wgBBxgAgAUHIB2pB3KjCABCWAQALENEBAAtB3r3CAEEQIAFByAdqQcyowgAQlgEAC0EEQQRBtPXCACgCACIAQQIgABsRAAAAC64IAQZ/IwBB8ABrIgQkACAEIAM2AgwgBCACNgIIQQEhCCABIQYCQCABQYECSQ0AQQAgAWshB0GAAiEFA0ACQCAFIAFPDQAgACAFaiwAAEG/f0wNAEEAIQggBSEGDAILIAVBf2ohBkEAIQggBUEBRg0BIAUgB2ogBiEFQQFHDQALCyAEIAY2AhQgBCAANgIQIARBAEEFIAgbNgIcIARBjurCAEHOg8AAIAgbNgIYAkACQAJAIAIgAUsiBSADIAFLckUEQCACIANLDQECQCACRSABIAJGckUEQCABIAJNDQEgACACaiwAAEFASA0BCyADIQILIAQgAjYCICACRSABIAJGcg0CIAFBAWohAwNAIAIgAUkEQCAAIAJqLAAAQUBODQQLIAJBf2ohBSACQQFGDQQgAiADRiAFIQJFDQALDAMLIAQgAiADIAUbNgIoIARBxABqQQM2AgAgBEHcAGpBBDYCACAEQdQAakEENgIAIARCAzcCNCAEQdSDwAA2AjAgBEEFNgJMIAQgBEHIAGo2AkAgBCAEQRhqNgJYIAQgBEEQajYCUCAEIARBKGo2AkggBEEwakHsg8AAEL8BAAsgBEHkAGpBBDYCACAEQdwAakEENgIAIARB1ABqQQU2AgAgBEHEAGpBBDYCACAEQgQ3AjQgBEH8g8AANgIwIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCYCAEIARBEGo2AlggBCAEQQxqNgJQIAQgBEEIajYCSCAEQTBqQZyEwAAQvwEACyACIQULAkAgASAFRg0AQQEhBgJAAkACQCAAIAVqIgcsAAAiAkF/TARAQQAhCCAAIAFqIgMhASADIAdBAWpHBEAgBy0AAUE/cSEIIAdBAmohAQsgAkEfcSEHIAJB/wFxQd8BSw0BIAggB0EGdHIhAQwCCyAEIAJB/wFxNgIkIARBKGohAgwCC0EAIQAgAyEGIAEgA0cEfyABQQFqIQYgAS0AAEE/cQUgAAsgCEEGdHIhACACQf8BcUHwAUkEQCAAIAdBDHRyIQEMAQtBACECIAMgBkcEfyAGLQAAQT9xBSACCyAHQRJ0QYCA8ABxIABBBnRyciIBQYCAxABGDQILIAQgATYCJEEBIQYgBEEoaiECIAFBgAFJDQBBAiEGIAFBgBBJDQBBA0EEIAFBgIAESRshBgsgBCAFNgIoIAQgBSAGajYCLCAEQcQAakEFNgIAIARB7ABqQQQ2AgAgBEHkAGpBBDYCACAEQdwAakEGNgIAIARB1ABqQQc2AgAgBEIFNwI0IARBrITAADYCMCAEIAI2AlggBEEFNgJMIAQgBEHIAGo2AkAgBCAEQRhqNgJoIAQgBEEQajYCYCAEIARBJGo2AlAgBCAEQSBqNgJIIARBMGpB1ITAABC/AQALQZTuwgBBK0HA7sIAELkBAAulCAEIfyMAQUBqIgMkACADQSRqIAE2AgAgA0E0aiACQRRqKAIAIgQ2AgAgA0EDOgA4IANBLGogAigCECIFIARBA3RqNgIAIANCgICAgIAENwMIIAMgADYCICADQQA2AhggA0EANgIQIAMgBTYCMCADIAU2AigCQAJAAkACQCACKAIIIgZFBEAgAigCACEIIAIoAgQiCSAEIAQgCUsbIgZFDQFBASEEIAAgCCgCACAIKAIEIAEoAgwRAwANBCAIQQhqIQJBASEHA0AgBSgCACADQQhqIAVBBGooAgARAQAEQAwGCyAHIAZPDQIgAkEEaiEAIAIoAgAhASAFQQhqIQUgAkEIaiECIAdBAWohByADKAIgIAEgACgCACADKAIkKAIMEQMARQ0ACwwECyACKAIAIQggAigCBCIJIAJBDGooAgAiAiACIAlLGyIKRQ0AQQEhBCAAIAgoAgAgCCgCBCABKAIMEQMADQMgBkEQaiEFIAhBCGohAkEBIQcDQCADIAVBeGooAgA2AgwgAyAFQRBqLQAAOgA4IAMgBUF8aigCADYCCEEAIQFBACEEAkACQAJAAkAgBUEIaigCAEEBaw4DAQIDAAsgBUEMaigCACEAQQEhBAwCCyAFQQxqKAIAIgYgAygCNCIESQRAQQAhBCADKAIwIAZBA3RqIgYoAgRBCEcNAiAGKAIAKAIAIQBBASEEDAILQcSFwAAgBiAEEKcBAAsgAygCKCIGIAMoAixGDQAgAyAGQQhqNgIoIAYoAgRBCEcNACAGKAIAKAIAIQBBASEECyADIAA2AhQgAyAENgIQAkACfwJAAkACQAJAAkAgBSgCAEEBaw4DAQAGBAsgAygCKCIAIAMoAixHDQEMBQsgBUEEaigCACIAIAMoAjQiBE8NASADKAIwIABBA3RqIgAoAgRBCEcNBCAAKAIAKAIADAMLIAMgAEEIajYCKCAAKAIEQQhHDQMgACgCACgCAAwCC0HEhcAAIAAgBBCnAQALIAVBBGooAgALIQRBASEBCyADIAQ2AhwgAyABNgIYAkAgBUFwaigCAEEBRwRAIAMoAigiBCADKAIsRg0EIAMgBEEIajYCKAwBCyAFQXRqKAIAIgAgAygCNCIBTw0EIAMoAjAgAEEDdGohBAsgBCgCACADQQhqIARBBGooAgARAQAEQEEBIQQMBQsgByAKTw0BIAJBBGohACACKAIAIQEgBUEkaiEFIAJBCGohAkEBIQQgB0EBaiEHIAMoAiAgASAAKAIAIAMoAiQoAgwRAwBFDQALDAMLIAkgB0sEQEEBIQQgAygCICAIIAdBA3RqIgAoAgAgACgCBCADKAIkKAIMEQMADQMLQQAhBAwCC0GU7sIAQStBwO7CABC5AQALQdSFwAAgACABEKcBAAsgA0FAayQAIAQL4AcCD38BfiMAQaACayICJAAgAkGYAWoQYiACQQE2ApgBQZyywgAhAQNAIAJBmAFqIAEoAgAgAUEEaigCACABQQhqKAIAIAFBDGooAgAQgQEgAUEQaiIBQfyzwgBHDQALQfyzwgAhAQNAIAJBmAFqIAEoAgAgAUEEaigCACABQQhqKAIAIAFBDGooAgAQggEgAUEQaiIBQdy1wgBHDQALQdy1wgAhAQNAIAJBmAFqIAFBBGooAgAgAUEIaigCACABLQAAEGkgAUEMaiIBQfy6wgBHDQALIAJBmAFqQQRyIQVByK/CACEBA0AgBSABQQRqKAIAIAEtAAAQOyABQQhqIgFBmLHCAEcNAAsgAkEQaiACQZgBakGEARC6ARogAiAANgKUASACIAJBlAFqNgKYASACQZgBakEEciACQRBqQYQBELoBGiACQZgBahAZEMEBIgMEQCADKAIARQRAIANBfzYCACADQQRqIgkgAigClAEiBRBdRQRAIAJBCGpBChCfASACKAIMIQogAigCCCELIAIgBTYCmAFCpcaIocicp/lLIRBBASEBIAUhAANAIBAgAK1C/wGDhUKzg4CAgCB+IRAgAUEERkUEQCACQZgBaiABaiABQQFqIQEtAAAhAAwBCwsgEEIZiKciDUH/AHEiAEEIdCAAciIAQRB0IAByIQ4gAygCBCIHIBCncSEBIAMoAgwhCCADKAIIIQACQANAAkAgACABaigAACIMIA5zIgRBf3MgBEH//ft3anFBgIGChHhxIgRFDQADQCAFIAggBGhBA3YgAWogB3EiD0EEdGooAgBHBEAgBEF/aiAEcSIEDQEMAgsLIAJBoAFqIAggD0EEdGoiACgCDDYCACAAQQA2AgwgACkCBCEQIAAgCjYCCCAAIAs2AgQgAiAQNwOYAQwCCyAGQQRqIgYgAWogB3EhASAMIAxBAXRxQYCBgoR4cUUNAAsgACAJIBAQlwEiAWotAABBAXEhBCADKAIQIgYgBEVyRQRAIAJBmAFqIAkQDiAJIBAQlwEhASADKAIEIQcgAygCDCEIIAMoAhAhBiADKAIIIQALIAMgBiAEazYCECAAIAFqIA1B/wBxIgQ6AAAgByABQXxqcSAAakEEaiAEOgAAIAggAUEEdGoiAEEANgIMIAAgCjYCCCAAIAs2AgQgACAFNgIAIAMgAygCFEEBajYCFCACQQA2ApgBCyACQZgBahClASADIAMoAgBBAWo2AgAgAkGgAmokAA8LENEBAAtB3r3CAEEQIAJBmAFqQcyowgAQlgEAC0GYvcIAQcYAIAJBmAFqQdyowgAQlgEAC6gGAQ1/IAEoAhAhAiAAKAIEIQMgACgCACEFAkACQAJAIAEoAggiDUEBRwRAIAINAQwDCyACRQ0BCwJAIANFBEBBACEDDAELIAMgBWohByABQRRqKAIAQQFqIQogBSIAIQsDQCAAQQFqIQQCQAJ/IAAsAAAiAkF/TARAAn8gBCAHRgRAQQAhCCAHDAELIAAtAAFBP3EhCCAAQQJqIgQLIQAgAkEfcSEJIAggCUEGdHIgAkH/AXEiDkHfAU0NARoCfyAAIAdGBEBBACEMIAcMAQsgAC0AAEE/cSEMIABBAWoiBAshAiAMIAhBBnRyIQggCCAJQQx0ciAOQfABSQ0BGgJ/IAIgB0YEQCAEIQBBAAwBCyACQQFqIQAgAi0AAEE/cQsgCUESdEGAgPAAcSAIQQZ0cnIiAkGAgMQARw0CDAQLIAJB/wFxCyECIAQhAAsgCkF/aiIKBEAgBiALayAAaiEGIAAhCyAAIAdHDQEMAgsLIAJBgIDEAEYNAAJAIAZFIAMgBkZyRQRAQQAhACAGIANPDQEgBSAGaiwAAEFASA0BCyAFIQALIAYgAyAAGyEDIAAgBSAAGyEFCyANDQAMAQtBACEEIAMEQCADIQIgBSEAA0AgBCAALQAAQcABcUGAAUZqIQQgAEEBaiEAIAJBf2oiAg0ACwsgAyAEayABKAIMIgdPDQBBACEGQQAhBCADBEAgAyECIAUhAANAIAQgAC0AAEHAAXFBgAFGaiEEIABBAWohACACQX9qIgINAAsLIAQgA2sgB2ohAgJAAkACQEEAIAEtADAiACAAQQNGG0EBaw4DAAEAAgsgAiEGQQAhAgwBCyACQQF2IQYgAkEBakEBdiECCyAGQQFqIQACfwJAA0AgAEF/aiIABEAgASgCGCABKAIEIAEoAhwoAhARAQBFDQEMAgsLIAEoAgQhBEEBIAEoAhggBSADIAEoAhwoAgwRAwANARogAkEBaiEAIAEoAhwhBSABKAIYIQEDQCAAQX9qIgBFBEBBAA8LIAEgBCAFKAIQEQEARQ0ACwtBAQsPCyABKAIYIAUgAyABQRxqKAIAKAIMEQMAC/0GAhV/AX4jAEHgAWsiAiQAIAIgATYCACABKAIgIQkgAiABLQAkOgAHIAIgAjYCDCACIAJBB2o2AgggAS0AJUUEQCABQRRqIQQgAkH4AGogASABQRBqIgogAUEMaiILEAACQCACKAJ8IgZFDQAgCUEBdCEMIAJBtAFqIQ0gAkGAAWohCANAAkAgAkFAayIDIAhBCGopAgA3AwAgAkHIAGoiBSAIQRBqKQIANwMAIAJB0ABqIgcgCEEYaikCADcDACACIAgpAgA3AzggAigCeCEOIAIoAqABIQ8gAigCpAEhECACKAKoASERIAIoAqwBIRIgAigCsAEhEyACQRBqIA1BKBC6ARogAkHgAGoiFCADKQMANwMAIAJB6ABqIhUgBSkDADcDACACQfAAaiIWIAcpAwA3AwAgAiACKQM4NwNYIAJB+ABqIAJBEGpBKBC6ARogASgCHCIDIAEoAhhGBEACQAJAIANBAWoiBSADSQ0AIANBAXQiByAFIAcgBUsbIgetQuQAfiIXQiCIpw0AIBenIgVBAE4NAQsQzgEACwJ/IANFBEAgBUEEENIBDAELIAQoAgAgA0HkAGxBBCAFEM8BCyIDRQ0BIAEgBzYCGCABIAM2AhQgASgCHCEDCyABKAIUIANB5ABsaiIDIAY2AgQgAyAONgIAIAMgEjYCNCADIA82AiggA0EIaiACKQNYNwIAIANBEGogFCkDADcCACADQRhqIBUpAwA3AgAgA0EgaiAWKQMANwIAIANBOGogEzYCACADQTBqIBE2AgAgA0EsaiAQNgIAIANBPGogAkH4AGpBKBC6ARogASABKAIcQQFqIgM2AhwgAyAMTwRAIAJBCGogBBAKIAQgCRC3AQsgAkH4AGogASAKIAsQACACKAJ8IgYNAQwCCwsgBUEEQbT1wgAoAgAiAEECIAAbEQAAAAsgAkEIaiAEEAogBCAJELcBIAEoAhwiBEEBdiIGBEAgASgCFCIDIARB5ABsakGcf2ohBANAIAJB+ABqIANB5AAQugEaIAMgBEHkABCZAUHkAGohAyAEIAJB+ABqQeQAELoBQZx/aiEEIAZBf2oiBg0ACwsgAUEBOgAlCwJAIAFBHGooAgAiAwRAIAEgA0F/aiIDNgIcIAAgASgCFCADQeQAbGpB5AAQugEaDAELIABBADYCBAsgAkHgAWokAAvVBgIOfwJ+IwBBEGsiBiQAIAEoAgQiCEEUbCEDIAEoAgAhBQJAIAhFBEAMAQsgBUEIaiEEIAMhAgNAIAQoAgAgBEF8aigCAGsgB2ohByAEQRRqIQQgAkFsaiICDQALCwJAIAetQgx+IhBCIIinRQRAIBCnIgJBf0oEQAJAAkAgAkUEQEEEIQkMAQsgAkEEENIBIglFDQELIAhFBEBBACECDAQLIAMgBWohDSABQRRqKAIAIQwgASgCECEOQQAhAgNAAkACQAJAAkACQCAFKAIIIgEgBSgCBCIKTwRAIAwgAUkNASABIAprIghFDQVBASEEIAJBAXQhAyACQQxsIQEgDiAKQQJ0aiEKA0AgBkEANgIIIAZCADcCACAGIAQgCiAEEHMCfyAEQQNGBEAgCEUNBSAKQQRqIQogCEF/aiEIQQMMAQsgBEEBagshBCAGKAIAIg9BgIDEAEYNBiAGKQIEIRAgAiAHRgRAIAJBAWoiByACSQ0GIAMgByADIAdLGyIHrUIMfiIRQiCIpw0GIBGnIgtBAEgNBgJ/IAJFBEAgC0EEENIBDAELIAkgAUEEIAsQzwELIglFDQULIAEgCWoiCyAPNgIAIAtBBGogEDcCACADQQJqIQMgAUEMaiEBIAJBAWohAiAIIARPDQALDAULIAogARCoAQALIAEgDBCmAQALQQFBABCoAQALIAtBBEG09cIAKAIAIgBBAiAAGxEAAAALEM4BAAsgDSAFQRRqIgVHDQALDAMLIAJBBEG09cIAKAIAIgBBAiAAGxEAAAALENMBAAsQ0wEACyAJIAIgBkEAQSAgAmdrEAUCQCACQQJJBEAgAiEDDAELIAlBDGohAUEBIQNBASEEA0ACQCAEIANBf2oiBUYNACABIAkgBUEMbGpBDBC1AUUNACADIARHBEAgBkEIaiIIIAFBCGoiBSgCADYCACAGIAEpAgA3AwAgBSAJIANBDGxqIgVBCGoiCigCADYCACABIAUpAgA3AgAgCiAIKAIANgIAIAUgBikDADcCAAsgA0EBaiEDCyABQQxqIQEgAiAEQQFqIgRHDQALIAMgAk0NAEG1rcIAQRxB1K3CABC5AQALIAAgAzYCCCAAIAc2AgQgACAJNgIAIAZBEGokAAueBgENfyABKAIAIQcCf0EAIAEoAggiBEUNABogByAEQRRsaiEOAkACQANAIAciCCgCCCIJIAgoAgQiA08EQCAJIAEoAiAiBEsNAiABKAIYIQ0gCSADayEPIANBAnQhC0EAIQpBACEEAkAgAyAJRg0AQQUgCyANaiIHKAIAIAIQNiIDQf8BcUECRg0AIANBAXFFDQAgCUECdCALa0F8aiEDIAdBBGohBUEBIQYDQCAGIQQgA0UNAUEFIAUoAgAgAhA2IgdB/wFxQQJGDQEgBEEBaiEGIANBfGohAyAFQQRqIQUgB0EBcQ0ACwsgCEEUaiEHAkAgDyAEayIGRQ0AIAsgCUECdCIFayEDIAUgDWpBfGohBQNAIANFDQFBBSAFKAIAIAIQNiIJQf8BcUECRiAJQQFxRXINASADQQRqIQMgBUF8aiEFIAYgCkEBaiIKRw0ACwsgCCAIKAIEIARqNgIEIAggCCgCCCAKazYCCCAIIAgtABEgCkEAR3I6ABEgByAORw0BDAMLCyADIAkQqAEACyAJIAQQpgEACyABKAIAIQcgASgCCAshBiABQQA2AghBACEEQQAhAgJAA0AgBCAGTw0BIARBFGwhCSAEIAxqQRRsIQogByEFIAQhAwJAAkACQANAIAMgBk8NASAFIAlqIghBCGoiCygCACAIQQRqKAIARg0DIAIEQCADIAxqIgQgBk8NAyAFIApqIgQgCCkCADcCACAEQRBqIAhBEGooAgA2AgAgBEEIaiALKQIANwIACyAFQRRqIQUgA0EBaiIDIAZJDQALIAMhBAwEC0HUrsIAIAMgBhCnAQALQeSuwgAgBCAGEKcBAAsgA0EBaiEEIAxBf2ohDCACQQFqIQIgCEERai0AAEECRw0ACyADQQFqIQQLIAJFIAYgBE1yRQRAIAcgBEEUbGoiA0EAIAJrQRRsaiADIAYgBGtBFGwQmQEaCyABIAYgAmsiBDYCCCAEBEAgBkEUbCACQRRsayEFQQAhAwNAIAcgAzYCACAHQRRqIQcgA0EBaiEDIAVBbGoiBQ0ACwsgACABQTAQugEaC6IHAQV/IABBeGoiASAAQXxqKAIAIgNBeHEiAGohAkGg9cIAAn8CQAJAAkACQCADQQFxDQAgA0EDcUUNASABKAIAIgMgAGohACABIANrIgFB+PTCACgCAEYEQCACKAIEQQNxQQNHDQFB8PTCACAANgIAIAIgAigCBEF+cTYCBCABIABBAXI2AgQgACABaiAANgIADwsgA0GAAk8EQCABEFkMAQsgASgCDCIEIAEoAggiBUcEQCAFIAQ2AgwgBCAFNgIIDAELQeDxwgBB4PHCACgCAEF+IANBA3Z3cTYCAAsCQCACKAIEIgNBAnEEQCACIANBfnE2AgQgASAAQQFyNgIEIAAgAWogADYCAAwBCwJAIAJB/PTCACgCAEcEQEH49MIAKAIAIAJHDQFB+PTCACABNgIAQfD0wgBB8PTCACgCACAAaiIANgIAIAEgAEEBcjYCBCAAIAFqIAA2AgAPC0H89MIAIAE2AgBB9PTCAEH09MIAKAIAIABqIgA2AgAgASAAQQFyNgIEQfj0wgAoAgAgAUYEQEHw9MIAQQA2AgBB+PTCAEEANgIAC0GY9cIAKAIAIgIgAE8NAkH89MIAKAIAIgBFDQICQEH09MIAKAIAIgNBKUkNAEGI9cIAIQEDQCABKAIAIgQgAE0EQCAEIAEoAgRqIABLDQILIAEoAggiAQ0ACwtBoPXCAAJ/Qf8fQZD1wgAoAgAiAEUNABpBACEBA0AgAUEBaiEBIAAoAggiAA0ACyABQf8fIAFB/x9LGws2AgAgAyACTQ0CQZj1wgBBfzYCAA8LIANBeHEiBCAAaiEAAkAgBEGAAk8EQCACEFkMAQsgAigCDCIEIAIoAggiAkcEQCACIAQ2AgwgBCACNgIIDAELQeDxwgBB4PHCACgCAEF+IANBA3Z3cTYCAAsgASAAQQFyNgIEIAAgAWogADYCACABQfj0wgAoAgBHDQBB8PTCACAANgIADAELIABBgAJJDQEgASAAEFFBoPXCAEGg9cIAKAIAQX9qIgA2AgAgAA0AQZD1wgAoAgAiAA0CQf8fDAMLDwsgAEEDdiICQQN0QejxwgBqIQACf0Hg8cIAKAIAIgNBASACQR9xdCICcQRAIAAoAggMAQtB4PHCACACIANyNgIAIAALIQIgACABNgIIIAIgATYCDCABIAA2AgwgASACNgIIDwtBACEBA0AgAUEBaiEBIAAoAggiAA0ACyABQf8fIAFB/x9LGws2AgALhwYBBn8CQAJAAkACQCACQQlPBEAgAiADEEQiAg0BQQAPC0EAIQIgA0HM/3tLDQJBECADQQtqQXhxIANBC0kbIQEgAEF8aiIGKAIAIgdBeHEhBAJAAkACQAJAIAdBA3EEQCAAQXhqIgggBGohBSAEIAFPDQFB/PTCACgCACAFRg0CQfj0wgAoAgAgBUYNAyAFKAIEIgdBAnENBiAHQXhxIgkgBGoiBCABTw0EDAYLIAFBgAJJIAQgAUEEcklyIAQgAWtBgYAIT3INBQwHCyAEIAFrIgJBEEkNBiAGIAEgB0EBcXJBAnI2AgAgASAIaiIBIAJBA3I2AgQgBSAFKAIEQQFyNgIEIAEgAhAvDAYLQfT0wgAoAgAgBGoiBCABTQ0DIAYgASAHQQFxckECcjYCACABIAhqIgIgBCABayIBQQFyNgIEQfT0wgAgATYCAEH89MIAIAI2AgAMBQtB8PTCACgCACAEaiIEIAFJDQICQCAEIAFrIgNBD00EQCAGIAdBAXEgBHJBAnI2AgAgBCAIaiIBIAEoAgRBAXI2AgRBACEDDAELIAYgASAHQQFxckECcjYCACABIAhqIgIgA0EBcjYCBCAEIAhqIgEgAzYCACABIAEoAgRBfnE2AgQLQfj0wgAgAjYCAEHw9MIAIAM2AgAMBAsgBCABayECAkAgCUGAAk8EQCAFEFkMAQsgBSgCDCIDIAUoAggiBUcEQCAFIAM2AgwgAyAFNgIIDAELQeDxwgBB4PHCACgCAEF+IAdBA3Z3cTYCAAsgAkEQTwRAIAYgASAGKAIAQQFxckECcjYCACABIAhqIgEgAkEDcjYCBCAEIAhqIgMgAygCBEEBcjYCBCABIAIQLwwECyAGIAQgBigCAEEBcXJBAnI2AgAgBCAIaiIBIAEoAgRBAXI2AgQMAwsgAiAAIAMgASABIANLGxC6ARogABAhDAELIAMQAyIBRQ0AIAEgACADIAYoAgAiAUF4cUEEQQggAUEDcRtrIgEgASADSxsQugEgABAhDwsgAg8LIAALwQUBC38jAEEgayIGJAACQAJAAkAgASACRwRAIAFBAWohAyABLAAAIgVBf0oNAQJ/IAIgA0YEQCACDAELIAEtAAFBP3EhBCABQQJqIgMLIQEgBUEfcSEHIAVB/wFxQd8BTQRAIAQgB0EGdHIhAQwDCwJ/IAEgAkYEQCACDAELIAEtAABBP3EhCCABQQFqIgMLIQEgCCAEQQZ0ciEEIAVB/wFxQfABSQRAIAQgB0EMdHIhAQwDCyABIAJGBH9BAAUgAUEBaiEDIAEtAABBP3ELIAdBEnRBgIDwAHEgBEEGdHJyIgFBgIDEAEcNAgsgAEEANgIIIABCBDcCAAwCCyAFQf8BcSEBCyAGQQhqIAJBA2oiDCADa0ECdkEBahCgASAGKAIMIQUgBigCCCIIIAE2AgAgBkEBNgIYIAYgBTYCFCAGIAg2AhACQCACIANGDQBBBCEHQQEhAQNAIANBAWohBQJAAkAgAywAACIEQX9MBEACfyACIAVGBEBBACEJIAIMAQsgAy0AAUE/cSEJIANBAmoiBQshAyAEQR9xIQogBEH/AXEiDUHfAUsNASAJIApBBnRyIQQgBSEDDAILIARB/wFxIQQgBSEDDAELAn8gAiADRgRAQQAhCyACDAELIAMtAABBP3EhCyADQQFqIgULIQQgCyAJQQZ0ciEJIA1B8AFJBEAgCSAKQQx0ciEEIAUhAwwBCwJ/IAIgBEYEQCAFIQNBAAwBCyAEQQFqIQMgBC0AAEE/cQsgCkESdEGAgPAAcSAJQQZ0cnIiBEGAgMQARg0CCyAGKAIUIAFGBEAgBkEQaiABIAwgA2tBAnZBAWoQgwEgBigCECEICyAHIAhqIAQ2AgAgBiABQQFqIgE2AhggB0EEaiEHIAIgA0cNAAsLIAAgBikDEDcCACAAQQhqIAZBGGooAgA2AgALIAZBIGokAAuEBQEQfyMAQfAAayIJJAAgAEGIAmohDSAJQcgAaiEOIAFBMkkhD0EBIQQCQANAAkAgBCABTwRAQQAhBwwBCyAAIARB5ABsaiEDQQEhBwNAQQAhAgJAA0AgAkEEaiIIQShGDQEgAiADaiEFIAghAkF/IAVBXGooAgAiCCAFQUBrKAIAIgVHIAggBUgbIghFDQALIAhB/wFxQf8BRg0CCyADQeQAaiEDQQEhAiAEQQFqIgQgAUkhByABIARHDQALDAILIAEgBEYhAiAPIAEgBEZyDQECQAJAIARBf2oiAiABSQRAIAdBAXEEQCAKQQFqIQogCUEIaiAAIAJB5ABsaiICQeQAELoBGiACIAAgBEHkAGwiB2oiAkHkABC6ARogAiAJQQhqQeQAELoBIQUgACAEEEkgASAEayIMQQJJDQMgBUHkAGohCEHAACECA0AgAkEEaiIDQegARg0EIAIgBWohBiADIQJBfyAGKAIAIgMgBkHkAGooAgAiBkcgAyAGSBsiA0UNAAsgA0H/AXFB/wFHDQMgCUEIaiAFQeQAELoBGiAFIAhB5AAQugEhECAMQQNJDQIgByANaiEHQQIhAgNAIAJBAWogECACQeQAbGohA0EAIQIDQCACQQRqIgZBKEYNBCACIAdqIQsgAiAOaiERIAYhAkF/IBEoAgAiBiALKAIAIgtHIAYgC0gbIgZFDQALIAZB/wFxQf8BRw0DIANBnH9qIANB5AAQugEaIAdB5ABqIQcgAyEIIgIgDEcNAAsMAgtBwKfCACAEIAEQpwEAC0Gwp8IAIAIgARCnAQALIAggCUEIakHkABC6ARoLIApBBUcNAAtBACECCyAJQfAAaiQAIAILpQUBBn8gACgCACIJQQFxIgogBGohCAJAIAlBBHFFBEBBACEBDAELIAIEQCACIQcgASEFA0AgBiAFLQAAQcABcUGAAUZqIQYgBUEBaiEFIAdBf2oiBw0ACwsgAiAIaiAGayEIC0ErQYCAxAAgChshBgJAAkAgACgCCEEBRwRAQQEhBSAAIAYgASACELYBDQEMAgsgAEEMaigCACIHIAhNBEBBASEFIAAgBiABIAIQtgENAQwCCwJAIAlBCHFFBEAgByAIayEHQQAhBQJAAkACQEEBIAAtADAiCCAIQQNGG0EBaw4DAAEAAgsgByEFQQAhBwwBCyAHQQF2IQUgB0EBakEBdiEHCyAFQQFqIQUDQCAFQX9qIgVFDQIgACgCGCAAKAIEIAAoAhwoAhARAQBFDQALQQEPC0EBIQUgAEEBOgAwIABBMDYCBCAAIAYgASACELYBDQEgByAIayEGQQAhBQJAAkACQEEBIAAtADAiASABQQNGG0EBaw4DAAEAAgsgBiEFQQAhBgwBCyAGQQF2IQUgBkEBakEBdiEGCyAFQQFqIQUCQANAIAVBf2oiBUUNASAAKAIYIAAoAgQgACgCHCgCEBEBAEUNAAtBAQ8LIAAoAgQhAUEBIQUgACgCGCADIAQgACgCHCgCDBEDAA0BIAZBAWohBiAAKAIcIQIgACgCGCEAA0AgBkF/aiIGRQRAQQAPCyAAIAEgAigCEBEBAEUNAAsMAQsgACgCBCEIQQEhBSAAIAYgASACELYBDQAgACgCGCADIAQgACgCHCgCDBEDAA0AIAdBAWohBiAAKAIcIQEgACgCGCEAA0AgBkF/aiIGRQRAQQAPCyAAIAggASgCEBEBAEUNAAsLIAUPCyAAKAIYIAMgBCAAQRxqKAIAKAIMEQMAC5UFAg1/An4jAEHQAGsiAyQAAkAgASgCCCIErUIUfiIQQiCIp0UEQCAQpyIFQX9KBEACQAJAIAVFBEBBBCEIDAELIAVBBBDSASIIRQ0BCyABKAIAIQogBEUEQEEAIQVBACEEDAQLIAogBEEUbGohDSABQSBqKAIAIQ4gASgCGCEPQQAhBSAKIQkDQCAJKAIAIQcgA0EANgIYIAMgBzYCFCADQbSqwgA2AhAgAyAONgIMIAMgDzYCCCADIAI2AgQgAyAJNgIAIANBOGogAxAoAkAgAy0ASUECRg0AAkADQAJAIANBMGogA0HIAGoiCygCACIHNgIAIANBKGogA0FAayIMKQMAIhA3AwAgAyADKQM4IhE3AyAgCyAHNgIAIAwgEDcDACADIBE3AzgCQCAEIAZHBEAgBiEFDAELIARBAWoiBiAESQ0DIARBAXQiByAGIAcgBksbIgatQhR+IhBCIIinDQMgEKciB0EASA0DAn8gBEUEQCAHQQQQ0gEMAQsgCCAEQRRsQQQgBxDPAQsiCEUNASAGIQQLIAggBUEUbGoiBiADKQM4NwIAIAZBEGogCygCADYCACAGQQhqIAwpAwA3AgAgA0E4aiADECggBUEBaiIFIQYgAy0ASUECRw0BDAMLCyAHQQRBtPXCACgCACIAQQIgABsRAAAACxDOAQALIA0gCUEUaiIJRw0ACwwDCyAFQQRBtPXCACgCACIAQQIgABsRAAAACxDTAQALENMBAAsgASgCBARAIAoQIQsgASAFNgIIIAEgBDYCBCABIAg2AgAgBQRAIAVBFGwhBUEAIQQDQCAIIAQ2AgAgCEEUaiEIIARBAWohBCAFQWxqIgUNAAsLIAAgAUEwELoBGiADQdAAaiQAC8AFAgh/BH4jAEGwAWsiACQAAkBB6PDCACgCAA0AIABBEGpB5ANBARCVASAAKQMQIQggAEHMAGoiAUHkAzYCACAAIAg3AkQgAEEWNgJAIABBQGsQfCAAQThqIgQgAEHIAGopAwA3AwAgACAAKQNANwMwIABBQGtBFBBSIABBIGoiAyABKQIANwMAIABBKGoiBSAAQdQAaiIGKAIANgIAIAAgACkCRDcDGCAAQQhqQRRBABCVASAAKQMIIQggAEEUQQAQlQEgACkDACEJIAEgBCkDADcCACAGQQA2AgAgAEHYAGogACkDGDcDACAAQeAAaiADKQMANwMAIABB6ABqIAUoAgA2AgAgAEGIAWpBADYCACAAQYABaiIEIAk3AwAgAEH4AGpCADcDACAAQfAAaiAINwMAIABB7ABqQQA2AgAgAEEANgJAIAAgACkDMDcCRANAIABBQGsgAmoiASkCACEIIAEgAkHg8MIAaiIDKQIANwIAIAJB6PDCAGoiBSkCACEJIAJB8PDCAGoiBikCACEKIAJB+PDCAGoiBykCACELIAMgCDcCACABQRhqIgMpAgAhCCADIAs3AgAgAUEQaiIDKQIAIQsgAyAKNwIAIAFBCGoiASkCACEKIAEgCTcCACAHIAg3AgAgBiALNwIAIAUgCjcCACACQUBrIAJBIGohAkHNAEkNAAsgBCkCACEIIARBoPHCACkCADcCAEGg8cIAIAg3AgAgBEEIaiICKAIAIQEgAkGo8cIAKAIANgIAQajxwgAgATYCACAAKAJIIgFFDQAgAEHcAGooAgAhBCAAKAJYIQIgACgCcCEDIAAoAnQgACgChAEhBiAAKAKAASEHIAAoAkwEQCABECELIAIEQCAAQUBrIAJBAWoQogEgBBAhCwRAIAMQIQsgBkUNACAHECELIABBsAFqJABB4PDCAAv9BAEMfwJAAkACQAJAIAEoAgAiBEEIaigCACICIAQoAgQiBE8EQCABQQxqKAIAIgMgAkkNAUECIQMgASgCGCIGIAIgBGsiCE8NBAJAIAEoAggiBSAEQQJ0aiIKIAZBAnRqIgMgBSACQQJ0aiIJRgRAQQAhBQwBC0EAIQUDQCADQQRqIQYgASgCBCEHIAEoAhAhCyADKAIAIQxBACEDQQAhBAJ/A0BBASECIAMgC2otAAAgDCAHEDYiDUH/AXFBAkcEQCAEIQJBASANQQFxDQIaCyACIQQgA0EBaiIDQQNHDQALQQJBACACQQFxGwsiB0EBcUUgB0ECRnJFBEAgBUEBaiEFIAYiAyAJRw0BCwsgASgCGCEGCyABIAUgBmoiAjYCGCAIIAJJDQJBAiEDIAogAkECdGoiBiAJRg0EQQAhBQNAIAYiAkEEaiEGIAEoAgQhCCABKAIQIQogAigCACEHQQAhA0EAIQQCfwNAQQEhAiADIApqLQAAIAcgCBA2IgtB/wFxQQJHBEAgBCECQQEgC0EBcQ0CGgsgAiEEIANBAWoiA0EDRw0AC0ECQQAgAkEBcRsLIgdBAkdBACAHQQFxG0UEQCAFQQFqIQUgBiAJRw0BDAULCyAFDQNBAiEDDAQLIAQgAhCoAQALIAIgAxCmAQALIAIgCBCoAQALIAEoAhgiAyAFaiECIAMgASgCACIEKAIEIgdqIgkgBWohCCABKAIUIQZBASEDIAQtABFFBEAgAiAEQQhqKAIAIAdrSSEDCyABIAI2AhggAEEKOgAQIAAgBTYCDCAAIAg2AgggACAJNgIEIAAgBjYCACABIAZBAWo2AhQLIAAgAzoAEQuJBQILfwN+IwBBQGoiBiQAIAYgAjYCDEKlxoihyJyn+UshD0EBIQUgAiEEA0AgDyAErUL/AYOFQrODgICAIH4hDyAFQQRGRQRAIAZBDGogBWogBUEBaiEFLQAAIQQMAQsLIA9CGYinIgxB/wBxIgRBCHQgBHIiBEEQdCAEciENIAEoAgAiCCAPp3EhBSABKAIIIQkgASgCBCEEAkADQAJAIAQgBWooAAAiCyANcyIHQX9zIAdB//37d2pxQYCBgoR4cSIHRQ0AA0AgAiAJIAdoQQN2IAVqIAhxIg5BKGxqKAIARwRAIAdBf2ogB3EiBw0BDAILCyAJIA5BKGxqIgEpAwghDyABIAMpAwA3AwggACAPNwMAIAFBIGoiAikDACEPIAIgA0EYaikDADcDACABQRhqIgIpAwAhECACIAMpAxA3AwAgAUEQaiIBKQMAIREgASADKQMINwMAIABBGGogDzcDACAAIBA3AxAgACARNwMIDAILIApBBGoiCiAFaiAIcSEFIAsgC0EBdHFBgIGChHhxRQ0ACyAGQShqIANBGGopAgA3AgAgBkEgaiADQRBqKQIANwIAIAZBGGogA0EIaikCADcCACAGIAMpAgA3AhAgBCABIA8QlwEiBWotAABBAXEhAyABKAIMIgcgA0VyRQRAIAZBMGogARASIAEgDxCXASEFIAEoAgAhCCABKAIIIQkgASgCDCEHIAEoAgQhBAsgASAHIANrNgIMIAQgBWogDEH/AHEiAzoAACAIIAVBfGpxIARqQQRqIAM6AAAgCSAFQShsaiIDIAI2AgAgA0EEaiAGQQxqQSQQugEaIAEgASgCEEEBajYCECAAQQI6ABwLIAZBQGskAAvZBAERfyMAQRBrIggkAAJAIAEoAgQiAkUEQCAAQQA2AgAMAQsgASACQX9qNgIEIAEgASgCACIEQQRqNgIAIAEoAgghBwJAAkACQAJAAn8gAiAHIAcgAksbIAEoAhAiBUUNABoDQCACIAZqIgNBAUYNAiABIARBCGo2AgAgASAFIAZqQX9qNgIQIAEgA0F+ajYCBCAEQQRqIQQgBSAGQX9qIgZqDQALIAIgBmoiAiAHIAcgAksbCyIHQQFqIgZBAk8EQANAIAcgBkF/aiICSQ0FIAEoAgwhCSAIQqXGiKHInKf5SzcDCCAEIAIgCEEIahBxIAgoAggiBUEZdiIDQQh0IANyIgNBEHQgA3IhDyACQQJ0IRAgCSgCACILIAVxIQUgCSgCBCERQQAhDANAIAUgEWooAAAiDSAPcyIDQX9zIANB//37d2pxQYCBgoR4cSIDBEAgCSgCCCEOA0AgDiADaEEDdiAFaiALcSISQRhsaiIKKAIIIAJGBEAgBCAKKAIAIgpGDQcgBCAKIBAQtQFFDQcLIANBf2ogA3EiAw0ACwsgDEEEaiIMIAVqIAtxIQUgDSANQQF0cUGAgYKEeHFFDQALIAIhBiACQQFLDQALCyAHRQ0CIABBATYCBCAAIAQ2AgAgAEEMakEBNgIAIABBCGogBDYCAAwECyAAQQA2AgAMAwsgASAGQX5qNgIQIAAgAjYCBCAAIAQ2AgAgAEEMaiAOIBJBGGxqIgEoAhQ2AgAgAEEIaiABKAIMNgIADAILQQFBABCmAQALIAIgBxCmAQALIAhBEGokAAvBBAINfwF+IwBBIGsiByQAIAFBf2ohDCABQTJJIQ1BASEDAkACQAJ/A0ACf0EAIAMgAU8NABpBASAAIANBDGxqIgRBAyAEQXRqQQMQlAFB/wFxQf8BRg0AGgNAQQEgAyAMRg0DGiADQQFqIQMgBEEMaiICQQMgBEEDEJQBIAIhBEH/AXFB/wFHDQALIAMgAUkLIAEgA0YgDSABIANGcg0BGiADQX9qIgIgAU8NAkUNAyAKQQFqIQogACADQQxsaiIFKQIAIQ8gBSAAIAJBDGxqIgIpAgA3AgAgAiAPNwIAIAVBCGoiBigCACEEIAYgAkEIaiICKAIANgIAIAIgBDYCACAAIAMQTgJAIAEgA2siC0ECSQ0AIAVBDGoiCEEDIAVBAxCUAUH/AXFB/wFHDQAgBSkCACEPIAUgCCkCADcCACAHQRhqIg4gBigCADYCACAGIAhBCGooAgA2AgAgByAPNwMQAkAgC0EDSQ0AIAVBGGoiCUEDIAdBEGpBAxCUAUH/AXFB/wFHDQBBAyECQQIhBgNAIAZBDGwgBWoiCEF0aiIEIAkpAgA3AgAgBEEIaiAJQQhqKAIANgIAIAIgC08NASACQQFqIgQgAkkNASACQQxsIQkgAiEGIAQhAiAFIAlqIglBAyAHQRBqQQMQlAFB/wFxQf8BRg0ACwsgCCAHKQMQNwIAIAhBCGogDigCADYCAAsgCkEFRw0AC0EACyAHQSBqJAAPC0Gwp8IAIAIgARCnAQALQcCnwgAgAyABEKcBAAvZBAIPfwF+IwBBMGsiBCQAIARCpcaIocicp/lLNwMIIAIoAgAiCiACKAIIIgwgBEEIahBxIAQpAwgiE0IZiKciDkH/AHEiBUEIdCAFciIFQRB0IAVyIQ8gDEECdCEQIAEoAgAiCCATp3EhByABKAIIIQkgASgCBCEFAkADQAJAIAUgB2ooAAAiDSAPcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAJIAZoQQN2IAdqIAhxIhFBGGxqIgsoAgggDEYEQCAKIAsoAgAiC0YNASAKIAsgEBC1AUUNAQsgBkF/aiAGcSIGDQEMAgsLIAAgCSARQRhsaiIBKQIMNwIAIAEgAykCADcCDCAAQQhqIAFBFGoiACgCADYCACAAIANBCGooAgA2AgAgAigCBEUNAiAKECEMAgsgEkEEaiISIAdqIAhxIQcgDSANQQF0cUGAgYKEeHFFDQALIARBEGoiByACQQhqKAIANgIAIARBHGogA0EIaigCADYCACAEIAIpAgA3AwggBCADKQIANwIUIAUgASATEJcBIgJqLQAAQQFxIQMgASABKAIMIgYgA0VyBH8gBgUgBEEgaiABEA8gASATEJcBIQIgASgCACEIIAEoAgghCSABKAIEIQUgASgCDAsgA2s2AgwgAiAFaiAOQf8AcSIDOgAAIAggAkF8anEgBWpBBGogAzoAACAJIAJBGGxqIgJBEGogBEEYaikDADcCACACQQhqIAcpAwA3AgAgAiAEKQMINwIAIAEgASgCEEEBajYCECAAQQA2AgALIARBMGokAAulBAEUfwJAIAJBAXUiCCACSQRAQQAgACgCGGshFCAAKAIQIhIgACgCBGpBf2ohCSAAQQxqIABBCGogACgCAEEBRhshFSACIREDQAJAAkAgASAIQRhsaiIWKAIEIgcgBGtFDQAgFCAEayEXQQAgBGshCyAHIQwgCSENIBIhDiAEIQUCQAJAAkADQEF/IQ8gDiAXakUNAyALIA5qQX9qIBUoAgAiD08NASALIAxqQX9qIAdPDQIgCyANai0AACAWKAIAIAtqIAxqQX9qLQAAayIPDQMgDUF/aiENIA5Bf2ohDiAFQQFqIQUgCyAMQX9qIgxqDQALIAUhBAwDC0Hgv8IAIARBf3MgDmogDxCnAQALQfC/wgAgBEF/cyAMaiAHEKcBAAsgBSEEDAELQQAhDwsgCiAEIA9BAEgiBxshCgJAIAggESAHGyIRIAYgCCAHGyIGayINQQJODQAgEyAGIBFGIAZBAEpyckEBIRNBAXFFDQACfwNAIAYgAk8NBQJAIAogASAGQRhsaiIJKAIEIgVPBEAgACASIAVrIgQ2AhAgCSgCECIFRQ0BIAUgACADIAlBFGooAgAoAgwRAwAgACAENgIQDQELQQAgCSgCCCIGQQBIDQIaDAELCyABIAZBGGxqKAIMCw8LIAogBCAQIAcbIhAgCiAQSRshBCANQQF1IAZqIgggAkkNAAsLQdC/wgAgCCACEKcBAAtBgMDCACAGIAIQpwEAC88FAgR/AX5BASABKAIYQScgAUEcaigCACgCEBEBAEUEQEECIQICQAJAAkAgACgCACIAQXdqIgRBHksEQCAAQdwARw0BDAILQfQAIQMCQAJAIARBAWsOHgECAgACAgICAgICAgICAgICAgICAgICAgMCAgICAwQLQfIAIQMMAwtB7gAhAwwCCwJAAn5BgIbAACAAEFpFBEACQAJAIABBgIAETwRAIABBgIAISQ0BIABBkPxHakGQ/AtJIABB4ot0akHijSxJciAAQZ+odGpBnxhJIABB3uJ0akEOSXJyIABB/v//AHFBnvAKRiAAQamydWpBKUlycg0CIABBy5F1akEKSw0FDAILIABB+JHAAEEpQcqSwABBpQJB75TAAEG6AhBKRQ0BDAQLIABBqZfAAEEjQe+XwABBpgFBlZnAAEGYAxBKDQMLIABBAXJnQQJ2QQdzrUKAgICA0ACEDAELIABBAXJnQQJ2QQdzrUKAgICA0ACECyEGQQMhAgwBC0EBIQILIAAhAwsDQCACIQRB3AAhAEEBIQICQAJAAkACQCAEQQFrDgMCAwABCwJAAkACQAJAAkAgBkIgiKdB/wFxQQFrDgUEAwIBAAULIAZC/////49gg0KAgICAwACEIQZBAyECDAYLIAZC/////49gg0KAgICAMIQhBkH1ACEAQQMhAgwFCyAGQv////+PYINCgICAgCCEIQZB+wAhAEEDIQIMBAsgAyAGpyICQQJ0QRxxdkEPcSIAQTByIABB1wBqIABBCkkbIQAgAgRAIAZCf3xC/////w+DIAZCgICAgHCDhCEGQQMhAgwECyAGQv////+PYINCgICAgBCEIQZBAyECDAMLIAZC/////49ggyEGQf0AIQBBAyECDAILIAEoAhhBJyABKAIcKAIQEQEADwtBACECIAMhAAsgASgCGCAAIAEoAhwoAhARAQBFDQALCwuHBQEEfyAAIAFqIQICQAJAAkAgACgCBCIDQQFxDQAgA0EDcUUNASAAKAIAIgMgAWohASAAIANrIgBB+PTCACgCAEYEQCACKAIEQQNxQQNHDQFB8PTCACABNgIAIAIgAigCBEF+cTYCBCAAIAFBAXI2AgQgAiABNgIADwsgA0GAAk8EQCAAEFkMAQsgACgCDCIEIAAoAggiBUcEQCAFIAQ2AgwgBCAFNgIIDAELQeDxwgBB4PHCACgCAEF+IANBA3Z3cTYCAAsgAigCBCIDQQJxBEAgAiADQX5xNgIEIAAgAUEBcjYCBCAAIAFqIAE2AgAMAgsCQCACQfz0wgAoAgBHBEBB+PTCACgCACACRw0BQfj0wgAgADYCAEHw9MIAQfD0wgAoAgAgAWoiATYCACAAIAFBAXI2AgQgACABaiABNgIADwtB/PTCACAANgIAQfT0wgBB9PTCACgCACABaiIBNgIAIAAgAUEBcjYCBCAAQfj0wgAoAgBHDQFB8PTCAEEANgIAQfj0wgBBADYCAA8LIANBeHEiBCABaiEBAkAgBEGAAk8EQCACEFkMAQsgAigCDCIEIAIoAggiAkcEQCACIAQ2AgwgBCACNgIIDAELQeDxwgBB4PHCACgCAEF+IANBA3Z3cTYCAAsgACABQQFyNgIEIAAgAWogATYCACAAQfj0wgAoAgBHDQFB8PTCACABNgIACw8LIAFBgAJPBEAgACABEFEPCyABQQN2IgJBA3RB6PHCAGohAQJ/QeDxwgAoAgAiA0EBIAJBH3F0IgJxBEAgASgCCAwBC0Hg8cIAIAIgA3I2AgAgAQshAiABIAA2AgggAiAANgIMIAAgATYCDCAAIAI2AggLzQQCCX8BfiMAQRBrIgQkACAEIAE2AgAgASgCGCEDIAQgAS0AHDoAByAEIAQ2AgwgBCAEQQdqNgIIAkACQCABLQAdRQRAIAFBDGohCCADQQF0IQkDQCABKAIEIQUgASgCACECAkADQCACIAVGDQEgASACQQRqIgc2AgAgAigCACABIAEoAggiCkEBajYCCCAHIQJFDQALIAEoAhQiAiABKAIQRgRAIAJBAWoiBSACSQ0FIAJBAXQiBiAFIAYgBUsbIgVB/////wFxIAVHDQUgBUEDdCIGQQBIDQUCfyACRQRAIAZBBBDSAQwBCyAIKAIAIAJBA3RBBCAGEM8BCyICRQ0EIAEgBTYCECABIAI2AgwgASgCFCECCyABKAIMIAJBA3RqIgIgB0F8ajYCBCACIAo2AgAgASABKAIUQQFqIgI2AhQgAiAJSQ0BIARBCGogCBALIAEoAhQgA0kNASABIAM2AhQMAQsLIARBCGogCBALIAEoAhQiAiADTwRAIAEgAzYCFCADIQILIAJBAXYiBwRAIAJBA3QgCCgCACICakF4aiEDA0AgAikCACELIAIgAykCADcCACADIAs3AgAgAkEIaiECIANBeGohAyAHQX9qIgcNAAsLIAFBAToAHQsCQCABQRRqKAIAIgJFBEBBACECDAELIAEgAkF/aiICNgIUIAEoAgwgAkEDdGoiASgCBCECIAEoAgAhAQsgACACNgIEIAAgATYCACAEQRBqJAAPCyAGQQRBtPXCACgCACIAQQIgABsRAAAACxDOAQALmAQBCH8jAEEQayIDJAAgAyAANgIAIAAoAhQhAiADIAAtABg6AAcgAyADNgIMIAMgA0EHajYCCAJAAkAgAC0AGUUEQCAAQQhqIQcgACgCACIEIAAoAgRHBEAgAkEBdCEIIABBEGooAgAhAQNAIAAgBEE8ajYCACAAKAIMIAFGBEAgAUEBaiIGIAFJDQUgAUEBdCIFIAYgBSAGSxsiBkH/////A3EgBkcNBSAGQQJ0IgVBAEgNBQJ/IAFFBEAgBUEEENIBDAELIAcoAgAgAUECdEEEIAUQzwELIgFFDQQgACAGNgIMIAAgATYCCCAAKAIQIQELIAAoAgggAUECdGogBDYCACAAIAAoAhBBAWoiATYCEAJAIAEgCEkNACADQQhqIAcQDSAAKAIQIgEgAkkNACAAIAI2AhAgAiEBCyAAKAIAIgQgACgCBEcNAAsLIANBCGogBxANIABBEGooAgAiASACTwRAIAAgAjYCECACIQELIAFBAXYiBQRAIAFBAnQgBygCACIBakF8aiEEA0AgASgCACECIAEgBCgCADYCACAEIAI2AgAgAUEEaiEBIARBfGohBCAFQX9qIgUNAAsLIABBAToAGQsCf0EAIABBEGooAgAiAkUNABogACACQX9qIgI2AhAgACgCCCACQQJ0aigCAAsgA0EQaiQADwsgBUEEQbT1wgAoAgAiAEECIAAbEQAAAAsQzgEAC5sEAg9/AX4jAEEgayIDJAAgA0KlxoihyJyn+Us3AxAgASgCACIHIAEoAggiCyADQRBqEHEgAykDECISQhmIpyINQf8AcSIEQQh0IARyIgRBEHQgBHIhDiALQQJ0IQ8gACgCACIIIBKncSEFIAAoAgghCSAAKAIEIQQCQANAAkAgBCAFaigAACIMIA5zIgZBf3MgBkH//ft3anFBgIGChHhxIgZFDQADQAJAIAkgBmhBA3YgBWogCHEiEEEEdGoiCigCCCALRgRAIAcgCigCACIKRg0BIAcgCiAPELUBRQ0BCyAGQX9qIAZxIgYNAQwCCwsgCSAQQQR0aiACOgAMIAEoAgRFDQIgBxAhDAILIBFBBGoiESAFaiAIcSEFIAwgDEEBdHFBgIGChHhxRQ0ACyADQQhqIgYgAUEIaigCADYCACADIAEpAgA3AwAgBCAAIBIQlwEiAWotAABBAXEhBSAAIAAoAgwiByAFRXIEfyAHBSADQRBqIAAQEyAAIBIQlwEhASAAKAIAIQggACgCCCEJIAAoAgQhBCAAKAIMCyAFazYCDCABIARqIA1B/wBxIgU6AAAgCCABQXxqcSAEakEEaiAFOgAAIAkgAUEEdGoiAUEIaiAGKAIANgIAIAEgAykDADcCACABIAI6AAwgASADLwAQOwANIAFBD2ogA0ESai0AADoAACAAIAAoAhBBAWo2AhALIANBIGokAAvqAwEHfyMAQSBrIgMkAAJAAkAgACgCECIBIAAoAhRPDQAgACgCBCECIAMgAEEMaiAAQQhqIAAoAgBBAUYbKAIAIgQ2AgQgAyACNgIAIAMgATYCCCADIAQ2AgwCQCABRSABIARGckUEQCAEIAFNDQEgASACaiwAAEG/f0wNAQsgASAERg0BAkAgASACaiIFLAAAIgdBf0oEQCAHQf8BcSEBDAELAn8gAiAEaiIBIAVBAWpGBEAgAQwBCyAFLQABQT9xIQYgBUECagshAiAHQR9xIQUgB0H/AXFB3wFNBEAgBiAFQQZ0ciEBDAELAn8gASACRgRAIAEhBEEADAELIAJBAWohBCACLQAAQT9xCyAGQQZ0ciEGIAdB/wFxQfABSQRAIAYgBUEMdHIhAQwBC0EAIQIgASAERwR/IAQtAABBP3EFQQALIAVBEnRBgIDwAHEgBkEGdHJyIgFBgIDEAEYNAwsgAUHhAEkgAUH8AUtyDQEgAUHhAGsiBEEDdiIBQRRJBEBBACECIAFBkMDCAGotAABBASAEQQdxdHFFDQMgABCQAUEBIQIMAwtBkL7CACABQRQQpwEACyADIANBDGo2AhggAyADQQhqNgIUIAMgAzYCECADQRBqEMcBAAtBACECCyADQSBqJAAgAgvlAwEHfyMAQSBrIgIkAAJAAkACQCAAKAIQIgEgACgCFE8NACAAKAIEIQMgAiAAQQxqIABBCGogACgCAEEBRhsoAgAiBDYCBCACIAM2AgAgAiABNgIIIAIgBDYCDCABRSABIARGckUEQCAEIAFNDQMgASADaiwAAEG/f0wNAwsgASAERg0AAkAgASADaiIFLAAAIgdBf0oEQCAHQf8BcSEBDAELAn8gAyAEaiIBIAVBAWpGBEAgAQwBCyAFLQABQT9xIQYgBUECagshAyAHQR9xIQUgB0H/AXFB3wFNBEAgBiAFQQZ0ciEBDAELAn8gASADRgRAIAEhBEEADAELIANBAWohBCADLQAAQT9xCyAGQQZ0ciEDIAdB/wFxQfABSQRAIAMgBUEMdHIhAQwBC0EAIQYgASAERwR/IAQtAABBP3EFQQALIAVBEnRBgIDwAHEgA0EGdHJyIgFBgIDEAEYNAQsgAUHhAEkgAUH8AUtyRQRAIAFB4QBrIgRBA3YiAUEUTw0CQQAhBiABQZDAwgBqLQAAQQEgBEEHcXRxDQELIAAQkAFBASEGCyACQSBqJAAgBg8LQZC/wgAgAUEUEKcBAAsgAiACQQxqNgIYIAIgAkEIajYCFCACIAI2AhAgAkEQahDHAQAL/gMBBn8jAEEwayIFJAAgBUEIaiAAQQxqIgcgAEEIaiIIIAAoAgBBAUYbKAIAELEBIAVBADYCGCAFIAUpAwg3AxAgACgCACEJIAAoAgQhBgJAAkACQCABRQ0AIAcgCCAJQQFGGygCACIKIAFGDQAgCiABTQ0BIAEgBmosAABBv39MDQELIAJFIAcgCCAJGygCACIHIAJGckUEQCAHIAJNDQIgAiAGaiwAAEG/f0wNAgsgBUEQakEAIAEQkQEgBSAFKAIYIgggAWoiCTYCGCAIIAUoAhBqIAYgARC6ARogBUEQaiAJIAQQkQEgBSAFKAIYIgggBGoiCTYCGCAIIAUoAhBqIAMgBBC6ARogBUEQaiAJIAYgB2ogAiAGaiIGayIDEJEBIAUgBSgCGCIHIANqNgIYIAcgBSgCEGogBiADELoBGiAAIAEgAmsgBGoiBCAAKAIUajYCFAJAAkAgACgCECIDIAJJBEAgAyABSw0BDAILIAMgBGohAQsgACABNgIQCyAAQQRqIQEgBUEoaiICIAVBGGooAgA2AgAgBSAFKQMQNwMgAkAgACgCAEUNACAAQQhqKAIARQ0AIAEoAgAQIQsgAEEBNgIAIAEgBSkDIDcCACABQQhqIAIoAgA2AgAgBUEwaiQADwsgBiAKQQAgARAaAAsgBiAHQQAgAhAaAAuTBAEBf0EBIQMCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCAAQf8BcUEBaw4HAAECAwQFBgwLQQAhAyABQb8BSw0LIAFBBnZB0KzAAGotAAAiAEEBTQRAIABBA3RB2KzAAGopAwBCASABQT9xrYaDQgBSDwtBkKzAACAAQQIQpwEACyABQXdqQQVJDQkgAUEgRg0KQQAhAyABQYABSSABQb/gAEtyDQogAUEGdkHNqsAAai0AACIAQQVNBEAgAEEDdEGgrMAAaikDAEIBIAFBP3GthoNCAFIPC0GQrMAAIABBBhCnAQALQQAhAyABQZDAAEwEQCABQV9qIgBBDU0NBSABQUZqIgBBBUsNCiAAQQJrDgMKCgoJCyABQe+/f2pBBEkNCCABQcS/f2oiAEENS0EBIAB0QYHwAHFFcg0HDAgLQQAhAyABQV9xQb9/akEaSQ0IQQEhAyABQYABSQ0IQdjBwAAgARBaQQFzDwtBACEDIAFBX3FBv39qQRpJDQcgAUGAAUkNA0HYwcAAIAEQWg0HQQEhA0HorMAAIAEQWkUNBwwEC0ECIQMgAiABEFdB/wFxIgBBCEYNBiAAQQZGDwtBAiEDIAIgARBXQf8BcSIAQQhGDQUgAEEHRg8LIABBAWsOCgQEBAQDBAMDBAQDC0EBIQMgAUFQakEKTw0DC0EADwsgAUGmwABHDQELQQEPCyADC+4DAQd/IwBBMGsiBCQAIAEoAmRFBEACQCABQX82AmQgASgCdA0AIAFB6ABqIQcgAUGAAWpBADYCACABQfAAakKAgICAcDcCACAEQQA2AiggBCABQUBrNgIkIARBAjYCICAEIAM2AhwgBCACNgIYIAFB+ABqIQggBEEIaiAEQRhqECogBCgCCCIFBEADQCAEKAIQIQYgBCgCFCEJIAcgBSAFIAQoAgwiCkECdGoQuAEgCCAGIAYgCUECdGoQuAEgCSAKayIGBEADQCABKAJwIgUgASgCbEYEQCAHIAVBARCDASABKAJwIQULIAEoAmggBUECdGpBADYCACABIAEoAnBBAWo2AnAgBkF/aiIGDQALCyAEQQhqIARBGGoQKiAEKAIIIgUNAAsLAkACQCADIAEoAoABRgRAIAEoAngiBSACRg0BIAUgAiADQQJ0ELUBRQ0BCyAEQRhqIAcQqwEgBEEIaiAIEKsBIARBLGogBEEQaigCADYCACAEIAQpAwg3AiQgAEEQaiAEQShqKQMANwIAIABBCGogBEEgaikDADcCACAAIAQpAxg3AgAMAQsgAEEANgIACyABIAEoAnRBAWo2AnQgASABKAJkQQFqNgJkIARBMGokAA8LC0HevcIAQRAgBEEYakG4r8IAEJYBAAvKAwIOfwF+IAFBf2ohCyAAQQxqIQwgAUEySSENQQEhAwJAAkACfwNAAn9BACADIAFPDQAaQQEgACADQQN0IgZqIgJBfGooAgAoAgAgAigCBCgCACICSQ0AGiAGIAxqIQUDQEEBIAMgC0YNAxogA0EBaiEDIAIgBSgCACgCACICTyAFQQhqIQUNAAsgAyABSQsgASADRiANIAEgA0ZyDQEaIANBf2oiAiABTw0CRQ0DIAhBAWohCCAAIAJBA3RqIgIpAgAhECACIAAgA0EDdGoiBCkCADcCACAEIBA3AgAgACADEGoCQCABIANrIgpBAkkNACAEKAIEIgkoAgAgBEEMaigCACgCAE8NACAEKAIAIQ4gBCAEKQIINwIAIARBCGohBwJAIApBA0kNACAJKAIAIARBFGooAgAoAgBPDQBBAyECQQIhBQNAIAQgBUEDdGoiB0F4aiAHKQIANwIAIAIgCk8NASACQQFqIgYgAkkNASACQQN0IQ8gAiEFIAYhAiAJKAIAIAQgD2ooAgQoAgBJDQALCyAHIAk2AgQgByAONgIACyAIQQVHDQALQQALDwtBsKfCACACIAEQpwEAC0HAp8IAIAMgARCnAQALzAMBD38gACgCBCEMIAAoAhQhDSAAKAIQIQpBAyEGIABBDGogAEEIaiAAKAIAQQFGGyEOQQYhCQJAA0ACQAJAIAMgBkEYbCICQYzRwgBqKAIAIgVPDQAgAkGI0cIAaiEPAkADQCANIAMgCmoiAkYEQEF/IQIMBAsgAiAOKAIAIhBPDQEgAiAMai0AACAPKAIAIANqLQAAayICDQMgA0EBaiIDIAVHDQALIAUhAwwBC0Gwv8IAIAIgEBCnAQALQQAhAgsgByADIAJBAEgiBRshBwJAIAYgCSAFGyIJIAQgBiAFGyIEayICQQJODQAgCyAEIAlGIARBAEpyckEBIQtBAXFFDQACfwNAIARBBUsNBAJAIAcgBEEYbCIDQYzRwgBqKAIAIgVPBEAgACAFIApqIgU2AhAgA0GY0cIAaigCACICRQ0BIAIgACABIANBnNHCAGooAgAoAgwRAwAgACAFNgIQDQELQQAgA0GQ0cIAaigCACIEQQBIDQIaDAELCyAEQRhsQZTRwgBqKAIACw8LIAMgCCAFGyIIIAcgByAISxshAyACQQF1IARqIgZBBkkNAAtBoL/CACAGQQYQpwEAC0HAv8IAIARBBhCnAQAL3QMBBX8CQCABQSBqKAIAIgRFDQAgBEECdCEFIAEoAhgiBiEDA0ACQCADKAIAIgJBv39qQRpJDQAgAkGAAU8EQEH45cAAIAIQWg0BCyADQQRqIQMgBUF8aiIFDQEMAgsLIARBAnQhBQNAIAYoAgAiAyEEIAYgAyADQQBBtwUgA0HsPEkbIgIgAkHbAmoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBrgFqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQdcAaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEraiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEWaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkELaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEFaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEDaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEBaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEBaiICIAJBBHRB0PjAAGooAgAgA0sbQQR0IgJB0PjAAGooAgBGBEAgAkHU+MAAaigCACEECyAEIARBgIDEAEYbNgIAIAZBBGohBiAFQXxqIgUNAAsLIAAgAUEwELoBGgvIAwILfwF+IwBBEGsiBiQAIAYgATYCAEKlxoihyJyn+UshDkEBIQQgASEDA0AgDiADrUL/AYOFQrODgICAIH4hDiAEQQRHBEAgBCAGaiAEQQFqIQQtAAAhAwwBCwsgDkIZiKciDEH/AHEiA0EIdCADciIDQRB0IANyIQ0gACgCACIHIA6ncSEEIAAoAgghCSAAKAIEIQMCQANAAkAgAyAEaigAACIKIA1zIgVBf3MgBUH//ft3anFBgIGChHhxIgVFDQADQCABIAkgBWhBA3YgBGogB3FBA3RqIgsoAgBHBEAgBUF/aiAFcSIFDQEMAgsLIAstAAQaIAsgAjoABAwCCyAIQQRqIgggBGogB3EhBCAKIApBAXRxQYCBgoR4cUUNAAsgAyAAIA4QlwEiBGotAABBAXEhBSAAKAIMIgggBUVyRQRAIAYgABARIAAgDhCXASEEIAAoAgAhByAAKAIIIQkgACgCDCEIIAAoAgQhAwsgACAIIAVrNgIMIAMgBGogDEH/AHEiBToAACAHIARBfGpxIANqQQRqIAU6AAAgCSAEQQN0aiIDIAI6AAQgAyABNgIAIAAgACgCEEEBajYCEAsgBkEQaiQAC6oDAQR/IwBBQGoiAyQAAkACQAJAIAEoAggiBUEBTQRAIAVBAWsNAyADQQhqIAIgASgCDCIEIAFBFGooAgAQZiABQQxqIQUgAygCCA0BIAFBIGooAgAhBAwCCxDRAQALIANBIGoiBiADQRBqKAIANgIAIAMgAykDCDcDGCADQShqIANBGGoQqwEgAUEQaigCAARAIAQQIQsgBSADKQMoNwIAIAVBCGogA0EwaiIEKAIANgIAIAQgBigCADYCACADIAMpAxg3AyggAUEYaiEEIAFBHGooAgAEQCAEKAIAECELIAQgAykDKDcCACAEQQhqIANBMGooAgA2AgAgASgCAEEIaiABQSBqKAIAIgQ2AgALIANBKGogAiABKAIYIgIgBBA3IAMoAigEQCABQRBqKAIABEAgBSgCABAhCyAFIAMpAyg3AgAgBUEIaiADQTBqKAIANgIAIAFBHGooAgAEQCACECELIAFBGGoiAkEIaiADQTRqIgVBCGooAgA2AgAgAiAFKQIANwIAIAEoAgBBCGogASgCIDYCAAsLIAAgAUEwELoBGiADQUBrJAALsgMBDn8gAUF/aiEMIABBBGohDSABQTJJIQ5BASEDAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0ECdCIEaiIHKAIAIgIgB0F8aigCAEkNABogBCANaiEFA0BBASADIAxGDQMaIANBAWohAyAFKAIAIgQgAk8gBUEEaiEFIAQhAg0ACyADIAFJCyEFIAEgA0YgDiABIANGcg0BGgJAAkACQAJAIANBf2oiAiABSQRAIAVFDQEgCUEBaiEJIAAgAkECdGoiAigCACEEIAIgACADQQJ0aiIGKAIANgIAIAYgBDYCACAAIAMQciABIANrIgtBAkkNBCAGKAIEIgIgBigCACIKTw0EIAZBBGohBCAGIAI2AgAgC0EDSQ0DIAYoAggiCCAKTw0DIAZBCGohBUEDIQJBAiEHA0AgB0ECdCAGakF8aiAINgIAIAIgC08NAyACQQFqIgggAkkNAyAFIQQgBiACQQJ0aiIPIQUgAiEHIAghAiAPKAIAIgggCkkNAAsMAwtBsKfCACACIAEQpwEAC0HAp8IAIAMgARCnAQALIAUhBAsgBCAKNgIACyAJQQVHDQALQQALC7IDAQ1/IAFBf2ohCyAAQQRqIQwgAUEySSENQQEhAwJAAkACfwNAAn9BACADIAFPDQAaQQEgACADQQJ0IgdqIgQoAgAiAiAEQXxqKAIAELIBDQAaIAcgDGohBgNAQQEgAyALRg0DGiADQQFqIQMgBigCACIEIAIQsgEgBkEEaiEGIAQhAkUNAAsgAyABSQsgASADRiANIAEgA0ZyDQEaIANBf2oiAiABTw0CRQ0DIAhBAWohCCAAIAJBAnRqIgQoAgAhAiAEIAAgA0ECdGoiBSgCADYCACAFIAI2AgAgACADEH0CQCABIANrIgpBAkkNACAFKAIEIgIgBSgCACIJELIBRQ0AIAVBBGohBiAFIAI2AgACQCAKQQNJDQAgBSgCCCAJELIBRQ0AIAVBCGohBEEDIQJBAiEHA0AgB0ECdCAFakF8aiAEIgYoAgA2AgAgAiAKTw0BIAJBAWoiBCACSQ0BIAJBAnQhDiACIQcgBCECIAUgDmoiBCgCACAJELIBDQALCyAGIAk2AgALIAhBBUcNAAtBAAsPC0Gwp8IAIAIgARCnAQALQcCnwgAgAyABEKcBAAu8AwILfwN+IwBBIGsiBSQAAkACQAJAIAFBAkkNACAAQShqIgMoAgAgACgCCCIITw0AIAApAwAhDSAAIAApAyA3AwAgAEEUaikCACEOIAApAgwhDyAAQRBqIABBMGopAwA3AwAgAEEIaiADKQMANwMAIABBHGooAgAhAyAAQRhqIABBOGopAwA3AwAgBUEQaiIKIA43AwAgBUEYaiILIAM2AgAgBSAPNwMIIABBIGohAkEDIQZBASEHAkAgAUEDSQ0AQQIhCQNAIAkiAyABTw0DIAAgA0EFdGoiBCgCCCAITw0BIANBf2oiAiABTw0EIAAgAkEFdGoiAiAEKQMANwMAIAJBGGogBEEYaikDADcDACACQRBqIARBEGopAwA3AwAgAkEIaiAEQQhqKQMANwMAIAYgAUkEQCAGIQkgBkEBaiICIAZJIAIhBiADIQcgBCECDQIMAQsLIAMhByAEIQILIAIgDTcDACAAIAdBBXRqIAg2AgggAkEcaiALKAIANgIAIAJBFGogCikDADcCACACIAUpAwg3AgwLIAVBIGokAA8LQdCswgAgAyABEKcBAAtB4KzCACACIAEQpwEAC8YDAgh/AX4jAEEgayICJAACQBDBASIGRQ0AAkAgBigCAEUEQCAGQX82AgAgBkEEaiABEFYiBUUNASAFKAIIIgQEQCAEQQR0IQcgBSgCAEEMaiEBA0AgASgCACADaiEDIAFBEGohASAHQXBqIgcNAAsLIAJBCGogAyAEahCxASACQQA2AhggAiACKAIMNgIUIAIgAigCCCIENgIQIAUoAggiAQRAIAFBBHQhByAFKAIAQQxqIQNBACEBA0AgA0F4aigCACEIIAJBEGogASADKAIAIgUQkQEgAiAFIAIoAhgiCWoiATYCGCAJIAIoAhAiBGogCCAFELoBGiACKAIUIAFGBEAgAkEQaiABQQEQkQEgAigCECEEIAIoAhghAQsgASAEakEAOgAAIAIgAUEBaiIBNgIYIANBEGohAyAHQXBqIgcNAAsLIAIpAhQhCiAGIAYoAgBBAWo2AgAgBEUNAiACIAo3AhQgAiAENgIQIAIgAkEQahCOASAAIAIpAwA3AgAgAkEgaiQADwtB3r3CAEEQIAJBEGpB8L3CABCWAQALQZTuwgBBK0HA7sIAELkBAAtBmL3CAEHGACACQRBqQYC+wgAQlgEAC58DAQN/IwBB0ABrIgUkACAFIAI2AkAgBSACNgI8IAUgATYCOCAFQRBqIAVBOGoQjgEgBSgCFCEHIAUoAhAhBiAFIAQ2AkAgBSAENgI8IAUgAzYCOCAFQQhqIAVBOGoQjgEgBSgCDCEEIAUoAgghAxDCASIBBEACQCABKAIARQRAIAFBfzYCACABQQRqIAAQVSIARQ0BIAVBGGogBiAGIAdqECMgBUEoaiADIAMgBGoQIyAFQUBrIAVBIGooAgA2AgAgBUHMAGogBUEwaigCADYCACAFIAUpAxg3AzggBSAFKQMoNwJEIABBmAFqIQIgACgCnAEEQCACKAIAECELIAAoAqgBBEAgACgCpAEQIQsgAiAFKQM4NwIAIAJBEGogBUHIAGopAwA3AgAgAkEIaiAFQUBrKQMANwIAIAEgASgCAEEBajYCACAEBEAgAxAhCyAHBEAgBhAhCyAFQdAAaiQADwtB3r3CAEEQIAVBOGpBzKjCABCWAQALQZTuwgBBK0HA7sIAELkBAAtBmL3CAEHGACAFQThqQdyowgAQlgEAC60DAgp/AnwgAigCBCIHIAFBGGooAgBqIgQgAygCBCIFSwRAIANBCGooAgAhCCABKwMAIQ4CQCACQQhqKAIAIgsgB2siCUUEQAwBCyAIIAVrIgZFBEAgDiEPRAAAAAAAAAAAIQ4MAQsgDiAOIAm4Ig+iRAAAAAAAACRAoiAPIAa4oKObRAAAAAAAACRAoyIPoUQAAAAAAAAkQKIQfkQAAAAAAAAkQKMhDgsgAigCACEMAn9BACACLQAoIgJBCkYNABpBACACQXtqIgJBBEsNABpCgYKEgBAgAq1CA4aIpwshDSAEIAVrIQQgAygCACEGAkAgAy0AKCICQQpGDQAgAkF7aiICQQRLDQBCgYKEgBAgAq1CA4aIpyEKCyAAQQE6AB0gACANOgAcIAAgCTYCGCAAQQA2AhQgACALNgIQIAAgBzYCDCAAIAw2AgggACAPOQMAIABBPWogAS0AHToAACAAQTxqIAo6AAAgAEE4aiAENgIAIABBNGpBADYCACAAQTBqIAg2AgAgAEEsaiAFNgIAIABBKGogBjYCACAAQSBqIA45AwAPCyAAQQI6ABwL5wIBBn8CQAJAIAJBA3EiBEUNAEEEIARrIgRFDQAgAyAEIAQgA0sbIQVBACEEIAFB/wFxIQgDQCAEIAVGDQEgAiAEaiAEQQFqIQQtAAAiBiAIRw0AC0EBIQMgBiABQf8BcUZBAWpBAXEgBGpBf2ohBAwBCyABQf8BcSEIAkACQCADQQhJDQAgBSADQXhqIgZLDQAgCEGBgoQIbCEEA0AgAiAFaiIHQQRqKAIAIARzIglBf3MgCUH//ft3anEgBygCACAEcyIHQX9zIAdB//37d2pxckGAgYKEeHFFBEAgBUEIaiIFIAZNDQELCyAFIANLDQELIAIgBWohAiADIAVrIQZBACEDQQAhBAJAA0AgBCAGRg0BIAIgBGogBEEBaiEELQAAIgcgCEcNAAtBASEDIAcgAUH/AXFGQQFqQQFxIARqQX9qIQQLIAQgBWohBAwBCyAFIAMQqAEACyAAIAQ2AgQgACADNgIAC+ICAQV/AkBBzf97IABBECAAQRBLGyIAayABTQ0AIABBECABQQtqQXhxIAFBC0kbIgRqQQxqEAMiAkUNACACQXhqIQECQCAAQX9qIgMgAnFFBEAgASEADAELIAJBfGoiBSgCACIGQXhxIAIgA2pBACAAa3FBeGoiAiAAIAJqIAIgAWtBEEsbIgAgAWsiAmshAyAGQQNxBEAgACADIAAoAgRBAXFyQQJyNgIEIAAgA2oiAyADKAIEQQFyNgIEIAUgAiAFKAIAQQFxckECcjYCACAAIAAoAgRBAXI2AgQgASACEC8MAQsgASgCACEBIAAgAzYCBCAAIAEgAmo2AgALAkAgACgCBCIBQQNxRQ0AIAFBeHEiAiAEQRBqTQ0AIAAgBCABQQFxckECcjYCBCAAIARqIgEgAiAEayIEQQNyNgIEIAAgAmoiAiACKAIEQQFyNgIEIAEgBBAvCyAAQQhqIQMLIAML/wIBCX8jAEEgayIDJAACQAJAAkAgACgCECIIIAAoAhhrIAJIDQAgAEEIaiEEIABBDGohBiAAKAIAIQkgACgCBCEHAkAgCCACayIFRQ0AIAYgBCAJQQFGGygCACIKIAVGDQAgCiAFTQ0BIAUgB2osAABBQEgNAQsgAyAGIAQgCRsoAgAiBDYCBCADIAc2AgAgAyAFNgIIIAMgBDYCDCAFRSAEIAVGckUEQCAEIAVNDQIgBSAHaiwAAEG/f0wNAgsgBSAHaiEGAkAgAkEAIAQgBWsiBCACRxtFBEAgAyAENgIUIAMgBjYCEAwBCyAEIAJNDQEgByAIaiIHLAAAQUBIDQEgAyAENgIUIAMgBjYCECAHLAAAQb9/TA0DCyABIAZHBEAgASAGIAIQtQENAQsgACAFNgIQQQEhCwsgA0EgaiQAIAsPCyADIANBDGo2AhggAyADQQhqNgIUIAMgAzYCECADQRBqEMcBAAsgA0EQaiIAKAIAIAAoAgRBACACEBoAC/cCAQp/IwBB8ABrIggkACAAQUBrIQsDQCACQQF0IgNBAXIhBQJAAkACQAJAIANBAmoiByABTw0AIAUgAU8NASAAIANB5ABsaiEJQQAhAwNAIANBBGoiBEEoRg0BIAMgCWohBiAEIQNBfyAGQYgCaigCACIEIAZBpAFqKAIAIgZHIAQgBkgbIgRFDQALIARB/wFxQf8BRw0AIAchBQsCQCAFIAFPDQAgAiABTw0CIAsgBUHkAGwiA2ohBiALIAJB5ABsIgJqIQkgACADaiEHIAAgAmohBEEAIQMDQCADQQRqIgJBKEYNASADIAlqIQogAyAGaiEMIAIhA0F/IAwoAgAiAiAKKAIAIgpHIAIgCkgbIgJFDQALIAJB/wFxQf8BRg0DCyAIQfAAaiQADwtBrKjCACAFIAEQpwEAC0G8qMIAIAIgARCnAQALIAhBDGogBEHkABC6ARogBCAHQeQAEJkBGiAHIAhBDGpB5AAQugEaIAUhAgwACwAL+QIBBX8jAEEwayIEJAACQAJAIABBCGooAgAiBSAAKAIEIgZPBEAgBSACSw0BIAUgBmshByAAIAMoAgAEfyADKAJUDQMgA0F/NgJUQQAhAiADQeAAaiIIQQA2AgAgA0HYAGoiAEEAIAcQkQEgASAGQQJ0aiABIAVBAnRqIAAQSyAAKAIAIQEgBEEQaiIFIAgoAgAiADYCACAEIAA2AiggBEIANwMgIAQgADYCHCAEQQA2AhggBCABNgIMIARBADYCCCAEQQhqIAMoAgARBwAaIAQoAgwhACAEQRRqKAIAIAUoAgAiByAEKAIIIghBAUYbIgUEQCAFIQYgACEBA0AgAiABLQAAQcABcUGAAUZqIQIgAUEBaiEBIAZBf2oiBg0ACwsgCEUgB0VyRQRAIAAQIQsgAyADKAJUQQFqNgJUIAUgAmsFIAcLNgIMIARBMGokAA8LIAYgBRCoAQALIAUgAhCmAQALQd69wgBBECAEQQhqQbivwgAQlgEAC+ICAQt/IwBBEGsiBiQAIABBCGooAgAiBSAAKAIEIgRPBEAgBSACTQRAIAZCpcaIocicp/lLNwMIIAEgBEECdGoiAiAFIARrIgUgBkEIahBxIAYoAggiAUEZdiIEQQh0IARyIgRBEHQgBHIhCiAFQQJ0IQsgAygCGCIEIAFxIQEgA0EgaigCACEIIANBHGooAgAhDANAAkACQCABIAxqKAAAIgkgCnMiA0F/cyADQf/9+3dqcUGAgYKEeHEiA0UNAANAAkAgBSAIIANoQQN2IAFqIARxIg1BBHRqIgcoAghGBEAgBygCACIHIAJGDQEgAiAHIAsQtQFFDQELIANBf2ogA3EiAw0BDAILCyAIIA1BBHRqLQAMIQMMAQsgDkEEaiIOIAFqIARxIQFBCiEDIAkgCUEBdHFBgIGChHhxRQ0BCwsgACADOgAQIAZBEGokAA8LIAUgAhCmAQALIAQgBRCoAQAL3QIBCn8jAEHwAGsiByQAAkAgAUECSQ0AIAFBfmohBCAAIAFB5ABsaiIFQbh+aiEGIAVBnH9qIQhBWCEBA0AgAUEEaiIDRQ0BIAEgBWohAiADIQFBfyACQaB/aigCACIDIAJBBGooAgAiAkcgAyACSBsiA0UNAAsgA0H/AXFB/wFHDQAgB0EIaiAIQeQAELoBGiAIIAZB5AAQugEaIAAgBEHkAGwiAWohAwJAIARFDQAgACABakFcaiEGIAdByABqIQoDQCAAIARBf2oiCEHkAGxqIQVBACEBA0AgAUEEaiICQShGDQIgASAKaiEJIAEgBmohCyACIQFBfyALKAIAIgIgCSgCACIJRyACIAlIGyICRQ0ACyACQf8BcUH/AUcNASAAIARB5ABsaiAFQeQAELoBGiAGQZx/aiEGIAUhAyAIIgQNAAsLIAMgB0EIakHkABC6ARoLIAdB8ABqJAALzQIBBn8gASACQQF0aiEJIABBgP4DcUEIdiEKIABB/wFxIQwCQAJAAkADQCABQQJqIQsgByABLQABIgJqIQggCiABLQAAIgFHBEAgASAKSw0DIAghByALIgEgCUcNAQwDCyAIIAdPBEAgCCAESw0CIAMgB2ohAQJAA0AgAkUNASACQX9qIQIgAS0AACABQQFqIQEgDEcNAAtBACECDAULIAghByALIgEgCUcNAQwDCwsgByAIEKgBAAsgCCAEEKYBAAsgAEH//wNxIQcgBSAGaiEDQQEhAgNAAkAgBUEBaiEAAn8gACAFLQAAIgFBGHRBGHUiBEEATg0AGiAAIANGDQEgBS0AASAEQf8AcUEIdHIhASAFQQJqCyEFIAcgAWsiB0EASA0CIAJBAXMhAiADIAVHDQEMAgsLQZTuwgBBK0HA7sIAELkBAAsgAkEBcQvbAgEDfyMAQRBrIgMkACAAIAFHBEADQCAAQQRqAkAgACgCACIAQYABTwRAIANBADYCDAJ/IABB/w9NBEAgAyAAQT9xQYABcjoADSADIABBBnZBH3FBwAFyOgAMQQIMAQsgAEH//wNNBEAgAyAAQT9xQYABcjoADiADIABBBnZBP3FBgAFyOgANIAMgAEEMdkEPcUHgAXI6AAxBAwwBCyADIABBP3FBgAFyOgAPIAMgAEESdkHwAXI6AAwgAyAAQQZ2QT9xQYABcjoADiADIABBDHZBP3FBgAFyOgANQQQLIQAgAiACKAIIIAAQkQEgAiACKAIIIgQgAGo2AgggBCACKAIAaiADQQxqIAAQugEaDAELIAIoAggiBCACKAIERgR/IAIgBEEBEJEBIAIoAggFIAQLIAIoAgBqIAA6AAAgAiACKAIIQQFqNgIICyIAIAFHDQALCyADQRBqJAAL4gICBn8CfiMAQSBrIgMkAAJAEMEBIgRFDQACQCAEKAIARQRAIARBfzYCAEEEIQYgBEEEaiABEFYiAUUNAQJ+IAEoAggiAkUEQEIADAELAkACQCACQf////8DcSACRw0AIAJBAnQiBUEASA0AIAEoAgAhASAFQQQQ0gEiBg0BIAVBBEG09cIAKAIAIgBBAiAAGxEAAAALEM4BAAsgAq0hCCACQQR0IgchBSAGIQIDQCACIAEoAgA2AgAgAkEEaiECIAFBEGohASAFQXBqIgUNAAsgB0FwakEEdkEBaq1CIIYLIQkgBCAEKAIAQQFqNgIAIAZFDQIgAyAIIAmENwIUIAMgBjYCECADQQhqIANBEGoQhgEgACADKQMINwIAIANBIGokAA8LQd69wgBBECADQRBqQfC9wgAQlgEAC0GU7sIAQStBwO7CABC5AQALQZi9wgBBxgAgA0EQakGAvsIAEJYBAAvCAgEKfyMAQfAAayIGJAACQCABQQJJDQAgAEHkAGohBEHAACECA0AgAkEEaiIDQegARg0BIAAgAmohBSADIQJBfyAFKAIAIgMgBUHkAGooAgAiBUcgAyAFSBsiA0UNAAsgA0H/AXFB/wFHDQAgBkEIaiAAQeQAELoBGiAAIARB5AAQugEiCUHkAGohAwJAIAFBA0kNACAJQYgCaiEHIAZByABqIQpBAiECA0AgAkEBaiAJIAJB5ABsaiEAQQAhAgNAIAJBBGoiBEEoRg0CIAIgB2ohCCACIApqIQsgBCECQX8gCygCACIEIAgoAgAiCEcgBCAISBsiBEUNAAsgBEH/AXFB/wFHDQEgAEGcf2ogAEHkABC6ARogB0HkAGohByAAIQMiAiABRw0ACwsgAyAGQQhqQeQAELoBGgsgBkHwAGokAAu9AgIEfwF+IwBBEGsiBCQAAkAgAUECSQ0AIAFBDGwgAGoiA0F0aiICQQMgA0FoaiIDQQMQlAFB/wFxQf8BRw0AIAIpAgAhBiACIAMpAgA3AgAgBEEIaiIFIAJBCGoiAigCADYCACACIANBCGooAgA2AgAgBCAGNwMAIAAgAUF+aiICQQxsaiEDAkAgAkUNACAEQQMgACABQX1qIgJBDGxqQQMQlAFB/wFxQf8BRw0AIAFBDGwgAGpBUGohASAAAn8CQANAIAFBIGogAUEUaigCADYCACABQRhqIAFBDGopAgA3AgAgAkUNASACQX9qIQIgBEEDIAFBAxCUASABQXRqIQFB/wFxQf8BRg0ACyACQQFqDAELQQALQQxsaiEDCyADIAQpAwA3AgAgA0EIaiAFKAIANgIACyAEQRBqJAALvgICBX8BfiMAQTBrIgQkAEEnIQICQCAAQpDOAFQEQCAAIQcMAQsDQCAEQQlqIAJqIgNBfGogACAAQpDOAIAiB0LwsX9+fKciBUH//wNxQeQAbiIGQQF0QfaAwABqLwAAOwAAIANBfmogBkGcf2wgBWpB//8DcUEBdEH2gMAAai8AADsAACACQXxqIQIgAEL/wdcvViAHIQANAAsLIAenIgNB4wBKBEAgAkF+aiICIARBCWpqIAenIgVB//8DcUHkAG4iA0Gcf2wgBWpB//8DcUEBdEH2gMAAai8AADsAAAsCQCADQQpOBEAgAkF+aiICIARBCWpqIANBAXRB9oDAAGovAAA7AAAMAQsgAkF/aiICIARBCWpqIANBMGo6AAALIAFBjurCAEEAIARBCWogAmpBJyACaxAlIARBMGokAAu2AgEHfwJ/IAFBIGooAgAiBCABQSxqKAIAIgVNBEAgASAENgIsIAQMAQsgAUEkaiAFIAQgBWsiBhCRASABKAIsIQMgASgCJCEHIAECfwJAIAZBAk8EQCAFQX9zIARqIQQDQCADIAdqQQA6AAAgA0EBaiEDIARBf2oiBA0ACwwBCyAGDQAgAwwBCyADIAdqQQA6AAAgA0EBagsiBDYCLCABKAIgCyEDAkAgBCADIAMgBEsbIgZFDQAgASgCJCEHIAEoAhghBEEAIQMDQCADQQFqIQUgAyAHagJAIAIgBCgCACIIEFdB/wFxIgNBCEcNAEEAIQNBBCAIIAIQNiIIQf8BcUECRg0AIAhBAnRBBHEhAwsgAzoAACAFIAZPDQEgBEEEaiEEIAUhAwwACwALIAAgAUEwELoBGgu/AgEEfyAAQgA3AhAgAAJ/QQAgAUEIdiIDRQ0AGkEfIAFB////B0sNABogAUEGIANnIgNrQR9xdkEBcSADQQF0a0E+agsiAjYCHCACQQJ0QfDzwgBqIQQCQAJAAkACQEHk8cIAKAIAIgVBASACQR9xdCIDcQRAIAQoAgAiAygCBEF4cSABRw0BIAMhAgwCC0Hk8cIAIAMgBXI2AgAgBCAANgIAIAAgBDYCGAwDCyABQQBBGSACQQF2a0EfcSACQR9GG3QhBANAIAMgBEEddkEEcWpBEGoiBSgCACICRQ0CIARBAXQhBCACIQMgAigCBEF4cSABRw0ACwsgAigCCCIBIAA2AgwgAiAANgIIIABBADYCGCAAIAI2AgwgACABNgIIDwsgBSAANgIAIAAgAzYCGAsgACAANgIMIAAgADYCCAu4AgEGfyMAQRBrIgIkAAJAIAJBfwJ/AkAgAQRAIAFBCEkNASABIAFB/////wFxRgRAIAFBA3RBB24MAwtBtKbCAEEcQdCmwgAQuQEACyAAQgA3AgAgAEEUakEANgIAIABBDGpCBDcCACAAQQhqQbCmwgA2AgAMAgsgAUEBagtBf2pndiIBQQFqIgQQogECQCACKAIEIgMEQCACKAIIIQUgAigCACIGIAMQ0gEiB0UNASAHQf8BIAFBBWoQwAEhAyAAQRRqQQA2AgAgAEEMaiADIAVqNgIAIABBCGogAzYCACAAIAE2AgQgAEEANgIAIABBEGogASAEQQN2QQdsIAFBCEkbNgIADAILQbSmwgBBHEHQpsIAELkBAAsgBiADQbT1wgAoAgAiAEECIAAbEQAAAAsgAkEQaiQAC7gCAQZ/IwBBEGsiAiQAAkAgAkF/An8CQCABBEAgAUEISQ0BIAEgAUH/////AXFGBEAgAUEDdEEHbgwDC0G0psIAQRxB0KbCABC5AQALIABCADcCACAAQRRqQQA2AgAgAEEMakIINwIAIABBCGpBsKbCADYCAAwCCyABQQFqC0F/amd2IgFBAWoiBBCcAQJAIAIoAgQiAwRAIAIoAgghBSACKAIAIgYgAxDSASIHRQ0BIAdB/wEgAUEFahDAASEDIABBFGpBADYCACAAQQxqIAMgBWo2AgAgAEEIaiADNgIAIAAgATYCBCAAQQA2AgAgAEEQaiABIARBA3ZBB2wgAUEISRs2AgAMAgtBtKbCAEEcQdCmwgAQuQEACyAGIANBtPXCACgCACIAQQIgABsRAAAACyACQRBqJAALqQIBA38jAEGAAWsiBCQAIAAoAgAhAAJAAkACfwJAIAEoAgAiA0EQcUUEQCAAKAIAIQIgA0EgcQ0BIAKtIAEQTwwCCyAAKAIAIQJBACEAA0AgACAEakH/AGogAkEPcSIDQTByIANB1wBqIANBCkkbOgAAIABBf2ohACACQQR2IgINAAsgAEGAAWoiAkGBAU8NAiABQcyDwABBAiAAIARqQYABakEAIABrECUMAQtBACEAA0AgACAEakH/AGogAkEPcSIDQTByIANBN2ogA0EKSRs6AAAgAEF/aiEAIAJBBHYiAg0ACyAAQYABaiICQYEBTw0CIAFBzIPAAEECIAAgBGpBgAFqQQAgAGsQJQsgBEGAAWokAA8LIAJBgAEQqAEACyACQYABEKgBAAumAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQQgACgCBCEIA0ACQAJAIAIgCGooAAAiBiAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIARQ0AA0AgASAEIABoQQN2IAJqIANxIglB7AFsaigCAEcEQCAAQX9qIABxIgANAQwCCwsgBCAJQewBbGpBBGohAAwBCyAFQQRqIgUgAmogA3EhAkEAIQAgBiAGQQF0cUGAgYKEeHFFDQELCyAAC6QCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghBCAAKAIEIQgDQAJAAkAgAiAIaigAACIGIAdzIgBBf3MgAEH//ft3anFBgIGChHhxIgBFDQADQCABIAQgAGhBA3YgAmogA3EiCUEEdGooAgBHBEAgAEF/aiAAcSIADQEMAgsLIAQgCUEEdGpBBGohAAwBCyAFQQRqIgUgAmogA3EhAkEAIQAgBiAGQQF0cUGAgYKEeHFFDQELCyAAC6gCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEGIAMgACgCBCIDcSECIABBDGooAgAhByAAQQhqKAIAIQhBACEEA0ACQAJAIAIgCGooAAAiBSAGcyIAQX9zIABB//37d2pxQYCBgoR4cSIARQ0AA0AgASAHIABoQQN2IAJqIANxQQN0aiIJKAIARwRAIABBf2ogAHEiAA0BDAILCyAJLQAEIQAMAQsgBEEEaiIEIAJqIANxIQJBCCEAIAUgBUEBdHFBgIGChHhxRQ0BCwsgAAuoAgEEfyMAQSBrIgIkAAJAAkACQAJAAkACQBDCASIDBEAgAygCAA0GIANBfzYCACADQQRqIAAQVSIFRQ0CEMEBIgQEQCAEKAIADQcgBEF/NgIAIARBBGogABBWIgBFDQQgBSABNgIQIAAoAgQgAU8NAiACQQhqIAAgACgCCCIAIAEgAGtBABBhIAIoAghBAUcNAiACQRBqKAIADQUQzgEACwwFCwwECyAEIAQoAgBBAWo2AgAgAyADKAIAQQFqNgIAIAJBIGokAA8LQZTuwgBBK0HA7sIAELkBAAtBlO7CAEErQcDuwgAQuQEAC0Gz78IAQShB2KvCABC5AQALQZi9wgBBxgAgAkEYakHcqMIAEJYBAAtB3r3CAEEQIAJBGGpBzKjCABCWAQALsgIBBX8gACgCGCEEAkACQCAAIAAoAgwiAUYEQCAAQRRBECAAQRRqIgEoAgAiAxtqKAIAIgINAUEAIQEMAgsgACgCCCICIAE2AgwgASACNgIIDAELIAEgAEEQaiADGyEDA0AgAyEFIAIiAUEUaiIDKAIAIgJFBEAgAUEQaiEDIAEoAhAhAgsgAg0ACyAFQQA2AgALAkAgBEUNAAJAIAAgACgCHEECdEHw88IAaiICKAIARgRAIAIgATYCACABDQFB5PHCAEHk8cIAKAIAQX4gACgCHHdxNgIADwsgBEEQQRQgBCgCECAARhtqIAE2AgAgAUUNAQsgASAENgIYIAAoAhAiAgRAIAEgAjYCECACIAE2AhgLIABBFGooAgAiAEUNACABQRRqIAA2AgAgACABNgIYCwunAgECfwJ/IAFBgBBPBEACQAJAAkACQAJAIAFBgIAETwRAIAFBDHZBcGoiAkGAAkkNAUHQnMAAIAJBgAIQpwEACyABQQZ2QWBqIgJB3wdLDQEgAEGEAmooAgAiAyAAIAJqQZgCai0AACICTQ0CIAAoAoACIAJBA3RqDAYLIAFBBnZBP3EgACACakH4CWotAABBBnRyIgIgAEGMAmooAgAiA08NAiAAQZQCaigCACIDIAAoAogCIAJqLQAAIgJNDQMgACgCkAIgAkEDdGoMBQtBsJzAACACQeAHEKcBAAtBwJzAACACIAMQpwEAC0HgnMAAIAIgAxCnAQALQfCcwAAgAiADEKcBAAsgACABQQN2Qfj///8BcWoLKQMAQgEgAUE/ca2Gg0IAUguUAgEDfyMAQYABayIDJAACQAJAAn8CQCABKAIAIgJBEHFFBEAgAkEgcQ0BIACtIAEQTwwCC0EAIQIDQCACIANqQf8AaiAAQQ9xIgRBMHIgBEHXAGogBEEKSRs6AAAgAkF/aiECIABBBHYiAA0ACyACQYABaiIAQYEBTw0CIAFBzIPAAEECIAIgA2pBgAFqQQAgAmsQJQwBC0EAIQIDQCACIANqQf8AaiAAQQ9xIgRBMHIgBEE3aiAEQQpJGzoAACACQX9qIQIgAEEEdiIADQALIAJBgAFqIgBBgQFPDQIgAUHMg8AAQQIgAiADakGAAWpBACACaxAlCyADQYABaiQADwsgAEGAARCoAQALIABBgAEQqAEAC5MCAQt/IwBBEGsiBSQAAkACQCABQQhPBEAgAUECdiICQQF0IQdBfyEGQX8gAUF/amd2IQggAkEYbCAAakF0aiEEIAEhAgNAIAYgB2oiAyABTw0CIAJBDXQgAnMiAkERdiACcyICQQV0IAJzIgIgCHEiA0EAIAEgAyABSRtrIgMgAU8NAyAFQQhqIgkgBEEIaiIKKAIANgIAIAUgBCkCADcDACAAIANBDGxqIgNBCGoiCygCACEMIAQgAykCADcCACAKIAw2AgAgCyAJKAIANgIAIAMgBSkDADcCACAEQQxqIQQgBkEBaiIGQQJHDQALCyAFQRBqJAAPC0Gwp8IAIAMgARCnAQALQcCnwgAgAyABEKcBAAuVAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQggACgCBCEJQQAhBANAAkAgAiAJaigAACIFIAdzIgBBf3MgAEH//ft3anFBgIGChHhxIgAEQANAQQEhBiAIIABoQQN2IAJqIANxQQR0aigCACABRg0CIABBf2ogAHEiAA0ACwsgBEEEaiIEIAJqIANxIQJBACEGIAUgBUEBdHFBgIGChHhxRQ0BCwsgBguWAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQggACgCBCEJQQAhBANAAkAgAiAJaigAACIFIAdzIgBBf3MgAEH//ft3anFBgIGChHhxIgAEQANAQQEhBiAIIABoQQN2IAJqIANxQewBbGooAgAgAUYNAiAAQX9qIABxIgANAAsLIARBBGoiBCACaiADcSECQQAhBiAFIAVBAXRxQYCBgoR4cUUNAQsLIAYLlQICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEIIAAoAgQhCUEAIQQDQAJAIAIgCWooAAAiBSAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIABEADQEEBIQYgCCAAaEEDdiACaiADcUEobGooAgAgAUYNAiAAQX9qIABxIgANAAsLIARBBGoiBCACaiADcSECQQAhBiAFIAVBAXRxQYCBgoR4cUUNAQsLIAYLqAIBBH8jAEEwayICJAAgASgCCCEFIAIgARCrASACKAIIIQQgAkEANgIoIAJCATcDICACQSBqQQAgBBCRASACKAIoIQMgBARAIAIoAiAgA2pBACAEQQJ0QXxqQQJ2QQFqIgQQwAEaIAMgBGohAwsgAkEYaiIEIAM2AgAgAiACKQMgNwMQQRRBBBDSASIDRQRAQRRBBEG09cIAKAIAIgBBAiAAGxEAAAALIANBigI7ARAgAyAFNgIMIAMgBTYCCCADQgA3AgAgAEKBgICAEDcCBCAAIAM2AgAgACACKQMANwIYIAAgAikDEDcCJCAAIAEpAgA3AgwgAEEUaiABQQhqKAIANgIAIABBIGogAkEIaigCADYCACAAQSxqIAQoAgA2AgAgAkEwaiQAC+IBAQJ/AkAgASgCBCIFIAJrIANPDQAgAiADaiIDIAJJIQICQAJAAkAgBARAIAJFDQEgACADNgIEDAMLIAJFDQEgACADNgIEDAILIAVBAXQiAiADIAIgA0sbIQMLIAMgA0H/////AHFHBEAMAQsgA0EEdCIEQX9MBEAMAQsCfyAFRQRAIARBBBDSAQwBCyABKAIAIAVBBHRBBCAEEM8BCyICRQRAIARBBEG09cIAKAIAIgBBAiAAGxEAAAALIAEgAzYCBCABIAI2AgAMAQsgAEEIakEANgIAQQEhBgsgACAGNgIAC5kCAgF/A34jAEEgayIBJAAgAUEYakEUELEBIAEpAxghAiABQRBqQRQQoAEgASkDECEDIAFBCGpBFBCgASABKQMIIQQgAEGAAWpBADYCACAAQfAAakIANwIAIABB6ABqIAM3AgAgAEHgAGpCADcCACAAQdgAaiACNwIAIABB0ABqQgA3AgAgAEHIAGpCBDcCACAAQcQAakGwpsIANgIAIABBPGpCADcCACAAQTRqQgQ3AgAgAEEwakGwpsIANgIAIABBKGpCADcCACAAQSBqQgQ3AgAgAEEcakGwpsIANgIAIABBFGpCADcCACAAQQxqQgQ3AgAgAEEIakGwpsIANgIAIABCADcCACAAQfgAaiAENwIAIAFBIGokAAvuAQEHfyMAQfAAayIFJAACQAJAIAFBCE8EQCABQQJ2IgJBAXQhB0F/IQZBfyABQX9qZ3YhCCACQcgBbCAAakGcf2ohBCABIQIDQCAGIAdqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAhxIgNBACABIAMgAUkbayIDIAFPDQMgBUEMaiAEQeQAELoBGiAEIAAgA0HkAGxqIgRB5AAQmQEgBCAFQQxqQeQAELoBGkHkAGohBCAGQQFqIgZBAkcNAAsLIAVB8ABqJAAPC0Gwp8IAIAMgARCnAQALQcCnwgAgAyABEKcBAAuTAgEFfyAAKAIAIgQoAgQoAgAiBSABKAIAIghBA3RqKAIEKAIAIAUgAigCACIGQQN0aigCBCgCACIHSQRAIAEgBjYCACACIAg2AgAgBCgCCCIEIAQoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQN0aigCBCgCACEHCyAHIAUgAygCACIIQQN0aigCBCgCAEkEQCACIAg2AgAgAyAGNgIAIAQoAggiAyADKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkEDdGooAgQoAgAhBwsgBSABKAIAIgBBA3RqKAIEKAIAIAdJBEAgASAGNgIAIAIgADYCACAEKAIIIgAgACgCAEEBajYCAAsL/AECBn8BfiMAQRBrIgUkAANAIAJBAXQiA0EBciEEAkACQAJAIANBAmoiAyABSQRAIAQgAU8NASADIAQgACAEQQxsakEDIAAgA0EMbGpBAxCUAUH/AXFB/wFGGyEECyAEIAFJBEAgAiABTw0CIAAgAkEMbGoiAkEDIAAgBEEMbGoiA0EDEJQBQf8BcUH/AUYNAwsgBUEQaiQADwtBrKjCACAEIAEQpwEAC0G8qMIAIAIgARCnAQALIANBCGoiBigCACEHIAIpAgAhCSACIAMpAgA3AgAgAkEIaiICKAIAIQggAiAHNgIAIAYgCDYCACADIAk3AgAgBCECDAALAAuDAgEDfyMAQTBrIgQkAAJAAkAgASgCZEUEQCABQX82AmQgAUHwAGpBADYCACAEQQA2AhggBCABQSxqNgIUIARBAjYCECAEIAM2AgwgBCACNgIIIAFB6ABqIQYgBEEgaiAEQQhqECogBCgCIA0BDAILQd69wgBBECAEQQhqQbivwgAQlgEACwNAIAYgBCgCKCIFIAUgBCgCLEECdGoQuAEgBEEgaiAEQQhqECogBCgCIA0ACwsCQAJAIAMgASgCcEYEQCABKAJoIgUgAkYNASAFIAIgA0ECdBC1AUUNAQsgACAGEKsBDAELIABBADYCAAsgASABKAJkQQFqNgJkIARBMGokAAuAAgEFfyAAKAIAIgQoAgQoAgAiBSACKAIAIgZBAnRqKAIAIgcgBSABKAIAIghBAnRqKAIASQRAIAEgBjYCACACIAg2AgAgBCgCCCIEIAQoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQJ0aigCACEHCyAFIAMoAgAiCEECdGooAgAgB0kEfyACIAg2AgAgAyAGNgIAIAQoAggiAyADKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkECdGooAgAFIAcLIAUgASgCACIAQQJ0aigCAEkEQCABIAY2AgAgAiAANgIAIAQoAggiACAAKAIAQQFqNgIACwvjAQEGfyMAQRBrIgUkACAAKAIAIgEEQCAAKAIEIgJBBGohAyABIAJqQQFqIQYgAigCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAFFBEADQCADIAZPDQIgBEHgAGohBCADKAIAIANBBGoiAiEDQYCBgoR4cSIBQYCBgoR4Rg0ACyABQYCBgoR4cyEBIAIhAwsgBCABaEEDdkEYbGoiAigCBARAIAIoAgAQIQsgAUF/aiABcSEBIAIoAhBFDQEgAigCDBAhDAELCyAFIAAoAgBBAWoQmgEgACgCBBAhCyAFQRBqJAAL5QEBBH8jAEFAaiIEJAAgBEEIaiABIAEgAmoQIyAEQRhqIAAgBCgCCCIFIAQoAhAiARBmIAQoAgwhBgJAIAQoAhgiAkUEQCAGIQcgBSECDAELIARBIGooAgAhASAEKAIcIQcgBkUNACAFECELIARBGGogACACIAEQNyAEIAE2AjggBCAHNgI0IAQgAjYCMCAAQRhqIgAgBEEwaiADEDICQCAEKAIYIgFFDQAgBEE4aiAEQSxqKAIANgIAIAQgBEEkaikCADcDMCAAIARBMGogAxAyIAQoAhxFDQAgARAhCyAEQUBrJAAL2AEBBX8CQCABQQJJDQAgACABQX5qIgJBA3RqIgMoAgQoAgAgAUEDdCAAaiIEQXxqKAIAIgUoAgBPDQAgBEF4aiIEKAIAIQYgBCADKQIANwIAAkAgAkUNACAAIAFBfWoiAkEDdGoiASgCBCgCACAFKAIATw0AIAFBfGohASAAAn8CQANAIAFBDGogAUEEaikCADcCACACRQ0BIAJBf2ohAiABKAIAIAFBeGohASgCACAFKAIASQ0ACyACQQFqDAELQQALQQN0aiEDCyADIAU2AgQgAyAGNgIACwvdAQIHfwF+IwBBEGsiBCQAIAFBAXYiAwRAIANBf2ohAgNAIAAgASACEGUgAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQQxsIABqQXRqIQIgASEDA0AgA0F/aiIFIAFPDQIgBEEIaiAAQQhqIgMoAgAiBjYCACAEIAApAgAiCTcDACACQQhqIgcoAgAhCCAAIAIpAgA3AgAgAyAINgIAIAcgBjYCACACIAk3AgAgACAFQQAQZSACQXRqIQIgBSIDQQFLDQALCyAEQRBqJAAPC0HAp8IAIANBf2ogARCnAQAL3QEBBn8CQAJAAkAgAUECSQ0AIAAoAgQiBigCACAAQQxqKAIAKAIATw0AIAAoAgAhByAAIAApAgg3AgAgAEEIaiECAkAgAUEDSQ0AQQIhA0EDIQQDQCADIAFPDQMgBigCACAAIANBA3RqIgUoAgQoAgBPDQEgA0F/aiICIAFPDQQgACACQQN0aiAFKQIANwIAIAQgAUkEQCAFIQIgBCIDQQFqIgQgA0kNAgwBCwsgBSECCyACIAY2AgQgAiAHNgIACw8LQdCswgAgAyABEKcBAAtB4KzCACACIAEQpwEAC9QBAQZ/IwBBEGsiBSQAIAAoAgAiAgRAIAAoAgQiAUEEaiEDIAEgAmpBAWohBiABKAIAQX9zQYCBgoR4cSEBIAAoAgghBANAAkAgAQR/IAEFA0AgAyAGTw0CIARB4ABqIQQgAygCACADQQRqIgIhA0GAgYKEeHEiAUGAgYKEeEYNAAsgAiEDIAFBgIGChHhzCyICIAJBf2pxIQEgBCACaEEDdkEYbGoiAigCEEUNASACKAIMECEMAQsLIAUgACgCAEEBahCaASAAKAIEECELIAVBEGokAAvTAQEGfyMAQRBrIgUkACAAKAIAIgIEQCAAKAIEIgFBBGohAyABIAJqQQFqIQYgASgCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAEEfyABBQNAIAMgBk8NAiAEQUBrIQQgAygCACADQQRqIgIhA0GAgYKEeHEiAUGAgYKEeEYNAAsgAiEDIAFBgIGChHhzCyICIAJBf2pxIQEgBCACaEEDdkEEdGoiAigCBEUNASACKAIAECEMAQsLIAUgACgCAEEBahCjASAAKAIEECELIAVBEGokAAvOAQIGfwF+AkACQCABQQhPBEAgAUECdiICQQF0IQZBfyEFQX8gAUF/amd2IQcgAkEEdCAAakF4aiEEIAEhAgNAIAUgBmoiAyABTw0CIAJBDXQgAnMiAkERdiACcyICQQV0IAJzIgIgB3EiA0EAIAEgAyABSRtrIgMgAU8NAyAEKQIAIQggBCAAIANBA3RqIgMpAgA3AgAgAyAINwIAIARBCGohBCAFQQFqIgVBAkcNAAsLDwtBsKfCACADIAEQpwEAC0HAp8IAIAMgARCnAQALzAEBB38CQAJAIAFBCE8EQCABQQJ2IgJBAXQhBkF/IQVBfyABQX9qZ3YhByACQQN0IABqQXxqIQQgASECA0AgBSAGaiIDIAFPDQIgAkENdCACcyICQRF2IAJzIgJBBXQgAnMiAiAHcSIDQQAgASADIAFJG2siAyABTw0DIAQoAgAhCCAEIAAgA0ECdGoiAygCADYCACADIAg2AgAgBEEEaiEEIAVBAWoiBUECRw0ACwsPC0Gwp8IAIAMgARCnAQALQcCnwgAgAyABEKcBAAvJAQIDfwF+IwBBEGsiBSABNgIIIAIpAwAhBkEBIQMgASEEA0AgBiAErUL/AYOFQrODgICAIH4hBiADQQRGRQRAIAVBCGogA2ogA0EBaiEDLQAAIQQMAQsLIAIgBjcDACABBEAgACABQQJ0aiEBA0AgBSAAKAIAIgQ2AgxBASEDA0AgBiAErUL/AYOFQrODgICAIH4hBiADQQRGRQRAIAVBDGogA2ogA0EBaiEDLQAAIQQMAQsLIAIgBjcDACAAQQRqIgAgAUcNAAsLC8IBAQV/AkAgAUECSQ0AIAFBAnQgAGpBfGoiAygCACIEIAAgAUF+aiIFQQJ0aiIGKAIAIgJPDQAgAyACNgIAAkACQCAFRQ0AIAQgACABQX1qIgNBAnRqIgIoAgAiBU8NACAGIAU2AgAgA0UNASABQQJ0IABqQXBqIQEDQCAEIAEoAgAiAE8EQCABQQRqIQIMAwsgAUEEaiAANgIAIAFBfGohASADQX9qIgMNAAsgAUEEaiECDAELIAYhAgsgAiAENgIACwvUAQEBfyMAQeAAayIEJAAgBCABNgIIIAQgAzYCDCABIANGBEAgACACIAFBAnQQugEaIARB4ABqJAAPCyAEQTxqQQk2AgAgBEE0akEKNgIAIARBJGpBAzYCACAEQgM3AhQgBEHM7MIANgIQIARBCjYCLCAEIARBCGo2AkAgBCAEQQxqNgJEIARCBDcDWCAEQgE3AkwgBEHE7MIANgJIIAQgBEEoajYCICAEIARByABqNgI4IAQgBEHEAGo2AjAgBCAEQUBrNgIoIARBEGpB5OzCABC/AQALxAEBBn8CQAJAAkAgAUECSQ0AIAAoAgQiBCAAKAIAIgUQsgFFDQAgAEEEaiECIAAgBDYCAEEDIQQCQCABQQNJDQBBAiEDA0AgAyABTw0DIAAgA0ECdGoiBigCACIHIAUQsgFFDQEgA0F/aiICIAFPDQQgACACQQJ0aiAHNgIAIAQgAUkEQCAGIQIgBCIDQQFqIgQgA0kNAgwBCwsgBiECCyACIAU2AgALDwtB0KzCACADIAEQpwEAC0HgrMIAIAIgARCnAQALugEBBH8jAEHwAGsiBCQAIAFBAXYiAgRAIAJBf2ohAgNAIAAgASACEEYgAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQeQAbCAAakGcf2ohAyABIQIDQCACQX9qIgUgAU8NAiAEQQxqIABB5AAQugEaIAAgA0HkABCZASADIARBDGpB5AAQugEhAyAFQQAQRiADQZx/aiEDIAUiAkEBSw0ACwsgBEHwAGokAA8LQcCnwgAgAkF/aiABEKcBAAvBAQIEfwF+QQMhAiMAQRBrIgRBAzYCCCABKQMAIQZBASEDA0AgBiACrUL/AYOFQrODgICAIH4hBiADQQRGRQRAIARBCGogA2ogA0EBaiEDLQAAIQIMAQsLIAEgBjcDACAAQQxqIQUDQCAEIAAoAgAiAjYCDEEBIQMDQCAGIAKtQv8Bg4VCs4OAgIAgfiEGIANBBEZFBEAgBEEMaiADaiADQQFqIQMtAAAhAgwBCwsgASAGNwMAIABBBGoiACAFRw0ACwu8AQECfwJAIAAoAgQiAUUNACAAKAIMIgIEQCACQTxsIQIDQCABEKoBIAFBPGohASACQURqIgINAAsLIAAoAggEQCAAKAIEECELIABBFGoQjwEgAEGcAWooAgAEQCAAQZgBaigCABAhCyAAQagBaigCAARAIABBpAFqKAIAECELIABBuAFqEG0gAEHQAWooAgAEQCAAQcwBaigCABAhCyAAQdwBaigCACIBRQ0AIABB4AFqKAIARQ0AIAEQIQsLwgECAn8BfgNAIAJBAXQiBEEBciEDAkACQAJAIARBAmoiBCABSQRAIAMgAU8NASAEIAMgACAEQQN0aigCBCgCACAAIANBA3RqKAIEKAIASRshAwsgAyABSQRAIAIgAU8NAiAAIANBA3RqIgQoAgQoAgAgACACQQN0aiICKAIEKAIASQ0DCw8LQayowgAgAyABEKcBAAtBvKjCACACIAEQpwEACyACKQIAIQUgAiAEKQIANwIAIAQgBTcCACADIQIMAAsAC9oBAgZ/AX4jACIAIABBQGpBYHEiACQAAkBBxPHCACgCAA0AIABBGGpBFBCgASAAKQMYIQYgAEEQakEUEKABIABCADcCLCAAIAY3AiRByPHCACgCAEHI8cIAIAApAyg3AwAgAEEANgIgQcTxwgAoAgAhAUHA8cIAIAApAyA3AwAgAEEANgI8IAAgACkDEDcCNEHY8cIAKAIAIQRB2PHCACAAKQM4NwMAQdTxwgAoAgAhBUHQ8cIAIAApAzA3AwAgAUUNAARAIAEQIQsgBEUNACAFECELJABBwPHCAAuuAQEEfwNAIAJBAXQiBEEBciEDAkACQAJAIARBAmoiBCABSQRAIAMgAU8NASAEIAMgACADQQJ0aigCACAAIARBAnRqKAIASRshAwsgAyABSQRAIAIgAU8NAiAAIAJBAnRqIgIoAgAiBCAAIANBAnRqIgUoAgAiBkkNAwsPC0GsqMIAIAMgARCnAQALQbyowgAgAiABEKcBAAsgAiAGNgIAIAUgBDYCACADIQIMAAsAC7IBAQR/A0AgAkEBdCIEQQFyIQMCQAJAAkAgBEECaiIEIAFJBEAgAyABTw0BIAQgAyAAIANBAnRqKAIAIAAgBEECdGooAgAQsgEbIQMLIAMgAUkEQCACIAFPDQIgACACQQJ0aiICKAIAIgQgACADQQJ0aiIFKAIAIgYQsgENAwsPC0GsqMIAIAMgARCnAQALQbyowgAgAiABEKcBAAsgAiAGNgIAIAUgBDYCACADIQIMAAsAC6cBAgR/AXwCQCAAKAIAIgNFDQADQCAAKAIEIAAoAgAiBCACbEEDdGogBLg5AwAgACgCBCABaiAAKAIAuDkDACABQQhqIQEgAyACQQFqIgJHDQALIAAoAgAiAkECSQ0AQQEhAQNAIAAoAgQgACgCACABbEEDdGpBCGogAUF/argiBTkDACAAKAIEIAEgACgCAGpBA3RqIAU5AwAgAiABQQFqIgFHDQALCwurAQEFfwJAIAFBAkkNACABQQJ0IABqQXxqIgUoAgAiAiAAIAFBfmoiA0ECdGoiBCgCACIGELIBRQ0AIAUgBjYCAAJAIANFDQAgAiABQQJ0IABqIgBBdGooAgAQsgFFDQAgAEFwaiEAA0ACQCAAIgFBCGogAUEEaigCADYCACADQX9qIgNFDQAgAUF8aiEAIAIgASgCABCyAQ0BCwsgAUEEaiEECyAEIAI2AgALC88BAwF/An4CfCMAQRBrIQEgAL0iAkI0iEL/D4MiA0KyCFgEfCAAmiAAIAJCAFMbIQQCfAJAIANC/gdaBEAgBEQAAAAAAAAwQ6BEAAAAAAAAMMOgIAShIgVEAAAAAAAA4D9kDQEgBCAFoCIAIAVEAAAAAAAA4L9lQQFzDQIaIABEAAAAAAAA8D+gDAILIAEgBEQAAAAAAAAwQ6A5AwggASsDCBogAEQAAAAAAAAAAKIPCyAEIAWgRAAAAAAAAPC/oAsiAJogACACQgBTGwUgAAsLsgEBBX8CQAJAIAEoAgAiAgRAIAEoAgQhBAwBCyABKAIMIQUgASgCCCEDA0AgAyAFTw0CIAMoAgAhAiABIANBBGoiAzYCCCABIAEoAgRBoAFqIgQ2AgQgASACQYCBgoR4cSIGQYCBgoR4cyICNgIAIAZBgIGChHhGDQALCyABIAJBf2ogAnE2AgAgASABKAIQQX9qNgIQIAAgBCACaEEDdkEobGpBKBC6ARoPCyAAQQI6ACQLqwEBB38CQAJAIAAoAggiA0ECSQRAIAMhAQwBCyAAKAIAIgZBBGohAkEBIQFBASEEA0AgAigCACIHIAFBAnQgBmoiBUF8aigCAEcEQCABIARHBEAgAiAFKAIANgIAIAUgBzYCAAsgAUEBaiEBCyACQQRqIQIgAyAEQQFqIgRHDQALIAEgA0sEQEG1rcIAQRxB1K3CABC5AQALIAAoAgggAUkNAQsgACABNgIICwuTAQEBfyMAQdAAayIFJAAgBUEQaiABIAEgAmoQIyAFQSBqIAMgAyAEahAjIAVBOGogBUEYaigCADYCACAFIAUpAxA3AzAgBUHIAGogBUEoaigCADYCACAFIAUpAyA3A0AgBSAAQSxqIAVBMGogBUFAaxAsAkAgBSgCACIARQ0AIAUoAgRFDQAgABAhCyAFQdAAaiQAC5MBAQF/IwBB0ABrIgUkACAFQRBqIAEgASACahAjIAVBIGogAyADIARqECMgBUE4aiAFQRhqKAIANgIAIAUgBSkDEDcDMCAFQcgAaiAFQShqKAIANgIAIAUgBSkDIDcDQCAFIABBQGsgBUEwaiAFQUBrECwCQCAFKAIAIgBFDQAgBSgCBEUNACAAECELIAVB0ABqJAALoQEBAn8CQAJAIAAoAgQiAyABayACSQRAIAEgAmoiAiABSQ0BIANBAXQiASACIAEgAksbIgFB/////wNxIAFHDQEgAUECdCIEQQBIDQECfyADRQRAIARBBBDSAQwBCyAAKAIAIANBAnRBBCAEEM8BCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQzgEACyAEQQRBtPXCACgCACIAQQIgABsRAAAAC6ABAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf///z9xIAFHDQEgAUEFdCIEQQBIDQECfyADRQRAIARBCBDSAQwBCyAAKAIAIANBBXRBCCAEEM8BCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQzgEACyAEQQhBtPXCACgCACIAQQIgABsRAAAAC6EBAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf////8BcSABRw0BIAFBA3QiBEEASA0BAn8gA0UEQCAEQQgQ0gEMAQsgACgCACADQQN0QQggBBDPAQsiAkUNAiAAIAE2AgQgACACNgIACw8LEM4BAAsgBEEIQbT1wgAoAgAiAEECIAAbEQAAAAuwAQEDfwJAAkACQCABKAIEIgMgASgCCCICRgRAIAMhAgwBCyADIAJJDQECQCACRQRAIAMEQCABKAIAECELIAFBBDYCAEEAIQIMAQsgASgCACADQQJ0QQQgAkECdCIEEM8BIgNFDQMgASADNgIACyABIAI2AgQLIAAgAjYCBCAAIAEoAgA2AgAPC0Hl7sIAQSRBjO/CABC5AQALIARBBEG09cIAKAIAIgBBAiAAGxEAAAALngEBCH8gACgCBCgCACIDIAEoAgAiBkHkAGxqQUBrIQcgAyACKAIAIghB5ABsakFAayEJAkADQCAEQQRqIgNBKEYNASAEIAlqIQogBCAHaiEFIAMhBEF/IAUoAgAiBSAKKAIAIgNHIAUgA0gbIgNFDQALIANB/wFxQf8BRw0AIAEgCDYCACACIAY2AgAgACgCCCIAIAAoAgBBAWo2AgALC5gBAQN/IAFBAXYiAwRAIANBf2ohAgNAIAAgASACEHogAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQQJ0IABqQXxqIQIgASEDA0AgA0F/aiIEIAFPDQIgACgCACEDIAAgAigCADYCACACIAM2AgAgACAEQQAQeiACQXxqIQIgBCIDQQFLDQALCw8LQcCnwgAgA0F/aiABEKcBAAuYAQEDfyABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB7IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUECdCAAakF8aiECIAEhAwNAIANBf2oiBCABTw0CIAAoAgAhAyAAIAIoAgA2AgAgAiADNgIAIAAgBEEAEHsgAkF8aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCnAQALmgECA38BfiABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB4IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUEDdCAAakF4aiECIAEhAwNAIANBf2oiBCABTw0CIAApAgAhBSAAIAIpAgA3AgAgAiAFNwIAIAAgBEEAEHggAkF4aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCnAQALnAEBAn8CQAJAIAEgACgCBEYEQCABQQFqIgIgAUkNASABQQF0IgMgAiADIAJLGyICQf////8BcSACRw0BIAJBA3QiA0EASA0BAn8gAUUEQCADQQQQ0gEMAQsgACgCACABQQN0QQQgAxDPAQsiAUUNAiAAIAI2AgQgACABNgIACw8LEM4BAAsgA0EEQbT1wgAoAgAiAEECIAAbEQAAAAu3AQIEfwJ+IwBBMGsiACQAAkBBiPDCACgCAA0AIABBGGpBChBTIABBEGogAEEsaigCACIDNgIAIABBCGogAEEkaikCACIENwMAQYTwwgAoAgAhAUGI8MIAKAIAIQJBhPDCACAAKQIcIgU3AgBBjPDCACAENwIAQZTwwgAgAzYCACAAIAU3AwBBgPDCAEEANgIAIAFFIAJFcg0AIABBGGogAUEBahCcASACECELIABBMGokAEGA8MIAC7cBAgR/An4jAEEwayIAJAACQEHQ8MIAKAIADQAgAEEYakEKEFMgAEEQaiAAQSxqKAIAIgM2AgAgAEEIaiAAQSRqKQIAIgQ3AwBBzPDCACgCACEBQdDwwgAoAgAhAkHM8MIAIAApAhwiBTcCAEHU8MIAIAQ3AgBB3PDCACADNgIAIAAgBTcDAEHI8MIAQQA2AgAgAUUgAkVyDQAgAEEYaiABQQFqEJwBIAIQIQsgAEEwaiQAQcjwwgALqAEBAn8CQAJAAkAgASgCBCIDIAEoAggiAkYEQCADIQIMAQsgAyACSQ0BAkAgAkUEQCADBEAgASgCABAhCyABQQE2AgBBACECDAELIAEoAgAgA0EBIAIQzwEiA0UNAyABIAM2AgALIAEgAjYCBAsgACACNgIEIAAgASgCADYCAA8LQeXuwgBBJEGM78IAELkBAAsgAkEBQbT1wgAoAgAiAEECIAAbEQAAAAuMAQECfyMAQRBrIgEkACAAKAIEIgIEQCABIAJBAWoQogEgAEEIaigCABAhCyAAQRhqEG4gAEEsahBoIABBQGsQaCAAQdwAaigCAARAIABB2ABqKAIAECELIABB7ABqKAIABEAgAEHoAGooAgAQIQsgAEH8AGooAgAEQCAAQfgAaigCABAhCyABQRBqJAALigEBBH8gACAAKAIQIgJBAWoiATYCEAJAIAEgAkkNACAAQQxqIABBCGogACgCACICQQFGGygCACIDIAFGDQAgAEEMaiAAQQhqIAIbIQQDQCADIAFLBEAgACgCBCABaiwAAEG/f0oNAgsgACABQQFqIgI2AhAgAiABSQ0BIAQoAgAiAyACIgFHDQALCwuLAQEBfwJAAkAgACgCBCIDIAFrIAJJBEAgASACaiICIAFJDQEgA0EBdCIBIAIgASACSxsiAUEASA0BAn8gA0UEQCABQQEQ0gEMAQsgACgCACADQQEgARDPAQsiAkUNAiAAIAE2AgQgACACNgIACw8LEM4BAAsgAUEBQbT1wgAoAgAiAEECIAAbEQAAAAudAQIDfwF8IAAgACgCCCACIAFrEIUBIAAoAgghAyAAIAEgAkcEfyAAKAIAIANBA3RqIQQgASEAA0AgAC0AACEFIABBAWohAEQAAAAAAADwPyEGAkAgBUF8aiIFQQNLDQACQCAFQQFrDgIBAQALRAAAAAAAAOA/IQYLIAQgBjkDACAEQQhqIQQgACACRw0ACyACIAMgAWtqBSADCzYCCAuDAQEDfyMAQSBrIgIkACABKAIAIQMgAkEIaiABKAIIIgEQoAEgAkEANgIYIAIgAikDCDcDECACQRBqQQAgARCDASACIAEgAigCGCIEajYCGCACKAIQIARBAnRqIAMgAUECdBC6ARogAEEIaiACKAIYNgIAIAAgAikDEDcCACACQSBqJAALbAEFfyADIAEgASADSxshCANAAkAgBCAISQRAIARBAWoiBSAETw0BC0F/IAEgA0cgASADSRsPCyACKAIAIQYgACgCACEHIAJBBGohAiAAQQRqIQAgBSEEQX8gBiAHRyAHIAZJGyIFRQ0ACyAFC34BAX8CQAJAIAEgAUH/////AXFGBEAgAUEDdCIDQX9MDQECQCADRQRAQQghAgwBCwJ/IAJFBEAgA0EIENIBDAELIAMQvgELIgJFDQMLIAAgATYCBCAAIAI2AgAPCxDTAQALENMBAAsgA0EIQbT1wgAoAgAiAEECIAAbEQAAAAuBAQEBfyMAQUBqIgQkACAEIAE2AgwgBCAANgIIIAQgAzYCFCAEIAI2AhAgBEEsakECNgIAIARBPGpBAzYCACAEQgI3AhwgBEGUqsAANgIYIARBBDYCNCAEIARBMGo2AiggBCAEQRBqNgI4IAQgBEEIajYCMCAEQRhqQaiqwAAQvwEAC3ABBX8gACgCBCEDIAGnIQIgACgCACEEQQAhAANAIABBBGoiACACIARxIgVqIQIgAyAFaigAAEGAgYKEeHEiBkUNAAsgAyAGaEEDdiAFaiAEcSICaiwAAEEATgR/IAMoAgBBgIGChHhxaEEDdgUgAgsLdAECfyAAIAAoAgggARCDASAAKAIAIAAoAggiAkECdGohAwJAAkAgAUECTwRAIAFBf2ohAQNAIANBADYCACACQQFqIQIgA0EEaiEDIAFBf2oiAQ0ACwwBCyABRQ0BCyADQQA2AgAgAkEBaiECCyAAIAI2AggLbwEBfwJAIAEgAE8EQCACRQ0BIAAhAwNAIAMgAS0AADoAACABQQFqIQEgA0EBaiEDIAJBf2oiAg0ACwwBCyACRQ0AIAFBf2ohASAAQX9qIQMDQCACIANqIAEgAmotAAA6AAAgAkF/aiICDQALCyAAC2YCAX8BfiABrUIYfiIDQiCIpwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAOnaiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtnAgF/AX4gAa1C7AF+IgNCIIinBEAgAEEANgIEDwsCQAJAIAFBB2pBfHEiAiABQQRqSQ0AIAIgA6dqIgEgAkkNACABQX1JDQELIABBADYCBA8LIAAgAjYCCCAAQQQ2AgQgACABNgIAC2YCAX8BfiABrUIofiIDQiCIpwRAIABBADYCBA8LAkACQCABQQtqQXhxIgIgAUEEakkNACACIAOnaiIBIAJJDQAgAUF5SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEINgIEIAAgATYCAAtwAgJ/AX4CQAJAIAGtQuQAfiIEQiCIp0UEQCAEpyICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ0gEiA0UNAwsgACABNgIEIAAgAzYCAA8LENMBAAsQ0wEACyACQQRBtPXCACgCACIAQQIgABsRAAAAC2cBA38jAEEgayICJAACQCAAKAIAIAEQWw0AIAFBHGooAgAhAyABKAIYIAJCBDcDGCACQgE3AgwgAkG8hcAANgIIIAMgAkEIahAbDQAgACgCBCABEFsgAkEgaiQADwsgAkEgaiQAQQELbgECfwJAAkAgASABQf////8AcUYEQCABQQR0IgJBf0wNAQJAIAJFBEBBBCEDDAELIAJBBBDSASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ0wEACxDTAQALIAJBBEG09cIAKAIAIgBBAiAAGxEAAAALbgECfwJAAkAgASABQf////8DcUYEQCABQQJ0IgJBf0wNAQJAIAJFBEBBBCEDDAELIAJBBBDSASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ0wEACxDTAQALIAJBBEG09cIAKAIAIgBBAiAAGxEAAAALbgECfwJAAkAgASABQf////8BcUYEQCABQQN0IgJBf0wNAQJAIAJFBEBBBCEDDAELIAJBBBDSASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ0wEACxDTAQALIAJBBEG09cIAKAIAIgBBAiAAGxEAAAALZgEBfyABIAFB/////wFxRwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAFBA3RqIgEgAkkNACABQX1JDQELIABBADYCBA8LIAAgAjYCCCAAQQQ2AgQgACABNgIAC2YBAX8gASABQf////8AcUcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiABQQR0aiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAttAQJ/AkACQCABIAFB////P3FGBEAgAUEFdCICQX9MDQECQCACRQRAQQghAwwBCyACQQgQ0gEiA0UNAwsgACABNgIEIAAgAzYCAA8LENMBAAsQ0wEACyACQQhBtPXCACgCACIAQQIgABsRAAAAC2ABAn8CQCAAKAIAIgFFDQAgAEEIaigCACICBEAgAkEEdCECIAFBCGohAQNAIAEoAgAEQCABQXxqKAIAECELIAFBEGohASACQXBqIgINAAsLIAAoAgRFDQAgACgCABAhCwtwAQF/IwBBMGsiAiQAIAIgATYCBCACIAA2AgAgAkEcakECNgIAIAJBLGpBBTYCACACQgI3AgwgAkHAgsAANgIIIAJBBTYCJCACIAJBIGo2AhggAiACQQRqNgIoIAIgAjYCICACQQhqQdCCwAAQvwEAC20BAX8jAEEwayIDJAAgAyACNgIEIAMgATYCACADQRxqQQI2AgAgA0EsakEFNgIAIANCAjcCDCADQaSAwAA2AgggA0EFNgIkIAMgA0EgajYCGCADIAM2AiggAyADQQRqNgIgIANBCGogABC/AQALcAEBfyMAQTBrIgIkACACIAE2AgQgAiAANgIAIAJBHGpBAjYCACACQSxqQQU2AgAgAkICNwIMIAJBiIPAADYCCCACQQU2AiQgAiACQSBqNgIYIAIgAkEEajYCKCACIAI2AiAgAkEIakGYg8AAEL8BAAtWAQJ/IwBBIGsiAiQAIAFBHGooAgAhAyABKAIYIAJBGGogAEEQaikCADcDACACQRBqIABBCGopAgA3AwAgAiAAKQIANwMIIAMgAkEIahAbIAJBIGokAAtTACAAQQxqKAIABEAgACgCCBAhCyAAQRhqKAIABEAgAEEUaigCABAhCyAAQSRqKAIABEAgAEEgaigCABAhCyAAQTBqKAIABEAgAEEsaigCABAhCwtkAQJ/IwBBIGsiAiQAIAEoAgAhAyACQQhqIAEoAggiARCgASACQQA2AhggAiACKQMINwMQIAJBEGogAyADIAFBAnRqELgBIABBCGogAigCGDYCACAAIAIpAxA3AgAgAkEgaiQAC1oBA38CQCABQQhqKAIAIgIgASgCBCIDTwRAIAFBHGooAgAiBCACSQ0BIAEoAhghASAAIAIgA2s2AgQgACABIANBAnRqNgIADwsgAyACEKgBAAsgAiAEEKYBAAtXAQN/AkAgAUEIaigCACICIAEoAgQiA08EQCABQSRqKAIAIgQgAkkNASABKAIgIQEgACACIANrNgIEIAAgASADajYCAA8LIAMgAhCoAQALIAIgBBCmAQALagECf0EBIQACQAJAQaj1wgAoAgBBAUcEQEGo9cIAQoGAgIAQNwMADAELQaz1wgBBrPXCACgCAEEBaiIANgIAIABBAksNAQtBsPXCACgCACIBQX9MDQBBsPXCACABNgIAIABBAUsNAAALAAtYAQN/IAAoAgQoAgAiAyACKAIAIgRBDGxqQQMgAyABKAIAIgVBDGxqQQMQlAFB/wFxQf8BRgRAIAEgBDYCACACIAU2AgAgACgCCCIAIAAoAgBBAWo2AgALC0sBA38gAEEMaigCACIDBEAgACgCBCADQTxsakFEaiECIAMhAQNAIAIQqgEgAkFEaiECIAFBf2oiAQ0ACwsgAEEANgIAIAAgATYCDAtUAQF/AkAgAUF/SgRAAkAgAUUEQEEBIQIMAQsgAUEBENIBIgJFDQILIAAgATYCBCAAIAI2AgAPCxDTAQALIAFBAUG09cIAKAIAIgBBAiAAGxEAAAALTgECf0F/IAEoAjgiAiAAKAI4IgNHIAIgA0kbIgIEfyACBSAAQSBqKAIAIABBKGooAgAgAUEgaigCACABQShqKAIAEJQBC0H/AXFB/wFGC1IBA38gACgCBCgCACIDIAIoAgAiBEECdGooAgAgAyABKAIAIgVBAnRqKAIAELIBBEAgASAENgIAIAIgBTYCACAAKAIIIgAgACgCAEEBajYCAAsLTgEBfyMAQRBrIgIkACACIAAgAUEBQQEQYQJAIAIoAgBBAUYEQCACQQhqKAIARQ0BQbPvwgBBKEHc78IAELkBAAsgAkEQaiQADwsQzgEAC0MBA38CQCACRQ0AA0AgAC0AACIEIAEtAAAiBUYEQCABQQFqIQEgAEEBaiEAIAJBf2oiAg0BDAILCyAEIAVrIQMLIAMLSgACfyABQYCAxABHBEBBASAAKAIYIAEgAEEcaigCACgCEBEBAA0BGgsgAkUEQEEADwsgACgCGCACIAMgAEEcaigCACgCDBEDAAsLRgECfyAAKAIIIgIgAUsEQCAAKAIAIAJB5ABsakGcf2ohAwNAIAMQvQEgA0Gcf2ohAyABIAJBf2oiAkcNAAsLIAAgAjYCCAs6AQF/IAAgACgCCCACIAFrQQJ2IgIQgwEgACAAKAIIIgMgAmo2AgggACgCACADQQJ0aiACIAEgAhBzCzsBAX8jAEEgayIDJAAgA0IENwMQIANCATcCBCADIAE2AhwgAyAANgIYIAMgA0EYajYCACADIAIQvwEACzMBAX8gAgRAIAAhAwNAIAMgAS0AADoAACADQQFqIQMgAUEBaiEBIAJBf2oiAg0ACwsgAAtFAQJ/IwBBEGsiASQAIAAoAgwhAiAAKAIIRQRAQZTuwgBBK0HA7sIAELkBAAsgASACKQIINwMIIAEgAikCADcDABCuAQALKwACQCAAQXxLDQAgAEUEQEEEDwsgACAAQX1JQQJ0ENIBIgBFDQAgAA8LAAsmACAAQSxqKAIABEAgACgCKBAhCyAAQThqKAIABEAgACgCNBAhCwsqAQF/AkAgABADIgFFDQAgAUF8ai0AAEEDcUUNACABQQAgABDAARoLIAELMgEBfyMAQRBrIgIkACACIAE2AgwgAiAANgIIIAJBtIDAADYCBCACQQE2AgAgAhC7AQALKQEBfyACBEAgACEDA0AgAyABOgAAIANBAWohAyACQX9qIgINAAsLIAALPgBBoPDCACgCAEUEQEGk8MIAQgQ3AgBBoPDCAEGwpsIANgIAQZjwwgBCADcCAEGs8MIAQQA2AgALQZjwwgALPgBBuPDCACgCAEUEQEG88MIAQgQ3AgBBuPDCAEGwpsIANgIAQbDwwgBCADcCAEHE8MIAQQA2AgALQbDwwgALIAACQCABQXxLDQAgACABQQQgAhDPASIARQ0AIAAPCwALJgAgACgCACABIAIQswEgACgCACACIAMQswEgACgCACABIAIQswELJgAgACgCACABIAIQhwEgACgCACACIAMQhwEgACgCACABIAIQhwELJgAgACgCACABIAIQrwEgACgCACACIAMQrwEgACgCACABIAIQrwELJgEBfyAAKAIAIgEoAgAgASgCBCAAKAIEKAIAIAAoAggoAgAQGgALFAAgACAAIAEgACABYxsgASABYhsLHAAgASgCGEH4qcAAQQsgAUEcaigCACgCDBEDAAscACABKAIYQYOqwABBDiABQRxqKAIAKAIMEQMACxwAIAEoAhhBie7CAEELIAFBHGooAgAoAgwRAwALFAAgACgCACABIAAoAgQoAgwRAQALCwAgAQRAIAAQIQsLEgBBgIDAAEERQZSAwAAQuQEACwwAIAAgASACIAMQIgsLACAANQIAIAEQTwsGABCuAQALGQACfyABQQlPBEAgASAAEEQMAQsgABADCwsGABDOAQALDQBCs5rmjsnyhtztAAsDAAELAwABCwvYvQK4DQBBgIDAAAv8BWNhcGFjaXR5IG92ZXJmbG93AAAAnLcQABcAAAAJAwAABQAAAEQAEAAgAAAAZAAQABIAAAALAAAAAAAAAAEAAAAMAAAAaW5kZXggb3V0IG9mIGJvdW5kczogdGhlIGxlbiBpcyAgYnV0IHRoZSBpbmRleCBpcyAwMDAxMDIwMzA0MDUwNjA3MDgwOTEwMTExMjEzMTQxNTE2MTcxODE5MjAyMTIyMjMyNDI1MjYyNzI4MjkzMDMxMzIzMzM0MzUzNjM3MzgzOTQwNDE0MjQzNDQ0NTQ2NDc0ODQ5NTA1MTUyNTM1NDU1NTY1NzU4NTk2MDYxNjI2MzY0NjU2NjY3Njg2OTcwNzE3MjczNzQ3NTc2Nzc3ODc5ODA4MTgyODM4NDg1ODY4Nzg4ODk5MDkxOTI5Mzk0OTU5Njk3OTg5OQAAYAEQAAYAAABmARAAIgAAAHS2EAAYAAAAaAoAAAUAAABpbmRleCAgb3V0IG9mIHJhbmdlIGZvciBzbGljZSBvZiBsZW5ndGggqAEQABYAAAC+ARAADQAAAHS2EAAYAAAAbgoAAAUAAABzbGljZSBpbmRleCBzdGFydHMgYXQgIGJ1dCBlbmRzIGF0IGAweFsuLi5dAHoCEAALAAAA4hQQABYAAADLARAAAQAAAGQCEAAWAAAAAwgAAAkAAADAFBAADgAAAM4UEAAEAAAA0hQQABAAAADLARAAAQAAAGQCEAAWAAAABwgAAAUAAAB6AhAACwAAAIUCEAAmAAAAqwIQAAgAAACzAhAABgAAAMsBEAABAAAAZAIQABYAAAAUCAAABQAAAHNyYy9saWJjb3JlL3N0ci9tb2QucnNieXRlIGluZGV4ICBpcyBub3QgYSBjaGFyIGJvdW5kYXJ5OyBpdCBpcyBpbnNpZGUgIChieXRlcyApIG9mIGAAAAD6AhAAAgAAAOQCEAAWAAAAYAQAABEAAADkAhAAFgAAAFQEAAAoAAAAc3JjL2xpYmNvcmUvZm10L21vZC5ycy4uAEHghsAACw7//////////////////wBBkIfAAAsC+AMAQbKHwAALB/7/////v7YAQcKHwAALDf8HAAAAAAD4//8AAAEAQdqHwAALEMCfnz0AAAAAAgAAAP///wcAQfSHwAALuBXA/wEAAAAAAAD4DyCgDhAASgAAAPAQEAAAAgAA8BIQADoAAAAAAQIDBAUGBwgJCAoLDA0ODxAREhMUAhUWFxgZGhscHR4fIAICAgICAgICAgIhAgICAgICAgICAgICAgIiIyQlJgInAigCAgIpKisCLC0uLzACAjECAgIyAgICAgICAgIzAgI0AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI1AjYCNwICAgICAgICOAI5AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI6OzwCAgICPQICPj9AQUJDREVGAgICRwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJIAgICAgICAgICAgJJAgICAgI7AgABAgICAgMCAgICBAIFBgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIHAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAAQMFBQYGAwcGCAgJEQocCxkMFA0SDg0PBBADEhITCRYBFwUYAhkDGgccAh0BHxYgAysELAItCy4BMAMxAjIBpwKpAqoEqwj6AvsF/QT+A/8JrXh5i42iMFdYi4yQHB3dDg9LTPv8Li8/XF1fteKEjY6RkqmxurvFxsnK3uTl/wAEERIpMTQ3Ojs9SUpdhI6SqbG0urvGys7P5OUABA0OERIpMTQ6O0VGSUpeZGWEkZudyc7PDREpRUlXZGWNkam0urvFyd/k5fAEDRFFSWRlgIGEsry+v9XX8PGDhYukpr6/xcfOz9rbSJi9zcbOz0lOT1dZXl+Jjo+xtre/wcbH1xEWF1tc9vf+/4ANbXHe3w4PH25vHB1ffX6ur7u8+hYXHh9GR05PWFpcXn5/tcXU1dzw8fVyc490dZaXL18mLi+nr7e/x8/X35pAl5gwjx/Awc7/Tk9aWwcIDxAnL+7vbm83PT9CRZCR/v9TZ3XIydDR2Nnn/v8AIF8igt8EgkQIGwQGEYGsDoCrNR4VgOADGQgBBC8ENAQHAwEHBgcRClAPEgdVCAIEHAoJAwgDBwMCAwMDDAQFAwsGAQ4VBToDEQcGBRAHVwcCBxUNUARDAy0DAQQRBg8MOgQdJV8gbQRqJYDIBYKwAxoGgv0DWQcVCxcJFAwUDGoGCgYaBlkHKwVGCiwEDAQBAzELLAQaBgsDgKwGCgYfQUwELQN0CDwDDwM8BzgIKwWC/xEYCC8RLQMgECEPgIwEgpcZCxWIlAUvBTsHAg4YCYCwMHQMgNYaDAWA/wWAtgUkDJvGCtIwEISNAzcJgVwUgLgIgMcwNQQKBjgIRggMBnQLHgNaBFkJgIMYHAoWCUgIgIoGq6QMFwQxoQSB2iYHDAUFgKURgW0QeCgqBkwEgI0EgL4DGwMPDQAGAQEDAQQCCAgJAgoFCwIQAREEEgUTERQCFQIXAhkEHAUdCCQBagNrArwC0QLUDNUJ1gLXAtoB4AXhAugC7iDwBPkG+gIMJzs+Tk+Pnp6fBgcJNj0+VvPQ0QQUGDY3Vle9Nc7P4BKHiY6eBA0OERIpMTQ6RUZJSk5PZGVaXLa3GxyoqdjZCTeQkagHCjs+ZmmPkm9f7u9aYpqbJyhVnaCho6SnqK26vMQGCwwVHTo/RVGmp8zNoAcZGiIlPj/FxgQgIyUmKDM4OkhKTFBTVVZYWlxeYGNlZmtzeH1/iqSqr7DA0Axyo6TLzG5vXiJ7BQMELQNlBAEvLoCCHQMxDxwEJAkeBSsFRAQOKoCqBiQEJAQoCDQLAYCQgTcJFgoIgJg5A2MICTAWBSEDGwUBQDgESwUvBAoHCQdAICcEDAk2AzoFGgcEDAdQSTczDTMHLggKgSYfgIEoCCqAhhcJTgQeD0MOGQcKBkcJJwl1Cz9BKgY7BQoGUQYBBRADBYCLYCBICAqApl4iRQsKBg0TOQcKNiwEEIDAPGRTDAGAoEUbSAhTHTmBB0YKHQNHSTcDDggKBjkHCoE2GYDHMg2Dm2Z1C4DEiryEL4/RgkehuYI5ByoEAmAmCkYKKAUTgrBbZUsEOQcRQAQcl/gIgvOlDYEfMQMRBAiBjIkEawUNAwkHEJNggPYKcwhuF0aAmhQMVwkZgIeBRwOFQg8VhVArgNUtAxoEAoFwOgUBhQCA1ylMBAoEAoMRREw9gMI8BgEEVQUbNAKBDiwEZAxWCg0DXQM9OR0NLAQJBwIOBoCag9YKDQMLBXQMWQcMFAwEOAgKBigIHlJ3AzEDgKYMFAQDBQMNBoVqAAAAgA4QACAAAAAnAAAAGQAAAIAOEAAgAAAAKAAAACAAAACADhAAIAAAACoAAAAZAAAAgA4QACAAAAArAAAAGAAAAIAOEAAgAAAALAAAACAAAABzcmMvbGliY29yZS91bmljb2RlL2Jvb2xfdHJpZS5ycwAAwPvvPgAAAAAADgBBup3AAAuSAfj/+////wcAAAAAAAAU/iH+AAwAAAACAAAAAAAAUB4ggAAMAABABgAAAAAAABCGOQIAAAAjAL4hAAAMAAD8AgAAAAAAANAeIMAADAAAAAQAAAAAAABAASCAAAAAAAARAAAAAAAAwME9YAAMAAAAAgAAAAAAAJBEMGAADAAAAAMAAAAAAABYHiCAAAwAAAAAhFyAAEHWnsAACwTyB4B/AEHmnsAACwTyHwA/AEHznsAACxYDAACgAgAAAAAAAP5/3+D//v///x9AAEGVn8AAC6UB4P1mAAAAwwEAHgBkIAAgAAAAAAAAAOAAAAAAAAAcAAAAHAAAAAwAAAAMAAAAAAAAALA/QP4PIAAAAAAAOAAAAAAAAGAAAAAAAgAAAAAAAIcBBA4AAIAJAAAAAAAAQH/lH/ifAAAAAAAA/38PAAAAAADwFwQAAAAA+A8AAwAAADw7AAAAAAAAQKMDAAAAAAAA8M8AAAD3//0hEAP/////////+wAQAEHCoMAACw3/////AQAAAAAAAIADAEHXoMAACxWAAAAAAP////8AAAAAAPwAAAAAAAYAQfWgwAALB4D3PwAAAMAAQYahwAALLwMARAgAAGAAAAAwAAAA//8DgAAAAADAPwAAgP8DAAAAAAAHAAAAAADIMwAAAAAgAEG9ocAACzF+ZgAIEAAAAAAAEAAAAAAAAJ3BAgAAAAAwQAAAAAAAICEAAAAAAEAAAAAA//8AAP//AEH3ocAACwcBAAAAAgADAEGYosAACwQEAAAFAEGkosAACwEGAEGtosAACz8HAAAICQoACwwNDg8AABAREgAAExQVFgAAFxgZGhsAHAAAAB0AAAAAAAAeHyAhAAAAAAAiACMAJCUmAAAAACcAQdujwAALAigpAEHto8AACwIqKwBBoqTAAAsBLABBtaTAAAsFLS4AAC8AQdikwAALAzAxMgBB8KTAAAsMMwAAACkAAAAAAAA0AEGTpcAACwM1ADYAQbClwAALCDc4AAA4ODg5AEH/pcAACwYgAAAAAAEAQY6mwAALVMAHbvAAAAAAAIcAAAAAYAAAAAAAAADwAAAAwP8BAAAAAAACAAAAAAAA/38AAAAAAACAAwAAAAAAeAYHAAAAgO8fAAAAAAAAAAgAAwAAAAAAwH8AHgBB7abAAAsagNNAAAAAgPgHAAADAAAAAAAAWAEAgADAHx8AQY+nwAALBf9cAABAAEGep8AACwP5pQ0AQa2nwAALB4A8sAEAADAAQb6nwAALA/inAQBBzafAAAsvKL8AAAAA4LwPAAAAAAAAAID/BgAA8AwBAAAA/gcAAAAA+HmAAH4OAAAAAAD8fwMAQYaowAALE3+/AAD8///8bQAAAAAAAAB+tL8AQaKowAALAaMAQa6owAALHRgAAAAAAAAAHwAAAAAAAAB/AACAAAAAAAAAAIAHAEHTqMAACwFgAEHcqMAAC0agwwf45w8AAAA8AAAcAAAAAAAAAP///////3/4//////8fIAAQAAD4/v8AAH////nbBwAAAAAAAADwAAAAAH8AAAAAAPAHAEGsqcAAC/kC////////////////////////AABiZWdpbiA8PSBlbmQgKCA8PSApIHdoZW4gc2xpY2luZyBgIGlzIG91dCBvZiBib3VuZHMgb2YgYEJvcnJvd0Vycm9yQm9ycm93TXV0RXJyb3IAAAAOtRAAAAAAACQVEAACAAAAOiAAADgVEAAVAAAAjQQAAAUAAABzcmMvbGliY29yZS9yZXN1bHQucnMAAQIBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEDAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQQFAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEDAACADhAAIAAAADoAAAAwAAAAAD4AAAEAQbCswAALLCAAAAABAAAAAQAAAAAAAAD/BwAAAIMAAAAAAIAAAAAAAAEAAAAAAAD/////AEHnrMAACwmAAAAAAAAA/wMAQf6swAALAgxyAEG0rsAACwL/AwBBxq7AAAsC/wMAQeCuwAALHf8DAAAAAAAAYBwQACEAAABoHRAAAAIAAGgfEAAuAEGFr8AACx4BAAIAAQABAAMABAAFAAEABgABAAcABwgAAAAACQcAQa2vwAALAQoAQbuvwAALFwsAAAAMBwAAAAANAA4AAA8AAAcQAAAPAEHhr8AACwYRCQAAEhMAQfGvwAALAxQVFgBB/a/AAAsCFxgAQZOwwAALARkAQaCwwAALCxoAAAAAABsAHB0eAEH4s8AACxgcAAABAAAAAB8AAAcJAAAgAAcAAAAAABAAQdy2wAALhAIHAAAAAAECAwMDBAMDAwMDAwUGBwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwBB7LjAAAswwP8AAAAAAADA//ADAAAAAMD//AAAAAAAwP8HAAAAAADA/wB/AAAAf8D//wEAAP8DAEGkucAACwb//w8A/wMAQbW5wAALFf7/HwAAAAAAwAEAAAAAAP8D/wPA/wBB0rnAAAsK/wcAAAAA/wP/AwBB5rnAAAsU/wMAAAAAAADxAwAA////////5wMAQYS6wAALCP////////8PAEGVusAACw78//8AAAAAAADA////DwBBr7rAAAsMIIAAAAD+AwAHAAA8AEHEusAACzf/AwAAAP/+/wAAAAD/AwAAAAD+/wAAAAAAAD8AAAD/AwAA/wMAAAAAAQIDAAAAAAQFBgAHAAAIAEGJu8AAC0MJCgsMAA0OAA8QEQASEwAAAAAUFQAAAAAWAAAXGAAAABkAFRoAABsAAAAVAAAAAAAcABwAAAAAABwAHR4AAAAAAAAfAEHZu8AACwYgAAAAHAgAQee7wAALASEAQfi7wAALAiIjAEGRvcAACwUIAAAAJABBor3AAAsBJQBBs73AAAsDJgAnAEHHvcAACwEoAEHtvcAACwcdAAAAAAAVAEGLvsAACwMpABwAQZm+wAALBCorACwAQay+wAALAS0AQfC+wAALEoD//////w8A/////////wEADABBjL/AAAsO/v//DwAAAAAPAAAAAgQAQaK/wAALAT4AQay/wAALEv8DAAAAAAD/AAAA/gAAAACA/wBBx7/AAAsF+AAAwA8AQde/wAALFTD///z///////8BAAAAAABgAAAA4ABB9b/AAAsR+AAAAAAA/wAAAP8AAAAAAP4AQY/AwAALJ/wAAAAAAAD/AwAAAAD///9/AAAA4H8AAAAAAB4AAAAAAAAA/P///wBBvsDAAAsUwP8AAP8D/v8fAAAA/wMAAAAA/wMAQd7AwAALNf8PAAAAAP//BwAAAP///x8AAP//HwAAAAAA/////////////////38AAAAA//sDAAAA//9/AEGcwcAACxb//w8AAAAAAP///wEAwP///////4D/AEG+wcAACxT+////////7x4A/v////+//z//HwBB4MHAAAtW/v//B/7//wcAAAAAAAQgBP//f////3/////////////////////////////////////////////////////////////////////////////D/wMAH1AAQcDCwAALiA0gAAAAAADfvEDX///7////////////v///////////////////////A/z///////////////////////////7///9/Av//////AQAAAAD/v7YA////hwcAAAD/B//////////+AMD////////////////vH/7hAJwAAP///////wDg////////////////AwAA/P///wcwBNAmEACGAAAAACsQAIAEAACALxAAbwAAAAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiIyQkJCQkJSYnKCkqKywkJCQkJCQkJC0uLzAxMjM0NTY3ODk6Ozw9Ph8/QEFCQ0RFRiQkJEckJCQkSElKSx9MTR9OT1AfHx8fHx8fHx8fH1FSHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fH1NUJFVWV1hZWh8fHx8fHx9bLFxdXiRfYB8fHx8fHx8fJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkNx8kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCRhJCQkJCQkJCQkJCQkJCQkJCQkYmMkJCQkZGUkYWYkZ2hpaiRrbG1ub0NwcXJzdCR1JCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkdncfHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8kJCQkJHgkeXp7fH0kJCQkfiF/gB+BJIKDhHGFAAECAwQFBgcIBQUJBQoLDAcHBwcHBwcHBwcNDg8HEBEFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBf////z/HwAA////Af8HAAAAAAAA///fPwAA8P/4A////////////+//3+H/DwD+/++f+f///cXjn1mAsA8AAxDuh/n///1tw4cZAl4AAD8A7r/7///97eO/GwEADwAAHu6f+f///e3jnxnAsA8AAgDsxz3WGMf/w8cdgQAAAAAA79/9///9/+PfHWAHDwAAAO/f/f///e/j3x1gQA8ABgDv3/3/////599d8IAPAAD87P9//P//+y9/gF//AAAMAP7///////8HfyAAAAAAAADW9///r///O18gAPAAAAAAAQAAAAAAAAD//v///x/+/wP///7///8fAEHQz8AAC5wB////////f/kAAP//////////ADz/////vyD///////f///////////89fz3//////z3/////PX89/3//////////Pf//////////BwAAAAD//wAA/////////////z8//v///////////////5////7//wf////////////H/wH/3w8A//8PAP//DwD/3w0A////////z///AYAQAEH00MAAC94B//////////////8B//////8H//////////8/AP///3//D/8BAAD///8/HwD//////w////8DAAAAAAAA////D/////////9//v8fAAAAAACAAAAA////////7//vDwAAAAAAAP//////8wD8/////7//AwD///////9/AADgAPz///8//wH//////+cAAAAAAN5vBAAAAACA/x8A//8/P/////8/P/+q////P////////99f3B/PD/8f3B8AAAAAAAACgAAA/x8AAAAAhPwvPlC9//PgQwAA//////8BAEHe0sAAC4QBwP///////wMAAP//////f///////f///////////H3gMAP////+/IP////////+AAAD//38Af39/f39/f3//////AAAAAACAAADgAAAA/gM+H///f+D+//////////////fg///////+//9/AAD///8HAAAAAAAA//////////8AAP8fAEHq08AAC7YL//////8//x///wAMAAD//////3/wjwAAgP/8//////n///////98AAAAAACA/7//////AAAA////////DwAvAAAAAAD86AD8////B/////8HAP///x/////////3/wCAAAD//wB8/z8AAP//f/z/////////fwUAADj//zwAfn5+AH9////////3/wD/////////BwAA/////w8A//9/+P//////D///////P///////AwAAAAB/APjg//1/X9v/////////////////AwAAAPj///////////////8////8////////AAAAAAD/DwAAAAAAAN///////////x8AAAAA/v//B/7//wfA/////Pz8HAAAAAAAAQIDBAUEBAQEBgcICQoLAgIMDQ4PBAQCAgICEBEEBBITFBUWBBcEGBkaGxwdHgQCHyAgIQQEBAQEBAQiIwQkAiMlJiAnAigpBCorLC0EBAIuAi8EBDAxMjM0BDUEBAQ2BDc4BAQ5Ojs8PTYEBAQEPj9ABEFCQwQEBAQkBAQEBAICAgICAgICAgICAgICRAQCRQICAkYEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAgICAgICAgICRQQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICAkcEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICNhQESElKSwQEBAQEBAQEBAQCBAQCTE1OAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJPAgICAgICAgICAgIgBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICFFACAgICAlEEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCUlMEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAJUVVZXWAICAgJZWltcXV4EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBF8EBARgYQQEBAQEYgQEBAQEBAQEBAQEBAQEBAQEBAQEAgICYwJkBAQEBAQEBAQEBAQEBAQEBAQEZWZnBAQEBAQEBAQEaGlqBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgICAgICAgICAgICAgICAgICAgICawICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIFAgICCgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICbAICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJtBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgJuBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAT/7///f///t/8//z8AAAAA////////////////////BwBBqN/AAAt8////////HwD///8f////////AQAAAAAA/////wDg////B///////B////z//////D/8+AAAAAAD///8/AAD/////D/////8P//////8A////////DwAAAP///////38A//8/AP8AAAA//f////+/kf//PwD//38A////fwBBrODAAAtt//83AP//PwD///8D/////////8Bv8O/+//8/AAAAAAD///8f////HwAAAAD//v//HwAAAP///////z8A//8/AP//BwD//wMAAAAAAP8BAAAAAAAA////////BwD//////wAAAP///x+AAP//PwBBpOHAAAuVAv//fwD8////////AQAA////AQAAcAD/////RwAeAAAUAAAAAP//+////59Af73/v/8B/////////wEAAO+f+f///e3jnxmB4A8AAAC7BwCAAAAAALMAAAAAAAAA////////P38AAAA/AAAAAP////////9/EQAAAAAAAAD///////8/Af///+f/BwAA/////////wEAAAAA//////////8AAACAAAAAAP/8///////8GgAAAP///////+d/AAD///////////8gAAAAAP/9/////39/AQAAAAAA/P////z///5/AH/7/////3+0ywAAAL/9////f3sBAAAAAP///wMAAAAA//////9/AAAPAAAAAAAAAH8AQcLjwAALKv///z8AAP///////wAADwAAAPj//+D//wAAAAAAAP+H/////////4D//wBB9OPAAAuAAgsAAAD/////////AAAABwDwAP///////////w///////wf/H/8B/0MAAAAA///f///////////fZN7/6+//////////v+ff3////3tf/P3///////////8//////f//9/////f//9/////f//9/////f/////3////9///3DwAAAAAAAH////nbBwAA//////8fgD8AQAAAAAAAAP//////DwAAHwAAAAAAAACPCAAAAAAAAO////+W/vcKhOqWqpb3917/+/8P7vv/DwAAAAAAAP///wP///8D////AwAAAAAAAP//fwAAAAAA/////wMA////////AQAAAP///z8AQYDmwAALBP7//wcAQZDmwAALMv//f38AAAAAVVVVVVVVVaqqVFVVVVVVK9bO27HV0q4RkKSqSlVV0lVVVVVVVVUFbHpVAEHm5sAACz1FgEDX/v/7DwAAAIAcVVVVkOb///////8AAAAAAABVVVVVAVRVVVVVVVWrKlVVVVVVVVVVVVVVVf7///9/AEH458AACxXwOBAAGgAAAMA5EADAAQAAgDsQABoAQbLowAALAgECAEG+6MAACwIBAwBB4ujAAAsVBAAAAAAABQUGBQcICQoAAAAACwwNAEGC6cAACwIODwBBoOnAAAsEEBEFEgBBie3AAAsHExQAFRYXGABB7O/AAAuEAhkAAAAAAQICAgIDAgICAgICBAUGAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAEH88cAACzr/////vyAAAAAAAAD///////8/AAAA///////nVVVVVVVVVVVVVRVAVVVVVQD/AD8A/wD/AD8AqgD/AEG/8sAACxoPAA8ADwAfAA+EOCc+UD0PwCAAAAD//wAACABB5vLAAAsuwP///wAAAAAAAP//////fwAAAAAAAJ3qJcBVVVVVBSgEAFVVVVVVFQAAVVVVBQBBnPPAAAsVVFVUVVVVVVVVVQBqVShFVVV9X1V0AEG888AACwT+//8HAEHQ88AACwQBAAIDAEHy88AACwEEAEGi9MAACwEFAEG59cAACwEGAEHQ9cAACxAHCAkKCwwNDg8QERITFBUWAEGk9sAACwEXAEHE9sAACwMCGBkAQYj3wAALBf//////AEGW98AAC78B/////w8AAAAAAP///////wcAAAAAAP//////////AAAAAP///wMAAPD//z8AAAD///8DAADQZN4/AAAA////AwAAsOffHwAAAHtf/AEAAPD//z8AAAD///8DAADw//8/AAAA////AwAA8P//PwAAAP///wMAAAD///8BAAAA/P//BwAAAPD//x8AAADA//9/AAAAAP///wEAAAAEAAAAAAAA/////wMAAAD/A////wP///8DAAAAAAAAQQAAAGEAQeD4wAALBUIAAABiAEHw+MAACwVDAAAAYwBBgPnAAAsFRAAAAGQAQZD5wAALBUUAAABlAEGg+cAACwVGAAAAZgBBsPnAAAsFRwAAAGcAQcD5wAALBUgAAABoAEHQ+cAACwVJAAAAaQBB4PnAAAsFSgAAAGoAQfD5wAALBUsAAABrAEGA+sAACwVMAAAAbABBkPrAAAsFTQAAAG0AQaD6wAALBU4AAABuAEGw+sAACwVPAAAAbwBBwPrAAAsFUAAAAHAAQdD6wAALBVEAAABxAEHg+sAACwVSAAAAcgBB8PrAAAsFUwAAAHMAQYD7wAALBVQAAAB0AEGQ+8AACwVVAAAAdQBBoPvAAAsFVgAAAHYAQbD7wAALBVcAAAB3AEHA+8AACwVYAAAAeABB0PvAAAsFWQAAAHkAQeD7wAALBVoAAAB6AEHw+8AACwXAAAAA4ABBgPzAAAsFwQAAAOEAQZD8wAALBcIAAADiAEGg/MAACwXDAAAA4wBBsPzAAAsFxAAAAOQAQcD8wAALBcUAAADlAEHQ/MAACwXGAAAA5gBB4PzAAAsFxwAAAOcAQfD8wAALBcgAAADoAEGA/cAACwXJAAAA6QBBkP3AAAsFygAAAOoAQaD9wAALBcsAAADrAEGw/cAACwXMAAAA7ABBwP3AAAsFzQAAAO0AQdD9wAALBc4AAADuAEHg/cAACwXPAAAA7wBB8P3AAAsF0AAAAPAAQYD+wAALBdEAAADxAEGQ/sAACwXSAAAA8gBBoP7AAAsF0wAAAPMAQbD+wAALBdQAAAD0AEHA/sAACwXVAAAA9QBB0P7AAAsF1gAAAPYAQeD+wAALBdgAAAD4AEHw/sAACwXZAAAA+QBBgP/AAAsF2gAAAPoAQZD/wAALBdsAAAD7AEGg/8AACwXcAAAA/ABBsP/AAAsF3QAAAP0AQcD/wAALBd4AAAD+AEHR/8AACwUBAAABAQBB4P/AAAsGAgEAAAMBAEHw/8AACwYEAQAABQEAQYCAwQALBgYBAAAHAQBBkIDBAAsGCAEAAAkBAEGggMEACwYKAQAACwEAQbCAwQALBgwBAAANAQBBwIDBAAsGDgEAAA8BAEHQgMEACwYQAQAAEQEAQeCAwQALBhIBAAATAQBB8IDBAAsGFAEAABUBAEGAgcEACwYWAQAAFwEAQZCBwQALBhgBAAAZAQBBoIHBAAsGGgEAABsBAEGwgcEACwYcAQAAHQEAQcCBwQALBh4BAAAfAQBB0IHBAAsGIAEAACEBAEHggcEACwYiAQAAIwEAQfCBwQALBiQBAAAlAQBBgILBAAsGJgEAACcBAEGQgsEACwYoAQAAKQEAQaCCwQALBioBAAArAQBBsILBAAsGLAEAAC0BAEHAgsEACwYuAQAALwEAQdCCwQALFjABAABpAAAABwMAAAAAAAAyAQAAMwEAQfCCwQALBjQBAAA1AQBBgIPBAAsGNgEAADcBAEGQg8EACwY5AQAAOgEAQaCDwQALBjsBAAA8AQBBsIPBAAsGPQEAAD4BAEHAg8EACwY/AQAAQAEAQdCDwQALBkEBAABCAQBB4IPBAAsGQwEAAEQBAEHwg8EACwZFAQAARgEAQYCEwQALBkcBAABIAQBBkITBAAsGSgEAAEsBAEGghMEACwZMAQAATQEAQbCEwQALBk4BAABPAQBBwITBAAsGUAEAAFEBAEHQhMEACwZSAQAAUwEAQeCEwQALBlQBAABVAQBB8ITBAAsGVgEAAFcBAEGAhcEACwZYAQAAWQEAQZCFwQALBloBAABbAQBBoIXBAAsGXAEAAF0BAEGwhcEACwZeAQAAXwEAQcCFwQALBmABAABhAQBB0IXBAAsGYgEAAGMBAEHghcEACwZkAQAAZQEAQfCFwQALBmYBAABnAQBBgIbBAAsGaAEAAGkBAEGQhsEACwZqAQAAawEAQaCGwQALBmwBAABtAQBBsIbBAAsGbgEAAG8BAEHAhsEACwZwAQAAcQEAQdCGwQALBnIBAABzAQBB4IbBAAsGdAEAAHUBAEHwhsEACwZ2AQAAdwEAQYCHwQALBXgBAAD/AEGQh8EACwZ5AQAAegEAQaCHwQALBnsBAAB8AQBBsIfBAAsGfQEAAH4BAEHAh8EACwaBAQAAUwIAQdCHwQALBoIBAACDAQBB4IfBAAsGhAEAAIUBAEHwh8EACwaGAQAAVAIAQYCIwQALBocBAACIAQBBkIjBAAsGiQEAAFYCAEGgiMEACwaKAQAAVwIAQbCIwQALBosBAACMAQBBwIjBAAsGjgEAAN0BAEHQiMEACwaPAQAAWQIAQeCIwQALBpABAABbAgBB8IjBAAsGkQEAAJIBAEGAicEACwaTAQAAYAIAQZCJwQALBpQBAABjAgBBoInBAAsGlgEAAGkCAEGwicEACwaXAQAAaAIAQcCJwQALBpgBAACZAQBB0InBAAsGnAEAAG8CAEHgicEACwadAQAAcgIAQfCJwQALBp8BAAB1AgBBgIrBAAsGoAEAAKEBAEGQisEACwaiAQAAowEAQaCKwQALBqQBAAClAQBBsIrBAAsGpgEAAIACAEHAisEACwanAQAAqAEAQdCKwQALBqkBAACDAgBB4IrBAAsGrAEAAK0BAEHwisEACwauAQAAiAIAQYCLwQALBq8BAACwAQBBkIvBAAsGsQEAAIoCAEGgi8EACwayAQAAiwIAQbCLwQALBrMBAAC0AQBBwIvBAAsGtQEAALYBAEHQi8EACwa3AQAAkgIAQeCLwQALBrgBAAC5AQBB8IvBAAsGvAEAAL0BAEGAjMEACwbEAQAAxgEAQZCMwQALBsUBAADGAQBBoIzBAAsGxwEAAMkBAEGwjMEACwbIAQAAyQEAQcCMwQALBsoBAADMAQBB0IzBAAsGywEAAMwBAEHgjMEACwbNAQAAzgEAQfCMwQALBs8BAADQAQBBgI3BAAsG0QEAANIBAEGQjcEACwbTAQAA1AEAQaCNwQALBtUBAADWAQBBsI3BAAsG1wEAANgBAEHAjcEACwbZAQAA2gEAQdCNwQALBtsBAADcAQBB4I3BAAsG3gEAAN8BAEHwjcEACwbgAQAA4QEAQYCOwQALBuIBAADjAQBBkI7BAAsG5AEAAOUBAEGgjsEACwbmAQAA5wEAQbCOwQALBugBAADpAQBBwI7BAAsG6gEAAOsBAEHQjsEACwbsAQAA7QEAQeCOwQALBu4BAADvAQBB8I7BAAsG8QEAAPMBAEGAj8EACwbyAQAA8wEAQZCPwQALBvQBAAD1AQBBoI/BAAsG9gEAAJUBAEGwj8EACwb3AQAAvwEAQcCPwQALBvgBAAD5AQBB0I/BAAsG+gEAAPsBAEHgj8EACwb8AQAA/QEAQfCPwQALBv4BAAD/AQBBgZDBAAsFAgAAAQIAQZCQwQALBgICAAADAgBBoJDBAAsGBAIAAAUCAEGwkMEACwYGAgAABwIAQcCQwQALBggCAAAJAgBB0JDBAAsGCgIAAAsCAEHgkMEACwYMAgAADQIAQfCQwQALBg4CAAAPAgBBgJHBAAsGEAIAABECAEGQkcEACwYSAgAAEwIAQaCRwQALBhQCAAAVAgBBsJHBAAsGFgIAABcCAEHAkcEACwYYAgAAGQIAQdCRwQALBhoCAAAbAgBB4JHBAAsGHAIAAB0CAEHwkcEACwYeAgAAHwIAQYCSwQALBiACAACeAQBBkJLBAAsGIgIAACMCAEGgksEACwYkAgAAJQIAQbCSwQALBiYCAAAnAgBBwJLBAAsGKAIAACkCAEHQksEACwYqAgAAKwIAQeCSwQALBiwCAAAtAgBB8JLBAAsGLgIAAC8CAEGAk8EACwYwAgAAMQIAQZCTwQALBjICAAAzAgBBoJPBAAsGOgIAAGUsAEGwk8EACwY7AgAAPAIAQcCTwQALBj0CAACaAQBB0JPBAAsGPgIAAGYsAEHgk8EACwZBAgAAQgIAQfCTwQALBkMCAACAAQBBgJTBAAsGRAIAAIkCAEGQlMEACwZFAgAAjAIAQaCUwQALBkYCAABHAgBBsJTBAAsGSAIAAEkCAEHAlMEACwZKAgAASwIAQdCUwQALBkwCAABNAgBB4JTBAAsGTgIAAE8CAEHwlMEACwZwAwAAcQMAQYCVwQALBnIDAABzAwBBkJXBAAsGdgMAAHcDAEGglcEACwZ/AwAA8wMAQbCVwQALBoYDAACsAwBBwJXBAAsGiAMAAK0DAEHQlcEACwaJAwAArgMAQeCVwQALBooDAACvAwBB8JXBAAsGjAMAAMwDAEGAlsEACwaOAwAAzQMAQZCWwQALBo8DAADOAwBBoJbBAAsGkQMAALEDAEGwlsEACwaSAwAAsgMAQcCWwQALBpMDAACzAwBB0JbBAAsGlAMAALQDAEHglsEACwaVAwAAtQMAQfCWwQALBpYDAAC2AwBBgJfBAAsGlwMAALcDAEGQl8EACwaYAwAAuAMAQaCXwQALBpkDAAC5AwBBsJfBAAsGmgMAALoDAEHAl8EACwabAwAAuwMAQdCXwQALBpwDAAC8AwBB4JfBAAsGnQMAAL0DAEHwl8EACwaeAwAAvgMAQYCYwQALBp8DAAC/AwBBkJjBAAsGoAMAAMADAEGgmMEACwahAwAAwQMAQbCYwQALBqMDAADDAwBBwJjBAAsGpAMAAMQDAEHQmMEACwalAwAAxQMAQeCYwQALBqYDAADGAwBB8JjBAAsGpwMAAMcDAEGAmcEACwaoAwAAyAMAQZCZwQALBqkDAADJAwBBoJnBAAsGqgMAAMoDAEGwmcEACwarAwAAywMAQcCZwQALBs8DAADXAwBB0JnBAAsG2AMAANkDAEHgmcEACwbaAwAA2wMAQfCZwQALBtwDAADdAwBBgJrBAAsG3gMAAN8DAEGQmsEACwbgAwAA4QMAQaCawQALBuIDAADjAwBBsJrBAAsG5AMAAOUDAEHAmsEACwbmAwAA5wMAQdCawQALBugDAADpAwBB4JrBAAsG6gMAAOsDAEHwmsEACwbsAwAA7QMAQYCbwQALBu4DAADvAwBBkJvBAAsG9AMAALgDAEGgm8EACwb3AwAA+AMAQbCbwQALBvkDAADyAwBBwJvBAAsG+gMAAPsDAEHQm8EACwb9AwAAewMAQeCbwQALBv4DAAB8AwBB8JvBAAsG/wMAAH0DAEGBnMEACwUEAABQBABBkJzBAAsGAQQAAFEEAEGgnMEACwYCBAAAUgQAQbCcwQALBgMEAABTBABBwJzBAAsGBAQAAFQEAEHQnMEACwYFBAAAVQQAQeCcwQALBgYEAABWBABB8JzBAAsGBwQAAFcEAEGAncEACwYIBAAAWAQAQZCdwQALBgkEAABZBABBoJ3BAAsGCgQAAFoEAEGwncEACwYLBAAAWwQAQcCdwQALBgwEAABcBABB0J3BAAsGDQQAAF0EAEHgncEACwYOBAAAXgQAQfCdwQALBg8EAABfBABBgJ7BAAsGEAQAADAEAEGQnsEACwYRBAAAMQQAQaCewQALBhIEAAAyBABBsJ7BAAsGEwQAADMEAEHAnsEACwYUBAAANAQAQdCewQALBhUEAAA1BABB4J7BAAsGFgQAADYEAEHwnsEACwYXBAAANwQAQYCfwQALBhgEAAA4BABBkJ/BAAsGGQQAADkEAEGgn8EACwYaBAAAOgQAQbCfwQALBhsEAAA7BABBwJ/BAAsGHAQAADwEAEHQn8EACwYdBAAAPQQAQeCfwQALBh4EAAA+BABB8J/BAAsGHwQAAD8EAEGAoMEACwYgBAAAQAQAQZCgwQALBiEEAABBBABBoKDBAAsGIgQAAEIEAEGwoMEACwYjBAAAQwQAQcCgwQALBiQEAABEBABB0KDBAAsGJQQAAEUEAEHgoMEACwYmBAAARgQAQfCgwQALBicEAABHBABBgKHBAAsGKAQAAEgEAEGQocEACwYpBAAASQQAQaChwQALBioEAABKBABBsKHBAAsGKwQAAEsEAEHAocEACwYsBAAATAQAQdChwQALBi0EAABNBABB4KHBAAsGLgQAAE4EAEHwocEACwYvBAAATwQAQYCiwQALBmAEAABhBABBkKLBAAsGYgQAAGMEAEGgosEACwZkBAAAZQQAQbCiwQALBmYEAABnBABBwKLBAAsGaAQAAGkEAEHQosEACwZqBAAAawQAQeCiwQALBmwEAABtBABB8KLBAAsGbgQAAG8EAEGAo8EACwZwBAAAcQQAQZCjwQALBnIEAABzBABBoKPBAAsGdAQAAHUEAEGwo8EACwZ2BAAAdwQAQcCjwQALBngEAAB5BABB0KPBAAsGegQAAHsEAEHgo8EACwZ8BAAAfQQAQfCjwQALBn4EAAB/BABBgKTBAAsGgAQAAIEEAEGQpMEACwaKBAAAiwQAQaCkwQALBowEAACNBABBsKTBAAsGjgQAAI8EAEHApMEACwaQBAAAkQQAQdCkwQALBpIEAACTBABB4KTBAAsGlAQAAJUEAEHwpMEACwaWBAAAlwQAQYClwQALBpgEAACZBABBkKXBAAsGmgQAAJsEAEGgpcEACwacBAAAnQQAQbClwQALBp4EAACfBABBwKXBAAsGoAQAAKEEAEHQpcEACwaiBAAAowQAQeClwQALBqQEAAClBABB8KXBAAsGpgQAAKcEAEGApsEACwaoBAAAqQQAQZCmwQALBqoEAACrBABBoKbBAAsGrAQAAK0EAEGwpsEACwauBAAArwQAQcCmwQALBrAEAACxBABB0KbBAAsGsgQAALMEAEHgpsEACwa0BAAAtQQAQfCmwQALBrYEAAC3BABBgKfBAAsGuAQAALkEAEGQp8EACwa6BAAAuwQAQaCnwQALBrwEAAC9BABBsKfBAAsGvgQAAL8EAEHAp8EACwbABAAAzwQAQdCnwQALBsEEAADCBABB4KfBAAsGwwQAAMQEAEHwp8EACwbFBAAAxgQAQYCowQALBscEAADIBABBkKjBAAsGyQQAAMoEAEGgqMEACwbLBAAAzAQAQbCowQALBs0EAADOBABBwKjBAAsG0AQAANEEAEHQqMEACwbSBAAA0wQAQeCowQALBtQEAADVBABB8KjBAAsG1gQAANcEAEGAqcEACwbYBAAA2QQAQZCpwQALBtoEAADbBABBoKnBAAsG3AQAAN0EAEGwqcEACwbeBAAA3wQAQcCpwQALBuAEAADhBABB0KnBAAsG4gQAAOMEAEHgqcEACwbkBAAA5QQAQfCpwQALBuYEAADnBABBgKrBAAsG6AQAAOkEAEGQqsEACwbqBAAA6wQAQaCqwQALBuwEAADtBABBsKrBAAsG7gQAAO8EAEHAqsEACwbwBAAA8QQAQdCqwQALBvIEAADzBABB4KrBAAsG9AQAAPUEAEHwqsEACwb2BAAA9wQAQYCrwQALBvgEAAD5BABBkKvBAAsG+gQAAPsEAEGgq8EACwb8BAAA/QQAQbCrwQALBv4EAAD/BABBwavBAAsFBQAAAQUAQdCrwQALBgIFAAADBQBB4KvBAAsGBAUAAAUFAEHwq8EACwYGBQAABwUAQYCswQALBggFAAAJBQBBkKzBAAsGCgUAAAsFAEGgrMEACwYMBQAADQUAQbCswQALBg4FAAAPBQBBwKzBAAsGEAUAABEFAEHQrMEACwYSBQAAEwUAQeCswQALBhQFAAAVBQBB8KzBAAsGFgUAABcFAEGArcEACwYYBQAAGQUAQZCtwQALBhoFAAAbBQBBoK3BAAsGHAUAAB0FAEGwrcEACwYeBQAAHwUAQcCtwQALBiAFAAAhBQBB0K3BAAsGIgUAACMFAEHgrcEACwYkBQAAJQUAQfCtwQALBiYFAAAnBQBBgK7BAAsGKAUAACkFAEGQrsEACwYqBQAAKwUAQaCuwQALBiwFAAAtBQBBsK7BAAsGLgUAAC8FAEHArsEACwYxBQAAYQUAQdCuwQALBjIFAABiBQBB4K7BAAsGMwUAAGMFAEHwrsEACwY0BQAAZAUAQYCvwQALBjUFAABlBQBBkK/BAAsGNgUAAGYFAEGgr8EACwY3BQAAZwUAQbCvwQALBjgFAABoBQBBwK/BAAsGOQUAAGkFAEHQr8EACwY6BQAAagUAQeCvwQALBjsFAABrBQBB8K/BAAsGPAUAAGwFAEGAsMEACwY9BQAAbQUAQZCwwQALBj4FAABuBQBBoLDBAAsGPwUAAG8FAEGwsMEACwZABQAAcAUAQcCwwQALBkEFAABxBQBB0LDBAAsGQgUAAHIFAEHgsMEACwZDBQAAcwUAQfCwwQALBkQFAAB0BQBBgLHBAAsGRQUAAHUFAEGQscEACwZGBQAAdgUAQaCxwQALBkcFAAB3BQBBsLHBAAsGSAUAAHgFAEHAscEACwZJBQAAeQUAQdCxwQALBkoFAAB6BQBB4LHBAAsGSwUAAHsFAEHwscEACwZMBQAAfAUAQYCywQALBk0FAAB9BQBBkLLBAAsGTgUAAH4FAEGgssEACwZPBQAAfwUAQbCywQALBlAFAACABQBBwLLBAAsGUQUAAIEFAEHQssEACwZSBQAAggUAQeCywQALBlMFAACDBQBB8LLBAAsGVAUAAIQFAEGAs8EACwZVBQAAhQUAQZCzwQALBlYFAACGBQBBoLPBAAsGoBAAAAAtAEGws8EACwahEAAAAS0AQcCzwQALBqIQAAACLQBB0LPBAAsGoxAAAAMtAEHgs8EACwakEAAABC0AQfCzwQALBqUQAAAFLQBBgLTBAAsGphAAAAYtAEGQtMEACwanEAAABy0AQaC0wQALBqgQAAAILQBBsLTBAAsGqRAAAAktAEHAtMEACwaqEAAACi0AQdC0wQALBqsQAAALLQBB4LTBAAsGrBAAAAwtAEHwtMEACwatEAAADS0AQYC1wQALBq4QAAAOLQBBkLXBAAsGrxAAAA8tAEGgtcEACwawEAAAEC0AQbC1wQALBrEQAAARLQBBwLXBAAsGshAAABItAEHQtcEACwazEAAAEy0AQeC1wQALBrQQAAAULQBB8LXBAAsGtRAAABUtAEGAtsEACwa2EAAAFi0AQZC2wQALBrcQAAAXLQBBoLbBAAsGuBAAABgtAEGwtsEACwa5EAAAGS0AQcC2wQALBroQAAAaLQBB0LbBAAsGuxAAABstAEHgtsEACwa8EAAAHC0AQfC2wQALBr0QAAAdLQBBgLfBAAsGvhAAAB4tAEGQt8EACwa/EAAAHy0AQaC3wQALBsAQAAAgLQBBsLfBAAsGwRAAACEtAEHAt8EACwbCEAAAIi0AQdC3wQALBsMQAAAjLQBB4LfBAAsGxBAAACQtAEHwt8EACwbFEAAAJS0AQYC4wQALBscQAAAnLQBBkLjBAAsGzRAAAC0tAEGguMEACwagEwAAcKsAQbC4wQALBqETAABxqwBBwLjBAAsGohMAAHKrAEHQuMEACwajEwAAc6sAQeC4wQALBqQTAAB0qwBB8LjBAAsGpRMAAHWrAEGAucEACwamEwAAdqsAQZC5wQALBqcTAAB3qwBBoLnBAAsGqBMAAHirAEGwucEACwapEwAAeasAQcC5wQALBqoTAAB6qwBB0LnBAAsGqxMAAHurAEHgucEACwasEwAAfKsAQfC5wQALBq0TAAB9qwBBgLrBAAsGrhMAAH6rAEGQusEACwavEwAAf6sAQaC6wQALBrATAACAqwBBsLrBAAsGsRMAAIGrAEHAusEACwayEwAAgqsAQdC6wQALBrMTAACDqwBB4LrBAAsGtBMAAISrAEHwusEACwa1EwAAhasAQYC7wQALBrYTAACGqwBBkLvBAAsGtxMAAIerAEGgu8EACwa4EwAAiKsAQbC7wQALBrkTAACJqwBBwLvBAAsGuhMAAIqrAEHQu8EACwa7EwAAi6sAQeC7wQALBrwTAACMqwBB8LvBAAsGvRMAAI2rAEGAvMEACwa+EwAAjqsAQZC8wQALBr8TAACPqwBBoLzBAAsGwBMAAJCrAEGwvMEACwbBEwAAkasAQcC8wQALBsITAACSqwBB0LzBAAsGwxMAAJOrAEHgvMEACwbEEwAAlKsAQfC8wQALBsUTAACVqwBBgL3BAAsGxhMAAJarAEGQvcEACwbHEwAAl6sAQaC9wQALBsgTAACYqwBBsL3BAAsGyRMAAJmrAEHAvcEACwbKEwAAmqsAQdC9wQALBssTAACbqwBB4L3BAAsGzBMAAJyrAEHwvcEACwbNEwAAnasAQYC+wQALBs4TAACeqwBBkL7BAAsGzxMAAJ+rAEGgvsEACwbQEwAAoKsAQbC+wQALBtETAAChqwBBwL7BAAsG0hMAAKKrAEHQvsEACwbTEwAAo6sAQeC+wQALBtQTAACkqwBB8L7BAAsG1RMAAKWrAEGAv8EACwbWEwAApqsAQZC/wQALBtcTAACnqwBBoL/BAAsG2BMAAKirAEGwv8EACwbZEwAAqasAQcC/wQALBtoTAACqqwBB0L/BAAsG2xMAAKurAEHgv8EACwbcEwAArKsAQfC/wQALBt0TAACtqwBBgMDBAAsG3hMAAK6rAEGQwMEACwbfEwAAr6sAQaDAwQALBuATAACwqwBBsMDBAAsG4RMAALGrAEHAwMEACwbiEwAAsqsAQdDAwQALBuMTAACzqwBB4MDBAAsG5BMAALSrAEHwwMEACwblEwAAtasAQYDBwQALBuYTAAC2qwBBkMHBAAsG5xMAALerAEGgwcEACwboEwAAuKsAQbDBwQALBukTAAC5qwBBwMHBAAsG6hMAALqrAEHQwcEACwbrEwAAu6sAQeDBwQALBuwTAAC8qwBB8MHBAAsG7RMAAL2rAEGAwsEACwbuEwAAvqsAQZDCwQALBu8TAAC/qwBBoMLBAAsG8BMAAPgTAEGwwsEACwbxEwAA+RMAQcDCwQALBvITAAD6EwBB0MLBAAsG8xMAAPsTAEHgwsEACwb0EwAA/BMAQfDCwQALBvUTAAD9EwBBgMPBAAsGkBwAANAQAEGQw8EACwaRHAAA0RAAQaDDwQALBpIcAADSEABBsMPBAAsGkxwAANMQAEHAw8EACwaUHAAA1BAAQdDDwQALBpUcAADVEABB4MPBAAsGlhwAANYQAEHww8EACwaXHAAA1xAAQYDEwQALBpgcAADYEABBkMTBAAsGmRwAANkQAEGgxMEACwaaHAAA2hAAQbDEwQALBpscAADbEABBwMTBAAsGnBwAANwQAEHQxMEACwadHAAA3RAAQeDEwQALBp4cAADeEABB8MTBAAsGnxwAAN8QAEGAxcEACwagHAAA4BAAQZDFwQALBqEcAADhEABBoMXBAAsGohwAAOIQAEGwxcEACwajHAAA4xAAQcDFwQALBqQcAADkEABB0MXBAAsGpRwAAOUQAEHgxcEACwamHAAA5hAAQfDFwQALBqccAADnEABBgMbBAAsGqBwAAOgQAEGQxsEACwapHAAA6RAAQaDGwQALBqocAADqEABBsMbBAAsGqxwAAOsQAEHAxsEACwasHAAA7BAAQdDGwQALBq0cAADtEABB4MbBAAsGrhwAAO4QAEHwxsEACwavHAAA7xAAQYDHwQALBrAcAADwEABBkMfBAAsGsRwAAPEQAEGgx8EACwayHAAA8hAAQbDHwQALBrMcAADzEABBwMfBAAsGtBwAAPQQAEHQx8EACwa1HAAA9RAAQeDHwQALBrYcAAD2EABB8MfBAAsGtxwAAPcQAEGAyMEACwa4HAAA+BAAQZDIwQALBrkcAAD5EABBoMjBAAsGuhwAAPoQAEGwyMEACwa9HAAA/RAAQcDIwQALBr4cAAD+EABB0MjBAAsGvxwAAP8QAEHhyMEACwUeAAABHgBB8MjBAAsGAh4AAAMeAEGAycEACwYEHgAABR4AQZDJwQALBgYeAAAHHgBBoMnBAAsGCB4AAAkeAEGwycEACwYKHgAACx4AQcDJwQALBgweAAANHgBB0MnBAAsGDh4AAA8eAEHgycEACwYQHgAAER4AQfDJwQALBhIeAAATHgBBgMrBAAsGFB4AABUeAEGQysEACwYWHgAAFx4AQaDKwQALBhgeAAAZHgBBsMrBAAsGGh4AABseAEHAysEACwYcHgAAHR4AQdDKwQALBh4eAAAfHgBB4MrBAAsGIB4AACEeAEHwysEACwYiHgAAIx4AQYDLwQALBiQeAAAlHgBBkMvBAAsGJh4AACceAEGgy8EACwYoHgAAKR4AQbDLwQALBioeAAArHgBBwMvBAAsGLB4AAC0eAEHQy8EACwYuHgAALx4AQeDLwQALBjAeAAAxHgBB8MvBAAsGMh4AADMeAEGAzMEACwY0HgAANR4AQZDMwQALBjYeAAA3HgBBoMzBAAsGOB4AADkeAEGwzMEACwY6HgAAOx4AQcDMwQALBjweAAA9HgBB0MzBAAsGPh4AAD8eAEHgzMEACwZAHgAAQR4AQfDMwQALBkIeAABDHgBBgM3BAAsGRB4AAEUeAEGQzcEACwZGHgAARx4AQaDNwQALBkgeAABJHgBBsM3BAAsGSh4AAEseAEHAzcEACwZMHgAATR4AQdDNwQALBk4eAABPHgBB4M3BAAsGUB4AAFEeAEHwzcEACwZSHgAAUx4AQYDOwQALBlQeAABVHgBBkM7BAAsGVh4AAFceAEGgzsEACwZYHgAAWR4AQbDOwQALBloeAABbHgBBwM7BAAsGXB4AAF0eAEHQzsEACwZeHgAAXx4AQeDOwQALBmAeAABhHgBB8M7BAAsGYh4AAGMeAEGAz8EACwZkHgAAZR4AQZDPwQALBmYeAABnHgBBoM/BAAsGaB4AAGkeAEGwz8EACwZqHgAAax4AQcDPwQALBmweAABtHgBB0M/BAAsGbh4AAG8eAEHgz8EACwZwHgAAcR4AQfDPwQALBnIeAABzHgBBgNDBAAsGdB4AAHUeAEGQ0MEACwZ2HgAAdx4AQaDQwQALBngeAAB5HgBBsNDBAAsGeh4AAHseAEHA0MEACwZ8HgAAfR4AQdDQwQALBn4eAAB/HgBB4NDBAAsGgB4AAIEeAEHw0MEACwaCHgAAgx4AQYDRwQALBoQeAACFHgBBkNHBAAsGhh4AAIceAEGg0cEACwaIHgAAiR4AQbDRwQALBooeAACLHgBBwNHBAAsGjB4AAI0eAEHQ0cEACwaOHgAAjx4AQeDRwQALBpAeAACRHgBB8NHBAAsGkh4AAJMeAEGA0sEACwaUHgAAlR4AQZDSwQALBZ4eAADfAEGg0sEACwagHgAAoR4AQbDSwQALBqIeAACjHgBBwNLBAAsGpB4AAKUeAEHQ0sEACwamHgAApx4AQeDSwQALBqgeAACpHgBB8NLBAAsGqh4AAKseAEGA08EACwasHgAArR4AQZDTwQALBq4eAACvHgBBoNPBAAsGsB4AALEeAEGw08EACwayHgAAsx4AQcDTwQALBrQeAAC1HgBB0NPBAAsGth4AALceAEHg08EACwa4HgAAuR4AQfDTwQALBroeAAC7HgBBgNTBAAsGvB4AAL0eAEGQ1MEACwa+HgAAvx4AQaDUwQALBsAeAADBHgBBsNTBAAsGwh4AAMMeAEHA1MEACwbEHgAAxR4AQdDUwQALBsYeAADHHgBB4NTBAAsGyB4AAMkeAEHw1MEACwbKHgAAyx4AQYDVwQALBsweAADNHgBBkNXBAAsGzh4AAM8eAEGg1cEACwbQHgAA0R4AQbDVwQALBtIeAADTHgBBwNXBAAsG1B4AANUeAEHQ1cEACwbWHgAA1x4AQeDVwQALBtgeAADZHgBB8NXBAAsG2h4AANseAEGA1sEACwbcHgAA3R4AQZDWwQALBt4eAADfHgBBoNbBAAsG4B4AAOEeAEGw1sEACwbiHgAA4x4AQcDWwQALBuQeAADlHgBB0NbBAAsG5h4AAOceAEHg1sEACwboHgAA6R4AQfDWwQALBuoeAADrHgBBgNfBAAsG7B4AAO0eAEGQ18EACwbuHgAA7x4AQaDXwQALBvAeAADxHgBBsNfBAAsG8h4AAPMeAEHA18EACwb0HgAA9R4AQdDXwQALBvYeAAD3HgBB4NfBAAsG+B4AAPkeAEHw18EACwb6HgAA+x4AQYDYwQALBvweAAD9HgBBkNjBAAsG/h4AAP8eAEGg2MEACwYIHwAAAB8AQbDYwQALBgkfAAABHwBBwNjBAAsGCh8AAAIfAEHQ2MEACwYLHwAAAx8AQeDYwQALBgwfAAAEHwBB8NjBAAsGDR8AAAUfAEGA2cEACwYOHwAABh8AQZDZwQALBg8fAAAHHwBBoNnBAAsGGB8AABAfAEGw2cEACwYZHwAAER8AQcDZwQALBhofAAASHwBB0NnBAAsGGx8AABMfAEHg2cEACwYcHwAAFB8AQfDZwQALBh0fAAAVHwBBgNrBAAsGKB8AACAfAEGQ2sEACwYpHwAAIR8AQaDawQALBiofAAAiHwBBsNrBAAsGKx8AACMfAEHA2sEACwYsHwAAJB8AQdDawQALBi0fAAAlHwBB4NrBAAsGLh8AACYfAEHw2sEACwYvHwAAJx8AQYDbwQALBjgfAAAwHwBBkNvBAAsGOR8AADEfAEGg28EACwY6HwAAMh8AQbDbwQALBjsfAAAzHwBBwNvBAAsGPB8AADQfAEHQ28EACwY9HwAANR8AQeDbwQALBj4fAAA2HwBB8NvBAAsGPx8AADcfAEGA3MEACwZIHwAAQB8AQZDcwQALBkkfAABBHwBBoNzBAAsGSh8AAEIfAEGw3MEACwZLHwAAQx8AQcDcwQALBkwfAABEHwBB0NzBAAsGTR8AAEUfAEHg3MEACwZZHwAAUR8AQfDcwQALBlsfAABTHwBBgN3BAAsGXR8AAFUfAEGQ3cEACwZfHwAAVx8AQaDdwQALBmgfAABgHwBBsN3BAAsGaR8AAGEfAEHA3cEACwZqHwAAYh8AQdDdwQALBmsfAABjHwBB4N3BAAsGbB8AAGQfAEHw3cEACwZtHwAAZR8AQYDewQALBm4fAABmHwBBkN7BAAsGbx8AAGcfAEGg3sEACwaIHwAAgB8AQbDewQALBokfAACBHwBBwN7BAAsGih8AAIIfAEHQ3sEACwaLHwAAgx8AQeDewQALBowfAACEHwBB8N7BAAsGjR8AAIUfAEGA38EACwaOHwAAhh8AQZDfwQALBo8fAACHHwBBoN/BAAsGmB8AAJAfAEGw38EACwaZHwAAkR8AQcDfwQALBpofAACSHwBB0N/BAAsGmx8AAJMfAEHg38EACwacHwAAlB8AQfDfwQALBp0fAACVHwBBgODBAAsGnh8AAJYfAEGQ4MEACwafHwAAlx8AQaDgwQALBqgfAACgHwBBsODBAAsGqR8AAKEfAEHA4MEACwaqHwAAoh8AQdDgwQALBqsfAACjHwBB4ODBAAsGrB8AAKQfAEHw4MEACwatHwAApR8AQYDhwQALBq4fAACmHwBBkOHBAAsGrx8AAKcfAEGg4cEACwa4HwAAsB8AQbDhwQALBrkfAACxHwBBwOHBAAsGuh8AAHAfAEHQ4cEACwa7HwAAcR8AQeDhwQALBrwfAACzHwBB8OHBAAsGyB8AAHIfAEGA4sEACwbJHwAAcx8AQZDiwQALBsofAAB0HwBBoOLBAAsGyx8AAHUfAEGw4sEACwbMHwAAwx8AQcDiwQALBtgfAADQHwBB0OLBAAsG2R8AANEfAEHg4sEACwbaHwAAdh8AQfDiwQALBtsfAAB3HwBBgOPBAAsG6B8AAOAfAEGQ48EACwbpHwAA4R8AQaDjwQALBuofAAB6HwBBsOPBAAsG6x8AAHsfAEHA48EACwbsHwAA5R8AQdDjwQALBvgfAAB4HwBB4OPBAAsG+R8AAHkfAEHw48EACwb6HwAAfB8AQYDkwQALBvsfAAB9HwBBkOTBAAsG/B8AAPMfAEGg5MEACwYmIQAAyQMAQbDkwQALBSohAABrAEHA5MEACwUrIQAA5QBB0OTBAAsGMiEAAE4hAEHg5MEACwZgIQAAcCEAQfDkwQALBmEhAABxIQBBgOXBAAsGYiEAAHIhAEGQ5cEACwZjIQAAcyEAQaDlwQALBmQhAAB0IQBBsOXBAAsGZSEAAHUhAEHA5cEACwZmIQAAdiEAQdDlwQALBmchAAB3IQBB4OXBAAsGaCEAAHghAEHw5cEACwZpIQAAeSEAQYDmwQALBmohAAB6IQBBkObBAAsGayEAAHshAEGg5sEACwZsIQAAfCEAQbDmwQALBm0hAAB9IQBBwObBAAsGbiEAAH4hAEHQ5sEACwZvIQAAfyEAQeDmwQALBoMhAACEIQBB8ObBAAsGtiQAANAkAEGA58EACwa3JAAA0SQAQZDnwQALBrgkAADSJABBoOfBAAsGuSQAANMkAEGw58EACwa6JAAA1CQAQcDnwQALBrskAADVJABB0OfBAAsGvCQAANYkAEHg58EACwa9JAAA1yQAQfDnwQALBr4kAADYJABBgOjBAAsGvyQAANkkAEGQ6MEACwbAJAAA2iQAQaDowQALBsEkAADbJABBsOjBAAsGwiQAANwkAEHA6MEACwbDJAAA3SQAQdDowQALBsQkAADeJABB4OjBAAsGxSQAAN8kAEHw6MEACwbGJAAA4CQAQYDpwQALBsckAADhJABBkOnBAAsGyCQAAOIkAEGg6cEACwbJJAAA4yQAQbDpwQALBsokAADkJABBwOnBAAsGyyQAAOUkAEHQ6cEACwbMJAAA5iQAQeDpwQALBs0kAADnJABB8OnBAAsGziQAAOgkAEGA6sEACwbPJAAA6SQAQZHqwQALBSwAADAsAEGg6sEACwYBLAAAMSwAQbDqwQALBgIsAAAyLABBwOrBAAsGAywAADMsAEHQ6sEACwYELAAANCwAQeDqwQALBgUsAAA1LABB8OrBAAsGBiwAADYsAEGA68EACwYHLAAANywAQZDrwQALBggsAAA4LABBoOvBAAsGCSwAADksAEGw68EACwYKLAAAOiwAQcDrwQALBgssAAA7LABB0OvBAAsGDCwAADwsAEHg68EACwYNLAAAPSwAQfDrwQALBg4sAAA+LABBgOzBAAsGDywAAD8sAEGQ7MEACwYQLAAAQCwAQaDswQALBhEsAABBLABBsOzBAAsGEiwAAEIsAEHA7MEACwYTLAAAQywAQdDswQALBhQsAABELABB4OzBAAsGFSwAAEUsAEHw7MEACwYWLAAARiwAQYDtwQALBhcsAABHLABBkO3BAAsGGCwAAEgsAEGg7cEACwYZLAAASSwAQbDtwQALBhosAABKLABBwO3BAAsGGywAAEssAEHQ7cEACwYcLAAATCwAQeDtwQALBh0sAABNLABB8O3BAAsGHiwAAE4sAEGA7sEACwYfLAAATywAQZDuwQALBiAsAABQLABBoO7BAAsGISwAAFEsAEGw7sEACwYiLAAAUiwAQcDuwQALBiMsAABTLABB0O7BAAsGJCwAAFQsAEHg7sEACwYlLAAAVSwAQfDuwQALBiYsAABWLABBgO/BAAsGJywAAFcsAEGQ78EACwYoLAAAWCwAQaDvwQALBiksAABZLABBsO/BAAsGKiwAAFosAEHA78EACwYrLAAAWywAQdDvwQALBiwsAABcLABB4O/BAAsGLSwAAF0sAEHw78EACwYuLAAAXiwAQYDwwQALBmAsAABhLABBkPDBAAsGYiwAAGsCAEGg8MEACwZjLAAAfR0AQbDwwQALBmQsAAB9AgBBwPDBAAsGZywAAGgsAEHQ8MEACwZpLAAAaiwAQeDwwQALBmssAABsLABB8PDBAAsGbSwAAFECAEGA8cEACwZuLAAAcQIAQZDxwQALBm8sAABQAgBBoPHBAAsGcCwAAFICAEGw8cEACwZyLAAAcywAQcDxwQALBnUsAAB2LABB0PHBAAsGfiwAAD8CAEHg8cEACwZ/LAAAQAIAQfDxwQALBoAsAACBLABBgPLBAAsGgiwAAIMsAEGQ8sEACwaELAAAhSwAQaDywQALBoYsAACHLABBsPLBAAsGiCwAAIksAEHA8sEACwaKLAAAiywAQdDywQALBowsAACNLABB4PLBAAsGjiwAAI8sAEHw8sEACwaQLAAAkSwAQYDzwQALBpIsAACTLABBkPPBAAsGlCwAAJUsAEGg88EACwaWLAAAlywAQbDzwQALBpgsAACZLABBwPPBAAsGmiwAAJssAEHQ88EACwacLAAAnSwAQeDzwQALBp4sAACfLABB8PPBAAsGoCwAAKEsAEGA9MEACwaiLAAAoywAQZD0wQALBqQsAAClLABBoPTBAAsGpiwAAKcsAEGw9MEACwaoLAAAqSwAQcD0wQALBqosAACrLABB0PTBAAsGrCwAAK0sAEHg9MEACwauLAAArywAQfD0wQALBrAsAACxLABBgPXBAAsGsiwAALMsAEGQ9cEACwa0LAAAtSwAQaD1wQALBrYsAAC3LABBsPXBAAsGuCwAALksAEHA9cEACwa6LAAAuywAQdD1wQALBrwsAAC9LABB4PXBAAsGviwAAL8sAEHw9cEACwbALAAAwSwAQYD2wQALBsIsAADDLABBkPbBAAsGxCwAAMUsAEGg9sEACwbGLAAAxywAQbD2wQALBsgsAADJLABBwPbBAAsGyiwAAMssAEHQ9sEACwbMLAAAzSwAQeD2wQALBs4sAADPLABB8PbBAAsG0CwAANEsAEGA98EACwbSLAAA0ywAQZD3wQALBtQsAADVLABBoPfBAAsG1iwAANcsAEGw98EACwbYLAAA2SwAQcD3wQALBtosAADbLABB0PfBAAsG3CwAAN0sAEHg98EACwbeLAAA3ywAQfD3wQALBuAsAADhLABBgPjBAAsG4iwAAOMsAEGQ+MEACwbrLAAA7CwAQaD4wQALBu0sAADuLABBsPjBAAsG8iwAAPMsAEHA+MEACwZApgAAQaYAQdD4wQALBkKmAABDpgBB4PjBAAsGRKYAAEWmAEHw+MEACwZGpgAAR6YAQYD5wQALBkimAABJpgBBkPnBAAsGSqYAAEumAEGg+cEACwZMpgAATaYAQbD5wQALBk6mAABPpgBBwPnBAAsGUKYAAFGmAEHQ+cEACwZSpgAAU6YAQeD5wQALBlSmAABVpgBB8PnBAAsGVqYAAFemAEGA+sEACwZYpgAAWaYAQZD6wQALBlqmAABbpgBBoPrBAAsGXKYAAF2mAEGw+sEACwZepgAAX6YAQcD6wQALBmCmAABhpgBB0PrBAAsGYqYAAGOmAEHg+sEACwZkpgAAZaYAQfD6wQALBmamAABnpgBBgPvBAAsGaKYAAGmmAEGQ+8EACwZqpgAAa6YAQaD7wQALBmymAABtpgBBsPvBAAsGgKYAAIGmAEHA+8EACwaCpgAAg6YAQdD7wQALBoSmAACFpgBB4PvBAAsGhqYAAIemAEHw+8EACwaIpgAAiaYAQYD8wQALBoqmAACLpgBBkPzBAAsGjKYAAI2mAEGg/MEACwaOpgAAj6YAQbD8wQALBpCmAACRpgBBwPzBAAsGkqYAAJOmAEHQ/MEACwaUpgAAlaYAQeD8wQALBpamAACXpgBB8PzBAAsGmKYAAJmmAEGA/cEACwaapgAAm6YAQZD9wQALBiKnAAAjpwBBoP3BAAsGJKcAACWnAEGw/cEACwYmpwAAJ6cAQcD9wQALBiinAAAppwBB0P3BAAsGKqcAACunAEHg/cEACwYspwAALacAQfD9wQALBi6nAAAvpwBBgP7BAAsGMqcAADOnAEGQ/sEACwY0pwAANacAQaD+wQALBjanAAA3pwBBsP7BAAsGOKcAADmnAEHA/sEACwY6pwAAO6cAQdD+wQALBjynAAA9pwBB4P7BAAsGPqcAAD+nAEHw/sEACwZApwAAQacAQYD/wQALBkKnAABDpwBBkP/BAAsGRKcAAEWnAEGg/8EACwZGpwAAR6cAQbD/wQALBkinAABJpwBBwP/BAAsGSqcAAEunAEHQ/8EACwZMpwAATacAQeD/wQALBk6nAABPpwBB8P/BAAsGUKcAAFGnAEGAgMIACwZSpwAAU6cAQZCAwgALBlSnAABVpwBBoIDCAAsGVqcAAFenAEGwgMIACwZYpwAAWacAQcCAwgALBlqnAABbpwBB0IDCAAsGXKcAAF2nAEHggMIACwZepwAAX6cAQfCAwgALBmCnAABhpwBBgIHCAAsGYqcAAGOnAEGQgcIACwZkpwAAZacAQaCBwgALBmanAABnpwBBsIHCAAsGaKcAAGmnAEHAgcIACwZqpwAAa6cAQdCBwgALBmynAABtpwBB4IHCAAsGbqcAAG+nAEHwgcIACwZ5pwAAeqcAQYCCwgALBnunAAB8pwBBkILCAAsGfacAAHkdAEGggsIACwZ+pwAAf6cAQbCCwgALBoCnAACBpwBBwILCAAsGgqcAAIOnAEHQgsIACwaEpwAAhacAQeCCwgALBoanAACHpwBB8ILCAAsGi6cAAIynAEGAg8IACwaNpwAAZQIAQZCDwgALBpCnAACRpwBBoIPCAAsGkqcAAJOnAEGwg8IACwaWpwAAl6cAQcCDwgALBpinAACZpwBB0IPCAAsGmqcAAJunAEHgg8IACwacpwAAnacAQfCDwgALBp6nAACfpwBBgITCAAsGoKcAAKGnAEGQhMIACwaipwAAo6cAQaCEwgALBqSnAAClpwBBsITCAAsGpqcAAKenAEHAhMIACwaopwAAqacAQdCEwgALBqqnAABmAgBB4ITCAAsGq6cAAFwCAEHwhMIACwaspwAAYQIAQYCFwgALBq2nAABsAgBBkIXCAAsGrqcAAGoCAEGghcIACwawpwAAngIAQbCFwgALBrGnAACHAgBBwIXCAAsGsqcAAJ0CAEHQhcIACwazpwAAU6sAQeCFwgALBrSnAAC1pwBB8IXCAAsGtqcAALenAEGAhsIACwa4pwAAuacAQZCGwgALBrqnAAC7pwBBoIbCAAsGvKcAAL2nAEGwhsIACwa+pwAAv6cAQcCGwgALBsKnAADDpwBB0IbCAAsGxKcAAJSnAEHghsIACwbFpwAAggIAQfCGwgALBsanAACOHQBBgIfCAAsGIf8AAEH/AEGQh8IACwYi/wAAQv8AQaCHwgALBiP/AABD/wBBsIfCAAsGJP8AAET/AEHAh8IACwYl/wAARf8AQdCHwgALBib/AABG/wBB4IfCAAsGJ/8AAEf/AEHwh8IACwYo/wAASP8AQYCIwgALBin/AABJ/wBBkIjCAAsGKv8AAEr/AEGgiMIACwYr/wAAS/8AQbCIwgALBiz/AABM/wBBwIjCAAsGLf8AAE3/AEHQiMIACwYu/wAATv8AQeCIwgALBi//AABP/wBB8IjCAAsGMP8AAFD/AEGAicIACwYx/wAAUf8AQZCJwgALBjL/AABS/wBBoInCAAsGM/8AAFP/AEGwicIACwY0/wAAVP8AQcCJwgALBjX/AABV/wBB0InCAAsGNv8AAFb/AEHgicIACwY3/wAAV/8AQfCJwgALBjj/AABY/wBBgIrCAAsGOf8AAFn/AEGQisIACwY6/wAAWv8AQaGKwgALBgQBACgEAQBBsIrCAAsHAQQBACkEAQBBwIrCAAsHAgQBACoEAQBB0IrCAAsHAwQBACsEAQBB4IrCAAsHBAQBACwEAQBB8IrCAAsHBQQBAC0EAQBBgIvCAAsHBgQBAC4EAQBBkIvCAAsHBwQBAC8EAQBBoIvCAAsHCAQBADAEAQBBsIvCAAsHCQQBADEEAQBBwIvCAAsHCgQBADIEAQBB0IvCAAsHCwQBADMEAQBB4IvCAAsHDAQBADQEAQBB8IvCAAsHDQQBADUEAQBBgIzCAAsHDgQBADYEAQBBkIzCAAsHDwQBADcEAQBBoIzCAAsHEAQBADgEAQBBsIzCAAsHEQQBADkEAQBBwIzCAAsHEgQBADoEAQBB0IzCAAsHEwQBADsEAQBB4IzCAAsHFAQBADwEAQBB8IzCAAsHFQQBAD0EAQBBgI3CAAsHFgQBAD4EAQBBkI3CAAsHFwQBAD8EAQBBoI3CAAsHGAQBAEAEAQBBsI3CAAsHGQQBAEEEAQBBwI3CAAsHGgQBAEIEAQBB0I3CAAsHGwQBAEMEAQBB4I3CAAsHHAQBAEQEAQBB8I3CAAsHHQQBAEUEAQBBgI7CAAsHHgQBAEYEAQBBkI7CAAsHHwQBAEcEAQBBoI7CAAsHIAQBAEgEAQBBsI7CAAsHIQQBAEkEAQBBwI7CAAsHIgQBAEoEAQBB0I7CAAsHIwQBAEsEAQBB4I7CAAsHJAQBAEwEAQBB8I7CAAsHJQQBAE0EAQBBgI/CAAsHJgQBAE4EAQBBkI/CAAsHJwQBAE8EAQBBoI/CAAsHsAQBANgEAQBBsI/CAAsHsQQBANkEAQBBwI/CAAsHsgQBANoEAQBB0I/CAAsHswQBANsEAQBB4I/CAAsHtAQBANwEAQBB8I/CAAsHtQQBAN0EAQBBgJDCAAsHtgQBAN4EAQBBkJDCAAsHtwQBAN8EAQBBoJDCAAsHuAQBAOAEAQBBsJDCAAsHuQQBAOEEAQBBwJDCAAsHugQBAOIEAQBB0JDCAAsHuwQBAOMEAQBB4JDCAAsHvAQBAOQEAQBB8JDCAAsHvQQBAOUEAQBBgJHCAAsHvgQBAOYEAQBBkJHCAAsHvwQBAOcEAQBBoJHCAAsHwAQBAOgEAQBBsJHCAAsHwQQBAOkEAQBBwJHCAAsHwgQBAOoEAQBB0JHCAAsHwwQBAOsEAQBB4JHCAAsHxAQBAOwEAQBB8JHCAAsHxQQBAO0EAQBBgJLCAAsHxgQBAO4EAQBBkJLCAAsHxwQBAO8EAQBBoJLCAAsHyAQBAPAEAQBBsJLCAAsHyQQBAPEEAQBBwJLCAAsHygQBAPIEAQBB0JLCAAsHywQBAPMEAQBB4JLCAAsHzAQBAPQEAQBB8JLCAAsHzQQBAPUEAQBBgJPCAAsHzgQBAPYEAQBBkJPCAAsHzwQBAPcEAQBBoJPCAAsH0AQBAPgEAQBBsJPCAAsH0QQBAPkEAQBBwJPCAAsH0gQBAPoEAQBB0JPCAAsH0wQBAPsEAQBB4JPCAAsHgAwBAMAMAQBB8JPCAAsHgQwBAMEMAQBBgJTCAAsHggwBAMIMAQBBkJTCAAsHgwwBAMMMAQBBoJTCAAsHhAwBAMQMAQBBsJTCAAsHhQwBAMUMAQBBwJTCAAsHhgwBAMYMAQBB0JTCAAsHhwwBAMcMAQBB4JTCAAsHiAwBAMgMAQBB8JTCAAsHiQwBAMkMAQBBgJXCAAsHigwBAMoMAQBBkJXCAAsHiwwBAMsMAQBBoJXCAAsHjAwBAMwMAQBBsJXCAAsHjQwBAM0MAQBBwJXCAAsHjgwBAM4MAQBB0JXCAAsHjwwBAM8MAQBB4JXCAAsHkAwBANAMAQBB8JXCAAsHkQwBANEMAQBBgJbCAAsHkgwBANIMAQBBkJbCAAsHkwwBANMMAQBBoJbCAAsHlAwBANQMAQBBsJbCAAsHlQwBANUMAQBBwJbCAAsHlgwBANYMAQBB0JbCAAsHlwwBANcMAQBB4JbCAAsHmAwBANgMAQBB8JbCAAsHmQwBANkMAQBBgJfCAAsHmgwBANoMAQBBkJfCAAsHmwwBANsMAQBBoJfCAAsHnAwBANwMAQBBsJfCAAsHnQwBAN0MAQBBwJfCAAsHngwBAN4MAQBB0JfCAAsHnwwBAN8MAQBB4JfCAAsHoAwBAOAMAQBB8JfCAAsHoQwBAOEMAQBBgJjCAAsHogwBAOIMAQBBkJjCAAsHowwBAOMMAQBBoJjCAAsHpAwBAOQMAQBBsJjCAAsHpQwBAOUMAQBBwJjCAAsHpgwBAOYMAQBB0JjCAAsHpwwBAOcMAQBB4JjCAAsHqAwBAOgMAQBB8JjCAAsHqQwBAOkMAQBBgJnCAAsHqgwBAOoMAQBBkJnCAAsHqwwBAOsMAQBBoJnCAAsHrAwBAOwMAQBBsJnCAAsHrQwBAO0MAQBBwJnCAAsHrgwBAO4MAQBB0JnCAAsHrwwBAO8MAQBB4JnCAAsHsAwBAPAMAQBB8JnCAAsHsQwBAPEMAQBBgJrCAAsHsgwBAPIMAQBBkJrCAAsHoBgBAMAYAQBBoJrCAAsHoRgBAMEYAQBBsJrCAAsHohgBAMIYAQBBwJrCAAsHoxgBAMMYAQBB0JrCAAsHpBgBAMQYAQBB4JrCAAsHpRgBAMUYAQBB8JrCAAsHphgBAMYYAQBBgJvCAAsHpxgBAMcYAQBBkJvCAAsHqBgBAMgYAQBBoJvCAAsHqRgBAMkYAQBBsJvCAAsHqhgBAMoYAQBBwJvCAAsHqxgBAMsYAQBB0JvCAAsHrBgBAMwYAQBB4JvCAAsHrRgBAM0YAQBB8JvCAAsHrhgBAM4YAQBBgJzCAAsHrxgBAM8YAQBBkJzCAAsHsBgBANAYAQBBoJzCAAsHsRgBANEYAQBBsJzCAAsHshgBANIYAQBBwJzCAAsHsxgBANMYAQBB0JzCAAsHtBgBANQYAQBB4JzCAAsHtRgBANUYAQBB8JzCAAsHthgBANYYAQBBgJ3CAAsHtxgBANcYAQBBkJ3CAAsHuBgBANgYAQBBoJ3CAAsHuRgBANkYAQBBsJ3CAAsHuhgBANoYAQBBwJ3CAAsHuxgBANsYAQBB0J3CAAsHvBgBANwYAQBB4J3CAAsHvRgBAN0YAQBB8J3CAAsHvhgBAN4YAQBBgJ7CAAsHvxgBAN8YAQBBkJ7CAAsHQG4BAGBuAQBBoJ7CAAsHQW4BAGFuAQBBsJ7CAAsHQm4BAGJuAQBBwJ7CAAsHQ24BAGNuAQBB0J7CAAsHRG4BAGRuAQBB4J7CAAsHRW4BAGVuAQBB8J7CAAsHRm4BAGZuAQBBgJ/CAAsHR24BAGduAQBBkJ/CAAsHSG4BAGhuAQBBoJ/CAAsHSW4BAGluAQBBsJ/CAAsHSm4BAGpuAQBBwJ/CAAsHS24BAGtuAQBB0J/CAAsHTG4BAGxuAQBB4J/CAAsHTW4BAG1uAQBB8J/CAAsHTm4BAG5uAQBBgKDCAAsHT24BAG9uAQBBkKDCAAsHUG4BAHBuAQBBoKDCAAsHUW4BAHFuAQBBsKDCAAsHUm4BAHJuAQBBwKDCAAsHU24BAHNuAQBB0KDCAAsHVG4BAHRuAQBB4KDCAAsHVW4BAHVuAQBB8KDCAAsHVm4BAHZuAQBBgKHCAAsHV24BAHduAQBBkKHCAAsHWG4BAHhuAQBBoKHCAAsHWW4BAHluAQBBsKHCAAsHWm4BAHpuAQBBwKHCAAsHW24BAHtuAQBB0KHCAAsHXG4BAHxuAQBB4KHCAAsHXW4BAH1uAQBB8KHCAAsHXm4BAH5uAQBBgKLCAAsHX24BAH9uAQBBkaLCAAsG6QEAIukBAEGgosIACwcB6QEAI+kBAEGwosIACwcC6QEAJOkBAEHAosIACwcD6QEAJekBAEHQosIACwcE6QEAJukBAEHgosIACwcF6QEAJ+kBAEHwosIACwcG6QEAKOkBAEGAo8IACwcH6QEAKekBAEGQo8IACwcI6QEAKukBAEGgo8IACwcJ6QEAK+kBAEGwo8IACwcK6QEALOkBAEHAo8IACwcL6QEALekBAEHQo8IACwcM6QEALukBAEHgo8IACwcN6QEAL+kBAEHwo8IACwcO6QEAMOkBAEGApMIACwcP6QEAMekBAEGQpMIACwcQ6QEAMukBAEGgpMIACwcR6QEAM+kBAEGwpMIACwcS6QEANOkBAEHApMIACwcT6QEANekBAEHQpMIACwcU6QEANukBAEHgpMIACwcV6QEAN+kBAEHwpMIACwcW6QEAOOkBAEGApcIACwcX6QEAOekBAEGQpcIACwcY6QEAOukBAEGgpcIACwcZ6QEAO+kBAEGwpcIACwca6QEAPOkBAEHApcIACwcb6QEAPekBAEHQpcIACwcc6QEAPukBAEHgpcIACwcd6QEAP+kBAEHwpcIACwce6QEAQOkBAEGApsIACwcf6QEAQekBAEGQpsIACwcg6QEAQukBAEGgpsIACwch6QEAQ+kBAEGwpsIAC+MZ/////0hhc2ggdGFibGUgY2FwYWNpdHkgb3ZlcmZsb3dgkxAATgAAAFAAAAAbAAAAL2NhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvaGFzaGJyb3duLTAuNi4yL3NyYy9yYXcvbW9kLnJzAAAIlhAASAAAAAICAAAjAAAACJYQAEgAAAADAgAAIwAAAOCTEABJAAAAhwIAAB0AAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliY29yZS9zbGljZS9zb3J0LnJzAAAA4JMQAEkAAACdAAAAOgAAAOCTEABJAAAApAAAADAAAAANAAAAAAAAAAEAAAAOAAAADwAAAAAAAAABAAAAEAAAAHyUEAA9AAAAJQAAAA0AAAAvVXNlcnMvdGhhdW1hbnQvUGxheWdyb3VuZC9sdWNpZC1zdWdnZXN0L3J1c3QvY29yZS9zcmMvbGliLnJzRHVwbGljYXRlIHN0b3JlIGlkAHyUEAA9AAAALwAAAA0AAAB8lBAAPQAAADsAAAANAAAATWlzc2luZyBzdG9yZSBpZHyUEAA9AAAAQwAAAA0AAABhbHJlYWR5IG11dGFibHkgYm9ycm93ZWQRAAAAAAAAAAEAAAASAAAAAgEDABMAAAAAAAAAAQAAAA4AAAAUAAAAAAAAAAEAAAAOAAAAAAAAABUAAAAAAAAAAQAAAA4AAAB8lRAASwAAACMAAAAJAAAAL1VzZXJzL3RoYXVtYW50L1BsYXlncm91bmQvbHVjaWQtc3VnZ2VzdC9ydXN0L2NvcmUvc3JjL3Rva2VuaXphdGlvbi93b3JkLnJzTWFsZm9ybWVkIHdvcmRzAACctxAAFwAAALkBAAAnAAAACJYQAEgAAADsCgAACgAAAAiWEABIAAAA8goAAA4AAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliY29yZS9zbGljZS9tb2QucnNwlhAARQAAAAkDAAAeAAAAcJYQAEUAAAAMAwAANgAAAC9ydXN0Yy83MzUyOGUzMzlhYWUwZjE3YTE1ZmZhNDlhOGFjNjA4ZjUwYzZjZjE0L3NyYy9saWJhbGxvYy9zbGljZS5yc2Fzc2VydGlvbiBmYWlsZWQ6IG1pZCA8PSBsZW4AAAB0thAAGAAAADYEAAANAAAAYXNzZXJ0aW9uIGZhaWxlZDogaW5kZXggPD0gbGVuAAAUlxAAEwAAAOMDAAAJAAAAc3JjL2xpYmFsbG9jL3ZlYy5yc2Fzc2VydGlvbiBmYWlsZWQ6IGluZGV4IDwgbGVuFJcQABMAAAALBAAACQAAAHSXEABDAAAANwsAADAAAAB0lxAAQwAAAEMLAAAsAAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmFsbG9jL3ZlYy5ycwAWAAAAAAAAAAEAAAAOAAAABgAAAGIAAAAGAAAAYwAAAAYAAABkAAAABgAAAGYAAAAGAAAAZwAAAAYAAABoAAAABgAAAGoAAAAGAAAAawAAAAYAAABsAAAABgAAAG0AAAAGAAAAbgAAAAYAAABwAAAABgAAAHEAAAAGAAAAcgAAAAYAAABzAAAABgAAAHQAAAAGAAAAdgAAAAYAAAB3AAAABgAAAHgAAAAGAAAAegAAAAcAAABhAAAABwAAAGUAAAAHAAAAaQAAAAcAAABvAAAABwAAAHUAAAAHAAAAeQAAAGVudHJlcXVlc2nDiUVVSU/DnHnDkU7DsUXMgWXMgVXMiHXMiE7Mg27Mg2NvbW9jb250cmFkZXNkZWR1cmFudGVlc3RhbWFzcGFyYXBvcnByw7N4aW1vc29icmVwb3JxdWXDgcONw5PDmkHMgUnMgU/MgVXMgWHMgWnMgW/MgXXMgQAAAAGZEAADAAAA+ZgQAAIAAACwmBAAAwAAAKKYEAACAAAABJkQAAMAAAD7mBAAAgAAAAeZEAADAAAA/ZgQAAIAAAAKmRAAAwAAAP+YEAACAAAADZkQAAMAAAAOtRAAAgAAALOYEAADAAAAELUQAAIAAAAQmRAAAwAAABK1EAACAAAAE5kQAAMAAAAUtRAAAgAAABaZEAADAAAAFrUQAAIAAAC8mBAAAwAAAKuYEAACAAAAv5gQAAMAAACumBAAAgAAALaYEAADAAAAqJgQAAIAAAC5mBAAAwAAAAG3EAACAAAA+ZgQAAIAAAAItxAAAQAAAKKYEAACAAAApJgQAAEAAAD7mBAAAgAAAKaYEAABAAAA/ZgQAAIAAACnmBAAAQAAAP+YEAACAAAApZgQAAEAAAAOtRAAAgAAAPy2EAABAAAAELUQAAIAAAD9thAAAQAAABK1EAACAAAA/rYQAAEAAAAUtRAAAgAAAP+2EAABAAAAFrUQAAIAAAAAtxAAAQAAAKuYEAACAAAArZgQAAEAAACumBAAAgAAAAe3EAABAAAAqJgQAAIAAAClmBAAAQAAAAG3EAACAAAAALcQAAEAAAAJAAAAfJ0QAAIAAAAJAAAAGbYQAAIAAAAJAAAANrYQAAMAAAAJAAAAK7YQAAMAAAAJAAAABbcQAAIAAAAJAAAAfp0QAAMAAAAJAAAAgZ0QAAQAAAAJAAAAhZ0QAAQAAAAFAAAA/LYQAAEAAAAFAAAAiZ0QAAUAAAAFAAAAjp0QAAkAAAAFAAAA37UQAAUAAAAFAAAAl50QAAgAAAAFAAAAn50QAAYAAAAFAAAApZ0QAAQAAAAFAAAAwpgQAAQAAAAFAAAAqZ0QAAMAAAAFAAAAxpgQAAYAAAAFAAAAA7cQAAIAAAAFAAAArJ0QAAYAAAAFAAAAzJgQAAUAAAAFAAAA0ZgQAAcAAAAFAAAAlLMQAAIAAAAFAAAAsp0QAAYAAAAFAAAAmJgQAAUAAAAFAAAA2JgQAAQAAAAFAAAAuJ0QAAQAAAAFAAAAvJ0QAAUAAAAFAAAAwZ0QAAUAAAAFAAAAxp0QAAUAAAAFAAAAy50QAAUAAAAFAAAA0J0QAAQAAAAFAAAA1J0QAAcAAAAFAAAA35gQAAQAAAAFAAAA250QAAQAAAAFAAAA45gQAAMAAAAFAAAA5pgQAAgAAAAFAAAAnZgQAAMAAAAFAAAA350QAAUAAAAFAAAA5J0QAAMAAAAFAAAA7pgQAAUAAAAFAAAA550QAAQAAAAGAAAA650QAAYAAAAGAAAAwpgQAAQAAAAGAAAA/bYQAAEAAAAGAAAA8Z0QAAgAAAAGAAAA+Z0QAAcAAAAGAAAA3JgQAAMAAAAGAAAA/7YQAAEAAAAGAAAA250QAAQAAAAGAAAA85gQAAYAAAAGAAAAAJ4QAAQAAAAGAAAAoJgQAAIAAAAGAAAABJ4QAAQAAAAGAAAAALcQAAEAAAAGAAAAqpgQAAEAAABlbHVuYXVub3N1bmFzYWJham9hbHJlZGVkb3JhcXVlbGxvc2FycmliYWJham9jb25kZW50cm9lbmNpbWFlc3RvZXN0b3NmdWVyYWhhY2lhaGFzdGFtw6Fzb3B1ZXN0b3Blcm9zYWx2b3NpbnbDrWFhdW5xdWVlbnRvbmNlc2V4Y2VwdG9wdWVzc2lub05vcm1hbGl6YXRpb24gc2hvdWxkIGFsd2F5cyBiZSB0aGUgZmlyc3Qgc3RlcAAAAEieEABLAAAAYAAAAA0AAAAvVXNlcnMvdGhhdW1hbnQvUGxheWdyb3VuZC9sdWNpZC1zdWdnZXN0L3J1c3QvY29yZS9zcmMvdG9rZW5pemF0aW9uL3RleHQucnMAFwAAAGNhbm5vdCBhY2Nlc3MgYSBUaHJlYWQgTG9jYWwgU3RvcmFnZSB2YWx1ZSBkdXJpbmcgb3IgYWZ0ZXIgZGVzdHJ1Y3Rpb25hbHJlYWR5IGJvcnJvd2VkAAAYAAAAAAAAAAEAAAAOAAAAGQAAAAAAAAABAAAAEAAAACCfEABwAAAAoQAAABEAAAAvVXNlcnMvdGhhdW1hbnQvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvcnVzdC1zdGVtbWVycy0xLjIuMC9zcmMvc25vd2JhbGwvc25vd2JhbGxfZW52LnJzIJ8QAHAAAADKAAAAEQAAACCfEABwAAAADAEAABYAAAAgnxAAcAAAABIBAAAYAAAAIJ8QAHAAAAAuAQAAFgAAACCfEABwAAAAVgEAABYAAAAgnxAAcAAAAFwBAAAYAAAAIJ8QAHAAAABcAQAASQAAACCfEABwAAAAdwEAABYAAAARQRAAQaDAwgALFAERBAoZthAAAgAAAP//////////AEG8wMIACxAbthAABAAAAAAAAAD/////AEHUwMIACxAfthAAAgAAAP//////////AEHswMIACxAhthAAAgAAAP//////////AEGEwcIACxAjthAAAgAAAP//////////AEGcwcIACxAlthAAAgAAAP//////////AEG0wcIACxAnthAABAAAAAUAAAD/////AEHMwcIACxArthAAAwAAAP//////////AEHkwcIACxAuthAABQAAAAcAAAD/////AEH8wcIACxAzthAAAwAAAP//////////AEGUwsIACxA2thAAAwAAAP//////////AEGswsIACxA5thAABQAAAAoAAAD/////AEHEwsIACxA+thAAAwAAAP//////////AEHcwsIACw1pohAABAAAAP////8GAEH0wsIACw1kohAABQAAAP////8GAEGMw8IACw31tBAABQAAAP////8HAEGkw8IACw0FthAABQAAAP////8CAEG8w8IACw0KthAABgAAAP////8BAEHUw8IACw1tohAAAgAAAP////8GAEHsw8IACw1vohAAAgAAAP////8GAEGExMIACw1xohAAAgAAAP////8GAEGcxMIACw0QthAAAwAAAP////8DAEG0xMIACw0TthAAAwAAAP////8EAEHMxMIACw0WthAAAwAAAP////8FAEHkxMIACx1pZW5kb2FuZG9hcmVyaXIALLUQAAMAAAD/////AQBBjMXCAAsNL7UQAAUAAAD/////AgBBpMXCAAsNNLUQAAUAAAD/////BQBBvMXCAAsNObUQAAUAAAD/////AgBB1MXCAAsNPrUQAAMAAAD/////AQBB7MXCAAsNQbUQAAQAAAD/////AQBBhMbCAAsNRbUQAAMAAAD/////CQBBnMbCAAsNSLUQAAQAAAD/////AQBBtMbCAAsNTLUQAAYAAAD/////AwBBzMbCAAsNUrUQAAQAAAD/////CABB5MbCAAsNILUQAAQAAAD/////AQBB/MbCAAsNJLUQAAQAAAD/////AQBBlMfCAAsNKLUQAAQAAAD/////AgBBrMfCAAsNVrUQAAUAAAD/////BwBBxMfCAAsNW7UQAAYAAAANAAAABgBB3MfCAAsNYbUQAAYAAAD/////AgBB9MfCAAsNZ7UQAAYAAAD/////BABBjMjCAAsNbbUQAAMAAAD/////AQBBpMjCAAsNcLUQAAQAAAD/////AQBBvMjCAAsNdLUQAAMAAAD/////AQBB1MjCAAsNd7UQAAcAAAD/////AQBB7MjCAAsNfrUQAAcAAAD/////AQBBhMnCAAsNhbUQAAMAAAD/////CQBBnMnCAAsNiLUQAAQAAAD/////AgBBtMnCAAsNjLUQAAQAAAD/////AQBBzMnCAAsNkLUQAAYAAAD/////AgBB5MnCAAsNlrUQAAYAAAD/////BQBB/MnCAAsNnLUQAAYAAAD/////AgBBlMrCAAsNorUQAAQAAAD/////AQBBrMrCAAsNprUQAAUAAAD/////AQBBxMrCAAsNq7UQAAQAAAD/////CQBB3MrCAAsNr7UQAAUAAAD/////AQBB9MrCAAsNtLUQAAcAAAD/////AwBBjMvCAAsNu7UQAAYAAAD/////CABBpMvCAAsNwbUQAAUAAAD/////AQBBvMvCAAsNxrUQAAUAAAD/////AQBB1MvCAAsNy7UQAAcAAAD/////AgBB7MvCAAsN0rUQAAcAAAD/////BABBhMzCAAsN2bUQAAYAAAD/////AgBBnMzCAAsN37UQAAUAAAD/////AgBBtMzCAAsN5LUQAAQAAAD/////AQBBzMzCAAsN6LUQAAUAAAD/////AQBB5MzCAAsN7bUQAAQAAAD/////AQBB/MzCAAsN8bUQAAgAAAD/////AQBBlM3CAAsN+bUQAAgAAAD/////AQBBrM3CAAsNAbYQAAQAAAD/////CQBBxM3CAAscaWNsb2dlbnRlAAAAxKYQAAIAAAD//////////wBB6M3CAAsQWrMQAAIAAAD//////////wBBgM7CAAsQGLUQAAIAAAD//////////wBBmM7CAAsNHrUQAAIAAAD/////AQBBsM7CAAsRYXQAACC1EAAEAAAA/////wEAQczOwgALDSS1EAAEAAAA/////wEAQeTOwgALDSi1EAAEAAAA/////wEAQfzOwgALDcSmEAACAAAA/////wEAQZTPwgALDRq1EAAEAAAA/////wEAQazPwgALDR61EAACAAAA/////wEAQcTPwgALDfy2EAABAAAA/////wEAQdzPwgALDf22EAABAAAA/////wIAQfTPwgALDf+2EAABAAAA/////wEAQYzQwgALDRi1EAACAAAA/////wEAQaTQwgALDQ61EAACAAAA/////wEAQbzQwgALDRC1EAACAAAA/////wIAQdTQwgALDRK1EAACAAAA/////wEAQezQwgALDRS1EAACAAAA/////wEAQYTRwgALEWcAAAAOtRAAAAAAAP////8GAEGg0cIACw0OtRAAAgAAAAAAAAABAEG40cIACw0QtRAAAgAAAAAAAAACAEHQ0cIACw0StRAAAgAAAAAAAAADAEHo0cIACw0UtRAAAgAAAAAAAAAEAEGA0sIACw0WtRAAAgAAAAAAAAAFAEGY0sIACw3mtBAAAgAAAP////8BAEGw0sIACw3otBAAAgAAAP////8BAEHI0sIACw3qtBAAAwAAAP////8BAEHg0sIACw3ttBAAAwAAAP////8BAEH40sIACw3wtBAABQAAAP////8BAEGQ08IACw31tBAABQAAAP////8BAEGo08IACw36tBAAAgAAAP////8BAEHA08IACw38tBAAAwAAAP////8BAEHY08IACw3/tBAAAwAAAP////8BAEHw08IACw0CtRAABAAAAP////8BAEGI1MIACw0GtRAABQAAAP////8BAEGg1MIACw0LtRAAAwAAAP////8BAEG41MIACw04sxAAAwAAAP////8CAEHQ1MIACw07sxAAAwAAAP////8CAEHo1MIACw0+sxAAAwAAAP////8CAEGA1cIACw1BsxAAAwAAAP////8CAEGY1cIACw1EsxAABAAAAP////8CAEGw1cIACw1IsxAAAwAAAP////8CAEHI1cIACw1LsxAABQAAAAUAAAACAEHg1cIACw1QsxAABQAAAAUAAAACAEH41cIACw1VsxAABQAAAAUAAAACAEGQ1sIACw1asxAAAgAAAP////8CAEGo1sIACw1csxAAAgAAAP////8CAEHA1sIACw1esxAAAgAAAP////8CAEHY1sIACw1gsxAAAwAAAP////8CAEHw1sIACw1jsxAABAAAAP////8CAEGI18IACw1nsxAABAAAAP////8CAEGg18IACw1rsxAABAAAAP////8CAEG418IACw1vsxAAAgAAAP////8CAEHQ18IACw1xsxAABAAAABAAAAACAEHo18IACw11sxAABAAAABAAAAACAEGA2MIACw15sxAABQAAABAAAAACAEGY2MIACw1+sxAABAAAABAAAAACAEGw2MIACw2CsxAABgAAABQAAAACAEHI2MIACw2IsxAABgAAABQAAAACAEHg2MIACw2OsxAABgAAABQAAAACAEH42MIACw2UsxAAAgAAAP////8BAEGQ2cIACw2WsxAABAAAABgAAAACAEGo2cIACw2asxAABQAAABgAAAACAEHA2cIACw2fsxAABAAAAP////8CAEHY2cIACw2jsxAABQAAAP////8CAEHw2cIACw2osxAABQAAAP////8CAEGI2sIACw2tsxAABQAAAP////8CAEGg2sIACw2ysxAABQAAAP////8CAEG42sIACw23sxAAAwAAAP////8CAEHQ2sIACw26sxAAAwAAAP////8CAEHo2sIACw1pohAABAAAAP////8CAEGA28IACw1kohAABQAAAP////8CAEGY28IACw1tohAAAgAAAP////8CAEGw28IACw1vohAAAgAAAP////8CAEHI28IACw1xohAAAgAAAP////8CAEHg28IACw29sxAAAgAAAP////8CAEH428IACw2/sxAABAAAACcAAAACAEGQ3MIACw3DsxAABAAAACcAAAACAEGo3MIACw3HsxAABAAAACcAAAACAEHA3MIACw3LsxAABAAAACcAAAACAEHY3MIACw3PsxAABQAAACcAAAACAEHw3MIACw3UsxAABAAAACcAAAACAEGI3cIACw3YsxAABgAAAC0AAAACAEGg3cIACw3esxAABgAAAC0AAAACAEG43cIACw3ksxAABgAAAC0AAAACAEHQ3cIACw3qsxAAAgAAAP////8BAEHo3cIACw3ssxAABAAAADEAAAACAEGA3sIACw3wsxAABQAAADEAAAACAEGY3sIACw31sxAABQAAAP////8CAEGw3sIACw36sxAABQAAAP////8CAEHI3sIACw3/sxAABgAAAP////8CAEHg3sIACw0FtBAABQAAAP////8CAEH43sIACw0KtBAABwAAADcAAAACAEGQ38IACw0RtBAABwAAADcAAAACAEGo38IACw0YtBAABwAAADcAAAACAEHA38IACw0ftBAABQAAAP////8CAEHY38IACw0ktBAABgAAAP////8CAEHw38IACw0qtBAABgAAAP////8CAEGI4MIACw0wtBAABgAAAP////8CAEGg4MIACw02tBAABAAAAP////8CAEG44MIACw06tBAABAAAAP////8BAEHQ4MIACw0+tBAABgAAAEAAAAACAEHo4MIACw1EtBAABgAAAEAAAAACAEGA4cIACw1KtBAABgAAAEAAAAACAEGY4cIACw1QtBAABAAAAP////8CAEGw4cIACw1UtBAABAAAAP////8CAEHI4cIACw1YtBAABAAAAP////8CAEHg4cIACw1ctBAABwAAAEYAAAACAEH44cIACw1jtBAABwAAAEYAAAACAEGQ4sIACw1qtBAACAAAAEYAAAACAEGo4sIACw1ytBAABgAAAEYAAAACAEHA4sIACw14tBAACAAAAEoAAAACAEHY4sIACw2AtBAACAAAAEoAAAACAEHw4sIACw2ItBAACAAAAEoAAAACAEGI48IACw2QtBAABAAAAP////8BAEGg48IACw2UtBAABgAAAE4AAAACAEG448IACw2atBAABgAAAE4AAAACAEHQ48IACw2gtBAABgAAAE4AAAACAEHo48IACw2mtBAABwAAAE4AAAACAEGA5MIACw2ttBAACAAAAE4AAAACAEGY5MIACw21tBAABAAAAP////8CAEGw5MIACw25tBAABQAAAP////8CAEHI5MIACw2+tBAABQAAAP////8CAEHg5MIACw3DtBAABQAAAP////8CAEH45MIACw3ItBAAAwAAAP////8CAEGQ5cIACw3LtBAABAAAAP////8CAEGo5cIACw3PtBAABAAAAP////8CAEHA5cIACw3TtBAABAAAAP////8CAEHY5cIACw3XtBAABAAAAP////8CAEHw5cIACw3btBAABAAAAP////8CAEGI5sIACw3ftBAABAAAAP////8CAEGg5sIACw3jtBAAAwAAAP////8CAEG45sIAC7EJYWJhYWRhaWRhYXJhaWVyYcOtYWFyw61hZXLDrWFpcsOtYWFkZWRpZGFzZWllc2Vhc3RlaXN0ZWFuYWJhbmFyYW5pZXJhbsOtYW5hcsOtYW5lcsOtYW5pcsOtYW5lbmFzZW5pZXNlbmFyb25pZXJvbmFyw6FuZXLDoW5pcsOhbmFkb2lkb2FzYWJhc2FkYXNpZGFzYXJhc2llcmFzw61hc2Fyw61hc2Vyw61hc2lyw61hc2VzYXNlc2llc2VzYWJhaXNhcmFpc2llcmFpc8OtYWlzYXLDrWFpc2Vyw61haXNpcsOtYWlzYXNlaXNpZXNlaXNhc3RlaXNpc3RlaXPDoWlzw6lpc2Fyw6lpc2Vyw6lpc2lyw6lpc2Fkb3NpZG9zYW1vc8OhYmFtb3PDoXJhbW9zacOpcmFtb3PDrWFtb3NhcsOtYW1vc2Vyw61hbW9zaXLDrWFtb3NlbW9zYXJlbW9zZXJlbW9zaXJlbW9zw6FzZW1vc2nDqXNlbW9zaW1vc2Fyw6FzZXLDoXNpcsOhc8Otc2Fyw6FlcsOhaXLDoWFyw6llcsOpaXLDqWnDs3lheWV5YW55ZW55ZXJvbnllbmRveW95YXN5ZXN5YWlzeWFtb3N5w7PDocOpw63Ds8O6b3NhYmlsaXZhYmxlaWJsZWFudGVpY2FhbmNpYWVuY2lhYWRvcmFvc2Fpc3RhaXZhYW56YWxvZ8OtYWlkYWRtZW50ZWFtZW50ZWFjacOzbnVjacOzbmljb2lzbW9vc29hbWllbnRvaW1pZW50b2l2b2Fkb3JpY2FzYW5jaWFzZW5jaWFzYWRvcmFzb3Nhc2lzdGFzaXZhc2FuemFzbG9nw61hc2lkYWRlc2FibGVzaWJsZXNhY2lvbmVzdWNpb25lc2Fkb3Jlc2FudGVzaWNvc2lzbW9zb3Nvc2FtaWVudG9zaW1pZW50b3Npdm9zw6FuZG9pw6luZG/DoXLDqXLDrXJsYXNlbGFsZW1lc2Vsb3NlbG9sYXNzZWxhc2xlc2xvc3NlbG9zbm9zAAAAyLYQADQAAACMthAALQAAALm2EAAMAAAAxbYQAAMAAAB0thAAGAAAAKkIAAAJAAAAc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzYXNzZXJ0aW9uIGZhaWxlZDogYChsZWZ0ID09IHJpZ2h0KWAKICBsZWZ0OiBgYCwKIHJpZ2h0OiBgYDogZGVzdGluYXRpb24gYW5kIHNvdXJjZSBzbGljZXMgaGF2ZSBkaWZmZXJlbnQgbGVuZ3Roc2FlaW91w7xkZXVubkFBY2Nlc3NFcnJvcmNhbGxlZCBgT3B0aW9uOjp1bndyYXAoKWAgb24gYSBgTm9uZWAgdmFsdWUAULcQABUAAAB6AQAAFQAAAHNyYy9saWJjb3JlL29wdGlvbi5yc1RyaWVkIHRvIHNocmluayB0byBhIGxhcmdlciBjYXBhY2l0eQAAAJy3EAAXAAAAXQIAAAkAAABzcmMvbGliYWxsb2MvcmF3X3ZlYy5yc2ludGVybmFsIGVycm9yOiBlbnRlcmVkIHVucmVhY2hhYmxlIGNvZGUAnLcQABcAAAAKAgAAJwB7CXByb2R1Y2VycwIIbGFuZ3VhZ2UBBFJ1c3QADHByb2Nlc3NlZC1ieQMFcnVzdGMdMS40MC4wICg3MzUyOGUzMzkgMjAxOS0xMi0xNikGd2FscnVzBjAuMTQuMAx3YXNtLWJpbmRnZW4SMC4yLjU4ICgyOTAyY2ViMjYp';
-
-                var binary = null;
-
-                var isNode = typeof process !== 'undefined'
-                    && process.versions != null
-                    && process.versions.node != null;
-
-                if (isNode) {
-                    binary = Buffer.from(src, 'base64');
-                } else {
-                    var raw = window.atob(src);
-                    binary = new Uint8Array(new ArrayBuffer(raw.length));
-                    for(var i = 0; i < raw.length; i++) {
-                        binary[i] = raw.charCodeAt(i);
-                    }
-                }
-
-                var instantiatePromise = WebAssembly.instantiate(
-                    binary,
-                    {env: {memory: new WebAssembly.Memory({initial: 10})}}
-                );
-
-                var exportsPromise = instantiatePromise.then(result => {
-                    return result.instance.exports
-                });
-
-var compileWasm = exportsPromise.then(function(wasm) {
-                const __exports__ = {};
-
-                
-/**
-* @param {number} id
-*/
-__exports__.create_store = function(id) {
-    wasm.create_store(id);
-};
-
-/**
-* @param {number} id
-*/
-__exports__.destroy_store = function(id) {
-    wasm.destroy_store(id);
-};
-
-let WASM_VECTOR_LEN = 0;
-
-let cachegetUint8Memory0 = null;
-function getUint8Memory0() {
-    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
-    }
-    return cachegetUint8Memory0;
-}
-
-const lTextEncoder = typeof TextEncoder === 'undefined' ? require('util').TextEncoder : TextEncoder;
-
-let cachedTextEncoder = new lTextEncoder('utf-8');
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-}
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
-
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length);
-        getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
-        return ptr;
-    }
-
-    let len = arg.length;
-    let ptr = malloc(len);
-
-    const mem = getUint8Memory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
-    }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3);
-        const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
-
-        offset += ret.written;
-    }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {string} left
-* @param {string} right
-*/
-__exports__.highlight_with = function(store_id, left, right) {
-    var ptr0 = passStringToWasm0(left, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(right, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    wasm.highlight_with(store_id, ptr0, len0, ptr1, len1);
-};
-
-/**
-* @param {number} id
-* @param {number} limit
-*/
-__exports__.set_limit = function(id, limit) {
-    wasm.set_limit(id, limit);
-};
-
-let cachegetUint32Memory0 = null;
-function getUint32Memory0() {
-    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
-    }
-    return cachegetUint32Memory0;
-}
-
-function passArray32ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 4);
-    getUint32Memory0().set(arg, ptr / 4);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {Uint32Array} ids
-* @param {string} texts
-* @param {Uint32Array} ratings
-*/
-__exports__.set_records = function(store_id, ids, texts, ratings) {
-    var ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(texts, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    var ptr2 = passArray32ToWasm0(ratings, wasm.__wbindgen_malloc);
-    var len2 = WASM_VECTOR_LEN;
-    wasm.set_records(store_id, ptr0, len0, ptr1, len1, ptr2, len2);
-};
-
-/**
-* @param {number} store_id
-* @param {string} query
-*/
-__exports__.run_search = function(store_id, query) {
-    var ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    wasm.run_search(store_id, ptr0, len0);
-};
-
-let cachegetInt32Memory0 = null;
-function getInt32Memory0() {
-    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
-    }
-    return cachegetInt32Memory0;
-}
-
-function getArrayU32FromWasm0(ptr, len) {
-    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
-}
-/**
-* @param {number} store_id
-* @returns {Uint32Array}
-*/
-__exports__.get_result_ids = function(store_id) {
-    wasm.get_result_ids(8, store_id);
-    var r0 = getInt32Memory0()[8 / 4 + 0];
-    var r1 = getInt32Memory0()[8 / 4 + 1];
-    var v0 = getArrayU32FromWasm0(r0, r1).slice();
-    wasm.__wbindgen_free(r0, r1 * 4);
-    return v0;
-};
-
-const lTextDecoder = typeof TextDecoder === 'undefined' ? require('util').TextDecoder : TextDecoder;
-
-let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-
-cachedTextDecoder.decode();
-
-function getStringFromWasm0(ptr, len) {
-    return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
-}
-/**
-* @param {number} store_id
-* @returns {string}
-*/
-__exports__.get_result_titles = function(store_id) {
-    try {
-        wasm.get_result_titles(8, store_id);
-        var r0 = getInt32Memory0()[8 / 4 + 0];
-        var r1 = getInt32Memory0()[8 / 4 + 1];
-        return getStringFromWasm0(r0, r1);
-    } finally {
-        wasm.__wbindgen_free(r0, r1);
-    }
-};
-
-
-
-                return __exports__;
-            });
-
-const DEFAULT_LIMIT = 10;
-var NEXT_ID = 1;
-
-
-class LucidSuggest {
-    constructor() {
-        this.id         = NEXT_ID++;
-        this.limit      = DEFAULT_LIMIT;
-        this.records    = [];
-        this.setupQueue = compileWasm;
-
-        this.setup(wasm => {
-            wasm.create_store(this.id);
-            wasm.highlight_with(this.id, '{{', '}}');
-        });
-    }
-
-    setup(fn) {
-        this.setupQueue = this.setupQueue.then(async wasm => {
-            await fn(wasm);
-            this.setupQueue = Promise.resolve(wasm);
-            return wasm
-        });
-        return this.setupQueue
-    }
-
-    destroy() {
-        const oldQueue = this.setupQueue;
-        this.setupQueue = Promise.reject(new Error('Suggest destroyed'));
-        oldQueue.then(wasm => {
-            wasm.destroy_store(this.id);
-        });
-    }
-
-    setRecords(records) {
-        return this.setup(wasm => {
-            this.records = setRatings(records);
-            wasm.set_records(
-                this.id,
-                this.records.map(r => r.id),
-                this.records.map(r => r.title).join('\0'),
-                this.records.map(r => r.rating),
-            );
-        })
-    }
-
-    setLimit(limit) {
-        return this.setup(wasm => {
-            this.limit = limit;
-            wasm.set_limit(this.id, this.limit);
-        })
-    }
-
-    async search(query) {
-        const wasm = await this.setupQueue;
-        wasm.run_search(this.id, query);
-        const ids    = wasm.get_result_ids(this.id);
-        const titles = wasm.get_result_titles(this.id).split('\0');
-        const hits   = [];
-        for (let i = 0; i < ids.length; i++) {
-            const id     = ids[i];
-            const title  = titles[i];
-            const record = this.records.find(r => r.id === id);
-            if (!record) throw new Error(`Missing record ${id}`)
-            if (!title)  throw new Error(`Missing title for ${id}`)
-            hits.push(new Hit(title, record));
-        }
-        return hits
-    }
-}
-
-
-function highlight(hit, left, right) {
-    let result = '';
-    for (const {text, highlight} of hit.chunks) {
-        result += highlight
-            ? left + text + right
-            : text;
-    }
-    return result
-}
-
-
-class Hit {
-    constructor(title, record) {
-        this.record = record;
-        this.chunks = toChunks(title);
-    }
-
-    get title() {
-        return highlight(this, '[', ']')
-    }
-}
-
-
-function toChunks(title) {
-    const split  = title.split(/{{|}}/g);
-    const chunks = [];
-    for (let i = 0; i < split.length; i++) {
-        if (split[i] != '') {
-            chunks.push({
-                text: split[i],
-                highlight: i % 2 === 1,
-            });
-        }
-    }
-    return chunks
-}
-
-
-function setRatings(records) {
-    return records.some(r => r.rating != null && r.rating > 0)
-        ? records.map(r => ({...r, rating: r.rating > 0 ? r.rating : 0}))
-        : records.map((r, i) => ({...r, rating: records.length - i}))
-}
-
-exports.Hit = Hit;
-exports.LucidSuggest = LucidSuggest;
-exports.highlight = highlight;
@@ -1,346 +0,0 @@
-'use strict';
-
-Object.defineProperty(exports, '__esModule', { value: true });
-
-var src = 'AGFzbQEAAAABehNgAn9/AGACf38Bf2ADf39/AGADf39/AX9gAX8AYAR/f39/AGAFf39/f38AYAF/AX9gBH9/f38Bf2AAAX9gAABgB39/f39/f38AYAV/f39/fwF/YAd/f39/f39/AX9gAn9+AX9gAn5/AX9gAX8BfmABfAF8YAJ8fAF8A94B3AEFAgcCBwYGBgYGAAAAAAAAAAAAAAACCwAEBAUDBAEAAAIECAIBDAIJAAUAAQUIAQAABwIHCAcHBgMFAQgAAgIBAQAABgUFAQIFBQANAgEAAAAPAgAAAAEBAQEABAEBAAEBAQMABgQABQIFBQQFAAAABAQAAAIABQAAAAQCCQICBAARAAQGBgICAgACAAAAAAkJAAQIBAIEAgAIAgUOAAMAAAAAAQAAAAAAAAQAAgABBAAAAAoCBAABAgADCAACAgMEBwQHAAMJCQMFBQUEEgEBAQEACggBCgEKEAAEBAUBcAEaGgUDAQARBgkBfwFBgIDAAAsHxAEMBm1lbW9yeQIADGNyZWF0ZV9zdG9yZQAcDWRlc3Ryb3lfc3RvcmUAGA5oaWdobGlnaHRfd2l0aABDCXNldF9saW1pdABaC3NldF9yZWNvcmRzABYKcnVuX3NlYXJjaAADDmdldF9yZXN1bHRfaWRzAE4RZ2V0X3Jlc3VsdF90aXRsZXMAQhFfX3diaW5kZ2VuX21hbGxvYwDBARJfX3diaW5kZ2VuX3JlYWxsb2MAyAEPX193YmluZGdlbl9mcmVlANIBCTQBAEEBCxkC2gHRAR3VAaMBLtUBrgFW2wHZAdsBzwHbAdAB2wHOAdsB2wHbAdsBxwHbAdsBCp/qBtwB1VgDQX8FfgJ8IwBBgARrIgQkAAJAAkACQAJAAkAgASgCACIFIAEoAgRHBEAgBEHsAGohJyAEQdQDaiEoIARBlQNqIS8gBEHlA2ohMCAEQZACaiEdIARB+AJqIRggBEH4AGohMSAEQfUAaiE8IARByANqISIgBEHMA2ohGSAEQYQBaiE5IARB5ABqITogBEHgAGohPSAEQYsCaiEyIARB3QNqIR4gBEHsA2ohIyAEQdwCaiEpIARB7QJqIT4DQCABIAVBBGo2AgACQAJAAkACQAJAIAEoAggoAgAiBkEMaigCACIHIAUoAgAiBUsEQCAGKAIEIAVBPGxqIgUoAjghPyAFKAIIISogBSgCBCFAIAVBNGooAgAhMyAFQSxqKAIAITQgBUEoaigCACE1IAVBIGooAgAhNiAFQRxqKAIAITcgBUEUaigCACE4IAVBEGooAgAhJCADKAIAKAIAIQoQjwEiF0UNDBCQASIWRQ0MIBcoAgBFBEAgF0F/NgIAIBYoAgBFBEAgFkF/NgIAIBZBBGohCwJAIBdBBGoiESgCACIFRQRAQQAhBSAXQQA2AhQMAQsgFygCCEH/ASAFQQVqEMUBGiAXQQA2AhQgFygCBCIFQQhJDQAgBUEBakEDdkEHbCEFCyAXIAU2AhACQCALKAIAIgVFBEBBACEFIBZBADYCFAwBCyAWKAIIQf8BIAVBBWoQxQEaIBZBADYCFCAWKAIEIgVBCEkNACAFQQFqQQN2QQdsIQULIBYgBTYCECAKKAIEIgUEQCAKKAIAIhQgBUEUbGohJSAqICRBFGxqIhpBbGohKwNAAkAgCyAUKAIAIgkQYQ0AIAQgCTYCKCAEIBQvARA7AVAgBCAUKAIMNgI0IAQgFCgCCCIONgIwIAQgFCgCBCITNgIsIAQgCigCGCIfNgJIIAQgCigCECIgNgJAIAQgCigCCCIhNgI4IAQgCkEcaigCACIsNgJMIAQgCkEUaigCACJBNgJEIAQgCkEMaigCACJCNgI8IARBAjoAdCAkRQ0AQQIhByAqIQUCQANAAkACQCARIAUoAgAiDRBhDQAgBCAzNgK8ASAEIDQ2ArgBIAQgNTYCtAEgBCA2NgKwASAEIDc2AqwBIAQgODYCqAEgBCAFQRBqLwEAOwHAASAEIAVBDGooAgA2AqQBIAQgBUEIaigCACIMNgKgASAEIAVBBGooAgAiBjYCnAEgBCANNgKYAQJAAkAgJCANQQFqIhJNDQAgBCAzNgKsAiAEIDQ2AqgCIAQgNTYCpAIgBCA2NgKgAiAEIDc2ApwCIAQgODYCmAIgBCAqIBJBFGxqIggtABEiFToAsQIgBCAILQAQOgCwAiAEIAgoAgwiGzYClAIgBCAIKAIIIg82ApACIAQgCCgCBCIQNgKMAiAEIAgoAgA2AogCAkACQCAGIA9JBEAgECAMSQ0BIBAgDGshCAwCCyAGIA9rIQgMAQsQ1gEACyAOIBNrIAwgBmsgCGpJDQAgESASEGENACAEIBU6AJkDIARBCjoAmAMgBCAPNgL4AiAEIA02AvACIAQgMzYClAMgBCA0NgKQAyAEIDU2AowDIAQgNjYCiAMgBCA3NgKEAyAEIDg2AoADIAQgBjYC9AIgBCAQIAZrIBtqNgL8AiAEQcADaiAEQfACaiAEQShqEAEgBEGAAmoiDSAZQQhqIggpAgA3AwAgBEH2AWoiDCAeQQJqIhstAAA6AAAgBEHoAWoiBiAjQQhqIiYpAAA3AwAgBEHwAWoiDyAjQRBqIjsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiLUECRg0AIAQoAugDIRwgBCkD4AMhRSAEKALIAyEuIAQpA8ADIUYgBEHIAmoiECANKQMANwMAIARBqANqIhIgBikDADcDACAEQbADaiIVIA8oAgA2AgAgBCAEKQP4ATcDwAIgBCAELwH0ATsBuAMgBCAEKQPgATcDoAMgBCAMLQAAOgC6AyAEIC42AtgCIAQgRjcD0AIgKSAEKQPAAjcCACApQQhqIBApAwA3AgAgBCAtOgDsAiA+IAQvAbgDOwAAID5BAmogBC0AugM6AAAgBEHYAWoiLSAVKAIANgIAIARB0AFqIi4gEikDADcDACAEIAQpA6ADNwPIASAEQcADaiAEQdACaiAEQZgBaiAEQYgCahBEIA0gCCkCADcDACAMIBstAAA6AAAgBiAmKQAANwMAIA8gOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyImQQJGDQAgBCgC6AMhBSAEKQPgAyFHIAQoAsgDIQcgBCkDwAMhRiAQIA0pAwA3AwAgGCAGKQMANwMAIARBgANqIhMgDygCADYCACAEIAQpA/gBNwPAAiAEIAQvAfQBOwG4AyAEIAQpA+ABNwPwAiAEIAwtAAA6ALoDIAYgECkDADcDACAEIAQpA8ACNwPgASAEIAQvAbgDOwH4ASAEIAQtALoDOgD6ASAVIBMoAgA2AgAgEiAYKQMANwMAIAQgBCkD8AI3A6ADIAQgBzYCyAMgBCBGNwPAAyAZIAQpA+ABNwIAIAggBikDADcCACAEICY6ANwDIB4gBC8B+AE7AAAgGyAELQD6AToAACAEQfACaiARIAcgBEHAA2oQKSAZIAQpA6ADNwIAIAggEikDADcCACAZQRBqIgYgFSgCADYCACAEIAU2AsgDIAQgRzcDwAMgBEHwAmogESAFIARBwANqECkgGSAEKQPIATcCACAIIC4pAwA3AgAgBiAtKAIANgIAIAQgHDYCyAMgBCBFNwPAAyAEQfACaiALIBwgBEHAA2oQKQwBCwJAIAooAgQgCUEBaiIMTQ0AIAooAgAhBiAKKQIIIUUgCikCECFGIAQgCikCGDcDqAIgBCBGNwOgAiAEIEU3A5gCIAQgBiAMQRRsaiIGLQARIg86ALECIAQgBi0AEDoAsAIgBCAGKAIMIhA2ApQCIAQgBigCCCIINgKQAiAEIAYoAgQiDTYCjAIgBCAGKAIANgKIAiAEKAKcASESIAQoAqABAkACQCATIAhJBEAgDSAOSQ0BIA0gDmshBgwCCyATIAhrIQYMAQsQ1gEACyASayAOIBNrIAZqSQ0AIAsgDBBhDQAgBCAPOgCZAyAEQQo6AJgDIAQgCDYC+AIgBCAJNgLwAiAEICw2ApQDIAQgHzYCkAMgBCBBNgKMAyAEICA2AogDIAQgQjYChAMgBCAhNgKAAyAEIBM2AvQCIAQgDSATayAQajYC/AIgBEHAA2ogBEGYAWogBEHwAmoQASAEQYACaiINIBlBCGoiCCkCADcDACAEQfYBaiIMIB5BAmoiDy0AADoAACAEQegBaiIGICNBCGoiJikAADcDACAEQfABaiIQICNBEGoiOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyItQQJGDQAgBCgC6AMhLiAEKQPgAyFFIAQoAsgDIRwgBCkDwAMhRiAEQdABaiISIA0pAwA3AwAgBEG6A2oiQyAMLQAAOgAAIARBqANqIhUgBikDADcDACAEQbADaiIbIBAoAgA2AgAgBCAEKQP4ATcDyAEgBCAELwH0ATsBuAMgBCAEKQPgATcDoAMgBEHIAmoiRCASKQMANwMAIAQgBCkDyAE3A8ACIAQgBC8BuAM7AbgCIAQgQy0AADoAugIgBCAuNgLYAiAEIEU3A9ACICkgBCkDoAM3AgAgKUEIaiAVKQMANwIAIClBEGogGygCADYCACAEQcADaiAEQdACaiAEQShqIARBiAJqEEQgDSAIKQIANwMAIAwgDy0AADoAACAGICYpAAA3AwAgECA7KAAANgIAIAQgGSkCADcD+AEgBCAeLwAAOwH0ASAEICMpAAA3A+ABIAQtANwDIiZBAkYNACAEKALoAyEFIAQpA+ADIUcgBCgCyAMhByAEKQPAAyFFIBIgDSkDADcDACAYIAYpAwA3AwAgBEGAA2oiEyAQKAIANgIAIAQgBCkD+AE3A8gBIAQgBC8B9AE7AbgDIAQgBCkD4AE3A/ACIAQgDC0AADoAugMgBiASKQMANwMAIAQgBCkDyAE3A+ABIAQgBC8BuAM7AfgBIAQgBC0AugM6APoBIBsgEygCADYCACAVIBgpAwA3AwAgBCAEKQPwAjcDoAMgBCAcNgLIAyAEIEY3A8ADIBkgBCkDwAI3AgAgCCBEKQMANwIAIAQgLToA3AMgHiAELwG4AjsAACAPIAQtALoCOgAAIARB8AJqIBEgHCAEQcADahApIBkgBCkD4AE3AgAgCCAGKQMANwIAIB4gBC8B+AE7AAAgDyAELQD6AToAACAEIAc2AsgDIAQgRTcDwAMgBCAmOgDcAyAEQfACaiALIAcgBEHAA2oQKSAZIAQpA6ADNwIAIAggFSkDADcCACAZQRBqIBsoAgA2AgAgBCAFNgLIAyAEIEc3A8ADIARB8AJqIAsgBSAEQcADahApDAELIARBwANqIARBmAFqIARBKGoQASAEQdgCaiIIICJBCGooAgA2AgAgBCAiKQMANwPQAiAEKwPAAyFKIAQoAtQDIQ0gBCgC2AMhDCAELQDcAyEGIARB8AJqIB5BIxC/ARogBkECRg0BIARBqANqIg8gCCgCADYCACAEIAQpA9ACNwOgAyAEQYgCaiAEQfACakEjEL8BGiAYIA8oAgA2AgAgBCAEKQOgAzcD8AIgBCAELwCIAjsB0AIgBCAEQYoCai0AADoA0gIgBEHYA2oiECAyQRhqKQAANwMAIARB0ANqIhIgMkEQaikAADcDACAiIDJBCGopAAA3AwAgBCAyKQAANwPAAwJ/IEqbIktEAAAAAAAA8EFjIEtEAAAAAAAAAABmcQRAIEurDAELQQALIQ8CQAJAIAdB/wFxQQJGIhUNAEEAIQhBfyAMIA1rIA9BAXRrIhtBACAEQdgAaiAVGyIPKAIYIA8oAhRrAn8gDysDAJsiS0QAAAAAAADwQWMgS0QAAAAAAAAAAGZxBEAgS6sMAQtBAAtBAXRrIg9HIA8gG0kbQQFqIg9BAUsNASAPQQFrDQAgBg0BCyA9IAQpA/ACNwMAIDwgBC8B0AI7AAAgMSAEKQPAAzcDACA8QQJqIAQtANICOgAAID1BCGogGCgCADYCACAxQQhqICIpAwA3AwAgMUEQaiASKQMANwMAIDFBGGogECkDADcDACAEIAw2AnAgBCANNgJsIAQgBjoAdCAGQQFzIQggBCBKOQNYIEq9IUcgBiEHCyAFICtGDQQgBUEUaiEFIAhFDQMMBAsgBEECOgDcA0EAIQUDQCAEQdgAaiAFaiIGKQMAIUcgBiAEQcADaiAFaiIHKQMANwMAIAcgRzcDACAGQQhqIggpAwAhRSAIIAdBCGoiCCkDADcDACAGQRBqIhMpAwAhRiATIAdBEGoiEykDADcDACAGQRhqIgYpAwAhRyAGIAdBGGoiBikDADcDACAGIEc3AwAgEyBGNwMAIAggRTcDACAFQUBrIAVBIGohBUHBAEkNAAsgBC0AdCEHDAELIBogBUEUaiIFRw0BCwsgB0H/AXFBAkYNASAEKQNYIUcLIAQpA3ghRSAEKAKAASEFIAQoAmAhBiAZIDopAgA3AgAgGUEIaiIHIDpBCGopAgA3AgAgGUEQaiIIIDpBEGooAgA2AgAgBCAGNgLIAyAEIEc3A8ADIARB8AJqIBEgBiAEQcADahApIBkgOSkCADcCACAHIDlBCGopAgA3AgAgCCA5QRBqKAIANgIAIAQgBTYCyAMgBCBFNwPAAyAEQfACaiALIAUgBEHAA2oQKQsgFEEUaiIUICVHDQALCyAXKAIIIgUoAgAhCCAXKAIUIQYgF0EANgIUIBcoAhAhFCAXQQA2AhAgFygCDCEHIBdBCDYCDCAXQbCmwgA2AgggFygCBCEKIBdBADYCBCAEIBE2AoABIAQgBjYCfCAEIBQ2AnggBCAHNgJ0IAQgBTYCcCAEIAo2AmwgBCAGNgJoIAQgBSAKakEBajYCZCAEIAVBBGo2AmAgBCAHNgJcIAQgCEF/c0GAgYKEeHE2AlggBEHAA2ogBEHYAGoQggECfyAELQDkAyIGQQJGBEACQCAEKAJsIgVFBEBBACEFIARBADYCfAwBCyAEKAJwQf8BIAVBBWoQxQEaIARBADYCfCAFQQhJDQAgBUEBakEDdkEHbCEFCyAEIAU2AnggBCgCgAEiBSAnKQIANwIAIAVBEGogJ0EQaigCADYCACAFQQhqICdBCGopAgA3AgBCACFFQQgMAQsgBEHwAmogBEHAA2pBJBC/ARogHSAYQQhqKQMANwMAIARBmAJqIgogGEEQaikDADcDACAEQaACaiIIIBhBGGooAgA2AgAgBCAwQQJqLQAAOgCaASAEIDAvAAA7AZgBIAQgGCkDADcDiAIgBEEgakF/IAQoAmgiBUEBaiIHIAcgBUkbEKkBIAQoAiQhBSAEKAIgIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsEL8BGiAEQfACaiAEQcADahCCAQJAIAQtAJQDIghBAkYNAEEBIQZBICEJA0AgBEGIAmogBEHwAmpBJBC/ARogBEGwAWogHUEYaigCACIKNgIAIARBqAFqIB1BEGopAwAiRTcDACAEQaABaiAdQQhqKQMAIkY3AwAgBCAvQQJqLQAAOgDSAiAEIC8vAAA7AdACIAQgHSkDACJHNwOYASAYIEY3AwAgBEGAA2oiESBFNwMAIARBiANqIhQgCjYCACAEIEc3A/ACIAQgBC8B0AI7AYgCIAQgBC0A0gI6AIoCIAUgBkYEQCAEQShqIAVBfyAEKALQAyIFQQFqIgcgByAFSRsQhwEgBCgCKCEHCyAHIAlqIgUgBCkD8AI3AwAgGCkDACFFIBEpAwAhRiAUKAIAIQogBUEcaiAIOgAAIAVBHWogBC8BiAI7AAAgBUEfaiAELQCKAjoAACAFQRhqIAo2AgAgBUEQaiBGNwMAIAVBCGogRTcDACAEIAZBAWoiBjYCMCAEQfACaiAEQcADahCCASAELQCUAyIIQQJGDQEgCUEgaiEJIAQoAiwhBQwACwALAkAgBCgC1AMiBUUEQEEAIQUgBEEANgLkAwwBCyAEKALYA0H/ASAFQQVqEMUBGiAEQQA2AuQDIAVBCEkNACAFQQFqQQN2QQdsIQULIAQoAugDIgYgKCkCADcCACAEIAU2AuADIAZBCGogKEEIaikCADcCACAGQRBqIChBEGooAgA2AgAgBCkCLCFFIAQoAigLIQ0gFigCCCIFKAIAIQggFigCFCEGIBZBADYCFCAWKAIQIREgFkEANgIQIBYoAgwhByAWQQg2AgwgFkGwpsIANgIIIBYoAgQhCiAWQQA2AgQgBCALNgKAASAEIAY2AnwgBCARNgJ4IAQgBzYCdCAEIAU2AnAgBCAKNgJsIAQgBjYCaCAEIAUgCmpBAWo2AmQgBCAFQQRqNgJgIAQgBzYCXCAEIAhBf3NBgIGChHhxNgJYIARBwANqIARB2ABqEIIBAn8gBC0A5AMiBkECRgRAAkAgBCgCbCIFRQRAQQAhBSAEQQA2AnwMAQsgBCgCcEH/ASAFQQVqEMUBGiAEQQA2AnwgBUEISQ0AIAVBAWpBA3ZBB2whBQsgBCAFNgJ4IAQoAoABIgUgJykCADcCACAFQRBqICdBEGooAgA2AgAgBUEIaiAnQQhqKQIANwIAQQAhG0EAIRFBCAwBCyAEQfACaiAEQcADakEkEL8BGiAdIBhBCGopAwA3AwAgBEGYAmoiCiAYQRBqKQMANwMAIARBoAJqIgggGEEYaigCADYCACAEIDBBAmotAAA6AJoBIAQgMC8AADsBmAEgBCAYKQMANwOIAkEBIREgBEEYakF/IAQoAmgiBUEBaiIHIAcgBUkbEKkBIAQoAhwhBSAEKAIYIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsEL8BGiAEQfACaiAEQcADahCCASAELQCUAyIIQQJHBEBBASEGQSAhCQNAIARBiAJqIARB8AJqQSQQvwEaIARBsAFqIB1BGGooAgAiCjYCACAEQagBaiAdQRBqKQMAIkY3AwAgBEGgAWogHUEIaikDACJINwMAIAQgL0ECai0AADoA0gIgBCAvLwAAOwHQAiAEIB0pAwAiRzcDmAEgGCBINwMAIARBgANqIgsgRjcDACAEQYgDaiIRIAo2AgAgBCBHNwPwAiAEIAQvAdACOwGIAiAEIAQtANICOgCKAiAFIAZGBEAgBEEoaiAFQX8gBCgC0AMiBUEBaiIHIAcgBUkbEIcBIAQoAighBwsgByAJaiIFIAQpA/ACNwMAIBgpAwAhRiALKQMAIUggESgCACEKIAVBHGogCDoAACAFQR1qIAQvAYgCOwAAIAVBH2ogBC0AigI6AAAgBUEYaiAKNgIAIAVBEGogSDcDACAFQQhqIEY3AwAgBCAGQQFqIgo2AjAgBEHwAmogBEHAA2oQggEgBC0AlAMiCEECRwRAIAlBIGohCSAEKAIsIQUgCiEGDAELCyAGQQFqIRELAkAgBCgC1AMiBUUEQEEAIQUgBEEANgLkAwwBCyAEKALYA0H/ASAFQQVqEMUBGiAEQQA2AuQDIAVBCEkNACAFQQFqQQN2QQdsIQULIAQoAugDIgYgKCkCADcCACAEIAU2AuADIAZBCGogKEEIaikCADcCACAGQRBqIChBEGooAgA2AgAgBCgCLCEbIAQoAigLIQ8gRUIgiCJGpyIUQRVJDQMgBEEQaiBFQiGIpxCpASAEKAIUIAQoAhAhE0EAIQUgBEEANgJgIARCBDcDWCANQWBqIRwgDUGof2ohJUEAIQcgFCEKA0ACQCAKIgtBf2oiCkUEQEEBIQhBACEKDAELAkACQAJAAkACQCANIApBBXRqKAIIIAtBBXQiDCANakFIaigCACIGTwRAIAtBfmohDiAMICVqIQhBACEKQQAhCQNAIAkgDkYEQCALIQgMCAsgCUEBaiEJIAYgCCgCACIGTyAIQWBqIQgNAAsgCUEBaiEIIAlBf3MgC2ohCgwBCyAMICVqIQkDQAJAIApBAUYEQEEAIQoMAQsgCkF/aiEKIAYgCSgCACIGSSAJQWBqIQkNAQsLIAsgCkkNASALIBRLDQIgCyAKayIIQQF2Ig5FDQAgDSAKQQV0aiEGIAwgHGohCQNAIARB2ANqIgwgBkEYaiIQKQMANwMAIARB0ANqIhIgBkEQaiIVKQMANwMAICIgBkEIaiIaKQMANwMAIAQgBikDADcDwAMgCUEIaiIfKQMAIUggCUEQaiIgKQMAIUkgCUEYaiIhKQMAIUcgBiAJKQMANwMAIBAgRzcDACAVIEk3AwAgGiBINwMAICEgDCkDADcDACAgIBIpAwA3AwAgHyAiKQMANwMAIAkgBCkDwAM3AwAgBkEgaiEGIAlBYGohCSAOQX9qIg4NAAsLIApFIAhBCUtyDQQgCyAKayEIIApBf2ohBSAcIApBBXRqIQYDQCALIAVJDQMgCyAUSw0CIAYgCEEBaiIIEEEgBUF/aiIHIAVPDQQgBkFgaiEGIAchBSAIQQpJDQALDAMLIAogCxCtAQALIAsgFBCrAQALIAUgCxCtAQALIAdBAWohCiAEKAJgIgUhBwsgBCgCXCAHRgRAIARB2ABqIAcQjgEgBCgCYCIFIQcLIAQoAlgiBiAHQQN0aiIHIAg2AgQgByAKNgIAIAQgBUEBaiIFNgJgAn8CQCAFQQJJDQACQANAAkACQAJAAkAgBiAFQX9qQQN0aiIHKAIARQ0AIAVBA3QgBmoiDkF0aigCACILIAcoAgQiCE0NAEECIAVBAk0NBxogBiAFQX1qIgxBA3RqKAIEIgkgCCALak0NAUEDIAVBA00NBxogDkFkaigCACAJIAtqSw0GDAELIAVBA0kNASAHKAIEIQggBiAFQX1qIgxBA3RqKAIEIQkLIAkgCEkNAQsgBUF+aiEMCwJAAkACQAJAAkACQAJAIAUgDEEBaiISSwRAIAUgDE0NASAGIAxBA3QiH2oiBSgCBCIgIAUoAgBqIgUgBiASQQN0IiFqIgYoAgAiFUkNAiAFIBRLDQMgDSAVQQV0aiILIAYoAgQiEEEFdCIIaiEHIAVBBXQhGiAFIBVrIg4gEGsiBSAQTw0EIBMgByAFQQV0IgYQvwEiDiAGaiEJIBBBAU5BACAFQQFOG0UEQCAHIQUMBgsgGiAcaiEIIAchBQNAIAggBUFgaiIaIAlBYGoiLCAJQWhqKAIAIAVBaGooAgBJIgcbIgYpAwA3AwAgCEEYaiAGQRhqKQMANwMAIAhBEGogBkEQaikDADcDACAIQQhqIAZBCGopAwA3AwAgCSAsIAcbIQkgCyAaIAUgBxsiBU8NBiAIQWBqIQggCSAOIgZLDQALDAcLQeirwgAgEiAFEKwBAAtB6KvCACAMIAUQrAEACyAVIAUQrQEACyAFIBQQqwEACyAIIBMgCyAIEL8BIgZqIQkgDiAQSkEAIBBBAU4bDQEgCyEFCyATIQYMAQsgDSAaaiEOIAshBQNAIAUgByAGIAcoAgggBigCCEkiCxsiCCkDADcDACAFQRhqIAhBGGopAwA3AwAgBUEQaiAIQRBqKQMANwMAIAVBCGogCEEIaikDADcDACAGIAZBIGogCxshBiAFQSBqIQUgB0EgaiAHIAsbIgcgDk8NASAJIAZLDQALCyAFIAYgCSAGa0FgcRC/ARogBCgCYCIFIAxLBEAgBCgCWCIGIB9qIgcgECAgajYCBCAHIBU2AgAgBSASTQ0CIAYgIWoiByAHQQhqIAUgEkF/c2pBA3QQngEaIAQgBUF/aiIFNgJgIAUgBUEBTQ0EGgwBCwtB+KvCACAMIAUQrAEAC0GnrsIAQR1BxK7CABC+AQALIAULIQcgCg0ACyAEKAJcBEAgBhAhC0UNBCATECEMBAsMDAsMCwtB6KvCACAFIAcQrAEACyAUQQJJDQAgFEF+aiEFIA0gFEEFdGpBQGohBkECIQkDQCAFIBRLDQIgBiAJEEEgBkFgaiEGIAlBAWohCSAFQX9qIgVBf0cNAAsLIBFBFUkNASAEQQhqIBFBAXYQqQEgBCgCDCAEKAIIIRNBACEFIARBADYCYCAEQgQ3A1ggD0FgaiEcIA9BqH9qISVBACEHIBEhCgNAAkAgCiILQX9qIgpFBEBBASEIQQAhCgwBCwJAAkACQAJAAkAgDyAKQQV0aigCCCALQQV0IgwgD2pBSGooAgAiBk8EQCALQX5qIQ4gDCAlaiEIQQAhCkEAIQkDQCAJIA5GBEAgCyEIDAgLIAlBAWohCSAGIAgoAgAiBk8gCEFgaiEIDQALIAlBAWohCCAJQX9zIAtqIQoMAQsgDCAlaiEJA0ACQCAKQQFGBEBBACEKDAELIApBf2ohCiAGIAkoAgAiBkkgCUFgaiEJDQELCyALIApJDQEgCyARSw0CIAsgCmsiCEEBdiIORQ0AIA8gCkEFdGohBiAMIBxqIQkDQCAEQdgDaiIMIAZBGGoiECkDADcDACAEQdADaiISIAZBEGoiFSkDADcDACAiIAZBCGoiGikDADcDACAEIAYpAwA3A8ADIAlBCGoiHykDACFIIAlBEGoiICkDACFJIAlBGGoiISkDACFHIAYgCSkDADcDACAQIEc3AwAgFSBJNwMAIBogSDcDACAhIAwpAwA3AwAgICASKQMANwMAIB8gIikDADcDACAJIAQpA8ADNwMAIAZBIGohBiAJQWBqIQkgDkF/aiIODQALCyAKRSAIQQlLcg0EIAsgCmshCCAKQX9qIQUgHCAKQQV0aiEGA0AgCyAFSQ0DIAsgEUsNAiAGIAhBAWoiCBBBIAVBf2oiByAFTw0EIAZBYGohBiAHIQUgCEEKSQ0ACwwDCyAKIAsQrQEACyALIBEQqwEACyAFIAsQrQEACyAHQQFqIQogBCgCYCIFIQcLIAQoAlwgB0YEQCAEQdgAaiAHEI4BIAQoAmAiBSEHCyAEKAJYIgYgB0EDdGoiByAINgIEIAcgCjYCACAEIAVBAWoiBTYCYAJ/AkAgBUECSQ0AAkADQAJAAkACQAJAIAYgBUF/akEDdGoiBygCAEUNACAFQQN0IAZqIg5BdGooAgAiCyAHKAIEIghNDQBBAiAFQQJNDQcaIAYgBUF9aiIMQQN0aigCBCIJIAggC2pNDQFBAyAFQQNNDQcaIA5BZGooAgAgCSALaksNBgwBCyAFQQNJDQEgBygCBCEIIAYgBUF9aiIMQQN0aigCBCEJCyAJIAhJDQELIAVBfmohDAsCQAJAAkACQAJAAkACQCAFIAxBAWoiEksEQCAFIAxNDQEgBiAMQQN0Ih9qIgUoAgQiICAFKAIAaiIFIAYgEkEDdCIhaiIGKAIAIhVJDQIgBSARSw0DIA8gFUEFdGoiCyAGKAIEIhBBBXQiCGohByAFQQV0IRogBSAVayIOIBBrIgUgEE8NBCATIAcgBUEFdCIGEL8BIg4gBmohCSAQQQFOQQAgBUEBThtFBEAgByEFDAYLIBogHGohCCAHIQUDQCAIIAVBYGoiGiAJQWBqIiwgCUFoaigCACAFQWhqKAIASSIHGyIGKQMANwMAIAhBGGogBkEYaikDADcDACAIQRBqIAZBEGopAwA3AwAgCEEIaiAGQQhqKQMANwMAIAkgLCAHGyEJIAsgGiAFIAcbIgVPDQYgCEFgaiEIIAkgDiIGSw0ACwwHC0Hoq8IAIBIgBRCsAQALQeirwgAgDCAFEKwBAAsgFSAFEK0BAAsgBSAREKsBAAsgCCATIAsgCBC/ASIGaiEJIA4gEEpBACAQQQFOGw0BIAshBQsgEyEGDAELIA8gGmohDiALIQUDQCAFIAcgBiAHKAIIIAYoAghJIgsbIggpAwA3AwAgBUEYaiAIQRhqKQMANwMAIAVBEGogCEEQaikDADcDACAFQQhqIAhBCGopAwA3AwAgBiAGQSBqIAsbIQYgBUEgaiEFIAdBIGogByALGyIHIA5PDQEgCSAGSw0ACwsgBSAGIAkgBmtBYHEQvwEaIAQoAmAiBSAMSwRAIAQoAlgiBiAfaiIHIBAgIGo2AgQgByAVNgIAIAUgEk0NAiAGICFqIgcgB0EIaiAFIBJBf3NqQQN0EJ4BGiAEIAVBf2oiBTYCYCAFIAVBAU0NBBoMAQsLQfirwgAgDCAFEKwBAAtBp67CAEEdQcSuwgAQvgEACyAFCyEHIAoNAAsgBCgCXARAIAYQIQtFDQIgExAhDAILIAUgFBCtAQALIBFBAkkNACARQX5qIQUgDyARQQV0akFAaiEGQQIhCQNAIBEgBUkNBiAGIAkQQSAGQWBqIQYgCUEBaiEJIAVBf2oiBUF/Rw0ACwsgFiAWKAIAQQFqNgIAIBcgFygCAEEBajYCACANRQ0GIEanIgtBBXQhF0EAIQoCfyALRQRAQQAhB0EAIRNBACEWQQAMAQsgFEEFdCEGQQAhBUEAIRMDQCATIAUgDWoiB0EUaigCAGsgB0EYaigCAGoCfyAHKwMAmyJKRAAAAAAAAPBBYyBKRAAAAAAAAAAAZnEEQCBKqwwBC0EAC0EBdGshEyAGIAVBIGoiBUcNAAtBACEFQQAhBwNAIAcgBSANakEcai0AAEEBc2ohByAGIAVBIGoiBUcNAAtBACEJQQAhCANAIAggCSANaiIFQQxqKAIAayAFQRBqKAIAaiAFQRRqKAIAIAVBGGooAgBraiEIIAYgCUEgaiIJRw0AC0EAIQYgF0FgakEFdiIFIAtBf2oiDiAOIAVLGyIMBEAgDUEoaiEFQQAhCQNAIAZBACAFQWBqKAIAIhBBAWoiDiAFKAIAIhZrIhIgEiAOSxtqIgYgFiAQQX9zamogBiAOIBZJGyEGIAVBIGohBSAJQQFqIgkgDEkNAAsLQQAgBmshFkEAIAhrCyEIQQEhDiALQX9qIgUgC00EQCAFIAtJBEAgDSAFQQV0ai0AHSEOCyANKAIIIQUgDUEgaiANIBdqRwRAIA1BKGohBiAUQQV0QWBqIQkDQCAGKAIAIgogBSAFIApLGyEFIAZBIGohBiAJQWBqIgkNAAsLQQAgBWshCgtBACEGICQEQCAkQRRsIQkgKkEIaiEFA0AgBSgCACAFQXxqKAIAayAGaiEGIAVBFGohBSAJQWxqIgkNAAtBACAGayEGCyBFpyEFIAIoAgAoAgAoAgQiFEUgC0EBS3INAiALQQFrRQRAIBFBAUcgFEECSXINAyAPQRBqKAIAIA8oAgxrQQF0IA1BEGooAgAgDSgCDGtPDQMgDS0AHQ0DCyAFBEAgDRAhCyAbBEAgDxAhCyABKAIAIgUgASgCBEcNAAsLIABBADYCBAwBCyAAICQ2AgggACAqNgIEIAAgQDYCACAAQeAAaiAGNgIAIABB3ABqQQAgJGs2AgAgAEHYAGogPzYCACAAQdQAaiAKNgIAIABB0ABqIA42AgAgAEHMAGogFjYCACAAQcgAaiAINgIAIABBxABqIAc2AgAgAEFAayATNgIAIABBPGogETYCACAAQThqIBs2AgAgAEE0aiAPNgIAIABBMGogCzYCACAAQSxqIAU2AgAgAEEoaiANNgIAIABBJGogPzYCACAAQSBqIDM2AgAgAEEcaiA0NgIAIABBGGogNTYCACAAQRRqIDY2AgAgAEEQaiA3NgIAIABBDGogODYCAAsgBEGABGokAA8LIAUgERCtAQALQcLGwgBBECAEQfACakHMqMIAEJsBAAtB/MXCAEHGACAEQfACakHcqMIAEJsBAAvjHwMkfwN+BnwjAEHAAWsiBCQAAkACQAJAAkACQAJAAkACQCACQQhqKAIAIgMgAigCBCIGRg0AIAFBCGooAgAiBSABKAIEIgtGDQAgBSALayIFIAUgAyAGayIDIAMgBUsbIAItACkiBhshBSADQQJPQQAgBUEBSxsNASADIAVGDQIMAwsgAEECOgAcDAMLRAAAAAAAAPA/IAUgAyADIAVLIgsbuCADIAUgCxu4o6FEpHA9Ctej0D9jRQ0BCyAEQShqIAEQsQEgBCgCLCEFIAQoAighCQJAIAZFBEAgBSABKAIIIAEoAgRrIgYgA0EBaiIDIAMgBksbIgNPDQEgAyAFEKsBAAsgBSEDCwJAAkACQAJARAAAAAAAAPA/AnwCQBB8IgUEQCAEQSBqIAIQsQEgBCgCJCEGIAMEQCAGRQ0CIAUoAgANCyAEKAIgIQogBUF/NgIAIAUoAhANCyAFQQRqIQsgBUF/NgIQAkAgAyAFKAIMIghNBEAgBSADNgIMDAELIAsgAyAIaxCdAQsgBUEUaiEIAkAgBiAFKAIcIgxNBEAgBSAGNgIcDAELIAggBiAMaxCdAQsgBSgCBCAFKAIMIAkgAxB2IAUoAhQgBSgCHCAKIAYQdkEAIQMgBSgCBCAFKAIMIgYgBEGAAWpBAEEgIAZnaxAJIAUoAhQgBSgCHCIGIARBgAFqQQBBICAGZ2sQCSALEIMBIAgQgwEgBSgCHCEJAkAgBSgCDCIKRQRAQQAhBkEAIQtBACEIDAELQQAhBkEAIQtBACEIIAlFDQAgBSgCFCEMIAUoAgQhDgNAAkACQAJAAkBBfyAOIANBAnRqKAIAIg8gDCAGQQJ0aigCACIQRyAPIBBJGw4CAAIBCyAGQQFqIQYgA0EBaiEDIAhBAWohCAwCCyADQQFqIQMMAQsgBkEBaiEGCyALQQFqIQsgAyAKTw0BIAYgCUkNAAsLIAUgBSgCEEEBajYCECAFIAUoAgBBAWo2AgAgCLcgCSAKaiADayAGayALarijDAMLRAAAAAAAAAAARAAAAAAAAPA/IAYbDAILDAgLRAAAAAAAAAAAC6FEUrgehetR4D9jBEAgBEECOgBMECciB0UNByAEQRhqIAIQsQEgBCgCHCELIAQoAhghEyAEQRBqIAEQsQEgBygCLA0JIAQoAhQhGiAEKAIQIRQgB0F/NgIsIAcoAjwNCSAHQQA2AkggB0KAgICAcDcCOCAEQQhqIAIQsgEgB0EwaiAEKAIIIgMgAyAEKAIMahCXASAEIAEQsgEgB0FAayIVIAQoAgAiAyADIAQoAgRqEJcBIAcoAgANCSAHQX82AgAgBygCQCEGIAcoAjAhDSAHKAI4IglBAmoiAyAHKAJIIgpBAmoiBSADIAVLGyIDIAcoAgRNDQQgB0EEaiEPIANBAXYgA2oiDiAObCIMIAcoAhAiA00NAyAHQQhqIAMgDCADayIFEIgBIAcoAgggBygCECIMQQN0aiEDIAVBAkkNAUEBIQgDQCADQgA3AwAgA0EIaiEDIAhBAWoiCCAFSQ0ACyAIIAxqQX9qIQwMAgsgAEECOgAcDAULIAUNAAwBCyADQgA3AwAgDEEBaiEMCyAHIA42AgQgByAMNgIQIA8QfwsgCQRAIAlBA3QhCEECIQMDQCAHKAIIIgUgBygCBCIJIANsQQN0akEIaiAJIANBf2psQQN0IAVqQQhqKwMAIA0rAwCgOQMAIANBAWohAyANQQhqIQ0gCEF4aiIIDQALCyAKBEAgCkEDdCENQQIhAwNAIAcoAgggAyAHKAIEakEDdGoiBSAFQXhqKwMAIAYrAwCgOQMAIANBAWohAyAGQQhqIQYgDUF4aiINDQALCyAHKAIURQRAIAdBfzYCFAJAIAcoAhgiA0UEQEEAIQMgB0EANgIoDAELIAcoAhxB/wEgA0EFahDFARogB0EANgIoIAcoAhgiA0EISQ0AIANBAWpBA3ZBB2whAwsgByADNgIkIAsEQCAHQRhqIRYgEyALQQJ0aiENIBQgGkECdGohF0EAIQsgEyEOA0AgC0EBaiEPIA4oAgAhDCAaBEAgC0ECaiEYIAtBAnQgE2pBfGohGUEAIQlBACEDIBQhBQNAIAMhCCAEIAUoAgAiEDYCgAEgBUEEaiEFQqXGiKHInKf5SyEnQQEhAyAQIQYDQCAnIAatQv8Bg4VCs4OAgIAgfiEnIANBBEcEQCAEQYABaiADaiADQQFqIQMtAAAhBgwBCwsgJ6ciBkEZdiIDQQh0IANyIgNBEHQgA3IhGyAIQQFqIQMgBygCGCIRIAZxIQYgBygCHCEcQQAhEgJAA0ACQCAGIBxqKAAAIh0gG3MiCkF/cyAKQf/9+3dqcUGAgYKEeHEiCgRAIAcoAiAhHgNAIBAgHiAKaEEDdiAGaiARcUEDdGoiHygCAEYNAiAKQX9qIApxIgoNAAsLIBJBBGoiEiAGaiARcSEGQQAhCiAdIB1BAXRxQYCBgoR4cUUNAQwCCwsgH0EEaiEKCwJAAkACQCAHKAI4IgYgC0sEQCAHKAJIIgYgCE0NASAKQdiqwgAgChsoAgAhCiAHKAIwIAtBA3RqKwMAISogFSgCACAIQQN0aisDACErIAtFDQJEAAAAAAAA4D8hLCAMIBkoAgBHDQIMAwtB6KvCACALIAYQrAEAC0Hoq8IAIAggBhCsAQALRAAAAAAAAPA/ISwLIAgEfEQAAAAAAADgP0QAAAAAAADwPyAQIAhBAnQgFGpBfGooAgBGGwVEAAAAAAAA8D8LIS4gBygCCCIGIAcoAgQiESAKbCAJakEDdGorAwAhLSArICsgKiAqICtjGyAqICpiG0QAAAAAAADwP0QAAAAAAAAAACAMIBBHG6IgBiAPIBFsIhIgA2pBA3RqKwMAoCAtIA8gCWsgCGogCmu4RAAAAAAAAOA/oqAQzQEhLSAGIBIgCEECaiIIakEDdGorAwAhLyAqICwQzQEhKiAGIAggESAYbCIKakEDdGogKyAuEM0BIAYgAyAKakEDdGorAwCgICogL6AQzQEgLRDNATkDACADIAkgDCAQRhshCSAFIBdHDQALCyAOQQRqIQ4gBCAMNgKAAUKlxoihyJyn+UshJ0EBIQMgDCEGA0AgJyAGrUL/AYOFQrODgICAIH4hJyADQQRHBEAgBEGAAWogA2ogA0EBaiEDLQAAIQYMAQsLICdCGYinQf8AcSIFQQh0IAVyIgNBEHQgA3IhECAHKAIYIgggJ6dxIQMgBygCHCEGQQAhCgJAAkADQCADIAZqKAAAIgsgEHMiCUF/cyAJQf/9+3dqcUGAgYKEeHEiCQRAIAcoAiAhEQNAIBEgCWhBA3YgA2ogCHFBA3RqIhIoAgAgDEYNAyAJQX9qIAlxIgkNAAsLIApBBGoiCiADaiAIcSEDIAsgC0EBdHFBgIGChHhxRQ0ACyAGIBYgJxCcASIDai0AAEEBcSELIAcgBygCJCIJIAtFcgR/IAkFIARBgAFqIBYQFCAWICcQnAEhAyAHKAIYIQggBygCHCEGIAcoAiQLIAtrNgIkIAcoAiAgAyAGaiAFOgAAIAggA0F8anEgBmpBBGogBToAACADQQN0aiIDIA82AgQgAyAMNgIAIAcgBygCKEEBajYCKAwBCyASIA82AgQLIA8hCyANIA5HDQALCyAHIAcoAhRBAWo2AhQgByAHKAIAIgNBAWoiIjYCACAHIAcoAjxBAWo2AjwgByAHKAIsQQFqNgIsIANBAmoiA0EASgRAIAcgAzYCACACKAIMIhchAyACLQApIhsEQCAXIAEoAgwiAyAXIANLGyEDCyACKAIIIhYgAigCBCIaayIcIAEoAggiESABKAIEIhJrIhggHCAYSxsiBUEBaiILIANBf2oiFUsEQEEAIANrISNBACAFayEZQoGChIAQIAItACgiJEF7aiIDrUIDhoinISUgAigCACEdIANBBUkhJiALIQUDQCAFIg9Bf2oiBUEBIAVBAUsbIR4gCyEDA0ACQCAVIANPDQAgDyADayEGIANBf2ohCUEAIAcoAgQiAkEDdGshDCAHKAIIIA8gAiADbGoiDUEDdGohCCAHKAIQIQoDQAJAIAkiAyAcSyAFIBhLciAFIBVGIAMgI2pBf0ZxIAMgF0lycg0AIAUgASgCDElBACAbGw0CIAYgAyAZaiIJIAlBAEgbQQFKDQAgCiANSwRAIAgrAwAiKiADIB4gAyAeSxu4o0ThehSuR+HKP2QNASAEQQI6AJwBQQAhBgNAIARBMGogBmoiAikDACEnIAIgBEGAAWogBmoiCCkDADcDACAIICc3AwAgAkEIaiIJKQMAIScgCSAIQQhqIgkpAwA3AwAgAkEQaiIKKQMAISggCiAIQRBqIgopAwA3AwAgAkEYaiICKQMAISkgAiAIQRhqIgIpAwA3AwAgAiApNwMAIAogKDcDACAJICc3AwAgBkFAayAGQSBqIQZBwQBJDQALIAQgBC8BngE7AXwgBC0AnQEhDSAEKAKYASEIIAQoApQBIQkgBCgCkAEhCiAEKAKMASEMIAQoAogBIR8gBCsDgAEhKyAEKwOgASEsIAQoAqgBIRAgBCgCrAEhEyAEKAKwASEUIAQoArQBIQ4gBCgCuAEhAiAELQC8ASEhIAQtAL0BISAgBC0AnAEhBiAEIAQvAb4BOwF6AkACQCAGQQJHBEAgBCAELwF8OwGAASAEIAQvAXo7AX4gKyAqZQ0BC0EAIQkgG0EARyAFIBhGIAEoAgAhHwJ/QQAgAS0AKCICQQpGDQAaQQAgAkF7aiICQQRLDQAaQoGChIAQIAKtQgOGiKcLIQZBACAlQQAgJhsgJEEKRhshISAFIQggESEKIBIhDCAqISsgKiEsIB0hECAaIRMgFiEUQQAhDiADIQJyIg0hIAwBCyAEIAQvAYABOwF4IAQgBC8BfjsBdgsgBCANOgBNIAQgBjoATCAEIAg2AkggBCAJNgJEIAQgCjYCQCAEIAw2AjwgBCAfNgI4IAQgKzkDMCAEICA6AG0gBCAhOgBsIAQgAjYCaCAEIA42AmQgBCAUNgJgIAQgEzYCXCAEIBA2AlggBCAsOQNQIAQgBC8BeDsBTiAEIAQvAXY7AW4gKkQAAAAAAACwPGVBAXMNBAwDC0Hoq8IAIA0gChCsAQALIAZBAWohBiADQX9qIQkgCCAMaiEIIA0gAmshDSAVIANJDQALCwsgGUEBaiEZIBUgBUkNAAsLIAcgIjYCACAAIARBMGpBwAAQvwEaDAMLQYyqwgBBGCAEQYABakGkqsIAEJsBAAsMBAsgAEECOgAcCyAEQcABaiQADwtB/MXCAEHGACAEQYABakHcqMIAEJsBAAtBwsbCAEEQIARBgAFqQdyqwgAQmwEAC0HCxsIAQRAgBEGAAWpByKrCABCbAQALrx4BB38jAEEQayIDJAAgA0EANgIIIANCADcDACAAQRRqIQUgAEEQaiEEIAAoAhAiBiEBA0ACQAJAIAAQNUUNACAAIAAoAhAiAjYCHAJAIABB6srCABBNRQ0AIAAgACgCEDYCICAAEDVFDQAgACAAKAIcIAAoAiBB7MrCAEEBEDcMAgsgACACNgIQAkAgAEHrysIAEE1FDQAgACAAKAIQNgIgIAAQNUUNACAAIAAoAhwgACgCIEHtysIAQQEQNwwCCyAAIAI2AhAgAEHpysIAEE1FDQAgACAAKAIQIgI2AiAgACAAKAIcIAJB7srCAEEBEDcMAQsgACABNgIcIAAgATYCEAJAIABB6crCABBNRQ0AIAAgACgCEDYCICAAEDVFDQAgACAAKAIcIAAoAiBB7srCAEEBEDcMAQsgACABNgIQAkACQCAAQe/KwgAQTQRAIAAgACgCEDYCHCAAQerKwgAQTQ0BCyAEIAE2AgAgASAFKAIAIgFPDQEgABCUASAAKAIQIQEMAwsgACAAKAIQIgI2AiAgACAAKAIcIAJB7MrCAEEBEDcMAQsgACAGNgIQIAMgATYCCCADIAE2AgQgAyABNgIAAkACQAJAIAAQNUUNACAAEDVFDQAgBCgCACAFKAIATw0AIAAQlAEMAQsgACAGNgIQIABB8MrCAEEDIAMQOw0AIAQgBjYCACAGIAUoAgBPDQEgABCUASAAEDUNAANAIAQoAgAgBSgCAE8NAiAAEJQBIAAQNUUNAAsLIAMgBCgCADYCCAsgACAGNgIQAkAgABA1RQRAA0AgBCgCACAFKAIAIgFPDQIgABCUASAAEDVFDQALCyAAEDYgACgCECECRQRAA0AgAiAFKAIAIgFPDQIgABCUASAAEDYgACgCECECRQ0ACwsgAyACNgIEIAAQNUUEQANAIAQoAgAgBSgCACIBTw0CIAAQlAEgABA1RQ0ACwsgABA2IAAoAhAhAkUEQANAIAIgBSgCACIBTw0CIAAQlAEgABA2IAAoAhAhAkUNAAsLIAMgAjYCACAFKAIAIQELIAAgATYCICAAIAE2AhAgACAGNgIYAkACQAJAAkACQAJAAkAgAEG4y8IAQSsgAxAtIgJFDQAgACAAKAIQIgE2AhwgAkF/aiICQQ5LDQUCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAkEBaw4OAQIDBAUGBwgJCgsMDQ4ACyADKAIAIAFLDQ4gACABIAAoAiBBhunCAEEAEDcMEwsgAygCACABSw0NIAAgASAAKAIgQYbpwgBBABA3IAAgACgCECIBNgIgIAAoAhQhAiAAQcDTwgBBAhBiRQ0OIAAgACgCECIBNgIcIAAoAiAhAiADKAIAIAFNBEAgACABIAJBhunCAEEAEDcMEwsgACABIAJBwtPCAEEDEDcMEgsgAygCACABSw0MIAAgASAAKAIgQcXTwgBBAxA3DBELIAMoAgAgAUsNCyAAIAEgACgCIEHqysIAQQEQNwwQCyADKAIAIAFLDQogACABIAAoAiBByNPCAEEDEDcMDwsgAygCCCABSw0JIAAgASAAKAIgQYbpwgBBABA3IAAgACgCECIBNgIgIAAoAhQgAWshAgJAAkACQAJAIABBzNPCAEEGIAMQLSIGBEAgACAAKAIQIgE2AhwgBkF/aiIGQQNLDRMgBkEBaw4DAgMEAQsgBCAFKAIAIAJrNgIADBILIAMoAgAgAUsEQCAEIAUoAgAgAms2AgAMEgsgACABIAAoAiBBhunCAEEAEDcgACAAKAIQNgIgIABB3NTCAEECEGJFDQ4gACAAKAIQIgE2AhwgAygCACABTQRAIAAgASAAKAIgQYbpwgBBABA3DBILIAQgBSgCACACazYCAAwRCyADKAIAIAFNBEAgACABIAAoAiBBhunCAEEAEDcMEQsgAygCBCABTQRAIAAgASAAKAIgQd7UwgBBAxA3DBELIAQgBSgCACACazYCAAwQCyADKAIAIAFNBEAgACABIAAoAiBBhunCAEEAEDcMEAsgBCAFKAIAIAJrNgIADA8LIAMoAgggAU0EQCAAIAEgACgCIEHrysIAQQEQNwwPCyAEIAUoAgAgAms2AgAMDgsgAygCACABSw0IIAAgASAAKAIgQYbpwgBBABA3IAAgACgCECIBNgIgIAAoAhQgAWshAgJAAkACQCAAQeTUwgBBAyADEC0iBgRAIAAgACgCECIBNgIcIAZBf2oiBkECSw0RIAZBAWsOAgIDAQsgBCAFKAIAIAJrNgIADBALIAAoAiAhAiADKAIAIAFNBEAgACABIAJBhunCAEEAEDcMEAsgACABIAJBrNXCAEEDEDcMDwsgACgCICECIAMoAgAgAU0EQCAAIAEgAkGG6cIAQQAQNwwPCyAAIAEgAkHC08IAQQMQNwwOCyADKAIAIAFNBEAgACABIAAoAiBBhunCAEEAEDcMDgsgBCAFKAIAIAJrNgIADA0LIAMoAgAgAUsNByAAIAEgACgCIEGG6cIAQQAQNyAAIAAoAhAiATYCICAAKAIUIAFrIQEgAEHc1MIAQQIQYkUNCiAAIAAoAhAiAjYCHCADKAIAIAJLBEAgBCAFKAIAIAFrNgIADA0LIAAgAiAAKAIgQYbpwgBBABA3IAAgACgCEDYCICAAQcDTwgBBAhBiRQ0LIAAgACgCECIBNgIcIAAoAiAhAiADKAIAIAFNBEAgACABIAJBhunCAEEAEDcMDQsgACABIAJBwtPCAEEDEDcMDAsgACABIAAoAiBBr9XCAEEDEDcMCwsgAygCBCABSw0FIAAgASAAKAIgQbLVwgBBAhA3DAoLIAMoAgAgAU0EQCAAIAEgACgCIEGG6cIAQQAQNwwKCyADKAIEIAFLDQQgACABIAAoAiBB3tTCAEEDEDcMCQsgAygCBCABSw0DIABB1crCAEEUQfsBEDRFDQMgACAAKAIcIAAoAiBBhunCAEEAEDcMCAsgAygCCCABSw0CIAAgASAAKAIgQbTVwgBBAxA3DAILIAMoAgggAUsNASAAIAEgACgCIEHI08IAQQMQNwwBCyAAKAIUIQIgABAzRQ0AIAMoAgggBCgCAEsNACAAIAAoAhQgASACa2o2AhAgACAAKAIcIAAoAiBBhunCAEEAEDcLIAQgBSgCACIBNgIAIAEgAygCCCICTwRAIAAgATYCICAAIAE2AhAgACgCGCEBIAAgAjYCGAJAIABBmNfCAEEjIAMQLSICRQ0AIAAgACgCEDYCHCACQQFGBEAgAEHVysIAQRRB+wEQNEUNASAAIAAoAhwgACgCIEGG6cIAQQAQNwsgACABNgIYDAYLIAAgATYCGCADKAIIIQIgACgCFCEBCyAEIAE2AgAgASACTwRAIAAgATYCICAAIAE2AhAgACgCGCEGIAAgAjYCGAJAIABB4N3CAEEmIAMQLSICRQ0AIAAgACgCECIBNgIcAkAgAkF/aiICQQJLDQACQAJAAkAgAkEBaw4CAQIACyADKAIAIAFLDQMgACABIAAoAiBBhunCAEEAEDcMAgsgACABIAAoAiBBhunCAEEAEDcMAQsgACABIAAoAiBBhunCAEEAEDcgACAAKAIQIgE2AiAgACgCFCECIABBtNbCAEEBEGIEQCAAIAAoAhAiATYCHCAAIAEgACgCIEGG6cIAQQAQNwwBCyAEIAUoAgAgASACa2o2AgALIAAgBjYCGAwGCyAAIAY2AhggACgCFCEBCyAAIAE2AiAgACABNgIQAkAgBAJ/IABB8OTCAEEBEGIEQCAAIAAoAhAiAjYCHCAAKAIUIQYgAEHx5MIAQRFB6AEQNCEHIAAoAhQiASAHRQ0BGiAAIAIgBmsgAWo2AhAgACAAKAIcIAAoAiBBhunCAEEAEDcgACgCECEBDAILIAUoAgALIgE2AgALIAEgAygCCCICSQ0FIAAgATYCICAAIAE2AhAgACgCGCEGIAAgAjYCGAJAIABBhOXCAEEHIAMQLSICRQ0AIAAgACgCECIBNgIcIAJBf2oiAkEDSw0AAkACQAJAAkAgAkEBaw4DAwECAAsgAygCACABSw0DIAAoAhQhAiAAQfDkwgBBARBiRQRAIAAgACgCFCABIAJrajYCECAAQazmwgBBARBiRQ0ECyAAIAAoAhwgACgCIEGG6cIAQQAQNwwDCyAAIAEgACgCIEGG6cIAQQAQNwwCCyAAQa3mwgBBAhBiRQ0BIAAgACgCHCAAKAIgQYbpwgBBABA3DAELIAAgASAAKAIgQevKwgBBARA3CyAAIAY2AhgMBQsgBCAFKAIAIAEgAmtqNgIADAMLIAQgBSgCACACazYCAAwCCyAEIAUoAgAgAWs2AgAMAQsgBCAFKAIAIAFrNgIACyAAIAAoAhQiATYCICAAIAE2AhAgAEHuysIAQQEQYgRAIAAgACgCECIBNgIcIAAgASAAKAIgQevKwgBBARA3DAELIAAgACgCFDYCECAAQa/mwgBBAhBiBEAgACAAKAIQIgE2AhwgACABIAAoAiBBsebCAEEBEDcMAQsgBCAFKAIANgIACyAAIAAoAhQ2AhACQCAAQbjVwgBBBSADEC1FDQAgACAAKAIUIgE2AiAgACABNgIQIAEgACgCGE0NACAAEJYBIAAgACgCECIBNgIcIAAgASAAKAIgQYbpwgBBABA3CyAEIAUoAgA2AgBBAiEBA0AgAUF/aiEBIABB1crCAEEUQfsBEDQNAAsCQCABQQBKDQAgACAAKAIQIgE2AiAgACgCFCECIABBsNbCAEECEGJFBEAgACAAKAIUIAEgAmtqNgIQIABBstbCAEECEGJFDQELIAAgACgCECIBNgIcIAAgASAAKAIgQbTWwgBBARA3CyAAIAAoAhgiBjYCHCAAIAY2AhACQCAAQbjWwgBBBCADEDsiAUUNAANAIAAgACgCECICNgIgAkAgAUF/aiIBQQNLDQACQAJAAkACQCABQQFrDgMBAgMACyAAIAAoAhwgAkHrysIAQQEQNwwDCyAAIAAoAhwgAkHqysIAQQEQNwwCCyAAIAAoAhwgAkHpysIAQQEQNwwBCyACIAUoAgBPDQIgABCUAQsgACAAKAIQNgIcIABBuNbCAEEEIAMQOyIBDQALCyAEIAY2AgAgA0EQaiQAQQEPCyAEIAE2AgAMAAsAC5gcAht/AX4jAEHwA2siAyQAIAMgAjYCkAMgAyACNgKMAyADIAE2AogDIANB0ABqIANBiANqEJEBIAMoAlQhDyADKAJQIQ4CQAJAAkACQAJAAkACQBDHASIKBEAgCigCAA0GIApBfzYCACAKQQRqIAAQVyIERQ0BEMYBIgsEQCALKAIADQcgC0F/NgIAIAtBBGogABBYIghFDQMgA0GgAmogDiAOIA9qECMgA0GIA2ogA0GgAmoQYyADQaACaiADQYgDaiAEQRRqIgIQPiADKAKoAiIAQX9qIgEgAE8gASAAS3JFBEAgAygCoAIgAUEUbGpBADoAEQsgA0GIA2ogA0GgAmpBMBC/ARogA0GgAmogA0GIA2ogAhAmIANBiANqIANBoAJqIAIQICADQaACaiADQYgDahA8IAMoAqgCIgEEQCADKAKgAiEAIAFBFGwhASADQcACaigCACEFIAMoArgCIQwDQCAAIAwgBSACEEkgAEEUaiEAIAFBbGoiAQ0ACwsgA0GIA2ogA0GgAmpBMBC/ARogA0GgAmogA0GIA2ogAhBSIAMoAqACIQwCQCADKAKoAiIFRQRAIANBwAJqKAIAIQYgAygCuAIhDQwBCyAFQRRsIQEgA0HAAmooAgAhBiADKAK4AiENIAwhAANAIAAgDSAGIAIQSCAAQRRqIQAgAUFsaiIBDQALCyADQcgCaigCACEWIANBvAJqKAIAIRcgA0GwAmooAgAhGCADQbQCaigCACEAIAMoAqQCIRkgAygCrAIhECADKALEAiERIANB9ABqIANBzAJqKAIANgIAIANB7ABqIAY2AgAgA0HkAGogADYCACADIBE2AnAgAyANNgJoIAMgEDYCYCADIAU2AlwgAyAMNgJYAkAgCCgCCCICRQRAQQAhAgwBCyAIKAIAIAJBBHRqQXRqIQADQCACQX9qIQIgAEEEaigCAARAIAAoAgAQIQsgAEFwaiEAIAINAAsgAygCXCEFCyAIIAI2AgggAyADQdgAajYCfCADIAQ2AnggA0GMAWogBCgCrAE2AgAgAyAEKAKkATYCiAEgAyAEKAKgATYChAEgAyAEKAKYATYCgAECQCAFRQRAIAQoAtgBDQogBEHYAWohBSAEQX82AtgBIARB3AFqIQcgBCgC3AENASAEKAIEIQAgBCgCDCEBIANBIGogBCgCECICQQF0EKUBIAMpAyAhHiADQbACaiIGQQA2AgAgA0EAOwG4AiADIAI2ArQCIAMgHjcDqAIgAyAAIAFBPGxqNgKkAiADIAA2AqACAkAgA0GgAmoQMSIARQRAIANBADYCqAEgA0IENwOgASADKAKsAkUNASADKAKoAhAhDAELIAAoAgAhACADQRhqQQEQpQEgAygCHCEBIAMoAhgiCSAANgIAIANBATYC6AEgAyABNgLkASADIAk2AuABIANBoANqIANBuAJqKAIANgIAIANBmANqIAYpAwA3AwAgA0GQA2ogA0GoAmopAwA3AwAgAyADKQOgAjcDiAMCQCADQYgDahAxIgZFDQBBBCECQQEhAANAIAYoAgAhBiAAIAFGBEAgA0HgAWogAUEBEIYBIAMoAuABIQkLIAIgCWogBjYCACADIABBAWoiADYC6AEgA0GIA2oQMSIGRQ0BIAJBBGohAiADKALkASEBDAALAAsgA0GUA2ooAgAEQCADKAKQAxAhCyADQagBaiADQegBaigCADYCACADIAMpA+ABNwOgAQsgA0GIA2ogA0GgAWoQmAECQCAHKAIAIgBFDQAgBCgC4AFFDQAgABAhCyAHIAMpA4gDNwIAIAdBCGogA0GQA2ooAgA2AgAgA0GYAWogA0GoAWooAgA2AgAgAyADKQOgATcDkAEMBwsgBCgCsAENCSAEQX82ArABIARBADYC1AEgBEHMAWohCSAEKAIQIRIgBCAEKAK0ASIBBH8gCUEAIAEQhgEgBCgCzAEgBCgC1AEiAkECdGohACABQQFHBEAgAUF/aiEBA0AgAEEANgIAIAJBAWohAiAAQQRqIQAgAUF/aiIBDQALCyAAQQA2AgAgAkEBagVBAAs2AtQBIANBoAFqIANB2ABqEB8gAygCoAEhByADKAKoASIARQ0FIAcgAEEMbGohGiAHIQUDQCADQqXGiKHInKf5SzcDiAMgBSIBIANBiANqEHkgAygCiAMiAEEZdiICQQh0IAJyIgJBEHQgAnIhGyABQQxqIQUgBCgCuAEiEyAAcSEAIAQoArwBIRxBACEUAkADQAJAIAAgHGooAAAiFSAbcyICQX9zIAJB//37d2pxQYCBgoR4cSICBEAgBCgCwAEhHQNAIAEgHSACaEEDdiAAaiATcUEYbGoiBkYNAiABIAZBDBC6AUUNAiACQX9qIAJxIgINAAsLIBRBBGoiFCAAaiATcSEAIBUgFUEBdHFBgIGChHhxRQ0BDAILCyAGKAIUIgFFDQAgBigCDCEAIAFBAnQhAgNAIAkoAgAgACgCAEECdGoiASABKAIAQQFqNgIAIABBBGohACACQXxqIgINAAsLIAUgGkcNAAsMBQsgA0GQAWogBxCYAQwFCwwFCwwEC0GL68IAQStBuOvCABC+AQALQYvrwgBBK0G468IAEL4BAAsgBCgCzAEhACAEKALUASEBIANByABqIBJBFGwQpgEgAykDSCEeIANBtAJqQQA2AgAgA0EAOwG8AiADIBJBCmw2ArgCIAMgHjcCrAIgA0EANgKoAiADIAAgAUECdGo2AqQCIAMgADYCoAIgA0FAayADQaACahAwAkAgAygCREUEQCADQQA2ApgBIANCBDcDkAEgAygCsAJFDQEgAygCrAIQIQwBCyADKAJAIQAgA0E4akEBEKUBIAMoAjwhASADKAI4IgUgADYCACADQQE2AugBIAMgATYC5AEgAyAFNgLgASADQaADaiADQbgCaikDADcDACADQZgDaiIJIANBsAJqKQMANwMAIANBkANqIANBqAJqKQMANwMAIAMgAykDoAI3A4gDIANBMGogA0GIA2oQMAJAIAMoAjRFDQAgAygCMCEGQQQhAkEBIQADQCAAIAFGBEAgA0HgAWogAUEBEIYBIAMoAuABIQULIAIgBWogBjYCACADIABBAWoiADYC6AEgA0EoaiADQYgDahAwIAMoAixFDQEgAygCKCEGIAJBBGohAiADKALkASEBDAALAAsgCSgCAARAIAMoApQDECELIANBmAFqIANB6AFqKAIANgIAIAMgAykD4AE3A5ABCyADKAKkAQRAIAcQIQsgBEGwAWohBQsgBSAFKAIAQQFqNgIAIAMoApABIQcgAygCmAEhACADQRBqIAMoAngoAhAiAUEBdBCiASADKQMQIR4gA0G8AWpBADYCACADQQA7AcQBIAMgATYCwAEgAyAeNwK0ASADIAcgAEECdGo2AqQBIAMgBzYCoAEgAyADQYABajYCyAEgAyADQfwAajYCsAEgAyADQfwAajYCrAEgAyADQfgAajYCqAEgA0GgAmogA0GgAWoQHgJAAkACQAJAIAMoAqQCRQRAIANBADYC5AEMAQsgA0GIA2ogA0GgAmpB5AAQvwEaIANB4AFqIANByAFqIANBiANqEBUgAygC5AENAQsgAygCvAEiAQRAIAMoArQBIQAgAUHkAGwhAgNAIAAQwgEgAEHkAGohACACQZx/aiICDQALC0EAIQRBBCEFIAMoArgBRQ0BIAMoArQBECEMAQtBASECIANBCGpBARCkASADQegBaikDACEeIAMoAgwhACADKAIIIgUgAykD4AE3AgAgBUEIaiAeNwIAIANBATYC2AEgAyAANgLUASADIAU2AtABIANB4AFqIANBoAFqQSwQvwEaIANBoAJqIANB4AFqEB4CQCADKAKkAgRAIANBiAJqIQFBECEAA0AgA0GIA2ogA0GgAmpB5AAQvwEaIANBkAJqIAEgA0GIA2oQFSADKAKUAkUNAiADQZADaiIEIANBmAJqKQMANwMAIAMgAykDkAI3A4gDIAMoAtQBIAJGBEAgA0HQAWogAhC5ASADKALQASEFCyAAIAVqIgYgAykDiAM3AgAgBkEIaiAEKQMANwIAIAMgAkEBaiICNgLYASAAQRBqIQAgA0GgAmogA0HgAWoQHiADKAKkAg0ACwsgA0EANgKUAgsgA0H8AWooAgAiAQRAIAMoAvQBIQAgAUHkAGwhAQNAIAAQwgEgAEHkAGohACABQZx/aiIBDQALCyADQfgBaigCAARAIAMoAvQBECELIAMoAtQBIQQMAQtBACECCyADKAKUAQRAIAcQIQsgBSACQQR0aiEBAkAgASACBH8gBSEAA0AgAEEEaigCACIHBEAgAEEIaikCACEeIAAoAgAhBiAIKAIIIgIgCCgCBEYEQCAIIAIQuQEgCCgCCCECCyAIKAIAIAJBBHRqIgIgBzYCBCACIAY2AgAgAkEIaiAeNwIAIAggCCgCCEEBajYCCCAAQRBqIgAgAUcNAQwDCwsgAEEQagUgBQsiAEYNAANAIABBBGooAgAiAkUNASAAQQhqKAIABEAgAhAhCyAAQRBqIgIhACABIAJHDQALCyAEBEAgBRAhCyAZBEAgDBAhCyAYBEAgEBAhCyAXBEAgDRAhCyAWBEAgERAhCyALIAsoAgBBAWo2AgAgCiAKKAIAQQFqNgIAIA8EQCAOECELIANB8ANqJAAPC0H8xcIAQcYAIANBiANqQdyowgAQmwEAC0HCxsIAQRAgA0GIA2pBzKjCABCbAQALQcLGwgBBECADQYgDakG4qsIAEJsBAAvDGAIIfwF+AkACQAJAIABB9QFPBEAgAEHN/3tPDQIgAEELaiIAQXhxIQRB5O7CACgCACIIRQ0BQQAgBGshAgJAAkACf0EAIABBCHYiAEUNABpBHyAEQf///wdLDQAaIARBBiAAZyIAa0EfcXZBAXEgAEEBdGtBPmoLIgdBAnRB8PDCAGooAgAiAARAIARBAEEZIAdBAXZrQR9xIAdBH0YbdCEFA0ACQCAAKAIEQXhxIgYgBEkNACAGIARrIgYgAk8NACAAIQMgBiICDQBBACECDAMLIABBFGooAgAiBiABIAYgACAFQR12QQRxakEQaigCACIARxsgASAGGyEBIAVBAXQhBSAADQALIAEEQCABIQAMAgsgAw0CC0EAIQNBAiAHQR9xdCIAQQAgAGtyIAhxIgBFDQMgAEEAIABrcWhBAnRB8PDCAGooAgAiAEUNAwsDQCAAIAMgACgCBEF4cSIBIARPIAEgBGsiBiACSXEiBRshAyAGIAIgBRshAiAAKAIQIgEEfyABBSAAQRRqKAIACyIADQALIANFDQILQfDxwgAoAgAiACAET0EAIAIgACAEa08bDQEgAxBbAkAgAkEQTwRAIAMgBEEDcjYCBCADIARqIgEgAkEBcjYCBCABIAJqIAI2AgAgAkGAAk8EQCABIAIQUwwCCyACQQN2IgJBA3RB6O7CAGohAAJ/QeDuwgAoAgAiBUEBIAJBH3F0IgJxBEAgACgCCAwBC0Hg7sIAIAIgBXI2AgAgAAshAiAAIAE2AgggAiABNgIMIAEgADYCDCABIAI2AggMAQsgAyACIARqIgBBA3I2AgQgACADaiIAIAAoAgRBAXI2AgQLIANBCGoPCwJAAkBB4O7CACgCACIDQRAgAEELakF4cSAAQQtJGyIEQQN2IgJBH3EiAXYiAEEDcUUEQCAEQfDxwgAoAgBNDQMgAA0BQeTuwgAoAgAiAEUNAyAAQQAgAGtxaEECdEHw8MIAaigCACIBKAIEQXhxIARrIQIgASEFA0AgASgCECIARQRAIAFBFGooAgAiAEUNBAsgACgCBEF4cSAEayIBIAIgASACSSIBGyECIAAgBSABGyEFIAAhAQwACwALIABBf3NBAXEgAmoiAUEDdCIGQfDuwgBqKAIAIgBBCGohAgJAIAAoAggiBSAGQejuwgBqIgZHBEAgBSAGNgIMIAYgBTYCCAwBC0Hg7sIAIANBfiABd3E2AgALIAAgAUEDdCIBQQNyNgIEIAAgAWoiACAAKAIEQQFyNgIEDAMLAkBBAiABdCICQQAgAmtyIAAgAXRxIgBBACAAa3FoIgBBA3QiBUHw7sIAaigCACIBKAIIIgIgBUHo7sIAaiIFRwRAIAIgBTYCDCAFIAI2AggMAQtB4O7CACADQX4gAHdxNgIACyABIARBA3I2AgQgASAEaiIGIABBA3QiACAEayIFQQFyNgIEIAAgAWogBTYCAEHw8cIAKAIAIgAEQCAAQQN2IgNBA3RB6O7CAGohAEH48cIAKAIAIQICf0Hg7sIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtB4O7CACADIARyNgIAIAALIQMgACACNgIIIAMgAjYCDCACIAA2AgwgAiADNgIIC0H48cIAIAY2AgBB8PHCACAFNgIAIAFBCGoPCyAFEFsCQCACQRBPBEAgBSAEQQNyNgIEIAQgBWoiBiACQQFyNgIEIAIgBmogAjYCAEHw8cIAKAIAIgAEQCAAQQN2IgNBA3RB6O7CAGohAEH48cIAKAIAIQECf0Hg7sIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtB4O7CACADIARyNgIAIAALIQMgACABNgIIIAMgATYCDCABIAA2AgwgASADNgIIC0H48cIAIAY2AgBB8PHCACACNgIADAELIAUgAiAEaiIAQQNyNgIEIAAgBWoiACAAKAIEQQFyNgIECyAFQQhqDwsCQAJAAkACQEHw8cIAKAIAIgEgBEkEQEH08cIAKAIAIgAgBEsNBkEAIQIgBEGvgARqIgBBEHZAACIBQX9GDQUgAUEQdCIDRQ0FQYDywgAgAEGAgHxxIgVBgPLCACgCAGoiADYCAEGE8sIAQYTywgAoAgAiASAAIAEgAEsbNgIAQfzxwgAoAgAiAkUNAUGI8sIAIQADQCAAKAIAIgEgACgCBCIGaiADRg0DIAAoAggiAA0ACwwDC0H48cIAKAIAIQACQCABIARrIgNBD00EQEH48cIAQQA2AgBB8PHCAEEANgIAIAAgAUEDcjYCBCAAIAFqIgFBBGohBCABKAIEQQFyIQIMAQtB8PHCACADNgIAQfjxwgAgACAEaiICNgIAIAIgA0EBcjYCBCAAIAFqIAM2AgAgBEEDciECIABBBGohBAsgBCACNgIAIABBCGoPC0Gc8sIAKAIAIgBBACAAIANNG0UEQEGc8sIAIAM2AgALQQAhAEGg8sIAQf8fNgIAQYzywgAgBTYCAEGI8sIAIAM2AgBBlPLCAEEANgIAA0AgAEHw7sIAaiAAQejuwgBqIgE2AgAgAEH07sIAaiABNgIAIABBCGoiAEGAAkcNAAtB/PHCACADNgIAQfTxwgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRBmPLCAEGAgIABNgIADAILIAAoAgwgAyACTXIgASACS3INACAAIAUgBmo2AgRB/PHCAEH88cIAKAIAIgBBD2pBeHEiAUF4ajYCAEH08cIAQfTxwgAoAgAgBWoiAyAAIAFrakEIaiICNgIAIAFBfGogAkEBcjYCACAAIANqQSg2AgRBmPLCAEGAgIABNgIADAELQZzywgBBnPLCACgCACIAIAMgACADSRs2AgAgAyAFaiEBQYjywgAhAAJAA0AgASAAKAIARwRAIAAoAggiAA0BDAILCyAAKAIMDQAgACADNgIAIAAgACgCBCAFajYCBCADIARBA3I2AgQgAyAEaiEFIAEgA2sgBGshBAJAAkAgAUH88cIAKAIARwRAQfjxwgAoAgAgAUYNASABKAIEIgBBA3FBAUYEQAJAIABBeHEiAkGAAk8EQCABEFsMAQsgASgCDCIGIAEoAggiB0cEQCAHIAY2AgwgBiAHNgIIDAELQeDuwgBB4O7CACgCAEF+IABBA3Z3cTYCAAsgAiAEaiEEIAEgAmohAQsgASABKAIEQX5xNgIEIAUgBEEBcjYCBCAEIAVqIAQ2AgAgBEGAAk8EQCAFIAQQUwwDCyAEQQN2IgFBA3RB6O7CAGohAAJ/QeDuwgAoAgAiAkEBIAFBH3F0IgFxBEAgACgCCAwBC0Hg7sIAIAEgAnI2AgAgAAshAiAAIAU2AgggAiAFNgIMIAUgADYCDCAFIAI2AggMAgtB/PHCACAFNgIAQfTxwgBB9PHCACgCACAEaiIANgIAIAUgAEEBcjYCBAwBC0H48cIAIAU2AgBB8PHCAEHw8cIAKAIAIARqIgA2AgAgBSAAQQFyNgIEIAAgBWogADYCAAsgA0EIag8LQYjywgAhAANAAkAgACgCACIBIAJNBEAgASAAKAIEaiIGIAJLDQELIAAoAgghAAwBCwtB/PHCACADNgIAQfTxwgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRBmPLCAEGAgIABNgIAIAIgBkFgakF4cUF4aiIAIAAgAkEQakkbIgFBGzYCBEGI8sIAKQIAIQkgAUEQakGQ8sIAKQIANwIAIAEgCTcCCEGM8sIAIAU2AgBBiPLCACADNgIAQZDywgAgAUEIajYCAEGU8sIAQQA2AgAgAUEcaiEAA0AgAEEHNgIAIAYgAEEEaiIASw0ACyABIAJGDQAgASABKAIEQX5xNgIEIAIgASACayIAQQFyNgIEIAEgADYCACAAQYACTwRAIAIgABBTDAELIABBA3YiAUEDdEHo7sIAaiEAAn9B4O7CACgCACIDQQEgAUEfcXQiAXEEQCAAKAIIDAELQeDuwgAgASADcjYCACAACyEBIAAgAjYCCCABIAI2AgwgAiAANgIMIAIgATYCCAtBACECQfTxwgAoAgAiACAETQ0ADAELIAIPC0H08cIAIAAgBGsiATYCAEH88cIAQfzxwgAoAgAiACAEaiIDNgIAIAMgAUEBcjYCBCAAIARBA3I2AgQgAEEIagukFgEWfyMAQfADayIGJAACQAJAIAFBFUkNACAGQeAAaiEXIAZByAJqIRhBASERQQEhEgJAAkACQANAIAEhCiAAIQ4gA0FAayEQIBEgEnFBAXMhDwJAA0ACfwJAIAQEQCARQQFxRQRAIAAgARBmIARBf2ohBAsgBiACNgIIIAYgADYCiAEgBiABNgKMASAGIAFBAnYiBTYCDCAGIAVBAXQiBzYCECAGIAVBA2wiCTYCFCAGQQA2AhggBiAGQRhqNgKQAiAGIAZBiAFqNgKMAiAGIAZBCGo2AogCIAYgBkGIAmo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2AogDIAZBHGogBkEgaiAGQQxqIAZBiANqEMoBIAYgB0F/ajYCICAGIAdBAXI2AogDIAZBHGogBkEgaiAGQRBqIAZBiANqEMoBIAYgCUF/ajYCICAGIAlBAWo2AogDIAZBHGogBkEgaiAGQRRqIAZBiANqEMoBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMoBIAYoAhgiBUELSw0BIAYoAhAhCCAFRQwCCyAAIAEQeAwJCyAGKAKMASIJQQF2IgcEQCAGKAKIASIFIAlB5ABsakGcf2ohDANAIAZBiANqIAVB5AAQvwEaIAUgDEHkABCeAUHkAGohBSAMIAZBiANqQeQAEL8BQZx/aiEMIAdBf2oiBw0ACwsgASAGKAIQQX9zaiEIQQELRSAPckEBcUUEQCAAIAEQJA0ICyADRQRAIA4hACAKIQEMAgsCQCAIIAFJBEAgACAIQeQAbGoiCUFAayENQQAhBQJAA0AgBUEEaiIHQShGDQEgBSAQaiELIAUgDWohDCAHIQVBfyAMKAIAIgcgCygCACILRyAHIAtIGyIHRQ0ACyAHQf8BcUH/AUYNBAsgAQRAIAZBiANqIABB5AAQvwEaIAAgCUHkABCeASENIAkgBkGIA2pB5AAQvwEaIAZBiAJqIA1B5AAQvwEaIA1BXGohDCANQaQBaiETIA1B5ABqIRRBACEIIAEhCQNAAkAgCCAJQX9qIgdPDQAgEyAIQeQAbGohCwNAQQAhBQJAA0AgBUEEaiIAQShGDQEgBSAYaiESIAUgC2ohFSAAIQVBfyAVKAIAIgAgEigCACISRyAAIBJIGyIARQ0ACyAAQf8BcUH/AUYNAgsgC0HkAGohCyAIQQFqIgggB0cNAAsgByEIDAQLIAggB08NAyAMIAlB5ABsaiELA0AgByIJQX9qIQdBACEFAkADQCAFQQRqIgBBKEYNASAFIBhqIRIgBSALaiEVIAAhBUF/IBUoAgAiACASKAIAIhJHIAAgEkgbIgBFDQALIABB/wFxQf8BRw0AIAtBnH9qIQsgCCAHTw0FDAELCyAGQYgDaiAUIAhB5ABsaiIAQeQAEL8BGiAAIA0gCUHkAGxqIgBB5AAQngEaIAAgBkGIA2pB5AAQvwEaIAhBAWohCAwACwALQbCnwgBBAEEAEKwBAAtB0KfCACAIIAEQrAEACyANIAZBiAJqQeQAEL8BIQAgASAIQQFqIgVPBEAgACAFQeQAbGohACABIAVrIgFBFU8NAQwHCwsgBSABEK0BAAsCQAJAIAEEQCAIIAFPDQEgBkGIAmogAEHkABC/ARogACAAIAhB5ABsaiIFQeQAEJ4BIRQgBSAGQYgCakHkABC/ARogBkEgaiAUQeQAEL8BGiABQX9qIglFBEBBACEQDAMLIBRBpAFqIQtBACEQA0BBACEFA0AgBUEEaiIHQShGDQQgBSALaiEKIAUgF2ohDiAHIQVBfyAOKAIAIgcgCigCACIKRyAHIApIGyIHRQ0ACyAHQf8BcUH/AUcNAyALQeQAaiELIBBBAWoiECAJRw0ACyAJIRAMAgtBsKfCAEEAQQAQrAEAC0HAp8IAIAggARCsAQALIBRB5ABqIQ0gFCABQeQAbGpBXGohCyAJIQcDQCAHIgogEE0iEkUEQCAKQX9qIQdBACEFA0ACQCAFQQRqIg5BKEYEQEEAIQwMAQsgBSALaiEIIAUgF2ohDCAOIQVBfyAMKAIAIg4gCCgCACIIRyAOIAhIGyIMRQ0BCwsgC0Gcf2ohCyAMQX9HDQELCyAKIBBPBEAgCSAKSQ0CIA0gCkHkAGxqIQ5BgAEhE0EAIQlBACEKQQAhB0EAIQ9BgAEhESANIBBB5ABsaiIVIQ0DQCAOIA1rIghB5ABuIQUCQCAIQePIAUsiGQ0AIAVBgH9qIAUgDyAHSSIIIAogCUlyIgsbIQUgCwRAIBEgBSAIGyERIAUgEyAIGyETDAELIAUgBUEBdiIRayETCwJAIAcgD0cNACARRQRAIAZBiAFqIgchDwwBC0EAIQggBkGIAWohByANIQsDQCAHIAg6AAAgCEEBaiEIQcAAIQUDQAJAIAVBBGoiDEHoAEYEQEEAIQwMAQsgBSALaiEPIAZBIGogBWohFiAMIQVBfyAWKAIAIgwgDygCACIPRyAMIA9IGyIMRQ0BCwsgC0HkAGohCyAHIAxBf0dqIQcgCCARRw0ACyAGQYgBaiEPCwJAIAkgCkcNACATRQRAIAZBiAJqIgkhCgwBC0EAIQggBkGIAmohCSAOIQoDQCAJIAg6AAAgCiILQZx/aiEKIAhBAWohCEEAIQUDQAJAIAVBBGoiDEEoRgRAQQAhDAwBCyAFIBdqIRYgBSALaiEaIAwhBUF/IBYoAgAiDCAaQVxqKAIAIhZHIAwgFkgbIgxFDQELCyAJIAxBf0ZqIQkgCCATRw0ACyAGQYgCaiEKCyAJIAprIgUgByAPayIIIAggBUsbIggEQCAGQYgDaiANIA8tAABB5ABsakHkABC/ARogDSAPLQAAQeQAbGogDiAKLQAAQX9zQeQAbGpB5AAQvwEaIAhBAUcEQEEAIQUDQCAOIAUgCmoiCy0AAEF/c0HkAGxqIA0gBSAPakEBaiIMLQAAQeQAbGpB5AAQvwEaIA0gDC0AAEHkAGxqIA4gC0EBai0AAEF/c0HkAGxqQeQAEL8BGiAFQQJqIAVBAWoiCyEFIAhJDQALIAsgD2ohDyAKIAtqIQoLIA4gCi0AAEF/c0HkAGxqIAZBiANqQeQAEL8BGiAPQQFqIQ8gCkEBaiEKCyANIBFB5ABsaiANIAcgD0YbIQ0gDkEAIBNrQeQAbGogDiAJIApGGyEOIBkNAAsCQCAPIAdJBEAgDiEFA0AgBkGIA2ogDSAHQX9qIgctAABB5ABsaiIJQeQAEL8BGiAJIAVBnH9qIgVB5AAQngEaIAUgBkGIA2pB5AAQvwEaIA8gB0kNAAsMAQsgDSEFIAogCU8NAANAIAlBf2oiCS0AACEHIAZBiANqIAVB5AAQvwEaIAUgDiAHQX9zQeQAbGoiBUHkABCeASAFIAZBiANqQeQAEL8BGkHkAGohBSAKIAlJDQALCyAUIAZBIGpB5AAQvwEhByABIAUgFWtB5ABuIBBqIgVNDQMgBkGIAmogB0HkABC/ARogByAHIAVB5ABsaiIHQeQAEJ4BIQ4gByAGQYgCakHkABC/ASEHIAEgBWsiCkUNBCAKIAUgBSAKSxsgAUEDdiAHQeQAaiEJAkAgBSAKQX9qIgFPBEAgCSABIAIgByAEEAUgBSEBDAELIA4gBSACIAMgBBAFIAchAyAJIQALTyERIAFBFU8NAQwFCwsgECAKEK0BAAsgCiAJEKsBAAtBwKfCACAFIAEQrAEAC0G1rcIAQRxB1K3CABC+AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBKIAEgBUcNAAsLIAZB8ANqJAALgRUBF38jAEHAAmsiBiQAAkACQCABQRVJDQBBASEJQQEhEwJAAkACQANAIAEhDyAAIQ4gCSATcUEBcyERAkACQAJAAkACQAJAA0ACfwJAIAQEQCAJRQRAIAAgARBeIARBf2ohBAsgBiACNgIIIAYgADYCMCAGIAE2AjQgBiABQQJ2IgU2AgwgBiAFQQF0Igg2AhAgBiAFQQNsIgc2AhQgBkEANgIYIAYgBkEYajYCuAEgBiAGQTBqNgK0ASAGIAZBCGo2ArABIAYgBkGwAWo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2ArACIAZBHGogBkEgaiAGQQxqIAZBsAJqEMsBIAYgCEF/ajYCICAGIAhBAXI2ArACIAZBHGogBkEgaiAGQRBqIAZBsAJqEMsBIAYgB0F/ajYCICAGIAdBAWo2ArACIAZBHGogBkEgaiAGQRRqIAZBsAJqEMsBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMsBIAYoAhgiBUELSw0BIAYoAhAhByAFRQwCCyAAIAEQbgwOCyAGKAI0IgdBAXYiCARAIAYoAjAiBSAHQQxsakF0aiEHA0AgBkG4AmoiDCAFQQhqIgooAgA2AgAgBiAFKQIANwOwAiAHQQhqIgsoAgAhDSAFIAcpAgA3AgAgCiANNgIAIAsgDCgCADYCACAHIAYpA7ACNwIAIAVBDGohBSAHQXRqIQcgCEF/aiIIDQALCyABIAYoAhBBf3NqIQdBAQtFIBFyQQFxRQRAIAAgARArDQ0LIANFDQIgByABTw0BIANBAyAAIAdBDGxqIgVBAxCZAUH/AXFB/wFGBEAgASEPIAAhDgwECyAGQbgCaiIKIABBCGoiCygCADYCACAGIAApAgA3A7ACIAVBCGoiCCgCACEHIAAgBSkCADcCACALIAc2AgAgCCAKKAIANgIAIAUgBikDsAI3AgAgBkG4AWoiEiALKAIANgIAIAYgACkCADcDsAEgAEF0aiEUIABBDGohDUEAIQcgASEIA0ACQCAHIAhBf2oiDE8NACANIAdBDGxqIQUDQCAGQbABakEDIAVBAxCZAUH/AXFB/wFGDQEgBUEMaiEFIAwgB0EBaiIHRw0ACyAMIQcLIBQgCEEMbGohBQJAA0AgByAIQX9qIghPDQEgBkGwAWpBAyAFQQMQmQEgBUF0aiIMIQVB/wFxQf8BRg0ACyAKIA0gB0EMbGoiBUEIaiITKAIANgIAIAYgBSkCADcDsAIgDEEUaiIQKAIAIRYgBSAMQQxqIgUpAgA3AgAgEyAWNgIAIBAgCigCADYCACAFIAYpA7ACNwIAIAdBAWohBwwBCwsgACAGKQOwATcCACALIBIoAgA2AgAgASAHQQFqIgVPBEAgACAFQQxsaiEAIAEgBWsiAUEVTw0BDAwLCyAFIAEQrQEAC0HQp8IAIAcgARCsAQALIA9FDQELIAcgD08NASAGQbgBaiIUIA5BCGoiEigCADYCACAGIA4pAgA3A7ABIA4gB0EMbGoiAEEIaiIBKAIAIQUgDiAAKQIANwIAIBIgBTYCACABIBQoAgA2AgAgACAGKQOwATcCACAGQShqIhYgEigCADYCACAGIA4pAgA3AyAgDkEMaiEJQQAhDCAPQX9qIgFFDQIgCSEFA0AgBUEDIAZBIGpBAxCZAUH/AXFB/wFHDQMgBUEMaiEFIAEgDEEBaiIMRw0ACyABIQwMAgtBsKfCAEEAQQAQrAEAC0HAp8IAIAcgDxCsAQALIA4gD0EMbGohByABIQgDQAJAIAchACAIIgUgDE0iEw0AIAVBf2ohCCAAQXRqIgdBAyAGQSBqQQMQmQFB/wFxQf8BRw0BCwsgBSAMTwRAIAEgBUkNAkGAASENQQAhCEEAIQpBACEHQQAhC0GAASERIAkgDEEMbGoiFyEBA0AgACABayIJQQxuIQUCQCAJQYsYSyIYDQAgBUGAf2ogBSALIAdJIgkgCiAISXIiEBshBSAQBEAgESAFIAkbIREgBSANIAkbIQ0MAQsgBSAFQQF2IhFrIQ0LAkAgByALRw0AIBFFBEAgBkEwaiIHIQsMAQtBACEFIAEhCSAGQTBqIgshBwNAIAcgBToAACAHIAlBAyAGQSBqQQMQmQFB/wFxQf8BR2ohByAJQQxqIQkgESAFQQFqIgVHDQALCwJAIAggCkcNACANRQRAIAZBsAFqIgghCgwBCyAAQXRqIQVBACEJIAZBsAFqIgohCANAIAggCToAACAIIAVBAyAGQSBqQQMQmQFB/wFxQf8BRmohCCAFQXRqIQUgDSAJQQFqIglHDQALCyAIIAprIgUgByALayIJIAkgBUsbIgkEQCAGQbgCaiIZIAEgCy0AAEEMbGoiBUEIaigCADYCACAGIAUpAgA3A7ACIAEgCy0AAEEMbGoiBSAAIAotAABBf3NBDGxqIhApAgA3AgAgBUEIaiAQQQhqKAIANgIAIAlBAUcEQEEAIQUDQCAAIAUgCmoiEC0AAEF/c0EMbGoiFSABIAUgC2pBAWoiGi0AAEEMbGoiGykCADcCACAVQQhqIBtBCGooAgA2AgAgASAaLQAAQQxsaiIVIAAgEEEBai0AAEF/c0EMbGoiECkCADcCACAVQQhqIBBBCGooAgA2AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgCiAQaiEKCyAAIAotAABBf3NBDGxqIgUgBikDsAI3AgAgBUEIaiAZKAIANgIAIAtBAWohCyAKQQFqIQoLIAEgEUEMbGogASAHIAtGGyEBIABBACANa0EMbGogACAIIApGGyEAIBgNAAsCQCALIAdJBEAgACEFA0AgBkG4AmoiCCABIAdBf2oiBy0AAEEMbGoiAEEIaiIJKAIANgIAIAYgACkCADcDsAIgBUF0aiIFQQhqIgooAgAhDSAAIAUpAgA3AgAgCSANNgIAIAogCCgCADYCACAFIAYpA7ACNwIAIAsgB0kNAAsMAQsgASEFIAogCE8NAANAIAhBf2oiCC0AACEBIAZBuAJqIgcgBUEIaiIJKAIANgIAIAYgBSkCADcDsAIgACABQX9zQQxsaiIBQQhqIgsoAgAhDSAFIAEpAgA3AgAgCSANNgIAIAsgBygCADYCACABIAYpA7ACNwIAIAVBDGohBSAKIAhJDQALCyAOIAYpAyA3AgAgEiAWKAIANgIAIA8gBSAXa0EMbiAMaiIBTQ0DIBQgEigCADYCACAGIA4pAgA3A7ABIA4gAUEMbGoiBUEIaiIAKAIAIQggDiAFKQIANwIAIBIgCDYCACAAIBQoAgA2AgAgBSAGKQOwATcCACAPIAFrIghFDQQgCCABIAEgCEsbIA9BA3YgBUEMaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAYgDiEADAELIA4gASACIAMgBBAGIAUhAyAIIQELTyEJIAFBFU8NAQwFCwsgDCAFEK0BAAsgBSABEKsBAAtBwKfCACABIA8QrAEAC0G1rcIAQRxB1K3CABC+AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBQIAEgBUcNAAsLIAZBwAJqJAAL5BACFH8BfiMAQbACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEOIAAhDCAPIBNxQQFzIQkCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEHIgBEF/aiEECyAGIAI2AgwgBiAANgIwIAYgATYCNCAGIAFBAnYiCDYCECAGIAhBAXQiBTYCFCAGIAhBA2wiBzYCGCAGQQA2AhwgBiAGQRxqNgK4ASAGIAZBMGo2ArQBIAYgBkEMajYCsAEgBiAGQbABajYCICABQTJPBEAgBiAIQX9qNgIkIAYgCEEBajYCKCAGQSBqIAZBJGogBkEQaiAGQShqEGcgBiAFQX9qNgIkIAYgBUEBcjYCKCAGQSBqIAZBJGogBkEUaiAGQShqEGcgBiAHQX9qNgIkIAYgB0EBajYCKCAGQSBqIAZBJGogBkEYaiAGQShqEGcLIAZBIGogBkEQaiAGQRRqIAZBGGoQZyAGKAIcIghBC0sNASAIRSEHIAYoAhQMAgsgACABEI0BDA4LIAYoAjQiB0EBdiIIBEAgBigCMCIFIAdBA3RqQXhqIQcDQCAFKQIAIRkgBSAHKQIANwIAIAcgGTcCACAFQQhqIQUgB0F4aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCFEF/c2oLIQUgB0UgCXJBAXFFBEAgACABEDoNDQsgA0UNAiAFIAFPDQEgACAFQQN0aiIIKAIEKAIAIAMoAgQoAgBJBEAgASEOIAAhDAwECyAAKQIAIRkgACAIKQIANwIAIAggGTcCACAAQXxqIQsgAEEMaiERIABBCGohEiAAKAIEIQ0gACgCACETQQAhByABIQgDQAJAIAcgCEF/aiIKTw0AIBEgB0EDdGohBSANKAIAIRADQCAFKAIAKAIAIBBJDQEgBUEIaiEFIAogB0EBaiIHRw0ACyAKIQcLIAsgCEEDdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACAFQXhqIhAhBSgCACANKAIASQ0ACyASIAdBA3RqIgUpAgAhGSAFIBBBBGoiBSkCADcCACAFIBk3AgAgB0EBaiEHDAELCyAAIA02AgQgACATNgIAIAEgB0EBaiIITwRAIAAgCEEDdGohACABIAhrIgFBFU8NAQwMCwsgCCABEK0BAAtB0KfCACAFIAEQrAEACyAORQ0BCyAFIA5PDQEgDCkCACEZIAwgDCAFQQN0aiIAKQIANwIAIAAgGTcCACAMKAIAIQAgBiAMKAIEIhI2AiwgBiAANgIoIA5Bf2oiAUUEQEEAIQ0MAwsgDEEMaiEFIBIoAgAhAEEAIQ0DQCAAIAUoAgAoAgBPDQMgBUEIaiEFIAEgDUEBaiINRw0ACyABIQ0MAgtBsKfCAEEAQQAQrAEAC0HAp8IAIAUgDhCsAQALIAxBCGohCiAMIA5BA3RqIQcgASEAA0ACQCAHIQggACIFIA1NIhMNACAIQXhqIQcgBUF/aiEAIBIoAgAgCEF8aigCACgCAE8NAQsLIAUgDU8EQCABIAVJDQJBgAEhEUEAIQBBACEBQQAhB0EAIQtBgAEhDyAKIA1BA3RqIhUhCgNAAkAgCCAKayIFQYcQSyIWDQAgBUEDdiIFQYB/aiAFIAsgB0kiCSABIABJciIQGyEFIBAEQCAPIAUgCRshDyAFIBEgCRshEQwBCyAFIAVBAXYiD2shEQsCQCAHIAtHDQAgD0UEQCAGQTBqIgchCwwBC0EAIQUgCiEJIAZBMGoiCyEHA0AgByAFOgAAIAcgEigCACAJQQRqKAIAKAIAT2ohByAJQQhqIQkgDyAFQQFqIgVHDQALCwJAIAAgAUcNACARRQRAIAZBsAFqIgAhAQwBCyAIQXxqIQVBACEJIAZBsAFqIgEhAANAIAAgCToAACAAIAYoAiwiEigCACAFKAIAKAIASWohACAFQXhqIQUgESAJQQFqIglHDQALCyAIIBFBA3RrIAggACABayIFIAcgC2siCSAJIAVLGyIJBEAgCiALLQAAQQN0aiIFKAIEIRcgBSgCACEYIAUgCCABLQAAQX9zQQN0aikCADcCACAJQQFHBEBBACEFA0AgCCABIAVqIhAtAABBf3NBA3RqIAogBSALakEBaiIULQAAQQN0aikCADcCACAKIBQtAABBA3RqIAggEEEBai0AAEF/c0EDdGopAgA3AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgASAQaiEBCyAIIAEtAABBf3NBA3RqIgUgFzYCBCAFIBg2AgAgC0EBaiELIAFBAWohAQsgACABRhshCCAKIA9BA3RqIAogByALRhshCiAWDQALAkAgCyAHSQRAIAghBQNAIAogB0F/aiIHLQAAQQN0aiIAKQIAIRkgACAFQXhqIgUpAgA3AgAgBSAZNwIAIAsgB0kNAAsMAQsgCiEFIAEgAE8NAANAIAUpAgAhGSAFIAggAEF/aiIALQAAQX9zQQN0aiIHKQIANwIAIAcgGTcCACAFQQhqIQUgASAASQ0ACwsgDCAGKQMoIhk3AgAgDiAFIBVrQQN2IA1qIgFNDQMgDCAMIAFBA3RqIggpAgA3AgAgCCAZNwIAIA4gAWsiBUUNBCAFIAEgASAFSxsgDkEDdiAIQQhqIQACQCABIAVBf2oiBU8EQCAAIAUgAiAIIAQQByAMIQAMAQsgDCABIAIgAyAEEAcgCCEDIAUhAQtPIQ8gAUEVTw0BDAULCyANIAUQrQEACyAFIAEQqwEAC0HAp8IAIAEgDhCsAQALQbWtwgBBHEHUrcIAEL4BAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEG0gASAFRw0ACwsgBkGwAmokAAvvDwETfyMAQaACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEMIAAhDSAPIBNxQQFzIQsCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEHMgBEF/aiEECyAGIAI2AgAgBiAANgIgIAYgATYCJCAGIAFBAnYiBTYCBCAGIAVBAXQiCDYCCCAGIAVBA2wiBzYCDCAGQQA2AhAgBiAGQRBqNgKoASAGIAZBIGo2AqQBIAYgBjYCoAEgBiAGQaABajYCFCABQTJPBEAgBiAFQX9qNgIYIAYgBUEBajYCHCAGQRRqIAZBGGogBkEEaiAGQRxqEMkBIAYgCEF/ajYCGCAGIAhBAXI2AhwgBkEUaiAGQRhqIAZBCGogBkEcahDJASAGIAdBf2o2AhggBiAHQQFqNgIcIAZBFGogBkEYaiAGQQxqIAZBHGoQyQELIAZBFGogBkEEaiAGQQhqIAZBDGoQyQEgBigCECIFQQtLDQEgBUUhByAGKAIIDAILIAAgARCMAQwOCyAGKAIkIgdBAXYiCARAIAYoAiAiBSAHQQJ0akF8aiEHA0AgBSgCACEJIAUgBygCADYCACAHIAk2AgAgBUEEaiEFIAdBfGohByAIQX9qIggNAAsLQQEhByABIAYoAghBf3NqCyEFIAdFIAtyQQFxRQRAIAAgARBADQ0LIANFDQIgBSABTw0BIAMoAgAgACAFQQJ0aiIIKAIAIgcQtwEEQCABIQwgACENDAQLIAAoAgAhBSAAIAc2AgAgCCAFNgIAIABBfGohECAAQQRqIQogACgCACEOQQAhByABIQgDQAJAIAcgCEF/aiIJTw0AIAogB0ECdGohBQNAIA4gBSgCABC3AQ0BIAVBBGohBSAJIAdBAWoiB0cNAAsgCSEHCyAQIAhBAnRqIQUCQANAIAcgCEF/aiIITw0BIAUoAgAhCSAFQXxqIhEhBSAOIAkQtwENAAsgCiAHQQJ0aiIFKAIAIRMgBSAJNgIAIBFBBGogEzYCACAHQQFqIQcMAQsLIAAgDjYCACABIAdBAWoiBU8EQCAAIAVBAnRqIQAgASAFayIBQRVPDQEMDAsLIAUgARCtAQALQdCnwgAgBSABEKwBAAsgDEUNAQsgBSAMTw0BIA0oAgAhACANIA0gBUECdGoiASgCADYCACABIAA2AgAgDUEEaiEJIA0oAgAhEEEAIQ4gDEF/aiIBRQ0CIAkhBQNAIAUoAgAgEBC3AUUNAyAFQQRqIQUgASAOQQFqIg5HDQALIAEhDgwCC0Gwp8IAQQBBABCsAQALQcCnwgAgBSAMEKwBAAsgDSAMQQJ0aiEHIAEhCANAAkAgByEAIAgiBSAOTSITDQAgBUF/aiEIIABBfGoiBygCACAQELcBRQ0BCwsgBSAOTwRAIAEgBUkNAkGAASEPQQAhCEEAIQFBACEHQQAhC0GAASERIAkgDkECdGoiFSEJA0ACQCAAIAlrIgVBgwhLIhYNACAFQQJ2IgVBgH9qIAUgCyAHSSIKIAEgCElyIhIbIQUgEgRAIBEgBSAKGyERIAUgDyAKGyEPDAELIAUgBUEBdiIRayEPCwJAIAcgC0cNACARRQRAIAZBIGoiByELDAELQQAhBSAJIQogBkEgaiILIQcDQCAHIAU6AAAgByAKKAIAIBAQtwFBAXNqIQcgCkEEaiEKIBEgBUEBaiIFRw0ACwsCQCABIAhHDQAgD0UEQCAGQaABaiIIIQEMAQsgAEF8aiEFQQAhCiAGQaABaiIBIQgDQCAIIAo6AAAgBSgCACAQELcBIAhqIQggBUF8aiEFIA8gCkEBaiIKRw0ACwsgACAPQQJ0ayAAIAggAWsiBSAHIAtrIgogCiAFSxsiCgRAIAkgCy0AAEECdGoiBSgCACEXIAUgACABLQAAQX9zQQJ0aigCADYCACAKQQFHBEBBACEFA0AgACABIAVqIhItAABBf3NBAnRqIAkgBSALakEBaiIULQAAQQJ0aigCADYCACAJIBQtAABBAnRqIAAgEkEBai0AAEF/c0ECdGooAgA2AgAgBUECaiAFQQFqIhIhBSAKSQ0ACyALIBJqIQsgASASaiEBCyAAIAEtAABBf3NBAnRqIBc2AgAgC0EBaiELIAFBAWohAQsgASAIRhshACAJIBFBAnRqIAkgByALRhshCSAWDQALAkAgCyAHSQRAIAAhBQNAIAkgB0F/aiIHLQAAQQJ0aiIAKAIAIQEgACAFQXxqIgUoAgA2AgAgBSABNgIAIAsgB0kNAAsMAQsgCSEFIAEgCE8NAANAIAUoAgAhByAFIAAgCEF/aiIILQAAQX9zQQJ0aiIJKAIANgIAIAkgBzYCACAFQQRqIQUgASAISQ0ACwsgDSAQNgIAIAwgBSAVa0ECdiAOaiIBTQ0DIA0gDSABQQJ0aiIFKAIANgIAIAUgEDYCACAMIAFrIghFDQQgCCABIAEgCEsbIAxBA3YgBUEEaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAggDSEADAELIA0gASACIAMgBBAIIAUhAyAIIQELTyEPIAFBFU8NAQwFCwsgDiAFEK0BAAsgBSABEKsBAAtBwKfCACABIAwQrAEAC0G1rcIAQRxB1K3CABC+AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRCAASABIAVHDQALCyAGQaACaiQAC9cPARN/IwBBoAJrIgYkAAJAAkAgAUEVSQ0AQQEhD0EBIRMCQAJAAkADQCABIQwgACENIA8gE3FBAXMhCwJAAkACQAJAAkACQANAAn8CQCAEBEAgD0EBcUUEQCAAIAEQcyAEQX9qIQQLIAYgAjYCACAGIAA2AiAgBiABNgIkIAYgAUECdiIFNgIEIAYgBUEBdCIINgIIIAYgBUEDbCIHNgIMIAZBADYCECAGIAZBEGo2AqgBIAYgBkEgajYCpAEgBiAGNgKgASAGIAZBoAFqNgIUIAFBMk8EQCAGIAVBf2o2AhggBiAFQQFqNgIcIAZBFGogBkEYaiAGQQRqIAZBHGoQaiAGIAhBf2o2AhggBiAIQQFyNgIcIAZBFGogBkEYaiAGQQhqIAZBHGoQaiAGIAdBf2o2AhggBiAHQQFqNgIcIAZBFGogBkEYaiAGQQxqIAZBHGoQagsgBkEUaiAGQQRqIAZBCGogBkEMahBqIAYoAhAiBUELSw0BIAVFIQcgBigCCAwCCyAAIAEQiwEMDgsgBigCJCIHQQF2IggEQCAGKAIgIgUgB0ECdGpBfGohBwNAIAUoAgAhCSAFIAcoAgA2AgAgByAJNgIAIAVBBGohBSAHQXxqIQcgCEF/aiIIDQALC0EBIQcgASAGKAIIQX9zagshBSAHRSALckEBcUUEQCAAIAEQPw0NCyADRQ0CIAUgAU8NASADKAIAIAAgBUECdGoiCCgCACIHSQRAIAEhDCAAIQ0MBAsgACgCACEFIAAgBzYCACAIIAU2AgAgAEF8aiEQIABBBGohCiAAKAIAIQ5BACEHIAEhCANAAkAgByAIQX9qIglPDQAgCiAHQQJ0aiEFA0AgDiAFKAIASQ0BIAVBBGohBSAJIAdBAWoiB0cNAAsgCSEHCyAQIAhBAnRqIQUCQANAIAcgCEF/aiIITw0BIAUoAgAhCSAFQXxqIhEhBSAOIAlJDQALIAogB0ECdGoiBSgCACETIAUgCTYCACARQQRqIBM2AgAgB0EBaiEHDAELCyAAIA42AgAgASAHQQFqIgVPBEAgACAFQQJ0aiEAIAEgBWsiAUEVTw0BDAwLCyAFIAEQrQEAC0HQp8IAIAUgARCsAQALIAxFDQELIAUgDE8NASANKAIAIQAgDSANIAVBAnRqIgEoAgA2AgAgASAANgIAIA1BBGohCSANKAIAIRBBACEOIAxBf2oiAUUNAiAJIQUDQCAFKAIAIBBPDQMgBUEEaiEFIAEgDkEBaiIORw0ACyABIQ4MAgtBsKfCAEEAQQAQrAEAC0HAp8IAIAUgDBCsAQALIA0gDEECdGohByABIQgDQAJAIAchACAIIgUgDk0iEw0AIAVBf2ohCCAAQXxqIgcoAgAgEE8NAQsLIAUgDk8EQCABIAVJDQJBgAEhD0EAIQhBACEBQQAhB0EAIQtBgAEhESAJIA5BAnRqIhUhCQNAAkAgACAJayIFQYMISyIWDQAgBUECdiIFQYB/aiAFIAsgB0kiCiABIAhJciISGyEFIBIEQCARIAUgChshESAFIA8gChshDwwBCyAFIAVBAXYiEWshDwsCQCAHIAtHDQAgEUUEQCAGQSBqIgchCwwBC0EAIQUgCSEKIAZBIGoiCyEHA0AgByAFOgAAIAcgCigCACAQT2ohByAKQQRqIQogESAFQQFqIgVHDQALCwJAIAEgCEcNACAPRQRAIAZBoAFqIgghAQwBCyAAQXxqIQVBACEKIAZBoAFqIgEhCANAIAggCjoAACAIIAUoAgAgEElqIQggBUF8aiEFIA8gCkEBaiIKRw0ACwsgACAPQQJ0ayAAIAggAWsiBSAHIAtrIgogCiAFSxsiCgRAIAkgCy0AAEECdGoiBSgCACEXIAUgACABLQAAQX9zQQJ0aigCADYCACAKQQFHBEBBACEFA0AgACABIAVqIhItAABBf3NBAnRqIAkgBSALakEBaiIULQAAQQJ0aigCADYCACAJIBQtAABBAnRqIAAgEkEBai0AAEF/c0ECdGooAgA2AgAgBUECaiAFQQFqIhIhBSAKSQ0ACyALIBJqIQsgASASaiEBCyAAIAEtAABBf3NBAnRqIBc2AgAgC0EBaiELIAFBAWohAQsgASAIRhshACAJIBFBAnRqIAkgByALRhshCSAWDQALAkAgCyAHSQRAIAAhBQNAIAkgB0F/aiIHLQAAQQJ0aiIAKAIAIQEgACAFQXxqIgUoAgA2AgAgBSABNgIAIAsgB0kNAAsMAQsgCSEFIAEgCE8NAANAIAUoAgAhByAFIAAgCEF/aiIILQAAQX9zQQJ0aiIJKAIANgIAIAkgBzYCACAFQQRqIQUgASAISQ0ACwsgDSAQNgIAIAwgBSAVa0ECdiAOaiIBTQ0DIA0gDSABQQJ0aiIFKAIANgIAIAUgEDYCACAMIAFrIghFDQQgCCABIAEgCEsbIAxBA3YgBUEEaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAkgDSEADAELIA0gASACIAMgBBAJIAUhAyAIIQELTyEPIAFBFU8NAQwFCwsgDiAFEK0BAAsgBSABEKsBAAtBwKfCACABIAwQrAEAC0G1rcIAQRxB1K3CABC+AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRB1IAEgBUcNAAsLIAZBoAJqJAALtw4BGn8jAEGAAWsiByQAIAAoAgQhBCABKAIIIQkgASgCACENAkACQAJAIAAoAgAtAAAEQCAHIAQ2AgwgCUEVSQ0BIAcgCUEBdhCiASAHKAIEIAcoAgAhECAHQQA2AhggB0IENwMQIA1BnH9qIRVBBCEKQQAhBCAJIQYDQAJAIAYiA0EBRgRAQQAhBkEBIQAMAQsgA0F+aiECIA0gA0HkAGwiEWohBUFYIQECQAJAAkACQAJAAkACQANAIAFBBGoiAEUNASABIAVqIQggACEBIAhBoH9qKAIAIgYgCEEEaigCACIARg0ACyAGIABIDQELIA0gEWohBQNAIAIiBkUEQEEAIQYgAyEADAgLIAZBf2ohAkFYIQEDQAJAIAFBBGoiAEUEQEEAIQAMAQsgASAFaiEMIAAhAUF/IAxBvH5qKAIAIgggDEGgf2ooAgAiAEcgCCAASBsiAEUNAQsLIAVBnH9qIQUgAEF/Rw0ACyADIAZrIQAMAQsCQCACBEADQCACIgZBf2ohAkFYIQEDQCABQQRqIgBFDQMgASAFaiEMIAAhAUF/IAxBvH5qKAIAIgggDEGgf2ooAgAiAEcgCCAASBsiAEUNAAsgAEH/AXFB/wFHDQIgBUGcf2ohBSACDQALC0EAIQYLIAMgBkkNASADIAlLDQIgAyAGayIAQQF2IgVFDQAgDSAGQeQAbGohASARIBVqIQIDQCAHQRxqIAFB5AAQvwEaIAEgAkHkABCeAUHkAGohASACIAdBHGpB5AAQvwFBnH9qIQIgBUF/aiIFDQALCyAGRSAAQQlLcg0EIAMgBmshACAGQX9qIQEgFSAGQeQAbGohAgNAIAMgAUkNAyADIAlLDQIgAiAAQQFqIgAQTyABQX9qIgQgAU8NBCACQZx/aiECIAQhASAAQQpJDQALDAMLIAYgAxCtAQALIAMgCRCrAQALIAEgAxCtAQALIARBAWohBiALIQQLIAcoAhQgBEYEfyAHQRBqIAQQjgEgBygCECEKIAcoAhgiCwUgBAtBA3QgCmoiASAANgIEIAEgBjYCACAHIAtBAWoiCzYCGAJAIAsiBEECSQ0AAkADQAJAAkACQAJAIAogCyIDQX9qIgtBA3RqIgEoAgAEQCADQQN0IApqIgVBdGooAgAiCCABKAIEIgBLDQELIANBA0kNAiABKAIEIQAgCiADQX1qIgFBA3RqKAIEIQIMAQtBAiEEIANBAk0NBCAKIANBfWoiAUEDdGooAgQiAiAAIAhqTQ0AQQMhBCADQQNNDQQgBUFkaigCACACIAhqTQ0AIAMhCyADIQQMBQsgAiAASQ0BCyADQX5qIQELAkACQAJAAkACQAJAAkAgAyABQQFqIhNLBEAgAyABTQ0BIAogAUEDdCIZaiIAKAIEIhogACgCAGoiASAKIBNBA3QiG2oiACgCACISSQ0CIAEgCUsNAyANIBJB5ABsaiIIIAAoAgQiD0HkAGwiBWohBCANIAFB5ABsaiEUIAEgEmsiACAPayIBIA9PDQQgECAEIAFB5ABsIgAQvwEiDCAAaiEOIA9BAUggAUEBSHINBSAEIQUDQCAOQVxqIREgBUGcf2ohFiAOQZx/aiEXQQAhAQNAAkAgAUEEaiIAQShGBEBBACEADAELIAEgEWohAiABIAVqIQQgACEBQX8gBEFcaigCACIEIAIoAgAiAEcgBCAASBsiAEUNAQsLIBRBnH9qIhQgFiAXIABBf0YiABtB5AAQvwEaIA4gFyAAGyEOIAggFiAFIAAbIgVPDQcgDiAMIgJLDQALDAcLQeirwgAgEyADEKwBAAtB6KvCACABIAMQrAEACyASIAEQrQEACyABIAkQqwEACyAQIAggBRC/ASICIAVqIQ4gD0EBSCAAIA9MckUEQCAIIQUDQEHAACEBA0ACQCABQQRqIgBB6ABGBEBBACEADAELIAEgBGohDCABIAJqIQggACEBQX8gCCgCACIIIAwoAgAiAEcgCCAASBsiAEUNAQsLIAQgAiAAQX9GIgEbIQAgAiACQeQAaiABGyECIAUgAEHkABC/AUHkAGohBSAEQeQAaiAEIAEbIgQgFE8NBCAOIAJLDQALDAMLIAghBQwBCyAEIQULIBAhAgsgBSACIA4gAmsiACAAQeQAcGsQvwEaIAogGWoiACAPIBpqNgIEIAAgEjYCACAKIBtqIgAgAEEIaiADIBNBf3NqQQN0EJ4BGiAHIAs2AhggC0EBSw0ACyALIQQMAQsgAyELCyAGDQALIAcoAhQEQCAKECELRQ0CIBAQIQwCCyAHIAQ2AhAgByAHQRBqNgIcIA0gCSAHQRxqQQBBICAJZ2sQBQwBC0ECIQEgCUECSQ0AIA0gCUF+aiIAQeQAbGohAgNAIAkgAEkNAiACIAEQTyACQZx/aiECIAFBAWohASAAQX9qIgBBf0cNAAsLIAdBgAFqJAAPCyAAIAkQrQEAC78MAhZ/AX4jAEEgayIHJAAgACgCBCEDIAEoAgghCCABKAIAIQsCQAJAAkAgACgCAC0AAARAIAcgAzYCDCAIQRVJDQEgByAIQQF2EKYBIAcoAgQgBygCACEQQQAhASAHQQA2AhggB0IENwMQIAtBeGohEyALQWxqIRVBBCEJQQAhAyAIIQYDQAJAIAYiBUF/aiIGRQRAQQAhBkEBIQQMAQsCQAJAAkACQAJAIAVBA3QiDSALakF0aigCACgCACIAIAsgBkEDdGooAgQoAgBPBEAgBUF+aiEUIA0gFWohBEEAIQZBACECA0AgAiAURgRAIAUhBAwICyACQQFqIQIgBCgCACgCACIKIABPIARBeGohBCAKIQANAAsgAkEBaiEEIAJBf3MgBWohBgwBCyANIBVqIQIDQAJAIAZBAUYEQEEAIQYMAQsgBkF/aiEGIAIoAgAoAgAiBCAASSACQXhqIQIgBCEADQELCyAFIAZJDQEgBSAISw0CIAUgBmsiBEEBdiIKRQ0AIAsgBkEDdGohACANIBNqIQIDQCAAKQIAIRggACACKQIANwIAIAIgGDcCACAAQQhqIQAgAkF4aiECIApBf2oiCg0ACwsgBkUgBEEJS3INBCAFIAZrIQQgBkF/aiEAIBMgBkEDdGohAgNAIAUgAEkNAyAFIAhLDQIgAiAEQQFqIgQQbyAAQX9qIgMgAE8NBCACQXhqIQIgAyEAIARBCkkNAAsMAwsgBiAFEK0BAAsgBSAIEKsBAAsgACAFEK0BAAsgA0EBaiEGIAEhAwsgBygCFCADRgR/IAdBEGogAxCOASAHKAIQIQkgBygCGCIBBSADC0EDdCAJaiIAIAQ2AgQgACAGNgIAIAcgAUEBaiIBNgIYIAEhAwJAIAFBAkkNAAJAA0ACQAJAAkACQCAJIAFBf2pBA3RqIgAoAgBFDQAgAUEDdCAJaiIEQXRqKAIAIgUgACgCBCICTQ0AQQIhAyABQQJNDQYgCSABQX1qIgxBA3RqKAIEIgAgAiAFak0NAUEDIQMgAUEDTQ0GIARBZGooAgAgACAFak0NASABIQMMBgsgAUEDSQ0BIAAoAgQhAiAJIAFBfWoiDEEDdGooAgQhAAsgACACSQ0BCyABQX5qIQwLAkACQCABIAxBAWoiEUsEQCABIAxLBEAgCSAMQQN0IhdqIgAoAgQiFCAAKAIAaiIDIAkgEUEDdCINaiIAKAIAIhJPBEAgAyAITQRAIAsgEkEDdGoiBCAAKAIEIg5BA3QiCmohASADQQN0IQ8CQAJAIAMgEmsiAyAOayIFIA5JBEAgECABIAVBA3QiABC/ASIKIABqIQIgDkEBSCAFQQFIcg0BIA8gE2ohAANAAkAgACABQXhqIgUgAkF4aiIDIAFBfGooAgAoAgAgAkF8aigCACgCAEkiDxspAgA3AgAgAiADIA8bIQIgBCAFIAEgDxsiAU8NACAAQXhqIQAgAiAKSw0BCwsgBygCECEJDAELIAogECAEIAoQvwEiAGohAiADIA5KQQAgDkEBThsNASAEIQELIBAhAAwGCyALIA9qIQMDQCAEIAEgACAAKAIEKAIAIAEoAgQoAgBJIgUbKQIANwIAIAAgAEEIaiAFGyEAIARBCGohBCABQQhqIAEgBRsiASADTw0FIAIgAEsNAAsMBAsgAyAIEKsBAAsgEiADEK0BAAtB6KvCACAMIAEQrAEAC0Hoq8IAIBEgARCsAQALIAQhAQsgASAAIAIgAGtBeHEQvwEaIAcoAhgiASAMSwRAIAkgF2oiACAOIBRqNgIEIAAgEjYCACABIBFNDQIgCSANaiIAIABBCGogASARQX9zakEDdBCeARogByABQX9qIgE2AhggASEDIAFBAU0NAwwBCwtB+KvCACAMIAEQrAEAC0GnrsIAQR1BxK7CABC+AQALIAYNAAsgBygCFARAIAkQIQtFDQIgEBAhDAILIAcgAzYCDCAHIAdBDGo2AhAgCyAIIAdBEGpBAEEgIAhnaxAHDAELQQIhASAIQQJJDQAgCyAIQX5qIgBBA3RqIQIDQCAIIABJDQIgAiABEG8gAkF4aiECIAFBAWohASAAQX9qIgBBf0cNAAsLIAdBIGokAA8LIAAgCBCtAQAL5gwCDH8EfiMAQeAAayIGJAACQAJAAkACQCABKAIQIgNBAWoiBSADTwRAIAEoAgAiAiEDAkAgBSACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQAJ/IAUgA0EBaiIDIAUgA0sbIgNFBEBBsKbCACEKQQQhBEEADAELIAZBIGpBfwJ/IANBCE8EQCADIANB/////wFxRgRAIANBA3RBB24MAgsMCQsgA0EBagtBf2pndiIHQQFqIgUQoAEgBigCJCIERQ0HIAYoAighAiAGKAIgIgMgBBDXASIKRQ0CIApB/wEgB0EFahDFASACaiEEIAcgBUEDdkEHbCAHQQhJGwshAiAGIAEoAhAiAzYCMCAGIAIgA2s2AiwgBiAENgIoIAYgCjYCJCAGIAc2AiAgASgCBCIDQQRqIQIgASgCACADakEBaiENIAMoAgBBf3NBgIGChHhxIQggASgCCCEJA0ACQAJAAkAgCARAIAIhAwwBCwNAIAIgDU8NAiAJQbAHaiEJIAIoAgAgAkEEaiIDIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQgLIAYgCSAIaEEDdkHsAWxqIgwoAgAiBDYCUCAIQX9qIQtCpcaIocicp/lLIQ5BASECA0AgDiAErUL/AYOFQrODgICAIH4hDiACQQRGDQIgBkHQAGogAmogAkEBaiECLQAAIQQMAAsACyABKQIAIQ8gASAGKQMgNwIAIAZBKGoiAykDACEOIAMgAUEIaiIDKQIANwMAIAMgDjcCACAGQTBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiAPNwMgIABBADYCACAPpyIARQ0HIAZB0ABqIABBAWoQoAEgBigCJBAhDAcLIAogBkEgaiAOEJwBIgVqIA6nQRl2IgI6AAAgByAFQXxqcSAKakEEaiACOgAAIAYoAiggBUHsAWxqIAxB7AEQvwEaIAggC3EhCCADIQIMAAsACyACQQFqIQtBACECA0ACQAJ/IARBAXFFBEAgAiALTw0CIAJBAWoiAyACSQ0CIAIhBCADDAELIAJBA2oiBCACSSIFDQEgBCALSSIDRQ0BIAsgAkEEaiAFGyALIAMbCyECIAEoAgQgBGoiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhBAwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJ4BGgwDCyADIARBtPLCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIg0gA08Ef0EAIQMDQCADIgVBAWohAwJAIAUgASgCBGotAABBgAFHDQADQCAGIAEoAgggBUHsAWxqIggoAgAiBDYCIEKlxoihyJyn+UshDkEBIQIDQCAOIAStQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCAGQSBqIAJqIAJBAWohAi0AACEEDAELCyAFIAEoAgAiCSAOpyIMcSICayABIA4QnAEiByACa3MgCXFBBE8EQCABKAIEIgsgB2oiAi0AACACIAxBGXYiAjoAACALIAkgB0F8anFqQQRqIAI6AABB/wFGBEAgASgCBCICIAVqQf8BOgAAIAIgASgCACAFQXxqcWpBBGpB/wE6AAAgASgCCCAHQewBbGogCEHsARC/ARoMAwsgASgCCCAHQewBbGohCkEAIQIDQCACIApqIgcpAgAhECAHIAIgCGoiCSkCADcCACAHQRhqIgQpAgAhESAEIAlBGGoiDCkCADcCACAHQRBqIgQpAgAhDyAEIAlBEGoiCykCADcCACAHQQhqIgQpAgAhDiAEIAlBCGoiBCkCADcCACAEIA43AgAgCyAPNwIAIAwgETcCACAJIBA3AgAgAkFAayACQSBqIQJB7QFJDQALIAopAuABIQ4gCiAIKQLgATcC4AEgCkHoAWoiAigCACEEIAIgCEHoAWoiAigCADYCACAIIA43AuABIAIgBDYCAAwBCwsgASgCBCIEIAVqIAxBGXYiAjoAACAEIAkgBUF8anFqQQRqIAI6AAALIAMgDUcNAAsgASgCACICQQhJDQEgAkEBagUgDQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkHgAGokAA8LQbSmwgBBHEHQpsIAEL4BAAuODAEVfyMAQSBrIgYkACAAKAIEIQcgASgCCCEJIAEoAgAhCgJAAkACQCAAKAIALQAABEAgBiAHNgIMIAlBFUkNASAGIAlBAXYQpQEgBigCBCAGKAIAIQ5BACEBIAZBADYCGCAGQgQ3AxAgCkF8aiEQIApBdGohEUEEIQggCSEHA0AgByEFQQAhB0EBIQICQCAFQX9qIgRFDQACQAJAAkACQAJAAkAgCiAEQQJ0aigCACAFQQJ0IgcgCmpBeGooAgAiABC3AUUEQCAFQX5qIQQgByARaiECQQAhB0EAIQMDQCADIARGBEAgBSECDAkLIANBAWohAyAAIAIoAgAiABC3ASACQXxqIQJFDQALIANBAWohAiADQX9zIAVqIQQMAQsgByARaiECA0ACQCAEQQFGBEBBACEEDAELIARBf2ohBCAAIAIoAgAiABC3ASACQXxqIQINAQsLIAUgBEkNASAFIAlLDQIgBSAEayICQQF2IgtFDQAgCiAEQQJ0aiEAIAcgEGohAwNAIAAoAgAhByAAIAMoAgA2AgAgAyAHNgIAIABBBGohACADQXxqIQMgC0F/aiILDQALCyAERSACQQlLcg0EIAUgBGshAiAEQX9qIQAgECAEQQJ0aiEDA0AgBSAASQ0DIAUgCUsNAiADIAJBAWoiAhB3IABBf2oiByAATw0EIANBfGohAyAHIQAgAkEKSQ0ACwwDCyAEIAUQrQEACyAFIAkQqwEACyAAIAUQrQEACyAHQQFqIQcMAQsgBCEHCyAGKAIUIAFGBEAgBkEQaiABEI4BIAYoAhAhCCAGKAIYIQELIAggAUEDdGoiACACNgIEIAAgBzYCACAGIAFBAWoiATYCGAJAIAFBAkkNAAJAA0ACQAJAAkACQCAIIAFBf2pBA3RqIgAoAgBFDQAgAUEDdCAIaiIEQXRqKAIAIgMgACgCBCICTQ0AIAFBAk0NBiAIIAFBfWoiBUEDdGooAgQiACACIANqTQ0BIAFBA00NBiAEQWRqKAIAIAAgA2pLDQYMAQsgAUEDSQ0BIAAoAgQhAiAIIAFBfWoiBUEDdGooAgQhAAsgACACSQ0BCyABQX5qIQULAkACQCABIAVBAWoiC0sEQCABIAVLBEAgCCAFQQN0IhNqIgAoAgQiFCAAKAIAaiIAIAggC0EDdCIVaiIBKAIAIg9PBEAgACAJTQRAIAogD0ECdGoiAyABKAIEIgRBAnQiAmohASAAQQJ0IQwCQAJAIAAgD2siDSAEayIAIARJBEAgDiABIABBAnQiAhC/ASIWIAJqIQIgBEEBSCAAQQFIcg0BIAwgEGohAANAAkAgACABQXxqIgggAkF8aiIMIAwoAgAgCCgCABC3ASINGygCADYCACACIAwgDRshAiADIAggASANGyIBTw0AIABBfGohACACIBZLDQELCyAGKAIQIQgMAQsgDiADIAIQvwEiACACaiECIA0gBEpBACAEQQFOGw0BIAMhAQsgDiEADAYLIAogDGohDQNAIAMgASAAIAEoAgAgACgCABC3ASIMGygCADYCACAAIABBBGogDBshACADQQRqIQMgAUEEaiABIAwbIgEgDU8NBSACIABLDQALDAQLIAAgCRCrAQALIA8gABCtAQALQeirwgAgBSABEKwBAAtB6KvCACALIAEQrAEACyADIQELIAEgACACIABrQXxxEL8BGiAGKAIYIgAgBUsEQCAIIBNqIgEgBCAUajYCBCABIA82AgAgACALTQ0CIAggFWoiASABQQhqIAAgC0F/c2pBA3QQngEaIAYgAEF/aiIBNgIYIAFBAU0NAwwBCwtB+KvCACAFIAAQrAEAC0GnrsIAQR1BxK7CABC+AQALIAcNAAsgBigCFARAIAgQIQtFDQIgDhAhDAILIAYgBzYCDCAGIAZBDGo2AhAgCiAJIAZBEGpBAEEgIAlnaxAIDAELQQIhASAJQQJJDQAgCiAJQX5qIgBBAnRqIQIDQCAJIABJDQIgAiABEHcgAkF8aiECIAFBAWohASAAQX9qIgBBf0cNAAsLIAZBIGokAA8LIAAgCRCtAQALzwsCDH8CfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiIEIANPBEAgASgCACICIQMCQCAEIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAAn8gBCADQQFqIgMgBCADSxsiA0UEQEGwpsIAIQdBBCEFQQAMAQsgBkF/An8gA0EITwRAIAMgA0H/////AXFGBEAgA0EDdEEHbgwCCwwJCyADQQFqC0F/amd2IghBAWoiBBCoASAGKAIEIgVFDQcgBigCCCECIAYoAgAiAyAFENcBIgdFDQIgB0H/ASAIQQVqEMUBIAJqIQUgCCAEQQN2QQdsIAhBCEkbCyECIAYgASgCECIDNgIQIAYgAiADazYCDCAGIAU2AgggBiAHNgIEIAYgCDYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQ0gAygCAEF/c0GAgYKEeHEhCyABKAIIIQoDQAJAAkACQCALBEAgAiEDDAELA0AgAiANTw0CIApBQGshCiACKAIAIAJBBGoiAyECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyELC0EBIQIgBiAKIAtoQQF0QfAAcWoiDCgCACIFNgIwIAtBf2ohCUKlxoihyJyn+UshDgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIAZBMGogAmogAkEBaiECLQAAIQUMAAsACyABKQIAIQ8gASAGKQMANwIAIAZBCGoiAykDACEOIAMgAUEIaiIDKQIANwMAIAMgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiAPNwMAIABBADYCACAPpyIARQ0HIAZBMGogAEEBahCoASAGKAIEECEMBwsgByAGIA4QnAEiBGogDqdBGXYiAjoAACAIIARBfGpxIAdqQQRqIAI6AAAgBigCCCAEQQR0aiICIAwpAgA3AgAgAkEIaiAMQQhqKQIANwIAIAkgC3EhCyADIQIMAAsACyACQQFqIQlBACECA0ACQAJ/IAVBAXFFBEAgAiAJTw0CIAJBAWoiAyACSQ0CIAIhBSADDAELIAJBA2oiBSACSSIEDQEgBSAJSSIDRQ0BIAkgAkEEaiAEGyAJIAMbCyECIAEoAgQgBWoiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhBQwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJ4BGgwDCyADIAVBtPLCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIgwgA08Ef0EAIQMDQCADIgRBAWohAwJAIAQgASgCBGotAABBgAFHDQACQANAIAYgASgCCCAEQQR0aiIHKAIAIgU2AgBCpcaIocicp/lLIQ5BASECA0AgDiAFrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAGaiACQQFqIQItAAAhBQwBCwsgBCABKAIAIgogDqciDXEiAmsgASAOEJwBIgggAmtzIApxQQRJDQEgASgCBCIJIAhqIgItAAAgAiANQRl2IgI6AAAgCSAKIAhBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCEEEdGoiAikCACEPIAIgBykCADcCACACQQhqIgIpAgAhDiACIAdBCGoiAikCADcCACAHIA83AgAgAiAONwIADAELCyABKAIEIgIgBGpB/wE6AAAgAiABKAIAIARBfGpxakEEakH/AToAACABKAIIIAhBBHRqIgIgBykCADcCACACQQhqIAdBCGopAgA3AgAMAQsgASgCBCIFIARqIA1BGXYiAjoAACAFIAogBEF8anFqQQRqIAI6AAALIAMgDEcNAAsgASgCACICQQhJDQEgAkEBagUgDAtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQvgEAC6oLAgt/A34jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiAiADTwRAIAEoAgAiAyEEAkAgAiADQQFqQQN2QQdsIAQgA0EITxsiBEEBdksEQAJ/IAIgBEEBaiIDIAIgA0sbIgJFBEBBsKbCACEHQQAhAkEEDAELIAZBfwJ/IAJBCE8EQCACIAJB/////wFxRgRAIAJBA3RBB24MAgsMCQsgAkEBagtBf2pndiIJQQFqIgMQnwEgBigCBCICRQ0HIAYoAgghBCAGKAIAIgUgAhDXASIHRQ0CIAkgA0EDdkEHbCAJQQhJGyECIAdB/wEgCUEFahDFASAEagshAyAGIAEoAhAiBDYCECAGIAIgBGs2AgwgBiADNgIIIAYgBzYCBCAGIAk2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEKIAMoAgBBf3NBgIGChHhxIQUgASgCCCEDA0ACQCAFRQRAA0AgAiAKTw0CIANB4ABqIQMgAigCACACQQRqIgQhAkGAgYKEeHEiBUGAgYKEeEYNAAsgBUGAgYKEeHMhBSAEIQILIAZCpcaIocicp/lLNwMwIAMgBWhBA3ZBGGxqIgQoAgAgBCgCCCAGQTBqEHQgByAGIAYpAzAiDRCcASIIaiANp0EZdiILOgAAIAkgCEF8anEgB2pBBGogCzoAACAGKAIIIAhBGGxqIgggBCkCADcCACAIQQhqIAQpAgg3AgAgCEEQaiAEQRBqKQIANwIAIAVBf2ogBXEhBQwBCwsgASkCACENIAEgBikDADcCACAGQQhqIgIpAwAhDiACIAFBCGoiAikCADcDACACIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDTcDACAAQQA2AgAgDaciAEUNBSAGQTBqIABBAWoQnwEgBigCBBAhDAULIANBAWohBEEAIQNBACECA0ACQAJ/IANBAXFFBEAgAiAETw0CIAJBAWoiBSACSQ0CIAIhAyAFDAELIAJBA2oiAyACSSIFDQEgAyAESSIHRQ0BIAQgAkEEaiAFGyAEIAcbCyECIAEoAgQgA2oiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhAwwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJ4BGgwDCyAFIAJBtPLCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACICQQFqIgkgAk8Ef0EAIQMDQCADIgJBAWohAwJAIAIgASgCBGotAABBgAFHDQACQANAIAEoAgggBkKlxoihyJyn+Us3AwAgAkEYbGoiBCgCACAEKAIIIAYQdCACIAEoAgAiByAGKQMAIg2nIghxIgprIAEgDRCcASIFIAprcyAHcUEESQ0BIAEoAgQiCiAFaiILLQAAIAsgCEEZdiIIOgAAIAogByAFQXxqcWpBBGogCDoAAEH/AUcEQCABKAIIIAVBGGxqIgUpAgAhDSAFIAQpAgA3AgAgBUEQaiIHKQIAIQ4gByAEQRBqIgcpAgA3AgAgBUEIaiIFKQIAIQ8gBSAEQQhqIgUpAgA3AgAgBCANNwIAIAcgDjcCACAFIA83AgAMAQsLIAEoAgQiByACakH/AToAACAHIAEoAgAgAkF8anFqQQRqQf8BOgAAIAEoAgggBUEYbGoiAiAEKQIANwIAIAJBCGogBEEIaikCADcCACACQRBqIARBEGopAgA3AgAMAQsgASgCBCIEIAJqIAhBGXYiBToAACAEIAcgAkF8anFqQQRqIAU6AAALIAMgCUcNAAsgASgCACICQQhJDQEgAkEBagUgCQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQvgEAC50LAgt/A34jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiAiADTwRAIAEoAgAiAyEFAkAgAiADQQFqQQN2QQdsIAUgA0EITxsiBUEBdksEQAJ/IAIgBUEBaiIDIAIgA0sbIgJFBEBBsKbCACEHQQAhAkEEDAELIAZBfwJ/IAJBCE8EQCACIAJB/////wFxRgRAIAJBA3RBB24MAgsMCQsgAkEBagtBf2pndiIJQQFqIgMQnwEgBigCBCICRQ0HIAYoAgghBSAGKAIAIgQgAhDXASIHRQ0CIAkgA0EDdkEHbCAJQQhJGyECIAdB/wEgCUEFahDFASAFagshAyAGIAEoAhAiBTYCECAGIAIgBWs2AgwgBiADNgIIIAYgBzYCBCAGIAk2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEKIAMoAgBBf3NBgIGChHhxIQQgASgCCCEDA0ACQCAERQRAA0AgAiAKTw0CIANB4ABqIQMgAigCACACQQRqIgUhAkGAgYKEeHEiBEGAgYKEeEYNAAsgBEGAgYKEeHMhBCAFIQILIAZCpcaIocicp/lLNwMwIAMgBGhBA3ZBGGxqIgUgBkEwahB5IAcgBiAGKQMwIg0QnAEiCGogDadBGXYiCzoAACAJIAhBfGpxIAdqQQRqIAs6AAAgBigCCCAIQRhsaiIIIAUpAgA3AgAgCEEIaiAFQQhqKQIANwIAIAhBEGogBUEQaikCADcCACAEQX9qIARxIQQMAQsLIAEpAgAhDSABIAYpAwA3AgAgBkEIaiICKQMAIQ4gAiABQQhqIgIpAgA3AwAgAiAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA03AwAgAEEANgIAIA2nIgBFDQUgBkEwaiAAQQFqEJ8BIAYoAgQQIQwFCyADQQFqIQVBACEDQQAhAgNAAkACfyADQQFxRQRAIAIgBU8NAiACQQFqIgQgAkkNAiACIQMgBAwBCyACQQNqIgMgAkkiBA0BIAMgBUkiB0UNASAFIAJBBGogBBsgBSAHGwshAiABKAIEIANqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQMMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCeARoMAwsgBCACQbTywgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiAkEBaiIJIAJPBH9BACEDA0AgAyICQQFqIQMCQCACIAEoAgRqLQAAQYABRw0AAkADQCABKAIIIAZCpcaIocicp/lLNwMAIAJBGGxqIgUgBhB5IAIgASgCACIHIAYpAwAiDaciCHEiCmsgASANEJwBIgQgCmtzIAdxQQRJDQEgASgCBCIKIARqIgstAAAgCyAIQRl2Igg6AAAgCiAHIARBfGpxakEEaiAIOgAAQf8BRwRAIAEoAgggBEEYbGoiBCkCACENIAQgBSkCADcCACAEQRBqIgcpAgAhDiAHIAVBEGoiBykCADcCACAEQQhqIgQpAgAhDyAEIAVBCGoiBCkCADcCACAFIA03AgAgByAONwIAIAQgDzcCAAwBCwsgASgCBCIHIAJqQf8BOgAAIAcgASgCACACQXxqcWpBBGpB/wE6AAAgASgCCCAEQRhsaiICIAUpAgA3AgAgAkEIaiAFQQhqKQIANwIAIAJBEGogBUEQaikCADcCAAwBCyABKAIEIgUgAmogCEEZdiIEOgAAIAUgByACQXxqcWpBBGogBDoAAAsgAyAJRw0ACyABKAIAIgJBCEkNASACQQFqBSAJC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC+AQALjwsCDH8CfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiIEIANPBEAgASgCACICIQMCQCAEIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAAn8gBCADQQFqIgMgBCADSxsiA0UEQEGwpsIAIQdBBCEFQQAMAQsgBkF/An8gA0EITwRAIAMgA0H/////AXFGBEAgA0EDdEEHbgwCCwwJCyADQQFqC0F/amd2IghBAWoiBBCnASAGKAIEIgVFDQcgBigCCCECIAYoAgAiAyAFENcBIgdFDQIgB0H/ASAIQQVqEMUBIAJqIQUgCCAEQQN2QQdsIAhBCEkbCyECIAYgASgCECIDNgIQIAYgAiADazYCDCAGIAU2AgggBiAHNgIEIAYgCDYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQwgAygCAEF/c0GAgYKEeHEhCyABKAIIIQoDQAJAAkACQCALBEAgAiEDDAELA0AgAiAMTw0CIApBIGohCiACKAIAIAJBBGoiAyECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyELCyAGIAogC2hBOHFqIg0oAgAiBTYCMCALQX9qIQlCpcaIocicp/lLIQ5BASECA0AgDiAFrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBkEwaiACaiACQQFqIQItAAAhBQwACwALIAEpAgAhDyABIAYpAwA3AgAgBkEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA83AwAgAEEANgIAIA+nIgBFDQcgBkEwaiAAQQFqEKcBIAYoAgQQIQwHCyAHIAYgDhCcASIEaiAOp0EZdiICOgAAIAggBEF8anEgB2pBBGogAjoAACAGKAIIIARBA3RqIA0pAgA3AgAgCSALcSELIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBUEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEFIAMMAQsgAkEDaiIFIAJJIgQNASAFIAlJIgNFDQEgCSACQQRqIAQbIAkgAxsLIQIgASgCBCAFaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEFDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQngEaDAMLIAMgBUG08sIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDCADTwR/QQAhAwNAIAMiBEEBaiEDAkAgBCABKAIEai0AAEGAAUcNAAJAA0AgBiABKAIIIARBA3RqIgcoAgAiBTYCAEKlxoihyJyn+UshDkEBIQIDQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCACIAZqIAJBAWohAi0AACEFDAELCyAEIAEoAgAiCiAOpyINcSICayABIA4QnAEiCCACa3MgCnFBBEkNASABKAIEIgkgCGoiAi0AACACIA1BGXYiAjoAACAJIAogCEF8anFqQQRqIAI6AABB/wFHBEAgASgCCCAIQQN0aiICKQIAIQ4gAiAHKQIANwIAIAcgDkIgiDwABCAHIA4+AgAMAQsLIAEoAgQiAiAEakH/AToAACACIAEoAgAgBEF8anFqQQRqQf8BOgAAIAEoAgggCEEDdGogBykCADcCAAwBCyABKAIEIgUgBGogDUEZdiICOgAAIAUgCiAEQXxqcWpBBGogAjoAAAsgAyAMRw0ACyABKAIAIgJBCEkNASACQQFqBSAMC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC+AQALowsCDH8EfiMAQUBqIgQkAAJAAkACQCABKAIQIgNBAWoiBSADTwRAIAEoAgAiAiEDIAUgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEAgBCAFIANBAWoiAyAFIANLGxBVIARBCGooAgAhByAEKAIEIQogBCgCAEEBRgRAIAAgCjYCBCAAQQE2AgAgAEEIaiAHNgIADAULIARBEGooAgAhBSAEQQxqKAIAIQIgBCABKAIQIgM2AhAgBCAFIANrNgIMIAQgAjYCCCAEIAc2AgQgBCAKNgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDSADKAIAQX9zQYCBgoR4cSEMIAEoAgghCwNAAkACQAJAIAwEQCACIQMMAQsDQCACIA1PDQIgC0GgAWohCyACKAIAIAJBBGoiAyECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyEMCyAEIAsgDGhBA3ZBKGxqIggoAgAiBjYCMCAMQX9qIQlCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBEEwaiACaiACQQFqIQItAAAhBgwACwALIAEpAgAhDyABIAQpAwA3AgAgBEEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIARBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAEIA83AwAgAEEANgIAIA+nIgBFDQYgBEEwaiAAQQFqEKEBIAQoAgQQIQwGCyAHIAQgDhCcASIFaiAOp0EZdiICOgAAIAogBUF8anEgB2pBBGogAjoAACAEKAIIIAVBKGxqIAhBKBC/ARogCSAMcSEMIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBkEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEGIAMMAQsgAkEDaiIGIAJJIgUNASAGIAlJIgNFDQEgCSACQQRqIAUbIAkgAxsLIQIgASgCBCAGaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEGDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQEgAkEEaiACIAMQngEaDAILQbSmwgBBHEHQpsIAEL4BAAsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiINIANPBH9BACEDA0AgAyIFQQFqIQMCQCAFIAEoAgRqLQAAQYABRw0AAkADQCAEIAEoAgggBUEobGoiBygCACIGNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBq1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBGogAkEBaiECLQAAIQYMAQsLIAUgASgCACILIA6nIghxIgJrIAEgDhCcASIKIAJrcyALcUEESQ0BIAEoAgQiCSAKaiICLQAAIAIgCEEZdiICOgAAIAkgCyAKQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIApBKGxqIggpAwAhECAIIAcpAwA3AwAgCEEYaiICKQMAIREgAiAHQRhqIgkpAwA3AwAgCEEQaiICKQMAIQ8gAiAHQRBqIgYpAwA3AwAgCEEIaiICKQMAIQ4gAiAHQQhqIgIpAwA3AwAgByAQNwMAIAIgDjcDACAGIA83AwAgCSARNwMAIAhBIGoiAikCACEOIAIgB0EgaiICKQIANwIAIAIgDjcCAAwBCwsgASgCBCICIAVqQf8BOgAAIAIgASgCACAFQXxqcWpBBGpB/wE6AAAgASgCCCAKQShsaiAHQSgQvwEaDAELIAEoAgQiBiAFaiAIQRl2IgI6AAAgBiALIAVBfGpxakEEaiACOgAACyADIA1HDQALIAEoAgAiAkEISQ0BIAJBAWoFIA0LQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIARBQGskAAvoCgILfwJ+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgIgA08EQCABKAIAIgMhBQJAIAIgA0EBakEDdkEHbCAFIANBCE8bIgVBAXZLBEACfyACIAVBAWoiAyACIANLGyICRQRAQbCmwgAhB0EAIQJBBAwBCyAGQX8CfyACQQhPBEAgAiACQf////8BcUYEQCACQQN0QQduDAILDAkLIAJBAWoLQX9qZ3YiCUEBaiIDEKgBIAYoAgQiAkUNByAGKAIIIQUgBigCACIEIAIQ1wEiB0UNAiAJIANBA3ZBB2wgCUEISRshAiAHQf8BIAlBBWoQxQEgBWoLIQMgBiABKAIQIgU2AhAgBiACIAVrNgIMIAYgAzYCCCAGIAc2AgQgBiAJNgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohCiADKAIAQX9zQYCBgoR4cSEEIAEoAgghAwNAAkAgBEUEQANAIAIgCk8NAiADQUBrIQMgAigCACACQQRqIgUhAkGAgYKEeHEiBEGAgYKEeEYNAAsgBEGAgYKEeHMhBCAFIQILIAZCpcaIocicp/lLNwMwIAMgBGhBAXRB8ABxaiIFKAIAIAUoAgggBkEwahB0IAcgBiAGKQMwIg0QnAEiCGogDadBGXYiCzoAACAJIAhBfGpxIAdqQQRqIAs6AAAgBigCCCAIQQR0aiIIIAUpAgA3AgAgCEEIaiAFKQIINwIAIARBf2ogBHEhBAwBCwsgASkCACENIAEgBikDADcCACAGQQhqIgIpAwAhDiACIAFBCGoiAikCADcDACACIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDTcDACAAQQA2AgAgDaciAEUNBSAGQTBqIABBAWoQqAEgBigCBBAhDAULIANBAWohBUEAIQNBACECA0ACQAJ/IANBAXFFBEAgAiAFTw0CIAJBAWoiBCACSQ0CIAIhAyAEDAELIAJBA2oiAyACSSIEDQEgAyAFSSIHRQ0BIAUgAkEEaiAEGyAFIAcbCyECIAEoAgQgA2oiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhAwwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJ4BGgwDCyAEIAJBtPLCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACICQQFqIgkgAk8Ef0EAIQMDQCADIgJBAWohAwJAIAIgASgCBGotAABBgAFHDQACQANAIAEoAgggBkKlxoihyJyn+Us3AwAgAkEEdGoiBSgCACAFKAIIIAYQdCACIAEoAgAiByAGKQMAIg2nIghxIgprIAEgDRCcASIEIAprcyAHcUEESQ0BIAEoAgQiCiAEaiILLQAAIAsgCEEZdiIIOgAAIAogByAEQXxqcWpBBGogCDoAAEH/AUcEQCABKAIIIARBBHRqIgQpAgAhDSAEIAUpAgA3AgAgBEEIaiIEKQIAIQ4gBCAFQQhqIgQpAgA3AgAgBSANNwIAIAQgDjcCAAwBCwsgASgCBCIHIAJqQf8BOgAAIAcgASgCACACQXxqcWpBBGpB/wE6AAAgASgCCCAEQQR0aiICIAUpAgA3AgAgAkEIaiAFQQhqKQIANwIADAELIAEoAgQiBSACaiAIQRl2IgQ6AAAgBSAHIAJBfGpxakEEaiAEOgAACyADIAlHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAkLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAEL4BAAuhCgIMfwJ+IwBBQGoiBCQAAkACQAJAIAEoAhAiA0EBaiIFIANPBEAgASgCACICIQMgBSACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQCAEIAUgA0EBaiIDIAUgA0sbEFQgBEEIaigCACEHIAQoAgQhCCAEKAIAQQFGBEAgACAINgIEIABBATYCACAAQQhqIAc2AgAMBQsgBEEQaigCACEFIARBDGooAgAhAiAEIAEoAhAiAzYCECAEIAUgA2s2AgwgBCACNgIIIAQgBzYCBCAEIAg2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEMIAMoAgBBf3NBgIGChHhxIQsgASgCCCEKA0ACQAJAAkAgCwRAIAIhAwwBCwNAIAIgDE8NAiAKQSBqIQogAigCACACQQRqIgMhAkGAgYKEeHEiBUGAgYKEeEYNAAsgBUGAgYKEeHMhCwsgBCAKIAtoQThxaiINKAIAIgY2AjAgC0F/aiEJQqXGiKHInKf5SyEOQQEhAgNAIA4gBq1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIARBMGogAmogAkEBaiECLQAAIQYMAAsACyABKQIAIQ8gASAEKQMANwIAIARBCGoiAykDACEOIAMgAUEIaiIDKQIANwMAIAMgDjcCACAEQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBCAPNwMAIABBADYCACAPpyIARQ0GIARBMGogAEEBahCnASAEKAIEECEMBgsgByAEIA4QnAEiBWogDqdBGXYiAjoAACAIIAVBfGpxIAdqQQRqIAI6AAAgBCgCCCAFQQN0aiANKQIANwIAIAkgC3EhCyADIQIMAAsACyACQQFqIQlBACECA0ACQAJ/IAZBAXFFBEAgAiAJTw0CIAJBAWoiAyACSQ0CIAIhBiADDAELIAJBA2oiBiACSSIFDQEgBiAJSSIDRQ0BIAkgAkEEaiAFGyAJIAMbCyECIAEoAgQgBmoiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhBgwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0BIAJBBGogAiADEJ4BGgwCC0G0psIAQRxB0KbCABC+AQALIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDCADTwR/QQAhAwNAIAMiBUEBaiEDAkAgBSABKAIEai0AAEGAAUcNAAJAA0AgBCABKAIIIAVBA3RqIgcoAgAiBjYCAEKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCACIARqIAJBAWohAi0AACEGDAELCyAFIAEoAgAiCiAOpyINcSICayABIA4QnAEiCCACa3MgCnFBBEkNASABKAIEIgkgCGoiAi0AACACIA1BGXYiAjoAACAJIAogCEF8anFqQQRqIAI6AABB/wFHBEAgASgCCCAIQQN0aiICKQIAIQ4gAiAHKQIANwIAIAcgDkIgiD4CBCAHIA4+AgAMAQsLIAEoAgQiAiAFakH/AToAACACIAEoAgAgBUF8anFqQQRqQf8BOgAAIAEoAgggCEEDdGogBykCADcCAAwBCyABKAIEIgYgBWogDUEZdiICOgAAIAYgCiAFQXxqcWpBBGogAjoAAAsgAyAMRw0ACyABKAIAIgJBCEkNASACQQFqBSAMC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAEQUBrJAALvAkCGH8BfiMAQTBrIgMkACACQThqKAIAIAJBMGooAgAhCCACQSxqKAIAIAJBDGooAgAhCyACKAI0IRUgAigCKCEOIAIoAgAhFiACKAIEIQogASgCACIBKAIIIQ8gASgCACEQIANBCGogAkEQaigCACIHIAJBCGooAgAiAiABKAIEIhEgASgCDCISakEBamxqQQJ0ELYBIANBADYCKCADIAMpAwg3AyACQAJAIAIEQCAKIAJBFGxqIRcgCEEFdCEMIA5BdGohDSAPIBJBAnRqIRggECARQQJ0aiEZQQAhCAJAAkACQAJAAkACQANAIAgiBkEBaiEIIAoiCUEUaiEKIAwhAiANIQQgDiEBAkACfwJAA0AgAkUNASACQWBqIQIgBEEgaiEEIAEoAgghGiABQSBqIQEgBiAaRw0ACyAJKAIEIgIgBCgCAGoiASAFSQ0FIAcgAUkNBiAEQQRqKAIAIQQgA0EgaiADKAIoIAEgBWsQlQEgCyAFQQJ0aiALIAFBAnRqIgUgA0EgahBMIANBIGogAygCKCAREJUBIBAgGSADQSBqEEwgAiAEaiIGIAFJDQcgByAGSQ0IIANBIGogAygCKCAGIAFrEJUBIAUgCyAGQQJ0aiIEIANBIGoQTCADQSBqIAMoAiggEhCVASAPIBggA0EgahBMIAkoAggiAiAGSQ0JIAcgAkkNBCACIAZrDAELIAkoAggiAiAFSQ0BIAcgAkkNAyALIAVBAnRqIQQgAiAFawshASADQSBqIAMoAiggARCVASAEIAsgAkECdGogA0EgahBMIAkoAgghBSAKIBdGDQkMAQsLIAUgAhCtAQALIAIgBxCrAQALIAUgARCtAQALIAEgBxCrAQALIAEgBhCtAQALIAYgBxCrAQALIAYgAhCtAQALQQAhAgwBCyAHIAVPBEAgAygCKCECDAELIAUgBxCtAQALIANBIGogAiAHIAVrEJUBIAsgBUECdGogCyAHQQJ0aiADQSBqEEwCQCADKAIoIg1FDQBBACECIAMoAiAhBkEAIQkDQAJAAkACQCACIAZqIgwsAAAiCEF/SgRAIAhB/wFxIQQMAQsCfyAGIA1qIgEgDEEBakYEQEEAIQogAQwBCyAMLQABQT9xIQogDEECagshBCAIQR9xIQUCQAJAIAhB/wFxIgdB3wFNBEAgCiAFQQZ0ciEEDAELAn8gASAERgRAIAEhCEEADAELIARBAWohCCAELQAAQT9xCyAKQQZ0ciEEIAdB8AFJBEAgBCAFQQx0ciEEDAELIAEgCEYEf0EABSAILQAAQT9xCyAFQRJ0QYCA8ABxIARBBnRyciIEQYCAxABGDQELIARBgAFJDQFBAiEBIARBgBBJDQJBA0EEIARBgIAESRshAQwCC0GL68IAQStBuOvCABC+AQALQQEhASAEDQAgCUEBaiEJDAELIAlFBEBBACEJDAELIAYgAiAJa2ogDCABEJ4BGgsgDSABIAJqIgJLDQALIAlFDQAgAyANIAlrNgIoCyADQRhqIANBKGooAgAiATYCACADIAMpAyAiGzcDECAAIBY2AgAgACAbNwIEIABBDGogATYCAARAIA4QIQsEQCAVECELIANBMGokAAvJCgENfyMAQaACayIHJAAgByACNgJAIAcgAjYCPCAHIAE2AjggB0EoaiAHQThqEIkBIAcoAighASAHKAIsIQogByAENgJAIAcgBDYCPCAHIAM2AjggB0EgaiAHQThqEJEBIAcoAiQhCSAHKAIgIQ0gByAGNgJAIAcgBjYCPCAHIAU2AjggB0EYaiAHQThqEIkBIAcoAhwhDiAHKAIYIQsCQAJAAkACQBDHASIEBEAgByAHLwE0OwE2IAQoAgANAiAEQX82AgAgBEEEaiAAEFciDEUNAyAMELUBIAdB5ABqQQE7AQAgB0HgAGoiD0EANgIAIAdB2ABqQoCAgIAQNwMAIAdB1ABqIAk2AgAgB0HQAGpBADYCACAHQcwAaiAJNgIAIAdByABqIA02AgAgB0HEAGogCTYCACAHQfQAaiALIA5BAnRqNgIAIAdB5gBqIAcvATY7AQAgB0EANgJAIAcgASAKQQJ0ajYCPCAHIAE2AjggB0IANwN4IAcgCzYCcCAHQgA3A2ggCkUNASAMQRRqIQMgB0FAayEQIAcgAUEEajYCOCAHQYgBaiERQQAhAiABIQYDQCACQf8BcQ0CIAYoAgAhEgJ/AkACQCAHKAJUIgAgBygCUCICSQ0AIAcoAkwgAEkNACAHQRBqIAcoAlwgEGpBH2otAAAgBygCSCIIIAJqIAAgAmsQRSAHKAIQQQFGBEAgBygCFCEGA0AgByAGIAcoAlBqQQFqIgA2AlACQCAAIAcoAlwiAkkEQCAHKAJMIQUMAQsgBygCTCIFIABJDQAgAkEFTw0LIAAgAmsiBiAHKAJIaiITIA9GDQQgEyAPIAIQugFFDQQLIAcoAlQiBiAASSAFIAZJcg0CIAdBCGogAiAQakEfai0AACAHKAJIIABqIAYgAGsQRSAHKAIMIQYgBygCCEEBRg0ACwsgByAHKAJUNgJQCyAHLQBlDQQCQCAHLQBkBEAgBygCRCECIAcoAkAhBgwBCyAHKAJEIgIgBygCQCIGRg0FCyAHQQE6AGUgAiAGayEAIAcoAkggBmoMAQsgBygCQCECIAcgADYCQCAGIAJrIQAgAiAIagshAiAHKAJwIgUgBygCdEYNAiAHIAVBBGo2AnAgBSgCACEFIAdBwAFqIAIgACACahAjIAdB8AFqIAdBwAFqEGMgB0HAAWogB0HwAWogAxA+IAdB8AFqIAdBwAFqIAMQJiAHQcABaiAHQfABaiADECAgB0HwAWogB0HAAWoQPCAHKAL4ASIABEAgBygC8AEhBiAAQRRsIQIgBygCkAIhACAHKAKIAiEIA0AgBiAIIAAgAxBJIAZBFGohBiACQWxqIgINAAsLIAdBwAFqIAdB8AFqQTAQvwEaIAdB8AFqIAdBwAFqIAMQUiAHKAL4ASIABEAgBygC8AEhBiAAQRRsIQIgBygCkAIhACAHKAKIAiEIA0AgBiAIIAAgAxBIIAZBFGohBiACQWxqIgINAAsLIAdBwAFqIAdB8AFqQTAQvwEaIAcgEjYChAEgB0EANgKAASARIAdBwAFqQTAQvwEaIAcgBTYCuAEgDCAHQYABahAXIAcoAjgiBiAHKAI8Rg0CIAcgBkEEajYCOCAHLQBlIQIMAAsAC0H8xcIAQcYAIAdBOGpB5MbCABCbAQALIAQgBCgCAEEBajYCACAJBEAgDRAhCyAOQf////8DcQRAIAsQIQsgCkH/////A3EEQCABECELIAdBoAJqJAAPC0HCxsIAQRAgB0E4akHUxsIAEJsBAAtBi+vCAEErQbjrwgAQvgEACyACQQQQqwEAC5QKAhR/AX4jAEHgAGsiAyQAIAEgACgCACINNgIAAkAgACgCsAFFBEAgAEF/NgKwASADQRxqIAFBNGooAgA2AgAgA0EUaiABQShqKAIANgIAIANBDGogAUEcaigCADYCACADIAFBLGooAgA2AhggAyABQSBqKAIANgIQIAMgAUEUaigCADYCCCADIAFBEGooAgA2AgQgAyABKAIINgIAIANBQGsgAxAfIABBtAFqIgIgAigCAEEBajYCACADKAJAIQogAygCSCICRQ0BIAogAkEMbGohEiAAQbgBaiEPIABBvAFqIRMgAEHEAWohECAAQcABaiEUIABByAFqIREgCiEJA0AgA0HYAGogCUEIaigCADYCACADIAkpAgA3A1AgA0KlxoihyJyn+Us3AwAgA0HQAGogAxB5IAMpAwAiFkIZiKdB/wBxIgRBCHQgBHIiAkEQdCACciELIAlBDGohCSAAKAK4ASIHIBancSECIAAoAsABIQ4gACgCvAEhFUEAIQgCQAJAA0ACQCACIBVqKAAAIgwgC3MiBkF/cyAGQf/9+3dqcUGAgYKEeHEiBkUNAANAAkAgDiAGaEEDdiACaiAHcUEYbGoiBSADQdAAakYEQCADQdAAaiEFDAELIAUgA0HQAGpBDBC6AUUNACAGQX9qIAZxIgYNAQwCCwsgBUEMaiEIIAUoAhQhBEEAIQdBACECA0ACQCAEIAJLBEAgAkEBaiIGIAJPDQELIAUoAhAgBEYEQCAIIARBARCGASAFKAIUIQQLIAUoAgwgBEECdGogDTYCACAFIAUoAhRBAWo2AhQMBAsgCCgCACIMIAdqIQsgB0EEaiEHIAYhAgJAQX8gDSALKAIAIgtHIA0gC0kbDgIEAQALCyAEIAZBf2pPBEAgBSgCECAERgR/IAggBEEBEIYBIAgoAgAFIAwLIAdqIgIgAkF8aiICIARBAnQgB2tBBGoQngEaIAIgDTYCACAFIARBAWo2AhQMAwtB5K3CAEEeQYSuwgAQvgEACyAIQQRqIgggAmogB3EhAiAMIAxBAXRxQYCBgoR4cUUNAAsgACgCxAFFBEAgAyAPEBALIAMoAlghBSADKAJUIQcgAygCUCEIQQRBBBDXASIGRQ0BIAYgDTYCACAQIBAoAgAgEygCACIMIA8gFhCcASICaiILLQAAQQFxazYCACAUKAIAIAsgBDoAACAMIAAoArgBIAJBfGpxakEEaiAEOgAAIAJBGGxqIgJCgYCAgBA3AhAgAiAGNgIMIAIgBTYCCCACIAc2AgQgAiAINgIAIBEgESgCAEEBajYCAAsgCSASRw0BDAMLC0EEQQRBtPLCACgCACIAQQIgABsRAAAAC0HCxsIAQRAgA0G4qsIAEJsBAAsgAygCRARAIAoQIQsgACAAKAKwAUEBajYCsAEgAyABQTwQvwEhCQJAIABBDGooAgAiASAAQQhqKAIARgRAAkACQCABQQFqIgIgAUkNACABQQF0IgogAiAKIAJLGyIKrUI8fiIWQiCIpw0AIBanIgJBAE4NAQsQ0wEACwJ/IAFFBEAgAkEEENcBDAELIAAoAgQgAUE8bEEEIAIQ1AELIgFFDQEgACAKNgIIIAAgATYCBCAAKAIMIQELIAAoAgQgAUE8bGogCUE8EL8BGiAAIAAoAgxBAWo2AgwgACAAKAIAQQFqNgIAIAlB4ABqJAAPCyACQQRBtPLCACgCACIAQQIgABsRAAAAC+0IAgx/AX4jAEHQA2siBCQAAkAQxwEiAwRAAkAgAygCAEUEQCADQX82AgAgA0EEaiAAEGAEQCAEIAA2AghCpcaIocicp/lLIQ1BASECIAAhAQNAIA0gAa1C/wGDhUKzg4CAgCB+IQ0gAkEERkUEQCAEQQhqIAJqIAJBAWohAi0AACEBDAELCyANpyIBQRl2IgJBCHQgAnIiAkEQdCACciEJIAMoAgQiBiABcSECIAMoAgwhCiADKAIIIQgDQAJAAkAgAiAIaigAACIHIAlzIgFBf3MgAUH//ft3anFBgIGChHhxIgFFDQADQCAAIAogAWhBA3YgAmogBnEiC0HsAWxqIgwoAgBHBEAgAUF/aiABcSIBDQEMAgsLQYABIQIgCCALQewBbCIBQewBbSIFaiIHKAAAIgkgCUEBdHFBgIGChHhxaEEDdiAIIAVBfGogBnFqIgUoAAAiBiAGQQF0cUGAgYKEeHFnQQN2akEDTQRAIAMgAygCEEEBajYCEEH/ASECCyAHIAI6AAAgBUEEaiACOgAAIAMgAygCFEF/ajYCFCABIApqIgIoAgghASAMKAIEIQUgBEHwAWogAkEMakHgARC/ARogAUUEQEEAIQEMAgsgBCAFNgIIIARBEGogBEHwAWpB4AEQvwEaDAELIAVBBGoiBSACaiAGcSECQQAhASAHIAdBAXRxQYCBgoR4cUUNAQsLIAQgATYCDCAEQQhqEHogAyADKAIAQQFqNgIAAkACQBDGASIDBEAgAygCAA0CIANBfzYCACADQQRqIAAQX0UNASAEIAA2AghCpcaIocicp/lLIQ1BASECIAAhAQNAIA0gAa1C/wGDhUKzg4CAgCB+IQ0gAkEERkUEQCAEQQhqIAJqIAJBAWohAi0AACEBDAELCyANpyIBQRl2IgJBCHQgAnIiAkEQdCACciEJIAMoAgQiBiABcSECIAMoAgwhCiADKAIIIQhBACEFAkADQAJAAkAgAiAIaigAACIHIAlzIgFBf3MgAUH//ft3anFBgIGChHhxIgFFDQADQCAAIAogAWhBA3YgAmogBnEiC0EEdGooAgBHBEAgAUF/aiABcSIBDQEMAgsLQYABIQIgCCALQQR0IgBBBHUiAWoiBSgAACIHIAdBAXRxQYCBgoR4cWhBA3YgCCABQXxqIAZxaiIBKAAAIgYgBkEBdHFBgIGChHhxZ0EDdmpBA00EQCADIAMoAhBBAWo2AhBB/wEhAgsgBSACOgAAIAFBBGogAjoAACADIAMoAhRBf2o2AhQgACAKaiIAKAIEIgFFDQEgBCAAKQIINwIMIAQgATYCCAwDCyAFQQRqIgUgAmogBnEhAiAHIAdBAXRxQYCBgoR4cUUNAQsLIARBADYCCAsgBEEIahCqASADIAMoAgBBAWo2AgAgBEHQA2okAA8LDAQLENYBAAsMBAsQ1gEACwwCCwtB/MXCAEHGACAEQQhqQdyowgAQmwEAC0HCxsIAQRAgBEEIakHMqMIAEJsBAAvHCQINfwN+IwBBsAlrIgUkACAFIABBiAEQvwEhAQJAAkACQEH4xcIAKAIAEQkAIgMEQCABKAIAIQAgAUGIAWogAUEEckGEARC/ARogAygCAA0CIANBfzYCACADQQRqIgkgACgCABBgDQEgAUHIB2oQZUEEQQQQ1wEiBUUNAyAFQdsANgIAQQRBBBDXASICRQ0DIAJB3QA2AgAgAUGYAmpCADcDACABQQo2AqACIAFCgICAgMAANwOQAiABQaQCaiABQcgHakGEARC/ASEGIAFB4ANqQgA3AwAgAUHYA2pCgICAgMAANwMAIAFB0ANqQgQ3AwAgAUHMA2pBsKbCADYCACABQcgDakEANgIAIAFBuANqQoGAgIAQNwMAIAFBtANqIAI2AgAgAUGsA2pCgYCAgBA3AgAgAUHoA2pCADcDACABQgA3A8ADIAEgBTYCqAMgBhCSASAGIAFBiAFqQYQBEL8BGiAAKAIAIQUgAUHgBWogAUGQAmpB6AEQvwEaIAEgBTYCyAdCpcaIocicp/lLIQ5BASECIAUhAANAIA4gAK1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAFByAdqIAJqIAJBAWohAi0AACEADAELCyAOQhmIpyIMQf8AcSIAQQh0IAByIgBBEHQgAHIhDSADKAIEIgggDqdxIQIgAygCDCEGIAMoAgghAAJAA0ACQCAAIAJqKAAAIgogDXMiBEF/cyAEQf/9+3dqcUGAgYKEeHEiBEUNAANAIAUgBiAEaEEDdiACaiAIcSILQewBbGooAgBHBEAgBEF/aiAEcSIEDQEMAgsLIAFByAdqIAFB4AVqQegBEL8BGiAGIAtB7AFsakEEaiEIQQAhAgNAIAIgCGoiACkCACEOIAAgAUHIB2ogAmoiBSkCADcCACAFIA43AgAgAEEIaiIEKQIAIQ4gBCAFQQhqIgQpAgA3AgAgAEEQaiIHKQIAIQ8gByAFQRBqIgcpAgA3AgAgAEEYaiIAKQIAIRAgACAFQRhqIgApAgA3AgAgACAQNwIAIAcgDzcCACAEIA43AgAgAkFAayACQSBqIQJB6QFJDQALIAYgC0HsAWxqIgApAuQBIQ4gACABQagJaiIAKQMANwLkASAAIA43AwAgAUH4A2ogAUHIB2pB6AEQvwEaDAILIAdBBGoiByACaiAIcSECIAogCkEBdHFBgIGChHhxRQ0ACyABQcgHaiABQeAFakHoARC/ARogACAJIA4QnAEiAmotAABBAXEhBCADKAIQIgcgBEVyRQRAIAFB+ANqIAkQDCAJIA4QnAEhAiADKAIEIQggAygCDCEGIAMoAhAhByADKAIIIQALIAMgByAEazYCECAAIAJqIAxB/wBxIgQ6AAAgCCACQXxqcSAAakEEaiAEOgAAIAYgAkHsAWxqIgAgBTYCACAAQQRqIAFByAdqQegBEL8BGiADIAMoAhRBAWo2AhQgAUEANgL8AwsgAUH4A2oQeiADIAMoAgBBAWo2AgAgAUGwCWokAA8LIAFBBHIQkgFB/MXCAEHGACABQcgHakHcqMIAEJsBAAsQ1gEAC0HCxsIAQRAgAUHIB2pBzKjCABCbAQALQQRBBEG08sIAKAIAIgBBAiAAGxEAAAALrggBBn8jAEHwAGsiBCQAIAQgAzYCDCAEIAI2AghBASEIIAEhBgJAIAFBgQJJDQBBACABayEHQYACIQUDQAJAIAUgAU8NACAAIAVqLAAAQb9/TA0AQQAhCCAFIQYMAgsgBUF/aiEGQQAhCCAFQQFGDQEgBSAHaiAGIQVBAUcNAAsLIAQgBjYCFCAEIAA2AhAgBEEAQQUgCBs2AhwgBEGG6cIAQc6DwAAgCBs2AhgCQAJAAkAgAiABSyIFIAMgAUtyRQRAIAIgA0sNAQJAIAJFIAEgAkZyRQRAIAEgAk0NASAAIAJqLAAAQUBIDQELIAMhAgsgBCACNgIgIAJFIAEgAkZyDQIgAUEBaiEDA0AgAiABSQRAIAAgAmosAABBQE4NBAsgAkF/aiEFIAJBAUYNBCACIANGIAUhAkUNAAsMAwsgBCACIAMgBRs2AiggBEHEAGpBAzYCACAEQdwAakEENgIAIARB1ABqQQQ2AgAgBEIDNwI0IARB1IPAADYCMCAEQQU2AkwgBCAEQcgAajYCQCAEIARBGGo2AlggBCAEQRBqNgJQIAQgBEEoajYCSCAEQTBqQeyDwAAQxAEACyAEQeQAakEENgIAIARB3ABqQQQ2AgAgBEHUAGpBBTYCACAEQcQAakEENgIAIARCBDcCNCAEQfyDwAA2AjAgBEEFNgJMIAQgBEHIAGo2AkAgBCAEQRhqNgJgIAQgBEEQajYCWCAEIARBDGo2AlAgBCAEQQhqNgJIIARBMGpBnITAABDEAQALIAIhBQsCQCABIAVGDQBBASEGAkACQAJAIAAgBWoiBywAACICQX9MBEBBACEIIAAgAWoiAyEBIAMgB0EBakcEQCAHLQABQT9xIQggB0ECaiEBCyACQR9xIQcgAkH/AXFB3wFLDQEgCCAHQQZ0ciEBDAILIAQgAkH/AXE2AiQgBEEoaiECDAILQQAhACADIQYgASADRwR/IAFBAWohBiABLQAAQT9xBSAACyAIQQZ0ciEAIAJB/wFxQfABSQRAIAAgB0EMdHIhAQwBC0EAIQIgAyAGRwR/IAYtAABBP3EFIAILIAdBEnRBgIDwAHEgAEEGdHJyIgFBgIDEAEYNAgsgBCABNgIkQQEhBiAEQShqIQIgAUGAAUkNAEECIQYgAUGAEEkNAEEDQQQgAUGAgARJGyEGCyAEIAU2AiggBCAFIAZqNgIsIARBxABqQQU2AgAgBEHsAGpBBDYCACAEQeQAakEENgIAIARB3ABqQQY2AgAgBEHUAGpBBzYCACAEQgU3AjQgBEGshMAANgIwIAQgAjYCWCAEQQU2AkwgBCAEQcgAajYCQCAEIARBGGo2AmggBCAEQRBqNgJgIAQgBEEkajYCUCAEIARBIGo2AkggBEEwakHUhMAAEMQBAAtBi+vCAEErQbjrwgAQvgEAC6UIAQh/IwBBQGoiAyQAIANBJGogATYCACADQTRqIAJBFGooAgAiBDYCACADQQM6ADggA0EsaiACKAIQIgUgBEEDdGo2AgAgA0KAgICAgAQ3AwggAyAANgIgIANBADYCGCADQQA2AhAgAyAFNgIwIAMgBTYCKAJAAkACQAJAIAIoAggiBkUEQCACKAIAIQggAigCBCIJIAQgBCAJSxsiBkUNAUEBIQQgACAIKAIAIAgoAgQgASgCDBEDAA0EIAhBCGohAkEBIQcDQCAFKAIAIANBCGogBUEEaigCABEBAARADAYLIAcgBk8NAiACQQRqIQAgAigCACEBIAVBCGohBSACQQhqIQIgB0EBaiEHIAMoAiAgASAAKAIAIAMoAiQoAgwRAwBFDQALDAQLIAIoAgAhCCACKAIEIgkgAkEMaigCACICIAIgCUsbIgpFDQBBASEEIAAgCCgCACAIKAIEIAEoAgwRAwANAyAGQRBqIQUgCEEIaiECQQEhBwNAIAMgBUF4aigCADYCDCADIAVBEGotAAA6ADggAyAFQXxqKAIANgIIQQAhAUEAIQQCQAJAAkACQCAFQQhqKAIAQQFrDgMBAgMACyAFQQxqKAIAIQBBASEEDAILIAVBDGooAgAiBiADKAI0IgRJBEBBACEEIAMoAjAgBkEDdGoiBigCBEEIRw0CIAYoAgAoAgAhAEEBIQQMAgtBxIXAACAGIAQQrAEACyADKAIoIgYgAygCLEYNACADIAZBCGo2AiggBigCBEEIRw0AIAYoAgAoAgAhAEEBIQQLIAMgADYCFCADIAQ2AhACQAJ/AkACQAJAAkACQCAFKAIAQQFrDgMBAAYECyADKAIoIgAgAygCLEcNAQwFCyAFQQRqKAIAIgAgAygCNCIETw0BIAMoAjAgAEEDdGoiACgCBEEIRw0EIAAoAgAoAgAMAwsgAyAAQQhqNgIoIAAoAgRBCEcNAyAAKAIAKAIADAILQcSFwAAgACAEEKwBAAsgBUEEaigCAAshBEEBIQELIAMgBDYCHCADIAE2AhgCQCAFQXBqKAIAQQFHBEAgAygCKCIEIAMoAixGDQQgAyAEQQhqNgIoDAELIAVBdGooAgAiACADKAI0IgFPDQQgAygCMCAAQQN0aiEECyAEKAIAIANBCGogBEEEaigCABEBAARAQQEhBAwFCyAHIApPDQEgAkEEaiEAIAIoAgAhASAFQSRqIQUgAkEIaiECQQEhBCAHQQFqIQcgAygCICABIAAoAgAgAygCJCgCDBEDAEUNAAsMAwsgCSAHSwRAQQEhBCADKAIgIAggB0EDdGoiACgCACAAKAIEIAMoAiQoAgwRAwANAwtBACEEDAILQYvrwgBBK0G468IAEL4BAAtB1IXAACAAIAEQrAEACyADQUBrJAAgBAuKCAIPfwF+IwBBoAJrIgIkACACQZgBahBlIAJBATYCmAFBnLHCACEBA0AgAkGYAWogASgCACABQQRqKAIAIAFBCGooAgAgAUEMaigCABCEASABQRBqIgFB/LTCAEcNAAtB/LTCACEBA0AgAkGYAWogASgCACABQQRqKAIAIAFBCGooAgAgAUEMaigCABCFASABQRBqIgFBvLnCAEcNAAtBvLnCACEBA0AgAkGYAWogAUEEaigCACABQQhqKAIAIAEtAAAQbCABQQxqIgFBvL/CAEcNAAsgAkGYAWpBBHIhBUHIr8IAIQEDQCAFIAFBBGooAgAgAS0AABA9IAFBCGoiAUGYscIARw0AC0G8v8IAIQEDQCAFIAFBBGooAgAgAS0AABA9IAFBCGoiAUHcwcIARw0ACyACQRBqIAJBmAFqQYQBEL8BGiACIAA2ApQBIAIgAkGUAWo2ApgBIAJBmAFqQQRyIAJBEGpBhAEQvwEaIAJBmAFqEBkQxgEiAwRAIAMoAgBFBEAgA0F/NgIAIANBBGoiCSACKAKUASIFEF9FBEAgAkEIakEKEKQBIAIoAgwhCiACKAIIIQsgAiAFNgKYAUKlxoihyJyn+UshEEEBIQEgBSEAA0AgECAArUL/AYOFQrODgICAIH4hECABQQRGRQRAIAJBmAFqIAFqIAFBAWohAS0AACEADAELCyAQQhmIpyINQf8AcSIAQQh0IAByIgBBEHQgAHIhDiADKAIEIgcgEKdxIQEgAygCDCEIIAMoAgghAAJAA0ACQCAAIAFqKAAAIgwgDnMiBEF/cyAEQf/9+3dqcUGAgYKEeHEiBEUNAANAIAUgCCAEaEEDdiABaiAHcSIPQQR0aigCAEcEQCAEQX9qIARxIgQNAQwCCwsgAkGgAWogCCAPQQR0aiIAKAIMNgIAIABBADYCDCAAKQIEIRAgACAKNgIIIAAgCzYCBCACIBA3A5gBDAILIAZBBGoiBiABaiAHcSEBIAwgDEEBdHFBgIGChHhxRQ0ACyAAIAkgEBCcASIBai0AAEEBcSEEIAMoAhAiBiAERXJFBEAgAkGYAWogCRAOIAkgEBCcASEBIAMoAgQhByADKAIMIQggAygCECEGIAMoAgghAAsgAyAGIARrNgIQIAAgAWogDUH/AHEiBDoAACAHIAFBfGpxIABqQQRqIAQ6AAAgCCABQQR0aiIAQQA2AgwgACAKNgIIIAAgCzYCBCAAIAU2AgAgAyADKAIUQQFqNgIUIAJBADYCmAELIAJBmAFqEKoBIAMgAygCAEEBajYCACACQaACaiQADwsQ1gEAC0HCxsIAQRAgAkGYAWpBzKjCABCbAQALQfzFwgBBxgAgAkGYAWpB3KjCABCbAQALqAYBDX8gASgCECECIAAoAgQhAyAAKAIAIQUCQAJAAkAgASgCCCINQQFHBEAgAg0BDAMLIAJFDQELAkAgA0UEQEEAIQMMAQsgAyAFaiEHIAFBFGooAgBBAWohCiAFIgAhCwNAIABBAWohBAJAAn8gACwAACICQX9MBEACfyAEIAdGBEBBACEIIAcMAQsgAC0AAUE/cSEIIABBAmoiBAshACACQR9xIQkgCCAJQQZ0ciACQf8BcSIOQd8BTQ0BGgJ/IAAgB0YEQEEAIQwgBwwBCyAALQAAQT9xIQwgAEEBaiIECyECIAwgCEEGdHIhCCAIIAlBDHRyIA5B8AFJDQEaAn8gAiAHRgRAIAQhAEEADAELIAJBAWohACACLQAAQT9xCyAJQRJ0QYCA8ABxIAhBBnRyciICQYCAxABHDQIMBAsgAkH/AXELIQIgBCEACyAKQX9qIgoEQCAGIAtrIABqIQYgACELIAAgB0cNAQwCCwsgAkGAgMQARg0AAkAgBkUgAyAGRnJFBEBBACEAIAYgA08NASAFIAZqLAAAQUBIDQELIAUhAAsgBiADIAAbIQMgACAFIAAbIQULIA0NAAwBC0EAIQQgAwRAIAMhAiAFIQADQCAEIAAtAABBwAFxQYABRmohBCAAQQFqIQAgAkF/aiICDQALCyADIARrIAEoAgwiB08NAEEAIQZBACEEIAMEQCADIQIgBSEAA0AgBCAALQAAQcABcUGAAUZqIQQgAEEBaiEAIAJBf2oiAg0ACwsgBCADayAHaiECAkACQAJAQQAgAS0AMCIAIABBA0YbQQFrDgMAAQACCyACIQZBACECDAELIAJBAXYhBiACQQFqQQF2IQILIAZBAWohAAJ/AkADQCAAQX9qIgAEQCABKAIYIAEoAgQgASgCHCgCEBEBAEUNAQwCCwsgASgCBCEEQQEgASgCGCAFIAMgASgCHCgCDBEDAA0BGiACQQFqIQAgASgCHCEFIAEoAhghAQNAIABBf2oiAEUEQEEADwsgASAEIAUoAhARAQBFDQALC0EBCw8LIAEoAhggBSADIAFBHGooAgAoAgwRAwAL/QYCFX8BfiMAQeABayICJAAgAiABNgIAIAEoAiAhCSACIAEtACQ6AAcgAiACNgIMIAIgAkEHajYCCCABLQAlRQRAIAFBFGohBCACQfgAaiABIAFBEGoiCiABQQxqIgsQAAJAIAIoAnwiBkUNACAJQQF0IQwgAkG0AWohDSACQYABaiEIA0ACQCACQUBrIgMgCEEIaikCADcDACACQcgAaiIFIAhBEGopAgA3AwAgAkHQAGoiByAIQRhqKQIANwMAIAIgCCkCADcDOCACKAJ4IQ4gAigCoAEhDyACKAKkASEQIAIoAqgBIREgAigCrAEhEiACKAKwASETIAJBEGogDUEoEL8BGiACQeAAaiIUIAMpAwA3AwAgAkHoAGoiFSAFKQMANwMAIAJB8ABqIhYgBykDADcDACACIAIpAzg3A1ggAkH4AGogAkEQakEoEL8BGiABKAIcIgMgASgCGEYEQAJAAkAgA0EBaiIFIANJDQAgA0EBdCIHIAUgByAFSxsiB61C5AB+IhdCIIinDQAgF6ciBUEATg0BCxDTAQALAn8gA0UEQCAFQQQQ1wEMAQsgBCgCACADQeQAbEEEIAUQ1AELIgNFDQEgASAHNgIYIAEgAzYCFCABKAIcIQMLIAEoAhQgA0HkAGxqIgMgBjYCBCADIA42AgAgAyASNgI0IAMgDzYCKCADQQhqIAIpA1g3AgAgA0EQaiAUKQMANwIAIANBGGogFSkDADcCACADQSBqIBYpAwA3AgAgA0E4aiATNgIAIANBMGogETYCACADQSxqIBA2AgAgA0E8aiACQfgAakEoEL8BGiABIAEoAhxBAWoiAzYCHCADIAxPBEAgAkEIaiAEEAogBCAJELwBCyACQfgAaiABIAogCxAAIAIoAnwiBg0BDAILCyAFQQRBtPLCACgCACIAQQIgABsRAAAACyACQQhqIAQQCiAEIAkQvAEgASgCHCIEQQF2IgYEQCABKAIUIgMgBEHkAGxqQZx/aiEEA0AgAkH4AGogA0HkABC/ARogAyAEQeQAEJ4BQeQAaiEDIAQgAkH4AGpB5AAQvwFBnH9qIQQgBkF/aiIGDQALCyABQQE6ACULAkAgAUEcaigCACIDBEAgASADQX9qIgM2AhwgACABKAIUIANB5ABsakHkABC/ARoMAQsgAEEANgIECyACQeABaiQAC9UGAg5/An4jAEEQayIGJAAgASgCBCIIQRRsIQMgASgCACEFAkAgCEUEQAwBCyAFQQhqIQQgAyECA0AgBCgCACAEQXxqKAIAayAHaiEHIARBFGohBCACQWxqIgINAAsLAkAgB61CDH4iEEIgiKdFBEAgEKciAkF/SgRAAkACQCACRQRAQQQhCQwBCyACQQQQ1wEiCUUNAQsgCEUEQEEAIQIMBAsgAyAFaiENIAFBFGooAgAhDCABKAIQIQ5BACECA0ACQAJAAkACQAJAIAUoAggiASAFKAIEIgpPBEAgDCABSQ0BIAEgCmsiCEUNBUEBIQQgAkEBdCEDIAJBDGwhASAOIApBAnRqIQoDQCAGQQA2AgggBkIANwIAIAYgBCAKIAQQdgJ/IARBA0YEQCAIRQ0FIApBBGohCiAIQX9qIQhBAwwBCyAEQQFqCyEEIAYoAgAiD0GAgMQARg0GIAYpAgQhECACIAdGBEAgAkEBaiIHIAJJDQYgAyAHIAMgB0sbIgetQgx+IhFCIIinDQYgEaciC0EASA0GAn8gAkUEQCALQQQQ1wEMAQsgCSABQQQgCxDUAQsiCUUNBQsgASAJaiILIA82AgAgC0EEaiAQNwIAIANBAmohAyABQQxqIQEgAkEBaiECIAggBE8NAAsMBQsgCiABEK0BAAsgASAMEKsBAAtBAUEAEK0BAAsgC0EEQbTywgAoAgAiAEECIAAbEQAAAAsQ0wEACyANIAVBFGoiBUcNAAsMAwsgAkEEQbTywgAoAgAiAEECIAAbEQAAAAsQ2AEACxDYAQALIAkgAiAGQQBBICACZ2sQBgJAIAJBAkkEQCACIQMMAQsgCUEMaiEBQQEhA0EBIQQDQAJAIAQgA0F/aiIFRg0AIAEgCSAFQQxsakEMELoBRQ0AIAMgBEcEQCAGQQhqIgggAUEIaiIFKAIANgIAIAYgASkCADcDACAFIAkgA0EMbGoiBUEIaiIKKAIANgIAIAEgBSkCADcCACAKIAgoAgA2AgAgBSAGKQMANwIACyADQQFqIQMLIAFBDGohASACIARBAWoiBEcNAAsgAyACTQ0AQbWtwgBBHEHUrcIAEL4BAAsgACADNgIIIAAgBzYCBCAAIAk2AgAgBkEQaiQAC54GAQ1/IAEoAgAhBwJ/QQAgASgCCCIERQ0AGiAHIARBFGxqIQ4CQAJAA0AgByIIKAIIIgkgCCgCBCIDTwRAIAkgASgCICIESw0CIAEoAhghDSAJIANrIQ8gA0ECdCELQQAhCkEAIQQCQCADIAlGDQBBBSALIA1qIgcoAgAgAhA4IgNB/wFxQQJGDQAgA0EBcUUNACAJQQJ0IAtrQXxqIQMgB0EEaiEFQQEhBgNAIAYhBCADRQ0BQQUgBSgCACACEDgiB0H/AXFBAkYNASAEQQFqIQYgA0F8aiEDIAVBBGohBSAHQQFxDQALCyAIQRRqIQcCQCAPIARrIgZFDQAgCyAJQQJ0IgVrIQMgBSANakF8aiEFA0AgA0UNAUEFIAUoAgAgAhA4IglB/wFxQQJGIAlBAXFFcg0BIANBBGohAyAFQXxqIQUgBiAKQQFqIgpHDQALCyAIIAgoAgQgBGo2AgQgCCAIKAIIIAprNgIIIAggCC0AESAKQQBHcjoAESAHIA5HDQEMAwsLIAMgCRCtAQALIAkgBBCrAQALIAEoAgAhByABKAIICyEGIAFBADYCCEEAIQRBACECAkADQCAEIAZPDQEgBEEUbCEJIAQgDGpBFGwhCiAHIQUgBCEDAkACQAJAA0AgAyAGTw0BIAUgCWoiCEEIaiILKAIAIAhBBGooAgBGDQMgAgRAIAMgDGoiBCAGTw0DIAUgCmoiBCAIKQIANwIAIARBEGogCEEQaigCADYCACAEQQhqIAspAgA3AgALIAVBFGohBSADQQFqIgMgBkkNAAsgAyEEDAQLQdSuwgAgAyAGEKwBAAtB5K7CACAEIAYQrAEACyADQQFqIQQgDEF/aiEMIAJBAWohAiAIQRFqLQAAQQJHDQALIANBAWohBAsgAkUgBiAETXJFBEAgByAEQRRsaiIDQQAgAmtBFGxqIAMgBiAEa0EUbBCeARoLIAEgBiACayIENgIIIAQEQCAGQRRsIAJBFGxrIQVBACEDA0AgByADNgIAIAdBFGohByADQQFqIQMgBUFsaiIFDQALCyAAIAFBMBC/ARoLogcBBX8gAEF4aiIBIABBfGooAgAiA0F4cSIAaiECQaDywgACfwJAAkACQAJAIANBAXENACADQQNxRQ0BIAEoAgAiAyAAaiEAIAEgA2siAUH48cIAKAIARgRAIAIoAgRBA3FBA0cNAUHw8cIAIAA2AgAgAiACKAIEQX5xNgIEIAEgAEEBcjYCBCAAIAFqIAA2AgAPCyADQYACTwRAIAEQWwwBCyABKAIMIgQgASgCCCIFRwRAIAUgBDYCDCAEIAU2AggMAQtB4O7CAEHg7sIAKAIAQX4gA0EDdndxNgIACwJAIAIoAgQiA0ECcQRAIAIgA0F+cTYCBCABIABBAXI2AgQgACABaiAANgIADAELAkAgAkH88cIAKAIARwRAQfjxwgAoAgAgAkcNAUH48cIAIAE2AgBB8PHCAEHw8cIAKAIAIABqIgA2AgAgASAAQQFyNgIEIAAgAWogADYCAA8LQfzxwgAgATYCAEH08cIAQfTxwgAoAgAgAGoiADYCACABIABBAXI2AgRB+PHCACgCACABRgRAQfDxwgBBADYCAEH48cIAQQA2AgALQZjywgAoAgAiAiAATw0CQfzxwgAoAgAiAEUNAgJAQfTxwgAoAgAiA0EpSQ0AQYjywgAhAQNAIAEoAgAiBCAATQRAIAQgASgCBGogAEsNAgsgASgCCCIBDQALC0Gg8sIAAn9B/x9BkPLCACgCACIARQ0AGkEAIQEDQCABQQFqIQEgACgCCCIADQALIAFB/x8gAUH/H0sbCzYCACADIAJNDQJBmPLCAEF/NgIADwsgA0F4cSIEIABqIQACQCAEQYACTwRAIAIQWwwBCyACKAIMIgQgAigCCCICRwRAIAIgBDYCDCAEIAI2AggMAQtB4O7CAEHg7sIAKAIAQX4gA0EDdndxNgIACyABIABBAXI2AgQgACABaiAANgIAIAFB+PHCACgCAEcNAEHw8cIAIAA2AgAMAQsgAEGAAkkNASABIAAQU0Gg8sIAQaDywgAoAgBBf2oiADYCACAADQBBkPLCACgCACIADQJB/x8MAwsPCyAAQQN2IgJBA3RB6O7CAGohAAJ/QeDuwgAoAgAiA0EBIAJBH3F0IgJxBEAgACgCCAwBC0Hg7sIAIAIgA3I2AgAgAAshAiAAIAE2AgggAiABNgIMIAEgADYCDCABIAI2AggPC0EAIQEDQCABQQFqIQEgACgCCCIADQALIAFB/x8gAUH/H0sbCzYCAAuHBgEGfwJAAkACQAJAIAJBCU8EQCACIAMQRiICDQFBAA8LQQAhAiADQcz/e0sNAkEQIANBC2pBeHEgA0ELSRshASAAQXxqIgYoAgAiB0F4cSEEAkACQAJAAkAgB0EDcQRAIABBeGoiCCAEaiEFIAQgAU8NAUH88cIAKAIAIAVGDQJB+PHCACgCACAFRg0DIAUoAgQiB0ECcQ0GIAdBeHEiCSAEaiIEIAFPDQQMBgsgAUGAAkkgBCABQQRySXIgBCABa0GBgAhPcg0FDAcLIAQgAWsiAkEQSQ0GIAYgASAHQQFxckECcjYCACABIAhqIgEgAkEDcjYCBCAFIAUoAgRBAXI2AgQgASACEC8MBgtB9PHCACgCACAEaiIEIAFNDQMgBiABIAdBAXFyQQJyNgIAIAEgCGoiAiAEIAFrIgFBAXI2AgRB9PHCACABNgIAQfzxwgAgAjYCAAwFC0Hw8cIAKAIAIARqIgQgAUkNAgJAIAQgAWsiA0EPTQRAIAYgB0EBcSAEckECcjYCACAEIAhqIgEgASgCBEEBcjYCBEEAIQMMAQsgBiABIAdBAXFyQQJyNgIAIAEgCGoiAiADQQFyNgIEIAQgCGoiASADNgIAIAEgASgCBEF+cTYCBAtB+PHCACACNgIAQfDxwgAgAzYCAAwECyAEIAFrIQICQCAJQYACTwRAIAUQWwwBCyAFKAIMIgMgBSgCCCIFRwRAIAUgAzYCDCADIAU2AggMAQtB4O7CAEHg7sIAKAIAQX4gB0EDdndxNgIACyACQRBPBEAgBiABIAYoAgBBAXFyQQJyNgIAIAEgCGoiASACQQNyNgIEIAQgCGoiAyADKAIEQQFyNgIEIAEgAhAvDAQLIAYgBCAGKAIAQQFxckECcjYCACAEIAhqIgEgASgCBEEBcjYCBAwDCyACIAAgAyABIAEgA0sbEL8BGiAAECEMAQsgAxAEIgFFDQAgASAAIAMgBigCACIBQXhxQQRBCCABQQNxG2siASABIANLGxC/ASAAECEPCyACDwsgAAvBBQELfyMAQSBrIgYkAAJAAkACQCABIAJHBEAgAUEBaiEDIAEsAAAiBUF/Sg0BAn8gAiADRgRAIAIMAQsgAS0AAUE/cSEEIAFBAmoiAwshASAFQR9xIQcgBUH/AXFB3wFNBEAgBCAHQQZ0ciEBDAMLAn8gASACRgRAIAIMAQsgAS0AAEE/cSEIIAFBAWoiAwshASAIIARBBnRyIQQgBUH/AXFB8AFJBEAgBCAHQQx0ciEBDAMLIAEgAkYEf0EABSABQQFqIQMgAS0AAEE/cQsgB0ESdEGAgPAAcSAEQQZ0cnIiAUGAgMQARw0CCyAAQQA2AgggAEIENwIADAILIAVB/wFxIQELIAZBCGogAkEDaiIMIANrQQJ2QQFqEKUBIAYoAgwhBSAGKAIIIgggATYCACAGQQE2AhggBiAFNgIUIAYgCDYCEAJAIAIgA0YNAEEEIQdBASEBA0AgA0EBaiEFAkACQCADLAAAIgRBf0wEQAJ/IAIgBUYEQEEAIQkgAgwBCyADLQABQT9xIQkgA0ECaiIFCyEDIARBH3EhCiAEQf8BcSINQd8BSw0BIAkgCkEGdHIhBCAFIQMMAgsgBEH/AXEhBCAFIQMMAQsCfyACIANGBEBBACELIAIMAQsgAy0AAEE/cSELIANBAWoiBQshBCALIAlBBnRyIQkgDUHwAUkEQCAJIApBDHRyIQQgBSEDDAELAn8gAiAERgRAIAUhA0EADAELIARBAWohAyAELQAAQT9xCyAKQRJ0QYCA8ABxIAlBBnRyciIEQYCAxABGDQILIAYoAhQgAUYEQCAGQRBqIAEgDCADa0ECdkEBahCGASAGKAIQIQgLIAcgCGogBDYCACAGIAFBAWoiATYCGCAHQQRqIQcgAiADRw0ACwsgACAGKQMQNwIAIABBCGogBkEYaigCADYCAAsgBkEgaiQAC4QFARB/IwBB8ABrIgkkACAAQYgCaiENIAlByABqIQ4gAUEySSEPQQEhBAJAA0ACQCAEIAFPBEBBACEHDAELIAAgBEHkAGxqIQNBASEHA0BBACECAkADQCACQQRqIghBKEYNASACIANqIQUgCCECQX8gBUFcaigCACIIIAVBQGsoAgAiBUcgCCAFSBsiCEUNAAsgCEH/AXFB/wFGDQILIANB5ABqIQNBASECIARBAWoiBCABSSEHIAEgBEcNAAsMAgsgASAERiECIA8gASAERnINAQJAAkAgBEF/aiICIAFJBEAgB0EBcQRAIApBAWohCiAJQQhqIAAgAkHkAGxqIgJB5AAQvwEaIAIgACAEQeQAbCIHaiICQeQAEL8BGiACIAlBCGpB5AAQvwEhBSAAIAQQSiABIARrIgxBAkkNAyAFQeQAaiEIQcAAIQIDQCACQQRqIgNB6ABGDQQgAiAFaiEGIAMhAkF/IAYoAgAiAyAGQeQAaigCACIGRyADIAZIGyIDRQ0ACyADQf8BcUH/AUcNAyAJQQhqIAVB5AAQvwEaIAUgCEHkABC/ASEQIAxBA0kNAiAHIA1qIQdBAiECA0AgAkEBaiAQIAJB5ABsaiEDQQAhAgNAIAJBBGoiBkEoRg0EIAIgB2ohCyACIA5qIREgBiECQX8gESgCACIGIAsoAgAiC0cgBiALSBsiBkUNAAsgBkH/AXFB/wFHDQMgA0Gcf2ogA0HkABC/ARogB0HkAGohByADIQgiAiAMRw0ACwwCC0HAp8IAIAQgARCsAQALQbCnwgAgAiABEKwBAAsgCCAJQQhqQeQAEL8BGgsgCkEFRw0AC0EAIQILIAlB8ABqJAAgAgulBQEGfyAAKAIAIglBAXEiCiAEaiEIAkAgCUEEcUUEQEEAIQEMAQsgAgRAIAIhByABIQUDQCAGIAUtAABBwAFxQYABRmohBiAFQQFqIQUgB0F/aiIHDQALCyACIAhqIAZrIQgLQStBgIDEACAKGyEGAkACQCAAKAIIQQFHBEBBASEFIAAgBiABIAIQuwENAQwCCyAAQQxqKAIAIgcgCE0EQEEBIQUgACAGIAEgAhC7AQ0BDAILAkAgCUEIcUUEQCAHIAhrIQdBACEFAkACQAJAQQEgAC0AMCIIIAhBA0YbQQFrDgMAAQACCyAHIQVBACEHDAELIAdBAXYhBSAHQQFqQQF2IQcLIAVBAWohBQNAIAVBf2oiBUUNAiAAKAIYIAAoAgQgACgCHCgCEBEBAEUNAAtBAQ8LQQEhBSAAQQE6ADAgAEEwNgIEIAAgBiABIAIQuwENASAHIAhrIQZBACEFAkACQAJAQQEgAC0AMCIBIAFBA0YbQQFrDgMAAQACCyAGIQVBACEGDAELIAZBAXYhBSAGQQFqQQF2IQYLIAVBAWohBQJAA0AgBUF/aiIFRQ0BIAAoAhggACgCBCAAKAIcKAIQEQEARQ0AC0EBDwsgACgCBCEBQQEhBSAAKAIYIAMgBCAAKAIcKAIMEQMADQEgBkEBaiEGIAAoAhwhAiAAKAIYIQADQCAGQX9qIgZFBEBBAA8LIAAgASACKAIQEQEARQ0ACwwBCyAAKAIEIQhBASEFIAAgBiABIAIQuwENACAAKAIYIAMgBCAAKAIcKAIMEQMADQAgB0EBaiEGIAAoAhwhASAAKAIYIQADQCAGQX9qIgZFBEBBAA8LIAAgCCABKAIQEQEARQ0ACwsgBQ8LIAAoAhggAyAEIABBHGooAgAoAgwRAwALlQUCDX8CfiMAQdAAayIDJAACQCABKAIIIgStQhR+IhBCIIinRQRAIBCnIgVBf0oEQAJAAkAgBUUEQEEEIQgMAQsgBUEEENcBIghFDQELIAEoAgAhCiAERQRAQQAhBUEAIQQMBAsgCiAEQRRsaiENIAFBIGooAgAhDiABKAIYIQ9BACEFIAohCQNAIAkoAgAhByADQQA2AhggAyAHNgIUIANBtKrCADYCECADIA42AgwgAyAPNgIIIAMgAjYCBCADIAk2AgAgA0E4aiADECgCQCADLQBJQQJGDQACQANAAkAgA0EwaiADQcgAaiILKAIAIgc2AgAgA0EoaiADQUBrIgwpAwAiEDcDACADIAMpAzgiETcDICALIAc2AgAgDCAQNwMAIAMgETcDOAJAIAQgBkcEQCAGIQUMAQsgBEEBaiIGIARJDQMgBEEBdCIHIAYgByAGSxsiBq1CFH4iEEIgiKcNAyAQpyIHQQBIDQMCfyAERQRAIAdBBBDXAQwBCyAIIARBFGxBBCAHENQBCyIIRQ0BIAYhBAsgCCAFQRRsaiIGIAMpAzg3AgAgBkEQaiALKAIANgIAIAZBCGogDCkDADcCACADQThqIAMQKCAFQQFqIgUhBiADLQBJQQJHDQEMAwsLIAdBBEG08sIAKAIAIgBBAiAAGxEAAAALENMBAAsgDSAJQRRqIglHDQALDAMLIAVBBEG08sIAKAIAIgBBAiAAGxEAAAALENgBAAsQ2AEACyABKAIEBEAgChAhCyABIAU2AgggASAENgIEIAEgCDYCACAFBEAgBUEUbCEFQQAhBANAIAggBDYCACAIQRRqIQggBEEBaiEEIAVBbGoiBQ0ACwsgACABQTAQvwEaIANB0ABqJAALwAUCCH8EfiMAQbABayIAJAACQEHo7cIAKAIADQAgAEEQakHkA0EBEJoBIAApAxAhCCAAQcwAaiIBQeQDNgIAIAAgCDcCRCAAQRY2AkAgAEFAaxB/IABBOGoiBCAAQcgAaikDADcDACAAIAApA0A3AzAgAEFAa0EUEFQgAEEgaiIDIAEpAgA3AwAgAEEoaiIFIABB1ABqIgYoAgA2AgAgACAAKQJENwMYIABBCGpBFEEAEJoBIAApAwghCCAAQRRBABCaASAAKQMAIQkgASAEKQMANwIAIAZBADYCACAAQdgAaiAAKQMYNwMAIABB4ABqIAMpAwA3AwAgAEHoAGogBSgCADYCACAAQYgBakEANgIAIABBgAFqIgQgCTcDACAAQfgAakIANwMAIABB8ABqIAg3AwAgAEHsAGpBADYCACAAQQA2AkAgACAAKQMwNwJEA0AgAEFAayACaiIBKQIAIQggASACQeDtwgBqIgMpAgA3AgAgAkHo7cIAaiIFKQIAIQkgAkHw7cIAaiIGKQIAIQogAkH47cIAaiIHKQIAIQsgAyAINwIAIAFBGGoiAykCACEIIAMgCzcCACABQRBqIgMpAgAhCyADIAo3AgAgAUEIaiIBKQIAIQogASAJNwIAIAcgCDcCACAGIAs3AgAgBSAKNwIAIAJBQGsgAkEgaiECQc0ASQ0ACyAEKQIAIQggBEGg7sIAKQIANwIAQaDuwgAgCDcCACAEQQhqIgIoAgAhASACQajuwgAoAgA2AgBBqO7CACABNgIAIAAoAkgiAUUNACAAQdwAaigCACEEIAAoAlghAiAAKAJwIQMgACgCdCAAKAKEASEGIAAoAoABIQcgACgCTARAIAEQIQsgAgRAIABBQGsgAkEBahCnASAEECELBEAgAxAhCyAGRQ0AIAcQIQsgAEGwAWokAEHg7cIAC/0EAQx/AkACQAJAAkAgASgCACIEQQhqKAIAIgIgBCgCBCIETwRAIAFBDGooAgAiAyACSQ0BQQIhAyABKAIYIgYgAiAEayIITw0EAkAgASgCCCIFIARBAnRqIgogBkECdGoiAyAFIAJBAnRqIglGBEBBACEFDAELQQAhBQNAIANBBGohBiABKAIEIQcgASgCECELIAMoAgAhDEEAIQNBACEEAn8DQEEBIQIgAyALai0AACAMIAcQOCINQf8BcUECRwRAIAQhAkEBIA1BAXENAhoLIAIhBCADQQFqIgNBA0cNAAtBAkEAIAJBAXEbCyIHQQFxRSAHQQJGckUEQCAFQQFqIQUgBiIDIAlHDQELCyABKAIYIQYLIAEgBSAGaiICNgIYIAggAkkNAkECIQMgCiACQQJ0aiIGIAlGDQRBACEFA0AgBiICQQRqIQYgASgCBCEIIAEoAhAhCiACKAIAIQdBACEDQQAhBAJ/A0BBASECIAMgCmotAAAgByAIEDgiC0H/AXFBAkcEQCAEIQJBASALQQFxDQIaCyACIQQgA0EBaiIDQQNHDQALQQJBACACQQFxGwsiB0ECR0EAIAdBAXEbRQRAIAVBAWohBSAGIAlHDQEMBQsLIAUNA0ECIQMMBAsgBCACEK0BAAsgAiADEKsBAAsgAiAIEK0BAAsgASgCGCIDIAVqIQIgAyABKAIAIgQoAgQiB2oiCSAFaiEIIAEoAhQhBkEBIQMgBC0AEUUEQCACIARBCGooAgAgB2tJIQMLIAEgAjYCGCAAQQo6ABAgACAFNgIMIAAgCDYCCCAAIAk2AgQgACAGNgIAIAEgBkEBajYCFAsgACADOgARC4kFAgt/A34jAEFAaiIGJAAgBiACNgIMQqXGiKHInKf5SyEPQQEhBSACIQQDQCAPIAStQv8Bg4VCs4OAgIAgfiEPIAVBBEZFBEAgBkEMaiAFaiAFQQFqIQUtAAAhBAwBCwsgD0IZiKciDEH/AHEiBEEIdCAEciIEQRB0IARyIQ0gASgCACIIIA+ncSEFIAEoAgghCSABKAIEIQQCQANAAkAgBCAFaigAACILIA1zIgdBf3MgB0H//ft3anFBgIGChHhxIgdFDQADQCACIAkgB2hBA3YgBWogCHEiDkEobGooAgBHBEAgB0F/aiAHcSIHDQEMAgsLIAkgDkEobGoiASkDCCEPIAEgAykDADcDCCAAIA83AwAgAUEgaiICKQMAIQ8gAiADQRhqKQMANwMAIAFBGGoiAikDACEQIAIgAykDEDcDACABQRBqIgEpAwAhESABIAMpAwg3AwAgAEEYaiAPNwMAIAAgEDcDECAAIBE3AwgMAgsgCkEEaiIKIAVqIAhxIQUgCyALQQF0cUGAgYKEeHFFDQALIAZBKGogA0EYaikCADcCACAGQSBqIANBEGopAgA3AgAgBkEYaiADQQhqKQIANwIAIAYgAykCADcCECAEIAEgDxCcASIFai0AAEEBcSEDIAEoAgwiByADRXJFBEAgBkEwaiABEBIgASAPEJwBIQUgASgCACEIIAEoAgghCSABKAIMIQcgASgCBCEECyABIAcgA2s2AgwgBCAFaiAMQf8AcSIDOgAAIAggBUF8anEgBGpBBGogAzoAACAJIAVBKGxqIgMgAjYCACADQQRqIAZBDGpBJBC/ARogASABKAIQQQFqNgIQIABBAjoAHAsgBkFAayQAC9kEARF/IwBBEGsiCCQAAkAgASgCBCICRQRAIABBADYCAAwBCyABIAJBf2o2AgQgASABKAIAIgRBBGo2AgAgASgCCCEHAkACQAJAAkACfyACIAcgByACSxsgASgCECIFRQ0AGgNAIAIgBmoiA0EBRg0CIAEgBEEIajYCACABIAUgBmpBf2o2AhAgASADQX5qNgIEIARBBGohBCAFIAZBf2oiBmoNAAsgAiAGaiICIAcgByACSxsLIgdBAWoiBkECTwRAA0AgByAGQX9qIgJJDQUgASgCDCEJIAhCpcaIocicp/lLNwMIIAQgAiAIQQhqEHQgCCgCCCIFQRl2IgNBCHQgA3IiA0EQdCADciEPIAJBAnQhECAJKAIAIgsgBXEhBSAJKAIEIRFBACEMA0AgBSARaigAACINIA9zIgNBf3MgA0H//ft3anFBgIGChHhxIgMEQCAJKAIIIQ4DQCAOIANoQQN2IAVqIAtxIhJBGGxqIgooAgggAkYEQCAEIAooAgAiCkYNByAEIAogEBC6AUUNBwsgA0F/aiADcSIDDQALCyAMQQRqIgwgBWogC3EhBSANIA1BAXRxQYCBgoR4cUUNAAsgAiEGIAJBAUsNAAsLIAdFDQIgAEEBNgIEIAAgBDYCACAAQQxqQQE2AgAgAEEIaiAENgIADAQLIABBADYCAAwDCyABIAZBfmo2AhAgACACNgIEIAAgBDYCACAAQQxqIA4gEkEYbGoiASgCFDYCACAAQQhqIAEoAgw2AgAMAgtBAUEAEKsBAAsgAiAHEKsBAAsgCEEQaiQAC8EEAg1/AX4jAEEgayIHJAAgAUF/aiEMIAFBMkkhDUEBIQMCQAJAAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0EMbGoiBEEDIARBdGpBAxCZAUH/AXFB/wFGDQAaA0BBASADIAxGDQMaIANBAWohAyAEQQxqIgJBAyAEQQMQmQEgAiEEQf8BcUH/AUcNAAsgAyABSQsgASADRiANIAEgA0ZyDQEaIANBf2oiAiABTw0CRQ0DIApBAWohCiAAIANBDGxqIgUpAgAhDyAFIAAgAkEMbGoiAikCADcCACACIA83AgAgBUEIaiIGKAIAIQQgBiACQQhqIgIoAgA2AgAgAiAENgIAIAAgAxBQAkAgASADayILQQJJDQAgBUEMaiIIQQMgBUEDEJkBQf8BcUH/AUcNACAFKQIAIQ8gBSAIKQIANwIAIAdBGGoiDiAGKAIANgIAIAYgCEEIaigCADYCACAHIA83AxACQCALQQNJDQAgBUEYaiIJQQMgB0EQakEDEJkBQf8BcUH/AUcNAEEDIQJBAiEGA0AgBkEMbCAFaiIIQXRqIgQgCSkCADcCACAEQQhqIAlBCGooAgA2AgAgAiALTw0BIAJBAWoiBCACSQ0BIAJBDGwhCSACIQYgBCECIAUgCWoiCUEDIAdBEGpBAxCZAUH/AXFB/wFGDQALCyAIIAcpAxA3AgAgCEEIaiAOKAIANgIACyAKQQVHDQALQQALIAdBIGokAA8LQbCnwgAgAiABEKwBAAtBwKfCACADIAEQrAEAC9kEAg9/AX4jAEEwayIEJAAgBEKlxoihyJyn+Us3AwggAigCACIKIAIoAggiDCAEQQhqEHQgBCkDCCITQhmIpyIOQf8AcSIFQQh0IAVyIgVBEHQgBXIhDyAMQQJ0IRAgASgCACIIIBOncSEHIAEoAgghCSABKAIEIQUCQANAAkAgBSAHaigAACINIA9zIgZBf3MgBkH//ft3anFBgIGChHhxIgZFDQADQAJAIAkgBmhBA3YgB2ogCHEiEUEYbGoiCygCCCAMRgRAIAogCygCACILRg0BIAogCyAQELoBRQ0BCyAGQX9qIAZxIgYNAQwCCwsgACAJIBFBGGxqIgEpAgw3AgAgASADKQIANwIMIABBCGogAUEUaiIAKAIANgIAIAAgA0EIaigCADYCACACKAIERQ0CIAoQIQwCCyASQQRqIhIgB2ogCHEhByANIA1BAXRxQYCBgoR4cUUNAAsgBEEQaiIHIAJBCGooAgA2AgAgBEEcaiADQQhqKAIANgIAIAQgAikCADcDCCAEIAMpAgA3AhQgBSABIBMQnAEiAmotAABBAXEhAyABIAEoAgwiBiADRXIEfyAGBSAEQSBqIAEQDyABIBMQnAEhAiABKAIAIQggASgCCCEJIAEoAgQhBSABKAIMCyADazYCDCACIAVqIA5B/wBxIgM6AAAgCCACQXxqcSAFakEEaiADOgAAIAkgAkEYbGoiAkEQaiAEQRhqKQMANwIAIAJBCGogBykDADcCACACIAQpAwg3AgAgASABKAIQQQFqNgIQIABBADYCAAsgBEEwaiQAC6UEARR/AkAgAkEBdSIIIAJJBEBBACAAKAIYayEUIAAoAhAiEiAAKAIEakF/aiEJIABBDGogAEEIaiAAKAIAQQFGGyEVIAIhEQNAAkACQCABIAhBGGxqIhYoAgQiByAEa0UNACAUIARrIRdBACAEayELIAchDCAJIQ0gEiEOIAQhBQJAAkACQANAQX8hDyAOIBdqRQ0DIAsgDmpBf2ogFSgCACIPTw0BIAsgDGpBf2ogB08NAiALIA1qLQAAIBYoAgAgC2ogDGpBf2otAABrIg8NAyANQX9qIQ0gDkF/aiEOIAVBAWohBSALIAxBf2oiDGoNAAsgBSEEDAMLQeTIwgAgBEF/cyAOaiAPEKwBAAtB9MjCACAEQX9zIAxqIAcQrAEACyAFIQQMAQtBACEPCyAKIAQgD0EASCIHGyEKAkAgCCARIAcbIhEgBiAIIAcbIgZrIg1BAk4NACATIAYgEUYgBkEASnJyQQEhE0EBcUUNAAJ/A0AgBiACTw0FAkAgCiABIAZBGGxqIgkoAgQiBU8EQCAAIBIgBWsiBDYCECAJKAIQIgVFDQEgBSAAIAMgCUEUaigCACgCDBEDACAAIAQ2AhANAQtBACAJKAIIIgZBAEgNAhoMAQsLIAEgBkEYbGooAgwLDwsgCiAEIBAgBxsiECAKIBBJGyEEIA1BAXUgBmoiCCACSQ0ACwtB1MjCACAIIAIQrAEAC0GEycIAIAYgAhCsAQALzwUCBH8BfkEBIAEoAhhBJyABQRxqKAIAKAIQEQEARQRAQQIhAgJAAkACQCAAKAIAIgBBd2oiBEEeSwRAIABB3ABHDQEMAgtB9AAhAwJAAkAgBEEBaw4eAQICAAICAgICAgICAgICAgICAgICAgICAwICAgIDBAtB8gAhAwwDC0HuACEDDAILAkACfkGAhsAAIAAQXEUEQAJAAkAgAEGAgARPBEAgAEGAgAhJDQEgAEGQ/EdqQZD8C0kgAEHii3RqQeKNLElyIABBn6h0akGfGEkgAEHe4nRqQQ5JcnIgAEH+//8AcUGe8ApGIABBqbJ1akEpSXJyDQIgAEHLkXVqQQpLDQUMAgsgAEH4kcAAQSlBypLAAEGlAkHvlMAAQboCEEtFDQEMBAsgAEGpl8AAQSNB75fAAEGmAUGVmcAAQZgDEEsNAwsgAEEBcmdBAnZBB3OtQoCAgIDQAIQMAQsgAEEBcmdBAnZBB3OtQoCAgIDQAIQLIQZBAyECDAELQQEhAgsgACEDCwNAIAIhBEHcACEAQQEhAgJAAkACQAJAIARBAWsOAwIDAAELAkACQAJAAkACQCAGQiCIp0H/AXFBAWsOBQQDAgEABQsgBkL/////j2CDQoCAgIDAAIQhBkEDIQIMBgsgBkL/////j2CDQoCAgIAwhCEGQfUAIQBBAyECDAULIAZC/////49gg0KAgICAIIQhBkH7ACEAQQMhAgwECyADIAanIgJBAnRBHHF2QQ9xIgBBMHIgAEHXAGogAEEKSRshACACBEAgBkJ/fEL/////D4MgBkKAgICAcIOEIQZBAyECDAQLIAZC/////49gg0KAgICAEIQhBkEDIQIMAwsgBkL/////j2CDIQZB/QAhAEEDIQIMAgsgASgCGEEnIAEoAhwoAhARAQAPC0EAIQIgAyEACyABKAIYIAAgASgCHCgCEBEBAEUNAAsLC4cFAQR/IAAgAWohAgJAAkACQCAAKAIEIgNBAXENACADQQNxRQ0BIAAoAgAiAyABaiEBIAAgA2siAEH48cIAKAIARgRAIAIoAgRBA3FBA0cNAUHw8cIAIAE2AgAgAiACKAIEQX5xNgIEIAAgAUEBcjYCBCACIAE2AgAPCyADQYACTwRAIAAQWwwBCyAAKAIMIgQgACgCCCIFRwRAIAUgBDYCDCAEIAU2AggMAQtB4O7CAEHg7sIAKAIAQX4gA0EDdndxNgIACyACKAIEIgNBAnEEQCACIANBfnE2AgQgACABQQFyNgIEIAAgAWogATYCAAwCCwJAIAJB/PHCACgCAEcEQEH48cIAKAIAIAJHDQFB+PHCACAANgIAQfDxwgBB8PHCACgCACABaiIBNgIAIAAgAUEBcjYCBCAAIAFqIAE2AgAPC0H88cIAIAA2AgBB9PHCAEH08cIAKAIAIAFqIgE2AgAgACABQQFyNgIEIABB+PHCACgCAEcNAUHw8cIAQQA2AgBB+PHCAEEANgIADwsgA0F4cSIEIAFqIQECQCAEQYACTwRAIAIQWwwBCyACKAIMIgQgAigCCCICRwRAIAIgBDYCDCAEIAI2AggMAQtB4O7CAEHg7sIAKAIAQX4gA0EDdndxNgIACyAAIAFBAXI2AgQgACABaiABNgIAIABB+PHCACgCAEcNAUHw8cIAIAE2AgALDwsgAUGAAk8EQCAAIAEQUw8LIAFBA3YiAkEDdEHo7sIAaiEBAn9B4O7CACgCACIDQQEgAkEfcXQiAnEEQCABKAIIDAELQeDuwgAgAiADcjYCACABCyECIAEgADYCCCACIAA2AgwgACABNgIMIAAgAjYCCAvNBAIJfwF+IwBBEGsiBCQAIAQgATYCACABKAIYIQMgBCABLQAcOgAHIAQgBDYCDCAEIARBB2o2AggCQAJAIAEtAB1FBEAgAUEMaiEIIANBAXQhCQNAIAEoAgQhBSABKAIAIQICQANAIAIgBUYNASABIAJBBGoiBzYCACACKAIAIAEgASgCCCIKQQFqNgIIIAchAkUNAAsgASgCFCICIAEoAhBGBEAgAkEBaiIFIAJJDQUgAkEBdCIGIAUgBiAFSxsiBUH/////AXEgBUcNBSAFQQN0IgZBAEgNBQJ/IAJFBEAgBkEEENcBDAELIAgoAgAgAkEDdEEEIAYQ1AELIgJFDQQgASAFNgIQIAEgAjYCDCABKAIUIQILIAEoAgwgAkEDdGoiAiAHQXxqNgIEIAIgCjYCACABIAEoAhRBAWoiAjYCFCACIAlJDQEgBEEIaiAIEAsgASgCFCADSQ0BIAEgAzYCFAwBCwsgBEEIaiAIEAsgASgCFCICIANPBEAgASADNgIUIAMhAgsgAkEBdiIHBEAgAkEDdCAIKAIAIgJqQXhqIQMDQCACKQIAIQsgAiADKQIANwIAIAMgCzcCACACQQhqIQIgA0F4aiEDIAdBf2oiBw0ACwsgAUEBOgAdCwJAIAFBFGooAgAiAkUEQEEAIQIMAQsgASACQX9qIgI2AhQgASgCDCACQQN0aiIBKAIEIQIgASgCACEBCyAAIAI2AgQgACABNgIAIARBEGokAA8LIAZBBEG08sIAKAIAIgBBAiAAGxEAAAALENMBAAuYBAEIfyMAQRBrIgMkACADIAA2AgAgACgCFCECIAMgAC0AGDoAByADIAM2AgwgAyADQQdqNgIIAkACQCAALQAZRQRAIABBCGohByAAKAIAIgQgACgCBEcEQCACQQF0IQggAEEQaigCACEBA0AgACAEQTxqNgIAIAAoAgwgAUYEQCABQQFqIgYgAUkNBSABQQF0IgUgBiAFIAZLGyIGQf////8DcSAGRw0FIAZBAnQiBUEASA0FAn8gAUUEQCAFQQQQ1wEMAQsgBygCACABQQJ0QQQgBRDUAQsiAUUNBCAAIAY2AgwgACABNgIIIAAoAhAhAQsgACgCCCABQQJ0aiAENgIAIAAgACgCEEEBaiIBNgIQAkAgASAISQ0AIANBCGogBxANIAAoAhAiASACSQ0AIAAgAjYCECACIQELIAAoAgAiBCAAKAIERw0ACwsgA0EIaiAHEA0gAEEQaigCACIBIAJPBEAgACACNgIQIAIhAQsgAUEBdiIFBEAgAUECdCAHKAIAIgFqQXxqIQQDQCABKAIAIQIgASAEKAIANgIAIAQgAjYCACABQQRqIQEgBEF8aiEEIAVBf2oiBQ0ACwsgAEEBOgAZCwJ/QQAgAEEQaigCACICRQ0AGiAAIAJBf2oiAjYCECAAKAIIIAJBAnRqKAIACyADQRBqJAAPCyAFQQRBtPLCACgCACIAQQIgABsRAAAACxDTAQALmwQCD38BfiMAQSBrIgMkACADQqXGiKHInKf5SzcDECABKAIAIgcgASgCCCILIANBEGoQdCADKQMQIhJCGYinIg1B/wBxIgRBCHQgBHIiBEEQdCAEciEOIAtBAnQhDyAAKAIAIgggEqdxIQUgACgCCCEJIAAoAgQhBAJAA0ACQCAEIAVqKAAAIgwgDnMiBkF/cyAGQf/9+3dqcUGAgYKEeHEiBkUNAANAAkAgCSAGaEEDdiAFaiAIcSIQQQR0aiIKKAIIIAtGBEAgByAKKAIAIgpGDQEgByAKIA8QugFFDQELIAZBf2ogBnEiBg0BDAILCyAJIBBBBHRqIAI6AAwgASgCBEUNAiAHECEMAgsgEUEEaiIRIAVqIAhxIQUgDCAMQQF0cUGAgYKEeHFFDQALIANBCGoiBiABQQhqKAIANgIAIAMgASkCADcDACAEIAAgEhCcASIBai0AAEEBcSEFIAAgACgCDCIHIAVFcgR/IAcFIANBEGogABATIAAgEhCcASEBIAAoAgAhCCAAKAIIIQkgACgCBCEEIAAoAgwLIAVrNgIMIAEgBGogDUH/AHEiBToAACAIIAFBfGpxIARqQQRqIAU6AAAgCSABQQR0aiIBQQhqIAYoAgA2AgAgASADKQMANwIAIAEgAjoADCABIAMvABA7AA0gAUEPaiADQRJqLQAAOgAAIAAgACgCEEEBajYCEAsgA0EgaiQAC/kDAQd/IwBBIGsiAyQAAkACQCAAKAIQIAAoAhhNDQAgABCWASAAKAIQIQEgACgCBCECIAMgAEEMaiAAQQhqIAAoAgBBAUYbKAIAIgQ2AgQgAyACNgIAIAMgATYCCCADIAQ2AgwCQCABRSABIARGckUEQCAEIAFNDQEgASACaiwAAEG/f0wNAQsgASAERg0BAkAgASACaiIFLAAAIgdBf0oEQCAHQf8BcSEBDAELAn8gAiAEaiIBIAVBAWpGBEAgAQwBCyAFLQABQT9xIQYgBUECagshAiAHQR9xIQUgB0H/AXFB3wFNBEAgBiAFQQZ0ciEBDAELAn8gASACRgRAIAEhBEEADAELIAJBAWohBCACLQAAQT9xCyAGQQZ0ciEGIAdB/wFxQfABSQRAIAYgBUEMdHIhAQwBC0EAIQIgASAERwR/IAQtAABBP3EFQQALIAVBEnRBgIDwAHEgBkEGdHJyIgFBgIDEAEYNAwsgABCUASABQeEASSABQfsBS3INASABQeEAayIEQQN2IgFBFEkEQEEAIQIgAUHVysIAai0AAEEBIARBB3F0cUUNAyAAEJYBQQEhAgwDC0H0x8IAIAFBFBCsAQALIAMgA0EMajYCGCADIANBCGo2AhQgAyADNgIQIANBEGoQzAEAC0EAIQILIANBIGokACACC/ADAQd/IwBBIGsiBSQAAkACQAJAIAAoAhAgACgCGE0NACAAEJYBIAAoAhAhBCAAKAIEIQYgBSAAQQxqIABBCGogACgCAEEBRhsoAgAiBzYCBCAFIAY2AgAgBSAENgIIIAUgBzYCDCAERSAEIAdGckUEQCAHIARNDQMgBCAGaiwAAEG/f0wNAwsgBCAHRg0AAkAgBCAGaiIILAAAIgpBf0oEQCAKQf8BcSEEDAELAn8gBiAHaiIEIAhBAWpGBEAgBAwBCyAILQABQT9xIQkgCEECagshBiAKQR9xIQggCkH/AXFB3wFNBEAgCSAIQQZ0ciEEDAELAn8gBCAGRgRAIAQhB0EADAELIAZBAWohByAGLQAAQT9xCyAJQQZ0ciEGIApB/wFxQfABSQRAIAYgCEEMdHIhBAwBC0EAIQkgBCAHRwR/IActAABBP3EFQQALIAhBEnRBgIDwAHEgBkEGdHJyIgRBgIDEAEYNAQsgABCUASAEQeEASSAEIANLckUEQCAEQeEAayIEQQN2IgMgAk8NAkEAIQkgASADai0AAEEBIARBB3F0cQ0BCyAAEJYBQQEhCQsgBUEgaiQAIAkPC0GUyMIAIAMgAhCsAQALIAUgBUEMajYCGCAFIAVBCGo2AhQgBSAFNgIQIAVBEGoQzAEAC+oDAQd/IwBBIGsiAyQAAkACQCAAKAIQIgEgACgCFE8NACAAKAIEIQIgAyAAQQxqIABBCGogACgCAEEBRhsoAgAiBDYCBCADIAI2AgAgAyABNgIIIAMgBDYCDAJAIAFFIAEgBEZyRQRAIAQgAU0NASABIAJqLAAAQb9/TA0BCyABIARGDQECQCABIAJqIgUsAAAiB0F/SgRAIAdB/wFxIQEMAQsCfyACIARqIgEgBUEBakYEQCABDAELIAUtAAFBP3EhBiAFQQJqCyECIAdBH3EhBSAHQf8BcUHfAU0EQCAGIAVBBnRyIQEMAQsCfyABIAJGBEAgASEEQQAMAQsgAkEBaiEEIAItAABBP3ELIAZBBnRyIQYgB0H/AXFB8AFJBEAgBiAFQQx0ciEBDAELQQAhAiABIARHBH8gBC0AAEE/cQVBAAsgBUESdEGAgPAAcSAGQQZ0cnIiAUGAgMQARg0DCyABQeEASSABQfsBS3INASABQeEAayIEQQN2IgFBFEkEQEEAIQIgAUHVysIAai0AAEEBIARBB3F0cUUNAyAAEJQBQQEhAgwDC0H0xsIAIAFBFBCsAQALIAMgA0EMajYCGCADIANBCGo2AhQgAyADNgIQIANBEGoQzAEAC0EAIQILIANBIGokACACC+UDAQd/IwBBIGsiAiQAAkACQAJAIAAoAhAiASAAKAIUTw0AIAAoAgQhAyACIABBDGogAEEIaiAAKAIAQQFGGygCACIENgIEIAIgAzYCACACIAE2AgggAiAENgIMIAFFIAEgBEZyRQRAIAQgAU0NAyABIANqLAAAQb9/TA0DCyABIARGDQACQCABIANqIgUsAAAiB0F/SgRAIAdB/wFxIQEMAQsCfyADIARqIgEgBUEBakYEQCABDAELIAUtAAFBP3EhBiAFQQJqCyEDIAdBH3EhBSAHQf8BcUHfAU0EQCAGIAVBBnRyIQEMAQsCfyABIANGBEAgASEEQQAMAQsgA0EBaiEEIAMtAABBP3ELIAZBBnRyIQMgB0H/AXFB8AFJBEAgAyAFQQx0ciEBDAELQQAhBiABIARHBH8gBC0AAEE/cQVBAAsgBUESdEGAgPAAcSADQQZ0cnIiAUGAgMQARg0BCyABQeEASSABQfsBS3JFBEAgAUHhAGsiBEEDdiIBQRRPDQJBACEGIAFB1crCAGotAABBASAEQQdxdHENAQsgABCUAUEBIQYLIAJBIGokACAGDwtBhMjCACABQRQQrAEACyACIAJBDGo2AhggAiACQQhqNgIUIAIgAjYCECACQRBqEMwBAAv+AwEGfyMAQTBrIgUkACAFQQhqIABBDGoiByAAQQhqIgggACgCAEEBRhsoAgAQtgEgBUEANgIYIAUgBSkDCDcDECAAKAIAIQkgACgCBCEGAkACQAJAIAFFDQAgByAIIAlBAUYbKAIAIgogAUYNACAKIAFNDQEgASAGaiwAAEG/f0wNAQsgAkUgByAIIAkbKAIAIgcgAkZyRQRAIAcgAk0NAiACIAZqLAAAQb9/TA0CCyAFQRBqQQAgARCVASAFIAUoAhgiCCABaiIJNgIYIAggBSgCEGogBiABEL8BGiAFQRBqIAkgBBCVASAFIAUoAhgiCCAEaiIJNgIYIAggBSgCEGogAyAEEL8BGiAFQRBqIAkgBiAHaiACIAZqIgZrIgMQlQEgBSAFKAIYIgcgA2o2AhggByAFKAIQaiAGIAMQvwEaIAAgASACayAEaiIEIAAoAhRqNgIUAkACQCAAKAIQIgMgAkkEQCADIAFLDQEMAgsgAyAEaiEBCyAAIAE2AhALIABBBGohASAFQShqIgIgBUEYaigCADYCACAFIAUpAxA3AyACQCAAKAIARQ0AIABBCGooAgBFDQAgASgCABAhCyAAQQE2AgAgASAFKQMgNwIAIAFBCGogAigCADYCACAFQTBqJAAPCyAGIApBACABEBoACyAGIAdBACACEBoAC5MEAQF/QQEhAwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIABB/wFxQQFrDgcAAQIDBAUGDAtBACEDIAFBvwFLDQsgAUEGdkHQrMAAai0AACIAQQFNBEAgAEEDdEHYrMAAaikDAEIBIAFBP3GthoNCAFIPC0GQrMAAIABBAhCsAQALIAFBd2pBBUkNCSABQSBGDQpBACEDIAFBgAFJIAFBv+AAS3INCiABQQZ2Qc2qwABqLQAAIgBBBU0EQCAAQQN0QaCswABqKQMAQgEgAUE/ca2Gg0IAUg8LQZCswAAgAEEGEKwBAAtBACEDIAFBkMAATARAIAFBX2oiAEENTQ0FIAFBRmoiAEEFSw0KIABBAmsOAwoKCgkLIAFB779/akEESQ0IIAFBxL9/aiIAQQ1LQQEgAHRBgfAAcUVyDQcMCAtBACEDIAFBX3FBv39qQRpJDQhBASEDIAFBgAFJDQhB2MHAACABEFxBAXMPC0EAIQMgAUFfcUG/f2pBGkkNByABQYABSQ0DQdjBwAAgARBcDQdBASEDQeiswAAgARBcRQ0HDAQLQQIhAyACIAEQWUH/AXEiAEEIRg0GIABBBkYPC0ECIQMgAiABEFlB/wFxIgBBCEYNBSAAQQdGDwsgAEEBaw4KBAQEBAMEAwMEBAMLQQEhAyABQVBqQQpPDQMLQQAPCyABQabAAEcNAQtBAQ8LIAML7gMBB38jAEEwayIEJAAgASgCZEUEQAJAIAFBfzYCZCABKAJ0DQAgAUHoAGohByABQYABakEANgIAIAFB8ABqQoCAgIBwNwIAIARBADYCKCAEIAFBQGs2AiQgBEECNgIgIAQgAzYCHCAEIAI2AhggAUH4AGohCCAEQQhqIARBGGoQKiAEKAIIIgUEQANAIAQoAhAhBiAEKAIUIQkgByAFIAUgBCgCDCIKQQJ0ahC9ASAIIAYgBiAJQQJ0ahC9ASAJIAprIgYEQANAIAEoAnAiBSABKAJsRgRAIAcgBUEBEIYBIAEoAnAhBQsgASgCaCAFQQJ0akEANgIAIAEgASgCcEEBajYCcCAGQX9qIgYNAAsLIARBCGogBEEYahAqIAQoAggiBQ0ACwsCQAJAIAMgASgCgAFGBEAgASgCeCIFIAJGDQEgBSACIANBAnQQugFFDQELIARBGGogBxCwASAEQQhqIAgQsAEgBEEsaiAEQRBqKAIANgIAIAQgBCkDCDcCJCAAQRBqIARBKGopAwA3AgAgAEEIaiAEQSBqKQMANwIAIAAgBCkDGDcCAAwBCyAAQQA2AgALIAEgASgCdEEBajYCdCABIAEoAmRBAWo2AmQgBEEwaiQADwsLQcLGwgBBECAEQRhqQbivwgAQmwEAC8oDAg5/AX4gAUF/aiELIABBDGohDCABQTJJIQ1BASEDAkACQAJ/A0ACf0EAIAMgAU8NABpBASAAIANBA3QiBmoiAkF8aigCACgCACACKAIEKAIAIgJJDQAaIAYgDGohBQNAQQEgAyALRg0DGiADQQFqIQMgAiAFKAIAKAIAIgJPIAVBCGohBQ0ACyADIAFJCyABIANGIA0gASADRnINARogA0F/aiICIAFPDQJFDQMgCEEBaiEIIAAgAkEDdGoiAikCACEQIAIgACADQQN0aiIEKQIANwIAIAQgEDcCACAAIAMQbQJAIAEgA2siCkECSQ0AIAQoAgQiCSgCACAEQQxqKAIAKAIATw0AIAQoAgAhDiAEIAQpAgg3AgAgBEEIaiEHAkAgCkEDSQ0AIAkoAgAgBEEUaigCACgCAE8NAEEDIQJBAiEFA0AgBCAFQQN0aiIHQXhqIAcpAgA3AgAgAiAKTw0BIAJBAWoiBiACSQ0BIAJBA3QhDyACIQUgBiECIAkoAgAgBCAPaigCBCgCAEkNAAsLIAcgCTYCBCAHIA42AgALIAhBBUcNAAtBAAsPC0Gwp8IAIAIgARCsAQALQcCnwgAgAyABEKwBAAuzAwEPfwJAIAJBAXUiCCACSQRAIAAoAhQhDiAAKAIQIQwgACgCBCEPIABBDGogAEEIaiAAKAIAQQFGGyEQIAIhCQNAAkACQCAEIAEgCEEYbGoiESgCBCIGTw0AAkADQCAOIAQgDGoiB0YEQEF/IQcMBAsgByAQKAIAIhJPDQEgByAPai0AACARKAIAIARqLQAAayIHDQMgBEEBaiIEIAZHDQALIAYhBAwBC0G0yMIAIAcgEhCsAQALQQAhBwsgCiAEIAdBAEgiBhshCgJAIAggCSAGGyIJIAUgCCAGGyIFayIHQQJODQAgDSAFIAlGIAVBAEpyckEBIQ1BAXFFDQACfwNAIAUgAk8NBQJAIAogASAFQRhsaiIEKAIEIglPBEAgACAJIAxqIgk2AhAgBCgCECIGRQ0BIAYgACADIARBFGooAgAoAgwRAwAgACAJNgIQDQELQQAgBCgCCCIFQQBIDQIaDAELCyABIAVBGGxqKAIMCw8LIAQgCyAGGyILIAogCiALSxshBCAHQQF1IAVqIgggAkkNAAsLQaTIwgAgCCACEKwBAAtBxMjCACAFIAIQrAEAC90DAQV/AkAgAUEgaigCACIERQ0AIARBAnQhBSABKAIYIgYhAwNAAkAgAygCACICQb9/akEaSQ0AIAJBgAFPBEBB+OXAACACEFwNAQsgA0EEaiEDIAVBfGoiBQ0BDAILCyAEQQJ0IQUDQCAGKAIAIgMhBCAGIAMgA0EAQbcFIANB7DxJGyICIAJB2wJqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQa4BaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkHXAGoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBK2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBFmoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBC2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBBWoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBA2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBAWoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBAWoiAiACQQR0QdD4wABqKAIAIANLG0EEdCICQdD4wABqKAIARgRAIAJB1PjAAGooAgAhBAsgBCAEQYCAxABGGzYCACAGQQRqIQYgBUF8aiIFDQALCyAAIAFBMBC/ARoLyAMCC38BfiMAQRBrIgYkACAGIAE2AgBCpcaIocicp/lLIQ5BASEEIAEhAwNAIA4gA61C/wGDhUKzg4CAgCB+IQ4gBEEERwRAIAQgBmogBEEBaiEELQAAIQMMAQsLIA5CGYinIgxB/wBxIgNBCHQgA3IiA0EQdCADciENIAAoAgAiByAOp3EhBCAAKAIIIQkgACgCBCEDAkADQAJAIAMgBGooAAAiCiANcyIFQX9zIAVB//37d2pxQYCBgoR4cSIFRQ0AA0AgASAJIAVoQQN2IARqIAdxQQN0aiILKAIARwRAIAVBf2ogBXEiBQ0BDAILCyALLQAEGiALIAI6AAQMAgsgCEEEaiIIIARqIAdxIQQgCiAKQQF0cUGAgYKEeHFFDQALIAMgACAOEJwBIgRqLQAAQQFxIQUgACgCDCIIIAVFckUEQCAGIAAQESAAIA4QnAEhBCAAKAIAIQcgACgCCCEJIAAoAgwhCCAAKAIEIQMLIAAgCCAFazYCDCADIARqIAxB/wBxIgU6AAAgByAEQXxqcSADakEEaiAFOgAAIAkgBEEDdGoiAyACOgAEIAMgATYCACAAIAAoAhBBAWo2AhALIAZBEGokAAuqAwEEfyMAQUBqIgMkAAJAAkACQCABKAIIIgVBAU0EQCAFQQFrDQMgA0EIaiACIAEoAgwiBCABQRRqKAIAEGkgAUEMaiEFIAMoAggNASABQSBqKAIAIQQMAgsQ1gEACyADQSBqIgYgA0EQaigCADYCACADIAMpAwg3AxggA0EoaiADQRhqELABIAFBEGooAgAEQCAEECELIAUgAykDKDcCACAFQQhqIANBMGoiBCgCADYCACAEIAYoAgA2AgAgAyADKQMYNwMoIAFBGGohBCABQRxqKAIABEAgBCgCABAhCyAEIAMpAyg3AgAgBEEIaiADQTBqKAIANgIAIAEoAgBBCGogAUEgaigCACIENgIACyADQShqIAIgASgCGCICIAQQOSADKAIoBEAgAUEQaigCAARAIAUoAgAQIQsgBSADKQMoNwIAIAVBCGogA0EwaigCADYCACABQRxqKAIABEAgAhAhCyABQRhqIgJBCGogA0E0aiIFQQhqKAIANgIAIAIgBSkCADcCACABKAIAQQhqIAEoAiA2AgALCyAAIAFBMBC/ARogA0FAayQAC7IDAQ5/IAFBf2ohDCAAQQRqIQ0gAUEySSEOQQEhAwJ/A0ACf0EAIAMgAU8NABpBASAAIANBAnQiBGoiBygCACICIAdBfGooAgBJDQAaIAQgDWohBQNAQQEgAyAMRg0DGiADQQFqIQMgBSgCACIEIAJPIAVBBGohBSAEIQINAAsgAyABSQshBSABIANGIA4gASADRnINARoCQAJAAkACQCADQX9qIgIgAUkEQCAFRQ0BIAlBAWohCSAAIAJBAnRqIgIoAgAhBCACIAAgA0ECdGoiBigCADYCACAGIAQ2AgAgACADEHUgASADayILQQJJDQQgBigCBCICIAYoAgAiCk8NBCAGQQRqIQQgBiACNgIAIAtBA0kNAyAGKAIIIgggCk8NAyAGQQhqIQVBAyECQQIhBwNAIAdBAnQgBmpBfGogCDYCACACIAtPDQMgAkEBaiIIIAJJDQMgBSEEIAYgAkECdGoiDyEFIAIhByAIIQIgDygCACIIIApJDQALDAMLQbCnwgAgAiABEKwBAAtBwKfCACADIAEQrAEACyAFIQQLIAQgCjYCAAsgCUEFRw0AC0EACwuzAwENfyABQX9qIQsgAEEEaiEMIAFBMkkhDUEBIQMCQAJAAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0ECdCIHaiIEKAIAIgIgBEF8aigCABC3AQ0AGiAHIAxqIQYDQEEBIAMgC0YNAxogA0EBaiEDIAYoAgAiBCACELcBIAZBBGohBiAEIQJFDQALIAMgAUkLIAEgA0YgDSABIANGcg0BGiADQX9qIgIgAU8NAkUNAyAIQQFqIQggACACQQJ0aiIEKAIAIQIgBCAAIANBAnRqIgUoAgA2AgAgBSACNgIAIAAgAxCAAQJAIAEgA2siCkECSQ0AIAUoAgQiAiAFKAIAIgkQtwFFDQAgBUEEaiEGIAUgAjYCAAJAIApBA0kNACAFKAIIIAkQtwFFDQAgBUEIaiEEQQMhAkECIQcDQCAHQQJ0IAVqQXxqIAQiBigCADYCACACIApPDQEgAkEBaiIEIAJJDQEgAkECdCEOIAIhByAEIQIgBSAOaiIEKAIAIAkQtwENAAsLIAYgCTYCAAsgCEEFRw0AC0EACw8LQbCnwgAgAiABEKwBAAtBwKfCACADIAEQrAEAC7wDAgt/A34jAEEgayIFJAACQAJAAkAgAUECSQ0AIABBKGoiAygCACAAKAIIIghPDQAgACkDACENIAAgACkDIDcDACAAQRRqKQIAIQ4gACkCDCEPIABBEGogAEEwaikDADcDACAAQQhqIAMpAwA3AwAgAEEcaigCACEDIABBGGogAEE4aikDADcDACAFQRBqIgogDjcDACAFQRhqIgsgAzYCACAFIA83AwggAEEgaiECQQMhBkEBIQcCQCABQQNJDQBBAiEJA0AgCSIDIAFPDQMgACADQQV0aiIEKAIIIAhPDQEgA0F/aiICIAFPDQQgACACQQV0aiICIAQpAwA3AwAgAkEYaiAEQRhqKQMANwMAIAJBEGogBEEQaikDADcDACACQQhqIARBCGopAwA3AwAgBiABSQRAIAYhCSAGQQFqIgIgBkkgAiEGIAMhByAEIQINAgwBCwsgAyEHIAQhAgsgAiANNwMAIAAgB0EFdGogCDYCCCACQRxqIAsoAgA2AgAgAkEUaiAKKQMANwIAIAIgBSkDCDcCDAsgBUEgaiQADwtB0KzCACADIAEQrAEAC0HgrMIAIAIgARCsAQALxgMCCH8BfiMAQSBrIgIkAAJAEMYBIgZFDQACQCAGKAIARQRAIAZBfzYCACAGQQRqIAEQWCIFRQ0BIAUoAggiBARAIARBBHQhByAFKAIAQQxqIQEDQCABKAIAIANqIQMgAUEQaiEBIAdBcGoiBw0ACwsgAkEIaiADIARqELYBIAJBADYCGCACIAIoAgw2AhQgAiACKAIIIgQ2AhAgBSgCCCIBBEAgAUEEdCEHIAUoAgBBDGohA0EAIQEDQCADQXhqKAIAIQggAkEQaiABIAMoAgAiBRCVASACIAUgAigCGCIJaiIBNgIYIAkgAigCECIEaiAIIAUQvwEaIAIoAhQgAUYEQCACQRBqIAFBARCVASACKAIQIQQgAigCGCEBCyABIARqQQA6AAAgAiABQQFqIgE2AhggA0EQaiEDIAdBcGoiBw0ACwsgAikCFCEKIAYgBigCAEEBajYCACAERQ0CIAIgCjcCFCACIAQ2AhAgAiACQRBqEJEBIAAgAikDADcCACACQSBqJAAPC0HCxsIAQRAgAkEQakHUxsIAEJsBAAtBi+vCAEErQbjrwgAQvgEAC0H8xcIAQcYAIAJBEGpB5MbCABCbAQALnwMBA38jAEHQAGsiBSQAIAUgAjYCQCAFIAI2AjwgBSABNgI4IAVBEGogBUE4ahCRASAFKAIUIQcgBSgCECEGIAUgBDYCQCAFIAQ2AjwgBSADNgI4IAVBCGogBUE4ahCRASAFKAIMIQQgBSgCCCEDEMcBIgEEQAJAIAEoAgBFBEAgAUF/NgIAIAFBBGogABBXIgBFDQEgBUEYaiAGIAYgB2oQIyAFQShqIAMgAyAEahAjIAVBQGsgBUEgaigCADYCACAFQcwAaiAFQTBqKAIANgIAIAUgBSkDGDcDOCAFIAUpAyg3AkQgAEGYAWohAiAAKAKcAQRAIAIoAgAQIQsgACgCqAEEQCAAKAKkARAhCyACIAUpAzg3AgAgAkEQaiAFQcgAaikDADcCACACQQhqIAVBQGspAwA3AgAgASABKAIAQQFqNgIAIAQEQCADECELIAcEQCAGECELIAVB0ABqJAAPC0HCxsIAQRAgBUE4akHMqMIAEJsBAAtBi+vCAEErQbjrwgAQvgEAC0H8xcIAQcYAIAVBOGpB3KjCABCbAQALrgMCCn8CfCACKAIEIgcgAUEYaigCAGoiBCADKAIEIgVLBEAgA0EIaigCACEIIAErAwAhDgJAIAJBCGooAgAiCyAHayIJRQRADAELIAggBWsiBkUEQCAOIQ9EAAAAAAAAAAAhDgwBCyAOIA4gCbgiD6JEAAAAAAAAJECiIA8gBrigo5tEAAAAAAAAJECjIg+hRAAAAAAAACRAohCBAUQAAAAAAAAkQKMhDgsgAigCACEMAn9BACACLQAoIgJBCkYNABpBACACQXtqIgJBBEsNABpCgYKEgBAgAq1CA4aIpwshDSAEIAVrIQQgAygCACEGAkAgAy0AKCICQQpGDQAgAkF7aiICQQRLDQBCgYKEgBAgAq1CA4aIpyEKCyAAQQE6AB0gACANOgAcIAAgCTYCGCAAQQA2AhQgACALNgIQIAAgBzYCDCAAIAw2AgggACAPOQMAIABBPWogAS0AHToAACAAQTxqIAo6AAAgAEE4aiAENgIAIABBNGpBADYCACAAQTBqIAg2AgAgAEEsaiAFNgIAIABBKGogBjYCACAAQSBqIA45AwAPCyAAQQI6ABwL5wIBBn8CQAJAIAJBA3EiBEUNAEEEIARrIgRFDQAgAyAEIAQgA0sbIQVBACEEIAFB/wFxIQgDQCAEIAVGDQEgAiAEaiAEQQFqIQQtAAAiBiAIRw0AC0EBIQMgBiABQf8BcUZBAWpBAXEgBGpBf2ohBAwBCyABQf8BcSEIAkACQCADQQhJDQAgBSADQXhqIgZLDQAgCEGBgoQIbCEEA0AgAiAFaiIHQQRqKAIAIARzIglBf3MgCUH//ft3anEgBygCACAEcyIHQX9zIAdB//37d2pxckGAgYKEeHFFBEAgBUEIaiIFIAZNDQELCyAFIANLDQELIAIgBWohAiADIAVrIQZBACEDQQAhBAJAA0AgBCAGRg0BIAIgBGogBEEBaiEELQAAIgcgCEcNAAtBASEDIAcgAUH/AXFGQQFqQQFxIARqQX9qIQQLIAQgBWohBAwBCyAFIAMQrQEACyAAIAQ2AgQgACADNgIAC+ICAQV/AkBBzf97IABBECAAQRBLGyIAayABTQ0AIABBECABQQtqQXhxIAFBC0kbIgRqQQxqEAQiAkUNACACQXhqIQECQCAAQX9qIgMgAnFFBEAgASEADAELIAJBfGoiBSgCACIGQXhxIAIgA2pBACAAa3FBeGoiAiAAIAJqIAIgAWtBEEsbIgAgAWsiAmshAyAGQQNxBEAgACADIAAoAgRBAXFyQQJyNgIEIAAgA2oiAyADKAIEQQFyNgIEIAUgAiAFKAIAQQFxckECcjYCACAAIAAoAgRBAXI2AgQgASACEC8MAQsgASgCACEBIAAgAzYCBCAAIAEgAmo2AgALAkAgACgCBCIBQQNxRQ0AIAFBeHEiAiAEQRBqTQ0AIAAgBCABQQFxckECcjYCBCAAIARqIgEgAiAEayIEQQNyNgIEIAAgAmoiAiACKAIEQQFyNgIEIAEgBBAvCyAAQQhqIQMLIAML9wIBCn8jAEHwAGsiCCQAIABBQGshCwNAIAJBAXQiA0EBciEFAkACQAJAAkAgA0ECaiIHIAFPDQAgBSABTw0BIAAgA0HkAGxqIQlBACEDA0AgA0EEaiIEQShGDQEgAyAJaiEGIAQhA0F/IAZBiAJqKAIAIgQgBkGkAWooAgAiBkcgBCAGSBsiBEUNAAsgBEH/AXFB/wFHDQAgByEFCwJAIAUgAU8NACACIAFPDQIgCyAFQeQAbCIDaiEGIAsgAkHkAGwiAmohCSAAIANqIQcgACACaiEEQQAhAwNAIANBBGoiAkEoRg0BIAMgCWohCiADIAZqIQwgAiEDQX8gDCgCACICIAooAgAiCkcgAiAKSBsiAkUNAAsgAkH/AXFB/wFGDQMLIAhB8ABqJAAPC0GsqMIAIAUgARCsAQALQbyowgAgAiABEKwBAAsgCEEMaiAEQeQAEL8BGiAEIAdB5AAQngEaIAcgCEEMakHkABC/ARogBSECDAALAAv5AgEFfyMAQTBrIgQkAAJAAkAgAEEIaigCACIFIAAoAgQiBk8EQCAFIAJLDQEgBSAGayEHIAAgAygCAAR/IAMoAlQNAyADQX82AlRBACECIANB4ABqIghBADYCACADQdgAaiIAQQAgBxCVASABIAZBAnRqIAEgBUECdGogABBMIAAoAgAhASAEQRBqIgUgCCgCACIANgIAIAQgADYCKCAEQgA3AyAgBCAANgIcIARBADYCGCAEIAE2AgwgBEEANgIIIARBCGogAygCABEHABogBCgCDCEAIARBFGooAgAgBSgCACIHIAQoAggiCEEBRhsiBQRAIAUhBiAAIQEDQCACIAEtAABBwAFxQYABRmohAiABQQFqIQEgBkF/aiIGDQALCyAIRSAHRXJFBEAgABAhCyADIAMoAlRBAWo2AlQgBSACawUgBws2AgwgBEEwaiQADwsgBiAFEK0BAAsgBSACEKsBAAtBwsbCAEEQIARBCGpBuK/CABCbAQAL4gIBC38jAEEQayIGJAAgAEEIaigCACIFIAAoAgQiBE8EQCAFIAJNBEAgBkKlxoihyJyn+Us3AwggASAEQQJ0aiICIAUgBGsiBSAGQQhqEHQgBigCCCIBQRl2IgRBCHQgBHIiBEEQdCAEciEKIAVBAnQhCyADKAIYIgQgAXEhASADQSBqKAIAIQggA0EcaigCACEMA0ACQAJAIAEgDGooAAAiCSAKcyIDQX9zIANB//37d2pxQYCBgoR4cSIDRQ0AA0ACQCAFIAggA2hBA3YgAWogBHEiDUEEdGoiBygCCEYEQCAHKAIAIgcgAkYNASACIAcgCxC6AUUNAQsgA0F/aiADcSIDDQEMAgsLIAggDUEEdGotAAwhAwwBCyAOQQRqIg4gAWogBHEhAUEKIQMgCSAJQQF0cUGAgYKEeHFFDQELCyAAIAM6ABAgBkEQaiQADwsgBSACEKsBAAsgBCAFEK0BAAvdAgEKfyMAQfAAayIHJAACQCABQQJJDQAgAUF+aiEEIAAgAUHkAGxqIgVBuH5qIQYgBUGcf2ohCEFYIQEDQCABQQRqIgNFDQEgASAFaiECIAMhAUF/IAJBoH9qKAIAIgMgAkEEaigCACICRyADIAJIGyIDRQ0ACyADQf8BcUH/AUcNACAHQQhqIAhB5AAQvwEaIAggBkHkABC/ARogACAEQeQAbCIBaiEDAkAgBEUNACAAIAFqQVxqIQYgB0HIAGohCgNAIAAgBEF/aiIIQeQAbGohBUEAIQEDQCABQQRqIgJBKEYNAiABIApqIQkgASAGaiELIAIhAUF/IAsoAgAiAiAJKAIAIglHIAIgCUgbIgJFDQALIAJB/wFxQf8BRw0BIAAgBEHkAGxqIAVB5AAQvwEaIAZBnH9qIQYgBSEDIAgiBA0ACwsgAyAHQQhqQeQAEL8BGgsgB0HwAGokAAvNAgEGfyABIAJBAXRqIQkgAEGA/gNxQQh2IQogAEH/AXEhDAJAAkACQANAIAFBAmohCyAHIAEtAAEiAmohCCAKIAEtAAAiAUcEQCABIApLDQMgCCEHIAsiASAJRw0BDAMLIAggB08EQCAIIARLDQIgAyAHaiEBAkADQCACRQ0BIAJBf2ohAiABLQAAIAFBAWohASAMRw0AC0EAIQIMBQsgCCEHIAsiASAJRw0BDAMLCyAHIAgQrQEACyAIIAQQqwEACyAAQf//A3EhByAFIAZqIQNBASECA0ACQCAFQQFqIQACfyAAIAUtAAAiAUEYdEEYdSIEQQBODQAaIAAgA0YNASAFLQABIARB/wBxQQh0ciEBIAVBAmoLIQUgByABayIHQQBIDQIgAkEBcyECIAMgBUcNAQwCCwtBi+vCAEErQbjrwgAQvgEACyACQQFxC9sCAQN/IwBBEGsiAyQAIAAgAUcEQANAIABBBGoCQCAAKAIAIgBBgAFPBEAgA0EANgIMAn8gAEH/D00EQCADIABBP3FBgAFyOgANIAMgAEEGdkEfcUHAAXI6AAxBAgwBCyAAQf//A00EQCADIABBP3FBgAFyOgAOIAMgAEEGdkE/cUGAAXI6AA0gAyAAQQx2QQ9xQeABcjoADEEDDAELIAMgAEE/cUGAAXI6AA8gAyAAQRJ2QfABcjoADCADIABBBnZBP3FBgAFyOgAOIAMgAEEMdkE/cUGAAXI6AA1BBAshACACIAIoAgggABCVASACIAIoAggiBCAAajYCCCAEIAIoAgBqIANBDGogABC/ARoMAQsgAigCCCIEIAIoAgRGBH8gAiAEQQEQlQEgAigCCAUgBAsgAigCAGogADoAACACIAIoAghBAWo2AggLIgAgAUcNAAsLIANBEGokAAvKAgEFfyMAQSBrIgIkAAJAIAAoAhAiAyAAKAIUTw0AIAAoAgQhBSACIABBDGogAEEIaiAAKAIAQQFGGygCACIENgIEIAIgBTYCACACIAM2AgggAiAENgIMAkACQCADRSADIARGckUEQCAEIANNDQEgAyAFaiwAAEG/f0wNAQsgAyAFaiAEIANrIAFBARCTAUUNAiAAIAAoAhBBAWoiATYCECABRQ0BIABBDGogAEEIaiAAKAIAIgNBAUYbKAIAIgQgAUYNASAAQQxqIABBCGogAxshBQNAIAQgAUsEQCAAKAIEIAFqLAAAQb9/Sg0DC0EBIQYgACABQQFqIgM2AhAgAyABSQ0DIAUoAgAiBCADIgFHDQALDAILIAIgAkEMajYCGCACIAJBCGo2AhQgAiACNgIQIAJBEGoQzAEAC0EBIQYLIAJBIGokACAGC+ICAgZ/An4jAEEgayIDJAACQBDGASIERQ0AAkAgBCgCAEUEQCAEQX82AgBBBCEGIARBBGogARBYIgFFDQECfiABKAIIIgJFBEBCAAwBCwJAAkAgAkH/////A3EgAkcNACACQQJ0IgVBAEgNACABKAIAIQEgBUEEENcBIgYNASAFQQRBtPLCACgCACIAQQIgABsRAAAACxDTAQALIAKtIQggAkEEdCIHIQUgBiECA0AgAiABKAIANgIAIAJBBGohAiABQRBqIQEgBUFwaiIFDQALIAdBcGpBBHZBAWqtQiCGCyEJIAQgBCgCAEEBajYCACAGRQ0CIAMgCCAJhDcCFCADIAY2AhAgA0EIaiADQRBqEIkBIAAgAykDCDcCACADQSBqJAAPC0HCxsIAQRAgA0EQakHUxsIAEJsBAAtBi+vCAEErQbjrwgAQvgEAC0H8xcIAQcYAIANBEGpB5MbCABCbAQALwgIBCn8jAEHwAGsiBiQAAkAgAUECSQ0AIABB5ABqIQRBwAAhAgNAIAJBBGoiA0HoAEYNASAAIAJqIQUgAyECQX8gBSgCACIDIAVB5ABqKAIAIgVHIAMgBUgbIgNFDQALIANB/wFxQf8BRw0AIAZBCGogAEHkABC/ARogACAEQeQAEL8BIglB5ABqIQMCQCABQQNJDQAgCUGIAmohByAGQcgAaiEKQQIhAgNAIAJBAWogCSACQeQAbGohAEEAIQIDQCACQQRqIgRBKEYNAiACIAdqIQggAiAKaiELIAQhAkF/IAsoAgAiBCAIKAIAIghHIAQgCEgbIgRFDQALIARB/wFxQf8BRw0BIABBnH9qIABB5AAQvwEaIAdB5ABqIQcgACEDIgIgAUcNAAsLIAMgBkEIakHkABC/ARoLIAZB8ABqJAALvQICBH8BfiMAQRBrIgQkAAJAIAFBAkkNACABQQxsIABqIgNBdGoiAkEDIANBaGoiA0EDEJkBQf8BcUH/AUcNACACKQIAIQYgAiADKQIANwIAIARBCGoiBSACQQhqIgIoAgA2AgAgAiADQQhqKAIANgIAIAQgBjcDACAAIAFBfmoiAkEMbGohAwJAIAJFDQAgBEEDIAAgAUF9aiICQQxsakEDEJkBQf8BcUH/AUcNACABQQxsIABqQVBqIQEgAAJ/AkADQCABQSBqIAFBFGooAgA2AgAgAUEYaiABQQxqKQIANwIAIAJFDQEgAkF/aiECIARBAyABQQMQmQEgAUF0aiEBQf8BcUH/AUYNAAsgAkEBagwBC0EAC0EMbGohAwsgAyAEKQMANwIAIANBCGogBSgCADYCAAsgBEEQaiQAC74CAgV/AX4jAEEwayIEJABBJyECAkAgAEKQzgBUBEAgACEHDAELA0AgBEEJaiACaiIDQXxqIAAgAEKQzgCAIgdC8LF/fnynIgVB//8DcUHkAG4iBkEBdEH2gMAAai8AADsAACADQX5qIAZBnH9sIAVqQf//A3FBAXRB9oDAAGovAAA7AAAgAkF8aiECIABC/8HXL1YgByEADQALCyAHpyIDQeMASgRAIAJBfmoiAiAEQQlqaiAHpyIFQf//A3FB5ABuIgNBnH9sIAVqQf//A3FBAXRB9oDAAGovAAA7AAALAkAgA0EKTgRAIAJBfmoiAiAEQQlqaiADQQF0QfaAwABqLwAAOwAADAELIAJBf2oiAiAEQQlqaiADQTBqOgAACyABQYbpwgBBACAEQQlqIAJqQScgAmsQJSAEQTBqJAALtgIBB38CfyABQSBqKAIAIgQgAUEsaigCACIFTQRAIAEgBDYCLCAEDAELIAFBJGogBSAEIAVrIgYQlQEgASgCLCEDIAEoAiQhByABAn8CQCAGQQJPBEAgBUF/cyAEaiEEA0AgAyAHakEAOgAAIANBAWohAyAEQX9qIgQNAAsMAQsgBg0AIAMMAQsgAyAHakEAOgAAIANBAWoLIgQ2AiwgASgCIAshAwJAIAQgAyADIARLGyIGRQ0AIAEoAiQhByABKAIYIQRBACEDA0AgA0EBaiEFIAMgB2oCQCACIAQoAgAiCBBZQf8BcSIDQQhHDQBBACEDQQQgCCACEDgiCEH/AXFBAkYNACAIQQJ0QQRxIQMLIAM6AAAgBSAGTw0BIARBBGohBCAFIQMMAAsACyAAIAFBMBC/ARoLvwIBBH8gAEIANwIQIAACf0EAIAFBCHYiA0UNABpBHyABQf///wdLDQAaIAFBBiADZyIDa0EfcXZBAXEgA0EBdGtBPmoLIgI2AhwgAkECdEHw8MIAaiEEAkACQAJAAkBB5O7CACgCACIFQQEgAkEfcXQiA3EEQCAEKAIAIgMoAgRBeHEgAUcNASADIQIMAgtB5O7CACADIAVyNgIAIAQgADYCACAAIAQ2AhgMAwsgAUEAQRkgAkEBdmtBH3EgAkEfRht0IQQDQCADIARBHXZBBHFqQRBqIgUoAgAiAkUNAiAEQQF0IQQgAiEDIAIoAgRBeHEgAUcNAAsLIAIoAggiASAANgIMIAIgADYCCCAAQQA2AhggACACNgIMIAAgATYCCA8LIAUgADYCACAAIAM2AhgLIAAgADYCDCAAIAA2AggLuAIBBn8jAEEQayICJAACQCACQX8CfwJAIAEEQCABQQhJDQEgASABQf////8BcUYEQCABQQN0QQduDAMLQbSmwgBBHEHQpsIAEL4BAAsgAEIANwIAIABBFGpBADYCACAAQQxqQgQ3AgAgAEEIakGwpsIANgIADAILIAFBAWoLQX9qZ3YiAUEBaiIEEKcBAkAgAigCBCIDBEAgAigCCCEFIAIoAgAiBiADENcBIgdFDQEgB0H/ASABQQVqEMUBIQMgAEEUakEANgIAIABBDGogAyAFajYCACAAQQhqIAM2AgAgACABNgIEIABBADYCACAAQRBqIAEgBEEDdkEHbCABQQhJGzYCAAwCC0G0psIAQRxB0KbCABC+AQALIAYgA0G08sIAKAIAIgBBAiAAGxEAAAALIAJBEGokAAu4AgEGfyMAQRBrIgIkAAJAIAJBfwJ/AkAgAQRAIAFBCEkNASABIAFB/////wFxRgRAIAFBA3RBB24MAwtBtKbCAEEcQdCmwgAQvgEACyAAQgA3AgAgAEEUakEANgIAIABBDGpCCDcCACAAQQhqQbCmwgA2AgAMAgsgAUEBagtBf2pndiIBQQFqIgQQoQECQCACKAIEIgMEQCACKAIIIQUgAigCACIGIAMQ1wEiB0UNASAHQf8BIAFBBWoQxQEhAyAAQRRqQQA2AgAgAEEMaiADIAVqNgIAIABBCGogAzYCACAAIAE2AgQgAEEANgIAIABBEGogASAEQQN2QQdsIAFBCEkbNgIADAILQbSmwgBBHEHQpsIAEL4BAAsgBiADQbTywgAoAgAiAEECIAAbEQAAAAsgAkEQaiQAC6kCAQN/IwBBgAFrIgQkACAAKAIAIQACQAJAAn8CQCABKAIAIgNBEHFFBEAgACgCACECIANBIHENASACrSABEFEMAgsgACgCACECQQAhAANAIAAgBGpB/wBqIAJBD3EiA0EwciADQdcAaiADQQpJGzoAACAAQX9qIQAgAkEEdiICDQALIABBgAFqIgJBgQFPDQIgAUHMg8AAQQIgACAEakGAAWpBACAAaxAlDAELQQAhAANAIAAgBGpB/wBqIAJBD3EiA0EwciADQTdqIANBCkkbOgAAIABBf2ohACACQQR2IgINAAsgAEGAAWoiAkGBAU8NAiABQcyDwABBAiAAIARqQYABakEAIABrECULIARBgAFqJAAPCyACQYABEK0BAAsgAkGAARCtAQALpgICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEEIAAoAgQhCANAAkACQCACIAhqKAAAIgYgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgBCAAaEEDdiACaiADcSIJQewBbGooAgBHBEAgAEF/aiAAcSIADQEMAgsLIAQgCUHsAWxqQQRqIQAMAQsgBUEEaiIFIAJqIANxIQJBACEAIAYgBkEBdHFBgIGChHhxRQ0BCwsgAAukAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQQgACgCBCEIA0ACQAJAIAIgCGooAAAiBiAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIARQ0AA0AgASAEIABoQQN2IAJqIANxIglBBHRqKAIARwRAIABBf2ogAHEiAA0BDAILCyAEIAlBBHRqQQRqIQAMAQsgBUEEaiIFIAJqIANxIQJBACEAIAYgBkEBdHFBgIGChHhxRQ0BCwsgAAuoAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhBiADIAAoAgQiA3EhAiAAQQxqKAIAIQcgAEEIaigCACEIQQAhBANAAkACQCACIAhqKAAAIgUgBnMiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgByAAaEEDdiACaiADcUEDdGoiCSgCAEcEQCAAQX9qIABxIgANAQwCCwsgCS0ABCEADAELIARBBGoiBCACaiADcSECQQghACAFIAVBAXRxQYCBgoR4cUUNAQsLIAALqAIBBH8jAEEgayICJAACQAJAAkACQAJAAkAQxwEiAwRAIAMoAgANBiADQX82AgAgA0EEaiAAEFciBUUNAhDGASIEBEAgBCgCAA0HIARBfzYCACAEQQRqIAAQWCIARQ0EIAUgATYCECAAKAIEIAFPDQIgAkEIaiAAIAAoAggiACABIABrQQAQZCACKAIIQQFHDQIgAkEQaigCAA0FENMBAAsMBQsMBAsgBCAEKAIAQQFqNgIAIAMgAygCAEEBajYCACACQSBqJAAPC0GL68IAQStBuOvCABC+AQALQYvrwgBBK0G468IAEL4BAAtBq+zCAEEoQdirwgAQvgEAC0H8xcIAQcYAIAJBGGpB3KjCABCbAQALQcLGwgBBECACQRhqQcyowgAQmwEAC7ICAQV/IAAoAhghBAJAAkAgACAAKAIMIgFGBEAgAEEUQRAgAEEUaiIBKAIAIgMbaigCACICDQFBACEBDAILIAAoAggiAiABNgIMIAEgAjYCCAwBCyABIABBEGogAxshAwNAIAMhBSACIgFBFGoiAygCACICRQRAIAFBEGohAyABKAIQIQILIAINAAsgBUEANgIACwJAIARFDQACQCAAIAAoAhxBAnRB8PDCAGoiAigCAEYEQCACIAE2AgAgAQ0BQeTuwgBB5O7CACgCAEF+IAAoAhx3cTYCAA8LIARBEEEUIAQoAhAgAEYbaiABNgIAIAFFDQELIAEgBDYCGCAAKAIQIgIEQCABIAI2AhAgAiABNgIYCyAAQRRqKAIAIgBFDQAgAUEUaiAANgIAIAAgATYCGAsLpwIBAn8CfyABQYAQTwRAAkACQAJAAkACQCABQYCABE8EQCABQQx2QXBqIgJBgAJJDQFB0JzAACACQYACEKwBAAsgAUEGdkFgaiICQd8HSw0BIABBhAJqKAIAIgMgACACakGYAmotAAAiAk0NAiAAKAKAAiACQQN0agwGCyABQQZ2QT9xIAAgAmpB+AlqLQAAQQZ0ciICIABBjAJqKAIAIgNPDQIgAEGUAmooAgAiAyAAKAKIAiACai0AACICTQ0DIAAoApACIAJBA3RqDAULQbCcwAAgAkHgBxCsAQALQcCcwAAgAiADEKwBAAtB4JzAACACIAMQrAEAC0HwnMAAIAIgAxCsAQALIAAgAUEDdkH4////AXFqCykDAEIBIAFBP3GthoNCAFILlAIBA38jAEGAAWsiAyQAAkACQAJ/AkAgASgCACICQRBxRQRAIAJBIHENASAArSABEFEMAgtBACECA0AgAiADakH/AGogAEEPcSIEQTByIARB1wBqIARBCkkbOgAAIAJBf2ohAiAAQQR2IgANAAsgAkGAAWoiAEGBAU8NAiABQcyDwABBAiACIANqQYABakEAIAJrECUMAQtBACECA0AgAiADakH/AGogAEEPcSIEQTByIARBN2ogBEEKSRs6AAAgAkF/aiECIABBBHYiAA0ACyACQYABaiIAQYEBTw0CIAFBzIPAAEECIAIgA2pBgAFqQQAgAmsQJQsgA0GAAWokAA8LIABBgAEQrQEACyAAQYABEK0BAAuTAgELfyMAQRBrIgUkAAJAAkAgAUEITwRAIAFBAnYiAkEBdCEHQX8hBkF/IAFBf2pndiEIIAJBGGwgAGpBdGohBCABIQIDQCAGIAdqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAhxIgNBACABIAMgAUkbayIDIAFPDQMgBUEIaiIJIARBCGoiCigCADYCACAFIAQpAgA3AwAgACADQQxsaiIDQQhqIgsoAgAhDCAEIAMpAgA3AgAgCiAMNgIAIAsgCSgCADYCACADIAUpAwA3AgAgBEEMaiEEIAZBAWoiBkECRw0ACwsgBUEQaiQADwtBsKfCACADIAEQrAEAC0HAp8IAIAMgARCsAQALlQICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEIIAAoAgQhCUEAIQQDQAJAIAIgCWooAAAiBSAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIABEADQEEBIQYgCCAAaEEDdiACaiADcUEEdGooAgAgAUYNAiAAQX9qIABxIgANAAsLIARBBGoiBCACaiADcSECQQAhBiAFIAVBAXRxQYCBgoR4cUUNAQsLIAYLlgICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEIIAAoAgQhCUEAIQQDQAJAIAIgCWooAAAiBSAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIABEADQEEBIQYgCCAAaEEDdiACaiADcUHsAWxqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC5UCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghCCAAKAIEIQlBACEEA0ACQCACIAlqKAAAIgUgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAARAA0BBASEGIAggAGhBA3YgAmogA3FBKGxqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC5ECAQh/IwBBIGsiAyQAAkACQCAAKAIQIgQgACgCGGsgAkgNACAAQQhqIQUgAEEMaiEIIAAoAgAhCSAAKAIEIQYCQCAEIAJrIgRFDQAgCCAFIAlBAUYbKAIAIgogBEYNACAKIARNDQEgBCAGaiwAAEFASA0BCyADIAggBSAJGygCACIFNgIEIAMgBjYCACADIAQ2AgggAyAFNgIMIARFIAQgBUZyRQRAIAUgBE0NAiAEIAZqLAAAQb9/TA0CCyAEIAZqIAUgBGsgASACEJMBRQ0AIAAgACgCECACazYCEEEBIQcLIANBIGokACAHDwsgAyADQQxqNgIYIAMgA0EIajYCFCADIAM2AhAgA0EQahDMAQALqAIBBH8jAEEwayICJAAgASgCCCEFIAIgARCwASACKAIIIQQgAkEANgIoIAJCATcDICACQSBqQQAgBBCVASACKAIoIQMgBARAIAIoAiAgA2pBACAEQQJ0QXxqQQJ2QQFqIgQQxQEaIAMgBGohAwsgAkEYaiIEIAM2AgAgAiACKQMgNwMQQRRBBBDXASIDRQRAQRRBBEG08sIAKAIAIgBBAiAAGxEAAAALIANBigI7ARAgAyAFNgIMIAMgBTYCCCADQgA3AgAgAEKBgICAEDcCBCAAIAM2AgAgACACKQMANwIYIAAgAikDEDcCJCAAIAEpAgA3AgwgAEEUaiABQQhqKAIANgIAIABBIGogAkEIaigCADYCACAAQSxqIAQoAgA2AgAgAkEwaiQAC+IBAQJ/AkAgASgCBCIFIAJrIANPDQAgAiADaiIDIAJJIQICQAJAAkAgBARAIAJFDQEgACADNgIEDAMLIAJFDQEgACADNgIEDAILIAVBAXQiAiADIAIgA0sbIQMLIAMgA0H/////AHFHBEAMAQsgA0EEdCIEQX9MBEAMAQsCfyAFRQRAIARBBBDXAQwBCyABKAIAIAVBBHRBBCAEENQBCyICRQRAIARBBEG08sIAKAIAIgBBAiAAGxEAAAALIAEgAzYCBCABIAI2AgAMAQsgAEEIakEANgIAQQEhBgsgACAGNgIAC5kCAgF/A34jAEEgayIBJAAgAUEYakEUELYBIAEpAxghAiABQRBqQRQQpQEgASkDECEDIAFBCGpBFBClASABKQMIIQQgAEGAAWpBADYCACAAQfAAakIANwIAIABB6ABqIAM3AgAgAEHgAGpCADcCACAAQdgAaiACNwIAIABB0ABqQgA3AgAgAEHIAGpCBDcCACAAQcQAakGwpsIANgIAIABBPGpCADcCACAAQTRqQgQ3AgAgAEEwakGwpsIANgIAIABBKGpCADcCACAAQSBqQgQ3AgAgAEEcakGwpsIANgIAIABBFGpCADcCACAAQQxqQgQ3AgAgAEEIakGwpsIANgIAIABCADcCACAAQfgAaiAENwIAIAFBIGokAAvuAQEHfyMAQfAAayIFJAACQAJAIAFBCE8EQCABQQJ2IgJBAXQhB0F/IQZBfyABQX9qZ3YhCCACQcgBbCAAakGcf2ohBCABIQIDQCAGIAdqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAhxIgNBACABIAMgAUkbayIDIAFPDQMgBUEMaiAEQeQAEL8BGiAEIAAgA0HkAGxqIgRB5AAQngEgBCAFQQxqQeQAEL8BGkHkAGohBCAGQQFqIgZBAkcNAAsLIAVB8ABqJAAPC0Gwp8IAIAMgARCsAQALQcCnwgAgAyABEKwBAAuTAgEFfyAAKAIAIgQoAgQoAgAiBSABKAIAIghBA3RqKAIEKAIAIAUgAigCACIGQQN0aigCBCgCACIHSQRAIAEgBjYCACACIAg2AgAgBCgCCCIEIAQoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQN0aigCBCgCACEHCyAHIAUgAygCACIIQQN0aigCBCgCAEkEQCACIAg2AgAgAyAGNgIAIAQoAggiAyADKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkEDdGooAgQoAgAhBwsgBSABKAIAIgBBA3RqKAIEKAIAIAdJBEAgASAGNgIAIAIgADYCACAEKAIIIgAgACgCAEEBajYCAAsL/AECBn8BfiMAQRBrIgUkAANAIAJBAXQiA0EBciEEAkACQAJAIANBAmoiAyABSQRAIAQgAU8NASADIAQgACAEQQxsakEDIAAgA0EMbGpBAxCZAUH/AXFB/wFGGyEECyAEIAFJBEAgAiABTw0CIAAgAkEMbGoiAkEDIAAgBEEMbGoiA0EDEJkBQf8BcUH/AUYNAwsgBUEQaiQADwtBrKjCACAEIAEQrAEAC0G8qMIAIAIgARCsAQALIANBCGoiBigCACEHIAIpAgAhCSACIAMpAgA3AgAgAkEIaiICKAIAIQggAiAHNgIAIAYgCDYCACADIAk3AgAgBCECDAALAAuDAgEDfyMAQTBrIgQkAAJAAkAgASgCZEUEQCABQX82AmQgAUHwAGpBADYCACAEQQA2AhggBCABQSxqNgIUIARBAjYCECAEIAM2AgwgBCACNgIIIAFB6ABqIQYgBEEgaiAEQQhqECogBCgCIA0BDAILQcLGwgBBECAEQQhqQbivwgAQmwEACwNAIAYgBCgCKCIFIAUgBCgCLEECdGoQvQEgBEEgaiAEQQhqECogBCgCIA0ACwsCQAJAIAMgASgCcEYEQCABKAJoIgUgAkYNASAFIAIgA0ECdBC6AUUNAQsgACAGELABDAELIABBADYCAAsgASABKAJkQQFqNgJkIARBMGokAAuAAgEFfyAAKAIAIgQoAgQoAgAiBSACKAIAIgZBAnRqKAIAIgcgBSABKAIAIghBAnRqKAIASQRAIAEgBjYCACACIAg2AgAgBCgCCCIEIAQoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQJ0aigCACEHCyAFIAMoAgAiCEECdGooAgAgB0kEfyACIAg2AgAgAyAGNgIAIAQoAggiAyADKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkECdGooAgAFIAcLIAUgASgCACIAQQJ0aigCAEkEQCABIAY2AgAgAiAANgIAIAQoAggiACAAKAIAQQFqNgIACwvjAQEGfyMAQRBrIgUkACAAKAIAIgEEQCAAKAIEIgJBBGohAyABIAJqQQFqIQYgAigCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAFFBEADQCADIAZPDQIgBEHgAGohBCADKAIAIANBBGoiAiEDQYCBgoR4cSIBQYCBgoR4Rg0ACyABQYCBgoR4cyEBIAIhAwsgBCABaEEDdkEYbGoiAigCBARAIAIoAgAQIQsgAUF/aiABcSEBIAIoAhBFDQEgAigCDBAhDAELCyAFIAAoAgBBAWoQnwEgACgCBBAhCyAFQRBqJAAL5QEBBH8jAEFAaiIEJAAgBEEIaiABIAEgAmoQIyAEQRhqIAAgBCgCCCIFIAQoAhAiARBpIAQoAgwhBgJAIAQoAhgiAkUEQCAGIQcgBSECDAELIARBIGooAgAhASAEKAIcIQcgBkUNACAFECELIARBGGogACACIAEQOSAEIAE2AjggBCAHNgI0IAQgAjYCMCAAQRhqIgAgBEEwaiADEDICQCAEKAIYIgFFDQAgBEE4aiAEQSxqKAIANgIAIAQgBEEkaikCADcDMCAAIARBMGogAxAyIAQoAhxFDQAgARAhCyAEQUBrJAAL2AEBBX8CQCABQQJJDQAgACABQX5qIgJBA3RqIgMoAgQoAgAgAUEDdCAAaiIEQXxqKAIAIgUoAgBPDQAgBEF4aiIEKAIAIQYgBCADKQIANwIAAkAgAkUNACAAIAFBfWoiAkEDdGoiASgCBCgCACAFKAIATw0AIAFBfGohASAAAn8CQANAIAFBDGogAUEEaikCADcCACACRQ0BIAJBf2ohAiABKAIAIAFBeGohASgCACAFKAIASQ0ACyACQQFqDAELQQALQQN0aiEDCyADIAU2AgQgAyAGNgIACwvdAQIHfwF+IwBBEGsiBCQAIAFBAXYiAwRAIANBf2ohAgNAIAAgASACEGggAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQQxsIABqQXRqIQIgASEDA0AgA0F/aiIFIAFPDQIgBEEIaiAAQQhqIgMoAgAiBjYCACAEIAApAgAiCTcDACACQQhqIgcoAgAhCCAAIAIpAgA3AgAgAyAINgIAIAcgBjYCACACIAk3AgAgACAFQQAQaCACQXRqIQIgBSIDQQFLDQALCyAEQRBqJAAPC0HAp8IAIANBf2ogARCsAQAL3QEBBn8CQAJAAkAgAUECSQ0AIAAoAgQiBigCACAAQQxqKAIAKAIATw0AIAAoAgAhByAAIAApAgg3AgAgAEEIaiECAkAgAUEDSQ0AQQIhA0EDIQQDQCADIAFPDQMgBigCACAAIANBA3RqIgUoAgQoAgBPDQEgA0F/aiICIAFPDQQgACACQQN0aiAFKQIANwIAIAQgAUkEQCAFIQIgBCIDQQFqIgQgA0kNAgwBCwsgBSECCyACIAY2AgQgAiAHNgIACw8LQdCswgAgAyABEKwBAAtB4KzCACACIAEQrAEAC9QBAQZ/IwBBEGsiBSQAIAAoAgAiAgRAIAAoAgQiAUEEaiEDIAEgAmpBAWohBiABKAIAQX9zQYCBgoR4cSEBIAAoAgghBANAAkAgAQR/IAEFA0AgAyAGTw0CIARB4ABqIQQgAygCACADQQRqIgIhA0GAgYKEeHEiAUGAgYKEeEYNAAsgAiEDIAFBgIGChHhzCyICIAJBf2pxIQEgBCACaEEDdkEYbGoiAigCEEUNASACKAIMECEMAQsLIAUgACgCAEEBahCfASAAKAIEECELIAVBEGokAAvTAQEGfyMAQRBrIgUkACAAKAIAIgIEQCAAKAIEIgFBBGohAyABIAJqQQFqIQYgASgCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAEEfyABBQNAIAMgBk8NAiAEQUBrIQQgAygCACADQQRqIgIhA0GAgYKEeHEiAUGAgYKEeEYNAAsgAiEDIAFBgIGChHhzCyICIAJBf2pxIQEgBCACaEEDdkEEdGoiAigCBEUNASACKAIAECEMAQsLIAUgACgCAEEBahCoASAAKAIEECELIAVBEGokAAvOAQIGfwF+AkACQCABQQhPBEAgAUECdiICQQF0IQZBfyEFQX8gAUF/amd2IQcgAkEEdCAAakF4aiEEIAEhAgNAIAUgBmoiAyABTw0CIAJBDXQgAnMiAkERdiACcyICQQV0IAJzIgIgB3EiA0EAIAEgAyABSRtrIgMgAU8NAyAEKQIAIQggBCAAIANBA3RqIgMpAgA3AgAgAyAINwIAIARBCGohBCAFQQFqIgVBAkcNAAsLDwtBsKfCACADIAEQrAEAC0HAp8IAIAMgARCsAQALzAEBB38CQAJAIAFBCE8EQCABQQJ2IgJBAXQhBkF/IQVBfyABQX9qZ3YhByACQQN0IABqQXxqIQQgASECA0AgBSAGaiIDIAFPDQIgAkENdCACcyICQRF2IAJzIgJBBXQgAnMiAiAHcSIDQQAgASADIAFJG2siAyABTw0DIAQoAgAhCCAEIAAgA0ECdGoiAygCADYCACADIAg2AgAgBEEEaiEEIAVBAWoiBUECRw0ACwsPC0Gwp8IAIAMgARCsAQALQcCnwgAgAyABEKwBAAvJAQIDfwF+IwBBEGsiBSABNgIIIAIpAwAhBkEBIQMgASEEA0AgBiAErUL/AYOFQrODgICAIH4hBiADQQRGRQRAIAVBCGogA2ogA0EBaiEDLQAAIQQMAQsLIAIgBjcDACABBEAgACABQQJ0aiEBA0AgBSAAKAIAIgQ2AgxBASEDA0AgBiAErUL/AYOFQrODgICAIH4hBiADQQRGRQRAIAVBDGogA2ogA0EBaiEDLQAAIQQMAQsLIAIgBjcDACAAQQRqIgAgAUcNAAsLC8IBAQV/AkAgAUECSQ0AIAFBAnQgAGpBfGoiAygCACIEIAAgAUF+aiIFQQJ0aiIGKAIAIgJPDQAgAyACNgIAAkACQCAFRQ0AIAQgACABQX1qIgNBAnRqIgIoAgAiBU8NACAGIAU2AgAgA0UNASABQQJ0IABqQXBqIQEDQCAEIAEoAgAiAE8EQCABQQRqIQIMAwsgAUEEaiAANgIAIAFBfGohASADQX9qIgMNAAsgAUEEaiECDAELIAYhAgsgAiAENgIACwvUAQEBfyMAQeAAayIEJAAgBCABNgIIIAQgAzYCDCABIANGBEAgACACIAFBAnQQvwEaIARB4ABqJAAPCyAEQTxqQQk2AgAgBEE0akEKNgIAIARBJGpBAzYCACAEQgM3AhQgBEGcycIANgIQIARBCjYCLCAEIARBCGo2AkAgBCAEQQxqNgJEIARCBDcDWCAEQgE3AkwgBEGUycIANgJIIAQgBEEoajYCICAEIARByABqNgI4IAQgBEHEAGo2AjAgBCAEQUBrNgIoIARBEGpBtMnCABDEAQALxAEBBn8CQAJAAkAgAUECSQ0AIAAoAgQiBCAAKAIAIgUQtwFFDQAgAEEEaiECIAAgBDYCAEEDIQQCQCABQQNJDQBBAiEDA0AgAyABTw0DIAAgA0ECdGoiBigCACIHIAUQtwFFDQEgA0F/aiICIAFPDQQgACACQQJ0aiAHNgIAIAQgAUkEQCAGIQIgBCIDQQFqIgQgA0kNAgwBCwsgBiECCyACIAU2AgALDwtB0KzCACADIAEQrAEAC0HgrMIAIAIgARCsAQALugEBBH8jAEHwAGsiBCQAIAFBAXYiAgRAIAJBf2ohAgNAIAAgASACEEcgAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQeQAbCAAakGcf2ohAyABIQIDQCACQX9qIgUgAU8NAiAEQQxqIABB5AAQvwEaIAAgA0HkABCeASADIARBDGpB5AAQvwEhAyAFQQAQRyADQZx/aiEDIAUiAkEBSw0ACwsgBEHwAGokAA8LQcCnwgAgAkF/aiABEKwBAAvBAQIEfwF+QQMhAiMAQRBrIgRBAzYCCCABKQMAIQZBASEDA0AgBiACrUL/AYOFQrODgICAIH4hBiADQQRGRQRAIARBCGogA2ogA0EBaiEDLQAAIQIMAQsLIAEgBjcDACAAQQxqIQUDQCAEIAAoAgAiAjYCDEEBIQMDQCAGIAKtQv8Bg4VCs4OAgIAgfiEGIANBBEZFBEAgBEEMaiADaiADQQFqIQMtAAAhAgwBCwsgASAGNwMAIABBBGoiACAFRw0ACwu8AQECfwJAIAAoAgQiAUUNACAAKAIMIgIEQCACQTxsIQIDQCABEK8BIAFBPGohASACQURqIgINAAsLIAAoAggEQCAAKAIEECELIABBFGoQkgEgAEGcAWooAgAEQCAAQZgBaigCABAhCyAAQagBaigCAARAIABBpAFqKAIAECELIABBuAFqEHAgAEHQAWooAgAEQCAAQcwBaigCABAhCyAAQdwBaigCACIBRQ0AIABB4AFqKAIARQ0AIAEQIQsLwgECAn8BfgNAIAJBAXQiBEEBciEDAkACQAJAIARBAmoiBCABSQRAIAMgAU8NASAEIAMgACAEQQN0aigCBCgCACAAIANBA3RqKAIEKAIASRshAwsgAyABSQRAIAIgAU8NAiAAIANBA3RqIgQoAgQoAgAgACACQQN0aiICKAIEKAIASQ0DCw8LQayowgAgAyABEKwBAAtBvKjCACACIAEQrAEACyACKQIAIQUgAiAEKQIANwIAIAQgBTcCACADIQIMAAsAC9oBAgZ/AX4jACIAIABBQGpBYHEiACQAAkBBxO7CACgCAA0AIABBGGpBFBClASAAKQMYIQYgAEEQakEUEKUBIABCADcCLCAAIAY3AiRByO7CACgCAEHI7sIAIAApAyg3AwAgAEEANgIgQcTuwgAoAgAhAUHA7sIAIAApAyA3AwAgAEEANgI8IAAgACkDEDcCNEHY7sIAKAIAIQRB2O7CACAAKQM4NwMAQdTuwgAoAgAhBUHQ7sIAIAApAzA3AwAgAUUNAARAIAEQIQsgBEUNACAFECELJABBwO7CAAuuAQEEfwNAIAJBAXQiBEEBciEDAkACQAJAIARBAmoiBCABSQRAIAMgAU8NASAEIAMgACADQQJ0aigCACAAIARBAnRqKAIASRshAwsgAyABSQRAIAIgAU8NAiAAIAJBAnRqIgIoAgAiBCAAIANBAnRqIgUoAgAiBkkNAwsPC0GsqMIAIAMgARCsAQALQbyowgAgAiABEKwBAAsgAiAGNgIAIAUgBDYCACADIQIMAAsAC7IBAQR/A0AgAkEBdCIEQQFyIQMCQAJAAkAgBEECaiIEIAFJBEAgAyABTw0BIAQgAyAAIANBAnRqKAIAIAAgBEECdGooAgAQtwEbIQMLIAMgAUkEQCACIAFPDQIgACACQQJ0aiICKAIAIgQgACADQQJ0aiIFKAIAIgYQtwENAwsPC0GsqMIAIAMgARCsAQALQbyowgAgAiABEKwBAAsgAiAGNgIAIAUgBDYCACADIQIMAAsAC6cBAgR/AXwCQCAAKAIAIgNFDQADQCAAKAIEIAAoAgAiBCACbEEDdGogBLg5AwAgACgCBCABaiAAKAIAuDkDACABQQhqIQEgAyACQQFqIgJHDQALIAAoAgAiAkECSQ0AQQEhAQNAIAAoAgQgACgCACABbEEDdGpBCGogAUF/argiBTkDACAAKAIEIAEgACgCAGpBA3RqIAU5AwAgAiABQQFqIgFHDQALCwurAQEFfwJAIAFBAkkNACABQQJ0IABqQXxqIgUoAgAiAiAAIAFBfmoiA0ECdGoiBCgCACIGELcBRQ0AIAUgBjYCAAJAIANFDQAgAiABQQJ0IABqIgBBdGooAgAQtwFFDQAgAEFwaiEAA0ACQCAAIgFBCGogAUEEaigCADYCACADQX9qIgNFDQAgAUF8aiEAIAIgASgCABC3AQ0BCwsgAUEEaiEECyAEIAI2AgALC88BAwF/An4CfCMAQRBrIQEgAL0iAkI0iEL/D4MiA0KyCFgEfCAAmiAAIAJCAFMbIQQCfAJAIANC/gdaBEAgBEQAAAAAAAAwQ6BEAAAAAAAAMMOgIAShIgVEAAAAAAAA4D9kDQEgBCAFoCIAIAVEAAAAAAAA4L9lQQFzDQIaIABEAAAAAAAA8D+gDAILIAEgBEQAAAAAAAAwQ6A5AwggASsDCBogAEQAAAAAAAAAAKIPCyAEIAWgRAAAAAAAAPC/oAsiAJogACACQgBTGwUgAAsLsgEBBX8CQAJAIAEoAgAiAgRAIAEoAgQhBAwBCyABKAIMIQUgASgCCCEDA0AgAyAFTw0CIAMoAgAhAiABIANBBGoiAzYCCCABIAEoAgRBoAFqIgQ2AgQgASACQYCBgoR4cSIGQYCBgoR4cyICNgIAIAZBgIGChHhGDQALCyABIAJBf2ogAnE2AgAgASABKAIQQX9qNgIQIAAgBCACaEEDdkEobGpBKBC/ARoPCyAAQQI6ACQLqwEBB38CQAJAIAAoAggiA0ECSQRAIAMhAQwBCyAAKAIAIgZBBGohAkEBIQFBASEEA0AgAigCACIHIAFBAnQgBmoiBUF8aigCAEcEQCABIARHBEAgAiAFKAIANgIAIAUgBzYCAAsgAUEBaiEBCyACQQRqIQIgAyAEQQFqIgRHDQALIAEgA0sEQEG1rcIAQRxB1K3CABC+AQALIAAoAgggAUkNAQsgACABNgIICwuTAQEBfyMAQdAAayIFJAAgBUEQaiABIAEgAmoQIyAFQSBqIAMgAyAEahAjIAVBOGogBUEYaigCADYCACAFIAUpAxA3AzAgBUHIAGogBUEoaigCADYCACAFIAUpAyA3A0AgBSAAQSxqIAVBMGogBUFAaxAsAkAgBSgCACIARQ0AIAUoAgRFDQAgABAhCyAFQdAAaiQAC5MBAQF/IwBB0ABrIgUkACAFQRBqIAEgASACahAjIAVBIGogAyADIARqECMgBUE4aiAFQRhqKAIANgIAIAUgBSkDEDcDMCAFQcgAaiAFQShqKAIANgIAIAUgBSkDIDcDQCAFIABBQGsgBUEwaiAFQUBrECwCQCAFKAIAIgBFDQAgBSgCBEUNACAAECELIAVB0ABqJAALoQEBAn8CQAJAIAAoAgQiAyABayACSQRAIAEgAmoiAiABSQ0BIANBAXQiASACIAEgAksbIgFB/////wNxIAFHDQEgAUECdCIEQQBIDQECfyADRQRAIARBBBDXAQwBCyAAKAIAIANBAnRBBCAEENQBCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQ0wEACyAEQQRBtPLCACgCACIAQQIgABsRAAAAC6ABAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf///z9xIAFHDQEgAUEFdCIEQQBIDQECfyADRQRAIARBCBDXAQwBCyAAKAIAIANBBXRBCCAEENQBCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQ0wEACyAEQQhBtPLCACgCACIAQQIgABsRAAAAC6EBAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf////8BcSABRw0BIAFBA3QiBEEASA0BAn8gA0UEQCAEQQgQ1wEMAQsgACgCACADQQN0QQggBBDUAQsiAkUNAiAAIAE2AgQgACACNgIACw8LENMBAAsgBEEIQbTywgAoAgAiAEECIAAbEQAAAAuwAQEDfwJAAkACQCABKAIEIgMgASgCCCICRgRAIAMhAgwBCyADIAJJDQECQCACRQRAIAMEQCABKAIAECELIAFBBDYCAEEAIQIMAQsgASgCACADQQJ0QQQgAkECdCIEENQBIgNFDQMgASADNgIACyABIAI2AgQLIAAgAjYCBCAAIAEoAgA2AgAPC0Hd68IAQSRBhOzCABC+AQALIARBBEG08sIAKAIAIgBBAiAAGxEAAAALngEBCH8gACgCBCgCACIDIAEoAgAiBkHkAGxqQUBrIQcgAyACKAIAIghB5ABsakFAayEJAkADQCAEQQRqIgNBKEYNASAEIAlqIQogBCAHaiEFIAMhBEF/IAUoAgAiBSAKKAIAIgNHIAUgA0gbIgNFDQALIANB/wFxQf8BRw0AIAEgCDYCACACIAY2AgAgACgCCCIAIAAoAgBBAWo2AgALC5gBAQN/IAFBAXYiAwRAIANBf2ohAgNAIAAgASACEH0gAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQQJ0IABqQXxqIQIgASEDA0AgA0F/aiIEIAFPDQIgACgCACEDIAAgAigCADYCACACIAM2AgAgACAEQQAQfSACQXxqIQIgBCIDQQFLDQALCw8LQcCnwgAgA0F/aiABEKwBAAuYAQEDfyABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB+IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUECdCAAakF8aiECIAEhAwNAIANBf2oiBCABTw0CIAAoAgAhAyAAIAIoAgA2AgAgAiADNgIAIAAgBEEAEH4gAkF8aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCsAQALmgECA38BfiABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB7IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUEDdCAAakF4aiECIAEhAwNAIANBf2oiBCABTw0CIAApAgAhBSAAIAIpAgA3AgAgAiAFNwIAIAAgBEEAEHsgAkF4aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCsAQALnAEBAn8CQAJAIAEgACgCBEYEQCABQQFqIgIgAUkNASABQQF0IgMgAiADIAJLGyICQf////8BcSACRw0BIAJBA3QiA0EASA0BAn8gAUUEQCADQQQQ1wEMAQsgACgCACABQQN0QQQgAxDUAQsiAUUNAiAAIAI2AgQgACABNgIACw8LENMBAAsgA0EEQbTywgAoAgAiAEECIAAbEQAAAAu3AQIEfwJ+IwBBMGsiACQAAkBBiO3CACgCAA0AIABBGGpBChBVIABBEGogAEEsaigCACIDNgIAIABBCGogAEEkaikCACIENwMAQYTtwgAoAgAhAUGI7cIAKAIAIQJBhO3CACAAKQIcIgU3AgBBjO3CACAENwIAQZTtwgAgAzYCACAAIAU3AwBBgO3CAEEANgIAIAFFIAJFcg0AIABBGGogAUEBahChASACECELIABBMGokAEGA7cIAC7cBAgR/An4jAEEwayIAJAACQEHQ7cIAKAIADQAgAEEYakEKEFUgAEEQaiAAQSxqKAIAIgM2AgAgAEEIaiAAQSRqKQIAIgQ3AwBBzO3CACgCACEBQdDtwgAoAgAhAkHM7cIAIAApAhwiBTcCAEHU7cIAIAQ3AgBB3O3CACADNgIAIAAgBTcDAEHI7cIAQQA2AgAgAUUgAkVyDQAgAEEYaiABQQFqEKEBIAIQIQsgAEEwaiQAQcjtwgALqAEBAn8CQAJAAkAgASgCBCIDIAEoAggiAkYEQCADIQIMAQsgAyACSQ0BAkAgAkUEQCADBEAgASgCABAhCyABQQE2AgBBACECDAELIAEoAgAgA0EBIAIQ1AEiA0UNAyABIAM2AgALIAEgAjYCBAsgACACNgIEIAAgASgCADYCAA8LQd3rwgBBJEGE7MIAEL4BAAsgAkEBQbTywgAoAgAiAEECIAAbEQAAAAuMAQECfyMAQRBrIgEkACAAKAIEIgIEQCABIAJBAWoQpwEgAEEIaigCABAhCyAAQRhqEHEgAEEsahBrIABBQGsQayAAQdwAaigCAARAIABB2ABqKAIAECELIABB7ABqKAIABEAgAEHoAGooAgAQIQsgAEH8AGooAgAEQCAAQfgAaigCABAhCyABQRBqJAALogEBAn8jAEEQayIEJAACQAJAAkACQCADQQAgASADRxtFBEAgBCABNgIMIAQgADYCCAwBCyABIANNDQIgACADaiIFLAAAQb9/TA0CIAQgATYCDCAEIAA2AgggBSwAAEG/f0wNAQtBASEBIAAgAkYNAiACIAAgAxC6AQ0BDAILIARBCGoiACgCACAAKAIEQQAgAxAaAAtBACEBCyAEQRBqJAAgAQuKAQEEfyAAIAAoAhAiAkEBaiIBNgIQAkAgASACSQ0AIABBDGogAEEIaiAAKAIAIgJBAUYbKAIAIgMgAUYNACAAQQxqIABBCGogAhshBANAIAMgAUsEQCAAKAIEIAFqLAAAQb9/Sg0CCyAAIAFBAWoiAjYCECACIAFJDQEgBCgCACIDIAIiAUcNAAsLC4sBAQF/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQQBIDQECfyADRQRAIAFBARDXAQwBCyAAKAIAIANBASABENQBCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQ0wEACyABQQFBtPLCACgCACIAQQIgABsRAAAAC4YBAQR/IAAgACgCEEF/aiIBNgIQAkAgAUUNACAAQQxqIABBCGogACgCACICQQFGGygCACIDIAFGDQAgAEEMaiAAQQhqIAIbIQQDQCADIAFLBEAgACgCBCABaiwAAEG/f0oNAgsgACABQX9qIgI2AhAgAUEBRg0BIAQoAgAiAyACIgFHDQALCwudAQIDfwF8IAAgACgCCCACIAFrEIgBIAAoAgghAyAAIAEgAkcEfyAAKAIAIANBA3RqIQQgASEAA0AgAC0AACEFIABBAWohAEQAAAAAAADwPyEGAkAgBUF8aiIFQQNLDQACQCAFQQFrDgIBAQALRAAAAAAAAOA/IQYLIAQgBjkDACAEQQhqIQQgACACRw0ACyACIAMgAWtqBSADCzYCCAuDAQEDfyMAQSBrIgIkACABKAIAIQMgAkEIaiABKAIIIgEQpQEgAkEANgIYIAIgAikDCDcDECACQRBqQQAgARCGASACIAEgAigCGCIEajYCGCACKAIQIARBAnRqIAMgAUECdBC/ARogAEEIaiACKAIYNgIAIAAgAikDEDcCACACQSBqJAALbAEFfyADIAEgASADSxshCANAAkAgBCAISQRAIARBAWoiBSAETw0BC0F/IAEgA0cgASADSRsPCyACKAIAIQYgACgCACEHIAJBBGohAiAAQQRqIQAgBSEEQX8gBiAHRyAHIAZJGyIFRQ0ACyAFC34BAX8CQAJAIAEgAUH/////AXFGBEAgAUEDdCIDQX9MDQECQCADRQRAQQghAgwBCwJ/IAJFBEAgA0EIENcBDAELIAMQwwELIgJFDQMLIAAgATYCBCAAIAI2AgAPCxDYAQALENgBAAsgA0EIQbTywgAoAgAiAEECIAAbEQAAAAuBAQEBfyMAQUBqIgQkACAEIAE2AgwgBCAANgIIIAQgAzYCFCAEIAI2AhAgBEEsakECNgIAIARBPGpBAzYCACAEQgI3AhwgBEGUqsAANgIYIARBBDYCNCAEIARBMGo2AiggBCAEQRBqNgI4IAQgBEEIajYCMCAEQRhqQaiqwAAQxAEAC3ABBX8gACgCBCEDIAGnIQIgACgCACEEQQAhAANAIABBBGoiACACIARxIgVqIQIgAyAFaigAAEGAgYKEeHEiBkUNAAsgAyAGaEEDdiAFaiAEcSICaiwAAEEATgR/IAMoAgBBgIGChHhxaEEDdgUgAgsLdAECfyAAIAAoAgggARCGASAAKAIAIAAoAggiAkECdGohAwJAAkAgAUECTwRAIAFBf2ohAQNAIANBADYCACACQQFqIQIgA0EEaiEDIAFBf2oiAQ0ACwwBCyABRQ0BCyADQQA2AgAgAkEBaiECCyAAIAI2AggLbwEBfwJAIAEgAE8EQCACRQ0BIAAhAwNAIAMgAS0AADoAACABQQFqIQEgA0EBaiEDIAJBf2oiAg0ACwwBCyACRQ0AIAFBf2ohASAAQX9qIQMDQCACIANqIAEgAmotAAA6AAAgAkF/aiICDQALCyAAC2YCAX8BfiABrUIYfiIDQiCIpwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAOnaiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtnAgF/AX4gAa1C7AF+IgNCIIinBEAgAEEANgIEDwsCQAJAIAFBB2pBfHEiAiABQQRqSQ0AIAIgA6dqIgEgAkkNACABQX1JDQELIABBADYCBA8LIAAgAjYCCCAAQQQ2AgQgACABNgIAC2YCAX8BfiABrUIofiIDQiCIpwRAIABBADYCBA8LAkACQCABQQtqQXhxIgIgAUEEakkNACACIAOnaiIBIAJJDQAgAUF5SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEINgIEIAAgATYCAAtwAgJ/AX4CQAJAIAGtQuQAfiIEQiCIp0UEQCAEpyICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ1wEiA0UNAwsgACABNgIEIAAgAzYCAA8LENgBAAsQ2AEACyACQQRBtPLCACgCACIAQQIgABsRAAAAC2cBA38jAEEgayICJAACQCAAKAIAIAEQXQ0AIAFBHGooAgAhAyABKAIYIAJCBDcDGCACQgE3AgwgAkG8hcAANgIIIAMgAkEIahAbDQAgACgCBCABEF0gAkEgaiQADwsgAkEgaiQAQQELbgECfwJAAkAgASABQf////8AcUYEQCABQQR0IgJBf0wNAQJAIAJFBEBBBCEDDAELIAJBBBDXASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ2AEACxDYAQALIAJBBEG08sIAKAIAIgBBAiAAGxEAAAALbgECfwJAAkAgASABQf////8DcUYEQCABQQJ0IgJBf0wNAQJAIAJFBEBBBCEDDAELIAJBBBDXASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ2AEACxDYAQALIAJBBEG08sIAKAIAIgBBAiAAGxEAAAALbgECfwJAAkAgASABQf////8BcUYEQCABQQN0IgJBf0wNAQJAIAJFBEBBBCEDDAELIAJBBBDXASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ2AEACxDYAQALIAJBBEG08sIAKAIAIgBBAiAAGxEAAAALZgEBfyABIAFB/////wFxRwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAFBA3RqIgEgAkkNACABQX1JDQELIABBADYCBA8LIAAgAjYCCCAAQQQ2AgQgACABNgIAC2YBAX8gASABQf////8AcUcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiABQQR0aiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAttAQJ/AkACQCABIAFB////P3FGBEAgAUEFdCICQX9MDQECQCACRQRAQQghAwwBCyACQQgQ1wEiA0UNAwsgACABNgIEIAAgAzYCAA8LENgBAAsQ2AEACyACQQhBtPLCACgCACIAQQIgABsRAAAAC2ABAn8CQCAAKAIAIgFFDQAgAEEIaigCACICBEAgAkEEdCECIAFBCGohAQNAIAEoAgAEQCABQXxqKAIAECELIAFBEGohASACQXBqIgINAAsLIAAoAgRFDQAgACgCABAhCwtwAQF/IwBBMGsiAiQAIAIgATYCBCACIAA2AgAgAkEcakECNgIAIAJBLGpBBTYCACACQgI3AgwgAkHAgsAANgIIIAJBBTYCJCACIAJBIGo2AhggAiACQQRqNgIoIAIgAjYCICACQQhqQdCCwAAQxAEAC20BAX8jAEEwayIDJAAgAyACNgIEIAMgATYCACADQRxqQQI2AgAgA0EsakEFNgIAIANCAjcCDCADQaSAwAA2AgggA0EFNgIkIAMgA0EgajYCGCADIAM2AiggAyADQQRqNgIgIANBCGogABDEAQALcAEBfyMAQTBrIgIkACACIAE2AgQgAiAANgIAIAJBHGpBAjYCACACQSxqQQU2AgAgAkICNwIMIAJBiIPAADYCCCACQQU2AiQgAiACQSBqNgIYIAIgAkEEajYCKCACIAI2AiAgAkEIakGYg8AAEMQBAAtWAQJ/IwBBIGsiAiQAIAFBHGooAgAhAyABKAIYIAJBGGogAEEQaikCADcDACACQRBqIABBCGopAgA3AwAgAiAAKQIANwMIIAMgAkEIahAbIAJBIGokAAtTACAAQQxqKAIABEAgACgCCBAhCyAAQRhqKAIABEAgAEEUaigCABAhCyAAQSRqKAIABEAgAEEgaigCABAhCyAAQTBqKAIABEAgAEEsaigCABAhCwtkAQJ/IwBBIGsiAiQAIAEoAgAhAyACQQhqIAEoAggiARClASACQQA2AhggAiACKQMINwMQIAJBEGogAyADIAFBAnRqEL0BIABBCGogAigCGDYCACAAIAIpAxA3AgAgAkEgaiQAC1oBA38CQCABQQhqKAIAIgIgASgCBCIDTwRAIAFBHGooAgAiBCACSQ0BIAEoAhghASAAIAIgA2s2AgQgACABIANBAnRqNgIADwsgAyACEK0BAAsgAiAEEKsBAAtXAQN/AkAgAUEIaigCACICIAEoAgQiA08EQCABQSRqKAIAIgQgAkkNASABKAIgIQEgACACIANrNgIEIAAgASADajYCAA8LIAMgAhCtAQALIAIgBBCrAQALagECf0EBIQACQAJAQajywgAoAgBBAUcEQEGo8sIAQoGAgIAQNwMADAELQazywgBBrPLCACgCAEEBaiIANgIAIABBAksNAQtBsPLCACgCACIBQX9MDQBBsPLCACABNgIAIABBAUsNAAALAAtYAQN/IAAoAgQoAgAiAyACKAIAIgRBDGxqQQMgAyABKAIAIgVBDGxqQQMQmQFB/wFxQf8BRgRAIAEgBDYCACACIAU2AgAgACgCCCIAIAAoAgBBAWo2AgALC0sBA38gAEEMaigCACIDBEAgACgCBCADQTxsakFEaiECIAMhAQNAIAIQrwEgAkFEaiECIAFBf2oiAQ0ACwsgAEEANgIAIAAgATYCDAtUAQF/AkAgAUF/SgRAAkAgAUUEQEEBIQIMAQsgAUEBENcBIgJFDQILIAAgATYCBCAAIAI2AgAPCxDYAQALIAFBAUG08sIAKAIAIgBBAiAAGxEAAAALTgECf0F/IAEoAjgiAiAAKAI4IgNHIAIgA0kbIgIEfyACBSAAQSBqKAIAIABBKGooAgAgAUEgaigCACABQShqKAIAEJkBC0H/AXFB/wFGC1IBA38gACgCBCgCACIDIAIoAgAiBEECdGooAgAgAyABKAIAIgVBAnRqKAIAELcBBEAgASAENgIAIAIgBTYCACAAKAIIIgAgACgCAEEBajYCAAsLTgEBfyMAQRBrIgIkACACIAAgAUEBQQEQZAJAIAIoAgBBAUYEQCACQQhqKAIARQ0BQavswgBBKEHU7MIAEL4BAAsgAkEQaiQADwsQ0wEAC0MBA38CQCACRQ0AA0AgAC0AACIEIAEtAAAiBUYEQCABQQFqIQEgAEEBaiEAIAJBf2oiAg0BDAILCyAEIAVrIQMLIAMLSgACfyABQYCAxABHBEBBASAAKAIYIAEgAEEcaigCACgCEBEBAA0BGgsgAkUEQEEADwsgACgCGCACIAMgAEEcaigCACgCDBEDAAsLRgECfyAAKAIIIgIgAUsEQCAAKAIAIAJB5ABsakGcf2ohAwNAIAMQwgEgA0Gcf2ohAyABIAJBf2oiAkcNAAsLIAAgAjYCCAs6AQF/IAAgACgCCCACIAFrQQJ2IgIQhgEgACAAKAIIIgMgAmo2AgggACgCACADQQJ0aiACIAEgAhB2CzsBAX8jAEEgayIDJAAgA0IENwMQIANCATcCBCADIAE2AhwgAyAANgIYIAMgA0EYajYCACADIAIQxAEACzMBAX8gAgRAIAAhAwNAIAMgAS0AADoAACADQQFqIQMgAUEBaiEBIAJBf2oiAg0ACwsgAAtFAQJ/IwBBEGsiASQAIAAoAgwhAiAAKAIIRQRAQYvrwgBBK0G468IAEL4BAAsgASACKQIINwMIIAEgAikCADcDABCzAQALKwACQCAAQXxLDQAgAEUEQEEEDwsgACAAQX1JQQJ0ENcBIgBFDQAgAA8LAAsmACAAQSxqKAIABEAgACgCKBAhCyAAQThqKAIABEAgACgCNBAhCwsqAQF/AkAgABAEIgFFDQAgAUF8ai0AAEEDcUUNACABQQAgABDFARoLIAELMgEBfyMAQRBrIgIkACACIAE2AgwgAiAANgIIIAJBtIDAADYCBCACQQE2AgAgAhDAAQALKQEBfyACBEAgACEDA0AgAyABOgAAIANBAWohAyACQX9qIgINAAsLIAALPgBBoO3CACgCAEUEQEGk7cIAQgQ3AgBBoO3CAEGwpsIANgIAQZjtwgBCADcCAEGs7cIAQQA2AgALQZjtwgALPgBBuO3CACgCAEUEQEG87cIAQgQ3AgBBuO3CAEGwpsIANgIAQbDtwgBCADcCAEHE7cIAQQA2AgALQbDtwgALIAACQCABQXxLDQAgACABQQQgAhDUASIARQ0AIAAPCwALJgAgACgCACABIAIQuAEgACgCACACIAMQuAEgACgCACABIAIQuAELJgAgACgCACABIAIQigEgACgCACACIAMQigEgACgCACABIAIQigELJgAgACgCACABIAIQtAEgACgCACACIAMQtAEgACgCACABIAIQtAELJgEBfyAAKAIAIgEoAgAgASgCBCAAKAIEKAIAIAAoAggoAgAQGgALFAAgACAAIAEgACABYxsgASABYhsLHAAgASgCGEH4qcAAQQsgAUEcaigCACgCDBEDAAscACABKAIYQYOqwABBDiABQRxqKAIAKAIMEQMACxwAIAEoAhhBgOvCAEELIAFBHGooAgAoAgwRAwALFAAgACgCACABIAAoAgQoAgwRAQALCwAgAQRAIAAQIQsLEgBBgIDAAEERQZSAwAAQvgEACwwAIAAgASACIAMQIgsLACAANQIAIAEQUQsGABCzAQALGQACfyABQQlPBEAgASAAEEYMAQsgABAECwsGABDTAQALDQBCs5rmjsnyhtztAAsDAAELAwABCwvjuwL/DABBgIDAAAv8BWNhcGFjaXR5IG92ZXJmbG93AAAAFLYQABcAAAAJAwAABQAAAEQAEAAgAAAAZAAQABIAAAALAAAAAAAAAAEAAAAMAAAAaW5kZXggb3V0IG9mIGJvdW5kczogdGhlIGxlbiBpcyAgYnV0IHRoZSBpbmRleCBpcyAwMDAxMDIwMzA0MDUwNjA3MDgwOTEwMTExMjEzMTQxNTE2MTcxODE5MjAyMTIyMjMyNDI1MjYyNzI4MjkzMDMxMzIzMzM0MzUzNjM3MzgzOTQwNDE0MjQzNDQ0NTQ2NDc0ODQ5NTA1MTUyNTM1NDU1NTY1NzU4NTk2MDYxNjI2MzY0NjU2NjY3Njg2OTcwNzE3MjczNzQ3NTc2Nzc3ODc5ODA4MTgyODM4NDg1ODY4Nzg4ODk5MDkxOTI5Mzk0OTU5Njk3OTg5OQAAYAEQAAYAAABmARAAIgAAAMSkEAAYAAAAaAoAAAUAAABpbmRleCAgb3V0IG9mIHJhbmdlIGZvciBzbGljZSBvZiBsZW5ndGggqAEQABYAAAC+ARAADQAAAMSkEAAYAAAAbgoAAAUAAABzbGljZSBpbmRleCBzdGFydHMgYXQgIGJ1dCBlbmRzIGF0IGAweFsuLi5dAHoCEAALAAAA4hQQABYAAADLARAAAQAAAGQCEAAWAAAAAwgAAAkAAADAFBAADgAAAM4UEAAEAAAA0hQQABAAAADLARAAAQAAAGQCEAAWAAAABwgAAAUAAAB6AhAACwAAAIUCEAAmAAAAqwIQAAgAAACzAhAABgAAAMsBEAABAAAAZAIQABYAAAAUCAAABQAAAHNyYy9saWJjb3JlL3N0ci9tb2QucnNieXRlIGluZGV4ICBpcyBub3QgYSBjaGFyIGJvdW5kYXJ5OyBpdCBpcyBpbnNpZGUgIChieXRlcyApIG9mIGAAAAD6AhAAAgAAAOQCEAAWAAAAYAQAABEAAADkAhAAFgAAAFQEAAAoAAAAc3JjL2xpYmNvcmUvZm10L21vZC5ycy4uAEHghsAACw7//////////////////wBBkIfAAAsC+AMAQbKHwAALB/7/////v7YAQcKHwAALDf8HAAAAAAD4//8AAAEAQdqHwAALEMCfnz0AAAAAAgAAAP///wcAQfSHwAALuBXA/wEAAAAAAAD4DyCgDhAASgAAAPAQEAAAAgAA8BIQADoAAAAAAQIDBAUGBwgJCAoLDA0ODxAREhMUAhUWFxgZGhscHR4fIAICAgICAgICAgIhAgICAgICAgICAgICAgIiIyQlJgInAigCAgIpKisCLC0uLzACAjECAgIyAgICAgICAgIzAgI0AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI1AjYCNwICAgICAgICOAI5AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI6OzwCAgICPQICPj9AQUJDREVGAgICRwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJIAgICAgICAgICAgJJAgICAgI7AgABAgICAgMCAgICBAIFBgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIHAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAAQMFBQYGAwcGCAgJEQocCxkMFA0SDg0PBBADEhITCRYBFwUYAhkDGgccAh0BHxYgAysELAItCy4BMAMxAjIBpwKpAqoEqwj6AvsF/QT+A/8JrXh5i42iMFdYi4yQHB3dDg9LTPv8Li8/XF1fteKEjY6RkqmxurvFxsnK3uTl/wAEERIpMTQ3Ojs9SUpdhI6SqbG0urvGys7P5OUABA0OERIpMTQ6O0VGSUpeZGWEkZudyc7PDREpRUlXZGWNkam0urvFyd/k5fAEDRFFSWRlgIGEsry+v9XX8PGDhYukpr6/xcfOz9rbSJi9zcbOz0lOT1dZXl+Jjo+xtre/wcbH1xEWF1tc9vf+/4ANbXHe3w4PH25vHB1ffX6ur7u8+hYXHh9GR05PWFpcXn5/tcXU1dzw8fVyc490dZaXL18mLi+nr7e/x8/X35pAl5gwjx/Awc7/Tk9aWwcIDxAnL+7vbm83PT9CRZCR/v9TZ3XIydDR2Nnn/v8AIF8igt8EgkQIGwQGEYGsDoCrNR4VgOADGQgBBC8ENAQHAwEHBgcRClAPEgdVCAIEHAoJAwgDBwMCAwMDDAQFAwsGAQ4VBToDEQcGBRAHVwcCBxUNUARDAy0DAQQRBg8MOgQdJV8gbQRqJYDIBYKwAxoGgv0DWQcVCxcJFAwUDGoGCgYaBlkHKwVGCiwEDAQBAzELLAQaBgsDgKwGCgYfQUwELQN0CDwDDwM8BzgIKwWC/xEYCC8RLQMgECEPgIwEgpcZCxWIlAUvBTsHAg4YCYCwMHQMgNYaDAWA/wWAtgUkDJvGCtIwEISNAzcJgVwUgLgIgMcwNQQKBjgIRggMBnQLHgNaBFkJgIMYHAoWCUgIgIoGq6QMFwQxoQSB2iYHDAUFgKURgW0QeCgqBkwEgI0EgL4DGwMPDQAGAQEDAQQCCAgJAgoFCwIQAREEEgUTERQCFQIXAhkEHAUdCCQBagNrArwC0QLUDNUJ1gLXAtoB4AXhAugC7iDwBPkG+gIMJzs+Tk+Pnp6fBgcJNj0+VvPQ0QQUGDY3Vle9Nc7P4BKHiY6eBA0OERIpMTQ6RUZJSk5PZGVaXLa3GxyoqdjZCTeQkagHCjs+ZmmPkm9f7u9aYpqbJyhVnaCho6SnqK26vMQGCwwVHTo/RVGmp8zNoAcZGiIlPj/FxgQgIyUmKDM4OkhKTFBTVVZYWlxeYGNlZmtzeH1/iqSqr7DA0Axyo6TLzG5vXiJ7BQMELQNlBAEvLoCCHQMxDxwEJAkeBSsFRAQOKoCqBiQEJAQoCDQLAYCQgTcJFgoIgJg5A2MICTAWBSEDGwUBQDgESwUvBAoHCQdAICcEDAk2AzoFGgcEDAdQSTczDTMHLggKgSYfgIEoCCqAhhcJTgQeD0MOGQcKBkcJJwl1Cz9BKgY7BQoGUQYBBRADBYCLYCBICAqApl4iRQsKBg0TOQcKNiwEEIDAPGRTDAGAoEUbSAhTHTmBB0YKHQNHSTcDDggKBjkHCoE2GYDHMg2Dm2Z1C4DEiryEL4/RgkehuYI5ByoEAmAmCkYKKAUTgrBbZUsEOQcRQAQcl/gIgvOlDYEfMQMRBAiBjIkEawUNAwkHEJNggPYKcwhuF0aAmhQMVwkZgIeBRwOFQg8VhVArgNUtAxoEAoFwOgUBhQCA1ylMBAoEAoMRREw9gMI8BgEEVQUbNAKBDiwEZAxWCg0DXQM9OR0NLAQJBwIOBoCag9YKDQMLBXQMWQcMFAwEOAgKBigIHlJ3AzEDgKYMFAQDBQMNBoVqAAAAgA4QACAAAAAnAAAAGQAAAIAOEAAgAAAAKAAAACAAAACADhAAIAAAACoAAAAZAAAAgA4QACAAAAArAAAAGAAAAIAOEAAgAAAALAAAACAAAABzcmMvbGliY29yZS91bmljb2RlL2Jvb2xfdHJpZS5ycwAAwPvvPgAAAAAADgBBup3AAAuSAfj/+////wcAAAAAAAAU/iH+AAwAAAACAAAAAAAAUB4ggAAMAABABgAAAAAAABCGOQIAAAAjAL4hAAAMAAD8AgAAAAAAANAeIMAADAAAAAQAAAAAAABAASCAAAAAAAARAAAAAAAAwME9YAAMAAAAAgAAAAAAAJBEMGAADAAAAAMAAAAAAABYHiCAAAwAAAAAhFyAAEHWnsAACwTyB4B/AEHmnsAACwTyHwA/AEHznsAACxYDAACgAgAAAAAAAP5/3+D//v///x9AAEGVn8AAC6UB4P1mAAAAwwEAHgBkIAAgAAAAAAAAAOAAAAAAAAAcAAAAHAAAAAwAAAAMAAAAAAAAALA/QP4PIAAAAAAAOAAAAAAAAGAAAAAAAgAAAAAAAIcBBA4AAIAJAAAAAAAAQH/lH/ifAAAAAAAA/38PAAAAAADwFwQAAAAA+A8AAwAAADw7AAAAAAAAQKMDAAAAAAAA8M8AAAD3//0hEAP/////////+wAQAEHCoMAACw3/////AQAAAAAAAIADAEHXoMAACxWAAAAAAP////8AAAAAAPwAAAAAAAYAQfWgwAALB4D3PwAAAMAAQYahwAALLwMARAgAAGAAAAAwAAAA//8DgAAAAADAPwAAgP8DAAAAAAAHAAAAAADIMwAAAAAgAEG9ocAACzF+ZgAIEAAAAAAAEAAAAAAAAJ3BAgAAAAAwQAAAAAAAICEAAAAAAEAAAAAA//8AAP//AEH3ocAACwcBAAAAAgADAEGYosAACwQEAAAFAEGkosAACwEGAEGtosAACz8HAAAICQoACwwNDg8AABAREgAAExQVFgAAFxgZGhsAHAAAAB0AAAAAAAAeHyAhAAAAAAAiACMAJCUmAAAAACcAQdujwAALAigpAEHto8AACwIqKwBBoqTAAAsBLABBtaTAAAsFLS4AAC8AQdikwAALAzAxMgBB8KTAAAsMMwAAACkAAAAAAAA0AEGTpcAACwM1ADYAQbClwAALCDc4AAA4ODg5AEH/pcAACwYgAAAAAAEAQY6mwAALVMAHbvAAAAAAAIcAAAAAYAAAAAAAAADwAAAAwP8BAAAAAAACAAAAAAAA/38AAAAAAACAAwAAAAAAeAYHAAAAgO8fAAAAAAAAAAgAAwAAAAAAwH8AHgBB7abAAAsagNNAAAAAgPgHAAADAAAAAAAAWAEAgADAHx8AQY+nwAALBf9cAABAAEGep8AACwP5pQ0AQa2nwAALB4A8sAEAADAAQb6nwAALA/inAQBBzafAAAsvKL8AAAAA4LwPAAAAAAAAAID/BgAA8AwBAAAA/gcAAAAA+HmAAH4OAAAAAAD8fwMAQYaowAALE3+/AAD8///8bQAAAAAAAAB+tL8AQaKowAALAaMAQa6owAALHRgAAAAAAAAAHwAAAAAAAAB/AACAAAAAAAAAAIAHAEHTqMAACwFgAEHcqMAAC0agwwf45w8AAAA8AAAcAAAAAAAAAP///////3/4//////8fIAAQAAD4/v8AAH////nbBwAAAAAAAADwAAAAAH8AAAAAAPAHAEGsqcAAC/kC////////////////////////AABiZWdpbiA8PSBlbmQgKCA8PSApIHdoZW4gc2xpY2luZyBgIGlzIG91dCBvZiBib3VuZHMgb2YgYEJvcnJvd0Vycm9yQm9ycm93TXV0RXJyb3IAAACGtBAAAAAAACQVEAACAAAAOiAAADgVEAAVAAAAjQQAAAUAAABzcmMvbGliY29yZS9yZXN1bHQucnMAAQIBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEDAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQQFAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEDAACADhAAIAAAADoAAAAwAAAAAD4AAAEAQbCswAALLCAAAAABAAAAAQAAAAAAAAD/BwAAAIMAAAAAAIAAAAAAAAEAAAAAAAD/////AEHnrMAACwmAAAAAAAAA/wMAQf6swAALAgxyAEG0rsAACwL/AwBBxq7AAAsC/wMAQeCuwAALHf8DAAAAAAAAYBwQACEAAABoHRAAAAIAAGgfEAAuAEGFr8AACx4BAAIAAQABAAMABAAFAAEABgABAAcABwgAAAAACQcAQa2vwAALAQoAQbuvwAALFwsAAAAMBwAAAAANAA4AAA8AAAcQAAAPAEHhr8AACwYRCQAAEhMAQfGvwAALAxQVFgBB/a/AAAsCFxgAQZOwwAALARkAQaCwwAALCxoAAAAAABsAHB0eAEH4s8AACxgcAAABAAAAAB8AAAcJAAAgAAcAAAAAABAAQdy2wAALhAIHAAAAAAECAwMDBAMDAwMDAwUGBwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwBB7LjAAAswwP8AAAAAAADA//ADAAAAAMD//AAAAAAAwP8HAAAAAADA/wB/AAAAf8D//wEAAP8DAEGkucAACwb//w8A/wMAQbW5wAALFf7/HwAAAAAAwAEAAAAAAP8D/wPA/wBB0rnAAAsK/wcAAAAA/wP/AwBB5rnAAAsU/wMAAAAAAADxAwAA////////5wMAQYS6wAALCP////////8PAEGVusAACw78//8AAAAAAADA////DwBBr7rAAAsMIIAAAAD+AwAHAAA8AEHEusAACzf/AwAAAP/+/wAAAAD/AwAAAAD+/wAAAAAAAD8AAAD/AwAA/wMAAAAAAQIDAAAAAAQFBgAHAAAIAEGJu8AAC0MJCgsMAA0OAA8QEQASEwAAAAAUFQAAAAAWAAAXGAAAABkAFRoAABsAAAAVAAAAAAAcABwAAAAAABwAHR4AAAAAAAAfAEHZu8AACwYgAAAAHAgAQee7wAALASEAQfi7wAALAiIjAEGRvcAACwUIAAAAJABBor3AAAsBJQBBs73AAAsDJgAnAEHHvcAACwEoAEHtvcAACwcdAAAAAAAVAEGLvsAACwMpABwAQZm+wAALBCorACwAQay+wAALAS0AQfC+wAALEoD//////w8A/////////wEADABBjL/AAAsO/v//DwAAAAAPAAAAAgQAQaK/wAALAT4AQay/wAALEv8DAAAAAAD/AAAA/gAAAACA/wBBx7/AAAsF+AAAwA8AQde/wAALFTD///z///////8BAAAAAABgAAAA4ABB9b/AAAsR+AAAAAAA/wAAAP8AAAAAAP4AQY/AwAALJ/wAAAAAAAD/AwAAAAD///9/AAAA4H8AAAAAAB4AAAAAAAAA/P///wBBvsDAAAsUwP8AAP8D/v8fAAAA/wMAAAAA/wMAQd7AwAALNf8PAAAAAP//BwAAAP///x8AAP//HwAAAAAA/////////////////38AAAAA//sDAAAA//9/AEGcwcAACxb//w8AAAAAAP///wEAwP///////4D/AEG+wcAACxT+////////7x4A/v////+//z//HwBB4MHAAAtW/v//B/7//wcAAAAAAAQgBP//f////3/////////////////////////////////////////////////////////////////////////////D/wMAH1AAQcDCwAALiA0gAAAAAADfvEDX///7////////////v///////////////////////A/z///////////////////////////7///9/Av//////AQAAAAD/v7YA////hwcAAAD/B//////////+AMD////////////////vH/7hAJwAAP///////wDg////////////////AwAA/P///wcwBNAmEACGAAAAACsQAIAEAACALxAAbwAAAAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiIyQkJCQkJSYnKCkqKywkJCQkJCQkJC0uLzAxMjM0NTY3ODk6Ozw9Ph8/QEFCQ0RFRiQkJEckJCQkSElKSx9MTR9OT1AfHx8fHx8fHx8fH1FSHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fH1NUJFVWV1hZWh8fHx8fHx9bLFxdXiRfYB8fHx8fHx8fJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkNx8kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCRhJCQkJCQkJCQkJCQkJCQkJCQkYmMkJCQkZGUkYWYkZ2hpaiRrbG1ub0NwcXJzdCR1JCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkdncfHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8kJCQkJHgkeXp7fH0kJCQkfiF/gB+BJIKDhHGFAAECAwQFBgcIBQUJBQoLDAcHBwcHBwcHBwcNDg8HEBEFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBf////z/HwAA////Af8HAAAAAAAA///fPwAA8P/4A////////////+//3+H/DwD+/++f+f///cXjn1mAsA8AAxDuh/n///1tw4cZAl4AAD8A7r/7///97eO/GwEADwAAHu6f+f///e3jnxnAsA8AAgDsxz3WGMf/w8cdgQAAAAAA79/9///9/+PfHWAHDwAAAO/f/f///e/j3x1gQA8ABgDv3/3/////599d8IAPAAD87P9//P//+y9/gF//AAAMAP7///////8HfyAAAAAAAADW9///r///O18gAPAAAAAAAQAAAAAAAAD//v///x/+/wP///7///8fAEHQz8AAC5wB////////f/kAAP//////////ADz/////vyD///////f///////////89fz3//////z3/////PX89/3//////////Pf//////////BwAAAAD//wAA/////////////z8//v///////////////5////7//wf////////////H/wH/3w8A//8PAP//DwD/3w0A////////z///AYAQAEH00MAAC94B//////////////8B//////8H//////////8/AP///3//D/8BAAD///8/HwD//////w////8DAAAAAAAA////D/////////9//v8fAAAAAACAAAAA////////7//vDwAAAAAAAP//////8wD8/////7//AwD///////9/AADgAPz///8//wH//////+cAAAAAAN5vBAAAAACA/x8A//8/P/////8/P/+q////P////////99f3B/PD/8f3B8AAAAAAAACgAAA/x8AAAAAhPwvPlC9//PgQwAA//////8BAEHe0sAAC4QBwP///////wMAAP//////f///////f///////////H3gMAP////+/IP////////+AAAD//38Af39/f39/f3//////AAAAAACAAADgAAAA/gM+H///f+D+//////////////fg///////+//9/AAD///8HAAAAAAAA//////////8AAP8fAEHq08AAC7YL//////8//x///wAMAAD//////3/wjwAAgP/8//////n///////98AAAAAACA/7//////AAAA////////DwAvAAAAAAD86AD8////B/////8HAP///x/////////3/wCAAAD//wB8/z8AAP//f/z/////////fwUAADj//zwAfn5+AH9////////3/wD/////////BwAA/////w8A//9/+P//////D///////P///////AwAAAAB/APjg//1/X9v/////////////////AwAAAPj///////////////8////8////////AAAAAAD/DwAAAAAAAN///////////x8AAAAA/v//B/7//wfA/////Pz8HAAAAAAAAQIDBAUEBAQEBgcICQoLAgIMDQ4PBAQCAgICEBEEBBITFBUWBBcEGBkaGxwdHgQCHyAgIQQEBAQEBAQiIwQkAiMlJiAnAigpBCorLC0EBAIuAi8EBDAxMjM0BDUEBAQ2BDc4BAQ5Ojs8PTYEBAQEPj9ABEFCQwQEBAQkBAQEBAICAgICAgICAgICAgICRAQCRQICAkYEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAgICAgICAgICRQQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICAkcEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICNhQESElKSwQEBAQEBAQEBAQCBAQCTE1OAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJPAgICAgICAgICAgIgBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICFFACAgICAlEEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCUlMEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAJUVVZXWAICAgJZWltcXV4EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBF8EBARgYQQEBAQEYgQEBAQEBAQEBAQEBAQEBAQEBAQEAgICYwJkBAQEBAQEBAQEBAQEBAQEBAQEZWZnBAQEBAQEBAQEaGlqBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgICAgICAgICAgICAgICAgICAgICawICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIFAgICCgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICbAICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJtBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgJuBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAT/7///f///t/8//z8AAAAA////////////////////BwBBqN/AAAt8////////HwD///8f////////AQAAAAAA/////wDg////B///////B////z//////D/8+AAAAAAD///8/AAD/////D/////8P//////8A////////DwAAAP///////38A//8/AP8AAAA//f////+/kf//PwD//38A////fwBBrODAAAtt//83AP//PwD///8D/////////8Bv8O/+//8/AAAAAAD///8f////HwAAAAD//v//HwAAAP///////z8A//8/AP//BwD//wMAAAAAAP8BAAAAAAAA////////BwD//////wAAAP///x+AAP//PwBBpOHAAAuVAv//fwD8////////AQAA////AQAAcAD/////RwAeAAAUAAAAAP//+////59Af73/v/8B/////////wEAAO+f+f///e3jnxmB4A8AAAC7BwCAAAAAALMAAAAAAAAA////////P38AAAA/AAAAAP////////9/EQAAAAAAAAD///////8/Af///+f/BwAA/////////wEAAAAA//////////8AAACAAAAAAP/8///////8GgAAAP///////+d/AAD///////////8gAAAAAP/9/////39/AQAAAAAA/P////z///5/AH/7/////3+0ywAAAL/9////f3sBAAAAAP///wMAAAAA//////9/AAAPAAAAAAAAAH8AQcLjwAALKv///z8AAP///////wAADwAAAPj//+D//wAAAAAAAP+H/////////4D//wBB9OPAAAuAAgsAAAD/////////AAAABwDwAP///////////w///////wf/H/8B/0MAAAAA///f///////////fZN7/6+//////////v+ff3////3tf/P3///////////8//////f//9/////f//9/////f//9/////f/////3////9///3DwAAAAAAAH////nbBwAA//////8fgD8AQAAAAAAAAP//////DwAAHwAAAAAAAACPCAAAAAAAAO////+W/vcKhOqWqpb3917/+/8P7vv/DwAAAAAAAP///wP///8D////AwAAAAAAAP//fwAAAAAA/////wMA////////AQAAAP///z8AQYDmwAALBP7//wcAQZDmwAALMv//f38AAAAAVVVVVVVVVaqqVFVVVVVVK9bO27HV0q4RkKSqSlVV0lVVVVVVVVUFbHpVAEHm5sAACz1FgEDX/v/7DwAAAIAcVVVVkOb///////8AAAAAAABVVVVVAVRVVVVVVVWrKlVVVVVVVVVVVVVVVf7///9/AEH458AACxXwOBAAGgAAAMA5EADAAQAAgDsQABoAQbLowAALAgECAEG+6MAACwIBAwBB4ujAAAsVBAAAAAAABQUGBQcICQoAAAAACwwNAEGC6cAACwIODwBBoOnAAAsEEBEFEgBBie3AAAsHExQAFRYXGABB7O/AAAuEAhkAAAAAAQICAgIDAgICAgICBAUGAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAEH88cAACzr/////vyAAAAAAAAD///////8/AAAA///////nVVVVVVVVVVVVVRVAVVVVVQD/AD8A/wD/AD8AqgD/AEG/8sAACxoPAA8ADwAfAA+EOCc+UD0PwCAAAAD//wAACABB5vLAAAsuwP///wAAAAAAAP//////fwAAAAAAAJ3qJcBVVVVVBSgEAFVVVVVVFQAAVVVVBQBBnPPAAAsVVFVUVVVVVVVVVQBqVShFVVV9X1V0AEG888AACwT+//8HAEHQ88AACwQBAAIDAEHy88AACwEEAEGi9MAACwEFAEG59cAACwEGAEHQ9cAACxAHCAkKCwwNDg8QERITFBUWAEGk9sAACwEXAEHE9sAACwMCGBkAQYj3wAALBf//////AEGW98AAC78B/////w8AAAAAAP///////wcAAAAAAP//////////AAAAAP///wMAAPD//z8AAAD///8DAADQZN4/AAAA////AwAAsOffHwAAAHtf/AEAAPD//z8AAAD///8DAADw//8/AAAA////AwAA8P//PwAAAP///wMAAAD///8BAAAA/P//BwAAAPD//x8AAADA//9/AAAAAP///wEAAAAEAAAAAAAA/////wMAAAD/A////wP///8DAAAAAAAAQQAAAGEAQeD4wAALBUIAAABiAEHw+MAACwVDAAAAYwBBgPnAAAsFRAAAAGQAQZD5wAALBUUAAABlAEGg+cAACwVGAAAAZgBBsPnAAAsFRwAAAGcAQcD5wAALBUgAAABoAEHQ+cAACwVJAAAAaQBB4PnAAAsFSgAAAGoAQfD5wAALBUsAAABrAEGA+sAACwVMAAAAbABBkPrAAAsFTQAAAG0AQaD6wAALBU4AAABuAEGw+sAACwVPAAAAbwBBwPrAAAsFUAAAAHAAQdD6wAALBVEAAABxAEHg+sAACwVSAAAAcgBB8PrAAAsFUwAAAHMAQYD7wAALBVQAAAB0AEGQ+8AACwVVAAAAdQBBoPvAAAsFVgAAAHYAQbD7wAALBVcAAAB3AEHA+8AACwVYAAAAeABB0PvAAAsFWQAAAHkAQeD7wAALBVoAAAB6AEHw+8AACwXAAAAA4ABBgPzAAAsFwQAAAOEAQZD8wAALBcIAAADiAEGg/MAACwXDAAAA4wBBsPzAAAsFxAAAAOQAQcD8wAALBcUAAADlAEHQ/MAACwXGAAAA5gBB4PzAAAsFxwAAAOcAQfD8wAALBcgAAADoAEGA/cAACwXJAAAA6QBBkP3AAAsFygAAAOoAQaD9wAALBcsAAADrAEGw/cAACwXMAAAA7ABBwP3AAAsFzQAAAO0AQdD9wAALBc4AAADuAEHg/cAACwXPAAAA7wBB8P3AAAsF0AAAAPAAQYD+wAALBdEAAADxAEGQ/sAACwXSAAAA8gBBoP7AAAsF0wAAAPMAQbD+wAALBdQAAAD0AEHA/sAACwXVAAAA9QBB0P7AAAsF1gAAAPYAQeD+wAALBdgAAAD4AEHw/sAACwXZAAAA+QBBgP/AAAsF2gAAAPoAQZD/wAALBdsAAAD7AEGg/8AACwXcAAAA/ABBsP/AAAsF3QAAAP0AQcD/wAALBd4AAAD+AEHR/8AACwUBAAABAQBB4P/AAAsGAgEAAAMBAEHw/8AACwYEAQAABQEAQYCAwQALBgYBAAAHAQBBkIDBAAsGCAEAAAkBAEGggMEACwYKAQAACwEAQbCAwQALBgwBAAANAQBBwIDBAAsGDgEAAA8BAEHQgMEACwYQAQAAEQEAQeCAwQALBhIBAAATAQBB8IDBAAsGFAEAABUBAEGAgcEACwYWAQAAFwEAQZCBwQALBhgBAAAZAQBBoIHBAAsGGgEAABsBAEGwgcEACwYcAQAAHQEAQcCBwQALBh4BAAAfAQBB0IHBAAsGIAEAACEBAEHggcEACwYiAQAAIwEAQfCBwQALBiQBAAAlAQBBgILBAAsGJgEAACcBAEGQgsEACwYoAQAAKQEAQaCCwQALBioBAAArAQBBsILBAAsGLAEAAC0BAEHAgsEACwYuAQAALwEAQdCCwQALFjABAABpAAAABwMAAAAAAAAyAQAAMwEAQfCCwQALBjQBAAA1AQBBgIPBAAsGNgEAADcBAEGQg8EACwY5AQAAOgEAQaCDwQALBjsBAAA8AQBBsIPBAAsGPQEAAD4BAEHAg8EACwY/AQAAQAEAQdCDwQALBkEBAABCAQBB4IPBAAsGQwEAAEQBAEHwg8EACwZFAQAARgEAQYCEwQALBkcBAABIAQBBkITBAAsGSgEAAEsBAEGghMEACwZMAQAATQEAQbCEwQALBk4BAABPAQBBwITBAAsGUAEAAFEBAEHQhMEACwZSAQAAUwEAQeCEwQALBlQBAABVAQBB8ITBAAsGVgEAAFcBAEGAhcEACwZYAQAAWQEAQZCFwQALBloBAABbAQBBoIXBAAsGXAEAAF0BAEGwhcEACwZeAQAAXwEAQcCFwQALBmABAABhAQBB0IXBAAsGYgEAAGMBAEHghcEACwZkAQAAZQEAQfCFwQALBmYBAABnAQBBgIbBAAsGaAEAAGkBAEGQhsEACwZqAQAAawEAQaCGwQALBmwBAABtAQBBsIbBAAsGbgEAAG8BAEHAhsEACwZwAQAAcQEAQdCGwQALBnIBAABzAQBB4IbBAAsGdAEAAHUBAEHwhsEACwZ2AQAAdwEAQYCHwQALBXgBAAD/AEGQh8EACwZ5AQAAegEAQaCHwQALBnsBAAB8AQBBsIfBAAsGfQEAAH4BAEHAh8EACwaBAQAAUwIAQdCHwQALBoIBAACDAQBB4IfBAAsGhAEAAIUBAEHwh8EACwaGAQAAVAIAQYCIwQALBocBAACIAQBBkIjBAAsGiQEAAFYCAEGgiMEACwaKAQAAVwIAQbCIwQALBosBAACMAQBBwIjBAAsGjgEAAN0BAEHQiMEACwaPAQAAWQIAQeCIwQALBpABAABbAgBB8IjBAAsGkQEAAJIBAEGAicEACwaTAQAAYAIAQZCJwQALBpQBAABjAgBBoInBAAsGlgEAAGkCAEGwicEACwaXAQAAaAIAQcCJwQALBpgBAACZAQBB0InBAAsGnAEAAG8CAEHgicEACwadAQAAcgIAQfCJwQALBp8BAAB1AgBBgIrBAAsGoAEAAKEBAEGQisEACwaiAQAAowEAQaCKwQALBqQBAAClAQBBsIrBAAsGpgEAAIACAEHAisEACwanAQAAqAEAQdCKwQALBqkBAACDAgBB4IrBAAsGrAEAAK0BAEHwisEACwauAQAAiAIAQYCLwQALBq8BAACwAQBBkIvBAAsGsQEAAIoCAEGgi8EACwayAQAAiwIAQbCLwQALBrMBAAC0AQBBwIvBAAsGtQEAALYBAEHQi8EACwa3AQAAkgIAQeCLwQALBrgBAAC5AQBB8IvBAAsGvAEAAL0BAEGAjMEACwbEAQAAxgEAQZCMwQALBsUBAADGAQBBoIzBAAsGxwEAAMkBAEGwjMEACwbIAQAAyQEAQcCMwQALBsoBAADMAQBB0IzBAAsGywEAAMwBAEHgjMEACwbNAQAAzgEAQfCMwQALBs8BAADQAQBBgI3BAAsG0QEAANIBAEGQjcEACwbTAQAA1AEAQaCNwQALBtUBAADWAQBBsI3BAAsG1wEAANgBAEHAjcEACwbZAQAA2gEAQdCNwQALBtsBAADcAQBB4I3BAAsG3gEAAN8BAEHwjcEACwbgAQAA4QEAQYCOwQALBuIBAADjAQBBkI7BAAsG5AEAAOUBAEGgjsEACwbmAQAA5wEAQbCOwQALBugBAADpAQBBwI7BAAsG6gEAAOsBAEHQjsEACwbsAQAA7QEAQeCOwQALBu4BAADvAQBB8I7BAAsG8QEAAPMBAEGAj8EACwbyAQAA8wEAQZCPwQALBvQBAAD1AQBBoI/BAAsG9gEAAJUBAEGwj8EACwb3AQAAvwEAQcCPwQALBvgBAAD5AQBB0I/BAAsG+gEAAPsBAEHgj8EACwb8AQAA/QEAQfCPwQALBv4BAAD/AQBBgZDBAAsFAgAAAQIAQZCQwQALBgICAAADAgBBoJDBAAsGBAIAAAUCAEGwkMEACwYGAgAABwIAQcCQwQALBggCAAAJAgBB0JDBAAsGCgIAAAsCAEHgkMEACwYMAgAADQIAQfCQwQALBg4CAAAPAgBBgJHBAAsGEAIAABECAEGQkcEACwYSAgAAEwIAQaCRwQALBhQCAAAVAgBBsJHBAAsGFgIAABcCAEHAkcEACwYYAgAAGQIAQdCRwQALBhoCAAAbAgBB4JHBAAsGHAIAAB0CAEHwkcEACwYeAgAAHwIAQYCSwQALBiACAACeAQBBkJLBAAsGIgIAACMCAEGgksEACwYkAgAAJQIAQbCSwQALBiYCAAAnAgBBwJLBAAsGKAIAACkCAEHQksEACwYqAgAAKwIAQeCSwQALBiwCAAAtAgBB8JLBAAsGLgIAAC8CAEGAk8EACwYwAgAAMQIAQZCTwQALBjICAAAzAgBBoJPBAAsGOgIAAGUsAEGwk8EACwY7AgAAPAIAQcCTwQALBj0CAACaAQBB0JPBAAsGPgIAAGYsAEHgk8EACwZBAgAAQgIAQfCTwQALBkMCAACAAQBBgJTBAAsGRAIAAIkCAEGQlMEACwZFAgAAjAIAQaCUwQALBkYCAABHAgBBsJTBAAsGSAIAAEkCAEHAlMEACwZKAgAASwIAQdCUwQALBkwCAABNAgBB4JTBAAsGTgIAAE8CAEHwlMEACwZwAwAAcQMAQYCVwQALBnIDAABzAwBBkJXBAAsGdgMAAHcDAEGglcEACwZ/AwAA8wMAQbCVwQALBoYDAACsAwBBwJXBAAsGiAMAAK0DAEHQlcEACwaJAwAArgMAQeCVwQALBooDAACvAwBB8JXBAAsGjAMAAMwDAEGAlsEACwaOAwAAzQMAQZCWwQALBo8DAADOAwBBoJbBAAsGkQMAALEDAEGwlsEACwaSAwAAsgMAQcCWwQALBpMDAACzAwBB0JbBAAsGlAMAALQDAEHglsEACwaVAwAAtQMAQfCWwQALBpYDAAC2AwBBgJfBAAsGlwMAALcDAEGQl8EACwaYAwAAuAMAQaCXwQALBpkDAAC5AwBBsJfBAAsGmgMAALoDAEHAl8EACwabAwAAuwMAQdCXwQALBpwDAAC8AwBB4JfBAAsGnQMAAL0DAEHwl8EACwaeAwAAvgMAQYCYwQALBp8DAAC/AwBBkJjBAAsGoAMAAMADAEGgmMEACwahAwAAwQMAQbCYwQALBqMDAADDAwBBwJjBAAsGpAMAAMQDAEHQmMEACwalAwAAxQMAQeCYwQALBqYDAADGAwBB8JjBAAsGpwMAAMcDAEGAmcEACwaoAwAAyAMAQZCZwQALBqkDAADJAwBBoJnBAAsGqgMAAMoDAEGwmcEACwarAwAAywMAQcCZwQALBs8DAADXAwBB0JnBAAsG2AMAANkDAEHgmcEACwbaAwAA2wMAQfCZwQALBtwDAADdAwBBgJrBAAsG3gMAAN8DAEGQmsEACwbgAwAA4QMAQaCawQALBuIDAADjAwBBsJrBAAsG5AMAAOUDAEHAmsEACwbmAwAA5wMAQdCawQALBugDAADpAwBB4JrBAAsG6gMAAOsDAEHwmsEACwbsAwAA7QMAQYCbwQALBu4DAADvAwBBkJvBAAsG9AMAALgDAEGgm8EACwb3AwAA+AMAQbCbwQALBvkDAADyAwBBwJvBAAsG+gMAAPsDAEHQm8EACwb9AwAAewMAQeCbwQALBv4DAAB8AwBB8JvBAAsG/wMAAH0DAEGBnMEACwUEAABQBABBkJzBAAsGAQQAAFEEAEGgnMEACwYCBAAAUgQAQbCcwQALBgMEAABTBABBwJzBAAsGBAQAAFQEAEHQnMEACwYFBAAAVQQAQeCcwQALBgYEAABWBABB8JzBAAsGBwQAAFcEAEGAncEACwYIBAAAWAQAQZCdwQALBgkEAABZBABBoJ3BAAsGCgQAAFoEAEGwncEACwYLBAAAWwQAQcCdwQALBgwEAABcBABB0J3BAAsGDQQAAF0EAEHgncEACwYOBAAAXgQAQfCdwQALBg8EAABfBABBgJ7BAAsGEAQAADAEAEGQnsEACwYRBAAAMQQAQaCewQALBhIEAAAyBABBsJ7BAAsGEwQAADMEAEHAnsEACwYUBAAANAQAQdCewQALBhUEAAA1BABB4J7BAAsGFgQAADYEAEHwnsEACwYXBAAANwQAQYCfwQALBhgEAAA4BABBkJ/BAAsGGQQAADkEAEGgn8EACwYaBAAAOgQAQbCfwQALBhsEAAA7BABBwJ/BAAsGHAQAADwEAEHQn8EACwYdBAAAPQQAQeCfwQALBh4EAAA+BABB8J/BAAsGHwQAAD8EAEGAoMEACwYgBAAAQAQAQZCgwQALBiEEAABBBABBoKDBAAsGIgQAAEIEAEGwoMEACwYjBAAAQwQAQcCgwQALBiQEAABEBABB0KDBAAsGJQQAAEUEAEHgoMEACwYmBAAARgQAQfCgwQALBicEAABHBABBgKHBAAsGKAQAAEgEAEGQocEACwYpBAAASQQAQaChwQALBioEAABKBABBsKHBAAsGKwQAAEsEAEHAocEACwYsBAAATAQAQdChwQALBi0EAABNBABB4KHBAAsGLgQAAE4EAEHwocEACwYvBAAATwQAQYCiwQALBmAEAABhBABBkKLBAAsGYgQAAGMEAEGgosEACwZkBAAAZQQAQbCiwQALBmYEAABnBABBwKLBAAsGaAQAAGkEAEHQosEACwZqBAAAawQAQeCiwQALBmwEAABtBABB8KLBAAsGbgQAAG8EAEGAo8EACwZwBAAAcQQAQZCjwQALBnIEAABzBABBoKPBAAsGdAQAAHUEAEGwo8EACwZ2BAAAdwQAQcCjwQALBngEAAB5BABB0KPBAAsGegQAAHsEAEHgo8EACwZ8BAAAfQQAQfCjwQALBn4EAAB/BABBgKTBAAsGgAQAAIEEAEGQpMEACwaKBAAAiwQAQaCkwQALBowEAACNBABBsKTBAAsGjgQAAI8EAEHApMEACwaQBAAAkQQAQdCkwQALBpIEAACTBABB4KTBAAsGlAQAAJUEAEHwpMEACwaWBAAAlwQAQYClwQALBpgEAACZBABBkKXBAAsGmgQAAJsEAEGgpcEACwacBAAAnQQAQbClwQALBp4EAACfBABBwKXBAAsGoAQAAKEEAEHQpcEACwaiBAAAowQAQeClwQALBqQEAAClBABB8KXBAAsGpgQAAKcEAEGApsEACwaoBAAAqQQAQZCmwQALBqoEAACrBABBoKbBAAsGrAQAAK0EAEGwpsEACwauBAAArwQAQcCmwQALBrAEAACxBABB0KbBAAsGsgQAALMEAEHgpsEACwa0BAAAtQQAQfCmwQALBrYEAAC3BABBgKfBAAsGuAQAALkEAEGQp8EACwa6BAAAuwQAQaCnwQALBrwEAAC9BABBsKfBAAsGvgQAAL8EAEHAp8EACwbABAAAzwQAQdCnwQALBsEEAADCBABB4KfBAAsGwwQAAMQEAEHwp8EACwbFBAAAxgQAQYCowQALBscEAADIBABBkKjBAAsGyQQAAMoEAEGgqMEACwbLBAAAzAQAQbCowQALBs0EAADOBABBwKjBAAsG0AQAANEEAEHQqMEACwbSBAAA0wQAQeCowQALBtQEAADVBABB8KjBAAsG1gQAANcEAEGAqcEACwbYBAAA2QQAQZCpwQALBtoEAADbBABBoKnBAAsG3AQAAN0EAEGwqcEACwbeBAAA3wQAQcCpwQALBuAEAADhBABB0KnBAAsG4gQAAOMEAEHgqcEACwbkBAAA5QQAQfCpwQALBuYEAADnBABBgKrBAAsG6AQAAOkEAEGQqsEACwbqBAAA6wQAQaCqwQALBuwEAADtBABBsKrBAAsG7gQAAO8EAEHAqsEACwbwBAAA8QQAQdCqwQALBvIEAADzBABB4KrBAAsG9AQAAPUEAEHwqsEACwb2BAAA9wQAQYCrwQALBvgEAAD5BABBkKvBAAsG+gQAAPsEAEGgq8EACwb8BAAA/QQAQbCrwQALBv4EAAD/BABBwavBAAsFBQAAAQUAQdCrwQALBgIFAAADBQBB4KvBAAsGBAUAAAUFAEHwq8EACwYGBQAABwUAQYCswQALBggFAAAJBQBBkKzBAAsGCgUAAAsFAEGgrMEACwYMBQAADQUAQbCswQALBg4FAAAPBQBBwKzBAAsGEAUAABEFAEHQrMEACwYSBQAAEwUAQeCswQALBhQFAAAVBQBB8KzBAAsGFgUAABcFAEGArcEACwYYBQAAGQUAQZCtwQALBhoFAAAbBQBBoK3BAAsGHAUAAB0FAEGwrcEACwYeBQAAHwUAQcCtwQALBiAFAAAhBQBB0K3BAAsGIgUAACMFAEHgrcEACwYkBQAAJQUAQfCtwQALBiYFAAAnBQBBgK7BAAsGKAUAACkFAEGQrsEACwYqBQAAKwUAQaCuwQALBiwFAAAtBQBBsK7BAAsGLgUAAC8FAEHArsEACwYxBQAAYQUAQdCuwQALBjIFAABiBQBB4K7BAAsGMwUAAGMFAEHwrsEACwY0BQAAZAUAQYCvwQALBjUFAABlBQBBkK/BAAsGNgUAAGYFAEGgr8EACwY3BQAAZwUAQbCvwQALBjgFAABoBQBBwK/BAAsGOQUAAGkFAEHQr8EACwY6BQAAagUAQeCvwQALBjsFAABrBQBB8K/BAAsGPAUAAGwFAEGAsMEACwY9BQAAbQUAQZCwwQALBj4FAABuBQBBoLDBAAsGPwUAAG8FAEGwsMEACwZABQAAcAUAQcCwwQALBkEFAABxBQBB0LDBAAsGQgUAAHIFAEHgsMEACwZDBQAAcwUAQfCwwQALBkQFAAB0BQBBgLHBAAsGRQUAAHUFAEGQscEACwZGBQAAdgUAQaCxwQALBkcFAAB3BQBBsLHBAAsGSAUAAHgFAEHAscEACwZJBQAAeQUAQdCxwQALBkoFAAB6BQBB4LHBAAsGSwUAAHsFAEHwscEACwZMBQAAfAUAQYCywQALBk0FAAB9BQBBkLLBAAsGTgUAAH4FAEGgssEACwZPBQAAfwUAQbCywQALBlAFAACABQBBwLLBAAsGUQUAAIEFAEHQssEACwZSBQAAggUAQeCywQALBlMFAACDBQBB8LLBAAsGVAUAAIQFAEGAs8EACwZVBQAAhQUAQZCzwQALBlYFAACGBQBBoLPBAAsGoBAAAAAtAEGws8EACwahEAAAAS0AQcCzwQALBqIQAAACLQBB0LPBAAsGoxAAAAMtAEHgs8EACwakEAAABC0AQfCzwQALBqUQAAAFLQBBgLTBAAsGphAAAAYtAEGQtMEACwanEAAABy0AQaC0wQALBqgQAAAILQBBsLTBAAsGqRAAAAktAEHAtMEACwaqEAAACi0AQdC0wQALBqsQAAALLQBB4LTBAAsGrBAAAAwtAEHwtMEACwatEAAADS0AQYC1wQALBq4QAAAOLQBBkLXBAAsGrxAAAA8tAEGgtcEACwawEAAAEC0AQbC1wQALBrEQAAARLQBBwLXBAAsGshAAABItAEHQtcEACwazEAAAEy0AQeC1wQALBrQQAAAULQBB8LXBAAsGtRAAABUtAEGAtsEACwa2EAAAFi0AQZC2wQALBrcQAAAXLQBBoLbBAAsGuBAAABgtAEGwtsEACwa5EAAAGS0AQcC2wQALBroQAAAaLQBB0LbBAAsGuxAAABstAEHgtsEACwa8EAAAHC0AQfC2wQALBr0QAAAdLQBBgLfBAAsGvhAAAB4tAEGQt8EACwa/EAAAHy0AQaC3wQALBsAQAAAgLQBBsLfBAAsGwRAAACEtAEHAt8EACwbCEAAAIi0AQdC3wQALBsMQAAAjLQBB4LfBAAsGxBAAACQtAEHwt8EACwbFEAAAJS0AQYC4wQALBscQAAAnLQBBkLjBAAsGzRAAAC0tAEGguMEACwagEwAAcKsAQbC4wQALBqETAABxqwBBwLjBAAsGohMAAHKrAEHQuMEACwajEwAAc6sAQeC4wQALBqQTAAB0qwBB8LjBAAsGpRMAAHWrAEGAucEACwamEwAAdqsAQZC5wQALBqcTAAB3qwBBoLnBAAsGqBMAAHirAEGwucEACwapEwAAeasAQcC5wQALBqoTAAB6qwBB0LnBAAsGqxMAAHurAEHgucEACwasEwAAfKsAQfC5wQALBq0TAAB9qwBBgLrBAAsGrhMAAH6rAEGQusEACwavEwAAf6sAQaC6wQALBrATAACAqwBBsLrBAAsGsRMAAIGrAEHAusEACwayEwAAgqsAQdC6wQALBrMTAACDqwBB4LrBAAsGtBMAAISrAEHwusEACwa1EwAAhasAQYC7wQALBrYTAACGqwBBkLvBAAsGtxMAAIerAEGgu8EACwa4EwAAiKsAQbC7wQALBrkTAACJqwBBwLvBAAsGuhMAAIqrAEHQu8EACwa7EwAAi6sAQeC7wQALBrwTAACMqwBB8LvBAAsGvRMAAI2rAEGAvMEACwa+EwAAjqsAQZC8wQALBr8TAACPqwBBoLzBAAsGwBMAAJCrAEGwvMEACwbBEwAAkasAQcC8wQALBsITAACSqwBB0LzBAAsGwxMAAJOrAEHgvMEACwbEEwAAlKsAQfC8wQALBsUTAACVqwBBgL3BAAsGxhMAAJarAEGQvcEACwbHEwAAl6sAQaC9wQALBsgTAACYqwBBsL3BAAsGyRMAAJmrAEHAvcEACwbKEwAAmqsAQdC9wQALBssTAACbqwBB4L3BAAsGzBMAAJyrAEHwvcEACwbNEwAAnasAQYC+wQALBs4TAACeqwBBkL7BAAsGzxMAAJ+rAEGgvsEACwbQEwAAoKsAQbC+wQALBtETAAChqwBBwL7BAAsG0hMAAKKrAEHQvsEACwbTEwAAo6sAQeC+wQALBtQTAACkqwBB8L7BAAsG1RMAAKWrAEGAv8EACwbWEwAApqsAQZC/wQALBtcTAACnqwBBoL/BAAsG2BMAAKirAEGwv8EACwbZEwAAqasAQcC/wQALBtoTAACqqwBB0L/BAAsG2xMAAKurAEHgv8EACwbcEwAArKsAQfC/wQALBt0TAACtqwBBgMDBAAsG3hMAAK6rAEGQwMEACwbfEwAAr6sAQaDAwQALBuATAACwqwBBsMDBAAsG4RMAALGrAEHAwMEACwbiEwAAsqsAQdDAwQALBuMTAACzqwBB4MDBAAsG5BMAALSrAEHwwMEACwblEwAAtasAQYDBwQALBuYTAAC2qwBBkMHBAAsG5xMAALerAEGgwcEACwboEwAAuKsAQbDBwQALBukTAAC5qwBBwMHBAAsG6hMAALqrAEHQwcEACwbrEwAAu6sAQeDBwQALBuwTAAC8qwBB8MHBAAsG7RMAAL2rAEGAwsEACwbuEwAAvqsAQZDCwQALBu8TAAC/qwBBoMLBAAsG8BMAAPgTAEGwwsEACwbxEwAA+RMAQcDCwQALBvITAAD6EwBB0MLBAAsG8xMAAPsTAEHgwsEACwb0EwAA/BMAQfDCwQALBvUTAAD9EwBBgMPBAAsGkBwAANAQAEGQw8EACwaRHAAA0RAAQaDDwQALBpIcAADSEABBsMPBAAsGkxwAANMQAEHAw8EACwaUHAAA1BAAQdDDwQALBpUcAADVEABB4MPBAAsGlhwAANYQAEHww8EACwaXHAAA1xAAQYDEwQALBpgcAADYEABBkMTBAAsGmRwAANkQAEGgxMEACwaaHAAA2hAAQbDEwQALBpscAADbEABBwMTBAAsGnBwAANwQAEHQxMEACwadHAAA3RAAQeDEwQALBp4cAADeEABB8MTBAAsGnxwAAN8QAEGAxcEACwagHAAA4BAAQZDFwQALBqEcAADhEABBoMXBAAsGohwAAOIQAEGwxcEACwajHAAA4xAAQcDFwQALBqQcAADkEABB0MXBAAsGpRwAAOUQAEHgxcEACwamHAAA5hAAQfDFwQALBqccAADnEABBgMbBAAsGqBwAAOgQAEGQxsEACwapHAAA6RAAQaDGwQALBqocAADqEABBsMbBAAsGqxwAAOsQAEHAxsEACwasHAAA7BAAQdDGwQALBq0cAADtEABB4MbBAAsGrhwAAO4QAEHwxsEACwavHAAA7xAAQYDHwQALBrAcAADwEABBkMfBAAsGsRwAAPEQAEGgx8EACwayHAAA8hAAQbDHwQALBrMcAADzEABBwMfBAAsGtBwAAPQQAEHQx8EACwa1HAAA9RAAQeDHwQALBrYcAAD2EABB8MfBAAsGtxwAAPcQAEGAyMEACwa4HAAA+BAAQZDIwQALBrkcAAD5EABBoMjBAAsGuhwAAPoQAEGwyMEACwa9HAAA/RAAQcDIwQALBr4cAAD+EABB0MjBAAsGvxwAAP8QAEHhyMEACwUeAAABHgBB8MjBAAsGAh4AAAMeAEGAycEACwYEHgAABR4AQZDJwQALBgYeAAAHHgBBoMnBAAsGCB4AAAkeAEGwycEACwYKHgAACx4AQcDJwQALBgweAAANHgBB0MnBAAsGDh4AAA8eAEHgycEACwYQHgAAER4AQfDJwQALBhIeAAATHgBBgMrBAAsGFB4AABUeAEGQysEACwYWHgAAFx4AQaDKwQALBhgeAAAZHgBBsMrBAAsGGh4AABseAEHAysEACwYcHgAAHR4AQdDKwQALBh4eAAAfHgBB4MrBAAsGIB4AACEeAEHwysEACwYiHgAAIx4AQYDLwQALBiQeAAAlHgBBkMvBAAsGJh4AACceAEGgy8EACwYoHgAAKR4AQbDLwQALBioeAAArHgBBwMvBAAsGLB4AAC0eAEHQy8EACwYuHgAALx4AQeDLwQALBjAeAAAxHgBB8MvBAAsGMh4AADMeAEGAzMEACwY0HgAANR4AQZDMwQALBjYeAAA3HgBBoMzBAAsGOB4AADkeAEGwzMEACwY6HgAAOx4AQcDMwQALBjweAAA9HgBB0MzBAAsGPh4AAD8eAEHgzMEACwZAHgAAQR4AQfDMwQALBkIeAABDHgBBgM3BAAsGRB4AAEUeAEGQzcEACwZGHgAARx4AQaDNwQALBkgeAABJHgBBsM3BAAsGSh4AAEseAEHAzcEACwZMHgAATR4AQdDNwQALBk4eAABPHgBB4M3BAAsGUB4AAFEeAEHwzcEACwZSHgAAUx4AQYDOwQALBlQeAABVHgBBkM7BAAsGVh4AAFceAEGgzsEACwZYHgAAWR4AQbDOwQALBloeAABbHgBBwM7BAAsGXB4AAF0eAEHQzsEACwZeHgAAXx4AQeDOwQALBmAeAABhHgBB8M7BAAsGYh4AAGMeAEGAz8EACwZkHgAAZR4AQZDPwQALBmYeAABnHgBBoM/BAAsGaB4AAGkeAEGwz8EACwZqHgAAax4AQcDPwQALBmweAABtHgBB0M/BAAsGbh4AAG8eAEHgz8EACwZwHgAAcR4AQfDPwQALBnIeAABzHgBBgNDBAAsGdB4AAHUeAEGQ0MEACwZ2HgAAdx4AQaDQwQALBngeAAB5HgBBsNDBAAsGeh4AAHseAEHA0MEACwZ8HgAAfR4AQdDQwQALBn4eAAB/HgBB4NDBAAsGgB4AAIEeAEHw0MEACwaCHgAAgx4AQYDRwQALBoQeAACFHgBBkNHBAAsGhh4AAIceAEGg0cEACwaIHgAAiR4AQbDRwQALBooeAACLHgBBwNHBAAsGjB4AAI0eAEHQ0cEACwaOHgAAjx4AQeDRwQALBpAeAACRHgBB8NHBAAsGkh4AAJMeAEGA0sEACwaUHgAAlR4AQZDSwQALBZ4eAADfAEGg0sEACwagHgAAoR4AQbDSwQALBqIeAACjHgBBwNLBAAsGpB4AAKUeAEHQ0sEACwamHgAApx4AQeDSwQALBqgeAACpHgBB8NLBAAsGqh4AAKseAEGA08EACwasHgAArR4AQZDTwQALBq4eAACvHgBBoNPBAAsGsB4AALEeAEGw08EACwayHgAAsx4AQcDTwQALBrQeAAC1HgBB0NPBAAsGth4AALceAEHg08EACwa4HgAAuR4AQfDTwQALBroeAAC7HgBBgNTBAAsGvB4AAL0eAEGQ1MEACwa+HgAAvx4AQaDUwQALBsAeAADBHgBBsNTBAAsGwh4AAMMeAEHA1MEACwbEHgAAxR4AQdDUwQALBsYeAADHHgBB4NTBAAsGyB4AAMkeAEHw1MEACwbKHgAAyx4AQYDVwQALBsweAADNHgBBkNXBAAsGzh4AAM8eAEGg1cEACwbQHgAA0R4AQbDVwQALBtIeAADTHgBBwNXBAAsG1B4AANUeAEHQ1cEACwbWHgAA1x4AQeDVwQALBtgeAADZHgBB8NXBAAsG2h4AANseAEGA1sEACwbcHgAA3R4AQZDWwQALBt4eAADfHgBBoNbBAAsG4B4AAOEeAEGw1sEACwbiHgAA4x4AQcDWwQALBuQeAADlHgBB0NbBAAsG5h4AAOceAEHg1sEACwboHgAA6R4AQfDWwQALBuoeAADrHgBBgNfBAAsG7B4AAO0eAEGQ18EACwbuHgAA7x4AQaDXwQALBvAeAADxHgBBsNfBAAsG8h4AAPMeAEHA18EACwb0HgAA9R4AQdDXwQALBvYeAAD3HgBB4NfBAAsG+B4AAPkeAEHw18EACwb6HgAA+x4AQYDYwQALBvweAAD9HgBBkNjBAAsG/h4AAP8eAEGg2MEACwYIHwAAAB8AQbDYwQALBgkfAAABHwBBwNjBAAsGCh8AAAIfAEHQ2MEACwYLHwAAAx8AQeDYwQALBgwfAAAEHwBB8NjBAAsGDR8AAAUfAEGA2cEACwYOHwAABh8AQZDZwQALBg8fAAAHHwBBoNnBAAsGGB8AABAfAEGw2cEACwYZHwAAER8AQcDZwQALBhofAAASHwBB0NnBAAsGGx8AABMfAEHg2cEACwYcHwAAFB8AQfDZwQALBh0fAAAVHwBBgNrBAAsGKB8AACAfAEGQ2sEACwYpHwAAIR8AQaDawQALBiofAAAiHwBBsNrBAAsGKx8AACMfAEHA2sEACwYsHwAAJB8AQdDawQALBi0fAAAlHwBB4NrBAAsGLh8AACYfAEHw2sEACwYvHwAAJx8AQYDbwQALBjgfAAAwHwBBkNvBAAsGOR8AADEfAEGg28EACwY6HwAAMh8AQbDbwQALBjsfAAAzHwBBwNvBAAsGPB8AADQfAEHQ28EACwY9HwAANR8AQeDbwQALBj4fAAA2HwBB8NvBAAsGPx8AADcfAEGA3MEACwZIHwAAQB8AQZDcwQALBkkfAABBHwBBoNzBAAsGSh8AAEIfAEGw3MEACwZLHwAAQx8AQcDcwQALBkwfAABEHwBB0NzBAAsGTR8AAEUfAEHg3MEACwZZHwAAUR8AQfDcwQALBlsfAABTHwBBgN3BAAsGXR8AAFUfAEGQ3cEACwZfHwAAVx8AQaDdwQALBmgfAABgHwBBsN3BAAsGaR8AAGEfAEHA3cEACwZqHwAAYh8AQdDdwQALBmsfAABjHwBB4N3BAAsGbB8AAGQfAEHw3cEACwZtHwAAZR8AQYDewQALBm4fAABmHwBBkN7BAAsGbx8AAGcfAEGg3sEACwaIHwAAgB8AQbDewQALBokfAACBHwBBwN7BAAsGih8AAIIfAEHQ3sEACwaLHwAAgx8AQeDewQALBowfAACEHwBB8N7BAAsGjR8AAIUfAEGA38EACwaOHwAAhh8AQZDfwQALBo8fAACHHwBBoN/BAAsGmB8AAJAfAEGw38EACwaZHwAAkR8AQcDfwQALBpofAACSHwBB0N/BAAsGmx8AAJMfAEHg38EACwacHwAAlB8AQfDfwQALBp0fAACVHwBBgODBAAsGnh8AAJYfAEGQ4MEACwafHwAAlx8AQaDgwQALBqgfAACgHwBBsODBAAsGqR8AAKEfAEHA4MEACwaqHwAAoh8AQdDgwQALBqsfAACjHwBB4ODBAAsGrB8AAKQfAEHw4MEACwatHwAApR8AQYDhwQALBq4fAACmHwBBkOHBAAsGrx8AAKcfAEGg4cEACwa4HwAAsB8AQbDhwQALBrkfAACxHwBBwOHBAAsGuh8AAHAfAEHQ4cEACwa7HwAAcR8AQeDhwQALBrwfAACzHwBB8OHBAAsGyB8AAHIfAEGA4sEACwbJHwAAcx8AQZDiwQALBsofAAB0HwBBoOLBAAsGyx8AAHUfAEGw4sEACwbMHwAAwx8AQcDiwQALBtgfAADQHwBB0OLBAAsG2R8AANEfAEHg4sEACwbaHwAAdh8AQfDiwQALBtsfAAB3HwBBgOPBAAsG6B8AAOAfAEGQ48EACwbpHwAA4R8AQaDjwQALBuofAAB6HwBBsOPBAAsG6x8AAHsfAEHA48EACwbsHwAA5R8AQdDjwQALBvgfAAB4HwBB4OPBAAsG+R8AAHkfAEHw48EACwb6HwAAfB8AQYDkwQALBvsfAAB9HwBBkOTBAAsG/B8AAPMfAEGg5MEACwYmIQAAyQMAQbDkwQALBSohAABrAEHA5MEACwUrIQAA5QBB0OTBAAsGMiEAAE4hAEHg5MEACwZgIQAAcCEAQfDkwQALBmEhAABxIQBBgOXBAAsGYiEAAHIhAEGQ5cEACwZjIQAAcyEAQaDlwQALBmQhAAB0IQBBsOXBAAsGZSEAAHUhAEHA5cEACwZmIQAAdiEAQdDlwQALBmchAAB3IQBB4OXBAAsGaCEAAHghAEHw5cEACwZpIQAAeSEAQYDmwQALBmohAAB6IQBBkObBAAsGayEAAHshAEGg5sEACwZsIQAAfCEAQbDmwQALBm0hAAB9IQBBwObBAAsGbiEAAH4hAEHQ5sEACwZvIQAAfyEAQeDmwQALBoMhAACEIQBB8ObBAAsGtiQAANAkAEGA58EACwa3JAAA0SQAQZDnwQALBrgkAADSJABBoOfBAAsGuSQAANMkAEGw58EACwa6JAAA1CQAQcDnwQALBrskAADVJABB0OfBAAsGvCQAANYkAEHg58EACwa9JAAA1yQAQfDnwQALBr4kAADYJABBgOjBAAsGvyQAANkkAEGQ6MEACwbAJAAA2iQAQaDowQALBsEkAADbJABBsOjBAAsGwiQAANwkAEHA6MEACwbDJAAA3SQAQdDowQALBsQkAADeJABB4OjBAAsGxSQAAN8kAEHw6MEACwbGJAAA4CQAQYDpwQALBsckAADhJABBkOnBAAsGyCQAAOIkAEGg6cEACwbJJAAA4yQAQbDpwQALBsokAADkJABBwOnBAAsGyyQAAOUkAEHQ6cEACwbMJAAA5iQAQeDpwQALBs0kAADnJABB8OnBAAsGziQAAOgkAEGA6sEACwbPJAAA6SQAQZHqwQALBSwAADAsAEGg6sEACwYBLAAAMSwAQbDqwQALBgIsAAAyLABBwOrBAAsGAywAADMsAEHQ6sEACwYELAAANCwAQeDqwQALBgUsAAA1LABB8OrBAAsGBiwAADYsAEGA68EACwYHLAAANywAQZDrwQALBggsAAA4LABBoOvBAAsGCSwAADksAEGw68EACwYKLAAAOiwAQcDrwQALBgssAAA7LABB0OvBAAsGDCwAADwsAEHg68EACwYNLAAAPSwAQfDrwQALBg4sAAA+LABBgOzBAAsGDywAAD8sAEGQ7MEACwYQLAAAQCwAQaDswQALBhEsAABBLABBsOzBAAsGEiwAAEIsAEHA7MEACwYTLAAAQywAQdDswQALBhQsAABELABB4OzBAAsGFSwAAEUsAEHw7MEACwYWLAAARiwAQYDtwQALBhcsAABHLABBkO3BAAsGGCwAAEgsAEGg7cEACwYZLAAASSwAQbDtwQALBhosAABKLABBwO3BAAsGGywAAEssAEHQ7cEACwYcLAAATCwAQeDtwQALBh0sAABNLABB8O3BAAsGHiwAAE4sAEGA7sEACwYfLAAATywAQZDuwQALBiAsAABQLABBoO7BAAsGISwAAFEsAEGw7sEACwYiLAAAUiwAQcDuwQALBiMsAABTLABB0O7BAAsGJCwAAFQsAEHg7sEACwYlLAAAVSwAQfDuwQALBiYsAABWLABBgO/BAAsGJywAAFcsAEGQ78EACwYoLAAAWCwAQaDvwQALBiksAABZLABBsO/BAAsGKiwAAFosAEHA78EACwYrLAAAWywAQdDvwQALBiwsAABcLABB4O/BAAsGLSwAAF0sAEHw78EACwYuLAAAXiwAQYDwwQALBmAsAABhLABBkPDBAAsGYiwAAGsCAEGg8MEACwZjLAAAfR0AQbDwwQALBmQsAAB9AgBBwPDBAAsGZywAAGgsAEHQ8MEACwZpLAAAaiwAQeDwwQALBmssAABsLABB8PDBAAsGbSwAAFECAEGA8cEACwZuLAAAcQIAQZDxwQALBm8sAABQAgBBoPHBAAsGcCwAAFICAEGw8cEACwZyLAAAcywAQcDxwQALBnUsAAB2LABB0PHBAAsGfiwAAD8CAEHg8cEACwZ/LAAAQAIAQfDxwQALBoAsAACBLABBgPLBAAsGgiwAAIMsAEGQ8sEACwaELAAAhSwAQaDywQALBoYsAACHLABBsPLBAAsGiCwAAIksAEHA8sEACwaKLAAAiywAQdDywQALBowsAACNLABB4PLBAAsGjiwAAI8sAEHw8sEACwaQLAAAkSwAQYDzwQALBpIsAACTLABBkPPBAAsGlCwAAJUsAEGg88EACwaWLAAAlywAQbDzwQALBpgsAACZLABBwPPBAAsGmiwAAJssAEHQ88EACwacLAAAnSwAQeDzwQALBp4sAACfLABB8PPBAAsGoCwAAKEsAEGA9MEACwaiLAAAoywAQZD0wQALBqQsAAClLABBoPTBAAsGpiwAAKcsAEGw9MEACwaoLAAAqSwAQcD0wQALBqosAACrLABB0PTBAAsGrCwAAK0sAEHg9MEACwauLAAArywAQfD0wQALBrAsAACxLABBgPXBAAsGsiwAALMsAEGQ9cEACwa0LAAAtSwAQaD1wQALBrYsAAC3LABBsPXBAAsGuCwAALksAEHA9cEACwa6LAAAuywAQdD1wQALBrwsAAC9LABB4PXBAAsGviwAAL8sAEHw9cEACwbALAAAwSwAQYD2wQALBsIsAADDLABBkPbBAAsGxCwAAMUsAEGg9sEACwbGLAAAxywAQbD2wQALBsgsAADJLABBwPbBAAsGyiwAAMssAEHQ9sEACwbMLAAAzSwAQeD2wQALBs4sAADPLABB8PbBAAsG0CwAANEsAEGA98EACwbSLAAA0ywAQZD3wQALBtQsAADVLABBoPfBAAsG1iwAANcsAEGw98EACwbYLAAA2SwAQcD3wQALBtosAADbLABB0PfBAAsG3CwAAN0sAEHg98EACwbeLAAA3ywAQfD3wQALBuAsAADhLABBgPjBAAsG4iwAAOMsAEGQ+MEACwbrLAAA7CwAQaD4wQALBu0sAADuLABBsPjBAAsG8iwAAPMsAEHA+MEACwZApgAAQaYAQdD4wQALBkKmAABDpgBB4PjBAAsGRKYAAEWmAEHw+MEACwZGpgAAR6YAQYD5wQALBkimAABJpgBBkPnBAAsGSqYAAEumAEGg+cEACwZMpgAATaYAQbD5wQALBk6mAABPpgBBwPnBAAsGUKYAAFGmAEHQ+cEACwZSpgAAU6YAQeD5wQALBlSmAABVpgBB8PnBAAsGVqYAAFemAEGA+sEACwZYpgAAWaYAQZD6wQALBlqmAABbpgBBoPrBAAsGXKYAAF2mAEGw+sEACwZepgAAX6YAQcD6wQALBmCmAABhpgBB0PrBAAsGYqYAAGOmAEHg+sEACwZkpgAAZaYAQfD6wQALBmamAABnpgBBgPvBAAsGaKYAAGmmAEGQ+8EACwZqpgAAa6YAQaD7wQALBmymAABtpgBBsPvBAAsGgKYAAIGmAEHA+8EACwaCpgAAg6YAQdD7wQALBoSmAACFpgBB4PvBAAsGhqYAAIemAEHw+8EACwaIpgAAiaYAQYD8wQALBoqmAACLpgBBkPzBAAsGjKYAAI2mAEGg/MEACwaOpgAAj6YAQbD8wQALBpCmAACRpgBBwPzBAAsGkqYAAJOmAEHQ/MEACwaUpgAAlaYAQeD8wQALBpamAACXpgBB8PzBAAsGmKYAAJmmAEGA/cEACwaapgAAm6YAQZD9wQALBiKnAAAjpwBBoP3BAAsGJKcAACWnAEGw/cEACwYmpwAAJ6cAQcD9wQALBiinAAAppwBB0P3BAAsGKqcAACunAEHg/cEACwYspwAALacAQfD9wQALBi6nAAAvpwBBgP7BAAsGMqcAADOnAEGQ/sEACwY0pwAANacAQaD+wQALBjanAAA3pwBBsP7BAAsGOKcAADmnAEHA/sEACwY6pwAAO6cAQdD+wQALBjynAAA9pwBB4P7BAAsGPqcAAD+nAEHw/sEACwZApwAAQacAQYD/wQALBkKnAABDpwBBkP/BAAsGRKcAAEWnAEGg/8EACwZGpwAAR6cAQbD/wQALBkinAABJpwBBwP/BAAsGSqcAAEunAEHQ/8EACwZMpwAATacAQeD/wQALBk6nAABPpwBB8P/BAAsGUKcAAFGnAEGAgMIACwZSpwAAU6cAQZCAwgALBlSnAABVpwBBoIDCAAsGVqcAAFenAEGwgMIACwZYpwAAWacAQcCAwgALBlqnAABbpwBB0IDCAAsGXKcAAF2nAEHggMIACwZepwAAX6cAQfCAwgALBmCnAABhpwBBgIHCAAsGYqcAAGOnAEGQgcIACwZkpwAAZacAQaCBwgALBmanAABnpwBBsIHCAAsGaKcAAGmnAEHAgcIACwZqpwAAa6cAQdCBwgALBmynAABtpwBB4IHCAAsGbqcAAG+nAEHwgcIACwZ5pwAAeqcAQYCCwgALBnunAAB8pwBBkILCAAsGfacAAHkdAEGggsIACwZ+pwAAf6cAQbCCwgALBoCnAACBpwBBwILCAAsGgqcAAIOnAEHQgsIACwaEpwAAhacAQeCCwgALBoanAACHpwBB8ILCAAsGi6cAAIynAEGAg8IACwaNpwAAZQIAQZCDwgALBpCnAACRpwBBoIPCAAsGkqcAAJOnAEGwg8IACwaWpwAAl6cAQcCDwgALBpinAACZpwBB0IPCAAsGmqcAAJunAEHgg8IACwacpwAAnacAQfCDwgALBp6nAACfpwBBgITCAAsGoKcAAKGnAEGQhMIACwaipwAAo6cAQaCEwgALBqSnAAClpwBBsITCAAsGpqcAAKenAEHAhMIACwaopwAAqacAQdCEwgALBqqnAABmAgBB4ITCAAsGq6cAAFwCAEHwhMIACwaspwAAYQIAQYCFwgALBq2nAABsAgBBkIXCAAsGrqcAAGoCAEGghcIACwawpwAAngIAQbCFwgALBrGnAACHAgBBwIXCAAsGsqcAAJ0CAEHQhcIACwazpwAAU6sAQeCFwgALBrSnAAC1pwBB8IXCAAsGtqcAALenAEGAhsIACwa4pwAAuacAQZCGwgALBrqnAAC7pwBBoIbCAAsGvKcAAL2nAEGwhsIACwa+pwAAv6cAQcCGwgALBsKnAADDpwBB0IbCAAsGxKcAAJSnAEHghsIACwbFpwAAggIAQfCGwgALBsanAACOHQBBgIfCAAsGIf8AAEH/AEGQh8IACwYi/wAAQv8AQaCHwgALBiP/AABD/wBBsIfCAAsGJP8AAET/AEHAh8IACwYl/wAARf8AQdCHwgALBib/AABG/wBB4IfCAAsGJ/8AAEf/AEHwh8IACwYo/wAASP8AQYCIwgALBin/AABJ/wBBkIjCAAsGKv8AAEr/AEGgiMIACwYr/wAAS/8AQbCIwgALBiz/AABM/wBBwIjCAAsGLf8AAE3/AEHQiMIACwYu/wAATv8AQeCIwgALBi//AABP/wBB8IjCAAsGMP8AAFD/AEGAicIACwYx/wAAUf8AQZCJwgALBjL/AABS/wBBoInCAAsGM/8AAFP/AEGwicIACwY0/wAAVP8AQcCJwgALBjX/AABV/wBB0InCAAsGNv8AAFb/AEHgicIACwY3/wAAV/8AQfCJwgALBjj/AABY/wBBgIrCAAsGOf8AAFn/AEGQisIACwY6/wAAWv8AQaGKwgALBgQBACgEAQBBsIrCAAsHAQQBACkEAQBBwIrCAAsHAgQBACoEAQBB0IrCAAsHAwQBACsEAQBB4IrCAAsHBAQBACwEAQBB8IrCAAsHBQQBAC0EAQBBgIvCAAsHBgQBAC4EAQBBkIvCAAsHBwQBAC8EAQBBoIvCAAsHCAQBADAEAQBBsIvCAAsHCQQBADEEAQBBwIvCAAsHCgQBADIEAQBB0IvCAAsHCwQBADMEAQBB4IvCAAsHDAQBADQEAQBB8IvCAAsHDQQBADUEAQBBgIzCAAsHDgQBADYEAQBBkIzCAAsHDwQBADcEAQBBoIzCAAsHEAQBADgEAQBBsIzCAAsHEQQBADkEAQBBwIzCAAsHEgQBADoEAQBB0IzCAAsHEwQBADsEAQBB4IzCAAsHFAQBADwEAQBB8IzCAAsHFQQBAD0EAQBBgI3CAAsHFgQBAD4EAQBBkI3CAAsHFwQBAD8EAQBBoI3CAAsHGAQBAEAEAQBBsI3CAAsHGQQBAEEEAQBBwI3CAAsHGgQBAEIEAQBB0I3CAAsHGwQBAEMEAQBB4I3CAAsHHAQBAEQEAQBB8I3CAAsHHQQBAEUEAQBBgI7CAAsHHgQBAEYEAQBBkI7CAAsHHwQBAEcEAQBBoI7CAAsHIAQBAEgEAQBBsI7CAAsHIQQBAEkEAQBBwI7CAAsHIgQBAEoEAQBB0I7CAAsHIwQBAEsEAQBB4I7CAAsHJAQBAEwEAQBB8I7CAAsHJQQBAE0EAQBBgI/CAAsHJgQBAE4EAQBBkI/CAAsHJwQBAE8EAQBBoI/CAAsHsAQBANgEAQBBsI/CAAsHsQQBANkEAQBBwI/CAAsHsgQBANoEAQBB0I/CAAsHswQBANsEAQBB4I/CAAsHtAQBANwEAQBB8I/CAAsHtQQBAN0EAQBBgJDCAAsHtgQBAN4EAQBBkJDCAAsHtwQBAN8EAQBBoJDCAAsHuAQBAOAEAQBBsJDCAAsHuQQBAOEEAQBBwJDCAAsHugQBAOIEAQBB0JDCAAsHuwQBAOMEAQBB4JDCAAsHvAQBAOQEAQBB8JDCAAsHvQQBAOUEAQBBgJHCAAsHvgQBAOYEAQBBkJHCAAsHvwQBAOcEAQBBoJHCAAsHwAQBAOgEAQBBsJHCAAsHwQQBAOkEAQBBwJHCAAsHwgQBAOoEAQBB0JHCAAsHwwQBAOsEAQBB4JHCAAsHxAQBAOwEAQBB8JHCAAsHxQQBAO0EAQBBgJLCAAsHxgQBAO4EAQBBkJLCAAsHxwQBAO8EAQBBoJLCAAsHyAQBAPAEAQBBsJLCAAsHyQQBAPEEAQBBwJLCAAsHygQBAPIEAQBB0JLCAAsHywQBAPMEAQBB4JLCAAsHzAQBAPQEAQBB8JLCAAsHzQQBAPUEAQBBgJPCAAsHzgQBAPYEAQBBkJPCAAsHzwQBAPcEAQBBoJPCAAsH0AQBAPgEAQBBsJPCAAsH0QQBAPkEAQBBwJPCAAsH0gQBAPoEAQBB0JPCAAsH0wQBAPsEAQBB4JPCAAsHgAwBAMAMAQBB8JPCAAsHgQwBAMEMAQBBgJTCAAsHggwBAMIMAQBBkJTCAAsHgwwBAMMMAQBBoJTCAAsHhAwBAMQMAQBBsJTCAAsHhQwBAMUMAQBBwJTCAAsHhgwBAMYMAQBB0JTCAAsHhwwBAMcMAQBB4JTCAAsHiAwBAMgMAQBB8JTCAAsHiQwBAMkMAQBBgJXCAAsHigwBAMoMAQBBkJXCAAsHiwwBAMsMAQBBoJXCAAsHjAwBAMwMAQBBsJXCAAsHjQwBAM0MAQBBwJXCAAsHjgwBAM4MAQBB0JXCAAsHjwwBAM8MAQBB4JXCAAsHkAwBANAMAQBB8JXCAAsHkQwBANEMAQBBgJbCAAsHkgwBANIMAQBBkJbCAAsHkwwBANMMAQBBoJbCAAsHlAwBANQMAQBBsJbCAAsHlQwBANUMAQBBwJbCAAsHlgwBANYMAQBB0JbCAAsHlwwBANcMAQBB4JbCAAsHmAwBANgMAQBB8JbCAAsHmQwBANkMAQBBgJfCAAsHmgwBANoMAQBBkJfCAAsHmwwBANsMAQBBoJfCAAsHnAwBANwMAQBBsJfCAAsHnQwBAN0MAQBBwJfCAAsHngwBAN4MAQBB0JfCAAsHnwwBAN8MAQBB4JfCAAsHoAwBAOAMAQBB8JfCAAsHoQwBAOEMAQBBgJjCAAsHogwBAOIMAQBBkJjCAAsHowwBAOMMAQBBoJjCAAsHpAwBAOQMAQBBsJjCAAsHpQwBAOUMAQBBwJjCAAsHpgwBAOYMAQBB0JjCAAsHpwwBAOcMAQBB4JjCAAsHqAwBAOgMAQBB8JjCAAsHqQwBAOkMAQBBgJnCAAsHqgwBAOoMAQBBkJnCAAsHqwwBAOsMAQBBoJnCAAsHrAwBAOwMAQBBsJnCAAsHrQwBAO0MAQBBwJnCAAsHrgwBAO4MAQBB0JnCAAsHrwwBAO8MAQBB4JnCAAsHsAwBAPAMAQBB8JnCAAsHsQwBAPEMAQBBgJrCAAsHsgwBAPIMAQBBkJrCAAsHoBgBAMAYAQBBoJrCAAsHoRgBAMEYAQBBsJrCAAsHohgBAMIYAQBBwJrCAAsHoxgBAMMYAQBB0JrCAAsHpBgBAMQYAQBB4JrCAAsHpRgBAMUYAQBB8JrCAAsHphgBAMYYAQBBgJvCAAsHpxgBAMcYAQBBkJvCAAsHqBgBAMgYAQBBoJvCAAsHqRgBAMkYAQBBsJvCAAsHqhgBAMoYAQBBwJvCAAsHqxgBAMsYAQBB0JvCAAsHrBgBAMwYAQBB4JvCAAsHrRgBAM0YAQBB8JvCAAsHrhgBAM4YAQBBgJzCAAsHrxgBAM8YAQBBkJzCAAsHsBgBANAYAQBBoJzCAAsHsRgBANEYAQBBsJzCAAsHshgBANIYAQBBwJzCAAsHsxgBANMYAQBB0JzCAAsHtBgBANQYAQBB4JzCAAsHtRgBANUYAQBB8JzCAAsHthgBANYYAQBBgJ3CAAsHtxgBANcYAQBBkJ3CAAsHuBgBANgYAQBBoJ3CAAsHuRgBANkYAQBBsJ3CAAsHuhgBANoYAQBBwJ3CAAsHuxgBANsYAQBB0J3CAAsHvBgBANwYAQBB4J3CAAsHvRgBAN0YAQBB8J3CAAsHvhgBAN4YAQBBgJ7CAAsHvxgBAN8YAQBBkJ7CAAsHQG4BAGBuAQBBoJ7CAAsHQW4BAGFuAQBBsJ7CAAsHQm4BAGJuAQBBwJ7CAAsHQ24BAGNuAQBB0J7CAAsHRG4BAGRuAQBB4J7CAAsHRW4BAGVuAQBB8J7CAAsHRm4BAGZuAQBBgJ/CAAsHR24BAGduAQBBkJ/CAAsHSG4BAGhuAQBBoJ/CAAsHSW4BAGluAQBBsJ/CAAsHSm4BAGpuAQBBwJ/CAAsHS24BAGtuAQBB0J/CAAsHTG4BAGxuAQBB4J/CAAsHTW4BAG1uAQBB8J/CAAsHTm4BAG5uAQBBgKDCAAsHT24BAG9uAQBBkKDCAAsHUG4BAHBuAQBBoKDCAAsHUW4BAHFuAQBBsKDCAAsHUm4BAHJuAQBBwKDCAAsHU24BAHNuAQBB0KDCAAsHVG4BAHRuAQBB4KDCAAsHVW4BAHVuAQBB8KDCAAsHVm4BAHZuAQBBgKHCAAsHV24BAHduAQBBkKHCAAsHWG4BAHhuAQBBoKHCAAsHWW4BAHluAQBBsKHCAAsHWm4BAHpuAQBBwKHCAAsHW24BAHtuAQBB0KHCAAsHXG4BAHxuAQBB4KHCAAsHXW4BAH1uAQBB8KHCAAsHXm4BAH5uAQBBgKLCAAsHX24BAH9uAQBBkaLCAAsG6QEAIukBAEGgosIACwcB6QEAI+kBAEGwosIACwcC6QEAJOkBAEHAosIACwcD6QEAJekBAEHQosIACwcE6QEAJukBAEHgosIACwcF6QEAJ+kBAEHwosIACwcG6QEAKOkBAEGAo8IACwcH6QEAKekBAEGQo8IACwcI6QEAKukBAEGgo8IACwcJ6QEAK+kBAEGwo8IACwcK6QEALOkBAEHAo8IACwcL6QEALekBAEHQo8IACwcM6QEALukBAEHgo8IACwcN6QEAL+kBAEHwo8IACwcO6QEAMOkBAEGApMIACwcP6QEAMekBAEGQpMIACwcQ6QEAMukBAEGgpMIACwcR6QEAM+kBAEGwpMIACwcS6QEANOkBAEHApMIACwcT6QEANekBAEHQpMIACwcU6QEANukBAEHgpMIACwcV6QEAN+kBAEHwpMIACwcW6QEAOOkBAEGApcIACwcX6QEAOekBAEGQpcIACwcY6QEAOukBAEGgpcIACwcZ6QEAO+kBAEGwpcIACwca6QEAPOkBAEHApcIACwcb6QEAPekBAEHQpcIACwcc6QEAPukBAEHgpcIACwcd6QEAP+kBAEHwpcIACwce6QEAQOkBAEGApsIACwcf6QEAQekBAEGQpsIACwcg6QEAQukBAEGgpsIACwch6QEAQ+kBAEGwpsIAC6kk/////0hhc2ggdGFibGUgY2FwYWNpdHkgb3ZlcmZsb3dgkxAATgAAAFAAAAAbAAAAL2NhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvaGFzaGJyb3duLTAuNi4yL3NyYy9yYXcvbW9kLnJzAAAIlhAASAAAAAICAAAjAAAACJYQAEgAAAADAgAAIwAAAOCTEABJAAAAhwIAAB0AAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliY29yZS9zbGljZS9zb3J0LnJzAAAA4JMQAEkAAACdAAAAOgAAAOCTEABJAAAApAAAADAAAAANAAAAAAAAAAEAAAAOAAAADwAAAAAAAAABAAAAEAAAAHyUEAA9AAAAJQAAAA0AAAAvVXNlcnMvdGhhdW1hbnQvUGxheWdyb3VuZC9sdWNpZC1zdWdnZXN0L3J1c3QvY29yZS9zcmMvbGliLnJzRHVwbGljYXRlIHN0b3JlIGlkAHyUEAA9AAAALwAAAA0AAAB8lBAAPQAAADsAAAANAAAATWlzc2luZyBzdG9yZSBpZHyUEAA9AAAAQwAAAA0AAABhbHJlYWR5IG11dGFibHkgYm9ycm93ZWQRAAAAAAAAAAEAAAASAAAAAgEDABMAAAAAAAAAAQAAAA4AAAAUAAAAAAAAAAEAAAAOAAAAAAAAABUAAAAAAAAAAQAAAA4AAAB8lRAASwAAACMAAAAJAAAAL1VzZXJzL3RoYXVtYW50L1BsYXlncm91bmQvbHVjaWQtc3VnZ2VzdC9ydXN0L2NvcmUvc3JjL3Rva2VuaXphdGlvbi93b3JkLnJzTWFsZm9ybWVkIHdvcmRzAAAUthAAFwAAALkBAAAnAAAACJYQAEgAAADsCgAACgAAAAiWEABIAAAA8goAAA4AAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliY29yZS9zbGljZS9tb2QucnNwlhAARQAAAAkDAAAeAAAAcJYQAEUAAAAMAwAANgAAAC9ydXN0Yy83MzUyOGUzMzlhYWUwZjE3YTE1ZmZhNDlhOGFjNjA4ZjUwYzZjZjE0L3NyYy9saWJhbGxvYy9zbGljZS5yc2Fzc2VydGlvbiBmYWlsZWQ6IG1pZCA8PSBsZW4AAADEpBAAGAAAADYEAAANAAAAYXNzZXJ0aW9uIGZhaWxlZDogaW5kZXggPD0gbGVuAAAUlxAAEwAAAOMDAAAJAAAAc3JjL2xpYmFsbG9jL3ZlYy5yc2Fzc2VydGlvbiBmYWlsZWQ6IGluZGV4IDwgbGVuFJcQABMAAAALBAAACQAAAHSXEABDAAAANwsAADAAAAB0lxAAQwAAAEMLAAAsAAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmFsbG9jL3ZlYy5ycwAWAAAAAAAAAAEAAAAOAAAABgAAAGIAAAAGAAAAYwAAAAYAAABkAAAABgAAAGYAAAAGAAAAZwAAAAYAAABoAAAABgAAAGoAAAAGAAAAawAAAAYAAABsAAAABgAAAG0AAAAGAAAAbgAAAAYAAABwAAAABgAAAHEAAAAGAAAAcgAAAAYAAABzAAAABgAAAHQAAAAGAAAAdgAAAAYAAAB3AAAABgAAAHgAAAAGAAAAegAAAAcAAABhAAAABwAAAGUAAAAHAAAAaQAAAAcAAABvAAAABwAAAHUAAAAHAAAAeQAAAG9yAAASohAAAwAAAMyhEAACAAAAFaIQAAMAAAAwqxAAAgAAABiiEAADAAAAz6EQAAIAAAAbohAAAwAAANGhEAACAAAAHqIQAAMAAADToRAAAgAAACGiEAADAAAA7KAQAAIAAAAkohAAAwAAADKrEAACAAAAJ6IQAAMAAADVoRAAAgAAACqiEAADAAAA16EQAAIAAAAtohAAAwAAANmhEAACAAAAMKIQAAMAAADboRAAAgAAADOiEAADAAAA3aEQAAIAAAA2ohAAAwAAAOChEAACAAAAOaIQAAMAAADioRAAAgAAADyiEAADAAAA5KEQAAIAAAA/ohAAAwAAAOahEAACAAAAQqIQAAMAAADKoRAAAgAAAEWiEAADAAAA6KEQAAIAAABIohAAAwAAAOqhEAACAAAAS6IQAAMAAADsoRAAAgAAAE6iEAADAAAA7qEQAAIAAABRohAAAwAAAPChEAACAAAAVKIQAAMAAABFsxAAAgAAAFeiEAADAAAA8qEQAAIAAABaohAAAwAAAE2lEAACAAAAXaIQAAMAAAD0oRAAAgAAAGCiEAADAAAA9qEQAAIAAABjohAAAwAAAC+zEAACAAAAZqIQAAMAAAD5oRAAAgAAAGmiEAADAAAA/KEQAAIAAADMoRAAAgAAAM6hEAABAAAAMKsQAAIAAAA0qxAAAQAAAM+hEAACAAAAVKUQAAEAAADRoRAAAgAAAM6hEAABAAAA06EQAAIAAABspRAAAQAAAOygEAACAAAAR7MQAAEAAAAyqxAAAgAAADSrEAABAAAA1aEQAAIAAABqpRAAAQAAANehEAACAAAAVKUQAAEAAADZoRAAAgAAAM6hEAABAAAA26EQAAIAAABtpRAAAQAAAN2hEAACAAAA36EQAAEAAADgoRAAAgAAAGylEAABAAAA4qEQAAIAAABHsxAAAQAAAOShEAACAAAANKsQAAEAAADmoRAAAgAAAGulEAABAAAAyqEQAAIAAABMpRAAAQAAAOihEAACAAAAaqUQAAEAAADqoRAAAgAAAM6hEAABAAAA7KEQAAIAAABtpRAAAQAAAO6hEAACAAAAbKUQAAEAAADwoRAAAgAAAG6lEAABAAAARbMQAAIAAAA0qxAAAQAAAPKhEAACAAAAa6UQAAEAAABNpRAAAgAAAGqlEAABAAAA9KEQAAIAAABppRAAAQAAAPahEAACAAAA+KEQAAEAAAAvsxAAAgAAADGzEAABAAAA+aEQAAIAAAD7oRAAAQAAAPyhEAACAAAAU6UQAAEAAAD+oRAAAgAAAACiEAACAAAAAqIQAAIAAAAEohAAAgAAAAaiEAACAAAACKIQAAIAAAAKohAAAgAAAAyiEAACAAAADqIQAAIAAAAIohAAAgAAABCiEAACAAAADKIQAAIAAAAJAAAA3KAQAAIAAAAJAAAA3qAQAAIAAAAJAAAA4KAQAAMAAAAJAAAA46AQAAEAAAAJAAAAUaUQAAIAAAAJAAAA5KAQAAMAAAAJAAAA56AQAAMAAAAJAAAA6qAQAAIAAAAJAAAAT6UQAAIAAAAFAAAA7KAQAAIAAAAFAAAA7qAQAAYAAAAFAAAA9KAQAAgAAAAFAAAA/KAQAAkAAAAFAAAABaEQAAUAAAAFAAAACqEQAAQAAAAFAAAADqEQAAMAAAAFAAAAEaEQAAUAAAAFAAAAFqEQAAQAAAAFAAAAGqEQAAUAAAAFAAAAH6EQAAYAAAAFAAAAJaEQAAQAAAAFAAAAT6UQAAIAAAAFAAAAKaEQAAYAAAAFAAAAL6EQAAkAAAAFAAAAOKEQAAUAAAAFAAAAPaEQAAgAAAAFAAAARaEQAAQAAAAFAAAASaEQAAcAAAAFAAAAUKEQAAcAAAAFAAAAerUQAAMAAAAFAAAAV6EQAAQAAAAFAAAAW6EQAAQAAAAFAAAAX6EQAAgAAAAFAAAAZ6EQAAMAAAAFAAAAaqEQAAMAAAAFAAAAbaEQAAQAAAAFAAAAcaEQAAQAAAAFAAAAdaEQAAUAAAAFAAAAeqEQAAQAAAAFAAAAeqEQAAQAAAAFAAAAfqEQAAMAAAAFAAAAgaEQAAQAAAAFAAAAhaEQAAQAAAAFAAAAiaEQAAMAAAAGAAAAjKEQAAMAAAAGAAAAGqEQAAUAAAAGAAAAj6EQAAQAAAAGAAAAk6EQAAcAAAAGAAAAmqEQAAIAAAAGAAAAnKEQAAcAAAAGAAAARaEQAAQAAAAGAAAAo6EQAAIAAAAGAAAAmJgQAAIAAAAGAAAApaEQAAIAAAAGAAAAV6EQAAQAAAAGAAAAp6EQAAgAAAAGAAAAr6EQAAQAAAAGAAAAs6EQAAcAAAAGAAAAuqEQAAUAAAAGAAAAZ6EQAAMAAAAGAAAAv6EQAAcAAAAGAAAAxqEQAAIAAAAHAAAAyKEQAAIAAAAHAAAAyqEQAAIAAAAHAAAAyQAAAAcAAADpAAAABwAAAMAAAAAHAAAAyAAAAAcAAADZAAAABwAAAOAAAAAHAAAA6AAAAAcAAAD5AAAABwAAAMIAAAAHAAAAygAAAAcAAADOAAAABwAAANQAAAAHAAAA2wAAAAcAAADiAAAABwAAAOoAAAAHAAAA7gAAAAcAAAD0AAAABwAAAPsAAAAHAAAAywAAAAcAAADPAAAABwAAANwAAAAHAAAAeAEAAAcAAADrAAAABwAAAO8AAAAHAAAA/AAAAAcAAAD/AAAABgAAAMcAAAAGAAAA5wAAAAYAAADRAAAABgAAAPEAAAAHAAAAxgAAAAcAAADmAAAABwAAAFIBAAAHAAAAUwEAAAcAAADYAAAABwAAAPgAAABsZWxhbGVzbHVuZWRlc2R1w6BhcHLDqHNhdS1kZWzDoGF1LWRlc3N1c2F2YW50YXZlY2Nlc2NldHRlY2V1eGNvbW1lY29udHJlZGFuc2RlcHVpc2RlcnJpw6hyZWVudHJlanVzcXUnw6BtYWlzbWFsZ3LDqW9wcG9zw6lwbHVzcG91cnByb2NoYWlucXVlcXVpc2Fuc3NhdWZzZWxvbnNvdXNzdXJ0b3VydmVyc3ZpYWNhcmRvbmNlbnN1aXRlZXRsb3JzcXVlbmlvdXBvdXJxdW9pcHVpc3B1aXNxdWVxdWFuZHF1b2lxdWVzaW5lw7TDiUXDgMOIw5nDucOCw4rDjsOUT8Obw6LDqsOuw7vDi8OPw5zFuMOvw7/Dh0PDkU7DscOGQUXDpmFlxZJPRcWTb2XDmMO4RcyBZcyBQcyARcyAVcyAYcyAZcyAdcyAQcyCRcyCScyCT8yCVcyCYcyCZcyCacyCb8yCdcyCRcyIScyIVcyIWcyIZcyIacyIdcyIecyIQ8ynY8ynTsyDbsyDTm9ybWFsaXphdGlvbiBzaG91bGQgYWx3YXlzIGJlIHRoZSBmaXJzdCBzdGVwAAAArKIQAEsAAABgAAAADQAAAC9Vc2Vycy90aGF1bWFudC9QbGF5Z3JvdW5kL2x1Y2lkLXN1Z2dlc3QvcnVzdC9jb3JlL3NyYy90b2tlbml6YXRpb24vdGV4dC5ycwAXAAAAY2Fubm90IGFjY2VzcyBhIFRocmVhZCBMb2NhbCBTdG9yYWdlIHZhbHVlIGR1cmluZyBvciBhZnRlciBkZXN0cnVjdGlvbmFscmVhZHkgYm9ycm93ZWQAABgAAAAAAAAAAQAAAA4AAAAZAAAAAAAAAAEAAAAQAAAAhKMQAHAAAAChAAAAEQAAAC9Vc2Vycy90aGF1bWFudC8uY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9ydXN0LXN0ZW1tZXJzLTEuMi4wL3NyYy9zbm93YmFsbC9zbm93YmFsbF9lbnYucnOEoxAAcAAAALYAAAARAAAAhKMQAHAAAADKAAAAEQAAAISjEABwAAAA3wAAABEAAACEoxAAcAAAAAwBAAAWAAAAhKMQAHAAAAASAQAAGAAAAISjEABwAAAALgEAABYAAACEoxAAcAAAAFYBAAAWAAAAhKMQAHAAAABcAQAAGAAAAISjEABwAAAAXAEAAEkAAACEoxAAcAAAAHcBAAAWAAAAGKUQADQAAADcpBAALQAAAAmlEAAMAAAAFaUQAAMAAADEpBAAGAAAAKkIAAAJAAAAc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzYXNzZXJ0aW9uIGZhaWxlZDogYChsZWZ0ID09IHJpZ2h0KWAKICBsZWZ0OiBgYCwKIHJpZ2h0OiBgYDogZGVzdGluYXRpb24gYW5kIHNvdXJjZSBzbGljZXMgaGF2ZSBkaWZmZXJlbnQgbGVuZ3Roc2/DvGRldW5uQRFBEAEAQeTKwgALHICCZwgFeXVpVUlZcXe1EAADAAAA//////////8AQYjLwgALEHq1EAADAAAA//////////8AQaDLwgALEH21EAADAAAA//////////8AQbjLwgALDae0EAAEAAAA/////wEAQdDLwgALDau0EAAGAAAA/////wIAQejLwgALDbG0EAAEAAAA/////wEAQYDMwgALDbW0EAAEAAAA/////wUAQZjMwgALDbm0EAAFAAAA/////wMAQbDMwgALDb60EAAEAAAA/////wEAQcjMwgALDcK0EAAEAAAA/////wEAQeDMwgALDca0EAAEAAAA/////wsAQfjMwgALDcq0EAAEAAAA/////wEAQZDNwgALDc60EAADAAAA/////wgAQajNwgALDdG0EAACAAAA/////wgAQcDNwgALDdO0EAAFAAAA/////wQAQdjNwgALDdi0EAAFAAAA/////wIAQfDNwgALDd20EAAFAAAA/////wQAQYjOwgALDeK0EAAFAAAA/////wIAQaDOwgALDee0EAAFAAAA/////wEAQbjOwgALDey0EAAHAAAA/////wIAQdDOwgALDfO0EAAFAAAA/////wEAQejOwgALDfi0EAAFAAAA/////wUAQYDPwgALDf20EAAGAAAA/////wMAQZjPwgALDQO1EAAFAAAA/////wEAQbDPwgALDQi1EAAFAAAA/////wEAQcjPwgALDQ21EAAFAAAA/////wsAQeDPwgALDRK1EAAFAAAA/////wEAQfjPwgALDRe1EAAEAAAA/////wgAQZDQwgALDRu1EAADAAAA/////wgAQajQwgALDR61EAAGAAAA/////wQAQcDQwgALDSS1EAAGAAAA/////wIAQdjQwgALDSq1EAAGAAAA/////wQAQfDQwgALDTC1EAAGAAAA/////wIAQYjRwgALDTa1EAAFAAAA/////w8AQaDRwgALDTu1EAAGAAAAHgAAAAYAQbjRwgALDUG1EAAJAAAAHwAAAAwAQdDRwgALDUq1EAAFAAAA/////wcAQejRwgALDU+1EAAEAAAA/////w8AQYDSwgALDVO1EAAFAAAAIgAAAAYAQZjSwgALDVi1EAAIAAAAIwAAAAwAQbDSwgALDWC1EAAGAAAAIgAAAA0AQcjSwgALDWa1EAAGAAAAIgAAAA4AQeDSwgALDWy1EAADAAAA/////woAQfjSwgALDW+1EAAEAAAAJwAAAAkAQZDTwgALDV6qEAADAAAA/////wEAQajTwgALDXO1EAAEAAAA/////wcAQcDTwgALGWljaXFVbG9nZW50AMKpEAADAAAA/////wMAQeTTwgALDayqEAADAAAA/////wMAQfzTwgALDZy0EAAEAAAA/////wQAQZTUwgALDaC0EAAEAAAA/////wQAQazUwgALDaS0EAADAAAA/////wIAQcTUwgALDZq0EAACAAAA/////wEAQdzUwgALFWF0ZXV4AAAAwKkQAAIAAAD/////AgBB/NTCAAsNlrQQAAQAAAD/////AQBBlNXCAAsNmrQQAAIAAAD/////AwBBrNXCAAscYWJsZWF1YWxhbnQAhrQQAAMAAAD//////////wBB0NXCAAsQibQQAAQAAAD//////////wBB6NXCAAsQjbQQAAMAAAD//////////wBBgNbCAAsQkLQQAAMAAAD//////////wBBmNbCAAsQk7QQAAMAAAD//////////wBBsNbCAAsVw6nDqGUAAACGtBAAAAAAAP////8EAEHQ1sIACw1tpRAAAQAAAAAAAAABAEHo1sIACw1spRAAAQAAAAAAAAACAEGA18IACw1upRAAAQAAAAAAAAADAEGY18IACw3dsxAAAwAAAP////8BAEGw18IACw3gsxAAAgAAAP////8BAEHI18IACw3isxAABAAAAP////8BAEHg18IACw3msxAABwAAAP////8BAEH418IACw1rpRAAAQAAAP////8BAEGQ2MIACw3tsxAABAAAAAQAAAABAEGo2MIACw3xsxAAAgAAAP////8BAEHA2MIACw3zsxAABAAAAP////8BAEHY2MIACw33sxAAAwAAAP////8BAEHw2MIACw36sxAABQAAAP////8BAEGI2cIACw3/sxAABQAAAP////8BAEGg2cIACw0EtBAACAAAAP////8BAEG42cIACw0MtBAABQAAAP////8BAEHQ2cIACw0RtBAAAgAAAP////8BAEHo2cIACw0TtBAABQAAAA0AAAABAEGA2sIACw0YtBAABgAAAA0AAAABAEGY2sIACw0etBAABgAAAP////8BAEGw2sIACw0ktBAABwAAAP////8BAEHI2sIACw0rtBAABQAAAP////8BAEHg2sIACw0wtBAABgAAAP////8BAEH42sIACw02tBAABwAAAP////8BAEGQ28IACw09tBAAAgAAAP////8BAEGo28IACw0/tBAABQAAABUAAAABAEHA28IACw1EtBAABgAAABUAAAABAEHY28IACw1KtBAABgAAAP////8BAEHw28IACw1QtBAABwAAAP////8BAEGI3MIACw1XtBAACAAAAP////8BAEGg3MIACw1ftBAABQAAAP////8BAEG43MIACw1ktBAABgAAAP////8BAEHQ3MIACw1qtBAABQAAAP////8BAEHo3MIACw1vtBAAAwAAAP////8BAEGA3cIACw1ytBAABQAAAP////8BAEGY3cIACw13tBAABgAAAP////8BAEGw3cIACw19tBAABAAAAP////8BAEHI3cIACw2BtBAABQAAAP////8BAEHg3cIACw1HsxAAAQAAAP////8DAEH43cIACw1IsxAAAwAAAAAAAAACAEGQ3sIACw1LsxAABAAAAP////8DAEGo3sIACw1PsxAABAAAAP////8DAEHA3sIACw1TsxAAAwAAAP////8CAEHY3sIACw1WsxAAAgAAAP////8DAEHw3sIACw1YsxAABAAAAAUAAAACAEGI38IACw1csxAAAgAAAP////8CAEGg38IACw1esxAAAgAAAP////8DAEG438IACw1gsxAABAAAAAgAAAACAEHQ38IACw1ksxAABQAAAP////8DAEHo38IACw1psxAABQAAAP////8DAEGA4MIACw1usxAABQAAAP////8DAEGY4MIACw1zsxAABQAAAP////8DAEGw4MIACw14sxAABAAAAP////8CAEHI4MIACw18sxAAAwAAAP////8DAEHg4MIACw1/sxAABQAAAA8AAAACAEH44MIACw2EsxAABAAAAP////8BAEGQ4cIACw2IsxAABgAAABEAAAACAEGo4cIACw2OsxAABwAAABEAAAADAEHA4cIACw2VsxAABQAAAP////8CAEHY4cIACw2asxAABAAAAP////8DAEHw4cIACw2esxAAAwAAAP////8CAEGI4sIACw2hsxAAAwAAAP////8DAEGg4sIACw2ksxAABQAAABcAAAACAEG44sIACw20qhAAAwAAAP////8DAEHQ4sIACw2psxAABQAAAP////8DAEHo4sIACw2usxAABwAAABoAAAACAEGA48IACw21sxAABgAAAP////8CAEGY48IACw27sxAABgAAAP////8DAEGw48IACw3BsxAABQAAAP////8CAEHI48IACw3GsxAAAwAAAP////8DAEHg48IACw3JsxAAAgAAAP////8CAEH448IACw3LsxAAAwAAACAAAAACAEGQ5MIACw3OsxAABQAAACEAAAACAEGo5MIACw3TsxAABgAAACEAAAADAEHA5MIACw3ZsxAABAAAACAAAAACAEHY5MIACw0wqxAAAgAAAP////8CAEHw5MIACwRzAUEUAEGB5cIACxCAAAA0qxAAAQAAAP////8DAEGc5cIACw0ysxAABQAAAAAAAAACAEG05cIACw03sxAABQAAAAAAAAACAEHM5cIACw08sxAAAwAAAP////8BAEHk5cIACw0/sxAAAwAAAP////8CAEH85cIACw1CsxAAAwAAAP////8CAEGU5sIACw1FsxAAAgAAAP////8EAEGs5sIAC7UGdGd1w6djScOocmVpw6hyZWlvbkllcmllcsOrYWVyYWFzc2VhbnRlw6llYWllcmFpZXJhc2VyYXPDom1lc2Fzc2VzYW50ZXPDonRlc8OpZXNhaXNlcmFpc2lvbnNlcmlvbnNhc3Npb25zZXJvbnNhbnRzw6lzYWl0ZXJhaXRhSWVudGVyYUllbnTDqHJlbnRhc3NlbnRlcm9udMOidGV6aWV6ZXJpZXphc3NpZXplcmV6aXJhaWVpc3NlaXNzYW50ZWlyYWlpcmlyYXNpZXPDrm1lc2lzc2VzaXNzYW50ZXPDrnRlc2lzaXJhaXNpc3NhaXNpcmlvbnNpc3Npb25zaXJvbnNpc3NvbnNpc3NhbnRzaXRpcmFpdGlzc2FpdGlzc2FudGlyYUllbnRpc3NhSWVudGlyZW50aXNzZW50aXJvbnTDrnRpcmllemlzc2llemlyZXppc3NlemVsbGVpbGxlbm5vbm5ldHRhYmlsaXZJw6hyacOocmV1c2lxVWVhdHJpY2VhbmNlZW5jZWxvZ2llYWJsZWlzbWVldXNlaXN0ZWl2ZWlmdXNpb25hdGlvbnV0aW9uYXRldXJpcVVlc2F0cmljZXNhbmNlc2VuY2VzbG9naWVzYWJsZXNpc21lc2V1c2VzaXN0ZXNpdmVzaWZzdXNpb25zYXRpb25zdXRpb25zYXRldXJzbWVudHNlbWVudHNpc3NlbWVudHNpdMOpc21lbnRlbWVudGlzc2VtZW50YW1tZW50ZW1tZW50YXV4ZWF1eGl0w6ljb2xwYXJ0YXBBY2Nlc3NFcnJvcmNhbGxlZCBgT3B0aW9uOjp1bndyYXAoKWAgb24gYSBgTm9uZWAgdmFsdWUAAMi1EAAVAAAAegEAABUAAABzcmMvbGliY29yZS9vcHRpb24ucnNUcmllZCB0byBzaHJpbmsgdG8gYSBsYXJnZXIgY2FwYWNpdHkAAAAUthAAFwAAAF0CAAAJAAAAc3JjL2xpYmFsbG9jL3Jhd192ZWMucnNpbnRlcm5hbCBlcnJvcjogZW50ZXJlZCB1bnJlYWNoYWJsZSBjb2RlABS2EAAXAAAACgIAACcAewlwcm9kdWNlcnMCCGxhbmd1YWdlAQRSdXN0AAxwcm9jZXNzZWQtYnkDBXJ1c3RjHTEuNDAuMCAoNzM1MjhlMzM5IDIwMTktMTItMTYpBndhbHJ1cwYwLjE0LjAMd2FzbS1iaW5kZ2VuEjAuMi41OCAoMjkwMmNlYjI2KQ==';
-
-                var binary = null;
-
-                var isNode = typeof process !== 'undefined'
-                    && process.versions != null
-                    && process.versions.node != null;
-
-                if (isNode) {
-                    binary = Buffer.from(src, 'base64');
-                } else {
-                    var raw = window.atob(src);
-                    binary = new Uint8Array(new ArrayBuffer(raw.length));
-                    for(var i = 0; i < raw.length; i++) {
-                        binary[i] = raw.charCodeAt(i);
-                    }
-                }
-
-                var instantiatePromise = WebAssembly.instantiate(
-                    binary,
-                    {env: {memory: new WebAssembly.Memory({initial: 10})}}
-                );
-
-                var exportsPromise = instantiatePromise.then(result => {
-                    return result.instance.exports
-                });
-
-var compileWasm = exportsPromise.then(function(wasm) {
-                const __exports__ = {};
-
-                
-/**
-* @param {number} id
-*/
-__exports__.create_store = function(id) {
-    wasm.create_store(id);
-};
-
-/**
-* @param {number} id
-*/
-__exports__.destroy_store = function(id) {
-    wasm.destroy_store(id);
-};
-
-let WASM_VECTOR_LEN = 0;
-
-let cachegetUint8Memory0 = null;
-function getUint8Memory0() {
-    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
-    }
-    return cachegetUint8Memory0;
-}
-
-const lTextEncoder = typeof TextEncoder === 'undefined' ? require('util').TextEncoder : TextEncoder;
-
-let cachedTextEncoder = new lTextEncoder('utf-8');
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-}
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
-
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length);
-        getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
-        return ptr;
-    }
-
-    let len = arg.length;
-    let ptr = malloc(len);
-
-    const mem = getUint8Memory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
-    }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3);
-        const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
-
-        offset += ret.written;
-    }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {string} left
-* @param {string} right
-*/
-__exports__.highlight_with = function(store_id, left, right) {
-    var ptr0 = passStringToWasm0(left, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(right, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    wasm.highlight_with(store_id, ptr0, len0, ptr1, len1);
-};
-
-/**
-* @param {number} id
-* @param {number} limit
-*/
-__exports__.set_limit = function(id, limit) {
-    wasm.set_limit(id, limit);
-};
-
-let cachegetUint32Memory0 = null;
-function getUint32Memory0() {
-    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
-    }
-    return cachegetUint32Memory0;
-}
-
-function passArray32ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 4);
-    getUint32Memory0().set(arg, ptr / 4);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {Uint32Array} ids
-* @param {string} texts
-* @param {Uint32Array} ratings
-*/
-__exports__.set_records = function(store_id, ids, texts, ratings) {
-    var ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(texts, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    var ptr2 = passArray32ToWasm0(ratings, wasm.__wbindgen_malloc);
-    var len2 = WASM_VECTOR_LEN;
-    wasm.set_records(store_id, ptr0, len0, ptr1, len1, ptr2, len2);
-};
-
-/**
-* @param {number} store_id
-* @param {string} query
-*/
-__exports__.run_search = function(store_id, query) {
-    var ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    wasm.run_search(store_id, ptr0, len0);
-};
-
-let cachegetInt32Memory0 = null;
-function getInt32Memory0() {
-    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
-    }
-    return cachegetInt32Memory0;
-}
-
-function getArrayU32FromWasm0(ptr, len) {
-    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
-}
-/**
-* @param {number} store_id
-* @returns {Uint32Array}
-*/
-__exports__.get_result_ids = function(store_id) {
-    wasm.get_result_ids(8, store_id);
-    var r0 = getInt32Memory0()[8 / 4 + 0];
-    var r1 = getInt32Memory0()[8 / 4 + 1];
-    var v0 = getArrayU32FromWasm0(r0, r1).slice();
-    wasm.__wbindgen_free(r0, r1 * 4);
-    return v0;
-};
-
-const lTextDecoder = typeof TextDecoder === 'undefined' ? require('util').TextDecoder : TextDecoder;
-
-let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-
-cachedTextDecoder.decode();
-
-function getStringFromWasm0(ptr, len) {
-    return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
-}
-/**
-* @param {number} store_id
-* @returns {string}
-*/
-__exports__.get_result_titles = function(store_id) {
-    try {
-        wasm.get_result_titles(8, store_id);
-        var r0 = getInt32Memory0()[8 / 4 + 0];
-        var r1 = getInt32Memory0()[8 / 4 + 1];
-        return getStringFromWasm0(r0, r1);
-    } finally {
-        wasm.__wbindgen_free(r0, r1);
-    }
-};
-
-
-
-                return __exports__;
-            });
-
-const DEFAULT_LIMIT = 10;
-var NEXT_ID = 1;
-
-
-class LucidSuggest {
-    constructor() {
-        this.id         = NEXT_ID++;
-        this.limit      = DEFAULT_LIMIT;
-        this.records    = [];
-        this.setupQueue = compileWasm;
-
-        this.setup(wasm => {
-            wasm.create_store(this.id);
-            wasm.highlight_with(this.id, '{{', '}}');
-        });
-    }
-
-    setup(fn) {
-        this.setupQueue = this.setupQueue.then(async wasm => {
-            await fn(wasm);
-            this.setupQueue = Promise.resolve(wasm);
-            return wasm
-        });
-        return this.setupQueue
-    }
-
-    destroy() {
-        const oldQueue = this.setupQueue;
-        this.setupQueue = Promise.reject(new Error('Suggest destroyed'));
-        oldQueue.then(wasm => {
-            wasm.destroy_store(this.id);
-        });
-    }
-
-    setRecords(records) {
-        return this.setup(wasm => {
-            this.records = setRatings(records);
-            wasm.set_records(
-                this.id,
-                this.records.map(r => r.id),
-                this.records.map(r => r.title).join('\0'),
-                this.records.map(r => r.rating),
-            );
-        })
-    }
-
-    setLimit(limit) {
-        return this.setup(wasm => {
-            this.limit = limit;
-            wasm.set_limit(this.id, this.limit);
-        })
-    }
-
-    async search(query) {
-        const wasm = await this.setupQueue;
-        wasm.run_search(this.id, query);
-        const ids    = wasm.get_result_ids(this.id);
-        const titles = wasm.get_result_titles(this.id).split('\0');
-        const hits   = [];
-        for (let i = 0; i < ids.length; i++) {
-            const id     = ids[i];
-            const title  = titles[i];
-            const record = this.records.find(r => r.id === id);
-            if (!record) throw new Error(`Missing record ${id}`)
-            if (!title)  throw new Error(`Missing title for ${id}`)
-            hits.push(new Hit(title, record));
-        }
-        return hits
-    }
-}
-
-
-function highlight(hit, left, right) {
-    let result = '';
-    for (const {text, highlight} of hit.chunks) {
-        result += highlight
-            ? left + text + right
-            : text;
-    }
-    return result
-}
-
-
-class Hit {
-    constructor(title, record) {
-        this.record = record;
-        this.chunks = toChunks(title);
-    }
-
-    get title() {
-        return highlight(this, '[', ']')
-    }
-}
-
-
-function toChunks(title) {
-    const split  = title.split(/{{|}}/g);
-    const chunks = [];
-    for (let i = 0; i < split.length; i++) {
-        if (split[i] != '') {
-            chunks.push({
-                text: split[i],
-                highlight: i % 2 === 1,
-            });
-        }
-    }
-    return chunks
-}
-
-
-function setRatings(records) {
-    return records.some(r => r.rating != null && r.rating > 0)
-        ? records.map(r => ({...r, rating: r.rating > 0 ? r.rating : 0}))
-        : records.map((r, i) => ({...r, rating: records.length - i}))
-}
-
-exports.Hit = Hit;
-exports.LucidSuggest = LucidSuggest;
-exports.highlight = highlight;
@@ -1,346 +0,0 @@
-'use strict';
-
-Object.defineProperty(exports, '__esModule', { value: true });
-
-var src = 'AGFzbQEAAAABehNgAn9/AGACf38Bf2ADf39/AGADf39/AX9gAX8AYAR/f39/AGAFf39/f38AYAF/AX9gAAF/YAR/f39/AX9gAABgB39/f39/f38AYAV/f39/fwF/YAd/f39/f39/AX9gAn9+AX9gAn5/AX9gAX8BfmABfAF8YAJ8fAF8A9kB1wEFAgIHBgYHBgYGAAAAAAAAAAAAAAACCwAEBAUDBAEAAAIECQIBDAIIAAUAAQUJAQAABwIHBwYDBQEAAgIBAwEAAAYFBQEDAgUFAA0CAAAADwIAAAABAQEBAAQBAQABAQEABgQABQIFBQQFAAAABAQAAAIABQAAAAQCCAICBAARAAQGBgICAgACAAAAAAgIAAQEAgIACQIFDgADAAAAAAEAAAAAAAAEAAIAAQQAAAAKAgQAAQIAAwkAAgIDBAcEBwADCAgDBQUFBBIBAQEBAAoJAQoBChAABAQFAXABGhoFAwEAEQYJAX8BQYCAwAALB8QBDAZtZW1vcnkCAAxjcmVhdGVfc3RvcmUAHA1kZXN0cm95X3N0b3JlABgOaGlnaGxpZ2h0X3dpdGgAQQlzZXRfbGltaXQAWAtzZXRfcmVjb3JkcwAWCnJ1bl9zZWFyY2gAAg5nZXRfcmVzdWx0X2lkcwBMEWdldF9yZXN1bHRfdGl0bGVzAEARX193YmluZGdlbl9tYWxsb2MAvAESX193YmluZGdlbl9yZWFsbG9jAMMBD19fd2JpbmRnZW5fZnJlZQDNAQk0AQBBAQsZBtUBzAEd0AGeAS7QAakBVNYB1AHWAcoB1gHLAdYByQHWAdYB1gHWAcIB1gHWAQrr0gbXAc9YA0F/BX4CfCMAQYAEayIEJAACQAJAAkACQAJAIAEoAgAiBSABKAIERwRAIARB7ABqIScgBEHUA2ohKCAEQZUDaiEvIARB5QNqITAgBEGQAmohHSAEQfgCaiEYIARB+ABqITEgBEH1AGohPCAEQcgDaiEiIARBzANqIRkgBEGEAWohOSAEQeQAaiE6IARB4ABqIT0gBEGLAmohMiAEQd0DaiEeIARB7ANqISMgBEHcAmohKSAEQe0CaiE+A0AgASAFQQRqNgIAAkACQAJAAkACQCABKAIIKAIAIgZBDGooAgAiByAFKAIAIgVLBEAgBigCBCAFQTxsaiIFKAI4IT8gBSgCCCEqIAUoAgQhQCAFQTRqKAIAITMgBUEsaigCACE0IAVBKGooAgAhNSAFQSBqKAIAITYgBUEcaigCACE3IAVBFGooAgAhOCAFQRBqKAIAISQgAygCACgCACEKEIwBIhdFDQwQjQEiFkUNDCAXKAIARQRAIBdBfzYCACAWKAIARQRAIBZBfzYCACAWQQRqIQsCQCAXQQRqIhEoAgAiBUUEQEEAIQUgF0EANgIUDAELIBcoAghB/wEgBUEFahDAARogF0EANgIUIBcoAgQiBUEISQ0AIAVBAWpBA3ZBB2whBQsgFyAFNgIQAkAgCygCACIFRQRAQQAhBSAWQQA2AhQMAQsgFigCCEH/ASAFQQVqEMABGiAWQQA2AhQgFigCBCIFQQhJDQAgBUEBakEDdkEHbCEFCyAWIAU2AhAgCigCBCIFBEAgCigCACIUIAVBFGxqISUgKiAkQRRsaiIaQWxqISsDQAJAIAsgFCgCACIJEF8NACAEIAk2AiggBCAULwEQOwFQIAQgFCgCDDYCNCAEIBQoAggiDjYCMCAEIBQoAgQiEzYCLCAEIAooAhgiHzYCSCAEIAooAhAiIDYCQCAEIAooAggiITYCOCAEIApBHGooAgAiLDYCTCAEIApBFGooAgAiQTYCRCAEIApBDGooAgAiQjYCPCAEQQI6AHQgJEUNAEECIQcgKiEFAkADQAJAAkAgESAFKAIAIg0QXw0AIAQgMzYCvAEgBCA0NgK4ASAEIDU2ArQBIAQgNjYCsAEgBCA3NgKsASAEIDg2AqgBIAQgBUEQai8BADsBwAEgBCAFQQxqKAIANgKkASAEIAVBCGooAgAiDDYCoAEgBCAFQQRqKAIAIgY2ApwBIAQgDTYCmAECQAJAICQgDUEBaiISTQ0AIAQgMzYCrAIgBCA0NgKoAiAEIDU2AqQCIAQgNjYCoAIgBCA3NgKcAiAEIDg2ApgCIAQgKiASQRRsaiIILQARIhU6ALECIAQgCC0AEDoAsAIgBCAIKAIMIhs2ApQCIAQgCCgCCCIPNgKQAiAEIAgoAgQiEDYCjAIgBCAIKAIANgKIAgJAAkAgBiAPSQRAIBAgDEkNASAQIAxrIQgMAgsgBiAPayEIDAELENEBAAsgDiATayAMIAZrIAhqSQ0AIBEgEhBfDQAgBCAVOgCZAyAEQQo6AJgDIAQgDzYC+AIgBCANNgLwAiAEIDM2ApQDIAQgNDYCkAMgBCA1NgKMAyAEIDY2AogDIAQgNzYChAMgBCA4NgKAAyAEIAY2AvQCIAQgECAGayAbajYC/AIgBEHAA2ogBEHwAmogBEEoahABIARBgAJqIg0gGUEIaiIIKQIANwMAIARB9gFqIgwgHkECaiIbLQAAOgAAIARB6AFqIgYgI0EIaiImKQAANwMAIARB8AFqIg8gI0EQaiI7KAAANgIAIAQgGSkCADcD+AEgBCAeLwAAOwH0ASAEICMpAAA3A+ABIAQtANwDIi1BAkYNACAEKALoAyEcIAQpA+ADIUUgBCgCyAMhLiAEKQPAAyFGIARByAJqIhAgDSkDADcDACAEQagDaiISIAYpAwA3AwAgBEGwA2oiFSAPKAIANgIAIAQgBCkD+AE3A8ACIAQgBC8B9AE7AbgDIAQgBCkD4AE3A6ADIAQgDC0AADoAugMgBCAuNgLYAiAEIEY3A9ACICkgBCkDwAI3AgAgKUEIaiAQKQMANwIAIAQgLToA7AIgPiAELwG4AzsAACA+QQJqIAQtALoDOgAAIARB2AFqIi0gFSgCADYCACAEQdABaiIuIBIpAwA3AwAgBCAEKQOgAzcDyAEgBEHAA2ogBEHQAmogBEGYAWogBEGIAmoQQiANIAgpAgA3AwAgDCAbLQAAOgAAIAYgJikAADcDACAPIDsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiJkECRg0AIAQoAugDIQUgBCkD4AMhRyAEKALIAyEHIAQpA8ADIUYgECANKQMANwMAIBggBikDADcDACAEQYADaiITIA8oAgA2AgAgBCAEKQP4ATcDwAIgBCAELwH0ATsBuAMgBCAEKQPgATcD8AIgBCAMLQAAOgC6AyAGIBApAwA3AwAgBCAEKQPAAjcD4AEgBCAELwG4AzsB+AEgBCAELQC6AzoA+gEgFSATKAIANgIAIBIgGCkDADcDACAEIAQpA/ACNwOgAyAEIAc2AsgDIAQgRjcDwAMgGSAEKQPgATcCACAIIAYpAwA3AgAgBCAmOgDcAyAeIAQvAfgBOwAAIBsgBC0A+gE6AAAgBEHwAmogESAHIARBwANqECkgGSAEKQOgAzcCACAIIBIpAwA3AgAgGUEQaiIGIBUoAgA2AgAgBCAFNgLIAyAEIEc3A8ADIARB8AJqIBEgBSAEQcADahApIBkgBCkDyAE3AgAgCCAuKQMANwIAIAYgLSgCADYCACAEIBw2AsgDIAQgRTcDwAMgBEHwAmogCyAcIARBwANqECkMAQsCQCAKKAIEIAlBAWoiDE0NACAKKAIAIQYgCikCCCFFIAopAhAhRiAEIAopAhg3A6gCIAQgRjcDoAIgBCBFNwOYAiAEIAYgDEEUbGoiBi0AESIPOgCxAiAEIAYtABA6ALACIAQgBigCDCIQNgKUAiAEIAYoAggiCDYCkAIgBCAGKAIEIg02AowCIAQgBigCADYCiAIgBCgCnAEhEiAEKAKgAQJAAkAgEyAISQRAIA0gDkkNASANIA5rIQYMAgsgEyAIayEGDAELENEBAAsgEmsgDiATayAGakkNACALIAwQXw0AIAQgDzoAmQMgBEEKOgCYAyAEIAg2AvgCIAQgCTYC8AIgBCAsNgKUAyAEIB82ApADIAQgQTYCjAMgBCAgNgKIAyAEIEI2AoQDIAQgITYCgAMgBCATNgL0AiAEIA0gE2sgEGo2AvwCIARBwANqIARBmAFqIARB8AJqEAEgBEGAAmoiDSAZQQhqIggpAgA3AwAgBEH2AWoiDCAeQQJqIg8tAAA6AAAgBEHoAWoiBiAjQQhqIiYpAAA3AwAgBEHwAWoiECAjQRBqIjsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiLUECRg0AIAQoAugDIS4gBCkD4AMhRSAEKALIAyEcIAQpA8ADIUYgBEHQAWoiEiANKQMANwMAIARBugNqIkMgDC0AADoAACAEQagDaiIVIAYpAwA3AwAgBEGwA2oiGyAQKAIANgIAIAQgBCkD+AE3A8gBIAQgBC8B9AE7AbgDIAQgBCkD4AE3A6ADIARByAJqIkQgEikDADcDACAEIAQpA8gBNwPAAiAEIAQvAbgDOwG4AiAEIEMtAAA6ALoCIAQgLjYC2AIgBCBFNwPQAiApIAQpA6ADNwIAIClBCGogFSkDADcCACApQRBqIBsoAgA2AgAgBEHAA2ogBEHQAmogBEEoaiAEQYgCahBCIA0gCCkCADcDACAMIA8tAAA6AAAgBiAmKQAANwMAIBAgOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyImQQJGDQAgBCgC6AMhBSAEKQPgAyFHIAQoAsgDIQcgBCkDwAMhRSASIA0pAwA3AwAgGCAGKQMANwMAIARBgANqIhMgECgCADYCACAEIAQpA/gBNwPIASAEIAQvAfQBOwG4AyAEIAQpA+ABNwPwAiAEIAwtAAA6ALoDIAYgEikDADcDACAEIAQpA8gBNwPgASAEIAQvAbgDOwH4ASAEIAQtALoDOgD6ASAbIBMoAgA2AgAgFSAYKQMANwMAIAQgBCkD8AI3A6ADIAQgHDYCyAMgBCBGNwPAAyAZIAQpA8ACNwIAIAggRCkDADcCACAEIC06ANwDIB4gBC8BuAI7AAAgDyAELQC6AjoAACAEQfACaiARIBwgBEHAA2oQKSAZIAQpA+ABNwIAIAggBikDADcCACAeIAQvAfgBOwAAIA8gBC0A+gE6AAAgBCAHNgLIAyAEIEU3A8ADIAQgJjoA3AMgBEHwAmogCyAHIARBwANqECkgGSAEKQOgAzcCACAIIBUpAwA3AgAgGUEQaiAbKAIANgIAIAQgBTYCyAMgBCBHNwPAAyAEQfACaiALIAUgBEHAA2oQKQwBCyAEQcADaiAEQZgBaiAEQShqEAEgBEHYAmoiCCAiQQhqKAIANgIAIAQgIikDADcD0AIgBCsDwAMhSiAEKALUAyENIAQoAtgDIQwgBC0A3AMhBiAEQfACaiAeQSMQugEaIAZBAkYNASAEQagDaiIPIAgoAgA2AgAgBCAEKQPQAjcDoAMgBEGIAmogBEHwAmpBIxC6ARogGCAPKAIANgIAIAQgBCkDoAM3A/ACIAQgBC8AiAI7AdACIAQgBEGKAmotAAA6ANICIARB2ANqIhAgMkEYaikAADcDACAEQdADaiISIDJBEGopAAA3AwAgIiAyQQhqKQAANwMAIAQgMikAADcDwAMCfyBKmyJLRAAAAAAAAPBBYyBLRAAAAAAAAAAAZnEEQCBLqwwBC0EACyEPAkACQCAHQf8BcUECRiIVDQBBACEIQX8gDCANayAPQQF0ayIbQQAgBEHYAGogFRsiDygCGCAPKAIUawJ/IA8rAwCbIktEAAAAAAAA8EFjIEtEAAAAAAAAAABmcQRAIEurDAELQQALQQF0ayIPRyAPIBtJG0EBaiIPQQFLDQEgD0EBaw0AIAYNAQsgPSAEKQPwAjcDACA8IAQvAdACOwAAIDEgBCkDwAM3AwAgPEECaiAELQDSAjoAACA9QQhqIBgoAgA2AgAgMUEIaiAiKQMANwMAIDFBEGogEikDADcDACAxQRhqIBApAwA3AwAgBCAMNgJwIAQgDTYCbCAEIAY6AHQgBkEBcyEIIAQgSjkDWCBKvSFHIAYhBwsgBSArRg0EIAVBFGohBSAIRQ0DDAQLIARBAjoA3ANBACEFA0AgBEHYAGogBWoiBikDACFHIAYgBEHAA2ogBWoiBykDADcDACAHIEc3AwAgBkEIaiIIKQMAIUUgCCAHQQhqIggpAwA3AwAgBkEQaiITKQMAIUYgEyAHQRBqIhMpAwA3AwAgBkEYaiIGKQMAIUcgBiAHQRhqIgYpAwA3AwAgBiBHNwMAIBMgRjcDACAIIEU3AwAgBUFAayAFQSBqIQVBwQBJDQALIAQtAHQhBwwBCyAaIAVBFGoiBUcNAQsLIAdB/wFxQQJGDQEgBCkDWCFHCyAEKQN4IUUgBCgCgAEhBSAEKAJgIQYgGSA6KQIANwIAIBlBCGoiByA6QQhqKQIANwIAIBlBEGoiCCA6QRBqKAIANgIAIAQgBjYCyAMgBCBHNwPAAyAEQfACaiARIAYgBEHAA2oQKSAZIDkpAgA3AgAgByA5QQhqKQIANwIAIAggOUEQaigCADYCACAEIAU2AsgDIAQgRTcDwAMgBEHwAmogCyAFIARBwANqECkLIBRBFGoiFCAlRw0ACwsgFygCCCIFKAIAIQggFygCFCEGIBdBADYCFCAXKAIQIRQgF0EANgIQIBcoAgwhByAXQQg2AgwgF0GwpsIANgIIIBcoAgQhCiAXQQA2AgQgBCARNgKAASAEIAY2AnwgBCAUNgJ4IAQgBzYCdCAEIAU2AnAgBCAKNgJsIAQgBjYCaCAEIAUgCmpBAWo2AmQgBCAFQQRqNgJgIAQgBzYCXCAEIAhBf3NBgIGChHhxNgJYIARBwANqIARB2ABqEH8CfyAELQDkAyIGQQJGBEACQCAEKAJsIgVFBEBBACEFIARBADYCfAwBCyAEKAJwQf8BIAVBBWoQwAEaIARBADYCfCAFQQhJDQAgBUEBakEDdkEHbCEFCyAEIAU2AnggBCgCgAEiBSAnKQIANwIAIAVBEGogJ0EQaigCADYCACAFQQhqICdBCGopAgA3AgBCACFFQQgMAQsgBEHwAmogBEHAA2pBJBC6ARogHSAYQQhqKQMANwMAIARBmAJqIgogGEEQaikDADcDACAEQaACaiIIIBhBGGooAgA2AgAgBCAwQQJqLQAAOgCaASAEIDAvAAA7AZgBIAQgGCkDADcDiAIgBEEgakF/IAQoAmgiBUEBaiIHIAcgBUkbEKQBIAQoAiQhBSAEKAIgIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsELoBGiAEQfACaiAEQcADahB/AkAgBC0AlAMiCEECRg0AQQEhBkEgIQkDQCAEQYgCaiAEQfACakEkELoBGiAEQbABaiAdQRhqKAIAIgo2AgAgBEGoAWogHUEQaikDACJFNwMAIARBoAFqIB1BCGopAwAiRjcDACAEIC9BAmotAAA6ANICIAQgLy8AADsB0AIgBCAdKQMAIkc3A5gBIBggRjcDACAEQYADaiIRIEU3AwAgBEGIA2oiFCAKNgIAIAQgRzcD8AIgBCAELwHQAjsBiAIgBCAELQDSAjoAigIgBSAGRgRAIARBKGogBUF/IAQoAtADIgVBAWoiByAHIAVJGxCEASAEKAIoIQcLIAcgCWoiBSAEKQPwAjcDACAYKQMAIUUgESkDACFGIBQoAgAhCiAFQRxqIAg6AAAgBUEdaiAELwGIAjsAACAFQR9qIAQtAIoCOgAAIAVBGGogCjYCACAFQRBqIEY3AwAgBUEIaiBFNwMAIAQgBkEBaiIGNgIwIARB8AJqIARBwANqEH8gBC0AlAMiCEECRg0BIAlBIGohCSAEKAIsIQUMAAsACwJAIAQoAtQDIgVFBEBBACEFIARBADYC5AMMAQsgBCgC2ANB/wEgBUEFahDAARogBEEANgLkAyAFQQhJDQAgBUEBakEDdkEHbCEFCyAEKALoAyIGICgpAgA3AgAgBCAFNgLgAyAGQQhqIChBCGopAgA3AgAgBkEQaiAoQRBqKAIANgIAIAQpAiwhRSAEKAIoCyENIBYoAggiBSgCACEIIBYoAhQhBiAWQQA2AhQgFigCECERIBZBADYCECAWKAIMIQcgFkEINgIMIBZBsKbCADYCCCAWKAIEIQogFkEANgIEIAQgCzYCgAEgBCAGNgJ8IAQgETYCeCAEIAc2AnQgBCAFNgJwIAQgCjYCbCAEIAY2AmggBCAFIApqQQFqNgJkIAQgBUEEajYCYCAEIAc2AlwgBCAIQX9zQYCBgoR4cTYCWCAEQcADaiAEQdgAahB/An8gBC0A5AMiBkECRgRAAkAgBCgCbCIFRQRAQQAhBSAEQQA2AnwMAQsgBCgCcEH/ASAFQQVqEMABGiAEQQA2AnwgBUEISQ0AIAVBAWpBA3ZBB2whBQsgBCAFNgJ4IAQoAoABIgUgJykCADcCACAFQRBqICdBEGooAgA2AgAgBUEIaiAnQQhqKQIANwIAQQAhG0EAIRFBCAwBCyAEQfACaiAEQcADakEkELoBGiAdIBhBCGopAwA3AwAgBEGYAmoiCiAYQRBqKQMANwMAIARBoAJqIgggGEEYaigCADYCACAEIDBBAmotAAA6AJoBIAQgMC8AADsBmAEgBCAYKQMANwOIAkEBIREgBEEYakF/IAQoAmgiBUEBaiIHIAcgBUkbEKQBIAQoAhwhBSAEKAIYIgcgBCkDiAI3AwAgByAGOgAcIAdBGGogCCgCADYCACAHQRBqIAopAwA3AwAgB0EIaiAdKQMANwMAIAcgBC8BmAE7AB0gB0EfaiAELQCaAToAACAEQQE2AjAgBCAFNgIsIAQgBzYCKCAEQcADaiAEQdgAakEsELoBGiAEQfACaiAEQcADahB/IAQtAJQDIghBAkcEQEEBIQZBICEJA0AgBEGIAmogBEHwAmpBJBC6ARogBEGwAWogHUEYaigCACIKNgIAIARBqAFqIB1BEGopAwAiRjcDACAEQaABaiAdQQhqKQMAIkg3AwAgBCAvQQJqLQAAOgDSAiAEIC8vAAA7AdACIAQgHSkDACJHNwOYASAYIEg3AwAgBEGAA2oiCyBGNwMAIARBiANqIhEgCjYCACAEIEc3A/ACIAQgBC8B0AI7AYgCIAQgBC0A0gI6AIoCIAUgBkYEQCAEQShqIAVBfyAEKALQAyIFQQFqIgcgByAFSRsQhAEgBCgCKCEHCyAHIAlqIgUgBCkD8AI3AwAgGCkDACFGIAspAwAhSCARKAIAIQogBUEcaiAIOgAAIAVBHWogBC8BiAI7AAAgBUEfaiAELQCKAjoAACAFQRhqIAo2AgAgBUEQaiBINwMAIAVBCGogRjcDACAEIAZBAWoiCjYCMCAEQfACaiAEQcADahB/IAQtAJQDIghBAkcEQCAJQSBqIQkgBCgCLCEFIAohBgwBCwsgBkEBaiERCwJAIAQoAtQDIgVFBEBBACEFIARBADYC5AMMAQsgBCgC2ANB/wEgBUEFahDAARogBEEANgLkAyAFQQhJDQAgBUEBakEDdkEHbCEFCyAEKALoAyIGICgpAgA3AgAgBCAFNgLgAyAGQQhqIChBCGopAgA3AgAgBkEQaiAoQRBqKAIANgIAIAQoAiwhGyAEKAIoCyEPIEVCIIgiRqciFEEVSQ0DIARBEGogRUIhiKcQpAEgBCgCFCAEKAIQIRNBACEFIARBADYCYCAEQgQ3A1ggDUFgaiEcIA1BqH9qISVBACEHIBQhCgNAAkAgCiILQX9qIgpFBEBBASEIQQAhCgwBCwJAAkACQAJAAkAgDSAKQQV0aigCCCALQQV0IgwgDWpBSGooAgAiBk8EQCALQX5qIQ4gDCAlaiEIQQAhCkEAIQkDQCAJIA5GBEAgCyEIDAgLIAlBAWohCSAGIAgoAgAiBk8gCEFgaiEIDQALIAlBAWohCCAJQX9zIAtqIQoMAQsgDCAlaiEJA0ACQCAKQQFGBEBBACEKDAELIApBf2ohCiAGIAkoAgAiBkkgCUFgaiEJDQELCyALIApJDQEgCyAUSw0CIAsgCmsiCEEBdiIORQ0AIA0gCkEFdGohBiAMIBxqIQkDQCAEQdgDaiIMIAZBGGoiECkDADcDACAEQdADaiISIAZBEGoiFSkDADcDACAiIAZBCGoiGikDADcDACAEIAYpAwA3A8ADIAlBCGoiHykDACFIIAlBEGoiICkDACFJIAlBGGoiISkDACFHIAYgCSkDADcDACAQIEc3AwAgFSBJNwMAIBogSDcDACAhIAwpAwA3AwAgICASKQMANwMAIB8gIikDADcDACAJIAQpA8ADNwMAIAZBIGohBiAJQWBqIQkgDkF/aiIODQALCyAKRSAIQQlLcg0EIAsgCmshCCAKQX9qIQUgHCAKQQV0aiEGA0AgCyAFSQ0DIAsgFEsNAiAGIAhBAWoiCBA/IAVBf2oiByAFTw0EIAZBYGohBiAHIQUgCEEKSQ0ACwwDCyAKIAsQqAEACyALIBQQpgEACyAFIAsQqAEACyAHQQFqIQogBCgCYCIFIQcLIAQoAlwgB0YEQCAEQdgAaiAHEIsBIAQoAmAiBSEHCyAEKAJYIgYgB0EDdGoiByAINgIEIAcgCjYCACAEIAVBAWoiBTYCYAJ/AkAgBUECSQ0AAkADQAJAAkACQAJAIAYgBUF/akEDdGoiBygCAEUNACAFQQN0IAZqIg5BdGooAgAiCyAHKAIEIghNDQBBAiAFQQJNDQcaIAYgBUF9aiIMQQN0aigCBCIJIAggC2pNDQFBAyAFQQNNDQcaIA5BZGooAgAgCSALaksNBgwBCyAFQQNJDQEgBygCBCEIIAYgBUF9aiIMQQN0aigCBCEJCyAJIAhJDQELIAVBfmohDAsCQAJAAkACQAJAAkACQCAFIAxBAWoiEksEQCAFIAxNDQEgBiAMQQN0Ih9qIgUoAgQiICAFKAIAaiIFIAYgEkEDdCIhaiIGKAIAIhVJDQIgBSAUSw0DIA0gFUEFdGoiCyAGKAIEIhBBBXQiCGohByAFQQV0IRogBSAVayIOIBBrIgUgEE8NBCATIAcgBUEFdCIGELoBIg4gBmohCSAQQQFOQQAgBUEBThtFBEAgByEFDAYLIBogHGohCCAHIQUDQCAIIAVBYGoiGiAJQWBqIiwgCUFoaigCACAFQWhqKAIASSIHGyIGKQMANwMAIAhBGGogBkEYaikDADcDACAIQRBqIAZBEGopAwA3AwAgCEEIaiAGQQhqKQMANwMAIAkgLCAHGyEJIAsgGiAFIAcbIgVPDQYgCEFgaiEIIAkgDiIGSw0ACwwHC0Hoq8IAIBIgBRCnAQALQeirwgAgDCAFEKcBAAsgFSAFEKgBAAsgBSAUEKYBAAsgCCATIAsgCBC6ASIGaiEJIA4gEEpBACAQQQFOGw0BIAshBQsgEyEGDAELIA0gGmohDiALIQUDQCAFIAcgBiAHKAIIIAYoAghJIgsbIggpAwA3AwAgBUEYaiAIQRhqKQMANwMAIAVBEGogCEEQaikDADcDACAFQQhqIAhBCGopAwA3AwAgBiAGQSBqIAsbIQYgBUEgaiEFIAdBIGogByALGyIHIA5PDQEgCSAGSw0ACwsgBSAGIAkgBmtBYHEQugEaIAQoAmAiBSAMSwRAIAQoAlgiBiAfaiIHIBAgIGo2AgQgByAVNgIAIAUgEk0NAiAGICFqIgcgB0EIaiAFIBJBf3NqQQN0EJkBGiAEIAVBf2oiBTYCYCAFIAVBAU0NBBoMAQsLQfirwgAgDCAFEKcBAAtBp67CAEEdQcSuwgAQuQEACyAFCyEHIAoNAAsgBCgCXARAIAYQIQtFDQQgExAhDAQLDAwLDAsLQeirwgAgBSAHEKcBAAsgFEECSQ0AIBRBfmohBSANIBRBBXRqQUBqIQZBAiEJA0AgBSAUSw0CIAYgCRA/IAZBYGohBiAJQQFqIQkgBUF/aiIFQX9HDQALCyARQRVJDQEgBEEIaiARQQF2EKQBIAQoAgwgBCgCCCETQQAhBSAEQQA2AmAgBEIENwNYIA9BYGohHCAPQah/aiElQQAhByARIQoDQAJAIAoiC0F/aiIKRQRAQQEhCEEAIQoMAQsCQAJAAkACQAJAIA8gCkEFdGooAgggC0EFdCIMIA9qQUhqKAIAIgZPBEAgC0F+aiEOIAwgJWohCEEAIQpBACEJA0AgCSAORgRAIAshCAwICyAJQQFqIQkgBiAIKAIAIgZPIAhBYGohCA0ACyAJQQFqIQggCUF/cyALaiEKDAELIAwgJWohCQNAAkAgCkEBRgRAQQAhCgwBCyAKQX9qIQogBiAJKAIAIgZJIAlBYGohCQ0BCwsgCyAKSQ0BIAsgEUsNAiALIAprIghBAXYiDkUNACAPIApBBXRqIQYgDCAcaiEJA0AgBEHYA2oiDCAGQRhqIhApAwA3AwAgBEHQA2oiEiAGQRBqIhUpAwA3AwAgIiAGQQhqIhopAwA3AwAgBCAGKQMANwPAAyAJQQhqIh8pAwAhSCAJQRBqIiApAwAhSSAJQRhqIiEpAwAhRyAGIAkpAwA3AwAgECBHNwMAIBUgSTcDACAaIEg3AwAgISAMKQMANwMAICAgEikDADcDACAfICIpAwA3AwAgCSAEKQPAAzcDACAGQSBqIQYgCUFgaiEJIA5Bf2oiDg0ACwsgCkUgCEEJS3INBCALIAprIQggCkF/aiEFIBwgCkEFdGohBgNAIAsgBUkNAyALIBFLDQIgBiAIQQFqIggQPyAFQX9qIgcgBU8NBCAGQWBqIQYgByEFIAhBCkkNAAsMAwsgCiALEKgBAAsgCyAREKYBAAsgBSALEKgBAAsgB0EBaiEKIAQoAmAiBSEHCyAEKAJcIAdGBEAgBEHYAGogBxCLASAEKAJgIgUhBwsgBCgCWCIGIAdBA3RqIgcgCDYCBCAHIAo2AgAgBCAFQQFqIgU2AmACfwJAIAVBAkkNAAJAA0ACQAJAAkACQCAGIAVBf2pBA3RqIgcoAgBFDQAgBUEDdCAGaiIOQXRqKAIAIgsgBygCBCIITQ0AQQIgBUECTQ0HGiAGIAVBfWoiDEEDdGooAgQiCSAIIAtqTQ0BQQMgBUEDTQ0HGiAOQWRqKAIAIAkgC2pLDQYMAQsgBUEDSQ0BIAcoAgQhCCAGIAVBfWoiDEEDdGooAgQhCQsgCSAISQ0BCyAFQX5qIQwLAkACQAJAAkACQAJAAkAgBSAMQQFqIhJLBEAgBSAMTQ0BIAYgDEEDdCIfaiIFKAIEIiAgBSgCAGoiBSAGIBJBA3QiIWoiBigCACIVSQ0CIAUgEUsNAyAPIBVBBXRqIgsgBigCBCIQQQV0IghqIQcgBUEFdCEaIAUgFWsiDiAQayIFIBBPDQQgEyAHIAVBBXQiBhC6ASIOIAZqIQkgEEEBTkEAIAVBAU4bRQRAIAchBQwGCyAaIBxqIQggByEFA0AgCCAFQWBqIhogCUFgaiIsIAlBaGooAgAgBUFoaigCAEkiBxsiBikDADcDACAIQRhqIAZBGGopAwA3AwAgCEEQaiAGQRBqKQMANwMAIAhBCGogBkEIaikDADcDACAJICwgBxshCSALIBogBSAHGyIFTw0GIAhBYGohCCAJIA4iBksNAAsMBwtB6KvCACASIAUQpwEAC0Hoq8IAIAwgBRCnAQALIBUgBRCoAQALIAUgERCmAQALIAggEyALIAgQugEiBmohCSAOIBBKQQAgEEEBThsNASALIQULIBMhBgwBCyAPIBpqIQ4gCyEFA0AgBSAHIAYgBygCCCAGKAIISSILGyIIKQMANwMAIAVBGGogCEEYaikDADcDACAFQRBqIAhBEGopAwA3AwAgBUEIaiAIQQhqKQMANwMAIAYgBkEgaiALGyEGIAVBIGohBSAHQSBqIAcgCxsiByAOTw0BIAkgBksNAAsLIAUgBiAJIAZrQWBxELoBGiAEKAJgIgUgDEsEQCAEKAJYIgYgH2oiByAQICBqNgIEIAcgFTYCACAFIBJNDQIgBiAhaiIHIAdBCGogBSASQX9zakEDdBCZARogBCAFQX9qIgU2AmAgBSAFQQFNDQQaDAELC0H4q8IAIAwgBRCnAQALQaeuwgBBHUHErsIAELkBAAsgBQshByAKDQALIAQoAlwEQCAGECELRQ0CIBMQIQwCCyAFIBQQqAEACyARQQJJDQAgEUF+aiEFIA8gEUEFdGpBQGohBkECIQkDQCARIAVJDQYgBiAJED8gBkFgaiEGIAlBAWohCSAFQX9qIgVBf0cNAAsLIBYgFigCAEEBajYCACAXIBcoAgBBAWo2AgAgDUUNBiBGpyILQQV0IRdBACEKAn8gC0UEQEEAIQdBACETQQAhFkEADAELIBRBBXQhBkEAIQVBACETA0AgEyAFIA1qIgdBFGooAgBrIAdBGGooAgBqAn8gBysDAJsiSkQAAAAAAADwQWMgSkQAAAAAAAAAAGZxBEAgSqsMAQtBAAtBAXRrIRMgBiAFQSBqIgVHDQALQQAhBUEAIQcDQCAHIAUgDWpBHGotAABBAXNqIQcgBiAFQSBqIgVHDQALQQAhCUEAIQgDQCAIIAkgDWoiBUEMaigCAGsgBUEQaigCAGogBUEUaigCACAFQRhqKAIAa2ohCCAGIAlBIGoiCUcNAAtBACEGIBdBYGpBBXYiBSALQX9qIg4gDiAFSxsiDARAIA1BKGohBUEAIQkDQCAGQQAgBUFgaigCACIQQQFqIg4gBSgCACIWayISIBIgDksbaiIGIBYgEEF/c2pqIAYgDiAWSRshBiAFQSBqIQUgCUEBaiIJIAxJDQALC0EAIAZrIRZBACAIawshCEEBIQ4gC0F/aiIFIAtNBEAgBSALSQRAIA0gBUEFdGotAB0hDgsgDSgCCCEFIA1BIGogDSAXakcEQCANQShqIQYgFEEFdEFgaiEJA0AgBigCACIKIAUgBSAKSxshBSAGQSBqIQYgCUFgaiIJDQALC0EAIAVrIQoLQQAhBiAkBEAgJEEUbCEJICpBCGohBQNAIAUoAgAgBUF8aigCAGsgBmohBiAFQRRqIQUgCUFsaiIJDQALQQAgBmshBgsgRachBSACKAIAKAIAKAIEIhRFIAtBAUtyDQIgC0EBa0UEQCARQQFHIBRBAklyDQMgD0EQaigCACAPKAIMa0EBdCANQRBqKAIAIA0oAgxrTw0DIA0tAB0NAwsgBQRAIA0QIQsgGwRAIA8QIQsgASgCACIFIAEoAgRHDQALCyAAQQA2AgQMAQsgACAkNgIIIAAgKjYCBCAAIEA2AgAgAEHgAGogBjYCACAAQdwAakEAICRrNgIAIABB2ABqID82AgAgAEHUAGogCjYCACAAQdAAaiAONgIAIABBzABqIBY2AgAgAEHIAGogCDYCACAAQcQAaiAHNgIAIABBQGsgEzYCACAAQTxqIBE2AgAgAEE4aiAbNgIAIABBNGogDzYCACAAQTBqIAs2AgAgAEEsaiAFNgIAIABBKGogDTYCACAAQSRqID82AgAgAEEgaiAzNgIAIABBHGogNDYCACAAQRhqIDU2AgAgAEEUaiA2NgIAIABBEGogNzYCACAAQQxqIDg2AgALIARBgARqJAAPCyAFIBEQqAEAC0GixMIAQRAgBEHwAmpBzKjCABCWAQALQdzDwgBBxgAgBEHwAmpB3KjCABCWAQAL4x8DJH8DfgZ8IwBBwAFrIgQkAAJAAkACQAJAAkACQAJAAkAgAkEIaigCACIDIAIoAgQiBkYNACABQQhqKAIAIgUgASgCBCILRg0AIAUgC2siBSAFIAMgBmsiAyADIAVLGyACLQApIgYbIQUgA0ECT0EAIAVBAUsbDQEgAyAFRg0CDAMLIABBAjoAHAwDC0QAAAAAAADwPyAFIAMgAyAFSyILG7ggAyAFIAsbuKOhRKRwPQrXo9A/Y0UNAQsgBEEoaiABEKwBIAQoAiwhBSAEKAIoIQkCQCAGRQRAIAUgASgCCCABKAIEayIGIANBAWoiAyADIAZLGyIDTw0BIAMgBRCmAQALIAUhAwsCQAJAAkACQEQAAAAAAADwPwJ8AkAQeSIFBEAgBEEgaiACEKwBIAQoAiQhBiADBEAgBkUNAiAFKAIADQsgBCgCICEKIAVBfzYCACAFKAIQDQsgBUEEaiELIAVBfzYCEAJAIAMgBSgCDCIITQRAIAUgAzYCDAwBCyALIAMgCGsQmAELIAVBFGohCAJAIAYgBSgCHCIMTQRAIAUgBjYCHAwBCyAIIAYgDGsQmAELIAUoAgQgBSgCDCAJIAMQcyAFKAIUIAUoAhwgCiAGEHNBACEDIAUoAgQgBSgCDCIGIARBgAFqQQBBICAGZ2sQCSAFKAIUIAUoAhwiBiAEQYABakEAQSAgBmdrEAkgCxCAASAIEIABIAUoAhwhCQJAIAUoAgwiCkUEQEEAIQZBACELQQAhCAwBC0EAIQZBACELQQAhCCAJRQ0AIAUoAhQhDCAFKAIEIQ4DQAJAAkACQAJAQX8gDiADQQJ0aigCACIPIAwgBkECdGooAgAiEEcgDyAQSRsOAgACAQsgBkEBaiEGIANBAWohAyAIQQFqIQgMAgsgA0EBaiEDDAELIAZBAWohBgsgC0EBaiELIAMgCk8NASAGIAlJDQALCyAFIAUoAhBBAWo2AhAgBSAFKAIAQQFqNgIAIAi3IAkgCmogA2sgBmsgC2q4owwDC0QAAAAAAAAAAEQAAAAAAADwPyAGGwwCCwwIC0QAAAAAAAAAAAuhRFK4HoXrUeA/YwRAIARBAjoATBAnIgdFDQcgBEEYaiACEKwBIAQoAhwhCyAEKAIYIRMgBEEQaiABEKwBIAcoAiwNCSAEKAIUIRogBCgCECEUIAdBfzYCLCAHKAI8DQkgB0EANgJIIAdCgICAgHA3AjggBEEIaiACEK0BIAdBMGogBCgCCCIDIAMgBCgCDGoQkgEgBCABEK0BIAdBQGsiFSAEKAIAIgMgAyAEKAIEahCSASAHKAIADQkgB0F/NgIAIAcoAkAhBiAHKAIwIQ0gBygCOCIJQQJqIgMgBygCSCIKQQJqIgUgAyAFSxsiAyAHKAIETQ0EIAdBBGohDyADQQF2IANqIg4gDmwiDCAHKAIQIgNNDQMgB0EIaiADIAwgA2siBRCFASAHKAIIIAcoAhAiDEEDdGohAyAFQQJJDQFBASEIA0AgA0IANwMAIANBCGohAyAIQQFqIgggBUkNAAsgCCAMakF/aiEMDAILIABBAjoAHAwFCyAFDQAMAQsgA0IANwMAIAxBAWohDAsgByAONgIEIAcgDDYCECAPEHwLIAkEQCAJQQN0IQhBAiEDA0AgBygCCCIFIAcoAgQiCSADbEEDdGpBCGogCSADQX9qbEEDdCAFakEIaisDACANKwMAoDkDACADQQFqIQMgDUEIaiENIAhBeGoiCA0ACwsgCgRAIApBA3QhDUECIQMDQCAHKAIIIAMgBygCBGpBA3RqIgUgBUF4aisDACAGKwMAoDkDACADQQFqIQMgBkEIaiEGIA1BeGoiDQ0ACwsgBygCFEUEQCAHQX82AhQCQCAHKAIYIgNFBEBBACEDIAdBADYCKAwBCyAHKAIcQf8BIANBBWoQwAEaIAdBADYCKCAHKAIYIgNBCEkNACADQQFqQQN2QQdsIQMLIAcgAzYCJCALBEAgB0EYaiEWIBMgC0ECdGohDSAUIBpBAnRqIRdBACELIBMhDgNAIAtBAWohDyAOKAIAIQwgGgRAIAtBAmohGCALQQJ0IBNqQXxqIRlBACEJQQAhAyAUIQUDQCADIQggBCAFKAIAIhA2AoABIAVBBGohBUKlxoihyJyn+UshJ0EBIQMgECEGA0AgJyAGrUL/AYOFQrODgICAIH4hJyADQQRHBEAgBEGAAWogA2ogA0EBaiEDLQAAIQYMAQsLICenIgZBGXYiA0EIdCADciIDQRB0IANyIRsgCEEBaiEDIAcoAhgiESAGcSEGIAcoAhwhHEEAIRICQANAAkAgBiAcaigAACIdIBtzIgpBf3MgCkH//ft3anFBgIGChHhxIgoEQCAHKAIgIR4DQCAQIB4gCmhBA3YgBmogEXFBA3RqIh8oAgBGDQIgCkF/aiAKcSIKDQALCyASQQRqIhIgBmogEXEhBkEAIQogHSAdQQF0cUGAgYKEeHFFDQEMAgsLIB9BBGohCgsCQAJAAkAgBygCOCIGIAtLBEAgBygCSCIGIAhNDQEgCkHYqsIAIAobKAIAIQogBygCMCALQQN0aisDACEqIBUoAgAgCEEDdGorAwAhKyALRQ0CRAAAAAAAAOA/ISwgDCAZKAIARw0CDAMLQeirwgAgCyAGEKcBAAtB6KvCACAIIAYQpwEAC0QAAAAAAADwPyEsCyAIBHxEAAAAAAAA4D9EAAAAAAAA8D8gECAIQQJ0IBRqQXxqKAIARhsFRAAAAAAAAPA/CyEuIAcoAggiBiAHKAIEIhEgCmwgCWpBA3RqKwMAIS0gKyArICogKiArYxsgKiAqYhtEAAAAAAAA8D9EAAAAAAAAAAAgDCAQRxuiIAYgDyARbCISIANqQQN0aisDAKAgLSAPIAlrIAhqIApruEQAAAAAAADgP6KgEMgBIS0gBiASIAhBAmoiCGpBA3RqKwMAIS8gKiAsEMgBISogBiAIIBEgGGwiCmpBA3RqICsgLhDIASAGIAMgCmpBA3RqKwMAoCAqIC+gEMgBIC0QyAE5AwAgAyAJIAwgEEYbIQkgBSAXRw0ACwsgDkEEaiEOIAQgDDYCgAFCpcaIocicp/lLISdBASEDIAwhBgNAICcgBq1C/wGDhUKzg4CAgCB+IScgA0EERwRAIARBgAFqIANqIANBAWohAy0AACEGDAELCyAnQhmIp0H/AHEiBUEIdCAFciIDQRB0IANyIRAgBygCGCIIICencSEDIAcoAhwhBkEAIQoCQAJAA0AgAyAGaigAACILIBBzIglBf3MgCUH//ft3anFBgIGChHhxIgkEQCAHKAIgIREDQCARIAloQQN2IANqIAhxQQN0aiISKAIAIAxGDQMgCUF/aiAJcSIJDQALCyAKQQRqIgogA2ogCHEhAyALIAtBAXRxQYCBgoR4cUUNAAsgBiAWICcQlwEiA2otAABBAXEhCyAHIAcoAiQiCSALRXIEfyAJBSAEQYABaiAWEBQgFiAnEJcBIQMgBygCGCEIIAcoAhwhBiAHKAIkCyALazYCJCAHKAIgIAMgBmogBToAACAIIANBfGpxIAZqQQRqIAU6AAAgA0EDdGoiAyAPNgIEIAMgDDYCACAHIAcoAihBAWo2AigMAQsgEiAPNgIECyAPIQsgDSAORw0ACwsgByAHKAIUQQFqNgIUIAcgBygCACIDQQFqIiI2AgAgByAHKAI8QQFqNgI8IAcgBygCLEEBajYCLCADQQJqIgNBAEoEQCAHIAM2AgAgAigCDCIXIQMgAi0AKSIbBEAgFyABKAIMIgMgFyADSxshAwsgAigCCCIWIAIoAgQiGmsiHCABKAIIIhEgASgCBCISayIYIBwgGEsbIgVBAWoiCyADQX9qIhVLBEBBACADayEjQQAgBWshGUKBgoSAECACLQAoIiRBe2oiA61CA4aIpyElIAIoAgAhHSADQQVJISYgCyEFA0AgBSIPQX9qIgVBASAFQQFLGyEeIAshAwNAAkAgFSADTw0AIA8gA2shBiADQX9qIQlBACAHKAIEIgJBA3RrIQwgBygCCCAPIAIgA2xqIg1BA3RqIQggBygCECEKA0ACQCAJIgMgHEsgBSAYS3IgBSAVRiADICNqQX9GcSADIBdJcnINACAFIAEoAgxJQQAgGxsNAiAGIAMgGWoiCSAJQQBIG0EBSg0AIAogDUsEQCAIKwMAIiogAyAeIAMgHksbuKNE4XoUrkfhyj9kDQEgBEECOgCcAUEAIQYDQCAEQTBqIAZqIgIpAwAhJyACIARBgAFqIAZqIggpAwA3AwAgCCAnNwMAIAJBCGoiCSkDACEnIAkgCEEIaiIJKQMANwMAIAJBEGoiCikDACEoIAogCEEQaiIKKQMANwMAIAJBGGoiAikDACEpIAIgCEEYaiICKQMANwMAIAIgKTcDACAKICg3AwAgCSAnNwMAIAZBQGsgBkEgaiEGQcEASQ0ACyAEIAQvAZ4BOwF8IAQtAJ0BIQ0gBCgCmAEhCCAEKAKUASEJIAQoApABIQogBCgCjAEhDCAEKAKIASEfIAQrA4ABISsgBCsDoAEhLCAEKAKoASEQIAQoAqwBIRMgBCgCsAEhFCAEKAK0ASEOIAQoArgBIQIgBC0AvAEhISAELQC9ASEgIAQtAJwBIQYgBCAELwG+ATsBegJAAkAgBkECRwRAIAQgBC8BfDsBgAEgBCAELwF6OwF+ICsgKmUNAQtBACEJIBtBAEcgBSAYRiABKAIAIR8Cf0EAIAEtACgiAkEKRg0AGkEAIAJBe2oiAkEESw0AGkKBgoSAECACrUIDhoinCyEGQQAgJUEAICYbICRBCkYbISEgBSEIIBEhCiASIQwgKiErICohLCAdIRAgGiETIBYhFEEAIQ4gAyECciINISAMAQsgBCAELwGAATsBeCAEIAQvAX47AXYLIAQgDToATSAEIAY6AEwgBCAINgJIIAQgCTYCRCAEIAo2AkAgBCAMNgI8IAQgHzYCOCAEICs5AzAgBCAgOgBtIAQgIToAbCAEIAI2AmggBCAONgJkIAQgFDYCYCAEIBM2AlwgBCAQNgJYIAQgLDkDUCAEIAQvAXg7AU4gBCAELwF2OwFuICpEAAAAAAAAsDxlQQFzDQQMAwtB6KvCACANIAoQpwEACyAGQQFqIQYgA0F/aiEJIAggDGohCCANIAJrIQ0gFSADSQ0ACwsLIBlBAWohGSAVIAVJDQALCyAHICI2AgAgACAEQTBqQcAAELoBGgwDC0GMqsIAQRggBEGAAWpBpKrCABCWAQALDAQLIABBAjoAHAsgBEHAAWokAA8LQdzDwgBBxgAgBEGAAWpB3KjCABCWAQALQaLEwgBBECAEQYABakHcqsIAEJYBAAtBosTCAEEQIARBgAFqQciqwgAQlgEAC5gcAht/AX4jAEHwA2siAyQAIAMgAjYCkAMgAyACNgKMAyADIAE2AogDIANB0ABqIANBiANqEI4BIAMoAlQhDyADKAJQIQ4CQAJAAkACQAJAAkACQBDCASIKBEAgCigCAA0GIApBfzYCACAKQQRqIAAQVSIERQ0BEMEBIgsEQCALKAIADQcgC0F/NgIAIAtBBGogABBWIghFDQMgA0GgAmogDiAOIA9qECMgA0GIA2ogA0GgAmoQYCADQaACaiADQYgDaiAEQRRqIgIQOyADKAKoAiIAQX9qIgEgAE8gASAAS3JFBEAgAygCoAIgAUEUbGpBADoAEQsgA0GIA2ogA0GgAmpBMBC6ARogA0GgAmogA0GIA2ogAhAmIANBiANqIANBoAJqIAIQICADQaACaiADQYgDahA5IAMoAqgCIgEEQCADKAKgAiEAIAFBFGwhASADQcACaigCACEFIAMoArgCIQwDQCAAIAwgBSACEEggAEEUaiEAIAFBbGoiAQ0ACwsgA0GIA2ogA0GgAmpBMBC6ARogA0GgAmogA0GIA2ogAhBQIAMoAqACIQwCQCADKAKoAiIFRQRAIANBwAJqKAIAIQYgAygCuAIhDQwBCyAFQRRsIQEgA0HAAmooAgAhBiADKAK4AiENIAwhAANAIAAgDSAGIAIQRyAAQRRqIQAgAUFsaiIBDQALCyADQcgCaigCACEWIANBvAJqKAIAIRcgA0GwAmooAgAhGCADQbQCaigCACEAIAMoAqQCIRkgAygCrAIhECADKALEAiERIANB9ABqIANBzAJqKAIANgIAIANB7ABqIAY2AgAgA0HkAGogADYCACADIBE2AnAgAyANNgJoIAMgEDYCYCADIAU2AlwgAyAMNgJYAkAgCCgCCCICRQRAQQAhAgwBCyAIKAIAIAJBBHRqQXRqIQADQCACQX9qIQIgAEEEaigCAARAIAAoAgAQIQsgAEFwaiEAIAINAAsgAygCXCEFCyAIIAI2AgggAyADQdgAajYCfCADIAQ2AnggA0GMAWogBCgCrAE2AgAgAyAEKAKkATYCiAEgAyAEKAKgATYChAEgAyAEKAKYATYCgAECQCAFRQRAIAQoAtgBDQogBEHYAWohBSAEQX82AtgBIARB3AFqIQcgBCgC3AENASAEKAIEIQAgBCgCDCEBIANBIGogBCgCECICQQF0EKABIAMpAyAhHiADQbACaiIGQQA2AgAgA0EAOwG4AiADIAI2ArQCIAMgHjcDqAIgAyAAIAFBPGxqNgKkAiADIAA2AqACAkAgA0GgAmoQMSIARQRAIANBADYCqAEgA0IENwOgASADKAKsAkUNASADKAKoAhAhDAELIAAoAgAhACADQRhqQQEQoAEgAygCHCEBIAMoAhgiCSAANgIAIANBATYC6AEgAyABNgLkASADIAk2AuABIANBoANqIANBuAJqKAIANgIAIANBmANqIAYpAwA3AwAgA0GQA2ogA0GoAmopAwA3AwAgAyADKQOgAjcDiAMCQCADQYgDahAxIgZFDQBBBCECQQEhAANAIAYoAgAhBiAAIAFGBEAgA0HgAWogAUEBEIMBIAMoAuABIQkLIAIgCWogBjYCACADIABBAWoiADYC6AEgA0GIA2oQMSIGRQ0BIAJBBGohAiADKALkASEBDAALAAsgA0GUA2ooAgAEQCADKAKQAxAhCyADQagBaiADQegBaigCADYCACADIAMpA+ABNwOgAQsgA0GIA2ogA0GgAWoQkwECQCAHKAIAIgBFDQAgBCgC4AFFDQAgABAhCyAHIAMpA4gDNwIAIAdBCGogA0GQA2ooAgA2AgAgA0GYAWogA0GoAWooAgA2AgAgAyADKQOgATcDkAEMBwsgBCgCsAENCSAEQX82ArABIARBADYC1AEgBEHMAWohCSAEKAIQIRIgBCAEKAK0ASIBBH8gCUEAIAEQgwEgBCgCzAEgBCgC1AEiAkECdGohACABQQFHBEAgAUF/aiEBA0AgAEEANgIAIAJBAWohAiAAQQRqIQAgAUF/aiIBDQALCyAAQQA2AgAgAkEBagVBAAs2AtQBIANBoAFqIANB2ABqEB8gAygCoAEhByADKAKoASIARQ0FIAcgAEEMbGohGiAHIQUDQCADQqXGiKHInKf5SzcDiAMgBSIBIANBiANqEHYgAygCiAMiAEEZdiICQQh0IAJyIgJBEHQgAnIhGyABQQxqIQUgBCgCuAEiEyAAcSEAIAQoArwBIRxBACEUAkADQAJAIAAgHGooAAAiFSAbcyICQX9zIAJB//37d2pxQYCBgoR4cSICBEAgBCgCwAEhHQNAIAEgHSACaEEDdiAAaiATcUEYbGoiBkYNAiABIAZBDBC1AUUNAiACQX9qIAJxIgINAAsLIBRBBGoiFCAAaiATcSEAIBUgFUEBdHFBgIGChHhxRQ0BDAILCyAGKAIUIgFFDQAgBigCDCEAIAFBAnQhAgNAIAkoAgAgACgCAEECdGoiASABKAIAQQFqNgIAIABBBGohACACQXxqIgINAAsLIAUgGkcNAAsMBQsgA0GQAWogBxCTAQwFCwwFCwwEC0Hf8sIAQStBjPPCABC5AQALQd/ywgBBK0GM88IAELkBAAsgBCgCzAEhACAEKALUASEBIANByABqIBJBFGwQoQEgAykDSCEeIANBtAJqQQA2AgAgA0EAOwG8AiADIBJBCmw2ArgCIAMgHjcCrAIgA0EANgKoAiADIAAgAUECdGo2AqQCIAMgADYCoAIgA0FAayADQaACahAwAkAgAygCREUEQCADQQA2ApgBIANCBDcDkAEgAygCsAJFDQEgAygCrAIQIQwBCyADKAJAIQAgA0E4akEBEKABIAMoAjwhASADKAI4IgUgADYCACADQQE2AugBIAMgATYC5AEgAyAFNgLgASADQaADaiADQbgCaikDADcDACADQZgDaiIJIANBsAJqKQMANwMAIANBkANqIANBqAJqKQMANwMAIAMgAykDoAI3A4gDIANBMGogA0GIA2oQMAJAIAMoAjRFDQAgAygCMCEGQQQhAkEBIQADQCAAIAFGBEAgA0HgAWogAUEBEIMBIAMoAuABIQULIAIgBWogBjYCACADIABBAWoiADYC6AEgA0EoaiADQYgDahAwIAMoAixFDQEgAygCKCEGIAJBBGohAiADKALkASEBDAALAAsgCSgCAARAIAMoApQDECELIANBmAFqIANB6AFqKAIANgIAIAMgAykD4AE3A5ABCyADKAKkAQRAIAcQIQsgBEGwAWohBQsgBSAFKAIAQQFqNgIAIAMoApABIQcgAygCmAEhACADQRBqIAMoAngoAhAiAUEBdBCdASADKQMQIR4gA0G8AWpBADYCACADQQA7AcQBIAMgATYCwAEgAyAeNwK0ASADIAcgAEECdGo2AqQBIAMgBzYCoAEgAyADQYABajYCyAEgAyADQfwAajYCsAEgAyADQfwAajYCrAEgAyADQfgAajYCqAEgA0GgAmogA0GgAWoQHgJAAkACQAJAIAMoAqQCRQRAIANBADYC5AEMAQsgA0GIA2ogA0GgAmpB5AAQugEaIANB4AFqIANByAFqIANBiANqEBUgAygC5AENAQsgAygCvAEiAQRAIAMoArQBIQAgAUHkAGwhAgNAIAAQvQEgAEHkAGohACACQZx/aiICDQALC0EAIQRBBCEFIAMoArgBRQ0BIAMoArQBECEMAQtBASECIANBCGpBARCfASADQegBaikDACEeIAMoAgwhACADKAIIIgUgAykD4AE3AgAgBUEIaiAeNwIAIANBATYC2AEgAyAANgLUASADIAU2AtABIANB4AFqIANBoAFqQSwQugEaIANBoAJqIANB4AFqEB4CQCADKAKkAgRAIANBiAJqIQFBECEAA0AgA0GIA2ogA0GgAmpB5AAQugEaIANBkAJqIAEgA0GIA2oQFSADKAKUAkUNAiADQZADaiIEIANBmAJqKQMANwMAIAMgAykDkAI3A4gDIAMoAtQBIAJGBEAgA0HQAWogAhC0ASADKALQASEFCyAAIAVqIgYgAykDiAM3AgAgBkEIaiAEKQMANwIAIAMgAkEBaiICNgLYASAAQRBqIQAgA0GgAmogA0HgAWoQHiADKAKkAg0ACwsgA0EANgKUAgsgA0H8AWooAgAiAQRAIAMoAvQBIQAgAUHkAGwhAQNAIAAQvQEgAEHkAGohACABQZx/aiIBDQALCyADQfgBaigCAARAIAMoAvQBECELIAMoAtQBIQQMAQtBACECCyADKAKUAQRAIAcQIQsgBSACQQR0aiEBAkAgASACBH8gBSEAA0AgAEEEaigCACIHBEAgAEEIaikCACEeIAAoAgAhBiAIKAIIIgIgCCgCBEYEQCAIIAIQtAEgCCgCCCECCyAIKAIAIAJBBHRqIgIgBzYCBCACIAY2AgAgAkEIaiAeNwIAIAggCCgCCEEBajYCCCAAQRBqIgAgAUcNAQwDCwsgAEEQagUgBQsiAEYNAANAIABBBGooAgAiAkUNASAAQQhqKAIABEAgAhAhCyAAQRBqIgIhACABIAJHDQALCyAEBEAgBRAhCyAZBEAgDBAhCyAYBEAgEBAhCyAXBEAgDRAhCyAWBEAgERAhCyALIAsoAgBBAWo2AgAgCiAKKAIAQQFqNgIAIA8EQCAOECELIANB8ANqJAAPC0Hcw8IAQcYAIANBiANqQdyowgAQlgEAC0GixMIAQRAgA0GIA2pBzKjCABCWAQALQaLEwgBBECADQYgDakG4qsIAEJYBAAvDGAIIfwF+AkACQAJAIABB9QFPBEAgAEHN/3tPDQIgAEELaiIAQXhxIQRBpPbCACgCACIIRQ0BQQAgBGshAgJAAkACf0EAIABBCHYiAEUNABpBHyAEQf///wdLDQAaIARBBiAAZyIAa0EfcXZBAXEgAEEBdGtBPmoLIgdBAnRBsPjCAGooAgAiAARAIARBAEEZIAdBAXZrQR9xIAdBH0YbdCEFA0ACQCAAKAIEQXhxIgYgBEkNACAGIARrIgYgAk8NACAAIQMgBiICDQBBACECDAMLIABBFGooAgAiBiABIAYgACAFQR12QQRxakEQaigCACIARxsgASAGGyEBIAVBAXQhBSAADQALIAEEQCABIQAMAgsgAw0CC0EAIQNBAiAHQR9xdCIAQQAgAGtyIAhxIgBFDQMgAEEAIABrcWhBAnRBsPjCAGooAgAiAEUNAwsDQCAAIAMgACgCBEF4cSIBIARPIAEgBGsiBiACSXEiBRshAyAGIAIgBRshAiAAKAIQIgEEfyABBSAAQRRqKAIACyIADQALIANFDQILQbD5wgAoAgAiACAET0EAIAIgACAEa08bDQEgAxBZAkAgAkEQTwRAIAMgBEEDcjYCBCADIARqIgEgAkEBcjYCBCABIAJqIAI2AgAgAkGAAk8EQCABIAIQUQwCCyACQQN2IgJBA3RBqPbCAGohAAJ/QaD2wgAoAgAiBUEBIAJBH3F0IgJxBEAgACgCCAwBC0Gg9sIAIAIgBXI2AgAgAAshAiAAIAE2AgggAiABNgIMIAEgADYCDCABIAI2AggMAQsgAyACIARqIgBBA3I2AgQgACADaiIAIAAoAgRBAXI2AgQLIANBCGoPCwJAAkBBoPbCACgCACIDQRAgAEELakF4cSAAQQtJGyIEQQN2IgJBH3EiAXYiAEEDcUUEQCAEQbD5wgAoAgBNDQMgAA0BQaT2wgAoAgAiAEUNAyAAQQAgAGtxaEECdEGw+MIAaigCACIBKAIEQXhxIARrIQIgASEFA0AgASgCECIARQRAIAFBFGooAgAiAEUNBAsgACgCBEF4cSAEayIBIAIgASACSSIBGyECIAAgBSABGyEFIAAhAQwACwALIABBf3NBAXEgAmoiAUEDdCIGQbD2wgBqKAIAIgBBCGohAgJAIAAoAggiBSAGQaj2wgBqIgZHBEAgBSAGNgIMIAYgBTYCCAwBC0Gg9sIAIANBfiABd3E2AgALIAAgAUEDdCIBQQNyNgIEIAAgAWoiACAAKAIEQQFyNgIEDAMLAkBBAiABdCICQQAgAmtyIAAgAXRxIgBBACAAa3FoIgBBA3QiBUGw9sIAaigCACIBKAIIIgIgBUGo9sIAaiIFRwRAIAIgBTYCDCAFIAI2AggMAQtBoPbCACADQX4gAHdxNgIACyABIARBA3I2AgQgASAEaiIGIABBA3QiACAEayIFQQFyNgIEIAAgAWogBTYCAEGw+cIAKAIAIgAEQCAAQQN2IgNBA3RBqPbCAGohAEG4+cIAKAIAIQICf0Gg9sIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtBoPbCACADIARyNgIAIAALIQMgACACNgIIIAMgAjYCDCACIAA2AgwgAiADNgIIC0G4+cIAIAY2AgBBsPnCACAFNgIAIAFBCGoPCyAFEFkCQCACQRBPBEAgBSAEQQNyNgIEIAQgBWoiBiACQQFyNgIEIAIgBmogAjYCAEGw+cIAKAIAIgAEQCAAQQN2IgNBA3RBqPbCAGohAEG4+cIAKAIAIQECf0Gg9sIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtBoPbCACADIARyNgIAIAALIQMgACABNgIIIAMgATYCDCABIAA2AgwgASADNgIIC0G4+cIAIAY2AgBBsPnCACACNgIADAELIAUgAiAEaiIAQQNyNgIEIAAgBWoiACAAKAIEQQFyNgIECyAFQQhqDwsCQAJAAkACQEGw+cIAKAIAIgEgBEkEQEG0+cIAKAIAIgAgBEsNBkEAIQIgBEGvgARqIgBBEHZAACIBQX9GDQUgAUEQdCIDRQ0FQcD5wgAgAEGAgHxxIgVBwPnCACgCAGoiADYCAEHE+cIAQcT5wgAoAgAiASAAIAEgAEsbNgIAQbz5wgAoAgAiAkUNAUHI+cIAIQADQCAAKAIAIgEgACgCBCIGaiADRg0DIAAoAggiAA0ACwwDC0G4+cIAKAIAIQACQCABIARrIgNBD00EQEG4+cIAQQA2AgBBsPnCAEEANgIAIAAgAUEDcjYCBCAAIAFqIgFBBGohBCABKAIEQQFyIQIMAQtBsPnCACADNgIAQbj5wgAgACAEaiICNgIAIAIgA0EBcjYCBCAAIAFqIAM2AgAgBEEDciECIABBBGohBAsgBCACNgIAIABBCGoPC0Hc+cIAKAIAIgBBACAAIANNG0UEQEHc+cIAIAM2AgALQQAhAEHg+cIAQf8fNgIAQcz5wgAgBTYCAEHI+cIAIAM2AgBB1PnCAEEANgIAA0AgAEGw9sIAaiAAQaj2wgBqIgE2AgAgAEG09sIAaiABNgIAIABBCGoiAEGAAkcNAAtBvPnCACADNgIAQbT5wgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRB2PnCAEGAgIABNgIADAILIAAoAgwgAyACTXIgASACS3INACAAIAUgBmo2AgRBvPnCAEG8+cIAKAIAIgBBD2pBeHEiAUF4ajYCAEG0+cIAQbT5wgAoAgAgBWoiAyAAIAFrakEIaiICNgIAIAFBfGogAkEBcjYCACAAIANqQSg2AgRB2PnCAEGAgIABNgIADAELQdz5wgBB3PnCACgCACIAIAMgACADSRs2AgAgAyAFaiEBQcj5wgAhAAJAA0AgASAAKAIARwRAIAAoAggiAA0BDAILCyAAKAIMDQAgACADNgIAIAAgACgCBCAFajYCBCADIARBA3I2AgQgAyAEaiEFIAEgA2sgBGshBAJAAkAgAUG8+cIAKAIARwRAQbj5wgAoAgAgAUYNASABKAIEIgBBA3FBAUYEQAJAIABBeHEiAkGAAk8EQCABEFkMAQsgASgCDCIGIAEoAggiB0cEQCAHIAY2AgwgBiAHNgIIDAELQaD2wgBBoPbCACgCAEF+IABBA3Z3cTYCAAsgAiAEaiEEIAEgAmohAQsgASABKAIEQX5xNgIEIAUgBEEBcjYCBCAEIAVqIAQ2AgAgBEGAAk8EQCAFIAQQUQwDCyAEQQN2IgFBA3RBqPbCAGohAAJ/QaD2wgAoAgAiAkEBIAFBH3F0IgFxBEAgACgCCAwBC0Gg9sIAIAEgAnI2AgAgAAshAiAAIAU2AgggAiAFNgIMIAUgADYCDCAFIAI2AggMAgtBvPnCACAFNgIAQbT5wgBBtPnCACgCACAEaiIANgIAIAUgAEEBcjYCBAwBC0G4+cIAIAU2AgBBsPnCAEGw+cIAKAIAIARqIgA2AgAgBSAAQQFyNgIEIAAgBWogADYCAAsgA0EIag8LQcj5wgAhAANAAkAgACgCACIBIAJNBEAgASAAKAIEaiIGIAJLDQELIAAoAgghAAwBCwtBvPnCACADNgIAQbT5wgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRB2PnCAEGAgIABNgIAIAIgBkFgakF4cUF4aiIAIAAgAkEQakkbIgFBGzYCBEHI+cIAKQIAIQkgAUEQakHQ+cIAKQIANwIAIAEgCTcCCEHM+cIAIAU2AgBByPnCACADNgIAQdD5wgAgAUEIajYCAEHU+cIAQQA2AgAgAUEcaiEAA0AgAEEHNgIAIAYgAEEEaiIASw0ACyABIAJGDQAgASABKAIEQX5xNgIEIAIgASACayIAQQFyNgIEIAEgADYCACAAQYACTwRAIAIgABBRDAELIABBA3YiAUEDdEGo9sIAaiEAAn9BoPbCACgCACIDQQEgAUEfcXQiAXEEQCAAKAIIDAELQaD2wgAgASADcjYCACAACyEBIAAgAjYCCCABIAI2AgwgAiAANgIMIAIgATYCCAtBACECQbT5wgAoAgAiACAETQ0ADAELIAIPC0G0+cIAIAAgBGsiATYCAEG8+cIAQbz5wgAoAgAiACAEaiIDNgIAIAMgAUEBcjYCBCAAIARBA3I2AgQgAEEIagukFgEWfyMAQfADayIGJAACQAJAIAFBFUkNACAGQeAAaiEXIAZByAJqIRhBASERQQEhEgJAAkACQANAIAEhCiAAIQ4gA0FAayEQIBEgEnFBAXMhDwJAA0ACfwJAIAQEQCARQQFxRQRAIAAgARBjIARBf2ohBAsgBiACNgIIIAYgADYCiAEgBiABNgKMASAGIAFBAnYiBTYCDCAGIAVBAXQiBzYCECAGIAVBA2wiCTYCFCAGQQA2AhggBiAGQRhqNgKQAiAGIAZBiAFqNgKMAiAGIAZBCGo2AogCIAYgBkGIAmo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2AogDIAZBHGogBkEgaiAGQQxqIAZBiANqEMUBIAYgB0F/ajYCICAGIAdBAXI2AogDIAZBHGogBkEgaiAGQRBqIAZBiANqEMUBIAYgCUF/ajYCICAGIAlBAWo2AogDIAZBHGogBkEgaiAGQRRqIAZBiANqEMUBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMUBIAYoAhgiBUELSw0BIAYoAhAhCCAFRQwCCyAAIAEQdQwJCyAGKAKMASIJQQF2IgcEQCAGKAKIASIFIAlB5ABsakGcf2ohDANAIAZBiANqIAVB5AAQugEaIAUgDEHkABCZAUHkAGohBSAMIAZBiANqQeQAELoBQZx/aiEMIAdBf2oiBw0ACwsgASAGKAIQQX9zaiEIQQELRSAPckEBcUUEQCAAIAEQJA0ICyADRQRAIA4hACAKIQEMAgsCQCAIIAFJBEAgACAIQeQAbGoiCUFAayENQQAhBQJAA0AgBUEEaiIHQShGDQEgBSAQaiELIAUgDWohDCAHIQVBfyAMKAIAIgcgCygCACILRyAHIAtIGyIHRQ0ACyAHQf8BcUH/AUYNBAsgAQRAIAZBiANqIABB5AAQugEaIAAgCUHkABCZASENIAkgBkGIA2pB5AAQugEaIAZBiAJqIA1B5AAQugEaIA1BXGohDCANQaQBaiETIA1B5ABqIRRBACEIIAEhCQNAAkAgCCAJQX9qIgdPDQAgEyAIQeQAbGohCwNAQQAhBQJAA0AgBUEEaiIAQShGDQEgBSAYaiESIAUgC2ohFSAAIQVBfyAVKAIAIgAgEigCACISRyAAIBJIGyIARQ0ACyAAQf8BcUH/AUYNAgsgC0HkAGohCyAIQQFqIgggB0cNAAsgByEIDAQLIAggB08NAyAMIAlB5ABsaiELA0AgByIJQX9qIQdBACEFAkADQCAFQQRqIgBBKEYNASAFIBhqIRIgBSALaiEVIAAhBUF/IBUoAgAiACASKAIAIhJHIAAgEkgbIgBFDQALIABB/wFxQf8BRw0AIAtBnH9qIQsgCCAHTw0FDAELCyAGQYgDaiAUIAhB5ABsaiIAQeQAELoBGiAAIA0gCUHkAGxqIgBB5AAQmQEaIAAgBkGIA2pB5AAQugEaIAhBAWohCAwACwALQbCnwgBBAEEAEKcBAAtB0KfCACAIIAEQpwEACyANIAZBiAJqQeQAELoBIQAgASAIQQFqIgVPBEAgACAFQeQAbGohACABIAVrIgFBFU8NAQwHCwsgBSABEKgBAAsCQAJAIAEEQCAIIAFPDQEgBkGIAmogAEHkABC6ARogACAAIAhB5ABsaiIFQeQAEJkBIRQgBSAGQYgCakHkABC6ARogBkEgaiAUQeQAELoBGiABQX9qIglFBEBBACEQDAMLIBRBpAFqIQtBACEQA0BBACEFA0AgBUEEaiIHQShGDQQgBSALaiEKIAUgF2ohDiAHIQVBfyAOKAIAIgcgCigCACIKRyAHIApIGyIHRQ0ACyAHQf8BcUH/AUcNAyALQeQAaiELIBBBAWoiECAJRw0ACyAJIRAMAgtBsKfCAEEAQQAQpwEAC0HAp8IAIAggARCnAQALIBRB5ABqIQ0gFCABQeQAbGpBXGohCyAJIQcDQCAHIgogEE0iEkUEQCAKQX9qIQdBACEFA0ACQCAFQQRqIg5BKEYEQEEAIQwMAQsgBSALaiEIIAUgF2ohDCAOIQVBfyAMKAIAIg4gCCgCACIIRyAOIAhIGyIMRQ0BCwsgC0Gcf2ohCyAMQX9HDQELCyAKIBBPBEAgCSAKSQ0CIA0gCkHkAGxqIQ5BgAEhE0EAIQlBACEKQQAhB0EAIQ9BgAEhESANIBBB5ABsaiIVIQ0DQCAOIA1rIghB5ABuIQUCQCAIQePIAUsiGQ0AIAVBgH9qIAUgDyAHSSIIIAogCUlyIgsbIQUgCwRAIBEgBSAIGyERIAUgEyAIGyETDAELIAUgBUEBdiIRayETCwJAIAcgD0cNACARRQRAIAZBiAFqIgchDwwBC0EAIQggBkGIAWohByANIQsDQCAHIAg6AAAgCEEBaiEIQcAAIQUDQAJAIAVBBGoiDEHoAEYEQEEAIQwMAQsgBSALaiEPIAZBIGogBWohFiAMIQVBfyAWKAIAIgwgDygCACIPRyAMIA9IGyIMRQ0BCwsgC0HkAGohCyAHIAxBf0dqIQcgCCARRw0ACyAGQYgBaiEPCwJAIAkgCkcNACATRQRAIAZBiAJqIgkhCgwBC0EAIQggBkGIAmohCSAOIQoDQCAJIAg6AAAgCiILQZx/aiEKIAhBAWohCEEAIQUDQAJAIAVBBGoiDEEoRgRAQQAhDAwBCyAFIBdqIRYgBSALaiEaIAwhBUF/IBYoAgAiDCAaQVxqKAIAIhZHIAwgFkgbIgxFDQELCyAJIAxBf0ZqIQkgCCATRw0ACyAGQYgCaiEKCyAJIAprIgUgByAPayIIIAggBUsbIggEQCAGQYgDaiANIA8tAABB5ABsakHkABC6ARogDSAPLQAAQeQAbGogDiAKLQAAQX9zQeQAbGpB5AAQugEaIAhBAUcEQEEAIQUDQCAOIAUgCmoiCy0AAEF/c0HkAGxqIA0gBSAPakEBaiIMLQAAQeQAbGpB5AAQugEaIA0gDC0AAEHkAGxqIA4gC0EBai0AAEF/c0HkAGxqQeQAELoBGiAFQQJqIAVBAWoiCyEFIAhJDQALIAsgD2ohDyAKIAtqIQoLIA4gCi0AAEF/c0HkAGxqIAZBiANqQeQAELoBGiAPQQFqIQ8gCkEBaiEKCyANIBFB5ABsaiANIAcgD0YbIQ0gDkEAIBNrQeQAbGogDiAJIApGGyEOIBkNAAsCQCAPIAdJBEAgDiEFA0AgBkGIA2ogDSAHQX9qIgctAABB5ABsaiIJQeQAELoBGiAJIAVBnH9qIgVB5AAQmQEaIAUgBkGIA2pB5AAQugEaIA8gB0kNAAsMAQsgDSEFIAogCU8NAANAIAlBf2oiCS0AACEHIAZBiANqIAVB5AAQugEaIAUgDiAHQX9zQeQAbGoiBUHkABCZASAFIAZBiANqQeQAELoBGkHkAGohBSAKIAlJDQALCyAUIAZBIGpB5AAQugEhByABIAUgFWtB5ABuIBBqIgVNDQMgBkGIAmogB0HkABC6ARogByAHIAVB5ABsaiIHQeQAEJkBIQ4gByAGQYgCakHkABC6ASEHIAEgBWsiCkUNBCAKIAUgBSAKSxsgAUEDdiAHQeQAaiEJAkAgBSAKQX9qIgFPBEAgCSABIAIgByAEEAQgBSEBDAELIA4gBSACIAMgBBAEIAchAyAJIQALTyERIAFBFU8NAQwFCwsgECAKEKgBAAsgCiAJEKYBAAtBwKfCACAFIAEQpwEAC0G1rcIAQRxB1K3CABC5AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBJIAEgBUcNAAsLIAZB8ANqJAALgRUBF38jAEHAAmsiBiQAAkACQCABQRVJDQBBASEJQQEhEwJAAkACQANAIAEhDyAAIQ4gCSATcUEBcyERAkACQAJAAkACQAJAA0ACfwJAIAQEQCAJRQRAIAAgARBcIARBf2ohBAsgBiACNgIIIAYgADYCMCAGIAE2AjQgBiABQQJ2IgU2AgwgBiAFQQF0Igg2AhAgBiAFQQNsIgc2AhQgBkEANgIYIAYgBkEYajYCuAEgBiAGQTBqNgK0ASAGIAZBCGo2ArABIAYgBkGwAWo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2ArACIAZBHGogBkEgaiAGQQxqIAZBsAJqEMYBIAYgCEF/ajYCICAGIAhBAXI2ArACIAZBHGogBkEgaiAGQRBqIAZBsAJqEMYBIAYgB0F/ajYCICAGIAdBAWo2ArACIAZBHGogBkEgaiAGQRRqIAZBsAJqEMYBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMYBIAYoAhgiBUELSw0BIAYoAhAhByAFRQwCCyAAIAEQawwOCyAGKAI0IgdBAXYiCARAIAYoAjAiBSAHQQxsakF0aiEHA0AgBkG4AmoiDCAFQQhqIgooAgA2AgAgBiAFKQIANwOwAiAHQQhqIgsoAgAhDSAFIAcpAgA3AgAgCiANNgIAIAsgDCgCADYCACAHIAYpA7ACNwIAIAVBDGohBSAHQXRqIQcgCEF/aiIIDQALCyABIAYoAhBBf3NqIQdBAQtFIBFyQQFxRQRAIAAgARArDQ0LIANFDQIgByABTw0BIANBAyAAIAdBDGxqIgVBAxCUAUH/AXFB/wFGBEAgASEPIAAhDgwECyAGQbgCaiIKIABBCGoiCygCADYCACAGIAApAgA3A7ACIAVBCGoiCCgCACEHIAAgBSkCADcCACALIAc2AgAgCCAKKAIANgIAIAUgBikDsAI3AgAgBkG4AWoiEiALKAIANgIAIAYgACkCADcDsAEgAEF0aiEUIABBDGohDUEAIQcgASEIA0ACQCAHIAhBf2oiDE8NACANIAdBDGxqIQUDQCAGQbABakEDIAVBAxCUAUH/AXFB/wFGDQEgBUEMaiEFIAwgB0EBaiIHRw0ACyAMIQcLIBQgCEEMbGohBQJAA0AgByAIQX9qIghPDQEgBkGwAWpBAyAFQQMQlAEgBUF0aiIMIQVB/wFxQf8BRg0ACyAKIA0gB0EMbGoiBUEIaiITKAIANgIAIAYgBSkCADcDsAIgDEEUaiIQKAIAIRYgBSAMQQxqIgUpAgA3AgAgEyAWNgIAIBAgCigCADYCACAFIAYpA7ACNwIAIAdBAWohBwwBCwsgACAGKQOwATcCACALIBIoAgA2AgAgASAHQQFqIgVPBEAgACAFQQxsaiEAIAEgBWsiAUEVTw0BDAwLCyAFIAEQqAEAC0HQp8IAIAcgARCnAQALIA9FDQELIAcgD08NASAGQbgBaiIUIA5BCGoiEigCADYCACAGIA4pAgA3A7ABIA4gB0EMbGoiAEEIaiIBKAIAIQUgDiAAKQIANwIAIBIgBTYCACABIBQoAgA2AgAgACAGKQOwATcCACAGQShqIhYgEigCADYCACAGIA4pAgA3AyAgDkEMaiEJQQAhDCAPQX9qIgFFDQIgCSEFA0AgBUEDIAZBIGpBAxCUAUH/AXFB/wFHDQMgBUEMaiEFIAEgDEEBaiIMRw0ACyABIQwMAgtBsKfCAEEAQQAQpwEAC0HAp8IAIAcgDxCnAQALIA4gD0EMbGohByABIQgDQAJAIAchACAIIgUgDE0iEw0AIAVBf2ohCCAAQXRqIgdBAyAGQSBqQQMQlAFB/wFxQf8BRw0BCwsgBSAMTwRAIAEgBUkNAkGAASENQQAhCEEAIQpBACEHQQAhC0GAASERIAkgDEEMbGoiFyEBA0AgACABayIJQQxuIQUCQCAJQYsYSyIYDQAgBUGAf2ogBSALIAdJIgkgCiAISXIiEBshBSAQBEAgESAFIAkbIREgBSANIAkbIQ0MAQsgBSAFQQF2IhFrIQ0LAkAgByALRw0AIBFFBEAgBkEwaiIHIQsMAQtBACEFIAEhCSAGQTBqIgshBwNAIAcgBToAACAHIAlBAyAGQSBqQQMQlAFB/wFxQf8BR2ohByAJQQxqIQkgESAFQQFqIgVHDQALCwJAIAggCkcNACANRQRAIAZBsAFqIgghCgwBCyAAQXRqIQVBACEJIAZBsAFqIgohCANAIAggCToAACAIIAVBAyAGQSBqQQMQlAFB/wFxQf8BRmohCCAFQXRqIQUgDSAJQQFqIglHDQALCyAIIAprIgUgByALayIJIAkgBUsbIgkEQCAGQbgCaiIZIAEgCy0AAEEMbGoiBUEIaigCADYCACAGIAUpAgA3A7ACIAEgCy0AAEEMbGoiBSAAIAotAABBf3NBDGxqIhApAgA3AgAgBUEIaiAQQQhqKAIANgIAIAlBAUcEQEEAIQUDQCAAIAUgCmoiEC0AAEF/c0EMbGoiFSABIAUgC2pBAWoiGi0AAEEMbGoiGykCADcCACAVQQhqIBtBCGooAgA2AgAgASAaLQAAQQxsaiIVIAAgEEEBai0AAEF/c0EMbGoiECkCADcCACAVQQhqIBBBCGooAgA2AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgCiAQaiEKCyAAIAotAABBf3NBDGxqIgUgBikDsAI3AgAgBUEIaiAZKAIANgIAIAtBAWohCyAKQQFqIQoLIAEgEUEMbGogASAHIAtGGyEBIABBACANa0EMbGogACAIIApGGyEAIBgNAAsCQCALIAdJBEAgACEFA0AgBkG4AmoiCCABIAdBf2oiBy0AAEEMbGoiAEEIaiIJKAIANgIAIAYgACkCADcDsAIgBUF0aiIFQQhqIgooAgAhDSAAIAUpAgA3AgAgCSANNgIAIAogCCgCADYCACAFIAYpA7ACNwIAIAsgB0kNAAsMAQsgASEFIAogCE8NAANAIAhBf2oiCC0AACEBIAZBuAJqIgcgBUEIaiIJKAIANgIAIAYgBSkCADcDsAIgACABQX9zQQxsaiIBQQhqIgsoAgAhDSAFIAEpAgA3AgAgCSANNgIAIAsgBygCADYCACABIAYpA7ACNwIAIAVBDGohBSAKIAhJDQALCyAOIAYpAyA3AgAgEiAWKAIANgIAIA8gBSAXa0EMbiAMaiIBTQ0DIBQgEigCADYCACAGIA4pAgA3A7ABIA4gAUEMbGoiBUEIaiIAKAIAIQggDiAFKQIANwIAIBIgCDYCACAAIBQoAgA2AgAgBSAGKQOwATcCACAPIAFrIghFDQQgCCABIAEgCEsbIA9BA3YgBUEMaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAUgDiEADAELIA4gASACIAMgBBAFIAUhAyAIIQELTyEJIAFBFU8NAQwFCwsgDCAFEKgBAAsgBSABEKYBAAtBwKfCACABIA8QpwEAC0G1rcIAQRxB1K3CABC5AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBOIAEgBUcNAAsLIAZBwAJqJAALrhMBB38jAEEQayIDJAAgACAAKAIQIgI2AhwgA0EANgIIIANCADcDACAAQRRqIQUCQCAAQdTGwgAgAxA9IgFFDQADQCAAIAAoAhAiBDYCIAJAIAFBf2oiAUECSw0AAkACQAJAIAFBAWsOAgECAAsgACAAKAIcIARBnMfCAEECEDUMAgsgACAAKAIcIARBnsfCAEECEDUMAQsgBCAFKAIATw0CIAAQkAELIAAgACgCEDYCHCAAQdTGwgAgAxA9IgENAAsLIABBEGohBCAAIAI2AhAgAyAAKAIUIgE2AgggAyABNgIEIAMgATYCAAJAAkACQCAAEDNFDQAgACgCECEBAkAgABA0RQ0AIAAQMw0CA0AgBCgCACAFKAIATw0BIAAQkAEgABAzRQ0ACwwCCyAAIAE2AhAgABAzRQ0AIAAQNA0BA0AgBCgCACAFKAIATw0BIAAQkAEgABA0RQ0ACwwBCyAAIAI2AhAgABA0RQ0BIAAoAhAhAQJAIAAQNEUNACAAEDMNAQNAIAQoAgAgBSgCAE8NASAAEJABIAAQM0UNAAsMAQsgACABNgIQIAAQM0UNASAEKAIAIAUoAgBPDQEgABCQAQsgAyAEKAIANgIICyAAIAI2AhACQCAAEDNFBEADQCAEKAIAIAUoAgAiAU8NAiAAEJABIAAQM0UNAAsLIAAQNCAAKAIQIQFFBEADQCABIAUoAgAiAU8NAiAAEJABIAAQNCAAKAIQIQFFDQALCyADIAE2AgQgABAzRQRAA0AgBCgCACAFKAIAIgFPDQIgABCQASAAEDNFDQALCyAAEDQgACgCECEBRQRAA0AgASAFKAIAIgFPDQIgABCQASAAEDQgACgCECEBRQ0ACwsgAyABNgIAIAUoAgAhAQsgACABNgIgIAAgATYCECAAIAI2AhgCQAJAAkACQAJAAkACQAJAIABBtMfCAEEtIAMQLSICRQ0AIAAgACgCECIBNgIcIAJBf2oiAkEISw0GAkACQAJAAkACQAJAAkACQAJAIAJBAWsOCAECAwQFBgcIAAsgAygCACABSw0IIAAgASAAKAIgQarvwgBBABA1DA4LIAMoAgAgAUsNByAAIAEgACgCIEHsz8IAQQMQNQwNCyADKAIAIAFLDQYgACABIAAoAiBB0PLCAEEBEDUMDAsgAygCACABSw0FIAAgASAAKAIgQe/PwgBBBBA1DAsLIAMoAgQgAUsNBCAAIAEgACgCIEGq78IAQQAQNSAAIAAoAhAiATYCICAAKAIUIAFrIQEgAEH0z8IAQQQgAxAtIgZFDQUgACAAKAIQIgI2AhwgAygCACACSwRAIAQgBSgCACABazYCAAwLCyAAIAIgACgCIEGq78IAQQAQNSAGQQFHDQogACAAKAIQNgIgIABB1NDCAEECEEVFDQYgACAAKAIQIgI2AhwgAygCACACTQRAIAAgAiAAKAIgQarvwgBBABA1DAsLIAQgBSgCACABazYCAAwKCyADKAIAIAFLDQMgACABIAAoAiBBqu/CAEEAEDUgACAAKAIQIgE2AiAgACgCFCABayEBIABB2NDCAEEDIAMQLSIGRQ0GIAAgACgCECICNgIcIAZBAUcNCSADKAIAIAJNBEAgACACIAAoAiBBqu/CAEEAEDUMCgsgBCAFKAIAIAFrNgIADAkLIAMoAgAgAUsNAiAAIAEgACgCIEGq78IAQQAQNSAAIAAoAhAiATYCICAAKAIUIAFrIQEgAEGg0cIAQQMgAxAtIgZFDQYgACAAKAIQIgI2AhwgBkEBRw0IIAMoAgAgAk0EQCAAIAIgACgCIEGq78IAQQAQNQwJCyAEIAUoAgAgAWs2AgAMCAsgAygCACABSw0BIAAgASAAKAIgQarvwgBBABA1IAAgACgCECIBNgIgIAAoAhQgAWshASAAQdTQwgBBAhBFRQ0GIAAgACgCECICNgIcIAMoAgAgAk0EQCAAIAIgACgCIEGq78IAQQAQNQwICyAEIAUoAgAgAWs2AgAMBwsgAygCCCABSw0AIABBzfLCAEEBEEVFDQAgACAAKAIcIAAoAiBB6NHCAEECEDUMBgsgBCAFKAIAIgE2AgAgASADKAIIIgJPBEAgACABNgIgIAAgATYCECAAKAIYIQEgACACNgIYIABBnNPCAEH4ACADEC0iAgRAIAAgACgCECIGNgIcIAJBAUYEQCAAIAYgACgCIEGq78IAQQAQNQsgACABNgIYDAcLIAAgATYCGCAAKAIUIQELIAAgATYCICAAIAE2AhAgAEHc6cIAQQcgAxAtIgJFDQYgACAAKAIQIgE2AhwgAkEBRw0GIAMoAgggAUsNBiAAIAEgACgCIEGq78IAQQAQNQwGCyAEIAUoAgAgAWs2AgAMBAsgBCAFKAIAIAFrNgIADAMLIAQgBSgCACABazYCAAwCCyAEIAUoAgAgAWs2AgAMAQsgBCAFKAIAIAFrNgIACyAAIAAoAhQiATYCICAAIAE2AhACQCAAQc7ywgBBARBFRQ0AIAAgACgCECIBNgIcIAAoAhQhAiAAQc3SwgBBARBFRQ0AIAQgBSgCACABIAJraiIBNgIAIAMoAgggAUsNACAAIAAoAhwgACgCIEGq78IAQQAQNQsgBCAFKAIANgIACyAAIAAoAhQiATYCICAAIAE2AhACQCAAQezRwgBBBCADEC0iAkUNACAAIAAoAhAiATYCHCACQX9qIgJBAUsNAAJ/AkAgAkEBawRAIAMoAgggAUsNAyAAIAEgACgCIEGq78IAQQAQNSAAIAAoAhAiATYCICAAKAIUIQIgAEHQ8sIAQQEQRUUNASAAIAAoAhAiBjYCHCAAKAIUIQcgAEHM0sIAQQEQRUUNASAGIAdrDAILIAAgASAAKAIgQc3SwgBBARA1DAILIAAgACgCFCABIAJrajYCECAAQc7ywgBBARBFRQ0BIAAgACgCECIBNgIcIAAoAhQhAiAAQc3SwgBBARBFRQ0BIAEgAmsLIQEgBCABIAUoAgBqIgE2AgAgAygCCCABSw0AIAAgACgCHCAAKAIgQarvwgBBABA1CyAAIAAoAhgiBjYCHCAAIAY2AhACQCAAQdDSwgAgAxA9IgFFDQADQCAAIAAoAhAiAjYCIAJAIAFBf2oiAUECSw0AAkACQAJAIAFBAWsOAgECAAsgACAAKAIcIAJBmNPCAEECEDUMAgsgACAAKAIcIAJBmtPCAEECEDUMAQsgAiAFKAIATw0CIAAQkAELIAAgACgCEDYCHCAAQdDSwgAgAxA9IgENAAsLIAQgBjYCACADQRBqJABBAQvkEAIUfwF+IwBBsAJrIgYkAAJAAkAgAUEVSQ0AQQEhD0EBIRMCQAJAAkADQCABIQ4gACEMIA8gE3FBAXMhCQJAAkACQAJAAkACQANAAn8CQCAEBEAgD0EBcUUEQCAAIAEQbyAEQX9qIQQLIAYgAjYCDCAGIAA2AjAgBiABNgI0IAYgAUECdiIINgIQIAYgCEEBdCIFNgIUIAYgCEEDbCIHNgIYIAZBADYCHCAGIAZBHGo2ArgBIAYgBkEwajYCtAEgBiAGQQxqNgKwASAGIAZBsAFqNgIgIAFBMk8EQCAGIAhBf2o2AiQgBiAIQQFqNgIoIAZBIGogBkEkaiAGQRBqIAZBKGoQZCAGIAVBf2o2AiQgBiAFQQFyNgIoIAZBIGogBkEkaiAGQRRqIAZBKGoQZCAGIAdBf2o2AiQgBiAHQQFqNgIoIAZBIGogBkEkaiAGQRhqIAZBKGoQZAsgBkEgaiAGQRBqIAZBFGogBkEYahBkIAYoAhwiCEELSw0BIAhFIQcgBigCFAwCCyAAIAEQigEMDgsgBigCNCIHQQF2IggEQCAGKAIwIgUgB0EDdGpBeGohBwNAIAUpAgAhGSAFIAcpAgA3AgAgByAZNwIAIAVBCGohBSAHQXhqIQcgCEF/aiIIDQALC0EBIQcgASAGKAIUQX9zagshBSAHRSAJckEBcUUEQCAAIAEQOA0NCyADRQ0CIAUgAU8NASAAIAVBA3RqIggoAgQoAgAgAygCBCgCAEkEQCABIQ4gACEMDAQLIAApAgAhGSAAIAgpAgA3AgAgCCAZNwIAIABBfGohCyAAQQxqIREgAEEIaiESIAAoAgQhDSAAKAIAIRNBACEHIAEhCANAAkAgByAIQX9qIgpPDQAgESAHQQN0aiEFIA0oAgAhEANAIAUoAgAoAgAgEEkNASAFQQhqIQUgCiAHQQFqIgdHDQALIAohBwsgCyAIQQN0aiEFAkADQCAHIAhBf2oiCE8NASAFKAIAIAVBeGoiECEFKAIAIA0oAgBJDQALIBIgB0EDdGoiBSkCACEZIAUgEEEEaiIFKQIANwIAIAUgGTcCACAHQQFqIQcMAQsLIAAgDTYCBCAAIBM2AgAgASAHQQFqIghPBEAgACAIQQN0aiEAIAEgCGsiAUEVTw0BDAwLCyAIIAEQqAEAC0HQp8IAIAUgARCnAQALIA5FDQELIAUgDk8NASAMKQIAIRkgDCAMIAVBA3RqIgApAgA3AgAgACAZNwIAIAwoAgAhACAGIAwoAgQiEjYCLCAGIAA2AiggDkF/aiIBRQRAQQAhDQwDCyAMQQxqIQUgEigCACEAQQAhDQNAIAAgBSgCACgCAE8NAyAFQQhqIQUgASANQQFqIg1HDQALIAEhDQwCC0Gwp8IAQQBBABCnAQALQcCnwgAgBSAOEKcBAAsgDEEIaiEKIAwgDkEDdGohByABIQADQAJAIAchCCAAIgUgDU0iEw0AIAhBeGohByAFQX9qIQAgEigCACAIQXxqKAIAKAIATw0BCwsgBSANTwRAIAEgBUkNAkGAASERQQAhAEEAIQFBACEHQQAhC0GAASEPIAogDUEDdGoiFSEKA0ACQCAIIAprIgVBhxBLIhYNACAFQQN2IgVBgH9qIAUgCyAHSSIJIAEgAElyIhAbIQUgEARAIA8gBSAJGyEPIAUgESAJGyERDAELIAUgBUEBdiIPayERCwJAIAcgC0cNACAPRQRAIAZBMGoiByELDAELQQAhBSAKIQkgBkEwaiILIQcDQCAHIAU6AAAgByASKAIAIAlBBGooAgAoAgBPaiEHIAlBCGohCSAPIAVBAWoiBUcNAAsLAkAgACABRw0AIBFFBEAgBkGwAWoiACEBDAELIAhBfGohBUEAIQkgBkGwAWoiASEAA0AgACAJOgAAIAAgBigCLCISKAIAIAUoAgAoAgBJaiEAIAVBeGohBSARIAlBAWoiCUcNAAsLIAggEUEDdGsgCCAAIAFrIgUgByALayIJIAkgBUsbIgkEQCAKIAstAABBA3RqIgUoAgQhFyAFKAIAIRggBSAIIAEtAABBf3NBA3RqKQIANwIAIAlBAUcEQEEAIQUDQCAIIAEgBWoiEC0AAEF/c0EDdGogCiAFIAtqQQFqIhQtAABBA3RqKQIANwIAIAogFC0AAEEDdGogCCAQQQFqLQAAQX9zQQN0aikCADcCACAFQQJqIAVBAWoiECEFIAlJDQALIAsgEGohCyABIBBqIQELIAggAS0AAEF/c0EDdGoiBSAXNgIEIAUgGDYCACALQQFqIQsgAUEBaiEBCyAAIAFGGyEIIAogD0EDdGogCiAHIAtGGyEKIBYNAAsCQCALIAdJBEAgCCEFA0AgCiAHQX9qIgctAABBA3RqIgApAgAhGSAAIAVBeGoiBSkCADcCACAFIBk3AgAgCyAHSQ0ACwwBCyAKIQUgASAATw0AA0AgBSkCACEZIAUgCCAAQX9qIgAtAABBf3NBA3RqIgcpAgA3AgAgByAZNwIAIAVBCGohBSABIABJDQALCyAMIAYpAygiGTcCACAOIAUgFWtBA3YgDWoiAU0NAyAMIAwgAUEDdGoiCCkCADcCACAIIBk3AgAgDiABayIFRQ0EIAUgASABIAVLGyAOQQN2IAhBCGohAAJAIAEgBUF/aiIFTwRAIAAgBSACIAggBBAHIAwhAAwBCyAMIAEgAiADIAQQByAIIQMgBSEBC08hDyABQRVPDQEMBQsLIA0gBRCoAQALIAUgARCmAQALQcCnwgAgASAOEKcBAAtBta3CAEEcQdStwgAQuQEACyABQQJJDQBBASEFA0AgACAFQQFqIgUQaiABIAVHDQALCyAGQbACaiQAC+4PARN/IwBBoAJrIgYkAAJAAkAgAUEVSQ0AQQEhD0EBIRMCQAJAAkADQCABIQwgACENIA8gE3FBAXMhCwJAAkACQAJAAkACQANAAn8CQCAEBEAgD0EBcUUEQCAAIAEQcCAEQX9qIQQLIAYgAjYCACAGIAA2AiAgBiABNgIkIAYgAUECdiIFNgIEIAYgBUEBdCIINgIIIAYgBUEDbCIHNgIMIAZBADYCECAGIAZBEGo2AqgBIAYgBkEgajYCpAEgBiAGNgKgASAGIAZBoAFqNgIUIAFBMk8EQCAGIAVBf2o2AhggBiAFQQFqNgIcIAZBFGogBkEYaiAGQQRqIAZBHGoQxAEgBiAIQX9qNgIYIAYgCEEBcjYCHCAGQRRqIAZBGGogBkEIaiAGQRxqEMQBIAYgB0F/ajYCGCAGIAdBAWo2AhwgBkEUaiAGQRhqIAZBDGogBkEcahDEAQsgBkEUaiAGQQRqIAZBCGogBkEMahDEASAGKAIQIgVBC0sNASAFRSEHIAYoAggMAgsgACABEIkBDA4LIAYoAiQiB0EBdiIIBEAgBigCICIFIAdBAnRqQXxqIQcDQCAFKAIAIQkgBSAHKAIANgIAIAcgCTYCACAFQQRqIQUgB0F8aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCCEF/c2oLIQUgB0UgC3JBAXFFBEAgACABED4NDQsgA0UNAiAFIAFPDQEgAygCACAAIAVBAnRqIggoAgAiBxCyAQRAIAEhDCAAIQ0MBAsgACgCACEFIAAgBzYCACAIIAU2AgAgAEF8aiEQIABBBGohCiAAKAIAIQ5BACEHIAEhCANAAkAgByAIQX9qIglPDQAgCiAHQQJ0aiEFA0AgDiAFKAIAELIBDQEgBUEEaiEFIAkgB0EBaiIHRw0ACyAJIQcLIBAgCEECdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACEJIAVBfGoiESEFIA4gCRCyAQ0ACyAKIAdBAnRqIgUoAgAhEyAFIAk2AgAgEUEEaiATNgIAIAdBAWohBwwBCwsgACAONgIAIAEgB0EBaiIFTwRAIAAgBUECdGohACABIAVrIgFBFU8NAQwMCwsgBSABEKgBAAtB0KfCACAFIAEQpwEACyAMRQ0BCyAFIAxPDQEgDSgCACEAIA0gDSAFQQJ0aiIBKAIANgIAIAEgADYCACANQQRqIQkgDSgCACEQQQAhDiAMQX9qIgFFDQIgCSEFA0AgBSgCACAQELIBRQ0DIAVBBGohBSABIA5BAWoiDkcNAAsgASEODAILQbCnwgBBAEEAEKcBAAtBwKfCACAFIAwQpwEACyANIAxBAnRqIQcgASEIA0ACQCAHIQAgCCIFIA5NIhMNACAFQX9qIQggAEF8aiIHKAIAIBAQsgFFDQELCyAFIA5PBEAgASAFSQ0CQYABIQ9BACEIQQAhAUEAIQdBACELQYABIREgCSAOQQJ0aiIVIQkDQAJAIAAgCWsiBUGDCEsiFg0AIAVBAnYiBUGAf2ogBSALIAdJIgogASAISXIiEhshBSASBEAgESAFIAobIREgBSAPIAobIQ8MAQsgBSAFQQF2IhFrIQ8LAkAgByALRw0AIBFFBEAgBkEgaiIHIQsMAQtBACEFIAkhCiAGQSBqIgshBwNAIAcgBToAACAHIAooAgAgEBCyAUEBc2ohByAKQQRqIQogESAFQQFqIgVHDQALCwJAIAEgCEcNACAPRQRAIAZBoAFqIgghAQwBCyAAQXxqIQVBACEKIAZBoAFqIgEhCANAIAggCjoAACAFKAIAIBAQsgEgCGohCCAFQXxqIQUgDyAKQQFqIgpHDQALCyAAIA9BAnRrIAAgCCABayIFIAcgC2siCiAKIAVLGyIKBEAgCSALLQAAQQJ0aiIFKAIAIRcgBSAAIAEtAABBf3NBAnRqKAIANgIAIApBAUcEQEEAIQUDQCAAIAEgBWoiEi0AAEF/c0ECdGogCSAFIAtqQQFqIhQtAABBAnRqKAIANgIAIAkgFC0AAEECdGogACASQQFqLQAAQX9zQQJ0aigCADYCACAFQQJqIAVBAWoiEiEFIApJDQALIAsgEmohCyABIBJqIQELIAAgAS0AAEF/c0ECdGogFzYCACALQQFqIQsgAUEBaiEBCyABIAhGGyEAIAkgEUECdGogCSAHIAtGGyEJIBYNAAsCQCALIAdJBEAgACEFA0AgCSAHQX9qIgctAABBAnRqIgAoAgAhASAAIAVBfGoiBSgCADYCACAFIAE2AgAgCyAHSQ0ACwwBCyAJIQUgASAITw0AA0AgBSgCACEHIAUgACAIQX9qIggtAABBf3NBAnRqIgkoAgA2AgAgCSAHNgIAIAVBBGohBSABIAhJDQALCyANIBA2AgAgDCAFIBVrQQJ2IA5qIgFNDQMgDSANIAFBAnRqIgUoAgA2AgAgBSAQNgIAIAwgAWsiCEUNBCAIIAEgASAISxsgDEEDdiAFQQRqIQACQCABIAhBf2oiCE8EQCAAIAggAiAFIAQQCCANIQAMAQsgDSABIAIgAyAEEAggBSEDIAghAQtPIQ8gAUEVTw0BDAULCyAOIAUQqAEACyAFIAEQpgEAC0HAp8IAIAEgDBCnAQALQbWtwgBBHEHUrcIAELkBAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEH0gASAFRw0ACwsgBkGgAmokAAvXDwETfyMAQaACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEMIAAhDSAPIBNxQQFzIQsCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEHAgBEF/aiEECyAGIAI2AgAgBiAANgIgIAYgATYCJCAGIAFBAnYiBTYCBCAGIAVBAXQiCDYCCCAGIAVBA2wiBzYCDCAGQQA2AhAgBiAGQRBqNgKoASAGIAZBIGo2AqQBIAYgBjYCoAEgBiAGQaABajYCFCABQTJPBEAgBiAFQX9qNgIYIAYgBUEBajYCHCAGQRRqIAZBGGogBkEEaiAGQRxqEGcgBiAIQX9qNgIYIAYgCEEBcjYCHCAGQRRqIAZBGGogBkEIaiAGQRxqEGcgBiAHQX9qNgIYIAYgB0EBajYCHCAGQRRqIAZBGGogBkEMaiAGQRxqEGcLIAZBFGogBkEEaiAGQQhqIAZBDGoQZyAGKAIQIgVBC0sNASAFRSEHIAYoAggMAgsgACABEIgBDA4LIAYoAiQiB0EBdiIIBEAgBigCICIFIAdBAnRqQXxqIQcDQCAFKAIAIQkgBSAHKAIANgIAIAcgCTYCACAFQQRqIQUgB0F8aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCCEF/c2oLIQUgB0UgC3JBAXFFBEAgACABEDwNDQsgA0UNAiAFIAFPDQEgAygCACAAIAVBAnRqIggoAgAiB0kEQCABIQwgACENDAQLIAAoAgAhBSAAIAc2AgAgCCAFNgIAIABBfGohECAAQQRqIQogACgCACEOQQAhByABIQgDQAJAIAcgCEF/aiIJTw0AIAogB0ECdGohBQNAIA4gBSgCAEkNASAFQQRqIQUgCSAHQQFqIgdHDQALIAkhBwsgECAIQQJ0aiEFAkADQCAHIAhBf2oiCE8NASAFKAIAIQkgBUF8aiIRIQUgDiAJSQ0ACyAKIAdBAnRqIgUoAgAhEyAFIAk2AgAgEUEEaiATNgIAIAdBAWohBwwBCwsgACAONgIAIAEgB0EBaiIFTwRAIAAgBUECdGohACABIAVrIgFBFU8NAQwMCwsgBSABEKgBAAtB0KfCACAFIAEQpwEACyAMRQ0BCyAFIAxPDQEgDSgCACEAIA0gDSAFQQJ0aiIBKAIANgIAIAEgADYCACANQQRqIQkgDSgCACEQQQAhDiAMQX9qIgFFDQIgCSEFA0AgBSgCACAQTw0DIAVBBGohBSABIA5BAWoiDkcNAAsgASEODAILQbCnwgBBAEEAEKcBAAtBwKfCACAFIAwQpwEACyANIAxBAnRqIQcgASEIA0ACQCAHIQAgCCIFIA5NIhMNACAFQX9qIQggAEF8aiIHKAIAIBBPDQELCyAFIA5PBEAgASAFSQ0CQYABIQ9BACEIQQAhAUEAIQdBACELQYABIREgCSAOQQJ0aiIVIQkDQAJAIAAgCWsiBUGDCEsiFg0AIAVBAnYiBUGAf2ogBSALIAdJIgogASAISXIiEhshBSASBEAgESAFIAobIREgBSAPIAobIQ8MAQsgBSAFQQF2IhFrIQ8LAkAgByALRw0AIBFFBEAgBkEgaiIHIQsMAQtBACEFIAkhCiAGQSBqIgshBwNAIAcgBToAACAHIAooAgAgEE9qIQcgCkEEaiEKIBEgBUEBaiIFRw0ACwsCQCABIAhHDQAgD0UEQCAGQaABaiIIIQEMAQsgAEF8aiEFQQAhCiAGQaABaiIBIQgDQCAIIAo6AAAgCCAFKAIAIBBJaiEIIAVBfGohBSAPIApBAWoiCkcNAAsLIAAgD0ECdGsgACAIIAFrIgUgByALayIKIAogBUsbIgoEQCAJIAstAABBAnRqIgUoAgAhFyAFIAAgAS0AAEF/c0ECdGooAgA2AgAgCkEBRwRAQQAhBQNAIAAgASAFaiISLQAAQX9zQQJ0aiAJIAUgC2pBAWoiFC0AAEECdGooAgA2AgAgCSAULQAAQQJ0aiAAIBJBAWotAABBf3NBAnRqKAIANgIAIAVBAmogBUEBaiISIQUgCkkNAAsgCyASaiELIAEgEmohAQsgACABLQAAQX9zQQJ0aiAXNgIAIAtBAWohCyABQQFqIQELIAEgCEYbIQAgCSARQQJ0aiAJIAcgC0YbIQkgFg0ACwJAIAsgB0kEQCAAIQUDQCAJIAdBf2oiBy0AAEECdGoiACgCACEBIAAgBUF8aiIFKAIANgIAIAUgATYCACALIAdJDQALDAELIAkhBSABIAhPDQADQCAFKAIAIQcgBSAAIAhBf2oiCC0AAEF/c0ECdGoiCSgCADYCACAJIAc2AgAgBUEEaiEFIAEgCEkNAAsLIA0gEDYCACAMIAUgFWtBAnYgDmoiAU0NAyANIA0gAUECdGoiBSgCADYCACAFIBA2AgAgDCABayIIRQ0EIAggASABIAhLGyAMQQN2IAVBBGohAAJAIAEgCEF/aiIITwRAIAAgCCACIAUgBBAJIA0hAAwBCyANIAEgAiADIAQQCSAFIQMgCCEBC08hDyABQRVPDQEMBQsLIA4gBRCoAQALIAUgARCmAQALQcCnwgAgASAMEKcBAAtBta3CAEEcQdStwgAQuQEACyABQQJJDQBBASEFA0AgACAFQQFqIgUQciABIAVHDQALCyAGQaACaiQAC7cOARp/IwBBgAFrIgckACAAKAIEIQQgASgCCCEJIAEoAgAhDQJAAkACQCAAKAIALQAABEAgByAENgIMIAlBFUkNASAHIAlBAXYQnQEgBygCBCAHKAIAIRAgB0EANgIYIAdCBDcDECANQZx/aiEVQQQhCkEAIQQgCSEGA0ACQCAGIgNBAUYEQEEAIQZBASEADAELIANBfmohAiANIANB5ABsIhFqIQVBWCEBAkACQAJAAkACQAJAAkADQCABQQRqIgBFDQEgASAFaiEIIAAhASAIQaB/aigCACIGIAhBBGooAgAiAEYNAAsgBiAASA0BCyANIBFqIQUDQCACIgZFBEBBACEGIAMhAAwICyAGQX9qIQJBWCEBA0ACQCABQQRqIgBFBEBBACEADAELIAEgBWohDCAAIQFBfyAMQbx+aigCACIIIAxBoH9qKAIAIgBHIAggAEgbIgBFDQELCyAFQZx/aiEFIABBf0cNAAsgAyAGayEADAELAkAgAgRAA0AgAiIGQX9qIQJBWCEBA0AgAUEEaiIARQ0DIAEgBWohDCAAIQFBfyAMQbx+aigCACIIIAxBoH9qKAIAIgBHIAggAEgbIgBFDQALIABB/wFxQf8BRw0CIAVBnH9qIQUgAg0ACwtBACEGCyADIAZJDQEgAyAJSw0CIAMgBmsiAEEBdiIFRQ0AIA0gBkHkAGxqIQEgESAVaiECA0AgB0EcaiABQeQAELoBGiABIAJB5AAQmQFB5ABqIQEgAiAHQRxqQeQAELoBQZx/aiECIAVBf2oiBQ0ACwsgBkUgAEEJS3INBCADIAZrIQAgBkF/aiEBIBUgBkHkAGxqIQIDQCADIAFJDQMgAyAJSw0CIAIgAEEBaiIAEE0gAUF/aiIEIAFPDQQgAkGcf2ohAiAEIQEgAEEKSQ0ACwwDCyAGIAMQqAEACyADIAkQpgEACyABIAMQqAEACyAEQQFqIQYgCyEECyAHKAIUIARGBH8gB0EQaiAEEIsBIAcoAhAhCiAHKAIYIgsFIAQLQQN0IApqIgEgADYCBCABIAY2AgAgByALQQFqIgs2AhgCQCALIgRBAkkNAAJAA0ACQAJAAkACQCAKIAsiA0F/aiILQQN0aiIBKAIABEAgA0EDdCAKaiIFQXRqKAIAIgggASgCBCIASw0BCyADQQNJDQIgASgCBCEAIAogA0F9aiIBQQN0aigCBCECDAELQQIhBCADQQJNDQQgCiADQX1qIgFBA3RqKAIEIgIgACAIak0NAEEDIQQgA0EDTQ0EIAVBZGooAgAgAiAIak0NACADIQsgAyEEDAULIAIgAEkNAQsgA0F+aiEBCwJAAkACQAJAAkACQAJAIAMgAUEBaiITSwRAIAMgAU0NASAKIAFBA3QiGWoiACgCBCIaIAAoAgBqIgEgCiATQQN0IhtqIgAoAgAiEkkNAiABIAlLDQMgDSASQeQAbGoiCCAAKAIEIg9B5ABsIgVqIQQgDSABQeQAbGohFCABIBJrIgAgD2siASAPTw0EIBAgBCABQeQAbCIAELoBIgwgAGohDiAPQQFIIAFBAUhyDQUgBCEFA0AgDkFcaiERIAVBnH9qIRYgDkGcf2ohF0EAIQEDQAJAIAFBBGoiAEEoRgRAQQAhAAwBCyABIBFqIQIgASAFaiEEIAAhAUF/IARBXGooAgAiBCACKAIAIgBHIAQgAEgbIgBFDQELCyAUQZx/aiIUIBYgFyAAQX9GIgAbQeQAELoBGiAOIBcgABshDiAIIBYgBSAAGyIFTw0HIA4gDCICSw0ACwwHC0Hoq8IAIBMgAxCnAQALQeirwgAgASADEKcBAAsgEiABEKgBAAsgASAJEKYBAAsgECAIIAUQugEiAiAFaiEOIA9BAUggACAPTHJFBEAgCCEFA0BBwAAhAQNAAkAgAUEEaiIAQegARgRAQQAhAAwBCyABIARqIQwgASACaiEIIAAhAUF/IAgoAgAiCCAMKAIAIgBHIAggAEgbIgBFDQELCyAEIAIgAEF/RiIBGyEAIAIgAkHkAGogARshAiAFIABB5AAQugFB5ABqIQUgBEHkAGogBCABGyIEIBRPDQQgDiACSw0ACwwDCyAIIQUMAQsgBCEFCyAQIQILIAUgAiAOIAJrIgAgAEHkAHBrELoBGiAKIBlqIgAgDyAaajYCBCAAIBI2AgAgCiAbaiIAIABBCGogAyATQX9zakEDdBCZARogByALNgIYIAtBAUsNAAsgCyEEDAELIAMhCwsgBg0ACyAHKAIUBEAgChAhC0UNAiAQECEMAgsgByAENgIQIAcgB0EQajYCHCANIAkgB0EcakEAQSAgCWdrEAQMAQtBAiEBIAlBAkkNACANIAlBfmoiAEHkAGxqIQIDQCAJIABJDQIgAiABEE0gAkGcf2ohAiABQQFqIQEgAEF/aiIAQX9HDQALCyAHQYABaiQADwsgACAJEKgBAAu/DAIWfwF+IwBBIGsiByQAIAAoAgQhAyABKAIIIQggASgCACELAkACQAJAIAAoAgAtAAAEQCAHIAM2AgwgCEEVSQ0BIAcgCEEBdhChASAHKAIEIAcoAgAhEEEAIQEgB0EANgIYIAdCBDcDECALQXhqIRMgC0FsaiEVQQQhCUEAIQMgCCEGA0ACQCAGIgVBf2oiBkUEQEEAIQZBASEEDAELAkACQAJAAkACQCAFQQN0Ig0gC2pBdGooAgAoAgAiACALIAZBA3RqKAIEKAIATwRAIAVBfmohFCANIBVqIQRBACEGQQAhAgNAIAIgFEYEQCAFIQQMCAsgAkEBaiECIAQoAgAoAgAiCiAATyAEQXhqIQQgCiEADQALIAJBAWohBCACQX9zIAVqIQYMAQsgDSAVaiECA0ACQCAGQQFGBEBBACEGDAELIAZBf2ohBiACKAIAKAIAIgQgAEkgAkF4aiECIAQhAA0BCwsgBSAGSQ0BIAUgCEsNAiAFIAZrIgRBAXYiCkUNACALIAZBA3RqIQAgDSATaiECA0AgACkCACEYIAAgAikCADcCACACIBg3AgAgAEEIaiEAIAJBeGohAiAKQX9qIgoNAAsLIAZFIARBCUtyDQQgBSAGayEEIAZBf2ohACATIAZBA3RqIQIDQCAFIABJDQMgBSAISw0CIAIgBEEBaiIEEGwgAEF/aiIDIABPDQQgAkF4aiECIAMhACAEQQpJDQALDAMLIAYgBRCoAQALIAUgCBCmAQALIAAgBRCoAQALIANBAWohBiABIQMLIAcoAhQgA0YEfyAHQRBqIAMQiwEgBygCECEJIAcoAhgiAQUgAwtBA3QgCWoiACAENgIEIAAgBjYCACAHIAFBAWoiATYCGCABIQMCQCABQQJJDQACQANAAkACQAJAAkAgCSABQX9qQQN0aiIAKAIARQ0AIAFBA3QgCWoiBEF0aigCACIFIAAoAgQiAk0NAEECIQMgAUECTQ0GIAkgAUF9aiIMQQN0aigCBCIAIAIgBWpNDQFBAyEDIAFBA00NBiAEQWRqKAIAIAAgBWpNDQEgASEDDAYLIAFBA0kNASAAKAIEIQIgCSABQX1qIgxBA3RqKAIEIQALIAAgAkkNAQsgAUF+aiEMCwJAAkAgASAMQQFqIhFLBEAgASAMSwRAIAkgDEEDdCIXaiIAKAIEIhQgACgCAGoiAyAJIBFBA3QiDWoiACgCACISTwRAIAMgCE0EQCALIBJBA3RqIgQgACgCBCIOQQN0IgpqIQEgA0EDdCEPAkACQCADIBJrIgMgDmsiBSAOSQRAIBAgASAFQQN0IgAQugEiCiAAaiECIA5BAUggBUEBSHINASAPIBNqIQADQAJAIAAgAUF4aiIFIAJBeGoiAyABQXxqKAIAKAIAIAJBfGooAgAoAgBJIg8bKQIANwIAIAIgAyAPGyECIAQgBSABIA8bIgFPDQAgAEF4aiEAIAIgCksNAQsLIAcoAhAhCQwBCyAKIBAgBCAKELoBIgBqIQIgAyAOSkEAIA5BAU4bDQEgBCEBCyAQIQAMBgsgCyAPaiEDA0AgBCABIAAgACgCBCgCACABKAIEKAIASSIFGykCADcCACAAIABBCGogBRshACAEQQhqIQQgAUEIaiABIAUbIgEgA08NBSACIABLDQALDAQLIAMgCBCmAQALIBIgAxCoAQALQeirwgAgDCABEKcBAAtB6KvCACARIAEQpwEACyAEIQELIAEgACACIABrQXhxELoBGiAHKAIYIgEgDEsEQCAJIBdqIgAgDiAUajYCBCAAIBI2AgAgASARTQ0CIAkgDWoiACAAQQhqIAEgEUF/c2pBA3QQmQEaIAcgAUF/aiIBNgIYIAEhAyABQQFNDQMMAQsLQfirwgAgDCABEKcBAAtBp67CAEEdQcSuwgAQuQEACyAGDQALIAcoAhQEQCAJECELRQ0CIBAQIQwCCyAHIAM2AgwgByAHQQxqNgIQIAsgCCAHQRBqQQBBICAIZ2sQBwwBC0ECIQEgCEECSQ0AIAsgCEF+aiIAQQN0aiECA0AgCCAASQ0CIAIgARBsIAJBeGohAiABQQFqIQEgAEF/aiIAQX9HDQALCyAHQSBqJAAPCyAAIAgQqAEAC+YMAgx/BH4jAEHgAGsiBiQAAkACQAJAAkAgASgCECIDQQFqIgUgA08EQCABKAIAIgIhAwJAIAUgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAFIANBAWoiAyAFIANLGyIDRQRAQbCmwgAhCkEEIQRBAAwBCyAGQSBqQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiB0EBaiIFEJsBIAYoAiQiBEUNByAGKAIoIQIgBigCICIDIAQQ0gEiCkUNAiAKQf8BIAdBBWoQwAEgAmohBCAHIAVBA3ZBB2wgB0EISRsLIQIgBiABKAIQIgM2AjAgBiACIANrNgIsIAYgBDYCKCAGIAo2AiQgBiAHNgIgIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDSADKAIAQX9zQYCBgoR4cSEIIAEoAgghCQNAAkACQAJAIAgEQCACIQMMAQsDQCACIA1PDQIgCUGwB2ohCSACKAIAIAJBBGoiAyECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyEICyAGIAkgCGhBA3ZB7AFsaiIMKAIAIgQ2AlAgCEF/aiELQqXGiKHInKf5SyEOQQEhAgNAIA4gBK1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIAZB0ABqIAJqIAJBAWohAi0AACEEDAALAAsgASkCACEPIAEgBikDIDcCACAGQShqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBkEwaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDzcDICAAQQA2AgAgD6ciAEUNByAGQdAAaiAAQQFqEJsBIAYoAiQQIQwHCyAKIAZBIGogDhCXASIFaiAOp0EZdiICOgAAIAcgBUF8anEgCmpBBGogAjoAACAGKAIoIAVB7AFsaiAMQewBELoBGiAIIAtxIQggAyECDAALAAsgAkEBaiELQQAhAgNAAkACfyAEQQFxRQRAIAIgC08NAiACQQFqIgMgAkkNAiACIQQgAwwBCyACQQNqIgQgAkkiBQ0BIAQgC0kiA0UNASALIAJBBGogBRsgCyADGwshAiABKAIEIARqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQQMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgAyAEQfT5wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiINIANPBH9BACEDA0AgAyIFQQFqIQMCQCAFIAEoAgRqLQAAQYABRw0AA0AgBiABKAIIIAVB7AFsaiIIKAIAIgQ2AiBCpcaIocicp/lLIQ5BASECA0AgDiAErUL/AYOFQrODgICAIH4hDiACQQRHBEAgBkEgaiACaiACQQFqIQItAAAhBAwBCwsgBSABKAIAIgkgDqciDHEiAmsgASAOEJcBIgcgAmtzIAlxQQRPBEAgASgCBCILIAdqIgItAAAgAiAMQRl2IgI6AAAgCyAJIAdBfGpxakEEaiACOgAAQf8BRgRAIAEoAgQiAiAFakH/AToAACACIAEoAgAgBUF8anFqQQRqQf8BOgAAIAEoAgggB0HsAWxqIAhB7AEQugEaDAMLIAEoAgggB0HsAWxqIQpBACECA0AgAiAKaiIHKQIAIRAgByACIAhqIgkpAgA3AgAgB0EYaiIEKQIAIREgBCAJQRhqIgwpAgA3AgAgB0EQaiIEKQIAIQ8gBCAJQRBqIgspAgA3AgAgB0EIaiIEKQIAIQ4gBCAJQQhqIgQpAgA3AgAgBCAONwIAIAsgDzcCACAMIBE3AgAgCSAQNwIAIAJBQGsgAkEgaiECQe0BSQ0ACyAKKQLgASEOIAogCCkC4AE3AuABIApB6AFqIgIoAgAhBCACIAhB6AFqIgIoAgA2AgAgCCAONwLgASACIAQ2AgAMAQsLIAEoAgQiBCAFaiAMQRl2IgI6AAAgBCAJIAVBfGpxakEEaiACOgAACyADIA1HDQALIAEoAgAiAkEISQ0BIAJBAWoFIA0LQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZB4ABqJAAPC0G0psIAQRxB0KbCABC5AQALjgwBFX8jAEEgayIGJAAgACgCBCEHIAEoAgghCSABKAIAIQoCQAJAAkAgACgCAC0AAARAIAYgBzYCDCAJQRVJDQEgBiAJQQF2EKABIAYoAgQgBigCACEOQQAhASAGQQA2AhggBkIENwMQIApBfGohECAKQXRqIRFBBCEIIAkhBwNAIAchBUEAIQdBASECAkAgBUF/aiIERQ0AAkACQAJAAkACQAJAIAogBEECdGooAgAgBUECdCIHIApqQXhqKAIAIgAQsgFFBEAgBUF+aiEEIAcgEWohAkEAIQdBACEDA0AgAyAERgRAIAUhAgwJCyADQQFqIQMgACACKAIAIgAQsgEgAkF8aiECRQ0ACyADQQFqIQIgA0F/cyAFaiEEDAELIAcgEWohAgNAAkAgBEEBRgRAQQAhBAwBCyAEQX9qIQQgACACKAIAIgAQsgEgAkF8aiECDQELCyAFIARJDQEgBSAJSw0CIAUgBGsiAkEBdiILRQ0AIAogBEECdGohACAHIBBqIQMDQCAAKAIAIQcgACADKAIANgIAIAMgBzYCACAAQQRqIQAgA0F8aiEDIAtBf2oiCw0ACwsgBEUgAkEJS3INBCAFIARrIQIgBEF/aiEAIBAgBEECdGohAwNAIAUgAEkNAyAFIAlLDQIgAyACQQFqIgIQdCAAQX9qIgcgAE8NBCADQXxqIQMgByEAIAJBCkkNAAsMAwsgBCAFEKgBAAsgBSAJEKYBAAsgACAFEKgBAAsgB0EBaiEHDAELIAQhBwsgBigCFCABRgRAIAZBEGogARCLASAGKAIQIQggBigCGCEBCyAIIAFBA3RqIgAgAjYCBCAAIAc2AgAgBiABQQFqIgE2AhgCQCABQQJJDQACQANAAkACQAJAAkAgCCABQX9qQQN0aiIAKAIARQ0AIAFBA3QgCGoiBEF0aigCACIDIAAoAgQiAk0NACABQQJNDQYgCCABQX1qIgVBA3RqKAIEIgAgAiADak0NASABQQNNDQYgBEFkaigCACAAIANqSw0GDAELIAFBA0kNASAAKAIEIQIgCCABQX1qIgVBA3RqKAIEIQALIAAgAkkNAQsgAUF+aiEFCwJAAkAgASAFQQFqIgtLBEAgASAFSwRAIAggBUEDdCITaiIAKAIEIhQgACgCAGoiACAIIAtBA3QiFWoiASgCACIPTwRAIAAgCU0EQCAKIA9BAnRqIgMgASgCBCIEQQJ0IgJqIQEgAEECdCEMAkACQCAAIA9rIg0gBGsiACAESQRAIA4gASAAQQJ0IgIQugEiFiACaiECIARBAUggAEEBSHINASAMIBBqIQADQAJAIAAgAUF8aiIIIAJBfGoiDCAMKAIAIAgoAgAQsgEiDRsoAgA2AgAgAiAMIA0bIQIgAyAIIAEgDRsiAU8NACAAQXxqIQAgAiAWSw0BCwsgBigCECEIDAELIA4gAyACELoBIgAgAmohAiANIARKQQAgBEEBThsNASADIQELIA4hAAwGCyAKIAxqIQ0DQCADIAEgACABKAIAIAAoAgAQsgEiDBsoAgA2AgAgACAAQQRqIAwbIQAgA0EEaiEDIAFBBGogASAMGyIBIA1PDQUgAiAASw0ACwwECyAAIAkQpgEACyAPIAAQqAEAC0Hoq8IAIAUgARCnAQALQeirwgAgCyABEKcBAAsgAyEBCyABIAAgAiAAa0F8cRC6ARogBigCGCIAIAVLBEAgCCATaiIBIAQgFGo2AgQgASAPNgIAIAAgC00NAiAIIBVqIgEgAUEIaiAAIAtBf3NqQQN0EJkBGiAGIABBf2oiATYCGCABQQFNDQMMAQsLQfirwgAgBSAAEKcBAAtBp67CAEEdQcSuwgAQuQEACyAHDQALIAYoAhQEQCAIECELRQ0CIA4QIQwCCyAGIAc2AgwgBiAGQQxqNgIQIAogCSAGQRBqQQBBICAJZ2sQCAwBC0ECIQEgCUECSQ0AIAogCUF+aiIAQQJ0aiECA0AgCSAASQ0CIAIgARB0IAJBfGohAiABQQFqIQEgAEF/aiIAQX9HDQALCyAGQSBqJAAPCyAAIAkQqAEAC88LAgx/An4jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiBCADTwRAIAEoAgAiAiEDAkAgBCACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQAJ/IAQgA0EBaiIDIAQgA0sbIgNFBEBBsKbCACEHQQQhBUEADAELIAZBfwJ/IANBCE8EQCADIANB/////wFxRgRAIANBA3RBB24MAgsMCQsgA0EBagtBf2pndiIIQQFqIgQQowEgBigCBCIFRQ0HIAYoAgghAiAGKAIAIgMgBRDSASIHRQ0CIAdB/wEgCEEFahDAASACaiEFIAggBEEDdkEHbCAIQQhJGwshAiAGIAEoAhAiAzYCECAGIAIgA2s2AgwgBiAFNgIIIAYgBzYCBCAGIAg2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiENIAMoAgBBf3NBgIGChHhxIQsgASgCCCEKA0ACQAJAAkAgCwRAIAIhAwwBCwNAIAIgDU8NAiAKQUBrIQogAigCACACQQRqIgMhAkGAgYKEeHEiBEGAgYKEeEYNAAsgBEGAgYKEeHMhCwtBASECIAYgCiALaEEBdEHwAHFqIgwoAgAiBTYCMCALQX9qIQlCpcaIocicp/lLIQ4DQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQTBqIAJqIAJBAWohAi0AACEFDAALAAsgASkCACEPIAEgBikDADcCACAGQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDzcDACAAQQA2AgAgD6ciAEUNByAGQTBqIABBAWoQowEgBigCBBAhDAcLIAcgBiAOEJcBIgRqIA6nQRl2IgI6AAAgCCAEQXxqcSAHakEEaiACOgAAIAYoAgggBEEEdGoiAiAMKQIANwIAIAJBCGogDEEIaikCADcCACAJIAtxIQsgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAFQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQUgAwwBCyACQQNqIgUgAkkiBA0BIAUgCUkiA0UNASAJIAJBBGogBBsgCSADGwshAiABKAIEIAVqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQUMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgAyAFQfT5wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIEQQFqIQMCQCAEIAEoAgRqLQAAQYABRw0AAkADQCAGIAEoAgggBEEEdGoiBygCACIFNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBmogAkEBaiECLQAAIQUMAQsLIAQgASgCACIKIA6nIg1xIgJrIAEgDhCXASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBBHRqIgIpAgAhDyACIAcpAgA3AgAgAkEIaiICKQIAIQ4gAiAHQQhqIgIpAgA3AgAgByAPNwIAIAIgDjcCAAwBCwsgASgCBCICIARqQf8BOgAAIAIgASgCACAEQXxqcWpBBGpB/wE6AAAgASgCCCAIQQR0aiICIAcpAgA3AgAgAkEIaiAHQQhqKQIANwIADAELIAEoAgQiBSAEaiANQRl2IgI6AAAgBSAKIARBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAELkBAAuqCwILfwN+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgIgA08EQCABKAIAIgMhBAJAIAIgA0EBakEDdkEHbCAEIANBCE8bIgRBAXZLBEACfyACIARBAWoiAyACIANLGyICRQRAQbCmwgAhB0EAIQJBBAwBCyAGQX8CfyACQQhPBEAgAiACQf////8BcUYEQCACQQN0QQduDAILDAkLIAJBAWoLQX9qZ3YiCUEBaiIDEJoBIAYoAgQiAkUNByAGKAIIIQQgBigCACIFIAIQ0gEiB0UNAiAJIANBA3ZBB2wgCUEISRshAiAHQf8BIAlBBWoQwAEgBGoLIQMgBiABKAIQIgQ2AhAgBiACIARrNgIMIAYgAzYCCCAGIAc2AgQgBiAJNgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohCiADKAIAQX9zQYCBgoR4cSEFIAEoAgghAwNAAkAgBUUEQANAIAIgCk8NAiADQeAAaiEDIAIoAgAgAkEEaiIEIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQUgBCECCyAGQqXGiKHInKf5SzcDMCADIAVoQQN2QRhsaiIEKAIAIAQoAgggBkEwahBxIAcgBiAGKQMwIg0QlwEiCGogDadBGXYiCzoAACAJIAhBfGpxIAdqQQRqIAs6AAAgBigCCCAIQRhsaiIIIAQpAgA3AgAgCEEIaiAEKQIINwIAIAhBEGogBEEQaikCADcCACAFQX9qIAVxIQUMAQsLIAEpAgAhDSABIAYpAwA3AgAgBkEIaiICKQMAIQ4gAiABQQhqIgIpAgA3AwAgAiAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA03AwAgAEEANgIAIA2nIgBFDQUgBkEwaiAAQQFqEJoBIAYoAgQQIQwFCyADQQFqIQRBACEDQQAhAgNAAkACfyADQQFxRQRAIAIgBE8NAiACQQFqIgUgAkkNAiACIQMgBQwBCyACQQNqIgMgAkkiBQ0BIAMgBEkiB0UNASAEIAJBBGogBRsgBCAHGwshAiABKAIEIANqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQMMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgBSACQfT5wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiAkEBaiIJIAJPBH9BACEDA0AgAyICQQFqIQMCQCACIAEoAgRqLQAAQYABRw0AAkADQCABKAIIIAZCpcaIocicp/lLNwMAIAJBGGxqIgQoAgAgBCgCCCAGEHEgAiABKAIAIgcgBikDACINpyIIcSIKayABIA0QlwEiBSAKa3MgB3FBBEkNASABKAIEIgogBWoiCy0AACALIAhBGXYiCDoAACAKIAcgBUF8anFqQQRqIAg6AABB/wFHBEAgASgCCCAFQRhsaiIFKQIAIQ0gBSAEKQIANwIAIAVBEGoiBykCACEOIAcgBEEQaiIHKQIANwIAIAVBCGoiBSkCACEPIAUgBEEIaiIFKQIANwIAIAQgDTcCACAHIA43AgAgBSAPNwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIAVBGGxqIgIgBCkCADcCACACQQhqIARBCGopAgA3AgAgAkEQaiAEQRBqKQIANwIADAELIAEoAgQiBCACaiAIQRl2IgU6AAAgBCAHIAJBfGpxakEEaiAFOgAACyADIAlHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAkLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAELkBAAudCwILfwN+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgIgA08EQCABKAIAIgMhBQJAIAIgA0EBakEDdkEHbCAFIANBCE8bIgVBAXZLBEACfyACIAVBAWoiAyACIANLGyICRQRAQbCmwgAhB0EAIQJBBAwBCyAGQX8CfyACQQhPBEAgAiACQf////8BcUYEQCACQQN0QQduDAILDAkLIAJBAWoLQX9qZ3YiCUEBaiIDEJoBIAYoAgQiAkUNByAGKAIIIQUgBigCACIEIAIQ0gEiB0UNAiAJIANBA3ZBB2wgCUEISRshAiAHQf8BIAlBBWoQwAEgBWoLIQMgBiABKAIQIgU2AhAgBiACIAVrNgIMIAYgAzYCCCAGIAc2AgQgBiAJNgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohCiADKAIAQX9zQYCBgoR4cSEEIAEoAgghAwNAAkAgBEUEQANAIAIgCk8NAiADQeAAaiEDIAIoAgAgAkEEaiIFIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQQgBSECCyAGQqXGiKHInKf5SzcDMCADIARoQQN2QRhsaiIFIAZBMGoQdiAHIAYgBikDMCINEJcBIghqIA2nQRl2Igs6AAAgCSAIQXxqcSAHakEEaiALOgAAIAYoAgggCEEYbGoiCCAFKQIANwIAIAhBCGogBUEIaikCADcCACAIQRBqIAVBEGopAgA3AgAgBEF/aiAEcSEEDAELCyABKQIAIQ0gASAGKQMANwIAIAZBCGoiAikDACEOIAIgAUEIaiICKQIANwMAIAIgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiANNwMAIABBADYCACANpyIARQ0FIAZBMGogAEEBahCaASAGKAIEECEMBQsgA0EBaiEFQQAhA0EAIQIDQAJAAn8gA0EBcUUEQCACIAVPDQIgAkEBaiIEIAJJDQIgAiEDIAQMAQsgAkEDaiIDIAJJIgQNASADIAVJIgdFDQEgBSACQQRqIAQbIAUgBxsLIQIgASgCBCADaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEDDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQmQEaDAMLIAQgAkH0+cIAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgJBAWoiCSACTwR/QQAhAwNAIAMiAkEBaiEDAkAgAiABKAIEai0AAEGAAUcNAAJAA0AgASgCCCAGQqXGiKHInKf5SzcDACACQRhsaiIFIAYQdiACIAEoAgAiByAGKQMAIg2nIghxIgprIAEgDRCXASIEIAprcyAHcUEESQ0BIAEoAgQiCiAEaiILLQAAIAsgCEEZdiIIOgAAIAogByAEQXxqcWpBBGogCDoAAEH/AUcEQCABKAIIIARBGGxqIgQpAgAhDSAEIAUpAgA3AgAgBEEQaiIHKQIAIQ4gByAFQRBqIgcpAgA3AgAgBEEIaiIEKQIAIQ8gBCAFQQhqIgQpAgA3AgAgBSANNwIAIAcgDjcCACAEIA83AgAMAQsLIAEoAgQiByACakH/AToAACAHIAEoAgAgAkF8anFqQQRqQf8BOgAAIAEoAgggBEEYbGoiAiAFKQIANwIAIAJBCGogBUEIaikCADcCACACQRBqIAVBEGopAgA3AgAMAQsgASgCBCIFIAJqIAhBGXYiBDoAACAFIAcgAkF8anFqQQRqIAQ6AAALIAMgCUcNAAsgASgCACICQQhJDQEgAkEBagUgCQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQuQEAC48LAgx/An4jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiBCADTwRAIAEoAgAiAiEDAkAgBCACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQAJ/IAQgA0EBaiIDIAQgA0sbIgNFBEBBsKbCACEHQQQhBUEADAELIAZBfwJ/IANBCE8EQCADIANB/////wFxRgRAIANBA3RBB24MAgsMCQsgA0EBagtBf2pndiIIQQFqIgQQogEgBigCBCIFRQ0HIAYoAgghAiAGKAIAIgMgBRDSASIHRQ0CIAdB/wEgCEEFahDAASACaiEFIAggBEEDdkEHbCAIQQhJGwshAiAGIAEoAhAiAzYCECAGIAIgA2s2AgwgBiAFNgIIIAYgBzYCBCAGIAg2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEMIAMoAgBBf3NBgIGChHhxIQsgASgCCCEKA0ACQAJAAkAgCwRAIAIhAwwBCwNAIAIgDE8NAiAKQSBqIQogAigCACACQQRqIgMhAkGAgYKEeHEiBEGAgYKEeEYNAAsgBEGAgYKEeHMhCwsgBiAKIAtoQThxaiINKAIAIgU2AjAgC0F/aiEJQqXGiKHInKf5SyEOQQEhAgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIAZBMGogAmogAkEBaiECLQAAIQUMAAsACyABKQIAIQ8gASAGKQMANwIAIAZBCGoiAykDACEOIAMgAUEIaiIDKQIANwMAIAMgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiAPNwMAIABBADYCACAPpyIARQ0HIAZBMGogAEEBahCiASAGKAIEECEMBwsgByAGIA4QlwEiBGogDqdBGXYiAjoAACAIIARBfGpxIAdqQQRqIAI6AAAgBigCCCAEQQN0aiANKQIANwIAIAkgC3EhCyADIQIMAAsACyACQQFqIQlBACECA0ACQAJ/IAVBAXFFBEAgAiAJTw0CIAJBAWoiAyACSQ0CIAIhBSADDAELIAJBA2oiBSACSSIEDQEgBSAJSSIDRQ0BIAkgAkEEaiAEGyAJIAMbCyECIAEoAgQgBWoiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhBQwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJkBGgwDCyADIAVB9PnCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIgwgA08Ef0EAIQMDQCADIgRBAWohAwJAIAQgASgCBGotAABBgAFHDQACQANAIAYgASgCCCAEQQN0aiIHKAIAIgU2AgBCpcaIocicp/lLIQ5BASECA0AgDiAFrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAGaiACQQFqIQItAAAhBQwBCwsgBCABKAIAIgogDqciDXEiAmsgASAOEJcBIgggAmtzIApxQQRJDQEgASgCBCIJIAhqIgItAAAgAiANQRl2IgI6AAAgCSAKIAhBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCEEDdGoiAikCACEOIAIgBykCADcCACAHIA5CIIg8AAQgByAOPgIADAELCyABKAIEIgIgBGpB/wE6AAAgAiABKAIAIARBfGpxakEEakH/AToAACABKAIIIAhBA3RqIAcpAgA3AgAMAQsgASgCBCIFIARqIA1BGXYiAjoAACAFIAogBEF8anFqQQRqIAI6AAALIAMgDEcNAAsgASgCACICQQhJDQEgAkEBagUgDAtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQuQEAC6MLAgx/BH4jAEFAaiIEJAACQAJAAkAgASgCECIDQQFqIgUgA08EQCABKAIAIgIhAyAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAIAQgBSADQQFqIgMgBSADSxsQUyAEQQhqKAIAIQcgBCgCBCEKIAQoAgBBAUYEQCAAIAo2AgQgAEEBNgIAIABBCGogBzYCAAwFCyAEQRBqKAIAIQUgBEEMaigCACECIAQgASgCECIDNgIQIAQgBSADazYCDCAEIAI2AgggBCAHNgIEIAQgCjYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQ0gAygCAEF/c0GAgYKEeHEhDCABKAIIIQsDQAJAAkACQCAMBEAgAiEDDAELA0AgAiANTw0CIAtBoAFqIQsgAigCACACQQRqIgMhAkGAgYKEeHEiBUGAgYKEeEYNAAsgBUGAgYKEeHMhDAsgBCALIAxoQQN2QShsaiIIKAIAIgY2AjAgDEF/aiEJQqXGiKHInKf5SyEOQQEhAgNAIA4gBq1C/wGDhUKzg4CAgCB+IQ4gAkEERg0CIARBMGogAmogAkEBaiECLQAAIQYMAAsACyABKQIAIQ8gASAEKQMANwIAIARBCGoiAykDACEOIAMgAUEIaiIDKQIANwMAIAMgDjcCACAEQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBCAPNwMAIABBADYCACAPpyIARQ0GIARBMGogAEEBahCcASAEKAIEECEMBgsgByAEIA4QlwEiBWogDqdBGXYiAjoAACAKIAVBfGpxIAdqQQRqIAI6AAAgBCgCCCAFQShsaiAIQSgQugEaIAkgDHEhDCADIQIMAAsACyACQQFqIQlBACECA0ACQAJ/IAZBAXFFBEAgAiAJTw0CIAJBAWoiAyACSQ0CIAIhBiADDAELIAJBA2oiBiACSSIFDQEgBiAJSSIDRQ0BIAkgAkEEaiAFGyAJIAMbCyECIAEoAgQgBmoiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhBgwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0BIAJBBGogAiADEJkBGgwCC0G0psIAQRxB0KbCABC5AQALIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDSADTwR/QQAhAwNAIAMiBUEBaiEDAkAgBSABKAIEai0AAEGAAUcNAAJAA0AgBCABKAIIIAVBKGxqIgcoAgAiBjYCAEKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCACIARqIAJBAWohAi0AACEGDAELCyAFIAEoAgAiCyAOpyIIcSICayABIA4QlwEiCiACa3MgC3FBBEkNASABKAIEIgkgCmoiAi0AACACIAhBGXYiAjoAACAJIAsgCkF8anFqQQRqIAI6AABB/wFHBEAgASgCCCAKQShsaiIIKQMAIRAgCCAHKQMANwMAIAhBGGoiAikDACERIAIgB0EYaiIJKQMANwMAIAhBEGoiAikDACEPIAIgB0EQaiIGKQMANwMAIAhBCGoiAikDACEOIAIgB0EIaiICKQMANwMAIAcgEDcDACACIA43AwAgBiAPNwMAIAkgETcDACAIQSBqIgIpAgAhDiACIAdBIGoiAikCADcCACACIA43AgAMAQsLIAEoAgQiAiAFakH/AToAACACIAEoAgAgBUF8anFqQQRqQf8BOgAAIAEoAgggCkEobGogB0EoELoBGgwBCyABKAIEIgYgBWogCEEZdiICOgAAIAYgCyAFQXxqcWpBBGogAjoAAAsgAyANRw0ACyABKAIAIgJBCEkNASACQQFqBSANC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAEQUBrJAAL6AoCC38CfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiICIANPBEAgASgCACIDIQUCQCACIANBAWpBA3ZBB2wgBSADQQhPGyIFQQF2SwRAAn8gAiAFQQFqIgMgAiADSxsiAkUEQEGwpsIAIQdBACECQQQMAQsgBkF/An8gAkEITwRAIAIgAkH/////AXFGBEAgAkEDdEEHbgwCCwwJCyACQQFqC0F/amd2IglBAWoiAxCjASAGKAIEIgJFDQcgBigCCCEFIAYoAgAiBCACENIBIgdFDQIgCSADQQN2QQdsIAlBCEkbIQIgB0H/ASAJQQVqEMABIAVqCyEDIAYgASgCECIFNgIQIAYgAiAFazYCDCAGIAM2AgggBiAHNgIEIAYgCTYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQogAygCAEF/c0GAgYKEeHEhBCABKAIIIQMDQAJAIARFBEADQCACIApPDQIgA0FAayEDIAIoAgAgAkEEaiIFIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQQgBSECCyAGQqXGiKHInKf5SzcDMCADIARoQQF0QfAAcWoiBSgCACAFKAIIIAZBMGoQcSAHIAYgBikDMCINEJcBIghqIA2nQRl2Igs6AAAgCSAIQXxqcSAHakEEaiALOgAAIAYoAgggCEEEdGoiCCAFKQIANwIAIAhBCGogBSkCCDcCACAEQX9qIARxIQQMAQsLIAEpAgAhDSABIAYpAwA3AgAgBkEIaiICKQMAIQ4gAiABQQhqIgIpAgA3AwAgAiAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA03AwAgAEEANgIAIA2nIgBFDQUgBkEwaiAAQQFqEKMBIAYoAgQQIQwFCyADQQFqIQVBACEDQQAhAgNAAkACfyADQQFxRQRAIAIgBU8NAiACQQFqIgQgAkkNAiACIQMgBAwBCyACQQNqIgMgAkkiBA0BIAMgBUkiB0UNASAFIAJBBGogBBsgBSAHGwshAiABKAIEIANqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQMMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCZARoMAwsgBCACQfT5wgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiAkEBaiIJIAJPBH9BACEDA0AgAyICQQFqIQMCQCACIAEoAgRqLQAAQYABRw0AAkADQCABKAIIIAZCpcaIocicp/lLNwMAIAJBBHRqIgUoAgAgBSgCCCAGEHEgAiABKAIAIgcgBikDACINpyIIcSIKayABIA0QlwEiBCAKa3MgB3FBBEkNASABKAIEIgogBGoiCy0AACALIAhBGXYiCDoAACAKIAcgBEF8anFqQQRqIAg6AABB/wFHBEAgASgCCCAEQQR0aiIEKQIAIQ0gBCAFKQIANwIAIARBCGoiBCkCACEOIAQgBUEIaiIEKQIANwIAIAUgDTcCACAEIA43AgAMAQsLIAEoAgQiByACakH/AToAACAHIAEoAgAgAkF8anFqQQRqQf8BOgAAIAEoAgggBEEEdGoiAiAFKQIANwIAIAJBCGogBUEIaikCADcCAAwBCyABKAIEIgUgAmogCEEZdiIEOgAAIAUgByACQXxqcWpBBGogBDoAAAsgAyAJRw0ACyABKAIAIgJBCEkNASACQQFqBSAJC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC5AQALoQoCDH8CfiMAQUBqIgQkAAJAAkACQCABKAIQIgNBAWoiBSADTwRAIAEoAgAiAiEDIAUgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEAgBCAFIANBAWoiAyAFIANLGxBSIARBCGooAgAhByAEKAIEIQggBCgCAEEBRgRAIAAgCDYCBCAAQQE2AgAgAEEIaiAHNgIADAULIARBEGooAgAhBSAEQQxqKAIAIQIgBCABKAIQIgM2AhAgBCAFIANrNgIMIAQgAjYCCCAEIAc2AgQgBCAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDCADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIAxPDQIgCkEgaiEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQsLIAQgCiALaEE4cWoiDSgCACIGNgIwIAtBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAEQTBqIAJqIAJBAWohAi0AACEGDAALAAsgASkCACEPIAEgBCkDADcCACAEQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBEEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAQgDzcDACAAQQA2AgAgD6ciAEUNBiAEQTBqIABBAWoQogEgBCgCBBAhDAYLIAcgBCAOEJcBIgVqIA6nQRl2IgI6AAAgCCAFQXxqcSAHakEEaiACOgAAIAQoAgggBUEDdGogDSkCADcCACAJIAtxIQsgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAGQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQYgAwwBCyACQQNqIgYgAkkiBQ0BIAYgCUkiA0UNASAJIAJBBGogBRsgCSADGwshAiABKAIEIAZqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQYMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NASACQQRqIAIgAxCZARoMAgtBtKbCAEEcQdCmwgAQuQEACyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIgwgA08Ef0EAIQMDQCADIgVBAWohAwJAIAUgASgCBGotAABBgAFHDQACQANAIAQgASgCCCAFQQN0aiIHKAIAIgY2AgBCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAEaiACQQFqIQItAAAhBgwBCwsgBSABKAIAIgogDqciDXEiAmsgASAOEJcBIgggAmtzIApxQQRJDQEgASgCBCIJIAhqIgItAAAgAiANQRl2IgI6AAAgCSAKIAhBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCEEDdGoiAikCACEOIAIgBykCADcCACAHIA5CIIg+AgQgByAOPgIADAELCyABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIAhBA3RqIAcpAgA3AgAMAQsgASgCBCIGIAVqIA1BGXYiAjoAACAGIAogBUF8anFqQQRqIAI6AAALIAMgDEcNAAsgASgCACICQQhJDQEgAkEBagUgDAtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBEFAayQAC7wJAhh/AX4jAEEwayIDJAAgAkE4aigCACACQTBqKAIAIQggAkEsaigCACACQQxqKAIAIQsgAigCNCEVIAIoAighDiACKAIAIRYgAigCBCEKIAEoAgAiASgCCCEPIAEoAgAhECADQQhqIAJBEGooAgAiByACQQhqKAIAIgIgASgCBCIRIAEoAgwiEmpBAWpsakECdBCxASADQQA2AiggAyADKQMINwMgAkACQCACBEAgCiACQRRsaiEXIAhBBXQhDCAOQXRqIQ0gDyASQQJ0aiEYIBAgEUECdGohGUEAIQgCQAJAAkACQAJAAkADQCAIIgZBAWohCCAKIglBFGohCiAMIQIgDSEEIA4hAQJAAn8CQANAIAJFDQEgAkFgaiECIARBIGohBCABKAIIIRogAUEgaiEBIAYgGkcNAAsgCSgCBCICIAQoAgBqIgEgBUkNBSAHIAFJDQYgBEEEaigCACEEIANBIGogAygCKCABIAVrEJEBIAsgBUECdGogCyABQQJ0aiIFIANBIGoQSyADQSBqIAMoAiggERCRASAQIBkgA0EgahBLIAIgBGoiBiABSQ0HIAcgBkkNCCADQSBqIAMoAiggBiABaxCRASAFIAsgBkECdGoiBCADQSBqEEsgA0EgaiADKAIoIBIQkQEgDyAYIANBIGoQSyAJKAIIIgIgBkkNCSAHIAJJDQQgAiAGawwBCyAJKAIIIgIgBUkNASAHIAJJDQMgCyAFQQJ0aiEEIAIgBWsLIQEgA0EgaiADKAIoIAEQkQEgBCALIAJBAnRqIANBIGoQSyAJKAIIIQUgCiAXRg0JDAELCyAFIAIQqAEACyACIAcQpgEACyAFIAEQqAEACyABIAcQpgEACyABIAYQqAEACyAGIAcQpgEACyAGIAIQqAEAC0EAIQIMAQsgByAFTwRAIAMoAighAgwBCyAFIAcQqAEACyADQSBqIAIgByAFaxCRASALIAVBAnRqIAsgB0ECdGogA0EgahBLAkAgAygCKCINRQ0AQQAhAiADKAIgIQZBACEJA0ACQAJAAkAgAiAGaiIMLAAAIghBf0oEQCAIQf8BcSEEDAELAn8gBiANaiIBIAxBAWpGBEBBACEKIAEMAQsgDC0AAUE/cSEKIAxBAmoLIQQgCEEfcSEFAkACQCAIQf8BcSIHQd8BTQRAIAogBUEGdHIhBAwBCwJ/IAEgBEYEQCABIQhBAAwBCyAEQQFqIQggBC0AAEE/cQsgCkEGdHIhBCAHQfABSQRAIAQgBUEMdHIhBAwBCyABIAhGBH9BAAUgCC0AAEE/cQsgBUESdEGAgPAAcSAEQQZ0cnIiBEGAgMQARg0BCyAEQYABSQ0BQQIhASAEQYAQSQ0CQQNBBCAEQYCABEkbIQEMAgtB3/LCAEErQYzzwgAQuQEAC0EBIQEgBA0AIAlBAWohCQwBCyAJRQRAQQAhCQwBCyAGIAIgCWtqIAwgARCZARoLIA0gASACaiICSw0ACyAJRQ0AIAMgDSAJazYCKAsgA0EYaiADQShqKAIAIgE2AgAgAyADKQMgIhs3AxAgACAWNgIAIAAgGzcCBCAAQQxqIAE2AgAEQCAOECELBEAgFRAhCyADQTBqJAALyQoBDX8jAEGgAmsiByQAIAcgAjYCQCAHIAI2AjwgByABNgI4IAdBKGogB0E4ahCGASAHKAIoIQEgBygCLCEKIAcgBDYCQCAHIAQ2AjwgByADNgI4IAdBIGogB0E4ahCOASAHKAIkIQkgBygCICENIAcgBjYCQCAHIAY2AjwgByAFNgI4IAdBGGogB0E4ahCGASAHKAIcIQ4gBygCGCELAkACQAJAAkAQwgEiBARAIAcgBy8BNDsBNiAEKAIADQIgBEF/NgIAIARBBGogABBVIgxFDQMgDBCwASAHQeQAakEBOwEAIAdB4ABqIg9BADYCACAHQdgAakKAgICAEDcDACAHQdQAaiAJNgIAIAdB0ABqQQA2AgAgB0HMAGogCTYCACAHQcgAaiANNgIAIAdBxABqIAk2AgAgB0H0AGogCyAOQQJ0ajYCACAHQeYAaiAHLwE2OwEAIAdBADYCQCAHIAEgCkECdGo2AjwgByABNgI4IAdCADcDeCAHIAs2AnAgB0IANwNoIApFDQEgDEEUaiEDIAdBQGshECAHIAFBBGo2AjggB0GIAWohEUEAIQIgASEGA0AgAkH/AXENAiAGKAIAIRICfwJAAkAgBygCVCIAIAcoAlAiAkkNACAHKAJMIABJDQAgB0EQaiAHKAJcIBBqQR9qLQAAIAcoAkgiCCACaiAAIAJrEEMgBygCEEEBRgRAIAcoAhQhBgNAIAcgBiAHKAJQakEBaiIANgJQAkAgACAHKAJcIgJJBEAgBygCTCEFDAELIAcoAkwiBSAASQ0AIAJBBU8NCyAAIAJrIgYgBygCSGoiEyAPRg0EIBMgDyACELUBRQ0ECyAHKAJUIgYgAEkgBSAGSXINAiAHQQhqIAIgEGpBH2otAAAgBygCSCAAaiAGIABrEEMgBygCDCEGIAcoAghBAUYNAAsLIAcgBygCVDYCUAsgBy0AZQ0EAkAgBy0AZARAIAcoAkQhAiAHKAJAIQYMAQsgBygCRCICIAcoAkAiBkYNBQsgB0EBOgBlIAIgBmshACAHKAJIIAZqDAELIAcoAkAhAiAHIAA2AkAgBiACayEAIAIgCGoLIQIgBygCcCIFIAcoAnRGDQIgByAFQQRqNgJwIAUoAgAhBSAHQcABaiACIAAgAmoQIyAHQfABaiAHQcABahBgIAdBwAFqIAdB8AFqIAMQOyAHQfABaiAHQcABaiADECYgB0HAAWogB0HwAWogAxAgIAdB8AFqIAdBwAFqEDkgBygC+AEiAARAIAcoAvABIQYgAEEUbCECIAcoApACIQAgBygCiAIhCANAIAYgCCAAIAMQSCAGQRRqIQYgAkFsaiICDQALCyAHQcABaiAHQfABakEwELoBGiAHQfABaiAHQcABaiADEFAgBygC+AEiAARAIAcoAvABIQYgAEEUbCECIAcoApACIQAgBygCiAIhCANAIAYgCCAAIAMQRyAGQRRqIQYgAkFsaiICDQALCyAHQcABaiAHQfABakEwELoBGiAHIBI2AoQBIAdBADYCgAEgESAHQcABakEwELoBGiAHIAU2ArgBIAwgB0GAAWoQFyAHKAI4IgYgBygCPEYNAiAHIAZBBGo2AjggBy0AZSECDAALAAtB3MPCAEHGACAHQThqQcTEwgAQlgEACyAEIAQoAgBBAWo2AgAgCQRAIA0QIQsgDkH/////A3EEQCALECELIApB/////wNxBEAgARAhCyAHQaACaiQADwtBosTCAEEQIAdBOGpBtMTCABCWAQALQd/ywgBBK0GM88IAELkBAAsgAkEEEKYBAAuUCgIUfwF+IwBB4ABrIgMkACABIAAoAgAiDTYCAAJAIAAoArABRQRAIABBfzYCsAEgA0EcaiABQTRqKAIANgIAIANBFGogAUEoaigCADYCACADQQxqIAFBHGooAgA2AgAgAyABQSxqKAIANgIYIAMgAUEgaigCADYCECADIAFBFGooAgA2AgggAyABQRBqKAIANgIEIAMgASgCCDYCACADQUBrIAMQHyAAQbQBaiICIAIoAgBBAWo2AgAgAygCQCEKIAMoAkgiAkUNASAKIAJBDGxqIRIgAEG4AWohDyAAQbwBaiETIABBxAFqIRAgAEHAAWohFCAAQcgBaiERIAohCQNAIANB2ABqIAlBCGooAgA2AgAgAyAJKQIANwNQIANCpcaIocicp/lLNwMAIANB0ABqIAMQdiADKQMAIhZCGYinQf8AcSIEQQh0IARyIgJBEHQgAnIhCyAJQQxqIQkgACgCuAEiByAWp3EhAiAAKALAASEOIAAoArwBIRVBACEIAkACQANAAkAgAiAVaigAACIMIAtzIgZBf3MgBkH//ft3anFBgIGChHhxIgZFDQADQAJAIA4gBmhBA3YgAmogB3FBGGxqIgUgA0HQAGpGBEAgA0HQAGohBQwBCyAFIANB0ABqQQwQtQFFDQAgBkF/aiAGcSIGDQEMAgsLIAVBDGohCCAFKAIUIQRBACEHQQAhAgNAAkAgBCACSwRAIAJBAWoiBiACTw0BCyAFKAIQIARGBEAgCCAEQQEQgwEgBSgCFCEECyAFKAIMIARBAnRqIA02AgAgBSAFKAIUQQFqNgIUDAQLIAgoAgAiDCAHaiELIAdBBGohByAGIQICQEF/IA0gCygCACILRyANIAtJGw4CBAEACwsgBCAGQX9qTwRAIAUoAhAgBEYEfyAIIARBARCDASAIKAIABSAMCyAHaiICIAJBfGoiAiAEQQJ0IAdrQQRqEJkBGiACIA02AgAgBSAEQQFqNgIUDAMLQeStwgBBHkGErsIAELkBAAsgCEEEaiIIIAJqIAdxIQIgDCAMQQF0cUGAgYKEeHFFDQALIAAoAsQBRQRAIAMgDxAQCyADKAJYIQUgAygCVCEHIAMoAlAhCEEEQQQQ0gEiBkUNASAGIA02AgAgECAQKAIAIBMoAgAiDCAPIBYQlwEiAmoiCy0AAEEBcWs2AgAgFCgCACALIAQ6AAAgDCAAKAK4ASACQXxqcWpBBGogBDoAACACQRhsaiICQoGAgIAQNwIQIAIgBjYCDCACIAU2AgggAiAHNgIEIAIgCDYCACARIBEoAgBBAWo2AgALIAkgEkcNAQwDCwtBBEEEQfT5wgAoAgAiAEECIAAbEQAAAAtBosTCAEEQIANBuKrCABCWAQALIAMoAkQEQCAKECELIAAgACgCsAFBAWo2ArABIAMgAUE8ELoBIQkCQCAAQQxqKAIAIgEgAEEIaigCAEYEQAJAAkAgAUEBaiICIAFJDQAgAUEBdCIKIAIgCiACSxsiCq1CPH4iFkIgiKcNACAWpyICQQBODQELEM4BAAsCfyABRQRAIAJBBBDSAQwBCyAAKAIEIAFBPGxBBCACEM8BCyIBRQ0BIAAgCjYCCCAAIAE2AgQgACgCDCEBCyAAKAIEIAFBPGxqIAlBPBC6ARogACAAKAIMQQFqNgIMIAAgACgCAEEBajYCACAJQeAAaiQADwsgAkEEQfT5wgAoAgAiAEECIAAbEQAAAAvtCAIMfwF+IwBB0ANrIgQkAAJAEMIBIgMEQAJAIAMoAgBFBEAgA0F/NgIAIANBBGogABBeBEAgBCAANgIIQqXGiKHInKf5SyENQQEhAiAAIQEDQCANIAGtQv8Bg4VCs4OAgIAgfiENIAJBBEZFBEAgBEEIaiACaiACQQFqIQItAAAhAQwBCwsgDaciAUEZdiICQQh0IAJyIgJBEHQgAnIhCSADKAIEIgYgAXEhAiADKAIMIQogAygCCCEIA0ACQAJAIAIgCGooAAAiByAJcyIBQX9zIAFB//37d2pxQYCBgoR4cSIBRQ0AA0AgACAKIAFoQQN2IAJqIAZxIgtB7AFsaiIMKAIARwRAIAFBf2ogAXEiAQ0BDAILC0GAASECIAggC0HsAWwiAUHsAW0iBWoiBygAACIJIAlBAXRxQYCBgoR4cWhBA3YgCCAFQXxqIAZxaiIFKAAAIgYgBkEBdHFBgIGChHhxZ0EDdmpBA00EQCADIAMoAhBBAWo2AhBB/wEhAgsgByACOgAAIAVBBGogAjoAACADIAMoAhRBf2o2AhQgASAKaiICKAIIIQEgDCgCBCEFIARB8AFqIAJBDGpB4AEQugEaIAFFBEBBACEBDAILIAQgBTYCCCAEQRBqIARB8AFqQeABELoBGgwBCyAFQQRqIgUgAmogBnEhAkEAIQEgByAHQQF0cUGAgYKEeHFFDQELCyAEIAE2AgwgBEEIahB3IAMgAygCAEEBajYCAAJAAkAQwQEiAwRAIAMoAgANAiADQX82AgAgA0EEaiAAEF1FDQEgBCAANgIIQqXGiKHInKf5SyENQQEhAiAAIQEDQCANIAGtQv8Bg4VCs4OAgIAgfiENIAJBBEZFBEAgBEEIaiACaiACQQFqIQItAAAhAQwBCwsgDaciAUEZdiICQQh0IAJyIgJBEHQgAnIhCSADKAIEIgYgAXEhAiADKAIMIQogAygCCCEIQQAhBQJAA0ACQAJAIAIgCGooAAAiByAJcyIBQX9zIAFB//37d2pxQYCBgoR4cSIBRQ0AA0AgACAKIAFoQQN2IAJqIAZxIgtBBHRqKAIARwRAIAFBf2ogAXEiAQ0BDAILC0GAASECIAggC0EEdCIAQQR1IgFqIgUoAAAiByAHQQF0cUGAgYKEeHFoQQN2IAggAUF8aiAGcWoiASgAACIGIAZBAXRxQYCBgoR4cWdBA3ZqQQNNBEAgAyADKAIQQQFqNgIQQf8BIQILIAUgAjoAACABQQRqIAI6AAAgAyADKAIUQX9qNgIUIAAgCmoiACgCBCIBRQ0BIAQgACkCCDcCDCAEIAE2AggMAwsgBUEEaiIFIAJqIAZxIQIgByAHQQF0cUGAgYKEeHFFDQELCyAEQQA2AggLIARBCGoQpQEgAyADKAIAQQFqNgIAIARB0ANqJAAPCwwECxDRAQALDAQLENEBAAsMAgsLQdzDwgBBxgAgBEEIakHcqMIAEJYBAAtBosTCAEEQIARBCGpBzKjCABCWAQALxwkCDX8DfiMAQbAJayIFJAAgBSAAQYgBELoBIQECQAJAAkBB2MPCACgCABEIACIDBEAgASgCACEAIAFBiAFqIAFBBHJBhAEQugEaIAMoAgANAiADQX82AgAgA0EEaiIJIAAoAgAQXg0BIAFByAdqEGJBBEEEENIBIgVFDQMgBUHbADYCAEEEQQQQ0gEiAkUNAyACQd0ANgIAIAFBmAJqQgA3AwAgAUEKNgKgAiABQoCAgIDAADcDkAIgAUGkAmogAUHIB2pBhAEQugEhBiABQeADakIANwMAIAFB2ANqQoCAgIDAADcDACABQdADakIENwMAIAFBzANqQbCmwgA2AgAgAUHIA2pBADYCACABQbgDakKBgICAEDcDACABQbQDaiACNgIAIAFBrANqQoGAgIAQNwIAIAFB6ANqQgA3AwAgAUIANwPAAyABIAU2AqgDIAYQjwEgBiABQYgBakGEARC6ARogACgCACEFIAFB4AVqIAFBkAJqQegBELoBGiABIAU2AsgHQqXGiKHInKf5SyEOQQEhAiAFIQADQCAOIACtQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCABQcgHaiACaiACQQFqIQItAAAhAAwBCwsgDkIZiKciDEH/AHEiAEEIdCAAciIAQRB0IAByIQ0gAygCBCIIIA6ncSECIAMoAgwhBiADKAIIIQACQANAAkAgACACaigAACIKIA1zIgRBf3MgBEH//ft3anFBgIGChHhxIgRFDQADQCAFIAYgBGhBA3YgAmogCHEiC0HsAWxqKAIARwRAIARBf2ogBHEiBA0BDAILCyABQcgHaiABQeAFakHoARC6ARogBiALQewBbGpBBGohCEEAIQIDQCACIAhqIgApAgAhDiAAIAFByAdqIAJqIgUpAgA3AgAgBSAONwIAIABBCGoiBCkCACEOIAQgBUEIaiIEKQIANwIAIABBEGoiBykCACEPIAcgBUEQaiIHKQIANwIAIABBGGoiACkCACEQIAAgBUEYaiIAKQIANwIAIAAgEDcCACAHIA83AgAgBCAONwIAIAJBQGsgAkEgaiECQekBSQ0ACyAGIAtB7AFsaiIAKQLkASEOIAAgAUGoCWoiACkDADcC5AEgACAONwMAIAFB+ANqIAFByAdqQegBELoBGgwCCyAHQQRqIgcgAmogCHEhAiAKIApBAXRxQYCBgoR4cUUNAAsgAUHIB2ogAUHgBWpB6AEQugEaIAAgCSAOEJcBIgJqLQAAQQFxIQQgAygCECIHIARFckUEQCABQfgDaiAJEAwgCSAOEJcBIQIgAygCBCEIIAMoAgwhBiADKAIQIQcgAygCCCEACyADIAcgBGs2AhAgACACaiAMQf8AcSIEOgAAIAggAkF8anEgAGpBBGogBDoAACAGIAJB7AFsaiIAIAU2AgAgAEEEaiABQcgHakHoARC6ARogAyADKAIUQQFqNgIUIAFBADYC/AMLIAFB+ANqEHcgAyADKAIAQQFqNgIAIAFBsAlqJAAPCyABQQRyEI8BQdzDwgBBxgAgAUHIB2pB3KjCABCWAQALENEBAAtBosTCAEEQIAFByAdqQcyowgAQlgEAC0EEQQRB9PnCACgCACIAQQIgABsRAAAAC64IAQZ/IwBB8ABrIgQkACAEIAM2AgwgBCACNgIIQQEhCCABIQYCQCABQYECSQ0AQQAgAWshB0GAAiEFA0ACQCAFIAFPDQAgACAFaiwAAEG/f0wNAEEAIQggBSEGDAILIAVBf2ohBkEAIQggBUEBRg0BIAUgB2ogBiEFQQFHDQALCyAEIAY2AhQgBCAANgIQIARBAEEFIAgbNgIcIARBqu/CAEHOg8AAIAgbNgIYAkACQAJAIAIgAUsiBSADIAFLckUEQCACIANLDQECQCACRSABIAJGckUEQCABIAJNDQEgACACaiwAAEFASA0BCyADIQILIAQgAjYCICACRSABIAJGcg0CIAFBAWohAwNAIAIgAUkEQCAAIAJqLAAAQUBODQQLIAJBf2ohBSACQQFGDQQgAiADRiAFIQJFDQALDAMLIAQgAiADIAUbNgIoIARBxABqQQM2AgAgBEHcAGpBBDYCACAEQdQAakEENgIAIARCAzcCNCAEQdSDwAA2AjAgBEEFNgJMIAQgBEHIAGo2AkAgBCAEQRhqNgJYIAQgBEEQajYCUCAEIARBKGo2AkggBEEwakHsg8AAEL8BAAsgBEHkAGpBBDYCACAEQdwAakEENgIAIARB1ABqQQU2AgAgBEHEAGpBBDYCACAEQgQ3AjQgBEH8g8AANgIwIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCYCAEIARBEGo2AlggBCAEQQxqNgJQIAQgBEEIajYCSCAEQTBqQZyEwAAQvwEACyACIQULAkAgASAFRg0AQQEhBgJAAkACQCAAIAVqIgcsAAAiAkF/TARAQQAhCCAAIAFqIgMhASADIAdBAWpHBEAgBy0AAUE/cSEIIAdBAmohAQsgAkEfcSEHIAJB/wFxQd8BSw0BIAggB0EGdHIhAQwCCyAEIAJB/wFxNgIkIARBKGohAgwCC0EAIQAgAyEGIAEgA0cEfyABQQFqIQYgAS0AAEE/cQUgAAsgCEEGdHIhACACQf8BcUHwAUkEQCAAIAdBDHRyIQEMAQtBACECIAMgBkcEfyAGLQAAQT9xBSACCyAHQRJ0QYCA8ABxIABBBnRyciIBQYCAxABGDQILIAQgATYCJEEBIQYgBEEoaiECIAFBgAFJDQBBAiEGIAFBgBBJDQBBA0EEIAFBgIAESRshBgsgBCAFNgIoIAQgBSAGajYCLCAEQcQAakEFNgIAIARB7ABqQQQ2AgAgBEHkAGpBBDYCACAEQdwAakEGNgIAIARB1ABqQQc2AgAgBEIFNwI0IARBrITAADYCMCAEIAI2AlggBEEFNgJMIAQgBEHIAGo2AkAgBCAEQRhqNgJoIAQgBEEQajYCYCAEIARBJGo2AlAgBCAEQSBqNgJIIARBMGpB1ITAABC/AQALQd/ywgBBK0GM88IAELkBAAulCAEIfyMAQUBqIgMkACADQSRqIAE2AgAgA0E0aiACQRRqKAIAIgQ2AgAgA0EDOgA4IANBLGogAigCECIFIARBA3RqNgIAIANCgICAgIAENwMIIAMgADYCICADQQA2AhggA0EANgIQIAMgBTYCMCADIAU2AigCQAJAAkACQCACKAIIIgZFBEAgAigCACEIIAIoAgQiCSAEIAQgCUsbIgZFDQFBASEEIAAgCCgCACAIKAIEIAEoAgwRAwANBCAIQQhqIQJBASEHA0AgBSgCACADQQhqIAVBBGooAgARAQAEQAwGCyAHIAZPDQIgAkEEaiEAIAIoAgAhASAFQQhqIQUgAkEIaiECIAdBAWohByADKAIgIAEgACgCACADKAIkKAIMEQMARQ0ACwwECyACKAIAIQggAigCBCIJIAJBDGooAgAiAiACIAlLGyIKRQ0AQQEhBCAAIAgoAgAgCCgCBCABKAIMEQMADQMgBkEQaiEFIAhBCGohAkEBIQcDQCADIAVBeGooAgA2AgwgAyAFQRBqLQAAOgA4IAMgBUF8aigCADYCCEEAIQFBACEEAkACQAJAAkAgBUEIaigCAEEBaw4DAQIDAAsgBUEMaigCACEAQQEhBAwCCyAFQQxqKAIAIgYgAygCNCIESQRAQQAhBCADKAIwIAZBA3RqIgYoAgRBCEcNAiAGKAIAKAIAIQBBASEEDAILQcSFwAAgBiAEEKcBAAsgAygCKCIGIAMoAixGDQAgAyAGQQhqNgIoIAYoAgRBCEcNACAGKAIAKAIAIQBBASEECyADIAA2AhQgAyAENgIQAkACfwJAAkACQAJAAkAgBSgCAEEBaw4DAQAGBAsgAygCKCIAIAMoAixHDQEMBQsgBUEEaigCACIAIAMoAjQiBE8NASADKAIwIABBA3RqIgAoAgRBCEcNBCAAKAIAKAIADAMLIAMgAEEIajYCKCAAKAIEQQhHDQMgACgCACgCAAwCC0HEhcAAIAAgBBCnAQALIAVBBGooAgALIQRBASEBCyADIAQ2AhwgAyABNgIYAkAgBUFwaigCAEEBRwRAIAMoAigiBCADKAIsRg0EIAMgBEEIajYCKAwBCyAFQXRqKAIAIgAgAygCNCIBTw0EIAMoAjAgAEEDdGohBAsgBCgCACADQQhqIARBBGooAgARAQAEQEEBIQQMBQsgByAKTw0BIAJBBGohACACKAIAIQEgBUEkaiEFIAJBCGohAkEBIQQgB0EBaiEHIAMoAiAgASAAKAIAIAMoAiQoAgwRAwBFDQALDAMLIAkgB0sEQEEBIQQgAygCICAIIAdBA3RqIgAoAgAgACgCBCADKAIkKAIMEQMADQMLQQAhBAwCC0Hf8sIAQStBjPPCABC5AQALQdSFwAAgACABEKcBAAsgA0FAayQAIAQL4AcCD38BfiMAQaACayICJAAgAkGYAWoQYiACQQE2ApgBQfixwgAhAQNAIAJBmAFqIAEoAgAgAUEEaigCACABQQhqKAIAIAFBDGooAgAQgQEgAUEQaiIBQfi1wgBHDQALQfi1wgAhAQNAIAJBmAFqIAEoAgAgAUEEaigCACABQQhqKAIAIAFBDGooAgAQggEgAUEQaiIBQfi5wgBHDQALQfi5wgAhAQNAIAJBmAFqIAFBBGooAgAgAUEIaigCACABLQAAEGkgAUEMaiIBQZDAwgBHDQALIAJBmAFqQQRyIQVByK/CACEBA0AgBSABQQRqKAIAIAEtAAAQOiABQQhqIgFBmLHCAEcNAAsgAkEQaiACQZgBakGEARC6ARogAiAANgKUASACIAJBlAFqNgKYASACQZgBakEEciACQRBqQYQBELoBGiACQZgBahAZEMEBIgMEQCADKAIARQRAIANBfzYCACADQQRqIgkgAigClAEiBRBdRQRAIAJBCGpBChCfASACKAIMIQogAigCCCELIAIgBTYCmAFCpcaIocicp/lLIRBBASEBIAUhAANAIBAgAK1C/wGDhUKzg4CAgCB+IRAgAUEERkUEQCACQZgBaiABaiABQQFqIQEtAAAhAAwBCwsgEEIZiKciDUH/AHEiAEEIdCAAciIAQRB0IAByIQ4gAygCBCIHIBCncSEBIAMoAgwhCCADKAIIIQACQANAAkAgACABaigAACIMIA5zIgRBf3MgBEH//ft3anFBgIGChHhxIgRFDQADQCAFIAggBGhBA3YgAWogB3EiD0EEdGooAgBHBEAgBEF/aiAEcSIEDQEMAgsLIAJBoAFqIAggD0EEdGoiACgCDDYCACAAQQA2AgwgACkCBCEQIAAgCjYCCCAAIAs2AgQgAiAQNwOYAQwCCyAGQQRqIgYgAWogB3EhASAMIAxBAXRxQYCBgoR4cUUNAAsgACAJIBAQlwEiAWotAABBAXEhBCADKAIQIgYgBEVyRQRAIAJBmAFqIAkQDiAJIBAQlwEhASADKAIEIQcgAygCDCEIIAMoAhAhBiADKAIIIQALIAMgBiAEazYCECAAIAFqIA1B/wBxIgQ6AAAgByABQXxqcSAAakEEaiAEOgAAIAggAUEEdGoiAEEANgIMIAAgCjYCCCAAIAs2AgQgACAFNgIAIAMgAygCFEEBajYCFCACQQA2ApgBCyACQZgBahClASADIAMoAgBBAWo2AgAgAkGgAmokAA8LENEBAAtBosTCAEEQIAJBmAFqQcyowgAQlgEAC0Hcw8IAQcYAIAJBmAFqQdyowgAQlgEAC6gGAQ1/IAEoAhAhAiAAKAIEIQMgACgCACEFAkACQAJAIAEoAggiDUEBRwRAIAINAQwDCyACRQ0BCwJAIANFBEBBACEDDAELIAMgBWohByABQRRqKAIAQQFqIQogBSIAIQsDQCAAQQFqIQQCQAJ/IAAsAAAiAkF/TARAAn8gBCAHRgRAQQAhCCAHDAELIAAtAAFBP3EhCCAAQQJqIgQLIQAgAkEfcSEJIAggCUEGdHIgAkH/AXEiDkHfAU0NARoCfyAAIAdGBEBBACEMIAcMAQsgAC0AAEE/cSEMIABBAWoiBAshAiAMIAhBBnRyIQggCCAJQQx0ciAOQfABSQ0BGgJ/IAIgB0YEQCAEIQBBAAwBCyACQQFqIQAgAi0AAEE/cQsgCUESdEGAgPAAcSAIQQZ0cnIiAkGAgMQARw0CDAQLIAJB/wFxCyECIAQhAAsgCkF/aiIKBEAgBiALayAAaiEGIAAhCyAAIAdHDQEMAgsLIAJBgIDEAEYNAAJAIAZFIAMgBkZyRQRAQQAhACAGIANPDQEgBSAGaiwAAEFASA0BCyAFIQALIAYgAyAAGyEDIAAgBSAAGyEFCyANDQAMAQtBACEEIAMEQCADIQIgBSEAA0AgBCAALQAAQcABcUGAAUZqIQQgAEEBaiEAIAJBf2oiAg0ACwsgAyAEayABKAIMIgdPDQBBACEGQQAhBCADBEAgAyECIAUhAANAIAQgAC0AAEHAAXFBgAFGaiEEIABBAWohACACQX9qIgINAAsLIAQgA2sgB2ohAgJAAkACQEEAIAEtADAiACAAQQNGG0EBaw4DAAEAAgsgAiEGQQAhAgwBCyACQQF2IQYgAkEBakEBdiECCyAGQQFqIQACfwJAA0AgAEF/aiIABEAgASgCGCABKAIEIAEoAhwoAhARAQBFDQEMAgsLIAEoAgQhBEEBIAEoAhggBSADIAEoAhwoAgwRAwANARogAkEBaiEAIAEoAhwhBSABKAIYIQEDQCAAQX9qIgBFBEBBAA8LIAEgBCAFKAIQEQEARQ0ACwtBAQsPCyABKAIYIAUgAyABQRxqKAIAKAIMEQMAC/0GAhV/AX4jAEHgAWsiAiQAIAIgATYCACABKAIgIQkgAiABLQAkOgAHIAIgAjYCDCACIAJBB2o2AgggAS0AJUUEQCABQRRqIQQgAkH4AGogASABQRBqIgogAUEMaiILEAACQCACKAJ8IgZFDQAgCUEBdCEMIAJBtAFqIQ0gAkGAAWohCANAAkAgAkFAayIDIAhBCGopAgA3AwAgAkHIAGoiBSAIQRBqKQIANwMAIAJB0ABqIgcgCEEYaikCADcDACACIAgpAgA3AzggAigCeCEOIAIoAqABIQ8gAigCpAEhECACKAKoASERIAIoAqwBIRIgAigCsAEhEyACQRBqIA1BKBC6ARogAkHgAGoiFCADKQMANwMAIAJB6ABqIhUgBSkDADcDACACQfAAaiIWIAcpAwA3AwAgAiACKQM4NwNYIAJB+ABqIAJBEGpBKBC6ARogASgCHCIDIAEoAhhGBEACQAJAIANBAWoiBSADSQ0AIANBAXQiByAFIAcgBUsbIgetQuQAfiIXQiCIpw0AIBenIgVBAE4NAQsQzgEACwJ/IANFBEAgBUEEENIBDAELIAQoAgAgA0HkAGxBBCAFEM8BCyIDRQ0BIAEgBzYCGCABIAM2AhQgASgCHCEDCyABKAIUIANB5ABsaiIDIAY2AgQgAyAONgIAIAMgEjYCNCADIA82AiggA0EIaiACKQNYNwIAIANBEGogFCkDADcCACADQRhqIBUpAwA3AgAgA0EgaiAWKQMANwIAIANBOGogEzYCACADQTBqIBE2AgAgA0EsaiAQNgIAIANBPGogAkH4AGpBKBC6ARogASABKAIcQQFqIgM2AhwgAyAMTwRAIAJBCGogBBAKIAQgCRC3AQsgAkH4AGogASAKIAsQACACKAJ8IgYNAQwCCwsgBUEEQfT5wgAoAgAiAEECIAAbEQAAAAsgAkEIaiAEEAogBCAJELcBIAEoAhwiBEEBdiIGBEAgASgCFCIDIARB5ABsakGcf2ohBANAIAJB+ABqIANB5AAQugEaIAMgBEHkABCZAUHkAGohAyAEIAJB+ABqQeQAELoBQZx/aiEEIAZBf2oiBg0ACwsgAUEBOgAlCwJAIAFBHGooAgAiAwRAIAEgA0F/aiIDNgIcIAAgASgCFCADQeQAbGpB5AAQugEaDAELIABBADYCBAsgAkHgAWokAAvVBgIOfwJ+IwBBEGsiBiQAIAEoAgQiCEEUbCEDIAEoAgAhBQJAIAhFBEAMAQsgBUEIaiEEIAMhAgNAIAQoAgAgBEF8aigCAGsgB2ohByAEQRRqIQQgAkFsaiICDQALCwJAIAetQgx+IhBCIIinRQRAIBCnIgJBf0oEQAJAAkAgAkUEQEEEIQkMAQsgAkEEENIBIglFDQELIAhFBEBBACECDAQLIAMgBWohDSABQRRqKAIAIQwgASgCECEOQQAhAgNAAkACQAJAAkACQCAFKAIIIgEgBSgCBCIKTwRAIAwgAUkNASABIAprIghFDQVBASEEIAJBAXQhAyACQQxsIQEgDiAKQQJ0aiEKA0AgBkEANgIIIAZCADcCACAGIAQgCiAEEHMCfyAEQQNGBEAgCEUNBSAKQQRqIQogCEF/aiEIQQMMAQsgBEEBagshBCAGKAIAIg9BgIDEAEYNBiAGKQIEIRAgAiAHRgRAIAJBAWoiByACSQ0GIAMgByADIAdLGyIHrUIMfiIRQiCIpw0GIBGnIgtBAEgNBgJ/IAJFBEAgC0EEENIBDAELIAkgAUEEIAsQzwELIglFDQULIAEgCWoiCyAPNgIAIAtBBGogEDcCACADQQJqIQMgAUEMaiEBIAJBAWohAiAIIARPDQALDAULIAogARCoAQALIAEgDBCmAQALQQFBABCoAQALIAtBBEH0+cIAKAIAIgBBAiAAGxEAAAALEM4BAAsgDSAFQRRqIgVHDQALDAMLIAJBBEH0+cIAKAIAIgBBAiAAGxEAAAALENMBAAsQ0wEACyAJIAIgBkEAQSAgAmdrEAUCQCACQQJJBEAgAiEDDAELIAlBDGohAUEBIQNBASEEA0ACQCAEIANBf2oiBUYNACABIAkgBUEMbGpBDBC1AUUNACADIARHBEAgBkEIaiIIIAFBCGoiBSgCADYCACAGIAEpAgA3AwAgBSAJIANBDGxqIgVBCGoiCigCADYCACABIAUpAgA3AgAgCiAIKAIANgIAIAUgBikDADcCAAsgA0EBaiEDCyABQQxqIQEgAiAEQQFqIgRHDQALIAMgAk0NAEG1rcIAQRxB1K3CABC5AQALIAAgAzYCCCAAIAc2AgQgACAJNgIAIAZBEGokAAueBgENfyABKAIAIQcCf0EAIAEoAggiBEUNABogByAEQRRsaiEOAkACQANAIAciCCgCCCIJIAgoAgQiA08EQCAJIAEoAiAiBEsNAiABKAIYIQ0gCSADayEPIANBAnQhC0EAIQpBACEEAkAgAyAJRg0AQQUgCyANaiIHKAIAIAIQNiIDQf8BcUECRg0AIANBAXFFDQAgCUECdCALa0F8aiEDIAdBBGohBUEBIQYDQCAGIQQgA0UNAUEFIAUoAgAgAhA2IgdB/wFxQQJGDQEgBEEBaiEGIANBfGohAyAFQQRqIQUgB0EBcQ0ACwsgCEEUaiEHAkAgDyAEayIGRQ0AIAsgCUECdCIFayEDIAUgDWpBfGohBQNAIANFDQFBBSAFKAIAIAIQNiIJQf8BcUECRiAJQQFxRXINASADQQRqIQMgBUF8aiEFIAYgCkEBaiIKRw0ACwsgCCAIKAIEIARqNgIEIAggCCgCCCAKazYCCCAIIAgtABEgCkEAR3I6ABEgByAORw0BDAMLCyADIAkQqAEACyAJIAQQpgEACyABKAIAIQcgASgCCAshBiABQQA2AghBACEEQQAhAgJAA0AgBCAGTw0BIARBFGwhCSAEIAxqQRRsIQogByEFIAQhAwJAAkACQANAIAMgBk8NASAFIAlqIghBCGoiCygCACAIQQRqKAIARg0DIAIEQCADIAxqIgQgBk8NAyAFIApqIgQgCCkCADcCACAEQRBqIAhBEGooAgA2AgAgBEEIaiALKQIANwIACyAFQRRqIQUgA0EBaiIDIAZJDQALIAMhBAwEC0HUrsIAIAMgBhCnAQALQeSuwgAgBCAGEKcBAAsgA0EBaiEEIAxBf2ohDCACQQFqIQIgCEERai0AAEECRw0ACyADQQFqIQQLIAJFIAYgBE1yRQRAIAcgBEEUbGoiA0EAIAJrQRRsaiADIAYgBGtBFGwQmQEaCyABIAYgAmsiBDYCCCAEBEAgBkEUbCACQRRsayEFQQAhAwNAIAcgAzYCACAHQRRqIQcgA0EBaiEDIAVBbGoiBQ0ACwsgACABQTAQugEaC6IHAQV/IABBeGoiASAAQXxqKAIAIgNBeHEiAGohAkHg+cIAAn8CQAJAAkACQCADQQFxDQAgA0EDcUUNASABKAIAIgMgAGohACABIANrIgFBuPnCACgCAEYEQCACKAIEQQNxQQNHDQFBsPnCACAANgIAIAIgAigCBEF+cTYCBCABIABBAXI2AgQgACABaiAANgIADwsgA0GAAk8EQCABEFkMAQsgASgCDCIEIAEoAggiBUcEQCAFIAQ2AgwgBCAFNgIIDAELQaD2wgBBoPbCACgCAEF+IANBA3Z3cTYCAAsCQCACKAIEIgNBAnEEQCACIANBfnE2AgQgASAAQQFyNgIEIAAgAWogADYCAAwBCwJAIAJBvPnCACgCAEcEQEG4+cIAKAIAIAJHDQFBuPnCACABNgIAQbD5wgBBsPnCACgCACAAaiIANgIAIAEgAEEBcjYCBCAAIAFqIAA2AgAPC0G8+cIAIAE2AgBBtPnCAEG0+cIAKAIAIABqIgA2AgAgASAAQQFyNgIEQbj5wgAoAgAgAUYEQEGw+cIAQQA2AgBBuPnCAEEANgIAC0HY+cIAKAIAIgIgAE8NAkG8+cIAKAIAIgBFDQICQEG0+cIAKAIAIgNBKUkNAEHI+cIAIQEDQCABKAIAIgQgAE0EQCAEIAEoAgRqIABLDQILIAEoAggiAQ0ACwtB4PnCAAJ/Qf8fQdD5wgAoAgAiAEUNABpBACEBA0AgAUEBaiEBIAAoAggiAA0ACyABQf8fIAFB/x9LGws2AgAgAyACTQ0CQdj5wgBBfzYCAA8LIANBeHEiBCAAaiEAAkAgBEGAAk8EQCACEFkMAQsgAigCDCIEIAIoAggiAkcEQCACIAQ2AgwgBCACNgIIDAELQaD2wgBBoPbCACgCAEF+IANBA3Z3cTYCAAsgASAAQQFyNgIEIAAgAWogADYCACABQbj5wgAoAgBHDQBBsPnCACAANgIADAELIABBgAJJDQEgASAAEFFB4PnCAEHg+cIAKAIAQX9qIgA2AgAgAA0AQdD5wgAoAgAiAA0CQf8fDAMLDwsgAEEDdiICQQN0Qaj2wgBqIQACf0Gg9sIAKAIAIgNBASACQR9xdCICcQRAIAAoAggMAQtBoPbCACACIANyNgIAIAALIQIgACABNgIIIAIgATYCDCABIAA2AgwgASACNgIIDwtBACEBA0AgAUEBaiEBIAAoAggiAA0ACyABQf8fIAFB/x9LGws2AgALhwYBBn8CQAJAAkACQCACQQlPBEAgAiADEEQiAg0BQQAPC0EAIQIgA0HM/3tLDQJBECADQQtqQXhxIANBC0kbIQEgAEF8aiIGKAIAIgdBeHEhBAJAAkACQAJAIAdBA3EEQCAAQXhqIgggBGohBSAEIAFPDQFBvPnCACgCACAFRg0CQbj5wgAoAgAgBUYNAyAFKAIEIgdBAnENBiAHQXhxIgkgBGoiBCABTw0EDAYLIAFBgAJJIAQgAUEEcklyIAQgAWtBgYAIT3INBQwHCyAEIAFrIgJBEEkNBiAGIAEgB0EBcXJBAnI2AgAgASAIaiIBIAJBA3I2AgQgBSAFKAIEQQFyNgIEIAEgAhAvDAYLQbT5wgAoAgAgBGoiBCABTQ0DIAYgASAHQQFxckECcjYCACABIAhqIgIgBCABayIBQQFyNgIEQbT5wgAgATYCAEG8+cIAIAI2AgAMBQtBsPnCACgCACAEaiIEIAFJDQICQCAEIAFrIgNBD00EQCAGIAdBAXEgBHJBAnI2AgAgBCAIaiIBIAEoAgRBAXI2AgRBACEDDAELIAYgASAHQQFxckECcjYCACABIAhqIgIgA0EBcjYCBCAEIAhqIgEgAzYCACABIAEoAgRBfnE2AgQLQbj5wgAgAjYCAEGw+cIAIAM2AgAMBAsgBCABayECAkAgCUGAAk8EQCAFEFkMAQsgBSgCDCIDIAUoAggiBUcEQCAFIAM2AgwgAyAFNgIIDAELQaD2wgBBoPbCACgCAEF+IAdBA3Z3cTYCAAsgAkEQTwRAIAYgASAGKAIAQQFxckECcjYCACABIAhqIgEgAkEDcjYCBCAEIAhqIgMgAygCBEEBcjYCBCABIAIQLwwECyAGIAQgBigCAEEBcXJBAnI2AgAgBCAIaiIBIAEoAgRBAXI2AgQMAwsgAiAAIAMgASABIANLGxC6ARogABAhDAELIAMQAyIBRQ0AIAEgACADIAYoAgAiAUF4cUEEQQggAUEDcRtrIgEgASADSxsQugEgABAhDwsgAg8LIAALwQUBC38jAEEgayIGJAACQAJAAkAgASACRwRAIAFBAWohAyABLAAAIgVBf0oNAQJ/IAIgA0YEQCACDAELIAEtAAFBP3EhBCABQQJqIgMLIQEgBUEfcSEHIAVB/wFxQd8BTQRAIAQgB0EGdHIhAQwDCwJ/IAEgAkYEQCACDAELIAEtAABBP3EhCCABQQFqIgMLIQEgCCAEQQZ0ciEEIAVB/wFxQfABSQRAIAQgB0EMdHIhAQwDCyABIAJGBH9BAAUgAUEBaiEDIAEtAABBP3ELIAdBEnRBgIDwAHEgBEEGdHJyIgFBgIDEAEcNAgsgAEEANgIIIABCBDcCAAwCCyAFQf8BcSEBCyAGQQhqIAJBA2oiDCADa0ECdkEBahCgASAGKAIMIQUgBigCCCIIIAE2AgAgBkEBNgIYIAYgBTYCFCAGIAg2AhACQCACIANGDQBBBCEHQQEhAQNAIANBAWohBQJAAkAgAywAACIEQX9MBEACfyACIAVGBEBBACEJIAIMAQsgAy0AAUE/cSEJIANBAmoiBQshAyAEQR9xIQogBEH/AXEiDUHfAUsNASAJIApBBnRyIQQgBSEDDAILIARB/wFxIQQgBSEDDAELAn8gAiADRgRAQQAhCyACDAELIAMtAABBP3EhCyADQQFqIgULIQQgCyAJQQZ0ciEJIA1B8AFJBEAgCSAKQQx0ciEEIAUhAwwBCwJ/IAIgBEYEQCAFIQNBAAwBCyAEQQFqIQMgBC0AAEE/cQsgCkESdEGAgPAAcSAJQQZ0cnIiBEGAgMQARg0CCyAGKAIUIAFGBEAgBkEQaiABIAwgA2tBAnZBAWoQgwEgBigCECEICyAHIAhqIAQ2AgAgBiABQQFqIgE2AhggB0EEaiEHIAIgA0cNAAsLIAAgBikDEDcCACAAQQhqIAZBGGooAgA2AgALIAZBIGokAAuEBQEQfyMAQfAAayIJJAAgAEGIAmohDSAJQcgAaiEOIAFBMkkhD0EBIQQCQANAAkAgBCABTwRAQQAhBwwBCyAAIARB5ABsaiEDQQEhBwNAQQAhAgJAA0AgAkEEaiIIQShGDQEgAiADaiEFIAghAkF/IAVBXGooAgAiCCAFQUBrKAIAIgVHIAggBUgbIghFDQALIAhB/wFxQf8BRg0CCyADQeQAaiEDQQEhAiAEQQFqIgQgAUkhByABIARHDQALDAILIAEgBEYhAiAPIAEgBEZyDQECQAJAIARBf2oiAiABSQRAIAdBAXEEQCAKQQFqIQogCUEIaiAAIAJB5ABsaiICQeQAELoBGiACIAAgBEHkAGwiB2oiAkHkABC6ARogAiAJQQhqQeQAELoBIQUgACAEEEkgASAEayIMQQJJDQMgBUHkAGohCEHAACECA0AgAkEEaiIDQegARg0EIAIgBWohBiADIQJBfyAGKAIAIgMgBkHkAGooAgAiBkcgAyAGSBsiA0UNAAsgA0H/AXFB/wFHDQMgCUEIaiAFQeQAELoBGiAFIAhB5AAQugEhECAMQQNJDQIgByANaiEHQQIhAgNAIAJBAWogECACQeQAbGohA0EAIQIDQCACQQRqIgZBKEYNBCACIAdqIQsgAiAOaiERIAYhAkF/IBEoAgAiBiALKAIAIgtHIAYgC0gbIgZFDQALIAZB/wFxQf8BRw0DIANBnH9qIANB5AAQugEaIAdB5ABqIQcgAyEIIgIgDEcNAAsMAgtBwKfCACAEIAEQpwEAC0Gwp8IAIAIgARCnAQALIAggCUEIakHkABC6ARoLIApBBUcNAAtBACECCyAJQfAAaiQAIAILpQUBBn8gACgCACIJQQFxIgogBGohCAJAIAlBBHFFBEBBACEBDAELIAIEQCACIQcgASEFA0AgBiAFLQAAQcABcUGAAUZqIQYgBUEBaiEFIAdBf2oiBw0ACwsgAiAIaiAGayEIC0ErQYCAxAAgChshBgJAAkAgACgCCEEBRwRAQQEhBSAAIAYgASACELYBDQEMAgsgAEEMaigCACIHIAhNBEBBASEFIAAgBiABIAIQtgENAQwCCwJAIAlBCHFFBEAgByAIayEHQQAhBQJAAkACQEEBIAAtADAiCCAIQQNGG0EBaw4DAAEAAgsgByEFQQAhBwwBCyAHQQF2IQUgB0EBakEBdiEHCyAFQQFqIQUDQCAFQX9qIgVFDQIgACgCGCAAKAIEIAAoAhwoAhARAQBFDQALQQEPC0EBIQUgAEEBOgAwIABBMDYCBCAAIAYgASACELYBDQEgByAIayEGQQAhBQJAAkACQEEBIAAtADAiASABQQNGG0EBaw4DAAEAAgsgBiEFQQAhBgwBCyAGQQF2IQUgBkEBakEBdiEGCyAFQQFqIQUCQANAIAVBf2oiBUUNASAAKAIYIAAoAgQgACgCHCgCEBEBAEUNAAtBAQ8LIAAoAgQhAUEBIQUgACgCGCADIAQgACgCHCgCDBEDAA0BIAZBAWohBiAAKAIcIQIgACgCGCEAA0AgBkF/aiIGRQRAQQAPCyAAIAEgAigCEBEBAEUNAAsMAQsgACgCBCEIQQEhBSAAIAYgASACELYBDQAgACgCGCADIAQgACgCHCgCDBEDAA0AIAdBAWohBiAAKAIcIQEgACgCGCEAA0AgBkF/aiIGRQRAQQAPCyAAIAggASgCEBEBAEUNAAsLIAUPCyAAKAIYIAMgBCAAQRxqKAIAKAIMEQMAC5UFAg1/An4jAEHQAGsiAyQAAkAgASgCCCIErUIUfiIQQiCIp0UEQCAQpyIFQX9KBEACQAJAIAVFBEBBBCEIDAELIAVBBBDSASIIRQ0BCyABKAIAIQogBEUEQEEAIQVBACEEDAQLIAogBEEUbGohDSABQSBqKAIAIQ4gASgCGCEPQQAhBSAKIQkDQCAJKAIAIQcgA0EANgIYIAMgBzYCFCADQbSqwgA2AhAgAyAONgIMIAMgDzYCCCADIAI2AgQgAyAJNgIAIANBOGogAxAoAkAgAy0ASUECRg0AAkADQAJAIANBMGogA0HIAGoiCygCACIHNgIAIANBKGogA0FAayIMKQMAIhA3AwAgAyADKQM4IhE3AyAgCyAHNgIAIAwgEDcDACADIBE3AzgCQCAEIAZHBEAgBiEFDAELIARBAWoiBiAESQ0DIARBAXQiByAGIAcgBksbIgatQhR+IhBCIIinDQMgEKciB0EASA0DAn8gBEUEQCAHQQQQ0gEMAQsgCCAEQRRsQQQgBxDPAQsiCEUNASAGIQQLIAggBUEUbGoiBiADKQM4NwIAIAZBEGogCygCADYCACAGQQhqIAwpAwA3AgAgA0E4aiADECggBUEBaiIFIQYgAy0ASUECRw0BDAMLCyAHQQRB9PnCACgCACIAQQIgABsRAAAACxDOAQALIA0gCUEUaiIJRw0ACwwDCyAFQQRB9PnCACgCACIAQQIgABsRAAAACxDTAQALENMBAAsgASgCBARAIAoQIQsgASAFNgIIIAEgBDYCBCABIAg2AgAgBQRAIAVBFGwhBUEAIQQDQCAIIAQ2AgAgCEEUaiEIIARBAWohBCAFQWxqIgUNAAsLIAAgAUEwELoBGiADQdAAaiQAC8AFAgh/BH4jAEGwAWsiACQAAkBBqPXCACgCAA0AIABBEGpB5ANBARCVASAAKQMQIQggAEHMAGoiAUHkAzYCACAAIAg3AkQgAEEWNgJAIABBQGsQfCAAQThqIgQgAEHIAGopAwA3AwAgACAAKQNANwMwIABBQGtBFBBSIABBIGoiAyABKQIANwMAIABBKGoiBSAAQdQAaiIGKAIANgIAIAAgACkCRDcDGCAAQQhqQRRBABCVASAAKQMIIQggAEEUQQAQlQEgACkDACEJIAEgBCkDADcCACAGQQA2AgAgAEHYAGogACkDGDcDACAAQeAAaiADKQMANwMAIABB6ABqIAUoAgA2AgAgAEGIAWpBADYCACAAQYABaiIEIAk3AwAgAEH4AGpCADcDACAAQfAAaiAINwMAIABB7ABqQQA2AgAgAEEANgJAIAAgACkDMDcCRANAIABBQGsgAmoiASkCACEIIAEgAkGg9cIAaiIDKQIANwIAIAJBqPXCAGoiBSkCACEJIAJBsPXCAGoiBikCACEKIAJBuPXCAGoiBykCACELIAMgCDcCACABQRhqIgMpAgAhCCADIAs3AgAgAUEQaiIDKQIAIQsgAyAKNwIAIAFBCGoiASkCACEKIAEgCTcCACAHIAg3AgAgBiALNwIAIAUgCjcCACACQUBrIAJBIGohAkHNAEkNAAsgBCkCACEIIARB4PXCACkCADcCAEHg9cIAIAg3AgAgBEEIaiICKAIAIQEgAkHo9cIAKAIANgIAQej1wgAgATYCACAAKAJIIgFFDQAgAEHcAGooAgAhBCAAKAJYIQIgACgCcCEDIAAoAnQgACgChAEhBiAAKAKAASEHIAAoAkwEQCABECELIAIEQCAAQUBrIAJBAWoQogEgBBAhCwRAIAMQIQsgBkUNACAHECELIABBsAFqJABBoPXCAAv9BAEMfwJAAkACQAJAIAEoAgAiBEEIaigCACICIAQoAgQiBE8EQCABQQxqKAIAIgMgAkkNAUECIQMgASgCGCIGIAIgBGsiCE8NBAJAIAEoAggiBSAEQQJ0aiIKIAZBAnRqIgMgBSACQQJ0aiIJRgRAQQAhBQwBC0EAIQUDQCADQQRqIQYgASgCBCEHIAEoAhAhCyADKAIAIQxBACEDQQAhBAJ/A0BBASECIAMgC2otAAAgDCAHEDYiDUH/AXFBAkcEQCAEIQJBASANQQFxDQIaCyACIQQgA0EBaiIDQQNHDQALQQJBACACQQFxGwsiB0EBcUUgB0ECRnJFBEAgBUEBaiEFIAYiAyAJRw0BCwsgASgCGCEGCyABIAUgBmoiAjYCGCAIIAJJDQJBAiEDIAogAkECdGoiBiAJRg0EQQAhBQNAIAYiAkEEaiEGIAEoAgQhCCABKAIQIQogAigCACEHQQAhA0EAIQQCfwNAQQEhAiADIApqLQAAIAcgCBA2IgtB/wFxQQJHBEAgBCECQQEgC0EBcQ0CGgsgAiEEIANBAWoiA0EDRw0AC0ECQQAgAkEBcRsLIgdBAkdBACAHQQFxG0UEQCAFQQFqIQUgBiAJRw0BDAULCyAFDQNBAiEDDAQLIAQgAhCoAQALIAIgAxCmAQALIAIgCBCoAQALIAEoAhgiAyAFaiECIAMgASgCACIEKAIEIgdqIgkgBWohCCABKAIUIQZBASEDIAQtABFFBEAgAiAEQQhqKAIAIAdrSSEDCyABIAI2AhggAEEKOgAQIAAgBTYCDCAAIAg2AgggACAJNgIEIAAgBjYCACABIAZBAWo2AhQLIAAgAzoAEQuJBQILfwN+IwBBQGoiBiQAIAYgAjYCDEKlxoihyJyn+UshD0EBIQUgAiEEA0AgDyAErUL/AYOFQrODgICAIH4hDyAFQQRGRQRAIAZBDGogBWogBUEBaiEFLQAAIQQMAQsLIA9CGYinIgxB/wBxIgRBCHQgBHIiBEEQdCAEciENIAEoAgAiCCAPp3EhBSABKAIIIQkgASgCBCEEAkADQAJAIAQgBWooAAAiCyANcyIHQX9zIAdB//37d2pxQYCBgoR4cSIHRQ0AA0AgAiAJIAdoQQN2IAVqIAhxIg5BKGxqKAIARwRAIAdBf2ogB3EiBw0BDAILCyAJIA5BKGxqIgEpAwghDyABIAMpAwA3AwggACAPNwMAIAFBIGoiAikDACEPIAIgA0EYaikDADcDACABQRhqIgIpAwAhECACIAMpAxA3AwAgAUEQaiIBKQMAIREgASADKQMINwMAIABBGGogDzcDACAAIBA3AxAgACARNwMIDAILIApBBGoiCiAFaiAIcSEFIAsgC0EBdHFBgIGChHhxRQ0ACyAGQShqIANBGGopAgA3AgAgBkEgaiADQRBqKQIANwIAIAZBGGogA0EIaikCADcCACAGIAMpAgA3AhAgBCABIA8QlwEiBWotAABBAXEhAyABKAIMIgcgA0VyRQRAIAZBMGogARASIAEgDxCXASEFIAEoAgAhCCABKAIIIQkgASgCDCEHIAEoAgQhBAsgASAHIANrNgIMIAQgBWogDEH/AHEiAzoAACAIIAVBfGpxIARqQQRqIAM6AAAgCSAFQShsaiIDIAI2AgAgA0EEaiAGQQxqQSQQugEaIAEgASgCEEEBajYCECAAQQI6ABwLIAZBQGskAAvZBAERfyMAQRBrIggkAAJAIAEoAgQiAkUEQCAAQQA2AgAMAQsgASACQX9qNgIEIAEgASgCACIEQQRqNgIAIAEoAgghBwJAAkACQAJAAn8gAiAHIAcgAksbIAEoAhAiBUUNABoDQCACIAZqIgNBAUYNAiABIARBCGo2AgAgASAFIAZqQX9qNgIQIAEgA0F+ajYCBCAEQQRqIQQgBSAGQX9qIgZqDQALIAIgBmoiAiAHIAcgAksbCyIHQQFqIgZBAk8EQANAIAcgBkF/aiICSQ0FIAEoAgwhCSAIQqXGiKHInKf5SzcDCCAEIAIgCEEIahBxIAgoAggiBUEZdiIDQQh0IANyIgNBEHQgA3IhDyACQQJ0IRAgCSgCACILIAVxIQUgCSgCBCERQQAhDANAIAUgEWooAAAiDSAPcyIDQX9zIANB//37d2pxQYCBgoR4cSIDBEAgCSgCCCEOA0AgDiADaEEDdiAFaiALcSISQRhsaiIKKAIIIAJGBEAgBCAKKAIAIgpGDQcgBCAKIBAQtQFFDQcLIANBf2ogA3EiAw0ACwsgDEEEaiIMIAVqIAtxIQUgDSANQQF0cUGAgYKEeHFFDQALIAIhBiACQQFLDQALCyAHRQ0CIABBATYCBCAAIAQ2AgAgAEEMakEBNgIAIABBCGogBDYCAAwECyAAQQA2AgAMAwsgASAGQX5qNgIQIAAgAjYCBCAAIAQ2AgAgAEEMaiAOIBJBGGxqIgEoAhQ2AgAgAEEIaiABKAIMNgIADAILQQFBABCmAQALIAIgBxCmAQALIAhBEGokAAvBBAINfwF+IwBBIGsiByQAIAFBf2ohDCABQTJJIQ1BASEDAkACQAJ/A0ACf0EAIAMgAU8NABpBASAAIANBDGxqIgRBAyAEQXRqQQMQlAFB/wFxQf8BRg0AGgNAQQEgAyAMRg0DGiADQQFqIQMgBEEMaiICQQMgBEEDEJQBIAIhBEH/AXFB/wFHDQALIAMgAUkLIAEgA0YgDSABIANGcg0BGiADQX9qIgIgAU8NAkUNAyAKQQFqIQogACADQQxsaiIFKQIAIQ8gBSAAIAJBDGxqIgIpAgA3AgAgAiAPNwIAIAVBCGoiBigCACEEIAYgAkEIaiICKAIANgIAIAIgBDYCACAAIAMQTgJAIAEgA2siC0ECSQ0AIAVBDGoiCEEDIAVBAxCUAUH/AXFB/wFHDQAgBSkCACEPIAUgCCkCADcCACAHQRhqIg4gBigCADYCACAGIAhBCGooAgA2AgAgByAPNwMQAkAgC0EDSQ0AIAVBGGoiCUEDIAdBEGpBAxCUAUH/AXFB/wFHDQBBAyECQQIhBgNAIAZBDGwgBWoiCEF0aiIEIAkpAgA3AgAgBEEIaiAJQQhqKAIANgIAIAIgC08NASACQQFqIgQgAkkNASACQQxsIQkgAiEGIAQhAiAFIAlqIglBAyAHQRBqQQMQlAFB/wFxQf8BRg0ACwsgCCAHKQMQNwIAIAhBCGogDigCADYCAAsgCkEFRw0AC0EACyAHQSBqJAAPC0Gwp8IAIAIgARCnAQALQcCnwgAgAyABEKcBAAvZBAIPfwF+IwBBMGsiBCQAIARCpcaIocicp/lLNwMIIAIoAgAiCiACKAIIIgwgBEEIahBxIAQpAwgiE0IZiKciDkH/AHEiBUEIdCAFciIFQRB0IAVyIQ8gDEECdCEQIAEoAgAiCCATp3EhByABKAIIIQkgASgCBCEFAkADQAJAIAUgB2ooAAAiDSAPcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAJIAZoQQN2IAdqIAhxIhFBGGxqIgsoAgggDEYEQCAKIAsoAgAiC0YNASAKIAsgEBC1AUUNAQsgBkF/aiAGcSIGDQEMAgsLIAAgCSARQRhsaiIBKQIMNwIAIAEgAykCADcCDCAAQQhqIAFBFGoiACgCADYCACAAIANBCGooAgA2AgAgAigCBEUNAiAKECEMAgsgEkEEaiISIAdqIAhxIQcgDSANQQF0cUGAgYKEeHFFDQALIARBEGoiByACQQhqKAIANgIAIARBHGogA0EIaigCADYCACAEIAIpAgA3AwggBCADKQIANwIUIAUgASATEJcBIgJqLQAAQQFxIQMgASABKAIMIgYgA0VyBH8gBgUgBEEgaiABEA8gASATEJcBIQIgASgCACEIIAEoAgghCSABKAIEIQUgASgCDAsgA2s2AgwgAiAFaiAOQf8AcSIDOgAAIAggAkF8anEgBWpBBGogAzoAACAJIAJBGGxqIgJBEGogBEEYaikDADcCACACQQhqIAcpAwA3AgAgAiAEKQMINwIAIAEgASgCEEEBajYCECAAQQA2AgALIARBMGokAAulBAEUfwJAIAJBAXUiCCACSQRAQQAgACgCGGshFCAAKAIQIhIgACgCBGpBf2ohCSAAQQxqIABBCGogACgCAEEBRhshFSACIREDQAJAAkAgASAIQRhsaiIWKAIEIgcgBGtFDQAgFCAEayEXQQAgBGshCyAHIQwgCSENIBIhDiAEIQUCQAJAAkADQEF/IQ8gDiAXakUNAyALIA5qQX9qIBUoAgAiD08NASALIAxqQX9qIAdPDQIgCyANai0AACAWKAIAIAtqIAxqQX9qLQAAayIPDQMgDUF/aiENIA5Bf2ohDiAFQQFqIQUgCyAMQX9qIgxqDQALIAUhBAwDC0GkxsIAIARBf3MgDmogDxCnAQALQbTGwgAgBEF/cyAMaiAHEKcBAAsgBSEEDAELQQAhDwsgCiAEIA9BAEgiBxshCgJAIAggESAHGyIRIAYgCCAHGyIGayINQQJODQAgEyAGIBFGIAZBAEpyckEBIRNBAXFFDQACfwNAIAYgAk8NBQJAIAogASAGQRhsaiIJKAIEIgVPBEAgACASIAVrIgQ2AhAgCSgCECIFRQ0BIAUgACADIAlBFGooAgAoAgwRAwAgACAENgIQDQELQQAgCSgCCCIGQQBIDQIaDAELCyABIAZBGGxqKAIMCw8LIAogBCAQIAcbIhAgCiAQSRshBCANQQF1IAZqIgggAkkNAAsLQZTGwgAgCCACEKcBAAtBxMbCACAGIAIQpwEAC88FAgR/AX5BASABKAIYQScgAUEcaigCACgCEBEBAEUEQEECIQICQAJAAkAgACgCACIAQXdqIgRBHksEQCAAQdwARw0BDAILQfQAIQMCQAJAIARBAWsOHgECAgACAgICAgICAgICAgICAgICAgICAgMCAgICAwQLQfIAIQMMAwtB7gAhAwwCCwJAAn5BgIbAACAAEFpFBEACQAJAIABBgIAETwRAIABBgIAISQ0BIABBkPxHakGQ/AtJIABB4ot0akHijSxJciAAQZ+odGpBnxhJIABB3uJ0akEOSXJyIABB/v//AHFBnvAKRiAAQamydWpBKUlycg0CIABBy5F1akEKSw0FDAILIABB+JHAAEEpQcqSwABBpQJB75TAAEG6AhBKRQ0BDAQLIABBqZfAAEEjQe+XwABBpgFBlZnAAEGYAxBKDQMLIABBAXJnQQJ2QQdzrUKAgICA0ACEDAELIABBAXJnQQJ2QQdzrUKAgICA0ACECyEGQQMhAgwBC0EBIQILIAAhAwsDQCACIQRB3AAhAEEBIQICQAJAAkACQCAEQQFrDgMCAwABCwJAAkACQAJAAkAgBkIgiKdB/wFxQQFrDgUEAwIBAAULIAZC/////49gg0KAgICAwACEIQZBAyECDAYLIAZC/////49gg0KAgICAMIQhBkH1ACEAQQMhAgwFCyAGQv////+PYINCgICAgCCEIQZB+wAhAEEDIQIMBAsgAyAGpyICQQJ0QRxxdkEPcSIAQTByIABB1wBqIABBCkkbIQAgAgRAIAZCf3xC/////w+DIAZCgICAgHCDhCEGQQMhAgwECyAGQv////+PYINCgICAgBCEIQZBAyECDAMLIAZC/////49ggyEGQf0AIQBBAyECDAILIAEoAhhBJyABKAIcKAIQEQEADwtBACECIAMhAAsgASgCGCAAIAEoAhwoAhARAQBFDQALCwuHBQEEfyAAIAFqIQICQAJAAkAgACgCBCIDQQFxDQAgA0EDcUUNASAAKAIAIgMgAWohASAAIANrIgBBuPnCACgCAEYEQCACKAIEQQNxQQNHDQFBsPnCACABNgIAIAIgAigCBEF+cTYCBCAAIAFBAXI2AgQgAiABNgIADwsgA0GAAk8EQCAAEFkMAQsgACgCDCIEIAAoAggiBUcEQCAFIAQ2AgwgBCAFNgIIDAELQaD2wgBBoPbCACgCAEF+IANBA3Z3cTYCAAsgAigCBCIDQQJxBEAgAiADQX5xNgIEIAAgAUEBcjYCBCAAIAFqIAE2AgAMAgsCQCACQbz5wgAoAgBHBEBBuPnCACgCACACRw0BQbj5wgAgADYCAEGw+cIAQbD5wgAoAgAgAWoiATYCACAAIAFBAXI2AgQgACABaiABNgIADwtBvPnCACAANgIAQbT5wgBBtPnCACgCACABaiIBNgIAIAAgAUEBcjYCBCAAQbj5wgAoAgBHDQFBsPnCAEEANgIAQbj5wgBBADYCAA8LIANBeHEiBCABaiEBAkAgBEGAAk8EQCACEFkMAQsgAigCDCIEIAIoAggiAkcEQCACIAQ2AgwgBCACNgIIDAELQaD2wgBBoPbCACgCAEF+IANBA3Z3cTYCAAsgACABQQFyNgIEIAAgAWogATYCACAAQbj5wgAoAgBHDQFBsPnCACABNgIACw8LIAFBgAJPBEAgACABEFEPCyABQQN2IgJBA3RBqPbCAGohAQJ/QaD2wgAoAgAiA0EBIAJBH3F0IgJxBEAgASgCCAwBC0Gg9sIAIAIgA3I2AgAgAQshAiABIAA2AgggAiAANgIMIAAgATYCDCAAIAI2AggLzQQCCX8BfiMAQRBrIgQkACAEIAE2AgAgASgCGCEDIAQgAS0AHDoAByAEIAQ2AgwgBCAEQQdqNgIIAkACQCABLQAdRQRAIAFBDGohCCADQQF0IQkDQCABKAIEIQUgASgCACECAkADQCACIAVGDQEgASACQQRqIgc2AgAgAigCACABIAEoAggiCkEBajYCCCAHIQJFDQALIAEoAhQiAiABKAIQRgRAIAJBAWoiBSACSQ0FIAJBAXQiBiAFIAYgBUsbIgVB/////wFxIAVHDQUgBUEDdCIGQQBIDQUCfyACRQRAIAZBBBDSAQwBCyAIKAIAIAJBA3RBBCAGEM8BCyICRQ0EIAEgBTYCECABIAI2AgwgASgCFCECCyABKAIMIAJBA3RqIgIgB0F8ajYCBCACIAo2AgAgASABKAIUQQFqIgI2AhQgAiAJSQ0BIARBCGogCBALIAEoAhQgA0kNASABIAM2AhQMAQsLIARBCGogCBALIAEoAhQiAiADTwRAIAEgAzYCFCADIQILIAJBAXYiBwRAIAJBA3QgCCgCACICakF4aiEDA0AgAikCACELIAIgAykCADcCACADIAs3AgAgAkEIaiECIANBeGohAyAHQX9qIgcNAAsLIAFBAToAHQsCQCABQRRqKAIAIgJFBEBBACECDAELIAEgAkF/aiICNgIUIAEoAgwgAkEDdGoiASgCBCECIAEoAgAhAQsgACACNgIEIAAgATYCACAEQRBqJAAPCyAGQQRB9PnCACgCACIAQQIgABsRAAAACxDOAQALmAQBCH8jAEEQayIDJAAgAyAANgIAIAAoAhQhAiADIAAtABg6AAcgAyADNgIMIAMgA0EHajYCCAJAAkAgAC0AGUUEQCAAQQhqIQcgACgCACIEIAAoAgRHBEAgAkEBdCEIIABBEGooAgAhAQNAIAAgBEE8ajYCACAAKAIMIAFGBEAgAUEBaiIGIAFJDQUgAUEBdCIFIAYgBSAGSxsiBkH/////A3EgBkcNBSAGQQJ0IgVBAEgNBQJ/IAFFBEAgBUEEENIBDAELIAcoAgAgAUECdEEEIAUQzwELIgFFDQQgACAGNgIMIAAgATYCCCAAKAIQIQELIAAoAgggAUECdGogBDYCACAAIAAoAhBBAWoiATYCEAJAIAEgCEkNACADQQhqIAcQDSAAKAIQIgEgAkkNACAAIAI2AhAgAiEBCyAAKAIAIgQgACgCBEcNAAsLIANBCGogBxANIABBEGooAgAiASACTwRAIAAgAjYCECACIQELIAFBAXYiBQRAIAFBAnQgBygCACIBakF8aiEEA0AgASgCACECIAEgBCgCADYCACAEIAI2AgAgAUEEaiEBIARBfGohBCAFQX9qIgUNAAsLIABBAToAGQsCf0EAIABBEGooAgAiAkUNABogACACQX9qIgI2AhAgACgCCCACQQJ0aigCAAsgA0EQaiQADwsgBUEEQfT5wgAoAgAiAEECIAAbEQAAAAsQzgEAC5sEAg9/AX4jAEEgayIDJAAgA0KlxoihyJyn+Us3AxAgASgCACIHIAEoAggiCyADQRBqEHEgAykDECISQhmIpyINQf8AcSIEQQh0IARyIgRBEHQgBHIhDiALQQJ0IQ8gACgCACIIIBKncSEFIAAoAgghCSAAKAIEIQQCQANAAkAgBCAFaigAACIMIA5zIgZBf3MgBkH//ft3anFBgIGChHhxIgZFDQADQAJAIAkgBmhBA3YgBWogCHEiEEEEdGoiCigCCCALRgRAIAcgCigCACIKRg0BIAcgCiAPELUBRQ0BCyAGQX9qIAZxIgYNAQwCCwsgCSAQQQR0aiACOgAMIAEoAgRFDQIgBxAhDAILIBFBBGoiESAFaiAIcSEFIAwgDEEBdHFBgIGChHhxRQ0ACyADQQhqIgYgAUEIaigCADYCACADIAEpAgA3AwAgBCAAIBIQlwEiAWotAABBAXEhBSAAIAAoAgwiByAFRXIEfyAHBSADQRBqIAAQEyAAIBIQlwEhASAAKAIAIQggACgCCCEJIAAoAgQhBCAAKAIMCyAFazYCDCABIARqIA1B/wBxIgU6AAAgCCABQXxqcSAEakEEaiAFOgAAIAkgAUEEdGoiAUEIaiAGKAIANgIAIAEgAykDADcCACABIAI6AAwgASADLwAQOwANIAFBD2ogA0ESai0AADoAACAAIAAoAhBBAWo2AhALIANBIGokAAvqAwEHfyMAQSBrIgMkAAJAAkAgACgCECIBIAAoAhRPDQAgACgCBCECIAMgAEEMaiAAQQhqIAAoAgBBAUYbKAIAIgQ2AgQgAyACNgIAIAMgATYCCCADIAQ2AgwCQCABRSABIARGckUEQCAEIAFNDQEgASACaiwAAEG/f0wNAQsgASAERg0BAkAgASACaiIFLAAAIgdBf0oEQCAHQf8BcSEBDAELAn8gAiAEaiIBIAVBAWpGBEAgAQwBCyAFLQABQT9xIQYgBUECagshAiAHQR9xIQUgB0H/AXFB3wFNBEAgBiAFQQZ0ciEBDAELAn8gASACRgRAIAEhBEEADAELIAJBAWohBCACLQAAQT9xCyAGQQZ0ciEGIAdB/wFxQfABSQRAIAYgBUEMdHIhAQwBC0EAIQIgASAERwR/IAQtAABBP3EFQQALIAVBEnRBgIDwAHEgBkEGdHJyIgFBgIDEAEYNAwsgAUHhAEkgAUH6AUtyDQEgAUHhAGsiBEEDdiIBQRRJBEBBACECIAFBoMfCAGotAABBASAEQQdxdHFFDQMgABCQAUEBIQIMAwtB1MTCACABQRQQpwEACyADIANBDGo2AhggAyADQQhqNgIUIAMgAzYCECADQRBqEMcBAAtBACECCyADQSBqJAAgAgvlAwEHfyMAQSBrIgIkAAJAAkACQCAAKAIQIgEgACgCFE8NACAAKAIEIQMgAiAAQQxqIABBCGogACgCAEEBRhsoAgAiBDYCBCACIAM2AgAgAiABNgIIIAIgBDYCDCABRSABIARGckUEQCAEIAFNDQMgASADaiwAAEG/f0wNAwsgASAERg0AAkAgASADaiIFLAAAIgdBf0oEQCAHQf8BcSEBDAELAn8gAyAEaiIBIAVBAWpGBEAgAQwBCyAFLQABQT9xIQYgBUECagshAyAHQR9xIQUgB0H/AXFB3wFNBEAgBiAFQQZ0ciEBDAELAn8gASADRgRAIAEhBEEADAELIANBAWohBCADLQAAQT9xCyAGQQZ0ciEDIAdB/wFxQfABSQRAIAMgBUEMdHIhAQwBC0EAIQYgASAERwR/IAQtAABBP3EFQQALIAVBEnRBgIDwAHEgA0EGdHJyIgFBgIDEAEYNAQsgAUHhAEkgAUH6AUtyRQRAIAFB4QBrIgRBA3YiAUEUTw0CQQAhBiABQaDHwgBqLQAAQQEgBEEHcXRxDQELIAAQkAFBASEGCyACQSBqJAAgBg8LQdTFwgAgAUEUEKcBAAsgAiACQQxqNgIYIAIgAkEIajYCFCACIAI2AhAgAkEQahDHAQAL/gMBBn8jAEEwayIFJAAgBUEIaiAAQQxqIgcgAEEIaiIIIAAoAgBBAUYbKAIAELEBIAVBADYCGCAFIAUpAwg3AxAgACgCACEJIAAoAgQhBgJAAkACQCABRQ0AIAcgCCAJQQFGGygCACIKIAFGDQAgCiABTQ0BIAEgBmosAABBv39MDQELIAJFIAcgCCAJGygCACIHIAJGckUEQCAHIAJNDQIgAiAGaiwAAEG/f0wNAgsgBUEQakEAIAEQkQEgBSAFKAIYIgggAWoiCTYCGCAIIAUoAhBqIAYgARC6ARogBUEQaiAJIAQQkQEgBSAFKAIYIgggBGoiCTYCGCAIIAUoAhBqIAMgBBC6ARogBUEQaiAJIAYgB2ogAiAGaiIGayIDEJEBIAUgBSgCGCIHIANqNgIYIAcgBSgCEGogBiADELoBGiAAIAEgAmsgBGoiBCAAKAIUajYCFAJAAkAgACgCECIDIAJJBEAgAyABSw0BDAILIAMgBGohAQsgACABNgIQCyAAQQRqIQEgBUEoaiICIAVBGGooAgA2AgAgBSAFKQMQNwMgAkAgACgCAEUNACAAQQhqKAIARQ0AIAEoAgAQIQsgAEEBNgIAIAEgBSkDIDcCACABQQhqIAIoAgA2AgAgBUEwaiQADwsgBiAKQQAgARAaAAsgBiAHQQAgAhAaAAuTBAEBf0EBIQMCQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCAAQf8BcUEBaw4HAAECAwQFBgwLQQAhAyABQb8BSw0LIAFBBnZB0KzAAGotAAAiAEEBTQRAIABBA3RB2KzAAGopAwBCASABQT9xrYaDQgBSDwtBkKzAACAAQQIQpwEACyABQXdqQQVJDQkgAUEgRg0KQQAhAyABQYABSSABQb/gAEtyDQogAUEGdkHNqsAAai0AACIAQQVNBEAgAEEDdEGgrMAAaikDAEIBIAFBP3GthoNCAFIPC0GQrMAAIABBBhCnAQALQQAhAyABQZDAAEwEQCABQV9qIgBBDU0NBSABQUZqIgBBBUsNCiAAQQJrDgMKCgoJCyABQe+/f2pBBEkNCCABQcS/f2oiAEENS0EBIAB0QYHwAHFFcg0HDAgLQQAhAyABQV9xQb9/akEaSQ0IQQEhAyABQYABSQ0IQdjBwAAgARBaQQFzDwtBACEDIAFBX3FBv39qQRpJDQcgAUGAAUkNA0HYwcAAIAEQWg0HQQEhA0HorMAAIAEQWkUNBwwEC0ECIQMgAiABEFdB/wFxIgBBCEYNBiAAQQZGDwtBAiEDIAIgARBXQf8BcSIAQQhGDQUgAEEHRg8LIABBAWsOCgQEBAQDBAMDBAQDC0EBIQMgAUFQakEKTw0DC0EADwsgAUGmwABHDQELQQEPCyADC+4DAQd/IwBBMGsiBCQAIAEoAmRFBEACQCABQX82AmQgASgCdA0AIAFB6ABqIQcgAUGAAWpBADYCACABQfAAakKAgICAcDcCACAEQQA2AiggBCABQUBrNgIkIARBAjYCICAEIAM2AhwgBCACNgIYIAFB+ABqIQggBEEIaiAEQRhqECogBCgCCCIFBEADQCAEKAIQIQYgBCgCFCEJIAcgBSAFIAQoAgwiCkECdGoQuAEgCCAGIAYgCUECdGoQuAEgCSAKayIGBEADQCABKAJwIgUgASgCbEYEQCAHIAVBARCDASABKAJwIQULIAEoAmggBUECdGpBADYCACABIAEoAnBBAWo2AnAgBkF/aiIGDQALCyAEQQhqIARBGGoQKiAEKAIIIgUNAAsLAkACQCADIAEoAoABRgRAIAEoAngiBSACRg0BIAUgAiADQQJ0ELUBRQ0BCyAEQRhqIAcQqwEgBEEIaiAIEKsBIARBLGogBEEQaigCADYCACAEIAQpAwg3AiQgAEEQaiAEQShqKQMANwIAIABBCGogBEEgaikDADcCACAAIAQpAxg3AgAMAQsgAEEANgIACyABIAEoAnRBAWo2AnQgASABKAJkQQFqNgJkIARBMGokAA8LC0GixMIAQRAgBEEYakG4r8IAEJYBAAvKAwIOfwF+IAFBf2ohCyAAQQxqIQwgAUEySSENQQEhAwJAAkACfwNAAn9BACADIAFPDQAaQQEgACADQQN0IgZqIgJBfGooAgAoAgAgAigCBCgCACICSQ0AGiAGIAxqIQUDQEEBIAMgC0YNAxogA0EBaiEDIAIgBSgCACgCACICTyAFQQhqIQUNAAsgAyABSQsgASADRiANIAEgA0ZyDQEaIANBf2oiAiABTw0CRQ0DIAhBAWohCCAAIAJBA3RqIgIpAgAhECACIAAgA0EDdGoiBCkCADcCACAEIBA3AgAgACADEGoCQCABIANrIgpBAkkNACAEKAIEIgkoAgAgBEEMaigCACgCAE8NACAEKAIAIQ4gBCAEKQIINwIAIARBCGohBwJAIApBA0kNACAJKAIAIARBFGooAgAoAgBPDQBBAyECQQIhBQNAIAQgBUEDdGoiB0F4aiAHKQIANwIAIAIgCk8NASACQQFqIgYgAkkNASACQQN0IQ8gAiEFIAYhAiAJKAIAIAQgD2ooAgQoAgBJDQALCyAHIAk2AgQgByAONgIACyAIQQVHDQALQQALDwtBsKfCACACIAEQpwEAC0HAp8IAIAMgARCnAQAL3QMBBX8CQCABQSBqKAIAIgRFDQAgBEECdCEFIAEoAhgiBiEDA0ACQCADKAIAIgJBv39qQRpJDQAgAkGAAU8EQEH45cAAIAIQWg0BCyADQQRqIQMgBUF8aiIFDQEMAgsLIARBAnQhBQNAIAYoAgAiAyEEIAYgAyADQQBBtwUgA0HsPEkbIgIgAkHbAmoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBrgFqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQdcAaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEraiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEWaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkELaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEFaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEDaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEBaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkEBaiICIAJBBHRB0PjAAGooAgAgA0sbQQR0IgJB0PjAAGooAgBGBEAgAkHU+MAAaigCACEECyAEIARBgIDEAEYbNgIAIAZBBGohBiAFQXxqIgUNAAsLIAAgAUEwELoBGgvIAwILfwF+IwBBEGsiBiQAIAYgATYCAEKlxoihyJyn+UshDkEBIQQgASEDA0AgDiADrUL/AYOFQrODgICAIH4hDiAEQQRHBEAgBCAGaiAEQQFqIQQtAAAhAwwBCwsgDkIZiKciDEH/AHEiA0EIdCADciIDQRB0IANyIQ0gACgCACIHIA6ncSEEIAAoAgghCSAAKAIEIQMCQANAAkAgAyAEaigAACIKIA1zIgVBf3MgBUH//ft3anFBgIGChHhxIgVFDQADQCABIAkgBWhBA3YgBGogB3FBA3RqIgsoAgBHBEAgBUF/aiAFcSIFDQEMAgsLIAstAAQaIAsgAjoABAwCCyAIQQRqIgggBGogB3EhBCAKIApBAXRxQYCBgoR4cUUNAAsgAyAAIA4QlwEiBGotAABBAXEhBSAAKAIMIgggBUVyRQRAIAYgABARIAAgDhCXASEEIAAoAgAhByAAKAIIIQkgACgCDCEIIAAoAgQhAwsgACAIIAVrNgIMIAMgBGogDEH/AHEiBToAACAHIARBfGpxIANqQQRqIAU6AAAgCSAEQQN0aiIDIAI6AAQgAyABNgIAIAAgACgCEEEBajYCEAsgBkEQaiQAC6oDAQR/IwBBQGoiAyQAAkACQAJAIAEoAggiBUEBTQRAIAVBAWsNAyADQQhqIAIgASgCDCIEIAFBFGooAgAQZiABQQxqIQUgAygCCA0BIAFBIGooAgAhBAwCCxDRAQALIANBIGoiBiADQRBqKAIANgIAIAMgAykDCDcDGCADQShqIANBGGoQqwEgAUEQaigCAARAIAQQIQsgBSADKQMoNwIAIAVBCGogA0EwaiIEKAIANgIAIAQgBigCADYCACADIAMpAxg3AyggAUEYaiEEIAFBHGooAgAEQCAEKAIAECELIAQgAykDKDcCACAEQQhqIANBMGooAgA2AgAgASgCAEEIaiABQSBqKAIAIgQ2AgALIANBKGogAiABKAIYIgIgBBA3IAMoAigEQCABQRBqKAIABEAgBSgCABAhCyAFIAMpAyg3AgAgBUEIaiADQTBqKAIANgIAIAFBHGooAgAEQCACECELIAFBGGoiAkEIaiADQTRqIgVBCGooAgA2AgAgAiAFKQIANwIAIAEoAgBBCGogASgCIDYCAAsLIAAgAUEwELoBGiADQUBrJAALsgMBDn8gAUF/aiEMIABBBGohDSABQTJJIQ5BASEDAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0ECdCIEaiIHKAIAIgIgB0F8aigCAEkNABogBCANaiEFA0BBASADIAxGDQMaIANBAWohAyAFKAIAIgQgAk8gBUEEaiEFIAQhAg0ACyADIAFJCyEFIAEgA0YgDiABIANGcg0BGgJAAkACQAJAIANBf2oiAiABSQRAIAVFDQEgCUEBaiEJIAAgAkECdGoiAigCACEEIAIgACADQQJ0aiIGKAIANgIAIAYgBDYCACAAIAMQciABIANrIgtBAkkNBCAGKAIEIgIgBigCACIKTw0EIAZBBGohBCAGIAI2AgAgC0EDSQ0DIAYoAggiCCAKTw0DIAZBCGohBUEDIQJBAiEHA0AgB0ECdCAGakF8aiAINgIAIAIgC08NAyACQQFqIgggAkkNAyAFIQQgBiACQQJ0aiIPIQUgAiEHIAghAiAPKAIAIgggCkkNAAsMAwtBsKfCACACIAEQpwEAC0HAp8IAIAMgARCnAQALIAUhBAsgBCAKNgIACyAJQQVHDQALQQALC6oDAQ9/IAAoAgQhDSAAKAIUIQ4gACgCECELIABBDGogAEEIaiAAKAIAQQFGGyEPQQEhB0EDIQoCQANAAkACQCADIAEgB0EYbGoiECgCBCIGTw0AAkADQCAOIAMgC2oiBEYEQEF/IQQMBAsgBCAPKAIAIhFPDQEgBCANai0AACAQKAIAIANqLQAAayIEDQMgA0EBaiIDIAZHDQALIAYhAwwBC0H0xcIAIAQgERCnAQALQQAhBAsgCCADIARBAEgiBhshCAJAIAcgCiAGGyIKIAUgByAGGyIFayIEQQJODQAgDCAFIApGIAVBAEpyckEBIQxBAXFFDQACfwNAIAVBAksNBAJAIAggASAFQRhsaiIDKAIEIgZPBEAgACAGIAtqIgY2AhAgAygCECIERQ0BIAQgACACIANBFGooAgAoAgwRAwAgACAGNgIQDQELQQAgAygCCCIFQQBIDQIaDAELCyABIAVBGGxqKAIMCw8LIAMgCSAGGyIJIAggCCAJSxshAyAEQQF1IAVqIgdBA0kNAAtB5MXCACAHQQMQpwEAC0GExsIAIAVBAxCnAQALsgMBDX8gAUF/aiELIABBBGohDCABQTJJIQ1BASEDAkACQAJ/A0ACf0EAIAMgAU8NABpBASAAIANBAnQiB2oiBCgCACICIARBfGooAgAQsgENABogByAMaiEGA0BBASADIAtGDQMaIANBAWohAyAGKAIAIgQgAhCyASAGQQRqIQYgBCECRQ0ACyADIAFJCyABIANGIA0gASADRnINARogA0F/aiICIAFPDQJFDQMgCEEBaiEIIAAgAkECdGoiBCgCACECIAQgACADQQJ0aiIFKAIANgIAIAUgAjYCACAAIAMQfQJAIAEgA2siCkECSQ0AIAUoAgQiAiAFKAIAIgkQsgFFDQAgBUEEaiEGIAUgAjYCAAJAIApBA0kNACAFKAIIIAkQsgFFDQAgBUEIaiEEQQMhAkECIQcDQCAHQQJ0IAVqQXxqIAQiBigCADYCACACIApPDQEgAkEBaiIEIAJJDQEgAkECdCEOIAIhByAEIQIgBSAOaiIEKAIAIAkQsgENAAsLIAYgCTYCAAsgCEEFRw0AC0EACw8LQbCnwgAgAiABEKcBAAtBwKfCACADIAEQpwEAC7wDAgt/A34jAEEgayIFJAACQAJAAkAgAUECSQ0AIABBKGoiAygCACAAKAIIIghPDQAgACkDACENIAAgACkDIDcDACAAQRRqKQIAIQ4gACkCDCEPIABBEGogAEEwaikDADcDACAAQQhqIAMpAwA3AwAgAEEcaigCACEDIABBGGogAEE4aikDADcDACAFQRBqIgogDjcDACAFQRhqIgsgAzYCACAFIA83AwggAEEgaiECQQMhBkEBIQcCQCABQQNJDQBBAiEJA0AgCSIDIAFPDQMgACADQQV0aiIEKAIIIAhPDQEgA0F/aiICIAFPDQQgACACQQV0aiICIAQpAwA3AwAgAkEYaiAEQRhqKQMANwMAIAJBEGogBEEQaikDADcDACACQQhqIARBCGopAwA3AwAgBiABSQRAIAYhCSAGQQFqIgIgBkkgAiEGIAMhByAEIQINAgwBCwsgAyEHIAQhAgsgAiANNwMAIAAgB0EFdGogCDYCCCACQRxqIAsoAgA2AgAgAkEUaiAKKQMANwIAIAIgBSkDCDcCDAsgBUEgaiQADwtB0KzCACADIAEQpwEAC0HgrMIAIAIgARCnAQALxgMCCH8BfiMAQSBrIgIkAAJAEMEBIgZFDQACQCAGKAIARQRAIAZBfzYCACAGQQRqIAEQViIFRQ0BIAUoAggiBARAIARBBHQhByAFKAIAQQxqIQEDQCABKAIAIANqIQMgAUEQaiEBIAdBcGoiBw0ACwsgAkEIaiADIARqELEBIAJBADYCGCACIAIoAgw2AhQgAiACKAIIIgQ2AhAgBSgCCCIBBEAgAUEEdCEHIAUoAgBBDGohA0EAIQEDQCADQXhqKAIAIQggAkEQaiABIAMoAgAiBRCRASACIAUgAigCGCIJaiIBNgIYIAkgAigCECIEaiAIIAUQugEaIAIoAhQgAUYEQCACQRBqIAFBARCRASACKAIQIQQgAigCGCEBCyABIARqQQA6AAAgAiABQQFqIgE2AhggA0EQaiEDIAdBcGoiBw0ACwsgAikCFCEKIAYgBigCAEEBajYCACAERQ0CIAIgCjcCFCACIAQ2AhAgAiACQRBqEI4BIAAgAikDADcCACACQSBqJAAPC0GixMIAQRAgAkEQakG0xMIAEJYBAAtB3/LCAEErQYzzwgAQuQEAC0Hcw8IAQcYAIAJBEGpBxMTCABCWAQALnwMBA38jAEHQAGsiBSQAIAUgAjYCQCAFIAI2AjwgBSABNgI4IAVBEGogBUE4ahCOASAFKAIUIQcgBSgCECEGIAUgBDYCQCAFIAQ2AjwgBSADNgI4IAVBCGogBUE4ahCOASAFKAIMIQQgBSgCCCEDEMIBIgEEQAJAIAEoAgBFBEAgAUF/NgIAIAFBBGogABBVIgBFDQEgBUEYaiAGIAYgB2oQIyAFQShqIAMgAyAEahAjIAVBQGsgBUEgaigCADYCACAFQcwAaiAFQTBqKAIANgIAIAUgBSkDGDcDOCAFIAUpAyg3AkQgAEGYAWohAiAAKAKcAQRAIAIoAgAQIQsgACgCqAEEQCAAKAKkARAhCyACIAUpAzg3AgAgAkEQaiAFQcgAaikDADcCACACQQhqIAVBQGspAwA3AgAgASABKAIAQQFqNgIAIAQEQCADECELIAcEQCAGECELIAVB0ABqJAAPC0GixMIAQRAgBUE4akHMqMIAEJYBAAtB3/LCAEErQYzzwgAQuQEAC0Hcw8IAQcYAIAVBOGpB3KjCABCWAQALrQMCCn8CfCACKAIEIgcgAUEYaigCAGoiBCADKAIEIgVLBEAgA0EIaigCACEIIAErAwAhDgJAIAJBCGooAgAiCyAHayIJRQRADAELIAggBWsiBkUEQCAOIQ9EAAAAAAAAAAAhDgwBCyAOIA4gCbgiD6JEAAAAAAAAJECiIA8gBrigo5tEAAAAAAAAJECjIg+hRAAAAAAAACRAohB+RAAAAAAAACRAoyEOCyACKAIAIQwCf0EAIAItACgiAkEKRg0AGkEAIAJBe2oiAkEESw0AGkKBgoSAECACrUIDhoinCyENIAQgBWshBCADKAIAIQYCQCADLQAoIgJBCkYNACACQXtqIgJBBEsNAEKBgoSAECACrUIDhoinIQoLIABBAToAHSAAIA06ABwgACAJNgIYIABBADYCFCAAIAs2AhAgACAHNgIMIAAgDDYCCCAAIA85AwAgAEE9aiABLQAdOgAAIABBPGogCjoAACAAQThqIAQ2AgAgAEE0akEANgIAIABBMGogCDYCACAAQSxqIAU2AgAgAEEoaiAGNgIAIABBIGogDjkDAA8LIABBAjoAHAvnAgEGfwJAAkAgAkEDcSIERQ0AQQQgBGsiBEUNACADIAQgBCADSxshBUEAIQQgAUH/AXEhCANAIAQgBUYNASACIARqIARBAWohBC0AACIGIAhHDQALQQEhAyAGIAFB/wFxRkEBakEBcSAEakF/aiEEDAELIAFB/wFxIQgCQAJAIANBCEkNACAFIANBeGoiBksNACAIQYGChAhsIQQDQCACIAVqIgdBBGooAgAgBHMiCUF/cyAJQf/9+3dqcSAHKAIAIARzIgdBf3MgB0H//ft3anFyQYCBgoR4cUUEQCAFQQhqIgUgBk0NAQsLIAUgA0sNAQsgAiAFaiECIAMgBWshBkEAIQNBACEEAkADQCAEIAZGDQEgAiAEaiAEQQFqIQQtAAAiByAIRw0AC0EBIQMgByABQf8BcUZBAWpBAXEgBGpBf2ohBAsgBCAFaiEEDAELIAUgAxCoAQALIAAgBDYCBCAAIAM2AgAL4gIBBX8CQEHN/3sgAEEQIABBEEsbIgBrIAFNDQAgAEEQIAFBC2pBeHEgAUELSRsiBGpBDGoQAyICRQ0AIAJBeGohAQJAIABBf2oiAyACcUUEQCABIQAMAQsgAkF8aiIFKAIAIgZBeHEgAiADakEAIABrcUF4aiICIAAgAmogAiABa0EQSxsiACABayICayEDIAZBA3EEQCAAIAMgACgCBEEBcXJBAnI2AgQgACADaiIDIAMoAgRBAXI2AgQgBSACIAUoAgBBAXFyQQJyNgIAIAAgACgCBEEBcjYCBCABIAIQLwwBCyABKAIAIQEgACADNgIEIAAgASACajYCAAsCQCAAKAIEIgFBA3FFDQAgAUF4cSICIARBEGpNDQAgACAEIAFBAXFyQQJyNgIEIAAgBGoiASACIARrIgRBA3I2AgQgACACaiICIAIoAgRBAXI2AgQgASAEEC8LIABBCGohAwsgAwv/AgEJfyMAQSBrIgMkAAJAAkACQCAAKAIQIgggACgCGGsgAkgNACAAQQhqIQQgAEEMaiEGIAAoAgAhCSAAKAIEIQcCQCAIIAJrIgVFDQAgBiAEIAlBAUYbKAIAIgogBUYNACAKIAVNDQEgBSAHaiwAAEFASA0BCyADIAYgBCAJGygCACIENgIEIAMgBzYCACADIAU2AgggAyAENgIMIAVFIAQgBUZyRQRAIAQgBU0NAiAFIAdqLAAAQb9/TA0CCyAFIAdqIQYCQCACQQAgBCAFayIEIAJHG0UEQCADIAQ2AhQgAyAGNgIQDAELIAQgAk0NASAHIAhqIgcsAABBQEgNASADIAQ2AhQgAyAGNgIQIAcsAABBv39MDQMLIAEgBkcEQCABIAYgAhC1AQ0BCyAAIAU2AhBBASELCyADQSBqJAAgCw8LIAMgA0EMajYCGCADIANBCGo2AhQgAyADNgIQIANBEGoQxwEACyADQRBqIgAoAgAgACgCBEEAIAIQGgAL9wIBCn8jAEHwAGsiCCQAIABBQGshCwNAIAJBAXQiA0EBciEFAkACQAJAAkAgA0ECaiIHIAFPDQAgBSABTw0BIAAgA0HkAGxqIQlBACEDA0AgA0EEaiIEQShGDQEgAyAJaiEGIAQhA0F/IAZBiAJqKAIAIgQgBkGkAWooAgAiBkcgBCAGSBsiBEUNAAsgBEH/AXFB/wFHDQAgByEFCwJAIAUgAU8NACACIAFPDQIgCyAFQeQAbCIDaiEGIAsgAkHkAGwiAmohCSAAIANqIQcgACACaiEEQQAhAwNAIANBBGoiAkEoRg0BIAMgCWohCiADIAZqIQwgAiEDQX8gDCgCACICIAooAgAiCkcgAiAKSBsiAkUNAAsgAkH/AXFB/wFGDQMLIAhB8ABqJAAPC0GsqMIAIAUgARCnAQALQbyowgAgAiABEKcBAAsgCEEMaiAEQeQAELoBGiAEIAdB5AAQmQEaIAcgCEEMakHkABC6ARogBSECDAALAAv5AgEFfyMAQTBrIgQkAAJAAkAgAEEIaigCACIFIAAoAgQiBk8EQCAFIAJLDQEgBSAGayEHIAAgAygCAAR/IAMoAlQNAyADQX82AlRBACECIANB4ABqIghBADYCACADQdgAaiIAQQAgBxCRASABIAZBAnRqIAEgBUECdGogABBLIAAoAgAhASAEQRBqIgUgCCgCACIANgIAIAQgADYCKCAEQgA3AyAgBCAANgIcIARBADYCGCAEIAE2AgwgBEEANgIIIARBCGogAygCABEHABogBCgCDCEAIARBFGooAgAgBSgCACIHIAQoAggiCEEBRhsiBQRAIAUhBiAAIQEDQCACIAEtAABBwAFxQYABRmohAiABQQFqIQEgBkF/aiIGDQALCyAIRSAHRXJFBEAgABAhCyADIAMoAlRBAWo2AlQgBSACawUgBws2AgwgBEEwaiQADwsgBiAFEKgBAAsgBSACEKYBAAtBosTCAEEQIARBCGpBuK/CABCWAQAL4gIBC38jAEEQayIGJAAgAEEIaigCACIFIAAoAgQiBE8EQCAFIAJNBEAgBkKlxoihyJyn+Us3AwggASAEQQJ0aiICIAUgBGsiBSAGQQhqEHEgBigCCCIBQRl2IgRBCHQgBHIiBEEQdCAEciEKIAVBAnQhCyADKAIYIgQgAXEhASADQSBqKAIAIQggA0EcaigCACEMA0ACQAJAIAEgDGooAAAiCSAKcyIDQX9zIANB//37d2pxQYCBgoR4cSIDRQ0AA0ACQCAFIAggA2hBA3YgAWogBHEiDUEEdGoiBygCCEYEQCAHKAIAIgcgAkYNASACIAcgCxC1AUUNAQsgA0F/aiADcSIDDQEMAgsLIAggDUEEdGotAAwhAwwBCyAOQQRqIg4gAWogBHEhAUEKIQMgCSAJQQF0cUGAgYKEeHFFDQELCyAAIAM6ABAgBkEQaiQADwsgBSACEKYBAAsgBCAFEKgBAAvdAgEKfyMAQfAAayIHJAACQCABQQJJDQAgAUF+aiEEIAAgAUHkAGxqIgVBuH5qIQYgBUGcf2ohCEFYIQEDQCABQQRqIgNFDQEgASAFaiECIAMhAUF/IAJBoH9qKAIAIgMgAkEEaigCACICRyADIAJIGyIDRQ0ACyADQf8BcUH/AUcNACAHQQhqIAhB5AAQugEaIAggBkHkABC6ARogACAEQeQAbCIBaiEDAkAgBEUNACAAIAFqQVxqIQYgB0HIAGohCgNAIAAgBEF/aiIIQeQAbGohBUEAIQEDQCABQQRqIgJBKEYNAiABIApqIQkgASAGaiELIAIhAUF/IAsoAgAiAiAJKAIAIglHIAIgCUgbIgJFDQALIAJB/wFxQf8BRw0BIAAgBEHkAGxqIAVB5AAQugEaIAZBnH9qIQYgBSEDIAgiBA0ACwsgAyAHQQhqQeQAELoBGgsgB0HwAGokAAvNAgEGfyABIAJBAXRqIQkgAEGA/gNxQQh2IQogAEH/AXEhDAJAAkACQANAIAFBAmohCyAHIAEtAAEiAmohCCAKIAEtAAAiAUcEQCABIApLDQMgCCEHIAsiASAJRw0BDAMLIAggB08EQCAIIARLDQIgAyAHaiEBAkADQCACRQ0BIAJBf2ohAiABLQAAIAFBAWohASAMRw0AC0EAIQIMBQsgCCEHIAsiASAJRw0BDAMLCyAHIAgQqAEACyAIIAQQpgEACyAAQf//A3EhByAFIAZqIQNBASECA0ACQCAFQQFqIQACfyAAIAUtAAAiAUEYdEEYdSIEQQBODQAaIAAgA0YNASAFLQABIARB/wBxQQh0ciEBIAVBAmoLIQUgByABayIHQQBIDQIgAkEBcyECIAMgBUcNAQwCCwtB3/LCAEErQYzzwgAQuQEACyACQQFxC9sCAQN/IwBBEGsiAyQAIAAgAUcEQANAIABBBGoCQCAAKAIAIgBBgAFPBEAgA0EANgIMAn8gAEH/D00EQCADIABBP3FBgAFyOgANIAMgAEEGdkEfcUHAAXI6AAxBAgwBCyAAQf//A00EQCADIABBP3FBgAFyOgAOIAMgAEEGdkE/cUGAAXI6AA0gAyAAQQx2QQ9xQeABcjoADEEDDAELIAMgAEE/cUGAAXI6AA8gAyAAQRJ2QfABcjoADCADIABBBnZBP3FBgAFyOgAOIAMgAEEMdkE/cUGAAXI6AA1BBAshACACIAIoAgggABCRASACIAIoAggiBCAAajYCCCAEIAIoAgBqIANBDGogABC6ARoMAQsgAigCCCIEIAIoAgRGBH8gAiAEQQEQkQEgAigCCAUgBAsgAigCAGogADoAACACIAIoAghBAWo2AggLIgAgAUcNAAsLIANBEGokAAviAgIGfwJ+IwBBIGsiAyQAAkAQwQEiBEUNAAJAIAQoAgBFBEAgBEF/NgIAQQQhBiAEQQRqIAEQViIBRQ0BAn4gASgCCCICRQRAQgAMAQsCQAJAIAJB/////wNxIAJHDQAgAkECdCIFQQBIDQAgASgCACEBIAVBBBDSASIGDQEgBUEEQfT5wgAoAgAiAEECIAAbEQAAAAsQzgEACyACrSEIIAJBBHQiByEFIAYhAgNAIAIgASgCADYCACACQQRqIQIgAUEQaiEBIAVBcGoiBQ0ACyAHQXBqQQR2QQFqrUIghgshCSAEIAQoAgBBAWo2AgAgBkUNAiADIAggCYQ3AhQgAyAGNgIQIANBCGogA0EQahCGASAAIAMpAwg3AgAgA0EgaiQADwtBosTCAEEQIANBEGpBtMTCABCWAQALQd/ywgBBK0GM88IAELkBAAtB3MPCAEHGACADQRBqQcTEwgAQlgEAC8ICAQp/IwBB8ABrIgYkAAJAIAFBAkkNACAAQeQAaiEEQcAAIQIDQCACQQRqIgNB6ABGDQEgACACaiEFIAMhAkF/IAUoAgAiAyAFQeQAaigCACIFRyADIAVIGyIDRQ0ACyADQf8BcUH/AUcNACAGQQhqIABB5AAQugEaIAAgBEHkABC6ASIJQeQAaiEDAkAgAUEDSQ0AIAlBiAJqIQcgBkHIAGohCkECIQIDQCACQQFqIAkgAkHkAGxqIQBBACECA0AgAkEEaiIEQShGDQIgAiAHaiEIIAIgCmohCyAEIQJBfyALKAIAIgQgCCgCACIIRyAEIAhIGyIERQ0ACyAEQf8BcUH/AUcNASAAQZx/aiAAQeQAELoBGiAHQeQAaiEHIAAhAyICIAFHDQALCyADIAZBCGpB5AAQugEaCyAGQfAAaiQAC70CAgR/AX4jAEEQayIEJAACQCABQQJJDQAgAUEMbCAAaiIDQXRqIgJBAyADQWhqIgNBAxCUAUH/AXFB/wFHDQAgAikCACEGIAIgAykCADcCACAEQQhqIgUgAkEIaiICKAIANgIAIAIgA0EIaigCADYCACAEIAY3AwAgACABQX5qIgJBDGxqIQMCQCACRQ0AIARBAyAAIAFBfWoiAkEMbGpBAxCUAUH/AXFB/wFHDQAgAUEMbCAAakFQaiEBIAACfwJAA0AgAUEgaiABQRRqKAIANgIAIAFBGGogAUEMaikCADcCACACRQ0BIAJBf2ohAiAEQQMgAUEDEJQBIAFBdGohAUH/AXFB/wFGDQALIAJBAWoMAQtBAAtBDGxqIQMLIAMgBCkDADcCACADQQhqIAUoAgA2AgALIARBEGokAAu+AgIFfwF+IwBBMGsiBCQAQSchAgJAIABCkM4AVARAIAAhBwwBCwNAIARBCWogAmoiA0F8aiAAIABCkM4AgCIHQvCxf358pyIFQf//A3FB5ABuIgZBAXRB9oDAAGovAAA7AAAgA0F+aiAGQZx/bCAFakH//wNxQQF0QfaAwABqLwAAOwAAIAJBfGohAiAAQv/B1y9WIAchAA0ACwsgB6ciA0HjAEoEQCACQX5qIgIgBEEJamogB6ciBUH//wNxQeQAbiIDQZx/bCAFakH//wNxQQF0QfaAwABqLwAAOwAACwJAIANBCk4EQCACQX5qIgIgBEEJamogA0EBdEH2gMAAai8AADsAAAwBCyACQX9qIgIgBEEJamogA0EwajoAAAsgAUGq78IAQQAgBEEJaiACakEnIAJrECUgBEEwaiQAC7YCAQd/An8gAUEgaigCACIEIAFBLGooAgAiBU0EQCABIAQ2AiwgBAwBCyABQSRqIAUgBCAFayIGEJEBIAEoAiwhAyABKAIkIQcgAQJ/AkAgBkECTwRAIAVBf3MgBGohBANAIAMgB2pBADoAACADQQFqIQMgBEF/aiIEDQALDAELIAYNACADDAELIAMgB2pBADoAACADQQFqCyIENgIsIAEoAiALIQMCQCAEIAMgAyAESxsiBkUNACABKAIkIQcgASgCGCEEQQAhAwNAIANBAWohBSADIAdqAkAgAiAEKAIAIggQV0H/AXEiA0EIRw0AQQAhA0EEIAggAhA2IghB/wFxQQJGDQAgCEECdEEEcSEDCyADOgAAIAUgBk8NASAEQQRqIQQgBSEDDAALAAsgACABQTAQugEaC78CAQR/IABCADcCECAAAn9BACABQQh2IgNFDQAaQR8gAUH///8HSw0AGiABQQYgA2ciA2tBH3F2QQFxIANBAXRrQT5qCyICNgIcIAJBAnRBsPjCAGohBAJAAkACQAJAQaT2wgAoAgAiBUEBIAJBH3F0IgNxBEAgBCgCACIDKAIEQXhxIAFHDQEgAyECDAILQaT2wgAgAyAFcjYCACAEIAA2AgAgACAENgIYDAMLIAFBAEEZIAJBAXZrQR9xIAJBH0YbdCEEA0AgAyAEQR12QQRxakEQaiIFKAIAIgJFDQIgBEEBdCEEIAIhAyACKAIEQXhxIAFHDQALCyACKAIIIgEgADYCDCACIAA2AgggAEEANgIYIAAgAjYCDCAAIAE2AggPCyAFIAA2AgAgACADNgIYCyAAIAA2AgwgACAANgIIC7gCAQZ/IwBBEGsiAiQAAkAgAkF/An8CQCABBEAgAUEISQ0BIAEgAUH/////AXFGBEAgAUEDdEEHbgwDC0G0psIAQRxB0KbCABC5AQALIABCADcCACAAQRRqQQA2AgAgAEEMakIENwIAIABBCGpBsKbCADYCAAwCCyABQQFqC0F/amd2IgFBAWoiBBCiAQJAIAIoAgQiAwRAIAIoAgghBSACKAIAIgYgAxDSASIHRQ0BIAdB/wEgAUEFahDAASEDIABBFGpBADYCACAAQQxqIAMgBWo2AgAgAEEIaiADNgIAIAAgATYCBCAAQQA2AgAgAEEQaiABIARBA3ZBB2wgAUEISRs2AgAMAgtBtKbCAEEcQdCmwgAQuQEACyAGIANB9PnCACgCACIAQQIgABsRAAAACyACQRBqJAALuAIBBn8jAEEQayICJAACQCACQX8CfwJAIAEEQCABQQhJDQEgASABQf////8BcUYEQCABQQN0QQduDAMLQbSmwgBBHEHQpsIAELkBAAsgAEIANwIAIABBFGpBADYCACAAQQxqQgg3AgAgAEEIakGwpsIANgIADAILIAFBAWoLQX9qZ3YiAUEBaiIEEJwBAkAgAigCBCIDBEAgAigCCCEFIAIoAgAiBiADENIBIgdFDQEgB0H/ASABQQVqEMABIQMgAEEUakEANgIAIABBDGogAyAFajYCACAAQQhqIAM2AgAgACABNgIEIABBADYCACAAQRBqIAEgBEEDdkEHbCABQQhJGzYCAAwCC0G0psIAQRxB0KbCABC5AQALIAYgA0H0+cIAKAIAIgBBAiAAGxEAAAALIAJBEGokAAupAgEDfyMAQYABayIEJAAgACgCACEAAkACQAJ/AkAgASgCACIDQRBxRQRAIAAoAgAhAiADQSBxDQEgAq0gARBPDAILIAAoAgAhAkEAIQADQCAAIARqQf8AaiACQQ9xIgNBMHIgA0HXAGogA0EKSRs6AAAgAEF/aiEAIAJBBHYiAg0ACyAAQYABaiICQYEBTw0CIAFBzIPAAEECIAAgBGpBgAFqQQAgAGsQJQwBC0EAIQADQCAAIARqQf8AaiACQQ9xIgNBMHIgA0E3aiADQQpJGzoAACAAQX9qIQAgAkEEdiICDQALIABBgAFqIgJBgQFPDQIgAUHMg8AAQQIgACAEakGAAWpBACAAaxAlCyAEQYABaiQADwsgAkGAARCoAQALIAJBgAEQqAEAC6YCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghBCAAKAIEIQgDQAJAAkAgAiAIaigAACIGIAdzIgBBf3MgAEH//ft3anFBgIGChHhxIgBFDQADQCABIAQgAGhBA3YgAmogA3EiCUHsAWxqKAIARwRAIABBf2ogAHEiAA0BDAILCyAEIAlB7AFsakEEaiEADAELIAVBBGoiBSACaiADcSECQQAhACAGIAZBAXRxQYCBgoR4cUUNAQsLIAALpAICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEEIAAoAgQhCANAAkACQCACIAhqKAAAIgYgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgBCAAaEEDdiACaiADcSIJQQR0aigCAEcEQCAAQX9qIABxIgANAQwCCwsgBCAJQQR0akEEaiEADAELIAVBBGoiBSACaiADcSECQQAhACAGIAZBAXRxQYCBgoR4cUUNAQsLIAALqAICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQYgAyAAKAIEIgNxIQIgAEEMaigCACEHIABBCGooAgAhCEEAIQQDQAJAAkAgAiAIaigAACIFIAZzIgBBf3MgAEH//ft3anFBgIGChHhxIgBFDQADQCABIAcgAGhBA3YgAmogA3FBA3RqIgkoAgBHBEAgAEF/aiAAcSIADQEMAgsLIAktAAQhAAwBCyAEQQRqIgQgAmogA3EhAkEIIQAgBSAFQQF0cUGAgYKEeHFFDQELCyAAC6gCAQR/IwBBIGsiAiQAAkACQAJAAkACQAJAEMIBIgMEQCADKAIADQYgA0F/NgIAIANBBGogABBVIgVFDQIQwQEiBARAIAQoAgANByAEQX82AgAgBEEEaiAAEFYiAEUNBCAFIAE2AhAgACgCBCABTw0CIAJBCGogACAAKAIIIgAgASAAa0EAEGEgAigCCEEBRw0CIAJBEGooAgANBRDOAQALDAULDAQLIAQgBCgCAEEBajYCACADIAMoAgBBAWo2AgAgAkEgaiQADwtB3/LCAEErQYzzwgAQuQEAC0Hf8sIAQStBjPPCABC5AQALQf/zwgBBKEHYq8IAELkBAAtB3MPCAEHGACACQRhqQdyowgAQlgEAC0GixMIAQRAgAkEYakHMqMIAEJYBAAuyAgEFfyAAKAIYIQQCQAJAIAAgACgCDCIBRgRAIABBFEEQIABBFGoiASgCACIDG2ooAgAiAg0BQQAhAQwCCyAAKAIIIgIgATYCDCABIAI2AggMAQsgASAAQRBqIAMbIQMDQCADIQUgAiIBQRRqIgMoAgAiAkUEQCABQRBqIQMgASgCECECCyACDQALIAVBADYCAAsCQCAERQ0AAkAgACAAKAIcQQJ0QbD4wgBqIgIoAgBGBEAgAiABNgIAIAENAUGk9sIAQaT2wgAoAgBBfiAAKAIcd3E2AgAPCyAEQRBBFCAEKAIQIABGG2ogATYCACABRQ0BCyABIAQ2AhggACgCECICBEAgASACNgIQIAIgATYCGAsgAEEUaigCACIARQ0AIAFBFGogADYCACAAIAE2AhgLC6cCAQJ/An8gAUGAEE8EQAJAAkACQAJAAkAgAUGAgARPBEAgAUEMdkFwaiICQYACSQ0BQdCcwAAgAkGAAhCnAQALIAFBBnZBYGoiAkHfB0sNASAAQYQCaigCACIDIAAgAmpBmAJqLQAAIgJNDQIgACgCgAIgAkEDdGoMBgsgAUEGdkE/cSAAIAJqQfgJai0AAEEGdHIiAiAAQYwCaigCACIDTw0CIABBlAJqKAIAIgMgACgCiAIgAmotAAAiAk0NAyAAKAKQAiACQQN0agwFC0GwnMAAIAJB4AcQpwEAC0HAnMAAIAIgAxCnAQALQeCcwAAgAiADEKcBAAtB8JzAACACIAMQpwEACyAAIAFBA3ZB+P///wFxagspAwBCASABQT9xrYaDQgBSC5QCAQN/IwBBgAFrIgMkAAJAAkACfwJAIAEoAgAiAkEQcUUEQCACQSBxDQEgAK0gARBPDAILQQAhAgNAIAIgA2pB/wBqIABBD3EiBEEwciAEQdcAaiAEQQpJGzoAACACQX9qIQIgAEEEdiIADQALIAJBgAFqIgBBgQFPDQIgAUHMg8AAQQIgAiADakGAAWpBACACaxAlDAELQQAhAgNAIAIgA2pB/wBqIABBD3EiBEEwciAEQTdqIARBCkkbOgAAIAJBf2ohAiAAQQR2IgANAAsgAkGAAWoiAEGBAU8NAiABQcyDwABBAiACIANqQYABakEAIAJrECULIANBgAFqJAAPCyAAQYABEKgBAAsgAEGAARCoAQALkwIBC38jAEEQayIFJAACQAJAIAFBCE8EQCABQQJ2IgJBAXQhB0F/IQZBfyABQX9qZ3YhCCACQRhsIABqQXRqIQQgASECA0AgBiAHaiIDIAFPDQIgAkENdCACcyICQRF2IAJzIgJBBXQgAnMiAiAIcSIDQQAgASADIAFJG2siAyABTw0DIAVBCGoiCSAEQQhqIgooAgA2AgAgBSAEKQIANwMAIAAgA0EMbGoiA0EIaiILKAIAIQwgBCADKQIANwIAIAogDDYCACALIAkoAgA2AgAgAyAFKQMANwIAIARBDGohBCAGQQFqIgZBAkcNAAsLIAVBEGokAA8LQbCnwgAgAyABEKcBAAtBwKfCACADIAEQpwEAC5UCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghCCAAKAIEIQlBACEEA0ACQCACIAlqKAAAIgUgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAARAA0BBASEGIAggAGhBA3YgAmogA3FBBHRqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC5YCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghCCAAKAIEIQlBACEEA0ACQCACIAlqKAAAIgUgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAARAA0BBASEGIAggAGhBA3YgAmogA3FB7AFsaigCACABRg0CIABBf2ogAHEiAA0ACwsgBEEEaiIEIAJqIANxIQJBACEGIAUgBUEBdHFBgIGChHhxRQ0BCwsgBguVAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQggACgCBCEJQQAhBANAAkAgAiAJaigAACIFIAdzIgBBf3MgAEH//ft3anFBgIGChHhxIgAEQANAQQEhBiAIIABoQQN2IAJqIANxQShsaigCACABRg0CIABBf2ogAHEiAA0ACwsgBEEEaiIEIAJqIANxIQJBACEGIAUgBUEBdHFBgIGChHhxRQ0BCwsgBguoAgEEfyMAQTBrIgIkACABKAIIIQUgAiABEKsBIAIoAgghBCACQQA2AiggAkIBNwMgIAJBIGpBACAEEJEBIAIoAighAyAEBEAgAigCICADakEAIARBAnRBfGpBAnZBAWoiBBDAARogAyAEaiEDCyACQRhqIgQgAzYCACACIAIpAyA3AxBBFEEEENIBIgNFBEBBFEEEQfT5wgAoAgAiAEECIAAbEQAAAAsgA0GKAjsBECADIAU2AgwgAyAFNgIIIANCADcCACAAQoGAgIAQNwIEIAAgAzYCACAAIAIpAwA3AhggACACKQMQNwIkIAAgASkCADcCDCAAQRRqIAFBCGooAgA2AgAgAEEgaiACQQhqKAIANgIAIABBLGogBCgCADYCACACQTBqJAAL4gEBAn8CQCABKAIEIgUgAmsgA08NACACIANqIgMgAkkhAgJAAkACQCAEBEAgAkUNASAAIAM2AgQMAwsgAkUNASAAIAM2AgQMAgsgBUEBdCICIAMgAiADSxshAwsgAyADQf////8AcUcEQAwBCyADQQR0IgRBf0wEQAwBCwJ/IAVFBEAgBEEEENIBDAELIAEoAgAgBUEEdEEEIAQQzwELIgJFBEAgBEEEQfT5wgAoAgAiAEECIAAbEQAAAAsgASADNgIEIAEgAjYCAAwBCyAAQQhqQQA2AgBBASEGCyAAIAY2AgALmQICAX8DfiMAQSBrIgEkACABQRhqQRQQsQEgASkDGCECIAFBEGpBFBCgASABKQMQIQMgAUEIakEUEKABIAEpAwghBCAAQYABakEANgIAIABB8ABqQgA3AgAgAEHoAGogAzcCACAAQeAAakIANwIAIABB2ABqIAI3AgAgAEHQAGpCADcCACAAQcgAakIENwIAIABBxABqQbCmwgA2AgAgAEE8akIANwIAIABBNGpCBDcCACAAQTBqQbCmwgA2AgAgAEEoakIANwIAIABBIGpCBDcCACAAQRxqQbCmwgA2AgAgAEEUakIANwIAIABBDGpCBDcCACAAQQhqQbCmwgA2AgAgAEIANwIAIABB+ABqIAQ3AgAgAUEgaiQAC+4BAQd/IwBB8ABrIgUkAAJAAkAgAUEITwRAIAFBAnYiAkEBdCEHQX8hBkF/IAFBf2pndiEIIAJByAFsIABqQZx/aiEEIAEhAgNAIAYgB2oiAyABTw0CIAJBDXQgAnMiAkERdiACcyICQQV0IAJzIgIgCHEiA0EAIAEgAyABSRtrIgMgAU8NAyAFQQxqIARB5AAQugEaIAQgACADQeQAbGoiBEHkABCZASAEIAVBDGpB5AAQugEaQeQAaiEEIAZBAWoiBkECRw0ACwsgBUHwAGokAA8LQbCnwgAgAyABEKcBAAtBwKfCACADIAEQpwEAC5MCAQV/IAAoAgAiBCgCBCgCACIFIAEoAgAiCEEDdGooAgQoAgAgBSACKAIAIgZBA3RqKAIEKAIAIgdJBEAgASAGNgIAIAIgCDYCACAEKAIIIgQgBCgCAEEBajYCACAAKAIAIgQoAgQoAgAiBSACKAIAIgZBA3RqKAIEKAIAIQcLIAcgBSADKAIAIghBA3RqKAIEKAIASQRAIAIgCDYCACADIAY2AgAgBCgCCCIDIAMoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQN0aigCBCgCACEHCyAFIAEoAgAiAEEDdGooAgQoAgAgB0kEQCABIAY2AgAgAiAANgIAIAQoAggiACAAKAIAQQFqNgIACwv8AQIGfwF+IwBBEGsiBSQAA0AgAkEBdCIDQQFyIQQCQAJAAkAgA0ECaiIDIAFJBEAgBCABTw0BIAMgBCAAIARBDGxqQQMgACADQQxsakEDEJQBQf8BcUH/AUYbIQQLIAQgAUkEQCACIAFPDQIgACACQQxsaiICQQMgACAEQQxsaiIDQQMQlAFB/wFxQf8BRg0DCyAFQRBqJAAPC0GsqMIAIAQgARCnAQALQbyowgAgAiABEKcBAAsgA0EIaiIGKAIAIQcgAikCACEJIAIgAykCADcCACACQQhqIgIoAgAhCCACIAc2AgAgBiAINgIAIAMgCTcCACAEIQIMAAsAC4MCAQN/IwBBMGsiBCQAAkACQCABKAJkRQRAIAFBfzYCZCABQfAAakEANgIAIARBADYCGCAEIAFBLGo2AhQgBEECNgIQIAQgAzYCDCAEIAI2AgggAUHoAGohBiAEQSBqIARBCGoQKiAEKAIgDQEMAgtBosTCAEEQIARBCGpBuK/CABCWAQALA0AgBiAEKAIoIgUgBSAEKAIsQQJ0ahC4ASAEQSBqIARBCGoQKiAEKAIgDQALCwJAAkAgAyABKAJwRgRAIAEoAmgiBSACRg0BIAUgAiADQQJ0ELUBRQ0BCyAAIAYQqwEMAQsgAEEANgIACyABIAEoAmRBAWo2AmQgBEEwaiQAC4ACAQV/IAAoAgAiBCgCBCgCACIFIAIoAgAiBkECdGooAgAiByAFIAEoAgAiCEECdGooAgBJBEAgASAGNgIAIAIgCDYCACAEKAIIIgQgBCgCAEEBajYCACAAKAIAIgQoAgQoAgAiBSACKAIAIgZBAnRqKAIAIQcLIAUgAygCACIIQQJ0aigCACAHSQR/IAIgCDYCACADIAY2AgAgBCgCCCIDIAMoAgBBAWo2AgAgACgCACIEKAIEKAIAIgUgAigCACIGQQJ0aigCAAUgBwsgBSABKAIAIgBBAnRqKAIASQRAIAEgBjYCACACIAA2AgAgBCgCCCIAIAAoAgBBAWo2AgALC+MBAQZ/IwBBEGsiBSQAIAAoAgAiAQRAIAAoAgQiAkEEaiEDIAEgAmpBAWohBiACKAIAQX9zQYCBgoR4cSEBIAAoAgghBANAAkAgAUUEQANAIAMgBk8NAiAEQeAAaiEEIAMoAgAgA0EEaiICIQNBgIGChHhxIgFBgIGChHhGDQALIAFBgIGChHhzIQEgAiEDCyAEIAFoQQN2QRhsaiICKAIEBEAgAigCABAhCyABQX9qIAFxIQEgAigCEEUNASACKAIMECEMAQsLIAUgACgCAEEBahCaASAAKAIEECELIAVBEGokAAvlAQEEfyMAQUBqIgQkACAEQQhqIAEgASACahAjIARBGGogACAEKAIIIgUgBCgCECIBEGYgBCgCDCEGAkAgBCgCGCICRQRAIAYhByAFIQIMAQsgBEEgaigCACEBIAQoAhwhByAGRQ0AIAUQIQsgBEEYaiAAIAIgARA3IAQgATYCOCAEIAc2AjQgBCACNgIwIABBGGoiACAEQTBqIAMQMgJAIAQoAhgiAUUNACAEQThqIARBLGooAgA2AgAgBCAEQSRqKQIANwMwIAAgBEEwaiADEDIgBCgCHEUNACABECELIARBQGskAAvYAQEFfwJAIAFBAkkNACAAIAFBfmoiAkEDdGoiAygCBCgCACABQQN0IABqIgRBfGooAgAiBSgCAE8NACAEQXhqIgQoAgAhBiAEIAMpAgA3AgACQCACRQ0AIAAgAUF9aiICQQN0aiIBKAIEKAIAIAUoAgBPDQAgAUF8aiEBIAACfwJAA0AgAUEMaiABQQRqKQIANwIAIAJFDQEgAkF/aiECIAEoAgAgAUF4aiEBKAIAIAUoAgBJDQALIAJBAWoMAQtBAAtBA3RqIQMLIAMgBTYCBCADIAY2AgALC90BAgd/AX4jAEEQayIEJAAgAUEBdiIDBEAgA0F/aiECA0AgACABIAIQZSACQX9qIgJBf0cNAAsLAkAgAUECTwRAIAFBDGwgAGpBdGohAiABIQMDQCADQX9qIgUgAU8NAiAEQQhqIABBCGoiAygCACIGNgIAIAQgACkCACIJNwMAIAJBCGoiBygCACEIIAAgAikCADcCACADIAg2AgAgByAGNgIAIAIgCTcCACAAIAVBABBlIAJBdGohAiAFIgNBAUsNAAsLIARBEGokAA8LQcCnwgAgA0F/aiABEKcBAAvdAQEGfwJAAkACQCABQQJJDQAgACgCBCIGKAIAIABBDGooAgAoAgBPDQAgACgCACEHIAAgACkCCDcCACAAQQhqIQICQCABQQNJDQBBAiEDQQMhBANAIAMgAU8NAyAGKAIAIAAgA0EDdGoiBSgCBCgCAE8NASADQX9qIgIgAU8NBCAAIAJBA3RqIAUpAgA3AgAgBCABSQRAIAUhAiAEIgNBAWoiBCADSQ0CDAELCyAFIQILIAIgBjYCBCACIAc2AgALDwtB0KzCACADIAEQpwEAC0HgrMIAIAIgARCnAQAL1AEBBn8jAEEQayIFJAAgACgCACICBEAgACgCBCIBQQRqIQMgASACakEBaiEGIAEoAgBBf3NBgIGChHhxIQEgACgCCCEEA0ACQCABBH8gAQUDQCADIAZPDQIgBEHgAGohBCADKAIAIANBBGoiAiEDQYCBgoR4cSIBQYCBgoR4Rg0ACyACIQMgAUGAgYKEeHMLIgIgAkF/anEhASAEIAJoQQN2QRhsaiICKAIQRQ0BIAIoAgwQIQwBCwsgBSAAKAIAQQFqEJoBIAAoAgQQIQsgBUEQaiQAC9MBAQZ/IwBBEGsiBSQAIAAoAgAiAgRAIAAoAgQiAUEEaiEDIAEgAmpBAWohBiABKAIAQX9zQYCBgoR4cSEBIAAoAgghBANAAkAgAQR/IAEFA0AgAyAGTw0CIARBQGshBCADKAIAIANBBGoiAiEDQYCBgoR4cSIBQYCBgoR4Rg0ACyACIQMgAUGAgYKEeHMLIgIgAkF/anEhASAEIAJoQQN2QQR0aiICKAIERQ0BIAIoAgAQIQwBCwsgBSAAKAIAQQFqEKMBIAAoAgQQIQsgBUEQaiQAC84BAgZ/AX4CQAJAIAFBCE8EQCABQQJ2IgJBAXQhBkF/IQVBfyABQX9qZ3YhByACQQR0IABqQXhqIQQgASECA0AgBSAGaiIDIAFPDQIgAkENdCACcyICQRF2IAJzIgJBBXQgAnMiAiAHcSIDQQAgASADIAFJG2siAyABTw0DIAQpAgAhCCAEIAAgA0EDdGoiAykCADcCACADIAg3AgAgBEEIaiEEIAVBAWoiBUECRw0ACwsPC0Gwp8IAIAMgARCnAQALQcCnwgAgAyABEKcBAAvMAQEHfwJAAkAgAUEITwRAIAFBAnYiAkEBdCEGQX8hBUF/IAFBf2pndiEHIAJBA3QgAGpBfGohBCABIQIDQCAFIAZqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAdxIgNBACABIAMgAUkbayIDIAFPDQMgBCgCACEIIAQgACADQQJ0aiIDKAIANgIAIAMgCDYCACAEQQRqIQQgBUEBaiIFQQJHDQALCw8LQbCnwgAgAyABEKcBAAtBwKfCACADIAEQpwEAC8kBAgN/AX4jAEEQayIFIAE2AgggAikDACEGQQEhAyABIQQDQCAGIAStQv8Bg4VCs4OAgIAgfiEGIANBBEZFBEAgBUEIaiADaiADQQFqIQMtAAAhBAwBCwsgAiAGNwMAIAEEQCAAIAFBAnRqIQEDQCAFIAAoAgAiBDYCDEEBIQMDQCAGIAStQv8Bg4VCs4OAgIAgfiEGIANBBEZFBEAgBUEMaiADaiADQQFqIQMtAAAhBAwBCwsgAiAGNwMAIABBBGoiACABRw0ACwsLwgEBBX8CQCABQQJJDQAgAUECdCAAakF8aiIDKAIAIgQgACABQX5qIgVBAnRqIgYoAgAiAk8NACADIAI2AgACQAJAIAVFDQAgBCAAIAFBfWoiA0ECdGoiAigCACIFTw0AIAYgBTYCACADRQ0BIAFBAnQgAGpBcGohAQNAIAQgASgCACIATwRAIAFBBGohAgwDCyABQQRqIAA2AgAgAUF8aiEBIANBf2oiAw0ACyABQQRqIQIMAQsgBiECCyACIAQ2AgALC9QBAQF/IwBB4ABrIgQkACAEIAE2AgggBCADNgIMIAEgA0YEQCAAIAIgAUECdBC6ARogBEHgAGokAA8LIARBPGpBCTYCACAEQTRqQQo2AgAgBEEkakEDNgIAIARCAzcCFCAEQZzxwgA2AhAgBEEKNgIsIAQgBEEIajYCQCAEIARBDGo2AkQgBEIENwNYIARCATcCTCAEQZTxwgA2AkggBCAEQShqNgIgIAQgBEHIAGo2AjggBCAEQcQAajYCMCAEIARBQGs2AiggBEEQakG08cIAEL8BAAvEAQEGfwJAAkACQCABQQJJDQAgACgCBCIEIAAoAgAiBRCyAUUNACAAQQRqIQIgACAENgIAQQMhBAJAIAFBA0kNAEECIQMDQCADIAFPDQMgACADQQJ0aiIGKAIAIgcgBRCyAUUNASADQX9qIgIgAU8NBCAAIAJBAnRqIAc2AgAgBCABSQRAIAYhAiAEIgNBAWoiBCADSQ0CDAELCyAGIQILIAIgBTYCAAsPC0HQrMIAIAMgARCnAQALQeCswgAgAiABEKcBAAu6AQEEfyMAQfAAayIEJAAgAUEBdiICBEAgAkF/aiECA0AgACABIAIQRiACQX9qIgJBf0cNAAsLAkAgAUECTwRAIAFB5ABsIABqQZx/aiEDIAEhAgNAIAJBf2oiBSABTw0CIARBDGogAEHkABC6ARogACADQeQAEJkBIAMgBEEMakHkABC6ASEDIAVBABBGIANBnH9qIQMgBSICQQFLDQALCyAEQfAAaiQADwtBwKfCACACQX9qIAEQpwEAC8EBAgR/AX5BAyECIwBBEGsiBEEDNgIIIAEpAwAhBkEBIQMDQCAGIAKtQv8Bg4VCs4OAgIAgfiEGIANBBEZFBEAgBEEIaiADaiADQQFqIQMtAAAhAgwBCwsgASAGNwMAIABBDGohBQNAIAQgACgCACICNgIMQQEhAwNAIAYgAq1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAEQQxqIANqIANBAWohAy0AACECDAELCyABIAY3AwAgAEEEaiIAIAVHDQALC7wBAQJ/AkAgACgCBCIBRQ0AIAAoAgwiAgRAIAJBPGwhAgNAIAEQqgEgAUE8aiEBIAJBRGoiAg0ACwsgACgCCARAIAAoAgQQIQsgAEEUahCPASAAQZwBaigCAARAIABBmAFqKAIAECELIABBqAFqKAIABEAgAEGkAWooAgAQIQsgAEG4AWoQbSAAQdABaigCAARAIABBzAFqKAIAECELIABB3AFqKAIAIgFFDQAgAEHgAWooAgBFDQAgARAhCwvCAQICfwF+A0AgAkEBdCIEQQFyIQMCQAJAAkAgBEECaiIEIAFJBEAgAyABTw0BIAQgAyAAIARBA3RqKAIEKAIAIAAgA0EDdGooAgQoAgBJGyEDCyADIAFJBEAgAiABTw0CIAAgA0EDdGoiBCgCBCgCACAAIAJBA3RqIgIoAgQoAgBJDQMLDwtBrKjCACADIAEQpwEAC0G8qMIAIAIgARCnAQALIAIpAgAhBSACIAQpAgA3AgAgBCAFNwIAIAMhAgwACwAL2gECBn8BfiMAIgAgAEFAakFgcSIAJAACQEGE9sIAKAIADQAgAEEYakEUEKABIAApAxghBiAAQRBqQRQQoAEgAEIANwIsIAAgBjcCJEGI9sIAKAIAQYj2wgAgACkDKDcDACAAQQA2AiBBhPbCACgCACEBQYD2wgAgACkDIDcDACAAQQA2AjwgACAAKQMQNwI0QZj2wgAoAgAhBEGY9sIAIAApAzg3AwBBlPbCACgCACEFQZD2wgAgACkDMDcDACABRQ0ABEAgARAhCyAERQ0AIAUQIQskAEGA9sIAC64BAQR/A0AgAkEBdCIEQQFyIQMCQAJAAkAgBEECaiIEIAFJBEAgAyABTw0BIAQgAyAAIANBAnRqKAIAIAAgBEECdGooAgBJGyEDCyADIAFJBEAgAiABTw0CIAAgAkECdGoiAigCACIEIAAgA0ECdGoiBSgCACIGSQ0DCw8LQayowgAgAyABEKcBAAtBvKjCACACIAEQpwEACyACIAY2AgAgBSAENgIAIAMhAgwACwALsgEBBH8DQCACQQF0IgRBAXIhAwJAAkACQCAEQQJqIgQgAUkEQCADIAFPDQEgBCADIAAgA0ECdGooAgAgACAEQQJ0aigCABCyARshAwsgAyABSQRAIAIgAU8NAiAAIAJBAnRqIgIoAgAiBCAAIANBAnRqIgUoAgAiBhCyAQ0DCw8LQayowgAgAyABEKcBAAtBvKjCACACIAEQpwEACyACIAY2AgAgBSAENgIAIAMhAgwACwALpwECBH8BfAJAIAAoAgAiA0UNAANAIAAoAgQgACgCACIEIAJsQQN0aiAEuDkDACAAKAIEIAFqIAAoAgC4OQMAIAFBCGohASADIAJBAWoiAkcNAAsgACgCACICQQJJDQBBASEBA0AgACgCBCAAKAIAIAFsQQN0akEIaiABQX9quCIFOQMAIAAoAgQgASAAKAIAakEDdGogBTkDACACIAFBAWoiAUcNAAsLC6sBAQV/AkAgAUECSQ0AIAFBAnQgAGpBfGoiBSgCACICIAAgAUF+aiIDQQJ0aiIEKAIAIgYQsgFFDQAgBSAGNgIAAkAgA0UNACACIAFBAnQgAGoiAEF0aigCABCyAUUNACAAQXBqIQADQAJAIAAiAUEIaiABQQRqKAIANgIAIANBf2oiA0UNACABQXxqIQAgAiABKAIAELIBDQELCyABQQRqIQQLIAQgAjYCAAsLzwEDAX8CfgJ8IwBBEGshASAAvSICQjSIQv8PgyIDQrIIWAR8IACaIAAgAkIAUxshBAJ8AkAgA0L+B1oEQCAERAAAAAAAADBDoEQAAAAAAAAww6AgBKEiBUQAAAAAAADgP2QNASAEIAWgIgAgBUQAAAAAAADgv2VBAXMNAhogAEQAAAAAAADwP6AMAgsgASAERAAAAAAAADBDoDkDCCABKwMIGiAARAAAAAAAAAAAog8LIAQgBaBEAAAAAAAA8L+gCyIAmiAAIAJCAFMbBSAACwuyAQEFfwJAAkAgASgCACICBEAgASgCBCEEDAELIAEoAgwhBSABKAIIIQMDQCADIAVPDQIgAygCACECIAEgA0EEaiIDNgIIIAEgASgCBEGgAWoiBDYCBCABIAJBgIGChHhxIgZBgIGChHhzIgI2AgAgBkGAgYKEeEYNAAsLIAEgAkF/aiACcTYCACABIAEoAhBBf2o2AhAgACAEIAJoQQN2QShsakEoELoBGg8LIABBAjoAJAurAQEHfwJAAkAgACgCCCIDQQJJBEAgAyEBDAELIAAoAgAiBkEEaiECQQEhAUEBIQQDQCACKAIAIgcgAUECdCAGaiIFQXxqKAIARwRAIAEgBEcEQCACIAUoAgA2AgAgBSAHNgIACyABQQFqIQELIAJBBGohAiADIARBAWoiBEcNAAsgASADSwRAQbWtwgBBHEHUrcIAELkBAAsgACgCCCABSQ0BCyAAIAE2AggLC5MBAQF/IwBB0ABrIgUkACAFQRBqIAEgASACahAjIAVBIGogAyADIARqECMgBUE4aiAFQRhqKAIANgIAIAUgBSkDEDcDMCAFQcgAaiAFQShqKAIANgIAIAUgBSkDIDcDQCAFIABBLGogBUEwaiAFQUBrECwCQCAFKAIAIgBFDQAgBSgCBEUNACAAECELIAVB0ABqJAALkwEBAX8jAEHQAGsiBSQAIAVBEGogASABIAJqECMgBUEgaiADIAMgBGoQIyAFQThqIAVBGGooAgA2AgAgBSAFKQMQNwMwIAVByABqIAVBKGooAgA2AgAgBSAFKQMgNwNAIAUgAEFAayAFQTBqIAVBQGsQLAJAIAUoAgAiAEUNACAFKAIERQ0AIAAQIQsgBUHQAGokAAuhAQECfwJAAkAgACgCBCIDIAFrIAJJBEAgASACaiICIAFJDQEgA0EBdCIBIAIgASACSxsiAUH/////A3EgAUcNASABQQJ0IgRBAEgNAQJ/IANFBEAgBEEEENIBDAELIAAoAgAgA0ECdEEEIAQQzwELIgJFDQIgACABNgIEIAAgAjYCAAsPCxDOAQALIARBBEH0+cIAKAIAIgBBAiAAGxEAAAALoAEBAn8CQAJAIAAoAgQiAyABayACSQRAIAEgAmoiAiABSQ0BIANBAXQiASACIAEgAksbIgFB////P3EgAUcNASABQQV0IgRBAEgNAQJ/IANFBEAgBEEIENIBDAELIAAoAgAgA0EFdEEIIAQQzwELIgJFDQIgACABNgIEIAAgAjYCAAsPCxDOAQALIARBCEH0+cIAKAIAIgBBAiAAGxEAAAALoQEBAn8CQAJAIAAoAgQiAyABayACSQRAIAEgAmoiAiABSQ0BIANBAXQiASACIAEgAksbIgFB/////wFxIAFHDQEgAUEDdCIEQQBIDQECfyADRQRAIARBCBDSAQwBCyAAKAIAIANBA3RBCCAEEM8BCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQzgEACyAEQQhB9PnCACgCACIAQQIgABsRAAAAC7ABAQN/AkACQAJAIAEoAgQiAyABKAIIIgJGBEAgAyECDAELIAMgAkkNAQJAIAJFBEAgAwRAIAEoAgAQIQsgAUEENgIAQQAhAgwBCyABKAIAIANBAnRBBCACQQJ0IgQQzwEiA0UNAyABIAM2AgALIAEgAjYCBAsgACACNgIEIAAgASgCADYCAA8LQbHzwgBBJEHY88IAELkBAAsgBEEEQfT5wgAoAgAiAEECIAAbEQAAAAueAQEIfyAAKAIEKAIAIgMgASgCACIGQeQAbGpBQGshByADIAIoAgAiCEHkAGxqQUBrIQkCQANAIARBBGoiA0EoRg0BIAQgCWohCiAEIAdqIQUgAyEEQX8gBSgCACIFIAooAgAiA0cgBSADSBsiA0UNAAsgA0H/AXFB/wFHDQAgASAINgIAIAIgBjYCACAAKAIIIgAgACgCAEEBajYCAAsLmAEBA38gAUEBdiIDBEAgA0F/aiECA0AgACABIAIQeiACQX9qIgJBf0cNAAsLAkAgAUECTwRAIAFBAnQgAGpBfGohAiABIQMDQCADQX9qIgQgAU8NAiAAKAIAIQMgACACKAIANgIAIAIgAzYCACAAIARBABB6IAJBfGohAiAEIgNBAUsNAAsLDwtBwKfCACADQX9qIAEQpwEAC5gBAQN/IAFBAXYiAwRAIANBf2ohAgNAIAAgASACEHsgAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQQJ0IABqQXxqIQIgASEDA0AgA0F/aiIEIAFPDQIgACgCACEDIAAgAigCADYCACACIAM2AgAgACAEQQAQeyACQXxqIQIgBCIDQQFLDQALCw8LQcCnwgAgA0F/aiABEKcBAAuaAQIDfwF+IAFBAXYiAwRAIANBf2ohAgNAIAAgASACEHggAkF/aiICQX9HDQALCwJAIAFBAk8EQCABQQN0IABqQXhqIQIgASEDA0AgA0F/aiIEIAFPDQIgACkCACEFIAAgAikCADcCACACIAU3AgAgACAEQQAQeCACQXhqIQIgBCIDQQFLDQALCw8LQcCnwgAgA0F/aiABEKcBAAucAQECfwJAAkAgASAAKAIERgRAIAFBAWoiAiABSQ0BIAFBAXQiAyACIAMgAksbIgJB/////wFxIAJHDQEgAkEDdCIDQQBIDQECfyABRQRAIANBBBDSAQwBCyAAKAIAIAFBA3RBBCADEM8BCyIBRQ0CIAAgAjYCBCAAIAE2AgALDwsQzgEACyADQQRB9PnCACgCACIAQQIgABsRAAAAC7cBAgR/An4jAEEwayIAJAACQEHI9MIAKAIADQAgAEEYakEKEFMgAEEQaiAAQSxqKAIAIgM2AgAgAEEIaiAAQSRqKQIAIgQ3AwBBxPTCACgCACEBQcj0wgAoAgAhAkHE9MIAIAApAhwiBTcCAEHM9MIAIAQ3AgBB1PTCACADNgIAIAAgBTcDAEHA9MIAQQA2AgAgAUUgAkVyDQAgAEEYaiABQQFqEJwBIAIQIQsgAEEwaiQAQcD0wgALtwECBH8CfiMAQTBrIgAkAAJAQZD1wgAoAgANACAAQRhqQQoQUyAAQRBqIABBLGooAgAiAzYCACAAQQhqIABBJGopAgAiBDcDAEGM9cIAKAIAIQFBkPXCACgCACECQYz1wgAgACkCHCIFNwIAQZT1wgAgBDcCAEGc9cIAIAM2AgAgACAFNwMAQYj1wgBBADYCACABRSACRXINACAAQRhqIAFBAWoQnAEgAhAhCyAAQTBqJABBiPXCAAuoAQECfwJAAkACQCABKAIEIgMgASgCCCICRgRAIAMhAgwBCyADIAJJDQECQCACRQRAIAMEQCABKAIAECELIAFBATYCAEEAIQIMAQsgASgCACADQQEgAhDPASIDRQ0DIAEgAzYCAAsgASACNgIECyAAIAI2AgQgACABKAIANgIADwtBsfPCAEEkQdjzwgAQuQEACyACQQFB9PnCACgCACIAQQIgABsRAAAAC4wBAQJ/IwBBEGsiASQAIAAoAgQiAgRAIAEgAkEBahCiASAAQQhqKAIAECELIABBGGoQbiAAQSxqEGggAEFAaxBoIABB3ABqKAIABEAgAEHYAGooAgAQIQsgAEHsAGooAgAEQCAAQegAaigCABAhCyAAQfwAaigCAARAIABB+ABqKAIAECELIAFBEGokAAuKAQEEfyAAIAAoAhAiAkEBaiIBNgIQAkAgASACSQ0AIABBDGogAEEIaiAAKAIAIgJBAUYbKAIAIgMgAUYNACAAQQxqIABBCGogAhshBANAIAMgAUsEQCAAKAIEIAFqLAAAQb9/Sg0CCyAAIAFBAWoiAjYCECACIAFJDQEgBCgCACIDIAIiAUcNAAsLC4sBAQF/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQQBIDQECfyADRQRAIAFBARDSAQwBCyAAKAIAIANBASABEM8BCyICRQ0CIAAgATYCBCAAIAI2AgALDwsQzgEACyABQQFB9PnCACgCACIAQQIgABsRAAAAC50BAgN/AXwgACAAKAIIIAIgAWsQhQEgACgCCCEDIAAgASACRwR/IAAoAgAgA0EDdGohBCABIQADQCAALQAAIQUgAEEBaiEARAAAAAAAAPA/IQYCQCAFQXxqIgVBA0sNAAJAIAVBAWsOAgEBAAtEAAAAAAAA4D8hBgsgBCAGOQMAIARBCGohBCAAIAJHDQALIAIgAyABa2oFIAMLNgIIC4MBAQN/IwBBIGsiAiQAIAEoAgAhAyACQQhqIAEoAggiARCgASACQQA2AhggAiACKQMINwMQIAJBEGpBACABEIMBIAIgASACKAIYIgRqNgIYIAIoAhAgBEECdGogAyABQQJ0ELoBGiAAQQhqIAIoAhg2AgAgACACKQMQNwIAIAJBIGokAAtsAQV/IAMgASABIANLGyEIA0ACQCAEIAhJBEAgBEEBaiIFIARPDQELQX8gASADRyABIANJGw8LIAIoAgAhBiAAKAIAIQcgAkEEaiECIABBBGohACAFIQRBfyAGIAdHIAcgBkkbIgVFDQALIAULfgEBfwJAAkAgASABQf////8BcUYEQCABQQN0IgNBf0wNAQJAIANFBEBBCCECDAELAn8gAkUEQCADQQgQ0gEMAQsgAxC+AQsiAkUNAwsgACABNgIEIAAgAjYCAA8LENMBAAsQ0wEACyADQQhB9PnCACgCACIAQQIgABsRAAAAC4EBAQF/IwBBQGoiBCQAIAQgATYCDCAEIAA2AgggBCADNgIUIAQgAjYCECAEQSxqQQI2AgAgBEE8akEDNgIAIARCAjcCHCAEQZSqwAA2AhggBEEENgI0IAQgBEEwajYCKCAEIARBEGo2AjggBCAEQQhqNgIwIARBGGpBqKrAABC/AQALcAEFfyAAKAIEIQMgAachAiAAKAIAIQRBACEAA0AgAEEEaiIAIAIgBHEiBWohAiADIAVqKAAAQYCBgoR4cSIGRQ0ACyADIAZoQQN2IAVqIARxIgJqLAAAQQBOBH8gAygCAEGAgYKEeHFoQQN2BSACCwt0AQJ/IAAgACgCCCABEIMBIAAoAgAgACgCCCICQQJ0aiEDAkACQCABQQJPBEAgAUF/aiEBA0AgA0EANgIAIAJBAWohAiADQQRqIQMgAUF/aiIBDQALDAELIAFFDQELIANBADYCACACQQFqIQILIAAgAjYCCAtvAQF/AkAgASAATwRAIAJFDQEgACEDA0AgAyABLQAAOgAAIAFBAWohASADQQFqIQMgAkF/aiICDQALDAELIAJFDQAgAUF/aiEBIABBf2ohAwNAIAIgA2ogASACai0AADoAACACQX9qIgINAAsLIAALZgIBfwF+IAGtQhh+IgNCIIinBEAgAEEANgIEDwsCQAJAIAFBB2pBfHEiAiABQQRqSQ0AIAIgA6dqIgEgAkkNACABQX1JDQELIABBADYCBA8LIAAgAjYCCCAAQQQ2AgQgACABNgIAC2cCAX8BfiABrULsAX4iA0IgiKcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiADp2oiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALZgIBfwF+IAGtQih+IgNCIIinBEAgAEEANgIEDwsCQAJAIAFBC2pBeHEiAiABQQRqSQ0AIAIgA6dqIgEgAkkNACABQXlJDQELIABBADYCBA8LIAAgAjYCCCAAQQg2AgQgACABNgIAC3ACAn8BfgJAAkAgAa1C5AB+IgRCIIinRQRAIASnIgJBf0wNAQJAIAJFBEBBBCEDDAELIAJBBBDSASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ0wEACxDTAQALIAJBBEH0+cIAKAIAIgBBAiAAGxEAAAALZwEDfyMAQSBrIgIkAAJAIAAoAgAgARBbDQAgAUEcaigCACEDIAEoAhggAkIENwMYIAJCATcCDCACQbyFwAA2AgggAyACQQhqEBsNACAAKAIEIAEQWyACQSBqJAAPCyACQSBqJABBAQtuAQJ/AkACQCABIAFB/////wBxRgRAIAFBBHQiAkF/TA0BAkAgAkUEQEEEIQMMAQsgAkEEENIBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDTAQALENMBAAsgAkEEQfT5wgAoAgAiAEECIAAbEQAAAAtuAQJ/AkACQCABIAFB/////wNxRgRAIAFBAnQiAkF/TA0BAkAgAkUEQEEEIQMMAQsgAkEEENIBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDTAQALENMBAAsgAkEEQfT5wgAoAgAiAEECIAAbEQAAAAtuAQJ/AkACQCABIAFB/////wFxRgRAIAFBA3QiAkF/TA0BAkAgAkUEQEEEIQMMAQsgAkEEENIBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDTAQALENMBAAsgAkEEQfT5wgAoAgAiAEECIAAbEQAAAAtmAQF/IAEgAUH/////AXFHBEAgAEEANgIEDwsCQAJAIAFBB2pBfHEiAiABQQRqSQ0AIAIgAUEDdGoiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALZgEBfyABIAFB/////wBxRwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAFBBHRqIgEgAkkNACABQX1JDQELIABBADYCBA8LIAAgAjYCCCAAQQQ2AgQgACABNgIAC20BAn8CQAJAIAEgAUH///8/cUYEQCABQQV0IgJBf0wNAQJAIAJFBEBBCCEDDAELIAJBCBDSASIDRQ0DCyAAIAE2AgQgACADNgIADwsQ0wEACxDTAQALIAJBCEH0+cIAKAIAIgBBAiAAGxEAAAALYAECfwJAIAAoAgAiAUUNACAAQQhqKAIAIgIEQCACQQR0IQIgAUEIaiEBA0AgASgCAARAIAFBfGooAgAQIQsgAUEQaiEBIAJBcGoiAg0ACwsgACgCBEUNACAAKAIAECELC3ABAX8jAEEwayICJAAgAiABNgIEIAIgADYCACACQRxqQQI2AgAgAkEsakEFNgIAIAJCAjcCDCACQcCCwAA2AgggAkEFNgIkIAIgAkEgajYCGCACIAJBBGo2AiggAiACNgIgIAJBCGpB0ILAABC/AQALbQEBfyMAQTBrIgMkACADIAI2AgQgAyABNgIAIANBHGpBAjYCACADQSxqQQU2AgAgA0ICNwIMIANBpIDAADYCCCADQQU2AiQgAyADQSBqNgIYIAMgAzYCKCADIANBBGo2AiAgA0EIaiAAEL8BAAtwAQF/IwBBMGsiAiQAIAIgATYCBCACIAA2AgAgAkEcakECNgIAIAJBLGpBBTYCACACQgI3AgwgAkGIg8AANgIIIAJBBTYCJCACIAJBIGo2AhggAiACQQRqNgIoIAIgAjYCICACQQhqQZiDwAAQvwEAC1YBAn8jAEEgayICJAAgAUEcaigCACEDIAEoAhggAkEYaiAAQRBqKQIANwMAIAJBEGogAEEIaikCADcDACACIAApAgA3AwggAyACQQhqEBsgAkEgaiQAC1MAIABBDGooAgAEQCAAKAIIECELIABBGGooAgAEQCAAQRRqKAIAECELIABBJGooAgAEQCAAQSBqKAIAECELIABBMGooAgAEQCAAQSxqKAIAECELC2QBAn8jAEEgayICJAAgASgCACEDIAJBCGogASgCCCIBEKABIAJBADYCGCACIAIpAwg3AxAgAkEQaiADIAMgAUECdGoQuAEgAEEIaiACKAIYNgIAIAAgAikDEDcCACACQSBqJAALWgEDfwJAIAFBCGooAgAiAiABKAIEIgNPBEAgAUEcaigCACIEIAJJDQEgASgCGCEBIAAgAiADazYCBCAAIAEgA0ECdGo2AgAPCyADIAIQqAEACyACIAQQpgEAC1cBA38CQCABQQhqKAIAIgIgASgCBCIDTwRAIAFBJGooAgAiBCACSQ0BIAEoAiAhASAAIAIgA2s2AgQgACABIANqNgIADwsgAyACEKgBAAsgAiAEEKYBAAtqAQJ/QQEhAAJAAkBB6PnCACgCAEEBRwRAQej5wgBCgYCAgBA3AwAMAQtB7PnCAEHs+cIAKAIAQQFqIgA2AgAgAEECSw0BC0Hw+cIAKAIAIgFBf0wNAEHw+cIAIAE2AgAgAEEBSw0AAAsAC1gBA38gACgCBCgCACIDIAIoAgAiBEEMbGpBAyADIAEoAgAiBUEMbGpBAxCUAUH/AXFB/wFGBEAgASAENgIAIAIgBTYCACAAKAIIIgAgACgCAEEBajYCAAsLSwEDfyAAQQxqKAIAIgMEQCAAKAIEIANBPGxqQURqIQIgAyEBA0AgAhCqASACQURqIQIgAUF/aiIBDQALCyAAQQA2AgAgACABNgIMC1QBAX8CQCABQX9KBEACQCABRQRAQQEhAgwBCyABQQEQ0gEiAkUNAgsgACABNgIEIAAgAjYCAA8LENMBAAsgAUEBQfT5wgAoAgAiAEECIAAbEQAAAAtOAQJ/QX8gASgCOCICIAAoAjgiA0cgAiADSRsiAgR/IAIFIABBIGooAgAgAEEoaigCACABQSBqKAIAIAFBKGooAgAQlAELQf8BcUH/AUYLUgEDfyAAKAIEKAIAIgMgAigCACIEQQJ0aigCACADIAEoAgAiBUECdGooAgAQsgEEQCABIAQ2AgAgAiAFNgIAIAAoAggiACAAKAIAQQFqNgIACwtOAQF/IwBBEGsiAiQAIAIgACABQQFBARBhAkAgAigCAEEBRgRAIAJBCGooAgBFDQFB//PCAEEoQaj0wgAQuQEACyACQRBqJAAPCxDOAQALQwEDfwJAIAJFDQADQCAALQAAIgQgAS0AACIFRgRAIAFBAWohASAAQQFqIQAgAkF/aiICDQEMAgsLIAQgBWshAwsgAwtKAAJ/IAFBgIDEAEcEQEEBIAAoAhggASAAQRxqKAIAKAIQEQEADQEaCyACRQRAQQAPCyAAKAIYIAIgAyAAQRxqKAIAKAIMEQMACwtGAQJ/IAAoAggiAiABSwRAIAAoAgAgAkHkAGxqQZx/aiEDA0AgAxC9ASADQZx/aiEDIAEgAkF/aiICRw0ACwsgACACNgIICzoBAX8gACAAKAIIIAIgAWtBAnYiAhCDASAAIAAoAggiAyACajYCCCAAKAIAIANBAnRqIAIgASACEHMLOwEBfyMAQSBrIgMkACADQgQ3AxAgA0IBNwIEIAMgATYCHCADIAA2AhggAyADQRhqNgIAIAMgAhC/AQALMwEBfyACBEAgACEDA0AgAyABLQAAOgAAIANBAWohAyABQQFqIQEgAkF/aiICDQALCyAAC0UBAn8jAEEQayIBJAAgACgCDCECIAAoAghFBEBB3/LCAEErQYzzwgAQuQEACyABIAIpAgg3AwggASACKQIANwMAEK4BAAsrAAJAIABBfEsNACAARQRAQQQPCyAAIABBfUlBAnQQ0gEiAEUNACAADwsACyYAIABBLGooAgAEQCAAKAIoECELIABBOGooAgAEQCAAKAI0ECELCyoBAX8CQCAAEAMiAUUNACABQXxqLQAAQQNxRQ0AIAFBACAAEMABGgsgAQsyAQF/IwBBEGsiAiQAIAIgATYCDCACIAA2AgggAkG0gMAANgIEIAJBATYCACACELsBAAspAQF/IAIEQCAAIQMDQCADIAE6AAAgA0EBaiEDIAJBf2oiAg0ACwsgAAs+AEHg9MIAKAIARQRAQeT0wgBCBDcCAEHg9MIAQbCmwgA2AgBB2PTCAEIANwIAQez0wgBBADYCAAtB2PTCAAs+AEH49MIAKAIARQRAQfz0wgBCBDcCAEH49MIAQbCmwgA2AgBB8PTCAEIANwIAQYT1wgBBADYCAAtB8PTCAAsgAAJAIAFBfEsNACAAIAFBBCACEM8BIgBFDQAgAA8LAAsmACAAKAIAIAEgAhCzASAAKAIAIAIgAxCzASAAKAIAIAEgAhCzAQsmACAAKAIAIAEgAhCHASAAKAIAIAIgAxCHASAAKAIAIAEgAhCHAQsmACAAKAIAIAEgAhCvASAAKAIAIAIgAxCvASAAKAIAIAEgAhCvAQsmAQF/IAAoAgAiASgCACABKAIEIAAoAgQoAgAgACgCCCgCABAaAAsUACAAIAAgASAAIAFjGyABIAFiGwscACABKAIYQfipwABBCyABQRxqKAIAKAIMEQMACxwAIAEoAhhBg6rAAEEOIAFBHGooAgAoAgwRAwALHAAgASgCGEHU8sIAQQsgAUEcaigCACgCDBEDAAsUACAAKAIAIAEgACgCBCgCDBEBAAsLACABBEAgABAhCwsSAEGAgMAAQRFBlIDAABC5AQALDAAgACABIAIgAxAiCwsAIAA1AgAgARBPCwYAEK4BAAsZAAJ/IAFBCU8EQCABIAAQRAwBCyAAEAMLCwYAEM4BAAsNAEKzmuaOyfKG3O0ACwMAAQsDAAELC5rCAq4NAEGAgMAAC/wFY2FwYWNpdHkgb3ZlcmZsb3cAAADouRAAFwAAAAkDAAAFAAAARAAQACAAAABkABAAEgAAAAsAAAAAAAAAAQAAAAwAAABpbmRleCBvdXQgb2YgYm91bmRzOiB0aGUgbGVuIGlzICBidXQgdGhlIGluZGV4IGlzIDAwMDEwMjAzMDQwNTA2MDcwODA5MTAxMTEyMTMxNDE1MTYxNzE4MTkyMDIxMjIyMzI0MjUyNjI3MjgyOTMwMzEzMjMzMzQzNTM2MzczODM5NDA0MTQyNDM0NDQ1NDY0NzQ4NDk1MDUxNTI1MzU0NTU1NjU3NTg1OTYwNjE2MjYzNjQ2NTY2Njc2ODY5NzA3MTcyNzM3NDc1NzY3Nzc4Nzk4MDgxODI4Mzg0ODU4Njg3ODg4OTkwOTE5MjkzOTQ5NTk2OTc5ODk5AABgARAABgAAAGYBEAAiAAAAxLgQABgAAABoCgAABQAAAGluZGV4ICBvdXQgb2YgcmFuZ2UgZm9yIHNsaWNlIG9mIGxlbmd0aCCoARAAFgAAAL4BEAANAAAAxLgQABgAAABuCgAABQAAAHNsaWNlIGluZGV4IHN0YXJ0cyBhdCAgYnV0IGVuZHMgYXQgYDB4Wy4uLl0AegIQAAsAAADiFBAAFgAAAMsBEAABAAAAZAIQABYAAAADCAAACQAAAMAUEAAOAAAAzhQQAAQAAADSFBAAEAAAAMsBEAABAAAAZAIQABYAAAAHCAAABQAAAHoCEAALAAAAhQIQACYAAACrAhAACAAAALMCEAAGAAAAywEQAAEAAABkAhAAFgAAABQIAAAFAAAAc3JjL2xpYmNvcmUvc3RyL21vZC5yc2J5dGUgaW5kZXggIGlzIG5vdCBhIGNoYXIgYm91bmRhcnk7IGl0IGlzIGluc2lkZSAgKGJ5dGVzICkgb2YgYAAAAPoCEAACAAAA5AIQABYAAABgBAAAEQAAAOQCEAAWAAAAVAQAACgAAABzcmMvbGliY29yZS9mbXQvbW9kLnJzLi4AQeCGwAALDv//////////////////AEGQh8AACwL4AwBBsofAAAsH/v////+/tgBBwofAAAsN/wcAAAAAAPj//wAAAQBB2ofAAAsQwJ+fPQAAAAACAAAA////BwBB9IfAAAu4FcD/AQAAAAAAAPgPIKAOEABKAAAA8BAQAAACAADwEhAAOgAAAAABAgMEBQYHCAkICgsMDQ4PEBESExQCFRYXGBkaGxwdHh8gAgICAgICAgICAiECAgICAgICAgICAgICAiIjJCUmAicCKAICAikqKwIsLS4vMAICMQICAjICAgICAgICAjMCAjQCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAjUCNgI3AgICAgICAgI4AjkCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAjo7PAICAgI9AgI+P0BBQkNERUYCAgJHAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAkgCAgICAgICAgICAkkCAgICAjsCAAECAgICAwICAgIEAgUGAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgcCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgABAwUFBgYDBwYICAkRChwLGQwUDRIODQ8EEAMSEhMJFgEXBRgCGQMaBxwCHQEfFiADKwQsAi0LLgEwAzECMgGnAqkCqgSrCPoC+wX9BP4D/wmteHmLjaIwV1iLjJAcHd0OD0tM+/wuLz9cXV+14oSNjpGSqbG6u8XGycre5OX/AAQREikxNDc6Oz1JSl2EjpKpsbS6u8bKzs/k5QAEDQ4REikxNDo7RUZJSl5kZYSRm53Jzs8NESlFSVdkZY2RqbS6u8XJ3+Tl8AQNEUVJZGWAgYSyvL6/1dfw8YOFi6Smvr/Fx87P2ttImL3Nxs7PSU5PV1leX4mOj7G2t7/BxsfXERYXW1z29/7/gA1tcd7fDg8fbm8cHV99fq6vu7z6FhceH0ZHTk9YWlxefn+1xdTV3PDx9XJzj3R1lpcvXyYuL6evt7/Hz9ffmkCXmDCPH8DBzv9OT1pbBwgPECcv7u9ubzc9P0JFkJH+/1NndcjJ0NHY2ef+/wAgXyKC3wSCRAgbBAYRgawOgKs1HhWA4AMZCAEELwQ0BAcDAQcGBxEKUA8SB1UIAgQcCgkDCAMHAwIDAwMMBAUDCwYBDhUFOgMRBwYFEAdXBwIHFQ1QBEMDLQMBBBEGDww6BB0lXyBtBGolgMgFgrADGgaC/QNZBxULFwkUDBQMagYKBhoGWQcrBUYKLAQMBAEDMQssBBoGCwOArAYKBh9BTAQtA3QIPAMPAzwHOAgrBYL/ERgILxEtAyAQIQ+AjASClxkLFYiUBS8FOwcCDhgJgLAwdAyA1hoMBYD/BYC2BSQMm8YK0jAQhI0DNwmBXBSAuAiAxzA1BAoGOAhGCAwGdAseA1oEWQmAgxgcChYJSAiAigarpAwXBDGhBIHaJgcMBQWApRGBbRB4KCoGTASAjQSAvgMbAw8NAAYBAQMBBAIICAkCCgULAhABEQQSBRMRFAIVAhcCGQQcBR0IJAFqA2sCvALRAtQM1QnWAtcC2gHgBeEC6ALuIPAE+Qb6AgwnOz5OT4+enp8GBwk2PT5W89DRBBQYNjdWV701zs/gEoeJjp4EDQ4REikxNDpFRklKTk9kZVpctrcbHKip2NkJN5CRqAcKOz5maY+Sb1/u71pimpsnKFWdoKGjpKeorbq8xAYLDBUdOj9FUaanzM2gBxkaIiU+P8XGBCAjJSYoMzg6SEpMUFNVVlhaXF5gY2Vma3N4fX+KpKqvsMDQDHKjpMvMbm9eInsFAwQtA2UEAS8ugIIdAzEPHAQkCR4FKwVEBA4qgKoGJAQkBCgINAsBgJCBNwkWCgiAmDkDYwgJMBYFIQMbBQFAOARLBS8ECgcJB0AgJwQMCTYDOgUaBwQMB1BJNzMNMwcuCAqBJh+AgSgIKoCGFwlOBB4PQw4ZBwoGRwknCXULP0EqBjsFCgZRBgEFEAMFgItgIEgICoCmXiJFCwoGDRM5Bwo2LAQQgMA8ZFMMAYCgRRtICFMdOYEHRgodA0dJNwMOCAoGOQcKgTYZgMcyDYObZnULgMSKvIQvj9GCR6G5gjkHKgQCYCYKRgooBROCsFtlSwQ5BxFABByX+AiC86UNgR8xAxEECIGMiQRrBQ0DCQcQk2CA9gpzCG4XRoCaFAxXCRmAh4FHA4VCDxWFUCuA1S0DGgQCgXA6BQGFAIDXKUwECgQCgxFETD2AwjwGAQRVBRs0AoEOLARkDFYKDQNdAz05HQ0sBAkHAg4GgJqD1goNAwsFdAxZBwwUDAQ4CAoGKAgeUncDMQOApgwUBAMFAw0GhWoAAACADhAAIAAAACcAAAAZAAAAgA4QACAAAAAoAAAAIAAAAIAOEAAgAAAAKgAAABkAAACADhAAIAAAACsAAAAYAAAAgA4QACAAAAAsAAAAIAAAAHNyYy9saWJjb3JlL3VuaWNvZGUvYm9vbF90cmllLnJzAADA++8+AAAAAAAOAEG6ncAAC5IB+P/7////BwAAAAAAABT+If4ADAAAAAIAAAAAAABQHiCAAAwAAEAGAAAAAAAAEIY5AgAAACMAviEAAAwAAPwCAAAAAAAA0B4gwAAMAAAABAAAAAAAAEABIIAAAAAAABEAAAAAAADAwT1gAAwAAAACAAAAAAAAkEQwYAAMAAAAAwAAAAAAAFgeIIAADAAAAACEXIAAQdaewAALBPIHgH8AQeaewAALBPIfAD8AQfOewAALFgMAAKACAAAAAAAA/n/f4P/+////H0AAQZWfwAALpQHg/WYAAADDAQAeAGQgACAAAAAAAAAA4AAAAAAAABwAAAAcAAAADAAAAAwAAAAAAAAAsD9A/g8gAAAAAAA4AAAAAAAAYAAAAAACAAAAAAAAhwEEDgAAgAkAAAAAAABAf+Uf+J8AAAAAAAD/fw8AAAAAAPAXBAAAAAD4DwADAAAAPDsAAAAAAABAowMAAAAAAADwzwAAAPf//SEQA//////////7ABAAQcKgwAALDf////8BAAAAAAAAgAMAQdegwAALFYAAAAAA/////wAAAAAA/AAAAAAABgBB9aDAAAsHgPc/AAAAwABBhqHAAAsvAwBECAAAYAAAADAAAAD//wOAAAAAAMA/AACA/wMAAAAAAAcAAAAAAMgzAAAAACAAQb2hwAALMX5mAAgQAAAAAAAQAAAAAAAAncECAAAAADBAAAAAAAAgIQAAAAAAQAAAAAD//wAA//8AQfehwAALBwEAAAACAAMAQZiiwAALBAQAAAUAQaSiwAALAQYAQa2iwAALPwcAAAgJCgALDA0ODwAAEBESAAATFBUWAAAXGBkaGwAcAAAAHQAAAAAAAB4fICEAAAAAACIAIwAkJSYAAAAAJwBB26PAAAsCKCkAQe2jwAALAiorAEGipMAACwEsAEG1pMAACwUtLgAALwBB2KTAAAsDMDEyAEHwpMAACwwzAAAAKQAAAAAAADQAQZOlwAALAzUANgBBsKXAAAsINzgAADg4ODkAQf+lwAALBiAAAAAAAQBBjqbAAAtUwAdu8AAAAAAAhwAAAABgAAAAAAAAAPAAAADA/wEAAAAAAAIAAAAAAAD/fwAAAAAAAIADAAAAAAB4BgcAAACA7x8AAAAAAAAACAADAAAAAADAfwAeAEHtpsAACxqA00AAAACA+AcAAAMAAAAAAABYAQCAAMAfHwBBj6fAAAsF/1wAAEAAQZ6nwAALA/mlDQBBrafAAAsHgDywAQAAMABBvqfAAAsD+KcBAEHNp8AACy8ovwAAAADgvA8AAAAAAAAAgP8GAADwDAEAAAD+BwAAAAD4eYAAfg4AAAAAAPx/AwBBhqjAAAsTf78AAPz///xtAAAAAAAAAH60vwBBoqjAAAsBowBBrqjAAAsdGAAAAAAAAAAfAAAAAAAAAH8AAIAAAAAAAAAAgAcAQdOowAALAWAAQdyowAALRqDDB/jnDwAAADwAABwAAAAAAAAA////////f/j//////x8gABAAAPj+/wAAf///+dsHAAAAAAAAAPAAAAAAfwAAAAAA8AcAQaypwAAL+QL///////////////////////8AAGJlZ2luIDw9IGVuZCAoIDw9ICkgd2hlbiBzbGljaW5nIGAgaXMgb3V0IG9mIGJvdW5kcyBvZiBgQm9ycm93RXJyb3JCb3Jyb3dNdXRFcnJvcgAAAKq3EAAAAAAAJBUQAAIAAAA6IAAAOBUQABUAAACNBAAABQAAAHNyYy9saWJjb3JlL3Jlc3VsdC5ycwABAgEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQMBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBBAUBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQMAAIAOEAAgAAAAOgAAADAAAAAAPgAAAQBBsKzAAAssIAAAAAEAAAABAAAAAAAAAP8HAAAAgwAAAAAAgAAAAAAAAQAAAAAAAP////8AQeeswAALCYAAAAAAAAD/AwBB/qzAAAsCDHIAQbSuwAALAv8DAEHGrsAACwL/AwBB4K7AAAsd/wMAAAAAAABgHBAAIQAAAGgdEAAAAgAAaB8QAC4AQYWvwAALHgEAAgABAAEAAwAEAAUAAQAGAAEABwAHCAAAAAAJBwBBra/AAAsBCgBBu6/AAAsXCwAAAAwHAAAAAA0ADgAADwAABxAAAA8AQeGvwAALBhEJAAASEwBB8a/AAAsDFBUWAEH9r8AACwIXGABBk7DAAAsBGQBBoLDAAAsLGgAAAAAAGwAcHR4AQfizwAALGBwAAAEAAAAAHwAABwkAACAABwAAAAAAEABB3LbAAAuEAgcAAAAAAQIDAwMEAwMDAwMDBQYHAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAEHsuMAACzDA/wAAAAAAAMD/8AMAAAAAwP/8AAAAAADA/wcAAAAAAMD/AH8AAAB/wP//AQAA/wMAQaS5wAALBv//DwD/AwBBtbnAAAsV/v8fAAAAAADAAQAAAAAA/wP/A8D/AEHSucAACwr/BwAAAAD/A/8DAEHmucAACxT/AwAAAAAAAPEDAAD////////nAwBBhLrAAAsI/////////w8AQZW6wAALDvz//wAAAAAAAMD///8PAEGvusAACwwggAAAAP4DAAcAADwAQcS6wAALN/8DAAAA//7/AAAAAP8DAAAAAP7/AAAAAAAAPwAAAP8DAAD/AwAAAAABAgMAAAAABAUGAAcAAAgAQYm7wAALQwkKCwwADQ4ADxARABITAAAAABQVAAAAABYAABcYAAAAGQAVGgAAGwAAABUAAAAAABwAHAAAAAAAHAAdHgAAAAAAAB8AQdm7wAALBiAAAAAcCABB57vAAAsBIQBB+LvAAAsCIiMAQZG9wAALBQgAAAAkAEGivcAACwElAEGzvcAACwMmACcAQce9wAALASgAQe29wAALBx0AAAAAABUAQYu+wAALAykAHABBmb7AAAsEKisALABBrL7AAAsBLQBB8L7AAAsSgP//////DwD/////////AQAMAEGMv8AACw7+//8PAAAAAA8AAAACBABBor/AAAsBPgBBrL/AAAsS/wMAAAAAAP8AAAD+AAAAAID/AEHHv8AACwX4AADADwBB17/AAAsVMP///P///////wEAAAAAAGAAAADgAEH1v8AACxH4AAAAAAD/AAAA/wAAAAAA/gBBj8DAAAsn/AAAAAAAAP8DAAAAAP///38AAADgfwAAAAAAHgAAAAAAAAD8////AEG+wMAACxTA/wAA/wP+/x8AAAD/AwAAAAD/AwBB3sDAAAs1/w8AAAAA//8HAAAA////HwAA//8fAAAAAAD/////////////////fwAAAAD/+wMAAAD//38AQZzBwAALFv//DwAAAAAA////AQDA////////gP8AQb7BwAALFP7////////vHgD+/////7//P/8fAEHgwcAAC1b+//8H/v//BwAAAAAABCAE//9/////f////////////////////////////////////////////////////////////////////////////8P/AwAfUABBwMLAAAuIDSAAAAAAAN+8QNf///v///////////+///////////////////////8D/P///////////////////////////v///38C//////8BAAAAAP+/tgD///+HBwAAAP8H//////////4AwP///////////////+8f/uEAnAAA////////AOD///////////////8DAAD8////BzAE0CYQAIYAAAAAKxAAgAQAAIAvEABvAAAAAAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCQkJCQlJicoKSorLCQkJCQkJCQkLS4vMDEyMzQ1Njc4OTo7PD0+Hz9AQUJDREVGJCQkRyQkJCRISUpLH0xNH05PUB8fHx8fHx8fHx8fUVIfHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fU1QkVVZXWFlaHx8fHx8fH1ssXF1eJF9gHx8fHx8fHx8kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQ3HyQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJGEkJCQkJCQkJCQkJCQkJCQkJCRiYyQkJCRkZSRhZiRnaGlqJGtsbW5vQ3BxcnN0JHUkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCR2dx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHyQkJCQkeCR5ent8fSQkJCR+IX+AH4EkgoOEcYUAAQIDBAUGBwgFBQkFCgsMBwcHBwcHBwcHBw0ODwcQEQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUF/////P8fAAD///8B/wcAAAAAAAD//98/AADw//gD////////////7//f4f8PAP7/75/5///9xeOfWYCwDwADEO6H+f///W3DhxkCXgAAPwDuv/v///3t478bAQAPAAAe7p/5///97eOfGcCwDwACAOzHPdYYx//Dxx2BAAAAAADv3/3///3/498dYAcPAAAA79/9///97+PfHWBADwAGAO/f/f/////n313wgA8AAPzs/3/8///7L3+AX/8AAAwA/v///////wd/IAAAAAAAANb3//+v//87XyAA8AAAAAABAAAAAAAAAP/+////H/7/A////v///x8AQdDPwAALnAH///////9/+QAA//////////8APP////+/IP//////9////////////z1/Pf//////Pf////89fz3/f/////////89//////////8HAAAAAP//AAD/////////////Pz/+////////////////n////v//B////////////8f/Af/fDwD//w8A//8PAP/fDQD////////P//8BgBAAQfTQwAAL3gH//////////////wH//////wf//////////z8A////f/8P/wEAAP///z8fAP//////D////wMAAAAAAAD///8P/////////3/+/x8AAAAAAIAAAAD////////v/+8PAAAAAAAA///////zAPz/////v/8DAP///////38AAOAA/P///z//Af//////5wAAAAAA3m8EAAAAAID/HwD//z8//////z8//6r///8/////////31/cH88P/x/cHwAAAAAAAAKAAAD/HwAAAACE/C8+UL3/8+BDAAD//////wEAQd7SwAALhAHA////////AwAA//////9///////9///////////8feAwA/////78g/////////4AAAP//fwB/f39/f39/f/////8AAAAAAIAAAOAAAAD+Az4f//9/4P7/////////////9+D///////7//38AAP///wcAAAAAAAD//////////wAA/x8AQerTwAALtgv//////z//H///AAwAAP//////f/CPAACA//z/////+f///////3wAAAAAAID/v/////8AAAD///////8PAC8AAAAAAPzoAPz///8H/////wcA////H/////////f/AIAAAP//AHz/PwAA//9//P////////9/BQAAOP//PAB+fn4Af3////////f/AP////////8HAAD/////DwD//3/4//////8P//////8///////8DAAAAAH8A+OD//X9f2/////////////////8DAAAA+P///////////////z////z///////8AAAAAAP8PAAAAAAAA3///////////HwAAAAD+//8H/v//B8D////8/PwcAAAAAAABAgMEBQQEBAQGBwgJCgsCAgwNDg8EBAICAgIQEQQEEhMUFRYEFwQYGRobHB0eBAIfICAhBAQEBAQEBCIjBCQCIyUmICcCKCkEKissLQQEAi4CLwQEMDEyMzQENQQEBDYENzgEBDk6Ozw9NgQEBAQ+P0AEQUJDBAQEBCQEBAQEAgICAgICAgICAgICAgJEBAJFAgICRgQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICAgICAgICAgJFBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgICRwQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgI2FARISUpLBAQEBAQEBAQEBAIEBAJMTU4CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAk8CAgICAgICAgICAiAEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgIUUAICAgICUQQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAJSUwQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAlRVVldYAgICAllaW1xdXgQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEXwQEBGBhBAQEBARiBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgJjAmQEBAQEBAQEBAQEBAQEBAQEBARlZmcEBAQEBAQEBARoaWoEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAgICAgICAgICAgICAgICAgICAgJrAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgUCAgIKAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJsAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAm0EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAm4EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBP/v//9///+3/z//PwAAAAD///////////////////8HAEGo38AAC3z///////8fAP///x////////8BAAAAAAD/////AOD///8H//////8H////P/////8P/z4AAAAAAP///z8AAP////8P/////w///////wD///////8PAAAA////////fwD//z8A/wAAAD/9/////7+R//8/AP//fwD///9/AEGs4MAAC23//zcA//8/AP///wP/////////wG/w7/7//z8AAAAAAP///x////8fAAAAAP/+//8fAAAA////////PwD//z8A//8HAP//AwAAAAAA/wEAAAAAAAD///////8HAP//////AAAA////H4AA//8/AEGk4cAAC5UC//9/APz///////8BAAD///8BAABwAP////9HAB4AABQAAAAA///7////n0B/vf+//wH/////////AQAA75/5///97eOfGYHgDwAAALsHAIAAAAAAswAAAAAAAAD///////8/fwAAAD8AAAAA/////////38RAAAAAAAAAP///////z8B////5/8HAAD/////////AQAAAAD//////////wAAAIAAAAAA//z///////waAAAA////////538AAP///////////yAAAAAA//3/////f38BAAAAAAD8/////P///n8Af/v/////f7TLAAAAv/3///9/ewEAAAAA////AwAAAAD//////38AAA8AAAAAAAAAfwBBwuPAAAsq////PwAA////////AAAPAAAA+P//4P//AAAAAAAA/4f/////////gP//AEH048AAC4ACCwAAAP////////8AAAAHAPAA////////////D///////B/8f/wH/QwAAAAD//9///////////99k3v/r7/////////+/59/f////e1/8/f///////////z/////9///3////9///3////9///3////9//////f////3///cPAAAAAAAAf///+dsHAAD//////x+APwBAAAAAAAAA//////8PAAAfAAAAAAAAAI8IAAAAAAAA7////5b+9wqE6paqlvf3Xv/7/w/u+/8PAAAAAAAA////A////wP///8DAAAAAAAA//9/AAAAAAD/////AwD///////8BAAAA////PwBBgObAAAsE/v//BwBBkObAAAsy//9/fwAAAABVVVVVVVVVqqpUVVVVVVUr1s7bsdXSrhGQpKpKVVXSVVVVVVVVVQVselUAQebmwAALPUWAQNf+//sPAAAAgBxVVVWQ5v///////wAAAAAAAFVVVVUBVFVVVVVVVasqVVVVVVVVVVVVVVVV/v///38AQfjnwAALFfA4EAAaAAAAwDkQAMABAACAOxAAGgBBsujAAAsCAQIAQb7owAALAgEDAEHi6MAACxUEAAAAAAAFBQYFBwgJCgAAAAALDA0AQYLpwAALAg4PAEGg6cAACwQQEQUSAEGJ7cAACwcTFAAVFhcYAEHs78AAC4QCGQAAAAABAgICAgMCAgICAgIEBQYCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAQfzxwAALOv////+/IAAAAAAAAP///////z8AAAD//////+dVVVVVVVVVVVVVFUBVVVVVAP8APwD/AP8APwCqAP8AQb/ywAALGg8ADwAPAB8AD4Q4Jz5QPQ/AIAAAAP//AAAIAEHm8sAACy7A////AAAAAAAA//////9/AAAAAAAAneolwFVVVVUFKAQAVVVVVVUVAABVVVUFAEGc88AACxVUVVRVVVVVVVVVAGpVKEVVVX1fVXQAQbzzwAALBP7//wcAQdDzwAALBAEAAgMAQfLzwAALAQQAQaL0wAALAQUAQbn1wAALAQYAQdD1wAALEAcICQoLDA0ODxAREhMUFRYAQaT2wAALARcAQcT2wAALAwIYGQBBiPfAAAsF//////8AQZb3wAALvwH/////DwAAAAAA////////BwAAAAAA//////////8AAAAA////AwAA8P//PwAAAP///wMAANBk3j8AAAD///8DAACw598fAAAAe1/8AQAA8P//PwAAAP///wMAAPD//z8AAAD///8DAADw//8/AAAA////AwAAAP///wEAAAD8//8HAAAA8P//HwAAAMD//38AAAAA////AQAAAAQAAAAAAAD/////AwAAAP8D////A////wMAAAAAAABBAAAAYQBB4PjAAAsFQgAAAGIAQfD4wAALBUMAAABjAEGA+cAACwVEAAAAZABBkPnAAAsFRQAAAGUAQaD5wAALBUYAAABmAEGw+cAACwVHAAAAZwBBwPnAAAsFSAAAAGgAQdD5wAALBUkAAABpAEHg+cAACwVKAAAAagBB8PnAAAsFSwAAAGsAQYD6wAALBUwAAABsAEGQ+sAACwVNAAAAbQBBoPrAAAsFTgAAAG4AQbD6wAALBU8AAABvAEHA+sAACwVQAAAAcABB0PrAAAsFUQAAAHEAQeD6wAALBVIAAAByAEHw+sAACwVTAAAAcwBBgPvAAAsFVAAAAHQAQZD7wAALBVUAAAB1AEGg+8AACwVWAAAAdgBBsPvAAAsFVwAAAHcAQcD7wAALBVgAAAB4AEHQ+8AACwVZAAAAeQBB4PvAAAsFWgAAAHoAQfD7wAALBcAAAADgAEGA/MAACwXBAAAA4QBBkPzAAAsFwgAAAOIAQaD8wAALBcMAAADjAEGw/MAACwXEAAAA5ABBwPzAAAsFxQAAAOUAQdD8wAALBcYAAADmAEHg/MAACwXHAAAA5wBB8PzAAAsFyAAAAOgAQYD9wAALBckAAADpAEGQ/cAACwXKAAAA6gBBoP3AAAsFywAAAOsAQbD9wAALBcwAAADsAEHA/cAACwXNAAAA7QBB0P3AAAsFzgAAAO4AQeD9wAALBc8AAADvAEHw/cAACwXQAAAA8ABBgP7AAAsF0QAAAPEAQZD+wAALBdIAAADyAEGg/sAACwXTAAAA8wBBsP7AAAsF1AAAAPQAQcD+wAALBdUAAAD1AEHQ/sAACwXWAAAA9gBB4P7AAAsF2AAAAPgAQfD+wAALBdkAAAD5AEGA/8AACwXaAAAA+gBBkP/AAAsF2wAAAPsAQaD/wAALBdwAAAD8AEGw/8AACwXdAAAA/QBBwP/AAAsF3gAAAP4AQdH/wAALBQEAAAEBAEHg/8AACwYCAQAAAwEAQfD/wAALBgQBAAAFAQBBgIDBAAsGBgEAAAcBAEGQgMEACwYIAQAACQEAQaCAwQALBgoBAAALAQBBsIDBAAsGDAEAAA0BAEHAgMEACwYOAQAADwEAQdCAwQALBhABAAARAQBB4IDBAAsGEgEAABMBAEHwgMEACwYUAQAAFQEAQYCBwQALBhYBAAAXAQBBkIHBAAsGGAEAABkBAEGggcEACwYaAQAAGwEAQbCBwQALBhwBAAAdAQBBwIHBAAsGHgEAAB8BAEHQgcEACwYgAQAAIQEAQeCBwQALBiIBAAAjAQBB8IHBAAsGJAEAACUBAEGAgsEACwYmAQAAJwEAQZCCwQALBigBAAApAQBBoILBAAsGKgEAACsBAEGwgsEACwYsAQAALQEAQcCCwQALBi4BAAAvAQBB0ILBAAsWMAEAAGkAAAAHAwAAAAAAADIBAAAzAQBB8ILBAAsGNAEAADUBAEGAg8EACwY2AQAANwEAQZCDwQALBjkBAAA6AQBBoIPBAAsGOwEAADwBAEGwg8EACwY9AQAAPgEAQcCDwQALBj8BAABAAQBB0IPBAAsGQQEAAEIBAEHgg8EACwZDAQAARAEAQfCDwQALBkUBAABGAQBBgITBAAsGRwEAAEgBAEGQhMEACwZKAQAASwEAQaCEwQALBkwBAABNAQBBsITBAAsGTgEAAE8BAEHAhMEACwZQAQAAUQEAQdCEwQALBlIBAABTAQBB4ITBAAsGVAEAAFUBAEHwhMEACwZWAQAAVwEAQYCFwQALBlgBAABZAQBBkIXBAAsGWgEAAFsBAEGghcEACwZcAQAAXQEAQbCFwQALBl4BAABfAQBBwIXBAAsGYAEAAGEBAEHQhcEACwZiAQAAYwEAQeCFwQALBmQBAABlAQBB8IXBAAsGZgEAAGcBAEGAhsEACwZoAQAAaQEAQZCGwQALBmoBAABrAQBBoIbBAAsGbAEAAG0BAEGwhsEACwZuAQAAbwEAQcCGwQALBnABAABxAQBB0IbBAAsGcgEAAHMBAEHghsEACwZ0AQAAdQEAQfCGwQALBnYBAAB3AQBBgIfBAAsFeAEAAP8AQZCHwQALBnkBAAB6AQBBoIfBAAsGewEAAHwBAEGwh8EACwZ9AQAAfgEAQcCHwQALBoEBAABTAgBB0IfBAAsGggEAAIMBAEHgh8EACwaEAQAAhQEAQfCHwQALBoYBAABUAgBBgIjBAAsGhwEAAIgBAEGQiMEACwaJAQAAVgIAQaCIwQALBooBAABXAgBBsIjBAAsGiwEAAIwBAEHAiMEACwaOAQAA3QEAQdCIwQALBo8BAABZAgBB4IjBAAsGkAEAAFsCAEHwiMEACwaRAQAAkgEAQYCJwQALBpMBAABgAgBBkInBAAsGlAEAAGMCAEGgicEACwaWAQAAaQIAQbCJwQALBpcBAABoAgBBwInBAAsGmAEAAJkBAEHQicEACwacAQAAbwIAQeCJwQALBp0BAAByAgBB8InBAAsGnwEAAHUCAEGAisEACwagAQAAoQEAQZCKwQALBqIBAACjAQBBoIrBAAsGpAEAAKUBAEGwisEACwamAQAAgAIAQcCKwQALBqcBAACoAQBB0IrBAAsGqQEAAIMCAEHgisEACwasAQAArQEAQfCKwQALBq4BAACIAgBBgIvBAAsGrwEAALABAEGQi8EACwaxAQAAigIAQaCLwQALBrIBAACLAgBBsIvBAAsGswEAALQBAEHAi8EACwa1AQAAtgEAQdCLwQALBrcBAACSAgBB4IvBAAsGuAEAALkBAEHwi8EACwa8AQAAvQEAQYCMwQALBsQBAADGAQBBkIzBAAsGxQEAAMYBAEGgjMEACwbHAQAAyQEAQbCMwQALBsgBAADJAQBBwIzBAAsGygEAAMwBAEHQjMEACwbLAQAAzAEAQeCMwQALBs0BAADOAQBB8IzBAAsGzwEAANABAEGAjcEACwbRAQAA0gEAQZCNwQALBtMBAADUAQBBoI3BAAsG1QEAANYBAEGwjcEACwbXAQAA2AEAQcCNwQALBtkBAADaAQBB0I3BAAsG2wEAANwBAEHgjcEACwbeAQAA3wEAQfCNwQALBuABAADhAQBBgI7BAAsG4gEAAOMBAEGQjsEACwbkAQAA5QEAQaCOwQALBuYBAADnAQBBsI7BAAsG6AEAAOkBAEHAjsEACwbqAQAA6wEAQdCOwQALBuwBAADtAQBB4I7BAAsG7gEAAO8BAEHwjsEACwbxAQAA8wEAQYCPwQALBvIBAADzAQBBkI/BAAsG9AEAAPUBAEGgj8EACwb2AQAAlQEAQbCPwQALBvcBAAC/AQBBwI/BAAsG+AEAAPkBAEHQj8EACwb6AQAA+wEAQeCPwQALBvwBAAD9AQBB8I/BAAsG/gEAAP8BAEGBkMEACwUCAAABAgBBkJDBAAsGAgIAAAMCAEGgkMEACwYEAgAABQIAQbCQwQALBgYCAAAHAgBBwJDBAAsGCAIAAAkCAEHQkMEACwYKAgAACwIAQeCQwQALBgwCAAANAgBB8JDBAAsGDgIAAA8CAEGAkcEACwYQAgAAEQIAQZCRwQALBhICAAATAgBBoJHBAAsGFAIAABUCAEGwkcEACwYWAgAAFwIAQcCRwQALBhgCAAAZAgBB0JHBAAsGGgIAABsCAEHgkcEACwYcAgAAHQIAQfCRwQALBh4CAAAfAgBBgJLBAAsGIAIAAJ4BAEGQksEACwYiAgAAIwIAQaCSwQALBiQCAAAlAgBBsJLBAAsGJgIAACcCAEHAksEACwYoAgAAKQIAQdCSwQALBioCAAArAgBB4JLBAAsGLAIAAC0CAEHwksEACwYuAgAALwIAQYCTwQALBjACAAAxAgBBkJPBAAsGMgIAADMCAEGgk8EACwY6AgAAZSwAQbCTwQALBjsCAAA8AgBBwJPBAAsGPQIAAJoBAEHQk8EACwY+AgAAZiwAQeCTwQALBkECAABCAgBB8JPBAAsGQwIAAIABAEGAlMEACwZEAgAAiQIAQZCUwQALBkUCAACMAgBBoJTBAAsGRgIAAEcCAEGwlMEACwZIAgAASQIAQcCUwQALBkoCAABLAgBB0JTBAAsGTAIAAE0CAEHglMEACwZOAgAATwIAQfCUwQALBnADAABxAwBBgJXBAAsGcgMAAHMDAEGQlcEACwZ2AwAAdwMAQaCVwQALBn8DAADzAwBBsJXBAAsGhgMAAKwDAEHAlcEACwaIAwAArQMAQdCVwQALBokDAACuAwBB4JXBAAsGigMAAK8DAEHwlcEACwaMAwAAzAMAQYCWwQALBo4DAADNAwBBkJbBAAsGjwMAAM4DAEGglsEACwaRAwAAsQMAQbCWwQALBpIDAACyAwBBwJbBAAsGkwMAALMDAEHQlsEACwaUAwAAtAMAQeCWwQALBpUDAAC1AwBB8JbBAAsGlgMAALYDAEGAl8EACwaXAwAAtwMAQZCXwQALBpgDAAC4AwBBoJfBAAsGmQMAALkDAEGwl8EACwaaAwAAugMAQcCXwQALBpsDAAC7AwBB0JfBAAsGnAMAALwDAEHgl8EACwadAwAAvQMAQfCXwQALBp4DAAC+AwBBgJjBAAsGnwMAAL8DAEGQmMEACwagAwAAwAMAQaCYwQALBqEDAADBAwBBsJjBAAsGowMAAMMDAEHAmMEACwakAwAAxAMAQdCYwQALBqUDAADFAwBB4JjBAAsGpgMAAMYDAEHwmMEACwanAwAAxwMAQYCZwQALBqgDAADIAwBBkJnBAAsGqQMAAMkDAEGgmcEACwaqAwAAygMAQbCZwQALBqsDAADLAwBBwJnBAAsGzwMAANcDAEHQmcEACwbYAwAA2QMAQeCZwQALBtoDAADbAwBB8JnBAAsG3AMAAN0DAEGAmsEACwbeAwAA3wMAQZCawQALBuADAADhAwBBoJrBAAsG4gMAAOMDAEGwmsEACwbkAwAA5QMAQcCawQALBuYDAADnAwBB0JrBAAsG6AMAAOkDAEHgmsEACwbqAwAA6wMAQfCawQALBuwDAADtAwBBgJvBAAsG7gMAAO8DAEGQm8EACwb0AwAAuAMAQaCbwQALBvcDAAD4AwBBsJvBAAsG+QMAAPIDAEHAm8EACwb6AwAA+wMAQdCbwQALBv0DAAB7AwBB4JvBAAsG/gMAAHwDAEHwm8EACwb/AwAAfQMAQYGcwQALBQQAAFAEAEGQnMEACwYBBAAAUQQAQaCcwQALBgIEAABSBABBsJzBAAsGAwQAAFMEAEHAnMEACwYEBAAAVAQAQdCcwQALBgUEAABVBABB4JzBAAsGBgQAAFYEAEHwnMEACwYHBAAAVwQAQYCdwQALBggEAABYBABBkJ3BAAsGCQQAAFkEAEGgncEACwYKBAAAWgQAQbCdwQALBgsEAABbBABBwJ3BAAsGDAQAAFwEAEHQncEACwYNBAAAXQQAQeCdwQALBg4EAABeBABB8J3BAAsGDwQAAF8EAEGAnsEACwYQBAAAMAQAQZCewQALBhEEAAAxBABBoJ7BAAsGEgQAADIEAEGwnsEACwYTBAAAMwQAQcCewQALBhQEAAA0BABB0J7BAAsGFQQAADUEAEHgnsEACwYWBAAANgQAQfCewQALBhcEAAA3BABBgJ/BAAsGGAQAADgEAEGQn8EACwYZBAAAOQQAQaCfwQALBhoEAAA6BABBsJ/BAAsGGwQAADsEAEHAn8EACwYcBAAAPAQAQdCfwQALBh0EAAA9BABB4J/BAAsGHgQAAD4EAEHwn8EACwYfBAAAPwQAQYCgwQALBiAEAABABABBkKDBAAsGIQQAAEEEAEGgoMEACwYiBAAAQgQAQbCgwQALBiMEAABDBABBwKDBAAsGJAQAAEQEAEHQoMEACwYlBAAARQQAQeCgwQALBiYEAABGBABB8KDBAAsGJwQAAEcEAEGAocEACwYoBAAASAQAQZChwQALBikEAABJBABBoKHBAAsGKgQAAEoEAEGwocEACwYrBAAASwQAQcChwQALBiwEAABMBABB0KHBAAsGLQQAAE0EAEHgocEACwYuBAAATgQAQfChwQALBi8EAABPBABBgKLBAAsGYAQAAGEEAEGQosEACwZiBAAAYwQAQaCiwQALBmQEAABlBABBsKLBAAsGZgQAAGcEAEHAosEACwZoBAAAaQQAQdCiwQALBmoEAABrBABB4KLBAAsGbAQAAG0EAEHwosEACwZuBAAAbwQAQYCjwQALBnAEAABxBABBkKPBAAsGcgQAAHMEAEGgo8EACwZ0BAAAdQQAQbCjwQALBnYEAAB3BABBwKPBAAsGeAQAAHkEAEHQo8EACwZ6BAAAewQAQeCjwQALBnwEAAB9BABB8KPBAAsGfgQAAH8EAEGApMEACwaABAAAgQQAQZCkwQALBooEAACLBABBoKTBAAsGjAQAAI0EAEGwpMEACwaOBAAAjwQAQcCkwQALBpAEAACRBABB0KTBAAsGkgQAAJMEAEHgpMEACwaUBAAAlQQAQfCkwQALBpYEAACXBABBgKXBAAsGmAQAAJkEAEGQpcEACwaaBAAAmwQAQaClwQALBpwEAACdBABBsKXBAAsGngQAAJ8EAEHApcEACwagBAAAoQQAQdClwQALBqIEAACjBABB4KXBAAsGpAQAAKUEAEHwpcEACwamBAAApwQAQYCmwQALBqgEAACpBABBkKbBAAsGqgQAAKsEAEGgpsEACwasBAAArQQAQbCmwQALBq4EAACvBABBwKbBAAsGsAQAALEEAEHQpsEACwayBAAAswQAQeCmwQALBrQEAAC1BABB8KbBAAsGtgQAALcEAEGAp8EACwa4BAAAuQQAQZCnwQALBroEAAC7BABBoKfBAAsGvAQAAL0EAEGwp8EACwa+BAAAvwQAQcCnwQALBsAEAADPBABB0KfBAAsGwQQAAMIEAEHgp8EACwbDBAAAxAQAQfCnwQALBsUEAADGBABBgKjBAAsGxwQAAMgEAEGQqMEACwbJBAAAygQAQaCowQALBssEAADMBABBsKjBAAsGzQQAAM4EAEHAqMEACwbQBAAA0QQAQdCowQALBtIEAADTBABB4KjBAAsG1AQAANUEAEHwqMEACwbWBAAA1wQAQYCpwQALBtgEAADZBABBkKnBAAsG2gQAANsEAEGgqcEACwbcBAAA3QQAQbCpwQALBt4EAADfBABBwKnBAAsG4AQAAOEEAEHQqcEACwbiBAAA4wQAQeCpwQALBuQEAADlBABB8KnBAAsG5gQAAOcEAEGAqsEACwboBAAA6QQAQZCqwQALBuoEAADrBABBoKrBAAsG7AQAAO0EAEGwqsEACwbuBAAA7wQAQcCqwQALBvAEAADxBABB0KrBAAsG8gQAAPMEAEHgqsEACwb0BAAA9QQAQfCqwQALBvYEAAD3BABBgKvBAAsG+AQAAPkEAEGQq8EACwb6BAAA+wQAQaCrwQALBvwEAAD9BABBsKvBAAsG/gQAAP8EAEHBq8EACwUFAAABBQBB0KvBAAsGAgUAAAMFAEHgq8EACwYEBQAABQUAQfCrwQALBgYFAAAHBQBBgKzBAAsGCAUAAAkFAEGQrMEACwYKBQAACwUAQaCswQALBgwFAAANBQBBsKzBAAsGDgUAAA8FAEHArMEACwYQBQAAEQUAQdCswQALBhIFAAATBQBB4KzBAAsGFAUAABUFAEHwrMEACwYWBQAAFwUAQYCtwQALBhgFAAAZBQBBkK3BAAsGGgUAABsFAEGgrcEACwYcBQAAHQUAQbCtwQALBh4FAAAfBQBBwK3BAAsGIAUAACEFAEHQrcEACwYiBQAAIwUAQeCtwQALBiQFAAAlBQBB8K3BAAsGJgUAACcFAEGArsEACwYoBQAAKQUAQZCuwQALBioFAAArBQBBoK7BAAsGLAUAAC0FAEGwrsEACwYuBQAALwUAQcCuwQALBjEFAABhBQBB0K7BAAsGMgUAAGIFAEHgrsEACwYzBQAAYwUAQfCuwQALBjQFAABkBQBBgK/BAAsGNQUAAGUFAEGQr8EACwY2BQAAZgUAQaCvwQALBjcFAABnBQBBsK/BAAsGOAUAAGgFAEHAr8EACwY5BQAAaQUAQdCvwQALBjoFAABqBQBB4K/BAAsGOwUAAGsFAEHwr8EACwY8BQAAbAUAQYCwwQALBj0FAABtBQBBkLDBAAsGPgUAAG4FAEGgsMEACwY/BQAAbwUAQbCwwQALBkAFAABwBQBBwLDBAAsGQQUAAHEFAEHQsMEACwZCBQAAcgUAQeCwwQALBkMFAABzBQBB8LDBAAsGRAUAAHQFAEGAscEACwZFBQAAdQUAQZCxwQALBkYFAAB2BQBBoLHBAAsGRwUAAHcFAEGwscEACwZIBQAAeAUAQcCxwQALBkkFAAB5BQBB0LHBAAsGSgUAAHoFAEHgscEACwZLBQAAewUAQfCxwQALBkwFAAB8BQBBgLLBAAsGTQUAAH0FAEGQssEACwZOBQAAfgUAQaCywQALBk8FAAB/BQBBsLLBAAsGUAUAAIAFAEHAssEACwZRBQAAgQUAQdCywQALBlIFAACCBQBB4LLBAAsGUwUAAIMFAEHwssEACwZUBQAAhAUAQYCzwQALBlUFAACFBQBBkLPBAAsGVgUAAIYFAEGgs8EACwagEAAAAC0AQbCzwQALBqEQAAABLQBBwLPBAAsGohAAAAItAEHQs8EACwajEAAAAy0AQeCzwQALBqQQAAAELQBB8LPBAAsGpRAAAAUtAEGAtMEACwamEAAABi0AQZC0wQALBqcQAAAHLQBBoLTBAAsGqBAAAAgtAEGwtMEACwapEAAACS0AQcC0wQALBqoQAAAKLQBB0LTBAAsGqxAAAAstAEHgtMEACwasEAAADC0AQfC0wQALBq0QAAANLQBBgLXBAAsGrhAAAA4tAEGQtcEACwavEAAADy0AQaC1wQALBrAQAAAQLQBBsLXBAAsGsRAAABEtAEHAtcEACwayEAAAEi0AQdC1wQALBrMQAAATLQBB4LXBAAsGtBAAABQtAEHwtcEACwa1EAAAFS0AQYC2wQALBrYQAAAWLQBBkLbBAAsGtxAAABctAEGgtsEACwa4EAAAGC0AQbC2wQALBrkQAAAZLQBBwLbBAAsGuhAAABotAEHQtsEACwa7EAAAGy0AQeC2wQALBrwQAAAcLQBB8LbBAAsGvRAAAB0tAEGAt8EACwa+EAAAHi0AQZC3wQALBr8QAAAfLQBBoLfBAAsGwBAAACAtAEGwt8EACwbBEAAAIS0AQcC3wQALBsIQAAAiLQBB0LfBAAsGwxAAACMtAEHgt8EACwbEEAAAJC0AQfC3wQALBsUQAAAlLQBBgLjBAAsGxxAAACctAEGQuMEACwbNEAAALS0AQaC4wQALBqATAABwqwBBsLjBAAsGoRMAAHGrAEHAuMEACwaiEwAAcqsAQdC4wQALBqMTAABzqwBB4LjBAAsGpBMAAHSrAEHwuMEACwalEwAAdasAQYC5wQALBqYTAAB2qwBBkLnBAAsGpxMAAHerAEGgucEACwaoEwAAeKsAQbC5wQALBqkTAAB5qwBBwLnBAAsGqhMAAHqrAEHQucEACwarEwAAe6sAQeC5wQALBqwTAAB8qwBB8LnBAAsGrRMAAH2rAEGAusEACwauEwAAfqsAQZC6wQALBq8TAAB/qwBBoLrBAAsGsBMAAICrAEGwusEACwaxEwAAgasAQcC6wQALBrITAACCqwBB0LrBAAsGsxMAAIOrAEHgusEACwa0EwAAhKsAQfC6wQALBrUTAACFqwBBgLvBAAsGthMAAIarAEGQu8EACwa3EwAAh6sAQaC7wQALBrgTAACIqwBBsLvBAAsGuRMAAImrAEHAu8EACwa6EwAAiqsAQdC7wQALBrsTAACLqwBB4LvBAAsGvBMAAIyrAEHwu8EACwa9EwAAjasAQYC8wQALBr4TAACOqwBBkLzBAAsGvxMAAI+rAEGgvMEACwbAEwAAkKsAQbC8wQALBsETAACRqwBBwLzBAAsGwhMAAJKrAEHQvMEACwbDEwAAk6sAQeC8wQALBsQTAACUqwBB8LzBAAsGxRMAAJWrAEGAvcEACwbGEwAAlqsAQZC9wQALBscTAACXqwBBoL3BAAsGyBMAAJirAEGwvcEACwbJEwAAmasAQcC9wQALBsoTAACaqwBB0L3BAAsGyxMAAJurAEHgvcEACwbMEwAAnKsAQfC9wQALBs0TAACdqwBBgL7BAAsGzhMAAJ6rAEGQvsEACwbPEwAAn6sAQaC+wQALBtATAACgqwBBsL7BAAsG0RMAAKGrAEHAvsEACwbSEwAAoqsAQdC+wQALBtMTAACjqwBB4L7BAAsG1BMAAKSrAEHwvsEACwbVEwAApasAQYC/wQALBtYTAACmqwBBkL/BAAsG1xMAAKerAEGgv8EACwbYEwAAqKsAQbC/wQALBtkTAACpqwBBwL/BAAsG2hMAAKqrAEHQv8EACwbbEwAAq6sAQeC/wQALBtwTAACsqwBB8L/BAAsG3RMAAK2rAEGAwMEACwbeEwAArqsAQZDAwQALBt8TAACvqwBBoMDBAAsG4BMAALCrAEGwwMEACwbhEwAAsasAQcDAwQALBuITAACyqwBB0MDBAAsG4xMAALOrAEHgwMEACwbkEwAAtKsAQfDAwQALBuUTAAC1qwBBgMHBAAsG5hMAALarAEGQwcEACwbnEwAAt6sAQaDBwQALBugTAAC4qwBBsMHBAAsG6RMAALmrAEHAwcEACwbqEwAAuqsAQdDBwQALBusTAAC7qwBB4MHBAAsG7BMAALyrAEHwwcEACwbtEwAAvasAQYDCwQALBu4TAAC+qwBBkMLBAAsG7xMAAL+rAEGgwsEACwbwEwAA+BMAQbDCwQALBvETAAD5EwBBwMLBAAsG8hMAAPoTAEHQwsEACwbzEwAA+xMAQeDCwQALBvQTAAD8EwBB8MLBAAsG9RMAAP0TAEGAw8EACwaQHAAA0BAAQZDDwQALBpEcAADREABBoMPBAAsGkhwAANIQAEGww8EACwaTHAAA0xAAQcDDwQALBpQcAADUEABB0MPBAAsGlRwAANUQAEHgw8EACwaWHAAA1hAAQfDDwQALBpccAADXEABBgMTBAAsGmBwAANgQAEGQxMEACwaZHAAA2RAAQaDEwQALBpocAADaEABBsMTBAAsGmxwAANsQAEHAxMEACwacHAAA3BAAQdDEwQALBp0cAADdEABB4MTBAAsGnhwAAN4QAEHwxMEACwafHAAA3xAAQYDFwQALBqAcAADgEABBkMXBAAsGoRwAAOEQAEGgxcEACwaiHAAA4hAAQbDFwQALBqMcAADjEABBwMXBAAsGpBwAAOQQAEHQxcEACwalHAAA5RAAQeDFwQALBqYcAADmEABB8MXBAAsGpxwAAOcQAEGAxsEACwaoHAAA6BAAQZDGwQALBqkcAADpEABBoMbBAAsGqhwAAOoQAEGwxsEACwarHAAA6xAAQcDGwQALBqwcAADsEABB0MbBAAsGrRwAAO0QAEHgxsEACwauHAAA7hAAQfDGwQALBq8cAADvEABBgMfBAAsGsBwAAPAQAEGQx8EACwaxHAAA8RAAQaDHwQALBrIcAADyEABBsMfBAAsGsxwAAPMQAEHAx8EACwa0HAAA9BAAQdDHwQALBrUcAAD1EABB4MfBAAsGthwAAPYQAEHwx8EACwa3HAAA9xAAQYDIwQALBrgcAAD4EABBkMjBAAsGuRwAAPkQAEGgyMEACwa6HAAA+hAAQbDIwQALBr0cAAD9EABBwMjBAAsGvhwAAP4QAEHQyMEACwa/HAAA/xAAQeHIwQALBR4AAAEeAEHwyMEACwYCHgAAAx4AQYDJwQALBgQeAAAFHgBBkMnBAAsGBh4AAAceAEGgycEACwYIHgAACR4AQbDJwQALBgoeAAALHgBBwMnBAAsGDB4AAA0eAEHQycEACwYOHgAADx4AQeDJwQALBhAeAAARHgBB8MnBAAsGEh4AABMeAEGAysEACwYUHgAAFR4AQZDKwQALBhYeAAAXHgBBoMrBAAsGGB4AABkeAEGwysEACwYaHgAAGx4AQcDKwQALBhweAAAdHgBB0MrBAAsGHh4AAB8eAEHgysEACwYgHgAAIR4AQfDKwQALBiIeAAAjHgBBgMvBAAsGJB4AACUeAEGQy8EACwYmHgAAJx4AQaDLwQALBigeAAApHgBBsMvBAAsGKh4AACseAEHAy8EACwYsHgAALR4AQdDLwQALBi4eAAAvHgBB4MvBAAsGMB4AADEeAEHwy8EACwYyHgAAMx4AQYDMwQALBjQeAAA1HgBBkMzBAAsGNh4AADceAEGgzMEACwY4HgAAOR4AQbDMwQALBjoeAAA7HgBBwMzBAAsGPB4AAD0eAEHQzMEACwY+HgAAPx4AQeDMwQALBkAeAABBHgBB8MzBAAsGQh4AAEMeAEGAzcEACwZEHgAARR4AQZDNwQALBkYeAABHHgBBoM3BAAsGSB4AAEkeAEGwzcEACwZKHgAASx4AQcDNwQALBkweAABNHgBB0M3BAAsGTh4AAE8eAEHgzcEACwZQHgAAUR4AQfDNwQALBlIeAABTHgBBgM7BAAsGVB4AAFUeAEGQzsEACwZWHgAAVx4AQaDOwQALBlgeAABZHgBBsM7BAAsGWh4AAFseAEHAzsEACwZcHgAAXR4AQdDOwQALBl4eAABfHgBB4M7BAAsGYB4AAGEeAEHwzsEACwZiHgAAYx4AQYDPwQALBmQeAABlHgBBkM/BAAsGZh4AAGceAEGgz8EACwZoHgAAaR4AQbDPwQALBmoeAABrHgBBwM/BAAsGbB4AAG0eAEHQz8EACwZuHgAAbx4AQeDPwQALBnAeAABxHgBB8M/BAAsGch4AAHMeAEGA0MEACwZ0HgAAdR4AQZDQwQALBnYeAAB3HgBBoNDBAAsGeB4AAHkeAEGw0MEACwZ6HgAAex4AQcDQwQALBnweAAB9HgBB0NDBAAsGfh4AAH8eAEHg0MEACwaAHgAAgR4AQfDQwQALBoIeAACDHgBBgNHBAAsGhB4AAIUeAEGQ0cEACwaGHgAAhx4AQaDRwQALBogeAACJHgBBsNHBAAsGih4AAIseAEHA0cEACwaMHgAAjR4AQdDRwQALBo4eAACPHgBB4NHBAAsGkB4AAJEeAEHw0cEACwaSHgAAkx4AQYDSwQALBpQeAACVHgBBkNLBAAsFnh4AAN8AQaDSwQALBqAeAAChHgBBsNLBAAsGoh4AAKMeAEHA0sEACwakHgAApR4AQdDSwQALBqYeAACnHgBB4NLBAAsGqB4AAKkeAEHw0sEACwaqHgAAqx4AQYDTwQALBqweAACtHgBBkNPBAAsGrh4AAK8eAEGg08EACwawHgAAsR4AQbDTwQALBrIeAACzHgBBwNPBAAsGtB4AALUeAEHQ08EACwa2HgAAtx4AQeDTwQALBrgeAAC5HgBB8NPBAAsGuh4AALseAEGA1MEACwa8HgAAvR4AQZDUwQALBr4eAAC/HgBBoNTBAAsGwB4AAMEeAEGw1MEACwbCHgAAwx4AQcDUwQALBsQeAADFHgBB0NTBAAsGxh4AAMceAEHg1MEACwbIHgAAyR4AQfDUwQALBsoeAADLHgBBgNXBAAsGzB4AAM0eAEGQ1cEACwbOHgAAzx4AQaDVwQALBtAeAADRHgBBsNXBAAsG0h4AANMeAEHA1cEACwbUHgAA1R4AQdDVwQALBtYeAADXHgBB4NXBAAsG2B4AANkeAEHw1cEACwbaHgAA2x4AQYDWwQALBtweAADdHgBBkNbBAAsG3h4AAN8eAEGg1sEACwbgHgAA4R4AQbDWwQALBuIeAADjHgBBwNbBAAsG5B4AAOUeAEHQ1sEACwbmHgAA5x4AQeDWwQALBugeAADpHgBB8NbBAAsG6h4AAOseAEGA18EACwbsHgAA7R4AQZDXwQALBu4eAADvHgBBoNfBAAsG8B4AAPEeAEGw18EACwbyHgAA8x4AQcDXwQALBvQeAAD1HgBB0NfBAAsG9h4AAPceAEHg18EACwb4HgAA+R4AQfDXwQALBvoeAAD7HgBBgNjBAAsG/B4AAP0eAEGQ2MEACwb+HgAA/x4AQaDYwQALBggfAAAAHwBBsNjBAAsGCR8AAAEfAEHA2MEACwYKHwAAAh8AQdDYwQALBgsfAAADHwBB4NjBAAsGDB8AAAQfAEHw2MEACwYNHwAABR8AQYDZwQALBg4fAAAGHwBBkNnBAAsGDx8AAAcfAEGg2cEACwYYHwAAEB8AQbDZwQALBhkfAAARHwBBwNnBAAsGGh8AABIfAEHQ2cEACwYbHwAAEx8AQeDZwQALBhwfAAAUHwBB8NnBAAsGHR8AABUfAEGA2sEACwYoHwAAIB8AQZDawQALBikfAAAhHwBBoNrBAAsGKh8AACIfAEGw2sEACwYrHwAAIx8AQcDawQALBiwfAAAkHwBB0NrBAAsGLR8AACUfAEHg2sEACwYuHwAAJh8AQfDawQALBi8fAAAnHwBBgNvBAAsGOB8AADAfAEGQ28EACwY5HwAAMR8AQaDbwQALBjofAAAyHwBBsNvBAAsGOx8AADMfAEHA28EACwY8HwAANB8AQdDbwQALBj0fAAA1HwBB4NvBAAsGPh8AADYfAEHw28EACwY/HwAANx8AQYDcwQALBkgfAABAHwBBkNzBAAsGSR8AAEEfAEGg3MEACwZKHwAAQh8AQbDcwQALBksfAABDHwBBwNzBAAsGTB8AAEQfAEHQ3MEACwZNHwAARR8AQeDcwQALBlkfAABRHwBB8NzBAAsGWx8AAFMfAEGA3cEACwZdHwAAVR8AQZDdwQALBl8fAABXHwBBoN3BAAsGaB8AAGAfAEGw3cEACwZpHwAAYR8AQcDdwQALBmofAABiHwBB0N3BAAsGax8AAGMfAEHg3cEACwZsHwAAZB8AQfDdwQALBm0fAABlHwBBgN7BAAsGbh8AAGYfAEGQ3sEACwZvHwAAZx8AQaDewQALBogfAACAHwBBsN7BAAsGiR8AAIEfAEHA3sEACwaKHwAAgh8AQdDewQALBosfAACDHwBB4N7BAAsGjB8AAIQfAEHw3sEACwaNHwAAhR8AQYDfwQALBo4fAACGHwBBkN/BAAsGjx8AAIcfAEGg38EACwaYHwAAkB8AQbDfwQALBpkfAACRHwBBwN/BAAsGmh8AAJIfAEHQ38EACwabHwAAkx8AQeDfwQALBpwfAACUHwBB8N/BAAsGnR8AAJUfAEGA4MEACwaeHwAAlh8AQZDgwQALBp8fAACXHwBBoODBAAsGqB8AAKAfAEGw4MEACwapHwAAoR8AQcDgwQALBqofAACiHwBB0ODBAAsGqx8AAKMfAEHg4MEACwasHwAApB8AQfDgwQALBq0fAAClHwBBgOHBAAsGrh8AAKYfAEGQ4cEACwavHwAApx8AQaDhwQALBrgfAACwHwBBsOHBAAsGuR8AALEfAEHA4cEACwa6HwAAcB8AQdDhwQALBrsfAABxHwBB4OHBAAsGvB8AALMfAEHw4cEACwbIHwAAch8AQYDiwQALBskfAABzHwBBkOLBAAsGyh8AAHQfAEGg4sEACwbLHwAAdR8AQbDiwQALBswfAADDHwBBwOLBAAsG2B8AANAfAEHQ4sEACwbZHwAA0R8AQeDiwQALBtofAAB2HwBB8OLBAAsG2x8AAHcfAEGA48EACwboHwAA4B8AQZDjwQALBukfAADhHwBBoOPBAAsG6h8AAHofAEGw48EACwbrHwAAex8AQcDjwQALBuwfAADlHwBB0OPBAAsG+B8AAHgfAEHg48EACwb5HwAAeR8AQfDjwQALBvofAAB8HwBBgOTBAAsG+x8AAH0fAEGQ5MEACwb8HwAA8x8AQaDkwQALBiYhAADJAwBBsOTBAAsFKiEAAGsAQcDkwQALBSshAADlAEHQ5MEACwYyIQAATiEAQeDkwQALBmAhAABwIQBB8OTBAAsGYSEAAHEhAEGA5cEACwZiIQAAciEAQZDlwQALBmMhAABzIQBBoOXBAAsGZCEAAHQhAEGw5cEACwZlIQAAdSEAQcDlwQALBmYhAAB2IQBB0OXBAAsGZyEAAHchAEHg5cEACwZoIQAAeCEAQfDlwQALBmkhAAB5IQBBgObBAAsGaiEAAHohAEGQ5sEACwZrIQAAeyEAQaDmwQALBmwhAAB8IQBBsObBAAsGbSEAAH0hAEHA5sEACwZuIQAAfiEAQdDmwQALBm8hAAB/IQBB4ObBAAsGgyEAAIQhAEHw5sEACwa2JAAA0CQAQYDnwQALBrckAADRJABBkOfBAAsGuCQAANIkAEGg58EACwa5JAAA0yQAQbDnwQALBrokAADUJABBwOfBAAsGuyQAANUkAEHQ58EACwa8JAAA1iQAQeDnwQALBr0kAADXJABB8OfBAAsGviQAANgkAEGA6MEACwa/JAAA2SQAQZDowQALBsAkAADaJABBoOjBAAsGwSQAANskAEGw6MEACwbCJAAA3CQAQcDowQALBsMkAADdJABB0OjBAAsGxCQAAN4kAEHg6MEACwbFJAAA3yQAQfDowQALBsYkAADgJABBgOnBAAsGxyQAAOEkAEGQ6cEACwbIJAAA4iQAQaDpwQALBskkAADjJABBsOnBAAsGyiQAAOQkAEHA6cEACwbLJAAA5SQAQdDpwQALBswkAADmJABB4OnBAAsGzSQAAOckAEHw6cEACwbOJAAA6CQAQYDqwQALBs8kAADpJABBkerBAAsFLAAAMCwAQaDqwQALBgEsAAAxLABBsOrBAAsGAiwAADIsAEHA6sEACwYDLAAAMywAQdDqwQALBgQsAAA0LABB4OrBAAsGBSwAADUsAEHw6sEACwYGLAAANiwAQYDrwQALBgcsAAA3LABBkOvBAAsGCCwAADgsAEGg68EACwYJLAAAOSwAQbDrwQALBgosAAA6LABBwOvBAAsGCywAADssAEHQ68EACwYMLAAAPCwAQeDrwQALBg0sAAA9LABB8OvBAAsGDiwAAD4sAEGA7MEACwYPLAAAPywAQZDswQALBhAsAABALABBoOzBAAsGESwAAEEsAEGw7MEACwYSLAAAQiwAQcDswQALBhMsAABDLABB0OzBAAsGFCwAAEQsAEHg7MEACwYVLAAARSwAQfDswQALBhYsAABGLABBgO3BAAsGFywAAEcsAEGQ7cEACwYYLAAASCwAQaDtwQALBhksAABJLABBsO3BAAsGGiwAAEosAEHA7cEACwYbLAAASywAQdDtwQALBhwsAABMLABB4O3BAAsGHSwAAE0sAEHw7cEACwYeLAAATiwAQYDuwQALBh8sAABPLABBkO7BAAsGICwAAFAsAEGg7sEACwYhLAAAUSwAQbDuwQALBiIsAABSLABBwO7BAAsGIywAAFMsAEHQ7sEACwYkLAAAVCwAQeDuwQALBiUsAABVLABB8O7BAAsGJiwAAFYsAEGA78EACwYnLAAAVywAQZDvwQALBigsAABYLABBoO/BAAsGKSwAAFksAEGw78EACwYqLAAAWiwAQcDvwQALBissAABbLABB0O/BAAsGLCwAAFwsAEHg78EACwYtLAAAXSwAQfDvwQALBi4sAABeLABBgPDBAAsGYCwAAGEsAEGQ8MEACwZiLAAAawIAQaDwwQALBmMsAAB9HQBBsPDBAAsGZCwAAH0CAEHA8MEACwZnLAAAaCwAQdDwwQALBmksAABqLABB4PDBAAsGaywAAGwsAEHw8MEACwZtLAAAUQIAQYDxwQALBm4sAABxAgBBkPHBAAsGbywAAFACAEGg8cEACwZwLAAAUgIAQbDxwQALBnIsAABzLABBwPHBAAsGdSwAAHYsAEHQ8cEACwZ+LAAAPwIAQeDxwQALBn8sAABAAgBB8PHBAAsGgCwAAIEsAEGA8sEACwaCLAAAgywAQZDywQALBoQsAACFLABBoPLBAAsGhiwAAIcsAEGw8sEACwaILAAAiSwAQcDywQALBoosAACLLABB0PLBAAsGjCwAAI0sAEHg8sEACwaOLAAAjywAQfDywQALBpAsAACRLABBgPPBAAsGkiwAAJMsAEGQ88EACwaULAAAlSwAQaDzwQALBpYsAACXLABBsPPBAAsGmCwAAJksAEHA88EACwaaLAAAmywAQdDzwQALBpwsAACdLABB4PPBAAsGniwAAJ8sAEHw88EACwagLAAAoSwAQYD0wQALBqIsAACjLABBkPTBAAsGpCwAAKUsAEGg9MEACwamLAAApywAQbD0wQALBqgsAACpLABBwPTBAAsGqiwAAKssAEHQ9MEACwasLAAArSwAQeD0wQALBq4sAACvLABB8PTBAAsGsCwAALEsAEGA9cEACwayLAAAsywAQZD1wQALBrQsAAC1LABBoPXBAAsGtiwAALcsAEGw9cEACwa4LAAAuSwAQcD1wQALBrosAAC7LABB0PXBAAsGvCwAAL0sAEHg9cEACwa+LAAAvywAQfD1wQALBsAsAADBLABBgPbBAAsGwiwAAMMsAEGQ9sEACwbELAAAxSwAQaD2wQALBsYsAADHLABBsPbBAAsGyCwAAMksAEHA9sEACwbKLAAAyywAQdD2wQALBswsAADNLABB4PbBAAsGziwAAM8sAEHw9sEACwbQLAAA0SwAQYD3wQALBtIsAADTLABBkPfBAAsG1CwAANUsAEGg98EACwbWLAAA1ywAQbD3wQALBtgsAADZLABBwPfBAAsG2iwAANssAEHQ98EACwbcLAAA3SwAQeD3wQALBt4sAADfLABB8PfBAAsG4CwAAOEsAEGA+MEACwbiLAAA4ywAQZD4wQALBussAADsLABBoPjBAAsG7SwAAO4sAEGw+MEACwbyLAAA8ywAQcD4wQALBkCmAABBpgBB0PjBAAsGQqYAAEOmAEHg+MEACwZEpgAARaYAQfD4wQALBkamAABHpgBBgPnBAAsGSKYAAEmmAEGQ+cEACwZKpgAAS6YAQaD5wQALBkymAABNpgBBsPnBAAsGTqYAAE+mAEHA+cEACwZQpgAAUaYAQdD5wQALBlKmAABTpgBB4PnBAAsGVKYAAFWmAEHw+cEACwZWpgAAV6YAQYD6wQALBlimAABZpgBBkPrBAAsGWqYAAFumAEGg+sEACwZcpgAAXaYAQbD6wQALBl6mAABfpgBBwPrBAAsGYKYAAGGmAEHQ+sEACwZipgAAY6YAQeD6wQALBmSmAABlpgBB8PrBAAsGZqYAAGemAEGA+8EACwZopgAAaaYAQZD7wQALBmqmAABrpgBBoPvBAAsGbKYAAG2mAEGw+8EACwaApgAAgaYAQcD7wQALBoKmAACDpgBB0PvBAAsGhKYAAIWmAEHg+8EACwaGpgAAh6YAQfD7wQALBoimAACJpgBBgPzBAAsGiqYAAIumAEGQ/MEACwaMpgAAjaYAQaD8wQALBo6mAACPpgBBsPzBAAsGkKYAAJGmAEHA/MEACwaSpgAAk6YAQdD8wQALBpSmAACVpgBB4PzBAAsGlqYAAJemAEHw/MEACwaYpgAAmaYAQYD9wQALBpqmAACbpgBBkP3BAAsGIqcAACOnAEGg/cEACwYkpwAAJacAQbD9wQALBianAAAnpwBBwP3BAAsGKKcAACmnAEHQ/cEACwYqpwAAK6cAQeD9wQALBiynAAAtpwBB8P3BAAsGLqcAAC+nAEGA/sEACwYypwAAM6cAQZD+wQALBjSnAAA1pwBBoP7BAAsGNqcAADenAEGw/sEACwY4pwAAOacAQcD+wQALBjqnAAA7pwBB0P7BAAsGPKcAAD2nAEHg/sEACwY+pwAAP6cAQfD+wQALBkCnAABBpwBBgP/BAAsGQqcAAEOnAEGQ/8EACwZEpwAARacAQaD/wQALBkanAABHpwBBsP/BAAsGSKcAAEmnAEHA/8EACwZKpwAAS6cAQdD/wQALBkynAABNpwBB4P/BAAsGTqcAAE+nAEHw/8EACwZQpwAAUacAQYCAwgALBlKnAABTpwBBkIDCAAsGVKcAAFWnAEGggMIACwZWpwAAV6cAQbCAwgALBlinAABZpwBBwIDCAAsGWqcAAFunAEHQgMIACwZcpwAAXacAQeCAwgALBl6nAABfpwBB8IDCAAsGYKcAAGGnAEGAgcIACwZipwAAY6cAQZCBwgALBmSnAABlpwBBoIHCAAsGZqcAAGenAEGwgcIACwZopwAAaacAQcCBwgALBmqnAABrpwBB0IHCAAsGbKcAAG2nAEHggcIACwZupwAAb6cAQfCBwgALBnmnAAB6pwBBgILCAAsGe6cAAHynAEGQgsIACwZ9pwAAeR0AQaCCwgALBn6nAAB/pwBBsILCAAsGgKcAAIGnAEHAgsIACwaCpwAAg6cAQdCCwgALBoSnAACFpwBB4ILCAAsGhqcAAIenAEHwgsIACwaLpwAAjKcAQYCDwgALBo2nAABlAgBBkIPCAAsGkKcAAJGnAEGgg8IACwaSpwAAk6cAQbCDwgALBpanAACXpwBBwIPCAAsGmKcAAJmnAEHQg8IACwaapwAAm6cAQeCDwgALBpynAACdpwBB8IPCAAsGnqcAAJ+nAEGAhMIACwagpwAAoacAQZCEwgALBqKnAACjpwBBoITCAAsGpKcAAKWnAEGwhMIACwampwAAp6cAQcCEwgALBqinAACppwBB0ITCAAsGqqcAAGYCAEHghMIACwarpwAAXAIAQfCEwgALBqynAABhAgBBgIXCAAsGracAAGwCAEGQhcIACwaupwAAagIAQaCFwgALBrCnAACeAgBBsIXCAAsGsacAAIcCAEHAhcIACwaypwAAnQIAQdCFwgALBrOnAABTqwBB4IXCAAsGtKcAALWnAEHwhcIACwa2pwAAt6cAQYCGwgALBrinAAC5pwBBkIbCAAsGuqcAALunAEGghsIACwa8pwAAvacAQbCGwgALBr6nAAC/pwBBwIbCAAsGwqcAAMOnAEHQhsIACwbEpwAAlKcAQeCGwgALBsWnAACCAgBB8IbCAAsGxqcAAI4dAEGAh8IACwYh/wAAQf8AQZCHwgALBiL/AABC/wBBoIfCAAsGI/8AAEP/AEGwh8IACwYk/wAARP8AQcCHwgALBiX/AABF/wBB0IfCAAsGJv8AAEb/AEHgh8IACwYn/wAAR/8AQfCHwgALBij/AABI/wBBgIjCAAsGKf8AAEn/AEGQiMIACwYq/wAASv8AQaCIwgALBiv/AABL/wBBsIjCAAsGLP8AAEz/AEHAiMIACwYt/wAATf8AQdCIwgALBi7/AABO/wBB4IjCAAsGL/8AAE//AEHwiMIACwYw/wAAUP8AQYCJwgALBjH/AABR/wBBkInCAAsGMv8AAFL/AEGgicIACwYz/wAAU/8AQbCJwgALBjT/AABU/wBBwInCAAsGNf8AAFX/AEHQicIACwY2/wAAVv8AQeCJwgALBjf/AABX/wBB8InCAAsGOP8AAFj/AEGAisIACwY5/wAAWf8AQZCKwgALBjr/AABa/wBBoYrCAAsGBAEAKAQBAEGwisIACwcBBAEAKQQBAEHAisIACwcCBAEAKgQBAEHQisIACwcDBAEAKwQBAEHgisIACwcEBAEALAQBAEHwisIACwcFBAEALQQBAEGAi8IACwcGBAEALgQBAEGQi8IACwcHBAEALwQBAEGgi8IACwcIBAEAMAQBAEGwi8IACwcJBAEAMQQBAEHAi8IACwcKBAEAMgQBAEHQi8IACwcLBAEAMwQBAEHgi8IACwcMBAEANAQBAEHwi8IACwcNBAEANQQBAEGAjMIACwcOBAEANgQBAEGQjMIACwcPBAEANwQBAEGgjMIACwcQBAEAOAQBAEGwjMIACwcRBAEAOQQBAEHAjMIACwcSBAEAOgQBAEHQjMIACwcTBAEAOwQBAEHgjMIACwcUBAEAPAQBAEHwjMIACwcVBAEAPQQBAEGAjcIACwcWBAEAPgQBAEGQjcIACwcXBAEAPwQBAEGgjcIACwcYBAEAQAQBAEGwjcIACwcZBAEAQQQBAEHAjcIACwcaBAEAQgQBAEHQjcIACwcbBAEAQwQBAEHgjcIACwccBAEARAQBAEHwjcIACwcdBAEARQQBAEGAjsIACwceBAEARgQBAEGQjsIACwcfBAEARwQBAEGgjsIACwcgBAEASAQBAEGwjsIACwchBAEASQQBAEHAjsIACwciBAEASgQBAEHQjsIACwcjBAEASwQBAEHgjsIACwckBAEATAQBAEHwjsIACwclBAEATQQBAEGAj8IACwcmBAEATgQBAEGQj8IACwcnBAEATwQBAEGgj8IACwewBAEA2AQBAEGwj8IACwexBAEA2QQBAEHAj8IACweyBAEA2gQBAEHQj8IACwezBAEA2wQBAEHgj8IACwe0BAEA3AQBAEHwj8IACwe1BAEA3QQBAEGAkMIACwe2BAEA3gQBAEGQkMIACwe3BAEA3wQBAEGgkMIACwe4BAEA4AQBAEGwkMIACwe5BAEA4QQBAEHAkMIACwe6BAEA4gQBAEHQkMIACwe7BAEA4wQBAEHgkMIACwe8BAEA5AQBAEHwkMIACwe9BAEA5QQBAEGAkcIACwe+BAEA5gQBAEGQkcIACwe/BAEA5wQBAEGgkcIACwfABAEA6AQBAEGwkcIACwfBBAEA6QQBAEHAkcIACwfCBAEA6gQBAEHQkcIACwfDBAEA6wQBAEHgkcIACwfEBAEA7AQBAEHwkcIACwfFBAEA7QQBAEGAksIACwfGBAEA7gQBAEGQksIACwfHBAEA7wQBAEGgksIACwfIBAEA8AQBAEGwksIACwfJBAEA8QQBAEHAksIACwfKBAEA8gQBAEHQksIACwfLBAEA8wQBAEHgksIACwfMBAEA9AQBAEHwksIACwfNBAEA9QQBAEGAk8IACwfOBAEA9gQBAEGQk8IACwfPBAEA9wQBAEGgk8IACwfQBAEA+AQBAEGwk8IACwfRBAEA+QQBAEHAk8IACwfSBAEA+gQBAEHQk8IACwfTBAEA+wQBAEHgk8IACweADAEAwAwBAEHwk8IACweBDAEAwQwBAEGAlMIACweCDAEAwgwBAEGQlMIACweDDAEAwwwBAEGglMIACweEDAEAxAwBAEGwlMIACweFDAEAxQwBAEHAlMIACweGDAEAxgwBAEHQlMIACweHDAEAxwwBAEHglMIACweIDAEAyAwBAEHwlMIACweJDAEAyQwBAEGAlcIACweKDAEAygwBAEGQlcIACweLDAEAywwBAEGglcIACweMDAEAzAwBAEGwlcIACweNDAEAzQwBAEHAlcIACweODAEAzgwBAEHQlcIACwePDAEAzwwBAEHglcIACweQDAEA0AwBAEHwlcIACweRDAEA0QwBAEGAlsIACweSDAEA0gwBAEGQlsIACweTDAEA0wwBAEGglsIACweUDAEA1AwBAEGwlsIACweVDAEA1QwBAEHAlsIACweWDAEA1gwBAEHQlsIACweXDAEA1wwBAEHglsIACweYDAEA2AwBAEHwlsIACweZDAEA2QwBAEGAl8IACweaDAEA2gwBAEGQl8IACwebDAEA2wwBAEGgl8IACwecDAEA3AwBAEGwl8IACwedDAEA3QwBAEHAl8IACweeDAEA3gwBAEHQl8IACwefDAEA3wwBAEHgl8IACwegDAEA4AwBAEHwl8IACwehDAEA4QwBAEGAmMIACweiDAEA4gwBAEGQmMIACwejDAEA4wwBAEGgmMIACwekDAEA5AwBAEGwmMIACwelDAEA5QwBAEHAmMIACwemDAEA5gwBAEHQmMIACwenDAEA5wwBAEHgmMIACweoDAEA6AwBAEHwmMIACwepDAEA6QwBAEGAmcIACweqDAEA6gwBAEGQmcIACwerDAEA6wwBAEGgmcIACwesDAEA7AwBAEGwmcIACwetDAEA7QwBAEHAmcIACweuDAEA7gwBAEHQmcIACwevDAEA7wwBAEHgmcIACwewDAEA8AwBAEHwmcIACwexDAEA8QwBAEGAmsIACweyDAEA8gwBAEGQmsIACwegGAEAwBgBAEGgmsIACwehGAEAwRgBAEGwmsIACweiGAEAwhgBAEHAmsIACwejGAEAwxgBAEHQmsIACwekGAEAxBgBAEHgmsIACwelGAEAxRgBAEHwmsIACwemGAEAxhgBAEGAm8IACwenGAEAxxgBAEGQm8IACweoGAEAyBgBAEGgm8IACwepGAEAyRgBAEGwm8IACweqGAEAyhgBAEHAm8IACwerGAEAyxgBAEHQm8IACwesGAEAzBgBAEHgm8IACwetGAEAzRgBAEHwm8IACweuGAEAzhgBAEGAnMIACwevGAEAzxgBAEGQnMIACwewGAEA0BgBAEGgnMIACwexGAEA0RgBAEGwnMIACweyGAEA0hgBAEHAnMIACwezGAEA0xgBAEHQnMIACwe0GAEA1BgBAEHgnMIACwe1GAEA1RgBAEHwnMIACwe2GAEA1hgBAEGAncIACwe3GAEA1xgBAEGQncIACwe4GAEA2BgBAEGgncIACwe5GAEA2RgBAEGwncIACwe6GAEA2hgBAEHAncIACwe7GAEA2xgBAEHQncIACwe8GAEA3BgBAEHgncIACwe9GAEA3RgBAEHwncIACwe+GAEA3hgBAEGAnsIACwe/GAEA3xgBAEGQnsIACwdAbgEAYG4BAEGgnsIACwdBbgEAYW4BAEGwnsIACwdCbgEAYm4BAEHAnsIACwdDbgEAY24BAEHQnsIACwdEbgEAZG4BAEHgnsIACwdFbgEAZW4BAEHwnsIACwdGbgEAZm4BAEGAn8IACwdHbgEAZ24BAEGQn8IACwdIbgEAaG4BAEGgn8IACwdJbgEAaW4BAEGwn8IACwdKbgEAam4BAEHAn8IACwdLbgEAa24BAEHQn8IACwdMbgEAbG4BAEHgn8IACwdNbgEAbW4BAEHwn8IACwdObgEAbm4BAEGAoMIACwdPbgEAb24BAEGQoMIACwdQbgEAcG4BAEGgoMIACwdRbgEAcW4BAEGwoMIACwdSbgEAcm4BAEHAoMIACwdTbgEAc24BAEHQoMIACwdUbgEAdG4BAEHgoMIACwdVbgEAdW4BAEHwoMIACwdWbgEAdm4BAEGAocIACwdXbgEAd24BAEGQocIACwdYbgEAeG4BAEGgocIACwdZbgEAeW4BAEGwocIACwdabgEAem4BAEHAocIACwdbbgEAe24BAEHQocIACwdcbgEAfG4BAEHgocIACwddbgEAfW4BAEHwocIACwdebgEAfm4BAEGAosIACwdfbgEAf24BAEGRosIACwbpAQAi6QEAQaCiwgALBwHpAQAj6QEAQbCiwgALBwLpAQAk6QEAQcCiwgALBwPpAQAl6QEAQdCiwgALBwTpAQAm6QEAQeCiwgALBwXpAQAn6QEAQfCiwgALBwbpAQAo6QEAQYCjwgALBwfpAQAp6QEAQZCjwgALBwjpAQAq6QEAQaCjwgALBwnpAQAr6QEAQbCjwgALBwrpAQAs6QEAQcCjwgALBwvpAQAt6QEAQdCjwgALBwzpAQAu6QEAQeCjwgALBw3pAQAv6QEAQfCjwgALBw7pAQAw6QEAQYCkwgALBw/pAQAx6QEAQZCkwgALBxDpAQAy6QEAQaCkwgALBxHpAQAz6QEAQbCkwgALBxLpAQA06QEAQcCkwgALBxPpAQA16QEAQdCkwgALBxTpAQA26QEAQeCkwgALBxXpAQA36QEAQfCkwgALBxbpAQA46QEAQYClwgALBxfpAQA56QEAQZClwgALBxjpAQA66QEAQaClwgALBxnpAQA76QEAQbClwgALBxrpAQA86QEAQcClwgALBxvpAQA96QEAQdClwgALBxzpAQA+6QEAQeClwgALBx3pAQA/6QEAQfClwgALBx7pAQBA6QEAQYCmwgALBx/pAQBB6QEAQZCmwgALByDpAQBC6QEAQaCmwgALByHpAQBD6QEAQbCmwgALsSD/////SGFzaCB0YWJsZSBjYXBhY2l0eSBvdmVyZmxvd2CTEABOAAAAUAAAABsAAAAvY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9oYXNoYnJvd24tMC42LjIvc3JjL3Jhdy9tb2QucnMAAAiWEABIAAAAAgIAACMAAAAIlhAASAAAAAMCAAAjAAAA4JMQAEkAAACHAgAAHQAAAC9ydXN0Yy83MzUyOGUzMzlhYWUwZjE3YTE1ZmZhNDlhOGFjNjA4ZjUwYzZjZjE0L3NyYy9saWJjb3JlL3NsaWNlL3NvcnQucnMAAADgkxAASQAAAJ0AAAA6AAAA4JMQAEkAAACkAAAAMAAAAA0AAAAAAAAAAQAAAA4AAAAPAAAAAAAAAAEAAAAQAAAAfJQQAD0AAAAlAAAADQAAAC9Vc2Vycy90aGF1bWFudC9QbGF5Z3JvdW5kL2x1Y2lkLXN1Z2dlc3QvcnVzdC9jb3JlL3NyYy9saWIucnNEdXBsaWNhdGUgc3RvcmUgaWQAfJQQAD0AAAAvAAAADQAAAHyUEAA9AAAAOwAAAA0AAABNaXNzaW5nIHN0b3JlIGlkfJQQAD0AAABDAAAADQAAAGFscmVhZHkgbXV0YWJseSBib3Jyb3dlZBEAAAAAAAAAAQAAABIAAAACAQMAEwAAAAAAAAABAAAADgAAABQAAAAAAAAAAQAAAA4AAAAAAAAAFQAAAAAAAAABAAAADgAAAHyVEABLAAAAIwAAAAkAAAAvVXNlcnMvdGhhdW1hbnQvUGxheWdyb3VuZC9sdWNpZC1zdWdnZXN0L3J1c3QvY29yZS9zcmMvdG9rZW5pemF0aW9uL3dvcmQucnNNYWxmb3JtZWQgd29yZHMAAOi5EAAXAAAAuQEAACcAAAAIlhAASAAAAOwKAAAKAAAACJYQAEgAAADyCgAADgAAAC9ydXN0Yy83MzUyOGUzMzlhYWUwZjE3YTE1ZmZhNDlhOGFjNjA4ZjUwYzZjZjE0L3NyYy9saWJjb3JlL3NsaWNlL21vZC5yc3CWEABFAAAACQMAAB4AAABwlhAARQAAAAwDAAA2AAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmFsbG9jL3NsaWNlLnJzYXNzZXJ0aW9uIGZhaWxlZDogbWlkIDw9IGxlbgAAAMS4EAAYAAAANgQAAA0AAABhc3NlcnRpb24gZmFpbGVkOiBpbmRleCA8PSBsZW4AABSXEAATAAAA4wMAAAkAAABzcmMvbGliYWxsb2MvdmVjLnJzYXNzZXJ0aW9uIGZhaWxlZDogaW5kZXggPCBsZW4UlxAAEwAAAAsEAAAJAAAAdJcQAEMAAAA3CwAAMAAAAHSXEABDAAAAQwsAACwAAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliYWxsb2MvdmVjLnJzABYAAAAAAAAAAQAAAA4AAAAGAAAAYgAAAAYAAABjAAAABgAAAGQAAAAGAAAAZgAAAAYAAABnAAAABgAAAGgAAAAGAAAAagAAAAYAAABrAAAABgAAAGwAAAAGAAAAbQAAAAYAAABuAAAABgAAAHAAAAAGAAAAcQAAAAYAAAByAAAABgAAAHMAAAAGAAAAdAAAAAYAAAB2AAAABgAAAHcAAAAGAAAAeAAAAAYAAAB6AAAABwAAAGEAAAAHAAAAZQAAAAcAAABpAAAABwAAAG8AAAAHAAAAdQAAAAcAAAB5AAAAw6BlbnRyZW1haXNxdWV2aWHDtMOJRcOAw4jDmVXDqMO5w4LDiknDlE/DosOHQ0XMgWXMgUHMgEXMgFXMgGHMgGXMgHXMgEHMgkXMgk/MgmHMgmXMgm/MgkPMp2PMp3Vt8JgQAAMAAADDmBAAAgAAAPOYEAADAAAAqrcQAAIAAAAeoRAAAwAAAAihEAACAAAAxpgQAAMAAACrmBAAAgAAACGhEAADAAAACqEQAAIAAAAkoRAAAwAAAAyhEAACAAAAJ6EQAAMAAAAOoRAAAgAAACqhEAADAAAAhrUQAAIAAADJmBAAAwAAAKy3EAACAAAALaEQAAMAAACItRAAAgAAADChEAADAAAAirUQAAIAAAAzoRAAAwAAABChEAACAAAA3pgQAAMAAAC5mBAAAgAAAOGYEAADAAAAu5gQAAIAAADkmBAAAwAAAL6YEAACAAAA55gQAAMAAADBmBAAAgAAAOqYEAADAAAArrcQAAIAAADtmBAAAwAAAKmYEAACAAAANqEQAAMAAAASoRAAAgAAADmhEAADAAAAFKEQAAIAAAA8oRAAAwAAAJipEAACAAAAP6EQAAMAAACaqRAAAgAAAMyYEAADAAAArpgQAAIAAADPmBAAAwAAALCYEAACAAAAQqEQAAMAAAAWoRAAAgAAAEWhEAADAAAAGKEQAAIAAADSmBAAAwAAALKYEAACAAAA1ZgQAAMAAACYmBAAAgAAANiYEAADAAAAtZgQAAIAAABIoRAAAwAAABqhEAACAAAAS6EQAAMAAAAcoRAAAgAAANuYEAADAAAAt5gQAAIAAADDmBAAAgAAAMWYEAABAAAAqrcQAAIAAABNqRAAAQAAAAihEAACAAAAU7kQAAEAAACrmBAAAgAAAK2YEAABAAAACqEQAAIAAAC9mBAAAQAAAAyhEAACAAAAwJgQAAEAAAAOoRAAAgAAALSYEAABAAAAhrUQAAIAAABMuRAAAQAAAKy3EAACAAAATbkQAAEAAACItRAAAgAAAE65EAABAAAAirUQAAIAAABPuRAAAQAAABChEAACAAAAULkQAAEAAAC5mBAAAgAAAFO5EAABAAAAu5gQAAIAAACtmBAAAQAAAL6YEAACAAAAwJgQAAEAAADBmBAAAgAAAEy5EAABAAAArrcQAAIAAABNuRAAAQAAAKmYEAACAAAAT7kQAAEAAAASoRAAAgAAAFO5EAABAAAAFKEQAAIAAADAmBAAAQAAAJipEAACAAAATLkQAAEAAACaqRAAAgAAAE+5EAABAAAArpgQAAIAAABTuRAAAQAAALCYEAACAAAArZgQAAEAAAAWoRAAAgAAAL2YEAABAAAAGKEQAAIAAADAmBAAAQAAALKYEAACAAAAtJgQAAEAAACYmBAAAgAAAEy5EAABAAAAtZgQAAIAAABNuRAAAQAAABqhEAACAAAATrkQAAEAAAAcoRAAAgAAAE+5EAABAAAAt5gQAAIAAABQuRAAAQAAAAkAAABPuRAAAQAAAAkAAABMuRAAAQAAAAkAAACEtRAAAgAAAAkAAAA4thAAAgAAAAkAAAD2mBAAAgAAAAkAAAAQoBAAAwAAAAkAAAAToBAAAwAAAAkAAAAWoBAABAAAAAUAAAAaoBAABgAAAAUAAAAgoBAABQAAAAUAAAAloBAABQAAAAUAAABfuBAABQAAAAUAAAAqoBAADwAAAAUAAAA5oBAABgAAAAUAAAA/oBAABgAAAAUAAABFoBAABwAAAAUAAABMoBAABAAAAAUAAABQoBAABgAAAAUAAABWoBAAAwAAAAUAAABZoBAABAAAAAUAAABdoBAACAAAAAUAAABloBAABgAAAAUAAABRuRAAAgAAAAUAAABroBAABgAAAAUAAABxoBAABQAAAAUAAAB2oBAACAAAAAUAAAB+oBAABwAAAAUAAAD2tRAAAgAAAAUAAACamBAABQAAAAUAAACFoBAABAAAAAUAAACJoBAABQAAAAUAAAC8tRAABAAAAAUAAACVthAABQAAAAUAAACOoBAABgAAAAUAAACUoBAABAAAAAUAAACfmBAABAAAAAUAAACYoBAAAwAAAAUAAACboBAABgAAAAUAAAChoBAABAAAAAUAAACloBAABQAAAAUAAACqoBAAAwAAAAUAAACtoBAACAAAAAUAAACjmBAAAwAAAAUAAAC1oBAAAwAAAAUAAAC4oBAAAwAAAAUAAAC7oBAABQAAAAUAAACmmBAAAwAAAAYAAADAoBAABQAAAAYAAABZoBAABAAAAAYAAADFoBAABwAAAAYAAABNuRAAAQAAAAYAAADMoBAACAAAAAYAAADUoBAABgAAAAYAAADaoBAABAAAAAYAAACYoBAAAwAAAAYAAADeoBAAAwAAAAYAAACctxAAAgAAAAYAAAChoBAABAAAAAYAAADhoBAABAAAAAYAAACqoBAAAwAAAAYAAADloBAABgAAAAYAAADroBAABgAAAAYAAADxoBAACAAAAAYAAAD5oBAABgAAAAYAAAD/oBAAAgAAAAYAAAABoRAABwAAAHVtYXVuc3VtYXNhYmFpeG9hY2ltYWFsw6ltYXByb3hpbWFkYW1lbnRlYXF1ZWxhYXF1ZWxlYXF1ZWxlc2F0w6lhdHLDoXNjb21jb21vY29uZm9ybWVjb250cmFkZXBvaXNkZXNkZWRpc3RhbnRlZHVyYW50ZWVzdGFlc3Rhc2V4Y2V0b2ZvcmFtYXNvcG9zdG9wYXJhcGVydG9wb3JwcsOzeGltb3NlbXNvYnNvYnJlYWdvcmFjb250dWRvZW5xdWFudG9lbnTDo29sb2dvbmVtcG9pc3BvcsOpbXBvcnF1ZXBvcnRhbnRvcXVhbmRvc2V0b2Rhdmlhw4HDjcOTw5rDusODw5XDjMOSw6zDskHMgUnMgU/MgVXMgWHMgWnMgW/MgXXMgUHMg0/Mg2HMg2/Mg0nMgE/MgGnMgG/MgE5vcm1hbGl6YXRpb24gc2hvdWxkIGFsd2F5cyBiZSB0aGUgZmlyc3Qgc3RlcACMoRAASwAAAGAAAAANAAAAL1VzZXJzL3RoYXVtYW50L1BsYXlncm91bmQvbHVjaWQtc3VnZ2VzdC9ydXN0L2NvcmUvc3JjL3Rva2VuaXphdGlvbi90ZXh0LnJzABcAAABjYW5ub3QgYWNjZXNzIGEgVGhyZWFkIExvY2FsIFN0b3JhZ2UgdmFsdWUgZHVyaW5nIG9yIGFmdGVyIGRlc3RydWN0aW9uYWxyZWFkeSBib3Jyb3dlZAAAGAAAAAAAAAABAAAADgAAABkAAAAAAAAAAQAAABAAAABkohAAcAAAAKEAAAARAAAAL1VzZXJzL3RoYXVtYW50Ly5jYXJnby9yZWdpc3RyeS9zcmMvZ2l0aHViLmNvbS0xZWNjNjI5OWRiOWVjODIzL3J1c3Qtc3RlbW1lcnMtMS4yLjAvc3JjL3Nub3diYWxsL3Nub3diYWxsX2Vudi5yc2SiEABwAAAAygAAABEAAABkohAAcAAAAAwBAAAWAAAAZKIQAHAAAAASAQAAGAAAAGSiEABwAAAALgEAABYAAABkohAAcAAAAFYBAAAWAAAAZKIQAHAAAABcAQAAGAAAAGSiEABwAAAAXAEAAEkAAABkohAAcAAAAHcBAAAWAAAAqrcQAAAAAAD/////AwBB7MbCAAsNmKkQAAIAAAAAAAAAAQBBhMfCAAsNmqkQAAIAAAAAAAAAAgBBnMfCAAsHYX5vfhFBEABBsMfCAAsRAxMMAse3EAADAAAA/////wEAQczHwgALDcq3EAAGAAAA/////wEAQeTHwgALDdC3EAAGAAAA/////wQAQfzHwgALDda3EAAFAAAA/////wIAQZTIwgALDaa1EAADAAAA/////wkAQazIwgALDdu3EAAFAAAA/////wEAQcTIwgALDeC3EAADAAAA/////wEAQdzIwgALDeO3EAAEAAAA/////wEAQfTIwgALDee3EAADAAAA/////wgAQYzJwgALDeq3EAADAAAA/////wEAQaTJwgALDe23EAAFAAAA/////wcAQbzJwgALDbi3EAAEAAAA/////wEAQdTJwgALDfK3EAAFAAAA/////wYAQezJwgALDfe3EAAGAAAADAAAAAUAQYTKwgALDf23EAAFAAAA/////wEAQZzKwgALDcC3EAAFAAAA/////wEAQbTKwgALDQK4EAADAAAA/////wEAQczKwgALDQW4EAAEAAAA/////wEAQeTKwgALDQm4EAADAAAA/////wEAQfzKwgALDQy4EAAGAAAA/////wEAQZTLwgALDRK4EAAGAAAA/////wEAQazLwgALDRi4EAADAAAA/////wgAQcTLwgALDRu4EAAGAAAA/////wEAQdzLwgALDSG4EAAGAAAA/////wMAQfTLwgALDSe4EAAEAAAA/////wEAQYzMwgALDSu4EAAEAAAA/////wEAQaTMwgALDS+4EAAHAAAA/////wQAQbzMwgALDTa4EAAGAAAA/////wIAQdTMwgALDVy2EAAEAAAA/////wkAQezMwgALDTy4EAAGAAAA/////wEAQYTNwgALDUK4EAAEAAAA/////wEAQZzNwgALDUa4EAAFAAAA/////wEAQbTNwgALDUu4EAAEAAAA/////wgAQczNwgALDU+4EAAEAAAA/////wEAQeTNwgALDVO4EAAGAAAA/////wcAQfzNwgALDVm4EAAGAAAA/////wEAQZTOwgALDV+4EAAFAAAA/////wEAQazOwgALDWS4EAAHAAAA/////wEAQcTOwgALDWu4EAAHAAAA/////wMAQdzOwgALDXK4EAAEAAAA/////wEAQfTOwgALDXa4EAAFAAAA/////wEAQYzPwgALDXu4EAAEAAAA/////wEAQaTPwgALDX+4EAAHAAAA/////wEAQbzPwgALDYa4EAAHAAAA/////wEAQdTPwgALDY24EAAEAAAA/////wgAQezPwgALGGxvZ2VudGUAsLcQAAIAAAD//////////wBBjNDCAAsQxbcQAAIAAAD//////////wBBpNDCAAsQhLUQAAIAAAD//////////wBBvNDCAAsNtrcQAAIAAAD/////AQBB1NDCAAsRYXQAALi3EAAEAAAA/////wEAQfDQwgALDby3EAAEAAAA/////wEAQYjRwgALDcC3EAAFAAAA/////wEAQaDRwgALDbC3EAACAAAA/////wEAQbjRwgALDbK3EAAEAAAA/////wEAQdDRwgALDba3EAACAAAA/////wEAQejRwgALEWlyAABNuRAAAQAAAP////8BAEGE0sIACw2qtxAAAgAAAP////8CAEGc0sIACw2stxAAAgAAAP////8BAEG00sIACw2utxAAAgAAAP////8BAEHM0sIACxFnYwAAqrcQAAAAAAD/////AwBB6NLCAAsNnKMQAAIAAAAAAAAAAQBBgNPCAAsNnqMQAAIAAAAAAAAAAgBBmNPCAAsRw6PDtYy1EAADAAAA/////wEAQbTTwgALDY+1EAADAAAA/////wEAQczTwgALDZK1EAACAAAA/////wEAQeTTwgALDZS1EAAEAAAAAgAAAAEAQfzTwgALDZi1EAAEAAAAAgAAAAEAQZTUwgALDZy1EAAEAAAAAgAAAAEAQazUwgALDaC1EAADAAAA/////wEAQcTUwgALDaO1EAADAAAA/////wEAQdzUwgALDaa1EAADAAAA/////wEAQfTUwgALDam1EAADAAAA/////wEAQYzVwgALDay1EAAEAAAA/////wEAQaTVwgALDbC1EAAEAAAA/////wEAQbzVwgALDbS1EAAEAAAA/////wEAQdTVwgALDbi1EAAEAAAA/////wEAQezVwgALDby1EAAEAAAA/////wEAQYTWwgALDcC1EAAEAAAA/////wEAQZzWwgALDcS1EAACAAAA/////wEAQbTWwgALDca1EAAEAAAAEAAAAAEAQczWwgALDcq1EAAEAAAAEAAAAAEAQeTWwgALDc61EAAEAAAAEAAAAAEAQfzWwgALDdK1EAACAAAA/////wEAQZTXwgALDdS1EAADAAAAFAAAAAEAQazXwgALDde1EAAFAAAAFQAAAAEAQcTXwgALDdy1EAAFAAAAFQAAAAEAQdzXwgALDeG1EAAFAAAAFQAAAAEAQfTXwgALDea1EAAEAAAAFAAAAAEAQYzYwgALDeq1EAAEAAAAFAAAAAEAQaTYwgALDe61EAAEAAAAFAAAAAEAQbzYwgALDfK1EAAEAAAAFAAAAAEAQdTYwgALDfa1EAACAAAA/////wEAQezYwgALDfi1EAAEAAAAHQAAAAEAQYTZwgALDfy1EAAEAAAAHQAAAAEAQZ3ZwgALDLYQAAQAAAAdAAAAAQBBtNnCAAsNBLYQAAUAAAAdAAAAAQBBzNnCAAsNCbYQAAUAAAAdAAAAAQBB5NnCAAsNDrYQAAUAAAAdAAAAAQBB/NnCAAsNE7YQAAMAAAD/////AQBBlNrCAAsNFrYQAAMAAAD/////AQBBrNrCAAsNGbYQAAQAAAD/////AQBBxNrCAAsNHbYQAAQAAAD/////AQBB3NrCAAsNIbYQAAQAAAD/////AQBB9NrCAAsNJbYQAAUAAAD/////AQBBjNvCAAsNKrYQAAUAAAD/////AQBBpNvCAAsNL7YQAAUAAAD/////AQBBvNvCAAsNNLYQAAIAAAD/////AQBB1NvCAAsNNrYQAAIAAAD/////AQBB7NvCAAsN6KgQAAIAAAD/////AQBBhNzCAAsNOLYQAAIAAAD/////AQBBnNzCAAsNOrYQAAQAAAAvAAAAAQBBtNzCAAsNPrYQAAQAAAAvAAAAAQBBzNzCAAsNQrYQAAMAAAAvAAAAAQBB5NzCAAsNRbYQAAUAAAAyAAAAAQBB/NzCAAsNSrYQAAUAAAAyAAAAAQBBlN3CAAsNT7YQAAUAAAAyAAAAAQBBrN3CAAsNVLYQAAQAAAAvAAAAAQBBxN3CAAsNWLYQAAQAAAAvAAAAAQBB3N3CAAsNXLYQAAQAAAAvAAAAAQBB9N3CAAsNYLYQAAQAAAAvAAAAAQBBjN7CAAsNZLYQAAIAAAD/////AQBBpN7CAAsNZrYQAAUAAAA6AAAAAQBBvN7CAAsNa7YQAAUAAAA6AAAAAQBB1N7CAAsNcLYQAAUAAAA6AAAAAQBB7N7CAAsNdbYQAAQAAAA6AAAAAQBBhN/CAAsNebYQAAQAAAA6AAAAAQBBnN/CAAsNfbYQAAQAAAA6AAAAAQBBtN/CAAsNgbYQAAUAAAA6AAAAAQBBzN/CAAsNhrYQAAUAAAA6AAAAAQBB5N/CAAsNi7YQAAUAAAA6AAAAAQBB/N/CAAsNkLYQAAUAAAA6AAAAAQBBlODCAAsNlbYQAAUAAAA6AAAAAQBBrODCAAsNmrYQAAUAAAA6AAAAAQBBxODCAAsNn7YQAAIAAAD/////AQBB3ODCAAsNobYQAAMAAABHAAAAAQBB9ODCAAsNpLYQAAMAAABHAAAAAQBBjOHCAAsNp7YQAAUAAABJAAAAAQBBpOHCAAsNrLYQAAUAAABJAAAAAQBBvOHCAAsNsbYQAAUAAABJAAAAAQBB1OHCAAsNtrYQAAYAAABJAAAAAQBB7OHCAAsNvLYQAAYAAABJAAAAAQBBhOLCAAsNwrYQAAYAAABJAAAAAQBBnOLCAAsNyLYQAAcAAABJAAAAAQBBtOLCAAsNz7YQAAcAAABJAAAAAQBBzOLCAAsN1rYQAAcAAABJAAAAAQBB5OLCAAsN3bYQAAYAAABJAAAAAQBB/OLCAAsN47YQAAUAAABJAAAAAQBBlOPCAAsN6LYQAAcAAABUAAAAAQBBrOPCAAsN77YQAAcAAABUAAAAAQBBxOPCAAsN9rYQAAcAAABUAAAAAQBB3OPCAAsN/bYQAAQAAAD/////AQBB9OPCAAsNAbcQAAQAAAD/////AQBBjOTCAAsNBbcQAAQAAAD/////AQBBpOTCAAsNCbcQAAcAAABaAAAAAQBBvOTCAAsNELcQAAcAAABaAAAAAQBB1OTCAAsNF7cQAAcAAABaAAAAAQBB7OTCAAsNHrcQAAcAAABaAAAAAQBBhOXCAAsNJbcQAAYAAABaAAAAAQBBnOXCAAsNK7cQAAgAAABfAAAAAQBBtOXCAAsNM7cQAAgAAABfAAAAAQBBzOXCAAsNO7cQAAgAAABfAAAAAQBB5OXCAAsNQ7cQAAQAAAD/////AQBB/OXCAAsNR7cQAAYAAABjAAAAAQBBlObCAAsNTbcQAAYAAABjAAAAAQBBrObCAAsNU7cQAAYAAABjAAAAAQBBxObCAAsNWbcQAAgAAABjAAAAAQBB3ObCAAsNYbcQAAgAAABjAAAAAQBB9ObCAAsNabcQAAgAAABjAAAAAQBBjOfCAAsNcbcQAAQAAAD/////AQBBpOfCAAsNdbcQAAUAAAD/////AQBBvOfCAAsNercQAAUAAAD/////AQBB1OfCAAsNf7cQAAUAAAD/////AQBB7OfCAAsNhLcQAAUAAAD/////AQBBhOjCAAsNibcQAAUAAAD/////AQBBnOjCAAsNjrcQAAUAAAD/////AQBBtOjCAAsNk7cQAAUAAAD/////AQBBzOjCAAsNmLcQAAIAAAD/////AQBB5OjCAAsNmrcQAAIAAAD/////AQBB/OjCAAsNnLcQAAIAAAD/////AQBBlOnCAAsNnrcQAAQAAAD/////AQBBrOnCAAsNorcQAAQAAAD/////AQBBxOnCAAsNprcQAAQAAAD/////AQBB3OnCAAsNTLkQAAEAAAD/////AQBB9OnCAAsNTrkQAAEAAAD/////AQBBjOrCAAsNT7kQAAEAAAD/////AQBBpOrCAAsNhLUQAAIAAAD/////AQBBvOrCAAsNhrUQAAIAAAD/////AQBB1OrCAAsNiLUQAAIAAAD/////AQBB7OrCAAsNirUQAAIAAAD/////AQBBhOvCAAuxCW9zw6HDrcOzYWRhaWRhaWFhcmlhZXJpYWlyaWFhcmFlcmFpcmFhdmFhc3NlZXNzZWlzc2Vhc3RlZXN0ZWlzdGVlaWFyZWllcmVpaXJlaWFtaWFtYXJpYW1lcmlhbWlyaWFtYXJhbWVyYW1pcmFtYXZhbWVtYXJlbWVyZW1pcmVtYXNzZW1lc3NlbWlzc2VtYWRvaWRvYW5kb2VuZG9pbmRvYXJhfm9lcmF+b2lyYX5vYXJlcmFzYWRhc2lkYXNpYXNhcmlhc2VyaWFzaXJpYXNhcmFzZXJhc2lyYXNhdmFzZXNhcmRlc2VyZGVzaXJkZXNhcmVzZXJlc2lyZXNhc3Nlc2Vzc2VzaXNzZXNhc3Rlc2VzdGVzaXN0ZXNpc2Fpc2Vpc2FyZWlzZXJlaXNpcmVpc8OhcmVpc8OpcmVpc8OtcmVpc8Ohc3NlaXPDqXNzZWlzw61zc2Vpc8OhdmVpc8OtZWlzYXLDrWVpc2Vyw61laXNpcsOtZWlzYWRvc2lkb3NhbW9zw6FyYW1vc8OpcmFtb3PDrXJhbW9zw6F2YW1vc8OtYW1vc2Fyw61hbW9zZXLDrWFtb3NpcsOtYW1vc2Vtb3NhcmVtb3NlcmVtb3NpcmVtb3PDoXNzZW1vc8Oqc3NlbW9zw61zc2Vtb3NpbW9zYXJtb3Nlcm1vc2lybW9zw6Ftb3NhcsOhc2Vyw6FzaXLDoXNldWl1b3VhcsOhZXLDoWlyw6HDp8Opw6ppY2FiaWxpdmFudGVhdmVsw612ZWxhZGljYcOibmNpYcOqbmNpYWxvZ2lhYWRvcmFvc2Fpc3RhaXZhZXphaWRhZGVtZW50ZWFtZW50ZcOhdmVsaWNvaXNtb29zb2FtZW50b2ltZW50b2l2b2HDp2F+b3XDp2F+b2Fkb3JpY2Fzw6puY2lhc2xvZ2lhc2Fkb3Jhc29zYXNpc3Rhc2l2YXNlemFzaWRhZGVzYWRvcmVzYW50ZXNhw6dvfmVzdcOnb35lc2ljb3Npc21vc29zb3NhbWVudG9zaW1lbnRvc2l2b3MAAAAYuRAANAAAANy4EAAtAAAACbkQAAwAAAAVuRAAAwAAAMS4EAAYAAAAqQgAAAkAAABzcmMvbGliY29yZS9zbGljZS9tb2QucnNhc3NlcnRpb24gZmFpbGVkOiBgKGxlZnQgPT0gcmlnaHQpYAogIGxlZnQ6IGBgLAogcmlnaHQ6IGBgOiBkZXN0aW5hdGlvbiBhbmQgc291cmNlIHNsaWNlcyBoYXZlIGRpZmZlcmVudCBsZW5ndGhzYWVpb3VkZUFBY2Nlc3NFcnJvcmNhbGxlZCBgT3B0aW9uOjp1bndyYXAoKWAgb24gYSBgTm9uZWAgdmFsdWUAAJy5EAAVAAAAegEAABUAAABzcmMvbGliY29yZS9vcHRpb24ucnNUcmllZCB0byBzaHJpbmsgdG8gYSBsYXJnZXIgY2FwYWNpdHkAAADouRAAFwAAAF0CAAAJAAAAc3JjL2xpYmFsbG9jL3Jhd192ZWMucnNpbnRlcm5hbCBlcnJvcjogZW50ZXJlZCB1bnJlYWNoYWJsZSBjb2RlAOi5EAAXAAAACgIAACcAewlwcm9kdWNlcnMCCGxhbmd1YWdlAQRSdXN0AAxwcm9jZXNzZWQtYnkDBXJ1c3RjHTEuNDAuMCAoNzM1MjhlMzM5IDIwMTktMTItMTYpBndhbHJ1cwYwLjE0LjAMd2FzbS1iaW5kZ2VuEjAuMi41OCAoMjkwMmNlYjI2KQ==';
-
-                var binary = null;
-
-                var isNode = typeof process !== 'undefined'
-                    && process.versions != null
-                    && process.versions.node != null;
-
-                if (isNode) {
-                    binary = Buffer.from(src, 'base64');
-                } else {
-                    var raw = window.atob(src);
-                    binary = new Uint8Array(new ArrayBuffer(raw.length));
-                    for(var i = 0; i < raw.length; i++) {
-                        binary[i] = raw.charCodeAt(i);
-                    }
-                }
-
-                var instantiatePromise = WebAssembly.instantiate(
-                    binary,
-                    {env: {memory: new WebAssembly.Memory({initial: 10})}}
-                );
-
-                var exportsPromise = instantiatePromise.then(result => {
-                    return result.instance.exports
-                });
-
-var compileWasm = exportsPromise.then(function(wasm) {
-                const __exports__ = {};
-
-                
-/**
-* @param {number} id
-*/
-__exports__.create_store = function(id) {
-    wasm.create_store(id);
-};
-
-/**
-* @param {number} id
-*/
-__exports__.destroy_store = function(id) {
-    wasm.destroy_store(id);
-};
-
-let WASM_VECTOR_LEN = 0;
-
-let cachegetUint8Memory0 = null;
-function getUint8Memory0() {
-    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
-    }
-    return cachegetUint8Memory0;
-}
-
-const lTextEncoder = typeof TextEncoder === 'undefined' ? require('util').TextEncoder : TextEncoder;
-
-let cachedTextEncoder = new lTextEncoder('utf-8');
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-}
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
-
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length);
-        getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
-        return ptr;
-    }
-
-    let len = arg.length;
-    let ptr = malloc(len);
-
-    const mem = getUint8Memory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
-    }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3);
-        const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
-
-        offset += ret.written;
-    }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {string} left
-* @param {string} right
-*/
-__exports__.highlight_with = function(store_id, left, right) {
-    var ptr0 = passStringToWasm0(left, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(right, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    wasm.highlight_with(store_id, ptr0, len0, ptr1, len1);
-};
-
-/**
-* @param {number} id
-* @param {number} limit
-*/
-__exports__.set_limit = function(id, limit) {
-    wasm.set_limit(id, limit);
-};
-
-let cachegetUint32Memory0 = null;
-function getUint32Memory0() {
-    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
-    }
-    return cachegetUint32Memory0;
-}
-
-function passArray32ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 4);
-    getUint32Memory0().set(arg, ptr / 4);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {Uint32Array} ids
-* @param {string} texts
-* @param {Uint32Array} ratings
-*/
-__exports__.set_records = function(store_id, ids, texts, ratings) {
-    var ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(texts, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    var ptr2 = passArray32ToWasm0(ratings, wasm.__wbindgen_malloc);
-    var len2 = WASM_VECTOR_LEN;
-    wasm.set_records(store_id, ptr0, len0, ptr1, len1, ptr2, len2);
-};
-
-/**
-* @param {number} store_id
-* @param {string} query
-*/
-__exports__.run_search = function(store_id, query) {
-    var ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    wasm.run_search(store_id, ptr0, len0);
-};
-
-let cachegetInt32Memory0 = null;
-function getInt32Memory0() {
-    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
-    }
-    return cachegetInt32Memory0;
-}
-
-function getArrayU32FromWasm0(ptr, len) {
-    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
-}
-/**
-* @param {number} store_id
-* @returns {Uint32Array}
-*/
-__exports__.get_result_ids = function(store_id) {
-    wasm.get_result_ids(8, store_id);
-    var r0 = getInt32Memory0()[8 / 4 + 0];
-    var r1 = getInt32Memory0()[8 / 4 + 1];
-    var v0 = getArrayU32FromWasm0(r0, r1).slice();
-    wasm.__wbindgen_free(r0, r1 * 4);
-    return v0;
-};
-
-const lTextDecoder = typeof TextDecoder === 'undefined' ? require('util').TextDecoder : TextDecoder;
-
-let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-
-cachedTextDecoder.decode();
-
-function getStringFromWasm0(ptr, len) {
-    return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
-}
-/**
-* @param {number} store_id
-* @returns {string}
-*/
-__exports__.get_result_titles = function(store_id) {
-    try {
-        wasm.get_result_titles(8, store_id);
-        var r0 = getInt32Memory0()[8 / 4 + 0];
-        var r1 = getInt32Memory0()[8 / 4 + 1];
-        return getStringFromWasm0(r0, r1);
-    } finally {
-        wasm.__wbindgen_free(r0, r1);
-    }
-};
-
-
-
-                return __exports__;
-            });
-
-const DEFAULT_LIMIT = 10;
-var NEXT_ID = 1;
-
-
-class LucidSuggest {
-    constructor() {
-        this.id         = NEXT_ID++;
-        this.limit      = DEFAULT_LIMIT;
-        this.records    = [];
-        this.setupQueue = compileWasm;
-
-        this.setup(wasm => {
-            wasm.create_store(this.id);
-            wasm.highlight_with(this.id, '{{', '}}');
-        });
-    }
-
-    setup(fn) {
-        this.setupQueue = this.setupQueue.then(async wasm => {
-            await fn(wasm);
-            this.setupQueue = Promise.resolve(wasm);
-            return wasm
-        });
-        return this.setupQueue
-    }
-
-    destroy() {
-        const oldQueue = this.setupQueue;
-        this.setupQueue = Promise.reject(new Error('Suggest destroyed'));
-        oldQueue.then(wasm => {
-            wasm.destroy_store(this.id);
-        });
-    }
-
-    setRecords(records) {
-        return this.setup(wasm => {
-            this.records = setRatings(records);
-            wasm.set_records(
-                this.id,
-                this.records.map(r => r.id),
-                this.records.map(r => r.title).join('\0'),
-                this.records.map(r => r.rating),
-            );
-        })
-    }
-
-    setLimit(limit) {
-        return this.setup(wasm => {
-            this.limit = limit;
-            wasm.set_limit(this.id, this.limit);
-        })
-    }
-
-    async search(query) {
-        const wasm = await this.setupQueue;
-        wasm.run_search(this.id, query);
-        const ids    = wasm.get_result_ids(this.id);
-        const titles = wasm.get_result_titles(this.id).split('\0');
-        const hits   = [];
-        for (let i = 0; i < ids.length; i++) {
-            const id     = ids[i];
-            const title  = titles[i];
-            const record = this.records.find(r => r.id === id);
-            if (!record) throw new Error(`Missing record ${id}`)
-            if (!title)  throw new Error(`Missing title for ${id}`)
-            hits.push(new Hit(title, record));
-        }
-        return hits
-    }
-}
-
-
-function highlight(hit, left, right) {
-    let result = '';
-    for (const {text, highlight} of hit.chunks) {
-        result += highlight
-            ? left + text + right
-            : text;
-    }
-    return result
-}
-
-
-class Hit {
-    constructor(title, record) {
-        this.record = record;
-        this.chunks = toChunks(title);
-    }
-
-    get title() {
-        return highlight(this, '[', ']')
-    }
-}
-
-
-function toChunks(title) {
-    const split  = title.split(/{{|}}/g);
-    const chunks = [];
-    for (let i = 0; i < split.length; i++) {
-        if (split[i] != '') {
-            chunks.push({
-                text: split[i],
-                highlight: i % 2 === 1,
-            });
-        }
-    }
-    return chunks
-}
-
-
-function setRatings(records) {
-    return records.some(r => r.rating != null && r.rating > 0)
-        ? records.map(r => ({...r, rating: r.rating > 0 ? r.rating : 0}))
-        : records.map((r, i) => ({...r, rating: records.length - i}))
-}
-
-exports.Hit = Hit;
-exports.LucidSuggest = LucidSuggest;
-exports.highlight = highlight;
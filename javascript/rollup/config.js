@@ -1,16 +1,10 @@
 import wasm         from './plugin-wasm'
 import bindgenAsync from './plugin-bindgen-async'
 
-const lang = (process.env.RUSTFLAGS || '').match(/lang="(\w+)"/)?.[1]
-
-if (!lang) {
-    throw new Error("Missing RUSTFLAGS=\"--cfg lang=\"*\"\"")
-}
-
 export default {
     input: 'src/index.js',
     output: {
-        file: `${lang}.js`,
+        file: 'index.js',
         format: 'cjs',
     },
     plugins: [
@@ -1,346 +0,0 @@
-'use strict';
-
-Object.defineProperty(exports, '__esModule', { value: true });
-
-var src = 'AGFzbQEAAAABehNgAn9/AGACf38Bf2ADf39/AGADf39/AX9gAX8AYAR/f39/AGAFf39/f38AYAF/AX9gAAF/YAR/f39/AX9gAABgB39/f39/f38AYAV/f39/fwF/YAd/f39/f39/AX9gAn9+AX9gAn5/AX9gAX8BfmABfAF8YAJ8fAF8A9gB1gEFAgIHBgYGBgYAAAAAAAAAAAcAAAACCwAEBAUDBAEAAAIECQIBDAIIAAUAAQUJAQAABwIHBwIDBQEAAgIBAQAABgUFAQECBQUADQIAAAAPAgAAAAEBAQEABAEBAAEBAQAGBAAFAgUFBAUAAAAEBAAAAgAFAAAABAIIAgIEABEABAYGAgICAAIAAAAACAgABAQCAgAJAgUOAAMAAAAAAQAAAAAAAAQAAgABBAAAAAoCBAABAgADCQACAgMEBwQHAAMICAMFBQUEEgEBAQEACgkBCgEKEAAEBAUBcAEaGgUDAQARBgkBfwFBgIDAAAsHxAEMBm1lbW9yeQIADGNyZWF0ZV9zdG9yZQAcDWRlc3Ryb3lfc3RvcmUAGA5oaWdobGlnaHRfd2l0aABACXNldF9saW1pdABXC3NldF9yZWNvcmRzABYKcnVuX3NlYXJjaAACDmdldF9yZXN1bHRfaWRzAEsRZ2V0X3Jlc3VsdF90aXRsZXMAPxFfX3diaW5kZ2VuX21hbGxvYwC7ARJfX3diaW5kZ2VuX3JlYWxsb2MAwgEPX193YmluZGdlbl9mcmVlAMwBCTQBAEEBCxkR1AHLAR3PAZ0BLs8BqAFT1QHTAdUByQHVAcoB1QHIAdUB1QHVAdUBwQHVAdUBCrbHBtYBz1gDQX8FfgJ8IwBBgARrIgQkAAJAAkACQAJAAkAgASgCACIFIAEoAgRHBEAgBEHsAGohJyAEQdQDaiEoIARBlQNqIS8gBEHlA2ohMCAEQZACaiEdIARB+AJqIRggBEH4AGohMSAEQfUAaiE8IARByANqISIgBEHMA2ohGSAEQYQBaiE5IARB5ABqITogBEHgAGohPSAEQYsCaiEyIARB3QNqIR4gBEHsA2ohIyAEQdwCaiEpIARB7QJqIT4DQCABIAVBBGo2AgACQAJAAkACQAJAIAEoAggoAgAiBkEMaigCACIHIAUoAgAiBUsEQCAGKAIEIAVBPGxqIgUoAjghPyAFKAIIISogBSgCBCFAIAVBNGooAgAhMyAFQSxqKAIAITQgBUEoaigCACE1IAVBIGooAgAhNiAFQRxqKAIAITcgBUEUaigCACE4IAVBEGooAgAhJCADKAIAKAIAIQoQiwEiF0UNDBCMASIWRQ0MIBcoAgBFBEAgF0F/NgIAIBYoAgBFBEAgFkF/NgIAIBZBBGohCwJAIBdBBGoiESgCACIFRQRAQQAhBSAXQQA2AhQMAQsgFygCCEH/ASAFQQVqEL8BGiAXQQA2AhQgFygCBCIFQQhJDQAgBUEBakEDdkEHbCEFCyAXIAU2AhACQCALKAIAIgVFBEBBACEFIBZBADYCFAwBCyAWKAIIQf8BIAVBBWoQvwEaIBZBADYCFCAWKAIEIgVBCEkNACAFQQFqQQN2QQdsIQULIBYgBTYCECAKKAIEIgUEQCAKKAIAIhQgBUEUbGohJSAqICRBFGxqIhpBbGohKwNAAkAgCyAUKAIAIgkQXg0AIAQgCTYCKCAEIBQvARA7AVAgBCAUKAIMNgI0IAQgFCgCCCIONgIwIAQgFCgCBCITNgIsIAQgCigCGCIfNgJIIAQgCigCECIgNgJAIAQgCigCCCIhNgI4IAQgCkEcaigCACIsNgJMIAQgCkEUaigCACJBNgJEIAQgCkEMaigCACJCNgI8IARBAjoAdCAkRQ0AQQIhByAqIQUCQANAAkACQCARIAUoAgAiDRBeDQAgBCAzNgK8ASAEIDQ2ArgBIAQgNTYCtAEgBCA2NgKwASAEIDc2AqwBIAQgODYCqAEgBCAFQRBqLwEAOwHAASAEIAVBDGooAgA2AqQBIAQgBUEIaigCACIMNgKgASAEIAVBBGooAgAiBjYCnAEgBCANNgKYAQJAAkAgJCANQQFqIhJNDQAgBCAzNgKsAiAEIDQ2AqgCIAQgNTYCpAIgBCA2NgKgAiAEIDc2ApwCIAQgODYCmAIgBCAqIBJBFGxqIggtABEiFToAsQIgBCAILQAQOgCwAiAEIAgoAgwiGzYClAIgBCAIKAIIIg82ApACIAQgCCgCBCIQNgKMAiAEIAgoAgA2AogCAkACQCAGIA9JBEAgECAMSQ0BIBAgDGshCAwCCyAGIA9rIQgMAQsQ0AEACyAOIBNrIAwgBmsgCGpJDQAgESASEF4NACAEIBU6AJkDIARBCjoAmAMgBCAPNgL4AiAEIA02AvACIAQgMzYClAMgBCA0NgKQAyAEIDU2AowDIAQgNjYCiAMgBCA3NgKEAyAEIDg2AoADIAQgBjYC9AIgBCAQIAZrIBtqNgL8AiAEQcADaiAEQfACaiAEQShqEAEgBEGAAmoiDSAZQQhqIggpAgA3AwAgBEH2AWoiDCAeQQJqIhstAAA6AAAgBEHoAWoiBiAjQQhqIiYpAAA3AwAgBEHwAWoiDyAjQRBqIjsoAAA2AgAgBCAZKQIANwP4ASAEIB4vAAA7AfQBIAQgIykAADcD4AEgBC0A3AMiLUECRg0AIAQoAugDIRwgBCkD4AMhRSAEKALIAyEuIAQpA8ADIUYgBEHIAmoiECANKQMANwMAIARBqANqIhIgBikDADcDACAEQbADaiIVIA8oAgA2AgAgBCAEKQP4ATcDwAIgBCAELwH0ATsBuAMgBCAEKQPgATcDoAMgBCAMLQAAOgC6AyAEIC42AtgCIAQgRjcD0AIgKSAEKQPAAjcCACApQQhqIBApAwA3AgAgBCAtOgDsAiA+IAQvAbgDOwAAID5BAmogBC0AugM6AAAgBEHYAWoiLSAVKAIANgIAIARB0AFqIi4gEikDADcDACAEIAQpA6ADNwPIASAEQcADaiAEQdACaiAEQZgBaiAEQYgCahBBIA0gCCkCADcDACAMIBstAAA6AAAgBiAmKQAANwMAIA8gOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyImQQJGDQAgBCgC6AMhBSAEKQPgAyFHIAQoAsgDIQcgBCkDwAMhRiAQIA0pAwA3AwAgGCAGKQMANwMAIARBgANqIhMgDygCADYCACAEIAQpA/gBNwPAAiAEIAQvAfQBOwG4AyAEIAQpA+ABNwPwAiAEIAwtAAA6ALoDIAYgECkDADcDACAEIAQpA8ACNwPgASAEIAQvAbgDOwH4ASAEIAQtALoDOgD6ASAVIBMoAgA2AgAgEiAYKQMANwMAIAQgBCkD8AI3A6ADIAQgBzYCyAMgBCBGNwPAAyAZIAQpA+ABNwIAIAggBikDADcCACAEICY6ANwDIB4gBC8B+AE7AAAgGyAELQD6AToAACAEQfACaiARIAcgBEHAA2oQKSAZIAQpA6ADNwIAIAggEikDADcCACAZQRBqIgYgFSgCADYCACAEIAU2AsgDIAQgRzcDwAMgBEHwAmogESAFIARBwANqECkgGSAEKQPIATcCACAIIC4pAwA3AgAgBiAtKAIANgIAIAQgHDYCyAMgBCBFNwPAAyAEQfACaiALIBwgBEHAA2oQKQwBCwJAIAooAgQgCUEBaiIMTQ0AIAooAgAhBiAKKQIIIUUgCikCECFGIAQgCikCGDcDqAIgBCBGNwOgAiAEIEU3A5gCIAQgBiAMQRRsaiIGLQARIg86ALECIAQgBi0AEDoAsAIgBCAGKAIMIhA2ApQCIAQgBigCCCIINgKQAiAEIAYoAgQiDTYCjAIgBCAGKAIANgKIAiAEKAKcASESIAQoAqABAkACQCATIAhJBEAgDSAOSQ0BIA0gDmshBgwCCyATIAhrIQYMAQsQ0AEACyASayAOIBNrIAZqSQ0AIAsgDBBeDQAgBCAPOgCZAyAEQQo6AJgDIAQgCDYC+AIgBCAJNgLwAiAEICw2ApQDIAQgHzYCkAMgBCBBNgKMAyAEICA2AogDIAQgQjYChAMgBCAhNgKAAyAEIBM2AvQCIAQgDSATayAQajYC/AIgBEHAA2ogBEGYAWogBEHwAmoQASAEQYACaiINIBlBCGoiCCkCADcDACAEQfYBaiIMIB5BAmoiDy0AADoAACAEQegBaiIGICNBCGoiJikAADcDACAEQfABaiIQICNBEGoiOygAADYCACAEIBkpAgA3A/gBIAQgHi8AADsB9AEgBCAjKQAANwPgASAELQDcAyItQQJGDQAgBCgC6AMhLiAEKQPgAyFFIAQoAsgDIRwgBCkDwAMhRiAEQdABaiISIA0pAwA3AwAgBEG6A2oiQyAMLQAAOgAAIARBqANqIhUgBikDADcDACAEQbADaiIbIBAoAgA2AgAgBCAEKQP4ATcDyAEgBCAELwH0ATsBuAMgBCAEKQPgATcDoAMgBEHIAmoiRCASKQMANwMAIAQgBCkDyAE3A8ACIAQgBC8BuAM7AbgCIAQgQy0AADoAugIgBCAuNgLYAiAEIEU3A9ACICkgBCkDoAM3AgAgKUEIaiAVKQMANwIAIClBEGogGygCADYCACAEQcADaiAEQdACaiAEQShqIARBiAJqEEEgDSAIKQIANwMAIAwgDy0AADoAACAGICYpAAA3AwAgECA7KAAANgIAIAQgGSkCADcD+AEgBCAeLwAAOwH0ASAEICMpAAA3A+ABIAQtANwDIiZBAkYNACAEKALoAyEFIAQpA+ADIUcgBCgCyAMhByAEKQPAAyFFIBIgDSkDADcDACAYIAYpAwA3AwAgBEGAA2oiEyAQKAIANgIAIAQgBCkD+AE3A8gBIAQgBC8B9AE7AbgDIAQgBCkD4AE3A/ACIAQgDC0AADoAugMgBiASKQMANwMAIAQgBCkDyAE3A+ABIAQgBC8BuAM7AfgBIAQgBC0AugM6APoBIBsgEygCADYCACAVIBgpAwA3AwAgBCAEKQPwAjcDoAMgBCAcNgLIAyAEIEY3A8ADIBkgBCkDwAI3AgAgCCBEKQMANwIAIAQgLToA3AMgHiAELwG4AjsAACAPIAQtALoCOgAAIARB8AJqIBEgHCAEQcADahApIBkgBCkD4AE3AgAgCCAGKQMANwIAIB4gBC8B+AE7AAAgDyAELQD6AToAACAEIAc2AsgDIAQgRTcDwAMgBCAmOgDcAyAEQfACaiALIAcgBEHAA2oQKSAZIAQpA6ADNwIAIAggFSkDADcCACAZQRBqIBsoAgA2AgAgBCAFNgLIAyAEIEc3A8ADIARB8AJqIAsgBSAEQcADahApDAELIARBwANqIARBmAFqIARBKGoQASAEQdgCaiIIICJBCGooAgA2AgAgBCAiKQMANwPQAiAEKwPAAyFKIAQoAtQDIQ0gBCgC2AMhDCAELQDcAyEGIARB8AJqIB5BIxC5ARogBkECRg0BIARBqANqIg8gCCgCADYCACAEIAQpA9ACNwOgAyAEQYgCaiAEQfACakEjELkBGiAYIA8oAgA2AgAgBCAEKQOgAzcD8AIgBCAELwCIAjsB0AIgBCAEQYoCai0AADoA0gIgBEHYA2oiECAyQRhqKQAANwMAIARB0ANqIhIgMkEQaikAADcDACAiIDJBCGopAAA3AwAgBCAyKQAANwPAAwJ/IEqbIktEAAAAAAAA8EFjIEtEAAAAAAAAAABmcQRAIEurDAELQQALIQ8CQAJAIAdB/wFxQQJGIhUNAEEAIQhBfyAMIA1rIA9BAXRrIhtBACAEQdgAaiAVGyIPKAIYIA8oAhRrAn8gDysDAJsiS0QAAAAAAADwQWMgS0QAAAAAAAAAAGZxBEAgS6sMAQtBAAtBAXRrIg9HIA8gG0kbQQFqIg9BAUsNASAPQQFrDQAgBg0BCyA9IAQpA/ACNwMAIDwgBC8B0AI7AAAgMSAEKQPAAzcDACA8QQJqIAQtANICOgAAID1BCGogGCgCADYCACAxQQhqICIpAwA3AwAgMUEQaiASKQMANwMAIDFBGGogECkDADcDACAEIAw2AnAgBCANNgJsIAQgBjoAdCAGQQFzIQggBCBKOQNYIEq9IUcgBiEHCyAFICtGDQQgBUEUaiEFIAhFDQMMBAsgBEECOgDcA0EAIQUDQCAEQdgAaiAFaiIGKQMAIUcgBiAEQcADaiAFaiIHKQMANwMAIAcgRzcDACAGQQhqIggpAwAhRSAIIAdBCGoiCCkDADcDACAGQRBqIhMpAwAhRiATIAdBEGoiEykDADcDACAGQRhqIgYpAwAhRyAGIAdBGGoiBikDADcDACAGIEc3AwAgEyBGNwMAIAggRTcDACAFQUBrIAVBIGohBUHBAEkNAAsgBC0AdCEHDAELIBogBUEUaiIFRw0BCwsgB0H/AXFBAkYNASAEKQNYIUcLIAQpA3ghRSAEKAKAASEFIAQoAmAhBiAZIDopAgA3AgAgGUEIaiIHIDpBCGopAgA3AgAgGUEQaiIIIDpBEGooAgA2AgAgBCAGNgLIAyAEIEc3A8ADIARB8AJqIBEgBiAEQcADahApIBkgOSkCADcCACAHIDlBCGopAgA3AgAgCCA5QRBqKAIANgIAIAQgBTYCyAMgBCBFNwPAAyAEQfACaiALIAUgBEHAA2oQKQsgFEEUaiIUICVHDQALCyAXKAIIIgUoAgAhCCAXKAIUIQYgF0EANgIUIBcoAhAhFCAXQQA2AhAgFygCDCEHIBdBCDYCDCAXQbCmwgA2AgggFygCBCEKIBdBADYCBCAEIBE2AoABIAQgBjYCfCAEIBQ2AnggBCAHNgJ0IAQgBTYCcCAEIAo2AmwgBCAGNgJoIAQgBSAKakEBajYCZCAEIAVBBGo2AmAgBCAHNgJcIAQgCEF/c0GAgYKEeHE2AlggBEHAA2ogBEHYAGoQfgJ/IAQtAOQDIgZBAkYEQAJAIAQoAmwiBUUEQEEAIQUgBEEANgJ8DAELIAQoAnBB/wEgBUEFahC/ARogBEEANgJ8IAVBCEkNACAFQQFqQQN2QQdsIQULIAQgBTYCeCAEKAKAASIFICcpAgA3AgAgBUEQaiAnQRBqKAIANgIAIAVBCGogJ0EIaikCADcCAEIAIUVBCAwBCyAEQfACaiAEQcADakEkELkBGiAdIBhBCGopAwA3AwAgBEGYAmoiCiAYQRBqKQMANwMAIARBoAJqIgggGEEYaigCADYCACAEIDBBAmotAAA6AJoBIAQgMC8AADsBmAEgBCAYKQMANwOIAiAEQSBqQX8gBCgCaCIFQQFqIgcgByAFSRsQowEgBCgCJCEFIAQoAiAiByAEKQOIAjcDACAHIAY6ABwgB0EYaiAIKAIANgIAIAdBEGogCikDADcDACAHQQhqIB0pAwA3AwAgByAELwGYATsAHSAHQR9qIAQtAJoBOgAAIARBATYCMCAEIAU2AiwgBCAHNgIoIARBwANqIARB2ABqQSwQuQEaIARB8AJqIARBwANqEH4CQCAELQCUAyIIQQJGDQBBASEGQSAhCQNAIARBiAJqIARB8AJqQSQQuQEaIARBsAFqIB1BGGooAgAiCjYCACAEQagBaiAdQRBqKQMAIkU3AwAgBEGgAWogHUEIaikDACJGNwMAIAQgL0ECai0AADoA0gIgBCAvLwAAOwHQAiAEIB0pAwAiRzcDmAEgGCBGNwMAIARBgANqIhEgRTcDACAEQYgDaiIUIAo2AgAgBCBHNwPwAiAEIAQvAdACOwGIAiAEIAQtANICOgCKAiAFIAZGBEAgBEEoaiAFQX8gBCgC0AMiBUEBaiIHIAcgBUkbEIMBIAQoAighBwsgByAJaiIFIAQpA/ACNwMAIBgpAwAhRSARKQMAIUYgFCgCACEKIAVBHGogCDoAACAFQR1qIAQvAYgCOwAAIAVBH2ogBC0AigI6AAAgBUEYaiAKNgIAIAVBEGogRjcDACAFQQhqIEU3AwAgBCAGQQFqIgY2AjAgBEHwAmogBEHAA2oQfiAELQCUAyIIQQJGDQEgCUEgaiEJIAQoAiwhBQwACwALAkAgBCgC1AMiBUUEQEEAIQUgBEEANgLkAwwBCyAEKALYA0H/ASAFQQVqEL8BGiAEQQA2AuQDIAVBCEkNACAFQQFqQQN2QQdsIQULIAQoAugDIgYgKCkCADcCACAEIAU2AuADIAZBCGogKEEIaikCADcCACAGQRBqIChBEGooAgA2AgAgBCkCLCFFIAQoAigLIQ0gFigCCCIFKAIAIQggFigCFCEGIBZBADYCFCAWKAIQIREgFkEANgIQIBYoAgwhByAWQQg2AgwgFkGwpsIANgIIIBYoAgQhCiAWQQA2AgQgBCALNgKAASAEIAY2AnwgBCARNgJ4IAQgBzYCdCAEIAU2AnAgBCAKNgJsIAQgBjYCaCAEIAUgCmpBAWo2AmQgBCAFQQRqNgJgIAQgBzYCXCAEIAhBf3NBgIGChHhxNgJYIARBwANqIARB2ABqEH4CfyAELQDkAyIGQQJGBEACQCAEKAJsIgVFBEBBACEFIARBADYCfAwBCyAEKAJwQf8BIAVBBWoQvwEaIARBADYCfCAFQQhJDQAgBUEBakEDdkEHbCEFCyAEIAU2AnggBCgCgAEiBSAnKQIANwIAIAVBEGogJ0EQaigCADYCACAFQQhqICdBCGopAgA3AgBBACEbQQAhEUEIDAELIARB8AJqIARBwANqQSQQuQEaIB0gGEEIaikDADcDACAEQZgCaiIKIBhBEGopAwA3AwAgBEGgAmoiCCAYQRhqKAIANgIAIAQgMEECai0AADoAmgEgBCAwLwAAOwGYASAEIBgpAwA3A4gCQQEhESAEQRhqQX8gBCgCaCIFQQFqIgcgByAFSRsQowEgBCgCHCEFIAQoAhgiByAEKQOIAjcDACAHIAY6ABwgB0EYaiAIKAIANgIAIAdBEGogCikDADcDACAHQQhqIB0pAwA3AwAgByAELwGYATsAHSAHQR9qIAQtAJoBOgAAIARBATYCMCAEIAU2AiwgBCAHNgIoIARBwANqIARB2ABqQSwQuQEaIARB8AJqIARBwANqEH4gBC0AlAMiCEECRwRAQQEhBkEgIQkDQCAEQYgCaiAEQfACakEkELkBGiAEQbABaiAdQRhqKAIAIgo2AgAgBEGoAWogHUEQaikDACJGNwMAIARBoAFqIB1BCGopAwAiSDcDACAEIC9BAmotAAA6ANICIAQgLy8AADsB0AIgBCAdKQMAIkc3A5gBIBggSDcDACAEQYADaiILIEY3AwAgBEGIA2oiESAKNgIAIAQgRzcD8AIgBCAELwHQAjsBiAIgBCAELQDSAjoAigIgBSAGRgRAIARBKGogBUF/IAQoAtADIgVBAWoiByAHIAVJGxCDASAEKAIoIQcLIAcgCWoiBSAEKQPwAjcDACAYKQMAIUYgCykDACFIIBEoAgAhCiAFQRxqIAg6AAAgBUEdaiAELwGIAjsAACAFQR9qIAQtAIoCOgAAIAVBGGogCjYCACAFQRBqIEg3AwAgBUEIaiBGNwMAIAQgBkEBaiIKNgIwIARB8AJqIARBwANqEH4gBC0AlAMiCEECRwRAIAlBIGohCSAEKAIsIQUgCiEGDAELCyAGQQFqIRELAkAgBCgC1AMiBUUEQEEAIQUgBEEANgLkAwwBCyAEKALYA0H/ASAFQQVqEL8BGiAEQQA2AuQDIAVBCEkNACAFQQFqQQN2QQdsIQULIAQoAugDIgYgKCkCADcCACAEIAU2AuADIAZBCGogKEEIaikCADcCACAGQRBqIChBEGooAgA2AgAgBCgCLCEbIAQoAigLIQ8gRUIgiCJGpyIUQRVJDQMgBEEQaiBFQiGIpxCjASAEKAIUIAQoAhAhE0EAIQUgBEEANgJgIARCBDcDWCANQWBqIRwgDUGof2ohJUEAIQcgFCEKA0ACQCAKIgtBf2oiCkUEQEEBIQhBACEKDAELAkACQAJAAkACQCANIApBBXRqKAIIIAtBBXQiDCANakFIaigCACIGTwRAIAtBfmohDiAMICVqIQhBACEKQQAhCQNAIAkgDkYEQCALIQgMCAsgCUEBaiEJIAYgCCgCACIGTyAIQWBqIQgNAAsgCUEBaiEIIAlBf3MgC2ohCgwBCyAMICVqIQkDQAJAIApBAUYEQEEAIQoMAQsgCkF/aiEKIAYgCSgCACIGSSAJQWBqIQkNAQsLIAsgCkkNASALIBRLDQIgCyAKayIIQQF2Ig5FDQAgDSAKQQV0aiEGIAwgHGohCQNAIARB2ANqIgwgBkEYaiIQKQMANwMAIARB0ANqIhIgBkEQaiIVKQMANwMAICIgBkEIaiIaKQMANwMAIAQgBikDADcDwAMgCUEIaiIfKQMAIUggCUEQaiIgKQMAIUkgCUEYaiIhKQMAIUcgBiAJKQMANwMAIBAgRzcDACAVIEk3AwAgGiBINwMAICEgDCkDADcDACAgIBIpAwA3AwAgHyAiKQMANwMAIAkgBCkDwAM3AwAgBkEgaiEGIAlBYGohCSAOQX9qIg4NAAsLIApFIAhBCUtyDQQgCyAKayEIIApBf2ohBSAcIApBBXRqIQYDQCALIAVJDQMgCyAUSw0CIAYgCEEBaiIIED4gBUF/aiIHIAVPDQQgBkFgaiEGIAchBSAIQQpJDQALDAMLIAogCxCnAQALIAsgFBClAQALIAUgCxCnAQALIAdBAWohCiAEKAJgIgUhBwsgBCgCXCAHRgRAIARB2ABqIAcQigEgBCgCYCIFIQcLIAQoAlgiBiAHQQN0aiIHIAg2AgQgByAKNgIAIAQgBUEBaiIFNgJgAn8CQCAFQQJJDQACQANAAkACQAJAAkAgBiAFQX9qQQN0aiIHKAIARQ0AIAVBA3QgBmoiDkF0aigCACILIAcoAgQiCE0NAEECIAVBAk0NBxogBiAFQX1qIgxBA3RqKAIEIgkgCCALak0NAUEDIAVBA00NBxogDkFkaigCACAJIAtqSw0GDAELIAVBA0kNASAHKAIEIQggBiAFQX1qIgxBA3RqKAIEIQkLIAkgCEkNAQsgBUF+aiEMCwJAAkACQAJAAkACQAJAIAUgDEEBaiISSwRAIAUgDE0NASAGIAxBA3QiH2oiBSgCBCIgIAUoAgBqIgUgBiASQQN0IiFqIgYoAgAiFUkNAiAFIBRLDQMgDSAVQQV0aiILIAYoAgQiEEEFdCIIaiEHIAVBBXQhGiAFIBVrIg4gEGsiBSAQTw0EIBMgByAFQQV0IgYQuQEiDiAGaiEJIBBBAU5BACAFQQFOG0UEQCAHIQUMBgsgGiAcaiEIIAchBQNAIAggBUFgaiIaIAlBYGoiLCAJQWhqKAIAIAVBaGooAgBJIgcbIgYpAwA3AwAgCEEYaiAGQRhqKQMANwMAIAhBEGogBkEQaikDADcDACAIQQhqIAZBCGopAwA3AwAgCSAsIAcbIQkgCyAaIAUgBxsiBU8NBiAIQWBqIQggCSAOIgZLDQALDAcLQeirwgAgEiAFEKYBAAtB6KvCACAMIAUQpgEACyAVIAUQpwEACyAFIBQQpQEACyAIIBMgCyAIELkBIgZqIQkgDiAQSkEAIBBBAU4bDQEgCyEFCyATIQYMAQsgDSAaaiEOIAshBQNAIAUgByAGIAcoAgggBigCCEkiCxsiCCkDADcDACAFQRhqIAhBGGopAwA3AwAgBUEQaiAIQRBqKQMANwMAIAVBCGogCEEIaikDADcDACAGIAZBIGogCxshBiAFQSBqIQUgB0EgaiAHIAsbIgcgDk8NASAJIAZLDQALCyAFIAYgCSAGa0FgcRC5ARogBCgCYCIFIAxLBEAgBCgCWCIGIB9qIgcgECAgajYCBCAHIBU2AgAgBSASTQ0CIAYgIWoiByAHQQhqIAUgEkF/c2pBA3QQmAEaIAQgBUF/aiIFNgJgIAUgBUEBTQ0EGgwBCwtB+KvCACAMIAUQpgEAC0GnrsIAQR1BxK7CABC4AQALIAULIQcgCg0ACyAEKAJcBEAgBhAhC0UNBCATECEMBAsMDAsMCwtB6KvCACAFIAcQpgEACyAUQQJJDQAgFEF+aiEFIA0gFEEFdGpBQGohBkECIQkDQCAFIBRLDQIgBiAJED4gBkFgaiEGIAlBAWohCSAFQX9qIgVBf0cNAAsLIBFBFUkNASAEQQhqIBFBAXYQowEgBCgCDCAEKAIIIRNBACEFIARBADYCYCAEQgQ3A1ggD0FgaiEcIA9BqH9qISVBACEHIBEhCgNAAkAgCiILQX9qIgpFBEBBASEIQQAhCgwBCwJAAkACQAJAAkAgDyAKQQV0aigCCCALQQV0IgwgD2pBSGooAgAiBk8EQCALQX5qIQ4gDCAlaiEIQQAhCkEAIQkDQCAJIA5GBEAgCyEIDAgLIAlBAWohCSAGIAgoAgAiBk8gCEFgaiEIDQALIAlBAWohCCAJQX9zIAtqIQoMAQsgDCAlaiEJA0ACQCAKQQFGBEBBACEKDAELIApBf2ohCiAGIAkoAgAiBkkgCUFgaiEJDQELCyALIApJDQEgCyARSw0CIAsgCmsiCEEBdiIORQ0AIA8gCkEFdGohBiAMIBxqIQkDQCAEQdgDaiIMIAZBGGoiECkDADcDACAEQdADaiISIAZBEGoiFSkDADcDACAiIAZBCGoiGikDADcDACAEIAYpAwA3A8ADIAlBCGoiHykDACFIIAlBEGoiICkDACFJIAlBGGoiISkDACFHIAYgCSkDADcDACAQIEc3AwAgFSBJNwMAIBogSDcDACAhIAwpAwA3AwAgICASKQMANwMAIB8gIikDADcDACAJIAQpA8ADNwMAIAZBIGohBiAJQWBqIQkgDkF/aiIODQALCyAKRSAIQQlLcg0EIAsgCmshCCAKQX9qIQUgHCAKQQV0aiEGA0AgCyAFSQ0DIAsgEUsNAiAGIAhBAWoiCBA+IAVBf2oiByAFTw0EIAZBYGohBiAHIQUgCEEKSQ0ACwwDCyAKIAsQpwEACyALIBEQpQEACyAFIAsQpwEACyAHQQFqIQogBCgCYCIFIQcLIAQoAlwgB0YEQCAEQdgAaiAHEIoBIAQoAmAiBSEHCyAEKAJYIgYgB0EDdGoiByAINgIEIAcgCjYCACAEIAVBAWoiBTYCYAJ/AkAgBUECSQ0AAkADQAJAAkACQAJAIAYgBUF/akEDdGoiBygCAEUNACAFQQN0IAZqIg5BdGooAgAiCyAHKAIEIghNDQBBAiAFQQJNDQcaIAYgBUF9aiIMQQN0aigCBCIJIAggC2pNDQFBAyAFQQNNDQcaIA5BZGooAgAgCSALaksNBgwBCyAFQQNJDQEgBygCBCEIIAYgBUF9aiIMQQN0aigCBCEJCyAJIAhJDQELIAVBfmohDAsCQAJAAkACQAJAAkACQCAFIAxBAWoiEksEQCAFIAxNDQEgBiAMQQN0Ih9qIgUoAgQiICAFKAIAaiIFIAYgEkEDdCIhaiIGKAIAIhVJDQIgBSARSw0DIA8gFUEFdGoiCyAGKAIEIhBBBXQiCGohByAFQQV0IRogBSAVayIOIBBrIgUgEE8NBCATIAcgBUEFdCIGELkBIg4gBmohCSAQQQFOQQAgBUEBThtFBEAgByEFDAYLIBogHGohCCAHIQUDQCAIIAVBYGoiGiAJQWBqIiwgCUFoaigCACAFQWhqKAIASSIHGyIGKQMANwMAIAhBGGogBkEYaikDADcDACAIQRBqIAZBEGopAwA3AwAgCEEIaiAGQQhqKQMANwMAIAkgLCAHGyEJIAsgGiAFIAcbIgVPDQYgCEFgaiEIIAkgDiIGSw0ACwwHC0Hoq8IAIBIgBRCmAQALQeirwgAgDCAFEKYBAAsgFSAFEKcBAAsgBSAREKUBAAsgCCATIAsgCBC5ASIGaiEJIA4gEEpBACAQQQFOGw0BIAshBQsgEyEGDAELIA8gGmohDiALIQUDQCAFIAcgBiAHKAIIIAYoAghJIgsbIggpAwA3AwAgBUEYaiAIQRhqKQMANwMAIAVBEGogCEEQaikDADcDACAFQQhqIAhBCGopAwA3AwAgBiAGQSBqIAsbIQYgBUEgaiEFIAdBIGogByALGyIHIA5PDQEgCSAGSw0ACwsgBSAGIAkgBmtBYHEQuQEaIAQoAmAiBSAMSwRAIAQoAlgiBiAfaiIHIBAgIGo2AgQgByAVNgIAIAUgEk0NAiAGICFqIgcgB0EIaiAFIBJBf3NqQQN0EJgBGiAEIAVBf2oiBTYCYCAFIAVBAU0NBBoMAQsLQfirwgAgDCAFEKYBAAtBp67CAEEdQcSuwgAQuAEACyAFCyEHIAoNAAsgBCgCXARAIAYQIQtFDQIgExAhDAILIAUgFBCnAQALIBFBAkkNACARQX5qIQUgDyARQQV0akFAaiEGQQIhCQNAIBEgBUkNBiAGIAkQPiAGQWBqIQYgCUEBaiEJIAVBf2oiBUF/Rw0ACwsgFiAWKAIAQQFqNgIAIBcgFygCAEEBajYCACANRQ0GIEanIgtBBXQhF0EAIQoCfyALRQRAQQAhB0EAIRNBACEWQQAMAQsgFEEFdCEGQQAhBUEAIRMDQCATIAUgDWoiB0EUaigCAGsgB0EYaigCAGoCfyAHKwMAmyJKRAAAAAAAAPBBYyBKRAAAAAAAAAAAZnEEQCBKqwwBC0EAC0EBdGshEyAGIAVBIGoiBUcNAAtBACEFQQAhBwNAIAcgBSANakEcai0AAEEBc2ohByAGIAVBIGoiBUcNAAtBACEJQQAhCANAIAggCSANaiIFQQxqKAIAayAFQRBqKAIAaiAFQRRqKAIAIAVBGGooAgBraiEIIAYgCUEgaiIJRw0AC0EAIQYgF0FgakEFdiIFIAtBf2oiDiAOIAVLGyIMBEAgDUEoaiEFQQAhCQNAIAZBACAFQWBqKAIAIhBBAWoiDiAFKAIAIhZrIhIgEiAOSxtqIgYgFiAQQX9zamogBiAOIBZJGyEGIAVBIGohBSAJQQFqIgkgDEkNAAsLQQAgBmshFkEAIAhrCyEIQQEhDiALQX9qIgUgC00EQCAFIAtJBEAgDSAFQQV0ai0AHSEOCyANKAIIIQUgDUEgaiANIBdqRwRAIA1BKGohBiAUQQV0QWBqIQkDQCAGKAIAIgogBSAFIApLGyEFIAZBIGohBiAJQWBqIgkNAAsLQQAgBWshCgtBACEGICQEQCAkQRRsIQkgKkEIaiEFA0AgBSgCACAFQXxqKAIAayAGaiEGIAVBFGohBSAJQWxqIgkNAAtBACAGayEGCyBFpyEFIAIoAgAoAgAoAgQiFEUgC0EBS3INAiALQQFrRQRAIBFBAUcgFEECSXINAyAPQRBqKAIAIA8oAgxrQQF0IA1BEGooAgAgDSgCDGtPDQMgDS0AHQ0DCyAFBEAgDRAhCyAbBEAgDxAhCyABKAIAIgUgASgCBEcNAAsLIABBADYCBAwBCyAAICQ2AgggACAqNgIEIAAgQDYCACAAQeAAaiAGNgIAIABB3ABqQQAgJGs2AgAgAEHYAGogPzYCACAAQdQAaiAKNgIAIABB0ABqIA42AgAgAEHMAGogFjYCACAAQcgAaiAINgIAIABBxABqIAc2AgAgAEFAayATNgIAIABBPGogETYCACAAQThqIBs2AgAgAEE0aiAPNgIAIABBMGogCzYCACAAQSxqIAU2AgAgAEEoaiANNgIAIABBJGogPzYCACAAQSBqIDM2AgAgAEEcaiA0NgIAIABBGGogNTYCACAAQRRqIDY2AgAgAEEQaiA3NgIAIABBDGogODYCAAsgBEGABGokAA8LIAUgERCnAQALQcLLwgBBECAEQfACakHMqMIAEJUBAAtB/MrCAEHGACAEQfACakHcqMIAEJUBAAvhHwMkfwN+BnwjAEHAAWsiBCQAAkACQAJAAkACQAJAAkACQCACQQhqKAIAIgMgAigCBCIGRg0AIAFBCGooAgAiBSABKAIEIgtGDQAgBSALayIFIAUgAyAGayIDIAMgBUsbIAItACkiBhshBSADQQJPQQAgBUEBSxsNASADIAVGDQIMAwsgAEECOgAcDAMLRAAAAAAAAPA/IAUgAyADIAVLIgsbuCADIAUgCxu4o6FEpHA9Ctej0D9jRQ0BCyAEQShqIAEQqwEgBCgCLCEFIAQoAighCQJAIAZFBEAgBSABKAIIIAEoAgRrIgYgA0EBaiIDIAMgBksbIgNPDQEgAyAFEKUBAAsgBSEDCwJAAkACQAJARAAAAAAAAPA/AnwCQBB4IgUEQCAEQSBqIAIQqwEgBCgCJCEGIAMEQCAGRQ0CIAUoAgANCyAEKAIgIQogBUF/NgIAIAUoAhANCyAFQQRqIQsgBUF/NgIQAkAgAyAFKAIMIghNBEAgBSADNgIMDAELIAsgAyAIaxCXAQsgBUEUaiEIAkAgBiAFKAIcIgxNBEAgBSAGNgIcDAELIAggBiAMaxCXAQsgBSgCBCAFKAIMIAkgAxByIAUoAhQgBSgCHCAKIAYQckEAIQMgBSgCBCAFKAIMIgYgBEGAAWpBAEEgIAZnaxAIIAUoAhQgBSgCHCIGIARBgAFqQQBBICAGZ2sQCCALEH8gCBB/IAUoAhwhCQJAIAUoAgwiCkUEQEEAIQZBACELQQAhCAwBC0EAIQZBACELQQAhCCAJRQ0AIAUoAhQhDCAFKAIEIQ4DQAJAAkACQAJAQX8gDiADQQJ0aigCACIPIAwgBkECdGooAgAiEEcgDyAQSRsOAgACAQsgBkEBaiEGIANBAWohAyAIQQFqIQgMAgsgA0EBaiEDDAELIAZBAWohBgsgC0EBaiELIAMgCk8NASAGIAlJDQALCyAFIAUoAhBBAWo2AhAgBSAFKAIAQQFqNgIAIAi3IAkgCmogA2sgBmsgC2q4owwDC0QAAAAAAAAAAEQAAAAAAADwPyAGGwwCCwwIC0QAAAAAAAAAAAuhRFK4HoXrUeA/YwRAIARBAjoATBAnIgdFDQcgBEEYaiACEKsBIAQoAhwhCyAEKAIYIRMgBEEQaiABEKsBIAcoAiwNCSAEKAIUIRogBCgCECEUIAdBfzYCLCAHKAI8DQkgB0EANgJIIAdCgICAgHA3AjggBEEIaiACEKwBIAdBMGogBCgCCCIDIAMgBCgCDGoQkQEgBCABEKwBIAdBQGsiFSAEKAIAIgMgAyAEKAIEahCRASAHKAIADQkgB0F/NgIAIAcoAkAhBiAHKAIwIQ0gBygCOCIJQQJqIgMgBygCSCIKQQJqIgUgAyAFSxsiAyAHKAIETQ0EIAdBBGohDyADQQF2IANqIg4gDmwiDCAHKAIQIgNNDQMgB0EIaiADIAwgA2siBRCEASAHKAIIIAcoAhAiDEEDdGohAyAFQQJJDQFBASEIA0AgA0IANwMAIANBCGohAyAIQQFqIgggBUkNAAsgCCAMakF/aiEMDAILIABBAjoAHAwFCyAFDQAMAQsgA0IANwMAIAxBAWohDAsgByAONgIEIAcgDDYCECAPEHsLIAkEQCAJQQN0IQhBAiEDA0AgBygCCCIFIAcoAgQiCSADbEEDdGpBCGogCSADQX9qbEEDdCAFakEIaisDACANKwMAoDkDACADQQFqIQMgDUEIaiENIAhBeGoiCA0ACwsgCgRAIApBA3QhDUECIQMDQCAHKAIIIAMgBygCBGpBA3RqIgUgBUF4aisDACAGKwMAoDkDACADQQFqIQMgBkEIaiEGIA1BeGoiDQ0ACwsgBygCFEUEQCAHQX82AhQCQCAHKAIYIgNFBEBBACEDIAdBADYCKAwBCyAHKAIcQf8BIANBBWoQvwEaIAdBADYCKCAHKAIYIgNBCEkNACADQQFqQQN2QQdsIQMLIAcgAzYCJCALBEAgB0EYaiEWIBMgC0ECdGohDSAUIBpBAnRqIRdBACELIBMhDgNAIAtBAWohDyAOKAIAIQwgGgRAIAtBAmohGCALQQJ0IBNqQXxqIRlBACEJQQAhAyAUIQUDQCADIQggBCAFKAIAIhA2AoABIAVBBGohBUKlxoihyJyn+UshJ0EBIQMgECEGA0AgJyAGrUL/AYOFQrODgICAIH4hJyADQQRHBEAgBEGAAWogA2ogA0EBaiEDLQAAIQYMAQsLICenIgZBGXYiA0EIdCADciIDQRB0IANyIRsgCEEBaiEDIAcoAhgiESAGcSEGIAcoAhwhHEEAIRICQANAAkAgBiAcaigAACIdIBtzIgpBf3MgCkH//ft3anFBgIGChHhxIgoEQCAHKAIgIR4DQCAQIB4gCmhBA3YgBmogEXFBA3RqIh8oAgBGDQIgCkF/aiAKcSIKDQALCyASQQRqIhIgBmogEXEhBkEAIQogHSAdQQF0cUGAgYKEeHFFDQEMAgsLIB9BBGohCgsCQAJAAkAgBygCOCIGIAtLBEAgBygCSCIGIAhNDQEgCkHYqsIAIAobKAIAIQogBygCMCALQQN0aisDACEqIBUoAgAgCEEDdGorAwAhKyALRQ0CRAAAAAAAAOA/ISwgDCAZKAIARw0CDAMLQeirwgAgCyAGEKYBAAtB6KvCACAIIAYQpgEAC0QAAAAAAADwPyEsCyAIBHxEAAAAAAAA4D9EAAAAAAAA8D8gECAIQQJ0IBRqQXxqKAIARhsFRAAAAAAAAPA/CyEuIAcoAggiBiAHKAIEIhEgCmwgCWpBA3RqKwMAIS0gKyArICogKiArYxsgKiAqYhtEAAAAAAAA8D9EAAAAAAAAAAAgDCAQRxuiIAYgDyARbCISIANqQQN0aisDAKAgLSAPIAlrIAhqIApruEQAAAAAAADgP6KgEMcBIS0gBiASIAhBAmoiCGpBA3RqKwMAIS8gKiAsEMcBISogBiAIIBEgGGwiCmpBA3RqICsgLhDHASAGIAMgCmpBA3RqKwMAoCAqIC+gEMcBIC0QxwE5AwAgAyAJIAwgEEYbIQkgBSAXRw0ACwsgDkEEaiEOIAQgDDYCgAFCpcaIocicp/lLISdBASEDIAwhBgNAICcgBq1C/wGDhUKzg4CAgCB+IScgA0EERwRAIARBgAFqIANqIANBAWohAy0AACEGDAELCyAnQhmIp0H/AHEiBUEIdCAFciIDQRB0IANyIRAgBygCGCIIICencSEDIAcoAhwhBkEAIQoCQAJAA0AgAyAGaigAACILIBBzIglBf3MgCUH//ft3anFBgIGChHhxIgkEQCAHKAIgIREDQCARIAloQQN2IANqIAhxQQN0aiISKAIAIAxGDQMgCUF/aiAJcSIJDQALCyAKQQRqIgogA2ogCHEhAyALIAtBAXRxQYCBgoR4cUUNAAsgBiAWICcQlgEiA2otAABBAXEhCyAHIAcoAiQiCSALRXIEfyAJBSAEQYABaiAWEBQgFiAnEJYBIQMgBygCGCEIIAcoAhwhBiAHKAIkCyALazYCJCAHKAIgIAMgBmogBToAACAIIANBfGpxIAZqQQRqIAU6AAAgA0EDdGoiAyAPNgIEIAMgDDYCACAHIAcoAihBAWo2AigMAQsgEiAPNgIECyAPIQsgDSAORw0ACwsgByAHKAIUQQFqNgIUIAcgBygCACIDQQFqIiI2AgAgByAHKAI8QQFqNgI8IAcgBygCLEEBajYCLCADQQJqIgNBAEoEQCAHIAM2AgAgAigCDCIXIQMgAi0AKSIbBEAgFyABKAIMIgMgFyADSxshAwsgAigCCCIWIAIoAgQiGmsiHCABKAIIIhEgASgCBCISayIYIBwgGEsbIgVBAWoiCyADQX9qIhVLBEBBACADayEjQQAgBWshGUKBgoSAECACLQAoIiRBe2oiA61CA4aIpyElIAIoAgAhHSADQQVJISYgCyEFA0AgBSIPQX9qIgVBASAFQQFLGyEeIAshAwNAAkAgFSADTw0AIA8gA2shBiADQX9qIQlBACAHKAIEIgJBA3RrIQwgBygCCCAPIAIgA2xqIg1BA3RqIQggBygCECEKA0ACQCAJIgMgHEsgBSAYS3IgBSAVRiADICNqQX9GcSADIBdJcnINACAFIAEoAgxJQQAgGxsNAiAGIAMgGWoiCSAJQQBIG0EBSg0AIAogDUsEQCAIKwMAIiogAyAeIAMgHksbuKNE4XoUrkfhyj9kDQEgBEECOgCcAUEAIQYDQCAEQTBqIAZqIgIpAwAhJyACIARBgAFqIAZqIggpAwA3AwAgCCAnNwMAIAJBCGoiCSkDACEnIAkgCEEIaiIJKQMANwMAIAJBEGoiCikDACEoIAogCEEQaiIKKQMANwMAIAJBGGoiAikDACEpIAIgCEEYaiICKQMANwMAIAIgKTcDACAKICg3AwAgCSAnNwMAIAZBQGsgBkEgaiEGQcEASQ0ACyAEIAQvAZ4BOwF8IAQtAJ0BIQ0gBCgCmAEhCCAEKAKUASEJIAQoApABIQogBCgCjAEhDCAEKAKIASEfIAQrA4ABISsgBCsDoAEhLCAEKAKoASEQIAQoAqwBIRMgBCgCsAEhFCAEKAK0ASEOIAQoArgBIQIgBC0AvAEhISAELQC9ASEgIAQtAJwBIQYgBCAELwG+ATsBegJAAkAgBkECRwRAIAQgBC8BfDsBgAEgBCAELwF6OwF+ICsgKmUNAQtBACEJIBtBAEcgBSAYRiABKAIAIR8Cf0EAIAEtACgiAkEKRg0AGkEAIAJBe2oiAkEESw0AGkKBgoSAECACrUIDhoinCyEGQQAgJUEAICYbICRBCkYbISEgBSEIIBEhCiASIQwgKiErICohLCAdIRAgGiETIBYhFEEAIQ4gAyECciINISAMAQsgBCAELwGAATsBeCAEIAQvAX47AXYLIAQgDToATSAEIAY6AEwgBCAINgJIIAQgCTYCRCAEIAo2AkAgBCAMNgI8IAQgHzYCOCAEICs5AzAgBCAgOgBtIAQgIToAbCAEIAI2AmggBCAONgJkIAQgFDYCYCAEIBM2AlwgBCAQNgJYIAQgLDkDUCAEIAQvAXg7AU4gBCAELwF2OwFuICpEAAAAAAAAsDxlQQFzDQQMAwtB6KvCACANIAoQpgEACyAGQQFqIQYgA0F/aiEJIAggDGohCCANIAJrIQ0gFSADSQ0ACwsLIBlBAWohGSAVIAVJDQALCyAHICI2AgAgACAEQTBqQcAAELkBGgwDC0GMqsIAQRggBEGAAWpBpKrCABCVAQALDAQLIABBAjoAHAsgBEHAAWokAA8LQfzKwgBBxgAgBEGAAWpB3KjCABCVAQALQcLLwgBBECAEQYABakHcqsIAEJUBAAtBwsvCAEEQIARBgAFqQciqwgAQlQEAC5gcAht/AX4jAEHwA2siAyQAIAMgAjYCkAMgAyACNgKMAyADIAE2AogDIANB0ABqIANBiANqEI0BIAMoAlQhDyADKAJQIQ4CQAJAAkACQAJAAkACQBDBASIKBEAgCigCAA0GIApBfzYCACAKQQRqIAAQVCIERQ0BEMABIgsEQCALKAIADQcgC0F/NgIAIAtBBGogABBVIghFDQMgA0GgAmogDiAOIA9qECMgA0GIA2ogA0GgAmoQXyADQaACaiADQYgDaiAEQRRqIgIQOyADKAKoAiIAQX9qIgEgAE8gASAAS3JFBEAgAygCoAIgAUEUbGpBADoAEQsgA0GIA2ogA0GgAmpBMBC5ARogA0GgAmogA0GIA2ogAhAmIANBiANqIANBoAJqIAIQICADQaACaiADQYgDahA5IAMoAqgCIgEEQCADKAKgAiEAIAFBFGwhASADQcACaigCACEFIAMoArgCIQwDQCAAIAwgBSACEEcgAEEUaiEAIAFBbGoiAQ0ACwsgA0GIA2ogA0GgAmpBMBC5ARogA0GgAmogA0GIA2ogAhBPIAMoAqACIQwCQCADKAKoAiIFRQRAIANBwAJqKAIAIQYgAygCuAIhDQwBCyAFQRRsIQEgA0HAAmooAgAhBiADKAK4AiENIAwhAANAIAAgDSAGIAIQRiAAQRRqIQAgAUFsaiIBDQALCyADQcgCaigCACEWIANBvAJqKAIAIRcgA0GwAmooAgAhGCADQbQCaigCACEAIAMoAqQCIRkgAygCrAIhECADKALEAiERIANB9ABqIANBzAJqKAIANgIAIANB7ABqIAY2AgAgA0HkAGogADYCACADIBE2AnAgAyANNgJoIAMgEDYCYCADIAU2AlwgAyAMNgJYAkAgCCgCCCICRQRAQQAhAgwBCyAIKAIAIAJBBHRqQXRqIQADQCACQX9qIQIgAEEEaigCAARAIAAoAgAQIQsgAEFwaiEAIAINAAsgAygCXCEFCyAIIAI2AgggAyADQdgAajYCfCADIAQ2AnggA0GMAWogBCgCrAE2AgAgAyAEKAKkATYCiAEgAyAEKAKgATYChAEgAyAEKAKYATYCgAECQCAFRQRAIAQoAtgBDQogBEHYAWohBSAEQX82AtgBIARB3AFqIQcgBCgC3AENASAEKAIEIQAgBCgCDCEBIANBIGogBCgCECICQQF0EJ8BIAMpAyAhHiADQbACaiIGQQA2AgAgA0EAOwG4AiADIAI2ArQCIAMgHjcDqAIgAyAAIAFBPGxqNgKkAiADIAA2AqACAkAgA0GgAmoQMSIARQRAIANBADYCqAEgA0IENwOgASADKAKsAkUNASADKAKoAhAhDAELIAAoAgAhACADQRhqQQEQnwEgAygCHCEBIAMoAhgiCSAANgIAIANBATYC6AEgAyABNgLkASADIAk2AuABIANBoANqIANBuAJqKAIANgIAIANBmANqIAYpAwA3AwAgA0GQA2ogA0GoAmopAwA3AwAgAyADKQOgAjcDiAMCQCADQYgDahAxIgZFDQBBBCECQQEhAANAIAYoAgAhBiAAIAFGBEAgA0HgAWogAUEBEIIBIAMoAuABIQkLIAIgCWogBjYCACADIABBAWoiADYC6AEgA0GIA2oQMSIGRQ0BIAJBBGohAiADKALkASEBDAALAAsgA0GUA2ooAgAEQCADKAKQAxAhCyADQagBaiADQegBaigCADYCACADIAMpA+ABNwOgAQsgA0GIA2ogA0GgAWoQkgECQCAHKAIAIgBFDQAgBCgC4AFFDQAgABAhCyAHIAMpA4gDNwIAIAdBCGogA0GQA2ooAgA2AgAgA0GYAWogA0GoAWooAgA2AgAgAyADKQOgATcDkAEMBwsgBCgCsAENCSAEQX82ArABIARBADYC1AEgBEHMAWohCSAEKAIQIRIgBCAEKAK0ASIBBH8gCUEAIAEQggEgBCgCzAEgBCgC1AEiAkECdGohACABQQFHBEAgAUF/aiEBA0AgAEEANgIAIAJBAWohAiAAQQRqIQAgAUF/aiIBDQALCyAAQQA2AgAgAkEBagVBAAs2AtQBIANBoAFqIANB2ABqEB8gAygCoAEhByADKAKoASIARQ0FIAcgAEEMbGohGiAHIQUDQCADQqXGiKHInKf5SzcDiAMgBSIBIANBiANqEHUgAygCiAMiAEEZdiICQQh0IAJyIgJBEHQgAnIhGyABQQxqIQUgBCgCuAEiEyAAcSEAIAQoArwBIRxBACEUAkADQAJAIAAgHGooAAAiFSAbcyICQX9zIAJB//37d2pxQYCBgoR4cSICBEAgBCgCwAEhHQNAIAEgHSACaEEDdiAAaiATcUEYbGoiBkYNAiABIAZBDBC0AUUNAiACQX9qIAJxIgINAAsLIBRBBGoiFCAAaiATcSEAIBUgFUEBdHFBgIGChHhxRQ0BDAILCyAGKAIUIgFFDQAgBigCDCEAIAFBAnQhAgNAIAkoAgAgACgCAEECdGoiASABKAIAQQFqNgIAIABBBGohACACQXxqIgINAAsLIAUgGkcNAAsMBQsgA0GQAWogBxCSAQwFCwwFCwwEC0G57MIAQStB5OzCABC4AQALQbnswgBBK0Hk7MIAELgBAAsgBCgCzAEhACAEKALUASEBIANByABqIBJBFGwQoAEgAykDSCEeIANBtAJqQQA2AgAgA0EAOwG8AiADIBJBCmw2ArgCIAMgHjcCrAIgA0EANgKoAiADIAAgAUECdGo2AqQCIAMgADYCoAIgA0FAayADQaACahAwAkAgAygCREUEQCADQQA2ApgBIANCBDcDkAEgAygCsAJFDQEgAygCrAIQIQwBCyADKAJAIQAgA0E4akEBEJ8BIAMoAjwhASADKAI4IgUgADYCACADQQE2AugBIAMgATYC5AEgAyAFNgLgASADQaADaiADQbgCaikDADcDACADQZgDaiIJIANBsAJqKQMANwMAIANBkANqIANBqAJqKQMANwMAIAMgAykDoAI3A4gDIANBMGogA0GIA2oQMAJAIAMoAjRFDQAgAygCMCEGQQQhAkEBIQADQCAAIAFGBEAgA0HgAWogAUEBEIIBIAMoAuABIQULIAIgBWogBjYCACADIABBAWoiADYC6AEgA0EoaiADQYgDahAwIAMoAixFDQEgAygCKCEGIAJBBGohAiADKALkASEBDAALAAsgCSgCAARAIAMoApQDECELIANBmAFqIANB6AFqKAIANgIAIAMgAykD4AE3A5ABCyADKAKkAQRAIAcQIQsgBEGwAWohBQsgBSAFKAIAQQFqNgIAIAMoApABIQcgAygCmAEhACADQRBqIAMoAngoAhAiAUEBdBCcASADKQMQIR4gA0G8AWpBADYCACADQQA7AcQBIAMgATYCwAEgAyAeNwK0ASADIAcgAEECdGo2AqQBIAMgBzYCoAEgAyADQYABajYCyAEgAyADQfwAajYCsAEgAyADQfwAajYCrAEgAyADQfgAajYCqAEgA0GgAmogA0GgAWoQHgJAAkACQAJAIAMoAqQCRQRAIANBADYC5AEMAQsgA0GIA2ogA0GgAmpB5AAQuQEaIANB4AFqIANByAFqIANBiANqEBUgAygC5AENAQsgAygCvAEiAQRAIAMoArQBIQAgAUHkAGwhAgNAIAAQvAEgAEHkAGohACACQZx/aiICDQALC0EAIQRBBCEFIAMoArgBRQ0BIAMoArQBECEMAQtBASECIANBCGpBARCeASADQegBaikDACEeIAMoAgwhACADKAIIIgUgAykD4AE3AgAgBUEIaiAeNwIAIANBATYC2AEgAyAANgLUASADIAU2AtABIANB4AFqIANBoAFqQSwQuQEaIANBoAJqIANB4AFqEB4CQCADKAKkAgRAIANBiAJqIQFBECEAA0AgA0GIA2ogA0GgAmpB5AAQuQEaIANBkAJqIAEgA0GIA2oQFSADKAKUAkUNAiADQZADaiIEIANBmAJqKQMANwMAIAMgAykDkAI3A4gDIAMoAtQBIAJGBEAgA0HQAWogAhCzASADKALQASEFCyAAIAVqIgYgAykDiAM3AgAgBkEIaiAEKQMANwIAIAMgAkEBaiICNgLYASAAQRBqIQAgA0GgAmogA0HgAWoQHiADKAKkAg0ACwsgA0EANgKUAgsgA0H8AWooAgAiAQRAIAMoAvQBIQAgAUHkAGwhAQNAIAAQvAEgAEHkAGohACABQZx/aiIBDQALCyADQfgBaigCAARAIAMoAvQBECELIAMoAtQBIQQMAQtBACECCyADKAKUAQRAIAcQIQsgBSACQQR0aiEBAkAgASACBH8gBSEAA0AgAEEEaigCACIHBEAgAEEIaikCACEeIAAoAgAhBiAIKAIIIgIgCCgCBEYEQCAIIAIQswEgCCgCCCECCyAIKAIAIAJBBHRqIgIgBzYCBCACIAY2AgAgAkEIaiAeNwIAIAggCCgCCEEBajYCCCAAQRBqIgAgAUcNAQwDCwsgAEEQagUgBQsiAEYNAANAIABBBGooAgAiAkUNASAAQQhqKAIABEAgAhAhCyAAQRBqIgIhACABIAJHDQALCyAEBEAgBRAhCyAZBEAgDBAhCyAYBEAgEBAhCyAXBEAgDRAhCyAWBEAgERAhCyALIAsoAgBBAWo2AgAgCiAKKAIAQQFqNgIAIA8EQCAOECELIANB8ANqJAAPC0H8ysIAQcYAIANBiANqQdyowgAQlQEAC0HCy8IAQRAgA0GIA2pBzKjCABCVAQALQcLLwgBBECADQYgDakG4qsIAEJUBAAvDGAIIfwF+AkACQAJAIABB9QFPBEAgAEHN/3tPDQIgAEELaiIAQXhxIQRBhPDCACgCACIIRQ0BQQAgBGshAgJAAkACf0EAIABBCHYiAEUNABpBHyAEQf///wdLDQAaIARBBiAAZyIAa0EfcXZBAXEgAEEBdGtBPmoLIgdBAnRBkPLCAGooAgAiAARAIARBAEEZIAdBAXZrQR9xIAdBH0YbdCEFA0ACQCAAKAIEQXhxIgYgBEkNACAGIARrIgYgAk8NACAAIQMgBiICDQBBACECDAMLIABBFGooAgAiBiABIAYgACAFQR12QQRxakEQaigCACIARxsgASAGGyEBIAVBAXQhBSAADQALIAEEQCABIQAMAgsgAw0CC0EAIQNBAiAHQR9xdCIAQQAgAGtyIAhxIgBFDQMgAEEAIABrcWhBAnRBkPLCAGooAgAiAEUNAwsDQCAAIAMgACgCBEF4cSIBIARPIAEgBGsiBiACSXEiBRshAyAGIAIgBRshAiAAKAIQIgEEfyABBSAAQRRqKAIACyIADQALIANFDQILQZDzwgAoAgAiACAET0EAIAIgACAEa08bDQEgAxBYAkAgAkEQTwRAIAMgBEEDcjYCBCADIARqIgEgAkEBcjYCBCABIAJqIAI2AgAgAkGAAk8EQCABIAIQUAwCCyACQQN2IgJBA3RBiPDCAGohAAJ/QYDwwgAoAgAiBUEBIAJBH3F0IgJxBEAgACgCCAwBC0GA8MIAIAIgBXI2AgAgAAshAiAAIAE2AgggAiABNgIMIAEgADYCDCABIAI2AggMAQsgAyACIARqIgBBA3I2AgQgACADaiIAIAAoAgRBAXI2AgQLIANBCGoPCwJAAkBBgPDCACgCACIDQRAgAEELakF4cSAAQQtJGyIEQQN2IgJBH3EiAXYiAEEDcUUEQCAEQZDzwgAoAgBNDQMgAA0BQYTwwgAoAgAiAEUNAyAAQQAgAGtxaEECdEGQ8sIAaigCACIBKAIEQXhxIARrIQIgASEFA0AgASgCECIARQRAIAFBFGooAgAiAEUNBAsgACgCBEF4cSAEayIBIAIgASACSSIBGyECIAAgBSABGyEFIAAhAQwACwALIABBf3NBAXEgAmoiAUEDdCIGQZDwwgBqKAIAIgBBCGohAgJAIAAoAggiBSAGQYjwwgBqIgZHBEAgBSAGNgIMIAYgBTYCCAwBC0GA8MIAIANBfiABd3E2AgALIAAgAUEDdCIBQQNyNgIEIAAgAWoiACAAKAIEQQFyNgIEDAMLAkBBAiABdCICQQAgAmtyIAAgAXRxIgBBACAAa3FoIgBBA3QiBUGQ8MIAaigCACIBKAIIIgIgBUGI8MIAaiIFRwRAIAIgBTYCDCAFIAI2AggMAQtBgPDCACADQX4gAHdxNgIACyABIARBA3I2AgQgASAEaiIGIABBA3QiACAEayIFQQFyNgIEIAAgAWogBTYCAEGQ88IAKAIAIgAEQCAAQQN2IgNBA3RBiPDCAGohAEGY88IAKAIAIQICf0GA8MIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtBgPDCACADIARyNgIAIAALIQMgACACNgIIIAMgAjYCDCACIAA2AgwgAiADNgIIC0GY88IAIAY2AgBBkPPCACAFNgIAIAFBCGoPCyAFEFgCQCACQRBPBEAgBSAEQQNyNgIEIAQgBWoiBiACQQFyNgIEIAIgBmogAjYCAEGQ88IAKAIAIgAEQCAAQQN2IgNBA3RBiPDCAGohAEGY88IAKAIAIQECf0GA8MIAKAIAIgRBASADQR9xdCIDcQRAIAAoAggMAQtBgPDCACADIARyNgIAIAALIQMgACABNgIIIAMgATYCDCABIAA2AgwgASADNgIIC0GY88IAIAY2AgBBkPPCACACNgIADAELIAUgAiAEaiIAQQNyNgIEIAAgBWoiACAAKAIEQQFyNgIECyAFQQhqDwsCQAJAAkACQEGQ88IAKAIAIgEgBEkEQEGU88IAKAIAIgAgBEsNBkEAIQIgBEGvgARqIgBBEHZAACIBQX9GDQUgAUEQdCIDRQ0FQaDzwgAgAEGAgHxxIgVBoPPCACgCAGoiADYCAEGk88IAQaTzwgAoAgAiASAAIAEgAEsbNgIAQZzzwgAoAgAiAkUNAUGo88IAIQADQCAAKAIAIgEgACgCBCIGaiADRg0DIAAoAggiAA0ACwwDC0GY88IAKAIAIQACQCABIARrIgNBD00EQEGY88IAQQA2AgBBkPPCAEEANgIAIAAgAUEDcjYCBCAAIAFqIgFBBGohBCABKAIEQQFyIQIMAQtBkPPCACADNgIAQZjzwgAgACAEaiICNgIAIAIgA0EBcjYCBCAAIAFqIAM2AgAgBEEDciECIABBBGohBAsgBCACNgIAIABBCGoPC0G888IAKAIAIgBBACAAIANNG0UEQEG888IAIAM2AgALQQAhAEHA88IAQf8fNgIAQazzwgAgBTYCAEGo88IAIAM2AgBBtPPCAEEANgIAA0AgAEGQ8MIAaiAAQYjwwgBqIgE2AgAgAEGU8MIAaiABNgIAIABBCGoiAEGAAkcNAAtBnPPCACADNgIAQZTzwgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRBuPPCAEGAgIABNgIADAILIAAoAgwgAyACTXIgASACS3INACAAIAUgBmo2AgRBnPPCAEGc88IAKAIAIgBBD2pBeHEiAUF4ajYCAEGU88IAQZTzwgAoAgAgBWoiAyAAIAFrakEIaiICNgIAIAFBfGogAkEBcjYCACAAIANqQSg2AgRBuPPCAEGAgIABNgIADAELQbzzwgBBvPPCACgCACIAIAMgACADSRs2AgAgAyAFaiEBQajzwgAhAAJAA0AgASAAKAIARwRAIAAoAggiAA0BDAILCyAAKAIMDQAgACADNgIAIAAgACgCBCAFajYCBCADIARBA3I2AgQgAyAEaiEFIAEgA2sgBGshBAJAAkAgAUGc88IAKAIARwRAQZjzwgAoAgAgAUYNASABKAIEIgBBA3FBAUYEQAJAIABBeHEiAkGAAk8EQCABEFgMAQsgASgCDCIGIAEoAggiB0cEQCAHIAY2AgwgBiAHNgIIDAELQYDwwgBBgPDCACgCAEF+IABBA3Z3cTYCAAsgAiAEaiEEIAEgAmohAQsgASABKAIEQX5xNgIEIAUgBEEBcjYCBCAEIAVqIAQ2AgAgBEGAAk8EQCAFIAQQUAwDCyAEQQN2IgFBA3RBiPDCAGohAAJ/QYDwwgAoAgAiAkEBIAFBH3F0IgFxBEAgACgCCAwBC0GA8MIAIAEgAnI2AgAgAAshAiAAIAU2AgggAiAFNgIMIAUgADYCDCAFIAI2AggMAgtBnPPCACAFNgIAQZTzwgBBlPPCACgCACAEaiIANgIAIAUgAEEBcjYCBAwBC0GY88IAIAU2AgBBkPPCAEGQ88IAKAIAIARqIgA2AgAgBSAAQQFyNgIEIAAgBWogADYCAAsgA0EIag8LQajzwgAhAANAAkAgACgCACIBIAJNBEAgASAAKAIEaiIGIAJLDQELIAAoAgghAAwBCwtBnPPCACADNgIAQZTzwgAgBUFYaiIANgIAIAMgAEEBcjYCBCAAIANqQSg2AgRBuPPCAEGAgIABNgIAIAIgBkFgakF4cUF4aiIAIAAgAkEQakkbIgFBGzYCBEGo88IAKQIAIQkgAUEQakGw88IAKQIANwIAIAEgCTcCCEGs88IAIAU2AgBBqPPCACADNgIAQbDzwgAgAUEIajYCAEG088IAQQA2AgAgAUEcaiEAA0AgAEEHNgIAIAYgAEEEaiIASw0ACyABIAJGDQAgASABKAIEQX5xNgIEIAIgASACayIAQQFyNgIEIAEgADYCACAAQYACTwRAIAIgABBQDAELIABBA3YiAUEDdEGI8MIAaiEAAn9BgPDCACgCACIDQQEgAUEfcXQiAXEEQCAAKAIIDAELQYDwwgAgASADcjYCACAACyEBIAAgAjYCCCABIAI2AgwgAiAANgIMIAIgATYCCAtBACECQZTzwgAoAgAiACAETQ0ADAELIAIPC0GU88IAIAAgBGsiATYCAEGc88IAQZzzwgAoAgAiACAEaiIDNgIAIAMgAUEBcjYCBCAAIARBA3I2AgQgAEEIagukFgEWfyMAQfADayIGJAACQAJAIAFBFUkNACAGQeAAaiEXIAZByAJqIRhBASERQQEhEgJAAkACQANAIAEhCiAAIQ4gA0FAayEQIBEgEnFBAXMhDwJAA0ACfwJAIAQEQCARQQFxRQRAIAAgARBiIARBf2ohBAsgBiACNgIIIAYgADYCiAEgBiABNgKMASAGIAFBAnYiBTYCDCAGIAVBAXQiBzYCECAGIAVBA2wiCTYCFCAGQQA2AhggBiAGQRhqNgKQAiAGIAZBiAFqNgKMAiAGIAZBCGo2AogCIAYgBkGIAmo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2AogDIAZBHGogBkEgaiAGQQxqIAZBiANqEMQBIAYgB0F/ajYCICAGIAdBAXI2AogDIAZBHGogBkEgaiAGQRBqIAZBiANqEMQBIAYgCUF/ajYCICAGIAlBAWo2AogDIAZBHGogBkEgaiAGQRRqIAZBiANqEMQBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMQBIAYoAhgiBUELSw0BIAYoAhAhCCAFRQwCCyAAIAEQdAwJCyAGKAKMASIJQQF2IgcEQCAGKAKIASIFIAlB5ABsakGcf2ohDANAIAZBiANqIAVB5AAQuQEaIAUgDEHkABCYAUHkAGohBSAMIAZBiANqQeQAELkBQZx/aiEMIAdBf2oiBw0ACwsgASAGKAIQQX9zaiEIQQELRSAPckEBcUUEQCAAIAEQJA0ICyADRQRAIA4hACAKIQEMAgsCQCAIIAFJBEAgACAIQeQAbGoiCUFAayENQQAhBQJAA0AgBUEEaiIHQShGDQEgBSAQaiELIAUgDWohDCAHIQVBfyAMKAIAIgcgCygCACILRyAHIAtIGyIHRQ0ACyAHQf8BcUH/AUYNBAsgAQRAIAZBiANqIABB5AAQuQEaIAAgCUHkABCYASENIAkgBkGIA2pB5AAQuQEaIAZBiAJqIA1B5AAQuQEaIA1BXGohDCANQaQBaiETIA1B5ABqIRRBACEIIAEhCQNAAkAgCCAJQX9qIgdPDQAgEyAIQeQAbGohCwNAQQAhBQJAA0AgBUEEaiIAQShGDQEgBSAYaiESIAUgC2ohFSAAIQVBfyAVKAIAIgAgEigCACISRyAAIBJIGyIARQ0ACyAAQf8BcUH/AUYNAgsgC0HkAGohCyAIQQFqIgggB0cNAAsgByEIDAQLIAggB08NAyAMIAlB5ABsaiELA0AgByIJQX9qIQdBACEFAkADQCAFQQRqIgBBKEYNASAFIBhqIRIgBSALaiEVIAAhBUF/IBUoAgAiACASKAIAIhJHIAAgEkgbIgBFDQALIABB/wFxQf8BRw0AIAtBnH9qIQsgCCAHTw0FDAELCyAGQYgDaiAUIAhB5ABsaiIAQeQAELkBGiAAIA0gCUHkAGxqIgBB5AAQmAEaIAAgBkGIA2pB5AAQuQEaIAhBAWohCAwACwALQbCnwgBBAEEAEKYBAAtB0KfCACAIIAEQpgEACyANIAZBiAJqQeQAELkBIQAgASAIQQFqIgVPBEAgACAFQeQAbGohACABIAVrIgFBFU8NAQwHCwsgBSABEKcBAAsCQAJAIAEEQCAIIAFPDQEgBkGIAmogAEHkABC5ARogACAAIAhB5ABsaiIFQeQAEJgBIRQgBSAGQYgCakHkABC5ARogBkEgaiAUQeQAELkBGiABQX9qIglFBEBBACEQDAMLIBRBpAFqIQtBACEQA0BBACEFA0AgBUEEaiIHQShGDQQgBSALaiEKIAUgF2ohDiAHIQVBfyAOKAIAIgcgCigCACIKRyAHIApIGyIHRQ0ACyAHQf8BcUH/AUcNAyALQeQAaiELIBBBAWoiECAJRw0ACyAJIRAMAgtBsKfCAEEAQQAQpgEAC0HAp8IAIAggARCmAQALIBRB5ABqIQ0gFCABQeQAbGpBXGohCyAJIQcDQCAHIgogEE0iEkUEQCAKQX9qIQdBACEFA0ACQCAFQQRqIg5BKEYEQEEAIQwMAQsgBSALaiEIIAUgF2ohDCAOIQVBfyAMKAIAIg4gCCgCACIIRyAOIAhIGyIMRQ0BCwsgC0Gcf2ohCyAMQX9HDQELCyAKIBBPBEAgCSAKSQ0CIA0gCkHkAGxqIQ5BgAEhE0EAIQlBACEKQQAhB0EAIQ9BgAEhESANIBBB5ABsaiIVIQ0DQCAOIA1rIghB5ABuIQUCQCAIQePIAUsiGQ0AIAVBgH9qIAUgDyAHSSIIIAogCUlyIgsbIQUgCwRAIBEgBSAIGyERIAUgEyAIGyETDAELIAUgBUEBdiIRayETCwJAIAcgD0cNACARRQRAIAZBiAFqIgchDwwBC0EAIQggBkGIAWohByANIQsDQCAHIAg6AAAgCEEBaiEIQcAAIQUDQAJAIAVBBGoiDEHoAEYEQEEAIQwMAQsgBSALaiEPIAZBIGogBWohFiAMIQVBfyAWKAIAIgwgDygCACIPRyAMIA9IGyIMRQ0BCwsgC0HkAGohCyAHIAxBf0dqIQcgCCARRw0ACyAGQYgBaiEPCwJAIAkgCkcNACATRQRAIAZBiAJqIgkhCgwBC0EAIQggBkGIAmohCSAOIQoDQCAJIAg6AAAgCiILQZx/aiEKIAhBAWohCEEAIQUDQAJAIAVBBGoiDEEoRgRAQQAhDAwBCyAFIBdqIRYgBSALaiEaIAwhBUF/IBYoAgAiDCAaQVxqKAIAIhZHIAwgFkgbIgxFDQELCyAJIAxBf0ZqIQkgCCATRw0ACyAGQYgCaiEKCyAJIAprIgUgByAPayIIIAggBUsbIggEQCAGQYgDaiANIA8tAABB5ABsakHkABC5ARogDSAPLQAAQeQAbGogDiAKLQAAQX9zQeQAbGpB5AAQuQEaIAhBAUcEQEEAIQUDQCAOIAUgCmoiCy0AAEF/c0HkAGxqIA0gBSAPakEBaiIMLQAAQeQAbGpB5AAQuQEaIA0gDC0AAEHkAGxqIA4gC0EBai0AAEF/c0HkAGxqQeQAELkBGiAFQQJqIAVBAWoiCyEFIAhJDQALIAsgD2ohDyAKIAtqIQoLIA4gCi0AAEF/c0HkAGxqIAZBiANqQeQAELkBGiAPQQFqIQ8gCkEBaiEKCyANIBFB5ABsaiANIAcgD0YbIQ0gDkEAIBNrQeQAbGogDiAJIApGGyEOIBkNAAsCQCAPIAdJBEAgDiEFA0AgBkGIA2ogDSAHQX9qIgctAABB5ABsaiIJQeQAELkBGiAJIAVBnH9qIgVB5AAQmAEaIAUgBkGIA2pB5AAQuQEaIA8gB0kNAAsMAQsgDSEFIAogCU8NAANAIAlBf2oiCS0AACEHIAZBiANqIAVB5AAQuQEaIAUgDiAHQX9zQeQAbGoiBUHkABCYASAFIAZBiANqQeQAELkBGkHkAGohBSAKIAlJDQALCyAUIAZBIGpB5AAQuQEhByABIAUgFWtB5ABuIBBqIgVNDQMgBkGIAmogB0HkABC5ARogByAHIAVB5ABsaiIHQeQAEJgBIQ4gByAGQYgCakHkABC5ASEHIAEgBWsiCkUNBCAKIAUgBSAKSxsgAUEDdiAHQeQAaiEJAkAgBSAKQX9qIgFPBEAgCSABIAIgByAEEAQgBSEBDAELIA4gBSACIAMgBBAEIAchAyAJIQALTyERIAFBFU8NAQwFCwsgECAKEKcBAAsgCiAJEKUBAAtBwKfCACAFIAEQpgEAC0G1rcIAQRxB1K3CABC4AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBIIAEgBUcNAAsLIAZB8ANqJAALgRUBF38jAEHAAmsiBiQAAkACQCABQRVJDQBBASEJQQEhEwJAAkACQANAIAEhDyAAIQ4gCSATcUEBcyERAkACQAJAAkACQAJAA0ACfwJAIAQEQCAJRQRAIAAgARBbIARBf2ohBAsgBiACNgIIIAYgADYCMCAGIAE2AjQgBiABQQJ2IgU2AgwgBiAFQQF0Igg2AhAgBiAFQQNsIgc2AhQgBkEANgIYIAYgBkEYajYCuAEgBiAGQTBqNgK0ASAGIAZBCGo2ArABIAYgBkGwAWo2AhwgAUEyTwRAIAYgBUF/ajYCICAGIAVBAWo2ArACIAZBHGogBkEgaiAGQQxqIAZBsAJqEMUBIAYgCEF/ajYCICAGIAhBAXI2ArACIAZBHGogBkEgaiAGQRBqIAZBsAJqEMUBIAYgB0F/ajYCICAGIAdBAWo2ArACIAZBHGogBkEgaiAGQRRqIAZBsAJqEMUBCyAGQRxqIAZBDGogBkEQaiAGQRRqEMUBIAYoAhgiBUELSw0BIAYoAhAhByAFRQwCCyAAIAEQagwOCyAGKAI0IgdBAXYiCARAIAYoAjAiBSAHQQxsakF0aiEHA0AgBkG4AmoiDCAFQQhqIgooAgA2AgAgBiAFKQIANwOwAiAHQQhqIgsoAgAhDSAFIAcpAgA3AgAgCiANNgIAIAsgDCgCADYCACAHIAYpA7ACNwIAIAVBDGohBSAHQXRqIQcgCEF/aiIIDQALCyABIAYoAhBBf3NqIQdBAQtFIBFyQQFxRQRAIAAgARArDQ0LIANFDQIgByABTw0BIANBAyAAIAdBDGxqIgVBAxCTAUH/AXFB/wFGBEAgASEPIAAhDgwECyAGQbgCaiIKIABBCGoiCygCADYCACAGIAApAgA3A7ACIAVBCGoiCCgCACEHIAAgBSkCADcCACALIAc2AgAgCCAKKAIANgIAIAUgBikDsAI3AgAgBkG4AWoiEiALKAIANgIAIAYgACkCADcDsAEgAEF0aiEUIABBDGohDUEAIQcgASEIA0ACQCAHIAhBf2oiDE8NACANIAdBDGxqIQUDQCAGQbABakEDIAVBAxCTAUH/AXFB/wFGDQEgBUEMaiEFIAwgB0EBaiIHRw0ACyAMIQcLIBQgCEEMbGohBQJAA0AgByAIQX9qIghPDQEgBkGwAWpBAyAFQQMQkwEgBUF0aiIMIQVB/wFxQf8BRg0ACyAKIA0gB0EMbGoiBUEIaiITKAIANgIAIAYgBSkCADcDsAIgDEEUaiIQKAIAIRYgBSAMQQxqIgUpAgA3AgAgEyAWNgIAIBAgCigCADYCACAFIAYpA7ACNwIAIAdBAWohBwwBCwsgACAGKQOwATcCACALIBIoAgA2AgAgASAHQQFqIgVPBEAgACAFQQxsaiEAIAEgBWsiAUEVTw0BDAwLCyAFIAEQpwEAC0HQp8IAIAcgARCmAQALIA9FDQELIAcgD08NASAGQbgBaiIUIA5BCGoiEigCADYCACAGIA4pAgA3A7ABIA4gB0EMbGoiAEEIaiIBKAIAIQUgDiAAKQIANwIAIBIgBTYCACABIBQoAgA2AgAgACAGKQOwATcCACAGQShqIhYgEigCADYCACAGIA4pAgA3AyAgDkEMaiEJQQAhDCAPQX9qIgFFDQIgCSEFA0AgBUEDIAZBIGpBAxCTAUH/AXFB/wFHDQMgBUEMaiEFIAEgDEEBaiIMRw0ACyABIQwMAgtBsKfCAEEAQQAQpgEAC0HAp8IAIAcgDxCmAQALIA4gD0EMbGohByABIQgDQAJAIAchACAIIgUgDE0iEw0AIAVBf2ohCCAAQXRqIgdBAyAGQSBqQQMQkwFB/wFxQf8BRw0BCwsgBSAMTwRAIAEgBUkNAkGAASENQQAhCEEAIQpBACEHQQAhC0GAASERIAkgDEEMbGoiFyEBA0AgACABayIJQQxuIQUCQCAJQYsYSyIYDQAgBUGAf2ogBSALIAdJIgkgCiAISXIiEBshBSAQBEAgESAFIAkbIREgBSANIAkbIQ0MAQsgBSAFQQF2IhFrIQ0LAkAgByALRw0AIBFFBEAgBkEwaiIHIQsMAQtBACEFIAEhCSAGQTBqIgshBwNAIAcgBToAACAHIAlBAyAGQSBqQQMQkwFB/wFxQf8BR2ohByAJQQxqIQkgESAFQQFqIgVHDQALCwJAIAggCkcNACANRQRAIAZBsAFqIgghCgwBCyAAQXRqIQVBACEJIAZBsAFqIgohCANAIAggCToAACAIIAVBAyAGQSBqQQMQkwFB/wFxQf8BRmohCCAFQXRqIQUgDSAJQQFqIglHDQALCyAIIAprIgUgByALayIJIAkgBUsbIgkEQCAGQbgCaiIZIAEgCy0AAEEMbGoiBUEIaigCADYCACAGIAUpAgA3A7ACIAEgCy0AAEEMbGoiBSAAIAotAABBf3NBDGxqIhApAgA3AgAgBUEIaiAQQQhqKAIANgIAIAlBAUcEQEEAIQUDQCAAIAUgCmoiEC0AAEF/c0EMbGoiFSABIAUgC2pBAWoiGi0AAEEMbGoiGykCADcCACAVQQhqIBtBCGooAgA2AgAgASAaLQAAQQxsaiIVIAAgEEEBai0AAEF/c0EMbGoiECkCADcCACAVQQhqIBBBCGooAgA2AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgCiAQaiEKCyAAIAotAABBf3NBDGxqIgUgBikDsAI3AgAgBUEIaiAZKAIANgIAIAtBAWohCyAKQQFqIQoLIAEgEUEMbGogASAHIAtGGyEBIABBACANa0EMbGogACAIIApGGyEAIBgNAAsCQCALIAdJBEAgACEFA0AgBkG4AmoiCCABIAdBf2oiBy0AAEEMbGoiAEEIaiIJKAIANgIAIAYgACkCADcDsAIgBUF0aiIFQQhqIgooAgAhDSAAIAUpAgA3AgAgCSANNgIAIAogCCgCADYCACAFIAYpA7ACNwIAIAsgB0kNAAsMAQsgASEFIAogCE8NAANAIAhBf2oiCC0AACEBIAZBuAJqIgcgBUEIaiIJKAIANgIAIAYgBSkCADcDsAIgACABQX9zQQxsaiIBQQhqIgsoAgAhDSAFIAEpAgA3AgAgCSANNgIAIAsgBygCADYCACABIAYpA7ACNwIAIAVBDGohBSAKIAhJDQALCyAOIAYpAyA3AgAgEiAWKAIANgIAIA8gBSAXa0EMbiAMaiIBTQ0DIBQgEigCADYCACAGIA4pAgA3A7ABIA4gAUEMbGoiBUEIaiIAKAIAIQggDiAFKQIANwIAIBIgCDYCACAAIBQoAgA2AgAgBSAGKQOwATcCACAPIAFrIghFDQQgCCABIAEgCEsbIA9BA3YgBUEMaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAUgDiEADAELIA4gASACIAMgBBAFIAUhAyAIIQELTyEJIAFBFU8NAQwFCwsgDCAFEKcBAAsgBSABEKUBAAtBwKfCACABIA8QpgEAC0G1rcIAQRxB1K3CABC4AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRBNIAEgBUcNAAsLIAZBwAJqJAAL5BACFH8BfiMAQbACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEOIAAhDCAPIBNxQQFzIQkCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEG4gBEF/aiEECyAGIAI2AgwgBiAANgIwIAYgATYCNCAGIAFBAnYiCDYCECAGIAhBAXQiBTYCFCAGIAhBA2wiBzYCGCAGQQA2AhwgBiAGQRxqNgK4ASAGIAZBMGo2ArQBIAYgBkEMajYCsAEgBiAGQbABajYCICABQTJPBEAgBiAIQX9qNgIkIAYgCEEBajYCKCAGQSBqIAZBJGogBkEQaiAGQShqEGMgBiAFQX9qNgIkIAYgBUEBcjYCKCAGQSBqIAZBJGogBkEUaiAGQShqEGMgBiAHQX9qNgIkIAYgB0EBajYCKCAGQSBqIAZBJGogBkEYaiAGQShqEGMLIAZBIGogBkEQaiAGQRRqIAZBGGoQYyAGKAIcIghBC0sNASAIRSEHIAYoAhQMAgsgACABEIkBDA4LIAYoAjQiB0EBdiIIBEAgBigCMCIFIAdBA3RqQXhqIQcDQCAFKQIAIRkgBSAHKQIANwIAIAcgGTcCACAFQQhqIQUgB0F4aiEHIAhBf2oiCA0ACwtBASEHIAEgBigCFEF/c2oLIQUgB0UgCXJBAXFFBEAgACABEDgNDQsgA0UNAiAFIAFPDQEgACAFQQN0aiIIKAIEKAIAIAMoAgQoAgBJBEAgASEOIAAhDAwECyAAKQIAIRkgACAIKQIANwIAIAggGTcCACAAQXxqIQsgAEEMaiERIABBCGohEiAAKAIEIQ0gACgCACETQQAhByABIQgDQAJAIAcgCEF/aiIKTw0AIBEgB0EDdGohBSANKAIAIRADQCAFKAIAKAIAIBBJDQEgBUEIaiEFIAogB0EBaiIHRw0ACyAKIQcLIAsgCEEDdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACAFQXhqIhAhBSgCACANKAIASQ0ACyASIAdBA3RqIgUpAgAhGSAFIBBBBGoiBSkCADcCACAFIBk3AgAgB0EBaiEHDAELCyAAIA02AgQgACATNgIAIAEgB0EBaiIITwRAIAAgCEEDdGohACABIAhrIgFBFU8NAQwMCwsgCCABEKcBAAtB0KfCACAFIAEQpgEACyAORQ0BCyAFIA5PDQEgDCkCACEZIAwgDCAFQQN0aiIAKQIANwIAIAAgGTcCACAMKAIAIQAgBiAMKAIEIhI2AiwgBiAANgIoIA5Bf2oiAUUEQEEAIQ0MAwsgDEEMaiEFIBIoAgAhAEEAIQ0DQCAAIAUoAgAoAgBPDQMgBUEIaiEFIAEgDUEBaiINRw0ACyABIQ0MAgtBsKfCAEEAQQAQpgEAC0HAp8IAIAUgDhCmAQALIAxBCGohCiAMIA5BA3RqIQcgASEAA0ACQCAHIQggACIFIA1NIhMNACAIQXhqIQcgBUF/aiEAIBIoAgAgCEF8aigCACgCAE8NAQsLIAUgDU8EQCABIAVJDQJBgAEhEUEAIQBBACEBQQAhB0EAIQtBgAEhDyAKIA1BA3RqIhUhCgNAAkAgCCAKayIFQYcQSyIWDQAgBUEDdiIFQYB/aiAFIAsgB0kiCSABIABJciIQGyEFIBAEQCAPIAUgCRshDyAFIBEgCRshEQwBCyAFIAVBAXYiD2shEQsCQCAHIAtHDQAgD0UEQCAGQTBqIgchCwwBC0EAIQUgCiEJIAZBMGoiCyEHA0AgByAFOgAAIAcgEigCACAJQQRqKAIAKAIAT2ohByAJQQhqIQkgDyAFQQFqIgVHDQALCwJAIAAgAUcNACARRQRAIAZBsAFqIgAhAQwBCyAIQXxqIQVBACEJIAZBsAFqIgEhAANAIAAgCToAACAAIAYoAiwiEigCACAFKAIAKAIASWohACAFQXhqIQUgESAJQQFqIglHDQALCyAIIBFBA3RrIAggACABayIFIAcgC2siCSAJIAVLGyIJBEAgCiALLQAAQQN0aiIFKAIEIRcgBSgCACEYIAUgCCABLQAAQX9zQQN0aikCADcCACAJQQFHBEBBACEFA0AgCCABIAVqIhAtAABBf3NBA3RqIAogBSALakEBaiIULQAAQQN0aikCADcCACAKIBQtAABBA3RqIAggEEEBai0AAEF/c0EDdGopAgA3AgAgBUECaiAFQQFqIhAhBSAJSQ0ACyALIBBqIQsgASAQaiEBCyAIIAEtAABBf3NBA3RqIgUgFzYCBCAFIBg2AgAgC0EBaiELIAFBAWohAQsgACABRhshCCAKIA9BA3RqIAogByALRhshCiAWDQALAkAgCyAHSQRAIAghBQNAIAogB0F/aiIHLQAAQQN0aiIAKQIAIRkgACAFQXhqIgUpAgA3AgAgBSAZNwIAIAsgB0kNAAsMAQsgCiEFIAEgAE8NAANAIAUpAgAhGSAFIAggAEF/aiIALQAAQX9zQQN0aiIHKQIANwIAIAcgGTcCACAFQQhqIQUgASAASQ0ACwsgDCAGKQMoIhk3AgAgDiAFIBVrQQN2IA1qIgFNDQMgDCAMIAFBA3RqIggpAgA3AgAgCCAZNwIAIA4gAWsiBUUNBCAFIAEgASAFSxsgDkEDdiAIQQhqIQACQCABIAVBf2oiBU8EQCAAIAUgAiAIIAQQBiAMIQAMAQsgDCABIAIgAyAEEAYgCCEDIAUhAQtPIQ8gAUEVTw0BDAULCyANIAUQpwEACyAFIAEQpQEAC0HAp8IAIAEgDhCmAQALQbWtwgBBHEHUrcIAELgBAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEGkgASAFRw0ACwsgBkGwAmokAAvuDwETfyMAQaACayIGJAACQAJAIAFBFUkNAEEBIQ9BASETAkACQAJAA0AgASEMIAAhDSAPIBNxQQFzIQsCQAJAAkACQAJAAkADQAJ/AkAgBARAIA9BAXFFBEAgACABEG8gBEF/aiEECyAGIAI2AgAgBiAANgIgIAYgATYCJCAGIAFBAnYiBTYCBCAGIAVBAXQiCDYCCCAGIAVBA2wiBzYCDCAGQQA2AhAgBiAGQRBqNgKoASAGIAZBIGo2AqQBIAYgBjYCoAEgBiAGQaABajYCFCABQTJPBEAgBiAFQX9qNgIYIAYgBUEBajYCHCAGQRRqIAZBGGogBkEEaiAGQRxqEMMBIAYgCEF/ajYCGCAGIAhBAXI2AhwgBkEUaiAGQRhqIAZBCGogBkEcahDDASAGIAdBf2o2AhggBiAHQQFqNgIcIAZBFGogBkEYaiAGQQxqIAZBHGoQwwELIAZBFGogBkEEaiAGQQhqIAZBDGoQwwEgBigCECIFQQtLDQEgBUUhByAGKAIIDAILIAAgARCIAQwOCyAGKAIkIgdBAXYiCARAIAYoAiAiBSAHQQJ0akF8aiEHA0AgBSgCACEJIAUgBygCADYCACAHIAk2AgAgBUEEaiEFIAdBfGohByAIQX9qIggNAAsLQQEhByABIAYoAghBf3NqCyEFIAdFIAtyQQFxRQRAIAAgARA9DQ0LIANFDQIgBSABTw0BIAMoAgAgACAFQQJ0aiIIKAIAIgcQsQEEQCABIQwgACENDAQLIAAoAgAhBSAAIAc2AgAgCCAFNgIAIABBfGohECAAQQRqIQogACgCACEOQQAhByABIQgDQAJAIAcgCEF/aiIJTw0AIAogB0ECdGohBQNAIA4gBSgCABCxAQ0BIAVBBGohBSAJIAdBAWoiB0cNAAsgCSEHCyAQIAhBAnRqIQUCQANAIAcgCEF/aiIITw0BIAUoAgAhCSAFQXxqIhEhBSAOIAkQsQENAAsgCiAHQQJ0aiIFKAIAIRMgBSAJNgIAIBFBBGogEzYCACAHQQFqIQcMAQsLIAAgDjYCACABIAdBAWoiBU8EQCAAIAVBAnRqIQAgASAFayIBQRVPDQEMDAsLIAUgARCnAQALQdCnwgAgBSABEKYBAAsgDEUNAQsgBSAMTw0BIA0oAgAhACANIA0gBUECdGoiASgCADYCACABIAA2AgAgDUEEaiEJIA0oAgAhEEEAIQ4gDEF/aiIBRQ0CIAkhBQNAIAUoAgAgEBCxAUUNAyAFQQRqIQUgASAOQQFqIg5HDQALIAEhDgwCC0Gwp8IAQQBBABCmAQALQcCnwgAgBSAMEKYBAAsgDSAMQQJ0aiEHIAEhCANAAkAgByEAIAgiBSAOTSITDQAgBUF/aiEIIABBfGoiBygCACAQELEBRQ0BCwsgBSAOTwRAIAEgBUkNAkGAASEPQQAhCEEAIQFBACEHQQAhC0GAASERIAkgDkECdGoiFSEJA0ACQCAAIAlrIgVBgwhLIhYNACAFQQJ2IgVBgH9qIAUgCyAHSSIKIAEgCElyIhIbIQUgEgRAIBEgBSAKGyERIAUgDyAKGyEPDAELIAUgBUEBdiIRayEPCwJAIAcgC0cNACARRQRAIAZBIGoiByELDAELQQAhBSAJIQogBkEgaiILIQcDQCAHIAU6AAAgByAKKAIAIBAQsQFBAXNqIQcgCkEEaiEKIBEgBUEBaiIFRw0ACwsCQCABIAhHDQAgD0UEQCAGQaABaiIIIQEMAQsgAEF8aiEFQQAhCiAGQaABaiIBIQgDQCAIIAo6AAAgBSgCACAQELEBIAhqIQggBUF8aiEFIA8gCkEBaiIKRw0ACwsgACAPQQJ0ayAAIAggAWsiBSAHIAtrIgogCiAFSxsiCgRAIAkgCy0AAEECdGoiBSgCACEXIAUgACABLQAAQX9zQQJ0aigCADYCACAKQQFHBEBBACEFA0AgACABIAVqIhItAABBf3NBAnRqIAkgBSALakEBaiIULQAAQQJ0aigCADYCACAJIBQtAABBAnRqIAAgEkEBai0AAEF/c0ECdGooAgA2AgAgBUECaiAFQQFqIhIhBSAKSQ0ACyALIBJqIQsgASASaiEBCyAAIAEtAABBf3NBAnRqIBc2AgAgC0EBaiELIAFBAWohAQsgASAIRhshACAJIBFBAnRqIAkgByALRhshCSAWDQALAkAgCyAHSQRAIAAhBQNAIAkgB0F/aiIHLQAAQQJ0aiIAKAIAIQEgACAFQXxqIgUoAgA2AgAgBSABNgIAIAsgB0kNAAsMAQsgCSEFIAEgCE8NAANAIAUoAgAhByAFIAAgCEF/aiIILQAAQX9zQQJ0aiIJKAIANgIAIAkgBzYCACAFQQRqIQUgASAISQ0ACwsgDSAQNgIAIAwgBSAVa0ECdiAOaiIBTQ0DIA0gDSABQQJ0aiIFKAIANgIAIAUgEDYCACAMIAFrIghFDQQgCCABIAEgCEsbIAxBA3YgBUEEaiEAAkAgASAIQX9qIghPBEAgACAIIAIgBSAEEAcgDSEADAELIA0gASACIAMgBBAHIAUhAyAIIQELTyEPIAFBFU8NAQwFCwsgDiAFEKcBAAsgBSABEKUBAAtBwKfCACABIAwQpgEAC0G1rcIAQRxB1K3CABC4AQALIAFBAkkNAEEBIQUDQCAAIAVBAWoiBRB8IAEgBUcNAAsLIAZBoAJqJAAL1w8BE38jAEGgAmsiBiQAAkACQCABQRVJDQBBASEPQQEhEwJAAkACQANAIAEhDCAAIQ0gDyATcUEBcyELAkACQAJAAkACQAJAA0ACfwJAIAQEQCAPQQFxRQRAIAAgARBvIARBf2ohBAsgBiACNgIAIAYgADYCICAGIAE2AiQgBiABQQJ2IgU2AgQgBiAFQQF0Igg2AgggBiAFQQNsIgc2AgwgBkEANgIQIAYgBkEQajYCqAEgBiAGQSBqNgKkASAGIAY2AqABIAYgBkGgAWo2AhQgAUEyTwRAIAYgBUF/ajYCGCAGIAVBAWo2AhwgBkEUaiAGQRhqIAZBBGogBkEcahBmIAYgCEF/ajYCGCAGIAhBAXI2AhwgBkEUaiAGQRhqIAZBCGogBkEcahBmIAYgB0F/ajYCGCAGIAdBAWo2AhwgBkEUaiAGQRhqIAZBDGogBkEcahBmCyAGQRRqIAZBBGogBkEIaiAGQQxqEGYgBigCECIFQQtLDQEgBUUhByAGKAIIDAILIAAgARCHAQwOCyAGKAIkIgdBAXYiCARAIAYoAiAiBSAHQQJ0akF8aiEHA0AgBSgCACEJIAUgBygCADYCACAHIAk2AgAgBUEEaiEFIAdBfGohByAIQX9qIggNAAsLQQEhByABIAYoAghBf3NqCyEFIAdFIAtyQQFxRQRAIAAgARA8DQ0LIANFDQIgBSABTw0BIAMoAgAgACAFQQJ0aiIIKAIAIgdJBEAgASEMIAAhDQwECyAAKAIAIQUgACAHNgIAIAggBTYCACAAQXxqIRAgAEEEaiEKIAAoAgAhDkEAIQcgASEIA0ACQCAHIAhBf2oiCU8NACAKIAdBAnRqIQUDQCAOIAUoAgBJDQEgBUEEaiEFIAkgB0EBaiIHRw0ACyAJIQcLIBAgCEECdGohBQJAA0AgByAIQX9qIghPDQEgBSgCACEJIAVBfGoiESEFIA4gCUkNAAsgCiAHQQJ0aiIFKAIAIRMgBSAJNgIAIBFBBGogEzYCACAHQQFqIQcMAQsLIAAgDjYCACABIAdBAWoiBU8EQCAAIAVBAnRqIQAgASAFayIBQRVPDQEMDAsLIAUgARCnAQALQdCnwgAgBSABEKYBAAsgDEUNAQsgBSAMTw0BIA0oAgAhACANIA0gBUECdGoiASgCADYCACABIAA2AgAgDUEEaiEJIA0oAgAhEEEAIQ4gDEF/aiIBRQ0CIAkhBQNAIAUoAgAgEE8NAyAFQQRqIQUgASAOQQFqIg5HDQALIAEhDgwCC0Gwp8IAQQBBABCmAQALQcCnwgAgBSAMEKYBAAsgDSAMQQJ0aiEHIAEhCANAAkAgByEAIAgiBSAOTSITDQAgBUF/aiEIIABBfGoiBygCACAQTw0BCwsgBSAOTwRAIAEgBUkNAkGAASEPQQAhCEEAIQFBACEHQQAhC0GAASERIAkgDkECdGoiFSEJA0ACQCAAIAlrIgVBgwhLIhYNACAFQQJ2IgVBgH9qIAUgCyAHSSIKIAEgCElyIhIbIQUgEgRAIBEgBSAKGyERIAUgDyAKGyEPDAELIAUgBUEBdiIRayEPCwJAIAcgC0cNACARRQRAIAZBIGoiByELDAELQQAhBSAJIQogBkEgaiILIQcDQCAHIAU6AAAgByAKKAIAIBBPaiEHIApBBGohCiARIAVBAWoiBUcNAAsLAkAgASAIRw0AIA9FBEAgBkGgAWoiCCEBDAELIABBfGohBUEAIQogBkGgAWoiASEIA0AgCCAKOgAAIAggBSgCACAQSWohCCAFQXxqIQUgDyAKQQFqIgpHDQALCyAAIA9BAnRrIAAgCCABayIFIAcgC2siCiAKIAVLGyIKBEAgCSALLQAAQQJ0aiIFKAIAIRcgBSAAIAEtAABBf3NBAnRqKAIANgIAIApBAUcEQEEAIQUDQCAAIAEgBWoiEi0AAEF/c0ECdGogCSAFIAtqQQFqIhQtAABBAnRqKAIANgIAIAkgFC0AAEECdGogACASQQFqLQAAQX9zQQJ0aigCADYCACAFQQJqIAVBAWoiEiEFIApJDQALIAsgEmohCyABIBJqIQELIAAgAS0AAEF/c0ECdGogFzYCACALQQFqIQsgAUEBaiEBCyABIAhGGyEAIAkgEUECdGogCSAHIAtGGyEJIBYNAAsCQCALIAdJBEAgACEFA0AgCSAHQX9qIgctAABBAnRqIgAoAgAhASAAIAVBfGoiBSgCADYCACAFIAE2AgAgCyAHSQ0ACwwBCyAJIQUgASAITw0AA0AgBSgCACEHIAUgACAIQX9qIggtAABBf3NBAnRqIgkoAgA2AgAgCSAHNgIAIAVBBGohBSABIAhJDQALCyANIBA2AgAgDCAFIBVrQQJ2IA5qIgFNDQMgDSANIAFBAnRqIgUoAgA2AgAgBSAQNgIAIAwgAWsiCEUNBCAIIAEgASAISxsgDEEDdiAFQQRqIQACQCABIAhBf2oiCE8EQCAAIAggAiAFIAQQCCANIQAMAQsgDSABIAIgAyAEEAggBSEDIAghAQtPIQ8gAUEVTw0BDAULCyAOIAUQpwEACyAFIAEQpQEAC0HAp8IAIAEgDBCmAQALQbWtwgBBHEHUrcIAELgBAAsgAUECSQ0AQQEhBQNAIAAgBUEBaiIFEHEgASAFRw0ACwsgBkGgAmokAAu3DgEafyMAQYABayIHJAAgACgCBCEEIAEoAgghCSABKAIAIQ0CQAJAAkAgACgCAC0AAARAIAcgBDYCDCAJQRVJDQEgByAJQQF2EJwBIAcoAgQgBygCACEQIAdBADYCGCAHQgQ3AxAgDUGcf2ohFUEEIQpBACEEIAkhBgNAAkAgBiIDQQFGBEBBACEGQQEhAAwBCyADQX5qIQIgDSADQeQAbCIRaiEFQVghAQJAAkACQAJAAkACQAJAA0AgAUEEaiIARQ0BIAEgBWohCCAAIQEgCEGgf2ooAgAiBiAIQQRqKAIAIgBGDQALIAYgAEgNAQsgDSARaiEFA0AgAiIGRQRAQQAhBiADIQAMCAsgBkF/aiECQVghAQNAAkAgAUEEaiIARQRAQQAhAAwBCyABIAVqIQwgACEBQX8gDEG8fmooAgAiCCAMQaB/aigCACIARyAIIABIGyIARQ0BCwsgBUGcf2ohBSAAQX9HDQALIAMgBmshAAwBCwJAIAIEQANAIAIiBkF/aiECQVghAQNAIAFBBGoiAEUNAyABIAVqIQwgACEBQX8gDEG8fmooAgAiCCAMQaB/aigCACIARyAIIABIGyIARQ0ACyAAQf8BcUH/AUcNAiAFQZx/aiEFIAINAAsLQQAhBgsgAyAGSQ0BIAMgCUsNAiADIAZrIgBBAXYiBUUNACANIAZB5ABsaiEBIBEgFWohAgNAIAdBHGogAUHkABC5ARogASACQeQAEJgBQeQAaiEBIAIgB0EcakHkABC5AUGcf2ohAiAFQX9qIgUNAAsLIAZFIABBCUtyDQQgAyAGayEAIAZBf2ohASAVIAZB5ABsaiECA0AgAyABSQ0DIAMgCUsNAiACIABBAWoiABBMIAFBf2oiBCABTw0EIAJBnH9qIQIgBCEBIABBCkkNAAsMAwsgBiADEKcBAAsgAyAJEKUBAAsgASADEKcBAAsgBEEBaiEGIAshBAsgBygCFCAERgR/IAdBEGogBBCKASAHKAIQIQogBygCGCILBSAEC0EDdCAKaiIBIAA2AgQgASAGNgIAIAcgC0EBaiILNgIYAkAgCyIEQQJJDQACQANAAkACQAJAAkAgCiALIgNBf2oiC0EDdGoiASgCAARAIANBA3QgCmoiBUF0aigCACIIIAEoAgQiAEsNAQsgA0EDSQ0CIAEoAgQhACAKIANBfWoiAUEDdGooAgQhAgwBC0ECIQQgA0ECTQ0EIAogA0F9aiIBQQN0aigCBCICIAAgCGpNDQBBAyEEIANBA00NBCAFQWRqKAIAIAIgCGpNDQAgAyELIAMhBAwFCyACIABJDQELIANBfmohAQsCQAJAAkACQAJAAkACQCADIAFBAWoiE0sEQCADIAFNDQEgCiABQQN0IhlqIgAoAgQiGiAAKAIAaiIBIAogE0EDdCIbaiIAKAIAIhJJDQIgASAJSw0DIA0gEkHkAGxqIgggACgCBCIPQeQAbCIFaiEEIA0gAUHkAGxqIRQgASASayIAIA9rIgEgD08NBCAQIAQgAUHkAGwiABC5ASIMIABqIQ4gD0EBSCABQQFIcg0FIAQhBQNAIA5BXGohESAFQZx/aiEWIA5BnH9qIRdBACEBA0ACQCABQQRqIgBBKEYEQEEAIQAMAQsgASARaiECIAEgBWohBCAAIQFBfyAEQVxqKAIAIgQgAigCACIARyAEIABIGyIARQ0BCwsgFEGcf2oiFCAWIBcgAEF/RiIAG0HkABC5ARogDiAXIAAbIQ4gCCAWIAUgABsiBU8NByAOIAwiAksNAAsMBwtB6KvCACATIAMQpgEAC0Hoq8IAIAEgAxCmAQALIBIgARCnAQALIAEgCRClAQALIBAgCCAFELkBIgIgBWohDiAPQQFIIAAgD0xyRQRAIAghBQNAQcAAIQEDQAJAIAFBBGoiAEHoAEYEQEEAIQAMAQsgASAEaiEMIAEgAmohCCAAIQFBfyAIKAIAIgggDCgCACIARyAIIABIGyIARQ0BCwsgBCACIABBf0YiARshACACIAJB5ABqIAEbIQIgBSAAQeQAELkBQeQAaiEFIARB5ABqIAQgARsiBCAUTw0EIA4gAksNAAsMAwsgCCEFDAELIAQhBQsgECECCyAFIAIgDiACayIAIABB5ABwaxC5ARogCiAZaiIAIA8gGmo2AgQgACASNgIAIAogG2oiACAAQQhqIAMgE0F/c2pBA3QQmAEaIAcgCzYCGCALQQFLDQALIAshBAwBCyADIQsLIAYNAAsgBygCFARAIAoQIQtFDQIgEBAhDAILIAcgBDYCECAHIAdBEGo2AhwgDSAJIAdBHGpBAEEgIAlnaxAEDAELQQIhASAJQQJJDQAgDSAJQX5qIgBB5ABsaiECA0AgCSAASQ0CIAIgARBMIAJBnH9qIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgB0GAAWokAA8LIAAgCRCnAQALvwwCFn8BfiMAQSBrIgckACAAKAIEIQMgASgCCCEIIAEoAgAhCwJAAkACQCAAKAIALQAABEAgByADNgIMIAhBFUkNASAHIAhBAXYQoAEgBygCBCAHKAIAIRBBACEBIAdBADYCGCAHQgQ3AxAgC0F4aiETIAtBbGohFUEEIQlBACEDIAghBgNAAkAgBiIFQX9qIgZFBEBBACEGQQEhBAwBCwJAAkACQAJAAkAgBUEDdCINIAtqQXRqKAIAKAIAIgAgCyAGQQN0aigCBCgCAE8EQCAFQX5qIRQgDSAVaiEEQQAhBkEAIQIDQCACIBRGBEAgBSEEDAgLIAJBAWohAiAEKAIAKAIAIgogAE8gBEF4aiEEIAohAA0ACyACQQFqIQQgAkF/cyAFaiEGDAELIA0gFWohAgNAAkAgBkEBRgRAQQAhBgwBCyAGQX9qIQYgAigCACgCACIEIABJIAJBeGohAiAEIQANAQsLIAUgBkkNASAFIAhLDQIgBSAGayIEQQF2IgpFDQAgCyAGQQN0aiEAIA0gE2ohAgNAIAApAgAhGCAAIAIpAgA3AgAgAiAYNwIAIABBCGohACACQXhqIQIgCkF/aiIKDQALCyAGRSAEQQlLcg0EIAUgBmshBCAGQX9qIQAgEyAGQQN0aiECA0AgBSAASQ0DIAUgCEsNAiACIARBAWoiBBBrIABBf2oiAyAATw0EIAJBeGohAiADIQAgBEEKSQ0ACwwDCyAGIAUQpwEACyAFIAgQpQEACyAAIAUQpwEACyADQQFqIQYgASEDCyAHKAIUIANGBH8gB0EQaiADEIoBIAcoAhAhCSAHKAIYIgEFIAMLQQN0IAlqIgAgBDYCBCAAIAY2AgAgByABQQFqIgE2AhggASEDAkAgAUECSQ0AAkADQAJAAkACQAJAIAkgAUF/akEDdGoiACgCAEUNACABQQN0IAlqIgRBdGooAgAiBSAAKAIEIgJNDQBBAiEDIAFBAk0NBiAJIAFBfWoiDEEDdGooAgQiACACIAVqTQ0BQQMhAyABQQNNDQYgBEFkaigCACAAIAVqTQ0BIAEhAwwGCyABQQNJDQEgACgCBCECIAkgAUF9aiIMQQN0aigCBCEACyAAIAJJDQELIAFBfmohDAsCQAJAIAEgDEEBaiIRSwRAIAEgDEsEQCAJIAxBA3QiF2oiACgCBCIUIAAoAgBqIgMgCSARQQN0Ig1qIgAoAgAiEk8EQCADIAhNBEAgCyASQQN0aiIEIAAoAgQiDkEDdCIKaiEBIANBA3QhDwJAAkAgAyASayIDIA5rIgUgDkkEQCAQIAEgBUEDdCIAELkBIgogAGohAiAOQQFIIAVBAUhyDQEgDyATaiEAA0ACQCAAIAFBeGoiBSACQXhqIgMgAUF8aigCACgCACACQXxqKAIAKAIASSIPGykCADcCACACIAMgDxshAiAEIAUgASAPGyIBTw0AIABBeGohACACIApLDQELCyAHKAIQIQkMAQsgCiAQIAQgChC5ASIAaiECIAMgDkpBACAOQQFOGw0BIAQhAQsgECEADAYLIAsgD2ohAwNAIAQgASAAIAAoAgQoAgAgASgCBCgCAEkiBRspAgA3AgAgACAAQQhqIAUbIQAgBEEIaiEEIAFBCGogASAFGyIBIANPDQUgAiAASw0ACwwECyADIAgQpQEACyASIAMQpwEAC0Hoq8IAIAwgARCmAQALQeirwgAgESABEKYBAAsgBCEBCyABIAAgAiAAa0F4cRC5ARogBygCGCIBIAxLBEAgCSAXaiIAIA4gFGo2AgQgACASNgIAIAEgEU0NAiAJIA1qIgAgAEEIaiABIBFBf3NqQQN0EJgBGiAHIAFBf2oiATYCGCABIQMgAUEBTQ0DDAELC0H4q8IAIAwgARCmAQALQaeuwgBBHUHErsIAELgBAAsgBg0ACyAHKAIUBEAgCRAhC0UNAiAQECEMAgsgByADNgIMIAcgB0EMajYCECALIAggB0EQakEAQSAgCGdrEAYMAQtBAiEBIAhBAkkNACALIAhBfmoiAEEDdGohAgNAIAggAEkNAiACIAEQayACQXhqIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgB0EgaiQADwsgACAIEKcBAAvmDAIMfwR+IwBB4ABrIgYkAAJAAkACQAJAIAEoAhAiA0EBaiIFIANPBEAgASgCACICIQMCQCAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAAn8gBSADQQFqIgMgBSADSxsiA0UEQEGwpsIAIQpBBCEEQQAMAQsgBkEgakF/An8gA0EITwRAIAMgA0H/////AXFGBEAgA0EDdEEHbgwCCwwJCyADQQFqC0F/amd2IgdBAWoiBRCaASAGKAIkIgRFDQcgBigCKCECIAYoAiAiAyAEENEBIgpFDQIgCkH/ASAHQQVqEL8BIAJqIQQgByAFQQN2QQdsIAdBCEkbCyECIAYgASgCECIDNgIwIAYgAiADazYCLCAGIAQ2AiggBiAKNgIkIAYgBzYCICABKAIEIgNBBGohAiABKAIAIANqQQFqIQ0gAygCAEF/c0GAgYKEeHEhCCABKAIIIQkDQAJAAkACQCAIBEAgAiEDDAELA0AgAiANTw0CIAlBsAdqIQkgAigCACACQQRqIgMhAkGAgYKEeHEiBUGAgYKEeEYNAAsgBUGAgYKEeHMhCAsgBiAJIAhoQQN2QewBbGoiDCgCACIENgJQIAhBf2ohC0KlxoihyJyn+UshDkEBIQIDQCAOIAStQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQdAAaiACaiACQQFqIQItAAAhBAwACwALIAEpAgAhDyABIAYpAyA3AgAgBkEoaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIAZBMGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA83AyAgAEEANgIAIA+nIgBFDQcgBkHQAGogAEEBahCaASAGKAIkECEMBwsgCiAGQSBqIA4QlgEiBWogDqdBGXYiAjoAACAHIAVBfGpxIApqQQRqIAI6AAAgBigCKCAFQewBbGogDEHsARC5ARogCCALcSEIIAMhAgwACwALIAJBAWohC0EAIQIDQAJAAn8gBEEBcUUEQCACIAtPDQIgAkEBaiIDIAJJDQIgAiEEIAMMAQsgAkEDaiIEIAJJIgUNASAEIAtJIgNFDQEgCyACQQRqIAUbIAsgAxsLIQIgASgCBCAEaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEEDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQmAEaDAMLIAMgBEHU88IAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDSADTwR/QQAhAwNAIAMiBUEBaiEDAkAgBSABKAIEai0AAEGAAUcNAANAIAYgASgCCCAFQewBbGoiCCgCACIENgIgQqXGiKHInKf5SyEOQQEhAgNAIA4gBK1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAZBIGogAmogAkEBaiECLQAAIQQMAQsLIAUgASgCACIJIA6nIgxxIgJrIAEgDhCWASIHIAJrcyAJcUEETwRAIAEoAgQiCyAHaiICLQAAIAIgDEEZdiICOgAAIAsgCSAHQXxqcWpBBGogAjoAAEH/AUYEQCABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIAdB7AFsaiAIQewBELkBGgwDCyABKAIIIAdB7AFsaiEKQQAhAgNAIAIgCmoiBykCACEQIAcgAiAIaiIJKQIANwIAIAdBGGoiBCkCACERIAQgCUEYaiIMKQIANwIAIAdBEGoiBCkCACEPIAQgCUEQaiILKQIANwIAIAdBCGoiBCkCACEOIAQgCUEIaiIEKQIANwIAIAQgDjcCACALIA83AgAgDCARNwIAIAkgEDcCACACQUBrIAJBIGohAkHtAUkNAAsgCikC4AEhDiAKIAgpAuABNwLgASAKQegBaiICKAIAIQQgAiAIQegBaiICKAIANgIAIAggDjcC4AEgAiAENgIADAELCyABKAIEIgQgBWogDEEZdiICOgAAIAQgCSAFQXxqcWpBBGogAjoAAAsgAyANRw0ACyABKAIAIgJBCEkNASACQQFqBSANC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQeAAaiQADwtBtKbCAEEcQdCmwgAQuAEAC44MARV/IwBBIGsiBiQAIAAoAgQhByABKAIIIQkgASgCACEKAkACQAJAIAAoAgAtAAAEQCAGIAc2AgwgCUEVSQ0BIAYgCUEBdhCfASAGKAIEIAYoAgAhDkEAIQEgBkEANgIYIAZCBDcDECAKQXxqIRAgCkF0aiERQQQhCCAJIQcDQCAHIQVBACEHQQEhAgJAIAVBf2oiBEUNAAJAAkACQAJAAkACQCAKIARBAnRqKAIAIAVBAnQiByAKakF4aigCACIAELEBRQRAIAVBfmohBCAHIBFqIQJBACEHQQAhAwNAIAMgBEYEQCAFIQIMCQsgA0EBaiEDIAAgAigCACIAELEBIAJBfGohAkUNAAsgA0EBaiECIANBf3MgBWohBAwBCyAHIBFqIQIDQAJAIARBAUYEQEEAIQQMAQsgBEF/aiEEIAAgAigCACIAELEBIAJBfGohAg0BCwsgBSAESQ0BIAUgCUsNAiAFIARrIgJBAXYiC0UNACAKIARBAnRqIQAgByAQaiEDA0AgACgCACEHIAAgAygCADYCACADIAc2AgAgAEEEaiEAIANBfGohAyALQX9qIgsNAAsLIARFIAJBCUtyDQQgBSAEayECIARBf2ohACAQIARBAnRqIQMDQCAFIABJDQMgBSAJSw0CIAMgAkEBaiICEHMgAEF/aiIHIABPDQQgA0F8aiEDIAchACACQQpJDQALDAMLIAQgBRCnAQALIAUgCRClAQALIAAgBRCnAQALIAdBAWohBwwBCyAEIQcLIAYoAhQgAUYEQCAGQRBqIAEQigEgBigCECEIIAYoAhghAQsgCCABQQN0aiIAIAI2AgQgACAHNgIAIAYgAUEBaiIBNgIYAkAgAUECSQ0AAkADQAJAAkACQAJAIAggAUF/akEDdGoiACgCAEUNACABQQN0IAhqIgRBdGooAgAiAyAAKAIEIgJNDQAgAUECTQ0GIAggAUF9aiIFQQN0aigCBCIAIAIgA2pNDQEgAUEDTQ0GIARBZGooAgAgACADaksNBgwBCyABQQNJDQEgACgCBCECIAggAUF9aiIFQQN0aigCBCEACyAAIAJJDQELIAFBfmohBQsCQAJAIAEgBUEBaiILSwRAIAEgBUsEQCAIIAVBA3QiE2oiACgCBCIUIAAoAgBqIgAgCCALQQN0IhVqIgEoAgAiD08EQCAAIAlNBEAgCiAPQQJ0aiIDIAEoAgQiBEECdCICaiEBIABBAnQhDAJAAkAgACAPayINIARrIgAgBEkEQCAOIAEgAEECdCICELkBIhYgAmohAiAEQQFIIABBAUhyDQEgDCAQaiEAA0ACQCAAIAFBfGoiCCACQXxqIgwgDCgCACAIKAIAELEBIg0bKAIANgIAIAIgDCANGyECIAMgCCABIA0bIgFPDQAgAEF8aiEAIAIgFksNAQsLIAYoAhAhCAwBCyAOIAMgAhC5ASIAIAJqIQIgDSAESkEAIARBAU4bDQEgAyEBCyAOIQAMBgsgCiAMaiENA0AgAyABIAAgASgCACAAKAIAELEBIgwbKAIANgIAIAAgAEEEaiAMGyEAIANBBGohAyABQQRqIAEgDBsiASANTw0FIAIgAEsNAAsMBAsgACAJEKUBAAsgDyAAEKcBAAtB6KvCACAFIAEQpgEAC0Hoq8IAIAsgARCmAQALIAMhAQsgASAAIAIgAGtBfHEQuQEaIAYoAhgiACAFSwRAIAggE2oiASAEIBRqNgIEIAEgDzYCACAAIAtNDQIgCCAVaiIBIAFBCGogACALQX9zakEDdBCYARogBiAAQX9qIgE2AhggAUEBTQ0DDAELC0H4q8IAIAUgABCmAQALQaeuwgBBHUHErsIAELgBAAsgBw0ACyAGKAIUBEAgCBAhC0UNAiAOECEMAgsgBiAHNgIMIAYgBkEMajYCECAKIAkgBkEQakEAQSAgCWdrEAcMAQtBAiEBIAlBAkkNACAKIAlBfmoiAEECdGohAgNAIAkgAEkNAiACIAEQcyACQXxqIQIgAUEBaiEBIABBf2oiAEF/Rw0ACwsgBkEgaiQADwsgACAJEKcBAAvPCwIMfwJ+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgQgA08EQCABKAIAIgIhAwJAIAQgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAEIANBAWoiAyAEIANLGyIDRQRAQbCmwgAhB0EEIQVBAAwBCyAGQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiCEEBaiIEEKIBIAYoAgQiBUUNByAGKAIIIQIgBigCACIDIAUQ0QEiB0UNAiAHQf8BIAhBBWoQvwEgAmohBSAIIARBA3ZBB2wgCEEISRsLIQIgBiABKAIQIgM2AhAgBiACIANrNgIMIAYgBTYCCCAGIAc2AgQgBiAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDSADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIA1PDQIgCkFAayEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQsLQQEhAiAGIAogC2hBAXRB8ABxaiIMKAIAIgU2AjAgC0F/aiEJQqXGiKHInKf5SyEOA0AgDiAFrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBkEwaiACaiACQQFqIQItAAAhBQwACwALIAEpAgAhDyABIAYpAwA3AgAgBkEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIAZBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAGIA83AwAgAEEANgIAIA+nIgBFDQcgBkEwaiAAQQFqEKIBIAYoAgQQIQwHCyAHIAYgDhCWASIEaiAOp0EZdiICOgAAIAggBEF8anEgB2pBBGogAjoAACAGKAIIIARBBHRqIgIgDCkCADcCACACQQhqIAxBCGopAgA3AgAgCSALcSELIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBUEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEFIAMMAQsgAkEDaiIFIAJJIgQNASAFIAlJIgNFDQEgCSACQQRqIAQbIAkgAxsLIQIgASgCBCAFaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEFDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQmAEaDAMLIAMgBUHU88IAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgNBAWoiDCADTwR/QQAhAwNAIAMiBEEBaiEDAkAgBCABKAIEai0AAEGAAUcNAAJAA0AgBiABKAIIIARBBHRqIgcoAgAiBTYCAEKlxoihyJyn+UshDkEBIQIDQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEcEQCACIAZqIAJBAWohAi0AACEFDAELCyAEIAEoAgAiCiAOpyINcSICayABIA4QlgEiCCACa3MgCnFBBEkNASABKAIEIgkgCGoiAi0AACACIA1BGXYiAjoAACAJIAogCEF8anFqQQRqIAI6AABB/wFHBEAgASgCCCAIQQR0aiICKQIAIQ8gAiAHKQIANwIAIAJBCGoiAikCACEOIAIgB0EIaiICKQIANwIAIAcgDzcCACACIA43AgAMAQsLIAEoAgQiAiAEakH/AToAACACIAEoAgAgBEF8anFqQQRqQf8BOgAAIAEoAgggCEEEdGoiAiAHKQIANwIAIAJBCGogB0EIaikCADcCAAwBCyABKAIEIgUgBGogDUEZdiICOgAAIAUgCiAEQXxqcWpBBGogAjoAAAsgAyAMRw0ACyABKAIAIgJBCEkNASACQQFqBSAMC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC4AQALqgsCC38DfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiICIANPBEAgASgCACIDIQQCQCACIANBAWpBA3ZBB2wgBCADQQhPGyIEQQF2SwRAAn8gAiAEQQFqIgMgAiADSxsiAkUEQEGwpsIAIQdBACECQQQMAQsgBkF/An8gAkEITwRAIAIgAkH/////AXFGBEAgAkEDdEEHbgwCCwwJCyACQQFqC0F/amd2IglBAWoiAxCZASAGKAIEIgJFDQcgBigCCCEEIAYoAgAiBSACENEBIgdFDQIgCSADQQN2QQdsIAlBCEkbIQIgB0H/ASAJQQVqEL8BIARqCyEDIAYgASgCECIENgIQIAYgAiAEazYCDCAGIAM2AgggBiAHNgIEIAYgCTYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQogAygCAEF/c0GAgYKEeHEhBSABKAIIIQMDQAJAIAVFBEADQCACIApPDQIgA0HgAGohAyACKAIAIAJBBGoiBCECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyEFIAQhAgsgBkKlxoihyJyn+Us3AzAgAyAFaEEDdkEYbGoiBCgCACAEKAIIIAZBMGoQcCAHIAYgBikDMCINEJYBIghqIA2nQRl2Igs6AAAgCSAIQXxqcSAHakEEaiALOgAAIAYoAgggCEEYbGoiCCAEKQIANwIAIAhBCGogBCkCCDcCACAIQRBqIARBEGopAgA3AgAgBUF/aiAFcSEFDAELCyABKQIAIQ0gASAGKQMANwIAIAZBCGoiAikDACEOIAIgAUEIaiICKQIANwMAIAIgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiANNwMAIABBADYCACANpyIARQ0FIAZBMGogAEEBahCZASAGKAIEECEMBQsgA0EBaiEEQQAhA0EAIQIDQAJAAn8gA0EBcUUEQCACIARPDQIgAkEBaiIFIAJJDQIgAiEDIAUMAQsgAkEDaiIDIAJJIgUNASADIARJIgdFDQEgBCACQQRqIAUbIAQgBxsLIQIgASgCBCADaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEDDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQmAEaDAMLIAUgAkHU88IAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgJBAWoiCSACTwR/QQAhAwNAIAMiAkEBaiEDAkAgAiABKAIEai0AAEGAAUcNAAJAA0AgASgCCCAGQqXGiKHInKf5SzcDACACQRhsaiIEKAIAIAQoAgggBhBwIAIgASgCACIHIAYpAwAiDaciCHEiCmsgASANEJYBIgUgCmtzIAdxQQRJDQEgASgCBCIKIAVqIgstAAAgCyAIQRl2Igg6AAAgCiAHIAVBfGpxakEEaiAIOgAAQf8BRwRAIAEoAgggBUEYbGoiBSkCACENIAUgBCkCADcCACAFQRBqIgcpAgAhDiAHIARBEGoiBykCADcCACAFQQhqIgUpAgAhDyAFIARBCGoiBSkCADcCACAEIA03AgAgByAONwIAIAUgDzcCAAwBCwsgASgCBCIHIAJqQf8BOgAAIAcgASgCACACQXxqcWpBBGpB/wE6AAAgASgCCCAFQRhsaiICIAQpAgA3AgAgAkEIaiAEQQhqKQIANwIAIAJBEGogBEEQaikCADcCAAwBCyABKAIEIgQgAmogCEEZdiIFOgAAIAQgByACQXxqcWpBBGogBToAAAsgAyAJRw0ACyABKAIAIgJBCEkNASACQQFqBSAJC0EDdkEHbCECCyAAQQA2AgAgASACIAEoAhBrNgIMCyAGQUBrJAAPC0G0psIAQRxB0KbCABC4AQALnQsCC38DfiMAQUBqIgYkAAJAAkACQAJAIAEoAhAiA0EBaiICIANPBEAgASgCACIDIQUCQCACIANBAWpBA3ZBB2wgBSADQQhPGyIFQQF2SwRAAn8gAiAFQQFqIgMgAiADSxsiAkUEQEGwpsIAIQdBACECQQQMAQsgBkF/An8gAkEITwRAIAIgAkH/////AXFGBEAgAkEDdEEHbgwCCwwJCyACQQFqC0F/amd2IglBAWoiAxCZASAGKAIEIgJFDQcgBigCCCEFIAYoAgAiBCACENEBIgdFDQIgCSADQQN2QQdsIAlBCEkbIQIgB0H/ASAJQQVqEL8BIAVqCyEDIAYgASgCECIFNgIQIAYgAiAFazYCDCAGIAM2AgggBiAHNgIEIAYgCTYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQogAygCAEF/c0GAgYKEeHEhBCABKAIIIQMDQAJAIARFBEADQCACIApPDQIgA0HgAGohAyACKAIAIAJBBGoiBSECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyEEIAUhAgsgBkKlxoihyJyn+Us3AzAgAyAEaEEDdkEYbGoiBSAGQTBqEHUgByAGIAYpAzAiDRCWASIIaiANp0EZdiILOgAAIAkgCEF8anEgB2pBBGogCzoAACAGKAIIIAhBGGxqIgggBSkCADcCACAIQQhqIAVBCGopAgA3AgAgCEEQaiAFQRBqKQIANwIAIARBf2ogBHEhBAwBCwsgASkCACENIAEgBikDADcCACAGQQhqIgIpAwAhDiACIAFBCGoiAikCADcDACACIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDTcDACAAQQA2AgAgDaciAEUNBSAGQTBqIABBAWoQmQEgBigCBBAhDAULIANBAWohBUEAIQNBACECA0ACQAJ/IANBAXFFBEAgAiAFTw0CIAJBAWoiBCACSQ0CIAIhAyAEDAELIAJBA2oiAyACSSIEDQEgAyAFSSIHRQ0BIAUgAkEEaiAEGyAFIAcbCyECIAEoAgQgA2oiAyADKAIAIgNBB3ZBf3NBgYKECHEgA0H//v37B3JqNgIAQQEhAwwBCwsgASgCBCECIAEoAgBBAWoiA0EETw0CIAJBBGogAiADEJgBGgwDCyAEIAJB1PPCACgCACIAQQIgABsRAAAACwwDCyACIANqIAIoAAA2AAALAkAgASgCACICQQFqIgkgAk8Ef0EAIQMDQCADIgJBAWohAwJAIAIgASgCBGotAABBgAFHDQACQANAIAEoAgggBkKlxoihyJyn+Us3AwAgAkEYbGoiBSAGEHUgAiABKAIAIgcgBikDACINpyIIcSIKayABIA0QlgEiBCAKa3MgB3FBBEkNASABKAIEIgogBGoiCy0AACALIAhBGXYiCDoAACAKIAcgBEF8anFqQQRqIAg6AABB/wFHBEAgASgCCCAEQRhsaiIEKQIAIQ0gBCAFKQIANwIAIARBEGoiBykCACEOIAcgBUEQaiIHKQIANwIAIARBCGoiBCkCACEPIAQgBUEIaiIEKQIANwIAIAUgDTcCACAHIA43AgAgBCAPNwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIARBGGxqIgIgBSkCADcCACACQQhqIAVBCGopAgA3AgAgAkEQaiAFQRBqKQIANwIADAELIAEoAgQiBSACaiAIQRl2IgQ6AAAgBSAHIAJBfGpxakEEaiAEOgAACyADIAlHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAkLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAELgBAAuPCwIMfwJ+IwBBQGoiBiQAAkACQAJAAkAgASgCECIDQQFqIgQgA08EQCABKAIAIgIhAwJAIAQgAkEBakEDdkEHbCADIAJBCE8bIgNBAXZLBEACfyAEIANBAWoiAyAEIANLGyIDRQRAQbCmwgAhB0EEIQVBAAwBCyAGQX8CfyADQQhPBEAgAyADQf////8BcUYEQCADQQN0QQduDAILDAkLIANBAWoLQX9qZ3YiCEEBaiIEEKEBIAYoAgQiBUUNByAGKAIIIQIgBigCACIDIAUQ0QEiB0UNAiAHQf8BIAhBBWoQvwEgAmohBSAIIARBA3ZBB2wgCEEISRsLIQIgBiABKAIQIgM2AhAgBiACIANrNgIMIAYgBTYCCCAGIAc2AgQgBiAINgIAIAEoAgQiA0EEaiECIAEoAgAgA2pBAWohDCADKAIAQX9zQYCBgoR4cSELIAEoAgghCgNAAkACQAJAIAsEQCACIQMMAQsDQCACIAxPDQIgCkEgaiEKIAIoAgAgAkEEaiIDIQJBgIGChHhxIgRBgIGChHhGDQALIARBgIGChHhzIQsLIAYgCiALaEE4cWoiDSgCACIFNgIwIAtBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAWtQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAGQTBqIAJqIAJBAWohAi0AACEFDAALAAsgASkCACEPIAEgBikDADcCACAGQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBkEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAYgDzcDACAAQQA2AgAgD6ciAEUNByAGQTBqIABBAWoQoQEgBigCBBAhDAcLIAcgBiAOEJYBIgRqIA6nQRl2IgI6AAAgCCAEQXxqcSAHakEEaiACOgAAIAYoAgggBEEDdGogDSkCADcCACAJIAtxIQsgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAFQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQUgAwwBCyACQQNqIgUgAkkiBA0BIAUgCUkiA0UNASAJIAJBBGogBBsgCSADGwshAiABKAIEIAVqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQUMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NAiACQQRqIAIgAxCYARoMAwsgAyAFQdTzwgAoAgAiAEECIAAbEQAAAAsMAwsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIEQQFqIQMCQCAEIAEoAgRqLQAAQYABRw0AAkADQCAGIAEoAgggBEEDdGoiBygCACIFNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBa1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBmogAkEBaiECLQAAIQUMAQsLIAQgASgCACIKIA6nIg1xIgJrIAEgDhCWASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBA3RqIgIpAgAhDiACIAcpAgA3AgAgByAOQiCIPAAEIAcgDj4CAAwBCwsgASgCBCICIARqQf8BOgAAIAIgASgCACAEQXxqcWpBBGpB/wE6AAAgASgCCCAIQQN0aiAHKQIANwIADAELIAEoAgQiBSAEaiANQRl2IgI6AAAgBSAKIARBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIAZBQGskAA8LQbSmwgBBHEHQpsIAELgBAAuACwEGfyMAQRBrIgQkACAEIAAoAhQiAzYCDCAEIAM2AgggACgCECEFIAAQMyEBIAAoAhAhAgJAAkAgAQ0AA0AgAiAAKAIUIgFJBEAgABCPASAAEDMgACgCECECRQ0BDAILCyADIQIMAQsgBCACNgIMIAAQNEUEQANAIAAoAhAgACgCFCIBTw0CIAAQjwEgABA0RQ0ACwsgABAzRQRAA0AgACgCECAAKAIUIgFPDQIgABCPASAAEDNFDQALCyAAEDQgACgCECEDRQRAA0AgAyAAKAIUIgFPDQIgABCPASAAEDQgACgCECEDRQ0ACwsgBCADNgIIIAAoAhQhAQsgACABNgIQIAAgBTYCGEEAIQMgASACTwRAIAAgATYCICAAIAE2AhAgACACNgIYAkACQCAAQYDPwgBBCSAEQQhqEC0iAUUNACAAIAAoAhAiAjYCHCABQX9qIgFBAUsNASABQQFrBEAgACgCFCEBIABB2NDCABBERQRAIAAgACgCFCACIAFrajYCECAAQdrQwgAQREUNAgsgACAAKAIcIAAoAiAQNQwCCyAAIAIgACgCIBA1DAELIAAgACgCFCICNgIgIAAgAjYCEAJAIABB4NDCAEECIARBCGoQLSICBEAgACAAKAIQIgE2AhwgAkEBRw0BIAAgASAAKAIgEDUgACgCECEBDAELIAAgACgCFCIBNgIQCyAAIAE2AiAgACgCFCECIABBkNHCAEEaIARBCGoQLSIDBEAgACAAKAIQIgE2AhwgA0EBRgRAIAAgASAAKAIgEDUgACgCECEBCyAAIAE2AiAgACgCFCABayECAkACQCAAQYDWwgBBCCAEQQhqEC0iAwRAIAAgACgCECIBNgIcIANBf2oiA0EBSw0EIANBAWsNAQwCCyAAIAAoAhQgAms2AhAMAwsgACgCFCEDAkAgAEHY0MIAEERFBEAgACAAKAIUIAEgA2tqNgIQIABB2tDCABBERQ0BCyAAIAAoAhwgACgCIBA1DAMLIAAgACgCFCACazYCEAwCCyAAIAEgACgCIBA1DAELIAAgACgCFCACIAFrIgFrIgI2AiAgACACNgIQAkAgAEHA18IAQS4gBEEIahAtIgNFDQAgACAAKAIQIgI2AhwgA0F/aiIDQQFLDQEgA0EBawRAIAAoAhQhAyAAQdjQwgAQREUEQCAAIAAoAhQgAiADa2o2AhAgAEHa0MIAEERFDQILIAAgACgCHCAAKAIgEDUMAgsgACACIAAoAiAQNQwBCyAAIAAoAhQgAWsiAjYCICAAIAI2AhAgAEGQ4MIAQSQgBEEIahAtIgJFDQAgACAAKAIQIgE2AhwgAkEBRw0AIAAgASAAKAIgEDULIAAgACgCFCICNgIgIAAgAjYCEAJ/IABB3NDCABBEBEAgACAAKAIQIgI2AhwgACACIAAoAiAQNSAAKAIQIQEgACgCFAwBCyAAIAAoAhQiATYCECABCyECIAAgATYCIAJAIABB8ObCAEECIARBCGoQLSIGRQ0AIAAgACgCECIDNgIcIAZBAUcNACAEKAIIIANLDQAgACADIAAoAiAQNQsgACAAKAIUIAEgAmtqIgI2AiAgACACNgIQAkAgAEGg58IAQQQgBEEIahAtIgFFDQAgACAAKAIQIgI2AhwgAUF/aiIBQQJLDQACQAJAAkAgAUEBaw4CAQIACyAAIAIgACgCIBA1IAAgACgCEDYCICAAQYDowgAQREUNAiAAIAAoAhA2AhwgAEGA6MIAEERFDQIgACAAKAIcIAAoAiAQNQwCCyAAQYDowgAQREUNASAAIAAoAhwgACgCIBA1DAELIAAgAiAAKAIgEDULIAAgBTYCECAAIAU2AhhBASEDCyAEQRBqJAAgAwujCwIMfwR+IwBBQGoiBCQAAkACQAJAIAEoAhAiA0EBaiIFIANPBEAgASgCACICIQMgBSACQQFqQQN2QQdsIAMgAkEITxsiA0EBdksEQCAEIAUgA0EBaiIDIAUgA0sbEFIgBEEIaigCACEHIAQoAgQhCiAEKAIAQQFGBEAgACAKNgIEIABBATYCACAAQQhqIAc2AgAMBQsgBEEQaigCACEFIARBDGooAgAhAiAEIAEoAhAiAzYCECAEIAUgA2s2AgwgBCACNgIIIAQgBzYCBCAEIAo2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiENIAMoAgBBf3NBgIGChHhxIQwgASgCCCELA0ACQAJAAkAgDARAIAIhAwwBCwNAIAIgDU8NAiALQaABaiELIAIoAgAgAkEEaiIDIQJBgIGChHhxIgVBgIGChHhGDQALIAVBgIGChHhzIQwLIAQgCyAMaEEDdkEobGoiCCgCACIGNgIwIAxBf2ohCUKlxoihyJyn+UshDkEBIQIDQCAOIAatQv8Bg4VCs4OAgIAgfiEOIAJBBEYNAiAEQTBqIAJqIAJBAWohAi0AACEGDAALAAsgASkCACEPIAEgBCkDADcCACAEQQhqIgMpAwAhDiADIAFBCGoiAykCADcDACADIA43AgAgBEEQaiICKAIAIQMgAiABQRBqIgEoAgA2AgAgASADNgIAIAQgDzcDACAAQQA2AgAgD6ciAEUNBiAEQTBqIABBAWoQmwEgBCgCBBAhDAYLIAcgBCAOEJYBIgVqIA6nQRl2IgI6AAAgCiAFQXxqcSAHakEEaiACOgAAIAQoAgggBUEobGogCEEoELkBGiAJIAxxIQwgAyECDAALAAsgAkEBaiEJQQAhAgNAAkACfyAGQQFxRQRAIAIgCU8NAiACQQFqIgMgAkkNAiACIQYgAwwBCyACQQNqIgYgAkkiBQ0BIAYgCUkiA0UNASAJIAJBBGogBRsgCSADGwshAiABKAIEIAZqIgMgAygCACIDQQd2QX9zQYGChAhxIANB//79+wdyajYCAEEBIQYMAQsLIAEoAgQhAiABKAIAQQFqIgNBBE8NASACQQRqIAIgAxCYARoMAgtBtKbCAEEcQdCmwgAQuAEACyACIANqIAIoAAA2AAALAkAgASgCACIDQQFqIg0gA08Ef0EAIQMDQCADIgVBAWohAwJAIAUgASgCBGotAABBgAFHDQACQANAIAQgASgCCCAFQShsaiIHKAIAIgY2AgBCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRHBEAgAiAEaiACQQFqIQItAAAhBgwBCwsgBSABKAIAIgsgDqciCHEiAmsgASAOEJYBIgogAmtzIAtxQQRJDQEgASgCBCIJIApqIgItAAAgAiAIQRl2IgI6AAAgCSALIApBfGpxakEEaiACOgAAQf8BRwRAIAEoAgggCkEobGoiCCkDACEQIAggBykDADcDACAIQRhqIgIpAwAhESACIAdBGGoiCSkDADcDACAIQRBqIgIpAwAhDyACIAdBEGoiBikDADcDACAIQQhqIgIpAwAhDiACIAdBCGoiAikDADcDACAHIBA3AwAgAiAONwMAIAYgDzcDACAJIBE3AwAgCEEgaiICKQIAIQ4gAiAHQSBqIgIpAgA3AgAgAiAONwIADAELCyABKAIEIgIgBWpB/wE6AAAgAiABKAIAIAVBfGpxakEEakH/AToAACABKAIIIApBKGxqIAdBKBC5ARoMAQsgASgCBCIGIAVqIAhBGXYiAjoAACAGIAsgBUF8anFqQQRqIAI6AAALIAMgDUcNAAsgASgCACICQQhJDQEgAkEBagUgDQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBEFAayQAC+gKAgt/An4jAEFAaiIGJAACQAJAAkACQCABKAIQIgNBAWoiAiADTwRAIAEoAgAiAyEFAkAgAiADQQFqQQN2QQdsIAUgA0EITxsiBUEBdksEQAJ/IAIgBUEBaiIDIAIgA0sbIgJFBEBBsKbCACEHQQAhAkEEDAELIAZBfwJ/IAJBCE8EQCACIAJB/////wFxRgRAIAJBA3RBB24MAgsMCQsgAkEBagtBf2pndiIJQQFqIgMQogEgBigCBCICRQ0HIAYoAgghBSAGKAIAIgQgAhDRASIHRQ0CIAkgA0EDdkEHbCAJQQhJGyECIAdB/wEgCUEFahC/ASAFagshAyAGIAEoAhAiBTYCECAGIAIgBWs2AgwgBiADNgIIIAYgBzYCBCAGIAk2AgAgASgCBCIDQQRqIQIgASgCACADakEBaiEKIAMoAgBBf3NBgIGChHhxIQQgASgCCCEDA0ACQCAERQRAA0AgAiAKTw0CIANBQGshAyACKAIAIAJBBGoiBSECQYCBgoR4cSIEQYCBgoR4Rg0ACyAEQYCBgoR4cyEEIAUhAgsgBkKlxoihyJyn+Us3AzAgAyAEaEEBdEHwAHFqIgUoAgAgBSgCCCAGQTBqEHAgByAGIAYpAzAiDRCWASIIaiANp0EZdiILOgAAIAkgCEF8anEgB2pBBGogCzoAACAGKAIIIAhBBHRqIgggBSkCADcCACAIQQhqIAUpAgg3AgAgBEF/aiAEcSEEDAELCyABKQIAIQ0gASAGKQMANwIAIAZBCGoiAikDACEOIAIgAUEIaiICKQIANwMAIAIgDjcCACAGQRBqIgIoAgAhAyACIAFBEGoiASgCADYCACABIAM2AgAgBiANNwMAIABBADYCACANpyIARQ0FIAZBMGogAEEBahCiASAGKAIEECEMBQsgA0EBaiEFQQAhA0EAIQIDQAJAAn8gA0EBcUUEQCACIAVPDQIgAkEBaiIEIAJJDQIgAiEDIAQMAQsgAkEDaiIDIAJJIgQNASADIAVJIgdFDQEgBSACQQRqIAQbIAUgBxsLIQIgASgCBCADaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEDDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQIgAkEEaiACIAMQmAEaDAMLIAQgAkHU88IAKAIAIgBBAiAAGxEAAAALDAMLIAIgA2ogAigAADYAAAsCQCABKAIAIgJBAWoiCSACTwR/QQAhAwNAIAMiAkEBaiEDAkAgAiABKAIEai0AAEGAAUcNAAJAA0AgASgCCCAGQqXGiKHInKf5SzcDACACQQR0aiIFKAIAIAUoAgggBhBwIAIgASgCACIHIAYpAwAiDaciCHEiCmsgASANEJYBIgQgCmtzIAdxQQRJDQEgASgCBCIKIARqIgstAAAgCyAIQRl2Igg6AAAgCiAHIARBfGpxakEEaiAIOgAAQf8BRwRAIAEoAgggBEEEdGoiBCkCACENIAQgBSkCADcCACAEQQhqIgQpAgAhDiAEIAVBCGoiBCkCADcCACAFIA03AgAgBCAONwIADAELCyABKAIEIgcgAmpB/wE6AAAgByABKAIAIAJBfGpxakEEakH/AToAACABKAIIIARBBHRqIgIgBSkCADcCACACQQhqIAVBCGopAgA3AgAMAQsgASgCBCIFIAJqIAhBGXYiBDoAACAFIAcgAkF8anFqQQRqIAQ6AAALIAMgCUcNAAsgASgCACICQQhJDQEgAkEBagUgCQtBA3ZBB2whAgsgAEEANgIAIAEgAiABKAIQazYCDAsgBkFAayQADwtBtKbCAEEcQdCmwgAQuAEAC6EKAgx/An4jAEFAaiIEJAACQAJAAkAgASgCECIDQQFqIgUgA08EQCABKAIAIgIhAyAFIAJBAWpBA3ZBB2wgAyACQQhPGyIDQQF2SwRAIAQgBSADQQFqIgMgBSADSxsQUSAEQQhqKAIAIQcgBCgCBCEIIAQoAgBBAUYEQCAAIAg2AgQgAEEBNgIAIABBCGogBzYCAAwFCyAEQRBqKAIAIQUgBEEMaigCACECIAQgASgCECIDNgIQIAQgBSADazYCDCAEIAI2AgggBCAHNgIEIAQgCDYCACABKAIEIgNBBGohAiABKAIAIANqQQFqIQwgAygCAEF/c0GAgYKEeHEhCyABKAIIIQoDQAJAAkACQCALBEAgAiEDDAELA0AgAiAMTw0CIApBIGohCiACKAIAIAJBBGoiAyECQYCBgoR4cSIFQYCBgoR4Rg0ACyAFQYCBgoR4cyELCyAEIAogC2hBOHFqIg0oAgAiBjYCMCALQX9qIQlCpcaIocicp/lLIQ5BASECA0AgDiAGrUL/AYOFQrODgICAIH4hDiACQQRGDQIgBEEwaiACaiACQQFqIQItAAAhBgwACwALIAEpAgAhDyABIAQpAwA3AgAgBEEIaiIDKQMAIQ4gAyABQQhqIgMpAgA3AwAgAyAONwIAIARBEGoiAigCACEDIAIgAUEQaiIBKAIANgIAIAEgAzYCACAEIA83AwAgAEEANgIAIA+nIgBFDQYgBEEwaiAAQQFqEKEBIAQoAgQQIQwGCyAHIAQgDhCWASIFaiAOp0EZdiICOgAAIAggBUF8anEgB2pBBGogAjoAACAEKAIIIAVBA3RqIA0pAgA3AgAgCSALcSELIAMhAgwACwALIAJBAWohCUEAIQIDQAJAAn8gBkEBcUUEQCACIAlPDQIgAkEBaiIDIAJJDQIgAiEGIAMMAQsgAkEDaiIGIAJJIgUNASAGIAlJIgNFDQEgCSACQQRqIAUbIAkgAxsLIQIgASgCBCAGaiIDIAMoAgAiA0EHdkF/c0GBgoQIcSADQf/+/fsHcmo2AgBBASEGDAELCyABKAIEIQIgASgCAEEBaiIDQQRPDQEgAkEEaiACIAMQmAEaDAILQbSmwgBBHEHQpsIAELgBAAsgAiADaiACKAAANgAACwJAIAEoAgAiA0EBaiIMIANPBH9BACEDA0AgAyIFQQFqIQMCQCAFIAEoAgRqLQAAQYABRw0AAkADQCAEIAEoAgggBUEDdGoiBygCACIGNgIAQqXGiKHInKf5SyEOQQEhAgNAIA4gBq1C/wGDhUKzg4CAgCB+IQ4gAkEERwRAIAIgBGogAkEBaiECLQAAIQYMAQsLIAUgASgCACIKIA6nIg1xIgJrIAEgDhCWASIIIAJrcyAKcUEESQ0BIAEoAgQiCSAIaiICLQAAIAIgDUEZdiICOgAAIAkgCiAIQXxqcWpBBGogAjoAAEH/AUcEQCABKAIIIAhBA3RqIgIpAgAhDiACIAcpAgA3AgAgByAOQiCIPgIEIAcgDj4CAAwBCwsgASgCBCICIAVqQf8BOgAAIAIgASgCACAFQXxqcWpBBGpB/wE6AAAgASgCCCAIQQN0aiAHKQIANwIADAELIAEoAgQiBiAFaiANQRl2IgI6AAAgBiAKIAVBfGpxakEEaiACOgAACyADIAxHDQALIAEoAgAiAkEISQ0BIAJBAWoFIAwLQQN2QQdsIQILIABBADYCACABIAIgASgCEGs2AgwLIARBQGskAAu8CQIYfwF+IwBBMGsiAyQAIAJBOGooAgAgAkEwaigCACEIIAJBLGooAgAgAkEMaigCACELIAIoAjQhFSACKAIoIQ4gAigCACEWIAIoAgQhCiABKAIAIgEoAgghDyABKAIAIRAgA0EIaiACQRBqKAIAIgcgAkEIaigCACICIAEoAgQiESABKAIMIhJqQQFqbGpBAnQQsAEgA0EANgIoIAMgAykDCDcDIAJAAkAgAgRAIAogAkEUbGohFyAIQQV0IQwgDkF0aiENIA8gEkECdGohGCAQIBFBAnRqIRlBACEIAkACQAJAAkACQAJAA0AgCCIGQQFqIQggCiIJQRRqIQogDCECIA0hBCAOIQECQAJ/AkADQCACRQ0BIAJBYGohAiAEQSBqIQQgASgCCCEaIAFBIGohASAGIBpHDQALIAkoAgQiAiAEKAIAaiIBIAVJDQUgByABSQ0GIARBBGooAgAhBCADQSBqIAMoAiggASAFaxCQASALIAVBAnRqIAsgAUECdGoiBSADQSBqEEogA0EgaiADKAIoIBEQkAEgECAZIANBIGoQSiACIARqIgYgAUkNByAHIAZJDQggA0EgaiADKAIoIAYgAWsQkAEgBSALIAZBAnRqIgQgA0EgahBKIANBIGogAygCKCASEJABIA8gGCADQSBqEEogCSgCCCICIAZJDQkgByACSQ0EIAIgBmsMAQsgCSgCCCICIAVJDQEgByACSQ0DIAsgBUECdGohBCACIAVrCyEBIANBIGogAygCKCABEJABIAQgCyACQQJ0aiADQSBqEEogCSgCCCEFIAogF0YNCQwBCwsgBSACEKcBAAsgAiAHEKUBAAsgBSABEKcBAAsgASAHEKUBAAsgASAGEKcBAAsgBiAHEKUBAAsgBiACEKcBAAtBACECDAELIAcgBU8EQCADKAIoIQIMAQsgBSAHEKcBAAsgA0EgaiACIAcgBWsQkAEgCyAFQQJ0aiALIAdBAnRqIANBIGoQSgJAIAMoAigiDUUNAEEAIQIgAygCICEGQQAhCQNAAkACQAJAIAIgBmoiDCwAACIIQX9KBEAgCEH/AXEhBAwBCwJ/IAYgDWoiASAMQQFqRgRAQQAhCiABDAELIAwtAAFBP3EhCiAMQQJqCyEEIAhBH3EhBQJAAkAgCEH/AXEiB0HfAU0EQCAKIAVBBnRyIQQMAQsCfyABIARGBEAgASEIQQAMAQsgBEEBaiEIIAQtAABBP3ELIApBBnRyIQQgB0HwAUkEQCAEIAVBDHRyIQQMAQsgASAIRgR/QQAFIAgtAABBP3ELIAVBEnRBgIDwAHEgBEEGdHJyIgRBgIDEAEYNAQsgBEGAAUkNAUECIQEgBEGAEEkNAkEDQQQgBEGAgARJGyEBDAILQbnswgBBK0Hk7MIAELgBAAtBASEBIAQNACAJQQFqIQkMAQsgCUUEQEEAIQkMAQsgBiACIAlraiAMIAEQmAEaCyANIAEgAmoiAksNAAsgCUUNACADIA0gCWs2AigLIANBGGogA0EoaigCACIBNgIAIAMgAykDICIbNwMQIAAgFjYCACAAIBs3AgQgAEEMaiABNgIABEAgDhAhCwRAIBUQIQsgA0EwaiQAC8kKAQ1/IwBBoAJrIgckACAHIAI2AkAgByACNgI8IAcgATYCOCAHQShqIAdBOGoQhQEgBygCKCEBIAcoAiwhCiAHIAQ2AkAgByAENgI8IAcgAzYCOCAHQSBqIAdBOGoQjQEgBygCJCEJIAcoAiAhDSAHIAY2AkAgByAGNgI8IAcgBTYCOCAHQRhqIAdBOGoQhQEgBygCHCEOIAcoAhghCwJAAkACQAJAEMEBIgQEQCAHIAcvATQ7ATYgBCgCAA0CIARBfzYCACAEQQRqIAAQVCIMRQ0DIAwQrwEgB0HkAGpBATsBACAHQeAAaiIPQQA2AgAgB0HYAGpCgICAgBA3AwAgB0HUAGogCTYCACAHQdAAakEANgIAIAdBzABqIAk2AgAgB0HIAGogDTYCACAHQcQAaiAJNgIAIAdB9ABqIAsgDkECdGo2AgAgB0HmAGogBy8BNjsBACAHQQA2AkAgByABIApBAnRqNgI8IAcgATYCOCAHQgA3A3ggByALNgJwIAdCADcDaCAKRQ0BIAxBFGohAyAHQUBrIRAgByABQQRqNgI4IAdBiAFqIRFBACECIAEhBgNAIAJB/wFxDQIgBigCACESAn8CQAJAIAcoAlQiACAHKAJQIgJJDQAgBygCTCAASQ0AIAdBEGogBygCXCAQakEfai0AACAHKAJIIgggAmogACACaxBCIAcoAhBBAUYEQCAHKAIUIQYDQCAHIAYgBygCUGpBAWoiADYCUAJAIAAgBygCXCICSQRAIAcoAkwhBQwBCyAHKAJMIgUgAEkNACACQQVPDQsgACACayIGIAcoAkhqIhMgD0YNBCATIA8gAhC0AUUNBAsgBygCVCIGIABJIAUgBklyDQIgB0EIaiACIBBqQR9qLQAAIAcoAkggAGogBiAAaxBCIAcoAgwhBiAHKAIIQQFGDQALCyAHIAcoAlQ2AlALIActAGUNBAJAIActAGQEQCAHKAJEIQIgBygCQCEGDAELIAcoAkQiAiAHKAJAIgZGDQULIAdBAToAZSACIAZrIQAgBygCSCAGagwBCyAHKAJAIQIgByAANgJAIAYgAmshACACIAhqCyECIAcoAnAiBSAHKAJ0Rg0CIAcgBUEEajYCcCAFKAIAIQUgB0HAAWogAiAAIAJqECMgB0HwAWogB0HAAWoQXyAHQcABaiAHQfABaiADEDsgB0HwAWogB0HAAWogAxAmIAdBwAFqIAdB8AFqIAMQICAHQfABaiAHQcABahA5IAcoAvgBIgAEQCAHKALwASEGIABBFGwhAiAHKAKQAiEAIAcoAogCIQgDQCAGIAggACADEEcgBkEUaiEGIAJBbGoiAg0ACwsgB0HAAWogB0HwAWpBMBC5ARogB0HwAWogB0HAAWogAxBPIAcoAvgBIgAEQCAHKALwASEGIABBFGwhAiAHKAKQAiEAIAcoAogCIQgDQCAGIAggACADEEYgBkEUaiEGIAJBbGoiAg0ACwsgB0HAAWogB0HwAWpBMBC5ARogByASNgKEASAHQQA2AoABIBEgB0HAAWpBMBC5ARogByAFNgK4ASAMIAdBgAFqEBcgBygCOCIGIAcoAjxGDQIgByAGQQRqNgI4IActAGUhAgwACwALQfzKwgBBxgAgB0E4akHky8IAEJUBAAsgBCAEKAIAQQFqNgIAIAkEQCANECELIA5B/////wNxBEAgCxAhCyAKQf////8DcQRAIAEQIQsgB0GgAmokAA8LQcLLwgBBECAHQThqQdTLwgAQlQEAC0G57MIAQStB5OzCABC4AQALIAJBBBClAQALlAoCFH8BfiMAQeAAayIDJAAgASAAKAIAIg02AgACQCAAKAKwAUUEQCAAQX82ArABIANBHGogAUE0aigCADYCACADQRRqIAFBKGooAgA2AgAgA0EMaiABQRxqKAIANgIAIAMgAUEsaigCADYCGCADIAFBIGooAgA2AhAgAyABQRRqKAIANgIIIAMgAUEQaigCADYCBCADIAEoAgg2AgAgA0FAayADEB8gAEG0AWoiAiACKAIAQQFqNgIAIAMoAkAhCiADKAJIIgJFDQEgCiACQQxsaiESIABBuAFqIQ8gAEG8AWohEyAAQcQBaiEQIABBwAFqIRQgAEHIAWohESAKIQkDQCADQdgAaiAJQQhqKAIANgIAIAMgCSkCADcDUCADQqXGiKHInKf5SzcDACADQdAAaiADEHUgAykDACIWQhmIp0H/AHEiBEEIdCAEciICQRB0IAJyIQsgCUEMaiEJIAAoArgBIgcgFqdxIQIgACgCwAEhDiAAKAK8ASEVQQAhCAJAAkADQAJAIAIgFWooAAAiDCALcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAOIAZoQQN2IAJqIAdxQRhsaiIFIANB0ABqRgRAIANB0ABqIQUMAQsgBSADQdAAakEMELQBRQ0AIAZBf2ogBnEiBg0BDAILCyAFQQxqIQggBSgCFCEEQQAhB0EAIQIDQAJAIAQgAksEQCACQQFqIgYgAk8NAQsgBSgCECAERgRAIAggBEEBEIIBIAUoAhQhBAsgBSgCDCAEQQJ0aiANNgIAIAUgBSgCFEEBajYCFAwECyAIKAIAIgwgB2ohCyAHQQRqIQcgBiECAkBBfyANIAsoAgAiC0cgDSALSRsOAgQBAAsLIAQgBkF/ak8EQCAFKAIQIARGBH8gCCAEQQEQggEgCCgCAAUgDAsgB2oiAiACQXxqIgIgBEECdCAHa0EEahCYARogAiANNgIAIAUgBEEBajYCFAwDC0HkrcIAQR5BhK7CABC4AQALIAhBBGoiCCACaiAHcSECIAwgDEEBdHFBgIGChHhxRQ0ACyAAKALEAUUEQCADIA8QDwsgAygCWCEFIAMoAlQhByADKAJQIQhBBEEEENEBIgZFDQEgBiANNgIAIBAgECgCACATKAIAIgwgDyAWEJYBIgJqIgstAABBAXFrNgIAIBQoAgAgCyAEOgAAIAwgACgCuAEgAkF8anFqQQRqIAQ6AAAgAkEYbGoiAkKBgICAEDcCECACIAY2AgwgAiAFNgIIIAIgBzYCBCACIAg2AgAgESARKAIAQQFqNgIACyAJIBJHDQEMAwsLQQRBBEHU88IAKAIAIgBBAiAAGxEAAAALQcLLwgBBECADQbiqwgAQlQEACyADKAJEBEAgChAhCyAAIAAoArABQQFqNgKwASADIAFBPBC5ASEJAkAgAEEMaigCACIBIABBCGooAgBGBEACQAJAIAFBAWoiAiABSQ0AIAFBAXQiCiACIAogAksbIgqtQjx+IhZCIIinDQAgFqciAkEATg0BCxDNAQALAn8gAUUEQCACQQQQ0QEMAQsgACgCBCABQTxsQQQgAhDOAQsiAUUNASAAIAo2AgggACABNgIEIAAoAgwhAQsgACgCBCABQTxsaiAJQTwQuQEaIAAgACgCDEEBajYCDCAAIAAoAgBBAWo2AgAgCUHgAGokAA8LIAJBBEHU88IAKAIAIgBBAiAAGxEAAAAL7QgCDH8BfiMAQdADayIEJAACQBDBASIDBEACQCADKAIARQRAIANBfzYCACADQQRqIAAQXQRAIAQgADYCCEKlxoihyJyn+UshDUEBIQIgACEBA0AgDSABrUL/AYOFQrODgICAIH4hDSACQQRGRQRAIARBCGogAmogAkEBaiECLQAAIQEMAQsLIA2nIgFBGXYiAkEIdCACciICQRB0IAJyIQkgAygCBCIGIAFxIQIgAygCDCEKIAMoAgghCANAAkACQCACIAhqKAAAIgcgCXMiAUF/cyABQf/9+3dqcUGAgYKEeHEiAUUNAANAIAAgCiABaEEDdiACaiAGcSILQewBbGoiDCgCAEcEQCABQX9qIAFxIgENAQwCCwtBgAEhAiAIIAtB7AFsIgFB7AFtIgVqIgcoAAAiCSAJQQF0cUGAgYKEeHFoQQN2IAggBUF8aiAGcWoiBSgAACIGIAZBAXRxQYCBgoR4cWdBA3ZqQQNNBEAgAyADKAIQQQFqNgIQQf8BIQILIAcgAjoAACAFQQRqIAI6AAAgAyADKAIUQX9qNgIUIAEgCmoiAigCCCEBIAwoAgQhBSAEQfABaiACQQxqQeABELkBGiABRQRAQQAhAQwCCyAEIAU2AgggBEEQaiAEQfABakHgARC5ARoMAQsgBUEEaiIFIAJqIAZxIQJBACEBIAcgB0EBdHFBgIGChHhxRQ0BCwsgBCABNgIMIARBCGoQdiADIAMoAgBBAWo2AgACQAJAEMABIgMEQCADKAIADQIgA0F/NgIAIANBBGogABBcRQ0BIAQgADYCCEKlxoihyJyn+UshDUEBIQIgACEBA0AgDSABrUL/AYOFQrODgICAIH4hDSACQQRGRQRAIARBCGogAmogAkEBaiECLQAAIQEMAQsLIA2nIgFBGXYiAkEIdCACciICQRB0IAJyIQkgAygCBCIGIAFxIQIgAygCDCEKIAMoAgghCEEAIQUCQANAAkACQCACIAhqKAAAIgcgCXMiAUF/cyABQf/9+3dqcUGAgYKEeHEiAUUNAANAIAAgCiABaEEDdiACaiAGcSILQQR0aigCAEcEQCABQX9qIAFxIgENAQwCCwtBgAEhAiAIIAtBBHQiAEEEdSIBaiIFKAAAIgcgB0EBdHFBgIGChHhxaEEDdiAIIAFBfGogBnFqIgEoAAAiBiAGQQF0cUGAgYKEeHFnQQN2akEDTQRAIAMgAygCEEEBajYCEEH/ASECCyAFIAI6AAAgAUEEaiACOgAAIAMgAygCFEF/ajYCFCAAIApqIgAoAgQiAUUNASAEIAApAgg3AgwgBCABNgIIDAMLIAVBBGoiBSACaiAGcSECIAcgB0EBdHFBgIGChHhxRQ0BCwsgBEEANgIICyAEQQhqEKQBIAMgAygCAEEBajYCACAEQdADaiQADwsMBAsQ0AEACwwECxDQAQALDAILC0H8ysIAQcYAIARBCGpB3KjCABCVAQALQcLLwgBBECAEQQhqQcyowgAQlQEAC8cJAg1/A34jAEGwCWsiBSQAIAUgAEGIARC5ASEBAkACQAJAQfjKwgAoAgARCAAiAwRAIAEoAgAhACABQYgBaiABQQRyQYQBELkBGiADKAIADQIgA0F/NgIAIANBBGoiCSAAKAIAEF0NASABQcgHahBhQQRBBBDRASIFRQ0DIAVB2wA2AgBBBEEEENEBIgJFDQMgAkHdADYCACABQZgCakIANwMAIAFBCjYCoAIgAUKAgICAwAA3A5ACIAFBpAJqIAFByAdqQYQBELkBIQYgAUHgA2pCADcDACABQdgDakKAgICAwAA3AwAgAUHQA2pCBDcDACABQcwDakGwpsIANgIAIAFByANqQQA2AgAgAUG4A2pCgYCAgBA3AwAgAUG0A2ogAjYCACABQawDakKBgICAEDcCACABQegDakIANwMAIAFCADcDwAMgASAFNgKoAyAGEI4BIAYgAUGIAWpBhAEQuQEaIAAoAgAhBSABQeAFaiABQZACakHoARC5ARogASAFNgLIB0KlxoihyJyn+UshDkEBIQIgBSEAA0AgDiAArUL/AYOFQrODgICAIH4hDiACQQRHBEAgAUHIB2ogAmogAkEBaiECLQAAIQAMAQsLIA5CGYinIgxB/wBxIgBBCHQgAHIiAEEQdCAAciENIAMoAgQiCCAOp3EhAiADKAIMIQYgAygCCCEAAkADQAJAIAAgAmooAAAiCiANcyIEQX9zIARB//37d2pxQYCBgoR4cSIERQ0AA0AgBSAGIARoQQN2IAJqIAhxIgtB7AFsaigCAEcEQCAEQX9qIARxIgQNAQwCCwsgAUHIB2ogAUHgBWpB6AEQuQEaIAYgC0HsAWxqQQRqIQhBACECA0AgAiAIaiIAKQIAIQ4gACABQcgHaiACaiIFKQIANwIAIAUgDjcCACAAQQhqIgQpAgAhDiAEIAVBCGoiBCkCADcCACAAQRBqIgcpAgAhDyAHIAVBEGoiBykCADcCACAAQRhqIgApAgAhECAAIAVBGGoiACkCADcCACAAIBA3AgAgByAPNwIAIAQgDjcCACACQUBrIAJBIGohAkHpAUkNAAsgBiALQewBbGoiACkC5AEhDiAAIAFBqAlqIgApAwA3AuQBIAAgDjcDACABQfgDaiABQcgHakHoARC5ARoMAgsgB0EEaiIHIAJqIAhxIQIgCiAKQQF0cUGAgYKEeHFFDQALIAFByAdqIAFB4AVqQegBELkBGiAAIAkgDhCWASICai0AAEEBcSEEIAMoAhAiByAERXJFBEAgAUH4A2ogCRALIAkgDhCWASECIAMoAgQhCCADKAIMIQYgAygCECEHIAMoAgghAAsgAyAHIARrNgIQIAAgAmogDEH/AHEiBDoAACAIIAJBfGpxIABqQQRqIAQ6AAAgBiACQewBbGoiACAFNgIAIABBBGogAUHIB2pB6AEQuQEaIAMgAygCFEEBajYCFCABQQA2AvwDCyABQfgDahB2IAMgAygCAEEBajYCACABQbAJaiQADwsgAUEEchCOAUH8ysIAQcYAIAFByAdqQdyowgAQlQEACxDQAQALQcLLwgBBECABQcgHakHMqMIAEJUBAAtBBEEEQdTzwgAoAgAiAEECIAAbEQAAAAuuCAEGfyMAQfAAayIEJAAgBCADNgIMIAQgAjYCCEEBIQggASEGAkAgAUGBAkkNAEEAIAFrIQdBgAIhBQNAAkAgBSABTw0AIAAgBWosAABBv39MDQBBACEIIAUhBgwCCyAFQX9qIQZBACEIIAVBAUYNASAFIAdqIAYhBUEBRw0ACwsgBCAGNgIUIAQgADYCECAEQQBBBSAIGzYCHCAEQfTLwgBBzoPAACAIGzYCGAJAAkACQCACIAFLIgUgAyABS3JFBEAgAiADSw0BAkAgAkUgASACRnJFBEAgASACTQ0BIAAgAmosAABBQEgNAQsgAyECCyAEIAI2AiAgAkUgASACRnINAiABQQFqIQMDQCACIAFJBEAgACACaiwAAEFATg0ECyACQX9qIQUgAkEBRg0EIAIgA0YgBSECRQ0ACwwDCyAEIAIgAyAFGzYCKCAEQcQAakEDNgIAIARB3ABqQQQ2AgAgBEHUAGpBBDYCACAEQgM3AjQgBEHUg8AANgIwIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCWCAEIARBEGo2AlAgBCAEQShqNgJIIARBMGpB7IPAABC+AQALIARB5ABqQQQ2AgAgBEHcAGpBBDYCACAEQdQAakEFNgIAIARBxABqQQQ2AgAgBEIENwI0IARB/IPAADYCMCAEQQU2AkwgBCAEQcgAajYCQCAEIARBGGo2AmAgBCAEQRBqNgJYIAQgBEEMajYCUCAEIARBCGo2AkggBEEwakGchMAAEL4BAAsgAiEFCwJAIAEgBUYNAEEBIQYCQAJAAkAgACAFaiIHLAAAIgJBf0wEQEEAIQggACABaiIDIQEgAyAHQQFqRwRAIActAAFBP3EhCCAHQQJqIQELIAJBH3EhByACQf8BcUHfAUsNASAIIAdBBnRyIQEMAgsgBCACQf8BcTYCJCAEQShqIQIMAgtBACEAIAMhBiABIANHBH8gAUEBaiEGIAEtAABBP3EFIAALIAhBBnRyIQAgAkH/AXFB8AFJBEAgACAHQQx0ciEBDAELQQAhAiADIAZHBH8gBi0AAEE/cQUgAgsgB0ESdEGAgPAAcSAAQQZ0cnIiAUGAgMQARg0CCyAEIAE2AiRBASEGIARBKGohAiABQYABSQ0AQQIhBiABQYAQSQ0AQQNBBCABQYCABEkbIQYLIAQgBTYCKCAEIAUgBmo2AiwgBEHEAGpBBTYCACAEQewAakEENgIAIARB5ABqQQQ2AgAgBEHcAGpBBjYCACAEQdQAakEHNgIAIARCBTcCNCAEQayEwAA2AjAgBCACNgJYIARBBTYCTCAEIARByABqNgJAIAQgBEEYajYCaCAEIARBEGo2AmAgBCAEQSRqNgJQIAQgBEEgajYCSCAEQTBqQdSEwAAQvgEAC0G57MIAQStB5OzCABC4AQALpQgBCH8jAEFAaiIDJAAgA0EkaiABNgIAIANBNGogAkEUaigCACIENgIAIANBAzoAOCADQSxqIAIoAhAiBSAEQQN0ajYCACADQoCAgICABDcDCCADIAA2AiAgA0EANgIYIANBADYCECADIAU2AjAgAyAFNgIoAkACQAJAAkAgAigCCCIGRQRAIAIoAgAhCCACKAIEIgkgBCAEIAlLGyIGRQ0BQQEhBCAAIAgoAgAgCCgCBCABKAIMEQMADQQgCEEIaiECQQEhBwNAIAUoAgAgA0EIaiAFQQRqKAIAEQEABEAMBgsgByAGTw0CIAJBBGohACACKAIAIQEgBUEIaiEFIAJBCGohAiAHQQFqIQcgAygCICABIAAoAgAgAygCJCgCDBEDAEUNAAsMBAsgAigCACEIIAIoAgQiCSACQQxqKAIAIgIgAiAJSxsiCkUNAEEBIQQgACAIKAIAIAgoAgQgASgCDBEDAA0DIAZBEGohBSAIQQhqIQJBASEHA0AgAyAFQXhqKAIANgIMIAMgBUEQai0AADoAOCADIAVBfGooAgA2AghBACEBQQAhBAJAAkACQAJAIAVBCGooAgBBAWsOAwECAwALIAVBDGooAgAhAEEBIQQMAgsgBUEMaigCACIGIAMoAjQiBEkEQEEAIQQgAygCMCAGQQN0aiIGKAIEQQhHDQIgBigCACgCACEAQQEhBAwCC0HEhcAAIAYgBBCmAQALIAMoAigiBiADKAIsRg0AIAMgBkEIajYCKCAGKAIEQQhHDQAgBigCACgCACEAQQEhBAsgAyAANgIUIAMgBDYCEAJAAn8CQAJAAkACQAJAIAUoAgBBAWsOAwEABgQLIAMoAigiACADKAIsRw0BDAULIAVBBGooAgAiACADKAI0IgRPDQEgAygCMCAAQQN0aiIAKAIEQQhHDQQgACgCACgCAAwDCyADIABBCGo2AiggACgCBEEIRw0DIAAoAgAoAgAMAgtBxIXAACAAIAQQpgEACyAFQQRqKAIACyEEQQEhAQsgAyAENgIcIAMgATYCGAJAIAVBcGooAgBBAUcEQCADKAIoIgQgAygCLEYNBCADIARBCGo2AigMAQsgBUF0aigCACIAIAMoAjQiAU8NBCADKAIwIABBA3RqIQQLIAQoAgAgA0EIaiAEQQRqKAIAEQEABEBBASEEDAULIAcgCk8NASACQQRqIQAgAigCACEBIAVBJGohBSACQQhqIQJBASEEIAdBAWohByADKAIgIAEgACgCACADKAIkKAIMEQMARQ0ACwwDCyAJIAdLBEBBASEEIAMoAiAgCCAHQQN0aiIAKAIAIAAoAgQgAygCJCgCDBEDAA0DC0EAIQQMAgtBuezCAEErQeTswgAQuAEAC0HUhcAAIAAgARCmAQALIANBQGskACAEC4oIAg9/AX4jAEGgAmsiAiQAIAJBmAFqEGEgAkEBNgKYAUGcscIAIQEDQCACQZgBaiABKAIAIAFBBGooAgAgAUEIaigCACABQQxqKAIAEIABIAFBEGoiAUG8scIARw0AC0G8scIAIQEDQCACQZgBaiABKAIAIAFBBGooAgAgAUEIaigCACABQQxqKAIAEIEBIAFBEGoiAUHcscIARw0AC0HcscIAIQEDQCACQZgBaiABQQRqKAIAIAFBCGooAgAgAS0AABBoIAFBDGoiAUGcv8IARw0ACyACQZgBakEEciEFQcivwgAhAQNAIAUgAUEEaigCACABLQAAEDogAUEIaiIBQZixwgBHDQALQZy/wgAhAQNAIAUgAUEEaigCACABLQAAEDogAUEIaiIBQYzBwgBHDQALIAJBEGogAkGYAWpBhAEQuQEaIAIgADYClAEgAiACQZQBajYCmAEgAkGYAWpBBHIgAkEQakGEARC5ARogAkGYAWoQGRDAASIDBEAgAygCAEUEQCADQX82AgAgA0EEaiIJIAIoApQBIgUQXEUEQCACQQhqQQoQngEgAigCDCEKIAIoAgghCyACIAU2ApgBQqXGiKHInKf5SyEQQQEhASAFIQADQCAQIACtQv8Bg4VCs4OAgIAgfiEQIAFBBEZFBEAgAkGYAWogAWogAUEBaiEBLQAAIQAMAQsLIBBCGYinIg1B/wBxIgBBCHQgAHIiAEEQdCAAciEOIAMoAgQiByAQp3EhASADKAIMIQggAygCCCEAAkADQAJAIAAgAWooAAAiDCAOcyIEQX9zIARB//37d2pxQYCBgoR4cSIERQ0AA0AgBSAIIARoQQN2IAFqIAdxIg9BBHRqKAIARwRAIARBf2ogBHEiBA0BDAILCyACQaABaiAIIA9BBHRqIgAoAgw2AgAgAEEANgIMIAApAgQhECAAIAo2AgggACALNgIEIAIgEDcDmAEMAgsgBkEEaiIGIAFqIAdxIQEgDCAMQQF0cUGAgYKEeHFFDQALIAAgCSAQEJYBIgFqLQAAQQFxIQQgAygCECIGIARFckUEQCACQZgBaiAJEA0gCSAQEJYBIQEgAygCBCEHIAMoAgwhCCADKAIQIQYgAygCCCEACyADIAYgBGs2AhAgACABaiANQf8AcSIEOgAAIAcgAUF8anEgAGpBBGogBDoAACAIIAFBBHRqIgBBADYCDCAAIAo2AgggACALNgIEIAAgBTYCACADIAMoAhRBAWo2AhQgAkEANgKYAQsgAkGYAWoQpAEgAyADKAIAQQFqNgIAIAJBoAJqJAAPCxDQAQALQcLLwgBBECACQZgBakHMqMIAEJUBAAtB/MrCAEHGACACQZgBakHcqMIAEJUBAAuoBgENfyABKAIQIQIgACgCBCEDIAAoAgAhBQJAAkACQCABKAIIIg1BAUcEQCACDQEMAwsgAkUNAQsCQCADRQRAQQAhAwwBCyADIAVqIQcgAUEUaigCAEEBaiEKIAUiACELA0AgAEEBaiEEAkACfyAALAAAIgJBf0wEQAJ/IAQgB0YEQEEAIQggBwwBCyAALQABQT9xIQggAEECaiIECyEAIAJBH3EhCSAIIAlBBnRyIAJB/wFxIg5B3wFNDQEaAn8gACAHRgRAQQAhDCAHDAELIAAtAABBP3EhDCAAQQFqIgQLIQIgDCAIQQZ0ciEIIAggCUEMdHIgDkHwAUkNARoCfyACIAdGBEAgBCEAQQAMAQsgAkEBaiEAIAItAABBP3ELIAlBEnRBgIDwAHEgCEEGdHJyIgJBgIDEAEcNAgwECyACQf8BcQshAiAEIQALIApBf2oiCgRAIAYgC2sgAGohBiAAIQsgACAHRw0BDAILCyACQYCAxABGDQACQCAGRSADIAZGckUEQEEAIQAgBiADTw0BIAUgBmosAABBQEgNAQsgBSEACyAGIAMgABshAyAAIAUgABshBQsgDQ0ADAELQQAhBCADBEAgAyECIAUhAANAIAQgAC0AAEHAAXFBgAFGaiEEIABBAWohACACQX9qIgINAAsLIAMgBGsgASgCDCIHTw0AQQAhBkEAIQQgAwRAIAMhAiAFIQADQCAEIAAtAABBwAFxQYABRmohBCAAQQFqIQAgAkF/aiICDQALCyAEIANrIAdqIQICQAJAAkBBACABLQAwIgAgAEEDRhtBAWsOAwABAAILIAIhBkEAIQIMAQsgAkEBdiEGIAJBAWpBAXYhAgsgBkEBaiEAAn8CQANAIABBf2oiAARAIAEoAhggASgCBCABKAIcKAIQEQEARQ0BDAILCyABKAIEIQRBASABKAIYIAUgAyABKAIcKAIMEQMADQEaIAJBAWohACABKAIcIQUgASgCGCEBA0AgAEF/aiIARQRAQQAPCyABIAQgBSgCEBEBAEUNAAsLQQELDwsgASgCGCAFIAMgAUEcaigCACgCDBEDAAv9BgIVfwF+IwBB4AFrIgIkACACIAE2AgAgASgCICEJIAIgAS0AJDoAByACIAI2AgwgAiACQQdqNgIIIAEtACVFBEAgAUEUaiEEIAJB+ABqIAEgAUEQaiIKIAFBDGoiCxAAAkAgAigCfCIGRQ0AIAlBAXQhDCACQbQBaiENIAJBgAFqIQgDQAJAIAJBQGsiAyAIQQhqKQIANwMAIAJByABqIgUgCEEQaikCADcDACACQdAAaiIHIAhBGGopAgA3AwAgAiAIKQIANwM4IAIoAnghDiACKAKgASEPIAIoAqQBIRAgAigCqAEhESACKAKsASESIAIoArABIRMgAkEQaiANQSgQuQEaIAJB4ABqIhQgAykDADcDACACQegAaiIVIAUpAwA3AwAgAkHwAGoiFiAHKQMANwMAIAIgAikDODcDWCACQfgAaiACQRBqQSgQuQEaIAEoAhwiAyABKAIYRgRAAkACQCADQQFqIgUgA0kNACADQQF0IgcgBSAHIAVLGyIHrULkAH4iF0IgiKcNACAXpyIFQQBODQELEM0BAAsCfyADRQRAIAVBBBDRAQwBCyAEKAIAIANB5ABsQQQgBRDOAQsiA0UNASABIAc2AhggASADNgIUIAEoAhwhAwsgASgCFCADQeQAbGoiAyAGNgIEIAMgDjYCACADIBI2AjQgAyAPNgIoIANBCGogAikDWDcCACADQRBqIBQpAwA3AgAgA0EYaiAVKQMANwIAIANBIGogFikDADcCACADQThqIBM2AgAgA0EwaiARNgIAIANBLGogEDYCACADQTxqIAJB+ABqQSgQuQEaIAEgASgCHEEBaiIDNgIcIAMgDE8EQCACQQhqIAQQCSAEIAkQtgELIAJB+ABqIAEgCiALEAAgAigCfCIGDQEMAgsLIAVBBEHU88IAKAIAIgBBAiAAGxEAAAALIAJBCGogBBAJIAQgCRC2ASABKAIcIgRBAXYiBgRAIAEoAhQiAyAEQeQAbGpBnH9qIQQDQCACQfgAaiADQeQAELkBGiADIARB5AAQmAFB5ABqIQMgBCACQfgAakHkABC5AUGcf2ohBCAGQX9qIgYNAAsLIAFBAToAJQsCQCABQRxqKAIAIgMEQCABIANBf2oiAzYCHCAAIAEoAhQgA0HkAGxqQeQAELkBGgwBCyAAQQA2AgQLIAJB4AFqJAAL1QYCDn8CfiMAQRBrIgYkACABKAIEIghBFGwhAyABKAIAIQUCQCAIRQRADAELIAVBCGohBCADIQIDQCAEKAIAIARBfGooAgBrIAdqIQcgBEEUaiEEIAJBbGoiAg0ACwsCQCAHrUIMfiIQQiCIp0UEQCAQpyICQX9KBEACQAJAIAJFBEBBBCEJDAELIAJBBBDRASIJRQ0BCyAIRQRAQQAhAgwECyADIAVqIQ0gAUEUaigCACEMIAEoAhAhDkEAIQIDQAJAAkACQAJAAkAgBSgCCCIBIAUoAgQiCk8EQCAMIAFJDQEgASAKayIIRQ0FQQEhBCACQQF0IQMgAkEMbCEBIA4gCkECdGohCgNAIAZBADYCCCAGQgA3AgAgBiAEIAogBBByAn8gBEEDRgRAIAhFDQUgCkEEaiEKIAhBf2ohCEEDDAELIARBAWoLIQQgBigCACIPQYCAxABGDQYgBikCBCEQIAIgB0YEQCACQQFqIgcgAkkNBiADIAcgAyAHSxsiB61CDH4iEUIgiKcNBiARpyILQQBIDQYCfyACRQRAIAtBBBDRAQwBCyAJIAFBBCALEM4BCyIJRQ0FCyABIAlqIgsgDzYCACALQQRqIBA3AgAgA0ECaiEDIAFBDGohASACQQFqIQIgCCAETw0ACwwFCyAKIAEQpwEACyABIAwQpQEAC0EBQQAQpwEACyALQQRB1PPCACgCACIAQQIgABsRAAAACxDNAQALIA0gBUEUaiIFRw0ACwwDCyACQQRB1PPCACgCACIAQQIgABsRAAAACxDSAQALENIBAAsgCSACIAZBAEEgIAJnaxAFAkAgAkECSQRAIAIhAwwBCyAJQQxqIQFBASEDQQEhBANAAkAgBCADQX9qIgVGDQAgASAJIAVBDGxqQQwQtAFFDQAgAyAERwRAIAZBCGoiCCABQQhqIgUoAgA2AgAgBiABKQIANwMAIAUgCSADQQxsaiIFQQhqIgooAgA2AgAgASAFKQIANwIAIAogCCgCADYCACAFIAYpAwA3AgALIANBAWohAwsgAUEMaiEBIAIgBEEBaiIERw0ACyADIAJNDQBBta3CAEEcQdStwgAQuAEACyAAIAM2AgggACAHNgIEIAAgCTYCACAGQRBqJAALngYBDX8gASgCACEHAn9BACABKAIIIgRFDQAaIAcgBEEUbGohDgJAAkADQCAHIggoAggiCSAIKAIEIgNPBEAgCSABKAIgIgRLDQIgASgCGCENIAkgA2shDyADQQJ0IQtBACEKQQAhBAJAIAMgCUYNAEEFIAsgDWoiBygCACACEDYiA0H/AXFBAkYNACADQQFxRQ0AIAlBAnQgC2tBfGohAyAHQQRqIQVBASEGA0AgBiEEIANFDQFBBSAFKAIAIAIQNiIHQf8BcUECRg0BIARBAWohBiADQXxqIQMgBUEEaiEFIAdBAXENAAsLIAhBFGohBwJAIA8gBGsiBkUNACALIAlBAnQiBWshAyAFIA1qQXxqIQUDQCADRQ0BQQUgBSgCACACEDYiCUH/AXFBAkYgCUEBcUVyDQEgA0EEaiEDIAVBfGohBSAGIApBAWoiCkcNAAsLIAggCCgCBCAEajYCBCAIIAgoAgggCms2AgggCCAILQARIApBAEdyOgARIAcgDkcNAQwDCwsgAyAJEKcBAAsgCSAEEKUBAAsgASgCACEHIAEoAggLIQYgAUEANgIIQQAhBEEAIQICQANAIAQgBk8NASAEQRRsIQkgBCAMakEUbCEKIAchBSAEIQMCQAJAAkADQCADIAZPDQEgBSAJaiIIQQhqIgsoAgAgCEEEaigCAEYNAyACBEAgAyAMaiIEIAZPDQMgBSAKaiIEIAgpAgA3AgAgBEEQaiAIQRBqKAIANgIAIARBCGogCykCADcCAAsgBUEUaiEFIANBAWoiAyAGSQ0ACyADIQQMBAtB1K7CACADIAYQpgEAC0HkrsIAIAQgBhCmAQALIANBAWohBCAMQX9qIQwgAkEBaiECIAhBEWotAABBAkcNAAsgA0EBaiEECyACRSAGIARNckUEQCAHIARBFGxqIgNBACACa0EUbGogAyAGIARrQRRsEJgBGgsgASAGIAJrIgQ2AgggBARAIAZBFGwgAkEUbGshBUEAIQMDQCAHIAM2AgAgB0EUaiEHIANBAWohAyAFQWxqIgUNAAsLIAAgAUEwELkBGguiBwEFfyAAQXhqIgEgAEF8aigCACIDQXhxIgBqIQJBwPPCAAJ/AkACQAJAAkAgA0EBcQ0AIANBA3FFDQEgASgCACIDIABqIQAgASADayIBQZjzwgAoAgBGBEAgAigCBEEDcUEDRw0BQZDzwgAgADYCACACIAIoAgRBfnE2AgQgASAAQQFyNgIEIAAgAWogADYCAA8LIANBgAJPBEAgARBYDAELIAEoAgwiBCABKAIIIgVHBEAgBSAENgIMIAQgBTYCCAwBC0GA8MIAQYDwwgAoAgBBfiADQQN2d3E2AgALAkAgAigCBCIDQQJxBEAgAiADQX5xNgIEIAEgAEEBcjYCBCAAIAFqIAA2AgAMAQsCQCACQZzzwgAoAgBHBEBBmPPCACgCACACRw0BQZjzwgAgATYCAEGQ88IAQZDzwgAoAgAgAGoiADYCACABIABBAXI2AgQgACABaiAANgIADwtBnPPCACABNgIAQZTzwgBBlPPCACgCACAAaiIANgIAIAEgAEEBcjYCBEGY88IAKAIAIAFGBEBBkPPCAEEANgIAQZjzwgBBADYCAAtBuPPCACgCACICIABPDQJBnPPCACgCACIARQ0CAkBBlPPCACgCACIDQSlJDQBBqPPCACEBA0AgASgCACIEIABNBEAgBCABKAIEaiAASw0CCyABKAIIIgENAAsLQcDzwgACf0H/H0Gw88IAKAIAIgBFDQAaQQAhAQNAIAFBAWohASAAKAIIIgANAAsgAUH/HyABQf8fSxsLNgIAIAMgAk0NAkG488IAQX82AgAPCyADQXhxIgQgAGohAAJAIARBgAJPBEAgAhBYDAELIAIoAgwiBCACKAIIIgJHBEAgAiAENgIMIAQgAjYCCAwBC0GA8MIAQYDwwgAoAgBBfiADQQN2d3E2AgALIAEgAEEBcjYCBCAAIAFqIAA2AgAgAUGY88IAKAIARw0AQZDzwgAgADYCAAwBCyAAQYACSQ0BIAEgABBQQcDzwgBBwPPCACgCAEF/aiIANgIAIAANAEGw88IAKAIAIgANAkH/HwwDCw8LIABBA3YiAkEDdEGI8MIAaiEAAn9BgPDCACgCACIDQQEgAkEfcXQiAnEEQCAAKAIIDAELQYDwwgAgAiADcjYCACAACyECIAAgATYCCCACIAE2AgwgASAANgIMIAEgAjYCCA8LQQAhAQNAIAFBAWohASAAKAIIIgANAAsgAUH/HyABQf8fSxsLNgIAC4cGAQZ/AkACQAJAAkAgAkEJTwRAIAIgAxBDIgINAUEADwtBACECIANBzP97Sw0CQRAgA0ELakF4cSADQQtJGyEBIABBfGoiBigCACIHQXhxIQQCQAJAAkACQCAHQQNxBEAgAEF4aiIIIARqIQUgBCABTw0BQZzzwgAoAgAgBUYNAkGY88IAKAIAIAVGDQMgBSgCBCIHQQJxDQYgB0F4cSIJIARqIgQgAU8NBAwGCyABQYACSSAEIAFBBHJJciAEIAFrQYGACE9yDQUMBwsgBCABayICQRBJDQYgBiABIAdBAXFyQQJyNgIAIAEgCGoiASACQQNyNgIEIAUgBSgCBEEBcjYCBCABIAIQLwwGC0GU88IAKAIAIARqIgQgAU0NAyAGIAEgB0EBcXJBAnI2AgAgASAIaiICIAQgAWsiAUEBcjYCBEGU88IAIAE2AgBBnPPCACACNgIADAULQZDzwgAoAgAgBGoiBCABSQ0CAkAgBCABayIDQQ9NBEAgBiAHQQFxIARyQQJyNgIAIAQgCGoiASABKAIEQQFyNgIEQQAhAwwBCyAGIAEgB0EBcXJBAnI2AgAgASAIaiICIANBAXI2AgQgBCAIaiIBIAM2AgAgASABKAIEQX5xNgIEC0GY88IAIAI2AgBBkPPCACADNgIADAQLIAQgAWshAgJAIAlBgAJPBEAgBRBYDAELIAUoAgwiAyAFKAIIIgVHBEAgBSADNgIMIAMgBTYCCAwBC0GA8MIAQYDwwgAoAgBBfiAHQQN2d3E2AgALIAJBEE8EQCAGIAEgBigCAEEBcXJBAnI2AgAgASAIaiIBIAJBA3I2AgQgBCAIaiIDIAMoAgRBAXI2AgQgASACEC8MBAsgBiAEIAYoAgBBAXFyQQJyNgIAIAQgCGoiASABKAIEQQFyNgIEDAMLIAIgACADIAEgASADSxsQuQEaIAAQIQwBCyADEAMiAUUNACABIAAgAyAGKAIAIgFBeHFBBEEIIAFBA3EbayIBIAEgA0sbELkBIAAQIQ8LIAIPCyAAC8EFAQt/IwBBIGsiBiQAAkACQAJAIAEgAkcEQCABQQFqIQMgASwAACIFQX9KDQECfyACIANGBEAgAgwBCyABLQABQT9xIQQgAUECaiIDCyEBIAVBH3EhByAFQf8BcUHfAU0EQCAEIAdBBnRyIQEMAwsCfyABIAJGBEAgAgwBCyABLQAAQT9xIQggAUEBaiIDCyEBIAggBEEGdHIhBCAFQf8BcUHwAUkEQCAEIAdBDHRyIQEMAwsgASACRgR/QQAFIAFBAWohAyABLQAAQT9xCyAHQRJ0QYCA8ABxIARBBnRyciIBQYCAxABHDQILIABBADYCCCAAQgQ3AgAMAgsgBUH/AXEhAQsgBkEIaiACQQNqIgwgA2tBAnZBAWoQnwEgBigCDCEFIAYoAggiCCABNgIAIAZBATYCGCAGIAU2AhQgBiAINgIQAkAgAiADRg0AQQQhB0EBIQEDQCADQQFqIQUCQAJAIAMsAAAiBEF/TARAAn8gAiAFRgRAQQAhCSACDAELIAMtAAFBP3EhCSADQQJqIgULIQMgBEEfcSEKIARB/wFxIg1B3wFLDQEgCSAKQQZ0ciEEIAUhAwwCCyAEQf8BcSEEIAUhAwwBCwJ/IAIgA0YEQEEAIQsgAgwBCyADLQAAQT9xIQsgA0EBaiIFCyEEIAsgCUEGdHIhCSANQfABSQRAIAkgCkEMdHIhBCAFIQMMAQsCfyACIARGBEAgBSEDQQAMAQsgBEEBaiEDIAQtAABBP3ELIApBEnRBgIDwAHEgCUEGdHJyIgRBgIDEAEYNAgsgBigCFCABRgRAIAZBEGogASAMIANrQQJ2QQFqEIIBIAYoAhAhCAsgByAIaiAENgIAIAYgAUEBaiIBNgIYIAdBBGohByACIANHDQALCyAAIAYpAxA3AgAgAEEIaiAGQRhqKAIANgIACyAGQSBqJAALhAUBEH8jAEHwAGsiCSQAIABBiAJqIQ0gCUHIAGohDiABQTJJIQ9BASEEAkADQAJAIAQgAU8EQEEAIQcMAQsgACAEQeQAbGohA0EBIQcDQEEAIQICQANAIAJBBGoiCEEoRg0BIAIgA2ohBSAIIQJBfyAFQVxqKAIAIgggBUFAaygCACIFRyAIIAVIGyIIRQ0ACyAIQf8BcUH/AUYNAgsgA0HkAGohA0EBIQIgBEEBaiIEIAFJIQcgASAERw0ACwwCCyABIARGIQIgDyABIARGcg0BAkACQCAEQX9qIgIgAUkEQCAHQQFxBEAgCkEBaiEKIAlBCGogACACQeQAbGoiAkHkABC5ARogAiAAIARB5ABsIgdqIgJB5AAQuQEaIAIgCUEIakHkABC5ASEFIAAgBBBIIAEgBGsiDEECSQ0DIAVB5ABqIQhBwAAhAgNAIAJBBGoiA0HoAEYNBCACIAVqIQYgAyECQX8gBigCACIDIAZB5ABqKAIAIgZHIAMgBkgbIgNFDQALIANB/wFxQf8BRw0DIAlBCGogBUHkABC5ARogBSAIQeQAELkBIRAgDEEDSQ0CIAcgDWohB0ECIQIDQCACQQFqIBAgAkHkAGxqIQNBACECA0AgAkEEaiIGQShGDQQgAiAHaiELIAIgDmohESAGIQJBfyARKAIAIgYgCygCACILRyAGIAtIGyIGRQ0ACyAGQf8BcUH/AUcNAyADQZx/aiADQeQAELkBGiAHQeQAaiEHIAMhCCICIAxHDQALDAILQcCnwgAgBCABEKYBAAtBsKfCACACIAEQpgEACyAIIAlBCGpB5AAQuQEaCyAKQQVHDQALQQAhAgsgCUHwAGokACACC6UFAQZ/IAAoAgAiCUEBcSIKIARqIQgCQCAJQQRxRQRAQQAhAQwBCyACBEAgAiEHIAEhBQNAIAYgBS0AAEHAAXFBgAFGaiEGIAVBAWohBSAHQX9qIgcNAAsLIAIgCGogBmshCAtBK0GAgMQAIAobIQYCQAJAIAAoAghBAUcEQEEBIQUgACAGIAEgAhC1AQ0BDAILIABBDGooAgAiByAITQRAQQEhBSAAIAYgASACELUBDQEMAgsCQCAJQQhxRQRAIAcgCGshB0EAIQUCQAJAAkBBASAALQAwIgggCEEDRhtBAWsOAwABAAILIAchBUEAIQcMAQsgB0EBdiEFIAdBAWpBAXYhBwsgBUEBaiEFA0AgBUF/aiIFRQ0CIAAoAhggACgCBCAAKAIcKAIQEQEARQ0AC0EBDwtBASEFIABBAToAMCAAQTA2AgQgACAGIAEgAhC1AQ0BIAcgCGshBkEAIQUCQAJAAkBBASAALQAwIgEgAUEDRhtBAWsOAwABAAILIAYhBUEAIQYMAQsgBkEBdiEFIAZBAWpBAXYhBgsgBUEBaiEFAkADQCAFQX9qIgVFDQEgACgCGCAAKAIEIAAoAhwoAhARAQBFDQALQQEPCyAAKAIEIQFBASEFIAAoAhggAyAEIAAoAhwoAgwRAwANASAGQQFqIQYgACgCHCECIAAoAhghAANAIAZBf2oiBkUEQEEADwsgACABIAIoAhARAQBFDQALDAELIAAoAgQhCEEBIQUgACAGIAEgAhC1AQ0AIAAoAhggAyAEIAAoAhwoAgwRAwANACAHQQFqIQYgACgCHCEBIAAoAhghAANAIAZBf2oiBkUEQEEADwsgACAIIAEoAhARAQBFDQALCyAFDwsgACgCGCADIAQgAEEcaigCACgCDBEDAAuVBQINfwJ+IwBB0ABrIgMkAAJAIAEoAggiBK1CFH4iEEIgiKdFBEAgEKciBUF/SgRAAkACQCAFRQRAQQQhCAwBCyAFQQQQ0QEiCEUNAQsgASgCACEKIARFBEBBACEFQQAhBAwECyAKIARBFGxqIQ0gAUEgaigCACEOIAEoAhghD0EAIQUgCiEJA0AgCSgCACEHIANBADYCGCADIAc2AhQgA0G0qsIANgIQIAMgDjYCDCADIA82AgggAyACNgIEIAMgCTYCACADQThqIAMQKAJAIAMtAElBAkYNAAJAA0ACQCADQTBqIANByABqIgsoAgAiBzYCACADQShqIANBQGsiDCkDACIQNwMAIAMgAykDOCIRNwMgIAsgBzYCACAMIBA3AwAgAyARNwM4AkAgBCAGRwRAIAYhBQwBCyAEQQFqIgYgBEkNAyAEQQF0IgcgBiAHIAZLGyIGrUIUfiIQQiCIpw0DIBCnIgdBAEgNAwJ/IARFBEAgB0EEENEBDAELIAggBEEUbEEEIAcQzgELIghFDQEgBiEECyAIIAVBFGxqIgYgAykDODcCACAGQRBqIAsoAgA2AgAgBkEIaiAMKQMANwIAIANBOGogAxAoIAVBAWoiBSEGIAMtAElBAkcNAQwDCwsgB0EEQdTzwgAoAgAiAEECIAAbEQAAAAsQzQEACyANIAlBFGoiCUcNAAsMAwsgBUEEQdTzwgAoAgAiAEECIAAbEQAAAAsQ0gEACxDSAQALIAEoAgQEQCAKECELIAEgBTYCCCABIAQ2AgQgASAINgIAIAUEQCAFQRRsIQVBACEEA0AgCCAENgIAIAhBFGohCCAEQQFqIQQgBUFsaiIFDQALCyAAIAFBMBC5ARogA0HQAGokAAvABQIIfwR+IwBBsAFrIgAkAAJAQYjvwgAoAgANACAAQRBqQeQDQQEQlAEgACkDECEIIABBzABqIgFB5AM2AgAgACAINwJEIABBFjYCQCAAQUBrEHsgAEE4aiIEIABByABqKQMANwMAIAAgACkDQDcDMCAAQUBrQRQQUSAAQSBqIgMgASkCADcDACAAQShqIgUgAEHUAGoiBigCADYCACAAIAApAkQ3AxggAEEIakEUQQAQlAEgACkDCCEIIABBFEEAEJQBIAApAwAhCSABIAQpAwA3AgAgBkEANgIAIABB2ABqIAApAxg3AwAgAEHgAGogAykDADcDACAAQegAaiAFKAIANgIAIABBiAFqQQA2AgAgAEGAAWoiBCAJNwMAIABB+ABqQgA3AwAgAEHwAGogCDcDACAAQewAakEANgIAIABBADYCQCAAIAApAzA3AkQDQCAAQUBrIAJqIgEpAgAhCCABIAJBgO/CAGoiAykCADcCACACQYjvwgBqIgUpAgAhCSACQZDvwgBqIgYpAgAhCiACQZjvwgBqIgcpAgAhCyADIAg3AgAgAUEYaiIDKQIAIQggAyALNwIAIAFBEGoiAykCACELIAMgCjcCACABQQhqIgEpAgAhCiABIAk3AgAgByAINwIAIAYgCzcCACAFIAo3AgAgAkFAayACQSBqIQJBzQBJDQALIAQpAgAhCCAEQcDvwgApAgA3AgBBwO/CACAINwIAIARBCGoiAigCACEBIAJByO/CACgCADYCAEHI78IAIAE2AgAgACgCSCIBRQ0AIABB3ABqKAIAIQQgACgCWCECIAAoAnAhAyAAKAJ0IAAoAoQBIQYgACgCgAEhByAAKAJMBEAgARAhCyACBEAgAEFAayACQQFqEKEBIAQQIQsEQCADECELIAZFDQAgBxAhCyAAQbABaiQAQYDvwgAL/QQBDH8CQAJAAkACQCABKAIAIgRBCGooAgAiAiAEKAIEIgRPBEAgAUEMaigCACIDIAJJDQFBAiEDIAEoAhgiBiACIARrIghPDQQCQCABKAIIIgUgBEECdGoiCiAGQQJ0aiIDIAUgAkECdGoiCUYEQEEAIQUMAQtBACEFA0AgA0EEaiEGIAEoAgQhByABKAIQIQsgAygCACEMQQAhA0EAIQQCfwNAQQEhAiADIAtqLQAAIAwgBxA2Ig1B/wFxQQJHBEAgBCECQQEgDUEBcQ0CGgsgAiEEIANBAWoiA0EDRw0AC0ECQQAgAkEBcRsLIgdBAXFFIAdBAkZyRQRAIAVBAWohBSAGIgMgCUcNAQsLIAEoAhghBgsgASAFIAZqIgI2AhggCCACSQ0CQQIhAyAKIAJBAnRqIgYgCUYNBEEAIQUDQCAGIgJBBGohBiABKAIEIQggASgCECEKIAIoAgAhB0EAIQNBACEEAn8DQEEBIQIgAyAKai0AACAHIAgQNiILQf8BcUECRwRAIAQhAkEBIAtBAXENAhoLIAIhBCADQQFqIgNBA0cNAAtBAkEAIAJBAXEbCyIHQQJHQQAgB0EBcRtFBEAgBUEBaiEFIAYgCUcNAQwFCwsgBQ0DQQIhAwwECyAEIAIQpwEACyACIAMQpQEACyACIAgQpwEACyABKAIYIgMgBWohAiADIAEoAgAiBCgCBCIHaiIJIAVqIQggASgCFCEGQQEhAyAELQARRQRAIAIgBEEIaigCACAHa0khAwsgASACNgIYIABBCjoAECAAIAU2AgwgACAINgIIIAAgCTYCBCAAIAY2AgAgASAGQQFqNgIUCyAAIAM6ABELiQUCC38DfiMAQUBqIgYkACAGIAI2AgxCpcaIocicp/lLIQ9BASEFIAIhBANAIA8gBK1C/wGDhUKzg4CAgCB+IQ8gBUEERkUEQCAGQQxqIAVqIAVBAWohBS0AACEEDAELCyAPQhmIpyIMQf8AcSIEQQh0IARyIgRBEHQgBHIhDSABKAIAIgggD6dxIQUgASgCCCEJIAEoAgQhBAJAA0ACQCAEIAVqKAAAIgsgDXMiB0F/cyAHQf/9+3dqcUGAgYKEeHEiB0UNAANAIAIgCSAHaEEDdiAFaiAIcSIOQShsaigCAEcEQCAHQX9qIAdxIgcNAQwCCwsgCSAOQShsaiIBKQMIIQ8gASADKQMANwMIIAAgDzcDACABQSBqIgIpAwAhDyACIANBGGopAwA3AwAgAUEYaiICKQMAIRAgAiADKQMQNwMAIAFBEGoiASkDACERIAEgAykDCDcDACAAQRhqIA83AwAgACAQNwMQIAAgETcDCAwCCyAKQQRqIgogBWogCHEhBSALIAtBAXRxQYCBgoR4cUUNAAsgBkEoaiADQRhqKQIANwIAIAZBIGogA0EQaikCADcCACAGQRhqIANBCGopAgA3AgAgBiADKQIANwIQIAQgASAPEJYBIgVqLQAAQQFxIQMgASgCDCIHIANFckUEQCAGQTBqIAEQEiABIA8QlgEhBSABKAIAIQggASgCCCEJIAEoAgwhByABKAIEIQQLIAEgByADazYCDCAEIAVqIAxB/wBxIgM6AAAgCCAFQXxqcSAEakEEaiADOgAAIAkgBUEobGoiAyACNgIAIANBBGogBkEMakEkELkBGiABIAEoAhBBAWo2AhAgAEECOgAcCyAGQUBrJAAL2QQBEX8jAEEQayIIJAACQCABKAIEIgJFBEAgAEEANgIADAELIAEgAkF/ajYCBCABIAEoAgAiBEEEajYCACABKAIIIQcCQAJAAkACQAJ/IAIgByAHIAJLGyABKAIQIgVFDQAaA0AgAiAGaiIDQQFGDQIgASAEQQhqNgIAIAEgBSAGakF/ajYCECABIANBfmo2AgQgBEEEaiEEIAUgBkF/aiIGag0ACyACIAZqIgIgByAHIAJLGwsiB0EBaiIGQQJPBEADQCAHIAZBf2oiAkkNBSABKAIMIQkgCEKlxoihyJyn+Us3AwggBCACIAhBCGoQcCAIKAIIIgVBGXYiA0EIdCADciIDQRB0IANyIQ8gAkECdCEQIAkoAgAiCyAFcSEFIAkoAgQhEUEAIQwDQCAFIBFqKAAAIg0gD3MiA0F/cyADQf/9+3dqcUGAgYKEeHEiAwRAIAkoAgghDgNAIA4gA2hBA3YgBWogC3EiEkEYbGoiCigCCCACRgRAIAQgCigCACIKRg0HIAQgCiAQELQBRQ0HCyADQX9qIANxIgMNAAsLIAxBBGoiDCAFaiALcSEFIA0gDUEBdHFBgIGChHhxRQ0ACyACIQYgAkEBSw0ACwsgB0UNAiAAQQE2AgQgACAENgIAIABBDGpBATYCACAAQQhqIAQ2AgAMBAsgAEEANgIADAMLIAEgBkF+ajYCECAAIAI2AgQgACAENgIAIABBDGogDiASQRhsaiIBKAIUNgIAIABBCGogASgCDDYCAAwCC0EBQQAQpQEACyACIAcQpQEACyAIQRBqJAALwQQCDX8BfiMAQSBrIgckACABQX9qIQwgAUEySSENQQEhAwJAAkACfwNAAn9BACADIAFPDQAaQQEgACADQQxsaiIEQQMgBEF0akEDEJMBQf8BcUH/AUYNABoDQEEBIAMgDEYNAxogA0EBaiEDIARBDGoiAkEDIARBAxCTASACIQRB/wFxQf8BRw0ACyADIAFJCyABIANGIA0gASADRnINARogA0F/aiICIAFPDQJFDQMgCkEBaiEKIAAgA0EMbGoiBSkCACEPIAUgACACQQxsaiICKQIANwIAIAIgDzcCACAFQQhqIgYoAgAhBCAGIAJBCGoiAigCADYCACACIAQ2AgAgACADEE0CQCABIANrIgtBAkkNACAFQQxqIghBAyAFQQMQkwFB/wFxQf8BRw0AIAUpAgAhDyAFIAgpAgA3AgAgB0EYaiIOIAYoAgA2AgAgBiAIQQhqKAIANgIAIAcgDzcDEAJAIAtBA0kNACAFQRhqIglBAyAHQRBqQQMQkwFB/wFxQf8BRw0AQQMhAkECIQYDQCAGQQxsIAVqIghBdGoiBCAJKQIANwIAIARBCGogCUEIaigCADYCACACIAtPDQEgAkEBaiIEIAJJDQEgAkEMbCEJIAIhBiAEIQIgBSAJaiIJQQMgB0EQakEDEJMBQf8BcUH/AUYNAAsLIAggBykDEDcCACAIQQhqIA4oAgA2AgALIApBBUcNAAtBAAsgB0EgaiQADwtBsKfCACACIAEQpgEAC0HAp8IAIAMgARCmAQAL2QQCD38BfiMAQTBrIgQkACAEQqXGiKHInKf5SzcDCCACKAIAIgogAigCCCIMIARBCGoQcCAEKQMIIhNCGYinIg5B/wBxIgVBCHQgBXIiBUEQdCAFciEPIAxBAnQhECABKAIAIgggE6dxIQcgASgCCCEJIAEoAgQhBQJAA0ACQCAFIAdqKAAAIg0gD3MiBkF/cyAGQf/9+3dqcUGAgYKEeHEiBkUNAANAAkAgCSAGaEEDdiAHaiAIcSIRQRhsaiILKAIIIAxGBEAgCiALKAIAIgtGDQEgCiALIBAQtAFFDQELIAZBf2ogBnEiBg0BDAILCyAAIAkgEUEYbGoiASkCDDcCACABIAMpAgA3AgwgAEEIaiABQRRqIgAoAgA2AgAgACADQQhqKAIANgIAIAIoAgRFDQIgChAhDAILIBJBBGoiEiAHaiAIcSEHIA0gDUEBdHFBgIGChHhxRQ0ACyAEQRBqIgcgAkEIaigCADYCACAEQRxqIANBCGooAgA2AgAgBCACKQIANwMIIAQgAykCADcCFCAFIAEgExCWASICai0AAEEBcSEDIAEgASgCDCIGIANFcgR/IAYFIARBIGogARAOIAEgExCWASECIAEoAgAhCCABKAIIIQkgASgCBCEFIAEoAgwLIANrNgIMIAIgBWogDkH/AHEiAzoAACAIIAJBfGpxIAVqQQRqIAM6AAAgCSACQRhsaiICQRBqIARBGGopAwA3AgAgAkEIaiAHKQMANwIAIAIgBCkDCDcCACABIAEoAhBBAWo2AhAgAEEANgIACyAEQTBqJAALpQQBFH8CQCACQQF1IgggAkkEQEEAIAAoAhhrIRQgACgCECISIAAoAgRqQX9qIQkgAEEMaiAAQQhqIAAoAgBBAUYbIRUgAiERA0ACQAJAIAEgCEEYbGoiFigCBCIHIARrRQ0AIBQgBGshF0EAIARrIQsgByEMIAkhDSASIQ4gBCEFAkACQAJAA0BBfyEPIA4gF2pFDQMgCyAOakF/aiAVKAIAIg9PDQEgCyAMakF/aiAHTw0CIAsgDWotAAAgFigCACALaiAMakF/ai0AAGsiDw0DIA1Bf2ohDSAOQX9qIQ4gBUEBaiEFIAsgDEF/aiIMag0ACyAFIQQMAwtBlM3CACAEQX9zIA5qIA8QpgEAC0GkzcIAIARBf3MgDGogBxCmAQALIAUhBAwBC0EAIQ8LIAogBCAPQQBIIgcbIQoCQCAIIBEgBxsiESAGIAggBxsiBmsiDUECTg0AIBMgBiARRiAGQQBKcnJBASETQQFxRQ0AAn8DQCAGIAJPDQUCQCAKIAEgBkEYbGoiCSgCBCIFTwRAIAAgEiAFayIENgIQIAkoAhAiBUUNASAFIAAgAyAJQRRqKAIAKAIMEQMAIAAgBDYCEA0BC0EAIAkoAggiBkEASA0CGgwBCwsgASAGQRhsaigCDAsPCyAKIAQgECAHGyIQIAogEEkbIQQgDUEBdSAGaiIIIAJJDQALC0GEzcIAIAggAhCmAQALQbTNwgAgBiACEKYBAAvPBQIEfwF+QQEgASgCGEEnIAFBHGooAgAoAhARAQBFBEBBAiECAkACQAJAIAAoAgAiAEF3aiIEQR5LBEAgAEHcAEcNAQwCC0H0ACEDAkACQCAEQQFrDh4BAgIAAgICAgICAgICAgICAgICAgICAgIDAgICAgMEC0HyACEDDAMLQe4AIQMMAgsCQAJ+QYCGwAAgABBZRQRAAkACQCAAQYCABE8EQCAAQYCACEkNASAAQZD8R2pBkPwLSSAAQeKLdGpB4o0sSXIgAEGfqHRqQZ8YSSAAQd7idGpBDklyciAAQf7//wBxQZ7wCkYgAEGpsnVqQSlJcnINAiAAQcuRdWpBCksNBQwCCyAAQfiRwABBKUHKksAAQaUCQe+UwABBugIQSUUNAQwECyAAQamXwABBI0Hvl8AAQaYBQZWZwABBmAMQSQ0DCyAAQQFyZ0ECdkEHc61CgICAgNAAhAwBCyAAQQFyZ0ECdkEHc61CgICAgNAAhAshBkEDIQIMAQtBASECCyAAIQMLA0AgAiEEQdwAIQBBASECAkACQAJAAkAgBEEBaw4DAgMAAQsCQAJAAkACQAJAIAZCIIinQf8BcUEBaw4FBAMCAQAFCyAGQv////+PYINCgICAgMAAhCEGQQMhAgwGCyAGQv////+PYINCgICAgDCEIQZB9QAhAEEDIQIMBQsgBkL/////j2CDQoCAgIAghCEGQfsAIQBBAyECDAQLIAMgBqciAkECdEEccXZBD3EiAEEwciAAQdcAaiAAQQpJGyEAIAIEQCAGQn98Qv////8PgyAGQoCAgIBwg4QhBkEDIQIMBAsgBkL/////j2CDQoCAgIAQhCEGQQMhAgwDCyAGQv////+PYIMhBkH9ACEAQQMhAgwCCyABKAIYQScgASgCHCgCEBEBAA8LQQAhAiADIQALIAEoAhggACABKAIcKAIQEQEARQ0ACwsLhwUBBH8gACABaiECAkACQAJAIAAoAgQiA0EBcQ0AIANBA3FFDQEgACgCACIDIAFqIQEgACADayIAQZjzwgAoAgBGBEAgAigCBEEDcUEDRw0BQZDzwgAgATYCACACIAIoAgRBfnE2AgQgACABQQFyNgIEIAIgATYCAA8LIANBgAJPBEAgABBYDAELIAAoAgwiBCAAKAIIIgVHBEAgBSAENgIMIAQgBTYCCAwBC0GA8MIAQYDwwgAoAgBBfiADQQN2d3E2AgALIAIoAgQiA0ECcQRAIAIgA0F+cTYCBCAAIAFBAXI2AgQgACABaiABNgIADAILAkAgAkGc88IAKAIARwRAQZjzwgAoAgAgAkcNAUGY88IAIAA2AgBBkPPCAEGQ88IAKAIAIAFqIgE2AgAgACABQQFyNgIEIAAgAWogATYCAA8LQZzzwgAgADYCAEGU88IAQZTzwgAoAgAgAWoiATYCACAAIAFBAXI2AgQgAEGY88IAKAIARw0BQZDzwgBBADYCAEGY88IAQQA2AgAPCyADQXhxIgQgAWohAQJAIARBgAJPBEAgAhBYDAELIAIoAgwiBCACKAIIIgJHBEAgAiAENgIMIAQgAjYCCAwBC0GA8MIAQYDwwgAoAgBBfiADQQN2d3E2AgALIAAgAUEBcjYCBCAAIAFqIAE2AgAgAEGY88IAKAIARw0BQZDzwgAgATYCAAsPCyABQYACTwRAIAAgARBQDwsgAUEDdiICQQN0QYjwwgBqIQECf0GA8MIAKAIAIgNBASACQR9xdCICcQRAIAEoAggMAQtBgPDCACACIANyNgIAIAELIQIgASAANgIIIAIgADYCDCAAIAE2AgwgACACNgIIC80EAgl/AX4jAEEQayIEJAAgBCABNgIAIAEoAhghAyAEIAEtABw6AAcgBCAENgIMIAQgBEEHajYCCAJAAkAgAS0AHUUEQCABQQxqIQggA0EBdCEJA0AgASgCBCEFIAEoAgAhAgJAA0AgAiAFRg0BIAEgAkEEaiIHNgIAIAIoAgAgASABKAIIIgpBAWo2AgggByECRQ0ACyABKAIUIgIgASgCEEYEQCACQQFqIgUgAkkNBSACQQF0IgYgBSAGIAVLGyIFQf////8BcSAFRw0FIAVBA3QiBkEASA0FAn8gAkUEQCAGQQQQ0QEMAQsgCCgCACACQQN0QQQgBhDOAQsiAkUNBCABIAU2AhAgASACNgIMIAEoAhQhAgsgASgCDCACQQN0aiICIAdBfGo2AgQgAiAKNgIAIAEgASgCFEEBaiICNgIUIAIgCUkNASAEQQhqIAgQCiABKAIUIANJDQEgASADNgIUDAELCyAEQQhqIAgQCiABKAIUIgIgA08EQCABIAM2AhQgAyECCyACQQF2IgcEQCACQQN0IAgoAgAiAmpBeGohAwNAIAIpAgAhCyACIAMpAgA3AgAgAyALNwIAIAJBCGohAiADQXhqIQMgB0F/aiIHDQALCyABQQE6AB0LAkAgAUEUaigCACICRQRAQQAhAgwBCyABIAJBf2oiAjYCFCABKAIMIAJBA3RqIgEoAgQhAiABKAIAIQELIAAgAjYCBCAAIAE2AgAgBEEQaiQADwsgBkEEQdTzwgAoAgAiAEECIAAbEQAAAAsQzQEAC5gEAQh/IwBBEGsiAyQAIAMgADYCACAAKAIUIQIgAyAALQAYOgAHIAMgAzYCDCADIANBB2o2AggCQAJAIAAtABlFBEAgAEEIaiEHIAAoAgAiBCAAKAIERwRAIAJBAXQhCCAAQRBqKAIAIQEDQCAAIARBPGo2AgAgACgCDCABRgRAIAFBAWoiBiABSQ0FIAFBAXQiBSAGIAUgBksbIgZB/////wNxIAZHDQUgBkECdCIFQQBIDQUCfyABRQRAIAVBBBDRAQwBCyAHKAIAIAFBAnRBBCAFEM4BCyIBRQ0EIAAgBjYCDCAAIAE2AgggACgCECEBCyAAKAIIIAFBAnRqIAQ2AgAgACAAKAIQQQFqIgE2AhACQCABIAhJDQAgA0EIaiAHEAwgACgCECIBIAJJDQAgACACNgIQIAIhAQsgACgCACIEIAAoAgRHDQALCyADQQhqIAcQDCAAQRBqKAIAIgEgAk8EQCAAIAI2AhAgAiEBCyABQQF2IgUEQCABQQJ0IAcoAgAiAWpBfGohBANAIAEoAgAhAiABIAQoAgA2AgAgBCACNgIAIAFBBGohASAEQXxqIQQgBUF/aiIFDQALCyAAQQE6ABkLAn9BACAAQRBqKAIAIgJFDQAaIAAgAkF/aiICNgIQIAAoAgggAkECdGooAgALIANBEGokAA8LIAVBBEHU88IAKAIAIgBBAiAAGxEAAAALEM0BAAubBAIPfwF+IwBBIGsiAyQAIANCpcaIocicp/lLNwMQIAEoAgAiByABKAIIIgsgA0EQahBwIAMpAxAiEkIZiKciDUH/AHEiBEEIdCAEciIEQRB0IARyIQ4gC0ECdCEPIAAoAgAiCCASp3EhBSAAKAIIIQkgACgCBCEEAkADQAJAIAQgBWooAAAiDCAOcyIGQX9zIAZB//37d2pxQYCBgoR4cSIGRQ0AA0ACQCAJIAZoQQN2IAVqIAhxIhBBBHRqIgooAgggC0YEQCAHIAooAgAiCkYNASAHIAogDxC0AUUNAQsgBkF/aiAGcSIGDQEMAgsLIAkgEEEEdGogAjoADCABKAIERQ0CIAcQIQwCCyARQQRqIhEgBWogCHEhBSAMIAxBAXRxQYCBgoR4cUUNAAsgA0EIaiIGIAFBCGooAgA2AgAgAyABKQIANwMAIAQgACASEJYBIgFqLQAAQQFxIQUgACAAKAIMIgcgBUVyBH8gBwUgA0EQaiAAEBMgACASEJYBIQEgACgCACEIIAAoAgghCSAAKAIEIQQgACgCDAsgBWs2AgwgASAEaiANQf8AcSIFOgAAIAggAUF8anEgBGpBBGogBToAACAJIAFBBHRqIgFBCGogBigCADYCACABIAMpAwA3AgAgASACOgAMIAEgAy8AEDsADSABQQ9qIANBEmotAAA6AAAgACAAKAIQQQFqNgIQCyADQSBqJAAL6gMBB38jAEEgayIDJAACQAJAIAAoAhAiASAAKAIUTw0AIAAoAgQhAiADIABBDGogAEEIaiAAKAIAQQFGGygCACIENgIEIAMgAjYCACADIAE2AgggAyAENgIMAkAgAUUgASAERnJFBEAgBCABTQ0BIAEgAmosAABBv39MDQELIAEgBEYNAQJAIAEgAmoiBSwAACIHQX9KBEAgB0H/AXEhAQwBCwJ/IAIgBGoiASAFQQFqRgRAIAEMAQsgBS0AAUE/cSEGIAVBAmoLIQIgB0EfcSEFIAdB/wFxQd8BTQRAIAYgBUEGdHIhAQwBCwJ/IAEgAkYEQCABIQRBAAwBCyACQQFqIQQgAi0AAEE/cQsgBkEGdHIhBiAHQf8BcUHwAUkEQCAGIAVBDHRyIQEMAQtBACECIAEgBEcEfyAELQAAQT9xBUEACyAFQRJ0QYCA8ABxIAZBBnRyciIBQYCAxABGDQMLIAFBsAhJIAFBzwhLcg0BIAFBsAhrIgRBA3YiAUEESQRAQQAhAiABQfzOwgBqLQAAQQEgBEEHcXRxRQ0DIAAQjwFBASECDAMLQfTLwgAgAUEEEKYBAAsgAyADQQxqNgIYIAMgA0EIajYCFCADIAM2AhAgA0EQahDGAQALQQAhAgsgA0EgaiQAIAIL5QMBB38jAEEgayICJAACQAJAAkAgACgCECIBIAAoAhRPDQAgACgCBCEDIAIgAEEMaiAAQQhqIAAoAgBBAUYbKAIAIgQ2AgQgAiADNgIAIAIgATYCCCACIAQ2AgwgAUUgASAERnJFBEAgBCABTQ0DIAEgA2osAABBv39MDQMLIAEgBEYNAAJAIAEgA2oiBSwAACIHQX9KBEAgB0H/AXEhAQwBCwJ/IAMgBGoiASAFQQFqRgRAIAEMAQsgBS0AAUE/cSEGIAVBAmoLIQMgB0EfcSEFIAdB/wFxQd8BTQRAIAYgBUEGdHIhAQwBCwJ/IAEgA0YEQCABIQRBAAwBCyADQQFqIQQgAy0AAEE/cQsgBkEGdHIhAyAHQf8BcUHwAUkEQCADIAVBDHRyIQEMAQtBACEGIAEgBEcEfyAELQAAQT9xBUEACyAFQRJ0QYCA8ABxIANBBnRyciIBQYCAxABGDQELIAFBsAhJIAFBzwhLckUEQCABQbAIayIEQQN2IgFBBE8NAkEAIQYgAUH8zsIAai0AAEEBIARBB3F0cQ0BCyAAEI8BQQEhBgsgAkEgaiQAIAYPC0H0zMIAIAFBBBCmAQALIAIgAkEMajYCGCACIAJBCGo2AhQgAiACNgIQIAJBEGoQxgEAC/sDAQZ/IwBBMGsiAyQAIANBCGogAEEMaiIFIABBCGoiBiAAKAIAQQFGGygCABCwASADQQA2AhggAyADKQMINwMQIAAoAgAhByAAKAIEIQQCQAJAAkAgAUUNACAFIAYgB0EBRhsoAgAiCCABRg0AIAggAU0NASABIARqLAAAQb9/TA0BCyACRSAFIAYgBxsoAgAiBSACRnJFBEAgBSACTQ0CIAIgBGosAABBv39MDQILIANBEGpBACABEJABIAMgAygCGCIGIAFqIgc2AhggBiADKAIQaiAEIAEQuQEaIANBEGogB0EAEJABIAMgAygCGCIGIgc2AhggBiADKAIQakH0y8IAQQAQuQEaIANBEGogByAEIAVqIAIgBGoiBWsiBBCQASADIAMoAhgiBiAEajYCGCAGIAMoAhBqIAUgBBC5ARogACABIAJrIgUgACgCFGo2AhQCQAJAIAAoAhAiBCACSQRAIAQgAUsNAQwCCyAEIAVqIQELIAAgATYCEAsgAEEEaiEBIANBKGoiAiADQRhqKAIANgIAIAMgAykDEDcDIAJAIAAoAgBFDQAgAEEIaigCAEUNACABKAIAECELIABBATYCACABIAMpAyA3AgAgAUEIaiACKAIANgIAIANBMGokAA8LIAQgCEEAIAEQGgALIAQgBUEAIAIQGgALkwQBAX9BASEDAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAEH/AXFBAWsOBwABAgMEBQYMC0EAIQMgAUG/AUsNCyABQQZ2QdCswABqLQAAIgBBAU0EQCAAQQN0QdiswABqKQMAQgEgAUE/ca2Gg0IAUg8LQZCswAAgAEECEKYBAAsgAUF3akEFSQ0JIAFBIEYNCkEAIQMgAUGAAUkgAUG/4ABLcg0KIAFBBnZBzarAAGotAAAiAEEFTQRAIABBA3RBoKzAAGopAwBCASABQT9xrYaDQgBSDwtBkKzAACAAQQYQpgEAC0EAIQMgAUGQwABMBEAgAUFfaiIAQQ1NDQUgAUFGaiIAQQVLDQogAEECaw4DCgoKCQsgAUHvv39qQQRJDQggAUHEv39qIgBBDUtBASAAdEGB8ABxRXINBwwIC0EAIQMgAUFfcUG/f2pBGkkNCEEBIQMgAUGAAUkNCEHYwcAAIAEQWUEBcw8LQQAhAyABQV9xQb9/akEaSQ0HIAFBgAFJDQNB2MHAACABEFkNB0EBIQNB6KzAACABEFlFDQcMBAtBAiEDIAIgARBWQf8BcSIAQQhGDQYgAEEGRg8LQQIhAyACIAEQVkH/AXEiAEEIRg0FIABBB0YPCyAAQQFrDgoEBAQEAwQDAwQEAwtBASEDIAFBUGpBCk8NAwtBAA8LIAFBpsAARw0BC0EBDwsgAwvuAwEHfyMAQTBrIgQkACABKAJkRQRAAkAgAUF/NgJkIAEoAnQNACABQegAaiEHIAFBgAFqQQA2AgAgAUHwAGpCgICAgHA3AgAgBEEANgIoIAQgAUFAazYCJCAEQQI2AiAgBCADNgIcIAQgAjYCGCABQfgAaiEIIARBCGogBEEYahAqIAQoAggiBQRAA0AgBCgCECEGIAQoAhQhCSAHIAUgBSAEKAIMIgpBAnRqELcBIAggBiAGIAlBAnRqELcBIAkgCmsiBgRAA0AgASgCcCIFIAEoAmxGBEAgByAFQQEQggEgASgCcCEFCyABKAJoIAVBAnRqQQA2AgAgASABKAJwQQFqNgJwIAZBf2oiBg0ACwsgBEEIaiAEQRhqECogBCgCCCIFDQALCwJAAkAgAyABKAKAAUYEQCABKAJ4IgUgAkYNASAFIAIgA0ECdBC0AUUNAQsgBEEYaiAHEKoBIARBCGogCBCqASAEQSxqIARBEGooAgA2AgAgBCAEKQMINwIkIABBEGogBEEoaikDADcCACAAQQhqIARBIGopAwA3AgAgACAEKQMYNwIADAELIABBADYCAAsgASABKAJ0QQFqNgJ0IAEgASgCZEEBajYCZCAEQTBqJAAPCwtBwsvCAEEQIARBGGpBuK/CABCVAQALygMCDn8BfiABQX9qIQsgAEEMaiEMIAFBMkkhDUEBIQMCQAJAAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0EDdCIGaiICQXxqKAIAKAIAIAIoAgQoAgAiAkkNABogBiAMaiEFA0BBASADIAtGDQMaIANBAWohAyACIAUoAgAoAgAiAk8gBUEIaiEFDQALIAMgAUkLIAEgA0YgDSABIANGcg0BGiADQX9qIgIgAU8NAkUNAyAIQQFqIQggACACQQN0aiICKQIAIRAgAiAAIANBA3RqIgQpAgA3AgAgBCAQNwIAIAAgAxBpAkAgASADayIKQQJJDQAgBCgCBCIJKAIAIARBDGooAgAoAgBPDQAgBCgCACEOIAQgBCkCCDcCACAEQQhqIQcCQCAKQQNJDQAgCSgCACAEQRRqKAIAKAIATw0AQQMhAkECIQUDQCAEIAVBA3RqIgdBeGogBykCADcCACACIApPDQEgAkEBaiIGIAJJDQEgAkEDdCEPIAIhBSAGIQIgCSgCACAEIA9qKAIEKAIASQ0ACwsgByAJNgIEIAcgDjYCAAsgCEEFRw0AC0EACw8LQbCnwgAgAiABEKYBAAtBwKfCACADIAEQpgEAC90DAQV/AkAgAUEgaigCACIERQ0AIARBAnQhBSABKAIYIgYhAwNAAkAgAygCACICQb9/akEaSQ0AIAJBgAFPBEBB+OXAACACEFkNAQsgA0EEaiEDIAVBfGoiBQ0BDAILCyAEQQJ0IQUDQCAGKAIAIgMhBCAGIAMgA0EAQbcFIANB7DxJGyICIAJB2wJqIgIgAkEEdEHQ+MAAaigCACADSxsiAiACQa4BaiICIAJBBHRB0PjAAGooAgAgA0sbIgIgAkHXAGoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBK2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBFmoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBC2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBBWoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBA2oiAiACQQR0QdD4wABqKAIAIANLGyICIAJBAWoiAiACQQR0QdD4wABqKAIAIANLGyICIAJBAWoiAiACQQR0QdD4wABqKAIAIANLG0EEdCICQdD4wABqKAIARgRAIAJB1PjAAGooAgAhBAsgBCAEQYCAxABGGzYCACAGQQRqIQYgBUF8aiIFDQALCyAAIAFBMBC5ARoLyAMCC38BfiMAQRBrIgYkACAGIAE2AgBCpcaIocicp/lLIQ5BASEEIAEhAwNAIA4gA61C/wGDhUKzg4CAgCB+IQ4gBEEERwRAIAQgBmogBEEBaiEELQAAIQMMAQsLIA5CGYinIgxB/wBxIgNBCHQgA3IiA0EQdCADciENIAAoAgAiByAOp3EhBCAAKAIIIQkgACgCBCEDAkADQAJAIAMgBGooAAAiCiANcyIFQX9zIAVB//37d2pxQYCBgoR4cSIFRQ0AA0AgASAJIAVoQQN2IARqIAdxQQN0aiILKAIARwRAIAVBf2ogBXEiBQ0BDAILCyALLQAEGiALIAI6AAQMAgsgCEEEaiIIIARqIAdxIQQgCiAKQQF0cUGAgYKEeHFFDQALIAMgACAOEJYBIgRqLQAAQQFxIQUgACgCDCIIIAVFckUEQCAGIAAQECAAIA4QlgEhBCAAKAIAIQcgACgCCCEJIAAoAgwhCCAAKAIEIQMLIAAgCCAFazYCDCADIARqIAxB/wBxIgU6AAAgByAEQXxqcSADakEEaiAFOgAAIAkgBEEDdGoiAyACOgAEIAMgATYCACAAIAAoAhBBAWo2AhALIAZBEGokAAuqAwEEfyMAQUBqIgMkAAJAAkACQCABKAIIIgVBAU0EQCAFQQFrDQMgA0EIaiACIAEoAgwiBCABQRRqKAIAEGUgAUEMaiEFIAMoAggNASABQSBqKAIAIQQMAgsQ0AEACyADQSBqIgYgA0EQaigCADYCACADIAMpAwg3AxggA0EoaiADQRhqEKoBIAFBEGooAgAEQCAEECELIAUgAykDKDcCACAFQQhqIANBMGoiBCgCADYCACAEIAYoAgA2AgAgAyADKQMYNwMoIAFBGGohBCABQRxqKAIABEAgBCgCABAhCyAEIAMpAyg3AgAgBEEIaiADQTBqKAIANgIAIAEoAgBBCGogAUEgaigCACIENgIACyADQShqIAIgASgCGCICIAQQNyADKAIoBEAgAUEQaigCAARAIAUoAgAQIQsgBSADKQMoNwIAIAVBCGogA0EwaigCADYCACABQRxqKAIABEAgAhAhCyABQRhqIgJBCGogA0E0aiIFQQhqKAIANgIAIAIgBSkCADcCACABKAIAQQhqIAEoAiA2AgALCyAAIAFBMBC5ARogA0FAayQAC7IDAQ5/IAFBf2ohDCAAQQRqIQ0gAUEySSEOQQEhAwJ/A0ACf0EAIAMgAU8NABpBASAAIANBAnQiBGoiBygCACICIAdBfGooAgBJDQAaIAQgDWohBQNAQQEgAyAMRg0DGiADQQFqIQMgBSgCACIEIAJPIAVBBGohBSAEIQINAAsgAyABSQshBSABIANGIA4gASADRnINARoCQAJAAkACQCADQX9qIgIgAUkEQCAFRQ0BIAlBAWohCSAAIAJBAnRqIgIoAgAhBCACIAAgA0ECdGoiBigCADYCACAGIAQ2AgAgACADEHEgASADayILQQJJDQQgBigCBCICIAYoAgAiCk8NBCAGQQRqIQQgBiACNgIAIAtBA0kNAyAGKAIIIgggCk8NAyAGQQhqIQVBAyECQQIhBwNAIAdBAnQgBmpBfGogCDYCACACIAtPDQMgAkEBaiIIIAJJDQMgBSEEIAYgAkECdGoiDyEFIAIhByAIIQIgDygCACIIIApJDQALDAMLQbCnwgAgAiABEKYBAAtBwKfCACADIAEQpgEACyAFIQQLIAQgCjYCAAsgCUEFRw0AC0EACwuyAwENfyABQX9qIQsgAEEEaiEMIAFBMkkhDUEBIQMCQAJAAn8DQAJ/QQAgAyABTw0AGkEBIAAgA0ECdCIHaiIEKAIAIgIgBEF8aigCABCxAQ0AGiAHIAxqIQYDQEEBIAMgC0YNAxogA0EBaiEDIAYoAgAiBCACELEBIAZBBGohBiAEIQJFDQALIAMgAUkLIAEgA0YgDSABIANGcg0BGiADQX9qIgIgAU8NAkUNAyAIQQFqIQggACACQQJ0aiIEKAIAIQIgBCAAIANBAnRqIgUoAgA2AgAgBSACNgIAIAAgAxB8AkAgASADayIKQQJJDQAgBSgCBCICIAUoAgAiCRCxAUUNACAFQQRqIQYgBSACNgIAAkAgCkEDSQ0AIAUoAgggCRCxAUUNACAFQQhqIQRBAyECQQIhBwNAIAdBAnQgBWpBfGogBCIGKAIANgIAIAIgCk8NASACQQFqIgQgAkkNASACQQJ0IQ4gAiEHIAQhAiAFIA5qIgQoAgAgCRCxAQ0ACwsgBiAJNgIACyAIQQVHDQALQQALDwtBsKfCACACIAEQpgEAC0HAp8IAIAMgARCmAQALvAMCC38DfiMAQSBrIgUkAAJAAkACQCABQQJJDQAgAEEoaiIDKAIAIAAoAggiCE8NACAAKQMAIQ0gACAAKQMgNwMAIABBFGopAgAhDiAAKQIMIQ8gAEEQaiAAQTBqKQMANwMAIABBCGogAykDADcDACAAQRxqKAIAIQMgAEEYaiAAQThqKQMANwMAIAVBEGoiCiAONwMAIAVBGGoiCyADNgIAIAUgDzcDCCAAQSBqIQJBAyEGQQEhBwJAIAFBA0kNAEECIQkDQCAJIgMgAU8NAyAAIANBBXRqIgQoAgggCE8NASADQX9qIgIgAU8NBCAAIAJBBXRqIgIgBCkDADcDACACQRhqIARBGGopAwA3AwAgAkEQaiAEQRBqKQMANwMAIAJBCGogBEEIaikDADcDACAGIAFJBEAgBiEJIAZBAWoiAiAGSSACIQYgAyEHIAQhAg0CDAELCyADIQcgBCECCyACIA03AwAgACAHQQV0aiAINgIIIAJBHGogCygCADYCACACQRRqIAopAwA3AgAgAiAFKQMINwIMCyAFQSBqJAAPC0HQrMIAIAMgARCmAQALQeCswgAgAiABEKYBAAvGAwIIfwF+IwBBIGsiAiQAAkAQwAEiBkUNAAJAIAYoAgBFBEAgBkF/NgIAIAZBBGogARBVIgVFDQEgBSgCCCIEBEAgBEEEdCEHIAUoAgBBDGohAQNAIAEoAgAgA2ohAyABQRBqIQEgB0FwaiIHDQALCyACQQhqIAMgBGoQsAEgAkEANgIYIAIgAigCDDYCFCACIAIoAggiBDYCECAFKAIIIgEEQCABQQR0IQcgBSgCAEEMaiEDQQAhAQNAIANBeGooAgAhCCACQRBqIAEgAygCACIFEJABIAIgBSACKAIYIglqIgE2AhggCSACKAIQIgRqIAggBRC5ARogAigCFCABRgRAIAJBEGogAUEBEJABIAIoAhAhBCACKAIYIQELIAEgBGpBADoAACACIAFBAWoiATYCGCADQRBqIQMgB0FwaiIHDQALCyACKQIUIQogBiAGKAIAQQFqNgIAIARFDQIgAiAKNwIUIAIgBDYCECACIAJBEGoQjQEgACACKQMANwIAIAJBIGokAA8LQcLLwgBBECACQRBqQdTLwgAQlQEAC0G57MIAQStB5OzCABC4AQALQfzKwgBBxgAgAkEQakHky8IAEJUBAAufAwEDfyMAQdAAayIFJAAgBSACNgJAIAUgAjYCPCAFIAE2AjggBUEQaiAFQThqEI0BIAUoAhQhByAFKAIQIQYgBSAENgJAIAUgBDYCPCAFIAM2AjggBUEIaiAFQThqEI0BIAUoAgwhBCAFKAIIIQMQwQEiAQRAAkAgASgCAEUEQCABQX82AgAgAUEEaiAAEFQiAEUNASAFQRhqIAYgBiAHahAjIAVBKGogAyADIARqECMgBUFAayAFQSBqKAIANgIAIAVBzABqIAVBMGooAgA2AgAgBSAFKQMYNwM4IAUgBSkDKDcCRCAAQZgBaiECIAAoApwBBEAgAigCABAhCyAAKAKoAQRAIAAoAqQBECELIAIgBSkDODcCACACQRBqIAVByABqKQMANwIAIAJBCGogBUFAaykDADcCACABIAEoAgBBAWo2AgAgBARAIAMQIQsgBwRAIAYQIQsgBUHQAGokAA8LQcLLwgBBECAFQThqQcyowgAQlQEAC0G57MIAQStB5OzCABC4AQALQfzKwgBBxgAgBUE4akHcqMIAEJUBAAutAwIKfwJ8IAIoAgQiByABQRhqKAIAaiIEIAMoAgQiBUsEQCADQQhqKAIAIQggASsDACEOAkAgAkEIaigCACILIAdrIglFBEAMAQsgCCAFayIGRQRAIA4hD0QAAAAAAAAAACEODAELIA4gDiAJuCIPokQAAAAAAAAkQKIgDyAGuKCjm0QAAAAAAAAkQKMiD6FEAAAAAAAAJECiEH1EAAAAAAAAJECjIQ4LIAIoAgAhDAJ/QQAgAi0AKCICQQpGDQAaQQAgAkF7aiICQQRLDQAaQoGChIAQIAKtQgOGiKcLIQ0gBCAFayEEIAMoAgAhBgJAIAMtACgiAkEKRg0AIAJBe2oiAkEESw0AQoGChIAQIAKtQgOGiKchCgsgAEEBOgAdIAAgDToAHCAAIAk2AhggAEEANgIUIAAgCzYCECAAIAc2AgwgACAMNgIIIAAgDzkDACAAQT1qIAEtAB06AAAgAEE8aiAKOgAAIABBOGogBDYCACAAQTRqQQA2AgAgAEEwaiAINgIAIABBLGogBTYCACAAQShqIAY2AgAgAEEgaiAOOQMADwsgAEECOgAcC+cCAQZ/AkACQCACQQNxIgRFDQBBBCAEayIERQ0AIAMgBCAEIANLGyEFQQAhBCABQf8BcSEIA0AgBCAFRg0BIAIgBGogBEEBaiEELQAAIgYgCEcNAAtBASEDIAYgAUH/AXFGQQFqQQFxIARqQX9qIQQMAQsgAUH/AXEhCAJAAkAgA0EISQ0AIAUgA0F4aiIGSw0AIAhBgYKECGwhBANAIAIgBWoiB0EEaigCACAEcyIJQX9zIAlB//37d2pxIAcoAgAgBHMiB0F/cyAHQf/9+3dqcXJBgIGChHhxRQRAIAVBCGoiBSAGTQ0BCwsgBSADSw0BCyACIAVqIQIgAyAFayEGQQAhA0EAIQQCQANAIAQgBkYNASACIARqIARBAWohBC0AACIHIAhHDQALQQEhAyAHIAFB/wFxRkEBakEBcSAEakF/aiEECyAEIAVqIQQMAQsgBSADEKcBAAsgACAENgIEIAAgAzYCAAviAgEFfwJAQc3/eyAAQRAgAEEQSxsiAGsgAU0NACAAQRAgAUELakF4cSABQQtJGyIEakEMahADIgJFDQAgAkF4aiEBAkAgAEF/aiIDIAJxRQRAIAEhAAwBCyACQXxqIgUoAgAiBkF4cSACIANqQQAgAGtxQXhqIgIgACACaiACIAFrQRBLGyIAIAFrIgJrIQMgBkEDcQRAIAAgAyAAKAIEQQFxckECcjYCBCAAIANqIgMgAygCBEEBcjYCBCAFIAIgBSgCAEEBcXJBAnI2AgAgACAAKAIEQQFyNgIEIAEgAhAvDAELIAEoAgAhASAAIAM2AgQgACABIAJqNgIACwJAIAAoAgQiAUEDcUUNACABQXhxIgIgBEEQak0NACAAIAQgAUEBcXJBAnI2AgQgACAEaiIBIAIgBGsiBEEDcjYCBCAAIAJqIgIgAigCBEEBcjYCBCABIAQQLwsgAEEIaiEDCyADC/8CAQl/IwBBIGsiAiQAAkACQAJAIAAoAhAiByAAKAIYa0ECSA0AIABBCGohAyAAQQxqIQUgACgCACEIIAAoAgQhBgJAIAdBAmsiBEUNACAFIAMgCEEBRhsoAgAiCSAERg0AIAkgBE0NASAEIAZqLAAAQUBIDQELIAIgBSADIAgbKAIAIgM2AgQgAiAGNgIAIAIgBDYCCCACIAM2AgwgBEUgAyAERnJFBEAgAyAETQ0CIAQgBmosAABBv39MDQILIAQgBmohBQJAQQJBACADIARrIgNBAkcbRQRAIAIgAzYCFCACIAU2AhAMAQsgA0ECTQ0BIAYgB2oiBiwAAEFASA0BIAIgAzYCFCACIAU2AhAgBiwAAEG/f0wNAwsgASAFRwRAIAEgBUECELQBDQELIAAgBDYCEEEBIQoLIAJBIGokACAKDwsgAiACQQxqNgIYIAIgAkEIajYCFCACIAI2AhAgAkEQahDGAQALIAJBEGoiACgCACAAKAIEQQBBAhAaAAv3AgEKfyMAQfAAayIIJAAgAEFAayELA0AgAkEBdCIDQQFyIQUCQAJAAkACQCADQQJqIgcgAU8NACAFIAFPDQEgACADQeQAbGohCUEAIQMDQCADQQRqIgRBKEYNASADIAlqIQYgBCEDQX8gBkGIAmooAgAiBCAGQaQBaigCACIGRyAEIAZIGyIERQ0ACyAEQf8BcUH/AUcNACAHIQULAkAgBSABTw0AIAIgAU8NAiALIAVB5ABsIgNqIQYgCyACQeQAbCICaiEJIAAgA2ohByAAIAJqIQRBACEDA0AgA0EEaiICQShGDQEgAyAJaiEKIAMgBmohDCACIQNBfyAMKAIAIgIgCigCACIKRyACIApIGyICRQ0ACyACQf8BcUH/AUYNAwsgCEHwAGokAA8LQayowgAgBSABEKYBAAtBvKjCACACIAEQpgEACyAIQQxqIARB5AAQuQEaIAQgB0HkABCYARogByAIQQxqQeQAELkBGiAFIQIMAAsAC/kCAQV/IwBBMGsiBCQAAkACQCAAQQhqKAIAIgUgACgCBCIGTwRAIAUgAksNASAFIAZrIQcgACADKAIABH8gAygCVA0DIANBfzYCVEEAIQIgA0HgAGoiCEEANgIAIANB2ABqIgBBACAHEJABIAEgBkECdGogASAFQQJ0aiAAEEogACgCACEBIARBEGoiBSAIKAIAIgA2AgAgBCAANgIoIARCADcDICAEIAA2AhwgBEEANgIYIAQgATYCDCAEQQA2AgggBEEIaiADKAIAEQcAGiAEKAIMIQAgBEEUaigCACAFKAIAIgcgBCgCCCIIQQFGGyIFBEAgBSEGIAAhAQNAIAIgAS0AAEHAAXFBgAFGaiECIAFBAWohASAGQX9qIgYNAAsLIAhFIAdFckUEQCAAECELIAMgAygCVEEBajYCVCAFIAJrBSAHCzYCDCAEQTBqJAAPCyAGIAUQpwEACyAFIAIQpQEAC0HCy8IAQRAgBEEIakG4r8IAEJUBAAviAgELfyMAQRBrIgYkACAAQQhqKAIAIgUgACgCBCIETwRAIAUgAk0EQCAGQqXGiKHInKf5SzcDCCABIARBAnRqIgIgBSAEayIFIAZBCGoQcCAGKAIIIgFBGXYiBEEIdCAEciIEQRB0IARyIQogBUECdCELIAMoAhgiBCABcSEBIANBIGooAgAhCCADQRxqKAIAIQwDQAJAAkAgASAMaigAACIJIApzIgNBf3MgA0H//ft3anFBgIGChHhxIgNFDQADQAJAIAUgCCADaEEDdiABaiAEcSINQQR0aiIHKAIIRgRAIAcoAgAiByACRg0BIAIgByALELQBRQ0BCyADQX9qIANxIgMNAQwCCwsgCCANQQR0ai0ADCEDDAELIA5BBGoiDiABaiAEcSEBQQohAyAJIAlBAXRxQYCBgoR4cUUNAQsLIAAgAzoAECAGQRBqJAAPCyAFIAIQpQEACyAEIAUQpwEAC90CAQp/IwBB8ABrIgckAAJAIAFBAkkNACABQX5qIQQgACABQeQAbGoiBUG4fmohBiAFQZx/aiEIQVghAQNAIAFBBGoiA0UNASABIAVqIQIgAyEBQX8gAkGgf2ooAgAiAyACQQRqKAIAIgJHIAMgAkgbIgNFDQALIANB/wFxQf8BRw0AIAdBCGogCEHkABC5ARogCCAGQeQAELkBGiAAIARB5ABsIgFqIQMCQCAERQ0AIAAgAWpBXGohBiAHQcgAaiEKA0AgACAEQX9qIghB5ABsaiEFQQAhAQNAIAFBBGoiAkEoRg0CIAEgCmohCSABIAZqIQsgAiEBQX8gCygCACICIAkoAgAiCUcgAiAJSBsiAkUNAAsgAkH/AXFB/wFHDQEgACAEQeQAbGogBUHkABC5ARogBkGcf2ohBiAFIQMgCCIEDQALCyADIAdBCGpB5AAQuQEaCyAHQfAAaiQAC80CAQZ/IAEgAkEBdGohCSAAQYD+A3FBCHYhCiAAQf8BcSEMAkACQAJAA0AgAUECaiELIAcgAS0AASICaiEIIAogAS0AACIBRwRAIAEgCksNAyAIIQcgCyIBIAlHDQEMAwsgCCAHTwRAIAggBEsNAiADIAdqIQECQANAIAJFDQEgAkF/aiECIAEtAAAgAUEBaiEBIAxHDQALQQAhAgwFCyAIIQcgCyIBIAlHDQEMAwsLIAcgCBCnAQALIAggBBClAQALIABB//8DcSEHIAUgBmohA0EBIQIDQAJAIAVBAWohAAJ/IAAgBS0AACIBQRh0QRh1IgRBAE4NABogACADRg0BIAUtAAEgBEH/AHFBCHRyIQEgBUECagshBSAHIAFrIgdBAEgNAiACQQFzIQIgAyAFRw0BDAILC0G57MIAQStB5OzCABC4AQALIAJBAXEL2wIBA38jAEEQayIDJAAgACABRwRAA0AgAEEEagJAIAAoAgAiAEGAAU8EQCADQQA2AgwCfyAAQf8PTQRAIAMgAEE/cUGAAXI6AA0gAyAAQQZ2QR9xQcABcjoADEECDAELIABB//8DTQRAIAMgAEE/cUGAAXI6AA4gAyAAQQZ2QT9xQYABcjoADSADIABBDHZBD3FB4AFyOgAMQQMMAQsgAyAAQT9xQYABcjoADyADIABBEnZB8AFyOgAMIAMgAEEGdkE/cUGAAXI6AA4gAyAAQQx2QT9xQYABcjoADUEECyEAIAIgAigCCCAAEJABIAIgAigCCCIEIABqNgIIIAQgAigCAGogA0EMaiAAELkBGgwBCyACKAIIIgQgAigCBEYEfyACIARBARCQASACKAIIBSAECyACKAIAaiAAOgAAIAIgAigCCEEBajYCCAsiACABRw0ACwsgA0EQaiQAC+ICAgZ/An4jAEEgayIDJAACQBDAASIERQ0AAkAgBCgCAEUEQCAEQX82AgBBBCEGIARBBGogARBVIgFFDQECfiABKAIIIgJFBEBCAAwBCwJAAkAgAkH/////A3EgAkcNACACQQJ0IgVBAEgNACABKAIAIQEgBUEEENEBIgYNASAFQQRB1PPCACgCACIAQQIgABsRAAAACxDNAQALIAKtIQggAkEEdCIHIQUgBiECA0AgAiABKAIANgIAIAJBBGohAiABQRBqIQEgBUFwaiIFDQALIAdBcGpBBHZBAWqtQiCGCyEJIAQgBCgCAEEBajYCACAGRQ0CIAMgCCAJhDcCFCADIAY2AhAgA0EIaiADQRBqEIUBIAAgAykDCDcCACADQSBqJAAPC0HCy8IAQRAgA0EQakHUy8IAEJUBAAtBuezCAEErQeTswgAQuAEAC0H8ysIAQcYAIANBEGpB5MvCABCVAQALwgIBCn8jAEHwAGsiBiQAAkAgAUECSQ0AIABB5ABqIQRBwAAhAgNAIAJBBGoiA0HoAEYNASAAIAJqIQUgAyECQX8gBSgCACIDIAVB5ABqKAIAIgVHIAMgBUgbIgNFDQALIANB/wFxQf8BRw0AIAZBCGogAEHkABC5ARogACAEQeQAELkBIglB5ABqIQMCQCABQQNJDQAgCUGIAmohByAGQcgAaiEKQQIhAgNAIAJBAWogCSACQeQAbGohAEEAIQIDQCACQQRqIgRBKEYNAiACIAdqIQggAiAKaiELIAQhAkF/IAsoAgAiBCAIKAIAIghHIAQgCEgbIgRFDQALIARB/wFxQf8BRw0BIABBnH9qIABB5AAQuQEaIAdB5ABqIQcgACEDIgIgAUcNAAsLIAMgBkEIakHkABC5ARoLIAZB8ABqJAALvQICBH8BfiMAQRBrIgQkAAJAIAFBAkkNACABQQxsIABqIgNBdGoiAkEDIANBaGoiA0EDEJMBQf8BcUH/AUcNACACKQIAIQYgAiADKQIANwIAIARBCGoiBSACQQhqIgIoAgA2AgAgAiADQQhqKAIANgIAIAQgBjcDACAAIAFBfmoiAkEMbGohAwJAIAJFDQAgBEEDIAAgAUF9aiICQQxsakEDEJMBQf8BcUH/AUcNACABQQxsIABqQVBqIQEgAAJ/AkADQCABQSBqIAFBFGooAgA2AgAgAUEYaiABQQxqKQIANwIAIAJFDQEgAkF/aiECIARBAyABQQMQkwEgAUF0aiEBQf8BcUH/AUYNAAsgAkEBagwBC0EAC0EMbGohAwsgAyAEKQMANwIAIANBCGogBSgCADYCAAsgBEEQaiQAC74CAgV/AX4jAEEwayIEJABBJyECAkAgAEKQzgBUBEAgACEHDAELA0AgBEEJaiACaiIDQXxqIAAgAEKQzgCAIgdC8LF/fnynIgVB//8DcUHkAG4iBkEBdEH2gMAAai8AADsAACADQX5qIAZBnH9sIAVqQf//A3FBAXRB9oDAAGovAAA7AAAgAkF8aiECIABC/8HXL1YgByEADQALCyAHpyIDQeMASgRAIAJBfmoiAiAEQQlqaiAHpyIFQf//A3FB5ABuIgNBnH9sIAVqQf//A3FBAXRB9oDAAGovAAA7AAALAkAgA0EKTgRAIAJBfmoiAiAEQQlqaiADQQF0QfaAwABqLwAAOwAADAELIAJBf2oiAiAEQQlqaiADQTBqOgAACyABQfTLwgBBACAEQQlqIAJqQScgAmsQJSAEQTBqJAALtgIBB38CfyABQSBqKAIAIgQgAUEsaigCACIFTQRAIAEgBDYCLCAEDAELIAFBJGogBSAEIAVrIgYQkAEgASgCLCEDIAEoAiQhByABAn8CQCAGQQJPBEAgBUF/cyAEaiEEA0AgAyAHakEAOgAAIANBAWohAyAEQX9qIgQNAAsMAQsgBg0AIAMMAQsgAyAHakEAOgAAIANBAWoLIgQ2AiwgASgCIAshAwJAIAQgAyADIARLGyIGRQ0AIAEoAiQhByABKAIYIQRBACEDA0AgA0EBaiEFIAMgB2oCQCACIAQoAgAiCBBWQf8BcSIDQQhHDQBBACEDQQQgCCACEDYiCEH/AXFBAkYNACAIQQJ0QQRxIQMLIAM6AAAgBSAGTw0BIARBBGohBCAFIQMMAAsACyAAIAFBMBC5ARoLvwIBBH8gAEIANwIQIAACf0EAIAFBCHYiA0UNABpBHyABQf///wdLDQAaIAFBBiADZyIDa0EfcXZBAXEgA0EBdGtBPmoLIgI2AhwgAkECdEGQ8sIAaiEEAkACQAJAAkBBhPDCACgCACIFQQEgAkEfcXQiA3EEQCAEKAIAIgMoAgRBeHEgAUcNASADIQIMAgtBhPDCACADIAVyNgIAIAQgADYCACAAIAQ2AhgMAwsgAUEAQRkgAkEBdmtBH3EgAkEfRht0IQQDQCADIARBHXZBBHFqQRBqIgUoAgAiAkUNAiAEQQF0IQQgAiEDIAIoAgRBeHEgAUcNAAsLIAIoAggiASAANgIMIAIgADYCCCAAQQA2AhggACACNgIMIAAgATYCCA8LIAUgADYCACAAIAM2AhgLIAAgADYCDCAAIAA2AggLuAIBBn8jAEEQayICJAACQCACQX8CfwJAIAEEQCABQQhJDQEgASABQf////8BcUYEQCABQQN0QQduDAMLQbSmwgBBHEHQpsIAELgBAAsgAEIANwIAIABBFGpBADYCACAAQQxqQgQ3AgAgAEEIakGwpsIANgIADAILIAFBAWoLQX9qZ3YiAUEBaiIEEKEBAkAgAigCBCIDBEAgAigCCCEFIAIoAgAiBiADENEBIgdFDQEgB0H/ASABQQVqEL8BIQMgAEEUakEANgIAIABBDGogAyAFajYCACAAQQhqIAM2AgAgACABNgIEIABBADYCACAAQRBqIAEgBEEDdkEHbCABQQhJGzYCAAwCC0G0psIAQRxB0KbCABC4AQALIAYgA0HU88IAKAIAIgBBAiAAGxEAAAALIAJBEGokAAu4AgEGfyMAQRBrIgIkAAJAIAJBfwJ/AkAgAQRAIAFBCEkNASABIAFB/////wFxRgRAIAFBA3RBB24MAwtBtKbCAEEcQdCmwgAQuAEACyAAQgA3AgAgAEEUakEANgIAIABBDGpCCDcCACAAQQhqQbCmwgA2AgAMAgsgAUEBagtBf2pndiIBQQFqIgQQmwECQCACKAIEIgMEQCACKAIIIQUgAigCACIGIAMQ0QEiB0UNASAHQf8BIAFBBWoQvwEhAyAAQRRqQQA2AgAgAEEMaiADIAVqNgIAIABBCGogAzYCACAAIAE2AgQgAEEANgIAIABBEGogASAEQQN2QQdsIAFBCEkbNgIADAILQbSmwgBBHEHQpsIAELgBAAsgBiADQdTzwgAoAgAiAEECIAAbEQAAAAsgAkEQaiQAC6kCAQN/IwBBgAFrIgQkACAAKAIAIQACQAJAAn8CQCABKAIAIgNBEHFFBEAgACgCACECIANBIHENASACrSABEE4MAgsgACgCACECQQAhAANAIAAgBGpB/wBqIAJBD3EiA0EwciADQdcAaiADQQpJGzoAACAAQX9qIQAgAkEEdiICDQALIABBgAFqIgJBgQFPDQIgAUHMg8AAQQIgACAEakGAAWpBACAAaxAlDAELQQAhAANAIAAgBGpB/wBqIAJBD3EiA0EwciADQTdqIANBCkkbOgAAIABBf2ohACACQQR2IgINAAsgAEGAAWoiAkGBAU8NAiABQcyDwABBAiAAIARqQYABakEAIABrECULIARBgAFqJAAPCyACQYABEKcBAAsgAkGAARCnAQALpgICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEEIAAoAgQhCANAAkACQCACIAhqKAAAIgYgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgBCAAaEEDdiACaiADcSIJQewBbGooAgBHBEAgAEF/aiAAcSIADQEMAgsLIAQgCUHsAWxqQQRqIQAMAQsgBUEEaiIFIAJqIANxIQJBACEAIAYgBkEBdHFBgIGChHhxRQ0BCwsgAAukAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhByADIAAoAgAiA3EhAiAAKAIIIQQgACgCBCEIA0ACQAJAIAIgCGooAAAiBiAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIARQ0AA0AgASAEIABoQQN2IAJqIANxIglBBHRqKAIARwRAIABBf2ogAHEiAA0BDAILCyAEIAlBBHRqQQRqIQAMAQsgBUEEaiIFIAJqIANxIQJBACEAIAYgBkEBdHFBgIGChHhxRQ0BCwsgAAuoAgIIfwF+IwBBEGsiBCABNgIMQqXGiKHInKf5SyEKQQEhAiABIQMDQCAKIAOtQv8Bg4VCs4OAgIAgfiEKIAJBBEZFBEAgBEEMaiACaiACQQFqIQItAAAhAwwBCwsgCqciA0EZdiICQQh0IAJyIgJBEHQgAnIhBiADIAAoAgQiA3EhAiAAQQxqKAIAIQcgAEEIaigCACEIQQAhBANAAkACQCACIAhqKAAAIgUgBnMiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAEUNAANAIAEgByAAaEEDdiACaiADcUEDdGoiCSgCAEcEQCAAQX9qIABxIgANAQwCCwsgCS0ABCEADAELIARBBGoiBCACaiADcSECQQghACAFIAVBAXRxQYCBgoR4cUUNAQsLIAALqAIBBH8jAEEgayICJAACQAJAAkACQAJAAkAQwQEiAwRAIAMoAgANBiADQX82AgAgA0EEaiAAEFQiBUUNAhDAASIEBEAgBCgCAA0HIARBfzYCACAEQQRqIAAQVSIARQ0EIAUgATYCECAAKAIEIAFPDQIgAkEIaiAAIAAoAggiACABIABrQQAQYCACKAIIQQFHDQIgAkEQaigCAA0FEM0BAAsMBQsMBAsgBCAEKAIAQQFqNgIAIAMgAygCAEEBajYCACACQSBqJAAPC0G57MIAQStB5OzCABC4AQALQbnswgBBK0Hk7MIAELgBAAtB1+3CAEEoQdirwgAQuAEAC0H8ysIAQcYAIAJBGGpB3KjCABCVAQALQcLLwgBBECACQRhqQcyowgAQlQEAC7ICAQV/IAAoAhghBAJAAkAgACAAKAIMIgFGBEAgAEEUQRAgAEEUaiIBKAIAIgMbaigCACICDQFBACEBDAILIAAoAggiAiABNgIMIAEgAjYCCAwBCyABIABBEGogAxshAwNAIAMhBSACIgFBFGoiAygCACICRQRAIAFBEGohAyABKAIQIQILIAINAAsgBUEANgIACwJAIARFDQACQCAAIAAoAhxBAnRBkPLCAGoiAigCAEYEQCACIAE2AgAgAQ0BQYTwwgBBhPDCACgCAEF+IAAoAhx3cTYCAA8LIARBEEEUIAQoAhAgAEYbaiABNgIAIAFFDQELIAEgBDYCGCAAKAIQIgIEQCABIAI2AhAgAiABNgIYCyAAQRRqKAIAIgBFDQAgAUEUaiAANgIAIAAgATYCGAsLpwIBAn8CfyABQYAQTwRAAkACQAJAAkACQCABQYCABE8EQCABQQx2QXBqIgJBgAJJDQFB0JzAACACQYACEKYBAAsgAUEGdkFgaiICQd8HSw0BIABBhAJqKAIAIgMgACACakGYAmotAAAiAk0NAiAAKAKAAiACQQN0agwGCyABQQZ2QT9xIAAgAmpB+AlqLQAAQQZ0ciICIABBjAJqKAIAIgNPDQIgAEGUAmooAgAiAyAAKAKIAiACai0AACICTQ0DIAAoApACIAJBA3RqDAULQbCcwAAgAkHgBxCmAQALQcCcwAAgAiADEKYBAAtB4JzAACACIAMQpgEAC0HwnMAAIAIgAxCmAQALIAAgAUEDdkH4////AXFqCykDAEIBIAFBP3GthoNCAFILlAIBA38jAEGAAWsiAyQAAkACQAJ/AkAgASgCACICQRBxRQRAIAJBIHENASAArSABEE4MAgtBACECA0AgAiADakH/AGogAEEPcSIEQTByIARB1wBqIARBCkkbOgAAIAJBf2ohAiAAQQR2IgANAAsgAkGAAWoiAEGBAU8NAiABQcyDwABBAiACIANqQYABakEAIAJrECUMAQtBACECA0AgAiADakH/AGogAEEPcSIEQTByIARBN2ogBEEKSRs6AAAgAkF/aiECIABBBHYiAA0ACyACQYABaiIAQYEBTw0CIAFBzIPAAEECIAIgA2pBgAFqQQAgAmsQJQsgA0GAAWokAA8LIABBgAEQpwEACyAAQYABEKcBAAuTAgELfyMAQRBrIgUkAAJAAkAgAUEITwRAIAFBAnYiAkEBdCEHQX8hBkF/IAFBf2pndiEIIAJBGGwgAGpBdGohBCABIQIDQCAGIAdqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAhxIgNBACABIAMgAUkbayIDIAFPDQMgBUEIaiIJIARBCGoiCigCADYCACAFIAQpAgA3AwAgACADQQxsaiIDQQhqIgsoAgAhDCAEIAMpAgA3AgAgCiAMNgIAIAsgCSgCADYCACADIAUpAwA3AgAgBEEMaiEEIAZBAWoiBkECRw0ACwsgBUEQaiQADwtBsKfCACADIAEQpgEAC0HAp8IAIAMgARCmAQALlQICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEIIAAoAgQhCUEAIQQDQAJAIAIgCWooAAAiBSAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIABEADQEEBIQYgCCAAaEEDdiACaiADcUEEdGooAgAgAUYNAiAAQX9qIABxIgANAAsLIARBBGoiBCACaiADcSECQQAhBiAFIAVBAXRxQYCBgoR4cUUNAQsLIAYLlgICCH8BfiMAQRBrIgQgATYCDEKlxoihyJyn+UshCkEBIQIgASEDA0AgCiADrUL/AYOFQrODgICAIH4hCiACQQRGRQRAIARBDGogAmogAkEBaiECLQAAIQMMAQsLIAqnIgNBGXYiAkEIdCACciICQRB0IAJyIQcgAyAAKAIAIgNxIQIgACgCCCEIIAAoAgQhCUEAIQQDQAJAIAIgCWooAAAiBSAHcyIAQX9zIABB//37d2pxQYCBgoR4cSIABEADQEEBIQYgCCAAaEEDdiACaiADcUHsAWxqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC5UCAgh/AX4jAEEQayIEIAE2AgxCpcaIocicp/lLIQpBASECIAEhAwNAIAogA61C/wGDhUKzg4CAgCB+IQogAkEERkUEQCAEQQxqIAJqIAJBAWohAi0AACEDDAELCyAKpyIDQRl2IgJBCHQgAnIiAkEQdCACciEHIAMgACgCACIDcSECIAAoAgghCCAAKAIEIQlBACEEA0ACQCACIAlqKAAAIgUgB3MiAEF/cyAAQf/9+3dqcUGAgYKEeHEiAARAA0BBASEGIAggAGhBA3YgAmogA3FBKGxqKAIAIAFGDQIgAEF/aiAAcSIADQALCyAEQQRqIgQgAmogA3EhAkEAIQYgBSAFQQF0cUGAgYKEeHFFDQELCyAGC6gCAQR/IwBBMGsiAiQAIAEoAgghBSACIAEQqgEgAigCCCEEIAJBADYCKCACQgE3AyAgAkEgakEAIAQQkAEgAigCKCEDIAQEQCACKAIgIANqQQAgBEECdEF8akECdkEBaiIEEL8BGiADIARqIQMLIAJBGGoiBCADNgIAIAIgAikDIDcDEEEUQQQQ0QEiA0UEQEEUQQRB1PPCACgCACIAQQIgABsRAAAACyADQYoCOwEQIAMgBTYCDCADIAU2AgggA0IANwIAIABCgYCAgBA3AgQgACADNgIAIAAgAikDADcCGCAAIAIpAxA3AiQgACABKQIANwIMIABBFGogAUEIaigCADYCACAAQSBqIAJBCGooAgA2AgAgAEEsaiAEKAIANgIAIAJBMGokAAviAQECfwJAIAEoAgQiBSACayADTw0AIAIgA2oiAyACSSECAkACQAJAIAQEQCACRQ0BIAAgAzYCBAwDCyACRQ0BIAAgAzYCBAwCCyAFQQF0IgIgAyACIANLGyEDCyADIANB/////wBxRwRADAELIANBBHQiBEF/TARADAELAn8gBUUEQCAEQQQQ0QEMAQsgASgCACAFQQR0QQQgBBDOAQsiAkUEQCAEQQRB1PPCACgCACIAQQIgABsRAAAACyABIAM2AgQgASACNgIADAELIABBCGpBADYCAEEBIQYLIAAgBjYCAAuZAgIBfwN+IwBBIGsiASQAIAFBGGpBFBCwASABKQMYIQIgAUEQakEUEJ8BIAEpAxAhAyABQQhqQRQQnwEgASkDCCEEIABBgAFqQQA2AgAgAEHwAGpCADcCACAAQegAaiADNwIAIABB4ABqQgA3AgAgAEHYAGogAjcCACAAQdAAakIANwIAIABByABqQgQ3AgAgAEHEAGpBsKbCADYCACAAQTxqQgA3AgAgAEE0akIENwIAIABBMGpBsKbCADYCACAAQShqQgA3AgAgAEEgakIENwIAIABBHGpBsKbCADYCACAAQRRqQgA3AgAgAEEMakIENwIAIABBCGpBsKbCADYCACAAQgA3AgAgAEH4AGogBDcCACABQSBqJAAL7gEBB38jAEHwAGsiBSQAAkACQCABQQhPBEAgAUECdiICQQF0IQdBfyEGQX8gAUF/amd2IQggAkHIAWwgAGpBnH9qIQQgASECA0AgBiAHaiIDIAFPDQIgAkENdCACcyICQRF2IAJzIgJBBXQgAnMiAiAIcSIDQQAgASADIAFJG2siAyABTw0DIAVBDGogBEHkABC5ARogBCAAIANB5ABsaiIEQeQAEJgBIAQgBUEMakHkABC5ARpB5ABqIQQgBkEBaiIGQQJHDQALCyAFQfAAaiQADwtBsKfCACADIAEQpgEAC0HAp8IAIAMgARCmAQALkwIBBX8gACgCACIEKAIEKAIAIgUgASgCACIIQQN0aigCBCgCACAFIAIoAgAiBkEDdGooAgQoAgAiB0kEQCABIAY2AgAgAiAINgIAIAQoAggiBCAEKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkEDdGooAgQoAgAhBwsgByAFIAMoAgAiCEEDdGooAgQoAgBJBEAgAiAINgIAIAMgBjYCACAEKAIIIgMgAygCAEEBajYCACAAKAIAIgQoAgQoAgAiBSACKAIAIgZBA3RqKAIEKAIAIQcLIAUgASgCACIAQQN0aigCBCgCACAHSQRAIAEgBjYCACACIAA2AgAgBCgCCCIAIAAoAgBBAWo2AgALC/wBAgZ/AX4jAEEQayIFJAADQCACQQF0IgNBAXIhBAJAAkACQCADQQJqIgMgAUkEQCAEIAFPDQEgAyAEIAAgBEEMbGpBAyAAIANBDGxqQQMQkwFB/wFxQf8BRhshBAsgBCABSQRAIAIgAU8NAiAAIAJBDGxqIgJBAyAAIARBDGxqIgNBAxCTAUH/AXFB/wFGDQMLIAVBEGokAA8LQayowgAgBCABEKYBAAtBvKjCACACIAEQpgEACyADQQhqIgYoAgAhByACKQIAIQkgAiADKQIANwIAIAJBCGoiAigCACEIIAIgBzYCACAGIAg2AgAgAyAJNwIAIAQhAgwACwALgwIBA38jAEEwayIEJAACQAJAIAEoAmRFBEAgAUF/NgJkIAFB8ABqQQA2AgAgBEEANgIYIAQgAUEsajYCFCAEQQI2AhAgBCADNgIMIAQgAjYCCCABQegAaiEGIARBIGogBEEIahAqIAQoAiANAQwCC0HCy8IAQRAgBEEIakG4r8IAEJUBAAsDQCAGIAQoAigiBSAFIAQoAixBAnRqELcBIARBIGogBEEIahAqIAQoAiANAAsLAkACQCADIAEoAnBGBEAgASgCaCIFIAJGDQEgBSACIANBAnQQtAFFDQELIAAgBhCqAQwBCyAAQQA2AgALIAEgASgCZEEBajYCZCAEQTBqJAALgAIBBX8gACgCACIEKAIEKAIAIgUgAigCACIGQQJ0aigCACIHIAUgASgCACIIQQJ0aigCAEkEQCABIAY2AgAgAiAINgIAIAQoAggiBCAEKAIAQQFqNgIAIAAoAgAiBCgCBCgCACIFIAIoAgAiBkECdGooAgAhBwsgBSADKAIAIghBAnRqKAIAIAdJBH8gAiAINgIAIAMgBjYCACAEKAIIIgMgAygCAEEBajYCACAAKAIAIgQoAgQoAgAiBSACKAIAIgZBAnRqKAIABSAHCyAFIAEoAgAiAEECdGooAgBJBEAgASAGNgIAIAIgADYCACAEKAIIIgAgACgCAEEBajYCAAsL4wEBBn8jAEEQayIFJAAgACgCACIBBEAgACgCBCICQQRqIQMgASACakEBaiEGIAIoAgBBf3NBgIGChHhxIQEgACgCCCEEA0ACQCABRQRAA0AgAyAGTw0CIARB4ABqIQQgAygCACADQQRqIgIhA0GAgYKEeHEiAUGAgYKEeEYNAAsgAUGAgYKEeHMhASACIQMLIAQgAWhBA3ZBGGxqIgIoAgQEQCACKAIAECELIAFBf2ogAXEhASACKAIQRQ0BIAIoAgwQIQwBCwsgBSAAKAIAQQFqEJkBIAAoAgQQIQsgBUEQaiQAC+UBAQR/IwBBQGoiBCQAIARBCGogASABIAJqECMgBEEYaiAAIAQoAggiBSAEKAIQIgEQZSAEKAIMIQYCQCAEKAIYIgJFBEAgBiEHIAUhAgwBCyAEQSBqKAIAIQEgBCgCHCEHIAZFDQAgBRAhCyAEQRhqIAAgAiABEDcgBCABNgI4IAQgBzYCNCAEIAI2AjAgAEEYaiIAIARBMGogAxAyAkAgBCgCGCIBRQ0AIARBOGogBEEsaigCADYCACAEIARBJGopAgA3AzAgACAEQTBqIAMQMiAEKAIcRQ0AIAEQIQsgBEFAayQAC9gBAQV/AkAgAUECSQ0AIAAgAUF+aiICQQN0aiIDKAIEKAIAIAFBA3QgAGoiBEF8aigCACIFKAIATw0AIARBeGoiBCgCACEGIAQgAykCADcCAAJAIAJFDQAgACABQX1qIgJBA3RqIgEoAgQoAgAgBSgCAE8NACABQXxqIQEgAAJ/AkADQCABQQxqIAFBBGopAgA3AgAgAkUNASACQX9qIQIgASgCACABQXhqIQEoAgAgBSgCAEkNAAsgAkEBagwBC0EAC0EDdGohAwsgAyAFNgIEIAMgBjYCAAsL3QECB38BfiMAQRBrIgQkACABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhBkIAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUEMbCAAakF0aiECIAEhAwNAIANBf2oiBSABTw0CIARBCGogAEEIaiIDKAIAIgY2AgAgBCAAKQIAIgk3AwAgAkEIaiIHKAIAIQggACACKQIANwIAIAMgCDYCACAHIAY2AgAgAiAJNwIAIAAgBUEAEGQgAkF0aiECIAUiA0EBSw0ACwsgBEEQaiQADwtBwKfCACADQX9qIAEQpgEAC90BAQZ/AkACQAJAIAFBAkkNACAAKAIEIgYoAgAgAEEMaigCACgCAE8NACAAKAIAIQcgACAAKQIINwIAIABBCGohAgJAIAFBA0kNAEECIQNBAyEEA0AgAyABTw0DIAYoAgAgACADQQN0aiIFKAIEKAIATw0BIANBf2oiAiABTw0EIAAgAkEDdGogBSkCADcCACAEIAFJBEAgBSECIAQiA0EBaiIEIANJDQIMAQsLIAUhAgsgAiAGNgIEIAIgBzYCAAsPC0HQrMIAIAMgARCmAQALQeCswgAgAiABEKYBAAvUAQEGfyMAQRBrIgUkACAAKAIAIgIEQCAAKAIEIgFBBGohAyABIAJqQQFqIQYgASgCAEF/c0GAgYKEeHEhASAAKAIIIQQDQAJAIAEEfyABBQNAIAMgBk8NAiAEQeAAaiEEIAMoAgAgA0EEaiICIQNBgIGChHhxIgFBgIGChHhGDQALIAIhAyABQYCBgoR4cwsiAiACQX9qcSEBIAQgAmhBA3ZBGGxqIgIoAhBFDQEgAigCDBAhDAELCyAFIAAoAgBBAWoQmQEgACgCBBAhCyAFQRBqJAAL0wEBBn8jAEEQayIFJAAgACgCACICBEAgACgCBCIBQQRqIQMgASACakEBaiEGIAEoAgBBf3NBgIGChHhxIQEgACgCCCEEA0ACQCABBH8gAQUDQCADIAZPDQIgBEFAayEEIAMoAgAgA0EEaiICIQNBgIGChHhxIgFBgIGChHhGDQALIAIhAyABQYCBgoR4cwsiAiACQX9qcSEBIAQgAmhBA3ZBBHRqIgIoAgRFDQEgAigCABAhDAELCyAFIAAoAgBBAWoQogEgACgCBBAhCyAFQRBqJAALzgECBn8BfgJAAkAgAUEITwRAIAFBAnYiAkEBdCEGQX8hBUF/IAFBf2pndiEHIAJBBHQgAGpBeGohBCABIQIDQCAFIAZqIgMgAU8NAiACQQ10IAJzIgJBEXYgAnMiAkEFdCACcyICIAdxIgNBACABIAMgAUkbayIDIAFPDQMgBCkCACEIIAQgACADQQN0aiIDKQIANwIAIAMgCDcCACAEQQhqIQQgBUEBaiIFQQJHDQALCw8LQbCnwgAgAyABEKYBAAtBwKfCACADIAEQpgEAC8wBAQd/AkACQCABQQhPBEAgAUECdiICQQF0IQZBfyEFQX8gAUF/amd2IQcgAkEDdCAAakF8aiEEIAEhAgNAIAUgBmoiAyABTw0CIAJBDXQgAnMiAkERdiACcyICQQV0IAJzIgIgB3EiA0EAIAEgAyABSRtrIgMgAU8NAyAEKAIAIQggBCAAIANBAnRqIgMoAgA2AgAgAyAINgIAIARBBGohBCAFQQFqIgVBAkcNAAsLDwtBsKfCACADIAEQpgEAC0HAp8IAIAMgARCmAQALyQECA38BfiMAQRBrIgUgATYCCCACKQMAIQZBASEDIAEhBANAIAYgBK1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAFQQhqIANqIANBAWohAy0AACEEDAELCyACIAY3AwAgAQRAIAAgAUECdGohAQNAIAUgACgCACIENgIMQQEhAwNAIAYgBK1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAFQQxqIANqIANBAWohAy0AACEEDAELCyACIAY3AwAgAEEEaiIAIAFHDQALCwvCAQEFfwJAIAFBAkkNACABQQJ0IABqQXxqIgMoAgAiBCAAIAFBfmoiBUECdGoiBigCACICTw0AIAMgAjYCAAJAAkAgBUUNACAEIAAgAUF9aiIDQQJ0aiICKAIAIgVPDQAgBiAFNgIAIANFDQEgAUECdCAAakFwaiEBA0AgBCABKAIAIgBPBEAgAUEEaiECDAMLIAFBBGogADYCACABQXxqIQEgA0F/aiIDDQALIAFBBGohAgwBCyAGIQILIAIgBDYCAAsL1AEBAX8jAEHgAGsiBCQAIAQgATYCCCAEIAM2AgwgASADRgRAIAAgAiABQQJ0ELkBGiAEQeAAaiQADwsgBEE8akEJNgIAIARBNGpBCjYCACAEQSRqQQM2AgAgBEIDNwIUIARBzM3CADYCECAEQQo2AiwgBCAEQQhqNgJAIAQgBEEMajYCRCAEQgQ3A1ggBEIBNwJMIARBxM3CADYCSCAEIARBKGo2AiAgBCAEQcgAajYCOCAEIARBxABqNgIwIAQgBEFAazYCKCAEQRBqQeTNwgAQvgEAC8QBAQZ/AkACQAJAIAFBAkkNACAAKAIEIgQgACgCACIFELEBRQ0AIABBBGohAiAAIAQ2AgBBAyEEAkAgAUEDSQ0AQQIhAwNAIAMgAU8NAyAAIANBAnRqIgYoAgAiByAFELEBRQ0BIANBf2oiAiABTw0EIAAgAkECdGogBzYCACAEIAFJBEAgBiECIAQiA0EBaiIEIANJDQIMAQsLIAYhAgsgAiAFNgIACw8LQdCswgAgAyABEKYBAAtB4KzCACACIAEQpgEAC7oBAQR/IwBB8ABrIgQkACABQQF2IgIEQCACQX9qIQIDQCAAIAEgAhBFIAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUHkAGwgAGpBnH9qIQMgASECA0AgAkF/aiIFIAFPDQIgBEEMaiAAQeQAELkBGiAAIANB5AAQmAEgAyAEQQxqQeQAELkBIQMgBUEAEEUgA0Gcf2ohAyAFIgJBAUsNAAsLIARB8ABqJAAPC0HAp8IAIAJBf2ogARCmAQALwQECBH8BfkEDIQIjAEEQayIEQQM2AgggASkDACEGQQEhAwNAIAYgAq1C/wGDhUKzg4CAgCB+IQYgA0EERkUEQCAEQQhqIANqIANBAWohAy0AACECDAELCyABIAY3AwAgAEEMaiEFA0AgBCAAKAIAIgI2AgxBASEDA0AgBiACrUL/AYOFQrODgICAIH4hBiADQQRGRQRAIARBDGogA2ogA0EBaiEDLQAAIQIMAQsLIAEgBjcDACAAQQRqIgAgBUcNAAsLvAEBAn8CQCAAKAIEIgFFDQAgACgCDCICBEAgAkE8bCECA0AgARCpASABQTxqIQEgAkFEaiICDQALCyAAKAIIBEAgACgCBBAhCyAAQRRqEI4BIABBnAFqKAIABEAgAEGYAWooAgAQIQsgAEGoAWooAgAEQCAAQaQBaigCABAhCyAAQbgBahBsIABB0AFqKAIABEAgAEHMAWooAgAQIQsgAEHcAWooAgAiAUUNACAAQeABaigCAEUNACABECELC8IBAgJ/AX4DQCACQQF0IgRBAXIhAwJAAkACQCAEQQJqIgQgAUkEQCADIAFPDQEgBCADIAAgBEEDdGooAgQoAgAgACADQQN0aigCBCgCAEkbIQMLIAMgAUkEQCACIAFPDQIgACADQQN0aiIEKAIEKAIAIAAgAkEDdGoiAigCBCgCAEkNAwsPC0GsqMIAIAMgARCmAQALQbyowgAgAiABEKYBAAsgAikCACEFIAIgBCkCADcCACAEIAU3AgAgAyECDAALAAvaAQIGfwF+IwAiACAAQUBqQWBxIgAkAAJAQeTvwgAoAgANACAAQRhqQRQQnwEgACkDGCEGIABBEGpBFBCfASAAQgA3AiwgACAGNwIkQejvwgAoAgBB6O/CACAAKQMoNwMAIABBADYCIEHk78IAKAIAIQFB4O/CACAAKQMgNwMAIABBADYCPCAAIAApAxA3AjRB+O/CACgCACEEQfjvwgAgACkDODcDAEH078IAKAIAIQVB8O/CACAAKQMwNwMAIAFFDQAEQCABECELIARFDQAgBRAhCyQAQeDvwgALrgEBBH8DQCACQQF0IgRBAXIhAwJAAkACQCAEQQJqIgQgAUkEQCADIAFPDQEgBCADIAAgA0ECdGooAgAgACAEQQJ0aigCAEkbIQMLIAMgAUkEQCACIAFPDQIgACACQQJ0aiICKAIAIgQgACADQQJ0aiIFKAIAIgZJDQMLDwtBrKjCACADIAEQpgEAC0G8qMIAIAIgARCmAQALIAIgBjYCACAFIAQ2AgAgAyECDAALAAuyAQEEfwNAIAJBAXQiBEEBciEDAkACQAJAIARBAmoiBCABSQRAIAMgAU8NASAEIAMgACADQQJ0aigCACAAIARBAnRqKAIAELEBGyEDCyADIAFJBEAgAiABTw0CIAAgAkECdGoiAigCACIEIAAgA0ECdGoiBSgCACIGELEBDQMLDwtBrKjCACADIAEQpgEAC0G8qMIAIAIgARCmAQALIAIgBjYCACAFIAQ2AgAgAyECDAALAAunAQIEfwF8AkAgACgCACIDRQ0AA0AgACgCBCAAKAIAIgQgAmxBA3RqIAS4OQMAIAAoAgQgAWogACgCALg5AwAgAUEIaiEBIAMgAkEBaiICRw0ACyAAKAIAIgJBAkkNAEEBIQEDQCAAKAIEIAAoAgAgAWxBA3RqQQhqIAFBf2q4IgU5AwAgACgCBCABIAAoAgBqQQN0aiAFOQMAIAIgAUEBaiIBRw0ACwsLqwEBBX8CQCABQQJJDQAgAUECdCAAakF8aiIFKAIAIgIgACABQX5qIgNBAnRqIgQoAgAiBhCxAUUNACAFIAY2AgACQCADRQ0AIAIgAUECdCAAaiIAQXRqKAIAELEBRQ0AIABBcGohAANAAkAgACIBQQhqIAFBBGooAgA2AgAgA0F/aiIDRQ0AIAFBfGohACACIAEoAgAQsQENAQsLIAFBBGohBAsgBCACNgIACwvPAQMBfwJ+AnwjAEEQayEBIAC9IgJCNIhC/w+DIgNCsghYBHwgAJogACACQgBTGyEEAnwCQCADQv4HWgRAIAREAAAAAAAAMEOgRAAAAAAAADDDoCAEoSIFRAAAAAAAAOA/ZA0BIAQgBaAiACAFRAAAAAAAAOC/ZUEBcw0CGiAARAAAAAAAAPA/oAwCCyABIAREAAAAAAAAMEOgOQMIIAErAwgaIABEAAAAAAAAAACiDwsgBCAFoEQAAAAAAADwv6ALIgCaIAAgAkIAUxsFIAALC7IBAQV/AkACQCABKAIAIgIEQCABKAIEIQQMAQsgASgCDCEFIAEoAgghAwNAIAMgBU8NAiADKAIAIQIgASADQQRqIgM2AgggASABKAIEQaABaiIENgIEIAEgAkGAgYKEeHEiBkGAgYKEeHMiAjYCACAGQYCBgoR4Rg0ACwsgASACQX9qIAJxNgIAIAEgASgCEEF/ajYCECAAIAQgAmhBA3ZBKGxqQSgQuQEaDwsgAEECOgAkC6sBAQd/AkACQCAAKAIIIgNBAkkEQCADIQEMAQsgACgCACIGQQRqIQJBASEBQQEhBANAIAIoAgAiByABQQJ0IAZqIgVBfGooAgBHBEAgASAERwRAIAIgBSgCADYCACAFIAc2AgALIAFBAWohAQsgAkEEaiECIAMgBEEBaiIERw0ACyABIANLBEBBta3CAEEcQdStwgAQuAEACyAAKAIIIAFJDQELIAAgATYCCAsLkwEBAX8jAEHQAGsiBSQAIAVBEGogASABIAJqECMgBUEgaiADIAMgBGoQIyAFQThqIAVBGGooAgA2AgAgBSAFKQMQNwMwIAVByABqIAVBKGooAgA2AgAgBSAFKQMgNwNAIAUgAEEsaiAFQTBqIAVBQGsQLAJAIAUoAgAiAEUNACAFKAIERQ0AIAAQIQsgBUHQAGokAAuTAQEBfyMAQdAAayIFJAAgBUEQaiABIAEgAmoQIyAFQSBqIAMgAyAEahAjIAVBOGogBUEYaigCADYCACAFIAUpAxA3AzAgBUHIAGogBUEoaigCADYCACAFIAUpAyA3A0AgBSAAQUBrIAVBMGogBUFAaxAsAkAgBSgCACIARQ0AIAUoAgRFDQAgABAhCyAFQdAAaiQAC6EBAQJ/AkACQCAAKAIEIgMgAWsgAkkEQCABIAJqIgIgAUkNASADQQF0IgEgAiABIAJLGyIBQf////8DcSABRw0BIAFBAnQiBEEASA0BAn8gA0UEQCAEQQQQ0QEMAQsgACgCACADQQJ0QQQgBBDOAQsiAkUNAiAAIAE2AgQgACACNgIACw8LEM0BAAsgBEEEQdTzwgAoAgAiAEECIAAbEQAAAAugAQECfwJAAkAgACgCBCIDIAFrIAJJBEAgASACaiICIAFJDQEgA0EBdCIBIAIgASACSxsiAUH///8/cSABRw0BIAFBBXQiBEEASA0BAn8gA0UEQCAEQQgQ0QEMAQsgACgCACADQQV0QQggBBDOAQsiAkUNAiAAIAE2AgQgACACNgIACw8LEM0BAAsgBEEIQdTzwgAoAgAiAEECIAAbEQAAAAuhAQECfwJAAkAgACgCBCIDIAFrIAJJBEAgASACaiICIAFJDQEgA0EBdCIBIAIgASACSxsiAUH/////AXEgAUcNASABQQN0IgRBAEgNAQJ/IANFBEAgBEEIENEBDAELIAAoAgAgA0EDdEEIIAQQzgELIgJFDQIgACABNgIEIAAgAjYCAAsPCxDNAQALIARBCEHU88IAKAIAIgBBAiAAGxEAAAALsAEBA38CQAJAAkAgASgCBCIDIAEoAggiAkYEQCADIQIMAQsgAyACSQ0BAkAgAkUEQCADBEAgASgCABAhCyABQQQ2AgBBACECDAELIAEoAgAgA0ECdEEEIAJBAnQiBBDOASIDRQ0DIAEgAzYCAAsgASACNgIECyAAIAI2AgQgACABKAIANgIADwtBie3CAEEkQbDtwgAQuAEACyAEQQRB1PPCACgCACIAQQIgABsRAAAAC54BAQh/IAAoAgQoAgAiAyABKAIAIgZB5ABsakFAayEHIAMgAigCACIIQeQAbGpBQGshCQJAA0AgBEEEaiIDQShGDQEgBCAJaiEKIAQgB2ohBSADIQRBfyAFKAIAIgUgCigCACIDRyAFIANIGyIDRQ0ACyADQf8BcUH/AUcNACABIAg2AgAgAiAGNgIAIAAoAggiACAAKAIAQQFqNgIACwuYAQEDfyABQQF2IgMEQCADQX9qIQIDQCAAIAEgAhB5IAJBf2oiAkF/Rw0ACwsCQCABQQJPBEAgAUECdCAAakF8aiECIAEhAwNAIANBf2oiBCABTw0CIAAoAgAhAyAAIAIoAgA2AgAgAiADNgIAIAAgBEEAEHkgAkF8aiECIAQiA0EBSw0ACwsPC0HAp8IAIANBf2ogARCmAQALmAEBA38gAUEBdiIDBEAgA0F/aiECA0AgACABIAIQeiACQX9qIgJBf0cNAAsLAkAgAUECTwRAIAFBAnQgAGpBfGohAiABIQMDQCADQX9qIgQgAU8NAiAAKAIAIQMgACACKAIANgIAIAIgAzYCACAAIARBABB6IAJBfGohAiAEIgNBAUsNAAsLDwtBwKfCACADQX9qIAEQpgEAC5oBAgN/AX4gAUEBdiIDBEAgA0F/aiECA0AgACABIAIQdyACQX9qIgJBf0cNAAsLAkAgAUECTwRAIAFBA3QgAGpBeGohAiABIQMDQCADQX9qIgQgAU8NAiAAKQIAIQUgACACKQIANwIAIAIgBTcCACAAIARBABB3IAJBeGohAiAEIgNBAUsNAAsLDwtBwKfCACADQX9qIAEQpgEAC5wBAQJ/AkACQCABIAAoAgRGBEAgAUEBaiICIAFJDQEgAUEBdCIDIAIgAyACSxsiAkH/////AXEgAkcNASACQQN0IgNBAEgNAQJ/IAFFBEAgA0EEENEBDAELIAAoAgAgAUEDdEEEIAMQzgELIgFFDQIgACACNgIEIAAgATYCAAsPCxDNAQALIANBBEHU88IAKAIAIgBBAiAAGxEAAAALtwECBH8CfiMAQTBrIgAkAAJAQajuwgAoAgANACAAQRhqQQoQUiAAQRBqIABBLGooAgAiAzYCACAAQQhqIABBJGopAgAiBDcDAEGk7sIAKAIAIQFBqO7CACgCACECQaTuwgAgACkCHCIFNwIAQazuwgAgBDcCAEG07sIAIAM2AgAgACAFNwMAQaDuwgBBADYCACABRSACRXINACAAQRhqIAFBAWoQmwEgAhAhCyAAQTBqJABBoO7CAAu3AQIEfwJ+IwBBMGsiACQAAkBB8O7CACgCAA0AIABBGGpBChBSIABBEGogAEEsaigCACIDNgIAIABBCGogAEEkaikCACIENwMAQezuwgAoAgAhAUHw7sIAKAIAIQJB7O7CACAAKQIcIgU3AgBB9O7CACAENwIAQfzuwgAgAzYCACAAIAU3AwBB6O7CAEEANgIAIAFFIAJFcg0AIABBGGogAUEBahCbASACECELIABBMGokAEHo7sIAC6gBAQJ/AkACQAJAIAEoAgQiAyABKAIIIgJGBEAgAyECDAELIAMgAkkNAQJAIAJFBEAgAwRAIAEoAgAQIQsgAUEBNgIAQQAhAgwBCyABKAIAIANBASACEM4BIgNFDQMgASADNgIACyABIAI2AgQLIAAgAjYCBCAAIAEoAgA2AgAPC0GJ7cIAQSRBsO3CABC4AQALIAJBAUHU88IAKAIAIgBBAiAAGxEAAAALjAEBAn8jAEEQayIBJAAgACgCBCICBEAgASACQQFqEKEBIABBCGooAgAQIQsgAEEYahBtIABBLGoQZyAAQUBrEGcgAEHcAGooAgAEQCAAQdgAaigCABAhCyAAQewAaigCAARAIABB6ABqKAIAECELIABB/ABqKAIABEAgAEH4AGooAgAQIQsgAUEQaiQAC4oBAQR/IAAgACgCECICQQFqIgE2AhACQCABIAJJDQAgAEEMaiAAQQhqIAAoAgAiAkEBRhsoAgAiAyABRg0AIABBDGogAEEIaiACGyEEA0AgAyABSwRAIAAoAgQgAWosAABBv39KDQILIAAgAUEBaiICNgIQIAIgAUkNASAEKAIAIgMgAiIBRw0ACwsLiwEBAX8CQAJAIAAoAgQiAyABayACSQRAIAEgAmoiAiABSQ0BIANBAXQiASACIAEgAksbIgFBAEgNAQJ/IANFBEAgAUEBENEBDAELIAAoAgAgA0EBIAEQzgELIgJFDQIgACABNgIEIAAgAjYCAAsPCxDNAQALIAFBAUHU88IAKAIAIgBBAiAAGxEAAAALnQECA38BfCAAIAAoAgggAiABaxCEASAAKAIIIQMgACABIAJHBH8gACgCACADQQN0aiEEIAEhAANAIAAtAAAhBSAAQQFqIQBEAAAAAAAA8D8hBgJAIAVBfGoiBUEDSw0AAkAgBUEBaw4CAQEAC0QAAAAAAADgPyEGCyAEIAY5AwAgBEEIaiEEIAAgAkcNAAsgAiADIAFragUgAws2AggLgwEBA38jAEEgayICJAAgASgCACEDIAJBCGogASgCCCIBEJ8BIAJBADYCGCACIAIpAwg3AxAgAkEQakEAIAEQggEgAiABIAIoAhgiBGo2AhggAigCECAEQQJ0aiADIAFBAnQQuQEaIABBCGogAigCGDYCACAAIAIpAxA3AgAgAkEgaiQAC2wBBX8gAyABIAEgA0sbIQgDQAJAIAQgCEkEQCAEQQFqIgUgBE8NAQtBfyABIANHIAEgA0kbDwsgAigCACEGIAAoAgAhByACQQRqIQIgAEEEaiEAIAUhBEF/IAYgB0cgByAGSRsiBUUNAAsgBQt+AQF/AkACQCABIAFB/////wFxRgRAIAFBA3QiA0F/TA0BAkAgA0UEQEEIIQIMAQsCfyACRQRAIANBCBDRAQwBCyADEL0BCyICRQ0DCyAAIAE2AgQgACACNgIADwsQ0gEACxDSAQALIANBCEHU88IAKAIAIgBBAiAAGxEAAAALgQEBAX8jAEFAaiIEJAAgBCABNgIMIAQgADYCCCAEIAM2AhQgBCACNgIQIARBLGpBAjYCACAEQTxqQQM2AgAgBEICNwIcIARBlKrAADYCGCAEQQQ2AjQgBCAEQTBqNgIoIAQgBEEQajYCOCAEIARBCGo2AjAgBEEYakGoqsAAEL4BAAtwAQV/IAAoAgQhAyABpyECIAAoAgAhBEEAIQADQCAAQQRqIgAgAiAEcSIFaiECIAMgBWooAABBgIGChHhxIgZFDQALIAMgBmhBA3YgBWogBHEiAmosAABBAE4EfyADKAIAQYCBgoR4cWhBA3YFIAILC3QBAn8gACAAKAIIIAEQggEgACgCACAAKAIIIgJBAnRqIQMCQAJAIAFBAk8EQCABQX9qIQEDQCADQQA2AgAgAkEBaiECIANBBGohAyABQX9qIgENAAsMAQsgAUUNAQsgA0EANgIAIAJBAWohAgsgACACNgIIC28BAX8CQCABIABPBEAgAkUNASAAIQMDQCADIAEtAAA6AAAgAUEBaiEBIANBAWohAyACQX9qIgINAAsMAQsgAkUNACABQX9qIQEgAEF/aiEDA0AgAiADaiABIAJqLQAAOgAAIAJBf2oiAg0ACwsgAAtmAgF/AX4gAa1CGH4iA0IgiKcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiADp2oiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALZwIBfwF+IAGtQuwBfiIDQiCIpwRAIABBADYCBA8LAkACQCABQQdqQXxxIgIgAUEEakkNACACIAOnaiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtmAgF/AX4gAa1CKH4iA0IgiKcEQCAAQQA2AgQPCwJAAkAgAUELakF4cSICIAFBBGpJDQAgAiADp2oiASACSQ0AIAFBeUkNAQsgAEEANgIEDwsgACACNgIIIABBCDYCBCAAIAE2AgALcAICfwF+AkACQCABrULkAH4iBEIgiKdFBEAgBKciAkF/TA0BAkAgAkUEQEEEIQMMAQsgAkEEENEBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDSAQALENIBAAsgAkEEQdTzwgAoAgAiAEECIAAbEQAAAAtnAQN/IwBBIGsiAiQAAkAgACgCACABEFoNACABQRxqKAIAIQMgASgCGCACQgQ3AxggAkIBNwIMIAJBvIXAADYCCCADIAJBCGoQGw0AIAAoAgQgARBaIAJBIGokAA8LIAJBIGokAEEBC24BAn8CQAJAIAEgAUH/////AHFGBEAgAUEEdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ0QEiA0UNAwsgACABNgIEIAAgAzYCAA8LENIBAAsQ0gEACyACQQRB1PPCACgCACIAQQIgABsRAAAAC24BAn8CQAJAIAEgAUH/////A3FGBEAgAUECdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ0QEiA0UNAwsgACABNgIEIAAgAzYCAA8LENIBAAsQ0gEACyACQQRB1PPCACgCACIAQQIgABsRAAAAC24BAn8CQAJAIAEgAUH/////AXFGBEAgAUEDdCICQX9MDQECQCACRQRAQQQhAwwBCyACQQQQ0QEiA0UNAwsgACABNgIEIAAgAzYCAA8LENIBAAsQ0gEACyACQQRB1PPCACgCACIAQQIgABsRAAAAC2YBAX8gASABQf////8BcUcEQCAAQQA2AgQPCwJAAkAgAUEHakF8cSICIAFBBGpJDQAgAiABQQN0aiIBIAJJDQAgAUF9SQ0BCyAAQQA2AgQPCyAAIAI2AgggAEEENgIEIAAgATYCAAtmAQF/IAEgAUH/////AHFHBEAgAEEANgIEDwsCQAJAIAFBB2pBfHEiAiABQQRqSQ0AIAIgAUEEdGoiASACSQ0AIAFBfUkNAQsgAEEANgIEDwsgACACNgIIIABBBDYCBCAAIAE2AgALbQECfwJAAkAgASABQf///z9xRgRAIAFBBXQiAkF/TA0BAkAgAkUEQEEIIQMMAQsgAkEIENEBIgNFDQMLIAAgATYCBCAAIAM2AgAPCxDSAQALENIBAAsgAkEIQdTzwgAoAgAiAEECIAAbEQAAAAtgAQJ/AkAgACgCACIBRQ0AIABBCGooAgAiAgRAIAJBBHQhAiABQQhqIQEDQCABKAIABEAgAUF8aigCABAhCyABQRBqIQEgAkFwaiICDQALCyAAKAIERQ0AIAAoAgAQIQsLcAEBfyMAQTBrIgIkACACIAE2AgQgAiAANgIAIAJBHGpBAjYCACACQSxqQQU2AgAgAkICNwIMIAJBwILAADYCCCACQQU2AiQgAiACQSBqNgIYIAIgAkEEajYCKCACIAI2AiAgAkEIakHQgsAAEL4BAAttAQF/IwBBMGsiAyQAIAMgAjYCBCADIAE2AgAgA0EcakECNgIAIANBLGpBBTYCACADQgI3AgwgA0GkgMAANgIIIANBBTYCJCADIANBIGo2AhggAyADNgIoIAMgA0EEajYCICADQQhqIAAQvgEAC3ABAX8jAEEwayICJAAgAiABNgIEIAIgADYCACACQRxqQQI2AgAgAkEsakEFNgIAIAJCAjcCDCACQYiDwAA2AgggAkEFNgIkIAIgAkEgajYCGCACIAJBBGo2AiggAiACNgIgIAJBCGpBmIPAABC+AQALVgECfyMAQSBrIgIkACABQRxqKAIAIQMgASgCGCACQRhqIABBEGopAgA3AwAgAkEQaiAAQQhqKQIANwMAIAIgACkCADcDCCADIAJBCGoQGyACQSBqJAALUwAgAEEMaigCAARAIAAoAggQIQsgAEEYaigCAARAIABBFGooAgAQIQsgAEEkaigCAARAIABBIGooAgAQIQsgAEEwaigCAARAIABBLGooAgAQIQsLZAECfyMAQSBrIgIkACABKAIAIQMgAkEIaiABKAIIIgEQnwEgAkEANgIYIAIgAikDCDcDECACQRBqIAMgAyABQQJ0ahC3ASAAQQhqIAIoAhg2AgAgACACKQMQNwIAIAJBIGokAAtaAQN/AkAgAUEIaigCACICIAEoAgQiA08EQCABQRxqKAIAIgQgAkkNASABKAIYIQEgACACIANrNgIEIAAgASADQQJ0ajYCAA8LIAMgAhCnAQALIAIgBBClAQALVwEDfwJAIAFBCGooAgAiAiABKAIEIgNPBEAgAUEkaigCACIEIAJJDQEgASgCICEBIAAgAiADazYCBCAAIAEgA2o2AgAPCyADIAIQpwEACyACIAQQpQEAC2oBAn9BASEAAkACQEHI88IAKAIAQQFHBEBByPPCAEKBgICAEDcDAAwBC0HM88IAQczzwgAoAgBBAWoiADYCACAAQQJLDQELQdDzwgAoAgAiAUF/TA0AQdDzwgAgATYCACAAQQFLDQAACwALWAEDfyAAKAIEKAIAIgMgAigCACIEQQxsakEDIAMgASgCACIFQQxsakEDEJMBQf8BcUH/AUYEQCABIAQ2AgAgAiAFNgIAIAAoAggiACAAKAIAQQFqNgIACwtLAQN/IABBDGooAgAiAwRAIAAoAgQgA0E8bGpBRGohAiADIQEDQCACEKkBIAJBRGohAiABQX9qIgENAAsLIABBADYCACAAIAE2AgwLVAEBfwJAIAFBf0oEQAJAIAFFBEBBASECDAELIAFBARDRASICRQ0CCyAAIAE2AgQgACACNgIADwsQ0gEACyABQQFB1PPCACgCACIAQQIgABsRAAAAC04BAn9BfyABKAI4IgIgACgCOCIDRyACIANJGyICBH8gAgUgAEEgaigCACAAQShqKAIAIAFBIGooAgAgAUEoaigCABCTAQtB/wFxQf8BRgtSAQN/IAAoAgQoAgAiAyACKAIAIgRBAnRqKAIAIAMgASgCACIFQQJ0aigCABCxAQRAIAEgBDYCACACIAU2AgAgACgCCCIAIAAoAgBBAWo2AgALC04BAX8jAEEQayICJAAgAiAAIAFBAUEBEGACQCACKAIAQQFGBEAgAkEIaigCAEUNAUHX7cIAQShBgO7CABC4AQALIAJBEGokAA8LEM0BAAtDAQN/AkAgAkUNAANAIAAtAAAiBCABLQAAIgVGBEAgAUEBaiEBIABBAWohACACQX9qIgINAQwCCwsgBCAFayEDCyADC0oAAn8gAUGAgMQARwRAQQEgACgCGCABIABBHGooAgAoAhARAQANARoLIAJFBEBBAA8LIAAoAhggAiADIABBHGooAgAoAgwRAwALC0YBAn8gACgCCCICIAFLBEAgACgCACACQeQAbGpBnH9qIQMDQCADELwBIANBnH9qIQMgASACQX9qIgJHDQALCyAAIAI2AggLOgEBfyAAIAAoAgggAiABa0ECdiICEIIBIAAgACgCCCIDIAJqNgIIIAAoAgAgA0ECdGogAiABIAIQcgs7AQF/IwBBIGsiAyQAIANCBDcDECADQgE3AgQgAyABNgIcIAMgADYCGCADIANBGGo2AgAgAyACEL4BAAszAQF/IAIEQCAAIQMDQCADIAEtAAA6AAAgA0EBaiEDIAFBAWohASACQX9qIgINAAsLIAALRQECfyMAQRBrIgEkACAAKAIMIQIgACgCCEUEQEG57MIAQStB5OzCABC4AQALIAEgAikCCDcDCCABIAIpAgA3AwAQrQEACysAAkAgAEF8Sw0AIABFBEBBBA8LIAAgAEF9SUECdBDRASIARQ0AIAAPCwALJgAgAEEsaigCAARAIAAoAigQIQsgAEE4aigCAARAIAAoAjQQIQsLKgEBfwJAIAAQAyIBRQ0AIAFBfGotAABBA3FFDQAgAUEAIAAQvwEaCyABCzIBAX8jAEEQayICJAAgAiABNgIMIAIgADYCCCACQbSAwAA2AgQgAkEBNgIAIAIQugEACykBAX8gAgRAIAAhAwNAIAMgAToAACADQQFqIQMgAkF/aiICDQALCyAACz4AQcDuwgAoAgBFBEBBxO7CAEIENwIAQcDuwgBBsKbCADYCAEG47sIAQgA3AgBBzO7CAEEANgIAC0G47sIACz4AQdjuwgAoAgBFBEBB3O7CAEIENwIAQdjuwgBBsKbCADYCAEHQ7sIAQgA3AgBB5O7CAEEANgIAC0HQ7sIACyAAAkAgAUF8Sw0AIAAgAUEEIAIQzgEiAEUNACAADwsACyYAIAAoAgAgASACELIBIAAoAgAgAiADELIBIAAoAgAgASACELIBCyYAIAAoAgAgASACEIYBIAAoAgAgAiADEIYBIAAoAgAgASACEIYBCyYAIAAoAgAgASACEK4BIAAoAgAgAiADEK4BIAAoAgAgASACEK4BCyYBAX8gACgCACIBKAIAIAEoAgQgACgCBCgCACAAKAIIKAIAEBoACxQAIAAgACABIAAgAWMbIAEgAWIbCxwAIAEoAhhB+KnAAEELIAFBHGooAgAoAgwRAwALHAAgASgCGEGDqsAAQQ4gAUEcaigCACgCDBEDAAscACABKAIYQa7swgBBCyABQRxqKAIAKAIMEQMACxQAIAAoAgAgASAAKAIEKAIMEQEACwsAIAEEQCAAECELCxIAQYCAwABBEUGUgMAAELgBAAsMACAAIAEgAiADECILCwAgADUCACABEE4LBgAQrQEACxkAAn8gAUEJTwRAIAEgABBDDAELIAAQAwsLBgAQzQEACw0AQrOa5o7J8obc7QALAwABCwMAAQsLnr0C8gwAQYCAwAAL/AVjYXBhY2l0eSBvdmVyZmxvdwAAAMC2EAAXAAAACQMAAAUAAABEABAAIAAAAGQAEAASAAAACwAAAAAAAAABAAAADAAAAGluZGV4IG91dCBvZiBib3VuZHM6IHRoZSBsZW4gaXMgIGJ1dCB0aGUgaW5kZXggaXMgMDAwMTAyMDMwNDA1MDYwNzA4MDkxMDExMTIxMzE0MTUxNjE3MTgxOTIwMjEyMjIzMjQyNTI2MjcyODI5MzAzMTMyMzMzNDM1MzYzNzM4Mzk0MDQxNDI0MzQ0NDU0NjQ3NDg0OTUwNTE1MjUzNTQ1NTU2NTc1ODU5NjA2MTYyNjM2NDY1NjY2NzY4Njk3MDcxNzI3Mzc0NzU3Njc3Nzg3OTgwODE4MjgzODQ4NTg2ODc4ODg5OTA5MTkyOTM5NDk1OTY5Nzk4OTkAAGABEAAGAAAAZgEQACIAAAD0phAAGAAAAGgKAAAFAAAAaW5kZXggIG91dCBvZiByYW5nZSBmb3Igc2xpY2Ugb2YgbGVuZ3RoIKgBEAAWAAAAvgEQAA0AAAD0phAAGAAAAG4KAAAFAAAAc2xpY2UgaW5kZXggc3RhcnRzIGF0ICBidXQgZW5kcyBhdCBgMHhbLi4uXQB6AhAACwAAAOIUEAAWAAAAywEQAAEAAABkAhAAFgAAAAMIAAAJAAAAwBQQAA4AAADOFBAABAAAANIUEAAQAAAAywEQAAEAAABkAhAAFgAAAAcIAAAFAAAAegIQAAsAAACFAhAAJgAAAKsCEAAIAAAAswIQAAYAAADLARAAAQAAAGQCEAAWAAAAFAgAAAUAAABzcmMvbGliY29yZS9zdHIvbW9kLnJzYnl0ZSBpbmRleCAgaXMgbm90IGEgY2hhciBib3VuZGFyeTsgaXQgaXMgaW5zaWRlICAoYnl0ZXMgKSBvZiBgAAAA+gIQAAIAAADkAhAAFgAAAGAEAAARAAAA5AIQABYAAABUBAAAKAAAAHNyYy9saWJjb3JlL2ZtdC9tb2QucnMuLgBB4IbAAAsO//////////////////8AQZCHwAALAvgDAEGyh8AACwf+/////7+2AEHCh8AACw3/BwAAAAAA+P//AAABAEHah8AACxDAn589AAAAAAIAAAD///8HAEH0h8AAC7gVwP8BAAAAAAAA+A8goA4QAEoAAADwEBAAAAIAAPASEAA6AAAAAAECAwQFBgcICQgKCwwNDg8QERITFAIVFhcYGRobHB0eHyACAgICAgICAgICIQICAgICAgICAgICAgICIiMkJSYCJwIoAgICKSorAiwtLi8wAgIxAgICMgICAgICAgICMwICNAICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICNQI2AjcCAgICAgICAjgCOQICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICOjs8AgICAj0CAj4/QEFCQ0RFRgICAkcCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICSAICAgICAgICAgICSQICAgICOwIAAQICAgIDAgICAgQCBQYCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICBwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAAEDBQUGBgMHBggICREKHAsZDBQNEg4NDwQQAxISEwkWARcFGAIZAxoHHAIdAR8WIAMrBCwCLQsuATADMQIyAacCqQKqBKsI+gL7Bf0E/gP/Ca14eYuNojBXWIuMkBwd3Q4PS0z7/C4vP1xdX7XihI2OkZKpsbq7xcbJyt7k5f8ABBESKTE0Nzo7PUlKXYSOkqmxtLq7xsrOz+TlAAQNDhESKTE0OjtFRklKXmRlhJGbncnOzw0RKUVJV2RljZGptLq7xcnf5OXwBA0RRUlkZYCBhLK8vr/V1/Dxg4WLpKa+v8XHzs/a20iYvc3Gzs9JTk9XWV5fiY6Psba3v8HGx9cRFhdbXPb3/v+ADW1x3t8ODx9ubxwdX31+rq+7vPoWFx4fRkdOT1haXF5+f7XF1NXc8PH1cnOPdHWWly9fJi4vp6+3v8fP19+aQJeYMI8fwMHO/05PWlsHCA8QJy/u725vNz0/QkWQkf7/U2d1yMnQ0djZ5/7/ACBfIoLfBIJECBsEBhGBrA6AqzUeFYDgAxkIAQQvBDQEBwMBBwYHEQpQDxIHVQgCBBwKCQMIAwcDAgMDAwwEBQMLBgEOFQU6AxEHBgUQB1cHAgcVDVAEQwMtAwEEEQYPDDoEHSVfIG0EaiWAyAWCsAMaBoL9A1kHFQsXCRQMFAxqBgoGGgZZBysFRgosBAwEAQMxCywEGgYLA4CsBgoGH0FMBC0DdAg8Aw8DPAc4CCsFgv8RGAgvES0DIBAhD4CMBIKXGQsViJQFLwU7BwIOGAmAsDB0DIDWGgwFgP8FgLYFJAybxgrSMBCEjQM3CYFcFIC4CIDHMDUECgY4CEYIDAZ0Cx4DWgRZCYCDGBwKFglICICKBqukDBcEMaEEgdomBwwFBYClEYFtEHgoKgZMBICNBIC+AxsDDw0ABgEBAwEEAggICQIKBQsCEAERBBIFExEUAhUCFwIZBBwFHQgkAWoDawK8AtEC1AzVCdYC1wLaAeAF4QLoAu4g8AT5BvoCDCc7Pk5Pj56enwYHCTY9Plbz0NEEFBg2N1ZXvTXOz+ASh4mOngQNDhESKTE0OkVGSUpOT2RlWly2txscqKnY2Qk3kJGoBwo7PmZpj5JvX+7vWmKamycoVZ2goaOkp6iturzEBgsMFR06P0VRpqfMzaAHGRoiJT4/xcYEICMlJigzODpISkxQU1VWWFpcXmBjZWZrc3h9f4qkqq+wwNAMcqOky8xub14iewUDBC0DZQQBLy6Agh0DMQ8cBCQJHgUrBUQEDiqAqgYkBCQEKAg0CwGAkIE3CRYKCICYOQNjCAkwFgUhAxsFAUA4BEsFLwQKBwkHQCAnBAwJNgM6BRoHBAwHUEk3Mw0zBy4ICoEmH4CBKAgqgIYXCU4EHg9DDhkHCgZHCScJdQs/QSoGOwUKBlEGAQUQAwWAi2AgSAgKgKZeIkULCgYNEzkHCjYsBBCAwDxkUwwBgKBFG0gIUx05gQdGCh0DR0k3Aw4ICgY5BwqBNhmAxzINg5tmdQuAxIq8hC+P0YJHobmCOQcqBAJgJgpGCigFE4KwW2VLBDkHEUAEHJf4CILzpQ2BHzEDEQQIgYyJBGsFDQMJBxCTYID2CnMIbhdGgJoUDFcJGYCHgUcDhUIPFYVQK4DVLQMaBAKBcDoFAYUAgNcpTAQKBAKDEURMPYDCPAYBBFUFGzQCgQ4sBGQMVgoNA10DPTkdDSwECQcCDgaAmoPWCg0DCwV0DFkHDBQMBDgICgYoCB5SdwMxA4CmDBQEAwUDDQaFagAAAIAOEAAgAAAAJwAAABkAAACADhAAIAAAACgAAAAgAAAAgA4QACAAAAAqAAAAGQAAAIAOEAAgAAAAKwAAABgAAACADhAAIAAAACwAAAAgAAAAc3JjL2xpYmNvcmUvdW5pY29kZS9ib29sX3RyaWUucnMAAMD77z4AAAAAAA4AQbqdwAALkgH4//v///8HAAAAAAAAFP4h/gAMAAAAAgAAAAAAAFAeIIAADAAAQAYAAAAAAAAQhjkCAAAAIwC+IQAADAAA/AIAAAAAAADQHiDAAAwAAAAEAAAAAAAAQAEggAAAAAAAEQAAAAAAAMDBPWAADAAAAAIAAAAAAACQRDBgAAwAAAADAAAAAAAAWB4ggAAMAAAAAIRcgABB1p7AAAsE8geAfwBB5p7AAAsE8h8APwBB857AAAsWAwAAoAIAAAAAAAD+f9/g//7///8fQABBlZ/AAAulAeD9ZgAAAMMBAB4AZCAAIAAAAAAAAADgAAAAAAAAHAAAABwAAAAMAAAADAAAAAAAAACwP0D+DyAAAAAAADgAAAAAAABgAAAAAAIAAAAAAACHAQQOAACACQAAAAAAAEB/5R/4nwAAAAAAAP9/DwAAAAAA8BcEAAAAAPgPAAMAAAA8OwAAAAAAAECjAwAAAAAAAPDPAAAA9//9IRAD//////////sAEABBwqDAAAsN/////wEAAAAAAACAAwBB16DAAAsVgAAAAAD/////AAAAAAD8AAAAAAAGAEH1oMAACweA9z8AAADAAEGGocAACy8DAEQIAABgAAAAMAAAAP//A4AAAAAAwD8AAID/AwAAAAAABwAAAAAAyDMAAAAAIABBvaHAAAsxfmYACBAAAAAAABAAAAAAAACdwQIAAAAAMEAAAAAAACAhAAAAAABAAAAAAP//AAD//wBB96HAAAsHAQAAAAIAAwBBmKLAAAsEBAAABQBBpKLAAAsBBgBBraLAAAs/BwAACAkKAAsMDQ4PAAAQERIAABMUFRYAABcYGRobABwAAAAdAAAAAAAAHh8gIQAAAAAAIgAjACQlJgAAAAAnAEHbo8AACwIoKQBB7aPAAAsCKisAQaKkwAALASwAQbWkwAALBS0uAAAvAEHYpMAACwMwMTIAQfCkwAALDDMAAAApAAAAAAAANABBk6XAAAsDNQA2AEGwpcAACwg3OAAAODg4OQBB/6XAAAsGIAAAAAABAEGOpsAAC1TAB27wAAAAAACHAAAAAGAAAAAAAAAA8AAAAMD/AQAAAAAAAgAAAAAAAP9/AAAAAAAAgAMAAAAAAHgGBwAAAIDvHwAAAAAAAAAIAAMAAAAAAMB/AB4AQe2mwAALGoDTQAAAAID4BwAAAwAAAAAAAFgBAIAAwB8fAEGPp8AACwX/XAAAQABBnqfAAAsD+aUNAEGtp8AACweAPLABAAAwAEG+p8AACwP4pwEAQc2nwAALLyi/AAAAAOC8DwAAAAAAAACA/wYAAPAMAQAAAP4HAAAAAPh5gAB+DgAAAAAA/H8DAEGGqMAACxN/vwAA/P///G0AAAAAAAAAfrS/AEGiqMAACwGjAEGuqMAACx0YAAAAAAAAAB8AAAAAAAAAfwAAgAAAAAAAAACABwBB06jAAAsBYABB3KjAAAtGoMMH+OcPAAAAPAAAHAAAAAAAAAD///////9/+P//////HyAAEAAA+P7/AAB////52wcAAAAAAAAA8AAAAAB/AAAAAADwBwBBrKnAAAv5Av///////////////////////wAAYmVnaW4gPD0gZW5kICggPD0gKSB3aGVuIHNsaWNpbmcgYCBpcyBvdXQgb2YgYm91bmRzIG9mIGBCb3Jyb3dFcnJvckJvcnJvd011dEVycm9yAAAA9KUQAAAAAAAkFRAAAgAAADogAAA4FRAAFQAAAI0EAAAFAAAAc3JjL2xpYmNvcmUvcmVzdWx0LnJzAAECAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAwEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEEBQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAwAAgA4QACAAAAA6AAAAMAAAAAA+AAABAEGwrMAACywgAAAAAQAAAAEAAAAAAAAA/wcAAACDAAAAAACAAAAAAAABAAAAAAAA/////wBB56zAAAsJgAAAAAAAAP8DAEH+rMAACwIMcgBBtK7AAAsC/wMAQcauwAALAv8DAEHgrsAACx3/AwAAAAAAAGAcEAAhAAAAaB0QAAACAABoHxAALgBBha/AAAseAQACAAEAAQADAAQABQABAAYAAQAHAAcIAAAAAAkHAEGtr8AACwEKAEG7r8AACxcLAAAADAcAAAAADQAOAAAPAAAHEAAADwBB4a/AAAsGEQkAABITAEHxr8AACwMUFRYAQf2vwAALAhcYAEGTsMAACwEZAEGgsMAACwsaAAAAAAAbABwdHgBB+LPAAAsYHAAAAQAAAAAfAAAHCQAAIAAHAAAAAAAQAEHctsAAC4QCBwAAAAABAgMDAwQDAwMDAwMFBgcDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMAQey4wAALMMD/AAAAAAAAwP/wAwAAAADA//wAAAAAAMD/BwAAAAAAwP8AfwAAAH/A//8BAAD/AwBBpLnAAAsG//8PAP8DAEG1ucAACxX+/x8AAAAAAMABAAAAAAD/A/8DwP8AQdK5wAALCv8HAAAAAP8D/wMAQea5wAALFP8DAAAAAAAA8QMAAP///////+cDAEGEusAACwj/////////DwBBlbrAAAsO/P//AAAAAAAAwP///w8AQa+6wAALDCCAAAAA/gMABwAAPABBxLrAAAs3/wMAAAD//v8AAAAA/wMAAAAA/v8AAAAAAAA/AAAA/wMAAP8DAAAAAAECAwAAAAAEBQYABwAACABBibvAAAtDCQoLDAANDgAPEBEAEhMAAAAAFBUAAAAAFgAAFxgAAAAZABUaAAAbAAAAFQAAAAAAHAAcAAAAAAAcAB0eAAAAAAAAHwBB2bvAAAsGIAAAABwIAEHnu8AACwEhAEH4u8AACwIiIwBBkb3AAAsFCAAAACQAQaK9wAALASUAQbO9wAALAyYAJwBBx73AAAsBKABB7b3AAAsHHQAAAAAAFQBBi77AAAsDKQAcAEGZvsAACwQqKwAsAEGsvsAACwEtAEHwvsAACxKA//////8PAP////////8BAAwAQYy/wAALDv7//w8AAAAADwAAAAIEAEGiv8AACwE+AEGsv8AACxL/AwAAAAAA/wAAAP4AAAAAgP8AQce/wAALBfgAAMAPAEHXv8AACxUw///8////////AQAAAAAAYAAAAOAAQfW/wAALEfgAAAAAAP8AAAD/AAAAAAD+AEGPwMAACyf8AAAAAAAA/wMAAAAA////fwAAAOB/AAAAAAAeAAAAAAAAAPz///8AQb7AwAALFMD/AAD/A/7/HwAAAP8DAAAAAP8DAEHewMAACzX/DwAAAAD//wcAAAD///8fAAD//x8AAAAAAP////////////////9/AAAAAP/7AwAAAP//fwBBnMHAAAsW//8PAAAAAAD///8BAMD///////+A/wBBvsHAAAsU/v///////+8eAP7/////v/8//x8AQeDBwAALVv7//wf+//8HAAAAAAAEIAT//3////9/////////////////////////////////////////////////////////////////////////////w/8DAB9QAEHAwsAAC4gNIAAAAAAA37xA1///+////////////7///////////////////////wP8///////////////////////////+////fwL//////wEAAAAA/7+2AP///4cHAAAA/wf//////////gDA////////////////7x/+4QCcAAD///////8A4P///////////////wMAAPz///8HMATQJhAAhgAAAAArEACABAAAgC8QAG8AAAAAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHyAhIiMkJCQkJCUmJygpKissJCQkJCQkJCQtLi8wMTIzNDU2Nzg5Ojs8PT4fP0BBQkNERUYkJCRHJCQkJEhJSksfTE0fTk9QHx8fHx8fHx8fHx9RUh8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx9TVCRVVldYWVofHx8fHx8fWyxcXV4kX2AfHx8fHx8fHyQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJDcfJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkYSQkJCQkJCQkJCQkJCQkJCQkJGJjJCQkJGRlJGFmJGdoaWoka2xtbm9DcHFyc3QkdSQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJHZ3Hx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fJCQkJCR4JHl6e3x9JCQkJH4hf4AfgSSCg4RxhQABAgMEBQYHCAUFCQUKCwwHBwcHBwcHBwcHDQ4PBxARBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQX////8/x8AAP///wH/BwAAAAAAAP//3z8AAPD/+AP////////////v/9/h/w8A/v/vn/n///3F459ZgLAPAAMQ7of5///9bcOHGQJeAAA/AO6/+////e3jvxsBAA8AAB7un/n///3t458ZwLAPAAIA7Mc91hjH/8PHHYEAAAAAAO/f/f///f/j3x1gBw8AAADv3/3///3v498dYEAPAAYA79/9/////+ffXfCADwAA/Oz/f/z///svf4Bf/wAADAD+////////B38gAAAAAAAA1vf//6///ztfIADwAAAAAAEAAAAAAAAA//7///8f/v8D///+////HwBB0M/AAAucAf///////3/5AAD//////////wA8/////78g///////3////////////PX89//////89/////z1/Pf9//////////z3//////////wcAAAAA//8AAP////////////8/P/7///////////////+f///+//8H////////////x/8B/98PAP//DwD//w8A/98NAP///////8///wGAEABB9NDAAAveAf//////////////Af//////B///////////PwD///9//w//AQAA////Px8A//////8P////AwAAAAAAAP///w//////////f/7/HwAAAAAAgAAAAP///////+//7w8AAAAAAAD///////MA/P////+//wMA////////fwAA4AD8////P/8B///////nAAAAAADebwQAAAAAgP8fAP//Pz//////Pz//qv///z/////////fX9wfzw//H9wfAAAAAAAAAoAAAP8fAAAAAIT8Lz5Qvf/z4EMAAP//////AQBB3tLAAAuEAcD///////8DAAD//////3///////3///////////x94DAD/////vyD/////////gAAA//9/AH9/f39/f39//////wAAAAAAgAAA4AAAAP4DPh///3/g/v/////////////34P///////v//fwAA////BwAAAAAAAP//////////AAD/HwBB6tPAAAu2C///////P/8f//8ADAAA//////9/8I8AAID//P/////5////////fAAAAAAAgP+//////wAAAP///////w8ALwAAAAAA/OgA/P///wf/////BwD///8f////////9/8AgAAA//8AfP8/AAD//3/8/////////38FAAA4//88AH5+fgB/f///////9/8A/////////wcAAP////8PAP//f/j//////w///////z///////wMAAAAAfwD44P/9f1/b/////////////////wMAAAD4////////////////P////P///////wAAAAAA/w8AAAAAAADf//////////8fAAAAAP7//wf+//8HwP////z8/BwAAAAAAAECAwQFBAQEBAYHCAkKCwICDA0ODwQEAgICAhARBAQSExQVFgQXBBgZGhscHR4EAh8gICEEBAQEBAQEIiMEJAIjJSYgJwIoKQQqKywtBAQCLgIvBAQwMTIzNAQ1BAQENgQ3OAQEOTo7PD02BAQEBD4/QARBQkMEBAQEJAQEBAQCAgICAgICAgICAgICAkQEAkUCAgJGBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAgICAgICAgICAgICAkUEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAgJHBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCAgICAgICAjYUBEhJSksEBAQEBAQEBAQEAgQEAkxNTgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICTwICAgICAgICAgICIAQEBAQEBAQEBAQEBAQEBAQEBAQEAgICAhRQAgICAgJRBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAlJTBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQCVFVWV1gCAgICWVpbXF1eBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBARfBAQEYGEEBAQEBGIEBAQEBAQEBAQEBAQEBAQEBAQEBAICAmMCZAQEBAQEBAQEBAQEBAQEBAQEBGVmZwQEBAQEBAQEBGhpagQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICAgICAgICAgICAgICAgICAgICAmsCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICBQICAgoCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAmwCAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICbQQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAICAgICAgICbgQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE/+///3///7f/P/8/AAAAAP///////////////////wcAQajfwAALfP///////x8A////H////////wEAAAAAAP////8A4P///wf//////wf///8//////w//PgAAAAAA////PwAA/////w//////D///////AP///////w8AAAD///////9/AP//PwD/AAAAP/3/////v5H//z8A//9/AP///38AQazgwAALbf//NwD//z8A////A//////////Ab/Dv/v//PwAAAAAA////H////x8AAAAA//7//x8AAAD///////8/AP//PwD//wcA//8DAAAAAAD/AQAAAAAAAP///////wcA//////8AAAD///8fgAD//z8AQaThwAALlQL//38A/P///////wEAAP///wEAAHAA/////0cAHgAAFAAAAAD///v///+fQH+9/7//Af////////8BAADvn/n///3t458ZgeAPAAAAuwcAgAAAAACzAAAAAAAAAP///////z9/AAAAPwAAAAD/////////fxEAAAAAAAAA////////PwH////n/wcAAP////////8BAAAAAP//////////AAAAgAAAAAD//P///////BoAAAD////////nfwAA////////////IAAAAAD//f////9/fwEAAAAAAPz////8///+fwB/+/////9/tMsAAAC//f///397AQAAAAD///8DAAAAAP//////fwAADwAAAAAAAAB/AEHC48AACyr///8/AAD///////8AAA8AAAD4///g//8AAAAAAAD/h/////////+A//8AQfTjwAALgAILAAAA/////////wAAAAcA8AD///////////8P//////8H/x//Af9DAAAAAP//3///////////32Te/+vv/////////7/n39////97X/z9////////////P/////3///f////3///f////3///f////3/////9/////f//9w8AAAAAAAB////52wcAAP//////H4A/AEAAAAAAAAD//////w8AAB8AAAAAAAAAjwgAAAAAAADv////lv73CoTqlqqW9/de//v/D+77/w8AAAAAAAD///8D////A////wMAAAAAAAD//38AAAAAAP////8DAP///////wEAAAD///8/AEGA5sAACwT+//8HAEGQ5sAACzL//39/AAAAAFVVVVVVVVWqqlRVVVVVVSvWztux1dKuEZCkqkpVVdJVVVVVVVVVBWx6VQBB5ubAAAs9RYBA1/7/+w8AAACAHFVVVZDm////////AAAAAAAAVVVVVQFUVVVVVVVVqypVVVVVVVVVVVVVVVX+////fwBB+OfAAAsV8DgQABoAAADAORAAwAEAAIA7EAAaAEGy6MAACwIBAgBBvujAAAsCAQMAQeLowAALFQQAAAAAAAUFBgUHCAkKAAAAAAsMDQBBgunAAAsCDg8AQaDpwAALBBARBRIAQYntwAALBxMUABUWFxgAQezvwAALhAIZAAAAAAECAgICAwICAgICAgQFBgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgBB/PHAAAs6/////78gAAAAAAAA////////PwAAAP//////51VVVVVVVVVVVVUVQFVVVVUA/wA/AP8A/wA/AKoA/wBBv/LAAAsaDwAPAA8AHwAPhDgnPlA9D8AgAAAA//8AAAgAQebywAALLsD///8AAAAAAAD//////38AAAAAAACd6iXAVVVVVQUoBABVVVVVVRUAAFVVVQUAQZzzwAALFVRVVFVVVVVVVVUAalUoRVVVfV9VdABBvPPAAAsE/v//BwBB0PPAAAsEAQACAwBB8vPAAAsBBABBovTAAAsBBQBBufXAAAsBBgBB0PXAAAsQBwgJCgsMDQ4PEBESExQVFgBBpPbAAAsBFwBBxPbAAAsDAhgZAEGI98AACwX//////wBBlvfAAAu/Af////8PAAAAAAD///////8HAAAAAAD//////////wAAAAD///8DAADw//8/AAAA////AwAA0GTePwAAAP///wMAALDn3x8AAAB7X/wBAADw//8/AAAA////AwAA8P//PwAAAP///wMAAPD//z8AAAD///8DAAAA////AQAAAPz//wcAAADw//8fAAAAwP//fwAAAAD///8BAAAABAAAAAAAAP////8DAAAA/wP///8D////AwAAAAAAAEEAAABhAEHg+MAACwVCAAAAYgBB8PjAAAsFQwAAAGMAQYD5wAALBUQAAABkAEGQ+cAACwVFAAAAZQBBoPnAAAsFRgAAAGYAQbD5wAALBUcAAABnAEHA+cAACwVIAAAAaABB0PnAAAsFSQAAAGkAQeD5wAALBUoAAABqAEHw+cAACwVLAAAAawBBgPrAAAsFTAAAAGwAQZD6wAALBU0AAABtAEGg+sAACwVOAAAAbgBBsPrAAAsFTwAAAG8AQcD6wAALBVAAAABwAEHQ+sAACwVRAAAAcQBB4PrAAAsFUgAAAHIAQfD6wAALBVMAAABzAEGA+8AACwVUAAAAdABBkPvAAAsFVQAAAHUAQaD7wAALBVYAAAB2AEGw+8AACwVXAAAAdwBBwPvAAAsFWAAAAHgAQdD7wAALBVkAAAB5AEHg+8AACwVaAAAAegBB8PvAAAsFwAAAAOAAQYD8wAALBcEAAADhAEGQ/MAACwXCAAAA4gBBoPzAAAsFwwAAAOMAQbD8wAALBcQAAADkAEHA/MAACwXFAAAA5QBB0PzAAAsFxgAAAOYAQeD8wAALBccAAADnAEHw/MAACwXIAAAA6ABBgP3AAAsFyQAAAOkAQZD9wAALBcoAAADqAEGg/cAACwXLAAAA6wBBsP3AAAsFzAAAAOwAQcD9wAALBc0AAADtAEHQ/cAACwXOAAAA7gBB4P3AAAsFzwAAAO8AQfD9wAALBdAAAADwAEGA/sAACwXRAAAA8QBBkP7AAAsF0gAAAPIAQaD+wAALBdMAAADzAEGw/sAACwXUAAAA9ABBwP7AAAsF1QAAAPUAQdD+wAALBdYAAAD2AEHg/sAACwXYAAAA+ABB8P7AAAsF2QAAAPkAQYD/wAALBdoAAAD6AEGQ/8AACwXbAAAA+wBBoP/AAAsF3AAAAPwAQbD/wAALBd0AAAD9AEHA/8AACwXeAAAA/gBB0f/AAAsFAQAAAQEAQeD/wAALBgIBAAADAQBB8P/AAAsGBAEAAAUBAEGAgMEACwYGAQAABwEAQZCAwQALBggBAAAJAQBBoIDBAAsGCgEAAAsBAEGwgMEACwYMAQAADQEAQcCAwQALBg4BAAAPAQBB0IDBAAsGEAEAABEBAEHggMEACwYSAQAAEwEAQfCAwQALBhQBAAAVAQBBgIHBAAsGFgEAABcBAEGQgcEACwYYAQAAGQEAQaCBwQALBhoBAAAbAQBBsIHBAAsGHAEAAB0BAEHAgcEACwYeAQAAHwEAQdCBwQALBiABAAAhAQBB4IHBAAsGIgEAACMBAEHwgcEACwYkAQAAJQEAQYCCwQALBiYBAAAnAQBBkILBAAsGKAEAACkBAEGggsEACwYqAQAAKwEAQbCCwQALBiwBAAAtAQBBwILBAAsGLgEAAC8BAEHQgsEACxYwAQAAaQAAAAcDAAAAAAAAMgEAADMBAEHwgsEACwY0AQAANQEAQYCDwQALBjYBAAA3AQBBkIPBAAsGOQEAADoBAEGgg8EACwY7AQAAPAEAQbCDwQALBj0BAAA+AQBBwIPBAAsGPwEAAEABAEHQg8EACwZBAQAAQgEAQeCDwQALBkMBAABEAQBB8IPBAAsGRQEAAEYBAEGAhMEACwZHAQAASAEAQZCEwQALBkoBAABLAQBBoITBAAsGTAEAAE0BAEGwhMEACwZOAQAATwEAQcCEwQALBlABAABRAQBB0ITBAAsGUgEAAFMBAEHghMEACwZUAQAAVQEAQfCEwQALBlYBAABXAQBBgIXBAAsGWAEAAFkBAEGQhcEACwZaAQAAWwEAQaCFwQALBlwBAABdAQBBsIXBAAsGXgEAAF8BAEHAhcEACwZgAQAAYQEAQdCFwQALBmIBAABjAQBB4IXBAAsGZAEAAGUBAEHwhcEACwZmAQAAZwEAQYCGwQALBmgBAABpAQBBkIbBAAsGagEAAGsBAEGghsEACwZsAQAAbQEAQbCGwQALBm4BAABvAQBBwIbBAAsGcAEAAHEBAEHQhsEACwZyAQAAcwEAQeCGwQALBnQBAAB1AQBB8IbBAAsGdgEAAHcBAEGAh8EACwV4AQAA/wBBkIfBAAsGeQEAAHoBAEGgh8EACwZ7AQAAfAEAQbCHwQALBn0BAAB+AQBBwIfBAAsGgQEAAFMCAEHQh8EACwaCAQAAgwEAQeCHwQALBoQBAACFAQBB8IfBAAsGhgEAAFQCAEGAiMEACwaHAQAAiAEAQZCIwQALBokBAABWAgBBoIjBAAsGigEAAFcCAEGwiMEACwaLAQAAjAEAQcCIwQALBo4BAADdAQBB0IjBAAsGjwEAAFkCAEHgiMEACwaQAQAAWwIAQfCIwQALBpEBAACSAQBBgInBAAsGkwEAAGACAEGQicEACwaUAQAAYwIAQaCJwQALBpYBAABpAgBBsInBAAsGlwEAAGgCAEHAicEACwaYAQAAmQEAQdCJwQALBpwBAABvAgBB4InBAAsGnQEAAHICAEHwicEACwafAQAAdQIAQYCKwQALBqABAAChAQBBkIrBAAsGogEAAKMBAEGgisEACwakAQAApQEAQbCKwQALBqYBAACAAgBBwIrBAAsGpwEAAKgBAEHQisEACwapAQAAgwIAQeCKwQALBqwBAACtAQBB8IrBAAsGrgEAAIgCAEGAi8EACwavAQAAsAEAQZCLwQALBrEBAACKAgBBoIvBAAsGsgEAAIsCAEGwi8EACwazAQAAtAEAQcCLwQALBrUBAAC2AQBB0IvBAAsGtwEAAJICAEHgi8EACwa4AQAAuQEAQfCLwQALBrwBAAC9AQBBgIzBAAsGxAEAAMYBAEGQjMEACwbFAQAAxgEAQaCMwQALBscBAADJAQBBsIzBAAsGyAEAAMkBAEHAjMEACwbKAQAAzAEAQdCMwQALBssBAADMAQBB4IzBAAsGzQEAAM4BAEHwjMEACwbPAQAA0AEAQYCNwQALBtEBAADSAQBBkI3BAAsG0wEAANQBAEGgjcEACwbVAQAA1gEAQbCNwQALBtcBAADYAQBBwI3BAAsG2QEAANoBAEHQjcEACwbbAQAA3AEAQeCNwQALBt4BAADfAQBB8I3BAAsG4AEAAOEBAEGAjsEACwbiAQAA4wEAQZCOwQALBuQBAADlAQBBoI7BAAsG5gEAAOcBAEGwjsEACwboAQAA6QEAQcCOwQALBuoBAADrAQBB0I7BAAsG7AEAAO0BAEHgjsEACwbuAQAA7wEAQfCOwQALBvEBAADzAQBBgI/BAAsG8gEAAPMBAEGQj8EACwb0AQAA9QEAQaCPwQALBvYBAACVAQBBsI/BAAsG9wEAAL8BAEHAj8EACwb4AQAA+QEAQdCPwQALBvoBAAD7AQBB4I/BAAsG/AEAAP0BAEHwj8EACwb+AQAA/wEAQYGQwQALBQIAAAECAEGQkMEACwYCAgAAAwIAQaCQwQALBgQCAAAFAgBBsJDBAAsGBgIAAAcCAEHAkMEACwYIAgAACQIAQdCQwQALBgoCAAALAgBB4JDBAAsGDAIAAA0CAEHwkMEACwYOAgAADwIAQYCRwQALBhACAAARAgBBkJHBAAsGEgIAABMCAEGgkcEACwYUAgAAFQIAQbCRwQALBhYCAAAXAgBBwJHBAAsGGAIAABkCAEHQkcEACwYaAgAAGwIAQeCRwQALBhwCAAAdAgBB8JHBAAsGHgIAAB8CAEGAksEACwYgAgAAngEAQZCSwQALBiICAAAjAgBBoJLBAAsGJAIAACUCAEGwksEACwYmAgAAJwIAQcCSwQALBigCAAApAgBB0JLBAAsGKgIAACsCAEHgksEACwYsAgAALQIAQfCSwQALBi4CAAAvAgBBgJPBAAsGMAIAADECAEGQk8EACwYyAgAAMwIAQaCTwQALBjoCAABlLABBsJPBAAsGOwIAADwCAEHAk8EACwY9AgAAmgEAQdCTwQALBj4CAABmLABB4JPBAAsGQQIAAEICAEHwk8EACwZDAgAAgAEAQYCUwQALBkQCAACJAgBBkJTBAAsGRQIAAIwCAEGglMEACwZGAgAARwIAQbCUwQALBkgCAABJAgBBwJTBAAsGSgIAAEsCAEHQlMEACwZMAgAATQIAQeCUwQALBk4CAABPAgBB8JTBAAsGcAMAAHEDAEGAlcEACwZyAwAAcwMAQZCVwQALBnYDAAB3AwBBoJXBAAsGfwMAAPMDAEGwlcEACwaGAwAArAMAQcCVwQALBogDAACtAwBB0JXBAAsGiQMAAK4DAEHglcEACwaKAwAArwMAQfCVwQALBowDAADMAwBBgJbBAAsGjgMAAM0DAEGQlsEACwaPAwAAzgMAQaCWwQALBpEDAACxAwBBsJbBAAsGkgMAALIDAEHAlsEACwaTAwAAswMAQdCWwQALBpQDAAC0AwBB4JbBAAsGlQMAALUDAEHwlsEACwaWAwAAtgMAQYCXwQALBpcDAAC3AwBBkJfBAAsGmAMAALgDAEGgl8EACwaZAwAAuQMAQbCXwQALBpoDAAC6AwBBwJfBAAsGmwMAALsDAEHQl8EACwacAwAAvAMAQeCXwQALBp0DAAC9AwBB8JfBAAsGngMAAL4DAEGAmMEACwafAwAAvwMAQZCYwQALBqADAADAAwBBoJjBAAsGoQMAAMEDAEGwmMEACwajAwAAwwMAQcCYwQALBqQDAADEAwBB0JjBAAsGpQMAAMUDAEHgmMEACwamAwAAxgMAQfCYwQALBqcDAADHAwBBgJnBAAsGqAMAAMgDAEGQmcEACwapAwAAyQMAQaCZwQALBqoDAADKAwBBsJnBAAsGqwMAAMsDAEHAmcEACwbPAwAA1wMAQdCZwQALBtgDAADZAwBB4JnBAAsG2gMAANsDAEHwmcEACwbcAwAA3QMAQYCawQALBt4DAADfAwBBkJrBAAsG4AMAAOEDAEGgmsEACwbiAwAA4wMAQbCawQALBuQDAADlAwBBwJrBAAsG5gMAAOcDAEHQmsEACwboAwAA6QMAQeCawQALBuoDAADrAwBB8JrBAAsG7AMAAO0DAEGAm8EACwbuAwAA7wMAQZCbwQALBvQDAAC4AwBBoJvBAAsG9wMAAPgDAEGwm8EACwb5AwAA8gMAQcCbwQALBvoDAAD7AwBB0JvBAAsG/QMAAHsDAEHgm8EACwb+AwAAfAMAQfCbwQALBv8DAAB9AwBBgZzBAAsFBAAAUAQAQZCcwQALBgEEAABRBABBoJzBAAsGAgQAAFIEAEGwnMEACwYDBAAAUwQAQcCcwQALBgQEAABUBABB0JzBAAsGBQQAAFUEAEHgnMEACwYGBAAAVgQAQfCcwQALBgcEAABXBABBgJ3BAAsGCAQAAFgEAEGQncEACwYJBAAAWQQAQaCdwQALBgoEAABaBABBsJ3BAAsGCwQAAFsEAEHAncEACwYMBAAAXAQAQdCdwQALBg0EAABdBABB4J3BAAsGDgQAAF4EAEHwncEACwYPBAAAXwQAQYCewQALBhAEAAAwBABBkJ7BAAsGEQQAADEEAEGgnsEACwYSBAAAMgQAQbCewQALBhMEAAAzBABBwJ7BAAsGFAQAADQEAEHQnsEACwYVBAAANQQAQeCewQALBhYEAAA2BABB8J7BAAsGFwQAADcEAEGAn8EACwYYBAAAOAQAQZCfwQALBhkEAAA5BABBoJ/BAAsGGgQAADoEAEGwn8EACwYbBAAAOwQAQcCfwQALBhwEAAA8BABB0J/BAAsGHQQAAD0EAEHgn8EACwYeBAAAPgQAQfCfwQALBh8EAAA/BABBgKDBAAsGIAQAAEAEAEGQoMEACwYhBAAAQQQAQaCgwQALBiIEAABCBABBsKDBAAsGIwQAAEMEAEHAoMEACwYkBAAARAQAQdCgwQALBiUEAABFBABB4KDBAAsGJgQAAEYEAEHwoMEACwYnBAAARwQAQYChwQALBigEAABIBABBkKHBAAsGKQQAAEkEAEGgocEACwYqBAAASgQAQbChwQALBisEAABLBABBwKHBAAsGLAQAAEwEAEHQocEACwYtBAAATQQAQeChwQALBi4EAABOBABB8KHBAAsGLwQAAE8EAEGAosEACwZgBAAAYQQAQZCiwQALBmIEAABjBABBoKLBAAsGZAQAAGUEAEGwosEACwZmBAAAZwQAQcCiwQALBmgEAABpBABB0KLBAAsGagQAAGsEAEHgosEACwZsBAAAbQQAQfCiwQALBm4EAABvBABBgKPBAAsGcAQAAHEEAEGQo8EACwZyBAAAcwQAQaCjwQALBnQEAAB1BABBsKPBAAsGdgQAAHcEAEHAo8EACwZ4BAAAeQQAQdCjwQALBnoEAAB7BABB4KPBAAsGfAQAAH0EAEHwo8EACwZ+BAAAfwQAQYCkwQALBoAEAACBBABBkKTBAAsGigQAAIsEAEGgpMEACwaMBAAAjQQAQbCkwQALBo4EAACPBABBwKTBAAsGkAQAAJEEAEHQpMEACwaSBAAAkwQAQeCkwQALBpQEAACVBABB8KTBAAsGlgQAAJcEAEGApcEACwaYBAAAmQQAQZClwQALBpoEAACbBABBoKXBAAsGnAQAAJ0EAEGwpcEACwaeBAAAnwQAQcClwQALBqAEAAChBABB0KXBAAsGogQAAKMEAEHgpcEACwakBAAApQQAQfClwQALBqYEAACnBABBgKbBAAsGqAQAAKkEAEGQpsEACwaqBAAAqwQAQaCmwQALBqwEAACtBABBsKbBAAsGrgQAAK8EAEHApsEACwawBAAAsQQAQdCmwQALBrIEAACzBABB4KbBAAsGtAQAALUEAEHwpsEACwa2BAAAtwQAQYCnwQALBrgEAAC5BABBkKfBAAsGugQAALsEAEGgp8EACwa8BAAAvQQAQbCnwQALBr4EAAC/BABBwKfBAAsGwAQAAM8EAEHQp8EACwbBBAAAwgQAQeCnwQALBsMEAADEBABB8KfBAAsGxQQAAMYEAEGAqMEACwbHBAAAyAQAQZCowQALBskEAADKBABBoKjBAAsGywQAAMwEAEGwqMEACwbNBAAAzgQAQcCowQALBtAEAADRBABB0KjBAAsG0gQAANMEAEHgqMEACwbUBAAA1QQAQfCowQALBtYEAADXBABBgKnBAAsG2AQAANkEAEGQqcEACwbaBAAA2wQAQaCpwQALBtwEAADdBABBsKnBAAsG3gQAAN8EAEHAqcEACwbgBAAA4QQAQdCpwQALBuIEAADjBABB4KnBAAsG5AQAAOUEAEHwqcEACwbmBAAA5wQAQYCqwQALBugEAADpBABBkKrBAAsG6gQAAOsEAEGgqsEACwbsBAAA7QQAQbCqwQALBu4EAADvBABBwKrBAAsG8AQAAPEEAEHQqsEACwbyBAAA8wQAQeCqwQALBvQEAAD1BABB8KrBAAsG9gQAAPcEAEGAq8EACwb4BAAA+QQAQZCrwQALBvoEAAD7BABBoKvBAAsG/AQAAP0EAEGwq8EACwb+BAAA/wQAQcGrwQALBQUAAAEFAEHQq8EACwYCBQAAAwUAQeCrwQALBgQFAAAFBQBB8KvBAAsGBgUAAAcFAEGArMEACwYIBQAACQUAQZCswQALBgoFAAALBQBBoKzBAAsGDAUAAA0FAEGwrMEACwYOBQAADwUAQcCswQALBhAFAAARBQBB0KzBAAsGEgUAABMFAEHgrMEACwYUBQAAFQUAQfCswQALBhYFAAAXBQBBgK3BAAsGGAUAABkFAEGQrcEACwYaBQAAGwUAQaCtwQALBhwFAAAdBQBBsK3BAAsGHgUAAB8FAEHArcEACwYgBQAAIQUAQdCtwQALBiIFAAAjBQBB4K3BAAsGJAUAACUFAEHwrcEACwYmBQAAJwUAQYCuwQALBigFAAApBQBBkK7BAAsGKgUAACsFAEGgrsEACwYsBQAALQUAQbCuwQALBi4FAAAvBQBBwK7BAAsGMQUAAGEFAEHQrsEACwYyBQAAYgUAQeCuwQALBjMFAABjBQBB8K7BAAsGNAUAAGQFAEGAr8EACwY1BQAAZQUAQZCvwQALBjYFAABmBQBBoK/BAAsGNwUAAGcFAEGwr8EACwY4BQAAaAUAQcCvwQALBjkFAABpBQBB0K/BAAsGOgUAAGoFAEHgr8EACwY7BQAAawUAQfCvwQALBjwFAABsBQBBgLDBAAsGPQUAAG0FAEGQsMEACwY+BQAAbgUAQaCwwQALBj8FAABvBQBBsLDBAAsGQAUAAHAFAEHAsMEACwZBBQAAcQUAQdCwwQALBkIFAAByBQBB4LDBAAsGQwUAAHMFAEHwsMEACwZEBQAAdAUAQYCxwQALBkUFAAB1BQBBkLHBAAsGRgUAAHYFAEGgscEACwZHBQAAdwUAQbCxwQALBkgFAAB4BQBBwLHBAAsGSQUAAHkFAEHQscEACwZKBQAAegUAQeCxwQALBksFAAB7BQBB8LHBAAsGTAUAAHwFAEGAssEACwZNBQAAfQUAQZCywQALBk4FAAB+BQBBoLLBAAsGTwUAAH8FAEGwssEACwZQBQAAgAUAQcCywQALBlEFAACBBQBB0LLBAAsGUgUAAIIFAEHgssEACwZTBQAAgwUAQfCywQALBlQFAACEBQBBgLPBAAsGVQUAAIUFAEGQs8EACwZWBQAAhgUAQaCzwQALBqAQAAAALQBBsLPBAAsGoRAAAAEtAEHAs8EACwaiEAAAAi0AQdCzwQALBqMQAAADLQBB4LPBAAsGpBAAAAQtAEHws8EACwalEAAABS0AQYC0wQALBqYQAAAGLQBBkLTBAAsGpxAAAActAEGgtMEACwaoEAAACC0AQbC0wQALBqkQAAAJLQBBwLTBAAsGqhAAAAotAEHQtMEACwarEAAACy0AQeC0wQALBqwQAAAMLQBB8LTBAAsGrRAAAA0tAEGAtcEACwauEAAADi0AQZC1wQALBq8QAAAPLQBBoLXBAAsGsBAAABAtAEGwtcEACwaxEAAAES0AQcC1wQALBrIQAAASLQBB0LXBAAsGsxAAABMtAEHgtcEACwa0EAAAFC0AQfC1wQALBrUQAAAVLQBBgLbBAAsGthAAABYtAEGQtsEACwa3EAAAFy0AQaC2wQALBrgQAAAYLQBBsLbBAAsGuRAAABktAEHAtsEACwa6EAAAGi0AQdC2wQALBrsQAAAbLQBB4LbBAAsGvBAAABwtAEHwtsEACwa9EAAAHS0AQYC3wQALBr4QAAAeLQBBkLfBAAsGvxAAAB8tAEGgt8EACwbAEAAAIC0AQbC3wQALBsEQAAAhLQBBwLfBAAsGwhAAACItAEHQt8EACwbDEAAAIy0AQeC3wQALBsQQAAAkLQBB8LfBAAsGxRAAACUtAEGAuMEACwbHEAAAJy0AQZC4wQALBs0QAAAtLQBBoLjBAAsGoBMAAHCrAEGwuMEACwahEwAAcasAQcC4wQALBqITAAByqwBB0LjBAAsGoxMAAHOrAEHguMEACwakEwAAdKsAQfC4wQALBqUTAAB1qwBBgLnBAAsGphMAAHarAEGQucEACwanEwAAd6sAQaC5wQALBqgTAAB4qwBBsLnBAAsGqRMAAHmrAEHAucEACwaqEwAAeqsAQdC5wQALBqsTAAB7qwBB4LnBAAsGrBMAAHyrAEHwucEACwatEwAAfasAQYC6wQALBq4TAAB+qwBBkLrBAAsGrxMAAH+rAEGgusEACwawEwAAgKsAQbC6wQALBrETAACBqwBBwLrBAAsGshMAAIKrAEHQusEACwazEwAAg6sAQeC6wQALBrQTAACEqwBB8LrBAAsGtRMAAIWrAEGAu8EACwa2EwAAhqsAQZC7wQALBrcTAACHqwBBoLvBAAsGuBMAAIirAEGwu8EACwa5EwAAiasAQcC7wQALBroTAACKqwBB0LvBAAsGuxMAAIurAEHgu8EACwa8EwAAjKsAQfC7wQALBr0TAACNqwBBgLzBAAsGvhMAAI6rAEGQvMEACwa/EwAAj6sAQaC8wQALBsATAACQqwBBsLzBAAsGwRMAAJGrAEHAvMEACwbCEwAAkqsAQdC8wQALBsMTAACTqwBB4LzBAAsGxBMAAJSrAEHwvMEACwbFEwAAlasAQYC9wQALBsYTAACWqwBBkL3BAAsGxxMAAJerAEGgvcEACwbIEwAAmKsAQbC9wQALBskTAACZqwBBwL3BAAsGyhMAAJqrAEHQvcEACwbLEwAAm6sAQeC9wQALBswTAACcqwBB8L3BAAsGzRMAAJ2rAEGAvsEACwbOEwAAnqsAQZC+wQALBs8TAACfqwBBoL7BAAsG0BMAAKCrAEGwvsEACwbREwAAoasAQcC+wQALBtITAACiqwBB0L7BAAsG0xMAAKOrAEHgvsEACwbUEwAApKsAQfC+wQALBtUTAAClqwBBgL/BAAsG1hMAAKarAEGQv8EACwbXEwAAp6sAQaC/wQALBtgTAACoqwBBsL/BAAsG2RMAAKmrAEHAv8EACwbaEwAAqqsAQdC/wQALBtsTAACrqwBB4L/BAAsG3BMAAKyrAEHwv8EACwbdEwAArasAQYDAwQALBt4TAACuqwBBkMDBAAsG3xMAAK+rAEGgwMEACwbgEwAAsKsAQbDAwQALBuETAACxqwBBwMDBAAsG4hMAALKrAEHQwMEACwbjEwAAs6sAQeDAwQALBuQTAAC0qwBB8MDBAAsG5RMAALWrAEGAwcEACwbmEwAAtqsAQZDBwQALBucTAAC3qwBBoMHBAAsG6BMAALirAEGwwcEACwbpEwAAuasAQcDBwQALBuoTAAC6qwBB0MHBAAsG6xMAALurAEHgwcEACwbsEwAAvKsAQfDBwQALBu0TAAC9qwBBgMLBAAsG7hMAAL6rAEGQwsEACwbvEwAAv6sAQaDCwQALBvATAAD4EwBBsMLBAAsG8RMAAPkTAEHAwsEACwbyEwAA+hMAQdDCwQALBvMTAAD7EwBB4MLBAAsG9BMAAPwTAEHwwsEACwb1EwAA/RMAQYDDwQALBpAcAADQEABBkMPBAAsGkRwAANEQAEGgw8EACwaSHAAA0hAAQbDDwQALBpMcAADTEABBwMPBAAsGlBwAANQQAEHQw8EACwaVHAAA1RAAQeDDwQALBpYcAADWEABB8MPBAAsGlxwAANcQAEGAxMEACwaYHAAA2BAAQZDEwQALBpkcAADZEABBoMTBAAsGmhwAANoQAEGwxMEACwabHAAA2xAAQcDEwQALBpwcAADcEABB0MTBAAsGnRwAAN0QAEHgxMEACwaeHAAA3hAAQfDEwQALBp8cAADfEABBgMXBAAsGoBwAAOAQAEGQxcEACwahHAAA4RAAQaDFwQALBqIcAADiEABBsMXBAAsGoxwAAOMQAEHAxcEACwakHAAA5BAAQdDFwQALBqUcAADlEABB4MXBAAsGphwAAOYQAEHwxcEACwanHAAA5xAAQYDGwQALBqgcAADoEABBkMbBAAsGqRwAAOkQAEGgxsEACwaqHAAA6hAAQbDGwQALBqscAADrEABBwMbBAAsGrBwAAOwQAEHQxsEACwatHAAA7RAAQeDGwQALBq4cAADuEABB8MbBAAsGrxwAAO8QAEGAx8EACwawHAAA8BAAQZDHwQALBrEcAADxEABBoMfBAAsGshwAAPIQAEGwx8EACwazHAAA8xAAQcDHwQALBrQcAAD0EABB0MfBAAsGtRwAAPUQAEHgx8EACwa2HAAA9hAAQfDHwQALBrccAAD3EABBgMjBAAsGuBwAAPgQAEGQyMEACwa5HAAA+RAAQaDIwQALBrocAAD6EABBsMjBAAsGvRwAAP0QAEHAyMEACwa+HAAA/hAAQdDIwQALBr8cAAD/EABB4cjBAAsFHgAAAR4AQfDIwQALBgIeAAADHgBBgMnBAAsGBB4AAAUeAEGQycEACwYGHgAABx4AQaDJwQALBggeAAAJHgBBsMnBAAsGCh4AAAseAEHAycEACwYMHgAADR4AQdDJwQALBg4eAAAPHgBB4MnBAAsGEB4AABEeAEHwycEACwYSHgAAEx4AQYDKwQALBhQeAAAVHgBBkMrBAAsGFh4AABceAEGgysEACwYYHgAAGR4AQbDKwQALBhoeAAAbHgBBwMrBAAsGHB4AAB0eAEHQysEACwYeHgAAHx4AQeDKwQALBiAeAAAhHgBB8MrBAAsGIh4AACMeAEGAy8EACwYkHgAAJR4AQZDLwQALBiYeAAAnHgBBoMvBAAsGKB4AACkeAEGwy8EACwYqHgAAKx4AQcDLwQALBiweAAAtHgBB0MvBAAsGLh4AAC8eAEHgy8EACwYwHgAAMR4AQfDLwQALBjIeAAAzHgBBgMzBAAsGNB4AADUeAEGQzMEACwY2HgAANx4AQaDMwQALBjgeAAA5HgBBsMzBAAsGOh4AADseAEHAzMEACwY8HgAAPR4AQdDMwQALBj4eAAA/HgBB4MzBAAsGQB4AAEEeAEHwzMEACwZCHgAAQx4AQYDNwQALBkQeAABFHgBBkM3BAAsGRh4AAEceAEGgzcEACwZIHgAASR4AQbDNwQALBkoeAABLHgBBwM3BAAsGTB4AAE0eAEHQzcEACwZOHgAATx4AQeDNwQALBlAeAABRHgBB8M3BAAsGUh4AAFMeAEGAzsEACwZUHgAAVR4AQZDOwQALBlYeAABXHgBBoM7BAAsGWB4AAFkeAEGwzsEACwZaHgAAWx4AQcDOwQALBlweAABdHgBB0M7BAAsGXh4AAF8eAEHgzsEACwZgHgAAYR4AQfDOwQALBmIeAABjHgBBgM/BAAsGZB4AAGUeAEGQz8EACwZmHgAAZx4AQaDPwQALBmgeAABpHgBBsM/BAAsGah4AAGseAEHAz8EACwZsHgAAbR4AQdDPwQALBm4eAABvHgBB4M/BAAsGcB4AAHEeAEHwz8EACwZyHgAAcx4AQYDQwQALBnQeAAB1HgBBkNDBAAsGdh4AAHceAEGg0MEACwZ4HgAAeR4AQbDQwQALBnoeAAB7HgBBwNDBAAsGfB4AAH0eAEHQ0MEACwZ+HgAAfx4AQeDQwQALBoAeAACBHgBB8NDBAAsGgh4AAIMeAEGA0cEACwaEHgAAhR4AQZDRwQALBoYeAACHHgBBoNHBAAsGiB4AAIkeAEGw0cEACwaKHgAAix4AQcDRwQALBoweAACNHgBB0NHBAAsGjh4AAI8eAEHg0cEACwaQHgAAkR4AQfDRwQALBpIeAACTHgBBgNLBAAsGlB4AAJUeAEGQ0sEACwWeHgAA3wBBoNLBAAsGoB4AAKEeAEGw0sEACwaiHgAAox4AQcDSwQALBqQeAAClHgBB0NLBAAsGph4AAKceAEHg0sEACwaoHgAAqR4AQfDSwQALBqoeAACrHgBBgNPBAAsGrB4AAK0eAEGQ08EACwauHgAArx4AQaDTwQALBrAeAACxHgBBsNPBAAsGsh4AALMeAEHA08EACwa0HgAAtR4AQdDTwQALBrYeAAC3HgBB4NPBAAsGuB4AALkeAEHw08EACwa6HgAAux4AQYDUwQALBrweAAC9HgBBkNTBAAsGvh4AAL8eAEGg1MEACwbAHgAAwR4AQbDUwQALBsIeAADDHgBBwNTBAAsGxB4AAMUeAEHQ1MEACwbGHgAAxx4AQeDUwQALBsgeAADJHgBB8NTBAAsGyh4AAMseAEGA1cEACwbMHgAAzR4AQZDVwQALBs4eAADPHgBBoNXBAAsG0B4AANEeAEGw1cEACwbSHgAA0x4AQcDVwQALBtQeAADVHgBB0NXBAAsG1h4AANceAEHg1cEACwbYHgAA2R4AQfDVwQALBtoeAADbHgBBgNbBAAsG3B4AAN0eAEGQ1sEACwbeHgAA3x4AQaDWwQALBuAeAADhHgBBsNbBAAsG4h4AAOMeAEHA1sEACwbkHgAA5R4AQdDWwQALBuYeAADnHgBB4NbBAAsG6B4AAOkeAEHw1sEACwbqHgAA6x4AQYDXwQALBuweAADtHgBBkNfBAAsG7h4AAO8eAEGg18EACwbwHgAA8R4AQbDXwQALBvIeAADzHgBBwNfBAAsG9B4AAPUeAEHQ18EACwb2HgAA9x4AQeDXwQALBvgeAAD5HgBB8NfBAAsG+h4AAPseAEGA2MEACwb8HgAA/R4AQZDYwQALBv4eAAD/HgBBoNjBAAsGCB8AAAAfAEGw2MEACwYJHwAAAR8AQcDYwQALBgofAAACHwBB0NjBAAsGCx8AAAMfAEHg2MEACwYMHwAABB8AQfDYwQALBg0fAAAFHwBBgNnBAAsGDh8AAAYfAEGQ2cEACwYPHwAABx8AQaDZwQALBhgfAAAQHwBBsNnBAAsGGR8AABEfAEHA2cEACwYaHwAAEh8AQdDZwQALBhsfAAATHwBB4NnBAAsGHB8AABQfAEHw2cEACwYdHwAAFR8AQYDawQALBigfAAAgHwBBkNrBAAsGKR8AACEfAEGg2sEACwYqHwAAIh8AQbDawQALBisfAAAjHwBBwNrBAAsGLB8AACQfAEHQ2sEACwYtHwAAJR8AQeDawQALBi4fAAAmHwBB8NrBAAsGLx8AACcfAEGA28EACwY4HwAAMB8AQZDbwQALBjkfAAAxHwBBoNvBAAsGOh8AADIfAEGw28EACwY7HwAAMx8AQcDbwQALBjwfAAA0HwBB0NvBAAsGPR8AADUfAEHg28EACwY+HwAANh8AQfDbwQALBj8fAAA3HwBBgNzBAAsGSB8AAEAfAEGQ3MEACwZJHwAAQR8AQaDcwQALBkofAABCHwBBsNzBAAsGSx8AAEMfAEHA3MEACwZMHwAARB8AQdDcwQALBk0fAABFHwBB4NzBAAsGWR8AAFEfAEHw3MEACwZbHwAAUx8AQYDdwQALBl0fAABVHwBBkN3BAAsGXx8AAFcfAEGg3cEACwZoHwAAYB8AQbDdwQALBmkfAABhHwBBwN3BAAsGah8AAGIfAEHQ3cEACwZrHwAAYx8AQeDdwQALBmwfAABkHwBB8N3BAAsGbR8AAGUfAEGA3sEACwZuHwAAZh8AQZDewQALBm8fAABnHwBBoN7BAAsGiB8AAIAfAEGw3sEACwaJHwAAgR8AQcDewQALBoofAACCHwBB0N7BAAsGix8AAIMfAEHg3sEACwaMHwAAhB8AQfDewQALBo0fAACFHwBBgN/BAAsGjh8AAIYfAEGQ38EACwaPHwAAhx8AQaDfwQALBpgfAACQHwBBsN/BAAsGmR8AAJEfAEHA38EACwaaHwAAkh8AQdDfwQALBpsfAACTHwBB4N/BAAsGnB8AAJQfAEHw38EACwadHwAAlR8AQYDgwQALBp4fAACWHwBBkODBAAsGnx8AAJcfAEGg4MEACwaoHwAAoB8AQbDgwQALBqkfAAChHwBBwODBAAsGqh8AAKIfAEHQ4MEACwarHwAAox8AQeDgwQALBqwfAACkHwBB8ODBAAsGrR8AAKUfAEGA4cEACwauHwAAph8AQZDhwQALBq8fAACnHwBBoOHBAAsGuB8AALAfAEGw4cEACwa5HwAAsR8AQcDhwQALBrofAABwHwBB0OHBAAsGux8AAHEfAEHg4cEACwa8HwAAsx8AQfDhwQALBsgfAAByHwBBgOLBAAsGyR8AAHMfAEGQ4sEACwbKHwAAdB8AQaDiwQALBssfAAB1HwBBsOLBAAsGzB8AAMMfAEHA4sEACwbYHwAA0B8AQdDiwQALBtkfAADRHwBB4OLBAAsG2h8AAHYfAEHw4sEACwbbHwAAdx8AQYDjwQALBugfAADgHwBBkOPBAAsG6R8AAOEfAEGg48EACwbqHwAAeh8AQbDjwQALBusfAAB7HwBBwOPBAAsG7B8AAOUfAEHQ48EACwb4HwAAeB8AQeDjwQALBvkfAAB5HwBB8OPBAAsG+h8AAHwfAEGA5MEACwb7HwAAfR8AQZDkwQALBvwfAADzHwBBoOTBAAsGJiEAAMkDAEGw5MEACwUqIQAAawBBwOTBAAsFKyEAAOUAQdDkwQALBjIhAABOIQBB4OTBAAsGYCEAAHAhAEHw5MEACwZhIQAAcSEAQYDlwQALBmIhAAByIQBBkOXBAAsGYyEAAHMhAEGg5cEACwZkIQAAdCEAQbDlwQALBmUhAAB1IQBBwOXBAAsGZiEAAHYhAEHQ5cEACwZnIQAAdyEAQeDlwQALBmghAAB4IQBB8OXBAAsGaSEAAHkhAEGA5sEACwZqIQAAeiEAQZDmwQALBmshAAB7IQBBoObBAAsGbCEAAHwhAEGw5sEACwZtIQAAfSEAQcDmwQALBm4hAAB+IQBB0ObBAAsGbyEAAH8hAEHg5sEACwaDIQAAhCEAQfDmwQALBrYkAADQJABBgOfBAAsGtyQAANEkAEGQ58EACwa4JAAA0iQAQaDnwQALBrkkAADTJABBsOfBAAsGuiQAANQkAEHA58EACwa7JAAA1SQAQdDnwQALBrwkAADWJABB4OfBAAsGvSQAANckAEHw58EACwa+JAAA2CQAQYDowQALBr8kAADZJABBkOjBAAsGwCQAANokAEGg6MEACwbBJAAA2yQAQbDowQALBsIkAADcJABBwOjBAAsGwyQAAN0kAEHQ6MEACwbEJAAA3iQAQeDowQALBsUkAADfJABB8OjBAAsGxiQAAOAkAEGA6cEACwbHJAAA4SQAQZDpwQALBsgkAADiJABBoOnBAAsGySQAAOMkAEGw6cEACwbKJAAA5CQAQcDpwQALBsskAADlJABB0OnBAAsGzCQAAOYkAEHg6cEACwbNJAAA5yQAQfDpwQALBs4kAADoJABBgOrBAAsGzyQAAOkkAEGR6sEACwUsAAAwLABBoOrBAAsGASwAADEsAEGw6sEACwYCLAAAMiwAQcDqwQALBgMsAAAzLABB0OrBAAsGBCwAADQsAEHg6sEACwYFLAAANSwAQfDqwQALBgYsAAA2LABBgOvBAAsGBywAADcsAEGQ68EACwYILAAAOCwAQaDrwQALBgksAAA5LABBsOvBAAsGCiwAADosAEHA68EACwYLLAAAOywAQdDrwQALBgwsAAA8LABB4OvBAAsGDSwAAD0sAEHw68EACwYOLAAAPiwAQYDswQALBg8sAAA/LABBkOzBAAsGECwAAEAsAEGg7MEACwYRLAAAQSwAQbDswQALBhIsAABCLABBwOzBAAsGEywAAEMsAEHQ7MEACwYULAAARCwAQeDswQALBhUsAABFLABB8OzBAAsGFiwAAEYsAEGA7cEACwYXLAAARywAQZDtwQALBhgsAABILABBoO3BAAsGGSwAAEksAEGw7cEACwYaLAAASiwAQcDtwQALBhssAABLLABB0O3BAAsGHCwAAEwsAEHg7cEACwYdLAAATSwAQfDtwQALBh4sAABOLABBgO7BAAsGHywAAE8sAEGQ7sEACwYgLAAAUCwAQaDuwQALBiEsAABRLABBsO7BAAsGIiwAAFIsAEHA7sEACwYjLAAAUywAQdDuwQALBiQsAABULABB4O7BAAsGJSwAAFUsAEHw7sEACwYmLAAAViwAQYDvwQALBicsAABXLABBkO/BAAsGKCwAAFgsAEGg78EACwYpLAAAWSwAQbDvwQALBiosAABaLABBwO/BAAsGKywAAFssAEHQ78EACwYsLAAAXCwAQeDvwQALBi0sAABdLABB8O/BAAsGLiwAAF4sAEGA8MEACwZgLAAAYSwAQZDwwQALBmIsAABrAgBBoPDBAAsGYywAAH0dAEGw8MEACwZkLAAAfQIAQcDwwQALBmcsAABoLABB0PDBAAsGaSwAAGosAEHg8MEACwZrLAAAbCwAQfDwwQALBm0sAABRAgBBgPHBAAsGbiwAAHECAEGQ8cEACwZvLAAAUAIAQaDxwQALBnAsAABSAgBBsPHBAAsGciwAAHMsAEHA8cEACwZ1LAAAdiwAQdDxwQALBn4sAAA/AgBB4PHBAAsGfywAAEACAEHw8cEACwaALAAAgSwAQYDywQALBoIsAACDLABBkPLBAAsGhCwAAIUsAEGg8sEACwaGLAAAhywAQbDywQALBogsAACJLABBwPLBAAsGiiwAAIssAEHQ8sEACwaMLAAAjSwAQeDywQALBo4sAACPLABB8PLBAAsGkCwAAJEsAEGA88EACwaSLAAAkywAQZDzwQALBpQsAACVLABBoPPBAAsGliwAAJcsAEGw88EACwaYLAAAmSwAQcDzwQALBposAACbLABB0PPBAAsGnCwAAJ0sAEHg88EACwaeLAAAnywAQfDzwQALBqAsAAChLABBgPTBAAsGoiwAAKMsAEGQ9MEACwakLAAApSwAQaD0wQALBqYsAACnLABBsPTBAAsGqCwAAKksAEHA9MEACwaqLAAAqywAQdD0wQALBqwsAACtLABB4PTBAAsGriwAAK8sAEHw9MEACwawLAAAsSwAQYD1wQALBrIsAACzLABBkPXBAAsGtCwAALUsAEGg9cEACwa2LAAAtywAQbD1wQALBrgsAAC5LABBwPXBAAsGuiwAALssAEHQ9cEACwa8LAAAvSwAQeD1wQALBr4sAAC/LABB8PXBAAsGwCwAAMEsAEGA9sEACwbCLAAAwywAQZD2wQALBsQsAADFLABBoPbBAAsGxiwAAMcsAEGw9sEACwbILAAAySwAQcD2wQALBsosAADLLABB0PbBAAsGzCwAAM0sAEHg9sEACwbOLAAAzywAQfD2wQALBtAsAADRLABBgPfBAAsG0iwAANMsAEGQ98EACwbULAAA1SwAQaD3wQALBtYsAADXLABBsPfBAAsG2CwAANksAEHA98EACwbaLAAA2ywAQdD3wQALBtwsAADdLABB4PfBAAsG3iwAAN8sAEHw98EACwbgLAAA4SwAQYD4wQALBuIsAADjLABBkPjBAAsG6ywAAOwsAEGg+MEACwbtLAAA7iwAQbD4wQALBvIsAADzLABBwPjBAAsGQKYAAEGmAEHQ+MEACwZCpgAAQ6YAQeD4wQALBkSmAABFpgBB8PjBAAsGRqYAAEemAEGA+cEACwZIpgAASaYAQZD5wQALBkqmAABLpgBBoPnBAAsGTKYAAE2mAEGw+cEACwZOpgAAT6YAQcD5wQALBlCmAABRpgBB0PnBAAsGUqYAAFOmAEHg+cEACwZUpgAAVaYAQfD5wQALBlamAABXpgBBgPrBAAsGWKYAAFmmAEGQ+sEACwZapgAAW6YAQaD6wQALBlymAABdpgBBsPrBAAsGXqYAAF+mAEHA+sEACwZgpgAAYaYAQdD6wQALBmKmAABjpgBB4PrBAAsGZKYAAGWmAEHw+sEACwZmpgAAZ6YAQYD7wQALBmimAABppgBBkPvBAAsGaqYAAGumAEGg+8EACwZspgAAbaYAQbD7wQALBoCmAACBpgBBwPvBAAsGgqYAAIOmAEHQ+8EACwaEpgAAhaYAQeD7wQALBoamAACHpgBB8PvBAAsGiKYAAImmAEGA/MEACwaKpgAAi6YAQZD8wQALBoymAACNpgBBoPzBAAsGjqYAAI+mAEGw/MEACwaQpgAAkaYAQcD8wQALBpKmAACTpgBB0PzBAAsGlKYAAJWmAEHg/MEACwaWpgAAl6YAQfD8wQALBpimAACZpgBBgP3BAAsGmqYAAJumAEGQ/cEACwYipwAAI6cAQaD9wQALBiSnAAAlpwBBsP3BAAsGJqcAACenAEHA/cEACwYopwAAKacAQdD9wQALBiqnAAArpwBB4P3BAAsGLKcAAC2nAEHw/cEACwYupwAAL6cAQYD+wQALBjKnAAAzpwBBkP7BAAsGNKcAADWnAEGg/sEACwY2pwAAN6cAQbD+wQALBjinAAA5pwBBwP7BAAsGOqcAADunAEHQ/sEACwY8pwAAPacAQeD+wQALBj6nAAA/pwBB8P7BAAsGQKcAAEGnAEGA/8EACwZCpwAAQ6cAQZD/wQALBkSnAABFpwBBoP/BAAsGRqcAAEenAEGw/8EACwZIpwAASacAQcD/wQALBkqnAABLpwBB0P/BAAsGTKcAAE2nAEHg/8EACwZOpwAAT6cAQfD/wQALBlCnAABRpwBBgIDCAAsGUqcAAFOnAEGQgMIACwZUpwAAVacAQaCAwgALBlanAABXpwBBsIDCAAsGWKcAAFmnAEHAgMIACwZapwAAW6cAQdCAwgALBlynAABdpwBB4IDCAAsGXqcAAF+nAEHwgMIACwZgpwAAYacAQYCBwgALBmKnAABjpwBBkIHCAAsGZKcAAGWnAEGggcIACwZmpwAAZ6cAQbCBwgALBminAABppwBBwIHCAAsGaqcAAGunAEHQgcIACwZspwAAbacAQeCBwgALBm6nAABvpwBB8IHCAAsGeacAAHqnAEGAgsIACwZ7pwAAfKcAQZCCwgALBn2nAAB5HQBBoILCAAsGfqcAAH+nAEGwgsIACwaApwAAgacAQcCCwgALBoKnAACDpwBB0ILCAAsGhKcAAIWnAEHggsIACwaGpwAAh6cAQfCCwgALBounAACMpwBBgIPCAAsGjacAAGUCAEGQg8IACwaQpwAAkacAQaCDwgALBpKnAACTpwBBsIPCAAsGlqcAAJenAEHAg8IACwaYpwAAmacAQdCDwgALBpqnAACbpwBB4IPCAAsGnKcAAJ2nAEHwg8IACwaepwAAn6cAQYCEwgALBqCnAAChpwBBkITCAAsGoqcAAKOnAEGghMIACwakpwAApacAQbCEwgALBqanAACnpwBBwITCAAsGqKcAAKmnAEHQhMIACwaqpwAAZgIAQeCEwgALBqunAABcAgBB8ITCAAsGrKcAAGECAEGAhcIACwatpwAAbAIAQZCFwgALBq6nAABqAgBBoIXCAAsGsKcAAJ4CAEGwhcIACwaxpwAAhwIAQcCFwgALBrKnAACdAgBB0IXCAAsGs6cAAFOrAEHghcIACwa0pwAAtacAQfCFwgALBranAAC3pwBBgIbCAAsGuKcAALmnAEGQhsIACwa6pwAAu6cAQaCGwgALBrynAAC9pwBBsIbCAAsGvqcAAL+nAEHAhsIACwbCpwAAw6cAQdCGwgALBsSnAACUpwBB4IbCAAsGxacAAIICAEHwhsIACwbGpwAAjh0AQYCHwgALBiH/AABB/wBBkIfCAAsGIv8AAEL/AEGgh8IACwYj/wAAQ/8AQbCHwgALBiT/AABE/wBBwIfCAAsGJf8AAEX/AEHQh8IACwYm/wAARv8AQeCHwgALBif/AABH/wBB8IfCAAsGKP8AAEj/AEGAiMIACwYp/wAASf8AQZCIwgALBir/AABK/wBBoIjCAAsGK/8AAEv/AEGwiMIACwYs/wAATP8AQcCIwgALBi3/AABN/wBB0IjCAAsGLv8AAE7/AEHgiMIACwYv/wAAT/8AQfCIwgALBjD/AABQ/wBBgInCAAsGMf8AAFH/AEGQicIACwYy/wAAUv8AQaCJwgALBjP/AABT/wBBsInCAAsGNP8AAFT/AEHAicIACwY1/wAAVf8AQdCJwgALBjb/AABW/wBB4InCAAsGN/8AAFf/AEHwicIACwY4/wAAWP8AQYCKwgALBjn/AABZ/wBBkIrCAAsGOv8AAFr/AEGhisIACwYEAQAoBAEAQbCKwgALBwEEAQApBAEAQcCKwgALBwIEAQAqBAEAQdCKwgALBwMEAQArBAEAQeCKwgALBwQEAQAsBAEAQfCKwgALBwUEAQAtBAEAQYCLwgALBwYEAQAuBAEAQZCLwgALBwcEAQAvBAEAQaCLwgALBwgEAQAwBAEAQbCLwgALBwkEAQAxBAEAQcCLwgALBwoEAQAyBAEAQdCLwgALBwsEAQAzBAEAQeCLwgALBwwEAQA0BAEAQfCLwgALBw0EAQA1BAEAQYCMwgALBw4EAQA2BAEAQZCMwgALBw8EAQA3BAEAQaCMwgALBxAEAQA4BAEAQbCMwgALBxEEAQA5BAEAQcCMwgALBxIEAQA6BAEAQdCMwgALBxMEAQA7BAEAQeCMwgALBxQEAQA8BAEAQfCMwgALBxUEAQA9BAEAQYCNwgALBxYEAQA+BAEAQZCNwgALBxcEAQA/BAEAQaCNwgALBxgEAQBABAEAQbCNwgALBxkEAQBBBAEAQcCNwgALBxoEAQBCBAEAQdCNwgALBxsEAQBDBAEAQeCNwgALBxwEAQBEBAEAQfCNwgALBx0EAQBFBAEAQYCOwgALBx4EAQBGBAEAQZCOwgALBx8EAQBHBAEAQaCOwgALByAEAQBIBAEAQbCOwgALByEEAQBJBAEAQcCOwgALByIEAQBKBAEAQdCOwgALByMEAQBLBAEAQeCOwgALByQEAQBMBAEAQfCOwgALByUEAQBNBAEAQYCPwgALByYEAQBOBAEAQZCPwgALBycEAQBPBAEAQaCPwgALB7AEAQDYBAEAQbCPwgALB7EEAQDZBAEAQcCPwgALB7IEAQDaBAEAQdCPwgALB7MEAQDbBAEAQeCPwgALB7QEAQDcBAEAQfCPwgALB7UEAQDdBAEAQYCQwgALB7YEAQDeBAEAQZCQwgALB7cEAQDfBAEAQaCQwgALB7gEAQDgBAEAQbCQwgALB7kEAQDhBAEAQcCQwgALB7oEAQDiBAEAQdCQwgALB7sEAQDjBAEAQeCQwgALB7wEAQDkBAEAQfCQwgALB70EAQDlBAEAQYCRwgALB74EAQDmBAEAQZCRwgALB78EAQDnBAEAQaCRwgALB8AEAQDoBAEAQbCRwgALB8EEAQDpBAEAQcCRwgALB8IEAQDqBAEAQdCRwgALB8MEAQDrBAEAQeCRwgALB8QEAQDsBAEAQfCRwgALB8UEAQDtBAEAQYCSwgALB8YEAQDuBAEAQZCSwgALB8cEAQDvBAEAQaCSwgALB8gEAQDwBAEAQbCSwgALB8kEAQDxBAEAQcCSwgALB8oEAQDyBAEAQdCSwgALB8sEAQDzBAEAQeCSwgALB8wEAQD0BAEAQfCSwgALB80EAQD1BAEAQYCTwgALB84EAQD2BAEAQZCTwgALB88EAQD3BAEAQaCTwgALB9AEAQD4BAEAQbCTwgALB9EEAQD5BAEAQcCTwgALB9IEAQD6BAEAQdCTwgALB9MEAQD7BAEAQeCTwgALB4AMAQDADAEAQfCTwgALB4EMAQDBDAEAQYCUwgALB4IMAQDCDAEAQZCUwgALB4MMAQDDDAEAQaCUwgALB4QMAQDEDAEAQbCUwgALB4UMAQDFDAEAQcCUwgALB4YMAQDGDAEAQdCUwgALB4cMAQDHDAEAQeCUwgALB4gMAQDIDAEAQfCUwgALB4kMAQDJDAEAQYCVwgALB4oMAQDKDAEAQZCVwgALB4sMAQDLDAEAQaCVwgALB4wMAQDMDAEAQbCVwgALB40MAQDNDAEAQcCVwgALB44MAQDODAEAQdCVwgALB48MAQDPDAEAQeCVwgALB5AMAQDQDAEAQfCVwgALB5EMAQDRDAEAQYCWwgALB5IMAQDSDAEAQZCWwgALB5MMAQDTDAEAQaCWwgALB5QMAQDUDAEAQbCWwgALB5UMAQDVDAEAQcCWwgALB5YMAQDWDAEAQdCWwgALB5cMAQDXDAEAQeCWwgALB5gMAQDYDAEAQfCWwgALB5kMAQDZDAEAQYCXwgALB5oMAQDaDAEAQZCXwgALB5sMAQDbDAEAQaCXwgALB5wMAQDcDAEAQbCXwgALB50MAQDdDAEAQcCXwgALB54MAQDeDAEAQdCXwgALB58MAQDfDAEAQeCXwgALB6AMAQDgDAEAQfCXwgALB6EMAQDhDAEAQYCYwgALB6IMAQDiDAEAQZCYwgALB6MMAQDjDAEAQaCYwgALB6QMAQDkDAEAQbCYwgALB6UMAQDlDAEAQcCYwgALB6YMAQDmDAEAQdCYwgALB6cMAQDnDAEAQeCYwgALB6gMAQDoDAEAQfCYwgALB6kMAQDpDAEAQYCZwgALB6oMAQDqDAEAQZCZwgALB6sMAQDrDAEAQaCZwgALB6wMAQDsDAEAQbCZwgALB60MAQDtDAEAQcCZwgALB64MAQDuDAEAQdCZwgALB68MAQDvDAEAQeCZwgALB7AMAQDwDAEAQfCZwgALB7EMAQDxDAEAQYCawgALB7IMAQDyDAEAQZCawgALB6AYAQDAGAEAQaCawgALB6EYAQDBGAEAQbCawgALB6IYAQDCGAEAQcCawgALB6MYAQDDGAEAQdCawgALB6QYAQDEGAEAQeCawgALB6UYAQDFGAEAQfCawgALB6YYAQDGGAEAQYCbwgALB6cYAQDHGAEAQZCbwgALB6gYAQDIGAEAQaCbwgALB6kYAQDJGAEAQbCbwgALB6oYAQDKGAEAQcCbwgALB6sYAQDLGAEAQdCbwgALB6wYAQDMGAEAQeCbwgALB60YAQDNGAEAQfCbwgALB64YAQDOGAEAQYCcwgALB68YAQDPGAEAQZCcwgALB7AYAQDQGAEAQaCcwgALB7EYAQDRGAEAQbCcwgALB7IYAQDSGAEAQcCcwgALB7MYAQDTGAEAQdCcwgALB7QYAQDUGAEAQeCcwgALB7UYAQDVGAEAQfCcwgALB7YYAQDWGAEAQYCdwgALB7cYAQDXGAEAQZCdwgALB7gYAQDYGAEAQaCdwgALB7kYAQDZGAEAQbCdwgALB7oYAQDaGAEAQcCdwgALB7sYAQDbGAEAQdCdwgALB7wYAQDcGAEAQeCdwgALB70YAQDdGAEAQfCdwgALB74YAQDeGAEAQYCewgALB78YAQDfGAEAQZCewgALB0BuAQBgbgEAQaCewgALB0FuAQBhbgEAQbCewgALB0JuAQBibgEAQcCewgALB0NuAQBjbgEAQdCewgALB0RuAQBkbgEAQeCewgALB0VuAQBlbgEAQfCewgALB0ZuAQBmbgEAQYCfwgALB0duAQBnbgEAQZCfwgALB0huAQBobgEAQaCfwgALB0luAQBpbgEAQbCfwgALB0puAQBqbgEAQcCfwgALB0tuAQBrbgEAQdCfwgALB0xuAQBsbgEAQeCfwgALB01uAQBtbgEAQfCfwgALB05uAQBubgEAQYCgwgALB09uAQBvbgEAQZCgwgALB1BuAQBwbgEAQaCgwgALB1FuAQBxbgEAQbCgwgALB1JuAQBybgEAQcCgwgALB1NuAQBzbgEAQdCgwgALB1RuAQB0bgEAQeCgwgALB1VuAQB1bgEAQfCgwgALB1ZuAQB2bgEAQYChwgALB1duAQB3bgEAQZChwgALB1huAQB4bgEAQaChwgALB1luAQB5bgEAQbChwgALB1puAQB6bgEAQcChwgALB1tuAQB7bgEAQdChwgALB1xuAQB8bgEAQeChwgALB11uAQB9bgEAQfChwgALB15uAQB+bgEAQYCiwgALB19uAQB/bgEAQZGiwgALBukBACLpAQBBoKLCAAsHAekBACPpAQBBsKLCAAsHAukBACTpAQBBwKLCAAsHA+kBACXpAQBB0KLCAAsHBOkBACbpAQBB4KLCAAsHBekBACfpAQBB8KLCAAsHBukBACjpAQBBgKPCAAsHB+kBACnpAQBBkKPCAAsHCOkBACrpAQBBoKPCAAsHCekBACvpAQBBsKPCAAsHCukBACzpAQBBwKPCAAsHC+kBAC3pAQBB0KPCAAsHDOkBAC7pAQBB4KPCAAsHDekBAC/pAQBB8KPCAAsHDukBADDpAQBBgKTCAAsHD+kBADHpAQBBkKTCAAsHEOkBADLpAQBBoKTCAAsHEekBADPpAQBBsKTCAAsHEukBADTpAQBBwKTCAAsHE+kBADXpAQBB0KTCAAsHFOkBADbpAQBB4KTCAAsHFekBADfpAQBB8KTCAAsHFukBADjpAQBBgKXCAAsHF+kBADnpAQBBkKXCAAsHGOkBADrpAQBBoKXCAAsHGekBADvpAQBBsKXCAAsHGukBADzpAQBBwKXCAAsHG+kBAD3pAQBB0KXCAAsHHOkBAD7pAQBB4KXCAAsHHekBAD/pAQBB8KXCAAsHHukBAEDpAQBBgKbCAAsHH+kBAEHpAQBBkKbCAAsHIOkBAELpAQBBoKbCAAsHIekBAEPpAQBBsKbCAAvdKP////9IYXNoIHRhYmxlIGNhcGFjaXR5IG92ZXJmbG93YJMQAE4AAABQAAAAGwAAAC9jYXJnby9yZWdpc3RyeS9zcmMvZ2l0aHViLmNvbS0xZWNjNjI5OWRiOWVjODIzL2hhc2hicm93bi0wLjYuMi9zcmMvcmF3L21vZC5ycwAACJYQAEgAAAACAgAAIwAAAAiWEABIAAAAAwIAACMAAADgkxAASQAAAIcCAAAdAAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmNvcmUvc2xpY2Uvc29ydC5ycwAAAOCTEABJAAAAnQAAADoAAADgkxAASQAAAKQAAAAwAAAADQAAAAAAAAABAAAADgAAAA8AAAAAAAAAAQAAABAAAAB8lBAAPQAAACUAAAANAAAAL1VzZXJzL3RoYXVtYW50L1BsYXlncm91bmQvbHVjaWQtc3VnZ2VzdC9ydXN0L2NvcmUvc3JjL2xpYi5yc0R1cGxpY2F0ZSBzdG9yZSBpZAB8lBAAPQAAAC8AAAANAAAAfJQQAD0AAAA7AAAADQAAAE1pc3Npbmcgc3RvcmUgaWR8lBAAPQAAAEMAAAANAAAAYWxyZWFkeSBtdXRhYmx5IGJvcnJvd2VkEQAAAAAAAAABAAAAEgAAAAIBAwATAAAAAAAAAAEAAAAOAAAAFAAAAAAAAAABAAAADgAAAAAAAAAVAAAAAAAAAAEAAAAOAAAAfJUQAEsAAAAjAAAACQAAAC9Vc2Vycy90aGF1bWFudC9QbGF5Z3JvdW5kL2x1Y2lkLXN1Z2dlc3QvcnVzdC9jb3JlL3NyYy90b2tlbml6YXRpb24vd29yZC5yc01hbGZvcm1lZCB3b3JkcwAAwLYQABcAAAC5AQAAJwAAAAiWEABIAAAA7AoAAAoAAAAIlhAASAAAAPIKAAAOAAAAL3J1c3RjLzczNTI4ZTMzOWFhZTBmMTdhMTVmZmE0OWE4YWM2MDhmNTBjNmNmMTQvc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzcJYQAEUAAAAJAwAAHgAAAHCWEABFAAAADAMAADYAAAAvcnVzdGMvNzM1MjhlMzM5YWFlMGYxN2ExNWZmYTQ5YThhYzYwOGY1MGM2Y2YxNC9zcmMvbGliYWxsb2Mvc2xpY2UucnNhc3NlcnRpb24gZmFpbGVkOiBtaWQgPD0gbGVuAAAA9KYQABgAAAA2BAAADQAAAGFzc2VydGlvbiBmYWlsZWQ6IGluZGV4IDw9IGxlbgAAFJcQABMAAADjAwAACQAAAHNyYy9saWJhbGxvYy92ZWMucnNhc3NlcnRpb24gZmFpbGVkOiBpbmRleCA8IGxlbhSXEAATAAAACwQAAAkAAAB0lxAAQwAAADcLAAAwAAAAdJcQAEMAAABDCwAALAAAAC9ydXN0Yy83MzUyOGUzMzlhYWUwZjE3YTE1ZmZhNDlhOGFjNjA4ZjUwYzZjZjE0L3NyYy9saWJhbGxvYy92ZWMucnMAFgAAAAAAAAABAAAADgAAAAYAAABiAAAABgAAAGMAAAAGAAAAZAAAAAYAAABmAAAABgAAAGcAAAAGAAAAaAAAAAYAAABqAAAABgAAAGsAAAAGAAAAbAAAAAYAAABtAAAABgAAAG4AAAAGAAAAcAAAAAYAAABxAAAABgAAAHIAAAAGAAAAcwAAAAYAAAB0AAAABgAAAHYAAAAGAAAAdwAAAAYAAAB4AAAABgAAAHoAAAAHAAAAYQAAAAcAAABlAAAABwAAAGkAAAAHAAAAbwAAAAcAAAB1AAAABwAAAHkAAABjAAAA5KQQAAQAAADepBAAAgAAAOikEAAEAAAA4qQQAAIAAADepBAAAgAAAOCkEAACAAAA4qQQAAIAAABMtBAAAgAAAAUAAACYmBAAAQAAAAUAAACMoBAABgAAAAUAAACSoBAAEgAAAAUAAAAOthAAAgAAAAUAAACkoBAACgAAAAUAAACuoBAACgAAAAUAAAC4oBAADAAAAAUAAADEoBAABgAAAAUAAADKoBAADAAAAAUAAADWoBAADAAAAAUAAADioBAABAAAAAUAAADmoBAACgAAAAUAAADwoBAADAAAAAUAAAD8oBAADgAAAAUAAAAKoRAADgAAAAUAAAAYoRAAFAAAAAUAAAAsoRAABgAAAAUAAAAyoRAABAAAAAUAAAA2oRAABAAAAAUAAAA6oRAACQAAAAUAAABDoRAACwAAAAUAAABOoRAABAAAAAUAAABSoRAAAgAAAAUAAABUoRAABAAAAAUAAABYoRAACgAAAAUAAABioRAACgAAAAUAAABsoRAACAAAAAUAAAD+tBAABAAAAAUAAAB0oRAABgAAAAUAAAB6oRAACAAAAAUAAACCoRAAEAAAAAUAAACSoRAADAAAAAUAAACitBAAAgAAAAUAAACeoRAABAAAAAUAAACioRAACgAAAAUAAACsoRAABAAAAAUAAACwoRAABgAAAAUAAAC2oRAACgAAAAUAAADAoRAADAAAAAUAAADMoRAABAAAAAUAAADQoRAABgAAAAUAAADWoRAACgAAAAUAAADgoRAACAAAAAUAAADooRAADAAAAAUAAAD0oRAADAAAAAUAAAAAohAACgAAAAUAAAAKohAADgAAAAUAAAAYohAAFgAAAAUAAAAuohAABgAAAAUAAAA0ohAABgAAAAUAAAA6ohAADAAAAAUAAABGohAACgAAAAUAAABQohAACAAAAAUAAABYohAAAgAAAAUAAABaohAACgAAAAUAAABkohAACgAAAAUAAABuohAADAAAAAUAAAB6ohAABAAAAAUAAAB+ohAACgAAAAUAAAAgtBAAAgAAAAUAAACIohAACgAAAAYAAABYqBAAAgAAAAYAAABYqBAAAgAAAAYAAACSohAACgAAAAYAAACcohAADgAAAAYAAACqohAABgAAAAYAAACwohAABAAAAAYAAAC0ohAACAAAAAYAAAC8ohAACgAAAAYAAADGohAACAAAAAYAAADOohAABAAAAAYAAADSohAACAAAAAYAAABcqBAAAgAAAAYAAADaohAABgAAAAYAAAA0tRAABgAAAAYAAADgohAADAAAAAYAAADsohAABgAAAAYAAADsohAABgAAAAYAAADyohAACgAAAAYAAAD8ohAADgAAAAYAAAAKoxAAEAAAAAYAAAAaoxAADgAAAAYAAAAooxAADgAAAAYAAAA2oxAADgAAAAYAAABEoxAADgAAAAYAAABSoxAADgAAAAYAAABgoxAADgAAAAYAAAAqtRAABAAAAAYAAABuoxAACAAAAAYAAAB2oxAACAAAAAYAAAB+oxAAEgAAAAYAAABktRAABAAAAAYAAACQoxAADAAAAAYAAACcoxAACAAAAAYAAACkoxAAEAAAAAYAAAC0oxAADAAAAAYAAADAoxAADAAAAAYAAADMoxAACgAAAAYAAADWoxAABgAAAAYAAADcoxAADAAAAAYAAADooxAACgAAAAYAAADyoxAABAAAAAYAAAD2oxAACAAAAAYAAAD+oxAACgAAAAYAAAAIpBAACAAAAAYAAAAQpBAACAAAAAYAAAAYpBAABgAAAAYAAAAepBAABgAAAAYAAAAepBAABgAAAAYAAAAkpBAACgAAAAYAAAAkpBAACgAAAAYAAAAupBAACAAAAAcAAACSohAACgAAAAcAAAA2pBAABAAAAAcAAAA6pBAACAAAAAcAAAA6pBAACAAAAAcAAABCpBAABgAAAAcAAABIpBAABgAAAAcAAACwohAABAAAAAcAAABOpBAACAAAAAcAAABWpBAACAAAAAcAAADOohAABAAAAAcAAABepBAACgAAAAcAAADgohAADAAAAAcAAAB2oxAACAAAAAcAAABopBAABAAAAAcAAABspBAABgAAAAcAAABypBAADgAAAAcAAACApBAABAAAAAcAAACEpBAABAAAAAcAAACEpBAABAAAAAcAAACIpBAADgAAAAcAAACWpBAACgAAAAcAAACgpBAADAAAAAcAAACspBAACgAAAAcAAADcoxAADAAAAAcAAAC2pBAABgAAAAcAAAC8pBAADAAAAAcAAADIpBAADAAAAAcAAADUpBAABAAAAAcAAAAIpBAACAAAAAcAAAAQpBAACAAAAAcAAAAupBAACAAAAAcAAADYpBAABgAAAAYAAAAxBAAABgAAADIEAAAGAAAAMwQAAAYAAAA0BAAABgAAADYEAAAGAAAANwQAAAYAAAA6BAAABgAAADsEAAAGAAAAPAQAAAYAAAA9BAAABgAAAD8EAAAGAAAAQAQAAAYAAABBBAAABgAAAEIEAAAGAAAARAQAAAYAAABFBAAABgAAAEYEAAAGAAAARwQAAAYAAABIBAAABgAAAEkEAAAHAAAAMAQAAAcAAAA1BAAABwAAAFEEAAAHAAAAOAQAAAcAAAA+BAAABwAAAEMEAAAHAAAASwQAAAcAAABNBAAABwAAAE4EAAAHAAAATwQAANCx0LXQt9Cx0LvQsNCz0L7QtNCw0YDRj9Cy0LLQuNC00YPQstC00L7Qu9GM0LLQvNC10YHRgtC+0LLQvdC10LLQvdGD0YLRgNC40LLQvdGD0YLRgNGM0LLQvtCy0L7Qt9C70LXQstC+0LrRgNGD0LPQstC+0L/RgNC10LrQuNCy0L/QtdGA0LXQtNC40LLRgdC70LXQtNGB0YLQstC40LXQtNC70Y/QtNC+0LfQsNC40Lct0LfQsNC40Lct0L/QvtC00LjQt9C60LrQvtC60YDQvtC80LXQvNC10LbQtNGD0LzQuNC80L7QvdCw0LTQvdCw0LTQvtC90LDQv9GA0L7RgtC40LLQvdCw0YHRh9C10YLQvtCx0L7QutC+0LvQvtC+0YLQvtGC0L7Qv9C10YDQtdC00L/QtdGA0LXQtNC+0L/QvtC/0L7QtNC/0L7QtNC70LXQv9C+0LTQvtC/0L7Qt9Cw0LTQuNC/0L7QvNC40LzQvtC/0L7RgdC70LXQv9C+0YHRgNC10LTQuNC/0L7RgdGA0LXQtNGB0YLQstC+0LzQv9GA0LjQv9GA0L7Qv9GA0L7RgtC40LLQv9GD0YLRkdC80YDQsNC00LjRgdGB0LLQtdGA0YXRgdCy0YvRiNC10YHQutCy0L7Qt9GM0YHQvtGB0YDQtdC00LjRh9C10YDQtdC30LHRg9C00YLQvtCy0L/RgNC+0YfQtdC80LPQtNC10LTQsNC10LTQstCw0LXQttC10LvQuNC10YHQu9C40LbQtdC30LDRgtC+0LjQsdC+0LjQvNC10L3QvdC+0LrQsNC60LrQvtCz0LTQsNC60L7RgtC+0YDQsNGP0LrQvtGC0L7RgNC+0LPQvtC60L7RgtC+0YDQvtC10LrQvtGC0L7RgNC+0LzQutC+0YLQvtGA0YPRjtC60L7RgtC+0YDRi9C10LrQvtGC0L7RgNGL0LnQutC+0YLQvtGA0YvRhdC70LjQsdC+0LvQuNGI0YzQvdCw0YHRgtC+0LvRjNC60L7QvtC00L3QsNC60L7Qv9C+0LrQsNC/0L7QutCw0LzQtdGB0YLQv9C+0LrRg9C00LDQv9GD0YHQutCw0LnQv9GD0YHRgtGM0YDQsNC30YHQu9C+0LLQvdC+0YLQsNC60LbQtdGC0L7RgtC+0LbQtdGC0L7Rh9C90L7RhdC+0YLRjNGF0L7RgtGP0YfQtdC80YfRgtC+0YfRgtC+0LHRi9GH0YPRgtGM0LHRi9Cy0LXQtNGM0LLQvtC90LLQvtGC0LTQsNC20LXQtdGB0YLRjNC30LTQtdGB0YzQvdC10L3QtdGC0L3QtdGD0LbQtdC70LjQvdC40L3Rg9C/0L7QttCw0LvRg9C50L/QvtGH0YLQuNC/0YDQvtGB0YLQvtGA0LDQt9Cy0LXRgtCw0LzRgtC+0LvRjNC60L7Rg9Cz0L7QtNC90L7Rg9C20Y3RgtC+0IHQldGR0JXMiNC1zIhOb3JtYWxpemF0aW9uIHNob3VsZCBhbHdheXMgYmUgdGhlIGZpcnN0IHN0ZXAAAAAspRAASwAAAGAAAAANAAAAL1VzZXJzL3RoYXVtYW50L1BsYXlncm91bmQvbHVjaWQtc3VnZ2VzdC9ydXN0L2NvcmUvc3JjL3Rva2VuaXphdGlvbi90ZXh0LnJzABcAAABjYW5ub3QgYWNjZXNzIGEgVGhyZWFkIExvY2FsIFN0b3JhZ2UgdmFsdWUgZHVyaW5nIG9yIGFmdGVyIGRlc3RydWN0aW9uYWxyZWFkeSBib3Jyb3dlZAAAGAAAAAAAAAABAAAADgAAABkAAAAAAAAAAQAAABAAAAAEphAAcAAAAKEAAAARAAAAL1VzZXJzL3RoYXVtYW50Ly5jYXJnby9yZWdpc3RyeS9zcmMvZ2l0aHViLmNvbS0xZWNjNjI5OWRiOWVjODIzL3J1c3Qtc3RlbW1lcnMtMS4yLjAvc3JjL3Nub3diYWxsL3Nub3diYWxsX2Vudi5ycwSmEABwAAAAygAAABEAAAAEphAAcAAAAFYBAAAWAAAABKYQAHAAAABcAQAAGAAAAASmEABwAAAAXAEAAEkAAAAEphAAcAAAAHcBAAAWAAAASKcQADQAAAAMpxAALQAAADmnEAAMAAAARacQAAMAAAD0phAAGAAAAKkIAAAJAAAAc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzYXNzZXJ0aW9uIGZhaWxlZDogYChsZWZ0ID09IHJpZ2h0KWAKICBsZWZ0OiBgYCwKIHJpZ2h0OiBgYDogZGVzdGluYXRpb24gYW5kIHNvdXJjZSBzbGljZXMgaGF2ZSBkaWZmZXJlbnQgbGVuZ3RocyFBCOjstRAACgAAAP////8BAEGYz8IACw32tRAADAAAAAAAAAACAEGwz8IACw0CthAADAAAAAAAAAACAEHIz8IACw0OthAAAgAAAP////8BAEHgz8IACw0QthAABAAAAAMAAAACAEH4z8IACw0UthAABAAAAAMAAAACAEGQ0MIACw0YthAABgAAAP////8BAEGo0MIACw0ethAACAAAAAYAAAACAEHA0MIACw0mthAACAAAAAYAAAACAEHY0MIACxXQsNGP0LgAAOS1EAAEAAAA/////wEAQfjQwgALDei1EAAEAAAA/////wEAQZDRwgALDZS1EAAGAAAA/////wEAQajRwgALDZq1EAAGAAAA/////wEAQcDRwgALDaC1EAAEAAAA/////wEAQdjRwgALDaS1EAAEAAAA/////wEAQfDRwgALDeq0EAAEAAAA/////wEAQYjSwgALDai1EAAEAAAA/////wEAQaDSwgALDay1EAAEAAAA/////wEAQbjSwgALDbC1EAAEAAAA/////wEAQdDSwgALDbS1EAAEAAAA/////wEAQejSwgALDbi1EAAEAAAA/////wEAQYDTwgALDby1EAAEAAAA/////wEAQZjTwgALDcC1EAAEAAAA/////wEAQbDTwgALDVK0EAAEAAAA/////wEAQcjTwgALDcS1EAAEAAAA/////wEAQeDTwgALDci1EAAGAAAA/////wEAQfjTwgALDc61EAAGAAAA/////wEAQZDUwgALDdS1EAAEAAAA/////wEAQajUwgALDXS0EAAEAAAA/////wEAQcDUwgALDX60EAAEAAAA/////wEAQdjUwgALDYK0EAAEAAAA/////wEAQfDUwgALDUi1EAAEAAAA/////wEAQYjVwgALDZS0EAAEAAAA/////wEAQaDVwgALDUy1EAAEAAAA/////wEAQbjVwgALDZ60EAAEAAAA/////wEAQdDVwgALDdi1EAAGAAAA/////wEAQejVwgALDd61EAAGAAAA/////wEAQYDWwgALDXS1EAAEAAAA/////wEAQZjWwgALDXi1EAAGAAAAAAAAAAIAQbDWwgALDX61EAAGAAAAAAAAAAIAQcjWwgALDYS1EAACAAAA/////wEAQeDWwgALDYa1EAAEAAAAAwAAAAEAQfjWwgALDYq1EAAGAAAABAAAAAIAQZDXwgALDZS0EAAEAAAA/////wEAQajXwgALDZC1EAAEAAAA/////wEAQcDXwgALDaS0EAAEAAAA/////wIAQdjXwgALDai0EAAEAAAA/////wEAQfDXwgALDay0EAAGAAAAAQAAAAIAQYjYwgALDbK0EAAEAAAA/////wIAQaDYwgALDba0EAAEAAAA/////wEAQbjYwgALDbq0EAAGAAAABAAAAAIAQdDYwgALDcC0EAAEAAAA/////wIAQejYwgALDcS0EAAEAAAA/////wEAQYDZwgALDci0EAAGAAAABwAAAAIAQZjZwgALDc60EAAEAAAA/////wEAQbDZwgALDdK0EAAGAAAACQAAAAIAQcjZwgALDdi0EAAGAAAACQAAAAIAQeDZwgALDd60EAAGAAAA/////wEAQfjZwgALDeS0EAAGAAAA/////wIAQZDawgALDTK0EAACAAAA/////wIAQajawgALDeq0EAAEAAAADgAAAAIAQcDawgALDe60EAAEAAAA/////wEAQdjawgALDfK0EAAGAAAAEAAAAAIAQfDawgALDfi0EAAGAAAAEAAAAAIAQYjbwgALDf60EAAEAAAA/////wEAQaDbwgALDQK1EAAGAAAAEwAAAAIAQbjbwgALDQi1EAAGAAAA/////wEAQdDbwgALDQ61EAAGAAAA/////wIAQejbwgALDRS1EAAGAAAA/////wEAQYDcwgALDRq1EAAIAAAAFwAAAAIAQZjcwgALDSK1EAAIAAAAFwAAAAIAQbDcwgALDSq1EAAEAAAA/////wEAQcjcwgALDS61EAAGAAAAGgAAAAIAQeDcwgALDTS1EAAGAAAAGgAAAAIAQfjcwgALDXK0EAACAAAA/////wEAQZDdwgALDTq1EAAEAAAAHQAAAAIAQajdwgALDXS0EAAEAAAAHQAAAAIAQcDdwgALDT61EAACAAAA/////wEAQdjdwgALDUC1EAAEAAAAIAAAAAIAQfDdwgALDUS1EAAEAAAAIAAAAAIAQYjewgALDUi1EAAEAAAA/////wIAQaDewgALDZS0EAAEAAAA/////wEAQbjewgALDUy1EAAEAAAA/////wIAQdHewgALDLQQAAIAAAD/////AQBB6N7CAAsNULUQAAQAAAAmAAAAAgBBgN/CAAsNVLUQAAQAAAD/////AQBBmN/CAAsNWLUQAAYAAAAoAAAAAgBBsN/CAAsNXrUQAAYAAAAoAAAAAgBByN/CAAsNZLUQAAQAAAD/////AQBB4N/CAAsNaLUQAAYAAAArAAAAAgBB+N/CAAsNbrUQAAYAAAArAAAAAQBBkODCAAsNILQQAAIAAAD/////AQBBqODCAAsNIrQQAAQAAAD/////AQBBwODCAAsNJrQQAAYAAAABAAAAAQBB2ODCAAsNLLQQAAQAAAD/////AQBB8ODCAAsNMLQQAAIAAAD/////AQBBiOHCAAsNCLQQAAIAAAD/////AQBBoOHCAAsNMrQQAAIAAAD/////AQBBuOHCAAsNNLQQAAQAAAAGAAAAAQBB0OHCAAsNOLQQAAQAAAAGAAAAAQBB6OHCAAsNWqgQAAIAAAD/////AQBBgOLCAAsNPLQQAAQAAAAJAAAAAQBBmOLCAAsNQLQQAAQAAAAJAAAAAQBBsOLCAAsNWKgQAAIAAAD/////AQBByOLCAAsNRLQQAAQAAAD/////AQBB4OLCAAsNSLQQAAQAAAD/////AQBB+OLCAAsNTLQQAAIAAAD/////AQBBkOPCAAsNTrQQAAQAAAAPAAAAAQBBqOPCAAsNUrQQAAQAAAAPAAAAAQBBwOPCAAsNXKgQAAIAAAD/////AQBB2OPCAAsNVrQQAAQAAAASAAAAAQBB8OPCAAsNWrQQAAQAAAASAAAAAQBBiOTCAAsNXrQQAAYAAAASAAAAAQBBoOTCAAsNZLQQAAgAAAAVAAAAAQBBuOTCAAsNbLQQAAYAAAASAAAAAQBB0OTCAAsNcrQQAAIAAAD/////AQBB6OTCAAsNdLQQAAQAAAAYAAAAAQBBgOXCAAsNeLQQAAYAAAAZAAAAAQBBmOXCAAsNfrQQAAQAAAAYAAAAAQBBsOXCAAsNgrQQAAQAAAAYAAAAAQBByOXCAAsNhrQQAAQAAAD/////AQBB4OXCAAsNirQQAAYAAAAdAAAAAQBB+OXCAAsNkLQQAAQAAAD/////AQBBkObCAAsNlLQQAAQAAAD/////AQBBqObCAAsNmLQQAAYAAAAgAAAAAQBBwObCAAsNnrQQAAQAAAD/////AQBB2ObCAAsNorQQAAIAAAD/////AQBB8ObCAAsNErQQAAYAAAD/////AQBBiOfCAAsNGLQQAAgAAAD/////AQBBoOfCAAsNArQQAAYAAAD/////AQBBuOfCAAsNCLQQAAIAAAD/////AwBB0OfCAAsNCrQQAAgAAAD/////AQBB6efCAAsMtBAAAgAAAP////8CAEGA6MIAC40G0L3QtdC50YjRjNC10LnRiNC10L7RgdGC0L7RgdGC0YzRg9GP0YXQuNGP0YXQsNGF0YvRjtGM0Y7QuNGO0YzRj9C40Y/QtdCy0L7QstC10YzQtdC40LXQtdC40LjQuNGP0LzQuNC40Y/QvNC40LDQvNC40LnQtdC50LjQtdC50LjQudC+0LnRj9C80LjRj9C80LDQvNC10LzQuNC10LzQvtC80L7Ri9GC0Y7RgtGD0Y7RgtGP0YLQtdGC0YPQtdGC0LjRgtC90YvQtdC90YvRgtGM0YvRgtGM0LjRgtGM0LXRiNGM0LjRiNGM0YPRjtC70LDRi9C70LDQuNC70LDQvdCw0LXQvdCw0LXRgtC10LjRgtC10LnRgtC10YPQudGC0LXQtdC50YLQtdC70LjRi9C70LjQuNC70LjRg9C50LvRi9C70LjQu9GL0LzQuNC80LXQvdC70L7Ri9C70L7QuNC70L7QvdC+0LXQvdC+0L3QvdC+0LLRiNGL0LLRiNC40LLRiNGJ0Y7RidGD0Y7RidC90L3QtdC80YPQvtC80YPRi9GF0LjRhdGO0Y7QtdGO0L7RjtGP0Y/QsNGP0YvQtdC10LXQvtC10YvQvNC40LjQvNC40YvQudC10LPQvtC+0LPQvtGB0YzRgdGP0LLRiNC40YHRjNGL0LLRiNC40YHRjNC40LLRiNC40YHRjNCy0YvQstC40LLQstGI0LjRi9Cy0YjQuNC40LLRiNC4QWNjZXNzRXJyb3JjYWxsZWQgYE9wdGlvbjo6dW53cmFwKClgIG9uIGEgYE5vbmVgIHZhbHVldLYQABUAAAB6AQAAFQAAAHNyYy9saWJjb3JlL29wdGlvbi5yc1RyaWVkIHRvIHNocmluayB0byBhIGxhcmdlciBjYXBhY2l0eQAAAMC2EAAXAAAAXQIAAAkAAABzcmMvbGliYWxsb2MvcmF3X3ZlYy5yc2ludGVybmFsIGVycm9yOiBlbnRlcmVkIHVucmVhY2hhYmxlIGNvZGUAwLYQABcAAAAKAgAAJwB7CXByb2R1Y2VycwIIbGFuZ3VhZ2UBBFJ1c3QADHByb2Nlc3NlZC1ieQMFcnVzdGMdMS40MC4wICg3MzUyOGUzMzkgMjAxOS0xMi0xNikGd2FscnVzBjAuMTQuMAx3YXNtLWJpbmRnZW4SMC4yLjU4ICgyOTAyY2ViMjYp';
-
-                var binary = null;
-
-                var isNode = typeof process !== 'undefined'
-                    && process.versions != null
-                    && process.versions.node != null;
-
-                if (isNode) {
-                    binary = Buffer.from(src, 'base64');
-                } else {
-                    var raw = window.atob(src);
-                    binary = new Uint8Array(new ArrayBuffer(raw.length));
-                    for(var i = 0; i < raw.length; i++) {
-                        binary[i] = raw.charCodeAt(i);
-                    }
-                }
-
-                var instantiatePromise = WebAssembly.instantiate(
-                    binary,
-                    {env: {memory: new WebAssembly.Memory({initial: 10})}}
-                );
-
-                var exportsPromise = instantiatePromise.then(result => {
-                    return result.instance.exports
-                });
-
-var compileWasm = exportsPromise.then(function(wasm) {
-                const __exports__ = {};
-
-                
-/**
-* @param {number} id
-*/
-__exports__.create_store = function(id) {
-    wasm.create_store(id);
-};
-
-/**
-* @param {number} id
-*/
-__exports__.destroy_store = function(id) {
-    wasm.destroy_store(id);
-};
-
-let WASM_VECTOR_LEN = 0;
-
-let cachegetUint8Memory0 = null;
-function getUint8Memory0() {
-    if (cachegetUint8Memory0 === null || cachegetUint8Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint8Memory0 = new Uint8Array(wasm.memory.buffer);
-    }
-    return cachegetUint8Memory0;
-}
-
-const lTextEncoder = typeof TextEncoder === 'undefined' ? require('util').TextEncoder : TextEncoder;
-
-let cachedTextEncoder = new lTextEncoder('utf-8');
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-}
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
-
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length);
-        getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
-        return ptr;
-    }
-
-    let len = arg.length;
-    let ptr = malloc(len);
-
-    const mem = getUint8Memory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
-    }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3);
-        const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
-
-        offset += ret.written;
-    }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {string} left
-* @param {string} right
-*/
-__exports__.highlight_with = function(store_id, left, right) {
-    var ptr0 = passStringToWasm0(left, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(right, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    wasm.highlight_with(store_id, ptr0, len0, ptr1, len1);
-};
-
-/**
-* @param {number} id
-* @param {number} limit
-*/
-__exports__.set_limit = function(id, limit) {
-    wasm.set_limit(id, limit);
-};
-
-let cachegetUint32Memory0 = null;
-function getUint32Memory0() {
-    if (cachegetUint32Memory0 === null || cachegetUint32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetUint32Memory0 = new Uint32Array(wasm.memory.buffer);
-    }
-    return cachegetUint32Memory0;
-}
-
-function passArray32ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 4);
-    getUint32Memory0().set(arg, ptr / 4);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-/**
-* @param {number} store_id
-* @param {Uint32Array} ids
-* @param {string} texts
-* @param {Uint32Array} ratings
-*/
-__exports__.set_records = function(store_id, ids, texts, ratings) {
-    var ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
-    var len0 = WASM_VECTOR_LEN;
-    var ptr1 = passStringToWasm0(texts, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len1 = WASM_VECTOR_LEN;
-    var ptr2 = passArray32ToWasm0(ratings, wasm.__wbindgen_malloc);
-    var len2 = WASM_VECTOR_LEN;
-    wasm.set_records(store_id, ptr0, len0, ptr1, len1, ptr2, len2);
-};
-
-/**
-* @param {number} store_id
-* @param {string} query
-*/
-__exports__.run_search = function(store_id, query) {
-    var ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
-    var len0 = WASM_VECTOR_LEN;
-    wasm.run_search(store_id, ptr0, len0);
-};
-
-let cachegetInt32Memory0 = null;
-function getInt32Memory0() {
-    if (cachegetInt32Memory0 === null || cachegetInt32Memory0.buffer !== wasm.memory.buffer) {
-        cachegetInt32Memory0 = new Int32Array(wasm.memory.buffer);
-    }
-    return cachegetInt32Memory0;
-}
-
-function getArrayU32FromWasm0(ptr, len) {
-    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
-}
-/**
-* @param {number} store_id
-* @returns {Uint32Array}
-*/
-__exports__.get_result_ids = function(store_id) {
-    wasm.get_result_ids(8, store_id);
-    var r0 = getInt32Memory0()[8 / 4 + 0];
-    var r1 = getInt32Memory0()[8 / 4 + 1];
-    var v0 = getArrayU32FromWasm0(r0, r1).slice();
-    wasm.__wbindgen_free(r0, r1 * 4);
-    return v0;
-};
-
-const lTextDecoder = typeof TextDecoder === 'undefined' ? require('util').TextDecoder : TextDecoder;
-
-let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-
-cachedTextDecoder.decode();
-
-function getStringFromWasm0(ptr, len) {
-    return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
-}
-/**
-* @param {number} store_id
-* @returns {string}
-*/
-__exports__.get_result_titles = function(store_id) {
-    try {
-        wasm.get_result_titles(8, store_id);
-        var r0 = getInt32Memory0()[8 / 4 + 0];
-        var r1 = getInt32Memory0()[8 / 4 + 1];
-        return getStringFromWasm0(r0, r1);
-    } finally {
-        wasm.__wbindgen_free(r0, r1);
-    }
-};
-
-
-
-                return __exports__;
-            });
-
-const DEFAULT_LIMIT = 10;
-var NEXT_ID = 1;
-
-
-class LucidSuggest {
-    constructor() {
-        this.id         = NEXT_ID++;
-        this.limit      = DEFAULT_LIMIT;
-        this.records    = [];
-        this.setupQueue = compileWasm;
-
-        this.setup(wasm => {
-            wasm.create_store(this.id);
-            wasm.highlight_with(this.id, '{{', '}}');
-        });
-    }
-
-    setup(fn) {
-        this.setupQueue = this.setupQueue.then(async wasm => {
-            await fn(wasm);
-            this.setupQueue = Promise.resolve(wasm);
-            return wasm
-        });
-        return this.setupQueue
-    }
-
-    destroy() {
-        const oldQueue = this.setupQueue;
-        this.setupQueue = Promise.reject(new Error('Suggest destroyed'));
-        oldQueue.then(wasm => {
-            wasm.destroy_store(this.id);
-        });
-    }
-
-    setRecords(records) {
-        return this.setup(wasm => {
-            this.records = setRatings(records);
-            wasm.set_records(
-                this.id,
-                this.records.map(r => r.id),
-                this.records.map(r => r.title).join('\0'),
-                this.records.map(r => r.rating),
-            );
-        })
-    }
-
-    setLimit(limit) {
-        return this.setup(wasm => {
-            this.limit = limit;
-            wasm.set_limit(this.id, this.limit);
-        })
-    }
-
-    async search(query) {
-        const wasm = await this.setupQueue;
-        wasm.run_search(this.id, query);
-        const ids    = wasm.get_result_ids(this.id);
-        const titles = wasm.get_result_titles(this.id).split('\0');
-        const hits   = [];
-        for (let i = 0; i < ids.length; i++) {
-            const id     = ids[i];
-            const title  = titles[i];
-            const record = this.records.find(r => r.id === id);
-            if (!record) throw new Error(`Missing record ${id}`)
-            if (!title)  throw new Error(`Missing title for ${id}`)
-            hits.push(new Hit(title, record));
-        }
-        return hits
-    }
-}
-
-
-function highlight(hit, left, right) {
-    let result = '';
-    for (const {text, highlight} of hit.chunks) {
-        result += highlight
-            ? left + text + right
-            : text;
-    }
-    return result
-}
-
-
-class Hit {
-    constructor(title, record) {
-        this.record = record;
-        this.chunks = toChunks(title);
-    }
-
-    get title() {
-        return highlight(this, '[', ']')
-    }
-}
-
-
-function toChunks(title) {
-    const split  = title.split(/{{|}}/g);
-    const chunks = [];
-    for (let i = 0; i < split.length; i++) {
-        if (split[i] != '') {
-            chunks.push({
-                text: split[i],
-                highlight: i % 2 === 1,
-            });
-        }
-    }
-    return chunks
-}
-
-
-function setRatings(records) {
-    return records.some(r => r.rating != null && r.rating > 0)
-        ? records.map(r => ({...r, rating: r.rating > 0 ? r.rating : 0}))
-        : records.map((r, i) => ({...r, rating: records.length - i}))
-}
-
-exports.Hit = Hit;
-exports.LucidSuggest = LucidSuggest;
-exports.highlight = highlight;
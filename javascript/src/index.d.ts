@@ -1,10 +1,16 @@
 export class LucidSuggest {
+    constructor(options?: Options);
     setRecords(records: Record[]): Promise<void>;
     setLimit(limit: number): Promise<void>;
     search(query: string): Promise<Hit[]>;
 }
 
 
+export interface Options {
+    lang?: string,
+}
+
+
 export interface Record {
     id:      number,
     title:   string,
@@ -1,20 +1,26 @@
 import compileWasm from '../pkg/lucid_suggest_wasm'
 
 const DEFAULT_LIMIT = 10
+const DEFAULT_LANG  = 'en'
 var NEXT_ID = 1
 
 
 export class LucidSuggest {
-    constructor() {
+    constructor({lang = DEFAULT_LANG} = {}) {
         this.id         = NEXT_ID++
+        this.lang       = lang
         this.limit      = DEFAULT_LIMIT
         this.records    = []
         this.setupQueue = compileWasm
 
         this.setup(wasm => {
-            wasm.create_store(this.id)
+            if (!wasm.create_store(this.id, this.lang)) {
+                throw new Error(`Unsupported language ${this.lang}`)
+            }
             wasm.highlight_with(this.id, '{{', '}}')
         })
+        // An unsupported lang rejects the calls that wait for the setup queue, not the constructor.
+        this.setupQueue.catch(() => {})
     }
 
     setup(fn) {
@@ -29,9 +35,10 @@ export class LucidSuggest {
     destroy() {
         const oldQueue = this.setupQueue
         this.setupQueue = Promise.reject(new Error('Suggest destroyed'))
+        this.setupQueue.catch(() => {})
         oldQueue.then(wasm => {
             wasm.destroy_store(this.id)
-        })
+        }, () => {})
     }
 
     setRecords(records) {
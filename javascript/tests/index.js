@@ -1,4 +1,4 @@
-const {LucidSuggest, highlight} = require('../en')
+const {LucidSuggest, highlight} = require('../index')
 
 describe('Suggest', () => {
     const records = [
@@ -89,6 +89,12 @@ describe('Suggest', () => {
         expect(hits).toMatchSnapshot()
     })
 
+    test('Unsupported language', async () => {
+        const suggest = new LucidSuggest({lang: 'xx'})
+        await expect(suggest.setRecords(records)).rejects.toThrow('Unsupported language xx')
+        await expect(suggest.search('hel')).rejects.toThrow('Unsupported language xx')
+    })
+
     describe('Render', () => {
         test('highlight helper', async () => {
             const suggest = new LucidSuggest()
@@ -1,4 +1,4 @@
-const {LucidSuggest} = require('../de')
+const {LucidSuggest} = require('../index')
 
 
 describe('German language', () => {
@@ -9,7 +9,7 @@ describe('German language', () => {
         {id: 40, title: 'Hi-Fi-Mitteltöner, Speaker für die Mittelton-Wiedergabe'},
     ]
 
-    const suggest = new LucidSuggest()
+    const suggest = new LucidSuggest({lang: 'de'})
     suggest.setRecords(records)
 
     test('Empty input', async () => {
@@ -1,4 +1,4 @@
-const {LucidSuggest} = require('../en')
+const {LucidSuggest} = require('../index')
 
 
 describe('English language', () => {
@@ -9,7 +9,7 @@ describe('English language', () => {
         {id: 40, title: 'Vacuum Compression Storage Bags'},
     ]
 
-    const suggest = new LucidSuggest()
+    const suggest = new LucidSuggest({lang: 'en'})
     suggest.setRecords(records)
 
     test('Empty input', async () => {
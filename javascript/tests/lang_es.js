@@ -1,4 +1,4 @@
-const {LucidSuggest} = require('../es')
+const {LucidSuggest} = require('../index')
 
 
 describe('Spanish language', () => {
@@ -9,7 +9,7 @@ describe('Spanish language', () => {
         {id: 40, title: 'Deshumidificador Eléctrico portátil'},
     ]
 
-    const suggest = new LucidSuggest()
+    const suggest = new LucidSuggest({lang: 'es'})
     suggest.setRecords(records)
 
     test('Empty input', async () => {
@@ -1,4 +1,4 @@
-const {LucidSuggest} = require('../fr')
+const {LucidSuggest} = require('../index')
 
 
 describe('French language', () => {
@@ -9,7 +9,7 @@ describe('French language', () => {
         {id: 40, title: 'Sacs de Rangement Sous Vide'},
     ]
 
-    const suggest = new LucidSuggest()
+    const suggest = new LucidSuggest({lang: 'fr'})
     suggest.setRecords(records)
 
     test('Empty input', async () => {
@@ -0,0 +1,44 @@
+const {LucidSuggest} = require('../index')
+
+
+describe('Italian language', () => {
+    const records = [
+        {id: 10, title: 'Confezione da 24 pile alcaline AA'},
+        {id: 20, title: "Bottiglia dell'acqua in acciaio"},
+        {id: 30, title: 'Spazzolino elettrico'},
+        {id: 40, title: 'Macchina da caffè portatile'},
+    ]
+
+    const suggest = new LucidSuggest({lang: 'it'})
+    suggest.setRecords(records)
+
+    test('Empty input', async () => {
+        const hits = await suggest.search('')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Equality', async () => {
+        const hits = await suggest.search('spazzolino elettrico')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Stemming', async () => {
+        const hits = await suggest.search('alcalina ')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Partiles', async () => {
+        const hits = await suggest.search('da')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Normalization', async () => {
+        const hits = await suggest.search('caffe porta')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Elision', async () => {
+        const hits = await suggest.search('acqua')
+        expect(hits).toMatchSnapshot()
+    })
+})
\ No newline at end of file
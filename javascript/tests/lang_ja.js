@@ -0,0 +1,39 @@
+const {LucidSuggest} = require('../index')
+
+
+describe('Japanese language', () => {
+    const records = [
+        {id: 10, title: '東京タワーの観光ガイド'},
+        {id: 20, title: '京都の旅館'},
+        {id: 30, title: 'iPhone用ケース'},
+        {id: 40, title: 'ワイヤレスイヤホン'},
+    ]
+
+    const suggest = new LucidSuggest({lang: 'ja'})
+    suggest.setRecords(records)
+
+    test('Empty input', async () => {
+        const hits = await suggest.search('')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Bigrams', async () => {
+        const hits = await suggest.search('京都')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Unfinished input', async () => {
+        const hits = await suggest.search('イヤホ')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Mixed scripts', async () => {
+        const hits = await suggest.search('iphone ケース')
+        expect(hits).toMatchSnapshot()
+    })
+
+    test('Fullwidth', async () => {
+        const hits = await suggest.search('ｉｐｈｏｎｅ')
+        expect(hits).toMatchSnapshot()
+    })
+})
@@ -1,4 +1,4 @@
-const {LucidSuggest} = require('../pt')
+const {LucidSuggest} = require('../index')
 
 
 describe('Portuguese language', () => {
@@ -9,7 +9,7 @@ describe('Portuguese language', () => {
         {id: 40, title: 'Sacos de armazenamento a vácuo'},
     ]
 
-    const suggest = new LucidSuggest()
+    const suggest = new LucidSuggest({lang: 'pt'})
     suggest.setRecords(records)
 
     test('Empty input', async () => {
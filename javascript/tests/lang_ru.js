@@ -1,4 +1,4 @@
-const {LucidSuggest} = require('../ru')
+const {LucidSuggest} = require('../index')
 
 
 describe('Russian language', () => {
@@ -9,7 +9,7 @@ describe('Russian language', () => {
         {id: 40, title: 'Вакуумные компресионные мешки'},
     ]
 
-    const suggest = new LucidSuggest()
+    const suggest = new LucidSuggest({lang: 'ru'})
     suggest.setRecords(records)
 
     test('Empty input', async () => {
@@ -17,7 +17,7 @@ fn search_benchmark(criterion: &mut Criterion) {
                 let mut i = 0;
                 bench.iter(|| {
                     let query = &queries[i].to_ref();
-                    store.search(black_box(query));
+                    store.search(black_box(query), None);
                     i = (i + 1) % queries.len();
                 })
             });
@@ -87,7 +87,7 @@ impl SyntheticDataset {
         let mut rng     = thread_rng();
         for _ in 0..len {
             let record   = &records[rng.gen_range(0, records.len())];
-            let title    = &record.title;
+            let title    = record.title();
             let qlen_max = title.words[..min(3, title.words.len())]
                 .iter()
                 .map(|w| w.len())
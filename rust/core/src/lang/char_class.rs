@@ -82,6 +82,8 @@ fn is_punctuation(ch: char) -> bool {
         '.' | '!' | '?' => true,
         '-' | '‑' | '‒' | '–' | '—' => true,
         '…' | '‼' | '⁇' | '⁈' | '⁉' => true,
+        '،' | '؛' | '؟' | '־' => true,
+        '、' | '。' | '・' | '「' | '」' | '『' | '』' => true,
         _ => false,
     }
 }
@@ -136,6 +138,7 @@ mod tests {
         assert_eq!(Punctuation.matches(';',  &Lang::new()), Some(true));
         assert_eq!(Punctuation.matches('.',  &Lang::new()), Some(true));
         assert_eq!(Punctuation.matches(',',  &Lang::new()), Some(true));
+        assert_eq!(Punctuation.matches('。', &Lang::new()), Some(true));
         assert_eq!(Punctuation.matches('2',  &Lang::new()), Some(false));
         assert_eq!(Punctuation.matches('f',  &Lang::new()), Some(false));
         assert_eq!(Punctuation.matches(' ',  &Lang::new()), Some(false));
@@ -1,20 +1,35 @@
 use std::cell::RefCell;
-use fnv::{FnvHashMap as HashMap};
-use rust_stemmers::Stemmer;
+use fnv::{FnvHashMap as HashMap, FnvHashSet as HashSet};
 use crate::utils::to_vec;
-use super::{CharClass, PartOfSpeech};
+use super::{CharClass, PartOfSpeech, Phonetic, PhoneticKey, Stem};
 use super::normalize::Normalize;
 
 const BUFFER_CAPACITY: usize = 20;
+const COMPOUND_PART_LEN: usize = 3;
+const PROCLITIC_BASE_LEN: usize = 3;
+const APOSTROPHES: &[char] = &['\'', '’'];
+
+// Dropped chars and their positions in the source, see Text::marks.
+pub type Marks = Vec<(usize, char)>;
 
 
 pub struct Lang {
-    stemmer:      Option<Stemmer>,
+    stemmer:      Option<Box<dyn Stem>>,
+    phonetic:     Option<Phonetic>,
     char_map:     HashMap<char, CharClass>,
     pos_map:      HashMap<Vec<char>, PartOfSpeech>,
     compose_map:  HashMap<Vec<char>, Vec<char>>,
     reduce_map:   HashMap<Vec<char>, Vec<char>>,
-    stem_buffer:  RefCell<String>,
+    layout_map:   HashMap<char, char>,
+    case_map:     HashMap<char, char>,
+    translit_map: HashMap<Vec<char>, Vec<char>>,
+    synonyms:     HashMap<Vec<Vec<char>>, Vec<String>>,
+    synonym_len:  usize,
+    compounds:    HashSet<Vec<char>>,
+    links:        Vec<Vec<char>>,
+    elisions:     HashSet<Vec<char>>,
+    proclitics:   Vec<Vec<char>>,
+    bigrams:      Vec<(char, char)>,
     norm_buffer1: RefCell<Vec<char>>,
     norm_buffer2: RefCell<Vec<char>>,
 }
@@ -24,18 +39,32 @@ impl Lang {
     pub fn new() -> Self {
         Self {
             stemmer:      None,
+            phonetic:     None,
             char_map:     HashMap::default(),
             pos_map:      HashMap::default(),
             compose_map:  HashMap::default(),
             reduce_map:   HashMap::default(),
-            stem_buffer:  RefCell::new(String::with_capacity(BUFFER_CAPACITY)),
+            layout_map:   HashMap::default(),
+            case_map:     HashMap::default(),
+            translit_map: HashMap::default(),
+            synonyms:     HashMap::default(),
+            synonym_len:  0,
+            compounds:    HashSet::default(),
+            links:        Vec::new(),
+            elisions:     HashSet::default(),
+            proclitics:   Vec::new(),
+            bigrams:      Vec::new(),
             norm_buffer1: RefCell::new(Vec::with_capacity(BUFFER_CAPACITY)),
             norm_buffer2: RefCell::new(Vec::with_capacity(BUFFER_CAPACITY)),
         }
     }
 
-    pub fn set_stemmer(&mut self, stemmer: Option<Stemmer>) {
-        self.stemmer = stemmer;
+    pub fn set_stemmer<S>(&mut self, stemmer: Option<S>) where S: Stem + 'static {
+        self.stemmer = stemmer.map(|s| Box::new(s) as Box<dyn Stem>);
+    }
+
+    pub fn set_phonetic(&mut self, phonetic: Option<Phonetic>) {
+        self.phonetic = phonetic;
     }
 
     pub fn add_char_class(&mut self, ch: char, class: CharClass) {
@@ -49,7 +78,7 @@ impl Lang {
 
         self.pos_map.insert(composed, pos);
 
-        if let Some((_, reduced)) = reduced {
+        if let Some((_, reduced, _)) = reduced {
             self.pos_map.insert(reduced, pos);
         }
     }
@@ -62,18 +91,284 @@ impl Lang {
         self.reduce_map.insert(to_vec(from), to_vec(to));
     }
 
+    // Overrides generic Unicode lowercasing, like Turkish 'I' -> 'ı'.
+    pub fn add_lowercase(&mut self, upper: char, lower: char) {
+        self.case_map.insert(upper, lower);
+    }
+
+    pub fn lower(&self, ch: char) -> char {
+        let lower = match self.case_map.get(&ch) {
+            Some(&lower) => lower,
+            None         => ch.to_lowercase().next().unwrap_or(ch),
+        };
+        // Lowercase chars may reduce further, like Turkish 'I' -> 'ı' -> 'i'.
+        match self.reduce_map.get(&[lower][..]) {
+            Some(reduced) if lower != ch && reduced.len() == 1 => reduced[0],
+            _ => lower,
+        }
+    }
+
+    // Keys at the same position in both layouts are remapped in both directions.
+    pub fn add_layout_pair(&mut self, layout1: &str, layout2: &str) {
+        debug_assert!(layout1.chars().count() == layout2.chars().count(), "Layouts differ in length");
+        for (ch1, ch2) in layout1.chars().zip(layout2.chars()) {
+            self.layout_map.insert(ch1, ch2);
+            self.layout_map.insert(ch2, ch1);
+        }
+    }
+
+    // Romanization, the uppercase letter is added too: ('щ', "shch") also maps 'Щ'.
+    pub fn add_transliteration(&mut self, from: char, to: &str) {
+        self.translit_map.insert(vec![from], to_vec(to));
+        let mut upper = from.to_uppercase();
+        if let (Some(upper), None) = (upper.next(), upper.next()) {
+            self.translit_map.insert(vec![upper], to_vec(to));
+        }
+    }
+
+    pub fn remap_layout(&self, source: &str) -> Option<String> {
+        if self.layout_map.is_empty() {
+            return None;
+        }
+        let mut changed = false;
+        let remapped = source.chars()
+            .map(|ch| self.lower(ch))
+            .map(|ch| match self.layout_map.get(&ch) {
+                Some(&remapped) => { changed = true; remapped },
+                None            => ch,
+            })
+            .collect();
+        if changed { Some(remapped) } else { None }
+    }
+
+    // One-way rule: query "from" also matches records with "to", but not the other way around.
+    pub fn add_synonym(&mut self, from: &str, to: &str) {
+        let key = self.normalize_phrase(from);
+        if key.is_empty() || key == self.normalize_phrase(to) {
+            return;
+        }
+        self.synonym_len = max!(self.synonym_len, key.len());
+        let targets = self.synonyms.entry(key).or_default();
+        if !targets.iter().any(|t| t == to) {
+            targets.push(to.to_string());
+        }
+    }
+
+    // Two-way rule: every phrase in the group matches every other one.
+    pub fn add_synonyms(&mut self, group: &[&str]) {
+        for &from in group {
+            for &to in group {
+                self.add_synonym(from, to);
+            }
+        }
+    }
+
+    pub fn add_elision(&mut self, prefix: &str) {
+        for prefix in self.normalize_phrase(prefix) {
+            self.elisions.insert(prefix);
+        }
+    }
+
+    // Length of the elided prefix, apostrophe excluded: "l'acqua" -> 1.
+    pub fn elision(&self, word: &[char]) -> Option<usize> {
+        if self.elisions.is_empty() {
+            return None;
+        }
+        let len = word.iter().position(|ch| APOSTROPHES.contains(ch))?;
+        if len + 1 == word.len() || !self.elisions.contains(&word[.. len]) {
+            return None;
+        }
+        Some(len)
+    }
+
+    // Prefix attached without an apostrophe, like Arabic "ال" in "الكتاب".
+    pub fn add_proclitic(&mut self, prefix: &str) {
+        for prefix in self.normalize_phrase(prefix) {
+            self.proclitics.push(prefix);
+        }
+        self.proclitics.sort_by_key(|prefix| std::cmp::Reverse(prefix.len()));
+    }
+
+    // Length of the longest known prefix, if the rest is long enough to be a word.
+    pub fn proclitic(&self, word: &[char]) -> Option<usize> {
+        self.proclitics
+            .iter()
+            .find(|prefix| word.len() >= prefix.len() + PROCLITIC_BASE_LEN && word.starts_with(prefix))
+            .map(|prefix| prefix.len())
+    }
+
+    pub fn add_compound_part(&mut self, word: &str) {
+        for part in self.normalize_phrase(word) {
+            self.compounds.insert(part);
+        }
+    }
+
+    // Linking element between compound parts, like "s" in "Arbeitsplatz".
+    pub fn add_compound_link(&mut self, link: &str) {
+        self.links.push(to_vec(link));
+        self.links.sort_by_key(|link| link.len());
+    }
+
+    // Lengths of the parts, only if the whole word is built of known parts.
+    pub fn decompound(&self, word: &[char]) -> Option<Vec<usize>> {
+        if self.compounds.is_empty() {
+            return None;
+        }
+        let mut parts = Vec::new();
+        if !self.split_compound(word, &mut parts) || parts.len() < 2 {
+            return None;
+        }
+        Some(parts)
+    }
+
+    fn split_compound(&self, word: &[char], parts: &mut Vec<usize>) -> bool {
+        if self.is_compound_tail(word) {
+            parts.push(word.len());
+            return true;
+        }
+        if word.len() < 2 * COMPOUND_PART_LEN {
+            return false;
+        }
+        for len in (COMPOUND_PART_LEN ..= word.len() - COMPOUND_PART_LEN).rev() {
+            if !self.compounds.contains(&word[.. len]) {
+                continue;
+            }
+            let links = Some(&[][..]).into_iter().chain(self.links.iter().map(|l| &l[..]));
+            for link in links {
+                let head = len + link.len();
+                if !word[len ..].starts_with(link) { continue; }
+                if word.len() < head + COMPOUND_PART_LEN { continue; }
+                parts.push(head);
+                if self.split_compound(&word[head ..], parts) {
+                    return true;
+                }
+                parts.pop();
+            }
+        }
+        false
+    }
+
+    // Scripts written without spaces, like Han or Kana, are split into overlapping bigrams.
+    pub fn add_bigram_range(&mut self, first: char, last: char) {
+        self.bigrams.push((first, last));
+    }
+
+    pub fn is_bigram_char(&self, ch: char) -> bool {
+        self.bigrams.iter().any(|&(first, last)| first <= ch && ch <= last)
+    }
+
+    // Slices of the segments: bigram runs are split into overlapping pairs,
+    // other runs are kept whole, "iphone用ケース" -> "iphone", "用ケ", "ケー", "ース".
+    pub fn segment(&self, word: &[char]) -> Option<Vec<(usize, usize)>> {
+        if self.bigrams.is_empty() || !word.iter().any(|&ch| self.is_bigram_char(ch)) {
+            return None;
+        }
+        let mut segments = Vec::new();
+        let mut left     = 0;
+        while left < word.len() {
+            let bigram = self.is_bigram_char(word[left]);
+            let len    = word[left ..]
+                .iter()
+                .take_while(|&&ch| self.is_bigram_char(ch) == bigram)
+                .count();
+            if bigram && len > 1 {
+                segments.extend((left .. left + len - 1).map(|i| (i, i + 2)));
+            } else {
+                segments.push((left, left + len));
+            }
+            left += len;
+        }
+        if segments.len() < 2 {
+            return None;
+        }
+        Some(segments)
+    }
+
+    // The last part may carry an inflection, like "n" in "Kaffeemaschinen".
+    fn is_compound_tail(&self, word: &[char]) -> bool {
+        let known = |len: usize| len >= COMPOUND_PART_LEN && self.compounds.contains(&word[.. len]);
+        known(word.len()) || self.links
+            .iter()
+            .any(|link| word.ends_with(link) && known(word.len() - link.len()))
+    }
+
+    // Each variant is the query with a single phrase replaced by its synonym.
+    pub fn synonym_variants(&self, words: &[&[char]]) -> Vec<String> {
+        let mut variants = Vec::new();
+        if self.synonyms.is_empty() {
+            return variants;
+        }
+        for start in 0 .. words.len() {
+            for len in 1 ..= min!(self.synonym_len, words.len() - start) {
+                let key = words[start .. start + len]
+                    .iter()
+                    .map(|w| w.to_vec())
+                    .collect::<Vec<_>>();
+                let targets = match self.synonyms.get(&key) {
+                    Some(targets) => targets,
+                    None          => continue,
+                };
+                for target in targets {
+                    let variant = words[.. start].iter().map(|w| w.iter().collect::<String>())
+                        .chain(Some(target.clone()))
+                        .chain(words[start + len ..].iter().map(|w| w.iter().collect::<String>()))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    variants.push(variant);
+                }
+            }
+        }
+        variants
+    }
+
+    fn normalize_phrase(&self, phrase: &str) -> Vec<Vec<char>> {
+        phrase
+            .split_whitespace()
+            .map(|word| {
+                let word     = to_vec(word);
+                let composed = self.unicode_compose(&word).unwrap_or(word);
+                let reduced  = self.unicode_reduce(&composed).map(|(_, r, _)| r).unwrap_or(composed);
+                reduced.iter().map(|&ch| self.lower(ch)).collect()
+            })
+            .collect()
+    }
+
+    // Rough evidence that a query word is in this lang: function words and elisions count double,
+    // letters outside ASCII count for or against it depending on whether the lang knows them.
+    pub fn detect_score(&self, word: &[char]) -> isize {
+        let composed = self.unicode_compose(word).unwrap_or_else(|| word.to_vec());
+        let mut score = composed
+            .iter()
+            .filter(|ch| !ch.is_ascii() && ch.is_alphabetic())
+            .map(|&ch| if self.knows_char(ch) { 1 } else { -1 })
+            .sum::<isize>();
+
+        // Composed form, so that "à" doesn't count as "a".
+        let lowered = composed.iter().flat_map(|ch| ch.to_lowercase()).collect::<Vec<char>>();
+        if self.get_pos(&lowered).is_some() || self.elision(&lowered).is_some() {
+            score += 2;
+        }
+        score
+    }
+
+    fn knows_char(&self, ch: char) -> bool {
+        self.char_map.contains_key(&ch)
+            || self.reduce_map.contains_key(&[ch][..])
+            || self.translit_map.contains_key(&[ch][..])
+            || self.is_bigram_char(ch)
+    }
+
     pub fn stem(&self, word: &[char]) -> usize {
-        if let Some(stemmer) = &self.stemmer {
-            let buffer = &mut *self.stem_buffer.borrow_mut();
-            buffer.clear();
-            buffer.extend(word.iter());
-            let stem = stemmer.stem(&buffer);
-            stem.chars().count()
-        } else {
-            word.len()
+        match &self.stemmer {
+            Some(stemmer) => stemmer.stem_len(word),
+            None          => word.len(),
         }
     }
 
+    pub fn phonetic_key(&self, word: &[char]) -> Option<PhoneticKey> {
+        self.phonetic.as_ref()?.key(word)
+    }
+
     pub fn get_pos(&self, word: &[char]) -> Option<PartOfSpeech> {
         self.pos_map.get(word).cloned()
     }
@@ -97,16 +392,22 @@ impl Lang {
         }
     }
 
-    pub fn unicode_reduce(&self, word: &[char]) -> Option<(Vec<char>, Vec<char>)> {
+    // Source padded with '\0' to the reduced length, reduced chars, and the chars that were dropped.
+    pub fn unicode_reduce(&self, word: &[char]) -> Option<(Vec<char>, Vec<char>, Marks)> {
         let buffer1 = &mut *self.norm_buffer1.borrow_mut();
         let buffer2 = &mut *self.norm_buffer2.borrow_mut();
+        let mut marks = Vec::new();
         buffer1.clear();
         buffer2.clear();
 
         for (word_chunk, norm_chunk) in Normalize::new(word, &self.reduce_map) {
-            buffer1.extend(word_chunk);
+            let kept = min!(word_chunk.len(), norm_chunk.len());
+            buffer1.extend(&word_chunk[.. kept]);
             buffer2.extend(norm_chunk);
-            for _ in 0 .. norm_chunk.len() - word_chunk.len() {
+            for &ch in &word_chunk[kept ..] {
+                marks.push((buffer1.len(), ch));
+            }
+            for _ in kept .. norm_chunk.len() {
                 buffer1.push('\0');
             }
         }
@@ -114,7 +415,41 @@ impl Lang {
         if &buffer2[..] == word {
             None
         } else {
-            Some((buffer1.clone(), buffer2.clone()))
+            Some((buffer1.clone(), buffer2.clone(), marks))
+        }
+    }
+
+    // Romanized chars, and the source padded with '\0' like in unicode_reduce.
+    // Source chars romanized to nothing, like 'ь', are dropped into marks along with the given ones.
+    pub fn transliterate(&self, source: &[char], chars: &[char], marks: &[(usize, char)]) -> Option<(Vec<char>, Vec<char>, Marks)> {
+        if self.translit_map.is_empty() {
+            return None;
+        }
+        let mut padded    = Vec::with_capacity(source.len());
+        let mut romanized = Vec::with_capacity(chars.len());
+        let mut shifted   = Vec::with_capacity(marks.len());
+        let mut marks     = marks.iter().peekable();
+        let mut offset    = 0;
+
+        for (chars_chunk, translit_chunk) in Normalize::new(chars, &self.translit_map) {
+            let kept = min!(chars_chunk.len(), translit_chunk.len());
+            while let Some(&(pos, ch)) = marks.next_if(|&&(pos, _)| pos < offset + chars_chunk.len()) {
+                shifted.push((padded.len() + min!(pos - offset, kept), ch));
+            }
+            padded.extend(&source[offset .. offset + kept]);
+            romanized.extend(translit_chunk);
+            for &ch in &source[offset + kept .. offset + chars_chunk.len()] {
+                shifted.push((padded.len(), ch));
+            }
+            padded.resize(padded.len() + translit_chunk.len() - kept, '\0');
+            offset += chars_chunk.len();
+        }
+        shifted.extend(marks.map(|&(_, ch)| (padded.len(), ch)));
+
+        if &romanized[..] == chars {
+            None
+        } else {
+            Some((padded, romanized, shifted))
         }
     }
 }
@@ -123,8 +458,9 @@ impl Lang {
 #[cfg(test)]
 mod tests {
     use insta::assert_debug_snapshot;
-    use crate::utils::to_vec;
+    use crate::utils::{to_vec, to_str};
     use super::super::{CharClass, PartOfSpeech};
+    #[cfg(feature = "de")] use super::super::lang_german;
     use super::Lang;
 
     fn get_lang() -> Lang {
@@ -173,6 +509,135 @@ mod tests {
         assert_debug_snapshot!(output);
     }
 
+    #[test]
+    fn transliterate() {
+        let mut lang = get_lang();
+        let source   = to_vec("Щётка и ok");
+        assert_eq!(lang.transliterate(&source, &source, &[]), None);
+        for &(from, to) in &[('щ', "shch"), ('ё', "e"), ('т', "t"), ('к', "k"), ('а', "a"), ('и', "i")] {
+            lang.add_transliteration(from, to);
+        }
+        let (padded, romanized, marks) = lang.transliterate(&source, &source, &[]).unwrap();
+        assert_eq!(to_str(&romanized), "shchetka i ok");
+        assert_eq!(padded, to_vec("Щ\0\0\0ётка и ok"));
+        assert_eq!(marks, vec![]);
+        assert_eq!(lang.transliterate(&to_vec("ok"), &to_vec("ok"), &[]), None);
+    }
+
+    #[test]
+    fn transliterate_shorter() {
+        let mut lang = get_lang();
+        for &(from, to) in &[('щ', "shch"), ('ь', ""), ('ю', "yu")] {
+            lang.add_transliteration(from, to);
+        }
+        let source = to_vec("щьюx");
+        let (padded, romanized, marks) = lang.transliterate(&source, &source, &[(1, '\u{301}'), (4, '!')]).unwrap();
+        assert_eq!(to_str(&romanized), "shchyux");
+        assert_eq!(padded, to_vec("щ\0\0\0ю\0x"));
+        assert_eq!(marks, vec![(4, '\u{301}'), (4, 'ь'), (7, '!')]);
+    }
+
+    #[test]
+    fn synonyms_one_way() {
+        let mut lang = get_lang();
+        lang.add_synonym("sofa", "couch");
+        let sofa  = to_vec("sofa");
+        let couch = to_vec("couch");
+        let bed   = to_vec("bed");
+        assert_eq!(lang.synonym_variants(&[&sofa, &bed]), vec!["couch bed".to_string()]);
+        assert_eq!(lang.synonym_variants(&[&couch, &bed]), Vec::<String>::new());
+    }
+
+    #[test]
+    fn synonyms_two_way() {
+        let mut lang = get_lang();
+        lang.add_synonyms(&["TV", "television", "telly"]);
+        let tv    = to_vec("tv");
+        let telly = to_vec("telly");
+        assert_eq!(lang.synonym_variants(&[&tv]),    vec!["television".to_string(), "telly".to_string()]);
+        assert_eq!(lang.synonym_variants(&[&telly]), vec!["TV".to_string(), "television".to_string()]);
+    }
+
+    #[test]
+    fn synonyms_multi_word() {
+        let mut lang = get_lang();
+        lang.add_synonyms(&["night light", "nightlight"]);
+        let night = to_vec("night");
+        let light = to_vec("light");
+        let lamp  = to_vec("lamp");
+        let joint = to_vec("nightlight");
+        assert_eq!(lang.synonym_variants(&[&lamp, &night, &light]), vec!["lamp nightlight".to_string()]);
+        assert_eq!(lang.synonym_variants(&[&joint, &lamp]),         vec!["night light lamp".to_string()]);
+        assert_eq!(lang.synonym_variants(&[&night, &lamp]),         Vec::<String>::new());
+    }
+
+    #[test]
+    fn synonyms_normalized() {
+        let mut lang = get_lang();
+        lang.add_synonym("Fóo", "bar");
+        let foo = to_vec("foo");
+        assert_eq!(lang.synonym_variants(&[&foo]), vec!["bar".to_string()]);
+    }
+
+    #[test]
+    fn segment_bigrams() {
+        let mut lang = get_lang();
+        assert_eq!(lang.segment(&to_vec("東京都")), None);
+        lang.add_bigram_range('\u{3040}', '\u{30FF}');
+        lang.add_bigram_range('\u{4E00}', '\u{9FFF}');
+        assert_eq!(lang.segment(&to_vec("東京都")),        Some(vec![(0, 2), (1, 3)]));
+        assert_eq!(lang.segment(&to_vec("iphone用ケース")), Some(vec![(0, 6), (6, 8), (7, 9), (8, 10)]));
+        assert_eq!(lang.segment(&to_vec("東京2020")),      Some(vec![(0, 2), (2, 6)]));
+        assert_eq!(lang.segment(&to_vec("東")),            None);
+        assert_eq!(lang.segment(&to_vec("東京")),          None);
+        assert_eq!(lang.segment(&to_vec("tokyo")),         None);
+    }
+
+    #[test]
+    fn proclitic_custom() {
+        let mut lang = get_lang();
+        assert_eq!(lang.proclitic(&to_vec("unbox")), None);
+        lang.add_proclitic("un");
+        lang.add_proclitic("unbe");
+        assert_eq!(lang.proclitic(&to_vec("unbox")),      Some(2));
+        assert_eq!(lang.proclitic(&to_vec("unbeknown")),  Some(4));
+        assert_eq!(lang.proclitic(&to_vec("unbeat")),     Some(2));
+        assert_eq!(lang.proclitic(&to_vec("unit")),       None);
+        assert_eq!(lang.proclitic(&to_vec("box")),        None);
+    }
+
+    #[cfg(feature = "de")]
+    #[test]
+    fn decompound_basic() {
+        let lang = lang_german();
+        assert_eq!(lang.decompound(&to_vec("kaffeemaschine")),  Some(vec![6, 8]));
+        assert_eq!(lang.decompound(&to_vec("waschmaschinen")),  Some(vec![5, 9]));
+        assert_eq!(lang.decompound(&to_vec("arbeitsplatz")),    Some(vec![7, 5]));
+        assert_eq!(lang.decompound(&to_vec("kinderwagen")),     Some(vec![6, 5]));
+        assert_eq!(lang.decompound(&to_vec("hausturschlussel")), Some(vec![4, 3, 9]));
+    }
+
+    #[cfg(feature = "de")]
+    #[test]
+    fn decompound_known_word() {
+        let lang = lang_german();
+        assert_eq!(lang.decompound(&to_vec("maschine")),      None);
+        assert_eq!(lang.decompound(&to_vec("maschinen")),     None);
+        assert_eq!(lang.decompound(&to_vec("kaffeeklatsch")), None);
+    }
+
+    #[test]
+    fn decompound_custom() {
+        let mut lang = get_lang();
+        assert_eq!(lang.decompound(&to_vec("fooxbar")), None);
+        lang.add_compound_part("Fóox");
+        lang.add_compound_part("bar");
+        assert_eq!(lang.decompound(&to_vec("fooxbar")),  Some(vec![4, 3]));
+        assert_eq!(lang.decompound(&to_vec("fooxsbar")), None);
+        lang.add_compound_link("s");
+        assert_eq!(lang.decompound(&to_vec("fooxsbar")), Some(vec![5, 3]));
+    }
+
     #[test]
     fn get_pos_source() {
         let input = to_vec("fóo");
@@ -194,6 +659,16 @@ mod tests {
         assert_eq!(pos, None);
     }
 
+    #[cfg(feature = "de")]
+    #[test]
+    fn detect_score() {
+        let lang = lang_german();
+        assert_eq!(lang.detect_score(&to_vec("für")),    3);
+        assert_eq!(lang.detect_score(&to_vec("Straße")), 1);
+        assert_eq!(lang.detect_score(&to_vec("haus")),   0);
+        assert_eq!(lang.detect_score(&to_vec("café")),   -1);
+    }
+
     #[test]
     fn get_char_class_known() {
         let class_x = get_lang().get_char_class('x');
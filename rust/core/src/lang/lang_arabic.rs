@@ -0,0 +1,241 @@
+#![allow(dead_code)]
+
+use super::{CharClass, PartOfSpeech, SuffixStemmer};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Consonant,
+    Vowel,
+};
+
+use PartOfSpeech::{
+    Article,
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Article, "ال"),
+    (Article, "وال"),
+    (Article, "بال"),
+    (Article, "كال"),
+    (Article, "فال"),
+    (Article, "لل"),
+
+    (Preposition, "في"),
+    (Preposition, "من"),
+    (Preposition, "إلى"),
+    (Preposition, "على"),
+    (Preposition, "عن"),
+    (Preposition, "مع"),
+    (Preposition, "حتى"),
+    (Preposition, "منذ"),
+    (Preposition, "بين"),
+    (Preposition, "عند"),
+
+    (Conjunction, "و"),
+    (Conjunction, "أو"),
+    (Conjunction, "ثم"),
+    (Conjunction, "لكن"),
+    (Conjunction, "بل"),
+];
+
+
+// Detached as separate function words, the longest one first: "والكتاب" -> "وال", "كتاب".
+const PROCLITICS: &[&str] = &["وال", "بال", "كال", "فال", "لل", "ال", "و"];
+
+
+// Reduced forms, stemming runs after letter variants are folded.
+const SUFFIXES: &[&str] = &["ها", "ان", "ات", "ون", "ين", "يه", "ه", "ي"];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Consonant, 'ب'),
+    (Consonant, 'ت'),
+    (Consonant, 'ث'),
+    (Consonant, 'ج'),
+    (Consonant, 'ح'),
+    (Consonant, 'خ'),
+    (Consonant, 'د'),
+    (Consonant, 'ذ'),
+    (Consonant, 'ر'),
+    (Consonant, 'ز'),
+    (Consonant, 'س'),
+    (Consonant, 'ش'),
+    (Consonant, 'ص'),
+    (Consonant, 'ض'),
+    (Consonant, 'ط'),
+    (Consonant, 'ظ'),
+    (Consonant, 'ع'),
+    (Consonant, 'غ'),
+    (Consonant, 'ف'),
+    (Consonant, 'ق'),
+    (Consonant, 'ك'),
+    (Consonant, 'ل'),
+    (Consonant, 'م'),
+    (Consonant, 'ن'),
+    (Consonant, 'ه'),
+    (Consonant, 'ء'),
+    (Consonant, 'ؤ'),
+    (Consonant, 'ئ'),
+    (Vowel, 'ا'),
+    (Vowel, 'و'),
+    (Vowel, 'ي'),
+];
+
+
+// Harakat and tatweel are folded away for matching, results keep them.
+const STRIPPED_RANGES: &[(char, char)] = &[
+    ('\u{0610}', '\u{061A}'), // honorifics
+    ('\u{064B}', '\u{065F}'), // harakat
+    ('\u{0640}', '\u{0640}'), // tatweel
+    ('\u{0670}', '\u{0670}'), // superscript alef
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("آ", "آ"), // hamza, madda
+    ("أ", "أ"),
+    ("ؤ", "ؤ"),
+    ("إ", "إ"),
+    ("ئ", "ئ"),
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("أ", "ا"), // alef variants
+    ("إ", "ا"),
+    ("آ", "ا"),
+    ("ٱ", "ا"),
+    ("ة", "ه"), // teh marbuta
+    ("ى", "ي"), // alef maksura
+];
+
+
+pub fn lang_arabic() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(SuffixStemmer::new(SUFFIXES, 3)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(first, last) in STRIPPED_RANGES {
+        for ch in first ..= last { lang.add_unicode_reduction(&ch.to_string(), ""); }
+    }
+
+    for prefix in PROCLITICS { lang.add_proclitic(prefix); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_arabic, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_arabic();
+        let w    = to_vec("كتابات");
+        assert_eq!(lang.stem(&w), 4);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_arabic();
+        let w1   = to_vec("كتاب");
+        let w2   = to_vec("الي");
+        let w3   = to_vec("ال");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Preposition));
+        assert_eq!(lang.get_pos(&w3), Some(PartOfSpeech::Article));
+    }
+
+    #[test]
+    pub fn proclitic() {
+        let lang = lang_arabic();
+        assert_eq!(lang.proclitic(&to_vec("الكتاب")),  Some(2));
+        assert_eq!(lang.proclitic(&to_vec("والكتاب")), Some(3));
+        assert_eq!(lang.proclitic(&to_vec("وزير")),    Some(1));
+        assert_eq!(lang.proclitic(&to_vec("ولد")),     None);
+        assert_eq!(lang.proclitic(&to_vec("كتاب")),    None);
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang = lang_arabic();
+
+        let source1 = to_vec("كتاب");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("أحمر");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "أحمر");
+        assert_eq!(norm2.len(), source2.len() - 1);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_arabic();
+
+        let source1 = to_vec("كتاب");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("إضاءة");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "اضاءه");
+        assert_eq!(norm2.len(), source2.len());
+
+        let source3 = to_vec("كِتَابُ");
+        let (padded3, norm3, marks3) = lang.unicode_reduce(&source3).unwrap();
+        assert_eq!(to_str(&padded3), "كتاب");
+        assert_eq!(to_str(&norm3), "كتاب");
+        assert_eq!(marks3, vec![(1, '\u{0650}'), (2, '\u{064E}'), (4, '\u{064F}')]);
+
+        let source4 = to_vec("كتـــاب");
+        let (_, norm4, marks4) = lang.unicode_reduce(&source4).unwrap();
+        assert_eq!(to_str(&norm4), "كتاب");
+        assert_eq!(marks4.len(), 3);
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_arabic();
+        // latin
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        // arabic
+        assert_eq!(lang.get_char_class('ا'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('ك'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
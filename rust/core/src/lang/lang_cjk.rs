@@ -0,0 +1,164 @@
+#![allow(dead_code)]
+
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+
+// Han and Kana, written without spaces between words.
+const BIGRAM_RANGES: &[(char, char)] = &[
+    ('\u{3040}',  '\u{309F}'),  // hiragana
+    ('\u{30A0}',  '\u{30FF}'),  // katakana
+    ('\u{31F0}',  '\u{31FF}'),  // katakana phonetic extensions
+    ('\u{3400}',  '\u{4DBF}'),  // cjk unified ideographs extension a
+    ('\u{4E00}',  '\u{9FFF}'),  // cjk unified ideographs
+    ('\u{F900}',  '\u{FAFF}'),  // cjk compatibility ideographs
+    ('\u{FF66}',  '\u{FF9F}'),  // halfwidth katakana
+    ('\u{20000}', '\u{2FA1F}'), // cjk unified ideographs extensions b-f, supplement
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("が", "が"), // hiragana, dakuten and handakuten
+    ("ぎ", "ぎ"),
+    ("ぐ", "ぐ"),
+    ("げ", "げ"),
+    ("ご", "ご"),
+    ("ざ", "ざ"),
+    ("じ", "じ"),
+    ("ず", "ず"),
+    ("ぜ", "ぜ"),
+    ("ぞ", "ぞ"),
+    ("だ", "だ"),
+    ("ぢ", "ぢ"),
+    ("づ", "づ"),
+    ("で", "で"),
+    ("ど", "ど"),
+    ("ば", "ば"),
+    ("ぱ", "ぱ"),
+    ("び", "び"),
+    ("ぴ", "ぴ"),
+    ("ぶ", "ぶ"),
+    ("ぷ", "ぷ"),
+    ("べ", "べ"),
+    ("ぺ", "ぺ"),
+    ("ぼ", "ぼ"),
+    ("ぽ", "ぽ"),
+    ("ゔ", "ゔ"),
+    ("ゞ", "ゞ"),
+    ("ガ", "ガ"), // katakana
+    ("ギ", "ギ"),
+    ("グ", "グ"),
+    ("ゲ", "ゲ"),
+    ("ゴ", "ゴ"),
+    ("ザ", "ザ"),
+    ("ジ", "ジ"),
+    ("ズ", "ズ"),
+    ("ゼ", "ゼ"),
+    ("ゾ", "ゾ"),
+    ("ダ", "ダ"),
+    ("ヂ", "ヂ"),
+    ("ヅ", "ヅ"),
+    ("デ", "デ"),
+    ("ド", "ド"),
+    ("バ", "バ"),
+    ("パ", "パ"),
+    ("ビ", "ビ"),
+    ("ピ", "ピ"),
+    ("ブ", "ブ"),
+    ("プ", "プ"),
+    ("ベ", "ベ"),
+    ("ペ", "ペ"),
+    ("ボ", "ボ"),
+    ("ポ", "ポ"),
+    ("ヴ", "ヴ"),
+    ("ヷ", "ヷ"),
+    ("ヸ", "ヸ"),
+    ("ヹ", "ヹ"),
+    ("ヺ", "ヺ"),
+    ("ヾ", "ヾ"),
+];
+
+
+// Fullwidth forms of ASCII, like "ｉＰｈｏｎｅ" or "２０２０".
+const FULLWIDTH_RANGE: (u32, u32) = (0xFF01, 0xFF5E);
+const FULLWIDTH_SHIFT: u32        = 0xFEE0;
+
+
+pub fn lang_cjk() -> Lang {
+    let mut lang = Lang::new();
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+
+    for code in FULLWIDTH_RANGE.0 ..= FULLWIDTH_RANGE.1 {
+        let from = std::char::from_u32(code).unwrap();
+        let to   = std::char::from_u32(code - FULLWIDTH_SHIFT).unwrap();
+        lang.add_unicode_reduction(&from.to_string(), &to.to_string());
+    }
+
+    for &(first, last) in BIGRAM_RANGES { lang.add_bigram_range(first, last); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use crate::lang::CharClass;
+    use super::{lang_cjk, UTF_COMPOSE_MAP};
+
+    #[test]
+    fn segment() {
+        let lang = lang_cjk();
+        assert_eq!(lang.segment(&to_vec("東京タワー")), Some(vec![(0, 2), (1, 3), (2, 4), (3, 5)]));
+        assert_eq!(lang.segment(&to_vec("北京")),       None);
+        assert_eq!(lang.segment(&to_vec("beijing")),    None);
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang = lang_cjk();
+
+        let source1 = to_vec("東京");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("ガイド");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "ガイド");
+        assert_eq!(norm2.len(), source2.len() - 2);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_cjk();
+
+        let source1 = to_vec("東京");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("ｉＰｈｏｎｅ１２");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "iPhone12");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_cjk();
+        assert_eq!(lang.get_char_class('a'),  Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'),  Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('東'), None);
+    }
+}
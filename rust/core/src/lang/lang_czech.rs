@@ -0,0 +1,226 @@
+#![allow(dead_code)]
+
+use super::{CharClass, PartOfSpeech, SuffixStemmer};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Consonant,
+};
+
+use PartOfSpeech::{
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Preposition, "bez"),
+    (Preposition, "do"),
+    (Preposition, "k"),
+    (Preposition, "ke"),
+    (Preposition, "mezi"),
+    (Preposition, "na"),
+    (Preposition, "nad"),
+    (Preposition, "o"),
+    (Preposition, "od"),
+    (Preposition, "po"),
+    (Preposition, "pod"),
+    (Preposition, "podle"),
+    (Preposition, "pro"),
+    (Preposition, "před"),
+    (Preposition, "přes"),
+    (Preposition, "při"),
+    (Preposition, "s"),
+    (Preposition, "se"),
+    (Preposition, "u"),
+    (Preposition, "v"),
+    (Preposition, "ve"),
+    (Preposition, "z"),
+    (Preposition, "za"),
+    (Preposition, "ze"),
+
+    (Conjunction, "a"),
+    (Conjunction, "ale"),
+    (Conjunction, "ani"),
+    (Conjunction, "i"),
+    (Conjunction, "když"),
+    (Conjunction, "nebo"),
+    (Conjunction, "pokud"),
+    (Conjunction, "protože"),
+    (Conjunction, "tak"),
+    (Conjunction, "však"),
+    (Conjunction, "že"),
+];
+
+
+// Reduced forms, stemming runs after diacritics are stripped.
+const SUFFIXES: &[&str] = &["ami", "emi", "ech", "ich", "ych", "ove", "ovi", "ho", "mu", "em", "om", "um", "ou", "ch", "a", "e", "i", "o", "u", "y"];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Consonant, 'ř'),
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("Á", "Á"), // diacritics
+    ("Č", "Č"),
+    ("Ď", "Ď"),
+    ("É", "É"),
+    ("Ě", "Ě"),
+    ("Í", "Í"),
+    ("Ň", "Ň"),
+    ("Ó", "Ó"),
+    ("Ř", "Ř"),
+    ("Š", "Š"),
+    ("Ť", "Ť"),
+    ("Ú", "Ú"),
+    ("Ů", "Ů"),
+    ("Ý", "Ý"),
+    ("Ž", "Ž"),
+    ("á", "á"),
+    ("č", "č"),
+    ("ď", "ď"),
+    ("é", "é"),
+    ("ě", "ě"),
+    ("í", "í"),
+    ("ň", "ň"),
+    ("ó", "ó"),
+    ("ř", "ř"),
+    ("š", "š"),
+    ("ť", "ť"),
+    ("ú", "ú"),
+    ("ů", "ů"),
+    ("ý", "ý"),
+    ("ž", "ž"),
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("Á", "A"), // diacritics
+    ("Č", "C"),
+    ("Ď", "D"),
+    ("É", "E"),
+    ("Ě", "E"),
+    ("Í", "I"),
+    ("Ň", "N"),
+    ("Ó", "O"),
+    ("Ř", "R"),
+    ("Š", "S"),
+    ("Ť", "T"),
+    ("Ú", "U"),
+    ("Ů", "U"),
+    ("Ý", "Y"),
+    ("Ž", "Z"),
+    ("á", "a"),
+    ("č", "c"),
+    ("ď", "d"),
+    ("é", "e"),
+    ("ě", "e"),
+    ("í", "i"),
+    ("ň", "n"),
+    ("ó", "o"),
+    ("ř", "r"),
+    ("š", "s"),
+    ("ť", "t"),
+    ("ú", "u"),
+    ("ů", "u"),
+    ("ý", "y"),
+    ("ž", "z"),
+];
+
+
+pub fn lang_czech() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(SuffixStemmer::new(SUFFIXES, 3)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_czech, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_czech();
+        let w    = to_vec("hradem");
+        assert_eq!(lang.stem(&w), 4);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_czech();
+        let w1   = to_vec("hradem");
+        let w2   = to_vec("přes");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Preposition));
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang    = lang_czech();
+
+        let source1 = to_vec("hradem");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("řeka");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "řeka");
+        assert_eq!(norm2.len(), source2.len() - 1);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_czech();
+
+        let source1 = to_vec("hradem");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("řeka");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "reka");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_czech();
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('ř'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
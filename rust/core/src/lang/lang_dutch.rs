@@ -0,0 +1,204 @@
+#![allow(dead_code)]
+
+use rust_stemmers::{Algorithm, Stemmer};
+use super::{CharClass, PartOfSpeech};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Vowel,
+};
+
+use PartOfSpeech::{
+    Article,
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Article, "de"),
+    (Article, "het"),
+    (Article, "een"),
+
+    (Preposition, "aan"),
+    (Preposition, "achter"),
+    (Preposition, "bij"),
+    (Preposition, "binnen"),
+    (Preposition, "boven"),
+    (Preposition, "buiten"),
+    (Preposition, "door"),
+    (Preposition, "in"),
+    (Preposition, "met"),
+    (Preposition, "na"),
+    (Preposition, "naar"),
+    (Preposition, "naast"),
+    (Preposition, "om"),
+    (Preposition, "onder"),
+    (Preposition, "op"),
+    (Preposition, "over"),
+    (Preposition, "per"),
+    (Preposition, "sinds"),
+    (Preposition, "te"),
+    (Preposition, "tegen"),
+    (Preposition, "tot"),
+    (Preposition, "tussen"),
+    (Preposition, "uit"),
+    (Preposition, "van"),
+    (Preposition, "voor"),
+    (Preposition, "zonder"),
+
+    (Conjunction, "als"),
+    (Conjunction, "dan"),
+    (Conjunction, "dat"),
+    (Conjunction, "doch"),
+    (Conjunction, "en"),
+    (Conjunction, "maar"),
+    (Conjunction, "noch"),
+    (Conjunction, "of"),
+    (Conjunction, "omdat"),
+    (Conjunction, "terwijl"),
+    (Conjunction, "want"),
+    (Conjunction, "wanneer"),
+    (Conjunction, "zodat"),
+];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Vowel, 'ĳ'),
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("É", "É"), // acute accent
+    ("Ó", "Ó"),
+    ("é", "é"),
+    ("ó", "ó"),
+    ("È", "È"), // grave accent
+    ("è", "è"),
+    ("Ë", "Ë"), // diaeresis
+    ("Ï", "Ï"),
+    ("Ö", "Ö"),
+    ("Ü", "Ü"),
+    ("ë", "ë"),
+    ("ï", "ï"),
+    ("ö", "ö"),
+    ("ü", "ü"),
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("Ĳ", "IJ"), // ij ligature
+    ("ĳ", "ij"),
+    ("É", "E"), // acute accent
+    ("Ó", "O"),
+    ("é", "e"),
+    ("ó", "o"),
+    ("È", "E"), // grave accent
+    ("è", "e"),
+    ("Ë", "E"), // diaeresis
+    ("Ï", "I"),
+    ("Ö", "O"),
+    ("Ü", "U"),
+    ("ë", "e"),
+    ("ï", "i"),
+    ("ö", "o"),
+    ("ü", "u"),
+];
+
+
+pub fn lang_dutch() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(Stemmer::create(Algorithm::Dutch)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_dutch, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_dutch();
+        let w    = to_vec("fietsen");
+        assert_eq!(lang.stem(&w), 5);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_dutch();
+        let w1   = to_vec("fietsen");
+        let w2   = to_vec("het");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Article));
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang    = lang_dutch();
+
+        let source1 = to_vec("fietsen");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("ideeën");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "ideeën");
+        assert_eq!(norm2.len(), source2.len() - 1);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_dutch();
+
+        let source1 = to_vec("fietsen");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("ideeën");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "ideeen");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            if normal == "Ĳ" { continue; }
+            if normal == "ĳ" { continue; }
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_dutch();
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('ĳ'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
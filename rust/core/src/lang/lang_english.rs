@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 
 use rust_stemmers::{Algorithm, Stemmer};
-use super::{CharClass, PartOfSpeech};
+use super::{CharClass, PartOfSpeech, Phonetic};
 use super::Lang;
 use super::constants::CHAR_CLASSES_LATIN;
 
@@ -137,6 +137,7 @@ pub fn lang_english() -> Lang {
     let mut lang = Lang::new();
 
     lang.set_stemmer(Some(Stemmer::create(Algorithm::English)));
+    lang.set_phonetic(Some(Phonetic::Metaphone));
 
     for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
     for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
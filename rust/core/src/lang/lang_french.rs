@@ -364,13 +364,13 @@ mod tests {
         assert_eq!(norm1, None);
 
         let source2 = to_vec("château");
-        let (padded2, norm2) = lang.unicode_reduce(&source2).unwrap();
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
         assert_eq!(to_str(&padded2), to_str(&source2));
         assert_eq!(to_str(&norm2), "chateau");
         assert_eq!(norm2.len(), source2.len());
 
         let source3 = to_vec("sœur");
-        let (padded3, norm3) = lang.unicode_reduce(&source3).unwrap();
+        let (padded3, norm3, _) = lang.unicode_reduce(&source3).unwrap();
         assert_eq!(to_str(&padded3), "sœ\0ur");
         assert_eq!(to_str(&norm3), "soeur");
         assert_eq!(norm3.len(), source3.len() + 1);
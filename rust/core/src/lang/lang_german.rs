@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 
 use rust_stemmers::{Algorithm, Stemmer};
-use super::{CharClass, PartOfSpeech};
+use super::{CharClass, PartOfSpeech, Phonetic};
 use super::Lang;
 use super::constants::CHAR_CLASSES_LATIN;
 
@@ -113,6 +113,27 @@ const CHAR_CLASSES: &[(CharClass, char)] = &[
 ];
 
 
+const COMPOUND_PARTS: &[&str] = &[
+    "arbeit", "auto", "bad", "bahn", "bank", "batterie", "bett", "bier", "boden",
+    "brief", "brot", "buch", "computer", "dach", "decke", "eisen", "ende", "fahr",
+    "farbe", "fenster", "filter", "flasche", "fleisch", "garten", "geld", "gemüse",
+    "gerät", "glas", "hand", "haus", "hemd", "herd", "hof", "holz", "hose", "hund",
+    "hörer", "hütte", "jacke", "jahr", "kabel", "kaffee", "kanne", "karte", "kasten",
+    "kind", "kissen", "koffer", "kopf", "kühl", "küche", "lade", "laden", "lampe",
+    "land", "leder", "licht", "löffel", "mantel", "markt", "maschine", "messer",
+    "metall", "milch", "mittel", "motor", "nacht", "netz", "obst", "ofen", "papier",
+    "pfanne", "platz", "post", "rad", "regal", "reise", "saft", "sauger", "schlaf",
+    "schlüssel", "schrank", "schuh", "sommer", "spiegel", "spiel", "sport", "spül",
+    "stadt", "stahl", "staub", "stecker", "strom", "stuhl", "tag", "tasche", "tee",
+    "teil", "telefon", "teller", "tisch", "topf", "tür", "uhr", "wagen", "wand",
+    "wasch", "wasser", "woche", "wohn", "werk", "winter", "wurst", "zeit", "zeug",
+    "zimmer",
+];
+
+
+const COMPOUND_LINKS: &[&str] = &["s", "es", "n", "en", "e", "er"];
+
+
 const UTF_COMPOSE_MAP: &[(&'static str, &'static str)] = &[
     ("Ä", "Ä"),
     ("Ö", "Ö"),
@@ -138,12 +159,16 @@ pub fn lang_german() -> Lang {
     let mut lang = Lang::new();
 
     lang.set_stemmer(Some(Stemmer::create(Algorithm::German)));
+    lang.set_phonetic(Some(Phonetic::Cologne));
 
     for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
     for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
 
     for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
 
+    for part in COMPOUND_PARTS { lang.add_compound_part(part); }
+    for link in COMPOUND_LINKS { lang.add_compound_link(link); }
+
     for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
     for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
 
@@ -196,7 +221,7 @@ mod tests {
         assert_eq!(norm1, None);
 
         let source2 = to_vec("mädchen");
-        let (padded2, norm2) = lang.unicode_reduce(&source2).unwrap();
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
         assert_eq!(to_str(&padded2), to_str(&source2));
         assert_eq!(to_str(&norm2), "madchen");
         assert_eq!(norm2.len(), source2.len());
@@ -0,0 +1,254 @@
+#![allow(dead_code)]
+
+use rust_stemmers::{Algorithm, Stemmer};
+use super::{CharClass, PartOfSpeech};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Consonant,
+    Vowel,
+};
+
+use PartOfSpeech::{
+    Article,
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Article, "ένα"),
+    (Article, "ένας"),
+    (Article, "η"),
+    (Article, "μια"),
+    (Article, "μιας"),
+    (Article, "ο"),
+    (Article, "οι"),
+    (Article, "του"),
+    (Article, "των"),
+    (Article, "το"),
+    (Article, "τα"),
+    (Article, "τη"),
+    (Article, "την"),
+    (Article, "τον"),
+    (Article, "της"),
+    (Article, "τις"),
+    (Article, "τους"),
+
+    (Preposition, "από"),
+    (Preposition, "για"),
+    (Preposition, "με"),
+    (Preposition, "μετά"),
+    (Preposition, "παρά"),
+    (Preposition, "προς"),
+    (Preposition, "σε"),
+    (Preposition, "στα"),
+    (Preposition, "στη"),
+    (Preposition, "στην"),
+    (Preposition, "στο"),
+    (Preposition, "στον"),
+    (Preposition, "στις"),
+    (Preposition, "στους"),
+    (Preposition, "χωρίς"),
+    (Preposition, "ως"),
+
+    (Conjunction, "αλλά"),
+    (Conjunction, "αν"),
+    (Conjunction, "ή"),
+    (Conjunction, "και"),
+    (Conjunction, "να"),
+    (Conjunction, "όμως"),
+    (Conjunction, "ότι"),
+    (Conjunction, "ούτε"),
+    (Conjunction, "πως"),
+    (Conjunction, "ενώ"),
+    (Conjunction, "επειδή"),
+];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Consonant, 'β'),
+    (Consonant, 'γ'),
+    (Consonant, 'δ'),
+    (Consonant, 'ζ'),
+    (Consonant, 'θ'),
+    (Consonant, 'κ'),
+    (Consonant, 'λ'),
+    (Consonant, 'μ'),
+    (Consonant, 'ν'),
+    (Consonant, 'ξ'),
+    (Consonant, 'π'),
+    (Consonant, 'ρ'),
+    (Consonant, 'σ'),
+    (Consonant, 'ς'),
+    (Consonant, 'τ'),
+    (Consonant, 'φ'),
+    (Consonant, 'χ'),
+    (Consonant, 'ψ'),
+    (Vowel, 'α'),
+    (Vowel, 'ε'),
+    (Vowel, 'η'),
+    (Vowel, 'ι'),
+    (Vowel, 'ο'),
+    (Vowel, 'υ'),
+    (Vowel, 'ω'),
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("Ά", "Ά"), // tonos, dialytika
+    ("Έ", "Έ"),
+    ("Ή", "Ή"),
+    ("Ί", "Ί"),
+    ("Ό", "Ό"),
+    ("Ύ", "Ύ"),
+    ("Ώ", "Ώ"),
+    ("Ϊ", "Ϊ"),
+    ("Ϋ", "Ϋ"),
+    ("ά", "ά"),
+    ("έ", "έ"),
+    ("ή", "ή"),
+    ("ί", "ί"),
+    ("ό", "ό"),
+    ("ύ", "ύ"),
+    ("ώ", "ώ"),
+    ("ϊ", "ϊ"),
+    ("ϋ", "ϋ"),
+];
+
+
+// Accents are folded and final sigma is unified with the medial one,
+// so that "λόγος" matches "λογοσ" and prefix "λογο".
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("Ά", "Α"), // tonos, dialytika
+    ("Έ", "Ε"),
+    ("Ή", "Η"),
+    ("Ί", "Ι"),
+    ("Ό", "Ο"),
+    ("Ύ", "Υ"),
+    ("Ώ", "Ω"),
+    ("Ϊ", "Ι"),
+    ("Ϋ", "Υ"),
+    ("ά", "α"),
+    ("έ", "ε"),
+    ("ή", "η"),
+    ("ί", "ι"),
+    ("ό", "ο"),
+    ("ύ", "υ"),
+    ("ώ", "ω"),
+    ("ϊ", "ι"),
+    ("ϋ", "υ"),
+    ("ΐ", "ι"),
+    ("ΰ", "υ"),
+    ("ς", "σ"), // final sigma
+];
+
+
+pub fn lang_greek() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(Stemmer::create(Algorithm::Greek)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_greek, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_greek();
+        let w    = to_vec("δρομοι");
+        assert_eq!(lang.stem(&w), 4);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_greek();
+        let w1   = to_vec("δρομοι");
+        let w2   = to_vec("και");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Conjunction));
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang = lang_greek();
+
+        let source1 = to_vec("δρομοι");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("δρόμος");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "δρόμος");
+        assert_eq!(norm2.len(), source2.len() - 1);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_greek();
+
+        let source1 = to_vec("δρομοι");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("Αθήνα");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "Αθηνα");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_reduce_final_sigma() {
+        let lang = lang_greek();
+
+        let source = to_vec("δρόμος");
+        let (padded, norm, _) = lang.unicode_reduce(&source).unwrap();
+        assert_eq!(to_str(&padded), to_str(&source));
+        assert_eq!(to_str(&norm), "δρομοσ");
+        assert_eq!(norm.len(), source.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_greek();
+        // latin
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        // greek
+        assert_eq!(lang.get_char_class('ω'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('ψ'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
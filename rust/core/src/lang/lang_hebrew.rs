@@ -0,0 +1,160 @@
+#![allow(dead_code)]
+
+use super::{CharClass, PartOfSpeech};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Consonant,
+    Vowel,
+};
+
+use PartOfSpeech::{
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Preposition, "של"),
+    (Preposition, "עם"),
+    (Preposition, "על"),
+    (Preposition, "את"),
+    (Preposition, "אל"),
+    (Preposition, "מן"),
+    (Preposition, "בין"),
+    (Preposition, "אחרי"),
+    (Preposition, "לפני"),
+
+    (Conjunction, "או"),
+    (Conjunction, "אבל"),
+    (Conjunction, "כי"),
+    (Conjunction, "אם"),
+    (Conjunction, "גם"),
+];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Consonant, 'ב'),
+    (Consonant, 'ג'),
+    (Consonant, 'ד'),
+    (Consonant, 'ה'),
+    (Consonant, 'ז'),
+    (Consonant, 'ח'),
+    (Consonant, 'ט'),
+    (Consonant, 'כ'),
+    (Consonant, 'ך'),
+    (Consonant, 'ל'),
+    (Consonant, 'מ'),
+    (Consonant, 'ם'),
+    (Consonant, 'נ'),
+    (Consonant, 'ן'),
+    (Consonant, 'ס'),
+    (Consonant, 'ע'),
+    (Consonant, 'פ'),
+    (Consonant, 'ף'),
+    (Consonant, 'צ'),
+    (Consonant, 'ץ'),
+    (Consonant, 'ק'),
+    (Consonant, 'ר'),
+    (Consonant, 'ש'),
+    (Consonant, 'ת'),
+    (Vowel, 'א'),
+    (Vowel, 'ו'),
+    (Vowel, 'י'),
+];
+
+
+// Niqqud and cantillation are folded away for matching, results keep them.
+const STRIPPED_RANGES: &[(char, char)] = &[
+    ('\u{0591}', '\u{05BD}'),
+    ('\u{05BF}', '\u{05BF}'),
+    ('\u{05C1}', '\u{05C2}'),
+    ('\u{05C4}', '\u{05C5}'),
+    ('\u{05C7}', '\u{05C7}'),
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("ך", "כ"), // final forms
+    ("ם", "מ"),
+    ("ן", "נ"),
+    ("ף", "פ"),
+    ("ץ", "צ"),
+];
+
+
+pub fn lang_hebrew() -> Lang {
+    let mut lang = Lang::new();
+
+    for (from, to) in UTF_REDUCE_MAP { lang.add_unicode_reduction(from, to); }
+
+    for &(first, last) in STRIPPED_RANGES {
+        for ch in first ..= last { lang.add_unicode_reduction(&ch.to_string(), ""); }
+    }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_hebrew, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_hebrew();
+        let w1   = to_vec("ספר");
+        let w2   = to_vec("של");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Preposition));
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_hebrew();
+
+        let source1 = to_vec("ספר");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("שלום");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "שלומ");
+        assert_eq!(norm2.len(), source2.len());
+
+        let source3 = to_vec("שָׁלוֹם");
+        let (padded3, norm3, marks3) = lang.unicode_reduce(&source3).unwrap();
+        assert_eq!(to_str(&padded3), "שלום");
+        assert_eq!(to_str(&norm3), "שלומ");
+        assert_eq!(marks3, vec![(1, '\u{05B8}'), (1, '\u{05C1}'), (3, '\u{05B9}')]);
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_hebrew();
+        // latin
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        // hebrew
+        assert_eq!(lang.get_char_class('ו'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('ש'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
@@ -0,0 +1,247 @@
+#![allow(dead_code)]
+
+use rust_stemmers::{Algorithm, Stemmer};
+use super::{CharClass, PartOfSpeech};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use PartOfSpeech::{
+    Article,
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Article, "il"),
+    (Article, "lo"),
+    (Article, "la"),
+    (Article, "i"),
+    (Article, "gli"),
+    (Article, "le"),
+    (Article, "l"),
+    (Article, "un"),
+    (Article, "uno"),
+    (Article, "una"),
+
+    (Preposition, "a"),
+    (Preposition, "ad"),
+    (Preposition, "al"),
+    (Preposition, "all"),
+    (Preposition, "alla"),
+    (Preposition, "alle"),
+    (Preposition, "allo"),
+    (Preposition, "ai"),
+    (Preposition, "agli"),
+    (Preposition, "con"),
+    (Preposition, "contro"),
+    (Preposition, "da"),
+    (Preposition, "dal"),
+    (Preposition, "dall"),
+    (Preposition, "dalla"),
+    (Preposition, "dalle"),
+    (Preposition, "dallo"),
+    (Preposition, "dai"),
+    (Preposition, "dagli"),
+    (Preposition, "d"),
+    (Preposition, "di"),
+    (Preposition, "del"),
+    (Preposition, "dell"),
+    (Preposition, "della"),
+    (Preposition, "delle"),
+    (Preposition, "dello"),
+    (Preposition, "dei"),
+    (Preposition, "degli"),
+    (Preposition, "dentro"),
+    (Preposition, "dopo"),
+    (Preposition, "fra"),
+    (Preposition, "fuori"),
+    (Preposition, "in"),
+    (Preposition, "nel"),
+    (Preposition, "nell"),
+    (Preposition, "nella"),
+    (Preposition, "nelle"),
+    (Preposition, "nello"),
+    (Preposition, "nei"),
+    (Preposition, "negli"),
+    (Preposition, "per"),
+    (Preposition, "prima"),
+    (Preposition, "senza"),
+    (Preposition, "sopra"),
+    (Preposition, "sotto"),
+    (Preposition, "su"),
+    (Preposition, "sul"),
+    (Preposition, "sull"),
+    (Preposition, "sulla"),
+    (Preposition, "sulle"),
+    (Preposition, "sullo"),
+    (Preposition, "sui"),
+    (Preposition, "sugli"),
+    (Preposition, "tra"),
+    (Preposition, "verso"),
+
+    (Conjunction, "anche"),
+    (Conjunction, "come"),
+    (Conjunction, "dunque"),
+    (Conjunction, "e"),
+    (Conjunction, "ed"),
+    (Conjunction, "ma"),
+    (Conjunction, "mentre"),
+    (Conjunction, "né"),
+    (Conjunction, "o"),
+    (Conjunction, "od"),
+    (Conjunction, "oppure"),
+    (Conjunction, "perché"),
+    (Conjunction, "però"),
+    (Conjunction, "quando"),
+    (Conjunction, "quindi"),
+    (Conjunction, "se"),
+];
+
+
+// Elided article or preposition, written with an apostrophe: "l'acqua", "dell'olio", "un'auto".
+const ELISIONS: &[&str] = &["l", "un", "d", "all", "dall", "dell", "nell", "sull", "quell", "bell"];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("À", "À"), // grave accent
+    ("È", "È"),
+    ("Ì", "Ì"),
+    ("Ò", "Ò"),
+    ("Ù", "Ù"),
+    ("à", "à"),
+    ("è", "è"),
+    ("ì", "ì"),
+    ("ò", "ò"),
+    ("ù", "ù"),
+    ("É", "É"), // acute accent
+    ("é", "é"),
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("À", "A"), // grave accent
+    ("È", "E"),
+    ("Ì", "I"),
+    ("Ò", "O"),
+    ("Ù", "U"),
+    ("à", "a"),
+    ("è", "e"),
+    ("ì", "i"),
+    ("ò", "o"),
+    ("ù", "u"),
+    ("É", "E"), // acute accent
+    ("é", "e"),
+];
+
+
+pub fn lang_italian() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(Stemmer::create(Algorithm::Italian)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for elision in ELISIONS { lang.add_elision(elision); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_italian, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_italian();
+        let w = to_vec("spazzolino");
+        assert_eq!(lang.stem(&w), 9);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_italian();
+        let w1 = to_vec("spazzolino");
+        let w2 = to_vec("gli");
+        let w3 = to_vec("perche");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Article));
+        assert_eq!(lang.get_pos(&w3), Some(PartOfSpeech::Conjunction));
+    }
+
+    #[test]
+    pub fn elision() {
+        let lang = lang_italian();
+        assert_eq!(lang.elision(&to_vec("l'acqua")),    Some(1));
+        assert_eq!(lang.elision(&to_vec("dell’olio")),  Some(4));
+        assert_eq!(lang.elision(&to_vec("un'auto")),    Some(2));
+        assert_eq!(lang.elision(&to_vec("po'")),        None);
+        assert_eq!(lang.elision(&to_vec("l'")),         None);
+        assert_eq!(lang.elision(&to_vec("acqua")),      None);
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang = lang_italian();
+
+        let source1 = to_vec("spazzolino");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("caffè");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "caffè");
+        assert_eq!(norm2.len(), source2.len() - 1);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_italian();
+
+        let source1 = to_vec("spazzolino");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("caffè");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "caffe");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_italian();
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
@@ -0,0 +1,180 @@
+#![allow(dead_code)]
+
+use rust_stemmers::{Algorithm, Stemmer};
+use super::{CharClass, PartOfSpeech};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Vowel,
+};
+
+use PartOfSpeech::{
+    Article,
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Article, "en"),
+    (Article, "ei"),
+    (Article, "et"),
+    (Article, "den"),
+    (Article, "det"),
+    (Article, "de"),
+
+    (Preposition, "av"),
+    (Preposition, "bak"),
+    (Preposition, "blant"),
+    (Preposition, "etter"),
+    (Preposition, "for"),
+    (Preposition, "foran"),
+    (Preposition, "fra"),
+    (Preposition, "gjennom"),
+    (Preposition, "hos"),
+    (Preposition, "i"),
+    (Preposition, "med"),
+    (Preposition, "mellom"),
+    (Preposition, "mot"),
+    (Preposition, "om"),
+    (Preposition, "over"),
+    (Preposition, "på"),
+    (Preposition, "rundt"),
+    (Preposition, "til"),
+    (Preposition, "under"),
+    (Preposition, "uten"),
+    (Preposition, "ved"),
+
+    (Conjunction, "at"),
+    (Conjunction, "eller"),
+    (Conjunction, "enn"),
+    (Conjunction, "fordi"),
+    (Conjunction, "hvis"),
+    (Conjunction, "men"),
+    (Conjunction, "mens"),
+    (Conjunction, "når"),
+    (Conjunction, "og"),
+    (Conjunction, "som"),
+    (Conjunction, "så"),
+];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Vowel, 'å'),
+    (Vowel, 'æ'),
+    (Vowel, 'ø'),
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("Å", "Å"), // ring
+    ("å", "å"),
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("Å", "A"), // ring
+    ("å", "a"),
+    ("Ø", "O"), // stroke
+    ("ø", "o"),
+    ("Æ", "AE"), // ligature
+    ("æ", "ae"),
+];
+
+
+pub fn lang_norwegian() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(Stemmer::create(Algorithm::Norwegian)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_norwegian, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_norwegian();
+        let w    = to_vec("hestene");
+        assert_eq!(lang.stem(&w), 4);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_norwegian();
+        let w1   = to_vec("hestene");
+        let w2   = to_vec("ei");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Article));
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang    = lang_norwegian();
+
+        let source1 = to_vec("hestene");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("blå");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "blå");
+        assert_eq!(norm2.len(), source2.len() - 1);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_norwegian();
+
+        let source1 = to_vec("hestene");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("blå");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "bla");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            if normal == "Æ" { continue; }
+            if normal == "æ" { continue; }
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_norwegian();
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('å'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
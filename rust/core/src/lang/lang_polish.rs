@@ -0,0 +1,201 @@
+#![allow(dead_code)]
+
+use super::{CharClass, PartOfSpeech, SuffixStemmer};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Consonant,
+};
+
+use PartOfSpeech::{
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Preposition, "bez"),
+    (Preposition, "dla"),
+    (Preposition, "do"),
+    (Preposition, "ku"),
+    (Preposition, "między"),
+    (Preposition, "na"),
+    (Preposition, "nad"),
+    (Preposition, "o"),
+    (Preposition, "obok"),
+    (Preposition, "od"),
+    (Preposition, "po"),
+    (Preposition, "pod"),
+    (Preposition, "przed"),
+    (Preposition, "przez"),
+    (Preposition, "przy"),
+    (Preposition, "u"),
+    (Preposition, "w"),
+    (Preposition, "we"),
+    (Preposition, "według"),
+    (Preposition, "z"),
+    (Preposition, "za"),
+    (Preposition, "ze"),
+
+    (Conjunction, "a"),
+    (Conjunction, "albo"),
+    (Conjunction, "ale"),
+    (Conjunction, "ani"),
+    (Conjunction, "bo"),
+    (Conjunction, "czy"),
+    (Conjunction, "gdy"),
+    (Conjunction, "i"),
+    (Conjunction, "jednak"),
+    (Conjunction, "jeśli"),
+    (Conjunction, "lub"),
+    (Conjunction, "oraz"),
+    (Conjunction, "więc"),
+    (Conjunction, "że"),
+];
+
+
+// Reduced forms, stemming runs after diacritics are stripped.
+const SUFFIXES: &[&str] = &["owie", "ami", "ach", "ych", "ich", "ymi", "imi", "owi", "ego", "emu", "iej", "ow", "om", "em", "am", "ie", "a", "e", "i", "o", "u", "y"];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Consonant, 'ł'),
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("Ą", "Ą"), // diacritics
+    ("Ć", "Ć"),
+    ("Ę", "Ę"),
+    ("Ń", "Ń"),
+    ("Ó", "Ó"),
+    ("Ś", "Ś"),
+    ("Ź", "Ź"),
+    ("Ż", "Ż"),
+    ("ą", "ą"),
+    ("ć", "ć"),
+    ("ę", "ę"),
+    ("ń", "ń"),
+    ("ó", "ó"),
+    ("ś", "ś"),
+    ("ź", "ź"),
+    ("ż", "ż"),
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("Ą", "A"), // diacritics
+    ("Ć", "C"),
+    ("Ę", "E"),
+    ("Ń", "N"),
+    ("Ó", "O"),
+    ("Ś", "S"),
+    ("Ź", "Z"),
+    ("Ż", "Z"),
+    ("ą", "a"),
+    ("ć", "c"),
+    ("ę", "e"),
+    ("ń", "n"),
+    ("ó", "o"),
+    ("ś", "s"),
+    ("ź", "z"),
+    ("ż", "z"),
+    ("Ł", "L"), // stroke
+    ("ł", "l"),
+];
+
+
+pub fn lang_polish() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(SuffixStemmer::new(SUFFIXES, 3)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_polish, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_polish();
+        let w    = to_vec("kotami");
+        assert_eq!(lang.stem(&w), 3);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_polish();
+        let w1   = to_vec("kotami");
+        let w2   = to_vec("przez");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Preposition));
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang    = lang_polish();
+
+        let source1 = to_vec("kotami");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("żaba");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "żaba");
+        assert_eq!(norm2.len(), source2.len() - 1);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_polish();
+
+        let source1 = to_vec("kotami");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("żaba");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "zaba");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_polish();
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('ł'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
@@ -267,7 +267,7 @@ mod tests {
         assert_eq!(norm1, None);
 
         let source2 = to_vec("Conceição");
-        let (padded2, norm2) = lang.unicode_reduce(&source2).unwrap();
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
         assert_eq!(to_str(&padded2), to_str(&source2));
         assert_eq!(to_str(&norm2), "Conceicao");
         assert_eq!(norm2.len(), source2.len());
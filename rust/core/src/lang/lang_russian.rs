@@ -279,6 +279,53 @@ const UTF_REDUCE_MAP: &[(&'static str, &'static str)] = &[
 ];
 
 
+// Romanization as brand names are usually spelled, "х" is "h" like in "хонда" for "Honda".
+// Signs become apostrophes, since romanization can't be shorter than the source.
+const TRANSLIT: &[(char, &str)] = &[
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('е', "e"),
+    ('ж', "zh"),
+    ('з', "z"),
+    ('и', "i"),
+    ('й', "y"),
+    ('к', "k"),
+    ('л', "l"),
+    ('м', "m"),
+    ('н', "n"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "h"),
+    ('ц', "ts"),
+    ('ч', "ch"),
+    ('ш', "sh"),
+    ('щ', "shch"),
+    ('ъ', ""),
+    ('ы', "y"),
+    ('ь', ""),
+    ('э', "e"),
+    ('ю', "yu"),
+    ('я', "ya"),
+];
+
+
+// Shifted keys go first, so that unshifted ones take precedence when remapping back.
+const LAYOUT_PAIRS: &[(&str, &str)] = &[
+    ("~{}:\"<>",     "ёхъжэбю"),
+    ("`qwertyuiop[]", "ёйцукенгшщзхъ"),
+    ("asdfghjkl;'",   "фывапролджэ"),
+    ("zxcvbnm,.",     "ячсмитьбю"),
+];
+
+
 pub fn lang_russian() -> Lang {
     let mut lang = Lang::new();
 
@@ -287,6 +334,10 @@ pub fn lang_russian() -> Lang {
     for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
     for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
 
+    for (en, ru) in LAYOUT_PAIRS { lang.add_layout_pair(en, ru); }
+
+    for &(from, to) in TRANSLIT { lang.add_transliteration(from, to); }
+
     for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
 
     for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
@@ -300,7 +351,7 @@ pub fn lang_russian() -> Lang {
 mod tests {
     use crate::utils::{to_vec, to_str};
     use super::{PartOfSpeech, CharClass};
-    use super::{lang_russian, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+    use super::{lang_russian, UTF_COMPOSE_MAP, UTF_REDUCE_MAP, LAYOUT_PAIRS, TRANSLIT};
 
     #[test]
     pub fn stem() {
@@ -341,7 +392,7 @@ mod tests {
         assert_eq!(norm1, None);
 
         let source2 = to_vec("Ёлка");
-        let (padded2, norm2) = lang.unicode_reduce(&source2).unwrap();
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
         assert_eq!(to_str(&padded2), to_str(&source2));
         assert_eq!(to_str(&norm2), "Елка");
         assert_eq!(norm2.len(), source2.len());
@@ -355,6 +406,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn remap_layout() {
+        let lang = lang_russian();
+        assert_eq!(lang.remap_layout("vfufpby"),         Some("магазин".to_string()));
+        assert_eq!(lang.remap_layout("Vfufpby buheirb"), Some("магазин игрушки".to_string()));
+        assert_eq!(lang.remap_layout("ctynz,hm"),        Some("сентябрь".to_string()));
+        assert_eq!(lang.remap_layout("{jkjl"),           Some("холод".to_string()));
+        assert_eq!(lang.remap_layout("ьфпфяшту"),        Some("magazine".to_string()));
+        assert_eq!(lang.remap_layout("ёлка"),            Some("`krf".to_string()));
+        assert_eq!(lang.remap_layout("123 456"),         None);
+    }
+
+    #[test]
+    fn transliterate() {
+        let lang   = lang_russian();
+        let source = to_vec("щётка самсунг");
+        let chars  = lang.unicode_reduce(&source).unwrap().1;
+        let (padded, romanized, marks) = lang.transliterate(&source, &chars, &[]).unwrap();
+        assert_eq!(to_str(&romanized), "shchetka samsung");
+        assert_eq!(padded.len(), romanized.len());
+        assert_eq!(marks, vec![]);
+    }
+
+    #[test]
+    fn transliterate_signs() {
+        let lang   = lang_russian();
+        let source = to_vec("подъезд тень");
+        let (padded, romanized, marks) = lang.transliterate(&source, &source, &[]).unwrap();
+        assert_eq!(to_str(&romanized), "podezd ten");
+        assert_eq!(padded, to_vec("подезд тен"));
+        assert_eq!(marks, vec![(3, 'ъ'), (10, 'ь')]);
+    }
+
+    #[test]
+    fn translit_dimensions() {
+        for &(from, to) in TRANSLIT {
+            assert!(to.chars().all(|ch| ch.is_ascii_lowercase()), "TRANSLIT['{}'] is not romanized", from);
+        }
+    }
+
+    #[test]
+    fn layout_pairs_dimensions() {
+        for &(en, ru) in LAYOUT_PAIRS {
+            assert_eq!(en.chars().count(), ru.chars().count(), "LAYOUT_PAIRS['{}'] != LAYOUT_PAIRS['{}']", en, ru);
+        }
+    }
+
     #[test]
     fn unicode_reduce_map_dimenstions() {
         for &(normal, reduced) in UTF_REDUCE_MAP {
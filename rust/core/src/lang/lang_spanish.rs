@@ -220,7 +220,7 @@ mod tests {
         assert_eq!(norm1, None);
 
         let source2 = to_vec("piñata");
-        let (padded2, norm2) = lang.unicode_reduce(&source2).unwrap();
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
         assert_eq!(to_str(&padded2), to_str(&source2));
         assert_eq!(to_str(&norm2), "pinata");
         assert_eq!(norm2.len(), source2.len());
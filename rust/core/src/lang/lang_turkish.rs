@@ -0,0 +1,209 @@
+#![allow(dead_code)]
+
+use rust_stemmers::{Algorithm, Stemmer};
+use super::{CharClass, PartOfSpeech};
+use super::Lang;
+use super::constants::CHAR_CLASSES_LATIN;
+
+use CharClass::{
+    Vowel,
+};
+
+use PartOfSpeech::{
+    Article,
+    Preposition,
+    Conjunction,
+};
+
+
+const FUNCTION_WORDS: &[(PartOfSpeech, &str)] = &[
+    (Article, "bir"),
+
+    (Preposition, "beri"),
+    (Preposition, "doğru"),
+    (Preposition, "gibi"),
+    (Preposition, "göre"),
+    (Preposition, "ile"),
+    (Preposition, "için"),
+    (Preposition, "kadar"),
+    (Preposition, "karşı"),
+    (Preposition, "önce"),
+    (Preposition, "sonra"),
+
+    (Conjunction, "ama"),
+    (Conjunction, "ancak"),
+    (Conjunction, "çünkü"),
+    (Conjunction, "da"),
+    (Conjunction, "de"),
+    (Conjunction, "fakat"),
+    (Conjunction, "hem"),
+    (Conjunction, "ise"),
+    (Conjunction, "ki"),
+    (Conjunction, "ne"),
+    (Conjunction, "ve"),
+    (Conjunction, "veya"),
+    (Conjunction, "ya"),
+    (Conjunction, "yoksa"),
+];
+
+
+const CHAR_CLASSES: &[(CharClass, char)] = &[
+    (Vowel, 'ı'),
+];
+
+
+// Dotted and dotless i are distinct letters, generic lowercasing gets them wrong.
+const LOWERCASE_MAP: &[(char, char)] = &[
+    ('I', 'ı'),
+    ('İ', 'i'),
+];
+
+
+const UTF_COMPOSE_MAP: &[(&str, &str)] = &[
+    ("Ç", "Ç"), // cedilla
+    ("Ş", "Ş"),
+    ("ç", "ç"),
+    ("ş", "ş"),
+    ("Ğ", "Ğ"), // breve
+    ("ğ", "ğ"),
+    ("Ö", "Ö"), // diaeresis
+    ("Ü", "Ü"),
+    ("ö", "ö"),
+    ("ü", "ü"),
+    ("İ", "İ"), // dot above
+];
+
+
+const UTF_REDUCE_MAP: &[(&str, &str)] = &[
+    ("Ç", "C"), // cedilla
+    ("Ş", "S"),
+    ("ç", "c"),
+    ("ş", "s"),
+    ("Ğ", "G"), // breve
+    ("ğ", "g"),
+    ("Ö", "O"), // diaeresis
+    ("Ü", "U"),
+    ("ö", "o"),
+    ("ü", "u"),
+    ("ı", "i"), // dotless i
+];
+
+
+pub fn lang_turkish() -> Lang {
+    let mut lang = Lang::new();
+
+    lang.set_stemmer(Some(Stemmer::create(Algorithm::Turkish)));
+
+    for (from, to) in UTF_COMPOSE_MAP { lang.add_unicode_composition(from, to); }
+    for (from, to) in UTF_REDUCE_MAP  { lang.add_unicode_reduction(from, to); }
+
+    for &(upper, lower) in LOWERCASE_MAP { lang.add_lowercase(upper, lower); }
+
+    for &(pos, word) in FUNCTION_WORDS { lang.add_pos(word, pos); }
+
+    for &(class, ch) in CHAR_CLASSES_LATIN { lang.add_char_class(ch, class); }
+    for &(class, ch) in CHAR_CLASSES       { lang.add_char_class(ch, class); }
+
+    lang
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{to_vec, to_str};
+    use crate::tokenization::tokenize_record;
+    use super::{PartOfSpeech, CharClass};
+    use super::{lang_turkish, UTF_COMPOSE_MAP, UTF_REDUCE_MAP};
+
+    #[test]
+    pub fn stem() {
+        let lang = lang_turkish();
+        let w    = to_vec("kitaplar");
+        assert_eq!(lang.stem(&w), 5);
+    }
+
+    #[test]
+    pub fn get_pos() {
+        let lang = lang_turkish();
+        let w1   = to_vec("kitaplar");
+        let w2   = to_vec("bir");
+        let w3   = to_vec("icin");
+        assert_eq!(lang.get_pos(&w1), None);
+        assert_eq!(lang.get_pos(&w2), Some(PartOfSpeech::Article));
+        assert_eq!(lang.get_pos(&w3), Some(PartOfSpeech::Preposition));
+    }
+
+    #[test]
+    pub fn lower() {
+        let lang = lang_turkish();
+        assert_eq!(lang.lower('I'), 'i');
+        assert_eq!(lang.lower('İ'), 'i');
+        assert_eq!(lang.lower('Ş'), 's');
+        assert_eq!(lang.lower('A'), 'a');
+    }
+
+    #[test]
+    pub fn lower_dotted_dotless() {
+        let lang = lang_turkish();
+        let samples = ["ISTANBUL", "İSTANBUL", "İstanbul", "istanbul", "ıstanbul", "Istanbul"];
+        for sample in samples.iter() {
+            let text = tokenize_record(sample, &lang);
+            assert_eq!(to_str(&text.chars), "istanbul", "Failed lowercase \"{}\"", sample);
+            assert_eq!(to_str(&text.source).replace('\0', ""), *sample);
+        }
+    }
+
+    #[test]
+    fn unicode_compose() {
+        let lang    = lang_turkish();
+
+        let source1 = to_vec("kitaplar");
+        let norm1   = lang.unicode_compose(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("çiçek");
+        let norm2   = lang.unicode_compose(&source2).unwrap();
+        assert_eq!(to_str(&norm2), "çiçek");
+        assert_eq!(norm2.len(), source2.len() - 2);
+    }
+
+    #[test]
+    fn unicode_reduce() {
+        let lang = lang_turkish();
+
+        let source1 = to_vec("kitaplar");
+        let norm1   = lang.unicode_reduce(&source1);
+        assert_eq!(norm1, None);
+
+        let source2 = to_vec("ağaçlı");
+        let (padded2, norm2, _) = lang.unicode_reduce(&source2).unwrap();
+        assert_eq!(to_str(&padded2), to_str(&source2));
+        assert_eq!(to_str(&norm2), "agacli");
+        assert_eq!(norm2.len(), source2.len());
+    }
+
+    #[test]
+    fn unicode_compose_map_dimenstions() {
+        for &(nfd, nfc) in UTF_COMPOSE_MAP {
+            assert_eq!(nfd.chars().count(), 2);
+            assert_eq!(nfc.chars().count(), 1);
+        }
+    }
+
+    #[test]
+    fn unicode_reduce_map_dimenstions() {
+        for &(normal, reduced) in UTF_REDUCE_MAP {
+            assert_eq!(normal .chars().count(), 1, "UTF_REDUCE_MAP['{}'] != 1", normal);
+            assert_eq!(reduced.chars().count(), 1, "UTF_REDUCE_MAP['{}'].len() != 1", reduced);
+        }
+    }
+
+    #[test]
+    fn get_char_class() {
+        let lang = lang_turkish();
+        assert_eq!(lang.get_char_class('a'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('n'), Some(CharClass::Consonant));
+        assert_eq!(lang.get_char_class('ı'), Some(CharClass::Vowel));
+        assert_eq!(lang.get_char_class('%'), None);
+    }
+}
@@ -2,22 +2,50 @@ mod constants;
 mod char_class;
 mod normalize;
 mod pos;
+mod phonetic;
+mod stem;
 mod lang;
+#[cfg(feature = "ar")]                      mod lang_arabic;
 mod lang_basic;
-mod lang_english;
-mod lang_french;
-mod lang_german;
-mod lang_portuguese;
-mod lang_russian;
-mod lang_spanish;
+#[cfg(any(feature = "ja", feature = "zh"))] mod lang_cjk;
+#[cfg(feature = "cs")]                      mod lang_czech;
+#[cfg(feature = "da")]                      mod lang_danish;
+#[cfg(feature = "nl")]                      mod lang_dutch;
+#[cfg(feature = "en")]                      mod lang_english;
+#[cfg(feature = "fr")]                      mod lang_french;
+#[cfg(feature = "de")]                      mod lang_german;
+#[cfg(feature = "el")]                      mod lang_greek;
+#[cfg(feature = "he")]                      mod lang_hebrew;
+#[cfg(feature = "it")]                      mod lang_italian;
+#[cfg(feature = "no")]                      mod lang_norwegian;
+#[cfg(feature = "pl")]                      mod lang_polish;
+#[cfg(feature = "pt")]                      mod lang_portuguese;
+#[cfg(feature = "ru")]                      mod lang_russian;
+#[cfg(feature = "es")]                      mod lang_spanish;
+#[cfg(feature = "sv")]                      mod lang_swedish;
+#[cfg(feature = "tr")]                      mod lang_turkish;
 
 pub use char_class::{CharClass, CharPattern};
 pub use pos::PartOfSpeech;
+pub use phonetic::{Phonetic, PhoneticKey};
+pub use stem::{Stem, SuffixStemmer};
 pub use lang::Lang;
+#[cfg(feature = "ar")]                      pub use lang_arabic::lang_arabic;
 pub use lang_basic::lang_basic;
-pub use lang_german::lang_german;
-pub use lang_english::lang_english;
-pub use lang_french::lang_french;
-pub use lang_spanish::lang_spanish;
-pub use lang_portuguese::lang_portuguese;
-pub use lang_russian::lang_russian;
+#[cfg(any(feature = "ja", feature = "zh"))] pub use lang_cjk::lang_cjk;
+#[cfg(feature = "cs")]                      pub use lang_czech::lang_czech;
+#[cfg(feature = "da")]                      pub use lang_danish::lang_danish;
+#[cfg(feature = "nl")]                      pub use lang_dutch::lang_dutch;
+#[cfg(feature = "de")]                      pub use lang_german::lang_german;
+#[cfg(feature = "el")]                      pub use lang_greek::lang_greek;
+#[cfg(feature = "en")]                      pub use lang_english::lang_english;
+#[cfg(feature = "he")]                      pub use lang_hebrew::lang_hebrew;
+#[cfg(feature = "it")]                      pub use lang_italian::lang_italian;
+#[cfg(feature = "no")]                      pub use lang_norwegian::lang_norwegian;
+#[cfg(feature = "pl")]                      pub use lang_polish::lang_polish;
+#[cfg(feature = "fr")]                      pub use lang_french::lang_french;
+#[cfg(feature = "es")]                      pub use lang_spanish::lang_spanish;
+#[cfg(feature = "sv")]                      pub use lang_swedish::lang_swedish;
+#[cfg(feature = "tr")]                      pub use lang_turkish::lang_turkish;
+#[cfg(feature = "pt")]                      pub use lang_portuguese::lang_portuguese;
+#[cfg(feature = "ru")]                      pub use lang_russian::lang_russian;
@@ -0,0 +1,295 @@
+use std::fmt;
+
+const KEY_LEN: usize = 12;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhoneticKey {
+    len:  u8,
+    code: [u8; KEY_LEN],
+}
+
+
+impl PhoneticKey {
+    fn new() -> Self {
+        Self { len: 0, code: [0; KEY_LEN] }
+    }
+
+    fn push(&mut self, code: u8) {
+        if (self.len as usize) < KEY_LEN {
+            self.code[self.len as usize] = code;
+            self.len += 1;
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.code[.. self.len as usize]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+
+impl fmt::Debug for PhoneticKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", String::from_utf8_lossy(self.as_bytes()))
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Phonetic {
+    Metaphone,
+    Cologne,
+}
+
+
+impl Phonetic {
+    pub fn key(&self, word: &[char]) -> Option<PhoneticKey> {
+        let key = match self {
+            Phonetic::Metaphone => metaphone(word),
+            Phonetic::Cologne   => cologne(word),
+        };
+        Some(key).filter(|key| !key.is_empty())
+    }
+}
+
+
+fn is_vowel(ch: char) -> bool {
+    "aeiou".contains(ch)
+}
+
+
+// Primary-key Metaphone with a few Double Metaphone rules (silent "gn", "mb").
+fn metaphone(word: &[char]) -> PhoneticKey {
+    let chars = word.iter()
+        .cloned()
+        .filter(|ch| ch.is_ascii_alphabetic())
+        .collect::<Vec<char>>();
+    let at = |i: isize| -> char {
+        if i < 0 { return '\0'; }
+        chars.get(i as usize).cloned().unwrap_or('\0')
+    };
+
+    let mut key   = PhoneticKey::new();
+    let mut start = 0;
+    match (at(0), at(1)) {
+        ('a', 'e') | ('g', 'n') | ('k', 'n') | ('p', 'n') | ('w', 'r') => start = 1,
+        ('x', _) => { key.push(b'S'); start = 1; },
+        ('w', 'h') => { key.push(b'W'); start = 2; },
+        _ => {},
+    }
+
+    let mut i = start as isize;
+    while (i as usize) < chars.len() {
+        let (prev, ch, next, next2) = (at(i - 1), at(i), at(i + 1), at(i + 2));
+        let mut skip = 0;
+        if ch == prev && ch != 'c' {
+            i += 1;
+            continue;
+        }
+        match ch {
+            'a' | 'e' | 'i' | 'o' | 'u' => {
+                if i == 0 { key.push(b'A'); }
+            },
+            'b' => {
+                if !(prev == 'm' && next == '\0') { key.push(b'B'); }
+            },
+            'c' => {
+                if next == 'i' && next2 == 'a' {
+                    key.push(b'X');
+                } else if next == 'h' {
+                    key.push(if prev == 's' { b'K' } else { b'X' });
+                    skip = 1;
+                } else if next == 'e' || next == 'i' || next == 'y' {
+                    if prev != 's' { key.push(b'S'); }
+                } else {
+                    key.push(b'K');
+                }
+            },
+            'd' => {
+                if next == 'g' && (next2 == 'e' || next2 == 'i' || next2 == 'y') {
+                    key.push(b'J');
+                    skip = 1;
+                } else {
+                    key.push(b'T');
+                }
+            },
+            'g' => {
+                if next == 'h' && !is_vowel(next2) {
+                    skip = 1;
+                } else if next == 'n' {
+                    // Silent as in "sign", "champagne".
+                } else if next == 'e' || next == 'i' || next == 'y' {
+                    key.push(b'J');
+                } else {
+                    key.push(b'K');
+                }
+            },
+            'h' => {
+                if is_vowel(next) && !"cgpst".contains(prev) { key.push(b'H'); }
+            },
+            'k' => {
+                if prev != 'c' { key.push(b'K'); }
+            },
+            'p' => {
+                if next == 'h' {
+                    key.push(b'F');
+                    skip = 1;
+                } else {
+                    key.push(b'P');
+                }
+            },
+            'q' => key.push(b'K'),
+            's' => {
+                if next == 'h' {
+                    key.push(b'X');
+                    skip = 1;
+                } else if next == 'i' && (next2 == 'o' || next2 == 'a') {
+                    key.push(b'X');
+                } else {
+                    key.push(b'S');
+                }
+            },
+            't' => {
+                if next == 'i' && (next2 == 'o' || next2 == 'a') {
+                    key.push(b'X');
+                } else if next == 'h' {
+                    key.push(b'0');
+                    skip = 1;
+                } else if !(next == 'c' && next2 == 'h') {
+                    key.push(b'T');
+                }
+            },
+            'v' => key.push(b'F'),
+            'w' | 'y' => {
+                if is_vowel(next) { key.push(ch.to_ascii_uppercase() as u8); }
+            },
+            'x' => { key.push(b'K'); key.push(b'S'); },
+            'z' => key.push(b'S'),
+            _ => key.push(ch.to_ascii_uppercase() as u8),
+        }
+        i += 1 + skip;
+    }
+    key
+}
+
+
+// Kölner Phonetik: digits with repeats collapsed and vowels dropped after the first position.
+fn cologne(word: &[char]) -> PhoneticKey {
+    let chars = word.iter()
+        .map(|&ch| match ch {
+            'ä' => 'a',
+            'ö' => 'o',
+            'ü' => 'u',
+            'ß' => 's',
+            _   => ch,
+        })
+        .filter(|ch| ch.is_ascii_alphabetic())
+        .collect::<Vec<char>>();
+    let at = |i: usize| -> char {
+        chars.get(i).cloned().unwrap_or('\0')
+    };
+
+    let mut key  = PhoneticKey::new();
+    let mut last = None;
+    for (i, &ch) in chars.iter().enumerate() {
+        let prev = if i == 0 { '\0' } else { at(i - 1) };
+        let next = at(i + 1);
+        let codes: &[u8] = match ch {
+            'a' | 'e' | 'i' | 'j' | 'o' | 'u' | 'y' => b"0",
+            'h' => b"",
+            'b' => b"1",
+            'p' => if next == 'h' { b"3" } else { b"1" },
+            'd' | 't' => if "csz".contains(next) { b"8" } else { b"2" },
+            'f' | 'v' | 'w' => b"3",
+            'g' | 'k' | 'q' => b"4",
+            'c' => {
+                let hard = if i == 0 {
+                    "ahkloqrux".contains(next)
+                } else {
+                    "ahkoqux".contains(next) && !"sz".contains(prev)
+                };
+                if hard { b"4" } else { b"8" }
+            },
+            'x' => if "ckq".contains(prev) { b"8" } else { b"48" },
+            'l' => b"5",
+            'm' | 'n' => b"6",
+            'r' => b"7",
+            's' | 'z' => b"8",
+            _ => b"",
+        };
+        for &code in codes {
+            if last == Some(code) { continue; }
+            last = Some(code);
+            if code == b'0' && !key.is_empty() { continue; }
+            key.push(code);
+        }
+    }
+    key
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::to_vec;
+    use super::{Phonetic, PhoneticKey};
+
+    fn key(phonetic: Phonetic, word: &str) -> Option<PhoneticKey> {
+        phonetic.key(&to_vec(word))
+    }
+
+    fn code(phonetic: Phonetic, word: &str) -> String {
+        key(phonetic, word)
+            .map(|key| String::from_utf8_lossy(key.as_bytes()).to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn metaphone_basic() {
+        assert_eq!(code(Phonetic::Metaphone, "champagne"), "XMPN");
+        assert_eq!(code(Phonetic::Metaphone, "shampain"),  "XMPN");
+        assert_eq!(code(Phonetic::Metaphone, "knight"),    "NT");
+        assert_eq!(code(Phonetic::Metaphone, "night"),     "NT");
+        assert_eq!(code(Phonetic::Metaphone, "thumb"),     "0M");
+        assert_eq!(code(Phonetic::Metaphone, "phone"),     "FN");
+        assert_eq!(code(Phonetic::Metaphone, "xerox"),     "SRKS");
+        assert_eq!(code(Phonetic::Metaphone, "apple"),     "APL");
+    }
+
+    #[test]
+    fn metaphone_equal_keys() {
+        let sample = [
+            ("smith",    "smyth"),
+            ("philip",   "filip"),
+            ("catherine", "kathryn"),
+            ("nite",     "night"),
+        ];
+        for &(word1, word2) in sample.iter() {
+            assert_eq!(key(Phonetic::Metaphone, word1), key(Phonetic::Metaphone, word2), "{} != {}", word1, word2);
+        }
+    }
+
+    #[test]
+    fn cologne_basic() {
+        assert_eq!(code(Phonetic::Cologne, "müller"),       "657");
+        assert_eq!(code(Phonetic::Cologne, "mueller"),      "657");
+        assert_eq!(code(Phonetic::Cologne, "meier"),        "67");
+        assert_eq!(code(Phonetic::Cologne, "mayr"),         "67");
+        assert_eq!(code(Phonetic::Cologne, "schmidt"),      "862");
+        assert_eq!(code(Phonetic::Cologne, "schmitt"),      "862");
+        assert_eq!(code(Phonetic::Cologne, "wikipedia"),    "3412");
+        assert_eq!(code(Phonetic::Cologne, "christoph"),    "47823");
+        assert_eq!(code(Phonetic::Cologne, "email"),        "065");
+        assert_eq!(code(Phonetic::Cologne, "xaver"),        "4837");
+    }
+
+    #[test]
+    fn phonetic_empty() {
+        assert_eq!(key(Phonetic::Metaphone, ""),   None);
+        assert_eq!(key(Phonetic::Metaphone, "42"), None);
+        assert_eq!(key(Phonetic::Cologne,   "h"),  None);
+    }
+}
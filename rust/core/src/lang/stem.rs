@@ -0,0 +1,97 @@
+#[cfg(feature = "snowball")] use std::cell::RefCell;
+#[cfg(feature = "snowball")] use rust_stemmers::Stemmer;
+
+#[cfg(feature = "snowball")]
+const BUFFER_CAPACITY: usize = 20;
+
+#[cfg(feature = "snowball")]
+thread_local! {
+    static BUFFER: RefCell<String> = RefCell::new(String::with_capacity(BUFFER_CAPACITY));
+}
+
+
+pub trait Stem {
+    // Length of the word prefix that is kept as a stem.
+    fn stem_len(&self, word: &[char]) -> usize;
+}
+
+
+#[cfg(feature = "snowball")]
+impl Stem for Stemmer {
+    fn stem_len(&self, word: &[char]) -> usize {
+        BUFFER.with(|cell| {
+            let buffer = &mut *cell.borrow_mut();
+            buffer.clear();
+            buffer.extend(word.iter());
+            self.stem(buffer).chars().count()
+        })
+    }
+}
+
+
+impl<F> Stem for F where F: Fn(&[char]) -> usize {
+    fn stem_len(&self, word: &[char]) -> usize {
+        self(word)
+    }
+}
+
+
+// Light stemmer for languages without a Snowball algorithm:
+// strips the longest known suffix, keeping at least `min_len` chars.
+pub struct SuffixStemmer {
+    suffixes: Vec<Vec<char>>,
+    min_len:  usize,
+}
+
+
+impl SuffixStemmer {
+    pub fn new(suffixes: &[&str], min_len: usize) -> Self {
+        let mut suffixes = suffixes.iter()
+            .map(|s| s.chars().collect::<Vec<char>>())
+            .collect::<Vec<_>>();
+        suffixes.sort_by_key(|s| std::cmp::Reverse(s.len()));
+        Self { suffixes, min_len }
+    }
+}
+
+
+impl Stem for SuffixStemmer {
+    fn stem_len(&self, word: &[char]) -> usize {
+        self.suffixes.iter()
+            .find(|suffix| word.len() >= self.min_len + suffix.len() && word.ends_with(suffix))
+            .map(|suffix| word.len() - suffix.len())
+            .unwrap_or(word.len())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "snowball")] use rust_stemmers::{Algorithm, Stemmer};
+    use crate::utils::to_vec;
+    use super::{Stem, SuffixStemmer};
+
+    #[test]
+    #[cfg(feature = "snowball")]
+    fn stem_snowball() {
+        let stemmer = Stemmer::create(Algorithm::English);
+        assert_eq!(stemmer.stem_len(&to_vec("universe")),  7);
+        assert_eq!(stemmer.stem_len(&to_vec("mailboxes")), 7);
+    }
+
+    #[test]
+    fn stem_suffix() {
+        let stemmer = SuffixStemmer::new(&["s", "es", "ing"], 3);
+        assert_eq!(stemmer.stem_len(&to_vec("boxes")),  3);
+        assert_eq!(stemmer.stem_len(&to_vec("cats")),   3);
+        assert_eq!(stemmer.stem_len(&to_vec("its")),    3);
+        assert_eq!(stemmer.stem_len(&to_vec("ring")),   4);
+        assert_eq!(stemmer.stem_len(&to_vec("string")), 3);
+    }
+
+    #[test]
+    fn stem_closure() {
+        let stemmer = |word: &[char]| word.len() / 2;
+        assert_eq!(stemmer.stem_len(&to_vec("mailbox")), 3);
+    }
+}
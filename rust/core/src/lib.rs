@@ -10,23 +10,36 @@ pub mod lang;
 use std::cell::RefCell;
 use fnv::{FnvHashMap as HashMap};
 
+pub use matching::{MatchConfig, KeyboardLayout};
 pub use tokenization::{Word, WordShape, WordView, Text, TextOwn, TextRef, tokenize_query};
-pub use store::{Record, Store, DEFAULT_LIMIT};
-pub use search::{SearchResult};
-pub use lang::Lang;
-pub use lang::{
-    lang_german,
-    lang_english,
-    lang_french,
-    lang_portuguese,
-    lang_russian,
-    lang_spanish,
-};
+pub use store::{Record, Field, Attr, Store, DEFAULT_LIMIT, TITLE_FIELD};
+pub use search::{SearchResult, SearchResults, Filter, ScoreType, RankingConfig, Scorer};
+pub use search::{Explanation, explanations_to_json};
+pub use lang::{Lang, Stem, SuffixStemmer};
+#[cfg(feature = "ar")]                      pub use lang::lang_arabic;
+#[cfg(any(feature = "ja", feature = "zh"))] pub use lang::lang_cjk;
+#[cfg(feature = "cs")]                      pub use lang::lang_czech;
+#[cfg(feature = "da")]                      pub use lang::lang_danish;
+#[cfg(feature = "nl")]                      pub use lang::lang_dutch;
+#[cfg(feature = "de")]                      pub use lang::lang_german;
+#[cfg(feature = "el")]                      pub use lang::lang_greek;
+#[cfg(feature = "en")]                      pub use lang::lang_english;
+#[cfg(feature = "fr")]                      pub use lang::lang_french;
+#[cfg(feature = "he")]                      pub use lang::lang_hebrew;
+#[cfg(feature = "it")]                      pub use lang::lang_italian;
+#[cfg(feature = "no")]                      pub use lang::lang_norwegian;
+#[cfg(feature = "pl")]                      pub use lang::lang_polish;
+#[cfg(feature = "pt")]                      pub use lang::lang_portuguese;
+#[cfg(feature = "ru")]                      pub use lang::lang_russian;
+#[cfg(feature = "es")]                      pub use lang::lang_spanish;
+#[cfg(feature = "sv")]                      pub use lang::lang_swedish;
+#[cfg(feature = "tr")]                      pub use lang::lang_turkish;
 
 
 thread_local! {
     static STORES:  RefCell<HashMap<usize, Store>>             = RefCell::new(HashMap::default());
     static RESULTS: RefCell<HashMap<usize, Vec<SearchResult>>> = RefCell::new(HashMap::default());
+    static FACETS:  RefCell<HashMap<usize, Vec<(String, usize)>>> = RefCell::new(HashMap::default());
 }
 
 
@@ -48,6 +61,14 @@ pub fn create_store(id: usize, lang: Lang) {
         }
         buffers.insert(id, Vec::with_capacity(DEFAULT_LIMIT));
     });
+
+    FACETS.with(|cell| {
+        let buffers = &mut *cell.borrow_mut();
+        if buffers.contains_key(&id) {
+            panic!("Duplicate store id");
+        }
+        buffers.insert(id, Vec::new());
+    });
 }
 
 
@@ -68,6 +89,14 @@ pub fn destroy_store(id: usize) {
         }
         buffers.remove(&id);
     });
+
+    FACETS.with(|cell| {
+        let buffers = &mut *cell.borrow_mut();
+        if !buffers.contains_key(&id) {
+            panic!("Missing store id");
+        }
+        buffers.remove(&id);
+    });
 }
 
 
@@ -88,6 +117,125 @@ pub fn set_records<'a, I>(store_id: usize, records: I) where I: IntoIterator<Ite
 }
 
 
+pub fn upsert_records<'a, I>(store_id: usize, records: I) where I: IntoIterator<Item=(usize, &'a str, usize)> {
+    using_store(store_id, |store| {
+        for (id, title, rating) in records {
+            store.upsert(Record::new(id, title, rating, &store.lang));
+        }
+    });
+}
+
+
+// Lang id for records in another language, the main store lang has id 0.
+pub fn add_lang(store_id: usize, lang: Lang) -> usize {
+    using_store(store_id, |store| {
+        store.add_lang(lang)
+    })
+}
+
+
+// Same as set_records, with a lang id for each record.
+pub fn set_lang_records<'a, I>(store_id: usize, records: I) where I: IntoIterator<Item=(usize, &'a str, usize, usize)> {
+    using_store(store_id, |store| {
+        store.clear();
+        for (id, title, rating, lang) in records {
+            let mut record = Record::new(id, title, rating, store.lang_by_id(lang));
+            record.set_lang(lang);
+            store.add(record);
+        }
+    });
+}
+
+
+pub fn upsert_lang_records<'a, I>(store_id: usize, records: I) where I: IntoIterator<Item=(usize, &'a str, usize, usize)> {
+    using_store(store_id, |store| {
+        for (id, title, rating, lang) in records {
+            let mut record = Record::new(id, title, rating, store.lang_by_id(lang));
+            record.set_lang(lang);
+            store.upsert(record);
+        }
+    });
+}
+
+
+pub fn remove_records<I>(store_id: usize, ids: I) where I: IntoIterator<Item=usize> {
+    using_store(store_id, |store| {
+        for id in ids {
+            store.remove(id);
+        }
+    });
+}
+
+
+pub fn set_keyword_attrs<'a, I>(store_id: usize, name: &str, attrs: I) where I: IntoIterator<Item=(usize, &'a str)> {
+    let mut grouped: HashMap<usize, Vec<&str>> = HashMap::default();
+    for (id, value) in attrs {
+        grouped.entry(id).or_default().push(value);
+    }
+    using_store(store_id, |store| {
+        for (id, values) in grouped {
+            let attr = match values.len() {
+                1 => Attr::keyword(values[0]),
+                _ => Attr::keywords(&values),
+            };
+            store.set_attr(id, name, attr);
+        }
+    });
+}
+
+
+pub fn set_facet(store_id: usize, facet: Option<&str>) {
+    using_store(store_id, |store| {
+        store.facet = facet.map(|f| f.to_string());
+    });
+}
+
+
+pub fn set_ranking(store_id: usize, ranking: RankingConfig) {
+    using_store(store_id, |store| {
+        store.ranking = ranking;
+    });
+}
+
+
+pub fn add_scorer<S>(store_id: usize, name: &str, scorer: S) -> ScoreType where S: Scorer + 'static {
+    using_store(store_id, |store| {
+        store.add_scorer(name, scorer)
+    })
+}
+
+
+pub fn set_match_config(store_id: usize, config: MatchConfig) {
+    using_store(store_id, |store| {
+        store.matching = config;
+    });
+}
+
+
+pub fn add_synonym(store_id: usize, from: &str, to: &str) {
+    using_store(store_id, |store| {
+        store.lang.add_synonym(from, to);
+    });
+}
+
+
+pub fn add_synonyms(store_id: usize, group: &[&str]) {
+    using_store(store_id, |store| {
+        store.lang.add_synonyms(group);
+    });
+}
+
+
+// Records added before the call keep their old tokenization.
+pub fn add_compound_parts(store_id: usize, parts: &[&str]) {
+    using_store(store_id, |store| {
+        for part in parts {
+            store.lang.add_compound_part(part);
+        }
+    });
+}
+
+
 pub fn set_limit(store_id: usize, limit: usize)  {
     using_store(store_id, |store| {
     using_results(store_id, |buffer| {
@@ -102,13 +250,51 @@ pub fn set_limit(store_id: usize, limit: usize)  {
 pub fn run_search(store_id: usize, query: &str) {
     using_store(store_id, |store| {
     using_results(store_id, |buffer| {
+    using_facets(store_id, |facets| {
         let query = tokenize_query(query, &store.lang);
         let query = query.to_ref();
         buffer.clear();
-        for result in store.search(&query) {
+        facets.clear();
+        let results = match &store.facet {
+            Some(facet) => {
+                let SearchResults { results, facets: counts, .. } = store.search_facet(&query, None, facet);
+                facets.extend(counts);
+                results
+            },
+            None => store.search(&query, None),
+        };
+        for result in results {
             buffer.push(result);
         }
-    }); });
+    }); }); });
+}
+
+
+pub fn run_search_page(store_id: usize, query: &str, offset: usize, limit: usize) -> usize {
+    using_store(store_id, |store| {
+    using_results(store_id, |buffer| {
+    using_facets(store_id, |facets| {
+        let query = tokenize_query(query, &store.lang);
+        let query = query.to_ref();
+        buffer.clear();
+        facets.clear();
+        let facet = store.facet.as_deref();
+        let SearchResults { results, facets: counts, total } = store.search_page_facet(&query, None, facet, offset, limit);
+        facets.extend(counts);
+        for result in results {
+            buffer.push(result);
+        }
+        total
+    }) }) })
+}
+
+
+pub fn explain_search(store_id: usize, query: &str) -> Vec<Explanation> {
+    using_store(store_id, |store| {
+        let query = tokenize_query(query, &store.lang);
+        let query = query.to_ref();
+        store.search_explain(&query, None)
+    })
 }
 
 
@@ -128,3 +314,12 @@ pub fn using_results<T, F>(store_id: usize, f: F) -> T where F: (FnOnce(&mut Vec
         f(buffer)
     })
 }
+
+
+pub fn using_facets<T, F>(store_id: usize, f: F) -> T where F: (FnOnce(&mut Vec<(String, usize)>) -> T) {
+    FACETS.with(|cell| {
+        let buffers = &mut *cell.borrow_mut();
+        let buffer  = buffers.get_mut(&store_id).unwrap();
+        f(buffer)
+    })
+}
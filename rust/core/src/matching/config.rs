@@ -0,0 +1,115 @@
+use super::KeyboardLayout;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchConfig {
+    pub length_threshold:  f64,
+    pub jaccard_threshold: f64,
+    pub damlev_threshold:  f64,
+    pub cost_trans:        f64,
+    pub cost_double:       f64,
+    pub cost_vowel:        f64,
+    pub cost_notalpha:     f64,
+    pub cost_consonant:    f64,
+    pub cost_default:      f64,
+    pub cost_adjacent:     f64,
+    pub keyboard:          Option<KeyboardLayout>,
+    pub typo_budgets:      Vec<(usize, f64)>,
+    pub phonetic:          Option<f64>,
+}
+
+
+impl MatchConfig {
+    pub fn new() -> Self {
+        Self {
+            length_threshold:  0.26,
+            jaccard_threshold: 0.51,
+            damlev_threshold:  0.21,
+            cost_trans:        0.5,
+            cost_double:       0.5,
+            cost_vowel:        0.5,
+            cost_notalpha:     0.5,
+            cost_consonant:    1.0,
+            cost_default:      1.0,
+            cost_adjacent:     0.5,
+            keyboard:          None,
+            typo_budgets:      Vec::new(),
+            phonetic:          None,
+        }
+    }
+
+    // Same budgets as fuzziness AUTO in Elasticsearch.
+    pub fn fuzziness_auto() -> Self {
+        Self::new().typo_budgets(&[(0, 0.0), (3, 1.0), (6, 2.0)])
+    }
+
+    pub fn keyboard(mut self, keyboard: Option<KeyboardLayout>) -> Self {
+        self.keyboard = keyboard;
+        self
+    }
+
+    pub fn typo_budgets(mut self, budgets: &[(usize, f64)]) -> Self {
+        self.typo_budgets = budgets.to_vec();
+        self.typo_budgets.sort_by_key(|&(len, _)| len);
+        self
+    }
+
+    // Words with equal phonetic keys match with the given number of typos.
+    pub fn phonetic(mut self, typos: Option<f64>) -> Self {
+        self.phonetic = typos;
+        self
+    }
+
+    pub fn substitution_cost(&self, ch1: char, ch2: char, cost: f64) -> f64 {
+        match &self.keyboard {
+            Some(keyboard) if keyboard.adjacent(ch1, ch2) => f64::min(cost, self.cost_adjacent),
+            _ => cost,
+        }
+    }
+
+    pub fn typo_budget(&self, len: usize) -> Option<f64> {
+        self.typo_budgets.iter()
+            .take_while(|&&(min_len, _)| min_len <= len)
+            .last()
+            .map(|&(_, typos)| typos)
+    }
+}
+
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::MatchConfig;
+
+    #[test]
+    fn typo_budget_empty() {
+        let config = MatchConfig::new();
+        assert_eq!(config.typo_budget(0),  None);
+        assert_eq!(config.typo_budget(10), None);
+    }
+
+    #[test]
+    fn typo_budget_auto() {
+        let config = MatchConfig::fuzziness_auto();
+        assert_eq!(config.typo_budget(1), Some(0.0));
+        assert_eq!(config.typo_budget(2), Some(0.0));
+        assert_eq!(config.typo_budget(3), Some(1.0));
+        assert_eq!(config.typo_budget(5), Some(1.0));
+        assert_eq!(config.typo_budget(6), Some(2.0));
+        assert_eq!(config.typo_budget(9), Some(2.0));
+    }
+
+    #[test]
+    fn typo_budget_unsorted() {
+        let config = MatchConfig::new().typo_budgets(&[(8, 2.0), (4, 1.0)]);
+        assert_eq!(config.typo_budget(3), None);
+        assert_eq!(config.typo_budget(4), Some(1.0));
+        assert_eq!(config.typo_budget(8), Some(2.0));
+    }
+}
@@ -5,18 +5,12 @@ use fnv::{FnvHashMap as HashMap};
 use std::cell::RefCell;
 use crate::lang::CharClass;
 use crate::tokenization::{Word, WordView};
+use super::MatchConfig;
 use matrix::DistMatrix;
 
 
 const DEFAULT_CAPACITY: usize = 20;
 
-const COST_TRANS:     f64 = 0.5;
-const COST_DOUBLE:    f64 = 0.5;
-const COST_VOWEL:     f64 = 0.5;
-const COST_NOTALPHA:  f64 = 0.5;
-const COST_CONSONANT: f64 = 1.0;
-const COST_DEFAULT:   f64 = 1.0;
-
 
 pub struct DamerauLevenshtein {
     pub dists: RefCell<DistMatrix>,
@@ -35,16 +29,16 @@ impl DamerauLevenshtein {
         Self { dists, last_i1, costs1, costs2 }
     }
 
-    fn get_cost(class: &CharClass) -> f64 {
+    fn get_cost(class: &CharClass, config: &MatchConfig) -> f64 {
         match class {
-            CharClass::Consonant => COST_CONSONANT,
-            CharClass::Vowel     => COST_VOWEL,
-            CharClass::NotAlpha  => COST_NOTALPHA,
-            _                    => COST_DEFAULT,
+            CharClass::Consonant => config.cost_consonant,
+            CharClass::Vowel     => config.cost_vowel,
+            CharClass::NotAlpha  => config.cost_notalpha,
+            _                    => config.cost_default,
         }
     }
 
-    pub fn distance(&self, word1: &WordView, word2: &WordView) -> f64 {
+    pub fn distance(&self, word1: &WordView, word2: &WordView, config: &MatchConfig) -> f64 {
         let chars1 = word1.chars();
         let chars2 = word2.chars();
 
@@ -52,8 +46,8 @@ impl DamerauLevenshtein {
         let costs2 = &mut *self.costs2.borrow_mut();
         costs1.clear();
         costs2.clear();
-        costs1.extend(word1.classes().iter().map(Self::get_cost));
-        costs2.extend(word2.classes().iter().map(Self::get_cost));
+        costs1.extend(word1.classes().iter().map(|class| Self::get_cost(class, config)));
+        costs2.extend(word2.classes().iter().map(|class| Self::get_cost(class, config)));
 
         let dists = &mut *self.dists.borrow_mut();
         dists.prepare(&costs1, &costs2);
@@ -71,8 +65,8 @@ impl DamerauLevenshtein {
                 let cost2      = costs2[i2];
                 let double1    = i1 > 0 && ch1 == unsafe { *chars1.get_unchecked(i1 - 1) };
                 let double2    = i2 > 0 && ch2 == unsafe { *chars2.get_unchecked(i2 - 1) };
-                let double1    = if double1 { COST_DOUBLE } else { COST_DEFAULT };
-                let double2    = if double2 { COST_DOUBLE } else { COST_DEFAULT };
+                let double1    = if double1 { config.cost_double } else { config.cost_default };
+                let double2    = if double2 { config.cost_double } else { config.cost_default };
 
                 let dist_add   = unsafe { dists.get_unchecked(i1 + 2, i2 + 1) }
                                + f64::min(cost2, double2);
@@ -80,11 +74,13 @@ impl DamerauLevenshtein {
                 let dist_del   = unsafe { dists.get_unchecked(i1 + 1, i2 + 2) }
                                + f64::min(cost1, double1);
 
+                let cost_sub   = if ch1 == ch2 { 0.0 } else { config.substitution_cost(ch1, ch2, f64::max(cost1, cost2)) };
+
                 let dist_sub   = unsafe { dists.get_unchecked(i1 + 1, i2 + 1) }
-                               + f64::max(cost1, cost2) * ((ch1 != ch2) as usize) as f64;
+                               + cost_sub;
 
                 let dist_trans = unsafe { dists.get_unchecked(l1, l2) }
-                               + COST_TRANS * ((i1 - l1) + (i2 - l2) + 1) as f64;
+                               + config.cost_trans * ((i1 - l1) + (i2 - l2) + 1) as f64;
 
                 let dist       = min4(dist_add, dist_del, dist_sub, dist_trans);
 
@@ -112,14 +108,18 @@ fn min4(x1: f64, x2: f64, x3: f64, x4: f64) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::lang::lang_english;
+    #[cfg(feature = "en")]                      use crate::lang::lang_english;
+    #[cfg(all(feature = "en", feature = "ru"))] use crate::lang::lang_russian;
+    #[cfg(all(feature = "en", feature = "ru"))] use crate::matching::KeyboardLayout;
     use crate::tokenization::Text;
+    use crate::matching::MatchConfig;
     use super::DamerauLevenshtein;
 
 
     #[test]
     fn equality() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let sample = [
             Text::from_str(""),
             Text::from_str("a"),
@@ -127,13 +127,14 @@ mod tests {
             Text::from_str("abc"),
         ];
         for text in sample.iter() {
-            assert_eq!(damlev.distance(&text.view(0), &text.view(0)), 0.0);
+            assert_eq!(damlev.distance(&text.view(0), &text.view(0), &config), 0.0);
         }
     }
 
     #[test]
     fn prefix() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let sample = [
             (0.0, Text::from_str("abc"), Text::from_str("abc")),
             (1.0, Text::from_str("abc"), Text::from_str("ab")),
@@ -141,14 +142,15 @@ mod tests {
             (3.0, Text::from_str("abc"), Text::from_str("")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
     #[test]
     fn add_del_continuous() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let sample = [
             (1.0, Text::from_str("abc"), Text::from_str("xabc")),
             (2.0, Text::from_str("abc"), Text::from_str("xyabc")),
@@ -163,14 +165,15 @@ mod tests {
             (3.0, Text::from_str("abc"), Text::from_str("abcxyz")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
     #[test]
     fn sub_continuous() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let sample = [
             (1.0, Text::from_str("abcd"), Text::from_str("xbcd")),
             (2.0, Text::from_str("abcd"), Text::from_str("xycd")),
@@ -184,26 +187,28 @@ mod tests {
             (3.0, Text::from_str("abcd"), Text::from_str("axyz")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
         }
     }
 
     #[test]
     fn trans_continuous() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let sample = [
             (0.5, Text::from_str("abcd"), Text::from_str("bacd")), // swap 1 and 2
             (1.0, Text::from_str("abcd"), Text::from_str("badc")), // swap 3 and 4
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
     #[test]
     fn add_del_intermittent() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let sample = [
             (1.0, Text::from_str("abc"), Text::from_str("xabc")),
             (2.0, Text::from_str("abc"), Text::from_str("xaybc")),
@@ -214,14 +219,15 @@ mod tests {
             (3.0, Text::from_str("abc"), Text::from_str("axbycz")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
     #[test]
     fn sub_intermittent() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let sample = [
             (1.0, Text::from_str("abcd"), Text::from_str("xbcd")),
             (2.0, Text::from_str("abcd"), Text::from_str("xbyd")),
@@ -230,14 +236,15 @@ mod tests {
             (2.0, Text::from_str("abcd"), Text::from_str("axcy")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
     #[test]
     fn growth() {
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         for len in (1..501).step_by(100) {
             let mut s1 = String::with_capacity(len);
             let mut s2 = String::with_capacity(len);
@@ -246,16 +253,18 @@ mod tests {
             let t0 = Text::from_str("");
             let t1 = Text::from_str(&s1);
             let t2 = Text::from_str(&s2);
-            assert_eq!(damlev.distance(&t1.view(0), &t1.view(0)), 0.0);
-            assert_eq!(damlev.distance(&t1.view(0), &t0.view(0)), len as f64);
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), len as f64);
+            assert_eq!(damlev.distance(&t1.view(0), &t1.view(0), &config), 0.0);
+            assert_eq!(damlev.distance(&t1.view(0), &t0.view(0), &config), len as f64);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), len as f64);
         }
     }
 
+    #[cfg(feature = "en")]
     #[test]
     pub fn add_del_lang_consonant() {
         let lang   = lang_english();
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let text   = |s| Text::from_str(s).set_char_classes(&lang);
         let sample = [
             (1.0, text("pink"), text("spink")),
@@ -270,15 +279,17 @@ mod tests {
             (3.0, text("pink"), text("pinkstr")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
+    #[cfg(feature = "en")]
     #[test]
     pub fn add_del_lang_vowel() {
         let lang   = lang_english();
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let text   = |s| Text::from_str(s).set_char_classes(&lang);
         let sample = [
             (0.5, text("pink"), text("opink")),
@@ -293,15 +304,17 @@ mod tests {
             (1.5, text("pink"), text("pinkaio")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn sub_lang() {
         let lang   = lang_english();
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let text   = |s| Text::from_str(s).set_char_classes(&lang);
         let sample = [
             (0.5, text("pinky"), text("punky")),
@@ -311,15 +324,17 @@ mod tests {
             (2.0, text("pinky"), text("psnkn")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn add_del_double_lang() {
         let lang   = lang_english();
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let text   = |s| Text::from_str(s).set_char_classes(&lang);
         let sample = [
             // vowel end
@@ -336,15 +351,17 @@ mod tests {
             (2.0, text("pinky"),  text("pinkysss")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn sub_double_lang() {
         let lang   = lang_english();
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let text   = |s| Text::from_str(s).set_char_classes(&lang);
         let sample = [
             // consonant mid
@@ -367,15 +384,17 @@ mod tests {
             (1.5, text("pinky"), text("pooonky")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
         }
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn notalpha_lang() {
         let lang   = lang_english();
         let damlev = DamerauLevenshtein::new();
+        let config = MatchConfig::new();
         let text   = |s| Text::from_str(s).set_char_classes(&lang);
         let sample = [
             (0.5, text("pinky"), text("p_nky")),
@@ -383,8 +402,41 @@ mod tests {
             (2.0, text("pinky"), text("__nk_")),
         ];
         for (d, t1, t2) in sample.iter() {
-            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0)), *d);
-            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0)), *d);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config), *d);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config), *d);
+        }
+    }
+
+    #[cfg(all(feature = "en", feature = "ru"))]
+    #[test]
+    fn keyboard_adjacent() {
+        let lang_en  = lang_english();
+        let lang_ru  = lang_russian();
+        let damlev   = DamerauLevenshtein::new();
+        let keyboard = KeyboardLayout::qwerty().merge(&KeyboardLayout::jcuken());
+        let config1  = MatchConfig::new();
+        let config2  = MatchConfig::new().keyboard(Some(keyboard));
+        let en       = |s| Text::from_str(s).set_char_classes(&lang_en);
+        let ru       = |s| Text::from_str(s).set_char_classes(&lang_ru);
+        let sample   = [
+            // adjacent keys, words from datasets/e_commerce.json and javascript/tests/lang_ru.js
+            (1.0, 0.5, en("card"),      en("cafd")),
+            (1.0, 0.5, en("lamp"),      en("lanp")),
+            (1.0, 0.5, en("dish"),      en("disj")),
+            (1.0, 0.5, en("bowl"),      en("bowk")),
+            (1.0, 0.5, ru("кабель"),    ru("кабедь")),
+            (1.0, 0.5, ru("мешки"),     ru("мещки")),
+            (1.0, 0.5, ru("батарейки"), ru("батарецки")),
+            // distant keys
+            (1.0, 1.0, en("chair"),     en("phair")),
+            (1.0, 1.0, ru("кабель"),    ru("кабечь")),
+            // vowels are cheap anyway
+            (0.5, 0.5, en("lamp"),      en("lemp")),
+        ];
+        for (d1, d2, t1, t2) in sample.iter() {
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config1), *d1);
+            assert_eq!(damlev.distance(&t1.view(0), &t2.view(0), &config2), *d2);
+            assert_eq!(damlev.distance(&t2.view(0), &t1.view(0), &config2), *d2);
         }
     }
 }
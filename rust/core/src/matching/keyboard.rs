@@ -0,0 +1,134 @@
+use fnv::{FnvHashMap as HashMap};
+
+
+const ROW_SHIFTS:    &[f64] = &[0.0, 0.25, 0.75];
+const ADJACENT_DIST: f64    = 1.3;
+
+pub const QWERTY_ROWS: &[&str] = &["qwertyuiop",   "asdfghjkl",   "zxcvbnm"];
+pub const QWERTZ_ROWS: &[&str] = &["qwertzuiopü",  "asdfghjklöä", "yxcvbnm"];
+pub const AZERTY_ROWS: &[&str] = &["azertyuiop",   "qsdfghjklm",  "wxcvbn"];
+pub const JCUKEN_ROWS: &[&str] = &["йцукенгшщзхъ", "фывапролджэ", "ячсмитьбю"];
+
+
+// Key positions of a layout. Costs are not graded by distance: keys closer than
+// ADJACENT_DIST (neighbours in a row and the two below or above) count as adjacent
+// and get MatchConfig::cost_adjacent, all the other keys get the full cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardLayout {
+    keys: HashMap<char, (f64, f64)>,
+}
+
+
+impl KeyboardLayout {
+    pub fn new() -> Self {
+        Self { keys: HashMap::default() }
+    }
+
+    pub fn qwerty() -> Self { Self::new().add_rows(QWERTY_ROWS) }
+    pub fn qwertz() -> Self { Self::new().add_rows(QWERTZ_ROWS) }
+    pub fn azerty() -> Self { Self::new().add_rows(AZERTY_ROWS) }
+    pub fn jcuken() -> Self { Self::new().add_rows(JCUKEN_ROWS) }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "qwerty" => Some(Self::qwerty()),
+            "qwertz" => Some(Self::qwertz()),
+            "azerty" => Some(Self::azerty()),
+            "jcuken" => Some(Self::jcuken()),
+            _        => None,
+        }
+    }
+
+    // Rows go top to bottom, each next one is shifted right like on a real keyboard.
+    pub fn add_rows(mut self, rows: &[&str]) -> Self {
+        for (row, keys) in rows.iter().enumerate() {
+            let shift = ROW_SHIFTS.get(row).cloned().unwrap_or(0.0);
+            for (col, key) in keys.chars().enumerate() {
+                self.keys.insert(key, (row as f64, col as f64 + shift));
+            }
+        }
+        self
+    }
+
+    pub fn merge(mut self, other: &KeyboardLayout) -> Self {
+        for (&key, &pos) in other.keys.iter() {
+            self.keys.insert(key, pos);
+        }
+        self
+    }
+
+    pub fn distance(&self, ch1: char, ch2: char) -> Option<f64> {
+        let (row1, col1) = self.keys.get(&ch1)?;
+        let (row2, col2) = self.keys.get(&ch2)?;
+        Some((row1 - row2).hypot(col1 - col2))
+    }
+
+    pub fn adjacent(&self, ch1: char, ch2: char) -> bool {
+        match self.distance(ch1, ch2) {
+            Some(dist) => dist > 0.0 && dist < ADJACENT_DIST,
+            None       => false,
+        }
+    }
+}
+
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::KeyboardLayout;
+
+    #[test]
+    fn adjacent_qwerty() {
+        let layout = KeyboardLayout::qwerty();
+        let sample = [
+            (true,  'q', 'w'),
+            (true,  'q', 'a'),
+            (true,  's', 'e'),
+            (true,  's', 'z'),
+            (true,  'o', 'i'),
+            (true,  'n', 'h'),
+            (false, 'q', 'e'),
+            (false, 'a', 'e'),
+            (false, 'z', 'd'),
+            (false, 'q', 'm'),
+            (false, 'q', 'q'),
+            (false, 'q', 'й'),
+        ];
+        for &(expect, ch1, ch2) in sample.iter() {
+            assert_eq!(layout.adjacent(ch1, ch2), expect, "Failed adjacent('{}', '{}') == {}", ch1, ch2, expect);
+            assert_eq!(layout.adjacent(ch2, ch1), expect, "Failed adjacent('{}', '{}') == {}", ch2, ch1, expect);
+        }
+    }
+
+    #[test]
+    fn adjacent_jcuken() {
+        let layout = KeyboardLayout::jcuken();
+        let sample = [
+            (true,  'т', 'р'),
+            (true,  'о', 'р'),
+            (true,  'й', 'ф'),
+            (true,  'е', 'н'),
+            (false, 'к', 'л'),
+            (false, 'й', 'ю'),
+        ];
+        for &(expect, ch1, ch2) in sample.iter() {
+            assert_eq!(layout.adjacent(ch1, ch2), expect, "Failed adjacent('{}', '{}') == {}", ch1, ch2, expect);
+        }
+    }
+
+    #[test]
+    fn merge() {
+        let layout = KeyboardLayout::jcuken().merge(&KeyboardLayout::qwerty());
+        assert!( layout.adjacent('q', 'w'));
+        assert!( layout.adjacent('т', 'р'));
+        assert!(!layout.adjacent('q', 'й'));
+        assert_eq!(KeyboardLayout::from_name("jcuken"), Some(KeyboardLayout::jcuken()));
+        assert_eq!(KeyboardLayout::from_name("dvorak"), None);
+    }
+}
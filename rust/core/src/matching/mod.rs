@@ -1,9 +1,13 @@
+mod config;
 mod damlev;
+mod keyboard;
 mod jaccard;
 mod word;
 mod word_match;
 mod text;
 
+pub use config::MatchConfig;
+pub use keyboard::KeyboardLayout;
 pub use word_match::WordMatch;
 pub use word::word_match;
 pub use text::text_match;
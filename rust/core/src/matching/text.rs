@@ -2,7 +2,7 @@ use std::cmp::Ordering::{Equal, Less};
 use std::cell::RefCell;
 use fnv::{FnvHashMap as HashMap};
 use crate::tokenization::{Word, TextRef};
-use super::WordMatch;
+use super::{WordMatch, MatchConfig};
 use super::word::word_match;
 
 thread_local! {
@@ -11,7 +11,7 @@ thread_local! {
 }
 
 
-pub fn text_match(rtext: &TextRef, qtext: &TextRef) -> (Vec<WordMatch>, Vec<WordMatch>) {
+pub fn text_match(rtext: &TextRef, qtext: &TextRef, config: &MatchConfig) -> (Vec<WordMatch>, Vec<WordMatch>) {
     RMATCHES.with(|rcell| {
     QMATCHES.with(|qcell| {
         let rmatches = &mut *rcell.borrow_mut();
@@ -32,9 +32,10 @@ pub fn text_match(rtext: &TextRef, qtext: &TextRef) -> (Vec<WordMatch>, Vec<Word
 
                 None.or_else(|| {
                         let rnext = rtext.words.get(rword.offset + 1)?.to_view(rtext);
+                        if rword.overlaps(&rnext) { return None; }
                         if qword.len() < rword.len() + rword.dist(&rnext) { return None; }
                         if rmatches.contains_key(&(rword.offset + 1)) { return None; }
-                        let (rmatch,  qmatch)  = word_match(&rword.join(&rnext), &qword)?;
+                        let (rmatch,  qmatch)  = word_match(&rword.join(&rnext), &qword, config)?;
                         let (rmatch1, rmatch2) = rmatch.split(&rword, &rnext)?;
                         rmatches.insert(rmatch1.offset, rmatch1);
                         rmatches.insert(rmatch2.offset, rmatch2);
@@ -45,9 +46,10 @@ pub fn text_match(rtext: &TextRef, qtext: &TextRef) -> (Vec<WordMatch>, Vec<Word
                     })
                     .or_else(|| {
                         let qnext = qtext.words.get(qword.offset + 1)?.to_view(qtext);
+                        if qword.overlaps(&qnext) { return None; }
                         if rword.len() < qword.len() + qword.dist(&qnext) { return None; }
                         if qmatches.contains_key(&(qword.offset + 1)) { return None; }
-                        let (rmatch,  qmatch)  = word_match(&rword, &qword.join(&qnext))?;
+                        let (rmatch,  qmatch)  = word_match(&rword, &qword.join(&qnext), config)?;
                         let (qmatch1, qmatch2) = qmatch.split(&qword, &qnext)?;
                         rmatches.insert(rmatch.offset,  rmatch);
                         qmatches.insert(qmatch1.offset, qmatch1);
@@ -57,7 +59,7 @@ pub fn text_match(rtext: &TextRef, qtext: &TextRef) -> (Vec<WordMatch>, Vec<Word
                         Some(())
                     })
                     .or_else(|| {
-                        let (rmatch2, qmatch2) = word_match(&rword, &qword)?;
+                        let (rmatch2, qmatch2) = word_match(&rword, &qword, config)?;
                         let score2 = rmatch2.match_len() - 2 * (rmatch2.typos.ceil() as usize);
                         let score1 = candidate
                             .as_ref()
@@ -101,7 +103,10 @@ pub fn text_match(rtext: &TextRef, qtext: &TextRef) -> (Vec<WordMatch>, Vec<Word
 mod tests {
     use insta::assert_debug_snapshot;
     use crate::tokenization::{Text, TextOwn};
-    use crate::lang::{CharClass, lang_basic, lang_english, lang_spanish};
+    use crate::lang::{CharClass, lang_basic};
+    #[cfg(feature = "en")] use crate::lang::lang_english;
+    #[cfg(feature = "es")] use crate::lang::lang_spanish;
+    use crate::matching::MatchConfig;
     use super::{text_match};
 
 
@@ -117,7 +122,7 @@ mod tests {
     fn match_text_empty_both() {
         let rtext = text("");
         let qtext = text("").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -125,8 +130,8 @@ mod tests {
     fn match_text_empty_one() {
         let rtext = text("");
         let qtext = text("mailbox").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
-        assert_debug_snapshot!(text_match(&qtext.to_ref(), &rtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
+        assert_debug_snapshot!(text_match(&qtext.to_ref(), &rtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -134,7 +139,7 @@ mod tests {
     fn match_text_singleton_equality() {
         let rtext = text("mailbox");
         let qtext = text("mailbox").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -142,7 +147,7 @@ mod tests {
     fn match_text_singleton_typos() {
         let rtext = text("mailbox");
         let qtext = text("maiblox").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -150,7 +155,7 @@ mod tests {
     fn match_text_pair_first() {
         let rtext = text("yellow mailbox");
         let qtext = text("yelow").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -158,7 +163,7 @@ mod tests {
     fn match_text_pair_second() {
         let rtext = text("yellow mailbox");
         let qtext = text("maiblox").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -166,7 +171,7 @@ mod tests {
     fn match_text_pair_unfinished() {
         let rtext = text("yellow mailbox");
         let qtext = text("maiblox yel").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -174,7 +179,7 @@ mod tests {
     fn match_text_intersection() {
         let rtext = text("small yellow metal mailbox");
         let qtext = text("big malibox yelo").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -182,25 +187,27 @@ mod tests {
     fn match_text_best_rword_first() {
         let rtext = text("theory theme");
         let qtext = text("the").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
+    #[cfg(feature = "en")]
     #[test]
     fn match_text_best_rword_nonfunc() {
         let lang  = lang_english();
         let rtext = text("the theme").set_pos(&lang);
         let qtext = text("the").fin(false).set_pos(&lang);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
+    #[cfg(feature = "es")]
     #[test]
     fn match_text_best_rword_typos() {
         let lang  = lang_spanish();
         let rtext = text("Cepillo de dientes").set_pos(&lang);
         let qtext = text("de").fin(false).set_pos(&lang);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
 
@@ -208,63 +215,63 @@ mod tests {
     fn match_text_regression_best_match() {
         let rtext = text("sneaky");
         let qtext = text("sneak").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_query() {
         let rtext = text("wifi router");
         let qtext = text("wi fi router").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_query_unfihished() {
         let rtext = text("microbiology");
         let qtext = text("micro bio").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_query_typos() {
         let rtext = text("microbiology");
         let qtext = text("mcro byology").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_query_short() {
         let rtext = text("t-light");
         let qtext = text("tli").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_record() {
         let rtext = text("wi fi router");
         let qtext = text("wifi router").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_record_typos() {
         let rtext = text("micro biology");
         let qtext = text("mcrobiology").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_record_unfinished() {
         let rtext = text("micro biology");
         let qtext = text("microbio").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
     fn match_text_joined_regression_1() {
         let rtext = text("special, year");
         let qtext = text("especiall").fin(false);
-        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 
     #[test]
@@ -272,7 +279,7 @@ mod tests {
         let rtext1 = text("50's");
         let rtext2 = text("500w");
         let qtext  = text("50s").fin(false);
-        assert_debug_snapshot!(text_match(&rtext1.to_ref(), &qtext.to_ref()));
-        assert_debug_snapshot!(text_match(&rtext2.to_ref(), &qtext.to_ref()));
+        assert_debug_snapshot!(text_match(&rtext1.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
+        assert_debug_snapshot!(text_match(&rtext2.to_ref(), &qtext.to_ref(), &MatchConfig::new()));
     }
 }
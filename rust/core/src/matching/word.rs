@@ -1,34 +1,39 @@
 use crate::tokenization::{Word, WordView};
-use super::WordMatch;
+use super::{WordMatch, MatchConfig};
 use super::damlev::DamerauLevenshtein;
 use super::jaccard::Jaccard;
 
 
-const LENGTH_THRESHOLD:  f64 = 0.26;
-const JACCARD_THRESHOLD: f64 = 0.51;
-const DAMLEV_THRESHOLD:  f64 = 0.21;
-
 thread_local! {
     static DAMLEV:  DamerauLevenshtein = DamerauLevenshtein::new();
     static JACCARD: Jaccard<char>      = Jaccard::new();
 }
 
 
-pub fn word_match(rword: &WordView, qword: &WordView) -> Option<(WordMatch, WordMatch)> {
+pub fn word_match(rword: &WordView, qword: &WordView, config: &MatchConfig) -> Option<(WordMatch, WordMatch)> {
+    fuzzy_match(rword, qword, config)
+        .or_else(|| phonetic_match(rword, qword, config))
+}
+
+
+fn fuzzy_match(rword: &WordView, qword: &WordView, config: &MatchConfig) -> Option<(WordMatch, WordMatch)> {
     if qword.is_empty() || rword.is_empty() {
         return None;
     }
-    if !length_check(rword, qword) {
+
+    // Typo budget for the query length replaces the relative thresholds, pre-checks included.
+    let budget = config.typo_budget(qword.len());
+    if budget.is_none() && !length_check(rword, qword, config) {
         return None;
     }
-    if !jaccard_check(rword, qword) {
+    if budget.is_none() && !jaccard_check(rword, qword, config) {
         return None;
     }
 
     let mut best_match: Option<(WordMatch, WordMatch)> = None;
 
     DAMLEV.with(|damlev| {
-        damlev.distance(qword, rword);
+        damlev.distance(qword, rword, config);
         let dists = &*damlev.dists.borrow();
 
         let left  = if qword.fin { max!(qword.stem, rword.stem) } else { qword.stem } - 1;
@@ -54,8 +59,11 @@ pub fn word_match(rword: &WordView, qword: &WordView) -> Option<(WordMatch, Word
                 // Damlev matrix got extra 2 cols/rows, so for chars[i] get row[i+2].
                 let dist = dists.get(qslice + 1, rslice + 1);
 
-                let rel = dist / max!(qslice, rslice, 1) as f64;
-                if rel > DAMLEV_THRESHOLD { continue; }
+                let within = match budget {
+                    Some(budget) => dist <= budget,
+                    None         => dist / max!(qslice, rslice, 1) as f64 <= config.damlev_threshold,
+                };
+                if !within { continue; }
 
                 best_match = best_match
                     .take()
@@ -79,7 +87,23 @@ pub fn word_match(rword: &WordView, qword: &WordView) -> Option<(WordMatch, Word
 }
 
 
-pub fn length_check(rword: &WordView, qword: &WordView) -> bool {
+fn phonetic_match(rword: &WordView, qword: &WordView, config: &MatchConfig) -> Option<(WordMatch, WordMatch)> {
+    let typos = config.phonetic?;
+    let rkey  = rword.phone?;
+    let qkey  = qword.phone?;
+    if rkey != qkey {
+        return None;
+    }
+    // Typos are subtracted twice from the match length, keep it positive.
+    let min_len = 2 * (typos.ceil() as usize) + 1;
+    if rword.len() < min_len || qword.len() < min_len {
+        return None;
+    }
+    Some(WordMatch::new_pair(rword, qword, rword.len(), qword.len(), typos))
+}
+
+
+pub fn length_check(rword: &WordView, qword: &WordView, config: &MatchConfig) -> bool {
     let qlen  = qword.len();
     let rlen  = if qword.fin { rword.len() } else { min!(qlen, rword.len()) };
 
@@ -91,18 +115,18 @@ pub fn length_check(rword: &WordView, qword: &WordView) -> bool {
     let short = min!(qlen, rlen);
     let dist  = 1.0 - (short as f64 / long as f64);
 
-    dist < LENGTH_THRESHOLD
+    dist < config.length_threshold
 }
 
 
-pub fn jaccard_check(rword: &WordView, qword: &WordView) -> bool {
+pub fn jaccard_check(rword: &WordView, qword: &WordView, config: &MatchConfig) -> bool {
     let rslice = if qword.fin {
         rword.chars()
     } else {
         &rword.chars()[.. min!(qword.len() + 1, rword.len())]
     };
     let dist   = JACCARD.with(|j| j.rel_dist(rslice, qword.chars()));
-    dist < JACCARD_THRESHOLD
+    dist < config.jaccard_threshold
 }
 
 
@@ -110,7 +134,9 @@ pub fn jaccard_check(rword: &WordView, qword: &WordView) -> bool {
 mod tests {
     use insta::assert_debug_snapshot;
     use crate::tokenization::TextOwn;
-    use crate::lang::{Lang, CharClass, lang_english};
+    use crate::lang::{Lang, CharClass};
+    #[cfg(feature = "en")] use crate::lang::lang_english;
+    use crate::matching::MatchConfig;
     use super::{length_check, jaccard_check, word_match};
 
 
@@ -133,7 +159,7 @@ mod tests {
         for &(expect, query) in sample.iter() {
             let rtext  = text("mail");
             let qtext  = text(query);
-            let result = length_check(&rtext.view(0), &qtext.view(0));
+            let result = length_check(&rtext.view(0), &qtext.view(0), &MatchConfig::new());
             assert_eq!(result, expect, "Failed length_check(\"mail\", \"{}\") == {}", query, expect);
         }
     }
@@ -155,7 +181,7 @@ mod tests {
         for &(expect, query) in sample.iter() {
             let rtext  = text("mailbox");
             let qtext  = text(query);
-            let result = length_check(&rtext.view(0), &qtext.view(0));
+            let result = length_check(&rtext.view(0), &qtext.view(0), &MatchConfig::new());
             assert_eq!(result, expect, "Failed length_check(\"mailbox\", \"{}\") == {}", query, expect);
         }
     }
@@ -172,7 +198,7 @@ mod tests {
         for &(expect, query) in sample.iter() {
             let rtext  = text("mail");
             let qtext  = text(query);
-            let result = jaccard_check(&rtext.view(0), &qtext.view(0));
+            let result = jaccard_check(&rtext.view(0), &qtext.view(0), &MatchConfig::new());
             assert_eq!(result, expect, "Failed jaccard_check(\"mail\", \"{}\") == {}", query, expect);
         }
     }
@@ -189,7 +215,7 @@ mod tests {
         for &(expect, query) in sample.iter() {
             let rtext  = text("mailbox");
             let qtext  = text(query);
-            let result = jaccard_check(&rtext.view(0), &qtext.view(0));
+            let result = jaccard_check(&rtext.view(0), &qtext.view(0), &MatchConfig::new());
             assert_eq!(result, expect, "Failed jaccard_check(\"mailbox\", \"{}\") == {}", query, expect);
         }
     }
@@ -206,7 +232,7 @@ mod tests {
         for &(expect, query) in sample.iter() {
             let rtext  = text("mailbox");
             let qtext  = text(query);
-            let result = jaccard_check(&rtext.view(0), &qtext.view(0));
+            let result = jaccard_check(&rtext.view(0), &qtext.view(0), &MatchConfig::new());
             assert_eq!(result, expect, "Failed jaccard_check(\"mailbox\", \"{}\") == {}", query, expect);
         }
     }
@@ -227,7 +253,7 @@ mod tests {
         for &(expect, query) in sample.iter() {
             let rtext  = text("mail");
             let qtext  = text(query).fin(false);
-            let result = jaccard_check(&rtext.view(0), &qtext.view(0));
+            let result = jaccard_check(&rtext.view(0), &qtext.view(0), &MatchConfig::new());
             assert_eq!(result, expect, "Failed jaccard_check(\"mail\", \"{}\") == {}", query, expect);
         }
     }
@@ -246,7 +272,7 @@ mod tests {
         for &(expect, query) in sample.iter() {
             let rtext  = text("mail");
             let qtext  = text(query).fin(false);
-            let result = length_check(&rtext.view(0), &qtext.view(0));
+            let result = length_check(&rtext.view(0), &qtext.view(0), &MatchConfig::new());
             assert_eq!(result, expect, "Failed length_check(\"mail\", \"{}\") == {}", query, expect);
         }
     }
@@ -258,7 +284,7 @@ mod tests {
     fn match_word_empty_both() {
         let qtext  = TextOwn::from_str("");
         let rtext  = TextOwn::from_str("");
-        assert_eq!(word_match(&rtext.view(0), &qtext.view(0)), None);
+        assert_eq!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()), None);
     }
 
 
@@ -266,7 +292,7 @@ mod tests {
     fn match_word_empty_record() {
         let qtext  = TextOwn::from_str("mailbox").fin(false);
         let rtext  = TextOwn::from_str("");
-        assert_eq!(word_match(&rtext.view(0), &qtext.view(0)), None);
+        assert_eq!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()), None);
     }
 
 
@@ -274,7 +300,7 @@ mod tests {
     fn match_word_empty_query() {
         let qtext  = TextOwn::from_str("").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_eq!(word_match(&rtext.view(0), &qtext.view(0)), None);
+        assert_eq!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()), None);
     }
 
 
@@ -285,7 +311,7 @@ mod tests {
     fn match_word_full_strict() {
         let qtext  = TextOwn::from_str("mailbox").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
     }
 
 
@@ -293,7 +319,7 @@ mod tests {
     fn match_word_full_fuzzy_insertion() {
         let qtext  = TextOwn::from_str("mailybox").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
     }
 
 
@@ -301,7 +327,7 @@ mod tests {
     fn match_word_full_fuzzy_deletion() {
         let qtext  = TextOwn::from_str("mailox").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
     }
 
 
@@ -309,7 +335,7 @@ mod tests {
     fn match_word_full_fuzzy_transposition() {
         let qtext  = TextOwn::from_str("maiblox").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
     }
 
 
@@ -318,18 +344,19 @@ mod tests {
         let qtext1 = TextOwn::from_str("mailboxes").fin(true);
         let qtext2 = TextOwn::from_str("mailboxes").fin(false);
         let rtext  = TextOwn::from_str("mail");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext1.view(0)));
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext2.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext1.view(0), &MatchConfig::new()));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext2.view(0), &MatchConfig::new()));
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn match_word_full_stem() {
         let lang   = lang_english();
         let qtext1 = TextOwn::from_str("university").set_stem(&lang);
         let qtext2 = TextOwn::from_str("university");
         let rtext  = TextOwn::from_str("universe").set_stem(&lang);
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext1.view(0)));
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext2.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext1.view(0), &MatchConfig::new()));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext2.view(0), &MatchConfig::new()));
     }
 
 
@@ -340,7 +367,7 @@ mod tests {
     fn match_word_partial_strict() {
         let qtext  = TextOwn::from_str("mailb").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
     }
 
 
@@ -348,7 +375,7 @@ mod tests {
     fn match_word_partial_fuzzy_insertion() {
         let qtext  = TextOwn::from_str("maiylb").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
     }
 
 
@@ -356,7 +383,7 @@ mod tests {
     fn match_word_partial_fuzzy_deletion() {
         let qtext  = TextOwn::from_str("maib").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
     }
 
 
@@ -364,6 +391,98 @@ mod tests {
     fn match_word_partial_fuzzy_transposition() {
         let qtext  = TextOwn::from_str("malib").fin(false);
         let rtext  = TextOwn::from_str("mailbox");
-        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0)));
+        assert_debug_snapshot!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()));
+    }
+
+
+    #[test]
+    fn match_word_typo_budget() {
+        let qtext  = TextOwn::from_str("mailvox");
+        let rtext  = TextOwn::from_str("mailbox");
+        let strict = MatchConfig::new().typo_budgets(&[(0, 0.0), (8, 1.0)]);
+        let auto   = MatchConfig::fuzziness_auto();
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()).is_some());
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &strict).is_none());
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &auto).is_some());
+    }
+
+
+    #[test]
+    fn match_word_typo_budget_looser() {
+        let qtext  = TextOwn::from_str("maitbor");
+        let rtext  = TextOwn::from_str("mailbox");
+        let auto   = MatchConfig::fuzziness_auto();
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()).is_none());
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &auto).is_some());
+    }
+
+
+    #[test]
+    fn match_word_typo_budget_bounds() {
+        let auto   = MatchConfig::fuzziness_auto();
+        let sample = [
+            (false, "cup",    "cp"),
+            (true,  "cup",    "cuo"),
+            (false, "lamps",  "lanpd"),
+            (true,  "lamps",  "lanps"),
+            (false, "basket", "bskes"),
+            (true,  "basket", "bapkes"),
+            (false, "basket", "bspkes"),
+        ];
+        for &(expect, record, query) in sample.iter() {
+            let qtext  = TextOwn::from_str(query);
+            let rtext  = TextOwn::from_str(record);
+            let result = word_match(&rtext.view(0), &qtext.view(0), &auto).is_some();
+            assert_eq!(result, expect, "Failed word_match(\"{}\", \"{}\") == {}", record, query, expect);
+        }
+    }
+
+
+    #[test]
+    fn match_word_damlev_threshold() {
+        let qtext  = TextOwn::from_str("mailvox");
+        let rtext  = TextOwn::from_str("mailbox");
+        let mut config = MatchConfig::new();
+        config.damlev_threshold = 0.1;
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &config).is_none());
+    }
+
+
+    #[test]
+    fn match_word_costs() {
+        let qtext  = TextOwn::from_str("mailvox");
+        let rtext  = TextOwn::from_str("mailbox");
+        let mut config = MatchConfig::new();
+        config.cost_default = 0.5;
+        let (rmatch, _) = word_match(&rtext.view(0), &qtext.view(0), &config).unwrap();
+        assert_eq!(rmatch.typos, 0.5);
+    }
+
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn match_word_phonetic() {
+        let lang   = lang_english();
+        let qtext  = TextOwn::from_str("shampain").set_phone(&lang);
+        let rtext  = TextOwn::from_str("champagne").set_phone(&lang);
+        let config = MatchConfig::new().phonetic(Some(1.0));
+        let (rmatch, qmatch) = word_match(&rtext.view(0), &qtext.view(0), &config).unwrap();
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &MatchConfig::new()).is_none());
+        assert_eq!(rmatch.typos,     1.0);
+        assert_eq!(rmatch.subslice,  (0, 9));
+        assert_eq!(qmatch.subslice,  (0, 8));
+    }
+
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn match_word_phonetic_short() {
+        let lang   = lang_english();
+        let qtext  = TextOwn::from_str("nite").set_phone(&lang);
+        let rtext  = TextOwn::from_str("knight").set_phone(&lang);
+        let cheap  = MatchConfig::new().phonetic(Some(1.0));
+        let costly = MatchConfig::new().phonetic(Some(2.0));
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &cheap).is_some());
+        assert!(word_match(&rtext.view(0), &qtext.view(0), &costly).is_none());
     }
 }
@@ -0,0 +1,90 @@
+use std::fmt::Write;
+use crate::matching::WordMatch;
+
+
+#[derive(Debug)]
+pub struct Explanation {
+    pub id:         usize,
+    pub title:      String,
+    pub field:      String,
+    pub scores:     Vec<(String, isize)>,
+    pub rmatches:   Vec<(String, WordMatch)>,
+    pub qmatches:   Vec<(String, WordMatch)>,
+    pub layout:     bool,
+    pub decided_by: Option<String>,
+}
+
+
+impl Explanation {
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        write!(json, "{{\"id\":{},", self.id).unwrap();
+        write!(json, "\"title\":{},", json_string(&self.title)).unwrap();
+        write!(json, "\"field\":{},", json_string(&self.field)).unwrap();
+
+        json.push_str("\"scores\":{");
+        for (i, (name, score)) in self.scores.iter().enumerate() {
+            if i > 0 { json.push(','); }
+            write!(json, "{}:{}", json_string(name), score).unwrap();
+        }
+        json.push_str("},");
+
+        json.push_str("\"rmatches\":");
+        json_matches(&mut json, &self.rmatches);
+        json.push_str(",\"qmatches\":");
+        json_matches(&mut json, &self.qmatches);
+        write!(json, ",\"layout\":{}", self.layout).unwrap();
+
+        match &self.decided_by {
+            Some(name) => write!(json, ",\"decided_by\":{}}}", json_string(name)).unwrap(),
+            None       => json.push_str(",\"decided_by\":null}"),
+        }
+        json
+    }
+}
+
+
+pub fn explanations_to_json(explanations: &[Explanation]) -> String {
+    let items = explanations.iter()
+        .map(|e| e.to_json())
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}
+
+
+fn json_matches(json: &mut String, matches: &[(String, WordMatch)]) {
+    json.push('[');
+    for (i, (word, m)) in matches.iter().enumerate() {
+        if i > 0 { json.push(','); }
+        write!(json,
+            "{{\"word\":{},\"offset\":{},\"slice\":[{},{}],\"subslice\":[{},{}],\"typos\":{},\"func\":{},\"fin\":{}}}",
+            json_string(word),
+            m.offset,
+            m.slice.0,    m.slice.1,
+            m.subslice.0, m.subslice.1,
+            m.typos,
+            m.func,
+            m.fin,
+        ).unwrap();
+    }
+    json.push(']');
+}
+
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"'  => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => write!(escaped, "\\u{:04x}", ch as u32).unwrap(),
+            ch   => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
@@ -0,0 +1,82 @@
+use std::cmp::Ordering;
+use fnv::{FnvHashMap as HashMap};
+use crate::store::{Record, Attr};
+
+
+pub struct Facet<'a> {
+    name:   &'a str,
+    counts: HashMap<&'a str, usize>,
+}
+
+
+impl<'a> Facet<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            counts: HashMap::default(),
+        }
+    }
+
+    pub fn add(&mut self, record: &'a Record) {
+        let Self { name, counts } = self;
+        match record.get_attr(name) {
+            Some(Attr::Keyword(value)) => {
+                *counts.entry(value).or_insert(0) += 1;
+            },
+            Some(Attr::Keywords(values)) => {
+                for (i, value) in values.iter().enumerate() {
+                    if values[..i].contains(value) { continue; }
+                    *counts.entry(value).or_insert(0) += 1;
+                }
+            },
+            _ => {},
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<(String, usize)> {
+        let mut counts = self.counts
+            .iter()
+            .map(|(&value, &count)| (value.to_string(), count))
+            .collect::<Vec<_>>();
+        counts.sort_by(|(value1, count1), (value2, count2)| {
+            match count2.cmp(count1) {
+                Ordering::Equal => value1.cmp(value2),
+                ord             => ord,
+            }
+        });
+        counts
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::lang::Lang;
+    use crate::store::{Record, Attr};
+    use super::Facet;
+
+    #[test]
+    fn facet_counts() {
+        let lang = Lang::new();
+        let mut records = [
+            Record::new(10, "brown plush bear",     10, &lang),
+            Record::new(20, "the metal detector",   20, &lang),
+            Record::new(30, "yellow metal mailbox", 30, &lang),
+            Record::new(40, "thesaurus",            40, &lang),
+        ];
+        records[0].set_attr("category", Attr::keyword("toys"));
+        records[1].set_attr("category", Attr::keywords(&["garden", "tools", "garden"]));
+        records[2].set_attr("category", Attr::keywords(&["garden"]));
+        records[3].set_attr("category", Attr::Int(1));
+
+        let mut facet = Facet::new("category");
+        for record in &records {
+            facet.add(record);
+        }
+        assert_eq!(facet.to_vec(), vec![
+            ("garden".to_string(), 2),
+            ("tools".to_string(),  1),
+            ("toys".to_string(),   1),
+        ]);
+    }
+}
@@ -1,6 +1,70 @@
 use crate::tokenization::TextRef;
+use crate::store::{Record, Attr};
 use crate::search::Hit;
 
+
+#[derive(Clone, Debug)]
+pub enum Filter {
+    Eq(String, Attr),
+    In(String, Vec<Attr>),
+    Range(String, Option<i64>, Option<i64>),
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    Not(Box<Filter>),
+}
+
+
+impl Filter {
+    pub fn eq(name: &str, value: Attr) -> Filter {
+        Filter::Eq(name.to_string(), value)
+    }
+
+    pub fn one_of(name: &str, values: Vec<Attr>) -> Filter {
+        Filter::In(name.to_string(), values)
+    }
+
+    pub fn range(name: &str, min: Option<i64>, max: Option<i64>) -> Filter {
+        Filter::Range(name.to_string(), min, max)
+    }
+
+    pub fn and(filters: Vec<Filter>) -> Filter {
+        Filter::And(filters)
+    }
+
+    pub fn or(filters: Vec<Filter>) -> Filter {
+        Filter::Or(filters)
+    }
+
+    pub fn negate(filter: Filter) -> Filter {
+        Filter::Not(Box::new(filter))
+    }
+
+    pub fn matches(&self, record: &Record) -> bool {
+        match self {
+            Filter::Eq(name, value) => {
+                record.get_attr(name)
+                    .map(|attr| attr.contains(value))
+                    .unwrap_or(false)
+            },
+            Filter::In(name, values) => {
+                record.get_attr(name)
+                    .map(|attr| values.iter().any(|value| attr.contains(value)))
+                    .unwrap_or(false)
+            },
+            Filter::Range(name, min, max) => {
+                match record.get_attr(name).and_then(|attr| attr.as_int()) {
+                    Some(x) => x >= min.unwrap_or(i64::MIN) && x <= max.unwrap_or(i64::MAX),
+                    None    => false,
+                }
+            },
+            Filter::And(filters) => filters.iter().all(|f| f.matches(record)),
+            Filter::Or(filters)  => filters.iter().any(|f| f.matches(record)),
+            Filter::Not(filter)  => !filter.matches(record),
+        }
+    }
+}
+
+
 pub fn hit_matches(query: &TextRef, hit: &Hit) -> bool {
     if query.is_empty() { return true; }
     if hit.rmatches.len() == 0 { return false; }
@@ -12,3 +76,67 @@ pub fn hit_matches(query: &TextRef, hit: &Hit) -> bool {
     }
     true
 }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::lang::Lang;
+    use crate::store::{Record, Attr};
+    use super::Filter;
+
+    fn get_record() -> Record {
+        let mut record = Record::new(10, "garden shovel", 10, &Lang::new());
+        record.set_attr("stock",    Attr::Int(3));
+        record.set_attr("tenant",   Attr::keyword("acme"));
+        record.set_attr("category", Attr::keywords(&["garden", "garden/tools"]));
+        record
+    }
+
+    #[test]
+    fn filter_eq() {
+        let record = get_record();
+        assert!( Filter::eq("stock",    Attr::Int(3)).matches(&record));
+        assert!(!Filter::eq("stock",    Attr::Int(4)).matches(&record));
+        assert!( Filter::eq("tenant",   Attr::keyword("acme")).matches(&record));
+        assert!(!Filter::eq("tenant",   Attr::keyword("other")).matches(&record));
+        assert!( Filter::eq("category", Attr::keyword("garden/tools")).matches(&record));
+        assert!(!Filter::eq("category", Attr::keyword("kitchen")).matches(&record));
+        assert!(!Filter::eq("missing",  Attr::Int(3)).matches(&record));
+    }
+
+    #[test]
+    fn filter_in() {
+        let record = get_record();
+        let acme   = vec![Attr::keyword("foo"), Attr::keyword("acme")];
+        let other  = vec![Attr::keyword("foo"), Attr::keyword("bar")];
+        let garden = vec![Attr::keyword("kitchen"), Attr::keyword("garden")];
+        assert!( Filter::one_of("tenant",   acme).matches(&record));
+        assert!(!Filter::one_of("tenant",   other).matches(&record));
+        assert!( Filter::one_of("category", garden).matches(&record));
+        assert!(!Filter::one_of("stock",    vec![]).matches(&record));
+    }
+
+    #[test]
+    fn filter_range() {
+        let record = get_record();
+        assert!( Filter::range("stock",  Some(1), None).matches(&record));
+        assert!( Filter::range("stock",  Some(3), Some(3)).matches(&record));
+        assert!(!Filter::range("stock",  Some(4), None).matches(&record));
+        assert!(!Filter::range("stock",  None,    Some(2)).matches(&record));
+        assert!( Filter::range("stock",  None,    None).matches(&record));
+        assert!(!Filter::range("tenant", None,    None).matches(&record));
+    }
+
+    #[test]
+    fn filter_logic() {
+        let record  = get_record();
+        let instock = || Filter::range("stock", Some(1), None);
+        let kitchen = || Filter::eq("category", Attr::keyword("kitchen"));
+        assert!(!Filter::and(vec![instock(), kitchen()]).matches(&record));
+        assert!( Filter::or(vec![instock(), kitchen()]).matches(&record));
+        assert!( Filter::negate(kitchen()).matches(&record));
+        assert!(!Filter::negate(instock()).matches(&record));
+        assert!( Filter::and(vec![]).matches(&record));
+        assert!(!Filter::or(vec![]).matches(&record));
+    }
+}
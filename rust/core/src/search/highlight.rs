@@ -5,7 +5,7 @@ use crate::search::Hit;
 pub fn highlight(hit: &Hit, dividers: (&[char], &[char])) -> String {
     let (div_left, div_right) = dividers;
     let Hit {
-        title: Text { words, source, .. },
+        text: Text { words, source, marks, .. },
         rmatches,
         ..
     } = hit;
@@ -16,25 +16,33 @@ pub fn highlight(hit: &Hit, dividers: (&[char], &[char])) -> String {
         String::with_capacity((chars_src + chars_hl) * 4)
     };
 
-    let mut char_offset = 0;
-    for (word_offset, word) in words.iter().enumerate() {
-        match rmatches.iter().find(|m| m.offset == word_offset) {
-            Some(rmatch) => {
-                let match_start = word.slice.0 + rmatch.subslice.0;
-                let match_end   = word.slice.0 + rmatch.subslice.1;
-                highlighted.extend(&source[char_offset .. match_start]);
-                highlighted.extend(div_left);
-                highlighted.extend(&source[match_start .. match_end]);
-                highlighted.extend(div_right);
-                highlighted.extend(&source[match_end .. word.slice.1]);
-            },
-            None => {
-                highlighted.extend(&source[char_offset .. word.slice.1]);
-            },
+    // Matched chars are marked first, so that adjacent matches, like parts of a compound word,
+    // and overlapping ones, like CJK bigrams, share one pair of dividers.
+    let mut marked = vec![false; source.len()];
+    for rmatch in rmatches.iter() {
+        let left = words[rmatch.offset].slice.0;
+        for flag in &mut marked[left + rmatch.subslice.0 .. left + rmatch.subslice.1] {
+            *flag = true;
         }
-        char_offset = word.slice.1;
     }
-    highlighted.extend(&source[char_offset .. ]);
+
+    // Chars dropped by unicode reduction go back after the char they followed, inside its highlight.
+    let mut marks  = marks.iter().peekable();
+    let mut inside = false;
+    for (i, (&ch, &flag)) in source.iter().zip(marked.iter()).enumerate() {
+        while let Some(&(_, mark)) = marks.next_if(|&&(pos, _)| pos == i) {
+            highlighted.push(mark);
+        }
+        if flag != inside {
+            highlighted.extend(if flag { div_left } else { div_right });
+            inside = flag;
+        }
+        highlighted.push(ch);
+    }
+    highlighted.extend(marks.map(|&(_, mark)| mark));
+    if inside {
+        highlighted.extend(div_right);
+    }
     highlighted.retain(|ch| ch != '\0');
 
     highlighted
@@ -46,7 +54,10 @@ mod tests {
     use crate::matching::WordMatch;
     use crate::store::Record;
     use crate::search::Hit;
-    use crate::lang::{Lang, lang_german, lang_portuguese};
+    use crate::lang::Lang;
+    #[cfg(any(feature = "ja", feature = "zh"))] use crate::lang::lang_cjk;
+    #[cfg(feature = "de")]                      use crate::lang::lang_german;
+    #[cfg(feature = "pt")]                      use crate::lang::lang_portuguese;
     use super::highlight;
 
     const L: &[char] = &['['];
@@ -72,12 +83,32 @@ mod tests {
         (rmatch, qmatch)
     }
 
+    #[cfg(any(feature = "ja", feature = "zh"))]
+    #[test]
+    fn highlight_bigrams() {
+        let lang   = lang_cjk();
+        let record = Record::new(10, "東京タワー", 0, &lang);
+
+        let mut hit = Hit::from_field(&record, 0);
+        for &offset in &[1, 2] {
+            let (rmatch, qmatch) = mock_match(offset, 2);
+            hit.rmatches.push(rmatch);
+            hit.qmatches.push(qmatch);
+        }
+        assert_eq!(&highlight(&hit, (L, R)), "東[京タワ]ー");
+
+        let (rmatch, qmatch) = mock_match(3, 1);
+        hit.rmatches.push(rmatch);
+        hit.qmatches.push(qmatch);
+        assert_eq!(&highlight(&hit, (L, R)), "東[京タワ]ー");
+    }
+
     #[test]
     fn highlight_basic() {
         let lang   = Lang::new();
         let record = Record::new(10, "metal detector", 0, &lang);
 
-        let mut hit = Hit::from_record(&record);
+        let mut hit = Hit::from_field(&record, 0);
         let (rmatch, qmatch) = mock_match(1, 6);
         hit.rmatches.push(rmatch);
         hit.qmatches.push(qmatch);
@@ -93,7 +124,7 @@ mod tests {
         let lang   = Lang::new();
         let record = Record::new(10, "'metal' mailbox!", 0, &lang);
 
-        let mut hit = Hit::from_record(&record);
+        let mut hit = Hit::from_field(&record, 0);
         let (rmatch, qmatch) = mock_match(0, 5);
         hit.rmatches.push(rmatch);
         hit.qmatches.push(qmatch);
@@ -109,7 +140,7 @@ mod tests {
         let lang   = Lang::new();
         let record = Record::new(10, "metal detector", 0, &lang);
 
-        let mut hit = Hit::from_record(&record);
+        let mut hit = Hit::from_field(&record, 0);
         let (rmatch, qmatch) = mock_match(1, 6);
         hit.rmatches.push(rmatch);
         hit.qmatches.push(qmatch);
@@ -123,12 +154,31 @@ mod tests {
         assert_eq!(&received, expected);
     }
 
+    #[cfg(feature = "de")]
+    #[test]
+    fn highlight_compound() {
+        let lang   = lang_german();
+        let record = Record::new(10, "Kaffeemaschine", 0, &lang);
+
+        let mut hit = Hit::from_field(&record, 0);
+        let (rmatch, qmatch) = mock_match(1, 8);
+        hit.rmatches.push(rmatch);
+        hit.qmatches.push(qmatch);
+        assert_eq!(&highlight(&hit, (L, R)), "Kaffee[maschine]");
+
+        let (rmatch, qmatch) = mock_match(0, 6);
+        hit.rmatches.push(rmatch);
+        hit.qmatches.push(qmatch);
+        assert_eq!(&highlight(&hit, (L, R)), "[Kaffeemaschine]");
+    }
+
+    #[cfg(feature = "de")]
     #[test]
     fn highlight_utf_padded() {
         let lang   = lang_german();
         let record = Record::new(10, "Passstraße", 0, &lang);
 
-        let mut hit = Hit::from_record(&record);
+        let mut hit = Hit::from_field(&record, 0);
         let (rmatch, qmatch) = mock_match(0, 9);
         hit.rmatches.push(rmatch);
         hit.qmatches.push(qmatch);
@@ -139,12 +189,13 @@ mod tests {
         assert_eq!(&received, expected);
     }
 
+    #[cfg(feature = "pt")]
     #[test]
     fn highlight_utf_nfd() {
         let lang   = lang_portuguese();
         let record = Record::new(10, "Passstraße", 0, &lang);
 
-        let mut hit = Hit::from_record(&record);
+        let mut hit = Hit::from_field(&record, 0);
         let (rmatch, qmatch) = mock_match(0, 9);
         hit.rmatches.push(rmatch);
         hit.qmatches.push(qmatch);
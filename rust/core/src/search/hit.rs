@@ -7,24 +7,37 @@ use super::score::Scores;
 
 #[derive(Debug)]
 pub struct Hit<'a> {
-    pub id:      usize,
-    pub title:   TextRef<'a>,
-    pub rating:  usize,
+    pub ix:       usize,
+    pub id:       usize,
+    pub field:    usize,
+    pub text:     TextRef<'a>,
+    pub rating:   usize,
     pub rmatches: Vec<WordMatch>,
     pub qmatches: Vec<WordMatch>,
-    pub scores:  Scores,
+    pub scores:   Scores,
+    pub variant:  Option<usize>,
 }
 
 
 impl<'a> Hit<'a> {
-    pub fn from_record(record: &'a Record) -> Hit<'a> {
+    pub fn from_field(record: &'a Record, field: usize) -> Hit<'a> {
         Hit {
+            ix:       record.ix,
             id:       record.id,
-            title:    record.title.to_ref(),
+            field,
+            text:     record.fields[field].text.to_ref(),
             rating:   record.rating,
             scores:   Default::default(),
             rmatches: Vec::new(),
             qmatches: Vec::new(),
+            variant:  None,
         }
     }
+
+    pub fn from_translit(record: &'a Record, field: usize) -> Option<Hit<'a>> {
+        let translit = record.fields[field].translit.as_ref()?;
+        let mut hit  = Self::from_field(record, field);
+        hit.text = translit.to_ref();
+        Some(hit)
+    }
 }
@@ -3,81 +3,510 @@ mod score;
 mod result;
 mod filter;
 mod sort;
+mod scorer;
 mod highlight;
+mod facet;
+mod explain;
+mod variant;
 
+use std::cmp::Ordering;
+use fnv::{FnvHashMap as HashMap};
 use crate::utils::LimitSort;
-use crate::tokenization::TextRef;
-use crate::store::Store;
+use crate::tokenization::{TextRef, tokenize_query};
+use crate::matching::text_match;
+use crate::store::{Store, Record, Field};
+use crate::matching::WordMatch;
+use sort::Decision;
+use facet::Facet;
+use variant::QueryVariant;
 pub use hit::Hit;
-pub use result::SearchResult;
+pub use result::{SearchResult, SearchResults};
+pub use filter::Filter;
+pub use score::ScoreType;
+pub use sort::RankingConfig;
+pub use scorer::Scorer;
+pub use explain::{Explanation, explanations_to_json};
 
 
 impl Store {
     pub fn search<'a>(
         &'a self,
-        query: &'a TextRef<'a>,
+        query:  &'a TextRef<'a>,
+        filter: Option<&Filter>,
     ) -> Vec<SearchResult> {
-        let dividers = self.dividers();
+        let variants = self.query_variants(query);
+        let vqueries = variants.iter().map(|v| v.text.to_ref()).collect::<Vec<_>>();
+        self.search_hits(query, &variants, filter, self.limit)
+            .into_iter()
+            .map(|hit| {
+                self.to_result(hit_query(&hit, query, &vqueries), hit)
+            })
+            .collect()
+    }
+
+    pub fn search_explain<'a>(
+        &'a self,
+        query:  &'a TextRef<'a>,
+        filter: Option<&Filter>,
+    ) -> Vec<Explanation> {
+        let variants  = self.query_variants(query);
+        let vqueries  = variants.iter().map(|v| v.text.to_ref()).collect::<Vec<_>>();
+        let hits      = self.search_hits(query, &variants, filter, self.limit);
+        let decisions = hits.windows(2)
+            .map(|pair| self.ranking.decide(&pair[0], &pair[1]))
+            .collect::<Vec<_>>();
+
+        hits.into_iter()
+            .enumerate()
+            .map(|(i, hit)| {
+                let decided_by = match decisions.get(i) {
+                    Some(Decision::Score(score)) => Some(self.score_name(*score).to_string()),
+                    Some(Decision::Linear)       => Some("linear".to_string()),
+                    Some(Decision::Tie) | None   => None,
+                };
+                let scores = ScoreType::ALL.iter()
+                    .cloned()
+                    .chain((0 .. self.scorers.len()).map(ScoreType::Custom))
+                    .map(|score| (self.score_name(score).to_string(), hit.scores[score]))
+                    .collect();
+                let query    = hit_query(&hit, query, &vqueries);
+                let layout   = match hit.variant {
+                    Some(v) => variants[v].layout,
+                    None    => false,
+                };
+                let rmatches = match_words(&hit.text, &hit.rmatches);
+                let qmatches = match_words(query,     &hit.qmatches);
+                let result   = self.to_result(query, hit);
+                Explanation {
+                    id:    result.id,
+                    title: result.title,
+                    field: result.field,
+                    scores,
+                    rmatches,
+                    qmatches,
+                    layout,
+                    decided_by,
+                }
+            })
+            .collect()
+    }
+
+    pub fn score_name(&self, score: ScoreType) -> &str {
+        match score {
+            ScoreType::Custom(ix) => &self.scorers[ix].0,
+            _                     => score.name(),
+        }
+    }
 
-        let ixs = if query.words.len() > 0 {
-            self.index.borrow_mut().prepare(&query, self.limit)
+    // Builtin or custom score by name, see Store::add_scorer.
+    pub fn score_type(&self, name: &str) -> Option<ScoreType> {
+        ScoreType::from_name(name).or_else(|| {
+            self.scorers.iter()
+                .position(|(custom, _)| custom == name)
+                .map(ScoreType::Custom)
+        })
+    }
+
+    pub fn search_page<'a>(
+        &'a self,
+        query:  &'a TextRef<'a>,
+        filter: Option<&Filter>,
+        offset: usize,
+        limit:  usize,
+    ) -> SearchResults {
+        self.search_page_facet(query, filter, None, offset, limit)
+    }
+
+    pub fn search_facet<'a>(
+        &'a self,
+        query:  &'a TextRef<'a>,
+        filter: Option<&Filter>,
+        facet:  &str,
+    ) -> SearchResults {
+        // Same candidates as Store::search, so that a facet doesn't change the results.
+        self.facet_results(query, filter, Some(facet), 0, self.limit, Some(self.limit))
+    }
+
+    pub fn search_page_facet<'a>(
+        &'a self,
+        query:  &'a TextRef<'a>,
+        filter: Option<&Filter>,
+        facet:  Option<&str>,
+        offset: usize,
+        limit:  usize,
+    ) -> SearchResults {
+        // Pages are cut from every matching record, so that they neither overlap nor skip records.
+        self.facet_results(query, filter, facet, offset, limit, None)
+    }
+
+    // Facet counts and total are taken over the same candidates as the results.
+    fn facet_results<'a>(
+        &'a self,
+        query:  &'a TextRef<'a>,
+        filter: Option<&Filter>,
+        facet:  Option<&str>,
+        offset: usize,
+        limit:  usize,
+        pool:   Option<usize>,
+    ) -> SearchResults {
+        let variants = self.query_variants(query);
+        let vqueries = variants.iter().map(|v| v.text.to_ref()).collect::<Vec<_>>();
+
+        let hits   = self.merged_hits(query, &variants, filter, pool);
+        let total  = hits.len();
+        let facets = match facet {
+            Some(facet) => {
+                let mut counts = Facet::new(facet);
+                for hit in hits.iter() {
+                    counts.add(&self.records[hit.ix]);
+                }
+                counts.to_vec()
+            },
+            None => Vec::new(),
+        };
+        let results = self.sort_hits(query, hits, offset + limit)
+            .into_iter()
+            .skip(offset)
+            .map(|hit| {
+                self.to_result(hit_query(&hit, query, &vqueries), hit)
+            })
+            .collect();
+
+        SearchResults {
+            results,
+            facets,
+            total,
+        }
+    }
+
+    fn query_variants(&self, query: &TextRef) -> Vec<QueryVariant> {
+        let mut variants = Vec::new();
+        if query.is_empty() {
+            return variants;
+        }
+
+        let views     = (0 .. query.words.len()).map(|i| query.view(i)).collect::<Vec<_>>();
+        let words     = views.iter().map(|w| w.chars()).collect::<Vec<_>>();
+        let max_chars = words.iter().map(|w| w.len()).sum();
+
+        let source = query_source(query);
+        let langs  = self.query_langs(&source);
+        if !langs.contains(&0) {
+            return self.lang_variants(&source, &langs, max_chars);
+        }
+
+        // Same query typed with the keyboard switched to the other layout, e.g. "vfufpby" for "магазин".
+        if let Some(remapped) = self.lang.remap_layout(&source) {
+            variants.push(QueryVariant {
+                text:          tokenize_query(&remapped, &self.lang),
+                layout:        true,
+                substitutions: 0,
+                max_chars,
+                lang:          None,
+            });
+        }
+
+        // Same query romanized, e.g. "samsung" for "самсунг".
+        if let Some((_, romanized, _)) = self.lang.transliterate(query.chars, query.chars, &[]) {
+            variants.push(QueryVariant {
+                text:          tokenize_query(&romanized.iter().collect::<String>(), &self.lang),
+                layout:        false,
+                substitutions: 0,
+                max_chars,
+                lang:          None,
+            });
+        }
+
+        for synonym in self.lang.synonym_variants(&words) {
+            variants.push(QueryVariant {
+                text:          tokenize_query(&synonym, &self.lang),
+                layout:        false,
+                substitutions: 1,
+                max_chars,
+                lang:          None,
+            });
+        }
+
+        variants.extend(self.lang_variants(&source, &langs, max_chars));
+        variants
+    }
+
+    // Same query tokenized with other probable langs, matched against records in that lang.
+    fn lang_variants(&self, source: &str, langs: &[usize], max_chars: usize) -> Vec<QueryVariant> {
+        langs.iter()
+            .filter(|&&id| id > 0)
+            .map(|&id| QueryVariant {
+                text:          tokenize_query(source, self.lang_by_id(id)),
+                layout:        false,
+                substitutions: 0,
+                max_chars,
+                lang:          Some(id),
+            })
+            .collect()
+    }
+
+    // Probable langs of the query, just the main one if the store has no other.
+    fn query_langs(&self, source: &str) -> Vec<usize> {
+        if self.langs.is_empty() {
+            vec![0]
+        } else {
+            self.detect_langs(source)
+        }
+    }
+
+    fn search_hits<'a>(
+        &'a self,
+        query:    &TextRef,
+        variants: &[QueryVariant],
+        filter:   Option<&Filter>,
+        size:     usize,
+    ) -> Vec<Hit<'a>> {
+        let hits = self.merged_hits(query, variants, filter, Some(size));
+        self.sort_hits(query, hits, size)
+    }
+
+    // Candidates are picked from the trigram index for `pool` results, or all of them for None.
+    fn merged_hits<'a>(
+        &'a self,
+        query:    &TextRef,
+        variants: &[QueryVariant],
+        filter:   Option<&Filter>,
+        pool:     Option<usize>,
+    ) -> Vec<Hit<'a>> {
+        if query.is_empty() {
+            return self.query_hits(query, None, filter, pool);
+        }
+
+        // Query in the main lang is skipped when another lang is detected, like its variants.
+        let mut hits = if self.query_langs(&query_source(query)).contains(&0) {
+            self.query_hits(query, None, filter, pool)
         } else {
-            self.top_ixs()
+            Vec::new()
+        };
+
+        // Records matched by several queries keep the better hit, the original one on a tie.
+        let mut positions = hits.iter()
+            .enumerate()
+            .map(|(pos, hit)| (hit.ix, pos))
+            .collect::<HashMap<_, _>>();
+        for (v, variant) in variants.iter().enumerate() {
+            for mut hit in self.query_hits(&variant.text.to_ref(), Some(variant), filter, pool) {
+                hit.variant = Some(v);
+                match positions.get(&hit.ix) {
+                    Some(&pos) => {
+                        if self.ranking.compare(&hit, &hits[pos]) == Ordering::Less {
+                            hits[pos] = hit;
+                        }
+                    },
+                    None => {
+                        positions.insert(hit.ix, hits.len());
+                        hits.push(hit);
+                    },
+                }
+            }
+        }
+
+        hits
+    }
+
+    fn sort_hits<'a>(&self, query: &TextRef, mut hits: Vec<Hit<'a>>, size: usize) -> Vec<Hit<'a>> {
+        // Empty query keeps the order of top records, so that pages are consistent with each other.
+        if query.is_empty() {
+            hits.truncate(size);
+            return hits;
+        }
+
+        // Ties are broken by ix, so that consecutive pages don't overlap.
+        hits.into_iter()
+            .limit_sort_unstable(size, |h1, h2| {
+                self.ranking
+                    .compare(h1, h2)
+                    .then_with(|| h1.variant.cmp(&h2.variant))
+                    .then_with(|| h1.ix.cmp(&h2.ix))
+            })
+            .collect()
+    }
+
+    fn query_hits<'a>(
+        &'a self,
+        query:   &TextRef,
+        variant: Option<&QueryVariant>,
+        filter:  Option<&Filter>,
+        pool:    Option<usize>,
+    ) -> Vec<Hit<'a>> {
+        // Records in other langs are only matched by queries tokenized with their lang.
+        let lang     = Some(variant.and_then(|v| v.lang).unwrap_or(0))
+            .filter(|_| !query.is_empty() && !self.langs.is_empty());
+        let eligible = |ix: usize| self.eligible(ix, lang, filter);
+        let size     = pool.unwrap_or(self.records.len());
+
+        let ixs = match (query.words.len(), filter, pool) {
+            (0, None, _)       => self.top_ixs(query, size),
+            (0, Some(_), _)    => self.rank_top(query, self.records.iter().filter(|r| eligible(r.ix)), size),
+            (_, _, Some(size)) => self.index.borrow_mut().prepare(&query, size, eligible),
+            (_, _, None)       => self.index.borrow_mut().prepare_all(query, eligible),
         };
 
         ixs.iter()
-            .map(|&ix| {
-                Hit::from_record(&self.records[ix])
+            .filter_map(|&ix| {
+                self.best_hit(query, variant, &self.records[ix])
+            })
+            .collect()
+    }
+
+    fn eligible(&self, ix: usize, lang: Option<usize>, filter: Option<&Filter>) -> bool {
+        if let Some(lang) = lang {
+            if self.records[ix].lang != lang {
+                return false;
+            }
+        }
+        match filter {
+            Some(filter) => filter.matches(&self.records[ix]),
+            None         => true,
+        }
+    }
+
+    fn best_hit<'a>(&self, query: &TextRef, variant: Option<&QueryVariant>, record: &'a Record) -> Option<Hit<'a>> {
+        // Empty query matches every field equally, so stick to the title.
+        let nfields = if query.is_empty() { 1 } else { record.fields.len() };
+        (0 .. nfields)
+            .flat_map(|field| {
+                // Romanized text matches across scripts, like "samsung" and "Самсунг".
+                let translit = Some(field)
+                    .filter(|_| !query.is_empty())
+                    .and_then(|field| Hit::from_translit(record, field));
+                Some(Hit::from_field(record, field)).into_iter().chain(translit)
             })
             .map(|mut hit| {
-                score::score(query, &mut hit);
+                score::score(query, &mut hit, &self.matching);
+                if let Some(variant) = variant.filter(|v| v.substitutions > 0) {
+                    score::synonyms(&mut hit, variant.substitutions, variant.max_chars);
+                }
+                if variant.filter(|v| v.layout).is_some() {
+                    score::layout(&mut hit);
+                }
+                let weight = self.field_weight(&record.fields[hit.field].name);
+                score::weigh(&mut hit, weight);
+                for (_, scorer) in self.scorers.iter() {
+                    let custom = scorer.score(&hit, record);
+                    hit.scores.push_custom(custom);
+                }
                 hit
             })
             .filter(|hit| {
                 filter::hit_matches(query, hit)
             })
-            .limit_sort_unstable(self.limit, sort::compare_hits)
-            .map(|hit| {
-                SearchResult {
-                    id:    hit.id,
-                    title: highlight::highlight(&hit, dividers),
-                }
+            .min_by(|h1, h2| self.ranking.compare(h1, h2))
+    }
+
+    fn to_result(&self, query: &TextRef, hit: Hit) -> SearchResult {
+        let dividers = self.dividers();
+        let record   = &self.records[hit.ix];
+
+        let fields = record.fields
+            .iter()
+            .enumerate()
+            .map(|(field, Field { name, text, .. })| {
+                let highlighted = if field == hit.field {
+                    highlight::highlight(&hit, dividers)
+                } else {
+                    let mut field_hit = Hit::from_field(record, field);
+                    field_hit.rmatches = text_match(&text.to_ref(), query, &self.matching).0;
+                    highlight::highlight(&field_hit, dividers)
+                };
+                (name.clone(), highlighted)
             })
-            .collect()
+            .collect::<Vec<_>>();
+
+        SearchResult {
+            id:    hit.id,
+            title: fields[0].1.clone(),
+            field: fields[hit.field].0.clone(),
+            fields,
+        }
     }
 
-    fn top_ixs(&self) -> Vec<usize> {
+    fn top_ixs(&self, query: &TextRef, size: usize) -> Vec<usize> {
         let top_ixs = &mut *self.top_ixs.borrow_mut();
 
-        if let Some(ixs) = top_ixs {
-            return ixs.clone();
+        // Cached order only holds for the ranking it was made with.
+        if let Some((ranking, ixs)) = top_ixs {
+            if *ranking == self.ranking && (ixs.len() >= size || ixs.len() == self.records.len()) {
+                return ixs.iter().cloned().take(size).collect();
+            }
         }
 
-        let ixs = self.records
-            .iter()
+        let ixs = self.rank_top(query, self.records.iter(), size);
+
+        *top_ixs = Some((self.ranking.clone(), ixs.clone()));
+        ixs
+    }
+
+    // Empty query leaves rating and custom scores to the ranking, ties go by title.
+    fn rank_top<'a, I>(&self, query: &TextRef, records: I, size: usize) -> Vec<usize> where I: Iterator<Item=&'a Record> {
+        records
+            .filter_map(|record| self.best_hit(query, None, record))
             .limit_sort_unstable(
-                self.limit,
-                |r1, r2| {
-                    r2.rating
-                        .cmp(&r1.rating)
-                        .then_with(|| r1.title.chars.cmp(&r2.title.chars))
+                size,
+                |h1, h2| {
+                    self.ranking
+                        .compare(h1, h2)
+                        .then_with(|| h1.text.chars.cmp(h2.text.chars))
+                        .then_with(|| h1.ix.cmp(&h2.ix))
                 },
             )
-            .map(|r| r.ix)
-            .collect::<Vec<_>>();
+            .map(|hit| hit.ix)
+            .collect()
+    }
+}
 
-        *top_ixs = Some(ixs.clone());
-        ixs
+
+// Source of the query without the padding.
+fn query_source(query: &TextRef) -> String {
+    query.source
+        .iter()
+        .filter(|&&ch| ch != '\0')
+        .collect()
+}
+
+
+fn hit_query<'q>(hit: &Hit, query: &'q TextRef<'q>, vqueries: &'q [TextRef<'q>]) -> &'q TextRef<'q> {
+    match hit.variant {
+        Some(v) => &vqueries[v],
+        None    => query,
     }
 }
 
 
+fn match_words(text: &TextRef, matches: &[WordMatch]) -> Vec<(String, WordMatch)> {
+    matches.iter()
+        .map(|m| {
+            let word = text.source[m.slice.0 .. m.slice.1].iter().collect();
+            (word, m.clone())
+        })
+        .collect()
+}
+
+
 #[cfg(test)]
 mod tests {
     use insta::assert_debug_snapshot;
     use crate::tokenization::tokenize_query;
-    use crate::lang::{Lang, lang_english, lang_german};
-    use crate::store::{Store, Record};
+    use crate::lang::Lang;
+    #[cfg(feature = "ar")]                                      use crate::lang::lang_arabic;
+    #[cfg(any(feature = "ja", feature = "zh"))]                 use crate::lang::lang_cjk;
+    #[cfg(feature = "en")]                                      use crate::lang::lang_english;
+    #[cfg(all(feature = "de", feature = "fr", feature = "it"))] use crate::lang::lang_french;
+    #[cfg(feature = "de")]                                      use crate::lang::lang_german;
+    #[cfg(feature = "el")]                                      use crate::lang::lang_greek;
+    #[cfg(feature = "he")]                                      use crate::lang::lang_hebrew;
+    #[cfg(feature = "it")]                                      use crate::lang::lang_italian;
+    #[cfg(feature = "pl")]                                      use crate::lang::lang_polish;
+    #[cfg(feature = "ru")]                                      use crate::lang::lang_russian;
+    use crate::store::{Store, Record, Attr};
+    use crate::matching::MatchConfig;
+    use super::{Hit, Filter, RankingConfig, ScoreType, explanations_to_json};
+    #[cfg(feature = "en")] use super::Explanation;
 
     fn check(name: &str, lang: Lang, queries: &[&str]) {
         let mut store = Store::new();
@@ -91,7 +520,7 @@ mod tests {
         for (i, query) in queries.iter().enumerate() {
             let query   = tokenize_query(query, &store.lang);
             let query   = query.to_ref();
-            let results = store.search(&query);
+            let results = store.search(&query, None);
             assert_debug_snapshot!(format!("{}-{}", name, i), results);
         }
     }
@@ -109,7 +538,8 @@ mod tests {
         store.add(Record::new(30, "yellow metal mailbox", 10, &store.lang));
         store.add(Record::new(40, "thesaurus",            10, &store.lang));
         store.add(Record::new(50, "wi-fi router",         10, &store.lang));
-        assert_debug_snapshot!(store.top_ixs());
+        let query = tokenize_query("", &store.lang);
+        assert_debug_snapshot!(store.top_ixs(&query.to_ref(), store.limit));
     }
 
     #[test]
@@ -151,6 +581,7 @@ mod tests {
     }
 
 
+    #[cfg(feature = "en")]
     #[test]
     fn search_stemming() {
         let empty_lang = Lang::new();
@@ -162,13 +593,14 @@ mod tests {
         let query2   = tokenize_query("university", &store.lang);
         let query1   = query1.to_ref();
         let query2   = query2.to_ref();
-        let results1 = store.search(&query1);
-        let results2 = store.search(&query2);
+        let results1 = store.search(&query1, None);
+        let results2 = store.search(&query2, None);
 
         assert_debug_snapshot!(results1);
         assert_debug_snapshot!(results2);
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn search_particles() {
         check("particles_nolang", Lang::new(), &[
@@ -180,6 +612,7 @@ mod tests {
         ]);
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn search_joined() {
         check("joined_query", lang_english(), &[
@@ -192,6 +625,775 @@ mod tests {
     }
 
 
+    fn result_ids(store: &Store, query: &str) -> Vec<usize> {
+        let query = tokenize_query(query, &store.lang);
+        let query = query.to_ref();
+        store.search(&query, None).iter().map(|r| r.id).collect()
+    }
+
+    fn get_store() -> Store {
+        let mut store = Store::new();
+        store.add(Record::new(10, "brown plush bear",     10, &store.lang));
+        store.add(Record::new(20, "the metal detector",   20, &store.lang));
+        store.add(Record::new(30, "yellow metal mailbox", 30, &store.lang));
+        store.add(Record::new(40, "thesaurus",            40, &store.lang));
+        store.add(Record::new(50, "wi-fi router",         50, &store.lang));
+        store
+    }
+
+    #[test]
+    fn search_upsert_existing() {
+        let mut store = get_store();
+        assert_eq!(result_ids(&store, ""), vec![50, 40, 30, 20, 10]);
+        store.upsert(Record::new(30, "red wooden chair", 60, &store.lang));
+        assert_eq!(result_ids(&store, "mailbox"), Vec::<usize>::new());
+        assert_eq!(result_ids(&store, "metal"),   vec![20]);
+        assert_eq!(result_ids(&store, "wooden"),  vec![30]);
+        assert_eq!(result_ids(&store, ""),        vec![30, 50, 40, 20, 10]);
+        assert_eq!(store.records.len(), 5);
+    }
+
+    #[test]
+    fn search_upsert_new() {
+        let mut store = get_store();
+        assert_eq!(result_ids(&store, ""), vec![50, 40, 30, 20, 10]);
+        store.upsert(Record::new(60, "wooden mailbox", 60, &store.lang));
+        assert_eq!(result_ids(&store, "mailbox"), vec![60, 30]);
+        assert_eq!(result_ids(&store, ""),        vec![60, 50, 40, 30, 20, 10]);
+        assert_eq!(store.records.len(), 6);
+    }
+
+    #[test]
+    fn search_remove() {
+        let mut store = get_store();
+        assert_eq!(result_ids(&store, ""), vec![50, 40, 30, 20, 10]);
+        assert_eq!(store.remove(20).map(|r| r.id), Some(20));
+        assert_eq!(store.remove(20).map(|r| r.id), None);
+        assert_eq!(result_ids(&store, "metal"),  vec![30]);
+        assert_eq!(result_ids(&store, "router"), vec![50]);
+        assert_eq!(result_ids(&store, ""),       vec![50, 40, 30, 10]);
+        for (ix, record) in store.records.iter().enumerate() {
+            assert_eq!(record.ix, ix);
+            assert_eq!(store.ix_by_id[&record.id], ix);
+        }
+    }
+
+    #[test]
+    fn search_remove_last() {
+        let mut store = get_store();
+        store.remove(50);
+        store.remove(10);
+        assert_eq!(result_ids(&store, "router"), Vec::<usize>::new());
+        assert_eq!(result_ids(&store, "bear"),   Vec::<usize>::new());
+        assert_eq!(result_ids(&store, "metal"),  vec![30, 20]);
+        assert_eq!(result_ids(&store, ""),       vec![40, 30, 20]);
+    }
+
+
+    fn get_fields_store() -> Store {
+        let mut store = Store::new();
+        store.add(Record::with_fields(10, &[
+            ("title",       "brown plush bear"),
+            ("brand",       "teddy & co"),
+            ("description", "soft toy for kids"),
+        ], 10, &store.lang));
+        store.add(Record::with_fields(20, &[
+            ("title",       "teddy bear"),
+            ("brand",       "toyland"),
+            ("description", "classic plush toy"),
+        ], 20, &store.lang));
+        store.add(Record::with_fields(30, &[
+            ("title",       "metal detector"),
+            ("brand",       "garrett"),
+            ("description", "metal detector for kids"),
+        ], 30, &store.lang));
+        store
+    }
+
+    #[test]
+    fn search_fields_matched() {
+        let store   = get_fields_store();
+        let query   = tokenize_query("teddy", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results.len(), 2);
+        assert_eq!((results[0].id, results[0].field.as_str()), (20, "title"));
+        assert_eq!((results[1].id, results[1].field.as_str()), (10, "brand"));
+        assert_eq!(results[0].title, "[teddy] bear");
+        assert_eq!(results[1].title, "brown plush bear");
+        assert_eq!(results[1].fields, vec![
+            ("title".to_string(),       "brown plush bear".to_string()),
+            ("brand".to_string(),       "[teddy] & co".to_string()),
+            ("description".to_string(), "soft toy for kids".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn search_fields_highlight_all() {
+        let store   = get_fields_store();
+        let query   = tokenize_query("metal detector", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].field, "title");
+        assert_eq!(results[0].fields[2].1, "[metal] [detector] for kids");
+    }
+
+    #[test]
+    fn search_fields_weights() {
+        let mut store = get_fields_store();
+        assert_eq!(result_ids(&store, "plush"), vec![20, 10]);
+        store.set_field_weight("description", 0.5);
+        assert_eq!(result_ids(&store, "plush"), vec![10, 20]);
+        store.set_field_weight("title", 0.2);
+        assert_eq!(result_ids(&store, "plush"), vec![20, 10]);
+    }
+
+
+    fn get_attrs_store() -> Store {
+        let mut store = get_store();
+        let stock = [(10, 5), (20, 0), (30, 2), (40, 0), (50, 1)];
+        for &(id, count) in &stock {
+            let ix = store.ix_by_id[&id];
+            store.records[ix].set_attr("stock", Attr::Int(count));
+        }
+        store.records[0].set_attr("category", Attr::keywords(&["toys", "toys/plush"]));
+        store.records[4].set_attr("category", Attr::keywords(&["electronics"]));
+        store
+    }
+
+    fn filtered_ids(store: &Store, query: &str, filter: &Filter) -> Vec<usize> {
+        let query = tokenize_query(query, &store.lang);
+        let query = query.to_ref();
+        store.search(&query, Some(filter)).iter().map(|r| r.id).collect()
+    }
+
+    #[test]
+    fn search_ranking() {
+        let mut store = get_store();
+        assert_eq!(result_ids(&store, "metal"), vec![30, 20]);
+        store.ranking = RankingConfig::lexicographic(&[ScoreType::CharLen, ScoreType::Rating]);
+        assert_eq!(result_ids(&store, "metal"), vec![20, 30]);
+        store.ranking = RankingConfig::linear(&[(ScoreType::Chars, 1.0), (ScoreType::Rating, -1.0)]);
+        assert_eq!(result_ids(&store, "metal"), vec![20, 30]);
+        store.ranking = RankingConfig::default();
+        assert_eq!(result_ids(&store, "metal"), vec![30, 20]);
+    }
+
+    #[test]
+    fn search_ranking_empty() {
+        let mut store = get_store();
+        store.limit = 3;
+        assert_eq!(result_ids(&store, ""), vec![50, 40, 30]);
+        store.ranking = RankingConfig::linear(&[(ScoreType::Rating, -1.0)]);
+        assert_eq!(result_ids(&store, ""), vec![10, 20, 30]);
+        let tens = store.add_scorer("tens", |hit: &Hit, _: &Record| (hit.id % 20) as isize);
+        store.ranking = RankingConfig::lexicographic(&[tens, ScoreType::Rating]);
+        assert_eq!(result_ids(&store, ""), vec![50, 30, 10]);
+    }
+
+    #[test]
+    fn search_custom_scorer() {
+        let mut store = get_store();
+        store.records[1].set_attr("margin", Attr::Int(15));
+        store.records[2].set_attr("margin", Attr::Int(5));
+        let margin = store.add_scorer("margin", |_: &Hit, record: &Record| {
+            record.get_attr("margin").and_then(Attr::as_int).unwrap_or(0) as isize
+        });
+        assert_eq!(margin, ScoreType::Custom(0));
+        assert_eq!(store.score_type("margin"),  Some(margin));
+        assert_eq!(store.score_type("rating"),  Some(ScoreType::Rating));
+        assert_eq!(store.score_type("unknown"), None);
+        assert_eq!(result_ids(&store, "metal"), vec![30, 20]);
+
+        let mut order = vec![margin];
+        order.extend_from_slice(&ScoreType::ALL);
+        store.ranking = RankingConfig::lexicographic(&order);
+        assert_eq!(result_ids(&store, "metal"), vec![20, 30]);
+
+        store.ranking = RankingConfig::linear(&[(ScoreType::Rating, 1.0), (margin, 2.0)]);
+        assert_eq!(result_ids(&store, "metal"), vec![20, 30]);
+        store.ranking = RankingConfig::linear(&[(ScoreType::Rating, 1.0), (margin, 0.5)]);
+        assert_eq!(result_ids(&store, "metal"), vec![30, 20]);
+    }
+
+    #[test]
+    fn search_explain() {
+        let store   = get_store();
+        let query   = tokenize_query("metal", &store.lang);
+        let query   = query.to_ref();
+        let explain = store.search_explain(&query, None);
+        assert_debug_snapshot!(explain);
+    }
+
+    #[test]
+    fn search_explain_custom() {
+        let mut store = get_store();
+        let margin    = store.add_scorer("margin", |hit: &Hit, _: &Record| -(hit.id as isize));
+        store.ranking = RankingConfig::lexicographic(&[ScoreType::Words, margin]);
+        let query     = tokenize_query("metal", &store.lang);
+        let query     = query.to_ref();
+        let explain   = store.search_explain(&query, None);
+        let ids       = explain.iter().map(|e| e.id).collect::<Vec<_>>();
+        let decisions = explain.iter().map(|e| e.decided_by.clone()).collect::<Vec<_>>();
+        assert_eq!(ids,       vec![20, 30]);
+        assert_eq!(decisions, vec![Some("margin".to_string()), None]);
+        assert_eq!(explain[0].scores.last(), Some(&("margin".to_string(), -20)));
+    }
+
+    #[test]
+    fn search_explain_json() {
+        let store   = get_store();
+        let query   = tokenize_query("yelow mail", &store.lang);
+        let query   = query.to_ref();
+        let explain = store.search_explain(&query, None);
+        let json: serde_json::Value = serde_json::from_str(&explanations_to_json(&explain)).unwrap();
+        assert_eq!(json[0]["id"],                      30);
+        assert_eq!(json[0]["title"],                   "[yellow] metal [mail]box");
+        assert_eq!(json[0]["scores"]["chars"],         8);
+        assert_eq!(json[0]["rmatches"][0]["word"],     "yellow");
+        assert_eq!(json[0]["rmatches"][0]["typos"],    0.5);
+        assert_eq!(json[0]["qmatches"][1]["subslice"], serde_json::json!([0, 4]));
+        assert_eq!(json[0]["layout"],                  false);
+        assert_eq!(json[0]["decided_by"],              serde_json::Value::Null);
+    }
+
+    fn get_pages_store() -> Store {
+        let mut store = Store::new();
+        store.limit = 3;
+        for i in 0 .. 50 {
+            let title = format!("metal box {}", i);
+            store.add(Record::new(i, &title, i % 7, &store.lang));
+        }
+        store.add(Record::new(100, "wooden chair", 100, &store.lang));
+        store
+    }
+
+    fn page_ids(store: &Store, query: &str, offset: usize, limit: usize) -> (Vec<usize>, usize) {
+        let query = tokenize_query(query, &store.lang);
+        let query = query.to_ref();
+        let page  = store.search_page(&query, None, offset, limit);
+        (page.results.iter().map(|r| r.id).collect(), page.total)
+    }
+
+    #[test]
+    fn search_page_consecutive() {
+        let mut store = Store::new();
+        store.limit = 3;
+        for i in 0 .. 50 {
+            let title = format!("metal box {}", i);
+            store.add(Record::new(i, &title, i, &store.lang));
+        }
+        for &query in &["metal box", "metal", ""] {
+            let (all, total) = page_ids(&store, query, 0, 60);
+            assert_eq!(total, 50);
+            assert_eq!(&all[.. 3], &[49, 48, 47]);
+            let mut paged = Vec::new();
+            for offset in (0 .. 60).step_by(3) {
+                let (page, page_total) = page_ids(&store, query, offset, 3);
+                assert_eq!(page_total, total);
+                paged.extend(page);
+            }
+            assert_eq!(paged, all);
+        }
+    }
+
+    #[test]
+    fn search_page_total() {
+        let store = get_pages_store();
+        assert_eq!(page_ids(&store, "metal", 0,  3).1, 50);
+        assert_eq!(page_ids(&store, "chair", 0,  3),   (vec![100], 1));
+        assert_eq!(page_ids(&store, "",      0,  3).1, 51);
+        assert_eq!(page_ids(&store, "metal", 48, 3).0.len(), 2);
+        assert_eq!(page_ids(&store, "metal", 60, 3),   (vec![], 50));
+    }
+
+    #[test]
+    fn search_page_beyond_pool() {
+        // Default candidate pool for limit 3 is 30 records, the page needs more.
+        let store = get_pages_store();
+        let (page, _) = page_ids(&store, "metal", 40, 10);
+        assert_eq!(page.len(), 10);
+        assert_eq!(result_ids(&store, "metal").len(), 3);
+    }
+
+    #[test]
+    fn search_empty_limit_grows() {
+        let mut store = get_store();
+        store.limit = 2;
+        assert_eq!(result_ids(&store, ""), vec![50, 40]);
+        store.limit = 4;
+        assert_eq!(result_ids(&store, ""), vec![50, 40, 30, 20]);
+    }
+
+    #[test]
+    fn search_match_config() {
+        let mut store = get_store();
+        assert_eq!(result_ids(&store, "metol"),  vec![30, 20]);
+        assert_eq!(result_ids(&store, "mailbx"), vec![30]);
+        store.matching = MatchConfig::new().typo_budgets(&[(0, 0.0)]);
+        assert_eq!(result_ids(&store, "metol"),  Vec::<usize>::new());
+        assert_eq!(result_ids(&store, "metal"),  vec![30, 20]);
+        store.matching = MatchConfig::fuzziness_auto();
+        assert_eq!(result_ids(&store, "metol"),  vec![30, 20]);
+    }
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn search_phonetic() {
+        let mut store = Store::new();
+        store.lang = lang_english();
+        store.add(Record::new(10, "Moët Champagne Brut", 10, &store.lang));
+        store.add(Record::new(20, "Shampoo for dogs",    20, &store.lang));
+        store.add(Record::new(30, "Sparkling wine",      30, &store.lang));
+        assert_eq!(result_ids(&store, "shampain"),      Vec::<usize>::new());
+        store.matching = MatchConfig::new().phonetic(Some(1.0));
+        assert_eq!(result_ids(&store, "shampain"),      vec![10]);
+        assert_eq!(result_ids(&store, "shampo"),        vec![20]);
+    }
+
+    #[cfg(feature = "ru")]
+    fn get_layout_store() -> Store {
+        let mut store = Store::new();
+        store.lang = lang_russian();
+        store.add(Record::new(10, "Игрушечный магазин", 10, &store.lang));
+        store.add(Record::new(20, "Magazine rack",      20, &store.lang));
+        store.add(Record::new(30, "Телефон",            30, &store.lang));
+        store.add(Record::new(40, "ntktajy case",       40, &store.lang));
+        store
+    }
+
+    #[cfg(feature = "ru")]
+    #[test]
+    fn search_layout() {
+        let store = get_layout_store();
+        assert_eq!(result_ids(&store, "vfufpby"),  vec![10]);
+        assert_eq!(result_ids(&store, "ьфпфяшту"), vec![20, 10]);
+        assert_eq!(result_ids(&store, "магазин"),  vec![10, 20]);
+        assert_eq!(result_ids(&store, "ntktaj"),   vec![40, 30]);
+        assert_eq!(result_ids(&store, "телефон"),  vec![30, 40]);
+    }
+
+    #[cfg(feature = "ru")]
+    #[test]
+    fn search_layout_highlight() {
+        let store   = get_layout_store();
+        let query   = tokenize_query("vfufp", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "Игрушечный [магаз]ин");
+    }
+
+    #[cfg(feature = "ru")]
+    #[test]
+    fn search_layout_total() {
+        let store = get_layout_store();
+        let query = tokenize_query("ntktajy", &store.lang);
+        let query = query.to_ref();
+        let page  = store.search_page(&query, None, 0, 1);
+        assert_eq!(page.results.len(), 1);
+        assert_eq!(page.total,         2);
+    }
+
+    #[cfg(feature = "en")]
+    fn get_synonyms_store() -> Store {
+        let mut store = Store::new();
+        store.lang = lang_english();
+        store.lang.add_synonyms(&["tv", "television"]);
+        store.lang.add_synonyms(&["night light", "nightlight"]);
+        store.lang.add_synonym("sofa", "couch");
+        store.add(Record::new(10, "Samsung television",  10, &store.lang));
+        store.add(Record::new(20, "TV stand",            5,  &store.lang));
+        store.add(Record::new(30, "Nightlight for kids", 30, &store.lang));
+        store.add(Record::new(40, "Leather couch",       40, &store.lang));
+        store.add(Record::new(50, "Sofa bed",            50, &store.lang));
+        store
+    }
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn search_synonyms() {
+        let store = get_synonyms_store();
+        assert_eq!(result_ids(&store, "tv"),          vec![20, 10]);
+        assert_eq!(result_ids(&store, "television"),  vec![10, 20]);
+        assert_eq!(result_ids(&store, "night light"), vec![30]);
+        assert_eq!(result_ids(&store, "sofa"),        vec![50, 40]);
+        assert_eq!(result_ids(&store, "couch"),       vec![40]);
+    }
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn search_synonyms_highlight() {
+        let store   = get_synonyms_store();
+        let query   = tokenize_query("samsung tv", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[Samsung] [television]");
+    }
+
+    #[cfg(feature = "en")]
+    #[test]
+    fn search_synonyms_score() {
+        let store   = get_synonyms_store();
+        let query   = tokenize_query("tv", &store.lang);
+        let query   = query.to_ref();
+        let explain = store.search_explain(&query, None);
+        let syn     = |e: &Explanation| e.scores.iter().find(|(name, _)| name == "syn").unwrap().1;
+        assert_eq!(explain[0].id,         20);
+        assert_eq!(syn(&explain[0]),      0);
+        assert_eq!(syn(&explain[1]),      -1);
+        assert_eq!(explain[0].decided_by, Some("syn".to_string()));
+    }
+
+    #[test]
+    fn search_filter() {
+        let store   = get_attrs_store();
+        let instock = Filter::range("stock", Some(1), None);
+        assert_eq!(filtered_ids(&store, "metal", &instock), vec![30]);
+        assert_eq!(filtered_ids(&store, "the",   &instock), Vec::<usize>::new());
+        assert_eq!(filtered_ids(&store, "",      &instock), vec![50, 30, 10]);
+    }
+
+    #[test]
+    fn search_filter_upsert() {
+        let mut store = get_attrs_store();
+        let instock   = Filter::range("stock", Some(1), None);
+        store.upsert(Record::new(30, "yellow metal mailbox xl", 35, &store.lang));
+        assert_eq!(filtered_ids(&store, "metal", &instock), vec![30]);
+        let mut record = Record::new(30, "yellow metal mailbox", 30, &store.lang);
+        record.set_attr("stock", Attr::Int(0));
+        store.upsert(record);
+        assert_eq!(filtered_ids(&store, "metal", &instock), Vec::<usize>::new());
+        store.upsert(Record::new(50, "wi-fi router ac", 50, &store.lang));
+        assert_eq!(store.records[store.ix_by_id[&50]].get_attr("category"), Some(&Attr::keywords(&["electronics"])));
+    }
+
+    #[test]
+    fn search_filter_logic() {
+        let store  = get_attrs_store();
+        let filter = Filter::or(vec![
+            Filter::eq("category", Attr::keyword("toys")),
+            Filter::negate(Filter::range("stock", Some(1), None)),
+        ]);
+        assert_eq!(filtered_ids(&store, "", &filter), vec![40, 20, 10]);
+    }
+
+    #[test]
+    fn search_filter_before_limit() {
+        let mut store = get_attrs_store();
+        store.limit = 1;
+        let outofstock = Filter::eq("stock", Attr::Int(0));
+        assert_eq!(result_ids(&store, ""),                      vec![50]);
+        assert_eq!(filtered_ids(&store, "", &outofstock),       vec![40]);
+        assert_eq!(result_ids(&store, "metal"),                 vec![30]);
+        assert_eq!(filtered_ids(&store, "metal", &outofstock),  vec![20]);
+    }
+
+
+    fn get_facets_store() -> Store {
+        let mut store = get_attrs_store();
+        let categories = [
+            (10, &["toys", "toys/plush"][..]),
+            (20, &["garden", "garden/tools"][..]),
+            (30, &["garden", "garden/decor"][..]),
+            (40, &["books"][..]),
+            (50, &["electronics"][..]),
+        ];
+        for &(id, category) in &categories {
+            let ix = store.ix_by_id[&id];
+            store.records[ix].set_attr("category", Attr::keywords(category));
+        }
+        store
+    }
+
+    #[test]
+    fn search_facet_beyond_limit() {
+        let mut store = get_facets_store();
+        store.limit = 1;
+        let query   = tokenize_query("metal", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search_facet(&query, None, "category");
+        assert_eq!(results.results.len(), 1);
+        assert_eq!(results.results[0].id, 30);
+        assert_eq!(results.facets, vec![
+            ("garden".to_string(),       2),
+            ("garden/decor".to_string(), 1),
+            ("garden/tools".to_string(), 1),
+        ]);
+    }
+
+    #[test]
+    fn search_facet_filtered() {
+        let store   = get_facets_store();
+        let instock = Filter::range("stock", Some(1), None);
+        let query   = tokenize_query("", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search_facet(&query, Some(&instock), "category");
+        assert_eq!(results.results.iter().map(|r| r.id).collect::<Vec<_>>(), vec![50, 30, 10]);
+        assert_eq!(results.facets, vec![
+            ("electronics".to_string(),  1),
+            ("garden".to_string(),       1),
+            ("garden/decor".to_string(), 1),
+            ("toys".to_string(),         1),
+            ("toys/plush".to_string(),   1),
+        ]);
+    }
+
+    #[test]
+    fn search_page_facet() {
+        let store   = get_facets_store();
+        let query   = tokenize_query("metal", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search_page_facet(&query, None, Some("category"), 1, 1);
+        assert_eq!(results.results.iter().map(|r| r.id).collect::<Vec<_>>(), vec![20]);
+        assert_eq!(results.total, 2);
+        assert_eq!(results.facets, vec![
+            ("garden".to_string(),       2),
+            ("garden/decor".to_string(), 1),
+            ("garden/tools".to_string(), 1),
+        ]);
+    }
+
+    #[test]
+    fn search_facet_missing() {
+        let store   = get_facets_store();
+        let query   = tokenize_query("metal", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search_facet(&query, None, "brand");
+        assert_eq!(results.results.len(), 2);
+        assert_eq!(results.facets, vec![]);
+    }
+
+
+    #[cfg(feature = "de")]
+    #[test]
+    fn search_compound() {
+        let mut store = Store::new();
+        store.lang = lang_german();
+        store.add(Record::new(10, "Kaffeemaschine Deluxe", 10, &store.lang));
+        store.add(Record::new(20, "Waschmaschine",         20, &store.lang));
+        store.add(Record::new(30, "Kaffee und Kuchen",     30, &store.lang));
+
+        assert_eq!(result_ids(&store, "maschine"),        vec![20, 10]);
+        assert_eq!(result_ids(&store, "kaffee maschine"), vec![10, 20, 30]);
+        assert_eq!(result_ids(&store, "kaffeemaschine"),  vec![10, 20, 30]);
+        assert_eq!(result_ids(&store, "kaffeemasch"),     vec![10]);
+
+        let query   = tokenize_query("kaffee maschine", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[Kaffeemaschine] Deluxe");
+    }
+
+    #[cfg(feature = "it")]
+    #[test]
+    fn search_elision() {
+        let mut store = Store::new();
+        store.lang = lang_italian();
+        store.add(Record::new(10, "Bottiglia dell'acqua", 10, &store.lang));
+        store.add(Record::new(20, "L'olio extravergine",  20, &store.lang));
+        store.add(Record::new(30, "Acqua minerale",       30, &store.lang));
+
+        assert_eq!(result_ids(&store, "acqua"),   vec![30, 10]);
+        assert_eq!(result_ids(&store, "l’olio"),  vec![20]);
+
+        let query   = tokenize_query("olio", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "L'[olio] extravergine");
+    }
+
+    #[cfg(feature = "pl")]
+    #[test]
+    fn search_suffix_stemmer() {
+        let mut store = Store::new();
+        store.lang = lang_polish();
+        store.add(Record::new(10, "Książka kucharska",   10, &store.lang));
+        store.add(Record::new(20, "Półka na książki",    20, &store.lang));
+        store.add(Record::new(30, "Lampka nocna",        30, &store.lang));
+
+        assert_eq!(result_ids(&store, "ksiazkami "), vec![10, 20]);
+        assert_eq!(result_ids(&store, "polki "),     vec![20]);
+    }
+
+    #[cfg(feature = "ar")]
+    #[test]
+    fn search_arabic() {
+        let mut store = Store::new();
+        store.lang = lang_arabic();
+        store.add(Record::new(10, "كِتَابُ الطبخ",       10, &store.lang));
+        store.add(Record::new(20, "مكتبة الأطفال",      20, &store.lang));
+        store.add(Record::new(30, "حقيبة مدرسية زرقاء", 30, &store.lang));
+
+        assert_eq!(result_ids(&store, "والكتاب "), vec![10]);
+        assert_eq!(result_ids(&store, "مكتبه "),   vec![20]);
+        assert_eq!(result_ids(&store, "اطفال"),    vec![20]);
+
+        let query   = tokenize_query("طبخ", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "كِتَابُ ال[طبخ]");
+
+        let query   = tokenize_query("الاط", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "مكتبة [الأط]فال");
+
+        let query   = tokenize_query("كتاب", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[كِتَابُ] الطبخ");
+
+        let query   = tokenize_query("", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[2].title, "كِتَابُ الطبخ");
+    }
+
+    #[cfg(feature = "he")]
+    #[test]
+    fn search_hebrew() {
+        let mut store = Store::new();
+        store.lang = lang_hebrew();
+        store.add(Record::new(10, "סֵפֶר בישול",   10, &store.lang));
+        store.add(Record::new(20, "שלום עולם",     20, &store.lang));
+
+        assert_eq!(result_ids(&store, "ספר"),  vec![10]);
+        assert_eq!(result_ids(&store, "שלומ"), vec![20]);
+
+        let query   = tokenize_query("שלו", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[שלו]ם עולם");
+
+        let query   = tokenize_query("ספר", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[סֵפֶר] בישול");
+
+        let query   = tokenize_query("", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[1].title, "סֵפֶר בישול");
+    }
+
+    #[cfg(any(feature = "ja", feature = "zh"))]
+    #[test]
+    fn search_cjk_bigrams() {
+        let mut store = Store::new();
+        store.lang = lang_cjk();
+        store.add(Record::new(10, "東京タワーの観光ガイド", 10, &store.lang));
+        store.add(Record::new(20, "京都の旅館",             20, &store.lang));
+        store.add(Record::new(30, "iPhone用ケース",         30, &store.lang));
+
+        assert_eq!(result_ids(&store, "東京"),      vec![10]);
+        assert_eq!(result_ids(&store, "京都"),      vec![20]);
+        assert_eq!(result_ids(&store, "ケース"),    vec![30]);
+        assert_eq!(result_ids(&store, "ｉｐｈｏｎｅ"), vec![30]);
+
+        let query   = tokenize_query("観光ガイ", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "東京タワーの[観光ガイ]ド");
+    }
+
+    #[cfg(feature = "el")]
+    #[test]
+    fn search_greek_accents() {
+        let mut store = Store::new();
+        store.lang = lang_greek();
+        store.add(Record::new(10, "Οδηγός της Αθήνας", 10, &store.lang));
+        store.add(Record::new(20, "Χάρτης της Κρήτης", 20, &store.lang));
+
+        assert_eq!(result_ids(&store, "αθηνας"), vec![10]);
+        assert_eq!(result_ids(&store, "ΚΡΗΤΗΣ"), vec![20]);
+
+        let query   = tokenize_query("οδηγοσ", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[Οδηγός] της Αθήνας");
+    }
+
+    #[cfg(feature = "ru")]
+    #[test]
+    fn search_translit() {
+        let mut store = Store::new();
+        store.lang = lang_russian();
+        store.add(Record::new(10, "Смартфон Samsung Galaxy", 10, &store.lang));
+        store.add(Record::new(20, "Наушники Сони",           20, &store.lang));
+        store.add(Record::new(30, "Чехол для телефона",      30, &store.lang));
+        store.add(Record::new(40, "Зубная щётка",            40, &store.lang));
+
+        assert_eq!(result_ids(&store, "самсунг"), vec![10]);
+        assert_eq!(result_ids(&store, "sony "),   vec![20]);
+        assert_eq!(result_ids(&store, "чехол"),   vec![30]);
+
+        let query   = tokenize_query("samsung galaxy", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "Смартфон [Samsung] [Galaxy]");
+
+        let query   = tokenize_query("chehol", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[Чехол] для телефона");
+
+        let query   = tokenize_query("shchet", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "Зубная [щёт]ка");
+
+        store.add(Record::new(50, "Объектив для камеры", 50, &store.lang));
+        let query   = tokenize_query("obektiv", &store.lang);
+        let query   = query.to_ref();
+        let results = store.search(&query, None);
+        assert_eq!(results[0].title, "[Объектив] для камеры");
+    }
+
+    #[cfg(all(feature = "de", feature = "fr", feature = "it"))]
+    fn get_multi_lang_store() -> Store {
+        let mut store = Store::new();
+        store.lang = lang_german();
+        let french  = store.add_lang(lang_french());
+        let italian = store.add_lang(lang_italian());
+        let records = [
+            (10, "Kaffeemaschine mit Milchschäumer", 0),
+            (20, "Machine à café expresso",          french),
+            (30, "Macchina da caffè espresso",       italian),
+            (40, "Bottiglia dell'acqua",             italian),
+            (50, "Bouteilles d'eau",                 french),
+        ];
+        for &(id, title, lang) in records.iter() {
+            let mut record = Record::new(id, title, 10, store.lang_by_id(lang));
+            record.set_lang(lang);
+            store.add(record);
+        }
+        store
+    }
+
+    #[cfg(all(feature = "de", feature = "fr", feature = "it"))]
+    #[test]
+    fn search_detect_langs() {
+        let store = get_multi_lang_store();
+        assert_eq!(store.detect_langs("machine à café"),     vec![1]);
+        assert_eq!(store.detect_langs("macchina da caffè"),  vec![2]);
+        assert_eq!(store.detect_langs("kaffee für milch"),   vec![0]);
+        assert_eq!(store.detect_langs("espresso"),           vec![0, 1, 2]);
+    }
+
+    #[cfg(all(feature = "de", feature = "fr", feature = "it"))]
+    #[test]
+    fn search_multi_lang() {
+        let store = get_multi_lang_store();
+        assert_eq!(result_ids(&store, "l'acqua"),           vec![40]);
+        assert_eq!(result_ids(&store, "dell'acqua"),        vec![40]);
+        assert_eq!(result_ids(&store, "bouteille"),         vec![50]);
+        assert_eq!(result_ids(&store, "machine à café"),    vec![20]);
+        assert_eq!(result_ids(&store, "kaffee für milch"),  vec![10]);
+        assert_eq!(result_ids(&store, "espresso"),          vec![30, 20]);
+    }
+
+    #[cfg(feature = "de")]
     #[test]
     fn search_utf_normalization() {
         let mut store = Store::new();
@@ -209,7 +1411,7 @@ mod tests {
         for query in &queries {
             let query  = tokenize_query(query, &store.lang);
             let query  = query.to_ref();
-            let result = store.search(&query);
+            let result = store.search(&query, None);
             assert_debug_snapshot!(result);
         }
     }
@@ -1,6 +1,16 @@
 
 #[derive(Debug)]
 pub struct SearchResult {
-    pub id:    usize,
-    pub title: String,
+    pub id:     usize,
+    pub title:  String,
+    pub field:  String,
+    pub fields: Vec<(String, String)>,
+}
+
+
+#[derive(Debug)]
+pub struct SearchResults {
+    pub results: Vec<SearchResult>,
+    pub facets:  Vec<(String, usize)>,
+    pub total:   usize,
 }
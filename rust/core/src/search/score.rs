@@ -1,31 +1,99 @@
 use crate::tokenization::{Word, TextRef};
-use crate::matching::text_match;
+use crate::matching::{MatchConfig, text_match};
 use crate::search::Hit;
 
 
-pub const SCORES_SIZE: usize = 9;
+pub const SCORES_SIZE: usize = 11;
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScoreType {
-    Chars   = 0,
-    Words   = 1,
-    Tails   = 2,
-    Trans   = 3,
-    Fin     = 4,
-    Offset  = 5,
-    Rating  = 6,
-    WordLen = 7,
-    CharLen = 8,
+    Chars,
+    Syn,
+    Layout,
+    Words,
+    Tails,
+    Trans,
+    Fin,
+    Offset,
+    Rating,
+    WordLen,
+    CharLen,
+    Custom(usize),
+}
+
+
+impl ScoreType {
+    pub const ALL: [ScoreType; SCORES_SIZE] = [
+        ScoreType::Chars,
+        ScoreType::Syn,
+        ScoreType::Layout,
+        ScoreType::Words,
+        ScoreType::Tails,
+        ScoreType::Trans,
+        ScoreType::Fin,
+        ScoreType::Offset,
+        ScoreType::Rating,
+        ScoreType::WordLen,
+        ScoreType::CharLen,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ScoreType::Chars     => "chars",
+            ScoreType::Syn       => "syn",
+            ScoreType::Layout    => "layout",
+            ScoreType::Words     => "words",
+            ScoreType::Tails     => "tails",
+            ScoreType::Trans     => "trans",
+            ScoreType::Fin       => "fin",
+            ScoreType::Offset    => "offset",
+            ScoreType::Rating    => "rating",
+            ScoreType::WordLen   => "word_len",
+            ScoreType::CharLen   => "char_len",
+            ScoreType::Custom(_) => "custom",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ScoreType> {
+        ScoreType::ALL.iter()
+            .cloned()
+            .find(|score| score.name() == name)
+    }
+
+    fn builtin_ix(self) -> usize {
+        match self {
+            ScoreType::Chars     => 0,
+            ScoreType::Syn       => 1,
+            ScoreType::Layout    => 2,
+            ScoreType::Words     => 3,
+            ScoreType::Tails     => 4,
+            ScoreType::Trans     => 5,
+            ScoreType::Fin       => 6,
+            ScoreType::Offset    => 7,
+            ScoreType::Rating    => 8,
+            ScoreType::WordLen   => 9,
+            ScoreType::CharLen   => 10,
+            ScoreType::Custom(_) => panic!("Custom score has no builtin index"),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone)]
-pub struct Scores([isize; SCORES_SIZE]);
+pub struct Scores {
+    builtin: [isize; SCORES_SIZE],
+    custom:  Vec<isize>,
+}
 
 
 impl Scores {
     pub fn iter(&self) -> impl Iterator<Item=&isize> {
-        self.0.iter()
+        self.builtin.iter()
+    }
+
+    pub fn push_custom(&mut self, score: isize) {
+        self.custom.push(score);
     }
 }
 
@@ -34,27 +102,36 @@ impl std::ops::Index<ScoreType> for Scores {
     type Output = isize;
 
     fn index(&self, score: ScoreType) -> &Self::Output {
-        &self.0[score as usize]
+        match score {
+            ScoreType::Custom(ix) => self.custom.get(ix).unwrap_or(&0),
+            _                     => &self.builtin[score.builtin_ix()],
+        }
     }
 }
 
 
 impl std::ops::IndexMut<ScoreType> for Scores {
     fn index_mut(&mut self, score: ScoreType) -> &mut Self::Output {
-        &mut self.0[score as usize]
+        match score {
+            ScoreType::Custom(ix) => &mut self.custom[ix],
+            _                     => &mut self.builtin[score.builtin_ix()],
+        }
     }
 }
 
 
 impl Default for Scores {
     fn default() -> Scores {
-        Scores([0; SCORES_SIZE])
+        Scores {
+            builtin: [0; SCORES_SIZE],
+            custom:  Vec::new(),
+        }
     }
 }
 
 
-pub fn score(query: &TextRef, hit: &mut Hit) {
-    let (rmatches, qmatches) = text_match(&hit.title, &query);
+pub fn score(query: &TextRef, hit: &mut Hit, config: &MatchConfig) {
+    let (rmatches, qmatches) = text_match(&hit.text, &query, config);
     hit.rmatches = rmatches;
     hit.qmatches = qmatches;
 
@@ -65,15 +142,47 @@ pub fn score(query: &TextRef, hit: &mut Hit) {
     hit.scores[ScoreType::Fin]     = score_fin_up(hit);
     hit.scores[ScoreType::Offset]  = score_offset_down(hit);
     hit.scores[ScoreType::Rating]  = score_rating_up(hit);
-    hit.scores[ScoreType::WordLen] = score_word_len_down(hit);
-    hit.scores[ScoreType::CharLen] = score_char_len_down(hit);
+
+    // Nothing to match in an empty query, so short titles don't outrank the rest.
+    if !query.is_empty() {
+        hit.scores[ScoreType::WordLen] = score_word_len_down(hit);
+        hit.scores[ScoreType::CharLen] = score_char_len_down(hit);
+    }
+}
+
+
+pub fn weigh(hit: &mut Hit, weight: f64) {
+    let chars = hit.scores[ScoreType::Chars] as f64;
+    hit.scores[ScoreType::Chars] = (chars * weight).round() as isize;
 }
 
 
+// Synonym matches shouldn't outscore literal ones just because the synonym is longer.
+pub fn synonyms(hit: &mut Hit, substitutions: usize, max_chars: usize) {
+    let chars = hit.scores[ScoreType::Chars];
+    hit.scores[ScoreType::Chars] = min!(chars, max_chars as isize);
+    hit.scores[ScoreType::Syn]   = -(substitutions as isize);
+}
+
+
+// Query typed in the other layout ranks below the literal one, like "ntktajy" for "телефон".
+pub fn layout(hit: &mut Hit) {
+    hit.scores[ScoreType::Layout] = -1;
+}
+
+
+// Chars shared by overlapping matches, like CJK bigrams, are counted once.
 pub fn score_chars_up(hit: &Hit) -> isize {
+    let mut covered = 0;
     hit.rmatches
         .iter()
-        .map(|m| m.match_len() - 2 * (m.typos.ceil() as usize))
+        .map(|m| {
+            let left  = m.slice.0 + m.subslice.0;
+            let right = m.slice.0 + m.subslice.1;
+            let len   = right - min!(max!(left, covered), right);
+            covered   = max!(covered, right);
+            len.saturating_sub(2 * (m.typos.ceil() as usize))
+        })
         .sum::<usize>() as isize
 }
 
@@ -131,22 +240,24 @@ pub fn score_rating_up(hit: &Hit) -> isize {
 
 
 pub fn score_word_len_down(hit: &Hit) -> isize {
-    -(hit.title.words.len() as isize)
+    -(hit.text.words.len() as isize)
 }
 
 
 pub fn score_char_len_down(hit: &Hit) -> isize {
-    -(hit.title.words.iter().map(|w| w.len()).sum::<usize>() as isize)
+    -(hit.text.words.iter().map(|w| w.len()).sum::<usize>() as isize)
 }
 
 
 #[cfg(test)]
 mod tests {
-    use crate::lang::{Lang, lang_english};
+    use crate::lang::Lang;
+    #[cfg(feature = "en")] use crate::lang::lang_english;
     use crate::tokenization::tokenize_query;
     use crate::store::Record;
+    use crate::matching::MatchConfig;
     use crate::search::Hit;
-    use super::{score, ScoreType};
+    use super::{score, weigh, ScoreType};
 
     #[test]
     fn score_chars() {
@@ -155,12 +266,12 @@ mod tests {
         let r1     = Record::new(10, "half of it",  0, &lang);
         let r2     = Record::new(20, "quarter",     0, &lang);
         let r3     = Record::new(30, "whole thing", 0, &lang);
-        let mut h1 = Hit::from_record(&r1);
-        let mut h2 = Hit::from_record(&r2);
-        let mut h3 = Hit::from_record(&r3);
-        score(&q.to_ref(), &mut h1);
-        score(&q.to_ref(), &mut h2);
-        score(&q.to_ref(), &mut h3);
+        let mut h1 = Hit::from_field(&r1, 0);
+        let mut h2 = Hit::from_field(&r2, 0);
+        let mut h3 = Hit::from_field(&r3, 0);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
+        score(&q.to_ref(), &mut h3, &MatchConfig::new());
         assert_eq!(h1.scores[ScoreType::Chars], 4);
         assert_eq!(h2.scores[ScoreType::Chars], 7);
         assert_eq!(h3.scores[ScoreType::Chars], 0);
@@ -170,31 +281,32 @@ mod tests {
     fn score_chars_typos() {
         let lang   = Lang::new();
         let r      = Record::new(10, "small yellow metal mailbox", 0, &lang);
-        let mut h1 = Hit::from_record(&r);
-        let mut h2 = Hit::from_record(&r);
-        let mut h3 = Hit::from_record(&r);
+        let mut h1 = Hit::from_field(&r, 0);
+        let mut h2 = Hit::from_field(&r, 0);
+        let mut h3 = Hit::from_field(&r, 0);
         let q1     = tokenize_query("yellow mailbox", &lang);
         let q2     = tokenize_query("yelow maiblox", &lang);
         let q3     = tokenize_query("yellow mail", &lang);
-        score(&q1.to_ref(), &mut h1);
-        score(&q2.to_ref(), &mut h2);
-        score(&q3.to_ref(), &mut h3);
+        score(&q1.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q2.to_ref(), &mut h2, &MatchConfig::new());
+        score(&q3.to_ref(), &mut h3, &MatchConfig::new());
         assert_eq!(h1.scores[ScoreType::Chars], 13);
         assert_eq!(h2.scores[ScoreType::Chars], 9);
         assert_eq!(h3.scores[ScoreType::Chars], 10);
     }
 
 
+    #[cfg(feature = "en")]
     #[test]
     fn score_chars_regress_1() {
         let lang   = lang_english();
         let q      = tokenize_query("orn", &lang);
         let r1     = Record::new(10, "ornament", 0, &lang);
         let r2     = Record::new(20, "orange",   0, &lang);
-        let mut h1 = Hit::from_record(&r1);
-        let mut h2 = Hit::from_record(&r2);
-        score(&q.to_ref(), &mut h1);
-        score(&q.to_ref(), &mut h2);
+        let mut h1 = Hit::from_field(&r1, 0);
+        let mut h2 = Hit::from_field(&r2, 0);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
         assert!(h1.scores[ScoreType::Chars] > h2.scores[ScoreType::Chars]);
     }
 
@@ -208,35 +320,61 @@ mod tests {
         let r2     = Record::new(20, "greens",   0, &lang);
         let r3     = Record::new(30, "greeny",   0, &lang);
         let r4     = Record::new(40, "greenies", 0, &lang);
-        let mut h1 = Hit::from_record(&r1);
-        let mut h2 = Hit::from_record(&r2);
-        let mut h3 = Hit::from_record(&r3);
-        let mut h4 = Hit::from_record(&r4);
-        score(&q.to_ref(), &mut h1);
-        score(&q.to_ref(), &mut h2);
-        score(&q.to_ref(), &mut h3);
-        score(&q.to_ref(), &mut h4);
+        let mut h1 = Hit::from_field(&r1, 0);
+        let mut h2 = Hit::from_field(&r2, 0);
+        let mut h3 = Hit::from_field(&r3, 0);
+        let mut h4 = Hit::from_field(&r4, 0);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
+        score(&q.to_ref(), &mut h3, &MatchConfig::new());
+        score(&q.to_ref(), &mut h4, &MatchConfig::new());
         assert_eq!(h1.scores[ScoreType::Tails], -0);
         assert_eq!(h2.scores[ScoreType::Tails], -1);
         assert_eq!(h3.scores[ScoreType::Tails], -1);
         assert_eq!(h4.scores[ScoreType::Tails], -3);
     }
 
+    #[test]
+    fn score_weigh() {
+        let lang   = Lang::new();
+        let q      = tokenize_query("yellow mailbox", &lang);
+        let r      = Record::with_fields(10, &[
+            ("title",       "small yellow metal mailbox"),
+            ("description", "yellow mailbox"),
+        ], 0, &lang);
+        let mut h1 = Hit::from_field(&r, 0);
+        let mut h2 = Hit::from_field(&r, 1);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
+        weigh(&mut h1, 1.0);
+        weigh(&mut h2, 0.5);
+        assert_eq!(h1.scores[ScoreType::Chars], 13);
+        assert_eq!(h2.scores[ScoreType::Chars], 7);
+    }
+
     #[test]
     fn score_offset() {
         let lang   = Lang::new();
         let r      = Record::new(10, "small yellow metal mailbox", 0, &lang);
-        let mut h1 = Hit::from_record(&r);
-        let mut h2 = Hit::from_record(&r);
-        let mut h3 = Hit::from_record(&r);
+        let mut h1 = Hit::from_field(&r, 0);
+        let mut h2 = Hit::from_field(&r, 0);
+        let mut h3 = Hit::from_field(&r, 0);
         let q1     = tokenize_query("smal mailbox", &lang);
         let q2     = tokenize_query("yelow mailbox", &lang);
         let q3     = tokenize_query("metol maiblox", &lang);
-        score(&q1.to_ref(), &mut h1);
-        score(&q2.to_ref(), &mut h2);
-        score(&q3.to_ref(), &mut h3);
+        score(&q1.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q2.to_ref(), &mut h2, &MatchConfig::new());
+        score(&q3.to_ref(), &mut h3, &MatchConfig::new());
         assert_eq!(h1.scores[ScoreType::Offset], -0);
         assert_eq!(h2.scores[ScoreType::Offset], -1);
         assert_eq!(h3.scores[ScoreType::Offset], -2);
     }
+
+    #[test]
+    fn score_type_names() {
+        for &score_type in ScoreType::ALL.iter() {
+            assert_eq!(ScoreType::from_name(score_type.name()), Some(score_type));
+        }
+        assert_eq!(ScoreType::from_name("unknown"), None);
+    }
 }
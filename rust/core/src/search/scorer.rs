@@ -0,0 +1,57 @@
+use crate::store::Record;
+use crate::search::Hit;
+
+
+pub trait Scorer {
+    fn score(&self, hit: &Hit, record: &Record) -> isize;
+}
+
+
+impl<F> Scorer for F where F: Fn(&Hit, &Record) -> isize {
+    fn score(&self, hit: &Hit, record: &Record) -> isize {
+        self(hit, record)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::lang::Lang;
+    use crate::tokenization::tokenize_query;
+    use crate::store::Record;
+    use crate::matching::MatchConfig;
+    use crate::search::Hit;
+    use crate::search::score::score;
+    use super::Scorer;
+
+    struct FullWords;
+
+    impl Scorer for FullWords {
+        fn score(&self, hit: &Hit, _record: &Record) -> isize {
+            hit.rmatches.iter()
+                .filter(|m| m.match_len() == m.word_len())
+                .count() as isize
+        }
+    }
+
+    #[test]
+    fn scorer_struct() {
+        let lang   = Lang::new();
+        let q      = tokenize_query("yellow mail", &lang);
+        let r      = Record::new(10, "yellow metal mailbox", 0, &lang);
+        let mut h  = Hit::from_field(&r, 0);
+        score(&q.to_ref(), &mut h, &MatchConfig::new());
+        assert_eq!(FullWords.score(&h, &r), 1);
+    }
+
+    #[test]
+    fn scorer_closure() {
+        let lang   = Lang::new();
+        let q      = tokenize_query("mailbox", &lang);
+        let r      = Record::new(10, "yellow metal mailbox", 7, &lang);
+        let mut h  = Hit::from_field(&r, 0);
+        score(&q.to_ref(), &mut h, &MatchConfig::new());
+        let scorer = |hit: &Hit, record: &Record| (hit.rmatches.len() * record.rating) as isize;
+        assert_eq!(scorer.score(&h, &r), 7);
+    }
+}
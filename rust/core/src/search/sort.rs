@@ -1,5 +1,83 @@
 use std::cmp::Ordering;
 use crate::search::Hit;
+use crate::search::score::ScoreType;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RankingConfig {
+    Lexicographic(Vec<ScoreType>),
+    Linear(Vec<(ScoreType, f64)>),
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Decision {
+    Score(ScoreType),
+    Linear,
+    Tie,
+}
+
+
+impl RankingConfig {
+    pub fn lexicographic(order: &[ScoreType]) -> Self {
+        RankingConfig::Lexicographic(order.to_vec())
+    }
+
+    pub fn linear(weights: &[(ScoreType, f64)]) -> Self {
+        RankingConfig::Linear(weights.to_vec())
+    }
+
+    pub fn compare(&self, hit1: &Hit, hit2: &Hit) -> Ordering {
+        match self {
+            RankingConfig::Lexicographic(order) => {
+                order.iter()
+                    .map(|&score| hit2.scores[score].cmp(&hit1.scores[score]))
+                    .find(|&ord| ord != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            },
+            RankingConfig::Linear(weights) => {
+                let total1 = linear_total(weights, hit1);
+                let total2 = linear_total(weights, hit2);
+                // Equal totals fall back to the default order to keep results stable.
+                total2
+                    .partial_cmp(&total1)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| compare_hits(hit1, hit2))
+            },
+        }
+    }
+
+    pub fn decide(&self, hit1: &Hit, hit2: &Hit) -> Decision {
+        let order = match self {
+            RankingConfig::Lexicographic(order) => &order[..],
+            RankingConfig::Linear(weights) => {
+                if linear_total(weights, hit1) != linear_total(weights, hit2) {
+                    return Decision::Linear;
+                }
+                &ScoreType::ALL[..]
+            },
+        };
+        order.iter()
+            .cloned()
+            .find(|&score| hit1.scores[score] != hit2.scores[score])
+            .map(Decision::Score)
+            .unwrap_or(Decision::Tie)
+    }
+}
+
+
+impl Default for RankingConfig {
+    fn default() -> Self {
+        RankingConfig::lexicographic(&ScoreType::ALL)
+    }
+}
+
+
+fn linear_total(weights: &[(ScoreType, f64)], hit: &Hit) -> f64 {
+    weights.iter()
+        .map(|&(score, weight)| hit.scores[score] as f64 * weight)
+        .sum()
+}
 
 
 pub fn compare_hits(hit1: &Hit, hit2: &Hit) -> Ordering {
@@ -9,3 +87,86 @@ pub fn compare_hits(hit1: &Hit, hit2: &Hit) -> Ordering {
         .find(|&ord| ord != Ordering::Equal)
         .unwrap_or(Ordering::Equal)
 }
+
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use crate::lang::Lang;
+    use crate::tokenization::tokenize_query;
+    use crate::store::Record;
+    use crate::matching::MatchConfig;
+    use crate::search::Hit;
+    use crate::search::score::{score, ScoreType};
+    use super::{RankingConfig, Decision, compare_hits};
+
+    #[test]
+    fn ranking_default() {
+        let lang   = Lang::new();
+        let q      = tokenize_query("yellow mailbox", &lang);
+        let r1     = Record::new(10, "yellow mailbox", 10, &lang);
+        let r2     = Record::new(20, "yelow mailbox",  90, &lang);
+        let mut h1 = Hit::from_field(&r1, 0);
+        let mut h2 = Hit::from_field(&r2, 0);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
+        let ranking = RankingConfig::default();
+        assert_eq!(ranking.compare(&h1, &h2), compare_hits(&h1, &h2));
+        assert_eq!(ranking.compare(&h1, &h2), Ordering::Less);
+    }
+
+    #[test]
+    fn ranking_lexicographic() {
+        let lang   = Lang::new();
+        let q      = tokenize_query("yellow mailbox", &lang);
+        let r1     = Record::new(10, "yellow mailbox", 10, &lang);
+        let r2     = Record::new(20, "yelow mailbox",  90, &lang);
+        let mut h1 = Hit::from_field(&r1, 0);
+        let mut h2 = Hit::from_field(&r2, 0);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
+        let ranking = RankingConfig::lexicographic(&[ScoreType::Rating, ScoreType::Chars]);
+        assert_eq!(ranking.compare(&h1, &h2), Ordering::Greater);
+        let ranking = RankingConfig::lexicographic(&[ScoreType::Words]);
+        assert_eq!(ranking.compare(&h1, &h2), Ordering::Equal);
+    }
+
+    #[test]
+    fn ranking_linear() {
+        let lang   = Lang::new();
+        let q      = tokenize_query("yellow mailbox", &lang);
+        let r1     = Record::new(10, "yellow mailbox", 10, &lang);
+        let r2     = Record::new(20, "yelow mailbox",  13, &lang);
+        let mut h1 = Hit::from_field(&r1, 0);
+        let mut h2 = Hit::from_field(&r2, 0);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
+        let ranking = RankingConfig::linear(&[(ScoreType::Chars, 1.0), (ScoreType::Rating, 1.0)]);
+        assert_eq!(ranking.compare(&h1, &h2), Ordering::Less);
+        let ranking = RankingConfig::linear(&[(ScoreType::Chars, 1.0), (ScoreType::Rating, 2.0)]);
+        assert_eq!(ranking.compare(&h1, &h2), Ordering::Greater);
+        let ranking = RankingConfig::linear(&[]);
+        assert_eq!(ranking.compare(&h1, &h2), compare_hits(&h1, &h2));
+    }
+
+    #[test]
+    fn ranking_decide() {
+        let lang   = Lang::new();
+        let q      = tokenize_query("yellow mailbox", &lang);
+        let r1     = Record::new(10, "yellow mailbox", 10, &lang);
+        let r2     = Record::new(20, "yelow mailbox",  13, &lang);
+        let mut h1 = Hit::from_field(&r1, 0);
+        let mut h2 = Hit::from_field(&r2, 0);
+        score(&q.to_ref(), &mut h1, &MatchConfig::new());
+        score(&q.to_ref(), &mut h2, &MatchConfig::new());
+        let ranking = RankingConfig::default();
+        assert_eq!(ranking.decide(&h1, &h2), Decision::Score(ScoreType::Chars));
+        assert_eq!(ranking.decide(&h1, &h1), Decision::Tie);
+        let ranking = RankingConfig::lexicographic(&[ScoreType::Words, ScoreType::Rating]);
+        assert_eq!(ranking.decide(&h1, &h2), Decision::Score(ScoreType::Rating));
+        let ranking = RankingConfig::linear(&[(ScoreType::Words, 1.0)]);
+        assert_eq!(ranking.decide(&h1, &h2), Decision::Score(ScoreType::Chars));
+        let ranking = RankingConfig::linear(&[(ScoreType::Rating, 1.0)]);
+        assert_eq!(ranking.decide(&h1, &h2), Decision::Linear);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::tokenization::TextOwn;
+
+
+pub struct QueryVariant {
+    pub text:          TextOwn,
+    pub layout:        bool,
+    pub substitutions: usize,
+    pub max_chars:     usize,
+    pub lang:          Option<usize>,
+}
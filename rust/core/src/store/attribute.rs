@@ -0,0 +1,69 @@
+#[derive(Clone, PartialEq, Debug)]
+pub enum Attr {
+    Int(i64),
+    Keyword(String),
+    Keywords(Vec<String>),
+}
+
+
+impl Attr {
+    pub fn keyword(value: &str) -> Attr {
+        Attr::Keyword(value.to_string())
+    }
+
+    pub fn keywords(values: &[&str]) -> Attr {
+        Attr::Keywords(values.iter().map(|v| v.to_string()).collect())
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Attr::Int(x) => Some(*x),
+            _            => None,
+        }
+    }
+
+    pub fn contains(&self, value: &Attr) -> bool {
+        // Keyword sets contain each of their members and any of their subsets.
+        match (self, value) {
+            (Attr::Int(x),      Attr::Int(y))      => x == y,
+            (Attr::Keyword(x),  Attr::Keyword(y))  => x == y,
+            (Attr::Keywords(x), Attr::Keyword(y))  => x.contains(y),
+            (Attr::Keyword(x),  Attr::Keywords(y)) => y.iter().all(|y| x == y),
+            (Attr::Keywords(x), Attr::Keywords(y)) => y.iter().all(|y| x.contains(y)),
+            _ => false,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Attr;
+
+    #[test]
+    fn contains_int() {
+        assert!( Attr::Int(5).contains(&Attr::Int(5)));
+        assert!(!Attr::Int(5).contains(&Attr::Int(6)));
+        assert!(!Attr::Int(5).contains(&Attr::keyword("5")));
+    }
+
+    #[test]
+    fn contains_keyword() {
+        let attr = Attr::keyword("foo");
+        assert!( attr.contains(&Attr::keyword("foo")));
+        assert!(!attr.contains(&Attr::keyword("bar")));
+        assert!( attr.contains(&Attr::keywords(&["foo"])));
+        assert!(!attr.contains(&Attr::keywords(&["foo", "bar"])));
+        assert!(!attr.contains(&Attr::Int(5)));
+    }
+
+    #[test]
+    fn contains_keywords() {
+        let attr = Attr::keywords(&["garden", "garden/tools", "garden/tools/shovels"]);
+        assert!( attr.contains(&Attr::keyword("garden/tools")));
+        assert!(!attr.contains(&Attr::keyword("kitchen")));
+        assert!( attr.contains(&Attr::keywords(&["garden", "garden/tools"])));
+        assert!(!attr.contains(&Attr::keywords(&["garden", "kitchen"])));
+        assert!(!attr.contains(&Attr::Int(1)));
+    }
+}
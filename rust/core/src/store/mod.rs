@@ -1,8 +1,10 @@
+mod attribute;
 mod record;
 mod store;
 mod trigram_index;
 
-pub use record::Record;
+pub use attribute::Attr;
+pub use record::{Record, Field, TITLE_FIELD};
 pub use store::Store;
 pub use trigram_index::TrigramIndex;
 
@@ -1,23 +1,71 @@
-use crate::tokenization::{TextOwn, tokenize_record};
+use fnv::{FnvHashMap as HashMap};
+use crate::tokenization::{TextOwn, tokenize_record, tokenize_translit};
 use crate::lang::Lang;
+use super::Attr;
+
+
+pub static TITLE_FIELD: &str = "title";
+
+
+#[derive(Debug)]
+pub struct Field {
+    pub name:     String,
+    pub text:     TextOwn,
+    pub translit: Option<TextOwn>,
+}
 
 
 #[derive(Debug)]
 pub struct Record {
     pub ix:     usize,
     pub id:     usize,
-    pub title:  TextOwn,
+    pub fields: Vec<Field>,
     pub rating: usize,
+    pub lang:   usize,
+    pub attrs:  HashMap<String, Attr>,
 }
 
 
 impl Record {
     pub fn new(id: usize, source: &str, rating: usize, lang: &Lang) -> Record {
+        Record::with_fields(id, &[(TITLE_FIELD, source)], rating, lang)
+    }
+
+    pub fn with_fields(id: usize, fields: &[(&str, &str)], rating: usize, lang: &Lang) -> Record {
+        if fields.is_empty() {
+            panic!("Record must have at least one field");
+        }
+        let fields = fields.iter()
+            .map(|&(name, source)| Field {
+                name:     name.to_string(),
+                text:     tokenize_record(source, lang),
+                translit: tokenize_translit(source, lang),
+            })
+            .collect();
         Record {
             ix: 0,
             id,
-            title: tokenize_record(source, lang),
+            fields,
             rating,
+            lang:  0,
+            attrs: HashMap::default(),
         }
     }
+
+    // Id of the store lang the record was tokenized with, see Store::add_lang.
+    pub fn set_lang(&mut self, lang: usize) {
+        self.lang = lang;
+    }
+
+    pub fn set_attr(&mut self, name: &str, value: Attr) {
+        self.attrs.insert(name.to_string(), value);
+    }
+
+    pub fn get_attr(&self, name: &str) -> Option<&Attr> {
+        self.attrs.get(name)
+    }
+
+    pub fn title(&self) -> &TextOwn {
+        &self.fields[0].text
+    }
 }
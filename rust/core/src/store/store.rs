@@ -1,17 +1,27 @@
 use std::cell::RefCell;
+use fnv::{FnvHashMap as HashMap};
 use crate::utils::to_vec;
 use crate::lang::Lang;
-use super::{Record, TrigramIndex, DEFAULT_LIMIT};
+use crate::matching::MatchConfig;
+use crate::search::{RankingConfig, ScoreType, Scorer};
+use super::{Record, Attr, TrigramIndex, DEFAULT_LIMIT};
 
 
 pub struct Store {
     pub next_ix:  usize,
     pub records:  Vec<Record>,
+    pub ix_by_id: HashMap<usize, usize>,
     pub limit:    usize,
     pub lang:     Lang,
+    pub langs:    Vec<Lang>,
+    pub matching: MatchConfig,
+    pub weights:  HashMap<String, f64>,
+    pub facet:    Option<String>,
+    pub ranking:  RankingConfig,
+    pub scorers:  Vec<(String, Box<dyn Scorer>)>,
     pub dividers: (Vec<char>, Vec<char>),
     pub index:    RefCell<TrigramIndex>,
-    pub top_ixs:  RefCell<Option<Vec<usize>>>,
+    pub top_ixs:  RefCell<Option<(RankingConfig, Vec<usize>)>>,
 }
 
 
@@ -20,8 +30,15 @@ impl Store {
         Self {
             next_ix:  0,
             records:  Vec::new(),
+            ix_by_id: HashMap::default(),
             limit:    DEFAULT_LIMIT,
             lang:     Lang::new(),
+            langs:    Vec::new(),
+            matching: MatchConfig::default(),
+            weights:  HashMap::default(),
+            facet:    None,
+            ranking:  RankingConfig::default(),
+            scorers:  Vec::new(),
             dividers: (vec!['['], vec![']']),
             index:    RefCell::new(TrigramIndex::new()),
             top_ixs:  RefCell::new(None),
@@ -29,17 +46,110 @@ impl Store {
     }
 
     pub fn add(&mut self, mut record: Record) {
-        let Self { next_ix, index, records, .. } = self;
+        let Self { next_ix, index, records, ix_by_id, top_ixs, .. } = self;
         debug_assert!(*next_ix == records.len(), "Invalid store.next_ix");
         record.ix = *next_ix;
         index.borrow_mut().add(&record);
+        ix_by_id.insert(record.id, record.ix);
         records.push(record);
         *next_ix += 1;
+        top_ixs.replace(None);
+    }
+
+    pub fn upsert(&mut self, mut record: Record) {
+        let ix = match self.ix_by_id.get(&record.id) {
+            Some(&ix) => ix,
+            None => return self.add(record),
+        };
+        // Attrs are set apart from records, so the old ones are kept unless the record sets them too.
+        for (name, value) in self.records[ix].attrs.drain() {
+            record.attrs.entry(name).or_insert(value);
+        }
+        let index = &mut *self.index.borrow_mut();
+        record.ix = ix;
+        index.remove(&self.records[ix]);
+        index.add(&record);
+        self.records[ix] = record;
+        self.top_ixs.replace(None);
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<Record> {
+        let ix    = self.ix_by_id.remove(&id)?;
+        let index = &mut *self.index.borrow_mut();
+
+        // Move the last record into the vacant ix to keep ixs dense.
+        index.remove(&self.records[ix]);
+        if ix + 1 < self.records.len() {
+            let last = self.records.last_mut().unwrap();
+            index.remove(last);
+            last.ix = ix;
+            index.add(last);
+            self.ix_by_id.insert(last.id, ix);
+        }
+        let removed = self.records.swap_remove(ix);
+        self.next_ix -= 1;
+        self.top_ixs.replace(None);
+
+        Some(removed)
+    }
+
+    pub fn set_attr(&mut self, id: usize, name: &str, value: Attr) -> bool {
+        match self.ix_by_id.get(&id) {
+            Some(&ix) => {
+                self.records[ix].set_attr(name, value);
+                self.top_ixs.replace(None);
+                true
+            },
+            None => false,
+        }
     }
 
     pub fn clear(&mut self) {
         self.records.clear();
+        self.ix_by_id.clear();
         self.next_ix = 0;
+        self.index.borrow_mut().clear();
+        self.top_ixs.replace(None);
+    }
+
+    // Extra lang for records in another language, the main one has id 0.
+    pub fn add_lang(&mut self, lang: Lang) -> usize {
+        self.langs.push(lang);
+        self.langs.len()
+    }
+
+    pub fn lang_by_id(&self, id: usize) -> &Lang {
+        match id {
+            0 => &self.lang,
+            _ => &self.langs[id - 1],
+        }
+    }
+
+    // Ids of the most probable langs of the query, all of them when there is no evidence.
+    pub fn detect_langs(&self, source: &str) -> Vec<usize> {
+        let words  = source.split_whitespace().map(to_vec).collect::<Vec<_>>();
+        let scores = (0 ..= self.langs.len())
+            .map(|id| {
+                let lang = self.lang_by_id(id);
+                words.iter().map(|word| lang.detect_score(word)).sum::<isize>()
+            })
+            .collect::<Vec<_>>();
+        let best = scores.iter().cloned().max().unwrap_or(0);
+        (0 ..= self.langs.len()).filter(|&id| scores[id] == best).collect()
+    }
+
+    pub fn set_field_weight(&mut self, field: &str, weight: f64) {
+        self.weights.insert(field.to_string(), weight);
+    }
+
+    pub fn field_weight(&self, field: &str) -> f64 {
+        self.weights.get(field).cloned().unwrap_or(1.0)
+    }
+
+    pub fn add_scorer<S>(&mut self, name: &str, scorer: S) -> ScoreType where S: Scorer + 'static {
+        self.scorers.push((name.to_string(), Box::new(scorer)));
+        self.top_ixs.replace(None);
+        ScoreType::Custom(self.scorers.len() - 1)
     }
 
     pub fn highlight_with(&mut self, dividers: (&str, &str)) {
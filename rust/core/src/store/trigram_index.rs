@@ -29,8 +29,8 @@ impl TrigramIndex {
 
     pub fn add(&mut self, record: &Record) {
         let Self { dict, len, .. } = self;
-        let Record { ix, title, .. } = record;
-        let grams = Self::collect_grams(&title.to_ref());
+        let Record { ix, .. } = record;
+        let grams = Self::collect_record_grams(record);
         *len += 1;
         for &gram in grams.iter() {
             dict
@@ -49,17 +49,76 @@ impl TrigramIndex {
         }
     }
 
-    pub fn prepare(
-        &mut self,
-        query:   &TextRef,
-        size:    usize,
-    ) -> Vec<usize> {
-        let Self { counts, dict, .. } = self;
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.dict.clear();
+        self.counts.clear();
+    }
+
+    pub fn remove(&mut self, record: &Record) {
+        let Self { dict, len, .. } = self;
+        let Record { ix, .. } = record;
+        let grams = Self::collect_record_grams(record);
+        *len -= 1;
+        for gram in grams.iter() {
+            let empty = match dict.get_mut(gram) {
+                Some(ixs) => {
+                    if let Ok(i) = ixs.binary_search(ix) {
+                        ixs.remove(i);
+                    }
+                    ixs.is_empty()
+                },
+                None => continue,
+            };
+            if empty {
+                dict.remove(gram);
+            }
+        }
+    }
 
+    pub fn prepare<F>(
+        &mut self,
+        query:    &TextRef,
+        size:     usize,
+        eligible: F,
+    ) -> Vec<usize> where F: Fn(usize) -> bool {
         if query.words.len() == 0 {
             return Vec::new();
         }
 
+        self.count_grams(query);
+
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|&(ix, &count)| count > 0 && eligible(ix))
+            .limit_sort_unstable(size * 10, |(_, count1), (_, count2)| count2.cmp(count1))
+            .map(|(ix, _)| ix)
+            .collect()
+    }
+
+    pub fn prepare_all<F>(
+        &mut self,
+        query:    &TextRef,
+        eligible: F,
+    ) -> Vec<usize> where F: Fn(usize) -> bool {
+        if query.words.is_empty() {
+            return Vec::new();
+        }
+
+        self.count_grams(query);
+
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|&(ix, &count)| count > 0 && eligible(ix))
+            .map(|(ix, _)| ix)
+            .collect()
+    }
+
+    fn count_grams(&mut self, query: &TextRef) {
+        let Self { counts, dict, .. } = self;
+
         counts.clear();
         counts.resize(self.len, 0);
 
@@ -74,14 +133,19 @@ impl TrigramIndex {
                 }
             }
         }
+    }
 
-        counts
-            .iter()
-            .enumerate()
-            .filter(|(_, &count)| count > 0)
-            .limit_sort_unstable(size * 10, |(_, count1), (_, count2)| count2.cmp(count1))
-            .map(|(ix, _)| ix)
-            .collect()
+    fn collect_record_grams(record: &Record) -> Vec<[char; 3]> {
+        let mut grams = Vec::new();
+        for field in &record.fields {
+            grams.extend(Self::collect_grams(&field.text.to_ref()));
+            if let Some(translit) = &field.translit {
+                grams.extend(Self::collect_grams(&translit.to_ref()));
+            }
+        }
+        grams.sort_unstable();
+        grams.dedup();
+        grams
     }
 
     fn collect_grams(text: &TextRef) -> Vec<[char; 3]> {
@@ -149,7 +213,7 @@ mod tests {
         for (i, query) in queries.iter().enumerate() {
             let query = tokenize_query(query, &lang);
             let query = query.to_ref();
-            let mut prepared = index.prepare(&query, size);
+            let mut prepared = index.prepare(&query, size, |_| true);
             dbg!(&query);
             prepared.sort();
             assert_debug_snapshot!(format!("{}-{}", name, i), prepared);
@@ -177,6 +241,68 @@ mod tests {
         assert_snapshot!(export_dict(&index));
     }
 
+    #[test]
+    fn remove_first() {
+        let lang        = Lang::new();
+        let mut index   = TrigramIndex::new();
+        let mut record1 = Record::new(10, "Foo Bar", 10, &lang);
+        let mut record2 = Record::new(20, "Bar Baz", 20, &lang);
+        record1.ix = 0;
+        record2.ix = 1;
+        index.add(&record1);
+        index.add(&record2);
+        index.remove(&record1);
+        assert_snapshot!(export_dict(&index));
+    }
+
+    #[test]
+    fn remove_readd() {
+        let lang       = Lang::new();
+        let mut index  = TrigramIndex::new();
+        let mut record = Record::new(10, "Foo Bar", 10, &lang);
+        record.ix = 0;
+        index.add(&record);
+        let before = export_dict(&index);
+        index.remove(&record);
+        assert_eq!(export_dict(&index), "");
+        index.add(&record);
+        assert_eq!(export_dict(&index), before);
+    }
+
+    #[test]
+    fn clear() {
+        let (mut index, records) = get_index();
+        index.clear();
+        assert_eq!(export_dict(&index), "");
+        index.add(&records[2]);
+        assert_eq!(index.len, 1);
+        assert!(index.dict.values().all(|ixs| ixs == &[2]));
+    }
+
+    #[test]
+    fn prepare_eligible() {
+        let lang = Lang::new();
+        let (mut index, _) = get_index();
+        let query    = tokenize_query("metal", &lang);
+        let query    = query.to_ref();
+        let prepared = index.prepare(&query, 3, |ix| ix != 1);
+        assert_eq!(prepared, vec![2]);
+    }
+
+    #[test]
+    fn prepare_all() {
+        let lang = Lang::new();
+        let (mut index, _) = get_index();
+        let query        = tokenize_query("the", &lang);
+        let query        = query.to_ref();
+        let mut prepared = index.prepare_all(&query, |ix| ix != 3);
+        prepared.sort();
+        assert_eq!(prepared, vec![1]);
+        let mut prepared = index.prepare_all(&query, |_| true);
+        prepared.sort();
+        assert_eq!(prepared, vec![1, 3]);
+    }
+
     #[test]
     fn prepare_mismatch() {
         check_prepare("mismatch", 3, &["zzzap!"]);
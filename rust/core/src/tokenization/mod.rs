@@ -17,10 +17,14 @@ pub fn tokenize_query(source: &str, lang: &Lang) -> TextOwn {
         .fin(false)
         .split(&[CharClass::Whitespace, CharClass::Control, CharClass::Punctuation], lang)
         .strip(&[CharClass::NotAlphaNum], lang)
-        .lower()
+        .lower(lang)
+        .elide(lang)
+        .decompound(lang)
+        .segment(lang)
         .set_pos(lang)
         .set_char_classes(lang)
         .set_stem(lang)
+        .set_phone(lang)
 }
 
 
@@ -29,8 +33,35 @@ pub fn tokenize_record(source: &str, lang: &Lang) -> TextOwn {
         .normalize(lang)
         .split(&[CharClass::Whitespace, CharClass::Control, CharClass::Punctuation], lang)
         .strip(&[CharClass::NotAlphaNum], lang)
-        .lower()
+        .lower(lang)
+        .elide(lang)
+        .decompound(lang)
+        .segment(lang)
         .set_pos(lang)
         .set_char_classes(lang)
         .set_stem(lang)
+        .set_phone(lang)
 }
+
+
+// Romanized copy of the record for matching across scripts, highlighting still uses the original source.
+pub fn tokenize_translit(source: &str, lang: &Lang) -> Option<TextOwn> {
+    let text = Text::from_str(source).normalize(lang);
+    let (source, chars, marks) = lang.transliterate(&text.source, &text.chars, &text.marks)?;
+    let text = Text {
+            words:   vec![WordShape::new(chars.len())],
+            classes: Vec::new(),
+            source,
+            chars,
+            marks,
+        }
+        .split(&[CharClass::Whitespace, CharClass::Control, CharClass::Punctuation], lang)
+        .strip(&[CharClass::NotAlphaNum], lang)
+        .lower(lang)
+        .set_char_classes(lang)
+        .set_stem(lang)
+        .set_phone(lang);
+    Some(text)
+}
+
+
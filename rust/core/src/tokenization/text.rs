@@ -7,19 +7,22 @@ use super::word_view::WordView;
 
 
 #[derive(PartialEq)]
-pub struct Text<W, T, C> where
+pub struct Text<W, T, C, M> where
     W: AsRef<[WordShape]>,
     T: AsRef<[char]>,
-    C: AsRef<[CharClass]>
+    C: AsRef<[CharClass]>,
+    M: AsRef<[(usize, char)]>
 {
     pub words:   W,
     pub source:  T,
     pub chars:   T,
     pub classes: C,
+    // Chars dropped by unicode reduction, like Arabic harakat, with the number of source chars before them.
+    pub marks:   M,
 }
 
-pub type TextOwn     = Text<Vec<WordShape>, Vec<char>, Vec<CharClass>>;
-pub type TextRef<'a> = Text<&'a [WordShape], &'a [char], &'a [CharClass]>;
+pub type TextOwn     = Text<Vec<WordShape>, Vec<char>, Vec<CharClass>, Vec<(usize, char)>>;
+pub type TextRef<'a> = Text<&'a [WordShape], &'a [char], &'a [CharClass], &'a [(usize, char)]>;
 
 
 impl TextOwn {
@@ -32,6 +35,7 @@ impl TextOwn {
             source,
             chars,
             classes,
+            marks: Vec::new(),
         }
     }
 
@@ -48,6 +52,7 @@ impl TextOwn {
             source:  &self.source,
             chars:   &self.chars,
             classes: &self.classes,
+            marks:   &self.marks,
         }
     }
 }
@@ -60,15 +65,17 @@ impl<'a> TextRef<'a> {
             source:  self.source.to_vec(),
             chars:   self.chars.to_vec(),
             classes: self.classes.to_vec(),
+            marks:   self.marks.to_vec(),
         }
     }
 }
 
 
-impl<W, T, C> Text<W, T, C> where
+impl<W, T, C, M> Text<W, T, C, M> where
     W: AsRef<[WordShape]>,
     T: AsRef<[char]>,
-    C: AsRef<[CharClass]>
+    C: AsRef<[CharClass]>,
+    M: AsRef<[(usize, char)]>
 {
     pub fn is_empty(&self) -> bool {
         self.words.as_ref().is_empty()
@@ -100,9 +107,10 @@ impl TextOwn {
             self.chars            = nfc;
             self.words[0].slice.1 = self.chars.len();
         }
-        if let Some((source, chars)) = lang.unicode_reduce(&self.chars) {
+        if let Some((source, chars, marks)) = lang.unicode_reduce(&self.chars) {
             self.source           = source;
             self.chars            = chars;
+            self.marks            = marks;
             self.words[0].slice.1 = self.chars.len();
         }
         self
@@ -133,6 +141,51 @@ impl TextOwn {
         self
     }
 
+    pub fn elide(mut self, lang: &Lang) -> Self {
+        let mut words = Vec::with_capacity(self.words.len());
+        for word in &self.words {
+            match word.elide(&self.chars, lang) {
+                Some((prefix, word)) => words.extend(vec![prefix, word]),
+                None                 => words.push(word.clone()),
+            }
+        }
+        self.words = words;
+        for (offset, word) in self.words.iter_mut().enumerate() {
+            word.offset = offset;
+        }
+        self
+    }
+
+    pub fn decompound(mut self, lang: &Lang) -> Self {
+        let mut words = Vec::with_capacity(self.words.len());
+        for word in &self.words {
+            match word.decompound(&self.chars, lang) {
+                Some(parts) => words.extend(parts),
+                None        => words.push(word.clone()),
+            }
+        }
+        self.words = words;
+        for (offset, word) in self.words.iter_mut().enumerate() {
+            word.offset = offset;
+        }
+        self
+    }
+
+    pub fn segment(mut self, lang: &Lang) -> Self {
+        let mut words = Vec::with_capacity(self.words.len());
+        for word in &self.words {
+            match word.segment(&self.chars, lang) {
+                Some(segments) => words.extend(segments),
+                None           => words.push(word.clone()),
+            }
+        }
+        self.words = words;
+        for (offset, word) in self.words.iter_mut().enumerate() {
+            word.offset = offset;
+        }
+        self
+    }
+
     pub fn set_stem(mut self, lang: &Lang) -> Self {
         for word in &mut self.words {
             word.set_stem(&self.chars, lang);
@@ -140,6 +193,13 @@ impl TextOwn {
         self
     }
 
+    pub fn set_phone(mut self, lang: &Lang) -> Self {
+        for word in &mut self.words {
+            word.set_phone(&self.chars, lang);
+        }
+        self
+    }
+
     pub fn set_pos(mut self, lang: &Lang) -> Self {
         for word in &mut self.words {
             word.set_pos(&self.chars, lang);
@@ -164,10 +224,10 @@ impl TextOwn {
         self
     }
 
-    pub fn lower(mut self) -> Self {
+    pub fn lower(mut self, lang: &Lang) -> Self {
         if self.chars.iter().any(|ch| ch.is_uppercase()) {
             for ch in &mut self.chars {
-                *ch = ch.to_lowercase().next().unwrap_or(*ch);
+                *ch = lang.lower(*ch);
             }
         }
         self
@@ -175,10 +235,11 @@ impl TextOwn {
 }
 
 
-impl<W, T, C> fmt::Debug for Text<W, T, C> where
+impl<W, T, C, M> fmt::Debug for Text<W, T, C, M> where
     W: AsRef<[WordShape]>,
     T: AsRef<[char]>,
-    C: AsRef<[CharClass]>
+    C: AsRef<[CharClass]>,
+    M: AsRef<[(usize, char)]>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Text {{")?;
@@ -203,7 +264,12 @@ impl<W, T, C> fmt::Debug for Text<W, T, C> where
 mod tests {
     use insta::assert_debug_snapshot;
     use crate::utils::to_vec;
-    use crate::lang::{Lang, CharClass, PartOfSpeech, lang_english, lang_portuguese, lang_german};
+    use crate::lang::{Lang, CharClass};
+    #[cfg(feature = "en")]                      use crate::lang::PartOfSpeech;
+    #[cfg(any(feature = "ja", feature = "zh"))] use crate::lang::lang_cjk;
+    #[cfg(feature = "en")]                      use crate::lang::lang_english;
+    #[cfg(feature = "pt")]                      use crate::lang::lang_portuguese;
+    #[cfg(feature = "de")]                      use crate::lang::lang_german;
     use super::{WordShape, Text};
 
     use CharClass::{
@@ -211,6 +277,7 @@ mod tests {
         Punctuation,
     };
 
+    #[cfg(feature = "pt")]
     #[test]
     fn text_normalize_nfd() {
         let lang = lang_portuguese();
@@ -218,6 +285,7 @@ mod tests {
         assert_debug_snapshot!((&text.source, &text.chars, &text.words[0]));
     }
 
+    #[cfg(feature = "de")]
     #[test]
     fn text_normalize_pad0() {
         let lang = lang_german();
@@ -225,6 +293,28 @@ mod tests {
         assert_debug_snapshot!((&text.source, &text.chars, &text.words[0]));
     }
 
+    #[cfg(feature = "de")]
+    #[test]
+    fn text_decompound() {
+        let lang = lang_german();
+        let text = Text::from_str("neue kaffeemaschine")
+            .split(&[Whitespace], &lang)
+            .fin(false)
+            .decompound(&lang);
+        assert_debug_snapshot!(text.words);
+    }
+
+    #[cfg(any(feature = "ja", feature = "zh"))]
+    #[test]
+    fn text_segment() {
+        let lang = lang_cjk();
+        let text = Text::from_str("東京 iphone用ケース")
+            .split(&[Whitespace], &lang)
+            .fin(false)
+            .segment(&lang);
+        assert_debug_snapshot!(text.words);
+    }
+
     #[test]
     fn text_split() {
         let lang = Lang::new();
@@ -254,13 +344,14 @@ mod tests {
         let chars = to_vec("-Foo- , Baz; ");
         let text  = Text {
                 words:  vec![
-                    WordShape { offset: 0, slice: (0,  5), stem: 5, pos: None, fin: true },  // "-Foo-"
-                    WordShape { offset: 1, slice: (6,  7), stem: 1, pos: None, fin: true },  // ","
-                    WordShape { offset: 2, slice: (8, 13), stem: 5, pos: None, fin: true },  // "Baz; "
+                    WordShape { offset: 0, slice: (0,  5), stem: 5, pos: None, fin: true, phone: None },  // "-Foo-"
+                    WordShape { offset: 1, slice: (6,  7), stem: 1, pos: None, fin: true, phone: None },  // ","
+                    WordShape { offset: 2, slice: (8, 13), stem: 5, pos: None, fin: true, phone: None },  // "Baz; "
                 ],
                 source:  chars.clone(),
                 chars:   chars.clone(),
                 classes: chars.iter().map(|_| CharClass::Any).collect(),
+                marks:   Vec::new(),
             }
             .strip(&[Whitespace, Punctuation], &lang);
         assert_debug_snapshot!(text);
@@ -273,24 +364,26 @@ mod tests {
         let chars = to_vec("-Foo- Baz; ");
         let text1 = Text {
                 words:  vec![
-                    WordShape { offset: 0, slice: (0, 5), stem: 5, pos: None, fin: true },  // "-Foo-"
-                    WordShape { offset: 1, slice: (5, 8), stem: 3, pos: None, fin: true },  // "Baz"
+                    WordShape { offset: 0, slice: (0, 5), stem: 5, pos: None, fin: true, phone: None },  // "-Foo-"
+                    WordShape { offset: 1, slice: (5, 8), stem: 3, pos: None, fin: true, phone: None },  // "Baz"
                 ],
                 source:  chars.clone(),
                 chars:   chars.clone(),
                 classes: chars.iter().map(|_| CharClass::Any).collect(),
+                marks:   Vec::new(),
             }
             .fin(false)
             .strip(&[Whitespace, Punctuation], &lang);
 
         let text2 = Text {
                 words:  vec![
-                    WordShape { offset: 0, slice: (0,  5), stem: 5, pos: None, fin: true },  // "-Foo-"
-                    WordShape { offset: 1, slice: (5, 10), stem: 5, pos: None, fin: true },  // "Baz; "
+                    WordShape { offset: 0, slice: (0,  5), stem: 5, pos: None, fin: true, phone: None },  // "-Foo-"
+                    WordShape { offset: 1, slice: (5, 10), stem: 5, pos: None, fin: true, phone: None },  // "Baz; "
                 ],
                 source:  chars.clone(),
                 chars:   chars.clone(),
                 classes: chars.iter().map(|_| CharClass::Any).collect(),
+                marks:   Vec::new(),
             }
             .fin(false)
             .strip(&[Whitespace, Punctuation], &lang);
@@ -304,47 +397,52 @@ mod tests {
         let chars = to_vec("Foo, Bar Baz");
         let text  = Text {
                 words:  vec![
-                    WordShape { offset: 0, slice: (0,  4), stem: 4, pos: None, fin: true }, // "Foo,"
-                    WordShape { offset: 1, slice: (5,  8), stem: 3, pos: None, fin: true }, // "Bar"
-                    WordShape { offset: 2, slice: (9, 12), stem: 3, pos: None, fin: true }, // "Baz"
+                    WordShape { offset: 0, slice: (0,  4), stem: 4, pos: None, fin: true, phone: None }, // "Foo,"
+                    WordShape { offset: 1, slice: (5,  8), stem: 3, pos: None, fin: true, phone: None }, // "Bar"
+                    WordShape { offset: 2, slice: (9, 12), stem: 3, pos: None, fin: true, phone: None }, // "Baz"
                 ],
                 source:  chars.clone(),
                 chars:   chars.clone(),
                 classes: chars.iter().map(|_| CharClass::Any).collect(),
+                marks:   Vec::new(),
             }
-            .lower();
+            .lower(&Lang::new());
         assert_debug_snapshot!(text);
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn text_stem() {
         let chars = to_vec("hello universe");
         let lang  = lang_english();
         let text  = Text {
                 words: vec![
-                    WordShape { offset: 0, slice: (0,  5), stem: 5, pos: None, fin: true }, // "hello"
-                    WordShape { offset: 1, slice: (6, 14), stem: 8, pos: None, fin: true }, // "universe"
+                    WordShape { offset: 0, slice: (0,  5), stem: 5, pos: None, fin: true, phone: None }, // "hello"
+                    WordShape { offset: 1, slice: (6, 14), stem: 8, pos: None, fin: true, phone: None }, // "universe"
                 ],
                 source:  chars.clone(),
                 chars:   chars.clone(),
                 classes: chars.iter().map(|_| CharClass::Any).collect(),
+                marks:   Vec::new(),
             }.set_stem(&lang);
         assert_eq!(text.words[0].stem, 5);
         assert_eq!(text.words[1].stem, 7);
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn text_pos() {
         let chars = to_vec("the universe");
         let lang  = lang_english();
         let text  = Text {
                 words: vec![
-                    WordShape { offset: 0, slice: (0,  3), stem: 3, pos: None, fin: true }, // "hello"
-                    WordShape { offset: 1, slice: (4, 12), stem: 8, pos: None, fin: true }, // "universe"
+                    WordShape { offset: 0, slice: (0,  3), stem: 3, pos: None, fin: true, phone: None }, // "hello"
+                    WordShape { offset: 1, slice: (4, 12), stem: 8, pos: None, fin: true, phone: None }, // "universe"
                 ],
                 source:  chars.clone(),
                 chars:   chars.clone(),
                 classes: chars.iter().map(|_| CharClass::Any).collect(),
+                marks:   Vec::new(),
             }.set_pos(&lang);
         assert_eq!(text.words[0].pos, Some(PartOfSpeech::Article));
         assert_eq!(text.words[1].pos, None);
@@ -357,6 +455,7 @@ mod tests {
         assert_debug_snapshot!(text.classes);
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn text_mark_char_classes_lang_en() {
         let lang  = lang_english();
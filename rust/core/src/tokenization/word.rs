@@ -27,6 +27,13 @@ pub trait Word {
         }
     }
 
+    // Only overlapping segments, like CJK bigrams, share chars.
+    fn overlaps(&self, other: &Self) -> bool {
+        let (left1, right1) = self.slice();
+        let (left2, right2) = other.slice();
+        left1 < right2 && left2 < right1
+    }
+
     fn dist(&self, other: &Self) -> usize {
         let (left1, right1) = self.slice();
         let (left2, right2) = other.slice();
@@ -1,4 +1,4 @@
-use crate::lang::{Lang, CharClass, CharPattern, PartOfSpeech};
+use crate::lang::{Lang, CharClass, CharPattern, PartOfSpeech, PhoneticKey};
 use super::word::Word;
 use super::word_view::WordView;
 use super::word_split::WordSplit;
@@ -12,6 +12,7 @@ pub struct WordShape {
     pub stem:   usize,
     pub pos:    Option<PartOfSpeech>,
     pub fin:    bool,
+    pub phone:  Option<PhoneticKey>,
 }
 
 
@@ -32,13 +33,15 @@ impl WordShape {
             stem:   len,
             pos:    None,
             fin:    true,
+            phone:  None,
         }
     }
 
-    pub fn to_view<'a, W, T, C>(&'a self, text: &'a Text<W, T, C>) -> WordView<'a> where
+    pub fn to_view<'a, W, T, C, M>(&'a self, text: &'a Text<W, T, C, M>) -> WordView<'a> where
         W: AsRef<[WordShape]>,
         T: AsRef<[char]>,
-        C: AsRef<[CharClass]>
+        C: AsRef<[CharClass]>,
+        M: AsRef<[(usize, char)]>
     {
         WordView::new(self, text)
     }
@@ -50,6 +53,7 @@ impl WordShape {
             stem:   other.slice.0 - self.slice.0 + other.stem,
             pos:    None,
             fin:    other.fin,
+            phone:  None,
         }
     }
 
@@ -62,6 +66,68 @@ impl WordShape {
         WordSplit::new(self, chars, pattern, lang)
     }
 
+    // Elided prefixes are followed by an apostrophe, proclitics are attached directly.
+    pub fn elide(&self, chars: &[char], lang: &Lang) -> Option<(Self, Self)> {
+        let chars = &chars[self.slice.0 .. self.slice.1];
+        let (len, gap) = match lang.elision(chars) {
+            Some(len) => (len, 1),
+            None      => (lang.proclitic(chars)?, 0),
+        };
+        let prefix = Self {
+            offset: self.offset,
+            slice:  (self.slice.0, self.slice.0 + len),
+            stem:   len,
+            pos:    None,
+            fin:    true,
+            phone:  None,
+        };
+        let word = Self {
+            offset: self.offset,
+            slice:  (self.slice.0 + len + gap, self.slice.1),
+            stem:   self.slice.1 - self.slice.0 - len - gap,
+            pos:    None,
+            fin:    self.fin,
+            phone:  None,
+        };
+        Some((prefix, word))
+    }
+
+    pub fn decompound(&self, chars: &[char], lang: &Lang) -> Option<Vec<Self>> {
+        let chars = &chars[self.slice.0 .. self.slice.1];
+        let parts = lang.decompound(chars)?;
+        let mut left  = self.slice.0;
+        let mut words = Vec::with_capacity(parts.len());
+        for (i, &len) in parts.iter().enumerate() {
+            words.push(Self {
+                offset: self.offset,
+                slice:  (left, left + len),
+                stem:   len,
+                pos:    None,
+                fin:    self.fin || i + 1 < parts.len(),
+                phone:  None,
+            });
+            left += len;
+        }
+        Some(words)
+    }
+
+    pub fn segment(&self, chars: &[char], lang: &Lang) -> Option<Vec<Self>> {
+        let chars    = &chars[self.slice.0 .. self.slice.1];
+        let segments = lang.segment(chars)?;
+        let words    = segments
+            .into_iter()
+            .map(|(left, right)| Self {
+                offset: self.offset,
+                slice:  (self.slice.0 + left, self.slice.0 + right),
+                stem:   right - left,
+                pos:    None,
+                fin:    self.fin || right < chars.len(),
+                phone:  None,
+            })
+            .collect();
+        Some(words)
+    }
+
     pub fn strip<P: CharPattern>(&mut self, chars: &[char], pattern: &P, lang: &Lang) -> &mut Self {
         let chars = &chars[self.slice.0 .. self.slice.1];
         let left  = chars.iter()
@@ -90,11 +156,17 @@ impl WordShape {
         self
     }
 
-    pub fn lower(&mut self, chars: &mut [char]) -> &mut Self {
+    pub fn set_phone(&mut self, chars: &[char], lang: &Lang) -> &mut Self {
+        let chars = &chars[self.slice.0 .. self.slice.1];
+        self.phone = lang.phonetic_key(chars);
+        self
+    }
+
+    pub fn lower(&mut self, chars: &mut [char], lang: &Lang) -> &mut Self {
         let chars = &mut chars[self.slice.0 .. self.slice.1];
         if chars.iter().any(|ch| ch.is_uppercase()) {
             for ch in chars {
-                *ch = ch.to_lowercase().next().unwrap_or(*ch);
+                *ch = lang.lower(*ch);
             }
         }
         self
@@ -106,7 +178,8 @@ impl WordShape {
 mod tests {
     use insta::assert_debug_snapshot;
     use crate::utils::to_vec;
-    use crate::lang::{Lang, CharClass, PartOfSpeech, lang_english};
+    use crate::lang::{Lang, CharClass, PartOfSpeech};
+    #[cfg(feature = "en")] use crate::lang::lang_english;
     use super::{Word, WordShape};
 
     use CharClass::{
@@ -134,6 +207,19 @@ mod tests {
         assert_eq!(w2.dist(&w1), 0);
     }
 
+    #[test]
+    fn word_overlaps() {
+        let mut w1 = WordShape::new(2);
+        let mut w2 = WordShape::new(2);
+        let mut w3 = WordShape::new(2);
+        w1.slice = (0, 2);
+        w2.slice = (1, 3);
+        w3.slice = (2, 4);
+        assert!(w1.overlaps(&w2));
+        assert!(w2.overlaps(&w1));
+        assert!(!w1.overlaps(&w3));
+    }
+
     #[test]
     #[should_panic]
     fn word_dist_malformed() {
@@ -237,6 +323,7 @@ mod tests {
         assert_eq!(word2.fin, true);
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn word_stem() {
         let chars = to_vec("university");
@@ -246,6 +333,7 @@ mod tests {
         assert_eq!(word.stem, 7);
     }
 
+    #[cfg(feature = "en")]
     #[test]
     fn word_pos() {
         let lang   = lang_english();
@@ -263,7 +351,7 @@ mod tests {
     fn word_lower() {
         let mut chars = to_vec(" Foo Bar, Baz; ");
         let mut word  = WordShape::new(chars.len());
-        word.lower(&mut chars[..]);
+        word.lower(&mut chars[..], &Lang::new());
         assert_debug_snapshot!(word);
     }
 }
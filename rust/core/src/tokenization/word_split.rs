@@ -56,6 +56,7 @@ impl<'a, 'b, P: CharPattern> Iterator for WordSplit<'a, 'b, P> {
             stem:   len,
             pos:    None,
             fin:    word.fin || *char_offset + len < word.len(),
+            phone:  None,
         };
 
         *char_offset += splitted.len();
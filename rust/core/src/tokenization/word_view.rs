@@ -1,4 +1,4 @@
-use crate::lang::{CharClass, PartOfSpeech};
+use crate::lang::{CharClass, PartOfSpeech, PhoneticKey};
 use super::text::Text;
 use super::word::Word;
 use super::word_shape::WordShape;
@@ -11,6 +11,7 @@ pub struct WordView<'a> {
     pub stem:    usize,
     pub pos:     Option<PartOfSpeech>,
     pub fin:     bool,
+    pub phone:   Option<PhoneticKey>,
     source:      &'a [char],
     chars:       &'a [char],
     classes:     &'a [CharClass],
@@ -27,10 +28,11 @@ impl<'a> Word for WordView<'a> {
 
 
 impl<'a> WordView<'a> {
-    pub fn new<W, T, C>(word: &'a WordShape, text: &'a Text<W, T, C>) -> Self where
+    pub fn new<W, T, C, M>(word: &'a WordShape, text: &'a Text<W, T, C, M>) -> Self where
         W: AsRef<[WordShape]>,
         T: AsRef<[char]>,
-        C: AsRef<[CharClass]>
+        C: AsRef<[CharClass]>,
+        M: AsRef<[(usize, char)]>
     {
         Self {
             offset:  word.offset,
@@ -38,6 +40,7 @@ impl<'a> WordView<'a> {
             stem:    word.stem,
             pos:     word.pos,
             fin:     word.fin,
+            phone:   word.phone,
             source:  &text.source.as_ref(),
             chars:   &text.chars.as_ref(),
             classes: &text.classes.as_ref(),
@@ -51,6 +54,7 @@ impl<'a> WordView<'a> {
             stem:   self.stem,
             pos:    self.pos,
             fin:    self.fin,
+            phone:  self.phone,
         }
     }
 
@@ -73,6 +77,7 @@ impl<'a> WordView<'a> {
             stem:    other.slice.0 - self.slice.0 + other.stem,
             pos:     None,
             fin:     other.fin,
+            phone:   None,
             source:  &self.source,
             chars:   &self.chars,
             classes: &self.classes,
@@ -1,11 +1,12 @@
 #![cfg(test)]
+#![cfg(feature = "en")]
 #![allow(non_snake_case)]
 
 use std::cell::RefCell;
 use std::fs;
 use serde_json::Value;
 use regex::Regex;
-use lucid_suggest_core::{Store, Record, tokenize_query, lang_english, SearchResult};
+use lucid_suggest_core::{Store, Record, tokenize_query, lang_english, SearchResult, MatchConfig, KeyboardLayout};
 
 
 thread_local! {
@@ -80,7 +81,7 @@ fn assert_any_match(hits: &[SearchResult], pattern: &str) -> usize {
 fn ecommerce_joined_match() {
     using_store(|store| {
         let query = tokenize_query("night light", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<night> <light>");
         assert_any_match(&hits,    r"<nightlight>");
     });
@@ -91,7 +92,7 @@ fn ecommerce_joined_match() {
 fn ecommerce_unpopular_hit() {
     using_store(|store| {
         let query = tokenize_query("wise", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<wise>");
     });
 }
@@ -101,7 +102,7 @@ fn ecommerce_unpopular_hit() {
 fn ecommerce_longest_match() {
     using_store(|store| {
         let query = tokenize_query("i wise", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<wise>");
         assert_any_match(&hits,    r"<i>");
     });
@@ -112,7 +113,7 @@ fn ecommerce_longest_match() {
 fn ecommerce_match_surface() {
     using_store(|store| {
         let query = tokenize_query("it it ornament", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<ornament>");
         assert_hit_match(&hits[1], r"<it>.*<it>");
     });
@@ -126,7 +127,7 @@ fn ecommerce_match_surface() {
 fn ecommerce_case__little_bird() {
     using_store(|store| {
         let query = tokenize_query("little bird", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<little> <bird>");
         assert_all_match(&hits, r"<(little|bird)>")
     });
@@ -140,7 +141,7 @@ fn ecommerce_case__little_bird() {
 fn ecommerce_case__50s() {
     using_store(|store| {
         let query = tokenize_query("50s", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<50's>");
     });
 }
@@ -153,7 +154,7 @@ fn ecommerce_case__50s() {
 fn ecommerce_case__hold() {
     using_store(|store| {
         let query = tokenize_query("hold", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_all_match(&hits, r"<hold");
     });
 }
@@ -166,7 +167,7 @@ fn ecommerce_case__hold() {
 fn ecommerce_case__backp() {
     using_store(|store| {
         let query = tokenize_query("backp", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_all_match(&hits[..5], r"<backp>ack");
     });
 }
@@ -179,7 +180,7 @@ fn ecommerce_case__backp() {
 fn ecommerce_case__chop() {
     using_store(|store| {
         let query = tokenize_query("chop", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_all_match(&hits[..5], r"<chop>ping");
     });
 }
@@ -192,7 +193,7 @@ fn ecommerce_case__chop() {
 fn ecommerce_case__pop_corn() {
     using_store(|store| {
         let query = tokenize_query("pop corn", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_all_match(&hits[..2], r"<popcorn>");
     });
 }
@@ -208,7 +209,7 @@ fn ecommerce_case__orna() {
         for len in 3 .. query.chars().count() {
             let query = query.chars().take(len).collect::<String>();
             let query = tokenize_query(&query, &store.lang);
-            let hits  = store.search(&query.to_ref());
+            let hits  = store.search(&query.to_ref(), None);
             assert_hit_match(&hits[0], r"<orn");
         }
     });
@@ -222,16 +223,77 @@ fn ecommerce_case__orna() {
 fn ecommerce_case__vnt() {
     using_store(|store| {
         let query = tokenize_query("vn", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<vin>");
 
         let query = tokenize_query("vnt", &store.lang);
-        let hits  = store.search(&query.to_ref());
+        let hits  = store.search(&query.to_ref(), None);
         assert_hit_match(&hits[0], r"<vint>age");
     });
 }
 
 
+/**
+ * Catalog words typed with a neighboring key, like "bsg" for "bag",
+ * were missed or displaced unless the keyboard layout was configured.
+ */
+#[test]
+fn ecommerce_keyboard_typos() {
+    let sample = [
+        ("bsg",    r"<bag>"),
+        ("cuo",    r"<cup>"),
+        ("jat",    r"<jar>"),
+        ("pwn",    r"<pen>"),
+        ("kwy",    r"<key>"),
+        ("tij",    r"<tin>"),
+        ("cafd",   r"<card>"),
+        ("trsy",   r"<tray>"),
+        ("bekl",   r"<bell>"),
+        ("lanp",   r"<lamp>"),
+        ("disj",   r"<dish>"),
+        ("dokl",   r"<doll>"),
+        ("bowk",   r"<bowl>"),
+        ("vasr",   r"<vase>"),
+        ("soao",   r"<soap>"),
+        ("wovker", r"<wicker>"),
+    ];
+    using_store(|store| {
+        for &(query, pattern) in sample.iter() {
+            let query = tokenize_query(query, &store.lang);
+
+            store.matching = MatchConfig::new();
+            let hits   = store.search(&query.to_ref(), None);
+            let missed = hits.first().filter(|hit| Regex::new(pattern).unwrap().is_match(&hit.title.to_lowercase())).is_none();
+            assert!(missed, "Pattern \"{}\" matches top1 without the keyboard layout", pattern);
+
+            store.matching = MatchConfig::new().keyboard(Some(KeyboardLayout::qwerty()));
+            let hits = store.search(&query.to_ref(), None);
+            assert_hit_match(&hits[0], pattern);
+        }
+        store.matching = MatchConfig::new();
+    });
+}
+
+
+/**
+ * Facet counts were taken from every matching record, so top hits
+ * with a facet could differ from the plain search ones.
+ */
+#[test]
+fn ecommerce_facet_same_results() {
+    let sample = ["night light", "wise", "hold", "backp", "chop", "pop corn", "orna", "vnt", "bsg"];
+    using_store(|store| {
+        for &query in sample.iter() {
+            let query   = tokenize_query(query, &store.lang);
+            let plain   = store.search(&query.to_ref(), None);
+            let faceted = store.search_facet(&query.to_ref(), None, "category").results;
+            let ids     = |hits: &[SearchResult]| hits.iter().map(|r| r.id).collect::<Vec<_>>();
+            assert_eq!(ids(&faceted), ids(&plain), "Facet changed results of \"{}\"", query.source.iter().collect::<String>());
+        }
+    });
+}
+
+
 // // TODO fix
 // /**
 //  * When typing a joined word, all typos are absorbed by the second part,
@@ -241,7 +303,7 @@ fn ecommerce_case__vnt() {
 // fn ecommerce_case__greenpink() {
 //     using_store(|store| {
 //         let query = tokenize_query("greenpink", &store.lang);
-//         let hits  = store.search(&query.to_ref());
+//         let hits  = store.search(&query.to_ref(), None);
 //         dbg!(&hits);
 //         // assert_hit_match(&hits[0], r"<vin>");
 //     });
@@ -257,7 +319,7 @@ fn ecommerce_case__vnt() {
 // fn ecommerce_case__bauble() {
 //     using_store(|store| {
 //         let query = tokenize_query("babule", &store.lang);
-//         let hits  = store.search(&query.to_ref());
+//         let hits  = store.search(&query.to_ref(), None);
 //         dbg!(&hits);
 //         // assert_all_match(&hits[..5], r"<bauble>");
 //     });
@@ -0,0 +1,68 @@
+#![cfg(test)]
+#![cfg(feature = "en")]
+
+use lucid_suggest_core::{
+    create_store,
+    destroy_store,
+    set_records,
+    set_keyword_attrs,
+    set_facet,
+    set_limit,
+    run_search,
+    using_results,
+    using_facets,
+    lang_english,
+};
+
+
+const RECORDS: &[(usize, &str, usize)] = &[
+    (10, "brown plush bear",     10),
+    (20, "the metal detector",   20),
+    (30, "yellow metal mailbox", 30),
+    (40, "metal garden bench",   40),
+];
+
+const CATEGORIES: &[(usize, &str)] = &[
+    (10, "toys"),
+    (20, "tools"),
+    (30, "garden"),
+    (30, "decor"),
+    (40, "garden"),
+];
+
+
+fn search(store_id: usize, query: &str) -> (Vec<usize>, Vec<(String, usize)>) {
+    run_search(store_id, query);
+    let ids    = using_results(store_id, |results| results.iter().map(|r| r.id).collect());
+    let facets = using_facets(store_id, |facets| facets.clone());
+    (ids, facets)
+}
+
+
+#[test]
+fn facets_global_api() {
+    let id = 1;
+    create_store(id, lang_english());
+    set_records(id, RECORDS.iter().cloned());
+    set_keyword_attrs(id, "category", CATEGORIES.iter().cloned());
+    set_limit(id, 1);
+
+    let (ids, facets) = search(id, "metal");
+    assert_eq!(ids,    vec![40]);
+    assert_eq!(facets, vec![]);
+
+    set_facet(id, Some("category"));
+    let (ids, facets) = search(id, "metal");
+    assert_eq!(ids,    vec![40]);
+    assert_eq!(facets, vec![
+        ("garden".to_string(), 2),
+        ("decor".to_string(),  1),
+        ("tools".to_string(),  1),
+    ]);
+
+    set_facet(id, None);
+    let (_, facets) = search(id, "metal");
+    assert_eq!(facets, vec![]);
+
+    destroy_store(id);
+}
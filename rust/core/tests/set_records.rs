@@ -0,0 +1,119 @@
+#![cfg(test)]
+#![cfg(feature = "en")]
+
+use lucid_suggest_core::{
+    create_store,
+    destroy_store,
+    set_records,
+    set_limit,
+    run_search,
+    using_results,
+    using_store,
+    lang_english,
+};
+
+
+const DATASET_1: &[(usize, &str, usize)] = &[
+    (10, "brown plush bear",     10),
+    (20, "the metal detector",   20),
+    (30, "yellow metal mailbox", 30),
+    (40, "thesaurus",            40),
+    (50, "wi-fi router",         50),
+];
+
+const DATASET_2: &[(usize, &str, usize)] = &[
+    (1, "red wooden chair",   50),
+    (2, "metal garden bench", 40),
+    (3, "plush teddy bear",   30),
+];
+
+const DATASET_3: &[(usize, &str, usize)] = &[
+    (50, "glass coffee table", 10),
+    (10, "mailbox stand",      20),
+];
+
+
+fn search_ids(store_id: usize, query: &str) -> Vec<usize> {
+    run_search(store_id, query);
+    using_results(store_id, |results| results.iter().map(|r| r.id).collect())
+}
+
+
+fn search_titles(store_id: usize, query: &str) -> Vec<String> {
+    run_search(store_id, query);
+    using_results(store_id, |results| results.iter().map(|r| r.title.clone()).collect())
+}
+
+
+fn with_store<F: FnOnce(usize)>(store_id: usize, f: F) {
+    create_store(store_id, lang_english());
+    f(store_id);
+    destroy_store(store_id);
+}
+
+
+#[test]
+fn set_records_replaces_search_results() {
+    with_store(1, |id| {
+        set_records(id, DATASET_1.iter().cloned());
+        assert_eq!(search_ids(id, "metal"), vec![30, 20]);
+        assert_eq!(search_ids(id, "bear"),  vec![10]);
+
+        set_records(id, DATASET_2.iter().cloned());
+        assert_eq!(search_ids(id, "metal"),   vec![2]);
+        assert_eq!(search_ids(id, "bear"),    vec![3]);
+        assert_eq!(search_ids(id, "mailbox"), Vec::<usize>::new());
+        assert_eq!(search_ids(id, "router"),  Vec::<usize>::new());
+
+        set_records(id, DATASET_3.iter().cloned());
+        assert_eq!(search_ids(id, "metal"),   Vec::<usize>::new());
+        assert_eq!(search_ids(id, "bear"),    Vec::<usize>::new());
+        assert_eq!(search_ids(id, "mailbox"), vec![10]);
+        assert_eq!(search_titles(id, "table"), vec!["glass coffee [table]"]);
+    });
+}
+
+
+#[test]
+fn set_records_replaces_empty_query_results() {
+    with_store(2, |id| {
+        set_records(id, DATASET_1.iter().cloned());
+        assert_eq!(search_ids(id, ""), vec![50, 40, 30, 20, 10]);
+
+        set_records(id, DATASET_2.iter().cloned());
+        assert_eq!(search_ids(id, ""), vec![1, 2, 3]);
+
+        set_records(id, DATASET_3.iter().cloned());
+        assert_eq!(search_ids(id, ""), vec![10, 50]);
+
+        set_records(id, Vec::new());
+        assert_eq!(search_ids(id, ""), Vec::<usize>::new());
+    });
+}
+
+
+#[test]
+fn set_records_same_dataset_twice() {
+    with_store(3, |id| {
+        set_records(id, DATASET_1.iter().cloned());
+        let first = search_titles(id, "metal");
+        set_records(id, DATASET_1.iter().cloned());
+        assert_eq!(search_titles(id, "metal"), first);
+        using_store(id, |store| {
+            assert_eq!(store.records.len(), DATASET_1.len());
+        });
+    });
+}
+
+
+#[test]
+fn set_records_after_limit_change() {
+    with_store(4, |id| {
+        set_limit(id, 2);
+        set_records(id, DATASET_1.iter().cloned());
+        assert_eq!(search_ids(id, ""), vec![50, 40]);
+        set_limit(id, 10);
+        set_records(id, DATASET_2.iter().cloned());
+        assert_eq!(search_ids(id, ""), vec![1, 2, 3]);
+    });
+}
@@ -2,26 +2,43 @@ use wasm_bindgen::prelude::*;
 use lucid_suggest_core as core;
 
 
+// False if the lang is unknown or not compiled in.
 #[wasm_bindgen]
-pub fn create_store(id: usize) {
-    core::create_store(id, get_lang());
+pub fn create_store(id: usize, lang: &str) -> bool {
+    match get_lang(lang) {
+        Some(lang) => {
+            core::create_store(id, lang);
+            true
+        },
+        None => false,
+    }
 }
 
 
-#[cfg(lang = "de")] pub fn get_lang() -> core::Lang { core::lang_german() }
-#[cfg(lang = "en")] pub fn get_lang() -> core::Lang { core::lang_english() }
-#[cfg(lang = "es")] pub fn get_lang() -> core::Lang { core::lang_spanish() }
-#[cfg(lang = "fr")] pub fn get_lang() -> core::Lang { core::lang_french() }
-#[cfg(lang = "pt")] pub fn get_lang() -> core::Lang { core::lang_portuguese() }
-#[cfg(lang = "ru")] pub fn get_lang() -> core::Lang { core::lang_russian() }
-#[cfg(not(any(
-    lang = "de",
-    lang = "en",
-    lang = "es",
-    lang = "fr",
-    lang = "pt",
-    lang = "ru",
-)))] pub fn get_lang() -> core::Lang { core::Lang::new() }
+pub fn get_lang(code: &str) -> Option<core::Lang> {
+    match code {
+        #[cfg(feature = "ar")] "ar" => Some(core::lang_arabic()),
+        #[cfg(feature = "cs")] "cs" => Some(core::lang_czech()),
+        #[cfg(feature = "da")] "da" => Some(core::lang_danish()),
+        #[cfg(feature = "de")] "de" => Some(core::lang_german()),
+        #[cfg(feature = "el")] "el" => Some(core::lang_greek()),
+        #[cfg(feature = "en")] "en" => Some(core::lang_english()),
+        #[cfg(feature = "es")] "es" => Some(core::lang_spanish()),
+        #[cfg(feature = "fr")] "fr" => Some(core::lang_french()),
+        #[cfg(feature = "he")] "he" => Some(core::lang_hebrew()),
+        #[cfg(feature = "it")] "it" => Some(core::lang_italian()),
+        #[cfg(feature = "ja")] "ja" => Some(core::lang_cjk()),
+        #[cfg(feature = "nl")] "nl" => Some(core::lang_dutch()),
+        #[cfg(feature = "no")] "no" => Some(core::lang_norwegian()),
+        #[cfg(feature = "pl")] "pl" => Some(core::lang_polish()),
+        #[cfg(feature = "pt")] "pt" => Some(core::lang_portuguese()),
+        #[cfg(feature = "ru")] "ru" => Some(core::lang_russian()),
+        #[cfg(feature = "sv")] "sv" => Some(core::lang_swedish()),
+        #[cfg(feature = "tr")] "tr" => Some(core::lang_turkish()),
+        #[cfg(feature = "zh")] "zh" => Some(core::lang_cjk()),
+        _ => None,
+    }
+}
 
 
 #[wasm_bindgen]
@@ -42,6 +59,103 @@ pub fn set_limit(id: usize, limit: usize)  {
 }
 
 
+// Scores are builtin or custom names, with a weight each for a linear ranking.
+// An unknown name or a missing weight is an error, and the ranking stays unchanged.
+#[wasm_bindgen]
+pub fn set_ranking(store_id: usize, scores: String, weights: &[f64]) -> Result<(), JsValue> {
+    let names = scores
+        .split('\0')
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>();
+    if !weights.is_empty() && weights.len() != names.len() {
+        return Err(JsValue::from_str(&format!("Got {} scores and {} weights", names.len(), weights.len())));
+    }
+    core::using_store(store_id, |store| {
+        let resolve = |name: &str| store.score_type(name)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown score {}", name)));
+        let ranking = if weights.is_empty() {
+            let order = names.iter()
+                .map(|&name| resolve(name))
+                .collect::<Result<Vec<_>, _>>()?;
+            core::RankingConfig::Lexicographic(order)
+        } else {
+            let pairs = names.iter()
+                .zip(weights.iter().cloned())
+                .map(|(&name, weight)| resolve(name).map(|score| (score, weight)))
+                .collect::<Result<Vec<_>, _>>()?;
+            core::RankingConfig::Linear(pairs)
+        };
+        store.ranking = ranking;
+        Ok(())
+    })
+}
+
+
+#[wasm_bindgen]
+pub fn set_typo_thresholds(store_id: usize, length: f64, jaccard: f64, damlev: f64) {
+    core::using_store(store_id, |store| {
+        store.matching.length_threshold  = length;
+        store.matching.jaccard_threshold = jaccard;
+        store.matching.damlev_threshold  = damlev;
+    });
+}
+
+
+#[wasm_bindgen]
+pub fn set_typo_budgets(store_id: usize, lens: &[usize], typos: &[f64]) {
+    let budgets = lens.iter()
+        .cloned()
+        .zip(typos.iter().cloned())
+        .collect::<Vec<_>>();
+    core::using_store(store_id, |store| {
+        store.matching = store.matching.clone().typo_budgets(&budgets);
+    });
+}
+
+
+#[wasm_bindgen]
+pub fn set_phonetic(store_id: usize, typos: Option<f64>) {
+    core::using_store(store_id, |store| {
+        store.matching.phonetic = typos;
+    });
+}
+
+
+#[wasm_bindgen]
+pub fn add_synonym(store_id: usize, from: String, to: String) {
+    core::add_synonym(store_id, &from, &to);
+}
+
+
+#[wasm_bindgen]
+pub fn add_synonyms(store_id: usize, words: String) {
+    let group: Vec<&str> = words.split('\0').collect();
+    core::add_synonyms(store_id, &group);
+}
+
+
+#[wasm_bindgen]
+pub fn add_compound_parts(store_id: usize, parts: String) {
+    let parts: Vec<&str> = parts.split('\0').collect();
+    core::add_compound_parts(store_id, &parts);
+}
+
+
+#[wasm_bindgen]
+pub fn set_keyboard_layouts(store_id: usize, names: String) {
+    let keyboard = names
+        .split('\0')
+        .filter_map(core::KeyboardLayout::from_name)
+        .fold(None, |merged: Option<core::KeyboardLayout>, layout| match merged {
+            Some(merged) => Some(merged.merge(&layout)),
+            None         => Some(layout),
+        });
+    core::using_store(store_id, |store| {
+        store.matching.keyboard = keyboard;
+    });
+}
+
+
 #[wasm_bindgen]
 pub fn set_records(store_id: usize, ids: &[usize], texts: String, ratings: &[usize]) {
     let records = ids.iter()
@@ -53,12 +167,89 @@ pub fn set_records(store_id: usize, ids: &[usize], texts: String, ratings: &[usi
 }
 
 
+#[wasm_bindgen]
+pub fn upsert_records(store_id: usize, ids: &[usize], texts: String, ratings: &[usize]) {
+    let records = ids.iter()
+        .cloned()
+        .zip(texts.split('\0'))
+        .zip(ratings)
+        .map(|((id, text), &rating)| (id, text, rating));
+    core::upsert_records(store_id, records)
+}
+
+
+// Id for records in another language, None if the lang is unknown or not compiled in.
+#[wasm_bindgen]
+pub fn add_lang(store_id: usize, lang: &str) -> Option<usize> {
+    get_lang(lang).map(|lang| core::add_lang(store_id, lang))
+}
+
+
+#[wasm_bindgen]
+pub fn set_lang_records(store_id: usize, ids: &[usize], texts: String, ratings: &[usize], langs: &[usize]) {
+    let records = ids.iter()
+        .cloned()
+        .zip(texts.split('\0'))
+        .zip(ratings)
+        .zip(langs)
+        .map(|(((id, text), &rating), &lang)| (id, text, rating, lang));
+    core::set_lang_records(store_id, records)
+}
+
+
+#[wasm_bindgen]
+pub fn upsert_lang_records(store_id: usize, ids: &[usize], texts: String, ratings: &[usize], langs: &[usize]) {
+    let records = ids.iter()
+        .cloned()
+        .zip(texts.split('\0'))
+        .zip(ratings)
+        .zip(langs)
+        .map(|(((id, text), &rating), &lang)| (id, text, rating, lang));
+    core::upsert_lang_records(store_id, records)
+}
+
+
+#[wasm_bindgen]
+pub fn remove_records(store_id: usize, ids: &[usize]) {
+    core::remove_records(store_id, ids.iter().cloned())
+}
+
+
+#[wasm_bindgen]
+pub fn set_keyword_attrs(store_id: usize, name: &str, ids: &[usize], values: String) {
+    let attrs = ids.iter()
+        .cloned()
+        .zip(values.split('\0'));
+    core::set_keyword_attrs(store_id, name, attrs)
+}
+
+
+#[wasm_bindgen]
+pub fn set_facet(store_id: usize, facet: &str) {
+    let facet = if facet.is_empty() { None } else { Some(facet) };
+    core::set_facet(store_id, facet)
+}
+
+
 #[wasm_bindgen]
 pub fn run_search(store_id: usize, query: &str) {
     core::run_search(store_id, query)
 }
 
 
+#[wasm_bindgen]
+pub fn run_search_page(store_id: usize, query: &str, offset: usize, limit: usize) -> usize {
+    core::run_search_page(store_id, query, offset, limit)
+}
+
+
+#[wasm_bindgen]
+pub fn explain_search(store_id: usize, query: &str) -> String {
+    let explanations = core::explain_search(store_id, query);
+    core::explanations_to_json(&explanations)
+}
+
+
 #[wasm_bindgen]
 pub fn get_result_ids(store_id: usize) -> Vec<usize> {
     core::using_results(store_id, |results| {
@@ -81,3 +272,27 @@ pub fn get_result_titles(store_id: usize) -> String {
         concat
     })
 }
+
+
+#[wasm_bindgen]
+pub fn get_facet_values(store_id: usize) -> String {
+    core::using_facets(store_id, |facets| {
+        let bytelen: usize = facets.iter()
+            .map(|(value, _)| value.len())
+            .sum();
+        let mut concat = String::with_capacity(bytelen + facets.len());
+        for (value, _) in facets.iter() {
+            concat.push_str(value);
+            concat.push('\0');
+        }
+        concat
+    })
+}
+
+
+#[wasm_bindgen]
+pub fn get_facet_counts(store_id: usize) -> Vec<usize> {
+    core::using_facets(store_id, |facets| {
+        facets.iter().map(|&(_, count)| count).collect()
+    })
+}